@@ -31,6 +31,7 @@ fn benchmark_memory_store(c: &mut Criterion) {
                 let update = MemoryUpdate {
                     key: key.clone(),
                     value: value.clone(),
+                    ttl: None,
                 };
                 memory.store(update).unwrap();
             });
@@ -54,6 +55,7 @@ fn benchmark_memory_load(c: &mut Criterion) {
             .store(MemoryUpdate {
                 key: key.clone(),
                 value: value.clone(),
+                ttl: None,
             })
             .unwrap();
 
@@ -92,6 +94,7 @@ fn benchmark_memory_batch(c: &mut Criterion) {
                         .map(|i| MemoryUpdate {
                             key: MemoryKey::new(&format!("batch_key_{}", i)).unwrap(),
                             value: format!("value_{}", i),
+                            ttl: None,
                         })
                         .collect();
 
@@ -115,6 +118,7 @@ fn benchmark_memory_batch(c: &mut Criterion) {
                             .store(MemoryUpdate {
                                 key: key.clone(),
                                 value: format!("value_{}", i),
+                                ttl: None,
                             })
                             .unwrap();
                         key
@@ -214,6 +218,7 @@ fn benchmark_concurrent_memory(c: &mut Criterion) {
                                     let update = MemoryUpdate {
                                         key: key.clone(),
                                         value: format!("value_{}_{}", i, j),
+                                        ttl: None,
                                     };
                                     mem.store(update).unwrap();
                                     mem.load(&key).unwrap();
@@ -253,6 +258,7 @@ fn benchmark_agent_scenario(c: &mut Criterion) {
                 .store(MemoryUpdate {
                     key: context_key.clone(),
                     value: "agent context data".to_string(),
+                    ttl: None,
                 })
                 .unwrap();
 
@@ -265,6 +271,7 @@ fn benchmark_agent_scenario(c: &mut Criterion) {
                 .store(MemoryUpdate {
                     key: result_key,
                     value: result.output().to_string(),
+                    ttl: None,
                 })
                 .unwrap();
 
@@ -291,6 +298,7 @@ fn benchmark_resource_usage(c: &mut Criterion) {
                 let update = MemoryUpdate {
                     key,
                     value: format!("growth_value_{}", i),
+                    ttl: None,
                 };
                 memory.store(update).unwrap();
             }
@@ -313,6 +321,7 @@ fn benchmark_resource_usage(c: &mut Criterion) {
                     .store(MemoryUpdate {
                         key,
                         value: "cleanup_value".to_string(),
+                        ttl: None,
                     })
                     .unwrap();
             }
@@ -324,6 +333,7 @@ fn benchmark_resource_usage(c: &mut Criterion) {
                     .store(MemoryUpdate {
                         key,
                         value: String::new(),
+                        ttl: None,
                     })
                     .unwrap();
             }
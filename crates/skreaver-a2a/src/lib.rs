@@ -9,6 +9,8 @@
 //! - **Streaming**: Support for streaming task updates
 //! - **A2A Client**: Connect to external A2A agents (requires `client` feature)
 //! - **A2A Server**: Expose Skreaver agents via A2A (requires `server` feature)
+//! - **Push Notifications**: Webhook delivery with retries and a dead-letter
+//!   queue (requires `client` feature)
 //!
 //! ## Protocol Overview
 //!
@@ -59,6 +61,10 @@ pub mod types;
 #[cfg(feature = "client")]
 pub mod client;
 
+// Push notification delivery (requires client feature)
+#[cfg(feature = "client")]
+pub mod push;
+
 // Server module (requires server feature)
 #[cfg(feature = "server")]
 pub mod server;
@@ -105,6 +111,11 @@ pub use types::{
 // Re-export client types
 #[cfg(feature = "client")]
 pub use client::{A2aClient, AuthConfig};
+#[cfg(feature = "client")]
+pub use push::{
+    DeadLetterNotification, DeliveryOutcome, HttpWebhookSender, NotificationDeadLetterQueue,
+    PushDeliveryStats, PushNotifier, PushRetryPolicy, WebhookSender,
+};
 
 // Re-export server types
 #[cfg(feature = "server")]
@@ -0,0 +1,593 @@
+//! Push Notification Delivery
+//!
+//! This module delivers A2A push notifications to client webhooks with a
+//! retry policy and a dead-letter path for notifications that could not be
+//! delivered.
+//!
+//! # Overview
+//!
+//! - [`PushNotifier`] sends a payload to the webhook in a
+//!   [`PushNotificationConfig`], retrying retryable failures with exponential
+//!   backoff according to a [`PushRetryPolicy`]
+//! - When retries are exhausted, or the webhook rejects the notification
+//!   outright, the notification is stored in a [`NotificationDeadLetterQueue`]
+//!   together with its target and payload so it can be replayed later
+//! - Delivery outcomes are counted in [`PushDeliveryStats`]
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use skreaver_a2a::push::{HttpWebhookSender, PushNotifier, PushRetryPolicy};
+//!
+//! let notifier = PushNotifier::new(HttpWebhookSender::new()?, PushRetryPolicy::default());
+//! let outcome = notifier.notify(&config, &serde_json::to_value(&event)?).await;
+//!
+//! // Later, once the webhook is reachable again
+//! notifier.replay_dead_letters().await;
+//! ```
+
+use crate::error::{A2aError, A2aResult};
+use crate::types::PushNotificationConfig;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use reqwest::{Client, StatusCode};
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+use tokio::sync::RwLock;
+use tracing::{debug, info, warn};
+
+/// Header carrying the webhook secret from [`PushNotificationConfig::secret`]
+pub const NOTIFICATION_TOKEN_HEADER: &str = "X-A2A-Notification-Token";
+
+/// Default timeout for a single webhook request
+const DEFAULT_WEBHOOK_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Retry policy for push notification delivery
+#[derive(Debug, Clone, PartialEq)]
+pub struct PushRetryPolicy {
+    /// Total delivery attempts, including the first one
+    pub max_attempts: u32,
+    /// Delay before the first retry
+    pub initial_backoff: Duration,
+    /// Upper bound for the delay between retries
+    pub max_backoff: Duration,
+    /// Factor applied to the delay after each retry
+    pub multiplier: f64,
+}
+
+impl Default for PushRetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 4,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(30),
+            multiplier: 2.0,
+        }
+    }
+}
+
+impl PushRetryPolicy {
+    /// Create a policy with the given number of attempts and default backoff
+    pub fn new(max_attempts: u32) -> Self {
+        Self {
+            max_attempts: max_attempts.max(1),
+            ..Default::default()
+        }
+    }
+
+    /// Deliver once and never retry
+    pub fn no_retry() -> Self {
+        Self::new(1)
+    }
+
+    /// Set the delay before the first retry
+    pub fn with_initial_backoff(mut self, backoff: Duration) -> Self {
+        self.initial_backoff = backoff;
+        self
+    }
+
+    /// Set the upper bound for the delay between retries
+    pub fn with_max_backoff(mut self, backoff: Duration) -> Self {
+        self.max_backoff = backoff;
+        self
+    }
+
+    /// Set the backoff multiplier
+    pub fn with_multiplier(mut self, multiplier: f64) -> Self {
+        self.multiplier = multiplier;
+        self
+    }
+
+    /// Delay to wait after the given failed attempt (1-based)
+    pub fn backoff_for(&self, attempt: u32) -> Duration {
+        let factor = self
+            .multiplier
+            .max(1.0)
+            .powi(attempt.saturating_sub(1) as i32);
+        self.initial_backoff.mul_f64(factor).min(self.max_backoff)
+    }
+}
+
+/// Transport used to POST notifications to a webhook
+#[async_trait]
+pub trait WebhookSender: Send + Sync {
+    /// Deliver `payload` to the webhook described by `config`
+    ///
+    /// Failures that may succeed on a later attempt must be reported with a
+    /// retryable [`A2aError`] (see [`A2aError::is_retryable`]).
+    async fn send(
+        &self,
+        config: &PushNotificationConfig,
+        payload: &serde_json::Value,
+    ) -> A2aResult<()>;
+}
+
+/// [`WebhookSender`] that POSTs JSON payloads over HTTP
+#[derive(Debug, Clone)]
+pub struct HttpWebhookSender {
+    http: Client,
+}
+
+impl HttpWebhookSender {
+    /// Create a sender with the default request timeout
+    pub fn new() -> A2aResult<Self> {
+        let http = Client::builder()
+            .timeout(DEFAULT_WEBHOOK_TIMEOUT)
+            .user_agent(format!("skreaver-a2a/{}", env!("CARGO_PKG_VERSION")))
+            .build()
+            .map_err(|e| {
+                A2aError::connection_error(format!("Failed to create HTTP client: {}", e))
+            })?;
+        Ok(Self { http })
+    }
+
+    /// Create a sender using a custom HTTP client
+    pub fn with_http_client(http: Client) -> Self {
+        Self { http }
+    }
+}
+
+#[async_trait]
+impl WebhookSender for HttpWebhookSender {
+    async fn send(
+        &self,
+        config: &PushNotificationConfig,
+        payload: &serde_json::Value,
+    ) -> A2aResult<()> {
+        let mut request = self.http.post(&config.webhook_url).json(payload);
+        if let Some(secret) = &config.secret {
+            request = request.header(NOTIFICATION_TOKEN_HEADER, secret);
+        }
+
+        let response = request.send().await.map_err(|e| {
+            if e.is_timeout() {
+                A2aError::Timeout {
+                    timeout_ms: DEFAULT_WEBHOOK_TIMEOUT.as_millis() as u64,
+                }
+            } else {
+                A2aError::connection_error(format!("Webhook request failed: {}", e))
+            }
+        })?;
+
+        let status = response.status();
+        if status.is_success() {
+            return Ok(());
+        }
+
+        Err(match status {
+            StatusCode::TOO_MANY_REQUESTS => A2aError::RateLimitExceeded {
+                retry_after_seconds: response
+                    .headers()
+                    .get(reqwest::header::RETRY_AFTER)
+                    .and_then(|v| v.to_str().ok())
+                    .and_then(|v| v.parse().ok())
+                    .unwrap_or(1),
+            },
+            StatusCode::REQUEST_TIMEOUT => A2aError::Timeout {
+                timeout_ms: DEFAULT_WEBHOOK_TIMEOUT.as_millis() as u64,
+            },
+            s if s.is_server_error() => {
+                A2aError::connection_error(format!("Webhook returned {}", s))
+            }
+            s => A2aError::protocol_error(format!("Webhook rejected notification: {}", s)),
+        })
+    }
+}
+
+/// A notification that could not be delivered
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeadLetterNotification {
+    /// Webhook the notification was meant for
+    pub target: PushNotificationConfig,
+    /// Notification payload
+    pub payload: serde_json::Value,
+    /// Delivery attempts made before giving up
+    pub attempts: u32,
+    /// Error from the last attempt
+    pub last_error: String,
+    /// When the notification was dead-lettered
+    pub failed_at: DateTime<Utc>,
+}
+
+/// Bounded store of undelivered push notifications
+///
+/// When full, the oldest entry is dropped to make room for a new one.
+#[derive(Debug)]
+pub struct NotificationDeadLetterQueue {
+    entries: RwLock<VecDeque<DeadLetterNotification>>,
+    max_size: usize,
+}
+
+impl Default for NotificationDeadLetterQueue {
+    fn default() -> Self {
+        Self::new(10_000)
+    }
+}
+
+impl NotificationDeadLetterQueue {
+    /// Create a queue holding at most `max_size` notifications
+    pub fn new(max_size: usize) -> Self {
+        Self {
+            entries: RwLock::new(VecDeque::new()),
+            max_size: max_size.max(1),
+        }
+    }
+
+    /// Add an undelivered notification
+    pub async fn push(&self, entry: DeadLetterNotification) {
+        let mut entries = self.entries.write().await;
+        if entries.len() >= self.max_size {
+            entries.pop_front();
+            warn!(
+                max_size = self.max_size,
+                "Notification DLQ full, dropping oldest entry"
+            );
+        }
+        entries.push_back(entry);
+    }
+
+    /// List all dead-lettered notifications, oldest first
+    pub async fn list(&self) -> Vec<DeadLetterNotification> {
+        self.entries.read().await.iter().cloned().collect()
+    }
+
+    /// Remove and return all dead-lettered notifications
+    pub async fn drain(&self) -> Vec<DeadLetterNotification> {
+        self.entries.write().await.drain(..).collect()
+    }
+
+    /// Number of dead-lettered notifications
+    pub async fn len(&self) -> usize {
+        self.entries.read().await.len()
+    }
+
+    /// Check if the queue is empty
+    pub async fn is_empty(&self) -> bool {
+        self.entries.read().await.is_empty()
+    }
+}
+
+/// Snapshot of push delivery counters
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PushDeliveryStats {
+    /// Notifications delivered successfully
+    pub delivered: u64,
+    /// Retries performed after a failed attempt
+    pub retries: u64,
+    /// Notifications moved to the dead-letter queue
+    pub dead_lettered: u64,
+}
+
+#[derive(Debug, Default)]
+struct DeliveryCounters {
+    delivered: AtomicU64,
+    retries: AtomicU64,
+    dead_lettered: AtomicU64,
+}
+
+/// Result of delivering a single notification
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DeliveryOutcome {
+    /// The webhook accepted the notification
+    Delivered {
+        /// Attempts needed, including the successful one
+        attempts: u32,
+    },
+    /// Delivery failed and the notification was dead-lettered
+    DeadLettered {
+        /// Attempts made before giving up
+        attempts: u32,
+        /// Error from the last attempt
+        error: String,
+    },
+}
+
+impl DeliveryOutcome {
+    /// Whether the notification reached the webhook
+    pub fn is_delivered(&self) -> bool {
+        matches!(self, Self::Delivered { .. })
+    }
+}
+
+/// Delivers push notifications with retries and a dead-letter path
+pub struct PushNotifier<S> {
+    sender: S,
+    policy: PushRetryPolicy,
+    dlq: Arc<NotificationDeadLetterQueue>,
+    counters: DeliveryCounters,
+}
+
+impl<S> std::fmt::Debug for PushNotifier<S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PushNotifier")
+            .field("policy", &self.policy)
+            .field("counters", &self.counters)
+            .finish()
+    }
+}
+
+impl<S: WebhookSender> PushNotifier<S> {
+    /// Create a notifier with its own dead-letter queue
+    pub fn new(sender: S, policy: PushRetryPolicy) -> Self {
+        Self::with_dead_letter_queue(
+            sender,
+            policy,
+            Arc::new(NotificationDeadLetterQueue::default()),
+        )
+    }
+
+    /// Create a notifier that dead-letters into a shared queue
+    pub fn with_dead_letter_queue(
+        sender: S,
+        policy: PushRetryPolicy,
+        dlq: Arc<NotificationDeadLetterQueue>,
+    ) -> Self {
+        Self {
+            sender,
+            policy,
+            dlq,
+            counters: DeliveryCounters::default(),
+        }
+    }
+
+    /// Get the retry policy
+    pub fn policy(&self) -> &PushRetryPolicy {
+        &self.policy
+    }
+
+    /// Get the dead-letter queue
+    pub fn dead_letter_queue(&self) -> &Arc<NotificationDeadLetterQueue> {
+        &self.dlq
+    }
+
+    /// Current delivery counters
+    pub fn stats(&self) -> PushDeliveryStats {
+        PushDeliveryStats {
+            delivered: self.counters.delivered.load(Ordering::Relaxed),
+            retries: self.counters.retries.load(Ordering::Relaxed),
+            dead_lettered: self.counters.dead_lettered.load(Ordering::Relaxed),
+        }
+    }
+
+    /// Deliver a notification, dead-lettering it if every attempt fails
+    pub async fn notify(
+        &self,
+        config: &PushNotificationConfig,
+        payload: &serde_json::Value,
+    ) -> DeliveryOutcome {
+        let mut attempt = 1;
+        loop {
+            let error = match self.sender.send(config, payload).await {
+                Ok(()) => {
+                    self.counters.delivered.fetch_add(1, Ordering::Relaxed);
+                    debug!(
+                        config_id = %config.id,
+                        attempts = attempt,
+                        "Push notification delivered"
+                    );
+                    return DeliveryOutcome::Delivered { attempts: attempt };
+                }
+                Err(e) => e,
+            };
+
+            if !error.is_retryable() || attempt >= self.policy.max_attempts {
+                return self.dead_letter(config, payload, attempt, error).await;
+            }
+
+            let backoff = self.policy.backoff_for(attempt);
+            debug!(
+                config_id = %config.id,
+                attempt,
+                backoff_ms = backoff.as_millis() as u64,
+                error = %error,
+                "Push notification failed, retrying"
+            );
+            self.counters.retries.fetch_add(1, Ordering::Relaxed);
+            tokio::time::sleep(backoff).await;
+            attempt += 1;
+        }
+    }
+
+    /// Re-attempt delivery of every dead-lettered notification
+    ///
+    /// Notifications that fail again are returned to the dead-letter queue.
+    /// Returns the number that were delivered.
+    pub async fn replay_dead_letters(&self) -> usize {
+        let entries = self.dlq.drain().await;
+        let mut delivered = 0;
+        for entry in entries {
+            if self
+                .notify(&entry.target, &entry.payload)
+                .await
+                .is_delivered()
+            {
+                delivered += 1;
+            }
+        }
+        if delivered > 0 {
+            info!(delivered, "Replayed dead-lettered push notifications");
+        }
+        delivered
+    }
+
+    async fn dead_letter(
+        &self,
+        config: &PushNotificationConfig,
+        payload: &serde_json::Value,
+        attempts: u32,
+        error: A2aError,
+    ) -> DeliveryOutcome {
+        warn!(
+            config_id = %config.id,
+            webhook_url = %config.webhook_url,
+            attempts,
+            error = %error,
+            "Push notification undeliverable, moving to dead-letter queue"
+        );
+        self.counters.dead_lettered.fetch_add(1, Ordering::Relaxed);
+
+        let error = error.to_string();
+        self.dlq
+            .push(DeadLetterNotification {
+                target: config.clone(),
+                payload: payload.clone(),
+                attempts,
+                last_error: error.clone(),
+                failed_at: Utc::now(),
+            })
+            .await;
+
+        DeliveryOutcome::DeadLettered { attempts, error }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::AtomicU32;
+
+    /// Sender failing with a retryable error for the first `failures` calls
+    struct FlakySender {
+        failures: u32,
+        calls: AtomicU32,
+    }
+
+    impl FlakySender {
+        fn new(failures: u32) -> Self {
+            Self {
+                failures,
+                calls: AtomicU32::new(0),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl WebhookSender for FlakySender {
+        async fn send(&self, _: &PushNotificationConfig, _: &serde_json::Value) -> A2aResult<()> {
+            let call = self.calls.fetch_add(1, Ordering::SeqCst);
+            if call < self.failures {
+                Err(A2aError::connection_error("webhook unavailable"))
+            } else {
+                Ok(())
+            }
+        }
+    }
+
+    fn config() -> PushNotificationConfig {
+        PushNotificationConfig {
+            id: "cfg-1".to_string(),
+            webhook_url: "https://client.example.com/hook".to_string(),
+            events: vec![],
+            secret: None,
+        }
+    }
+
+    fn fast_policy(max_attempts: u32) -> PushRetryPolicy {
+        PushRetryPolicy::new(max_attempts).with_initial_backoff(Duration::from_millis(1))
+    }
+
+    #[test]
+    fn test_backoff_grows_and_is_capped() {
+        let policy = PushRetryPolicy::default()
+            .with_initial_backoff(Duration::from_millis(100))
+            .with_max_backoff(Duration::from_millis(300));
+
+        assert_eq!(policy.backoff_for(1), Duration::from_millis(100));
+        assert_eq!(policy.backoff_for(2), Duration::from_millis(200));
+        assert_eq!(policy.backoff_for(3), Duration::from_millis(300));
+        assert_eq!(policy.backoff_for(10), Duration::from_millis(300));
+    }
+
+    #[tokio::test]
+    async fn test_flaky_webhook_succeeds_within_retries() {
+        let notifier = PushNotifier::new(FlakySender::new(2), fast_policy(3));
+        let payload = serde_json::json!({"taskId": "task-1", "status": "completed"});
+
+        let outcome = notifier.notify(&config(), &payload).await;
+
+        assert_eq!(outcome, DeliveryOutcome::Delivered { attempts: 3 });
+        assert!(notifier.dead_letter_queue().is_empty().await);
+        assert_eq!(
+            notifier.stats(),
+            PushDeliveryStats {
+                delivered: 1,
+                retries: 2,
+                dead_lettered: 0,
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_failing_webhook_lands_in_dlq_and_replays() {
+        let notifier = PushNotifier::new(FlakySender::new(3), fast_policy(3));
+        let payload = serde_json::json!({"taskId": "task-1", "status": "failed"});
+
+        let outcome = notifier.notify(&config(), &payload).await;
+
+        assert!(matches!(
+            outcome,
+            DeliveryOutcome::DeadLettered { attempts: 3, .. }
+        ));
+        let dead = notifier.dead_letter_queue().list().await;
+        assert_eq!(dead.len(), 1);
+        assert_eq!(dead[0].target, config());
+        assert_eq!(dead[0].payload, payload);
+        assert_eq!(dead[0].attempts, 3);
+        assert_eq!(notifier.stats().dead_lettered, 1);
+
+        // The sender recovers, so replaying delivers the notification
+        assert_eq!(notifier.replay_dead_letters().await, 1);
+        assert!(notifier.dead_letter_queue().is_empty().await);
+    }
+
+    #[tokio::test]
+    async fn test_non_retryable_failure_is_dead_lettered_immediately() {
+        struct RejectingSender;
+
+        #[async_trait]
+        impl WebhookSender for RejectingSender {
+            async fn send(
+                &self,
+                _: &PushNotificationConfig,
+                _: &serde_json::Value,
+            ) -> A2aResult<()> {
+                Err(A2aError::protocol_error(
+                    "Webhook rejected notification: 400",
+                ))
+            }
+        }
+
+        let notifier = PushNotifier::new(RejectingSender, fast_policy(5));
+        let outcome = notifier.notify(&config(), &serde_json::json!({})).await;
+
+        assert!(matches!(
+            outcome,
+            DeliveryOutcome::DeadLettered { attempts: 1, .. }
+        ));
+        assert_eq!(notifier.stats().retries, 0);
+    }
+}
@@ -7,21 +7,28 @@ use std::sync::Arc;
 use tracing::info;
 
 use crate::error::{AgentError, AgentResult};
-use crate::traits::UnifiedAgent;
-use crate::types::{AgentInfo, Protocol, StreamEvent, UnifiedMessage, UnifiedTask};
+use crate::pool::{ConnectionPool, PooledConnection};
+use crate::retry::{ConnectOptions, RetryPolicy};
+use crate::traits::{UnifiedAgent, error_event};
+use crate::types::{AgentInfo, Protocol, StreamEvent, TaskStatus, UnifiedMessage, UnifiedTask};
 
 use super::conversions::{
     a2a_card_to_agent_info, a2a_to_unified_stream_event, a2a_to_unified_task,
     unified_to_a2a_message,
 };
 
-use skreaver_a2a::{A2aClient, AgentCard};
+use skreaver_a2a::{A2aClient, A2aError, AgentCard, StreamingEvent as A2aStreamingEvent};
 
 /// Adapter that wraps an A2A client to provide the unified agent interface.
+///
+/// Agent card discovery and task lookups are idempotent and are retried and
+/// hedged according to the adapter's [`RetryPolicy`]. Sending messages and
+/// cancelling tasks are never retried.
 pub struct A2aAgentAdapter {
     info: AgentInfo,
     client: A2aClient,
     agent_card: Option<Arc<AgentCard>>,
+    retry: RetryPolicy,
 }
 
 impl std::fmt::Debug for A2aAgentAdapter {
@@ -42,18 +49,40 @@ impl A2aAgentAdapter {
             info,
             client,
             agent_card: None,
+            retry: RetryPolicy::default(),
         }
     }
 
+    /// Set the retry policy used for idempotent calls.
+    pub fn with_retry_policy(mut self, retry: RetryPolicy) -> Self {
+        self.retry = retry;
+        self
+    }
+
     /// Connect to an A2A agent and create an adapter.
     pub async fn connect(url: &str) -> AgentResult<Self> {
+        Self::connect_with_options(url, ConnectOptions::default()).await
+    }
+
+    /// Connect to an A2A agent with custom connect options.
+    pub async fn connect_with_options(url: &str, options: ConnectOptions) -> AgentResult<Self> {
         info!(url = %url, "Connecting to A2A agent");
         let client = A2aClient::new(url).map_err(|e| AgentError::ConnectionError(e.to_string()))?;
-        let mut adapter = Self::new(client);
+        let mut adapter = Self::new(client).with_retry_policy(options.retry_policy());
         adapter.discover().await?;
         Ok(adapter)
     }
 
+    /// Connect to an A2A agent through a pool, reusing an existing connection.
+    ///
+    /// The URL is the pool key; an agent whose card can no longer be fetched
+    /// is rediscovered with a new client.
+    pub async fn connect_pooled(pool: &ConnectionPool<Self>, url: &str) -> AgentResult<Arc<Self>> {
+        let options = pool.config().connect_options.clone();
+        pool.get_or_connect(url, || Self::connect_with_options(url, options))
+            .await
+    }
+
     /// Connect with bearer token authentication.
     pub async fn connect_with_bearer(url: &str, token: impl Into<String>) -> AgentResult<Self> {
         let client = A2aClient::new(url)
@@ -81,10 +110,11 @@ impl A2aAgentAdapter {
     /// Discover the agent's capabilities.
     pub async fn discover(&mut self) -> AgentResult<()> {
         let card = self
-            .client
-            .get_agent_card()
-            .await
-            .map_err(|e| AgentError::ConnectionError(e.to_string()))?;
+            .retry
+            .execute(true, || async {
+                self.client.get_agent_card().await.map_err(client_error)
+            })
+            .await?;
 
         // Update info from agent card
         self.info = a2a_card_to_agent_info(&card);
@@ -102,6 +132,52 @@ impl A2aAgentAdapter {
     pub fn client(&self) -> &A2aClient {
         &self.client
     }
+
+    /// Get the retry policy.
+    pub fn retry_policy(&self) -> &RetryPolicy {
+        &self.retry
+    }
+}
+
+/// Map a client error so that only transient failures are retryable.
+fn client_error(error: A2aError) -> AgentError {
+    if error.is_retryable() {
+        AgentError::ConnectionError(error.to_string())
+    } else {
+        AgentError::A2a(error)
+    }
+}
+
+/// Map one item of an A2A event stream to unified stream items.
+///
+/// A status update that fails the task is followed by an error event, so
+/// consumers can react to the failure without inspecting every status.
+fn map_stream_item(result: Result<A2aStreamingEvent, A2aError>) -> Vec<AgentResult<StreamEvent>> {
+    let event = match result {
+        Ok(event) => a2a_to_unified_stream_event(&event),
+        Err(e) => return vec![Err(client_error(e))],
+    };
+
+    let failure = match &event {
+        StreamEvent::StatusUpdate {
+            task_id,
+            status: TaskStatus::Failed,
+            message,
+        } => Some(error_event(
+            task_id,
+            "task_failed",
+            message.as_deref().unwrap_or("Task failed"),
+        )),
+        _ => None,
+    };
+    std::iter::once(event).chain(failure).map(Ok).collect()
+}
+
+#[async_trait]
+impl PooledConnection for A2aAgentAdapter {
+    async fn is_healthy(&self) -> bool {
+        self.client.get_agent_card().await.is_ok()
+    }
 }
 
 #[async_trait]
@@ -150,24 +226,22 @@ impl UnifiedAgent for A2aAgentAdapter {
             .await
             .map_err(|e| AgentError::ConnectionError(e.to_string()))?;
 
-        // Map the stream
         use futures::StreamExt;
-        let mapped = stream.map(|result| {
-            result
-                .map(|event| a2a_to_unified_stream_event(&event))
-                .map_err(|e| AgentError::ConnectionError(e.to_string()))
-        });
+        let mapped = stream.flat_map(|result| futures::stream::iter(map_stream_item(result)));
 
         Ok(Box::pin(mapped))
     }
 
     async fn get_task(&self, task_id: &str) -> AgentResult<UnifiedTask> {
-        let task = self.client.get_task(task_id).await.map_err(|e| match e {
-            skreaver_a2a::A2aError::TaskNotFound { .. } => {
-                AgentError::TaskNotFound(task_id.to_string())
-            }
-            _ => AgentError::ConnectionError(e.to_string()),
-        })?;
+        let task = self
+            .retry
+            .execute(true, || async {
+                self.client.get_task(task_id).await.map_err(|e| match e {
+                    A2aError::TaskNotFound { .. } => AgentError::TaskNotFound(task_id.to_string()),
+                    _ => client_error(e),
+                })
+            })
+            .await?;
 
         Ok(a2a_to_unified_task(&task))
     }
@@ -201,4 +275,61 @@ mod tests {
         assert!(info.supports_streaming);
         assert!(info.protocols.contains(&Protocol::A2a));
     }
+
+    #[test]
+    fn test_client_error_retryability() {
+        let transient = client_error(A2aError::ConnectionError {
+            message: "refused".to_string(),
+        });
+        assert!(transient.is_retryable());
+
+        let permanent = client_error(A2aError::TaskNotFound {
+            task_id: "t-1".to_string(),
+        });
+        assert!(!permanent.is_retryable());
+    }
+
+    #[test]
+    fn test_failed_status_is_followed_by_error_event() {
+        let update = |status| {
+            Ok(A2aStreamingEvent::TaskStatusUpdate(
+                skreaver_a2a::TaskStatusUpdateEvent {
+                    task_id: "t-1".to_string(),
+                    status,
+                    message: Some(skreaver_a2a::Message::agent("quota exceeded")),
+                    timestamp: chrono::Utc::now(),
+                },
+            ))
+        };
+
+        let working = map_stream_item(update(skreaver_a2a::TaskStatus::Working));
+        assert_eq!(working.len(), 1);
+
+        let failed = map_stream_item(update(skreaver_a2a::TaskStatus::Failed));
+        assert_eq!(failed.len(), 2);
+        assert!(matches!(
+            failed[0],
+            Ok(StreamEvent::StatusUpdate {
+                status: TaskStatus::Failed,
+                ..
+            })
+        ));
+        match &failed[1] {
+            Ok(StreamEvent::Error {
+                task_id,
+                code,
+                message,
+            }) => {
+                assert_eq!(task_id, "t-1");
+                assert_eq!(code, "task_failed");
+                assert_eq!(message, "quota exceeded");
+            }
+            other => panic!("Wrong event: {:?}", other),
+        }
+
+        let error = map_stream_item(Err(A2aError::ConnectionError {
+            message: "reset".to_string(),
+        }));
+        assert!(matches!(&error[..], [Err(e)] if e.is_retryable()));
+    }
 }
@@ -3,6 +3,7 @@
 //! This module provides bidirectional conversion between unified agent types
 //! and A2A protocol types.
 
+use crate::traits::{artifact_added, status_update};
 use crate::types::{
     AgentInfo, Artifact, Capability, ContentPart, MessageRole, Protocol, StreamEvent, TaskStatus,
     UnifiedMessage, UnifiedTask,
@@ -341,20 +342,19 @@ pub fn a2a_to_unified_status(status: &A2aTaskStatus) -> TaskStatus {
 /// Convert A2A streaming event to unified stream event.
 pub fn a2a_to_unified_stream_event(event: &A2aStreamingEvent) -> StreamEvent {
     match event {
-        A2aStreamingEvent::TaskStatusUpdate(update) => StreamEvent::StatusUpdate {
-            task_id: update.task_id.clone(),
-            status: a2a_to_unified_status(&update.status),
-            message: update.message.as_ref().and_then(|m| {
-                // Extract text from first text part if available
+        A2aStreamingEvent::TaskStatusUpdate(update) => status_update(
+            &update.task_id,
+            a2a_to_unified_status(&update.status),
+            // Extract text from first text part if available
+            update.message.as_ref().and_then(|m| {
                 m.parts
                     .iter()
                     .find_map(|p| p.as_text().map(|s| s.to_string()))
             }),
-        },
-        A2aStreamingEvent::TaskArtifactUpdate(update) => StreamEvent::ArtifactAdded {
-            task_id: update.task_id.clone(),
-            artifact: a2a_to_unified_artifact(&update.artifact),
-        },
+        ),
+        A2aStreamingEvent::TaskArtifactUpdate(update) => {
+            artifact_added(&update.task_id, a2a_to_unified_artifact(&update.artifact))
+        }
     }
 }
 
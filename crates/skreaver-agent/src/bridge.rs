@@ -4,12 +4,17 @@
 //! to another, enabling cross-protocol communication.
 
 use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
 use std::sync::Arc;
-use tracing::debug;
+use std::time::Duration;
+use tracing::{debug, warn};
 
+use crate::circuit_breaker::{CircuitBreaker, CircuitBreakerConfig, CircuitState};
+use crate::discovery::DiscoveryProvider;
 use crate::error::{AgentError, AgentResult};
+use crate::orchestration::TargetHealth;
 use crate::storage::TaskCache;
-use crate::traits::UnifiedAgent;
+use crate::traits::{UnifiedAgent, terminal_event};
 use crate::types::{AgentInfo, StreamEvent, TaskStatus, UnifiedMessage, UnifiedTask};
 
 // Import A2A conversion functions when the feature is enabled
@@ -135,6 +140,7 @@ impl skreaver_a2a::AgentHandler for A2aBridgeHandler {
 pub struct ProxyAgent {
     info: AgentInfo,
     target: Arc<dyn UnifiedAgent>,
+    circuit_breaker: Option<CircuitBreaker>,
 }
 
 impl ProxyAgent {
@@ -156,13 +162,50 @@ impl ProxyAgent {
             info = info.with_streaming();
         }
 
-        Self { info, target }
+        Self {
+            info,
+            target,
+            circuit_breaker: None,
+        }
+    }
+
+    /// Guard messages to the target with a circuit breaker.
+    ///
+    /// Once the target keeps failing, sending messages fails fast with
+    /// [`AgentError::CircuitOpen`] for the configured cooldown instead of
+    /// waiting on the target.
+    pub fn with_circuit_breaker(mut self, config: CircuitBreakerConfig) -> Self {
+        self.circuit_breaker = Some(CircuitBreaker::new(self.target.info().id.clone(), config));
+        self
     }
 
     /// Get the target agent.
     pub fn target(&self) -> &dyn UnifiedAgent {
         self.target.as_ref()
     }
+
+    /// Get the circuit breaker, if one is configured.
+    pub fn circuit_breaker(&self) -> Option<&CircuitBreaker> {
+        self.circuit_breaker.as_ref()
+    }
+
+    /// Get the circuit state; a proxy without a breaker is always closed.
+    pub fn circuit_state(&self) -> CircuitState {
+        self.circuit_breaker
+            .as_ref()
+            .map_or(CircuitState::Closed, CircuitBreaker::state)
+    }
+
+    /// Run a call to the target through the circuit breaker, if any.
+    async fn guarded<T>(
+        &self,
+        call: impl std::future::Future<Output = AgentResult<T>>,
+    ) -> AgentResult<T> {
+        match &self.circuit_breaker {
+            Some(breaker) => breaker.call(call).await,
+            None => call.await,
+        }
+    }
 }
 
 #[async_trait]
@@ -176,7 +219,7 @@ impl UnifiedAgent for ProxyAgent {
             target = %self.target.info().id,
             "Proxying message"
         );
-        self.target.send_message(message).await
+        self.guarded(self.target.send_message(message)).await
     }
 
     async fn send_message_to_task(
@@ -184,7 +227,8 @@ impl UnifiedAgent for ProxyAgent {
         task_id: &str,
         message: UnifiedMessage,
     ) -> AgentResult<UnifiedTask> {
-        self.target.send_message_to_task(task_id, message).await
+        self.guarded(self.target.send_message_to_task(task_id, message))
+            .await
     }
 
     async fn send_message_streaming(
@@ -192,7 +236,8 @@ impl UnifiedAgent for ProxyAgent {
         message: UnifiedMessage,
     ) -> AgentResult<std::pin::Pin<Box<dyn futures::Stream<Item = AgentResult<StreamEvent>> + Send>>>
     {
-        self.target.send_message_streaming(message).await
+        self.guarded(self.target.send_message_streaming(message))
+            .await
     }
 
     async fn get_task(&self, task_id: &str) -> AgentResult<UnifiedTask> {
@@ -204,10 +249,78 @@ impl UnifiedAgent for ProxyAgent {
     }
 }
 
+/// Metadata key holding every target's [`FanOutResult`] on a fan-out task.
+pub const FAN_OUT_RESULTS_KEY: &str = "fan_out.results";
+
+/// Metadata key holding the [`FanOutResult`]s of failed targets only.
+pub const FAN_OUT_ERRORS_KEY: &str = "fan_out.errors";
+
+/// Metadata key holding the IDs of targets skipped as unhealthy.
+pub const FAN_OUT_SKIPPED_KEY: &str = "fan_out.skipped";
+
+/// How a [`FanOutAgent`] decides the combined status when targets fail.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum FanOutPolicy {
+    /// Fail the combined task if any target fails
+    AllOrNothing,
+    /// Complete with whatever succeeded; fail only if every target fails
+    #[default]
+    BestEffort,
+    /// Complete if at least this many targets succeed
+    Quorum(usize),
+}
+
+impl FanOutPolicy {
+    /// Check whether `succeeded` out of `total` targets satisfies the policy.
+    pub fn is_satisfied(&self, succeeded: usize, total: usize) -> bool {
+        match self {
+            Self::AllOrNothing => succeeded == total,
+            Self::BestEffort => succeeded > 0 || total == 0,
+            Self::Quorum(required) => succeeded >= *required,
+        }
+    }
+}
+
+/// Outcome of a single fan-out target.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FanOutResult {
+    /// ID of the target agent
+    pub agent_id: String,
+    /// ID of the task the target returned, if it responded
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub task_id: Option<String>,
+    /// Final status reported for the target
+    pub status: TaskStatus,
+    /// Error message if the call failed or timed out
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl FanOutResult {
+    /// Check whether the target succeeded.
+    pub fn is_success(&self) -> bool {
+        self.error.is_none()
+            && !matches!(
+                self.status,
+                TaskStatus::Failed | TaskStatus::Cancelled | TaskStatus::Rejected
+            )
+    }
+}
+
 /// A fan-out agent that sends messages to multiple agents.
+///
+/// Every target is called concurrently. The combined task carries each
+/// target's [`FanOutResult`] under [`FAN_OUT_RESULTS_KEY`] and the failures
+/// under [`FAN_OUT_ERRORS_KEY`]; its status is decided by the
+/// [`FanOutPolicy`]. With health checks enabled, targets reported unhealthy
+/// are skipped, listed under [`FAN_OUT_SKIPPED_KEY`], and do not count
+/// towards the policy.
 pub struct FanOutAgent {
     info: AgentInfo,
     targets: Vec<Arc<dyn UnifiedAgent>>,
+    policy: FanOutPolicy,
+    target_timeout: Option<Duration>,
+    health: TargetHealth,
     tasks: TaskCache,
 }
 
@@ -217,10 +330,48 @@ impl FanOutAgent {
         Self {
             info: AgentInfo::new(id, name),
             targets: Vec::new(),
+            policy: FanOutPolicy::default(),
+            target_timeout: None,
+            health: TargetHealth::default(),
             tasks: TaskCache::new(),
         }
     }
 
+    /// Set the policy deciding the combined status.
+    pub fn with_policy(mut self, policy: FanOutPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Bound how long each target may take before it counts as failed.
+    pub fn with_target_timeout(mut self, timeout: Duration) -> Self {
+        self.target_timeout = Some(timeout);
+        self
+    }
+
+    /// Skip targets that `provider` reports as unhealthy.
+    ///
+    /// Targets are matched to registrations by agent ID. A target is skipped
+    /// only when it is registered and none of its registrations is usable;
+    /// unregistered targets are always called.
+    pub fn with_health_checks(mut self, provider: Arc<dyn DiscoveryProvider>) -> Self {
+        self.health.provider = Some(provider);
+        self
+    }
+
+    /// Also skip targets reported as degraded (default: false).
+    pub fn with_skip_degraded(mut self, skip: bool) -> Self {
+        self.health.skip_degraded = skip;
+        self
+    }
+
+    /// Fail before calling any target if fewer than `min` remain after
+    /// skipping unhealthy ones (default: 1).
+    pub fn with_min_healthy_targets(mut self, min: usize) -> Self {
+        self.health.min_healthy_targets = min.max(1);
+        self
+    }
+
     /// Add a target agent.
     pub fn add_target(&mut self, agent: Arc<dyn UnifiedAgent>) {
         // Merge capabilities
@@ -236,6 +387,38 @@ impl FanOutAgent {
     pub fn targets(&self) -> &[Arc<dyn UnifiedAgent>] {
         &self.targets
     }
+
+    /// Get the aggregation policy.
+    pub fn policy(&self) -> FanOutPolicy {
+        self.policy
+    }
+
+    /// Read the per-target results recorded on a fan-out task.
+    pub fn results(task: &UnifiedTask) -> Vec<FanOutResult> {
+        task.metadata
+            .get(FAN_OUT_RESULTS_KEY)
+            .and_then(|value| serde_json::from_value(value.clone()).ok())
+            .unwrap_or_default()
+    }
+
+    /// Send to one target, failing it once the per-target timeout elapses.
+    async fn call_target(
+        &self,
+        target: &Arc<dyn UnifiedAgent>,
+        message: UnifiedMessage,
+    ) -> AgentResult<UnifiedTask> {
+        let call = target.send_message(message);
+        match self.target_timeout {
+            Some(timeout) => tokio::time::timeout(timeout, call).await.map_err(|_| {
+                AgentError::Timeout(format!(
+                    "{} did not respond within {:?}",
+                    target.info().id,
+                    timeout
+                ))
+            })?,
+            None => call.await,
+        }
+    }
 }
 
 #[async_trait]
@@ -245,21 +428,30 @@ impl UnifiedAgent for FanOutAgent {
     }
 
     async fn send_message(&self, message: UnifiedMessage) -> AgentResult<UnifiedTask> {
-        // Send to all targets concurrently
-        let futures: Vec<_> = self
-            .targets
+        let (targets, skipped) = self.health.select(&self.info.id, &self.targets).await?;
+
+        // Send to all selected targets concurrently
+        let futures: Vec<_> = targets
             .iter()
-            .map(|t| t.send_message(message.clone()))
+            .map(|t| self.call_target(t, message.clone()))
             .collect();
 
         let results = futures::future::join_all(futures).await;
 
         // Combine results into a single task
         let mut combined = UnifiedTask::new_with_uuid();
+        let mut outcomes = Vec::with_capacity(results.len());
 
-        for result in results {
+        for (target, result) in targets.iter().zip(results) {
+            let agent_id = target.info().id.clone();
             match result {
                 Ok(task) => {
+                    outcomes.push(FanOutResult {
+                        agent_id,
+                        task_id: Some(task.id.clone()),
+                        status: task.status,
+                        error: None,
+                    });
                     // Add messages from this task
                     for msg in task.messages {
                         combined.add_message(msg);
@@ -270,13 +462,41 @@ impl UnifiedAgent for FanOutAgent {
                     }
                 }
                 Err(e) => {
+                    warn!(target = %agent_id, error = %e, "Fan-out target failed");
                     // Add error as message
-                    combined.add_message(UnifiedMessage::agent(format!("Error: {}", e)));
+                    combined.add_message(UnifiedMessage::agent(format!(
+                        "Agent {} failed: {}",
+                        agent_id, e
+                    )));
+                    outcomes.push(FanOutResult {
+                        agent_id,
+                        task_id: None,
+                        status: TaskStatus::Failed,
+                        error: Some(e.to_string()),
+                    });
                 }
             }
         }
 
-        combined.set_status(TaskStatus::Completed);
+        let succeeded = outcomes.iter().filter(|o| o.is_success()).count();
+        combined.set_status(if self.policy.is_satisfied(succeeded, outcomes.len()) {
+            TaskStatus::Completed
+        } else {
+            TaskStatus::Failed
+        });
+
+        let errors: Vec<_> = outcomes.iter().filter(|o| !o.is_success()).collect();
+        combined
+            .metadata
+            .insert(FAN_OUT_ERRORS_KEY.to_string(), serde_json::json!(errors));
+        combined
+            .metadata
+            .insert(FAN_OUT_RESULTS_KEY.to_string(), serde_json::json!(outcomes));
+        if !skipped.is_empty() {
+            combined
+                .metadata
+                .insert(FAN_OUT_SKIPPED_KEY.to_string(), serde_json::json!(skipped));
+        }
 
         // Store the task for later retrieval
         self.tasks.insert(combined.clone()).await;
@@ -298,8 +518,9 @@ impl UnifiedAgent for FanOutAgent {
         message: UnifiedMessage,
     ) -> AgentResult<std::pin::Pin<Box<dyn futures::Stream<Item = AgentResult<StreamEvent>> + Send>>>
     {
-        // For streaming, we just use the first target that supports it
-        for target in &self.targets {
+        // For streaming, we just use the first healthy target that supports it
+        let (targets, _) = self.health.select(&self.info.id, &self.targets).await?;
+        for target in &targets {
             if target.supports_streaming() {
                 return target.send_message_streaming(message).await;
             }
@@ -307,17 +528,9 @@ impl UnifiedAgent for FanOutAgent {
 
         // Fall back to non-streaming
         let task = self.send_message(message).await?;
-        let task_id = task.id.clone();
-
-        let stream = async_stream::stream! {
-            yield Ok(StreamEvent::StatusUpdate {
-                task_id: task_id.clone(),
-                status: TaskStatus::Completed,
-                message: None,
-            });
-        };
-
-        Ok(Box::pin(stream))
+        Ok(Box::pin(futures::stream::once(async move {
+            Ok(terminal_event(&task))
+        })))
     }
 
     async fn get_task(&self, task_id: &str) -> AgentResult<UnifiedTask> {
@@ -341,6 +554,7 @@ impl UnifiedAgent for FanOutAgent {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::discovery::{AgentRegistration, HealthStatus, InMemoryDiscoveryProvider};
 
     #[test]
     fn test_fan_out_agent_creation() {
@@ -348,4 +562,293 @@ mod tests {
         assert_eq!(agent.info().id, "fan-out-1");
         assert!(agent.targets().is_empty());
     }
+
+    /// Agent that fails while `failing` is set and counts the calls it receives
+    struct FlakyAgent {
+        info: AgentInfo,
+        failing: std::sync::atomic::AtomicBool,
+        calls: std::sync::atomic::AtomicUsize,
+    }
+
+    #[async_trait]
+    impl UnifiedAgent for FlakyAgent {
+        fn info(&self) -> &AgentInfo {
+            &self.info
+        }
+
+        async fn send_message(&self, message: UnifiedMessage) -> AgentResult<UnifiedTask> {
+            use std::sync::atomic::Ordering;
+
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            if self.failing.load(Ordering::SeqCst) {
+                return Err(AgentError::Timeout("downstream".to_string()));
+            }
+            let mut task = UnifiedTask::new("task-1");
+            task.add_message(message);
+            task.set_status(TaskStatus::Completed);
+            Ok(task)
+        }
+
+        async fn send_message_to_task(
+            &self,
+            _task_id: &str,
+            message: UnifiedMessage,
+        ) -> AgentResult<UnifiedTask> {
+            self.send_message(message).await
+        }
+
+        async fn get_task(&self, task_id: &str) -> AgentResult<UnifiedTask> {
+            Err(AgentError::TaskNotFound(task_id.to_string()))
+        }
+
+        async fn cancel_task(&self, task_id: &str) -> AgentResult<UnifiedTask> {
+            Err(AgentError::TaskNotFound(task_id.to_string()))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_proxy_circuit_breaker_fails_fast() {
+        use crate::circuit_breaker::CircuitBreakerConfig;
+        use std::sync::atomic::Ordering;
+        use std::time::Duration;
+
+        let target = Arc::new(FlakyAgent {
+            info: AgentInfo::new("flaky", "Flaky"),
+            failing: true.into(),
+            calls: 0.into(),
+        });
+        let proxy = ProxyAgent::new("Proxy", target.clone()).with_circuit_breaker(
+            CircuitBreakerConfig::new()
+                .with_failure_threshold(2)
+                .with_cooldown(Duration::from_millis(20)),
+        );
+        assert_eq!(proxy.circuit_state(), CircuitState::Closed);
+
+        for _ in 0..2 {
+            let err = proxy
+                .send_message(UnifiedMessage::user("Hi"))
+                .await
+                .unwrap_err();
+            assert!(matches!(err, AgentError::Timeout(_)));
+        }
+        assert_eq!(proxy.circuit_state(), CircuitState::Open);
+
+        let err = proxy
+            .send_message_to_task("task-1", UnifiedMessage::user("Hi"))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, AgentError::CircuitOpen(ref id) if id == "flaky"));
+        assert_eq!(target.calls.load(Ordering::SeqCst), 2);
+
+        target.failing.store(false, Ordering::SeqCst);
+        tokio::time::sleep(Duration::from_millis(30)).await;
+        proxy
+            .send_message(UnifiedMessage::user("Hi"))
+            .await
+            .unwrap();
+        assert_eq!(proxy.circuit_state(), CircuitState::Closed);
+        assert_eq!(target.calls.load(Ordering::SeqCst), 3);
+    }
+
+    fn flaky(id: &str, failing: bool) -> Arc<dyn UnifiedAgent> {
+        Arc::new(FlakyAgent {
+            info: AgentInfo::new(id, id),
+            failing: failing.into(),
+            calls: 0.into(),
+        })
+    }
+
+    /// Agent that responds only after a long delay
+    struct SlowAgent {
+        info: AgentInfo,
+    }
+
+    #[async_trait]
+    impl UnifiedAgent for SlowAgent {
+        fn info(&self) -> &AgentInfo {
+            &self.info
+        }
+
+        async fn send_message(&self, message: UnifiedMessage) -> AgentResult<UnifiedTask> {
+            tokio::time::sleep(Duration::from_secs(10)).await;
+            let mut task = UnifiedTask::new("slow-task");
+            task.add_message(message);
+            task.set_status(TaskStatus::Completed);
+            Ok(task)
+        }
+
+        async fn send_message_to_task(
+            &self,
+            _task_id: &str,
+            message: UnifiedMessage,
+        ) -> AgentResult<UnifiedTask> {
+            self.send_message(message).await
+        }
+
+        async fn get_task(&self, task_id: &str) -> AgentResult<UnifiedTask> {
+            Err(AgentError::TaskNotFound(task_id.to_string()))
+        }
+
+        async fn cancel_task(&self, task_id: &str) -> AgentResult<UnifiedTask> {
+            Err(AgentError::TaskNotFound(task_id.to_string()))
+        }
+    }
+
+    fn fan_out(policy: FanOutPolicy, targets: &[(&str, bool)]) -> FanOutAgent {
+        let mut agent = FanOutAgent::new("fan-out", "Fan Out").with_policy(policy);
+        for (id, failing) in targets {
+            agent.add_target(flaky(id, *failing));
+        }
+        agent
+    }
+
+    #[tokio::test]
+    async fn test_fan_out_best_effort_collects_errors() {
+        let agent = fan_out(FanOutPolicy::BestEffort, &[("a", false), ("b", true)]);
+        let task = agent
+            .send_message(UnifiedMessage::user("Hi"))
+            .await
+            .unwrap();
+        assert_eq!(task.status, TaskStatus::Completed);
+
+        let results = FanOutAgent::results(&task);
+        assert_eq!(results.len(), 2);
+        assert!(results[0].is_success());
+        assert_eq!(results[0].task_id.as_deref(), Some("task-1"));
+        assert_eq!(results[1].agent_id, "b");
+        assert!(results[1].error.as_deref().unwrap().contains("downstream"));
+
+        let errors: Vec<FanOutResult> =
+            serde_json::from_value(task.metadata[FAN_OUT_ERRORS_KEY].clone()).unwrap();
+        assert_eq!(errors, vec![results[1].clone()]);
+
+        let all_failed = fan_out(FanOutPolicy::BestEffort, &[("a", true), ("b", true)]);
+        let task = all_failed
+            .send_message(UnifiedMessage::user("Hi"))
+            .await
+            .unwrap();
+        assert_eq!(task.status, TaskStatus::Failed);
+    }
+
+    #[tokio::test]
+    async fn test_fan_out_all_or_nothing_fails_on_any_error() {
+        let agent = fan_out(FanOutPolicy::AllOrNothing, &[("a", false), ("b", true)]);
+        let task = agent
+            .send_message(UnifiedMessage::user("Hi"))
+            .await
+            .unwrap();
+        assert_eq!(task.status, TaskStatus::Failed);
+        assert_eq!(FanOutAgent::results(&task).len(), 2);
+
+        let agent = fan_out(FanOutPolicy::AllOrNothing, &[("a", false), ("b", false)]);
+        let task = agent
+            .send_message(UnifiedMessage::user("Hi"))
+            .await
+            .unwrap();
+        assert_eq!(task.status, TaskStatus::Completed);
+    }
+
+    #[tokio::test]
+    async fn test_fan_out_quorum() {
+        let targets = [("a", false), ("b", false), ("c", true)];
+
+        let agent = fan_out(FanOutPolicy::Quorum(2), &targets);
+        let task = agent
+            .send_message(UnifiedMessage::user("Hi"))
+            .await
+            .unwrap();
+        assert_eq!(task.status, TaskStatus::Completed);
+
+        let agent = fan_out(FanOutPolicy::Quorum(3), &targets);
+        let task = agent
+            .send_message(UnifiedMessage::user("Hi"))
+            .await
+            .unwrap();
+        assert_eq!(task.status, TaskStatus::Failed);
+    }
+
+    async fn health_provider(statuses: &[(&str, HealthStatus)]) -> Arc<dyn DiscoveryProvider> {
+        let provider = InMemoryDiscoveryProvider::new();
+        for (agent_id, status) in statuses {
+            let mut registration = AgentRegistration::new(*agent_id, *agent_id);
+            registration.health_status = *status;
+            provider.register(registration).await.unwrap();
+        }
+        Arc::new(provider)
+    }
+
+    #[tokio::test]
+    async fn test_fan_out_skips_unhealthy_targets() {
+        let provider = health_provider(&[("b", HealthStatus::Unhealthy)]).await;
+        let agent = fan_out(FanOutPolicy::AllOrNothing, &[("a", false), ("b", true)])
+            .with_health_checks(provider);
+
+        let task = agent
+            .send_message(UnifiedMessage::user("Hi"))
+            .await
+            .unwrap();
+        assert_eq!(task.status, TaskStatus::Completed);
+        let results = FanOutAgent::results(&task);
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].agent_id, "a");
+        assert_eq!(
+            task.metadata.get(FAN_OUT_SKIPPED_KEY),
+            Some(&serde_json::json!(["b"]))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_fan_out_fails_below_min_healthy_targets() {
+        let provider = health_provider(&[
+            ("a", HealthStatus::Degraded),
+            ("b", HealthStatus::Unhealthy),
+        ])
+        .await;
+        let agent = fan_out(FanOutPolicy::BestEffort, &[("a", false), ("b", false)])
+            .with_health_checks(provider)
+            .with_min_healthy_targets(2);
+
+        let err = agent
+            .send_message(UnifiedMessage::user("Hi"))
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("1 healthy agents, 2 required"));
+
+        let agent = agent.with_skip_degraded(true).with_min_healthy_targets(1);
+        assert!(
+            agent
+                .send_message(UnifiedMessage::user("Hi"))
+                .await
+                .is_err()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_fan_out_target_timeout() {
+        let mut agent =
+            FanOutAgent::new("fan-out", "Fan Out").with_target_timeout(Duration::from_millis(20));
+        agent.add_target(flaky("fast", false));
+        agent.add_target(Arc::new(SlowAgent {
+            info: AgentInfo::new("slow", "Slow"),
+        }));
+
+        let started = std::time::Instant::now();
+        let task = agent
+            .send_message(UnifiedMessage::user("Hi"))
+            .await
+            .unwrap();
+        assert!(started.elapsed() < Duration::from_secs(5));
+        assert_eq!(task.status, TaskStatus::Completed);
+
+        let results = FanOutAgent::results(&task);
+        assert!(results[0].is_success());
+        assert!(!results[1].is_success());
+        assert!(
+            results[1]
+                .error
+                .as_deref()
+                .unwrap()
+                .contains("did not respond")
+        );
+    }
 }
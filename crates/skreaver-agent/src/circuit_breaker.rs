@@ -0,0 +1,370 @@
+//! Circuit breaking for calls to downstream agents.
+//!
+//! A [`CircuitBreaker`] stops calls to an agent that keeps failing, so
+//! callers fail fast instead of each waiting for the full timeout:
+//!
+//! - **Closed**: calls flow normally; failures are counted, and enough
+//!   consecutive failures within the failure window open the breaker
+//! - **Open**: calls fail immediately with [`AgentError::CircuitOpen`] until
+//!   the cooldown has elapsed
+//! - **HalfOpen**: a single probe call is admitted; success closes the
+//!   breaker, failure opens it again
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use skreaver_agent::{CircuitBreakerConfig, ProxyAgent};
+//! use std::time::Duration;
+//!
+//! let proxy = ProxyAgent::new("Search", remote_agent).with_circuit_breaker(
+//!     CircuitBreakerConfig::new()
+//!         .with_failure_threshold(3)
+//!         .with_cooldown(Duration::from_secs(10)),
+//! );
+//! ```
+
+use std::future::Future;
+use std::sync::{Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+use tracing::{info, warn};
+
+use crate::error::{AgentError, AgentResult};
+
+// ============================================================================
+// Configuration
+// ============================================================================
+
+/// Circuit breaker configuration.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CircuitBreakerConfig {
+    /// Consecutive failures that open the breaker
+    pub failure_threshold: u32,
+    /// Failures only count as consecutive while they fall within this window
+    pub failure_window: Duration,
+    /// How long an open breaker rejects calls before admitting a probe
+    pub cooldown: Duration,
+}
+
+impl Default for CircuitBreakerConfig {
+    fn default() -> Self {
+        Self {
+            failure_threshold: 5,
+            failure_window: Duration::from_secs(60),
+            cooldown: Duration::from_secs(30),
+        }
+    }
+}
+
+impl CircuitBreakerConfig {
+    /// Create a configuration with default settings.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the number of consecutive failures that open the breaker.
+    pub fn with_failure_threshold(mut self, threshold: u32) -> Self {
+        self.failure_threshold = threshold.max(1);
+        self
+    }
+
+    /// Set the window within which failures count as consecutive.
+    pub fn with_failure_window(mut self, window: Duration) -> Self {
+        self.failure_window = window;
+        self
+    }
+
+    /// Set how long an open breaker rejects calls.
+    pub fn with_cooldown(mut self, cooldown: Duration) -> Self {
+        self.cooldown = cooldown;
+        self
+    }
+}
+
+// ============================================================================
+// Circuit Breaker
+// ============================================================================
+
+/// State of a circuit breaker.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CircuitState {
+    /// Calls flow normally
+    #[default]
+    Closed,
+    /// Calls are rejected
+    Open,
+    /// A single probe call is admitted
+    HalfOpen,
+}
+
+impl std::fmt::Display for CircuitState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CircuitState::Closed => write!(f, "closed"),
+            CircuitState::Open => write!(f, "open"),
+            CircuitState::HalfOpen => write!(f, "half_open"),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Breaker {
+    state: CircuitState,
+    consecutive_failures: u32,
+    first_failure_at: Instant,
+    opened_at: Instant,
+    probe_in_flight: bool,
+}
+
+/// Circuit breaker guarding calls to a single downstream agent.
+#[derive(Debug)]
+pub struct CircuitBreaker {
+    name: String,
+    config: CircuitBreakerConfig,
+    breaker: Mutex<Breaker>,
+}
+
+impl CircuitBreaker {
+    /// Create a closed breaker; `name` identifies the guarded agent in errors and logs.
+    pub fn new(name: impl Into<String>, config: CircuitBreakerConfig) -> Self {
+        let now = Instant::now();
+        Self {
+            name: name.into(),
+            config,
+            breaker: Mutex::new(Breaker {
+                state: CircuitState::Closed,
+                consecutive_failures: 0,
+                first_failure_at: now,
+                opened_at: now,
+                probe_in_flight: false,
+            }),
+        }
+    }
+
+    /// Get the configuration.
+    pub fn config(&self) -> &CircuitBreakerConfig {
+        &self.config
+    }
+
+    /// Get the current state.
+    ///
+    /// An open breaker reports `Open` until the first call after its
+    /// cooldown is admitted as the half-open probe.
+    pub fn state(&self) -> CircuitState {
+        self.lock().state
+    }
+
+    /// Failures counted towards the threshold since the last success.
+    pub fn consecutive_failures(&self) -> u32 {
+        self.lock().consecutive_failures
+    }
+
+    /// Run `call` through the breaker.
+    ///
+    /// Fails with [`AgentError::CircuitOpen`] without running `call` while
+    /// the breaker is open, or while a half-open probe is still in flight.
+    /// Any error returned by `call` counts as a failure.
+    pub async fn call<T, F>(&self, call: F) -> AgentResult<T>
+    where
+        F: Future<Output = AgentResult<T>>,
+    {
+        let mut attempt = self.admit()?;
+        let result = call.await;
+        attempt.finish(result.is_ok());
+        result
+    }
+
+    fn admit(&self) -> AgentResult<Attempt<'_>> {
+        let mut breaker = self.lock();
+        let probe = match breaker.state {
+            CircuitState::Closed => false,
+            CircuitState::Open if breaker.opened_at.elapsed() >= self.config.cooldown => {
+                info!(agent = %self.name, "Circuit half-open, admitting probe");
+                breaker.state = CircuitState::HalfOpen;
+                breaker.probe_in_flight = true;
+                true
+            }
+            CircuitState::HalfOpen if !breaker.probe_in_flight => {
+                breaker.probe_in_flight = true;
+                true
+            }
+            CircuitState::Open | CircuitState::HalfOpen => {
+                return Err(AgentError::CircuitOpen(self.name.clone()));
+            }
+        };
+        Ok(Attempt {
+            breaker: self,
+            probe,
+            finished: false,
+        })
+    }
+
+    fn record(&self, probe: bool, success: bool) {
+        let mut breaker = self.lock();
+        if probe {
+            breaker.probe_in_flight = false;
+        }
+        if success {
+            if breaker.state == CircuitState::HalfOpen {
+                info!(agent = %self.name, "Circuit closed after successful probe");
+            }
+            breaker.state = CircuitState::Closed;
+            breaker.consecutive_failures = 0;
+            return;
+        }
+
+        let now = Instant::now();
+        if breaker.consecutive_failures == 0
+            || now.duration_since(breaker.first_failure_at) > self.config.failure_window
+        {
+            breaker.consecutive_failures = 0;
+            breaker.first_failure_at = now;
+        }
+        breaker.consecutive_failures = breaker.consecutive_failures.saturating_add(1);
+
+        let trips = breaker.state == CircuitState::HalfOpen
+            || (breaker.state == CircuitState::Closed
+                && breaker.consecutive_failures >= self.config.failure_threshold);
+        if trips {
+            warn!(
+                agent = %self.name,
+                failures = breaker.consecutive_failures,
+                "Circuit opened"
+            );
+            breaker.state = CircuitState::Open;
+            breaker.opened_at = now;
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Breaker> {
+        self.breaker.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// An admitted call; a probe that is dropped unfinished frees the probe slot.
+struct Attempt<'a> {
+    breaker: &'a CircuitBreaker,
+    probe: bool,
+    finished: bool,
+}
+
+impl Attempt<'_> {
+    fn finish(&mut self, success: bool) {
+        self.finished = true;
+        self.breaker.record(self.probe, success);
+    }
+}
+
+impl Drop for Attempt<'_> {
+    fn drop(&mut self) {
+        if self.probe && !self.finished {
+            self.breaker.lock().probe_in_flight = false;
+        }
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn fail(breaker: &CircuitBreaker) -> AgentResult<()> {
+        breaker
+            .call(async { Err(AgentError::Timeout("downstream".to_string())) })
+            .await
+    }
+
+    async fn succeed(breaker: &CircuitBreaker) -> AgentResult<()> {
+        breaker.call(async { Ok(()) }).await
+    }
+
+    #[tokio::test]
+    async fn test_opens_after_threshold_and_recovers_through_probe() {
+        let breaker = CircuitBreaker::new(
+            "search",
+            CircuitBreakerConfig::new()
+                .with_failure_threshold(2)
+                .with_cooldown(Duration::from_millis(20)),
+        );
+
+        assert!(matches!(fail(&breaker).await, Err(AgentError::Timeout(_))));
+        assert_eq!(breaker.state(), CircuitState::Closed);
+        assert!(matches!(fail(&breaker).await, Err(AgentError::Timeout(_))));
+        assert_eq!(breaker.state(), CircuitState::Open);
+
+        // Open: calls are rejected without running
+        let err = succeed(&breaker).await.unwrap_err();
+        assert!(matches!(err, AgentError::CircuitOpen(ref name) if name == "search"));
+        assert_eq!(err.error_code(), "CIRCUIT_OPEN");
+
+        // A failed probe reopens the breaker
+        tokio::time::sleep(Duration::from_millis(30)).await;
+        assert!(matches!(fail(&breaker).await, Err(AgentError::Timeout(_))));
+        assert_eq!(breaker.state(), CircuitState::Open);
+        assert!(matches!(
+            succeed(&breaker).await,
+            Err(AgentError::CircuitOpen(_))
+        ));
+
+        // A successful probe closes it
+        tokio::time::sleep(Duration::from_millis(30)).await;
+        succeed(&breaker).await.unwrap();
+        assert_eq!(breaker.state(), CircuitState::Closed);
+        assert_eq!(breaker.consecutive_failures(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_failures_outside_window_do_not_accumulate() {
+        let breaker = CircuitBreaker::new(
+            "search",
+            CircuitBreakerConfig::new()
+                .with_failure_threshold(2)
+                .with_failure_window(Duration::from_millis(20)),
+        );
+
+        let _ = fail(&breaker).await;
+        tokio::time::sleep(Duration::from_millis(30)).await;
+        let _ = fail(&breaker).await;
+        assert_eq!(breaker.state(), CircuitState::Closed);
+        assert_eq!(breaker.consecutive_failures(), 1);
+
+        // A success in between also resets the count
+        succeed(&breaker).await.unwrap();
+        let _ = fail(&breaker).await;
+        assert_eq!(breaker.state(), CircuitState::Closed);
+    }
+
+    #[tokio::test]
+    async fn test_only_one_probe_at_a_time() {
+        let breaker = CircuitBreaker::new(
+            "search",
+            CircuitBreakerConfig::new()
+                .with_failure_threshold(1)
+                .with_cooldown(Duration::ZERO),
+        );
+        let _ = fail(&breaker).await;
+
+        let (release, released) = tokio::sync::oneshot::channel::<()>();
+        let probe = breaker.call(async {
+            let _ = released.await;
+            Ok(())
+        });
+        tokio::pin!(probe);
+        // Start the probe without letting it finish
+        assert!(futures::poll!(probe.as_mut()).is_pending());
+        assert_eq!(breaker.state(), CircuitState::HalfOpen);
+        assert!(matches!(
+            succeed(&breaker).await,
+            Err(AgentError::CircuitOpen(_))
+        ));
+
+        release.send(()).unwrap();
+        probe.await.unwrap();
+        assert_eq!(breaker.state(), CircuitState::Closed);
+    }
+}
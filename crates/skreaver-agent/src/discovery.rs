@@ -31,7 +31,8 @@
 //! # Features
 //!
 //! - **Agent Registration**: Agents can register with metadata, capabilities, and health endpoints
-//! - **Health Checking**: Automatic health monitoring with configurable intervals
+//! - **Health Checking**: Automatic health monitoring with configurable intervals,
+//!   failure/recovery thresholds and backoff for unhealthy agents
 //! - **Filtering**: Query agents by protocol, capability, tags, or custom predicates
 //! - **Event Notifications**: Subscribe to agent registration/deregistration events
 //! - **Multiple Providers**: Support for in-memory, HTTP-based, and custom discovery providers
@@ -70,7 +71,7 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use tokio::sync::{RwLock, broadcast};
 use tracing::{debug, info, warn};
 use uuid::Uuid;
@@ -86,6 +87,9 @@ use crate::types::{AgentInfo, Capability, Protocol};
 /// Size of broadcast channels for discovery events
 const DISCOVERY_EVENT_CHANNEL_SIZE: usize = 100;
 
+/// How many times the health check backoff for an unhealthy agent may double
+const MAX_CHECK_BACKOFF_DOUBLINGS: u32 = 6;
+
 // ============================================================================
 // Core Types
 // ============================================================================
@@ -316,29 +320,255 @@ impl std::fmt::Display for HealthStatus {
 // Query Types
 // ============================================================================
 
+/// Comparison operator in a [`VersionConstraint`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum VersionOp {
+    Exact,
+    Greater,
+    GreaterEq,
+    Less,
+    LessEq,
+    /// Compatible with: same major (or minor while the major is 0)
+    Caret,
+    /// Same major and minor
+    Tilde,
+}
+
+/// A `major.minor.patch` version; missing parts are zero.
+type Version = (u64, u64, u64);
+
+fn parse_version(version: &str) -> Option<Version> {
+    let mut parts = version.trim().trim_start_matches('v').splitn(3, '.');
+    let major = parts.next()?.parse().ok()?;
+    let minor = parts.next().map_or(Some(0), |p| p.parse().ok())?;
+    // Ignore pre-release and build suffixes on the patch component
+    let patch = parts.next().map_or(Some(0), |p| {
+        p.split(['-', '+']).next().unwrap_or_default().parse().ok()
+    })?;
+    Some((major, minor, patch))
+}
+
+/// Version requirement for a capability, such as `>=1.2, <2` or `^1.4`.
+///
+/// Comparators are separated by commas and must all hold. Supported
+/// operators are `=`, `>`, `>=`, `<`, `<=`, `^` and `~`; a bare version
+/// means `=`. Serialized as its string form.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct VersionConstraint {
+    source: String,
+    comparators: Vec<(VersionOp, Version)>,
+}
+
+impl VersionConstraint {
+    /// Parse a version constraint.
+    pub fn parse(constraint: &str) -> AgentResult<Self> {
+        let invalid =
+            || AgentError::InvalidRequest(format!("Invalid version constraint: '{}'", constraint));
+
+        let comparators = constraint
+            .split(',')
+            .map(|part| {
+                let part = part.trim();
+                let (op, version) = [
+                    (">=", VersionOp::GreaterEq),
+                    ("<=", VersionOp::LessEq),
+                    (">", VersionOp::Greater),
+                    ("<", VersionOp::Less),
+                    ("=", VersionOp::Exact),
+                    ("^", VersionOp::Caret),
+                    ("~", VersionOp::Tilde),
+                ]
+                .into_iter()
+                .find_map(|(prefix, op)| part.strip_prefix(prefix).map(|rest| (op, rest)))
+                .unwrap_or((VersionOp::Exact, part));
+                parse_version(version)
+                    .map(|version| (op, version))
+                    .ok_or_else(invalid)
+            })
+            .collect::<AgentResult<Vec<_>>>()?;
+
+        Ok(Self {
+            source: constraint.trim().to_string(),
+            comparators,
+        })
+    }
+
+    /// Check whether a version satisfies every comparator.
+    ///
+    /// Versions that cannot be parsed never match.
+    pub fn matches(&self, version: &str) -> bool {
+        let Some(version) = parse_version(version) else {
+            return false;
+        };
+        self.comparators.iter().all(|&(op, bound)| match op {
+            VersionOp::Exact => version == bound,
+            VersionOp::Greater => version > bound,
+            VersionOp::GreaterEq => version >= bound,
+            VersionOp::Less => version < bound,
+            VersionOp::LessEq => version <= bound,
+            VersionOp::Caret if bound.0 == 0 => {
+                version >= bound && version.0 == 0 && version.1 == bound.1
+            }
+            VersionOp::Caret => version >= bound && version.0 == bound.0,
+            VersionOp::Tilde => version >= bound && (version.0, version.1) == (bound.0, bound.1),
+        })
+    }
+
+    /// The constraint as written.
+    pub fn as_str(&self) -> &str {
+        &self.source
+    }
+}
+
+impl TryFrom<String> for VersionConstraint {
+    type Error = AgentError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::parse(&value)
+    }
+}
+
+impl From<VersionConstraint> for String {
+    fn from(constraint: VersionConstraint) -> Self {
+        constraint.source
+    }
+}
+
+impl std::fmt::Display for VersionConstraint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.source)
+    }
+}
+
+/// Predicate on a single capability, optionally constrained by version.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CapabilityPredicate {
+    /// Capability identifier
+    pub id: String,
+    /// Required capability version
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<VersionConstraint>,
+}
+
+impl CapabilityPredicate {
+    /// Check whether a capability satisfies this predicate.
+    pub fn matches(&self, capability: &Capability) -> bool {
+        capability.id == self.id
+            && self.version.as_ref().is_none_or(|constraint| {
+                capability
+                    .version
+                    .as_deref()
+                    .is_some_and(|version| constraint.matches(version))
+            })
+    }
+}
+
+/// Boolean composition of capability predicates.
+///
+/// Serializes as a tagged tree so it can be sent over HTTP, e.g.
+/// `{"all": [{"capability": {"id": "search", "version": ">=1.2"}},
+/// {"not": {"capability": {"id": "legacy"}}}]}`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CapabilityQuery {
+    /// The agent offers a capability matching the predicate
+    Capability(CapabilityPredicate),
+    /// Every sub-query matches (an empty list matches)
+    All(Vec<CapabilityQuery>),
+    /// At least one sub-query matches (an empty list never matches)
+    Any(Vec<CapabilityQuery>),
+    /// The sub-query does not match
+    Not(Box<CapabilityQuery>),
+}
+
+impl CapabilityQuery {
+    /// Require a capability with any version.
+    pub fn capability(id: impl Into<String>) -> Self {
+        Self::Capability(CapabilityPredicate {
+            id: id.into(),
+            version: None,
+        })
+    }
+
+    /// Require a capability whose version satisfies `constraint`.
+    pub fn capability_version(id: impl Into<String>, constraint: &str) -> AgentResult<Self> {
+        Ok(Self::Capability(CapabilityPredicate {
+            id: id.into(),
+            version: Some(VersionConstraint::parse(constraint)?),
+        }))
+    }
+
+    /// Require all of the given queries.
+    pub fn all(queries: impl IntoIterator<Item = CapabilityQuery>) -> Self {
+        Self::All(queries.into_iter().collect())
+    }
+
+    /// Require any of the given queries.
+    pub fn any(queries: impl IntoIterator<Item = CapabilityQuery>) -> Self {
+        Self::Any(queries.into_iter().collect())
+    }
+
+    /// Evaluate the query against a set of capabilities.
+    pub fn matches(&self, capabilities: &[Capability]) -> bool {
+        match self {
+            Self::Capability(predicate) => capabilities.iter().any(|c| predicate.matches(c)),
+            Self::All(queries) => queries.iter().all(|q| q.matches(capabilities)),
+            Self::Any(queries) => queries.iter().any(|q| q.matches(capabilities)),
+            Self::Not(query) => !query.matches(capabilities),
+        }
+    }
+}
+
+impl std::ops::Not for CapabilityQuery {
+    type Output = CapabilityQuery;
+
+    /// Negate a query.
+    fn not(self) -> Self::Output {
+        CapabilityQuery::Not(Box::new(self))
+    }
+}
+
 /// Query builder for discovering agents.
-#[derive(Debug, Clone, Default)]
+///
+/// Queries are serializable so they can be submitted to a remote discovery
+/// endpoint; unset filters are omitted.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
 pub struct DiscoveryQuery {
     /// Filter by agent ID
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub agent_id: Option<String>,
     /// Filter by protocols
+    #[serde(skip_serializing_if = "Vec::is_empty")]
     pub protocols: Vec<Protocol>,
     /// Filter by capabilities (agent must have ALL specified)
+    #[serde(skip_serializing_if = "Vec::is_empty")]
     pub capabilities: Vec<String>,
     /// Filter by capabilities (agent must have ANY specified)
+    #[serde(skip_serializing_if = "Vec::is_empty")]
     pub capabilities_any: Vec<String>,
     /// Filter by tags (agent must have ALL specified)
+    #[serde(skip_serializing_if = "Vec::is_empty")]
     pub tags: Vec<String>,
     /// Filter by tags (agent must have ANY specified)
+    #[serde(skip_serializing_if = "Vec::is_empty")]
     pub tags_any: Vec<String>,
     /// Filter by health status
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub health_status: Option<HealthStatus>,
     /// Include stale registrations
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
     pub include_stale: bool,
     /// Maximum number of results
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub limit: Option<usize>,
     /// Custom filter predicate name (for provider-specific filters)
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub custom_filter: Option<String>,
+    /// Compound capability query (combined with the other filters)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub capability_query: Option<CapabilityQuery>,
 }
 
 impl DiscoveryQuery {
@@ -373,6 +603,21 @@ impl DiscoveryQuery {
         self
     }
 
+    /// Filter by a compound capability query.
+    ///
+    /// Calling this again combines the queries with `all`.
+    pub fn with_capability_query(mut self, query: CapabilityQuery) -> Self {
+        self.capability_query = Some(match self.capability_query.take() {
+            Some(CapabilityQuery::All(mut queries)) => {
+                queries.push(query);
+                CapabilityQuery::All(queries)
+            }
+            Some(existing) => CapabilityQuery::all([existing, query]),
+            None => query,
+        });
+        self
+    }
+
     /// Filter by tag (must have this tag).
     pub fn with_tag(mut self, tag: impl Into<String>) -> Self {
         self.tags.push(tag.into());
@@ -445,6 +690,15 @@ impl DiscoveryQuery {
             }
         }
 
+        // Check compound capability query
+        if self
+            .capability_query
+            .as_ref()
+            .is_some_and(|query| !query.matches(&registration.capabilities))
+        {
+            return false;
+        }
+
         // Check tags (must have ALL)
         if !self.tags.is_empty() {
             let reg_tags: HashSet<_> = registration.tags.iter().collect();
@@ -514,7 +768,7 @@ pub enum DeregistrationReason {
     /// Registration expired (no heartbeat)
     Expired,
     /// Health check failed repeatedly
-    Unhealthy,
+    HealthCheckFailed,
     /// Administrative removal
     Administrative,
 }
@@ -534,6 +788,18 @@ pub trait DiscoveryProvider: Send + Sync {
     /// Deregister an agent by registration ID.
     async fn deregister(&self, registration_id: &str) -> AgentResult<()>;
 
+    /// Deregister an agent for a specific reason.
+    ///
+    /// Providers that do not report reasons deregister it explicitly.
+    async fn deregister_with_reason(
+        &self,
+        registration_id: &str,
+        reason: DeregistrationReason,
+    ) -> AgentResult<()> {
+        let _ = reason;
+        self.deregister(registration_id).await
+    }
+
     /// Update heartbeat for a registration.
     async fn heartbeat(&self, registration_id: &str) -> AgentResult<()>;
 
@@ -622,18 +888,28 @@ impl DiscoveryProvider for InMemoryDiscoveryProvider {
     }
 
     async fn deregister(&self, registration_id: &str) -> AgentResult<()> {
+        self.deregister_with_reason(registration_id, DeregistrationReason::Explicit)
+            .await
+    }
+
+    async fn deregister_with_reason(
+        &self,
+        registration_id: &str,
+        reason: DeregistrationReason,
+    ) -> AgentResult<()> {
         let mut regs = self.registrations.write().await;
         if let Some(reg) = regs.remove(registration_id) {
             info!(
                 registration_id = %registration_id,
                 agent_id = %reg.agent_id,
+                reason = ?reason,
                 "Deregistering agent from discovery service"
             );
 
             self.emit_event(DiscoveryEvent::AgentDeregistered {
                 registration_id: registration_id.to_string(),
                 agent_id: reg.agent_id,
-                reason: DeregistrationReason::Explicit,
+                reason,
             });
 
             Ok(())
@@ -770,6 +1046,17 @@ pub struct DiscoveryConfig {
     pub cleanup_interval: Duration,
     /// HTTP client timeout for health checks
     pub health_check_timeout: Duration,
+    /// Consecutive failed health checks before an agent is marked unhealthy
+    pub unhealthy_threshold: u32,
+    /// Consecutive successful health checks before an unhealthy agent is
+    /// marked healthy again
+    pub healthy_threshold: u32,
+    /// Delay before an unhealthy agent is checked again, doubled after each
+    /// further failure (up to 64 times this value)
+    pub check_backoff: Duration,
+    /// Consecutive failed health checks after which an unhealthy agent is
+    /// deregistered; `None` keeps unhealthy agents registered
+    pub deregister_after: Option<u32>,
 }
 
 impl Default for DiscoveryConfig {
@@ -780,16 +1067,31 @@ impl Default for DiscoveryConfig {
             enable_cleanup: true,
             cleanup_interval: Duration::from_secs(60),
             health_check_timeout: Duration::from_secs(5),
+            unhealthy_threshold: 3,
+            healthy_threshold: 2,
+            check_backoff: Duration::from_secs(30),
+            deregister_after: None,
         }
     }
 }
 
+/// Consecutive health check results for one registration.
+#[derive(Debug, Default)]
+struct HealthTracker {
+    failures: u32,
+    successes: u32,
+    /// Unhealthy agents are not checked again before this instant
+    next_check: Option<Instant>,
+}
+
 /// The main discovery service.
 pub struct DiscoveryService {
     provider: Arc<dyn DiscoveryProvider>,
     config: DiscoveryConfig,
     /// Registered agents (Arc<dyn UnifiedAgent> for direct access)
     agents: RwLock<HashMap<String, Arc<dyn UnifiedAgent>>>,
+    /// Health check history, keyed by registration ID
+    health: RwLock<HashMap<String, HealthTracker>>,
 }
 
 impl DiscoveryService {
@@ -804,6 +1106,7 @@ impl DiscoveryService {
             provider,
             config: DiscoveryConfig::default(),
             agents: RwLock::new(HashMap::new()),
+            health: RwLock::new(HashMap::new()),
         }
     }
 
@@ -834,6 +1137,7 @@ impl DiscoveryService {
     /// Deregister an agent.
     pub async fn deregister(&self, registration_id: &str) -> AgentResult<()> {
         self.agents.write().await.remove(registration_id);
+        self.health.write().await.remove(registration_id);
         self.provider.deregister(registration_id).await
     }
 
@@ -894,12 +1198,108 @@ impl DiscoveryService {
     pub async fn cleanup_stale(&self) -> AgentResult<Vec<String>> {
         let removed = self.provider.cleanup_stale().await?;
         let mut agents = self.agents.write().await;
+        let mut health = self.health.write().await;
         for id in &removed {
             agents.remove(id);
+            health.remove(id);
         }
         Ok(removed)
     }
 
+    /// Record the result of a health check for a registration.
+    ///
+    /// An agent is only marked [`HealthStatus::Unhealthy`] after
+    /// `unhealthy_threshold` consecutive failed checks, and only recovers
+    /// after `healthy_threshold` consecutive successful ones. Any other
+    /// result is applied as soon as the agent is not flagged unhealthy.
+    /// Checks of an unhealthy agent back off exponentially, and with
+    /// `deregister_after` set it is deregistered with
+    /// [`DeregistrationReason::HealthCheckFailed`] once that many checks
+    /// have failed in a row.
+    pub async fn record_health_check(
+        &self,
+        registration_id: &str,
+        result: HealthStatus,
+    ) -> AgentResult<()> {
+        let registration = self
+            .provider
+            .get(registration_id)
+            .await?
+            .ok_or_else(|| AgentError::AgentNotFound(registration_id.to_string()))?;
+        let flagged = registration.health_status == HealthStatus::Unhealthy;
+
+        let mut health = self.health.write().await;
+        let tracker = health.entry(registration_id.to_string()).or_default();
+
+        if result != HealthStatus::Unhealthy {
+            tracker.failures = 0;
+            tracker.successes = tracker.successes.saturating_add(1);
+            if flagged && tracker.successes < self.config.healthy_threshold {
+                debug!(
+                    registration_id = %registration_id,
+                    successes = tracker.successes,
+                    "Unhealthy agent passed a health check"
+                );
+                return Ok(());
+            }
+            tracker.next_check = None;
+            drop(health);
+            return self.provider.update_health(registration_id, result).await;
+        }
+
+        tracker.successes = 0;
+        tracker.failures = tracker.failures.saturating_add(1);
+        let failures = tracker.failures;
+        if !flagged && failures < self.config.unhealthy_threshold {
+            debug!(
+                registration_id = %registration_id,
+                failures,
+                "Agent failed a health check"
+            );
+            return Ok(());
+        }
+        tracker.next_check = Some(Instant::now() + self.check_backoff(failures));
+
+        let deregister = self
+            .config
+            .deregister_after
+            .is_some_and(|after| failures >= after.max(self.config.unhealthy_threshold));
+        if deregister {
+            health.remove(registration_id);
+            drop(health);
+            self.provider
+                .update_health(registration_id, HealthStatus::Unhealthy)
+                .await?;
+            self.agents.write().await.remove(registration_id);
+            return self
+                .provider
+                .deregister_with_reason(registration_id, DeregistrationReason::HealthCheckFailed)
+                .await;
+        }
+        drop(health);
+        self.provider
+            .update_health(registration_id, HealthStatus::Unhealthy)
+            .await
+    }
+
+    /// Delay before re-checking an agent that has failed `failures` checks in a row.
+    fn check_backoff(&self, failures: u32) -> Duration {
+        let doublings = failures
+            .saturating_sub(self.config.unhealthy_threshold.max(1))
+            .min(MAX_CHECK_BACKOFF_DOUBLINGS);
+        self.config.check_backoff * 2u32.pow(doublings)
+    }
+
+    /// Whether a registration's backoff, if any, has passed.
+    async fn health_check_due(&self, registration_id: &str) -> bool {
+        self.health
+            .read()
+            .await
+            .get(registration_id)
+            .and_then(|tracker| tracker.next_check)
+            .is_none_or(|next_check| Instant::now() >= next_check)
+    }
+
     /// Start background tasks (health checking, cleanup).
     pub fn start_background_tasks(self: &Arc<Self>) -> BackgroundTaskHandle {
         let service = Arc::clone(self);
@@ -943,8 +1343,13 @@ impl DiscoveryService {
                 .or_else(|| reg.endpoint.as_ref().map(|e| format!("{}/health", e)));
 
             if let Some(url) = health_url {
+                if !self.health_check_due(&reg.id).await {
+                    continue;
+                }
                 let status = self.check_health(&url).await;
-                let _ = self.provider.update_health(&reg.id, status).await;
+                if let Err(e) = self.record_health_check(&reg.id, status).await {
+                    debug!(registration_id = %reg.id, error = %e, "Health check not recorded");
+                }
             }
         }
 
@@ -1101,6 +1506,138 @@ mod tests {
         assert!(!query4.matches(&reg));
     }
 
+    fn versioned_registration(agent_id: &str, capabilities: &[(&str, &str)]) -> AgentRegistration {
+        capabilities.iter().fold(
+            AgentRegistration::new(agent_id, agent_id),
+            |reg, (id, version)| {
+                reg.with_capability_full(Capability::new(*id, *id).with_version(*version))
+            },
+        )
+    }
+
+    #[tokio::test]
+    async fn test_capability_query_all_requires_every_capability() {
+        let provider = InMemoryDiscoveryProvider::new();
+        provider
+            .register(versioned_registration(
+                "full",
+                &[("search", "1.4.0"), ("summarize", "2.1.0")],
+            ))
+            .await
+            .unwrap();
+        provider
+            .register(versioned_registration(
+                "search-only",
+                &[("search", "1.4.0")],
+            ))
+            .await
+            .unwrap();
+        provider
+            .register(versioned_registration(
+                "old-search",
+                &[("search", "0.9.0"), ("summarize", "2.0.0")],
+            ))
+            .await
+            .unwrap();
+
+        let query = DiscoveryQuery::new().with_capability_query(CapabilityQuery::all([
+            CapabilityQuery::capability_version("search", ">=1.0, <2").unwrap(),
+            CapabilityQuery::capability("summarize"),
+        ]));
+
+        let results = provider.query(&query).await.unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].agent_id, "full");
+    }
+
+    #[tokio::test]
+    async fn test_capability_query_any_matches_either_capability() {
+        let provider = InMemoryDiscoveryProvider::new();
+        provider
+            .register(versioned_registration("searcher", &[("search", "1.0.0")]))
+            .await
+            .unwrap();
+        provider
+            .register(versioned_registration(
+                "translator",
+                &[("translate", "3.2.1")],
+            ))
+            .await
+            .unwrap();
+        provider
+            .register(versioned_registration("coder", &[("code", "1.0.0")]))
+            .await
+            .unwrap();
+
+        let query = DiscoveryQuery::new().with_capability_query(CapabilityQuery::any([
+            CapabilityQuery::capability("search"),
+            CapabilityQuery::capability_version("translate", "^3.1").unwrap(),
+        ]));
+
+        let mut matched: Vec<_> = provider
+            .query(&query)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|r| r.agent_id)
+            .collect();
+        matched.sort();
+        assert_eq!(matched, vec!["searcher", "translator"]);
+
+        // Negation excludes the searcher
+        let query = query.with_capability_query(!CapabilityQuery::capability("search"));
+        let results = provider.query(&query).await.unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].agent_id, "translator");
+    }
+
+    #[test]
+    fn test_capability_query_serialization() {
+        let query = DiscoveryQuery::new()
+            .with_protocol(Protocol::A2a)
+            .with_capability_query(CapabilityQuery::all([
+                CapabilityQuery::capability_version("search", ">=1.2").unwrap(),
+                !CapabilityQuery::capability("legacy"),
+            ]));
+
+        let json = serde_json::to_value(&query).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "protocols": ["a2a"],
+                "capability_query": {"all": [
+                    {"capability": {"id": "search", "version": ">=1.2"}},
+                    {"not": {"capability": {"id": "legacy"}}}
+                ]}
+            })
+        );
+
+        let parsed: DiscoveryQuery = serde_json::from_value(json).unwrap();
+        assert_eq!(parsed.capability_query, query.capability_query);
+
+        let invalid = serde_json::json!({
+            "capability_query": {"capability": {"id": "search", "version": ">=one"}}
+        });
+        assert!(serde_json::from_value::<DiscoveryQuery>(invalid).is_err());
+    }
+
+    #[test]
+    fn test_version_constraint_matching() {
+        let range = VersionConstraint::parse(">=1.2, <2").unwrap();
+        assert!(range.matches("1.2.0"));
+        assert!(range.matches("1.9.3"));
+        assert!(!range.matches("2.0.0"));
+        assert!(!range.matches("1.1.9"));
+        assert!(!range.matches("not-a-version"));
+
+        assert!(VersionConstraint::parse("^0.3").unwrap().matches("0.3.7"));
+        assert!(!VersionConstraint::parse("^0.3").unwrap().matches("0.4.0"));
+        assert!(VersionConstraint::parse("~1.4.2").unwrap().matches("1.4.9"));
+        assert!(!VersionConstraint::parse("~1.4.2").unwrap().matches("1.5.0"));
+        assert!(VersionConstraint::parse("1.0").unwrap().matches("1.0.0"));
+        assert!(VersionConstraint::parse("").is_err());
+    }
+
     #[tokio::test]
     async fn test_in_memory_provider() {
         let provider = InMemoryDiscoveryProvider::new();
@@ -1245,4 +1782,146 @@ mod tests {
             _ => panic!("Expected AgentDeregistered event"),
         }
     }
+    fn health_service(
+        config: DiscoveryConfig,
+    ) -> (DiscoveryService, Arc<InMemoryDiscoveryProvider>) {
+        let provider = Arc::new(InMemoryDiscoveryProvider::new());
+        let service = DiscoveryService::with_provider(provider.clone()).with_config(config);
+        (service, provider)
+    }
+
+    async fn health_of(service: &DiscoveryService, id: &str) -> HealthStatus {
+        service.get(id).await.unwrap().unwrap().health_status
+    }
+
+    #[tokio::test]
+    async fn test_health_thresholds_prevent_flapping() {
+        let (service, provider) = health_service(DiscoveryConfig {
+            unhealthy_threshold: 3,
+            healthy_threshold: 2,
+            check_backoff: Duration::from_secs(60),
+            ..DiscoveryConfig::default()
+        });
+        let id = service
+            .register(AgentRegistration::new("agent-1", "Test Agent"))
+            .await
+            .unwrap();
+        let mut rx = provider.subscribe();
+
+        // A blip below the threshold changes nothing
+        service
+            .record_health_check(&id, HealthStatus::Healthy)
+            .await
+            .unwrap();
+        for _ in 0..2 {
+            service
+                .record_health_check(&id, HealthStatus::Unhealthy)
+                .await
+                .unwrap();
+        }
+        service
+            .record_health_check(&id, HealthStatus::Healthy)
+            .await
+            .unwrap();
+        service
+            .record_health_check(&id, HealthStatus::Unhealthy)
+            .await
+            .unwrap();
+        assert_eq!(health_of(&service, &id).await, HealthStatus::Healthy);
+        assert!(service.health_check_due(&id).await);
+
+        service
+            .record_health_check(&id, HealthStatus::Unhealthy)
+            .await
+            .unwrap();
+        service
+            .record_health_check(&id, HealthStatus::Unhealthy)
+            .await
+            .unwrap();
+        assert_eq!(health_of(&service, &id).await, HealthStatus::Unhealthy);
+        assert!(!service.health_check_due(&id).await);
+
+        service
+            .record_health_check(&id, HealthStatus::Healthy)
+            .await
+            .unwrap();
+        assert_eq!(health_of(&service, &id).await, HealthStatus::Unhealthy);
+        service
+            .record_health_check(&id, HealthStatus::Healthy)
+            .await
+            .unwrap();
+        assert_eq!(health_of(&service, &id).await, HealthStatus::Healthy);
+        assert!(service.health_check_due(&id).await);
+
+        let mut transitions = Vec::new();
+        while let Ok(event) = rx.try_recv() {
+            if let DiscoveryEvent::HealthStatusChanged {
+                old_status,
+                new_status,
+                ..
+            } = event
+            {
+                transitions.push((old_status, new_status));
+            }
+        }
+        assert_eq!(
+            transitions,
+            vec![
+                (HealthStatus::Unknown, HealthStatus::Healthy),
+                (HealthStatus::Healthy, HealthStatus::Unhealthy),
+                (HealthStatus::Unhealthy, HealthStatus::Healthy),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_health_check_backoff_and_deregistration() {
+        let (service, provider) = health_service(DiscoveryConfig {
+            unhealthy_threshold: 2,
+            check_backoff: Duration::from_secs(1),
+            deregister_after: Some(4),
+            ..DiscoveryConfig::default()
+        });
+        assert_eq!(service.check_backoff(2), Duration::from_secs(1));
+        assert_eq!(service.check_backoff(3), Duration::from_secs(2));
+        assert_eq!(service.check_backoff(4), Duration::from_secs(4));
+        assert_eq!(service.check_backoff(u32::MAX), Duration::from_secs(64));
+
+        let id = service
+            .register(AgentRegistration::new("agent-1", "Test Agent"))
+            .await
+            .unwrap();
+        let mut rx = provider.subscribe();
+
+        for _ in 0..3 {
+            service
+                .record_health_check(&id, HealthStatus::Unhealthy)
+                .await
+                .unwrap();
+        }
+        assert_eq!(health_of(&service, &id).await, HealthStatus::Unhealthy);
+        assert!(matches!(
+            rx.try_recv().unwrap(),
+            DiscoveryEvent::HealthStatusChanged { .. }
+        ));
+        assert!(rx.try_recv().is_err());
+
+        service
+            .record_health_check(&id, HealthStatus::Unhealthy)
+            .await
+            .unwrap();
+        match rx.try_recv().unwrap() {
+            DiscoveryEvent::AgentDeregistered { reason, .. } => {
+                assert_eq!(reason, DeregistrationReason::HealthCheckFailed);
+            }
+            event => panic!("Expected AgentDeregistered event, got {:?}", event),
+        }
+        assert_eq!(service.count().await.unwrap(), 0);
+        assert!(
+            service
+                .record_health_check(&id, HealthStatus::Healthy)
+                .await
+                .is_err()
+        );
+    }
 }
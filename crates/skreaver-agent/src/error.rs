@@ -49,6 +49,10 @@ pub enum AgentError {
     #[error("Internal error: {0}")]
     Internal(String),
 
+    /// The circuit breaker guarding an agent is open.
+    #[error("Circuit open: {0}")]
+    CircuitOpen(String),
+
     /// MCP-specific error.
     #[cfg(feature = "mcp")]
     #[error("MCP error: {0}")]
@@ -83,6 +87,7 @@ impl AgentError {
             AgentError::InvalidResponse(_) => "INVALID_RESPONSE",
             AgentError::SerializationError(_) => "SERIALIZATION_ERROR",
             AgentError::Internal(_) => "INTERNAL_ERROR",
+            AgentError::CircuitOpen(_) => "CIRCUIT_OPEN",
             #[cfg(feature = "mcp")]
             AgentError::Mcp(_) => "MCP_ERROR",
             #[cfg(feature = "a2a")]
@@ -10,6 +10,11 @@
 //! - **MCP Adapter**: Use MCP servers through the unified interface (requires `mcp` feature)
 //! - **A2A Adapter**: Use A2A agents through the unified interface (requires `a2a` feature)
 //! - **Protocol Bridge**: Connect agents across protocols
+//! - **Retries**: Budgeted retries and request hedging for idempotent remote calls
+//! - **Circuit Breaking**: Fail fast on downstream agents that keep failing
+//! - **Connection Pooling**: Reuse adapter connections per target with health checks
+//! - **Routing Cache**: Reuse routing decisions until discovery changes
+//! - **Transcripts**: Record agent events and page or tail them by cursor
 //!
 //! ## Example: Using an MCP Server
 //!
@@ -66,12 +71,17 @@
 //! ```
 
 pub mod bridge;
+pub mod circuit_breaker;
 pub mod discovery;
 pub mod error;
 pub mod orchestration;
+pub mod pool;
 pub mod protocol_bridge;
+pub mod retry;
+pub mod routing_cache;
 pub mod storage;
 pub mod traits;
+pub mod transcript;
 pub mod types;
 
 // MCP adapter (requires mcp feature)
@@ -94,23 +104,46 @@ pub use types::{
 };
 
 // Re-export bridge types
-pub use bridge::{FanOutAgent, ProxyAgent};
+pub use bridge::{
+    FAN_OUT_ERRORS_KEY, FAN_OUT_RESULTS_KEY, FanOutAgent, FanOutPolicy, FanOutResult, ProxyAgent,
+};
+
+// Re-export circuit breaker types
+pub use circuit_breaker::{CircuitBreaker, CircuitBreakerConfig, CircuitState};
 
 // Re-export discovery types
 pub use discovery::{
-    AgentRegistration, BackgroundTaskHandle, DeregistrationReason, DiscoveryConfig, DiscoveryEvent,
-    DiscoveryProvider, DiscoveryQuery, DiscoveryService, HealthStatus, InMemoryDiscoveryProvider,
+    AgentRegistration, BackgroundTaskHandle, CapabilityPredicate, CapabilityQuery,
+    DeregistrationReason, DiscoveryConfig, DiscoveryEvent, DiscoveryProvider, DiscoveryQuery,
+    DiscoveryService, HealthStatus, InMemoryDiscoveryProvider, VersionConstraint,
 };
 
 // Re-export orchestration types
 pub use orchestration::{
     AggregationMode, CapabilityBasedSupervisor, ParallelAgent, RouterAgent, RoutingRule,
-    SequentialPipeline, SupervisorAgent, SupervisorDecision, SupervisorLogic, TransformMode,
+    RoutingStrategy, SequentialPipeline, StageRecovery, SupervisorAgent, SupervisorDecision,
+    SupervisorLogic, TransformMode,
 };
 
+// Re-export pool types
+pub use pool::{ConnectionPool, PoolConfig, PoolEvictionHandle, PooledConnection};
+
+// Re-export routing cache types
+pub use routing_cache::{RoutingCache, RoutingCacheConfig, RoutingCacheStats, RoutingDecision};
+
+// Re-export retry types
+pub use retry::{ConnectOptions, RetryBudget, RetryConfig, RetryPolicy};
+
 // Re-export storage types
 pub use storage::{
-    FileTaskStore, InMemoryTaskStore, TaskCache, TaskQuery, TaskStore, TaskStoreExt,
+    FileTaskStore, InMemoryTaskStore, PaginatedTasks, TaskCache, TaskCursor, TaskQuery, TaskStore,
+    TaskStoreExt,
+};
+
+// Re-export transcript types
+pub use transcript::{
+    FileTranscriptStorage, InMemoryTranscriptStorage, TranscriptCursor, TranscriptEntry,
+    TranscriptPage, TranscriptRecorder, TranscriptStorage, TranscriptStream,
 };
 
 // Re-export MCP adapter
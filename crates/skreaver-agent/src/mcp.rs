@@ -10,24 +10,31 @@ use std::sync::Arc;
 use tracing::{debug, info};
 
 use crate::error::{AgentError, AgentResult};
+use crate::pool::{ConnectionPool, PooledConnection};
+use crate::retry::{ConnectOptions, RetryPolicy};
 use crate::storage::TaskCache;
-use crate::traits::{ToolInvoker, UnifiedAgent};
+use crate::traits::{ToolInvoker, UnifiedAgent, error_event, message_added, status_update};
 use crate::types::{
     AgentInfo, Capability, ContentPart, MessageRole, Protocol, StreamEvent, TaskStatus,
     UnifiedMessage, UnifiedTask,
 };
 
-use skreaver_core::tool::{ExecutionResult, Tool};
+use skreaver_core::tool::{ExecutionResult, FailureReason, Tool};
 use skreaver_mcp::McpBridge;
 
 /// Adapter that wraps an MCP bridge to provide the unified agent interface.
 ///
 /// This allows external MCP servers to be used through the unified
 /// agent abstraction.
+///
+/// Tool calls are retried and hedged according to the adapter's
+/// [`RetryPolicy`], but only for tools the server annotates as read-only
+/// or idempotent.
 pub struct McpAgentAdapter {
     info: AgentInfo,
     bridge: Arc<McpBridge>,
-    tasks: TaskCache,
+    tasks: Arc<TaskCache>,
+    retry: RetryPolicy,
 }
 
 impl std::fmt::Debug for McpAgentAdapter {
@@ -65,17 +72,42 @@ impl McpAgentAdapter {
         Self {
             info: agent_info,
             bridge: Arc::new(bridge),
-            tasks: TaskCache::new(),
+            tasks: Arc::new(TaskCache::new()),
+            retry: RetryPolicy::default(),
         }
     }
 
+    /// Set the retry policy used for idempotent tool calls.
+    pub fn with_retry_policy(mut self, retry: RetryPolicy) -> Self {
+        self.retry = retry;
+        self
+    }
+
     /// Connect to an MCP server and create an adapter.
     pub async fn connect(command: &str) -> AgentResult<Self> {
+        Self::connect_with_options(command, ConnectOptions::default()).await
+    }
+
+    /// Connect to an MCP server with custom connect options.
+    pub async fn connect_with_options(command: &str, options: ConnectOptions) -> AgentResult<Self> {
         info!(command = %command, "Connecting to MCP server");
         let bridge = McpBridge::connect_stdio(command)
             .await
             .map_err(|e| AgentError::ConnectionError(e.to_string()))?;
-        Ok(Self::new(bridge))
+        Ok(Self::new(bridge).with_retry_policy(options.retry_policy()))
+    }
+
+    /// Connect to an MCP server through a pool, reusing an existing connection.
+    ///
+    /// The server command is the pool key; a closed connection is replaced
+    /// with a freshly spawned server.
+    pub async fn connect_pooled(
+        pool: &ConnectionPool<Self>,
+        command: &str,
+    ) -> AgentResult<Arc<Self>> {
+        let options = pool.config().connect_options.clone();
+        pool.get_or_connect(command, || Self::connect_with_options(command, options))
+            .await
     }
 
     /// Connect with custom arguments.
@@ -95,46 +127,82 @@ impl McpAgentAdapter {
         &self.bridge
     }
 
+    /// Get the retry policy.
+    pub fn retry_policy(&self) -> &RetryPolicy {
+        &self.retry
+    }
+
     /// Process a message by handling tool calls.
     async fn process_message(
         &self,
         task: &mut UnifiedTask,
         message: &UnifiedMessage,
     ) -> AgentResult<()> {
-        // Look for tool calls in the message
-        for part in &message.content {
-            if let ContentPart::ToolCall {
+        let meta = trace_meta(message);
+        for (id, name, arguments) in tool_calls(message) {
+            debug!(tool = %name, id = %id, "Processing tool call");
+            let result = call_tool(&self.bridge, &self.retry, &name, arguments, &meta).await;
+            task.add_message(tool_result_message(id, result));
+        }
+
+        Ok(())
+    }
+}
+
+/// Tool calls in a message as `(id, name, arguments)`.
+fn tool_calls(message: &UnifiedMessage) -> Vec<(String, String, serde_json::Value)> {
+    message
+        .content
+        .iter()
+        .filter_map(|part| match part {
+            ContentPart::ToolCall {
                 id,
                 name,
                 arguments,
-            } = part
-            {
-                debug!(tool = %name, id = %id, "Processing tool call");
-
-                // Find and call the tool
-                let result = self.invoke_tool(name, arguments.clone()).await;
-
-                // Add result as a message
-                let result_part = match result {
-                    Ok(value) => ContentPart::ToolResult {
-                        id: id.clone(),
-                        result: value,
-                        is_error: Some(false),
-                    },
-                    Err(e) => ContentPart::ToolResult {
-                        id: id.clone(),
-                        result: serde_json::json!({ "error": e.to_string() }),
-                        is_error: Some(true),
-                    },
-                };
-
-                let mut result_msg = UnifiedMessage::new(MessageRole::Agent, "");
-                result_msg.content = vec![result_part];
-                task.add_message(result_msg);
-            }
-        }
+            } => Some((id.clone(), name.clone(), arguments.clone())),
+            _ => None,
+        })
+        .collect()
+}
 
-        Ok(())
+/// W3C trace context entries copied from message metadata into MCP `_meta`.
+const TRACE_CONTEXT_KEYS: [&str; 2] = ["traceparent", "tracestate"];
+
+/// Request `_meta` carrying the message's trace context, if any.
+fn trace_meta(message: &UnifiedMessage) -> serde_json::Map<String, serde_json::Value> {
+    TRACE_CONTEXT_KEYS
+        .iter()
+        .filter_map(|key| {
+            let value = message.metadata.get(*key)?;
+            Some((key.to_string(), value.clone()))
+        })
+        .collect()
+}
+
+/// Wrap the outcome of a tool call in an agent message.
+fn tool_result_message(id: String, result: AgentResult<serde_json::Value>) -> UnifiedMessage {
+    let result_part = match result {
+        Ok(value) => ContentPart::ToolResult {
+            id,
+            result: value,
+            is_error: Some(false),
+        },
+        Err(e) => ContentPart::ToolResult {
+            id,
+            result: serde_json::json!({ "error": e.to_string() }),
+            is_error: Some(true),
+        },
+    };
+
+    let mut result_msg = UnifiedMessage::new(MessageRole::Agent, "");
+    result_msg.content = vec![result_part];
+    result_msg
+}
+
+#[async_trait]
+impl PooledConnection for McpAgentAdapter {
+    async fn is_healthy(&self) -> bool {
+        !self.bridge.is_closed()
     }
 }
 
@@ -183,40 +251,43 @@ impl UnifiedAgent for McpAgentAdapter {
         &self,
         message: UnifiedMessage,
     ) -> AgentResult<Pin<Box<dyn Stream<Item = AgentResult<StreamEvent>> + Send>>> {
-        // MCP doesn't natively support streaming, so we simulate it
-        let task = self.send_message(message).await?;
-        let task_id = task.id.clone();
+        // MCP has no task-level streaming, so report each tool result as
+        // soon as its call returns
+        let mut task = UnifiedTask::new_with_uuid();
+        task.add_message(message.clone());
+        task.set_status(TaskStatus::Working);
+        self.tasks.insert(task.clone()).await;
+
+        let bridge = Arc::clone(&self.bridge);
+        let retry = self.retry.clone();
+        let tasks = Arc::clone(&self.tasks);
+
+        let meta = trace_meta(&message);
 
         let stream = async_stream::stream! {
-            // Emit status working
-            yield Ok(StreamEvent::StatusUpdate {
-                task_id: task_id.clone(),
-                status: TaskStatus::Working,
-                message: None,
-            });
-
-            // Emit messages
-            for msg in &task.messages {
-                yield Ok(StreamEvent::MessageAdded {
-                    task_id: task_id.clone(),
-                    message: msg.clone(),
-                });
-            }
+            let task_id = task.id.clone();
+            yield Ok(status_update(&task_id, TaskStatus::Working, None));
+
+            for (id, name, arguments) in tool_calls(&message) {
+                debug!(tool = %name, id = %id, "Processing streamed tool call");
+                let result = call_tool(&bridge, &retry, &name, arguments, &meta).await;
+                if let Err(e) = &result {
+                    yield Ok(error_event(
+                        &task_id,
+                        "tool_error",
+                        format!("Tool '{}' failed: {}", name, e),
+                    ));
+                }
 
-            // Emit artifacts
-            for artifact in &task.artifacts {
-                yield Ok(StreamEvent::ArtifactAdded {
-                    task_id: task_id.clone(),
-                    artifact: artifact.clone(),
-                });
+                let result_msg = tool_result_message(id, result);
+                task.add_message(result_msg.clone());
+                tasks.insert(task.clone()).await;
+                yield Ok(message_added(&task_id, result_msg));
             }
 
-            // Emit completed
-            yield Ok(StreamEvent::StatusUpdate {
-                task_id: task_id.clone(),
-                status: TaskStatus::Completed,
-                message: None,
-            });
+            task.set_status(TaskStatus::Completed);
+            tasks.insert(task).await;
+            yield Ok(status_update(&task_id, TaskStatus::Completed, None));
         };
 
         Ok(Box::pin(stream))
@@ -247,22 +318,14 @@ impl ToolInvoker for McpAgentAdapter {
         name: &str,
         arguments: serde_json::Value,
     ) -> AgentResult<serde_json::Value> {
-        let tool = self
-            .bridge
-            .find_tool(name)
-            .ok_or_else(|| AgentError::CapabilityNotFound(name.to_string()))?;
-
-        let input = serde_json::to_string(&arguments)?;
-        let result = tool.call(input);
-
-        match result {
-            ExecutionResult::Success { output } => serde_json::from_str(&output)
-                .or_else(|_| Ok(serde_json::json!({ "output": output }))),
-            ExecutionResult::Failure { reason } => Err(AgentError::Internal(format!(
-                "Tool execution failed: {:?}",
-                reason
-            ))),
-        }
+        call_tool(
+            &self.bridge,
+            &self.retry,
+            name,
+            arguments,
+            &serde_json::Map::new(),
+        )
+        .await
     }
 
     fn list_tools(&self) -> Vec<Capability> {
@@ -274,6 +337,61 @@ impl ToolInvoker for McpAgentAdapter {
     }
 }
 
+/// Call a bridged tool, retrying only if the server marks it idempotent.
+async fn call_tool(
+    bridge: &McpBridge,
+    retry: &RetryPolicy,
+    name: &str,
+    arguments: serde_json::Value,
+    meta: &serde_json::Map<String, serde_json::Value>,
+) -> AgentResult<serde_json::Value> {
+    // Reuses the discovered tool list until the bridge's cache expires
+    let tool = bridge
+        .find_cached_tool(name)
+        .await?
+        .ok_or_else(|| AgentError::CapabilityNotFound(name.to_string()))?;
+
+    let input = serde_json::to_string(&arguments)?;
+    let idempotent = tool.is_idempotent();
+
+    retry
+        .execute(idempotent, || {
+            let tool = Arc::clone(&tool);
+            let input = input.clone();
+            let meta = meta.clone();
+            async move {
+                // Bridged tools block on the MCP call, so keep them off the async workers
+                let result = tokio::task::spawn_blocking(move || tool.call_with_meta(input, meta))
+                    .await
+                    .map_err(|e| AgentError::Internal(format!("Tool task failed: {}", e)))?;
+                execution_result_to_value(result)
+            }
+        })
+        .await
+}
+
+/// Convert a tool execution result into a JSON value.
+///
+/// Network and timeout failures map to retryable errors so that the retry
+/// policy can recover from them.
+fn execution_result_to_value(result: ExecutionResult) -> AgentResult<serde_json::Value> {
+    match result {
+        ExecutionResult::Success { output, .. } => {
+            serde_json::from_str(&output).or_else(|_| Ok(serde_json::json!({ "output": output })))
+        }
+        ExecutionResult::Failure {
+            reason: FailureReason::NetworkError { message },
+        } => Err(AgentError::ConnectionError(message)),
+        ExecutionResult::Failure {
+            reason: FailureReason::Timeout { operation },
+        } => Err(AgentError::Timeout(operation)),
+        ExecutionResult::Failure { reason } => Err(AgentError::Internal(format!(
+            "Tool execution failed: {:?}",
+            reason
+        ))),
+    }
+}
+
 /// Convert MCP tool info to unified capability.
 pub fn mcp_tool_to_capability(tool: &dyn Tool) -> Capability {
     Capability::new(tool.name(), tool.name()).with_tag("mcp")
@@ -292,9 +410,7 @@ mod tests {
                 "test_tool"
             }
             fn call(&self, _input: String) -> ExecutionResult {
-                ExecutionResult::Success {
-                    output: "success".to_string(),
-                }
+                ExecutionResult::success("success".to_string())
             }
         }
 
@@ -302,4 +418,36 @@ mod tests {
         assert_eq!(cap.id, "test_tool");
         assert!(cap.tags.contains(&"mcp".to_string()));
     }
+
+    #[test]
+    fn test_transient_tool_failures_are_retryable() {
+        let network = execution_result_to_value(ExecutionResult::Failure {
+            reason: FailureReason::NetworkError {
+                message: "connection reset".to_string(),
+            },
+        });
+        assert!(network.unwrap_err().is_retryable());
+
+        let invalid = execution_result_to_value(ExecutionResult::Failure {
+            reason: FailureReason::InvalidInput {
+                message: "bad arguments".to_string(),
+            },
+        });
+        assert!(!invalid.unwrap_err().is_retryable());
+    }
+
+    #[test]
+    fn test_trace_context_is_sent_as_request_meta() {
+        let message = UnifiedMessage::user("call")
+            .with_metadata(
+                "traceparent",
+                serde_json::json!("00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01"),
+            )
+            .with_metadata("bridge_id", serde_json::json!("bridge"));
+
+        let meta = trace_meta(&message);
+        assert_eq!(meta.len(), 1);
+        assert_eq!(meta["traceparent"], message.metadata["traceparent"]);
+        assert!(trace_meta(&UnifiedMessage::user("call")).is_empty());
+    }
 }
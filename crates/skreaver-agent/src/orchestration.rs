@@ -49,9 +49,9 @@
 //! use skreaver_agent::{SequentialPipeline, TransformMode};
 //!
 //! let pipeline = SequentialPipeline::new("analysis-pipeline", "Document Analysis")
-//!     .add_stage(preprocessor)    // Clean and normalize input
-//!     .add_stage(analyzer)        // Extract key information
-//!     .add_stage(summarizer)      // Generate summary
+//!     .add_stage(preprocessor, StageRecovery::Idempotent) // Clean and normalize input
+//!     .add_stage(analyzer, StageRecovery::Idempotent)     // Extract key information
+//!     .add_stage(summarizer, StageRecovery::Idempotent)   // Generate summary
 //!     .with_transform(TransformMode::LastMessage);
 //!
 //! let result = pipeline.send_message(UnifiedMessage::user("Analyze this...")).await?;
@@ -90,6 +90,18 @@
 //!     .add_rule(RoutingRule::capability_based("calculate", "math_agent"));
 //! ```
 //!
+//! When several agents offer the same capability, give each its own rule and
+//! pick a [`RoutingStrategy`] to balance load across the rules that match:
+//!
+//! ```rust,ignore
+//! use skreaver_agent::{RouterAgent, RoutingRule, RoutingStrategy};
+//!
+//! let router = RouterAgent::new("search-pool", "Search Pool")
+//!     .add_rule(RoutingRule::capability("search", search_a))
+//!     .add_rule(RoutingRule::capability("search", search_b))
+//!     .with_strategy(RoutingStrategy::LeastActive);
+//! ```
+//!
 //! ## Supervisor Agent
 //!
 //! Coordinate complex workflows with custom decision logic:
@@ -121,9 +133,9 @@
 //! ```rust,ignore
 //! // A pipeline that contains a parallel stage
 //! let pipeline = SequentialPipeline::new("complex", "Complex Workflow")
-//!     .add_stage(preprocessor)
-//!     .add_stage(Arc::new(parallel_search))  // ParallelAgent as a stage
-//!     .add_stage(postprocessor);
+//!     .add_stage(preprocessor, StageRecovery::Idempotent)
+//!     .add_stage(Arc::new(parallel_search), StageRecovery::Idempotent)  // ParallelAgent as a stage
+//!     .add_stage(postprocessor, StageRecovery::Idempotent);
 //!
 //! // A supervisor that manages routers
 //! let supervisor = SupervisorAgent::new("meta-supervisor", "Meta")
@@ -139,19 +151,157 @@
 //! - **ParallelAgent**: Collects all results, partial success possible
 //! - **RouterAgent**: Returns error if no matching agent or agent fails
 //! - **SupervisorAgent**: Depends on supervisor logic implementation
+//!
+//! # Checkpointing
+//!
+//! A `SequentialPipeline` built with [`SequentialPipeline::with_checkpoints`]
+//! saves its task to a [`TaskStore`] as each stage starts and completes. After
+//! a crash, [`SequentialPipeline::interrupted_tasks`] lists unfinished runs and
+//! [`SequentialPipeline::resume`] continues each from the first stage that did
+//! not complete, keeping earlier stage outputs. A stage that was cut off
+//! mid-run is re-run only if it was added as [`StageRecovery::Idempotent`].
+//!
+//! A `SupervisorAgent` built with [`SupervisorAgent::with_checkpoints`] does
+//! the same per decision iteration: [`SupervisorAgent::resume`] continues
+//! after the last completed iteration, re-running an interrupted one only if
+//! every agent it called was added as [`StageRecovery::Idempotent`].
+//!
+//! # Streaming Progress
+//!
+//! `SequentialPipeline` and `ParallelAgent` stream live progress from
+//! `send_message_streaming`: each stage (or parallel branch) reports
+//! [`StreamEvent::StageStarted`] and then [`StreamEvent::StageCompleted`] or
+//! [`StreamEvent::StageFailed`] as it happens. Once the run finishes, the
+//! aggregated messages and artifacts follow, and a terminal
+//! [`StreamEvent::StatusUpdate`] carries the final status.
 
 use async_trait::async_trait;
-use futures::Stream;
+use futures::channel::mpsc;
+use futures::{Stream, StreamExt};
 use std::collections::HashMap;
+use std::future::Future;
 use std::pin::Pin;
-use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard};
 use tracing::{debug, info, warn};
 
+use crate::discovery::{DiscoveryProvider, HealthStatus};
 use crate::error::{AgentError, AgentResult};
-use crate::storage::TaskCache;
+use crate::routing_cache::{RoutingCache, RoutingCacheConfig, RoutingDecision};
+use crate::storage::{TaskCache, TaskQuery, TaskStore};
 use crate::traits::UnifiedAgent;
 use crate::types::{AgentInfo, MessageRole, StreamEvent, TaskStatus, UnifiedMessage, UnifiedTask};
 
+type EventStream = Pin<Box<dyn Stream<Item = AgentResult<StreamEvent>> + Send>>;
+
+// ============================================================================
+// Stage progress streaming
+// ============================================================================
+
+/// Sink for per-stage progress events; a no-op when not streaming.
+#[derive(Clone, Default)]
+struct StageEvents(Option<mpsc::UnboundedSender<StreamEvent>>);
+
+impl StageEvents {
+    fn emit(&self, event: StreamEvent) {
+        if let Some(tx) = &self.0 {
+            // The receiver is gone only if the caller dropped the stream
+            let _ = tx.unbounded_send(event);
+        }
+    }
+
+    /// Run one stage, reporting its start and outcome.
+    async fn run_stage(
+        &self,
+        task_id: &str,
+        stage: usize,
+        agent: &Arc<dyn UnifiedAgent>,
+        message: UnifiedMessage,
+    ) -> AgentResult<UnifiedTask> {
+        let agent_id = agent.info().id.clone();
+        self.emit(StreamEvent::StageStarted {
+            task_id: task_id.to_string(),
+            stage,
+            agent_id: agent_id.clone(),
+        });
+
+        let result = agent.send_message(message).await;
+        self.emit(match &result {
+            Ok(task) => StreamEvent::StageCompleted {
+                task_id: task_id.to_string(),
+                stage,
+                agent_id,
+                status: task.status,
+            },
+            Err(e) => StreamEvent::StageFailed {
+                task_id: task_id.to_string(),
+                stage,
+                agent_id,
+                error: e.to_string(),
+            },
+        });
+        result
+    }
+}
+
+/// Stream stage events live while `run` executes, then the final result.
+///
+/// `run` receives the event sink and returns the aggregated task. Once it
+/// completes, the task's messages and artifacts are emitted followed by a
+/// terminal status update; if it fails, the error ends the stream.
+fn stream_stages<F, Fut>(run: F) -> EventStream
+where
+    F: FnOnce(StageEvents) -> Fut,
+    Fut: Future<Output = AgentResult<UnifiedTask>> + Send + 'static,
+{
+    enum Item {
+        Event(StreamEvent),
+        Done(AgentResult<UnifiedTask>),
+    }
+
+    let (tx, rx) = mpsc::unbounded();
+    let run = run(StageEvents(Some(tx)));
+
+    Box::pin(async_stream::stream! {
+        // The sender lives in `run`, so the event stream ends once it finishes
+        let mut items = std::pin::pin!(futures::stream::select(
+            rx.map(Item::Event),
+            futures::stream::once(run).map(Item::Done),
+        ));
+        let mut outcome = None;
+        while let Some(item) = items.next().await {
+            match item {
+                Item::Event(event) => yield Ok(event),
+                Item::Done(result) => outcome = Some(result),
+            }
+        }
+
+        match outcome {
+            Some(Ok(task)) => {
+                for msg in &task.messages {
+                    yield Ok(StreamEvent::MessageAdded {
+                        task_id: task.id.clone(),
+                        message: msg.clone(),
+                    });
+                }
+                for artifact in &task.artifacts {
+                    yield Ok(StreamEvent::ArtifactAdded {
+                        task_id: task.id.clone(),
+                        artifact: artifact.clone(),
+                    });
+                }
+                yield Ok(StreamEvent::StatusUpdate {
+                    task_id: task.id.clone(),
+                    status: task.status,
+                    message: None,
+                });
+            }
+            Some(Err(e)) => yield Err(e),
+            None => {}
+        }
+    })
+}
+
 // ============================================================================
 // SequentialPipeline - Chain agents in sequence
 // ============================================================================
@@ -164,20 +314,44 @@ use crate::types::{AgentInfo, MessageRole, StreamEvent, TaskStatus, UnifiedMessa
 /// # Example
 /// ```rust,ignore
 /// let pipeline = SequentialPipeline::new("analysis-pipeline", "Analysis Pipeline")
-///     .add_stage(preprocessor_agent)
-///     .add_stage(analyzer_agent)
-///     .add_stage(summarizer_agent);
+///     .add_stage(preprocessor_agent, StageRecovery::Idempotent)
+///     .add_stage(analyzer_agent, StageRecovery::Idempotent)
+///     .add_stage(summarizer_agent, StageRecovery::Idempotent);
 ///
 /// let result = pipeline.send_message(UnifiedMessage::user("Analyze this data")).await?;
 /// ```
 pub struct SequentialPipeline {
     info: AgentInfo,
-    stages: Vec<Arc<dyn UnifiedAgent>>,
+    stages: Vec<PipelineStage>,
     /// How to transform output from one stage to input for the next
     transform: TransformMode,
-    tasks: tokio::sync::RwLock<HashMap<String, PipelineTask>>,
+    tasks: Arc<tokio::sync::RwLock<HashMap<String, PipelineTask>>>,
+    /// Store for per-stage checkpoints, if resumption is enabled
+    checkpoints: Option<Arc<dyn TaskStore>>,
+}
+
+/// Whether a pipeline stage or supervised agent may be re-run after being
+/// interrupted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StageRecovery {
+    /// Running the stage twice is harmless, so an interrupted run is retried
+    Idempotent,
+    /// The stage has side effects; an interrupted run blocks resumption
+    RunOnce,
+}
+
+#[derive(Clone)]
+struct PipelineStage {
+    agent: Arc<dyn UnifiedAgent>,
+    recovery: StageRecovery,
 }
 
+/// Metadata keys under which pipeline progress is checkpointed.
+const CHECKPOINT_PIPELINE_KEY: &str = "pipeline.id";
+const CHECKPOINT_COMPLETED_KEY: &str = "pipeline.completed_stages";
+const CHECKPOINT_RUNNING_KEY: &str = "pipeline.running_stage";
+const CHECKPOINT_INPUT_KEY: &str = "pipeline.next_input";
+
 /// How to transform output between pipeline stages.
 #[derive(Debug, Clone, Copy, Default)]
 pub enum TransformMode {
@@ -203,12 +377,13 @@ impl SequentialPipeline {
             info: AgentInfo::new(id, name).with_description("Sequential agent pipeline"),
             stages: Vec::new(),
             transform: TransformMode::default(),
-            tasks: tokio::sync::RwLock::new(HashMap::new()),
+            tasks: Arc::new(tokio::sync::RwLock::new(HashMap::new())),
+            checkpoints: None,
         }
     }
 
-    /// Add a stage to the pipeline.
-    pub fn add_stage(mut self, agent: Arc<dyn UnifiedAgent>) -> Self {
+    /// Add a stage, declaring whether it may be re-run after an interruption.
+    pub fn add_stage(mut self, agent: Arc<dyn UnifiedAgent>, recovery: StageRecovery) -> Self {
         // Merge capabilities from the agent
         for cap in agent.capabilities() {
             if !self.info.capabilities.iter().any(|c| c.id == cap.id) {
@@ -221,7 +396,13 @@ impl SequentialPipeline {
                 self.info.protocols.push(*proto);
             }
         }
-        self.stages.push(agent);
+        self.stages.push(PipelineStage { agent, recovery });
+        self
+    }
+
+    /// Checkpoint progress to `store` so interrupted runs can be resumed.
+    pub fn with_checkpoints(mut self, store: Arc<dyn TaskStore>) -> Self {
+        self.checkpoints = Some(store);
         self
     }
 
@@ -236,9 +417,146 @@ impl SequentialPipeline {
         self.stages.len()
     }
 
+    /// IDs of checkpointed runs of this pipeline that did not finish.
+    pub async fn interrupted_tasks(&self) -> AgentResult<Vec<String>> {
+        interrupted_runs(
+            self.checkpoints.as_deref(),
+            CHECKPOINT_PIPELINE_KEY,
+            &self.info.id,
+        )
+        .await
+    }
+
+    /// Resume a checkpointed run from the first stage that did not complete.
+    ///
+    /// Outputs of completed stages are kept. A finished run is returned as is.
+    ///
+    /// # Errors
+    ///
+    /// Fails if checkpointing is disabled, the task is unknown or belongs to
+    /// another pipeline, or the interrupted stage is [`StageRecovery::RunOnce`].
+    pub async fn resume(&self, task_id: &str) -> AgentResult<UnifiedTask> {
+        let store = self.checkpoints.as_ref().ok_or_else(|| {
+            AgentError::Internal("Pipeline checkpointing is not enabled".to_string())
+        })?;
+        let task = store
+            .get(task_id)
+            .await?
+            .ok_or_else(|| AgentError::TaskNotFound(task_id.to_string()))?;
+        if task.is_terminal() {
+            return Ok(task);
+        }
+
+        let checkpoint = Checkpoint::read(&task);
+        if checkpoint.pipeline_id.as_deref() != Some(self.info.id.as_str()) {
+            return Err(AgentError::InvalidRequest(format!(
+                "Task {} was not checkpointed by pipeline {}",
+                task_id, self.info.id
+            )));
+        }
+        if let Some(running) = checkpoint.running_stage
+            && self
+                .stages
+                .get(running)
+                .is_some_and(|stage| stage.recovery == StageRecovery::RunOnce)
+        {
+            return Err(AgentError::Internal(format!(
+                "Pipeline stage {} was interrupted and is not safe to re-run",
+                running
+            )));
+        }
+
+        let input = match checkpoint.next_input {
+            Some(text) => UnifiedMessage::user(text),
+            None => task.messages.first().cloned().ok_or_else(|| {
+                AgentError::Internal(format!("Checkpoint for {} has no input", task_id))
+            })?,
+        };
+        info!(
+            pipeline = %self.info.id,
+            task_id = %task_id,
+            stage = checkpoint.completed,
+            "Resuming pipeline from checkpoint"
+        );
+
+        self.runner()
+            .run(task, checkpoint.completed, input, &StageEvents::default())
+            .await
+    }
+
+    fn runner(&self) -> PipelineRunner {
+        PipelineRunner {
+            pipeline_id: self.info.id.clone(),
+            stages: self.stages.clone(),
+            transform: self.transform,
+            tasks: Arc::clone(&self.tasks),
+            checkpoints: self.checkpoints.clone(),
+        }
+    }
+}
+
+/// IDs of unfinished runs that `owner_id` checkpointed under `owner_key`.
+///
+/// Runs waiting for user input are not interrupted and are left out.
+async fn interrupted_runs(
+    store: Option<&dyn TaskStore>,
+    owner_key: &str,
+    owner_id: &str,
+) -> AgentResult<Vec<String>> {
+    let Some(store) = store else {
+        return Ok(Vec::new());
+    };
+    let query = TaskQuery::new()
+        .active_only()
+        .with_metadata_key(owner_key)
+        .oldest_first();
+    Ok(store
+        .query(&query)
+        .await?
+        .into_iter()
+        .filter(|task| {
+            task.status != TaskStatus::InputRequired
+                && task.metadata.get(owner_key) == Some(&serde_json::Value::from(owner_id))
+        })
+        .map(|task| task.id)
+        .collect())
+}
+
+/// Pipeline progress recorded in a task's metadata.
+struct Checkpoint {
+    pipeline_id: Option<String>,
+    completed: usize,
+    running_stage: Option<usize>,
+    next_input: Option<String>,
+}
+
+impl Checkpoint {
+    fn read(task: &UnifiedTask) -> Self {
+        let index = |key| {
+            task.metadata
+                .get(key)
+                .and_then(serde_json::Value::as_u64)
+                .map(|n| n as usize)
+        };
+        let text = |key| {
+            task.metadata
+                .get(key)
+                .and_then(serde_json::Value::as_str)
+                .map(str::to_string)
+        };
+        Self {
+            pipeline_id: text(CHECKPOINT_PIPELINE_KEY),
+            completed: index(CHECKPOINT_COMPLETED_KEY).unwrap_or(0),
+            running_stage: index(CHECKPOINT_RUNNING_KEY),
+            next_input: text(CHECKPOINT_INPUT_KEY),
+        }
+    }
+}
+
+impl TransformMode {
     /// Extract the next input from a completed task.
-    fn extract_next_input(&self, task: &UnifiedTask) -> String {
-        match self.transform {
+    fn extract_next_input(self, task: &UnifiedTask) -> String {
+        match self {
             TransformMode::LastMessage => task
                 .messages
                 .iter()
@@ -264,31 +582,81 @@ impl SequentialPipeline {
     }
 }
 
-#[async_trait]
-impl UnifiedAgent for SequentialPipeline {
-    fn info(&self) -> &AgentInfo {
-        &self.info
+/// Everything needed to run a pipeline, detached from `&self` for streaming.
+struct PipelineRunner {
+    pipeline_id: String,
+    stages: Vec<PipelineStage>,
+    transform: TransformMode,
+    tasks: Arc<tokio::sync::RwLock<HashMap<String, PipelineTask>>>,
+    checkpoints: Option<Arc<dyn TaskStore>>,
+}
+
+impl PipelineRunner {
+    /// Start a fresh run of every stage.
+    async fn start(
+        &self,
+        message: UnifiedMessage,
+        events: &StageEvents,
+    ) -> AgentResult<UnifiedTask> {
+        let mut pipeline_task = UnifiedTask::new_with_uuid();
+        pipeline_task.add_message(message.clone());
+        if self.checkpoints.is_some() {
+            pipeline_task.metadata.insert(
+                CHECKPOINT_PIPELINE_KEY.to_string(),
+                self.pipeline_id.clone().into(),
+            );
+        }
+        self.run(pipeline_task, 0, message, events).await
     }
 
-    async fn send_message(&self, message: UnifiedMessage) -> AgentResult<UnifiedTask> {
-        if self.stages.is_empty() {
+    /// Persist the task's progress, if checkpointing is enabled.
+    async fn checkpoint(&self, task: &UnifiedTask) -> AgentResult<()> {
+        match &self.checkpoints {
+            Some(store) => store.save(task).await,
+            None => Ok(()),
+        }
+    }
+
+    /// Run stages from `first_stage` on, reporting stage progress to `events`.
+    async fn run(
+        &self,
+        mut pipeline_task: UnifiedTask,
+        first_stage: usize,
+        message: UnifiedMessage,
+        events: &StageEvents,
+    ) -> AgentResult<UnifiedTask> {
+        let stages = &self.stages;
+        if stages.is_empty() {
             return Err(AgentError::Internal("Pipeline has no stages".to_string()));
         }
 
-        let mut pipeline_task = UnifiedTask::new_with_uuid();
-        pipeline_task.add_message(message.clone());
+        pipeline_task.set_status(TaskStatus::Working);
+        events.emit(StreamEvent::StatusUpdate {
+            task_id: pipeline_task.id.clone(),
+            status: TaskStatus::Working,
+            message: Some("Pipeline started".to_string()),
+        });
 
         let mut current_input = message;
 
-        for (idx, stage) in self.stages.iter().enumerate() {
+        for (idx, stage) in stages.iter().enumerate().skip(first_stage) {
+            let agent = &stage.agent;
             debug!(
-                pipeline = %self.info.id,
+                pipeline = %self.pipeline_id,
                 stage = idx,
-                agent = %stage.info().id,
+                agent = %agent.info().id,
                 "Executing pipeline stage"
             );
 
-            let stage_result = stage.send_message(current_input.clone()).await?;
+            // Mark the stage as in flight so a crash here is detectable on resume
+            pipeline_task
+                .metadata
+                .insert(CHECKPOINT_RUNNING_KEY.to_string(), idx.into());
+            self.checkpoint(&pipeline_task).await?;
+
+            let stage_result = events
+                .run_stage(&pipeline_task.id, idx, agent, current_input.clone())
+                .await?;
 
             // Check if stage failed
             if stage_result.status == TaskStatus::Failed {
@@ -297,8 +665,9 @@ impl UnifiedAgent for SequentialPipeline {
                 pipeline_task.add_message(UnifiedMessage::agent(format!(
                     "Pipeline failed at stage {}: {}",
                     idx,
-                    stage.info().name
+                    agent.info().name
                 )));
+                self.checkpoint(&pipeline_task).await?;
                 return Ok(pipeline_task);
             }
 
@@ -315,12 +684,23 @@ impl UnifiedAgent for SequentialPipeline {
             }
 
             // Prepare input for next stage
-            if idx < self.stages.len() - 1 {
-                let next_text = self.extract_next_input(&stage_result);
+            if idx < stages.len() - 1 {
+                let next_text = self.transform.extract_next_input(&stage_result);
+                pipeline_task
+                    .metadata
+                    .insert(CHECKPOINT_INPUT_KEY.to_string(), next_text.clone().into());
                 current_input = UnifiedMessage::user(next_text);
             }
+            pipeline_task.metadata.remove(CHECKPOINT_RUNNING_KEY);
+            pipeline_task
+                .metadata
+                .insert(CHECKPOINT_COMPLETED_KEY.to_string(), (idx + 1).into());
+            self.checkpoint(&pipeline_task).await?;
         }
 
+        pipeline_task.set_status(TaskStatus::Completed);
+        self.checkpoint(&pipeline_task).await?;
+
         // Store pipeline task state
         let task_id = pipeline_task.id.clone();
         self.tasks.write().await.insert(
@@ -329,17 +709,26 @@ impl UnifiedAgent for SequentialPipeline {
                 task: pipeline_task.clone(),
             },
         );
-
-        pipeline_task.set_status(TaskStatus::Completed);
         info!(
-            pipeline = %self.info.id,
+            pipeline = %self.pipeline_id,
             task_id = %task_id,
-            stages = self.stages.len(),
+            stages = stages.len(),
             "Pipeline completed"
         );
 
         Ok(pipeline_task)
     }
+}
+
+#[async_trait]
+impl UnifiedAgent for SequentialPipeline {
+    fn info(&self) -> &AgentInfo {
+        &self.info
+    }
+
+    async fn send_message(&self, message: UnifiedMessage) -> AgentResult<UnifiedTask> {
+        self.runner().start(message, &StageEvents::default()).await
+    }
 
     async fn send_message_to_task(
         &self,
@@ -355,39 +744,11 @@ impl UnifiedAgent for SequentialPipeline {
         &self,
         message: UnifiedMessage,
     ) -> AgentResult<Pin<Box<dyn Stream<Item = AgentResult<StreamEvent>> + Send>>> {
-        // Execute pipeline and simulate streaming
-        let task = self.send_message(message).await?;
-        let task_id = task.id.clone();
-
-        let stream = async_stream::stream! {
-            yield Ok(StreamEvent::StatusUpdate {
-                task_id: task_id.clone(),
-                status: TaskStatus::Working,
-                message: Some("Pipeline started".to_string()),
-            });
-
-            for msg in &task.messages {
-                yield Ok(StreamEvent::MessageAdded {
-                    task_id: task_id.clone(),
-                    message: msg.clone(),
-                });
-            }
-
-            for artifact in &task.artifacts {
-                yield Ok(StreamEvent::ArtifactAdded {
-                    task_id: task_id.clone(),
-                    artifact: artifact.clone(),
-                });
-            }
-
-            yield Ok(StreamEvent::StatusUpdate {
-                task_id: task_id.clone(),
-                status: task.status,
-                message: None,
-            });
-        };
+        let runner = self.runner();
 
-        Ok(Box::pin(stream))
+        Ok(stream_stages(move |events| async move {
+            runner.start(message, &events).await
+        }))
     }
 
     async fn get_task(&self, task_id: &str) -> AgentResult<UnifiedTask> {
@@ -451,7 +812,9 @@ pub struct ParallelAgent {
     aggregation: AggregationMode,
     /// Maximum time to wait for all agents (in milliseconds)
     timeout_ms: Option<u64>,
-    tasks: TaskCache,
+    /// Health-based selection of the agents to run
+    health: TargetHealth,
+    tasks: Arc<TaskCache>,
 }
 
 impl ParallelAgent {
@@ -462,7 +825,8 @@ impl ParallelAgent {
             agents: Vec::new(),
             aggregation: AggregationMode::default(),
             timeout_ms: None,
-            tasks: TaskCache::new(),
+            health: TargetHealth::default(),
+            tasks: Arc::new(TaskCache::new()),
         }
     }
 
@@ -490,203 +854,373 @@ impl ParallelAgent {
         self
     }
 
+    /// Skip agents that `provider` reports as unhealthy.
+    ///
+    /// Agents are matched to registrations by agent ID. An agent is skipped
+    /// only when it is registered and none of its registrations is usable;
+    /// unregistered agents are always included.
+    pub fn with_health_checks(mut self, provider: Arc<dyn DiscoveryProvider>) -> Self {
+        self.health.provider = Some(provider);
+        self
+    }
+
+    /// Also skip agents reported as degraded (default: false).
+    pub fn with_skip_degraded(mut self, skip: bool) -> Self {
+        self.health.skip_degraded = skip;
+        self
+    }
+
+    /// Fail before running any agent if fewer than `min` remain after
+    /// skipping unhealthy ones (default: 1).
+    pub fn with_min_healthy_targets(mut self, min: usize) -> Self {
+        self.health.min_healthy_targets = min.max(1);
+        self
+    }
+
     /// Get the number of parallel agents.
     pub fn agent_count(&self) -> usize {
         self.agents.len()
     }
 }
 
-#[async_trait]
-impl UnifiedAgent for ParallelAgent {
-    fn info(&self) -> &AgentInfo {
-        &self.info
-    }
+/// Health-based selection of fan-out targets.
+///
+/// Shared by [`ParallelAgent`] and [`FanOutAgent`](crate::bridge::FanOutAgent).
+#[derive(Clone)]
+pub(crate) struct TargetHealth {
+    /// Discovery provider consulted for target health before fanning out
+    pub(crate) provider: Option<Arc<dyn DiscoveryProvider>>,
+    /// Also skip targets reported as degraded
+    pub(crate) skip_degraded: bool,
+    /// Minimum number of targets that must remain after skipping
+    pub(crate) min_healthy_targets: usize,
+}
 
-    async fn send_message(&self, message: UnifiedMessage) -> AgentResult<UnifiedTask> {
-        if self.agents.is_empty() {
-            return Err(AgentError::Internal(
-                "ParallelAgent has no agents".to_string(),
-            ));
+impl Default for TargetHealth {
+    fn default() -> Self {
+        Self {
+            provider: None,
+            skip_degraded: false,
+            min_healthy_targets: 1,
         }
+    }
+}
 
-        let mut combined = UnifiedTask::new_with_uuid();
-        combined.add_message(message.clone());
+impl TargetHealth {
+    /// Split `agents` into those to run and the IDs of those skipped as unhealthy.
+    ///
+    /// Fails if fewer than `min_healthy_targets` remain. When the health
+    /// lookup itself fails, every agent is kept.
+    pub(crate) async fn select(
+        &self,
+        owner_id: &str,
+        agents: &[Arc<dyn UnifiedAgent>],
+    ) -> AgentResult<(Vec<Arc<dyn UnifiedAgent>>, Vec<String>)> {
+        let Some(provider) = &self.provider else {
+            return Ok((agents.to_vec(), Vec::new()));
+        };
 
-        // Execute all agents in parallel
-        let futures: Vec<_> = self
-            .agents
-            .iter()
-            .map(|a| a.send_message(message.clone()))
-            .collect();
+        let registrations = match provider.list().await {
+            Ok(registrations) => registrations,
+            Err(e) => {
+                warn!(
+                    agent = %owner_id,
+                    error = %e,
+                    "Health lookup failed, fanning out to all agents"
+                );
+                return Ok((agents.to_vec(), Vec::new()));
+            }
+        };
 
-        // FirstComplete uses select_all for true racing — return as soon as
-        // the first future resolves, without waiting for the rest.
-        if self.aggregation == AggregationMode::FirstComplete {
-            let race = futures::future::select_all(futures);
-            let result = if let Some(timeout) = self.timeout_ms {
-                match tokio::time::timeout(std::time::Duration::from_millis(timeout), race).await {
-                    Ok((result, _, _)) => result,
-                    Err(_) => {
-                        warn!(
-                            agent = %self.info.id,
-                            timeout_ms = timeout,
-                            "Parallel execution timed out"
-                        );
-                        combined.add_message(UnifiedMessage::agent("All agents timed out"));
-                        combined.set_status(TaskStatus::Failed);
-                        return Ok(combined);
-                    }
-                }
-            } else {
-                let (result, _, _) = race.await;
-                result
-            };
+        let mut statuses: HashMap<&str, Vec<HealthStatus>> = HashMap::new();
+        for registration in &registrations {
+            statuses
+                .entry(registration.agent_id.as_str())
+                .or_default()
+                .push(registration.health_status);
+        }
 
-            match result {
-                Ok(task) => {
-                    for msg in task.messages {
-                        combined.add_message(msg);
-                    }
-                    for artifact in task.artifacts {
-                        combined.add_artifact(artifact);
-                    }
-                    combined.set_status(task.status);
-                }
-                Err(e) => {
-                    combined.add_message(UnifiedMessage::agent(format!("Error: {}", e)));
-                    combined.set_status(TaskStatus::Failed);
-                }
-            }
+        let usable = |status: &HealthStatus| match status {
+            HealthStatus::Unhealthy => false,
+            HealthStatus::Degraded => !self.skip_degraded,
+            HealthStatus::Healthy | HealthStatus::Unknown => true,
+        };
+
+        let (targets, skipped): (Vec<_>, Vec<_>) = agents.iter().cloned().partition(|a| {
+            statuses
+                .get(a.info().id.as_str())
+                .is_none_or(|s| s.iter().any(usable))
+        });
+        let skipped: Vec<String> = skipped.iter().map(|a| a.info().id.clone()).collect();
 
-            return Ok(combined);
+        if !skipped.is_empty() {
+            debug!(agent = %owner_id, skipped = ?skipped, "Skipping unhealthy agents");
+        }
+        if targets.len() < self.min_healthy_targets {
+            return Err(AgentError::Internal(format!(
+                "{} has {} healthy agents, {} required (skipped: {})",
+                owner_id,
+                targets.len(),
+                self.min_healthy_targets,
+                skipped.join(", ")
+            )));
         }
 
-        // All other modes wait for every future to complete.
-        let results = if let Some(timeout) = self.timeout_ms {
-            match tokio::time::timeout(
-                std::time::Duration::from_millis(timeout),
-                futures::future::join_all(futures),
-            )
-            .await
-            {
-                Ok(results) => results,
+        Ok((targets, skipped))
+    }
+}
+
+/// Inputs of one [`run_parallel`] call that do not change between calls.
+struct ParallelRun<'a> {
+    parallel_id: &'a str,
+    agents: &'a [Arc<dyn UnifiedAgent>],
+    /// Agents left out for health reasons
+    skipped: &'a [String],
+    aggregation: AggregationMode,
+    timeout_ms: Option<u64>,
+    tasks: &'a TaskCache,
+}
+
+/// Run all branches concurrently, reporting branch progress to `events`.
+///
+/// With [`AggregationMode::FirstComplete`] or a timeout, branches still running
+/// when the result is decided are dropped and report no outcome. Agents in
+/// `skipped` were left out for health reasons and are noted on the result.
+async fn run_parallel(
+    run: ParallelRun<'_>,
+    message: UnifiedMessage,
+    events: &StageEvents,
+) -> AgentResult<UnifiedTask> {
+    let ParallelRun {
+        parallel_id,
+        agents,
+        skipped,
+        aggregation,
+        timeout_ms,
+        tasks,
+    } = run;
+    if agents.is_empty() {
+        return Err(AgentError::Internal(
+            "ParallelAgent has no agents".to_string(),
+        ));
+    }
+
+    let mut combined = UnifiedTask::new_with_uuid();
+    combined.add_message(message.clone());
+    if !skipped.is_empty() {
+        combined
+            .metadata
+            .insert("skipped_agents".to_string(), serde_json::json!(skipped));
+        combined.add_message(UnifiedMessage::agent(format!(
+            "Skipped unhealthy agents: {}",
+            skipped.join(", ")
+        )));
+    }
+    events.emit(StreamEvent::StatusUpdate {
+        task_id: combined.id.clone(),
+        status: TaskStatus::Working,
+        message: None,
+    });
+
+    // Execute all agents in parallel
+    let task_id = combined.id.clone();
+    let futures: Vec<_> = agents
+        .iter()
+        .enumerate()
+        .map(|(idx, a)| Box::pin(events.run_stage(&task_id, idx, a, message.clone())))
+        .collect();
+
+    // FirstComplete uses select_all for true racing — return as soon as
+    // the first future resolves, without waiting for the rest.
+    if aggregation == AggregationMode::FirstComplete {
+        let race = futures::future::select_all(futures);
+        let result = if let Some(timeout) = timeout_ms {
+            match tokio::time::timeout(std::time::Duration::from_millis(timeout), race).await {
+                Ok((result, _, _)) => result,
                 Err(_) => {
                     warn!(
-                        agent = %self.info.id,
+                        agent = %parallel_id,
                         timeout_ms = timeout,
                         "Parallel execution timed out"
                     );
-                    combined.add_message(UnifiedMessage::agent("Some agents timed out"));
-                    combined.set_status(TaskStatus::Completed);
+                    combined.add_message(UnifiedMessage::agent("All agents timed out"));
+                    combined.set_status(TaskStatus::Failed);
                     return Ok(combined);
                 }
             }
         } else {
-            futures::future::join_all(futures).await
+            let (result, _, _) = race.await;
+            result
         };
 
-        // Process results based on aggregation mode
-        match self.aggregation {
-            AggregationMode::CollectAll => {
-                let mut any_success = false;
-                for (idx, result) in results.into_iter().enumerate() {
-                    match result {
-                        Ok(task) => {
-                            any_success = true;
-                            // Add agent identifier to messages
-                            for msg in task.messages {
-                                if msg.role == MessageRole::Agent {
-                                    let mut annotated = msg.clone();
-                                    annotated.metadata.insert(
-                                        "source_agent".to_string(),
-                                        serde_json::json!(self.agents[idx].info().id),
-                                    );
-                                    combined.add_message(annotated);
-                                }
-                            }
-                            for artifact in task.artifacts {
-                                combined.add_artifact(artifact);
-                            }
-                        }
-                        Err(e) => {
-                            combined.add_message(UnifiedMessage::agent(format!(
-                                "Agent {} failed: {}",
-                                self.agents[idx].info().id,
-                                e
-                            )));
-                        }
-                    }
+        match result {
+            Ok(task) => {
+                for msg in task.messages {
+                    combined.add_message(msg);
                 }
-                combined.set_status(if any_success {
-                    TaskStatus::Completed
-                } else {
-                    TaskStatus::Failed
-                });
+                for artifact in task.artifacts {
+                    combined.add_artifact(artifact);
+                }
+                combined.set_status(task.status);
             }
+            Err(e) => {
+                combined.add_message(UnifiedMessage::agent(format!("Error: {}", e)));
+                combined.set_status(TaskStatus::Failed);
+            }
+        }
 
-            AggregationMode::FirstSuccess => {
-                for result in results {
-                    if let Ok(task) = result
-                        && task.status == TaskStatus::Completed
-                    {
+        return Ok(combined);
+    }
+
+    // All other modes wait for every future to complete.
+    let results = if let Some(timeout) = timeout_ms {
+        match tokio::time::timeout(
+            std::time::Duration::from_millis(timeout),
+            futures::future::join_all(futures),
+        )
+        .await
+        {
+            Ok(results) => results,
+            Err(_) => {
+                warn!(
+                    agent = %parallel_id,
+                    timeout_ms = timeout,
+                    "Parallel execution timed out"
+                );
+                combined.add_message(UnifiedMessage::agent("Some agents timed out"));
+                combined.set_status(TaskStatus::Completed);
+                return Ok(combined);
+            }
+        }
+    } else {
+        futures::future::join_all(futures).await
+    };
+
+    // Process results based on aggregation mode
+    match aggregation {
+        AggregationMode::CollectAll => {
+            let mut any_success = false;
+            for (idx, result) in results.into_iter().enumerate() {
+                match result {
+                    Ok(task) => {
+                        any_success = true;
+                        // Add agent identifier to messages
                         for msg in task.messages {
-                            combined.add_message(msg);
+                            if msg.role == MessageRole::Agent {
+                                let mut annotated = msg.clone();
+                                annotated.metadata.insert(
+                                    "source_agent".to_string(),
+                                    serde_json::json!(agents[idx].info().id),
+                                );
+                                combined.add_message(annotated);
+                            }
                         }
                         for artifact in task.artifacts {
                             combined.add_artifact(artifact);
                         }
-                        combined.set_status(TaskStatus::Completed);
-                        break;
+                    }
+                    Err(e) => {
+                        combined.add_message(UnifiedMessage::agent(format!(
+                            "Agent {} failed: {}",
+                            agents[idx].info().id,
+                            e
+                        )));
                     }
                 }
-                if combined.status != TaskStatus::Completed {
-                    combined.set_status(TaskStatus::Failed);
-                    combined.add_message(UnifiedMessage::agent("No agent succeeded"));
+            }
+            combined.set_status(if any_success {
+                TaskStatus::Completed
+            } else {
+                TaskStatus::Failed
+            });
+        }
+
+        AggregationMode::FirstSuccess => {
+            for result in results {
+                if let Ok(task) = result
+                    && task.status == TaskStatus::Completed
+                {
+                    for msg in task.messages {
+                        combined.add_message(msg);
+                    }
+                    for artifact in task.artifacts {
+                        combined.add_artifact(artifact);
+                    }
+                    combined.set_status(TaskStatus::Completed);
+                    break;
                 }
             }
+            if combined.status != TaskStatus::Completed {
+                combined.set_status(TaskStatus::Failed);
+                combined.add_message(UnifiedMessage::agent("No agent succeeded"));
+            }
+        }
 
-            // Already handled above via early return
-            AggregationMode::FirstComplete => unreachable!(),
+        // Already handled above via early return
+        AggregationMode::FirstComplete => unreachable!(),
 
-            AggregationMode::RequireAll => {
-                let mut all_success = true;
-                for (idx, result) in results.into_iter().enumerate() {
-                    match result {
-                        Ok(task) => {
-                            if task.status != TaskStatus::Completed {
-                                all_success = false;
-                            }
-                            for msg in task.messages {
-                                if msg.role == MessageRole::Agent {
-                                    combined.add_message(msg);
-                                }
-                            }
-                            for artifact in task.artifacts {
-                                combined.add_artifact(artifact);
+        AggregationMode::RequireAll => {
+            let mut all_success = true;
+            for (idx, result) in results.into_iter().enumerate() {
+                match result {
+                    Ok(task) => {
+                        if task.status != TaskStatus::Completed {
+                            all_success = false;
+                        }
+                        for msg in task.messages {
+                            if msg.role == MessageRole::Agent {
+                                combined.add_message(msg);
                             }
                         }
-                        Err(e) => {
-                            all_success = false;
-                            combined.add_message(UnifiedMessage::agent(format!(
-                                "Agent {} failed: {}",
-                                self.agents[idx].info().id,
-                                e
-                            )));
+                        for artifact in task.artifacts {
+                            combined.add_artifact(artifact);
                         }
                     }
+                    Err(e) => {
+                        all_success = false;
+                        combined.add_message(UnifiedMessage::agent(format!(
+                            "Agent {} failed: {}",
+                            agents[idx].info().id,
+                            e
+                        )));
+                    }
                 }
-                combined.set_status(if all_success {
-                    TaskStatus::Completed
-                } else {
-                    TaskStatus::Failed
-                });
             }
+            combined.set_status(if all_success {
+                TaskStatus::Completed
+            } else {
+                TaskStatus::Failed
+            });
         }
+    }
 
-        // Store task
-        self.tasks.insert(combined.clone()).await;
+    // Store task
+    tasks.insert(combined.clone()).await;
+
+    Ok(combined)
+}
+
+#[async_trait]
+impl UnifiedAgent for ParallelAgent {
+    fn info(&self) -> &AgentInfo {
+        &self.info
+    }
 
-        Ok(combined)
+    async fn send_message(&self, message: UnifiedMessage) -> AgentResult<UnifiedTask> {
+        let (agents, skipped) = self.health.select(&self.info.id, &self.agents).await?;
+        run_parallel(
+            ParallelRun {
+                parallel_id: &self.info.id,
+                agents: &agents,
+                skipped: &skipped,
+                aggregation: self.aggregation,
+                timeout_ms: self.timeout_ms,
+                tasks: &self.tasks,
+            },
+            message,
+            &StageEvents::default(),
+        )
+        .await
     }
 
     async fn send_message_to_task(
@@ -701,31 +1235,27 @@ impl UnifiedAgent for ParallelAgent {
         &self,
         message: UnifiedMessage,
     ) -> AgentResult<Pin<Box<dyn Stream<Item = AgentResult<StreamEvent>> + Send>>> {
-        let task = self.send_message(message).await?;
-        let task_id = task.id.clone();
-
-        let stream = async_stream::stream! {
-            yield Ok(StreamEvent::StatusUpdate {
-                task_id: task_id.clone(),
-                status: TaskStatus::Working,
-                message: None,
-            });
-
-            for msg in &task.messages {
-                yield Ok(StreamEvent::MessageAdded {
-                    task_id: task_id.clone(),
-                    message: msg.clone(),
-                });
-            }
-
-            yield Ok(StreamEvent::StatusUpdate {
-                task_id: task_id.clone(),
-                status: task.status,
-                message: None,
-            });
-        };
-
-        Ok(Box::pin(stream))
+        let parallel_id = self.info.id.clone();
+        let (agents, skipped) = self.health.select(&self.info.id, &self.agents).await?;
+        let aggregation = self.aggregation;
+        let timeout_ms = self.timeout_ms;
+        let tasks = Arc::clone(&self.tasks);
+
+        Ok(stream_stages(move |events| async move {
+            run_parallel(
+                ParallelRun {
+                    parallel_id: &parallel_id,
+                    agents: &agents,
+                    skipped: &skipped,
+                    aggregation,
+                    timeout_ms,
+                    tasks: &tasks,
+                },
+                message,
+                &events,
+            )
+            .await
+        }))
     }
 
     async fn get_task(&self, task_id: &str) -> AgentResult<UnifiedTask> {
@@ -797,10 +1327,89 @@ impl RoutingRule {
     }
 }
 
+/// How a [`RouterAgent`] chooses among the rules that match a message.
+///
+/// Rules act as filters: only agents whose rule matches are candidates, and
+/// the strategy picks one of them. An agent targeted by several matching
+/// rules counts once. The fallback agent is only used when no rule matches.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum RoutingStrategy {
+    /// Route to the first matching rule
+    #[default]
+    FirstMatch,
+    /// Cycle through the matching agents
+    RoundRobin,
+    /// Spread messages over the matching agents in proportion to the weight
+    /// given for their agent id; agents without a weight receive none. If no
+    /// matching agent has a weight, the first match is used.
+    Weighted(Vec<(String, u32)>),
+    /// Route to the matching agent with the fewest in-flight requests,
+    /// preferring earlier rules on ties
+    LeastActive,
+}
+
+/// In-flight request counts per downstream agent id.
+#[derive(Debug, Default)]
+struct InFlightCounts(Mutex<HashMap<String, usize>>);
+
+impl InFlightCounts {
+    fn lock(&self) -> MutexGuard<'_, HashMap<String, usize>> {
+        self.0.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn get(&self, agent_id: &str) -> usize {
+        self.lock().get(agent_id).copied().unwrap_or(0)
+    }
+
+    /// Count a request to `agent_id` until the returned guard is dropped.
+    fn begin(self: &Arc<Self>, agent_id: &str) -> InFlight {
+        Self::begin_locked(self, &mut self.lock(), agent_id)
+    }
+
+    fn begin_locked(
+        counts: &Arc<Self>,
+        locked: &mut HashMap<String, usize>,
+        agent_id: &str,
+    ) -> InFlight {
+        *locked.entry(agent_id.to_string()).or_insert(0) += 1;
+        InFlight {
+            counts: Arc::clone(counts),
+            agent_id: agent_id.to_string(),
+        }
+    }
+}
+
+/// Guard holding one in-flight request to a downstream agent.
+struct InFlight {
+    counts: Arc<InFlightCounts>,
+    agent_id: String,
+}
+
+impl Drop for InFlight {
+    fn drop(&mut self) {
+        let mut counts = self.counts.lock();
+        if let Some(count) = counts.get_mut(&self.agent_id) {
+            *count -= 1;
+            if *count == 0 {
+                counts.remove(&self.agent_id);
+            }
+        }
+    }
+}
+
+/// A routing decision together with its in-flight request guard.
+struct Route<'a> {
+    target: &'a Arc<dyn UnifiedAgent>,
+    rule_name: &'a str,
+    in_flight: InFlight,
+}
+
 /// An agent that routes messages to different agents based on rules.
 ///
-/// Rules are evaluated in order, and the first matching rule determines
-/// the target agent. A fallback agent handles messages that match no rules.
+/// Rules are evaluated in order, and by default the first matching rule
+/// determines the target agent. With a balancing [`RoutingStrategy`] the
+/// target is instead chosen among all matching rules. A fallback agent
+/// handles messages that match no rules.
 ///
 /// # Example
 /// ```rust,ignore
@@ -815,6 +1424,10 @@ pub struct RouterAgent {
     info: AgentInfo,
     rules: Vec<RoutingRule>,
     fallback: Option<Arc<dyn UnifiedAgent>>,
+    cache: Option<Arc<RoutingCache>>,
+    strategy: RoutingStrategy,
+    next: AtomicUsize,
+    in_flight: Arc<InFlightCounts>,
     tasks: tokio::sync::RwLock<HashMap<String, (UnifiedTask, String)>>, // task + routed agent id
 }
 
@@ -825,10 +1438,51 @@ impl RouterAgent {
             info: AgentInfo::new(id, name).with_description("Message routing agent"),
             rules: Vec::new(),
             fallback: None,
+            cache: None,
+            strategy: RoutingStrategy::default(),
+            next: AtomicUsize::new(0),
+            in_flight: Arc::new(InFlightCounts::default()),
             tasks: tokio::sync::RwLock::new(HashMap::new()),
         }
     }
 
+    /// Set how the router chooses among matching rules.
+    pub fn with_strategy(mut self, strategy: RoutingStrategy) -> Self {
+        self.strategy = strategy;
+        self
+    }
+
+    /// Get the routing strategy.
+    pub fn strategy(&self) -> &RoutingStrategy {
+        &self.strategy
+    }
+
+    /// Number of requests currently in flight to the agent with `agent_id`.
+    pub fn in_flight(&self, agent_id: &str) -> usize {
+        self.in_flight.get(agent_id)
+    }
+
+    /// Cache routing decisions so repeated inputs skip rule evaluation.
+    ///
+    /// The cache is keyed by the normalized message text; use
+    /// [`with_cache`](Self::with_cache) for rules that inspect other features.
+    /// Only the [`RoutingStrategy::FirstMatch`] strategy consults the cache,
+    /// since caching a balanced decision would pin every repeat to one agent.
+    pub fn with_routing_cache(self, config: RoutingCacheConfig) -> Self {
+        self.with_cache(Arc::new(RoutingCache::new(config)))
+    }
+
+    /// Use the given routing cache.
+    pub fn with_cache(mut self, cache: Arc<RoutingCache>) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    /// Get the routing cache, if enabled.
+    pub fn routing_cache(&self) -> Option<&Arc<RoutingCache>> {
+        self.cache.as_ref()
+    }
+
     /// Add a routing rule.
     pub fn add_rule(mut self, rule: RoutingRule) -> Self {
         // Merge capabilities from target
@@ -853,9 +1507,129 @@ impl RouterAgent {
         self
     }
 
-    /// Find the target agent for a message.
-    fn find_target(&self, message: &UnifiedMessage) -> Option<(&Arc<dyn UnifiedAgent>, &str)> {
+    /// Route a message, counting the request as in flight to its target.
+    fn route(&self, message: &UnifiedMessage) -> Option<Route<'_>> {
+        if self.strategy != RoutingStrategy::FirstMatch {
+            return self.balance(message);
+        }
+        let (target, rule_name) = self.find_target(message)?;
+        let in_flight = self.in_flight.begin(&target.info().id);
+        Some(Route {
+            target,
+            rule_name,
+            in_flight,
+        })
+    }
+
+    /// Apply the balancing strategy to the agents whose rules match.
+    fn balance(&self, message: &UnifiedMessage) -> Option<Route<'_>> {
+        let mut candidates: Vec<&RoutingRule> = Vec::new();
         for rule in &self.rules {
+            let target_id = &rule.target.info().id;
+            if !candidates.iter().any(|c| &c.target.info().id == target_id)
+                && (rule.condition)(message)
+            {
+                candidates.push(rule);
+            }
+        }
+        if candidates.is_empty() {
+            let fallback = self.fallback.as_ref()?;
+            return Some(Route {
+                target: fallback,
+                rule_name: "fallback",
+                in_flight: self.in_flight.begin(&fallback.info().id),
+            });
+        }
+
+        // Select and count under one lock so concurrent requests see each other
+        let mut counts = self.in_flight.lock();
+        let chosen = match &self.strategy {
+            RoutingStrategy::FirstMatch => 0,
+            RoutingStrategy::RoundRobin => {
+                self.next.fetch_add(1, Ordering::Relaxed) % candidates.len()
+            }
+            RoutingStrategy::Weighted(weights) => {
+                let weight_of = |rule: &RoutingRule| {
+                    weights
+                        .iter()
+                        .find(|(id, _)| *id == rule.target.info().id)
+                        .map_or(0, |(_, weight)| u64::from(*weight))
+                };
+                let total: u64 = candidates.iter().map(|rule| weight_of(rule)).sum();
+                if total == 0 {
+                    0
+                } else {
+                    let mut ticket = self.next.fetch_add(1, Ordering::Relaxed) as u64 % total;
+                    candidates
+                        .iter()
+                        .position(|rule| {
+                            let weight = weight_of(rule);
+                            if ticket < weight {
+                                true
+                            } else {
+                                ticket -= weight;
+                                false
+                            }
+                        })
+                        .unwrap_or(0)
+                }
+            }
+            RoutingStrategy::LeastActive => candidates
+                .iter()
+                .enumerate()
+                .min_by_key(|(_, rule)| counts.get(&rule.target.info().id).copied().unwrap_or(0))
+                .map_or(0, |(index, _)| index),
+        };
+
+        let rule = candidates[chosen];
+        debug!(
+            router = %self.info.id,
+            rule = %rule.name,
+            target = %rule.target.info().id,
+            candidates = candidates.len(),
+            "Balanced route selected"
+        );
+        Some(Route {
+            target: &rule.target,
+            rule_name: &rule.name,
+            in_flight: InFlightCounts::begin_locked(
+                &self.in_flight,
+                &mut counts,
+                &rule.target.info().id,
+            ),
+        })
+    }
+
+    /// Find the first-match target agent for a message, consulting the routing cache.
+    fn find_target(&self, message: &UnifiedMessage) -> Option<(&Arc<dyn UnifiedAgent>, &str)> {
+        let Some(cache) = &self.cache else {
+            return self.route_target(self.match_rule(message)?);
+        };
+
+        let key = cache.key_for(message);
+        if let Some(decision) = cache.get(&key)
+            && let Some((target, name)) = self.route_target(decision.rule_index)
+            && target.info().id == decision.target_agent_id
+        {
+            return Some((target, name));
+        }
+
+        let rule_index = self.match_rule(message)?;
+        let (target, name) = self.route_target(rule_index)?;
+        cache.insert(
+            key,
+            RoutingDecision {
+                rule_index,
+                target_agent_id: target.info().id.clone(),
+            },
+        );
+        Some((target, name))
+    }
+
+    /// Evaluate the rules in order, returning the matching rule index or
+    /// `Some(None)` when only the fallback applies.
+    fn match_rule(&self, message: &UnifiedMessage) -> Option<Option<usize>> {
+        for (index, rule) in self.rules.iter().enumerate() {
             if (rule.condition)(message) {
                 debug!(
                     router = %self.info.id,
@@ -863,10 +1637,21 @@ impl RouterAgent {
                     target = %rule.target.info().id,
                     "Rule matched"
                 );
-                return Some((&rule.target, &rule.name));
+                return Some(Some(index));
             }
         }
-        self.fallback.as_ref().map(|f| (f, "fallback"))
+        self.fallback.as_ref().map(|_| None)
+    }
+
+    /// Resolve a rule index (or the fallback) to its target agent and rule name.
+    fn route_target(&self, rule_index: Option<usize>) -> Option<(&Arc<dyn UnifiedAgent>, &str)> {
+        match rule_index {
+            Some(index) => self
+                .rules
+                .get(index)
+                .map(|rule| (&rule.target, rule.name.as_str())),
+            None => self.fallback.as_ref().map(|f| (f, "fallback")),
+        }
     }
 }
 
@@ -877,8 +1662,12 @@ impl UnifiedAgent for RouterAgent {
     }
 
     async fn send_message(&self, message: UnifiedMessage) -> AgentResult<UnifiedTask> {
-        let (target, rule_name) = self
-            .find_target(&message)
+        let Route {
+            target,
+            rule_name,
+            in_flight,
+        } = self
+            .route(&message)
             .ok_or_else(|| AgentError::Internal("No matching route found".to_string()))?;
 
         info!(
@@ -889,6 +1678,7 @@ impl UnifiedAgent for RouterAgent {
         );
 
         let mut task = target.send_message(message).await?;
+        drop(in_flight);
 
         // Add routing metadata
         task.metadata
@@ -931,6 +1721,7 @@ impl UnifiedAgent for RouterAgent {
             .ok_or_else(|| AgentError::Internal("Routed agent not found".to_string()))?;
 
         drop(tasks);
+        let _in_flight = self.in_flight.begin(&agent.info().id);
         agent.send_message_to_task(task_id, message).await
     }
 
@@ -938,12 +1729,19 @@ impl UnifiedAgent for RouterAgent {
         &self,
         message: UnifiedMessage,
     ) -> AgentResult<Pin<Box<dyn Stream<Item = AgentResult<StreamEvent>> + Send>>> {
-        let (target, _) = self
-            .find_target(&message)
+        let Route {
+            target, in_flight, ..
+        } = self
+            .route(&message)
             .ok_or_else(|| AgentError::Internal("No matching route found".to_string()))?;
 
         if target.supports_streaming() {
-            target.send_message_streaming(message).await
+            // The request stays in flight until the stream is dropped
+            let stream = target.send_message_streaming(message).await?;
+            Ok(Box::pin(stream.map(move |event| {
+                let _ = &in_flight;
+                event
+            })))
         } else {
             let task = target.send_message(message).await?;
             let task_id = task.id.clone();
@@ -1154,32 +1952,57 @@ impl SupervisorLogic for CapabilityBasedSupervisor {
 pub struct SupervisorAgent<L: SupervisorLogic> {
     info: AgentInfo,
     agents: HashMap<String, Arc<dyn UnifiedAgent>>,
+    /// Whether each agent may be re-run after an interruption
+    recovery: HashMap<String, StageRecovery>,
     logic: L,
     max_iterations: usize,
     tasks: TaskCache,
+    /// Store for per-iteration checkpoints, if resumption is enabled
+    checkpoints: Option<Arc<dyn TaskStore>>,
 }
 
+/// Metadata keys under which supervisor progress is checkpointed.
+const CHECKPOINT_SUPERVISOR_KEY: &str = "supervisor.id";
+const CHECKPOINT_ITERATIONS_KEY: &str = "supervisor.completed_iterations";
+const CHECKPOINT_RUNNING_AGENTS_KEY: &str = "supervisor.running_agents";
+
 impl<L: SupervisorLogic> SupervisorAgent<L> {
     /// Create a new supervisor agent.
     pub fn new(id: impl Into<String>, name: impl Into<String>, logic: L) -> Self {
         Self {
             info: AgentInfo::new(id, name).with_description("Workflow coordinator"),
             agents: HashMap::new(),
+            recovery: HashMap::new(),
             logic,
             max_iterations: 10,
             tasks: TaskCache::new(),
+            checkpoints: None,
         }
     }
 
     /// Add an agent to the supervisor's pool.
-    pub fn add_agent(mut self, agent: Arc<dyn UnifiedAgent>) -> Self {
+    ///
+    /// The agent counts as [`StageRecovery::RunOnce`]: an interrupted call to
+    /// it blocks resuming a checkpointed run.
+    pub fn add_agent(self, agent: Arc<dyn UnifiedAgent>) -> Self {
+        self.add_agent_with_recovery(agent, StageRecovery::RunOnce)
+    }
+
+    /// Add an agent, declaring whether it may be re-run after an interruption.
+    pub fn add_agent_with_recovery(
+        mut self,
+        agent: Arc<dyn UnifiedAgent>,
+        recovery: StageRecovery,
+    ) -> Self {
         // Merge capabilities
         for cap in agent.capabilities() {
             if !self.info.capabilities.iter().any(|c| c.id == cap.id) {
                 self.info.capabilities.push(cap.clone());
             }
         }
-        self.agents.insert(agent.info().id.clone(), agent);
+        let agent_id = agent.info().id.clone();
+        self.recovery.insert(agent_id.clone(), recovery);
+        self.agents.insert(agent_id, agent);
         self
     }
 
@@ -1189,24 +2012,116 @@ impl<L: SupervisorLogic> SupervisorAgent<L> {
         self
     }
 
-    /// Get available agents as a slice.
-    fn agents_vec(&self) -> Vec<Arc<dyn UnifiedAgent>> {
-        self.agents.values().cloned().collect()
+    /// Checkpoint progress to `store` so interrupted runs can be resumed.
+    pub fn with_checkpoints(mut self, store: Arc<dyn TaskStore>) -> Self {
+        self.checkpoints = Some(store);
+        self
+    }
+
+    /// Get available agents as a slice.
+    fn agents_vec(&self) -> Vec<Arc<dyn UnifiedAgent>> {
+        self.agents.values().cloned().collect()
+    }
+
+    /// IDs of checkpointed runs of this supervisor that did not finish.
+    pub async fn interrupted_tasks(&self) -> AgentResult<Vec<String>> {
+        interrupted_runs(
+            self.checkpoints.as_deref(),
+            CHECKPOINT_SUPERVISOR_KEY,
+            &self.info.id,
+        )
+        .await
+    }
+
+    /// Persist the task's progress, if checkpointing is enabled.
+    async fn checkpoint(&self, task: &UnifiedTask) -> AgentResult<()> {
+        match &self.checkpoints {
+            Some(store) => store.save(task).await,
+            None => Ok(()),
+        }
+    }
+
+    /// Record that `iterations` iterations finished, if checkpointing is enabled.
+    async fn checkpoint_iteration(
+        &self,
+        task: &mut UnifiedTask,
+        iterations: usize,
+    ) -> AgentResult<()> {
+        task.metadata.remove(CHECKPOINT_RUNNING_AGENTS_KEY);
+        if self.checkpoints.is_none() {
+            return Ok(());
+        }
+        task.metadata
+            .insert(CHECKPOINT_ITERATIONS_KEY.to_string(), iterations.into());
+        self.checkpoint(task).await
     }
 }
 
-#[async_trait]
-impl<L: SupervisorLogic + 'static> UnifiedAgent for SupervisorAgent<L> {
-    fn info(&self) -> &AgentInfo {
-        &self.info
-    }
+impl<L: SupervisorLogic + 'static> SupervisorAgent<L> {
+    /// Resume a checkpointed run after its last completed iteration.
+    ///
+    /// Messages and artifacts of completed iterations are kept, and the
+    /// logic decides the next step from them. An iteration that was cut off
+    /// is run again from its decision.
+    ///
+    /// # Errors
+    ///
+    /// Fails if checkpointing is disabled, the task is unknown or belongs to
+    /// another supervisor, or an agent the interrupted iteration called is
+    /// [`StageRecovery::RunOnce`].
+    pub async fn resume(&self, task_id: &str) -> AgentResult<UnifiedTask> {
+        let store = self.checkpoints.as_ref().ok_or_else(|| {
+            AgentError::Internal("Supervisor checkpointing is not enabled".to_string())
+        })?;
+        let mut task = store
+            .get(task_id)
+            .await?
+            .ok_or_else(|| AgentError::TaskNotFound(task_id.to_string()))?;
+        if task.is_terminal() {
+            return Ok(task);
+        }
 
-    async fn send_message(&self, message: UnifiedMessage) -> AgentResult<UnifiedTask> {
-        let mut task = UnifiedTask::new_with_uuid();
-        task.add_message(message);
+        if task.metadata.get(CHECKPOINT_SUPERVISOR_KEY)
+            != Some(&serde_json::Value::from(self.info.id.as_str()))
+        {
+            return Err(AgentError::InvalidRequest(format!(
+                "Task {} was not checkpointed by supervisor {}",
+                task_id, self.info.id
+            )));
+        }
+        let running = task
+            .metadata
+            .remove(CHECKPOINT_RUNNING_AGENTS_KEY)
+            .and_then(|running| serde_json::from_value::<Vec<String>>(running).ok())
+            .unwrap_or_default();
+        if let Some(agent_id) = running
+            .iter()
+            .find(|id| self.recovery.get(*id) != Some(&StageRecovery::Idempotent))
+        {
+            return Err(AgentError::Internal(format!(
+                "Supervised agent {} was interrupted and is not safe to re-run",
+                agent_id
+            )));
+        }
+
+        let iterations = task
+            .metadata
+            .get(CHECKPOINT_ITERATIONS_KEY)
+            .and_then(serde_json::Value::as_u64)
+            .map_or(0, |n| n as usize);
+        info!(
+            supervisor = %self.info.id,
+            task_id = %task_id,
+            iteration = iterations,
+            "Resuming supervisor from checkpoint"
+        );
+        self.run(task, iterations).await
+    }
 
+    /// Run decision iterations on `task`, `iterations` of which already ran.
+    async fn run(&self, mut task: UnifiedTask, mut iterations: usize) -> AgentResult<UnifiedTask> {
         let available = self.agents_vec();
-        let mut iterations = 0;
+        task.set_status(TaskStatus::Working);
 
         loop {
             if iterations >= self.max_iterations {
@@ -1226,6 +2141,22 @@ impl<L: SupervisorLogic + 'static> UnifiedAgent for SupervisorAgent<L> {
                 "Supervisor decision"
             );
 
+            // Mark the called agents as in flight so a crash here is
+            // detectable on resume
+            let running: &[String] = match &decision {
+                SupervisorDecision::RouteToAgent(agent_id) => std::slice::from_ref(agent_id),
+                SupervisorDecision::ExecuteParallel(agent_ids)
+                | SupervisorDecision::ExecuteSequence(agent_ids) => agent_ids,
+                _ => &[],
+            };
+            if self.checkpoints.is_some() && !running.is_empty() {
+                task.metadata.insert(
+                    CHECKPOINT_RUNNING_AGENTS_KEY.to_string(),
+                    serde_json::json!(running),
+                );
+                self.checkpoint(&task).await?;
+            }
+
             match decision {
                 SupervisorDecision::RouteToAgent(agent_id) => {
                     if let Some(agent) = self.agents.get(&agent_id) {
@@ -1366,13 +2297,37 @@ impl<L: SupervisorLogic + 'static> UnifiedAgent for SupervisorAgent<L> {
                     break;
                 }
             }
+
+            // The iteration finished without ending the run
+            self.checkpoint_iteration(&mut task, iterations).await?;
         }
 
+        self.checkpoint_iteration(&mut task, iterations).await?;
+
         // Store task
         self.tasks.insert(task.clone()).await;
 
         Ok(task)
     }
+}
+
+#[async_trait]
+impl<L: SupervisorLogic + 'static> UnifiedAgent for SupervisorAgent<L> {
+    fn info(&self) -> &AgentInfo {
+        &self.info
+    }
+
+    async fn send_message(&self, message: UnifiedMessage) -> AgentResult<UnifiedTask> {
+        let mut task = UnifiedTask::new_with_uuid();
+        task.add_message(message);
+        if self.checkpoints.is_some() {
+            task.metadata.insert(
+                CHECKPOINT_SUPERVISOR_KEY.to_string(),
+                self.info.id.clone().into(),
+            );
+        }
+        self.run(task, 0).await
+    }
 
     async fn send_message_to_task(
         &self,
@@ -1458,13 +2413,19 @@ mod tests {
     struct MockAgent {
         info: AgentInfo,
         response: String,
+        delay_ms: u64,
     }
 
     impl MockAgent {
         fn new(id: &str, response: &str) -> Arc<Self> {
+            Self::delayed(id, response, 0)
+        }
+
+        fn delayed(id: &str, response: &str, delay_ms: u64) -> Arc<Self> {
             Arc::new(Self {
                 info: AgentInfo::new(id, id).with_capability(Capability::new(id, id)),
                 response: response.to_string(),
+                delay_ms,
             })
         }
     }
@@ -1476,6 +2437,9 @@ mod tests {
         }
 
         async fn send_message(&self, message: UnifiedMessage) -> AgentResult<UnifiedTask> {
+            if self.delay_ms > 0 {
+                tokio::time::sleep(std::time::Duration::from_millis(self.delay_ms)).await;
+            }
             let mut task = UnifiedTask::new_with_uuid();
             task.add_message(message);
             task.add_message(UnifiedMessage::agent(&self.response));
@@ -1491,24 +2455,6 @@ mod tests {
             self.send_message(message).await
         }
 
-        async fn send_message_streaming(
-            &self,
-            message: UnifiedMessage,
-        ) -> AgentResult<Pin<Box<dyn Stream<Item = AgentResult<StreamEvent>> + Send>>> {
-            let task = self.send_message(message).await?;
-            let task_id = task.id.clone();
-
-            let stream = async_stream::stream! {
-                yield Ok(StreamEvent::StatusUpdate {
-                    task_id: task_id.clone(),
-                    status: TaskStatus::Completed,
-                    message: None,
-                });
-            };
-
-            Ok(Box::pin(stream))
-        }
-
         async fn get_task(&self, task_id: &str) -> AgentResult<UnifiedTask> {
             Err(AgentError::TaskNotFound(task_id.to_string()))
         }
@@ -1524,8 +2470,8 @@ mod tests {
         let agent2 = MockAgent::new("agent2", "Step 2 complete");
 
         let pipeline = SequentialPipeline::new("test-pipeline", "Test Pipeline")
-            .add_stage(agent1)
-            .add_stage(agent2);
+            .add_stage(agent1, StageRecovery::Idempotent)
+            .add_stage(agent2, StageRecovery::Idempotent);
 
         assert_eq!(pipeline.stage_count(), 2);
 
@@ -1539,6 +2485,248 @@ mod tests {
         assert!(result.messages.len() >= 2);
     }
 
+    /// A stage that counts its calls and can be told to fail.
+    struct CountingStage {
+        info: AgentInfo,
+        calls: std::sync::atomic::AtomicUsize,
+        fail: bool,
+    }
+
+    impl CountingStage {
+        fn new(id: &str, fail: bool) -> Arc<Self> {
+            Arc::new(Self {
+                info: AgentInfo::new(id, id),
+                calls: std::sync::atomic::AtomicUsize::new(0),
+                fail,
+            })
+        }
+
+        fn calls(&self) -> usize {
+            self.calls.load(std::sync::atomic::Ordering::SeqCst)
+        }
+    }
+
+    #[async_trait]
+    impl UnifiedAgent for CountingStage {
+        fn info(&self) -> &AgentInfo {
+            &self.info
+        }
+
+        async fn send_message(&self, message: UnifiedMessage) -> AgentResult<UnifiedTask> {
+            self.calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            if self.fail {
+                return Err(AgentError::ConnectionError("process crashed".to_string()));
+            }
+            let mut task = UnifiedTask::new_with_uuid();
+            task.add_message(UnifiedMessage::agent(format!(
+                "{}({})",
+                self.info.id,
+                message.text_content()
+            )));
+            task.set_status(TaskStatus::Completed);
+            Ok(task)
+        }
+
+        async fn send_message_to_task(
+            &self,
+            _task_id: &str,
+            message: UnifiedMessage,
+        ) -> AgentResult<UnifiedTask> {
+            self.send_message(message).await
+        }
+
+        async fn send_message_streaming(
+            &self,
+            _message: UnifiedMessage,
+        ) -> AgentResult<Pin<Box<dyn Stream<Item = AgentResult<StreamEvent>> + Send>>> {
+            Err(AgentError::Internal("not streamed".to_string()))
+        }
+
+        async fn get_task(&self, task_id: &str) -> AgentResult<UnifiedTask> {
+            Err(AgentError::TaskNotFound(task_id.to_string()))
+        }
+
+        async fn cancel_task(&self, task_id: &str) -> AgentResult<UnifiedTask> {
+            Err(AgentError::TaskNotFound(task_id.to_string()))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_pipeline_resumes_after_last_checkpointed_stage() {
+        let store: Arc<dyn TaskStore> = crate::storage::InMemoryTaskStore::shared();
+        let (first, second) = (
+            CountingStage::new("s1", false),
+            CountingStage::new("s2", false),
+        );
+
+        // Stage 3 crashes, leaving stages 1 and 2 checkpointed
+        let crashed = SequentialPipeline::new("etl", "ETL")
+            .add_stage(first.clone(), StageRecovery::Idempotent)
+            .add_stage(second.clone(), StageRecovery::Idempotent)
+            .add_stage(CountingStage::new("s3", true), StageRecovery::Idempotent)
+            .with_checkpoints(Arc::clone(&store));
+        assert!(
+            crashed
+                .send_message(UnifiedMessage::user("in"))
+                .await
+                .is_err()
+        );
+
+        // A restarted pipeline finds the interrupted run and resumes at stage 3
+        let third = CountingStage::new("s3", false);
+        let restarted = SequentialPipeline::new("etl", "ETL")
+            .add_stage(first.clone(), StageRecovery::Idempotent)
+            .add_stage(second.clone(), StageRecovery::Idempotent)
+            .add_stage(third.clone(), StageRecovery::Idempotent)
+            .with_checkpoints(Arc::clone(&store));
+        let interrupted = restarted.interrupted_tasks().await.unwrap();
+        assert_eq!(interrupted.len(), 1);
+
+        let task = restarted.resume(&interrupted[0]).await.unwrap();
+        assert_eq!(task.status, TaskStatus::Completed);
+        assert_eq!((first.calls(), second.calls(), third.calls()), (1, 1, 1));
+
+        let outputs: Vec<String> = task
+            .messages
+            .iter()
+            .filter(|m| m.role == MessageRole::Agent)
+            .map(|m| m.text_content())
+            .collect();
+        assert_eq!(outputs, vec!["s1(in)", "s2(s1(in))", "s3(s2(s1(in)))"]);
+        assert!(restarted.interrupted_tasks().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_pipeline_refuses_to_rerun_interrupted_run_once_stage() {
+        let store: Arc<dyn TaskStore> = crate::storage::InMemoryTaskStore::shared();
+        let crashed = SequentialPipeline::new("billing", "Billing")
+            .add_stage(
+                CountingStage::new("quote", false),
+                StageRecovery::Idempotent,
+            )
+            .add_stage(CountingStage::new("charge", true), StageRecovery::RunOnce)
+            .with_checkpoints(Arc::clone(&store));
+        assert!(
+            crashed
+                .send_message(UnifiedMessage::user("order"))
+                .await
+                .is_err()
+        );
+
+        let charge = CountingStage::new("charge", false);
+        let restarted = SequentialPipeline::new("billing", "Billing")
+            .add_stage(
+                CountingStage::new("quote", false),
+                StageRecovery::Idempotent,
+            )
+            .add_stage(charge.clone(), StageRecovery::RunOnce)
+            .with_checkpoints(store);
+        let task_id = restarted.interrupted_tasks().await.unwrap().remove(0);
+
+        assert!(restarted.resume(&task_id).await.is_err());
+        assert_eq!(charge.calls(), 0);
+    }
+
+    /// Routes to each agent in turn, one per iteration, then completes.
+    struct RoundRobinLogic(Vec<&'static str>);
+
+    #[async_trait]
+    impl SupervisorLogic for RoundRobinLogic {
+        async fn decide(
+            &self,
+            task: &UnifiedTask,
+            _available_agents: &[Arc<dyn UnifiedAgent>],
+        ) -> SupervisorDecision {
+            let done = task
+                .messages
+                .iter()
+                .filter(|m| m.role == MessageRole::Agent)
+                .count();
+            match self.0.get(done) {
+                Some(agent_id) => SupervisorDecision::RouteToAgent(agent_id.to_string()),
+                None => SupervisorDecision::Complete,
+            }
+        }
+
+        async fn process_results(
+            &self,
+            _task: &mut UnifiedTask,
+            _results: Vec<AgentResult<UnifiedTask>>,
+        ) -> SupervisorDecision {
+            SupervisorDecision::RouteToAgent(String::new())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_supervisor_resumes_after_last_checkpointed_iteration() {
+        let store: Arc<dyn TaskStore> = crate::storage::InMemoryTaskStore::shared();
+        let logic = || RoundRobinLogic(vec!["s1", "s2", "s3"]);
+        let (first, second) = (
+            CountingStage::new("s1", false),
+            CountingStage::new("s2", false),
+        );
+
+        // The third call hangs and the process dies during it
+        let crashed = SupervisorAgent::new("etl", "ETL", logic())
+            .add_agent(first.clone())
+            .add_agent(second.clone())
+            .add_agent_with_recovery(
+                MockAgent::delayed("s3", "never", 60_000),
+                StageRecovery::Idempotent,
+            )
+            .with_checkpoints(Arc::clone(&store));
+        let run = crashed.send_message(UnifiedMessage::user("in"));
+        assert!(
+            tokio::time::timeout(std::time::Duration::from_millis(100), run)
+                .await
+                .is_err()
+        );
+
+        let third = CountingStage::new("s3", false);
+        let restarted = SupervisorAgent::new("etl", "ETL", logic())
+            .add_agent(first.clone())
+            .add_agent(second.clone())
+            .add_agent_with_recovery(third.clone(), StageRecovery::Idempotent)
+            .with_checkpoints(Arc::clone(&store));
+        let interrupted = restarted.interrupted_tasks().await.unwrap();
+        assert_eq!(interrupted.len(), 1);
+
+        let task = restarted.resume(&interrupted[0]).await.unwrap();
+        assert_eq!(task.status, TaskStatus::Completed);
+        assert_eq!((first.calls(), second.calls(), third.calls()), (1, 1, 1));
+        let outputs: Vec<String> = task
+            .messages
+            .iter()
+            .filter(|m| m.role == MessageRole::Agent)
+            .map(|m| m.text_content())
+            .collect();
+        assert_eq!(outputs, vec!["s1(in)", "s2(in)", "s3(in)"]);
+        assert!(restarted.interrupted_tasks().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_supervisor_refuses_to_rerun_interrupted_run_once_agent() {
+        let store: Arc<dyn TaskStore> = crate::storage::InMemoryTaskStore::shared();
+        let crashed = SupervisorAgent::new("billing", "Billing", RoundRobinLogic(vec!["charge"]))
+            .add_agent(MockAgent::delayed("charge", "never", 60_000))
+            .with_checkpoints(Arc::clone(&store));
+        let run = crashed.send_message(UnifiedMessage::user("order"));
+        assert!(
+            tokio::time::timeout(std::time::Duration::from_millis(100), run)
+                .await
+                .is_err()
+        );
+
+        let charge = CountingStage::new("charge", false);
+        let restarted = SupervisorAgent::new("billing", "Billing", RoundRobinLogic(vec!["charge"]))
+            .add_agent(charge.clone())
+            .with_checkpoints(store);
+        let task_id = restarted.interrupted_tasks().await.unwrap().remove(0);
+
+        assert!(restarted.resume(&task_id).await.is_err());
+        assert_eq!(charge.calls(), 0);
+    }
+
     #[tokio::test]
     async fn test_parallel_agent() {
         let agent1 = MockAgent::new("search1", "Result from search 1");
@@ -1566,6 +2754,187 @@ mod tests {
         assert_eq!(agent_messages.len(), 2);
     }
 
+    async fn health_provider(statuses: &[(&str, HealthStatus)]) -> Arc<dyn DiscoveryProvider> {
+        use crate::discovery::{AgentRegistration, InMemoryDiscoveryProvider};
+
+        let provider = InMemoryDiscoveryProvider::new();
+        for (agent_id, status) in statuses {
+            let mut registration = AgentRegistration::new(*agent_id, *agent_id);
+            registration.health_status = *status;
+            provider.register(registration).await.unwrap();
+        }
+        Arc::new(provider)
+    }
+
+    #[tokio::test]
+    async fn test_parallel_skips_unhealthy_agents() {
+        let provider = health_provider(&[
+            ("search1", HealthStatus::Healthy),
+            ("search2", HealthStatus::Unhealthy),
+        ])
+        .await;
+
+        let parallel = ParallelAgent::new("multi-search", "Multi Search")
+            .add_agent(MockAgent::new("search1", "Result from search 1"))
+            .add_agent(MockAgent::new("search2", "Result from search 2"))
+            .add_agent(MockAgent::new("search3", "Result from search 3"))
+            .with_health_checks(provider);
+
+        let result = parallel
+            .send_message(UnifiedMessage::user("Search query"))
+            .await
+            .unwrap();
+
+        assert_eq!(result.status, TaskStatus::Completed);
+        assert_eq!(
+            result.metadata.get("skipped_agents"),
+            Some(&serde_json::json!(["search2"]))
+        );
+        let sources: Vec<_> = result
+            .messages
+            .iter()
+            .filter_map(|m| m.metadata.get("source_agent"))
+            .collect();
+        assert_eq!(
+            sources,
+            vec![&serde_json::json!("search1"), &serde_json::json!("search3")]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_parallel_fails_below_min_healthy_targets() {
+        let provider = health_provider(&[
+            ("search1", HealthStatus::Degraded),
+            ("search2", HealthStatus::Unhealthy),
+        ])
+        .await;
+
+        let parallel = ParallelAgent::new("multi-search", "Multi Search")
+            .add_agent(MockAgent::new("search1", "Result from search 1"))
+            .add_agent(MockAgent::new("search2", "Result from search 2"))
+            .with_health_checks(provider)
+            .with_min_healthy_targets(2);
+
+        let err = parallel
+            .send_message(UnifiedMessage::user("Search query"))
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("1 healthy agents, 2 required"));
+
+        let parallel = parallel
+            .with_skip_degraded(true)
+            .with_min_healthy_targets(1);
+        assert!(
+            parallel
+                .send_message_streaming(UnifiedMessage::user("Search query"))
+                .await
+                .is_err()
+        );
+    }
+
+    /// Collect a stream, summarizing stage events as `(kind, stage, agent_id)`.
+    async fn collect_stage_events(
+        stream: EventStream,
+    ) -> (Vec<(&'static str, usize, String)>, Vec<StreamEvent>) {
+        let events: Vec<StreamEvent> = stream.map(|e| e.unwrap()).collect().await;
+        let stages = events
+            .iter()
+            .filter_map(|event| match event {
+                StreamEvent::StageStarted {
+                    stage, agent_id, ..
+                } => Some(("started", *stage, agent_id.clone())),
+                StreamEvent::StageCompleted {
+                    stage, agent_id, ..
+                } => Some(("completed", *stage, agent_id.clone())),
+                StreamEvent::StageFailed {
+                    stage, agent_id, ..
+                } => Some(("failed", *stage, agent_id.clone())),
+                _ => None,
+            })
+            .collect();
+        (stages, events)
+    }
+
+    #[tokio::test]
+    async fn test_pipeline_streams_stage_events_in_order() {
+        let pipeline = SequentialPipeline::new("pipeline", "Pipeline")
+            .add_stage(
+                MockAgent::new("clean", "cleaned"),
+                StageRecovery::Idempotent,
+            )
+            .add_stage(
+                MockAgent::new("analyze", "analyzed"),
+                StageRecovery::Idempotent,
+            )
+            .add_stage(
+                MockAgent::new("summarize", "summary"),
+                StageRecovery::Idempotent,
+            );
+
+        let stream = pipeline
+            .send_message_streaming(UnifiedMessage::user("Start"))
+            .await
+            .unwrap();
+        let (stages, events) = collect_stage_events(stream).await;
+
+        let expected: Vec<_> = ["clean", "analyze", "summarize"]
+            .iter()
+            .enumerate()
+            .flat_map(|(idx, id)| {
+                [
+                    ("started", idx, id.to_string()),
+                    ("completed", idx, id.to_string()),
+                ]
+            })
+            .collect();
+        assert_eq!(stages, expected);
+
+        // The aggregated result follows the stage events, ending with the final status
+        match events.last().unwrap() {
+            StreamEvent::StatusUpdate {
+                task_id, status, ..
+            } => {
+                assert_eq!(*status, TaskStatus::Completed);
+                assert!(pipeline.get_task(task_id).await.is_ok());
+            }
+            other => panic!("Expected terminal status update, got {:?}", other),
+        }
+        assert!(events.iter().any(|e| matches!(
+            e,
+            StreamEvent::MessageAdded { message, .. } if message.text_content() == "summary"
+        )));
+    }
+
+    #[tokio::test]
+    async fn test_parallel_branches_report_independently() {
+        let parallel = ParallelAgent::new("parallel", "Parallel")
+            .add_agent(MockAgent::delayed("slow", "slow result", 50))
+            .add_agent(MockAgent::new("fast", "fast result"));
+
+        let stream = parallel
+            .send_message_streaming(UnifiedMessage::user("Query"))
+            .await
+            .unwrap();
+        let (stages, events) = collect_stage_events(stream).await;
+
+        assert_eq!(
+            stages,
+            vec![
+                ("started", 0, "slow".to_string()),
+                ("started", 1, "fast".to_string()),
+                ("completed", 1, "fast".to_string()),
+                ("completed", 0, "slow".to_string()),
+            ]
+        );
+        assert!(matches!(
+            events.last(),
+            Some(StreamEvent::StatusUpdate {
+                status: TaskStatus::Completed,
+                ..
+            })
+        ));
+    }
+
     #[tokio::test]
     async fn test_router_agent() {
         let weather_agent = MockAgent::new("weather", "Sunny and warm");
@@ -1589,6 +2958,153 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_router_cache_hit_skips_rule_evaluation() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let evaluations = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&evaluations);
+        let router = RouterAgent::new("router", "Router")
+            .with_routing_cache(RoutingCacheConfig::default())
+            .add_rule(RoutingRule::new(
+                "counted",
+                move |msg| {
+                    counter.fetch_add(1, Ordering::SeqCst);
+                    msg.text_content().contains("weather")
+                },
+                MockAgent::new("weather", "Sunny"),
+            ));
+
+        for _ in 0..3 {
+            router
+                .send_message(UnifiedMessage::user("weather please"))
+                .await
+                .unwrap();
+        }
+
+        assert_eq!(evaluations.load(Ordering::SeqCst), 1);
+        let stats = router.routing_cache().unwrap().stats();
+        assert_eq!(stats.hits, 2);
+        assert_eq!(stats.misses, 1);
+    }
+
+    #[tokio::test]
+    async fn test_router_cache_invalidated_by_discovery_change() {
+        use crate::discovery::{DeregistrationReason, DiscoveryEvent};
+
+        let router = RouterAgent::new("router", "Router")
+            .with_routing_cache(RoutingCacheConfig::default())
+            .add_rule(RoutingRule::keyword(
+                "weather",
+                MockAgent::new("weather", "Sunny"),
+            ))
+            .with_fallback(MockAgent::new("search", "Results"));
+
+        for input in ["weather today", "find docs"] {
+            router
+                .send_message(UnifiedMessage::user(input))
+                .await
+                .unwrap();
+        }
+        let cache = router.routing_cache().unwrap();
+        assert_eq!(cache.stats().entries, 2);
+
+        cache.handle_discovery_event(&DiscoveryEvent::AgentDeregistered {
+            registration_id: "reg-1".to_string(),
+            agent_id: "weather".to_string(),
+            reason: DeregistrationReason::Explicit,
+        });
+
+        let stats = cache.stats();
+        assert_eq!(stats.entries, 1);
+        assert_eq!(stats.invalidations, 1);
+        let weather_key = cache.key_for(&UnifiedMessage::user("weather today"));
+        let search_key = cache.key_for(&UnifiedMessage::user("find docs"));
+        assert!(cache.get(&weather_key).is_none());
+        assert_eq!(
+            cache.get(&search_key).unwrap().target_agent_id,
+            "search".to_string()
+        );
+    }
+
+    /// Agent id that handled a routed task.
+    fn routed_to(task: &UnifiedTask) -> String {
+        task.metadata["target_agent"].as_str().unwrap().to_string()
+    }
+
+    #[tokio::test]
+    async fn test_router_round_robin_and_weighted_respect_rules() {
+        let balanced = |strategy| {
+            RouterAgent::new("router", "Router")
+                .add_rule(RoutingRule::keyword("search", MockAgent::new("a", "A")))
+                .add_rule(RoutingRule::keyword("search", MockAgent::new("b", "B")))
+                .add_rule(RoutingRule::keyword("math", MockAgent::new("c", "C")))
+                .with_strategy(strategy)
+        };
+
+        let router = balanced(RoutingStrategy::RoundRobin);
+        let mut targets = Vec::new();
+        for _ in 0..4 {
+            let task = router
+                .send_message(UnifiedMessage::user("search docs"))
+                .await
+                .unwrap();
+            targets.push(routed_to(&task));
+        }
+        assert_eq!(targets, vec!["a", "b", "a", "b"]);
+
+        let router = balanced(RoutingStrategy::Weighted(vec![
+            ("a".to_string(), 3),
+            ("b".to_string(), 1),
+            ("c".to_string(), 100),
+        ]));
+        let mut counts = HashMap::new();
+        for _ in 0..8 {
+            let task = router
+                .send_message(UnifiedMessage::user("search docs"))
+                .await
+                .unwrap();
+            *counts.entry(routed_to(&task)).or_insert(0) += 1;
+        }
+        assert_eq!(counts.get("a"), Some(&6));
+        assert_eq!(counts.get("b"), Some(&2));
+        assert_eq!(counts.get("c"), None);
+    }
+
+    #[tokio::test]
+    async fn test_router_least_active_avoids_busy_agent() {
+        let router = Arc::new(
+            RouterAgent::new("router", "Router")
+                .add_rule(RoutingRule::keyword(
+                    "search",
+                    MockAgent::delayed("slow", "Slow", 200),
+                ))
+                .add_rule(RoutingRule::keyword(
+                    "search",
+                    MockAgent::new("fast", "Fast"),
+                ))
+                .with_strategy(RoutingStrategy::LeastActive),
+        );
+
+        let busy = Arc::clone(&router);
+        let slow_request =
+            tokio::spawn(async move { busy.send_message(UnifiedMessage::user("search")).await });
+        while router.in_flight("slow") == 0 {
+            tokio::time::sleep(std::time::Duration::from_millis(5)).await;
+        }
+
+        let task = router
+            .send_message(UnifiedMessage::user("search"))
+            .await
+            .unwrap();
+        assert_eq!(routed_to(&task), "fast");
+
+        let task = slow_request.await.unwrap().unwrap();
+        assert_eq!(routed_to(&task), "slow");
+        assert_eq!(router.in_flight("slow"), 0);
+        assert_eq!(router.in_flight("fast"), 0);
+    }
+
     #[tokio::test]
     async fn test_router_fallback() {
         let fallback = MockAgent::new("fallback", "Fallback response");
@@ -0,0 +1,366 @@
+//! Connection pooling for protocol adapters.
+//!
+//! Connecting an adapter spawns a process (MCP) or performs discovery
+//! requests (A2A), so repeatedly connecting to the same remote is wasteful.
+//! A [`ConnectionPool`] keeps established connections keyed by target and
+//! hands out shared references to them.
+//!
+//! Pooled connections are health-checked before reuse once they have gone
+//! unchecked for longer than [`PoolConfig::health_check_interval`]. Broken
+//! connections are dropped and transparently re-established, and connections
+//! idle for longer than [`PoolConfig::idle_timeout`] are evicted.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use skreaver_agent::{A2aAgentAdapter, ConnectionPool, PoolConfig};
+//!
+//! let pool = ConnectionPool::new(PoolConfig::default());
+//! let first = A2aAgentAdapter::connect_pooled(&pool, "https://agent.example.com").await?;
+//! let second = A2aAgentAdapter::connect_pooled(&pool, "https://agent.example.com").await?;
+//! assert!(std::sync::Arc::ptr_eq(&first, &second));
+//! ```
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use async_trait::async_trait;
+use tokio::sync::Mutex;
+use tracing::{debug, info};
+
+use crate::error::AgentResult;
+use crate::retry::ConnectOptions;
+
+/// A connection that can be kept in a [`ConnectionPool`].
+#[async_trait]
+pub trait PooledConnection: Send + Sync + 'static {
+    /// Check whether the connection is still usable.
+    async fn is_healthy(&self) -> bool;
+}
+
+/// Configuration for a [`ConnectionPool`].
+#[derive(Debug, Clone)]
+pub struct PoolConfig {
+    /// Evict connections that have not been used for this long
+    pub idle_timeout: Duration,
+    /// Re-check health before reuse when the last check is older than this
+    pub health_check_interval: Duration,
+    /// Options used when the pool establishes a new connection
+    pub connect_options: ConnectOptions,
+}
+
+impl Default for PoolConfig {
+    fn default() -> Self {
+        Self {
+            idle_timeout: Duration::from_secs(300),
+            health_check_interval: Duration::from_secs(30),
+            connect_options: ConnectOptions::default(),
+        }
+    }
+}
+
+impl PoolConfig {
+    /// Create a pool configuration with default settings.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the idle timeout after which unused connections are evicted.
+    pub fn with_idle_timeout(mut self, timeout: Duration) -> Self {
+        self.idle_timeout = timeout;
+        self
+    }
+
+    /// Set how often pooled connections are health-checked before reuse.
+    pub fn with_health_check_interval(mut self, interval: Duration) -> Self {
+        self.health_check_interval = interval;
+        self
+    }
+
+    /// Set the options used for new connections.
+    pub fn with_connect_options(mut self, options: ConnectOptions) -> Self {
+        self.connect_options = options;
+        self
+    }
+}
+
+struct PoolEntry<C> {
+    connection: Arc<C>,
+    last_used: Instant,
+    last_health_check: Instant,
+}
+
+/// Pool of shared adapter connections keyed by target.
+pub struct ConnectionPool<C> {
+    config: PoolConfig,
+    entries: Mutex<HashMap<String, PoolEntry<C>>>,
+}
+
+impl<C> std::fmt::Debug for ConnectionPool<C> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ConnectionPool")
+            .field("config", &self.config)
+            .finish()
+    }
+}
+
+impl<C: PooledConnection> Default for ConnectionPool<C> {
+    fn default() -> Self {
+        Self::new(PoolConfig::default())
+    }
+}
+
+impl<C: PooledConnection> ConnectionPool<C> {
+    /// Create an empty pool.
+    pub fn new(config: PoolConfig) -> Self {
+        Self {
+            config,
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Get the pool configuration.
+    pub fn config(&self) -> &PoolConfig {
+        &self.config
+    }
+
+    /// Return the pooled connection for `target`, connecting if needed.
+    ///
+    /// An existing connection is reused unless it has been idle past the
+    /// idle timeout or fails its health check, in which case `connect` is
+    /// called to establish a replacement.
+    pub async fn get_or_connect<F, Fut>(&self, target: &str, connect: F) -> AgentResult<Arc<C>>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = AgentResult<C>>,
+    {
+        if let Some(connection) = self.checkout(target).await {
+            return Ok(connection);
+        }
+
+        info!(target = %target, "Establishing pooled connection");
+        let connection = Arc::new(connect().await?);
+
+        let mut entries = self.entries.lock().await;
+        // Another caller may have connected while we were connecting
+        if let Some(entry) = entries.get_mut(target) {
+            entry.last_used = Instant::now();
+            return Ok(Arc::clone(&entry.connection));
+        }
+
+        let now = Instant::now();
+        entries.insert(
+            target.to_string(),
+            PoolEntry {
+                connection: Arc::clone(&connection),
+                last_used: now,
+                last_health_check: now,
+            },
+        );
+        Ok(connection)
+    }
+
+    /// Take a reusable connection out of the pool, dropping broken ones.
+    async fn checkout(&self, target: &str) -> Option<Arc<C>> {
+        let (connection, needs_check) = {
+            let mut entries = self.entries.lock().await;
+            let entry = entries.get(target)?;
+
+            if entry.last_used.elapsed() >= self.config.idle_timeout {
+                debug!(target = %target, "Pooled connection idle, reconnecting");
+                entries.remove(target);
+                return None;
+            }
+
+            (
+                Arc::clone(&entry.connection),
+                entry.last_health_check.elapsed() >= self.config.health_check_interval,
+            )
+        };
+
+        // Health checks may hit the network, so run them without the lock
+        let healthy = !needs_check || connection.is_healthy().await;
+
+        let mut entries = self.entries.lock().await;
+        match entries.get_mut(target) {
+            Some(entry) if Arc::ptr_eq(&entry.connection, &connection) => {
+                if healthy {
+                    let now = Instant::now();
+                    entry.last_used = now;
+                    if needs_check {
+                        entry.last_health_check = now;
+                    }
+                    Some(connection)
+                } else {
+                    debug!(target = %target, "Pooled connection unhealthy, reconnecting");
+                    entries.remove(target);
+                    None
+                }
+            }
+            // Replaced concurrently; reuse the newer connection
+            Some(entry) => {
+                entry.last_used = Instant::now();
+                Some(Arc::clone(&entry.connection))
+            }
+            None => None,
+        }
+    }
+
+    /// Remove the connection for `target`, returning whether one was pooled.
+    pub async fn remove(&self, target: &str) -> bool {
+        self.entries.lock().await.remove(target).is_some()
+    }
+
+    /// Evict connections idle past the idle timeout, returning how many were removed.
+    pub async fn evict_idle(&self) -> usize {
+        let mut entries = self.entries.lock().await;
+        let before = entries.len();
+        entries.retain(|_, entry| entry.last_used.elapsed() < self.config.idle_timeout);
+        let evicted = before - entries.len();
+        if evicted > 0 {
+            debug!(evicted, "Evicted idle pooled connections");
+        }
+        evicted
+    }
+
+    /// Number of pooled connections.
+    pub async fn len(&self) -> usize {
+        self.entries.lock().await.len()
+    }
+
+    /// Check if the pool holds no connections.
+    pub async fn is_empty(&self) -> bool {
+        self.entries.lock().await.is_empty()
+    }
+
+    /// Start a background task that periodically evicts idle connections.
+    pub fn start_eviction_task(self: &Arc<Self>, interval: Duration) -> PoolEvictionHandle {
+        let pool = Arc::clone(self);
+        let handle = tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            loop {
+                ticker.tick().await;
+                pool.evict_idle().await;
+            }
+        });
+
+        PoolEvictionHandle { handle }
+    }
+}
+
+/// Handle for a pool's idle-eviction task.
+pub struct PoolEvictionHandle {
+    handle: tokio::task::JoinHandle<()>,
+}
+
+impl PoolEvictionHandle {
+    /// Stop the eviction task.
+    pub fn stop(self) {
+        self.handle.abort();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+    struct FakeConnection {
+        id: usize,
+        healthy: Arc<AtomicBool>,
+    }
+
+    #[async_trait]
+    impl PooledConnection for FakeConnection {
+        async fn is_healthy(&self) -> bool {
+            self.healthy.load(Ordering::SeqCst)
+        }
+    }
+
+    struct Connector {
+        connects: AtomicUsize,
+        healthy: Arc<AtomicBool>,
+    }
+
+    impl Connector {
+        fn new() -> Self {
+            Self {
+                connects: AtomicUsize::new(0),
+                healthy: Arc::new(AtomicBool::new(true)),
+            }
+        }
+
+        async fn connect(&self) -> AgentResult<FakeConnection> {
+            let id = self.connects.fetch_add(1, Ordering::SeqCst);
+            self.healthy.store(true, Ordering::SeqCst);
+            Ok(FakeConnection {
+                id,
+                healthy: Arc::clone(&self.healthy),
+            })
+        }
+    }
+
+    fn always_checked() -> PoolConfig {
+        PoolConfig::new().with_health_check_interval(Duration::ZERO)
+    }
+
+    #[tokio::test]
+    async fn test_pooled_connects_reuse_connection() {
+        let pool = ConnectionPool::new(always_checked());
+        let connector = Connector::new();
+
+        let first = pool
+            .get_or_connect("agent-a", || connector.connect())
+            .await
+            .unwrap();
+        let second = pool
+            .get_or_connect("agent-a", || connector.connect())
+            .await
+            .unwrap();
+
+        assert!(Arc::ptr_eq(&first, &second));
+        assert_eq!(connector.connects.load(Ordering::SeqCst), 1);
+        assert_eq!(pool.len().await, 1);
+    }
+
+    #[tokio::test]
+    async fn test_broken_connection_is_recreated() {
+        let pool = ConnectionPool::new(always_checked());
+        let connector = Connector::new();
+
+        let first = pool
+            .get_or_connect("agent-a", || connector.connect())
+            .await
+            .unwrap();
+        connector.healthy.store(false, Ordering::SeqCst);
+
+        let second = pool
+            .get_or_connect("agent-a", || connector.connect())
+            .await
+            .unwrap();
+
+        assert!(!Arc::ptr_eq(&first, &second));
+        assert_eq!(second.id, 1);
+        assert_eq!(connector.connects.load(Ordering::SeqCst), 2);
+        assert_eq!(pool.len().await, 1);
+    }
+
+    #[tokio::test]
+    async fn test_distinct_targets_and_idle_eviction() {
+        let pool = ConnectionPool::new(always_checked().with_idle_timeout(Duration::ZERO));
+        let connector = Connector::new();
+
+        pool.get_or_connect("agent-a", || connector.connect())
+            .await
+            .unwrap();
+        pool.get_or_connect("agent-b", || connector.connect())
+            .await
+            .unwrap();
+        assert_eq!(connector.connects.load(Ordering::SeqCst), 2);
+
+        assert_eq!(pool.evict_idle().await, 2);
+        assert!(pool.is_empty().await);
+    }
+}
@@ -127,6 +127,15 @@
 //! - `mcp`: Enables MCP-related bridges (`McpToA2aBridge`)
 //! - `a2a`: Enables A2A-related bridges (`A2aToMcpBridge`)
 //! - Both: Enables `ProtocolGateway` for full bidirectional bridging
+//! - `otel`: Continues the caller's OpenTelemetry trace across bridges
+//!
+//! # Trace Propagation
+//!
+//! Each forwarded message runs in a `protocol_bridge` span. With the `otel`
+//! feature, that span is parented on the W3C `traceparent` in the message
+//! metadata, and the outgoing message carries the span's own `traceparent`.
+//! A2A agents receive it in the message metadata; MCP agents send it as the
+//! tool call's `_meta`.
 //!
 //! # Error Handling
 //!
@@ -147,10 +156,10 @@ use std::collections::HashMap;
 use std::pin::Pin;
 #[cfg(any(feature = "mcp", feature = "a2a"))]
 use std::sync::Arc;
-#[cfg(any(feature = "mcp", feature = "a2a"))]
-use tracing::debug;
 #[cfg(all(feature = "mcp", feature = "a2a"))]
 use tracing::info;
+#[cfg(any(feature = "mcp", feature = "a2a"))]
+use tracing::{Instrument, debug};
 
 #[cfg(any(feature = "mcp", feature = "a2a"))]
 use crate::error::{AgentError, AgentResult};
@@ -163,6 +172,60 @@ use crate::types::{
     AgentInfo, Capability, ContentPart, Protocol, StreamEvent, UnifiedMessage, UnifiedTask,
 };
 
+// ============================================================================
+// Trace propagation
+// ============================================================================
+
+/// Span for a message crossing a bridge, continuing the caller's trace.
+///
+/// With the `otel` feature, the span is parented on the incoming
+/// `traceparent`, which is then replaced by the span's own context so the
+/// downstream agent records its work as a child of this hop.
+#[cfg(any(feature = "mcp", feature = "a2a"))]
+fn bridge_span(
+    bridge_id: &str,
+    from: Protocol,
+    metadata: &mut HashMap<String, serde_json::Value>,
+) -> tracing::Span {
+    let span = tracing::info_span!("protocol_bridge", bridge = %bridge_id, from = %from);
+
+    #[cfg(feature = "otel")]
+    {
+        use skreaver_observability::TraceContext;
+
+        let mut carrier = MetadataCarrier(metadata);
+        TraceContext::extract(&span, &carrier);
+        TraceContext::inject(&span, &mut carrier);
+    }
+    #[cfg(not(feature = "otel"))]
+    let _ = metadata;
+
+    span
+}
+
+/// Message metadata as a trace context carrier.
+#[cfg(all(feature = "otel", any(feature = "mcp", feature = "a2a")))]
+struct MetadataCarrier<'a>(&'a mut HashMap<String, serde_json::Value>);
+
+#[cfg(all(feature = "otel", any(feature = "mcp", feature = "a2a")))]
+impl skreaver_observability::otel::Injector for MetadataCarrier<'_> {
+    fn set(&mut self, key: &str, value: String) {
+        self.0
+            .insert(key.to_string(), serde_json::Value::String(value));
+    }
+}
+
+#[cfg(all(feature = "otel", any(feature = "mcp", feature = "a2a")))]
+impl skreaver_observability::otel::Extractor for MetadataCarrier<'_> {
+    fn get(&self, key: &str) -> Option<&str> {
+        self.0.get(key).and_then(serde_json::Value::as_str)
+    }
+
+    fn keys(&self) -> Vec<&str> {
+        self.0.keys().map(String::as_str).collect()
+    }
+}
+
 // ============================================================================
 // McpToA2aBridge - Expose MCP tools as A2A agent
 // ============================================================================
@@ -308,14 +371,19 @@ impl UnifiedAgent for McpToA2aBridge {
         &self.info
     }
 
-    async fn send_message(&self, message: UnifiedMessage) -> AgentResult<UnifiedTask> {
+    async fn send_message(&self, mut message: UnifiedMessage) -> AgentResult<UnifiedTask> {
         debug!(
             bridge = %self.info.id,
             "Forwarding message from A2A to MCP"
         );
 
         // Forward to MCP agent
-        let mut result = self.mcp_agent.send_message(message).await?;
+        let span = bridge_span(&self.info.id, Protocol::Mcp, &mut message.metadata);
+        let mut result = self
+            .mcp_agent
+            .send_message(message)
+            .instrument(span)
+            .await?;
 
         // Add bridge metadata
         result
@@ -334,16 +402,24 @@ impl UnifiedAgent for McpToA2aBridge {
     async fn send_message_to_task(
         &self,
         task_id: &str,
-        message: UnifiedMessage,
+        mut message: UnifiedMessage,
     ) -> AgentResult<UnifiedTask> {
-        self.mcp_agent.send_message_to_task(task_id, message).await
+        let span = bridge_span(&self.info.id, Protocol::Mcp, &mut message.metadata);
+        self.mcp_agent
+            .send_message_to_task(task_id, message)
+            .instrument(span)
+            .await
     }
 
     async fn send_message_streaming(
         &self,
-        message: UnifiedMessage,
+        mut message: UnifiedMessage,
     ) -> AgentResult<Pin<Box<dyn Stream<Item = AgentResult<StreamEvent>> + Send>>> {
-        self.mcp_agent.send_message_streaming(message).await
+        let span = bridge_span(&self.info.id, Protocol::Mcp, &mut message.metadata);
+        self.mcp_agent
+            .send_message_streaming(message)
+            .instrument(span)
+            .await
     }
 
     async fn get_task(&self, task_id: &str) -> AgentResult<UnifiedTask> {
@@ -517,14 +593,19 @@ impl UnifiedAgent for A2aToMcpBridge {
         &self.info
     }
 
-    async fn send_message(&self, message: UnifiedMessage) -> AgentResult<UnifiedTask> {
+    async fn send_message(&self, mut message: UnifiedMessage) -> AgentResult<UnifiedTask> {
         debug!(
             bridge = %self.info.id,
             "Forwarding message from MCP to A2A"
         );
 
         // Forward to A2A agent
-        let mut result = self.a2a_agent.send_message(message).await?;
+        let span = bridge_span(&self.info.id, Protocol::A2a, &mut message.metadata);
+        let mut result = self
+            .a2a_agent
+            .send_message(message)
+            .instrument(span)
+            .await?;
 
         // Add bridge metadata
         result
@@ -543,16 +624,24 @@ impl UnifiedAgent for A2aToMcpBridge {
     async fn send_message_to_task(
         &self,
         task_id: &str,
-        message: UnifiedMessage,
+        mut message: UnifiedMessage,
     ) -> AgentResult<UnifiedTask> {
-        self.a2a_agent.send_message_to_task(task_id, message).await
+        let span = bridge_span(&self.info.id, Protocol::A2a, &mut message.metadata);
+        self.a2a_agent
+            .send_message_to_task(task_id, message)
+            .instrument(span)
+            .await
     }
 
     async fn send_message_streaming(
         &self,
-        message: UnifiedMessage,
+        mut message: UnifiedMessage,
     ) -> AgentResult<Pin<Box<dyn Stream<Item = AgentResult<StreamEvent>> + Send>>> {
-        self.a2a_agent.send_message_streaming(message).await
+        let span = bridge_span(&self.info.id, Protocol::A2a, &mut message.metadata);
+        self.a2a_agent
+            .send_message_streaming(message)
+            .instrument(span)
+            .await
     }
 
     async fn get_task(&self, task_id: &str) -> AgentResult<UnifiedTask> {
@@ -746,6 +835,17 @@ impl ProtocolGateway {
         results
     }
 
+    /// Find agents whose capabilities satisfy a compound capability query.
+    pub fn find_by_capability_query(
+        &self,
+        query: &crate::discovery::CapabilityQuery,
+    ) -> Vec<Arc<dyn UnifiedAgent>> {
+        self.all_agents()
+            .into_iter()
+            .filter(|agent| query.matches(agent.capabilities()))
+            .collect()
+    }
+
     /// Get the MCP to A2A bridges.
     pub fn mcp_bridges(&self) -> &[Arc<McpToA2aBridge>] {
         &self.mcp_to_a2a_bridges
@@ -953,4 +1053,69 @@ mod tests {
         };
         assert_eq!(mapping.skill_id, "web_search");
     }
+
+    #[cfg(all(feature = "otel", feature = "a2a"))]
+    #[tokio::test]
+    async fn test_a2a_bridge_continues_incoming_trace() {
+        use crate::types::TaskStatus;
+        use opentelemetry::trace::TracerProvider;
+        use tracing::instrument::WithSubscriber;
+        use tracing_subscriber::layer::SubscriberExt;
+
+        /// Echoes each message back in a completed task
+        struct EchoAgent {
+            info: AgentInfo,
+        }
+
+        #[async_trait]
+        impl UnifiedAgent for EchoAgent {
+            fn info(&self) -> &AgentInfo {
+                &self.info
+            }
+
+            async fn send_message(&self, message: UnifiedMessage) -> AgentResult<UnifiedTask> {
+                let mut task = UnifiedTask::new("task-1");
+                task.add_message(message);
+                task.set_status(TaskStatus::Completed);
+                Ok(task)
+            }
+
+            async fn send_message_to_task(
+                &self,
+                _task_id: &str,
+                message: UnifiedMessage,
+            ) -> AgentResult<UnifiedTask> {
+                self.send_message(message).await
+            }
+
+            async fn get_task(&self, task_id: &str) -> AgentResult<UnifiedTask> {
+                Err(AgentError::TaskNotFound(task_id.to_string()))
+            }
+
+            async fn cancel_task(&self, task_id: &str) -> AgentResult<UnifiedTask> {
+                Err(AgentError::TaskNotFound(task_id.to_string()))
+            }
+        }
+
+        let provider = opentelemetry_sdk::trace::SdkTracerProvider::builder().build();
+        let subscriber = tracing_subscriber::registry()
+            .with(tracing_opentelemetry::layer().with_tracer(provider.tracer("test")));
+
+        let bridge = A2aToMcpBridge::new(Arc::new(EchoAgent {
+            info: AgentInfo::new("echo", "Echo"),
+        }));
+        let incoming = "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01";
+        let message =
+            UnifiedMessage::user("Hi").with_metadata("traceparent", serde_json::json!(incoming));
+
+        let task = bridge
+            .send_message(message)
+            .with_subscriber(subscriber)
+            .await
+            .unwrap();
+
+        let outgoing = task.messages[0].metadata["traceparent"].as_str().unwrap();
+        assert!(outgoing.starts_with("00-4bf92f3577b34da6a3ce929d0e0e4736-"));
+        assert_ne!(outgoing, incoming);
+    }
 }
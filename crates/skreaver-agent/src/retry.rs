@@ -0,0 +1,430 @@
+//! Retry budgets and request hedging for remote agent calls.
+//!
+//! Protocol adapters use a [`RetryPolicy`] to recover from transient
+//! network failures on idempotent operations. Retries draw from a shared
+//! token-bucket [`RetryBudget`] so that a failing dependency cannot trigger
+//! a retry storm: once the budget is exhausted, errors surface immediately
+//! until tokens refill.
+//!
+//! Hedging optionally fires a second attempt when the first has not
+//! responded within a delay, and takes whichever succeeds first. Hedged
+//! attempts also consume the budget.
+//!
+//! Non-idempotent operations are never retried or hedged.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use skreaver_agent::{A2aAgentAdapter, ConnectOptions, RetryConfig};
+//! use std::time::Duration;
+//!
+//! let options = ConnectOptions::new().with_retry(
+//!     RetryConfig::new()
+//!         .with_max_attempts(4)
+//!         .with_hedge_delay(Duration::from_millis(200)),
+//! );
+//! let agent = A2aAgentAdapter::connect_with_options("https://agent.example.com", options).await?;
+//! ```
+
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+use tracing::{debug, warn};
+
+use crate::error::AgentResult;
+
+// ============================================================================
+// Configuration
+// ============================================================================
+
+/// Retry and hedging configuration for idempotent remote calls.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RetryConfig {
+    /// Total attempts per call, including the first one
+    pub max_attempts: u32,
+    /// Delay before the first retry
+    pub initial_backoff: Duration,
+    /// Upper bound for the exponential backoff delay
+    pub max_backoff: Duration,
+    /// Maximum number of retry tokens held by the budget
+    pub budget_capacity: u32,
+    /// Retry tokens added back to the budget per second
+    pub budget_refill_per_sec: f64,
+    /// Fire a hedged attempt if the first has not completed after this delay
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hedge_delay: Option<Duration>,
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_secs(2),
+            budget_capacity: 10,
+            budget_refill_per_sec: 1.0,
+            hedge_delay: None,
+        }
+    }
+}
+
+impl RetryConfig {
+    /// Create a configuration with default retry settings.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a configuration that never retries or hedges.
+    pub fn disabled() -> Self {
+        Self {
+            max_attempts: 1,
+            budget_capacity: 0,
+            budget_refill_per_sec: 0.0,
+            ..Self::default()
+        }
+    }
+
+    /// Set the total number of attempts per call.
+    pub fn with_max_attempts(mut self, attempts: u32) -> Self {
+        self.max_attempts = attempts.max(1);
+        self
+    }
+
+    /// Set the initial and maximum backoff delays.
+    pub fn with_backoff(mut self, initial: Duration, max: Duration) -> Self {
+        self.initial_backoff = initial;
+        self.max_backoff = max.max(initial);
+        self
+    }
+
+    /// Set the retry budget capacity and refill rate.
+    pub fn with_budget(mut self, capacity: u32, refill_per_sec: f64) -> Self {
+        self.budget_capacity = capacity;
+        self.budget_refill_per_sec = refill_per_sec.max(0.0);
+        self
+    }
+
+    /// Enable request hedging after the given delay.
+    pub fn with_hedge_delay(mut self, delay: Duration) -> Self {
+        self.hedge_delay = Some(delay);
+        self
+    }
+}
+
+/// Options applied when connecting a protocol adapter.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ConnectOptions {
+    /// Retry configuration for idempotent calls (no retries when `None`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retry: Option<RetryConfig>,
+}
+
+impl ConnectOptions {
+    /// Create options with no retries.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Enable retries for idempotent calls.
+    pub fn with_retry(mut self, config: RetryConfig) -> Self {
+        self.retry = Some(config);
+        self
+    }
+
+    /// Build the retry policy described by these options.
+    pub fn retry_policy(&self) -> RetryPolicy {
+        RetryPolicy::new(self.retry.clone().unwrap_or_else(RetryConfig::disabled))
+    }
+}
+
+// ============================================================================
+// Retry Budget
+// ============================================================================
+
+#[derive(Debug)]
+struct BudgetState {
+    tokens: f64,
+    last_refill: Instant,
+}
+
+/// Token bucket limiting how many retries and hedges may be issued.
+#[derive(Debug)]
+pub struct RetryBudget {
+    capacity: f64,
+    refill_per_sec: f64,
+    state: Mutex<BudgetState>,
+}
+
+impl RetryBudget {
+    /// Create a full budget.
+    pub fn new(capacity: u32, refill_per_sec: f64) -> Self {
+        Self {
+            capacity: f64::from(capacity),
+            refill_per_sec,
+            state: Mutex::new(BudgetState {
+                tokens: f64::from(capacity),
+                last_refill: Instant::now(),
+            }),
+        }
+    }
+
+    /// Take one token from the budget, returning `false` if none are left.
+    pub fn try_acquire(&self) -> bool {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        self.refill(&mut state);
+        if state.tokens >= 1.0 {
+            state.tokens -= 1.0;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Number of whole tokens currently available.
+    pub fn available(&self) -> u32 {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        self.refill(&mut state);
+        state.tokens as u32
+    }
+
+    fn refill(&self, state: &mut BudgetState) {
+        let now = Instant::now();
+        let elapsed = now.duration_since(state.last_refill).as_secs_f64();
+        state.tokens = (state.tokens + elapsed * self.refill_per_sec).min(self.capacity);
+        state.last_refill = now;
+    }
+}
+
+// ============================================================================
+// Retry Policy
+// ============================================================================
+
+/// Executes remote calls with retries and hedging drawn from a shared budget.
+///
+/// Cloning a policy shares its budget.
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    config: RetryConfig,
+    budget: Arc<RetryBudget>,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::new(RetryConfig::disabled())
+    }
+}
+
+impl RetryPolicy {
+    /// Create a policy with a fresh budget.
+    pub fn new(config: RetryConfig) -> Self {
+        let budget = Arc::new(RetryBudget::new(
+            config.budget_capacity,
+            config.budget_refill_per_sec,
+        ));
+        Self { config, budget }
+    }
+
+    /// Get the policy configuration.
+    pub fn config(&self) -> &RetryConfig {
+        &self.config
+    }
+
+    /// Get the shared retry budget.
+    pub fn budget(&self) -> &RetryBudget {
+        &self.budget
+    }
+
+    /// Run an operation, retrying and hedging only if it is idempotent.
+    ///
+    /// Only errors for which [`AgentError::is_retryable`](crate::AgentError::is_retryable)
+    /// returns `true` are retried. The last error is returned once attempts
+    /// or budget run out.
+    pub async fn execute<T, F, Fut>(&self, idempotent: bool, operation: F) -> AgentResult<T>
+    where
+        F: Fn() -> Fut,
+        Fut: Future<Output = AgentResult<T>>,
+    {
+        if !idempotent {
+            return operation().await;
+        }
+
+        let mut backoff = self.config.initial_backoff;
+        let mut attempt = 1;
+        loop {
+            let error = match self.attempt(&operation).await {
+                Ok(value) => return Ok(value),
+                Err(e) if !e.is_retryable() => return Err(e),
+                Err(e) => e,
+            };
+
+            if attempt >= self.config.max_attempts {
+                debug!(attempt, error = %error, "Retry attempts exhausted");
+                return Err(error);
+            }
+            if !self.budget.try_acquire() {
+                warn!(attempt, error = %error, "Retry budget exhausted");
+                return Err(error);
+            }
+
+            debug!(attempt, ?backoff, error = %error, "Retrying after transient failure");
+            tokio::time::sleep(backoff).await;
+            backoff = (backoff * 2).min(self.config.max_backoff);
+            attempt += 1;
+        }
+    }
+
+    /// Run a single attempt, hedging it if configured.
+    async fn attempt<T, F, Fut>(&self, operation: &F) -> AgentResult<T>
+    where
+        F: Fn() -> Fut,
+        Fut: Future<Output = AgentResult<T>>,
+    {
+        let Some(delay) = self.config.hedge_delay else {
+            return operation().await;
+        };
+
+        let primary = operation();
+        tokio::pin!(primary);
+        tokio::select! {
+            result = &mut primary => return result,
+            _ = tokio::time::sleep(delay) => {}
+        }
+
+        if !self.budget.try_acquire() {
+            return primary.await;
+        }
+
+        debug!(?delay, "Sending hedged request");
+        let hedge = operation();
+        tokio::pin!(hedge);
+        tokio::select! {
+            result = &mut primary => match result {
+                Ok(value) => Ok(value),
+                Err(_) => hedge.await,
+            },
+            result = &mut hedge => match result {
+                Ok(value) => Ok(value),
+                Err(_) => primary.await,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::AgentError;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    fn fast_config() -> RetryConfig {
+        RetryConfig::new()
+            .with_backoff(Duration::from_millis(1), Duration::from_millis(5))
+            .with_budget(10, 0.0)
+    }
+
+    #[tokio::test]
+    async fn test_transient_failure_retries_within_budget() {
+        let policy = RetryPolicy::new(fast_config().with_max_attempts(3));
+        let calls = AtomicU32::new(0);
+
+        let result = policy
+            .execute(true, || async {
+                if calls.fetch_add(1, Ordering::SeqCst) < 2 {
+                    Err(AgentError::ConnectionError("reset".to_string()))
+                } else {
+                    Ok("done")
+                }
+            })
+            .await;
+
+        assert_eq!(result.unwrap(), "done");
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+        assert_eq!(policy.budget().available(), 8);
+    }
+
+    #[tokio::test]
+    async fn test_exhausted_budget_surfaces_error() {
+        let policy = RetryPolicy::new(fast_config().with_max_attempts(5).with_budget(1, 0.0));
+        let calls = AtomicU32::new(0);
+
+        let result: AgentResult<()> = policy
+            .execute(true, || async {
+                calls.fetch_add(1, Ordering::SeqCst);
+                Err(AgentError::Timeout("slow".to_string()))
+            })
+            .await;
+
+        assert!(matches!(result, Err(AgentError::Timeout(_))));
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+        assert_eq!(policy.budget().available(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_non_idempotent_and_permanent_errors_are_not_retried() {
+        let policy = RetryPolicy::new(fast_config());
+        let calls = AtomicU32::new(0);
+
+        let result: AgentResult<()> = policy
+            .execute(false, || async {
+                calls.fetch_add(1, Ordering::SeqCst);
+                Err(AgentError::ConnectionError("reset".to_string()))
+            })
+            .await;
+        assert!(result.is_err());
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+        let result: AgentResult<()> = policy
+            .execute(true, || async {
+                calls.fetch_add(1, Ordering::SeqCst);
+                Err(AgentError::InvalidRequest("bad".to_string()))
+            })
+            .await;
+        assert!(matches!(result, Err(AgentError::InvalidRequest(_))));
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+        assert_eq!(policy.budget().available(), 10);
+    }
+
+    #[tokio::test]
+    async fn test_hedged_request_takes_first_response() {
+        let policy = RetryPolicy::new(
+            fast_config()
+                .with_max_attempts(1)
+                .with_hedge_delay(Duration::from_millis(10)),
+        );
+        let calls = AtomicU32::new(0);
+
+        let result = policy
+            .execute(true, || async {
+                if calls.fetch_add(1, Ordering::SeqCst) == 0 {
+                    tokio::time::sleep(Duration::from_secs(5)).await;
+                    Ok("primary")
+                } else {
+                    Ok("hedge")
+                }
+            })
+            .await;
+
+        assert_eq!(result.unwrap(), "hedge");
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+        assert_eq!(policy.budget().available(), 9);
+    }
+
+    #[test]
+    fn test_budget_refills_up_to_capacity() {
+        let budget = RetryBudget::new(2, 1000.0);
+        assert!(budget.try_acquire());
+        assert!(budget.try_acquire());
+        std::thread::sleep(Duration::from_millis(10));
+        assert_eq!(budget.available(), 2);
+    }
+
+    #[test]
+    fn test_disabled_config_never_retries() {
+        let options = ConnectOptions::new();
+        let policy = options.retry_policy();
+        assert_eq!(policy.config().max_attempts, 1);
+        assert_eq!(policy.budget().available(), 0);
+    }
+}
@@ -0,0 +1,350 @@
+//! Routing decision cache for [`RouterAgent`](crate::RouterAgent).
+//!
+//! Evaluating routing rules can be costly, for example when capability-based
+//! rules run over a large discovery set. A [`RoutingCache`] remembers which
+//! rule a routing input resolved to, so repeated inputs with the same routing
+//! key skip rule evaluation entirely.
+//!
+//! Cached decisions expire after [`RoutingCacheConfig::ttl`] and are
+//! invalidated when discovery reports a change: entries routed to an agent
+//! that is deregistered or changes health are dropped, and a newly registered
+//! agent clears the cache since it may now win any route.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use skreaver_agent::{InMemoryDiscoveryProvider, RouterAgent, RoutingCacheConfig};
+//!
+//! let provider = InMemoryDiscoveryProvider::new();
+//! let router = RouterAgent::new("router", "Router")
+//!     .with_routing_cache(RoutingCacheConfig::default())
+//!     .add_rule(rule);
+//!
+//! // Keep the cache consistent with discovery
+//! let _watcher = router
+//!     .routing_cache()
+//!     .unwrap()
+//!     .watch_discovery(provider.subscribe());
+//! ```
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+
+use tokio::sync::broadcast;
+use tracing::debug;
+
+use crate::discovery::DiscoveryEvent;
+use crate::types::UnifiedMessage;
+
+/// Function deriving the cache key from a routing input.
+pub type RoutingKeyFn = dyn Fn(&UnifiedMessage) -> String + Send + Sync;
+
+/// Configuration for a [`RoutingCache`].
+#[derive(Debug, Clone)]
+pub struct RoutingCacheConfig {
+    /// Maximum number of cached decisions; the oldest is evicted when full
+    pub max_entries: usize,
+    /// How long a cached decision stays valid
+    pub ttl: Duration,
+}
+
+impl Default for RoutingCacheConfig {
+    fn default() -> Self {
+        Self {
+            max_entries: 1024,
+            ttl: Duration::from_secs(60),
+        }
+    }
+}
+
+impl RoutingCacheConfig {
+    /// Create a cache configuration with default settings.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the maximum number of cached decisions.
+    pub fn with_max_entries(mut self, max_entries: usize) -> Self {
+        self.max_entries = max_entries;
+        self
+    }
+
+    /// Set how long a cached decision stays valid.
+    pub fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = ttl;
+        self
+    }
+}
+
+/// A cached routing decision.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RoutingDecision {
+    /// Index of the matching rule, or `None` for the fallback agent
+    pub rule_index: Option<usize>,
+    /// ID of the agent the input was routed to
+    pub target_agent_id: String,
+}
+
+/// Snapshot of routing cache counters.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RoutingCacheStats {
+    /// Lookups answered from the cache
+    pub hits: u64,
+    /// Lookups that required rule evaluation
+    pub misses: u64,
+    /// Entries dropped because of discovery changes
+    pub invalidations: u64,
+    /// Decisions currently cached
+    pub entries: usize,
+}
+
+struct CacheEntry {
+    decision: RoutingDecision,
+    inserted_at: Instant,
+}
+
+#[derive(Default)]
+struct CacheState {
+    entries: HashMap<String, CacheEntry>,
+    /// Keys in insertion order, used for eviction
+    order: VecDeque<String>,
+}
+
+/// Cache of routing decisions keyed by routing input features.
+pub struct RoutingCache {
+    config: RoutingCacheConfig,
+    key_fn: Box<RoutingKeyFn>,
+    state: Mutex<CacheState>,
+    hits: AtomicU64,
+    misses: AtomicU64,
+    invalidations: AtomicU64,
+}
+
+impl std::fmt::Debug for RoutingCache {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RoutingCache")
+            .field("config", &self.config)
+            .field("stats", &self.stats())
+            .finish()
+    }
+}
+
+impl RoutingCache {
+    /// Create a cache keyed by the normalized text content of the input.
+    pub fn new(config: RoutingCacheConfig) -> Self {
+        Self::with_key_fn(config, default_routing_key)
+    }
+
+    /// Create a cache using a custom routing key.
+    ///
+    /// Inputs mapping to the same key must always route to the same rule, so
+    /// the key has to cover every feature the routing rules inspect.
+    pub fn with_key_fn(
+        config: RoutingCacheConfig,
+        key_fn: impl Fn(&UnifiedMessage) -> String + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            config,
+            key_fn: Box::new(key_fn),
+            state: Mutex::new(CacheState::default()),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+            invalidations: AtomicU64::new(0),
+        }
+    }
+
+    /// Get the cache configuration.
+    pub fn config(&self) -> &RoutingCacheConfig {
+        &self.config
+    }
+
+    /// Compute the routing key for a message.
+    pub fn key_for(&self, message: &UnifiedMessage) -> String {
+        (self.key_fn)(message)
+    }
+
+    /// Look up the cached decision for a routing key.
+    pub fn get(&self, key: &str) -> Option<RoutingDecision> {
+        let mut state = self.lock_state();
+        let decision = match state.entries.get(key) {
+            Some(entry) if entry.inserted_at.elapsed() < self.config.ttl => {
+                Some(entry.decision.clone())
+            }
+            Some(_) => {
+                state.entries.remove(key);
+                state.order.retain(|k| k != key);
+                None
+            }
+            None => None,
+        };
+
+        let counter = if decision.is_some() {
+            &self.hits
+        } else {
+            &self.misses
+        };
+        counter.fetch_add(1, Ordering::Relaxed);
+        decision
+    }
+
+    /// Cache the decision for a routing key.
+    pub fn insert(&self, key: String, decision: RoutingDecision) {
+        if self.config.max_entries == 0 {
+            return;
+        }
+
+        let mut state = self.lock_state();
+        if state.entries.contains_key(&key) {
+            state.order.retain(|k| k != &key);
+        }
+        while state.entries.len() >= self.config.max_entries {
+            let Some(oldest) = state.order.pop_front() else {
+                break;
+            };
+            state.entries.remove(&oldest);
+        }
+
+        state.order.push_back(key.clone());
+        state.entries.insert(
+            key,
+            CacheEntry {
+                decision,
+                inserted_at: Instant::now(),
+            },
+        );
+    }
+
+    /// Drop every decision routed to `agent_id`, returning how many were removed.
+    pub fn invalidate_agent(&self, agent_id: &str) -> usize {
+        let mut state = self.lock_state();
+        let before = state.entries.len();
+        state
+            .entries
+            .retain(|_, entry| entry.decision.target_agent_id != agent_id);
+        let removed = before - state.entries.len();
+
+        if removed > 0 {
+            let CacheState { entries, order } = &mut *state;
+            order.retain(|k| entries.contains_key(k));
+            self.invalidations
+                .fetch_add(removed as u64, Ordering::Relaxed);
+            debug!(agent_id = %agent_id, removed, "Invalidated routing decisions");
+        }
+        removed
+    }
+
+    /// Drop every cached decision.
+    pub fn clear(&self) {
+        let mut state = self.lock_state();
+        let removed = state.entries.len();
+        state.entries.clear();
+        state.order.clear();
+        self.invalidations
+            .fetch_add(removed as u64, Ordering::Relaxed);
+    }
+
+    /// Apply a discovery event to the cache.
+    pub fn handle_discovery_event(&self, event: &DiscoveryEvent) {
+        match event {
+            // A new agent may now win routes that previously went elsewhere
+            DiscoveryEvent::AgentRegistered { .. } => self.clear(),
+            DiscoveryEvent::AgentDeregistered { agent_id, .. }
+            | DiscoveryEvent::HealthStatusChanged { agent_id, .. } => {
+                self.invalidate_agent(agent_id);
+            }
+            DiscoveryEvent::Heartbeat { .. } => {}
+        }
+    }
+
+    /// Invalidate entries as discovery events arrive on `events`.
+    ///
+    /// If the receiver lags behind, the whole cache is cleared since the
+    /// missed events cannot be recovered.
+    pub fn watch_discovery(
+        self: &Arc<Self>,
+        mut events: broadcast::Receiver<DiscoveryEvent>,
+    ) -> tokio::task::JoinHandle<()> {
+        let cache = Arc::clone(self);
+        tokio::spawn(async move {
+            loop {
+                match events.recv().await {
+                    Ok(event) => cache.handle_discovery_event(&event),
+                    Err(broadcast::error::RecvError::Lagged(missed)) => {
+                        debug!(missed, "Routing cache lagged behind discovery events");
+                        cache.clear();
+                    }
+                    Err(broadcast::error::RecvError::Closed) => break,
+                }
+            }
+        })
+    }
+
+    /// Current cache counters.
+    pub fn stats(&self) -> RoutingCacheStats {
+        RoutingCacheStats {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+            invalidations: self.invalidations.load(Ordering::Relaxed),
+            entries: self.lock_state().entries.len(),
+        }
+    }
+
+    fn lock_state(&self) -> MutexGuard<'_, CacheState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// Default routing key: role plus whitespace-normalized, lowercased text.
+fn default_routing_key(message: &UnifiedMessage) -> String {
+    let text = message
+        .text_content()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase();
+    format!("{:?}:{}", message.role, text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn decision(rule_index: usize, agent: &str) -> RoutingDecision {
+        RoutingDecision {
+            rule_index: Some(rule_index),
+            target_agent_id: agent.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_default_key_normalizes_text() {
+        let cache = RoutingCache::new(RoutingCacheConfig::default());
+        assert_eq!(
+            cache.key_for(&UnifiedMessage::user("What's  the WEATHER")),
+            cache.key_for(&UnifiedMessage::user("what's the weather"))
+        );
+        assert_ne!(
+            cache.key_for(&UnifiedMessage::user("weather")),
+            cache.key_for(&UnifiedMessage::agent("weather"))
+        );
+    }
+
+    #[test]
+    fn test_eviction_and_ttl() {
+        let cache = RoutingCache::new(RoutingCacheConfig::new().with_max_entries(2));
+        cache.insert("a".into(), decision(0, "x"));
+        cache.insert("b".into(), decision(1, "y"));
+        cache.insert("c".into(), decision(2, "z"));
+
+        assert!(cache.get("a").is_none());
+        assert!(cache.get("b").is_some());
+        assert!(cache.get("c").is_some());
+
+        let expiring = RoutingCache::new(RoutingCacheConfig::new().with_ttl(Duration::ZERO));
+        expiring.insert("a".into(), decision(0, "x"));
+        assert!(expiring.get("a").is_none());
+        assert_eq!(expiring.stats().entries, 0);
+    }
+}
@@ -9,6 +9,7 @@
 //! - **InMemoryTaskStore**: Fast in-memory storage for testing/development
 //! - **FileTaskStore**: JSON file-based storage for simple persistence
 //! - **Query support**: Filter tasks by status, time range, session
+//! - **Cursor pagination**: Page through large stores with [`TaskCursor`]
 //!
 //! # Example: Basic Usage
 //!
@@ -45,7 +46,7 @@
 //! ```
 
 use async_trait::async_trait;
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, SecondsFormat, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::path::PathBuf;
@@ -99,6 +100,9 @@ pub struct TaskQuery {
     /// Offset for pagination
     #[serde(default)]
     pub offset: usize,
+    /// Resume after this position (used by [`TaskStore::query_page`])
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after: Option<TaskCursor>,
     /// Sort order (true = newest first)
     #[serde(default = "TaskQuery::default_newest_first")]
     pub newest_first: bool,
@@ -183,12 +187,27 @@ impl TaskQuery {
         self
     }
 
+    /// Resume a paginated query after `cursor`.
+    pub fn after(mut self, cursor: TaskCursor) -> Self {
+        self.after = Some(cursor);
+        self
+    }
+
     /// Sort oldest first.
     pub fn oldest_first(mut self) -> Self {
         self.newest_first = false;
         self
     }
 
+    /// The same filters without `limit` and `offset`.
+    fn unbounded(&self) -> Self {
+        Self {
+            limit: None,
+            offset: 0,
+            ..self.clone()
+        }
+    }
+
     /// Check if a task matches this query.
     pub fn matches(&self, task: &UnifiedTask) -> bool {
         // Check single status
@@ -263,6 +282,114 @@ impl TaskQuery {
     }
 }
 
+// ============================================================================
+// Task Cursor
+// ============================================================================
+
+/// Position in a paginated task query: the last task of the previous page.
+///
+/// Pages are ordered by creation time, then task ID, so a cursor stays valid
+/// while tasks are added concurrently: new tasks never shift earlier pages,
+/// and no task is returned twice.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct TaskCursor {
+    /// Creation time of the last task returned
+    pub created_at: DateTime<Utc>,
+    /// ID of the last task returned
+    pub task_id: String,
+}
+
+impl TaskCursor {
+    /// Cursor positioned at `task`.
+    pub fn at(task: &UnifiedTask) -> Self {
+        Self {
+            created_at: sort_time(task),
+            task_id: task.id.clone(),
+        }
+    }
+
+    /// Encode the cursor as an opaque string for APIs and UIs.
+    pub fn encode(&self) -> String {
+        format!(
+            "{}|{}",
+            self.created_at.to_rfc3339_opts(SecondsFormat::Nanos, true),
+            self.task_id
+        )
+    }
+
+    /// Decode a cursor produced by [`TaskCursor::encode`].
+    pub fn decode(encoded: &str) -> AgentResult<Self> {
+        let invalid = || AgentError::InvalidRequest(format!("Invalid task cursor: {}", encoded));
+        let (created_at, task_id) = encoded.split_once('|').ok_or_else(invalid)?;
+        let created_at = DateTime::parse_from_rfc3339(created_at)
+            .map_err(|_| invalid())?
+            .with_timezone(&Utc);
+        Ok(Self {
+            created_at,
+            task_id: task_id.to_string(),
+        })
+    }
+
+    /// Sort key this cursor points at.
+    fn key(&self) -> (DateTime<Utc>, &str) {
+        (self.created_at, &self.task_id)
+    }
+}
+
+impl std::fmt::Display for TaskCursor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.encode())
+    }
+}
+
+/// A page of tasks from [`TaskStore::query_page`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PaginatedTasks {
+    /// Tasks in query order
+    pub tasks: Vec<UnifiedTask>,
+    /// Cursor for the next page, or `None` on the last page
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_cursor: Option<TaskCursor>,
+}
+
+/// Creation time used for ordering; tasks without one sort first.
+fn sort_time(task: &UnifiedTask) -> DateTime<Utc> {
+    task.created_at.unwrap_or(DateTime::UNIX_EPOCH)
+}
+
+/// Select the page of `tasks` that `query` asks for.
+///
+/// Only matching tasks past `query.after` are cloned; `offset` is ignored.
+fn paginate<'a>(
+    tasks: impl IntoIterator<Item = &'a UnifiedTask>,
+    query: &TaskQuery,
+) -> PaginatedTasks {
+    let key = |task: &'a UnifiedTask| (sort_time(task), task.id.as_str());
+    let mut matching: Vec<&UnifiedTask> = tasks
+        .into_iter()
+        .filter(|t| query.matches(t))
+        .filter(|t| match &query.after {
+            Some(cursor) if query.newest_first => key(t) < cursor.key(),
+            Some(cursor) => key(t) > cursor.key(),
+            None => true,
+        })
+        .collect();
+
+    if query.newest_first {
+        matching.sort_by(|a, b| key(b).cmp(&key(a)));
+    } else {
+        matching.sort_by(|a, b| key(a).cmp(&key(b)));
+    }
+
+    let limit = query.limit.unwrap_or(matching.len());
+    let next_cursor =
+        (limit > 0 && matching.len() > limit).then(|| TaskCursor::at(matching[limit - 1]));
+    PaginatedTasks {
+        tasks: matching.into_iter().take(limit).cloned().collect(),
+        next_cursor,
+    }
+}
+
 // ============================================================================
 // Task Store Trait
 // ============================================================================
@@ -280,8 +407,20 @@ pub trait TaskStore: Send + Sync {
     async fn delete(&self, task_id: &str) -> AgentResult<bool>;
 
     /// Query tasks with filters.
+    ///
+    /// Loads every matching task before applying `offset` and `limit`; use
+    /// [`TaskStore::query_page`] for large stores.
     async fn query(&self, query: &TaskQuery) -> AgentResult<Vec<UnifiedTask>>;
 
+    /// Query one page of tasks, resuming after `query.after`.
+    ///
+    /// Returns at most `query.limit` tasks ordered by creation time and task
+    /// ID, plus the cursor for the following page. `offset` is ignored.
+    async fn query_page(&self, query: &TaskQuery) -> AgentResult<PaginatedTasks> {
+        let tasks = self.query(&query.unbounded()).await?;
+        Ok(paginate(&tasks, query))
+    }
+
     /// List all task IDs.
     async fn list_ids(&self) -> AgentResult<Vec<String>>;
 
@@ -514,6 +653,11 @@ impl TaskStore for InMemoryTaskStore {
         Ok(results)
     }
 
+    async fn query_page(&self, query: &TaskQuery) -> AgentResult<PaginatedTasks> {
+        let tasks = self.tasks.read().await;
+        Ok(paginate(tasks.values(), query))
+    }
+
     async fn list_ids(&self) -> AgentResult<Vec<String>> {
         let tasks = self.tasks.read().await;
         Ok(tasks.keys().cloned().collect())
@@ -768,6 +912,15 @@ impl TaskStore for FileTaskStore {
         Ok(results)
     }
 
+    async fn query_page(&self, query: &TaskQuery) -> AgentResult<PaginatedTasks> {
+        if let Some(ref cache) = self.cache {
+            return Ok(paginate(cache.read().await.values(), query));
+        }
+
+        let tasks = self.query(&query.unbounded()).await?;
+        Ok(paginate(&tasks, query))
+    }
+
     async fn list_ids(&self) -> AgentResult<Vec<String>> {
         let mut ids = Vec::new();
         let entries = std::fs::read_dir(&self.directory)?;
@@ -994,6 +1147,115 @@ mod tests {
         assert_eq!(page3.len(), 1);
     }
 
+    /// Drain every page of `query`, returning task IDs in order.
+    async fn collect_pages(store: &dyn TaskStore, query: TaskQuery) -> Vec<String> {
+        let mut ids = Vec::new();
+        let mut query = query;
+        loop {
+            let page = store.query_page(&query).await.unwrap();
+            assert!(page.tasks.len() <= query.limit.unwrap());
+            ids.extend(page.tasks.into_iter().map(|t| t.id));
+            match page.next_cursor {
+                Some(cursor) => query = query.after(cursor),
+                None => return ids,
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_query_page_walks_all_tasks_once() {
+        let store = InMemoryTaskStore::new();
+        // Identical timestamps make the task ID the tiebreaker
+        let created = Utc::now();
+        for i in 0..5 {
+            let mut task = UnifiedTask::new(format!("task-{}", i));
+            task.created_at = Some(created);
+            store.save(&task).await.unwrap();
+        }
+
+        let oldest = collect_pages(&store, TaskQuery::new().with_limit(2).oldest_first()).await;
+        assert_eq!(oldest, ["task-0", "task-1", "task-2", "task-3", "task-4"]);
+
+        let newest_first = TaskQuery {
+            newest_first: true,
+            ..TaskQuery::new().with_limit(2)
+        };
+        let newest = collect_pages(&store, newest_first).await;
+        assert_eq!(newest, ["task-4", "task-3", "task-2", "task-1", "task-0"]);
+
+        let completed = collect_pages(
+            &store,
+            TaskQuery::new()
+                .with_status(TaskStatus::Completed)
+                .with_limit(2),
+        )
+        .await;
+        assert!(completed.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_query_page_stable_under_concurrent_inserts() {
+        let store = InMemoryTaskStore::new();
+        let base = Utc::now();
+        for i in 0..4 {
+            let mut task = UnifiedTask::new(format!("old-{}", i));
+            task.created_at = Some(base + chrono::Duration::seconds(i));
+            store.save(&task).await.unwrap();
+        }
+
+        let query = TaskQuery {
+            newest_first: true,
+            ..TaskQuery::new().with_limit(2)
+        };
+        let first = store.query_page(&query).await.unwrap();
+        assert_eq!(first.tasks[0].id, "old-3");
+
+        // A newer task arrives between pages
+        let mut late = UnifiedTask::new("new-0");
+        late.created_at = Some(base + chrono::Duration::seconds(10));
+        store.save(&late).await.unwrap();
+
+        let second = store
+            .query_page(&query.clone().after(first.next_cursor.unwrap()))
+            .await
+            .unwrap();
+        let ids: Vec<_> = second.tasks.iter().map(|t| t.id.as_str()).collect();
+        assert_eq!(ids, ["old-1", "old-0"]);
+        assert!(second.next_cursor.is_none());
+    }
+
+    #[test]
+    fn test_task_cursor_encoding() {
+        let mut task = UnifiedTask::new("task|with-pipe");
+        task.created_at = Some(Utc::now());
+        let cursor = TaskCursor::at(&task);
+
+        let decoded = TaskCursor::decode(&cursor.encode()).unwrap();
+        assert_eq!(decoded, cursor);
+        assert_eq!(cursor.to_string(), cursor.encode());
+
+        assert!(matches!(
+            TaskCursor::decode("not-a-cursor"),
+            Err(AgentError::InvalidRequest(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_file_store_query_page() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let store = FileTaskStore::new(temp_dir.path()).unwrap();
+        for i in 0..3 {
+            store
+                .save(&UnifiedTask::new(format!("file-{}", i)))
+                .await
+                .unwrap();
+        }
+
+        let mut ids = collect_pages(&store, TaskQuery::new().with_limit(2).oldest_first()).await;
+        ids.sort();
+        assert_eq!(ids, ["file-0", "file-1", "file-2"]);
+    }
+
     #[tokio::test]
     async fn test_file_store() {
         let temp_dir = tempfile::tempdir().unwrap();
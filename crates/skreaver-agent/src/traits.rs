@@ -9,7 +9,7 @@ use std::pin::Pin;
 
 use crate::error::AgentResult;
 use crate::types::{
-    AgentInfo, Artifact, Capability, ContentPart, Protocol, StreamEvent, TaskStatus,
+    AgentInfo, Artifact, Capability, ContentPart, MessageRole, Protocol, StreamEvent, TaskStatus,
     UnifiedMessage, UnifiedTask,
 };
 
@@ -60,10 +60,19 @@ pub trait UnifiedAgent: Send + Sync {
     /// Send a message and receive streaming updates.
     ///
     /// Returns a stream of events as the agent processes the request.
+    ///
+    /// The default implementation is for backends that cannot stream: it
+    /// waits for [`send_message`](Self::send_message) and yields a single
+    /// terminal event for the finished task (see [`terminal_event`]).
     async fn send_message_streaming(
         &self,
         message: UnifiedMessage,
-    ) -> AgentResult<Pin<Box<dyn Stream<Item = AgentResult<StreamEvent>> + Send>>>;
+    ) -> AgentResult<Pin<Box<dyn Stream<Item = AgentResult<StreamEvent>> + Send>>> {
+        let task = self.send_message(message).await?;
+        Ok(Box::pin(futures::stream::once(async move {
+            Ok(terminal_event(&task))
+        })))
+    }
 
     /// Get the current state of a task.
     async fn get_task(&self, task_id: &str) -> AgentResult<UnifiedTask>;
@@ -283,6 +292,21 @@ pub fn error_event(
     }
 }
 
+/// Build the event that ends the stream of a finished task.
+///
+/// This is a status update carrying the task's final status and the text of
+/// its last agent message, if any.
+pub fn terminal_event(task: &UnifiedTask) -> StreamEvent {
+    let response = task
+        .messages
+        .iter()
+        .rev()
+        .find(|message| message.role == MessageRole::Agent)
+        .map(UnifiedMessage::text_content)
+        .filter(|text| !text.is_empty());
+    status_update(&task.id, task.status, response)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -347,4 +371,69 @@ mod tests {
             _ => panic!("Wrong event type"),
         }
     }
+
+    struct OneShotAgent {
+        info: AgentInfo,
+    }
+
+    #[async_trait]
+    impl UnifiedAgent for OneShotAgent {
+        fn info(&self) -> &AgentInfo {
+            &self.info
+        }
+
+        async fn send_message(&self, message: UnifiedMessage) -> AgentResult<UnifiedTask> {
+            let mut task = UnifiedTask::new("task-1");
+            task.add_message(message);
+            task.add_message(UnifiedMessage::agent("Done"));
+            task.set_status(TaskStatus::Completed);
+            Ok(task)
+        }
+
+        async fn send_message_to_task(
+            &self,
+            _task_id: &str,
+            message: UnifiedMessage,
+        ) -> AgentResult<UnifiedTask> {
+            self.send_message(message).await
+        }
+
+        async fn get_task(&self, task_id: &str) -> AgentResult<UnifiedTask> {
+            Err(crate::error::AgentError::TaskNotFound(task_id.to_string()))
+        }
+
+        async fn cancel_task(&self, task_id: &str) -> AgentResult<UnifiedTask> {
+            Err(crate::error::AgentError::TaskNotFound(task_id.to_string()))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_default_streaming_yields_single_terminal_event() {
+        use futures::StreamExt;
+
+        let agent = OneShotAgent {
+            info: AgentInfo::new("one-shot", "One Shot"),
+        };
+        let events: Vec<StreamEvent> = agent
+            .send_message_streaming(UnifiedMessage::user("Hi"))
+            .await
+            .unwrap()
+            .map(Result::unwrap)
+            .collect()
+            .await;
+
+        assert_eq!(events.len(), 1);
+        match &events[0] {
+            StreamEvent::StatusUpdate {
+                task_id,
+                status,
+                message,
+            } => {
+                assert_eq!(task_id, "task-1");
+                assert_eq!(*status, TaskStatus::Completed);
+                assert_eq!(message.as_deref(), Some("Done"));
+            }
+            other => panic!("Wrong event type: {:?}", other),
+        }
+    }
 }
@@ -0,0 +1,554 @@
+//! Agent transcript recording with cursor-based reads.
+//!
+//! A [`TranscriptRecorder`] appends [`StreamEvent`]s to a [`TranscriptStorage`]
+//! backend, assigning each event a sequence number. Consumers page through a
+//! transcript with [`TranscriptRecorder::read_from`] instead of reloading it,
+//! or follow it live with [`TranscriptRecorder::tail`].
+//!
+//! # Features
+//!
+//! - **TranscriptStorage trait**: Async append/read interface keyed by sequence
+//! - **InMemoryTranscriptStorage**: Fast in-memory storage for testing/development
+//! - **FileTranscriptStorage**: Append-only JSON Lines file with an offset index,
+//!   so reads seek directly to the cursor
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use futures::StreamExt;
+//! use skreaver_agent::{TranscriptCursor, TranscriptRecorder};
+//!
+//! let recorder = TranscriptRecorder::in_memory();
+//! recorder.record(status_update("task-1", TaskStatus::Working, None)).await?;
+//!
+//! // Page through the transcript
+//! let page = recorder.read_from(TranscriptCursor::start(), 100).await?;
+//! let next = recorder.read_from(page.next_cursor, 100).await?;
+//!
+//! // Follow new events as they are recorded
+//! let mut tail = recorder.tail().await?;
+//! while let Some(entry) = tail.next().await {
+//!     println!("{:?}", entry?.event);
+//! }
+//! ```
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use futures::Stream;
+use serde::{Deserialize, Serialize};
+use std::io::{BufRead, BufReader, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use std::pin::Pin;
+use std::sync::{Arc, Mutex, MutexGuard};
+use tokio::sync::{RwLock, broadcast};
+use tracing::{debug, info};
+
+use crate::error::{AgentError, AgentResult};
+use crate::types::StreamEvent;
+
+/// Capacity of the live tail channel; slower tails catch up from storage.
+const TAIL_CHANNEL_CAPACITY: usize = 256;
+
+/// Page size used when a tail catches up from storage.
+const TAIL_CATCH_UP_BATCH: usize = 256;
+
+/// Stream of transcript entries returned by [`TranscriptRecorder::tail`].
+pub type TranscriptStream = Pin<Box<dyn Stream<Item = AgentResult<TranscriptEntry>> + Send>>;
+
+// ============================================================================
+// Cursor and Entries
+// ============================================================================
+
+/// Position in a transcript: the sequence number of the next entry to read.
+///
+/// Cursors are opaque to consumers and serialize as a plain number, so they
+/// can be handed to UIs and sent back to resume reading.
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+#[serde(transparent)]
+pub struct TranscriptCursor(u64);
+
+impl TranscriptCursor {
+    /// Cursor at the beginning of a transcript.
+    pub fn start() -> Self {
+        Self(0)
+    }
+
+    /// Cursor positioned at the entry with sequence number `seq`.
+    pub fn at(seq: u64) -> Self {
+        Self(seq)
+    }
+
+    /// Sequence number of the next entry this cursor reads.
+    pub fn position(&self) -> u64 {
+        self.0
+    }
+}
+
+impl std::fmt::Display for TranscriptCursor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// A recorded transcript event.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TranscriptEntry {
+    /// Sequence number, contiguous from zero
+    pub seq: u64,
+    /// When the event was recorded
+    pub recorded_at: DateTime<Utc>,
+    /// The recorded event
+    pub event: StreamEvent,
+}
+
+/// A page of transcript entries.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TranscriptPage {
+    /// Entries in sequence order
+    pub entries: Vec<TranscriptEntry>,
+    /// Cursor to pass to the next `read_from` call
+    pub next_cursor: TranscriptCursor,
+    /// Whether more entries were already recorded past `next_cursor`
+    pub has_more: bool,
+}
+
+// ============================================================================
+// Storage Trait
+// ============================================================================
+
+/// Append-only storage for transcript entries.
+#[async_trait]
+pub trait TranscriptStorage: Send + Sync {
+    /// Append an event, assigning it the next sequence number.
+    async fn append(&self, event: StreamEvent) -> AgentResult<TranscriptEntry>;
+
+    /// Read up to `limit` entries starting at `cursor`.
+    async fn read_from(
+        &self,
+        cursor: TranscriptCursor,
+        limit: usize,
+    ) -> AgentResult<TranscriptPage>;
+
+    /// Cursor just past the last recorded entry.
+    async fn end_cursor(&self) -> AgentResult<TranscriptCursor>;
+}
+
+fn page_of(entries: Vec<TranscriptEntry>, cursor: TranscriptCursor, total: u64) -> TranscriptPage {
+    let next_cursor = TranscriptCursor(cursor.0.min(total) + entries.len() as u64);
+    TranscriptPage {
+        entries,
+        has_more: next_cursor.0 < total,
+        next_cursor,
+    }
+}
+
+// ============================================================================
+// In-Memory Storage
+// ============================================================================
+
+/// In-memory transcript storage.
+#[derive(Debug, Default)]
+pub struct InMemoryTranscriptStorage {
+    entries: RwLock<Vec<TranscriptEntry>>,
+}
+
+impl InMemoryTranscriptStorage {
+    /// Create an empty in-memory transcript.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl TranscriptStorage for InMemoryTranscriptStorage {
+    async fn append(&self, event: StreamEvent) -> AgentResult<TranscriptEntry> {
+        let mut entries = self.entries.write().await;
+        let entry = TranscriptEntry {
+            seq: entries.len() as u64,
+            recorded_at: Utc::now(),
+            event,
+        };
+        entries.push(entry.clone());
+        Ok(entry)
+    }
+
+    async fn read_from(
+        &self,
+        cursor: TranscriptCursor,
+        limit: usize,
+    ) -> AgentResult<TranscriptPage> {
+        let entries = self.entries.read().await;
+        let start = (cursor.0 as usize).min(entries.len());
+        let page = entries.iter().skip(start).take(limit).cloned().collect();
+        Ok(page_of(page, cursor, entries.len() as u64))
+    }
+
+    async fn end_cursor(&self) -> AgentResult<TranscriptCursor> {
+        Ok(TranscriptCursor(self.entries.read().await.len() as u64))
+    }
+}
+
+// ============================================================================
+// File Storage
+// ============================================================================
+
+/// Transcript storage backed by a JSON Lines file.
+///
+/// Byte offsets of each line are indexed when the file is opened and as
+/// entries are appended, so `read_from` seeks straight to the cursor rather
+/// than re-reading the whole file.
+#[derive(Debug, Clone)]
+pub struct FileTranscriptStorage {
+    inner: Arc<FileTranscriptInner>,
+}
+
+#[derive(Debug)]
+struct FileTranscriptInner {
+    path: PathBuf,
+    /// Byte offset of each entry's line, indexed by sequence number
+    offsets: Mutex<Vec<u64>>,
+}
+
+impl FileTranscriptInner {
+    fn lock_offsets(&self) -> MutexGuard<'_, Vec<u64>> {
+        self.offsets.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl FileTranscriptStorage {
+    /// Open a transcript file, creating it and its parent directory if needed.
+    ///
+    /// Existing entries are indexed so recording continues their sequence.
+    pub fn open(path: impl Into<PathBuf>) -> AgentResult<Self> {
+        let path = path.into();
+        if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            std::fs::create_dir_all(parent)?;
+        }
+        let file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .read(true)
+            .open(&path)?;
+
+        let mut offsets = Vec::new();
+        let mut reader = BufReader::new(file);
+        let mut offset = 0u64;
+        let mut line = String::new();
+        loop {
+            line.clear();
+            let read = reader.read_line(&mut line)?;
+            if read == 0 {
+                break;
+            }
+            if !line.trim().is_empty() {
+                offsets.push(offset);
+            }
+            offset += read as u64;
+        }
+
+        info!(path = %path.display(), entries = offsets.len(), "Opened transcript file");
+        Ok(Self {
+            inner: Arc::new(FileTranscriptInner {
+                path,
+                offsets: Mutex::new(offsets),
+            }),
+        })
+    }
+
+    /// Path of the transcript file.
+    pub fn path(&self) -> &Path {
+        &self.inner.path
+    }
+
+    async fn blocking<T, F>(&self, f: F) -> AgentResult<T>
+    where
+        T: Send + 'static,
+        F: FnOnce(&FileTranscriptInner) -> AgentResult<T> + Send + 'static,
+    {
+        let inner = Arc::clone(&self.inner);
+        tokio::task::spawn_blocking(move || f(&inner))
+            .await
+            .map_err(|e| AgentError::Internal(format!("Task join error: {}", e)))?
+    }
+}
+
+#[async_trait]
+impl TranscriptStorage for FileTranscriptStorage {
+    async fn append(&self, event: StreamEvent) -> AgentResult<TranscriptEntry> {
+        self.blocking(move |inner| {
+            // Hold the index lock across the write so sequence numbers match line order
+            let mut offsets = inner.lock_offsets();
+            let entry = TranscriptEntry {
+                seq: offsets.len() as u64,
+                recorded_at: Utc::now(),
+                event,
+            };
+            let mut line = serde_json::to_string(&entry)?;
+            line.push('\n');
+
+            let mut file = std::fs::OpenOptions::new().append(true).open(&inner.path)?;
+            let offset = file.seek(SeekFrom::End(0))?;
+            file.write_all(line.as_bytes())?;
+            offsets.push(offset);
+
+            debug!(seq = entry.seq, path = %inner.path.display(), "Appended transcript entry");
+            Ok(entry)
+        })
+        .await
+    }
+
+    async fn read_from(
+        &self,
+        cursor: TranscriptCursor,
+        limit: usize,
+    ) -> AgentResult<TranscriptPage> {
+        self.blocking(move |inner| {
+            let (start, total) = {
+                let offsets = inner.lock_offsets();
+                (
+                    offsets.get(cursor.0 as usize).copied(),
+                    offsets.len() as u64,
+                )
+            };
+            let Some(start) = start.filter(|_| limit > 0) else {
+                return Ok(page_of(Vec::new(), cursor, total));
+            };
+
+            let mut reader = BufReader::new(std::fs::File::open(&inner.path)?);
+            reader.seek(SeekFrom::Start(start))?;
+
+            let available = (total - cursor.0) as usize;
+            let mut entries = Vec::with_capacity(limit.min(available));
+            let mut line = String::new();
+            while entries.len() < limit.min(available) {
+                line.clear();
+                if reader.read_line(&mut line)? == 0 {
+                    break;
+                }
+                if !line.trim().is_empty() {
+                    entries.push(serde_json::from_str::<TranscriptEntry>(&line)?);
+                }
+            }
+            Ok(page_of(entries, cursor, total))
+        })
+        .await
+    }
+
+    async fn end_cursor(&self) -> AgentResult<TranscriptCursor> {
+        Ok(TranscriptCursor(self.inner.lock_offsets().len() as u64))
+    }
+}
+
+// ============================================================================
+// Recorder
+// ============================================================================
+
+/// Records agent events into a transcript and serves cursor reads and live tails.
+#[derive(Clone)]
+pub struct TranscriptRecorder {
+    storage: Arc<dyn TranscriptStorage>,
+    live: broadcast::Sender<TranscriptEntry>,
+}
+
+impl std::fmt::Debug for TranscriptRecorder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TranscriptRecorder")
+            .field("tails", &self.live.receiver_count())
+            .finish()
+    }
+}
+
+impl TranscriptRecorder {
+    /// Create a recorder over the given storage.
+    pub fn new(storage: Arc<dyn TranscriptStorage>) -> Self {
+        let (live, _) = broadcast::channel(TAIL_CHANNEL_CAPACITY);
+        Self { storage, live }
+    }
+
+    /// Create a recorder with in-memory storage.
+    pub fn in_memory() -> Self {
+        Self::new(Arc::new(InMemoryTranscriptStorage::new()))
+    }
+
+    /// Create a recorder writing to a JSON Lines file.
+    pub fn open_file(path: impl Into<PathBuf>) -> AgentResult<Self> {
+        Ok(Self::new(Arc::new(FileTranscriptStorage::open(path)?)))
+    }
+
+    /// Get the underlying storage.
+    pub fn storage(&self) -> &Arc<dyn TranscriptStorage> {
+        &self.storage
+    }
+
+    /// Record an event and notify live tails.
+    pub async fn record(&self, event: StreamEvent) -> AgentResult<TranscriptEntry> {
+        let entry = self.storage.append(event).await?;
+        // No tails subscribed is not an error
+        let _ = self.live.send(entry.clone());
+        Ok(entry)
+    }
+
+    /// Read up to `limit` entries starting at `cursor`.
+    pub async fn read_from(
+        &self,
+        cursor: TranscriptCursor,
+        limit: usize,
+    ) -> AgentResult<TranscriptPage> {
+        self.storage.read_from(cursor, limit).await
+    }
+
+    /// Follow events recorded from now on.
+    pub async fn tail(&self) -> AgentResult<TranscriptStream> {
+        let cursor = self.storage.end_cursor().await?;
+        Ok(self.tail_from(cursor))
+    }
+
+    /// Follow the transcript starting at `cursor`.
+    ///
+    /// Already-recorded entries are replayed from storage first, then new
+    /// entries are yielded as they are recorded. A tail that falls behind the
+    /// live channel catches up from storage, so no entries are skipped. The
+    /// stream ends when every recorder handle has been dropped.
+    pub fn tail_from(&self, cursor: TranscriptCursor) -> TranscriptStream {
+        // Subscribe before reading storage so no entry falls between the two
+        let mut live = self.live.subscribe();
+        let storage = Arc::clone(&self.storage);
+
+        Box::pin(async_stream::stream! {
+            let mut next = cursor;
+            let mut catch_up = true;
+
+            loop {
+                if catch_up {
+                    loop {
+                        match storage.read_from(next, TAIL_CATCH_UP_BATCH).await {
+                            Ok(page) => {
+                                for entry in page.entries {
+                                    yield Ok(entry);
+                                }
+                                next = page.next_cursor;
+                                if !page.has_more {
+                                    break;
+                                }
+                            }
+                            Err(e) => {
+                                yield Err(e);
+                                return;
+                            }
+                        }
+                    }
+                    catch_up = false;
+                }
+
+                match live.recv().await {
+                    Ok(entry) if entry.seq < next.0 => {}
+                    Ok(entry) if entry.seq == next.0 => {
+                        next = TranscriptCursor(entry.seq + 1);
+                        yield Ok(entry);
+                    }
+                    // Out-of-order delivery from concurrent writers: storage has the gap
+                    Ok(_) => catch_up = true,
+                    Err(broadcast::error::RecvError::Lagged(missed)) => {
+                        debug!(missed, "Transcript tail lagged, catching up from storage");
+                        catch_up = true;
+                    }
+                    Err(broadcast::error::RecvError::Closed) => break,
+                }
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::TaskStatus;
+    use futures::StreamExt;
+    use std::time::Duration;
+
+    fn event(n: usize) -> StreamEvent {
+        StreamEvent::StatusUpdate {
+            task_id: format!("task-{}", n),
+            status: TaskStatus::Working,
+            message: None,
+        }
+    }
+
+    async fn assert_contiguous_paging(recorder: &TranscriptRecorder) {
+        for n in 0..7 {
+            recorder.record(event(n)).await.unwrap();
+        }
+
+        let mut cursor = TranscriptCursor::start();
+        let mut seen = Vec::new();
+        loop {
+            let page = recorder.read_from(cursor, 3).await.unwrap();
+            assert!(page.entries.len() <= 3);
+            seen.extend(page.entries.iter().map(|e| e.seq));
+            cursor = page.next_cursor;
+            if !page.has_more {
+                break;
+            }
+        }
+
+        assert_eq!(seen, (0..7).collect::<Vec<_>>());
+        assert_eq!(cursor, TranscriptCursor::at(7));
+        let empty = recorder.read_from(cursor, 3).await.unwrap();
+        assert!(empty.entries.is_empty());
+        assert_eq!(empty.next_cursor, cursor);
+    }
+
+    #[tokio::test]
+    async fn test_cursor_paging_is_contiguous() {
+        assert_contiguous_paging(&TranscriptRecorder::in_memory()).await;
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("transcripts/session.jsonl");
+        assert_contiguous_paging(&TranscriptRecorder::open_file(&path).unwrap()).await;
+
+        // Reopening the file keeps sequence numbers and cursors valid
+        let reopened = TranscriptRecorder::open_file(&path).unwrap();
+        let page = reopened
+            .read_from(TranscriptCursor::at(5), 10)
+            .await
+            .unwrap();
+        assert_eq!(page.entries.len(), 2);
+        assert_eq!(page.entries[0].event, event(5));
+        assert_eq!(reopened.record(event(7)).await.unwrap().seq, 7);
+    }
+
+    #[tokio::test]
+    async fn test_tail_receives_appended_events() {
+        let recorder = TranscriptRecorder::in_memory();
+        recorder.record(event(0)).await.unwrap();
+
+        let mut tail = recorder.tail().await.unwrap();
+        let mut replay = recorder.tail_from(TranscriptCursor::start());
+
+        for n in 1..4 {
+            recorder.record(event(n)).await.unwrap();
+        }
+
+        for expected in 1..4u64 {
+            let entry = tokio::time::timeout(Duration::from_secs(1), tail.next())
+                .await
+                .unwrap()
+                .unwrap()
+                .unwrap();
+            assert_eq!(entry.seq, expected);
+        }
+
+        let replayed: Vec<u64> = replay
+            .by_ref()
+            .take(4)
+            .map(|e| e.unwrap().seq)
+            .collect()
+            .await;
+        assert_eq!(replayed, vec![0, 1, 2, 3]);
+
+        drop(recorder);
+        assert!(tail.next().await.is_none());
+    }
+}
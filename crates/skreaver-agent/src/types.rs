@@ -421,6 +421,9 @@ pub struct Capability {
     /// Description of what this capability does
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
+    /// Capability version (`major.minor.patch`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
     /// Input schema (JSON Schema)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub input_schema: Option<serde_json::Value>,
@@ -439,6 +442,7 @@ impl Capability {
             id: id.into(),
             name: name.into(),
             description: None,
+            version: None,
             input_schema: None,
             tags: Vec::new(),
             metadata: HashMap::new(),
@@ -451,6 +455,12 @@ impl Capability {
         self
     }
 
+    /// Set the capability version.
+    pub fn with_version(mut self, version: impl Into<String>) -> Self {
+        self.version = Some(version.into());
+        self
+    }
+
     /// Add an input schema.
     pub fn with_input_schema(mut self, schema: serde_json::Value) -> Self {
         self.input_schema = Some(schema);
@@ -578,6 +588,28 @@ pub enum StreamEvent {
         code: String,
         message: String,
     },
+    /// Orchestration stage (pipeline stage or parallel branch) started
+    StageStarted {
+        task_id: String,
+        /// Stage index in a pipeline, or branch index in a parallel agent
+        stage: usize,
+        agent_id: String,
+    },
+    /// Orchestration stage finished with a task
+    StageCompleted {
+        task_id: String,
+        stage: usize,
+        agent_id: String,
+        /// Status of the stage's own task
+        status: TaskStatus,
+    },
+    /// Orchestration stage returned an error
+    StageFailed {
+        task_id: String,
+        stage: usize,
+        agent_id: String,
+        error: String,
+    },
 }
 
 #[cfg(test)]
@@ -59,6 +59,19 @@ impl JwtClaims {
         }
     }
 
+    /// Scope the claims to a single audience, replacing the configured default
+    #[must_use]
+    pub fn with_audience(mut self, audience: impl Into<String>) -> Self {
+        self.aud = vec![audience.into()];
+        self
+    }
+
+    /// Check if the claims include the given audience
+    #[must_use]
+    pub fn has_audience(&self, audience: &str) -> bool {
+        self.aud.iter().any(|aud| aud == audience)
+    }
+
     /// Check if the token is expired
     #[must_use]
     pub fn is_expired(&self) -> bool {
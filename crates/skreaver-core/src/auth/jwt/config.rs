@@ -1,5 +1,6 @@
 //! JWT configuration
 
+use super::minimize::ClaimMinimization;
 use jsonwebtoken::Algorithm;
 
 /// JWT token refresh policy
@@ -42,10 +43,112 @@ impl Default for RefreshPolicy {
     }
 }
 
+/// An additional issuer whose tokens are accepted in a federated setup
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TrustedIssuer {
+    /// Expected `iss` claim
+    pub issuer: String,
+    /// Keys used to verify this issuer's tokens (defaults to the manager's
+    /// own verification key)
+    ///
+    /// Only the public half of asymmetric keys is needed.
+    pub keys: Option<KeyMaterial>,
+    /// Algorithm this issuer signs with (defaults to the manager's algorithm)
+    pub algorithm: Option<Algorithm>,
+    /// Key id; when set, only tokens whose header `kid` matches use this key
+    pub kid: Option<String>,
+}
+
+impl TrustedIssuer {
+    /// Trust `issuer`, verifying its tokens with the manager's own key
+    pub fn new(issuer: impl Into<String>) -> Self {
+        Self {
+            issuer: issuer.into(),
+            keys: None,
+            algorithm: None,
+            kid: None,
+        }
+    }
+
+    /// Verify this issuer's tokens with a dedicated HMAC secret and the
+    /// manager's algorithm
+    pub fn with_secret(mut self, secret: impl Into<String>) -> Self {
+        self.keys = Some(KeyMaterial::Hmac(secret.into()));
+        self
+    }
+
+    /// Verify this issuer's tokens with its own keys and algorithm
+    ///
+    /// `algorithm` must belong to the same family as the keys.
+    pub fn with_keys(mut self, algorithm: Algorithm, keys: KeyMaterial) -> Self {
+        self.algorithm = Some(algorithm);
+        self.keys = Some(keys);
+        self
+    }
+
+    /// Only use this key for tokens carrying the given `kid` header
+    pub fn with_kid(mut self, kid: impl Into<String>) -> Self {
+        self.kid = Some(kid.into());
+        self
+    }
+}
+
+/// Keys used to sign and verify tokens
+///
+/// The variant must match the family of [`JwtConfig::algorithm`]. Asymmetric
+/// keys are PEM encoded; omit the private key for managers that only verify
+/// tokens signed elsewhere.
+#[derive(Clone, PartialEq, Eq)]
+pub enum KeyMaterial {
+    /// Shared secret for HS256, HS384 and HS512
+    Hmac(String),
+    /// RSA keys for RS256/384/512 and PS256/384/512
+    RsaPem {
+        /// PKCS#1 or PKCS#8 private key, required to sign tokens
+        private: Option<String>,
+        /// SubjectPublicKeyInfo or PKCS#1 public key
+        public: String,
+    },
+    /// Elliptic curve keys for ES256 and ES384
+    EcdsaPem {
+        /// PKCS#8 private key, required to sign tokens
+        private: Option<String>,
+        /// SubjectPublicKeyInfo public key
+        public: String,
+    },
+}
+
+impl KeyMaterial {
+    /// Whether the material includes a key for signing tokens
+    pub fn can_sign(&self) -> bool {
+        match self {
+            Self::Hmac(_) => true,
+            Self::RsaPem { private, .. } | Self::EcdsaPem { private, .. } => private.is_some(),
+        }
+    }
+}
+
+impl std::fmt::Debug for KeyMaterial {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // Never print private keys or secrets
+        match self {
+            Self::Hmac(_) => f.write_str("Hmac(..)"),
+            Self::RsaPem { private, .. } => f
+                .debug_struct("RsaPem")
+                .field("private", &private.as_ref().map(|_| ".."))
+                .finish_non_exhaustive(),
+            Self::EcdsaPem { private, .. } => f
+                .debug_struct("EcdsaPem")
+                .field("private", &private.as_ref().map(|_| ".."))
+                .finish_non_exhaustive(),
+        }
+    }
+}
+
 /// JWT configuration
 #[derive(Debug, Clone)]
 pub struct JwtConfig {
-    /// Secret key for HMAC signing
+    /// Secret key for HMAC signing, used when `keys` is not set
     pub secret: String,
     /// Token issuer
     pub issuer: String,
@@ -55,10 +158,24 @@ pub struct JwtConfig {
     pub expiry_minutes: i64,
     /// Refresh token expiration in days
     pub refresh_expiry_days: i64,
-    /// Algorithm to use (HS256, HS384, HS512)
+    /// Algorithm to use (HS256 by default)
     pub algorithm: Algorithm,
+    /// Signing and verification keys
+    ///
+    /// `None` signs and verifies with `secret` as an HMAC key.
+    pub keys: Option<KeyMaterial>,
     /// Token refresh policy
     pub refresh: RefreshPolicy,
+    /// Other issuers whose tokens are accepted alongside `issuer`
+    ///
+    /// Empty by default, which accepts only tokens issued by `issuer`.
+    /// Tokens from any other issuer are rejected.
+    pub trusted_issuers: Vec<TrustedIssuer>,
+    /// Encode tokens in compact form
+    ///
+    /// `None` encodes every claim in full. Minimized tokens are decoded
+    /// regardless, provided role codes match the issuing configuration.
+    pub minimization: Option<ClaimMinimization>,
 }
 
 impl Default for JwtConfig {
@@ -70,7 +187,10 @@ impl Default for JwtConfig {
             expiry_minutes: 60,
             refresh_expiry_days: 30,
             algorithm: Algorithm::HS256,
+            keys: None,
             refresh: RefreshPolicy::default(),
+            trusted_issuers: Vec::new(),
+            minimization: None,
         }
     }
 }
@@ -84,6 +204,30 @@ impl JwtConfig {
         }
     }
 
+    /// Sign and verify with the given keys instead of `secret`
+    ///
+    /// `algorithm` must belong to the same family as the keys.
+    #[must_use]
+    pub fn with_keys(mut self, algorithm: Algorithm, keys: KeyMaterial) -> Self {
+        self.algorithm = algorithm;
+        self.keys = Some(keys);
+        self
+    }
+
+    /// Accept tokens from an additional issuer
+    #[must_use]
+    pub fn with_trusted_issuer(mut self, issuer: TrustedIssuer) -> Self {
+        self.trusted_issuers.push(issuer);
+        self
+    }
+
+    /// Encode tokens in compact form
+    #[must_use]
+    pub fn with_claim_minimization(mut self, minimization: ClaimMinimization) -> Self {
+        self.minimization = Some(minimization);
+        self
+    }
+
     /// Create config with refresh disabled
     pub fn no_refresh() -> Self {
         Self {
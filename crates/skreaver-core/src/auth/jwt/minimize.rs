@@ -0,0 +1,277 @@
+//! Compact JWT encoding for principals with many roles or custom claims
+//!
+//! Minimized tokens stay standard JWTs: registered claims (`iss`, `sub`,
+//! `aud`, `exp`, `iat`, `jti`) are always present, and the compact forms use
+//! private claim names:
+//!
+//! - `rc`: compact role codes, mapped back to role names on decode
+//! - `cs`: id of a [`ClaimSet`] held server-side in a [`ClaimSetStore`]
+
+use super::claims::JwtClaims;
+use dashmap::DashMap;
+use serde::{Deserialize, Deserializer, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::{BTreeMap, HashMap};
+
+/// Options for shrinking encoded tokens
+///
+/// Every manager decodes minimized tokens; these options only control how
+/// tokens are encoded, apart from `role_codes` which must match between the
+/// issuing and verifying managers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClaimMinimization {
+    /// Compact code for each role name; roles without a code keep their name
+    pub role_codes: BTreeMap<String, String>,
+    /// Omit claims holding their default value: `name` equal to `sub`,
+    /// `nbf` equal to `iat`, an `access` token type and empty roles or
+    /// custom claims. A single audience is encoded as a string.
+    pub omit_defaults: bool,
+    /// Replace roles and custom claims by a server-side claim set once their
+    /// encoded size exceeds this many bytes
+    pub claim_set_threshold: Option<usize>,
+}
+
+impl Default for ClaimMinimization {
+    fn default() -> Self {
+        Self {
+            role_codes: BTreeMap::new(),
+            omit_defaults: true,
+            claim_set_threshold: None,
+        }
+        .with_role_code("admin", "a")
+        .with_role_code("agent", "g")
+        .with_role_code("viewer", "v")
+    }
+}
+
+impl ClaimMinimization {
+    /// Encode `role` as `code`, replacing any role previously using `code`
+    #[must_use]
+    pub fn with_role_code(mut self, role: impl Into<String>, code: impl Into<String>) -> Self {
+        let code = code.into();
+        self.role_codes.retain(|_, existing| *existing != code);
+        self.role_codes.insert(role.into(), code);
+        self
+    }
+
+    /// Keep claims that hold their default value
+    #[must_use]
+    pub fn keep_defaults(mut self) -> Self {
+        self.omit_defaults = false;
+        self
+    }
+
+    /// Store roles and custom claims server-side once they exceed `bytes`
+    #[must_use]
+    pub fn with_claim_set_threshold(mut self, bytes: usize) -> Self {
+        self.claim_set_threshold = Some(bytes);
+        self
+    }
+
+    /// Convert full claims into their minimized JSON form
+    pub(super) fn minimize(
+        &self,
+        claims: &JwtClaims,
+        store: &dyn ClaimSetStore,
+    ) -> serde_json::Map<String, serde_json::Value> {
+        use serde_json::{Value, json};
+
+        let mut out = serde_json::Map::new();
+        out.insert("sub".into(), json!(claims.sub));
+        if !self.omit_defaults || claims.name != claims.sub {
+            out.insert("name".into(), json!(claims.name));
+        }
+        out.insert("iss".into(), json!(claims.iss));
+        match claims.aud.as_slice() {
+            [audience] if self.omit_defaults => out.insert("aud".into(), json!(audience)),
+            audiences => out.insert("aud".into(), json!(audiences)),
+        };
+        out.insert("exp".into(), json!(claims.exp));
+        out.insert("iat".into(), json!(claims.iat));
+        if !self.omit_defaults || claims.nbf != claims.iat {
+            out.insert("nbf".into(), json!(claims.nbf));
+        }
+        out.insert("jti".into(), json!(claims.jti));
+        if !self.omit_defaults || claims.typ != ACCESS_TYPE {
+            out.insert("typ".into(), json!(claims.typ));
+        }
+
+        let (coded, named): (Vec<_>, Vec<_>) = claims
+            .roles
+            .iter()
+            .partition(|role| self.role_codes.contains_key(*role));
+        let set = ClaimSet {
+            role_codes: coded.iter().map(|r| self.role_codes[*r].clone()).collect(),
+            roles: named.into_iter().cloned().collect(),
+            custom: claims.custom.clone(),
+        };
+
+        let encoded = serde_json::to_vec(&set).unwrap_or_default();
+        if self
+            .claim_set_threshold
+            .is_some_and(|threshold| encoded.len() > threshold)
+        {
+            let id = claim_set_id(&encoded);
+            store.put(&id, set);
+            out.insert("cs".into(), Value::String(id));
+            return out;
+        }
+
+        if !set.role_codes.is_empty() {
+            out.insert("rc".into(), json!(set.role_codes));
+        }
+        if !self.omit_defaults || !set.roles.is_empty() {
+            out.insert("roles".into(), json!(set.roles));
+        }
+        if !self.omit_defaults || !set.custom.is_empty() {
+            out.insert("custom".into(), json!(set.custom));
+        }
+        out
+    }
+
+    /// Map a compact role code back to its role name
+    fn role_for_code(&self, code: &str) -> Option<&str> {
+        self.role_codes
+            .iter()
+            .find(|(_, c)| c.as_str() == code)
+            .map(|(role, _)| role.as_str())
+    }
+}
+
+/// Token type assumed when the `typ` claim is omitted
+const ACCESS_TYPE: &str = "access";
+
+/// Roles and custom claims moved out of a token
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ClaimSet {
+    /// Compact role codes
+    #[serde(rename = "rc", default, skip_serializing_if = "Vec::is_empty")]
+    pub role_codes: Vec<String>,
+    /// Role names without a compact code
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub roles: Vec<String>,
+    /// Custom claims
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub custom: HashMap<String, serde_json::Value>,
+}
+
+/// Server-side storage for claim sets referenced by minimized tokens
+///
+/// Managers that verify each other's tokens must share a store.
+pub trait ClaimSetStore: Send + Sync {
+    /// Store a claim set under `id`, replacing any previous set
+    fn put(&self, id: &str, claims: ClaimSet);
+
+    /// Look up a claim set by id
+    fn get(&self, id: &str) -> Option<ClaimSet>;
+}
+
+/// Process-local claim set store
+#[derive(Debug, Default)]
+pub struct InMemoryClaimSetStore {
+    sets: DashMap<String, ClaimSet>,
+}
+
+impl InMemoryClaimSetStore {
+    /// Create an empty store
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of stored claim sets
+    pub fn len(&self) -> usize {
+        self.sets.len()
+    }
+
+    /// Check whether the store is empty
+    pub fn is_empty(&self) -> bool {
+        self.sets.is_empty()
+    }
+}
+
+impl ClaimSetStore for InMemoryClaimSetStore {
+    fn put(&self, id: &str, claims: ClaimSet) {
+        self.sets.insert(id.to_string(), claims);
+    }
+
+    fn get(&self, id: &str) -> Option<ClaimSet> {
+        self.sets.get(id).map(|set| set.clone())
+    }
+}
+
+/// Content-derived id so identical claim sets share one entry
+fn claim_set_id(encoded: &[u8]) -> String {
+    Sha256::digest(encoded)[..12]
+        .iter()
+        .map(|b| format!("{b:02x}"))
+        .collect()
+}
+
+/// Claims as they appear on the wire, in full or minimized form
+#[derive(Deserialize)]
+pub(super) struct WireClaims {
+    sub: String,
+    name: Option<String>,
+    iss: String,
+    #[serde(deserialize_with = "one_or_many")]
+    aud: Vec<String>,
+    exp: i64,
+    iat: i64,
+    nbf: Option<i64>,
+    jti: String,
+    typ: Option<String>,
+    #[serde(flatten)]
+    set: ClaimSet,
+    cs: Option<String>,
+}
+
+impl WireClaims {
+    /// Rebuild the full claims, resolving role codes and claim set references
+    ///
+    /// Returns `None` if a role code is unknown or the claim set is missing.
+    pub(super) fn expand(
+        self,
+        minimization: Option<&ClaimMinimization>,
+        store: &dyn ClaimSetStore,
+    ) -> Option<JwtClaims> {
+        let set = match self.cs {
+            Some(id) => store.get(&id)?,
+            None => self.set,
+        };
+
+        let mut roles = Vec::with_capacity(set.role_codes.len() + set.roles.len());
+        for code in &set.role_codes {
+            roles.push(minimization?.role_for_code(code)?.to_string());
+        }
+        roles.extend(set.roles);
+
+        Some(JwtClaims {
+            name: self.name.unwrap_or_else(|| self.sub.clone()),
+            sub: self.sub,
+            iss: self.iss,
+            aud: self.aud,
+            exp: self.exp,
+            iat: self.iat,
+            nbf: self.nbf.unwrap_or(self.iat),
+            jti: self.jti,
+            typ: self.typ.unwrap_or_else(|| ACCESS_TYPE.to_string()),
+            roles,
+            custom: set.custom,
+        })
+    }
+}
+
+/// Accept an audience given as a single string or an array (RFC 7519 §4.1.3)
+fn one_or_many<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<String>, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum OneOrMany {
+        One(String),
+        Many(Vec<String>),
+    }
+
+    Ok(match OneOrMany::deserialize(deserializer)? {
+        OneOrMany::One(audience) => vec![audience],
+        OneOrMany::Many(audiences) => audiences,
+    })
+}
@@ -4,13 +4,19 @@
 //! Revoked tokens are stored with TTL (time-to-live) equal to their remaining validity period,
 //! ensuring automatic cleanup once tokens would have expired anyway.
 
-#[cfg(feature = "redis")]
+#[cfg(any(feature = "redis", feature = "sqlite"))]
 use super::AuthError;
 use super::AuthResult;
 use async_trait::async_trait;
-use std::collections::HashSet;
-use std::sync::Arc;
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+use std::sync::{Arc, Weak};
+use std::time::Duration;
 use tokio::sync::RwLock;
+use tokio::time::Instant;
+
+/// Default interval between sweeps of expired in-memory blacklist entries
+pub const DEFAULT_BLACKLIST_SWEEP_INTERVAL: Duration = Duration::from_secs(60);
 
 /// Trait for token blacklist implementations
 #[async_trait]
@@ -27,6 +33,25 @@ pub trait TokenBlacklist: Send + Sync {
     /// Returns `AuthError::StorageError` if the blacklist operation fails.
     async fn revoke(&self, jti: &str, ttl_seconds: i64) -> AuthResult<()>;
 
+    /// Add a token to the blacklist until the given instant
+    ///
+    /// Pass the last instant validation still accepts the token, i.e. its
+    /// `exp` claim plus any clock skew leeway; `JwtManager::revoke` does this.
+    ///
+    /// The default implementation converts `expires_at` into a TTL relative to
+    /// now. Tokens that have already expired are not stored.
+    ///
+    /// # Errors
+    ///
+    /// Returns `AuthError::StorageError` if the blacklist operation fails.
+    async fn revoke_until(&self, jti: &str, expires_at: DateTime<Utc>) -> AuthResult<()> {
+        let ttl_seconds = (expires_at - Utc::now()).num_seconds();
+        if ttl_seconds > 0 {
+            self.revoke(jti, ttl_seconds).await?;
+        }
+        Ok(())
+    }
+
     /// Check if a token is blacklisted
     ///
     /// # Arguments
@@ -62,21 +87,82 @@ pub trait TokenBlacklist: Send + Sync {
 
 /// In-memory token blacklist (for testing and development)
 ///
+/// Entries stop counting as revoked once their TTL has passed. Expired entries
+/// are reclaimed by [`sweep_expired`](Self::sweep_expired), which a blacklist
+/// created with [`with_sweep_interval`](Self::with_sweep_interval) runs
+/// periodically in the background.
+///
 /// **Warning**: This implementation does not persist across restarts and should
-/// only be used for development and testing. Use `RedisBlacklist` in production.
+/// only be used for development and testing. Use `RedisBlacklist` or
+/// `SqliteBlacklist` in production.
 #[derive(Clone)]
 pub struct InMemoryBlacklist {
-    // Use HashSet for O(1) lookups
-    // Note: TTL is not enforced in memory (would require background task)
-    tokens: Arc<RwLock<HashSet<String>>>,
+    // JTI -> instant at which the revocation expires
+    tokens: Arc<RwLock<HashMap<String, Instant>>>,
+    sweep_interval: Option<Duration>,
 }
 
 impl InMemoryBlacklist {
-    /// Create a new in-memory blacklist
+    /// Create a new in-memory blacklist without a background sweep
     #[must_use]
     pub fn new() -> Self {
         Self {
-            tokens: Arc::new(RwLock::new(HashSet::new())),
+            tokens: Arc::new(RwLock::new(HashMap::new())),
+            sweep_interval: None,
+        }
+    }
+
+    /// Create a blacklist that removes expired entries every `interval`
+    ///
+    /// The sweep runs on the current Tokio runtime and stops once every clone
+    /// of the blacklist has been dropped.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called outside of a Tokio runtime.
+    #[must_use]
+    pub fn with_sweep_interval(interval: Duration) -> Self {
+        let blacklist = Self {
+            sweep_interval: Some(interval),
+            ..Self::new()
+        };
+        let tokens = Arc::downgrade(&blacklist.tokens);
+        tokio::spawn(Self::run_sweeper(tokens, interval));
+        blacklist
+    }
+
+    /// Interval of the background sweep, if one is running
+    #[must_use]
+    pub fn sweep_interval(&self) -> Option<Duration> {
+        self.sweep_interval
+    }
+
+    /// Remove entries whose TTL has passed, returning how many were removed
+    pub async fn sweep_expired(&self) -> usize {
+        Self::remove_expired(&self.tokens).await
+    }
+
+    async fn remove_expired(tokens: &RwLock<HashMap<String, Instant>>) -> usize {
+        let now = Instant::now();
+        let mut tokens = tokens.write().await;
+        let before = tokens.len();
+        tokens.retain(|_, expires_at| *expires_at > now);
+        before - tokens.len()
+    }
+
+    async fn run_sweeper(tokens: Weak<RwLock<HashMap<String, Instant>>>, interval: Duration) {
+        let mut ticker = tokio::time::interval(interval);
+        // The first tick completes immediately
+        ticker.tick().await;
+        loop {
+            ticker.tick().await;
+            let Some(tokens) = tokens.upgrade() else {
+                break;
+            };
+            let removed = Self::remove_expired(&tokens).await;
+            if removed > 0 {
+                tracing::debug!(removed, "Swept expired tokens from in-memory blacklist");
+            }
         }
     }
 }
@@ -89,15 +175,22 @@ impl Default for InMemoryBlacklist {
 
 #[async_trait]
 impl TokenBlacklist for InMemoryBlacklist {
-    async fn revoke(&self, jti: &str, _ttl_seconds: i64) -> AuthResult<()> {
+    async fn revoke(&self, jti: &str, ttl_seconds: i64) -> AuthResult<()> {
         let mut tokens = self.tokens.write().await;
-        tokens.insert(jti.to_string());
+        if ttl_seconds <= 0 {
+            tokens.remove(jti);
+            return Ok(());
+        }
+        let expires_at = Instant::now() + Duration::from_secs(ttl_seconds.unsigned_abs());
+        tokens.insert(jti.to_string(), expires_at);
         Ok(())
     }
 
     async fn is_revoked(&self, jti: &str) -> AuthResult<bool> {
         let tokens = self.tokens.read().await;
-        Ok(tokens.contains(jti))
+        Ok(tokens
+            .get(jti)
+            .is_some_and(|expires_at| *expires_at > Instant::now()))
     }
 
     async fn remove(&self, jti: &str) -> AuthResult<()> {
@@ -113,8 +206,12 @@ impl TokenBlacklist for InMemoryBlacklist {
     }
 
     async fn count(&self) -> AuthResult<usize> {
+        let now = Instant::now();
         let tokens = self.tokens.read().await;
-        Ok(tokens.len())
+        Ok(tokens
+            .values()
+            .filter(|expires_at| **expires_at > now)
+            .count())
     }
 }
 
@@ -193,12 +290,40 @@ impl TokenBlacklist for RedisBlacklist {
     async fn revoke(&self, jti: &str, ttl_seconds: i64) -> AuthResult<()> {
         use redis::AsyncCommands;
 
+        // A token that has already expired needs no blacklist entry
+        if ttl_seconds <= 0 {
+            return self.remove(jti).await;
+        }
+
         let mut conn = self.get_connection().await?;
         let key = self.get_key(jti);
 
         // Store with TTL (value doesn't matter, we just check existence)
         let _: () = conn
-            .set_ex(&key, "revoked", ttl_seconds as u64)
+            .set_ex(&key, "revoked", ttl_seconds.unsigned_abs())
+            .await
+            .map_err(|e| AuthError::StorageError(format!("Failed to revoke token: {}", e)))?;
+
+        Ok(())
+    }
+
+    async fn revoke_until(&self, jti: &str, expires_at: DateTime<Utc>) -> AuthResult<()> {
+        use redis::AsyncCommands;
+        use redis::{SetExpiry, SetOptions};
+
+        if expires_at <= Utc::now() {
+            return self.remove(jti).await;
+        }
+
+        let mut conn = self.get_connection().await?;
+        let key = self.get_key(jti);
+
+        // Expire at an absolute instant so the entry disappears exactly when
+        // the token would be rejected anyway
+        let expiry = u64::try_from(expires_at.timestamp_millis()).unwrap_or_default();
+        let options = SetOptions::default().with_expiration(SetExpiry::PXAT(expiry));
+        let _: () = conn
+            .set_options(&key, "revoked", options)
             .await
             .map_err(|e| AuthError::StorageError(format!("Failed to revoke token: {}", e)))?;
 
@@ -267,6 +392,244 @@ impl TokenBlacklist for RedisBlacklist {
     }
 }
 
+/// SQLite-based token blacklist (for production)
+///
+/// Revocations are stored as `jti` rows with an absolute expiry, so every
+/// process opening the same database file sees the same revocations. Tokens
+/// revoked through `JwtManager::revoke` expire at `exp` plus the validation
+/// leeway, so a row outlives every instant its token is still accepted. Rows
+/// whose expiry has passed no longer count as revoked; they are deleted
+/// lazily by [`is_revoked`](TokenBlacklist::is_revoked) and
+/// [`sweep_expired`](Self::sweep_expired), which a blacklist created with
+/// [`with_sweep_interval`](Self::with_sweep_interval) runs periodically in
+/// the background.
+///
+/// # Example
+///
+/// ```ignore
+/// use skreaver_core::auth::{SqliteBlacklist, TokenBlacklist};
+///
+/// let blacklist = SqliteBlacklist::open("revocations.db")?;
+/// blacklist.revoke("token-jti", 3600).await?;
+/// ```
+#[cfg(feature = "sqlite")]
+#[derive(Clone)]
+pub struct SqliteBlacklist {
+    conn: Arc<std::sync::Mutex<rusqlite::Connection>>,
+    sweep_interval: Option<Duration>,
+}
+
+#[cfg(feature = "sqlite")]
+impl SqliteBlacklist {
+    /// Open (or create) a blacklist stored in the database file at `path`
+    ///
+    /// # Errors
+    ///
+    /// Returns `AuthError::StorageError` if the database cannot be opened or
+    /// the schema cannot be created.
+    pub fn open(path: impl AsRef<std::path::Path>) -> AuthResult<Self> {
+        let conn = rusqlite::Connection::open(path).map_err(|e| {
+            AuthError::StorageError(format!("Failed to open SQLite blacklist: {}", e))
+        })?;
+        Self::from_connection(conn)
+    }
+
+    /// Create a blacklist in a private in-memory database (for testing)
+    ///
+    /// # Errors
+    ///
+    /// Returns `AuthError::StorageError` if the schema cannot be created.
+    pub fn in_memory() -> AuthResult<Self> {
+        let conn = rusqlite::Connection::open_in_memory().map_err(|e| {
+            AuthError::StorageError(format!("Failed to open SQLite blacklist: {}", e))
+        })?;
+        Self::from_connection(conn)
+    }
+
+    fn from_connection(conn: rusqlite::Connection) -> AuthResult<Self> {
+        // Other processes may hold the write lock briefly while revoking
+        conn.busy_timeout(Duration::from_secs(5))
+            .and_then(|()| {
+                conn.execute_batch(
+                    "CREATE TABLE IF NOT EXISTS revoked_tokens (
+                        jti TEXT PRIMARY KEY,
+                        expires_at INTEGER NOT NULL
+                    );
+                    CREATE INDEX IF NOT EXISTS idx_revoked_tokens_expires_at
+                        ON revoked_tokens (expires_at);",
+                )
+            })
+            .map_err(|e| {
+                AuthError::StorageError(format!("Failed to initialize SQLite blacklist: {}", e))
+            })?;
+
+        Ok(Self {
+            conn: Arc::new(std::sync::Mutex::new(conn)),
+            sweep_interval: None,
+        })
+    }
+
+    /// Remove expired entries every `interval` in the background
+    ///
+    /// The sweep runs on the current Tokio runtime and stops once every clone
+    /// of the blacklist has been dropped.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called outside of a Tokio runtime.
+    #[must_use]
+    pub fn with_sweep_interval(mut self, interval: Duration) -> Self {
+        self.sweep_interval = Some(interval);
+        tokio::spawn(Self::run_sweeper(Arc::downgrade(&self.conn), interval));
+        self
+    }
+
+    /// Interval of the background sweep, if one is running
+    #[must_use]
+    pub fn sweep_interval(&self) -> Option<Duration> {
+        self.sweep_interval
+    }
+
+    /// Remove entries whose TTL has passed, returning how many were removed
+    ///
+    /// # Errors
+    ///
+    /// Returns `AuthError::StorageError` if the delete fails.
+    pub async fn sweep_expired(&self) -> AuthResult<usize> {
+        Self::remove_expired(Arc::clone(&self.conn)).await
+    }
+
+    async fn remove_expired(
+        conn: Arc<std::sync::Mutex<rusqlite::Connection>>,
+    ) -> AuthResult<usize> {
+        Self::with_conn(conn, "sweep expired tokens", |conn| {
+            conn.execute(
+                "DELETE FROM revoked_tokens WHERE expires_at <= ?1",
+                [Utc::now().timestamp_millis()],
+            )
+        })
+        .await
+    }
+
+    async fn run_sweeper(conn: Weak<std::sync::Mutex<rusqlite::Connection>>, interval: Duration) {
+        let mut ticker = tokio::time::interval(interval);
+        // The first tick completes immediately
+        ticker.tick().await;
+        loop {
+            ticker.tick().await;
+            let Some(conn) = conn.upgrade() else {
+                break;
+            };
+            match Self::remove_expired(conn).await {
+                Ok(0) => {}
+                Ok(removed) => {
+                    tracing::debug!(removed, "Swept expired tokens from SQLite blacklist");
+                }
+                Err(e) => tracing::warn!("Failed to sweep SQLite blacklist: {}", e),
+            }
+        }
+    }
+
+    /// Run a query on the blocking thread pool
+    async fn with_conn<T, F>(
+        conn: Arc<std::sync::Mutex<rusqlite::Connection>>,
+        action: &'static str,
+        f: F,
+    ) -> AuthResult<T>
+    where
+        T: Send + 'static,
+        F: FnOnce(&rusqlite::Connection) -> rusqlite::Result<T> + Send + 'static,
+    {
+        tokio::task::spawn_blocking(move || {
+            let conn = conn.lock().unwrap_or_else(|e| e.into_inner());
+            f(&conn)
+        })
+        .await
+        .map_err(|e| AuthError::StorageError(format!("Failed to {}: {}", action, e)))?
+        .map_err(|e| AuthError::StorageError(format!("Failed to {}: {}", action, e)))
+    }
+
+    async fn run<T, F>(&self, action: &'static str, f: F) -> AuthResult<T>
+    where
+        T: Send + 'static,
+        F: FnOnce(&rusqlite::Connection) -> rusqlite::Result<T> + Send + 'static,
+    {
+        Self::with_conn(Arc::clone(&self.conn), action, f).await
+    }
+}
+
+#[cfg(feature = "sqlite")]
+#[async_trait]
+impl TokenBlacklist for SqliteBlacklist {
+    async fn revoke(&self, jti: &str, ttl_seconds: i64) -> AuthResult<()> {
+        // A token that has already expired needs no blacklist entry
+        if ttl_seconds <= 0 {
+            return self.remove(jti).await;
+        }
+        let expires_at = Utc::now() + chrono::Duration::seconds(ttl_seconds);
+        self.revoke_until(jti, expires_at).await
+    }
+
+    async fn revoke_until(&self, jti: &str, expires_at: DateTime<Utc>) -> AuthResult<()> {
+        if expires_at <= Utc::now() {
+            return self.remove(jti).await;
+        }
+
+        let jti = jti.to_string();
+        self.run("revoke token", move |conn| {
+            conn.execute(
+                "INSERT INTO revoked_tokens (jti, expires_at) VALUES (?1, ?2)
+                 ON CONFLICT (jti) DO UPDATE SET expires_at = excluded.expires_at",
+                rusqlite::params![jti, expires_at.timestamp_millis()],
+            )
+        })
+        .await?;
+        Ok(())
+    }
+
+    async fn is_revoked(&self, jti: &str) -> AuthResult<bool> {
+        let jti = jti.to_string();
+        self.run("check token", move |conn| {
+            let now = Utc::now().timestamp_millis();
+            conn.execute("DELETE FROM revoked_tokens WHERE expires_at <= ?1", [now])?;
+            conn.query_row(
+                "SELECT EXISTS (SELECT 1 FROM revoked_tokens WHERE jti = ?1 AND expires_at > ?2)",
+                rusqlite::params![jti, now],
+                |row| row.get(0),
+            )
+        })
+        .await
+    }
+
+    async fn remove(&self, jti: &str) -> AuthResult<()> {
+        let jti = jti.to_string();
+        self.run("remove token", move |conn| {
+            conn.execute("DELETE FROM revoked_tokens WHERE jti = ?1", [jti])
+        })
+        .await?;
+        Ok(())
+    }
+
+    async fn clear(&self) -> AuthResult<()> {
+        self.run("clear tokens", |conn| {
+            conn.execute("DELETE FROM revoked_tokens", [])
+        })
+        .await?;
+        Ok(())
+    }
+
+    async fn count(&self) -> AuthResult<usize> {
+        self.run("count tokens", |conn| {
+            conn.query_row(
+                "SELECT COUNT(*) FROM revoked_tokens WHERE expires_at > ?1",
+                [Utc::now().timestamp_millis()],
+                |row| row.get(0),
+            )
+        })
+        .await
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -339,6 +702,54 @@ mod tests {
         assert_eq!(blacklist.count().await.unwrap(), 2);
     }
 
+    #[tokio::test]
+    async fn test_in_memory_blacklist_entry_expires_after_ttl() {
+        let blacklist = InMemoryBlacklist::new();
+        blacklist.revoke("short-lived", 1).await.unwrap();
+        blacklist.revoke("long-lived", 3600).await.unwrap();
+        assert_eq!(blacklist.count().await.unwrap(), 2);
+
+        tokio::time::sleep(Duration::from_millis(1100)).await;
+
+        assert!(!blacklist.is_revoked("short-lived").await.unwrap());
+        assert!(blacklist.is_revoked("long-lived").await.unwrap());
+        assert_eq!(blacklist.count().await.unwrap(), 1);
+
+        assert_eq!(blacklist.sweep_expired().await, 1);
+        assert_eq!(blacklist.tokens.read().await.len(), 1);
+        assert_eq!(blacklist.count().await.unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_in_memory_blacklist_background_sweep() {
+        let blacklist = InMemoryBlacklist::with_sweep_interval(Duration::from_millis(50));
+        assert_eq!(blacklist.sweep_interval(), Some(Duration::from_millis(50)));
+
+        blacklist.revoke("token-1", 1).await.unwrap();
+        // Already expired tokens are never stored
+        blacklist.revoke("token-2", 0).await.unwrap();
+        assert_eq!(blacklist.count().await.unwrap(), 1);
+
+        tokio::time::sleep(Duration::from_millis(1200)).await;
+
+        assert!(blacklist.tokens.read().await.is_empty());
+        assert_eq!(blacklist.count().await.unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_in_memory_blacklist_revoke_until() {
+        let blacklist = InMemoryBlacklist::new();
+
+        let expires_at = Utc::now() + chrono::Duration::hours(1);
+        blacklist.revoke_until("token-1", expires_at).await.unwrap();
+        assert!(blacklist.is_revoked("token-1").await.unwrap());
+
+        let expired_at = Utc::now() - chrono::Duration::seconds(5);
+        blacklist.revoke_until("token-2", expired_at).await.unwrap();
+        assert!(!blacklist.is_revoked("token-2").await.unwrap());
+        assert_eq!(blacklist.count().await.unwrap(), 1);
+    }
+
     #[tokio::test]
     async fn test_in_memory_blacklist_clone() {
         let blacklist1 = InMemoryBlacklist::new();
@@ -352,4 +763,126 @@ mod tests {
         blacklist2.revoke("token-2", 3600).await.unwrap();
         assert!(blacklist1.is_revoked("token-2").await.unwrap());
     }
+
+    #[cfg(feature = "sqlite")]
+    mod sqlite {
+        use super::*;
+
+        fn stored_rows(blacklist: &SqliteBlacklist) -> i64 {
+            blacklist
+                .conn
+                .lock()
+                .unwrap()
+                .query_row("SELECT COUNT(*) FROM revoked_tokens", [], |row| row.get(0))
+                .unwrap()
+        }
+
+        #[tokio::test]
+        async fn test_sqlite_blacklist_revoke_and_check() {
+            let blacklist = SqliteBlacklist::in_memory().unwrap();
+            assert!(!blacklist.is_revoked("token-1").await.unwrap());
+
+            blacklist.revoke("token-1", 3600).await.unwrap();
+            assert!(blacklist.is_revoked("token-1").await.unwrap());
+            assert!(!blacklist.is_revoked("token-2").await.unwrap());
+
+            // Revoking again updates the entry instead of adding one
+            blacklist.revoke("token-1", 7200).await.unwrap();
+            assert_eq!(blacklist.count().await.unwrap(), 1);
+
+            blacklist.remove("token-1").await.unwrap();
+            assert!(!blacklist.is_revoked("token-1").await.unwrap());
+
+            blacklist.revoke("token-2", 3600).await.unwrap();
+            blacklist.clear().await.unwrap();
+            assert_eq!(blacklist.count().await.unwrap(), 0);
+        }
+
+        #[tokio::test]
+        async fn test_sqlite_blacklist_respects_ttl() {
+            let blacklist = SqliteBlacklist::in_memory().unwrap();
+            blacklist.revoke("short-lived", 1).await.unwrap();
+            blacklist.revoke("long-lived", 3600).await.unwrap();
+            // Already expired tokens are never stored
+            blacklist.revoke("expired", 0).await.unwrap();
+            assert_eq!(blacklist.count().await.unwrap(), 2);
+
+            tokio::time::sleep(Duration::from_millis(1100)).await;
+
+            // Expired rows stop counting before they are deleted
+            assert_eq!(blacklist.count().await.unwrap(), 1);
+            assert_eq!(stored_rows(&blacklist), 2);
+
+            // Checking a token deletes expired rows lazily
+            assert!(!blacklist.is_revoked("short-lived").await.unwrap());
+            assert!(blacklist.is_revoked("long-lived").await.unwrap());
+            assert_eq!(stored_rows(&blacklist), 1);
+        }
+
+        #[tokio::test]
+        async fn test_sqlite_blacklist_sweeps_expired_entries() {
+            let blacklist = SqliteBlacklist::in_memory().unwrap();
+            blacklist.revoke("token-1", 1).await.unwrap();
+            blacklist.revoke("token-2", 3600).await.unwrap();
+            tokio::time::sleep(Duration::from_millis(1100)).await;
+
+            assert_eq!(blacklist.sweep_expired().await.unwrap(), 1);
+            assert_eq!(stored_rows(&blacklist), 1);
+
+            let background = SqliteBlacklist::in_memory()
+                .unwrap()
+                .with_sweep_interval(Duration::from_millis(50));
+            assert_eq!(background.sweep_interval(), Some(Duration::from_millis(50)));
+            background.revoke("token-1", 1).await.unwrap();
+            tokio::time::sleep(Duration::from_millis(1200)).await;
+            assert_eq!(stored_rows(&background), 0);
+        }
+
+        #[tokio::test]
+        async fn test_sqlite_blacklist_keeps_revocation_through_leeway() {
+            use crate::auth::{JwtClaims, JwtConfig, JwtManager};
+            use jsonwebtoken::{EncodingKey, Header, encode};
+
+            let config = JwtConfig::default();
+            let blacklist = Arc::new(SqliteBlacklist::in_memory().unwrap());
+            let manager = JwtManager::with_blacklist(config.clone(), blacklist.clone());
+
+            // 30s past `exp` the token still passes validation leeway
+            let now = Utc::now();
+            let claims = JwtClaims {
+                sub: "user-123".to_string(),
+                name: "Test User".to_string(),
+                iss: config.issuer.clone(),
+                aud: config.audience.clone(),
+                exp: (now - chrono::Duration::seconds(30)).timestamp(),
+                iat: (now - chrono::Duration::minutes(20)).timestamp(),
+                nbf: (now - chrono::Duration::minutes(20)).timestamp(),
+                jti: uuid::Uuid::new_v4().to_string(),
+                typ: "refresh".to_string(),
+                roles: vec![],
+                custom: HashMap::new(),
+            };
+            let key = EncodingKey::from_secret(config.secret.as_bytes());
+            let token = encode(&Header::new(config.algorithm), &claims, &key).unwrap();
+
+            manager.revoke(&token).await.unwrap();
+            assert_eq!(stored_rows(&blacklist), 1);
+            assert!(blacklist.is_revoked(&claims.jti).await.unwrap());
+            assert!(manager.refresh(&token).await.is_err());
+        }
+
+        #[tokio::test]
+        async fn test_sqlite_blacklist_shared_between_instances() {
+            let path = std::env::temp_dir().join(format!("blacklist-{}.db", uuid::Uuid::new_v4()));
+            let first = SqliteBlacklist::open(&path).unwrap();
+            let second = SqliteBlacklist::open(&path).unwrap();
+
+            first.revoke("token-1", 3600).await.unwrap();
+            assert!(second.is_revoked("token-1").await.unwrap());
+            assert_eq!(second.count().await.unwrap(), 1);
+
+            drop((first, second));
+            std::fs::remove_file(&path).unwrap();
+        }
+    }
 }
@@ -21,13 +21,16 @@ pub mod storage;
 
 pub use api_key::{Active, ApiKey, ApiKeyConfig, ApiKeyManager, Expired, Key, Revoked};
 pub use jwt::{
-    AccessToken, JwtClaims, JwtConfig, JwtManager, JwtToken, RefreshToken, Token, TokenPair,
+    AccessToken, ClaimMinimization, ClaimSet, ClaimSetStore, InMemoryClaimSetStore, JwtClaims,
+    JwtConfig, JwtManager, JwtToken, KeyMaterial, RefreshToken, Token, TokenPair, TrustedIssuer,
 };
 #[cfg(feature = "redis")]
 pub use jwt_revocation::RedisBlacklist;
-pub use jwt_revocation::{InMemoryBlacklist, TokenBlacklist};
+#[cfg(feature = "sqlite")]
+pub use jwt_revocation::SqliteBlacklist;
+pub use jwt_revocation::{DEFAULT_BLACKLIST_SWEEP_INTERVAL, InMemoryBlacklist, TokenBlacklist};
 pub use middleware::{AuthMiddleware, AuthenticatedRequest, AuthenticationPolicy};
-pub use rbac::{Permission, Role, RoleManager, ToolPolicy};
+pub use rbac::{AuthzDenial, DenialReason, Permission, Role, RoleManager, ToolPolicy};
 pub use storage::{CredentialStorage, EncryptionKey, InMemoryStorage, SecureStorage};
 
 /// Authentication errors
@@ -45,6 +48,12 @@ pub enum AuthError {
     #[error("Invalid token: {0}")]
     InvalidToken(String),
 
+    #[error("Token audience {actual:?} does not include required audience '{required}'")]
+    AudienceMismatch {
+        required: String,
+        actual: Vec<String>,
+    },
+
     #[error("API key not found")]
     ApiKeyNotFound,
 
@@ -248,11 +257,20 @@ impl AuthManager {
 
     /// Check if a tool can be accessed by the principal
     pub fn check_tool_access(&self, tool_name: &str, principal: &Principal) -> bool {
+        self.authorize_tool(principal, tool_name).is_ok()
+    }
+
+    /// Authorize the principal to call a tool, explaining any denial
+    pub fn authorize_tool(
+        &self,
+        principal: &Principal,
+        tool_name: &str,
+    ) -> Result<(), AuthzDenial> {
         let roles = &principal.roles;
         let permissions = roles.iter().flat_map(|role| role.permissions()).collect();
 
         self.role_manager
-            .check_tool_access(tool_name, roles, &permissions)
+            .authorize_tool(tool_name, roles, &permissions)
     }
 
     /// Store a credential securely
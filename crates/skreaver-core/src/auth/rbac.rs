@@ -163,6 +163,30 @@ impl AccessRequirements {
 
         has_required_role && has_required_permissions
     }
+
+    /// Explain why the requirements are not met, or `None` if they are
+    pub fn unmet(&self, roles: &[Role], permissions: &HashSet<Permission>) -> Option<DenialReason> {
+        if !self.required_roles.is_empty() && !self.required_roles.iter().any(|r| roles.contains(r))
+        {
+            return Some(DenialReason::MissingRole {
+                required_any: sorted(self.required_roles.iter().cloned().collect()),
+            });
+        }
+
+        let missing: Vec<Permission> = self
+            .required_permissions
+            .iter()
+            .filter(|p| !permissions.contains(p))
+            .cloned()
+            .collect();
+        if !missing.is_empty() {
+            return Some(DenialReason::MissingPermission {
+                missing: sorted(missing),
+            });
+        }
+
+        None
+    }
 }
 
 impl Default for AccessRequirements {
@@ -303,6 +327,145 @@ impl ToolPolicy {
     }
 }
 
+/// Why access to a tool was denied
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "reason", rename_all = "snake_case")]
+pub enum DenialReason {
+    /// No policy matches the tool (default-deny)
+    NoPolicy,
+    /// A blocking policy matches the tool
+    Blocked {
+        /// Pattern of the blocking policy
+        pattern: String,
+        /// Reason recorded on the policy, if any
+        #[serde(skip_serializing_if = "Option::is_none")]
+        detail: Option<String>,
+    },
+    /// The principal holds none of the roles a policy requires
+    MissingRole {
+        /// Any one of these roles would satisfy the policy
+        required_any: Vec<Role>,
+    },
+    /// The principal lacks permissions a policy requires
+    MissingPermission {
+        /// Permissions that still have to be granted
+        missing: Vec<Permission>,
+    },
+    /// The security configuration disables every capability for the tool
+    CapabilitiesDisabled,
+    /// Emergency lockdown is active and the tool is not exempt
+    Lockdown {
+        /// Tools that remain callable during the lockdown
+        allowed_tools: Vec<String>,
+    },
+}
+
+/// Structured result of a denied tool authorization
+///
+/// Carries the specific [`DenialReason`] so callers can report what failed and
+/// what would satisfy the check instead of a bare `false`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AuthzDenial {
+    /// Tool that was requested
+    pub tool: String,
+    /// Why access was denied
+    #[serde(flatten)]
+    pub reason: DenialReason,
+}
+
+impl AuthzDenial {
+    /// Create a denial for a tool
+    pub fn new(tool: impl Into<String>, reason: DenialReason) -> Self {
+        Self {
+            tool: tool.into(),
+            reason,
+        }
+    }
+
+    /// Description of the denial without the "Permission denied" prefix
+    pub fn message(&self) -> String {
+        match &self.reason {
+            DenialReason::NoPolicy => {
+                format!(
+                    "No access policy grants permission to use tool '{}'.",
+                    self.tool
+                )
+            }
+            DenialReason::Blocked { pattern, detail } => match detail {
+                Some(detail) => format!(
+                    "Tool '{}' is blocked by policy '{}': {}",
+                    self.tool, pattern, detail
+                ),
+                None => format!("Tool '{}' is blocked by policy '{}'.", self.tool, pattern),
+            },
+            DenialReason::MissingRole { required_any } => format!(
+                "Tool '{}' requires one of the roles [{}].",
+                self.tool,
+                join(required_any)
+            ),
+            DenialReason::MissingPermission { missing } => format!(
+                "Tool '{}' requires the permissions [{}].",
+                self.tool,
+                join(missing)
+            ),
+            DenialReason::CapabilitiesDisabled => format!(
+                "Tool '{}' is not allowed by security policy. \
+                 All capabilities (filesystem, HTTP, network) are disabled.",
+                self.tool
+            ),
+            DenialReason::Lockdown { .. } => format!(
+                "System is in emergency lockdown mode. Tool '{}' is not in the allowed list.",
+                self.tool
+            ),
+        }
+    }
+
+    /// What would satisfy the check
+    pub fn remedy(&self) -> String {
+        match &self.reason {
+            DenialReason::NoPolicy => "Add a tool policy that allows this tool.".to_string(),
+            DenialReason::Blocked { pattern, .. } => {
+                format!("Remove the blocking policy '{}'.", pattern)
+            }
+            DenialReason::MissingRole { required_any } => {
+                format!("Grant any of the roles [{}].", join(required_any))
+            }
+            DenialReason::MissingPermission { missing } => {
+                format!("Grant the permissions [{}].", join(missing))
+            }
+            DenialReason::CapabilitiesDisabled => {
+                "Enable a filesystem, HTTP or network capability for this tool.".to_string()
+            }
+            DenialReason::Lockdown { allowed_tools } => format!(
+                "Lift the emergency lockdown or use one of [{}].",
+                allowed_tools.join(", ")
+            ),
+        }
+    }
+}
+
+impl fmt::Display for AuthzDenial {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Permission denied: {}", self.message())
+    }
+}
+
+impl std::error::Error for AuthzDenial {}
+
+fn join<T: fmt::Display>(items: &[T]) -> String {
+    items
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Sort for stable denial output (sets have no order)
+fn sorted<T: fmt::Display>(mut items: Vec<T>) -> Vec<T> {
+    items.sort_by_key(|item| item.to_string());
+    items
+}
+
 /// Role manager for RBAC
 pub struct RoleManager {
     /// Tool access policies
@@ -351,36 +514,66 @@ impl RoleManager {
 
     /// Check if a tool can be accessed
     ///
+    /// Boolean form of [`authorize_tool`](Self::authorize_tool).
+    pub fn check_tool_access(
+        &self,
+        tool_name: &str,
+        roles: &[Role],
+        permissions: &HashSet<Permission>,
+    ) -> bool {
+        self.authorize_tool(tool_name, roles, permissions).is_ok()
+    }
+
+    /// Authorize access to a tool, explaining any denial
+    ///
     /// SECURITY: Uses default-deny pattern. If no explicit policy matches the tool,
     /// access is DENIED. This prevents accidentally exposing dangerous tools that
     /// were added without an accompanying access policy.
-    pub fn check_tool_access(
+    ///
+    /// # Errors
+    ///
+    /// Returns the first failing policy's [`AuthzDenial`]
+    pub fn authorize_tool(
         &self,
         tool_name: &str,
         roles: &[Role],
         permissions: &HashSet<Permission>,
-    ) -> bool {
+    ) -> Result<(), AuthzDenial> {
         // Find all matching policies
-        let matching_policies: Vec<_> = self
+        let mut matching_policies = self
             .tool_policies
             .iter()
             .filter(|p| p.matches(tool_name))
-            .collect();
+            .peekable();
 
         // SECURITY: Default-deny if no policy matches
         // This prevents tools without explicit policies from being accessible
-        if matching_policies.is_empty() {
+        if matching_policies.peek().is_none() {
             tracing::warn!(
                 tool_name = %tool_name,
                 "Tool access DENIED - no policy found (secure default-deny)"
             );
-            return false;
+            return Err(AuthzDenial::new(tool_name, DenialReason::NoPolicy));
         }
 
         // All matching policies must allow access
-        matching_policies
-            .iter()
-            .all(|p| p.is_allowed(roles, permissions))
+        for policy in matching_policies {
+            let reason = match policy {
+                ToolPolicy::Blocked {
+                    tool_pattern,
+                    reason,
+                } => Some(DenialReason::Blocked {
+                    pattern: tool_pattern.clone(),
+                    detail: reason.clone(),
+                }),
+                ToolPolicy::Allowed { requirements, .. } => requirements.unmet(roles, permissions),
+            };
+            if let Some(reason) = reason {
+                return Err(AuthzDenial::new(tool_name, reason));
+            }
+        }
+
+        Ok(())
     }
 
     /// Add a default allow-all policy for tools matching a pattern
@@ -501,4 +694,48 @@ mod tests {
         // Tools NOT matching any pattern should still be denied
         assert!(!manager.check_tool_access("dangerous_exec", &agent_roles, &agent_perms));
     }
+
+    #[test]
+    fn test_authorize_tool_reports_specific_reason() {
+        let mut manager = RoleManager::with_defaults();
+        manager.add_tool_policy(ToolPolicy::blocked_with_reason(
+            "legacy_*".to_string(),
+            "deprecated".to_string(),
+        ));
+
+        let agent_roles = vec![Role::Agent];
+        let agent_perms = Role::Agent.permissions();
+
+        let denial = manager
+            .authorize_tool("shell_exec", &agent_roles, &agent_perms)
+            .unwrap_err();
+        assert_eq!(
+            denial.reason,
+            DenialReason::MissingRole {
+                required_any: vec![Role::Admin]
+            }
+        );
+        assert!(denial.remedy().contains("admin"));
+
+        let denial = manager
+            .authorize_tool("legacy_fetch", &agent_roles, &agent_perms)
+            .unwrap_err();
+        assert!(matches!(denial.reason, DenialReason::Blocked { .. }));
+
+        let denial = manager
+            .authorize_tool("http_get", &[Role::Viewer], &Role::Viewer.permissions())
+            .unwrap_err();
+        assert_eq!(
+            denial.reason,
+            DenialReason::MissingPermission {
+                missing: vec![Permission::ExecuteTool]
+            }
+        );
+
+        let denial = manager
+            .authorize_tool("unknown_tool", &agent_roles, &agent_perms)
+            .unwrap_err();
+        assert_eq!(denial.reason, DenialReason::NoPolicy);
+        assert!(denial.to_string().starts_with("Permission denied"));
+    }
 }
@@ -150,6 +150,44 @@ impl MemoryError {
         }
     }
 
+    /// Create an access denied error for an operation on `key`.
+    pub fn access_denied(
+        key: crate::memory::MemoryKey,
+        operation: MemoryOperation,
+        backend: MemoryBackend,
+        reason: String,
+    ) -> Self {
+        let kind = MemoryErrorKind::AccessDenied { reason };
+        match operation {
+            MemoryOperation::Load => MemoryError::LoadFailed { key, backend, kind },
+            MemoryOperation::Delete => MemoryError::DeleteFailed { key, backend, kind },
+            MemoryOperation::Store => MemoryError::StoreFailed { key, backend, kind },
+            operation => MemoryError::OperationFailed {
+                operation,
+                backend,
+                kind,
+            },
+        }
+    }
+
+    /// Create an error for a write to `key` through a read-only memory.
+    pub fn read_only(
+        key: crate::memory::MemoryKey,
+        operation: MemoryOperation,
+        backend: MemoryBackend,
+    ) -> Self {
+        let kind = MemoryErrorKind::ReadOnly;
+        match operation {
+            MemoryOperation::Delete => MemoryError::DeleteFailed { key, backend, kind },
+            MemoryOperation::Store => MemoryError::StoreFailed { key, backend, kind },
+            operation => MemoryError::OperationFailed {
+                operation,
+                backend,
+                kind,
+            },
+        }
+    }
+
     /// Create a network error.
     pub fn network_error(
         operation: MemoryOperation,
@@ -226,6 +264,16 @@ impl MemoryError {
         }
     }
 
+    /// Check if this error was caused by an access control violation.
+    pub fn is_access_denied(&self) -> bool {
+        matches!(self.kind(), MemoryErrorKind::AccessDenied { .. })
+    }
+
+    /// Check if this error was caused by writing to a read-only memory.
+    pub fn is_read_only(&self) -> bool {
+        matches!(self.kind(), MemoryErrorKind::ReadOnly)
+    }
+
     /// Check if this error is retryable.
     pub fn is_retryable(&self) -> bool {
         match self.kind() {
@@ -237,6 +285,7 @@ impl MemoryError {
             | MemoryErrorKind::KeyNotFound
             | MemoryErrorKind::KeyAlreadyExists
             | MemoryErrorKind::AccessDenied { .. }
+            | MemoryErrorKind::ReadOnly
             | MemoryErrorKind::SerializationError { .. }
             | MemoryErrorKind::IoError { .. }
             | MemoryErrorKind::Timeout { .. } // Timeouts are generally not retryable
@@ -44,6 +44,22 @@ pub enum ToolError {
         duration_ms: u64,
     },
 
+    /// Nested tool calls exceeded the maximum call depth.
+    MaxDepthExceeded {
+        /// Validated tool identifier of the rejected call
+        tool: ToolDispatch,
+        /// Maximum allowed nesting depth
+        max_depth: usize,
+    },
+
+    /// Tool output did not conform to the tool's declared output schema.
+    InvalidOutput {
+        /// Validated tool identifier
+        tool: ToolDispatch,
+        /// Reason why the output was rejected
+        reason: String,
+    },
+
     /// Tool registry is full or cannot accept more tools.
     RegistryFull,
 
@@ -86,6 +102,22 @@ impl fmt::Display for ToolError {
                     duration_ms
                 )
             }
+            ToolError::MaxDepthExceeded { tool, max_depth } => {
+                write!(
+                    f,
+                    "Tool '{}' exceeded the maximum tool call depth of {}",
+                    tool.name(),
+                    max_depth
+                )
+            }
+            ToolError::InvalidOutput { tool, reason } => {
+                write!(
+                    f,
+                    "Tool '{}' returned output that does not match its output schema: {}",
+                    tool.name(),
+                    reason
+                )
+            }
             ToolError::RegistryFull => write!(f, "Tool registry is full"),
             ToolError::InvalidToolId {
                 attempted_name,
@@ -164,13 +196,25 @@ impl ToolError {
         }
     }
 
+    /// Create a MaxDepthExceeded error for a validated tool.
+    pub fn max_depth_exceeded(tool: ToolDispatch, max_depth: usize) -> Self {
+        ToolError::MaxDepthExceeded { tool, max_depth }
+    }
+
+    /// Create an InvalidOutput error for a validated tool.
+    pub fn invalid_output(tool: ToolDispatch, reason: String) -> Self {
+        ToolError::InvalidOutput { tool, reason }
+    }
+
     /// Get the tool dispatch associated with this error, if available.
     pub fn tool(&self) -> Option<&ToolDispatch> {
         match self {
             ToolError::NotFound { tool }
             | ToolError::ExecutionFailed { tool, .. }
             | ToolError::InvalidInput { tool, .. }
-            | ToolError::Timeout { tool, .. } => Some(tool),
+            | ToolError::Timeout { tool, .. }
+            | ToolError::MaxDepthExceeded { tool, .. }
+            | ToolError::InvalidOutput { tool, .. } => Some(tool),
             ToolError::RegistryFull | ToolError::InvalidToolId { .. } => None,
         }
     }
@@ -191,6 +191,9 @@ pub enum MemoryErrorKind {
     /// Backend-specific authentication/authorization
     AccessDenied { reason: String },
 
+    /// Write attempted through a read-only memory
+    ReadOnly,
+
     /// Backend service unavailable
     ServiceUnavailable { retry_after_ms: Option<u64> },
 
@@ -234,6 +237,9 @@ impl fmt::Display for MemoryErrorKind {
             MemoryErrorKind::AccessDenied { reason } => {
                 write!(f, "access denied: {}", reason)
             }
+            MemoryErrorKind::ReadOnly => {
+                write!(f, "memory is read-only")
+            }
             MemoryErrorKind::ServiceUnavailable { retry_after_ms } => {
                 if let Some(retry_ms) = retry_after_ms {
                     write!(f, "service unavailable (retry after {}ms)", retry_ms)
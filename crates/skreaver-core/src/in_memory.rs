@@ -1,9 +1,11 @@
 use dashmap::DashMap;
 use std::collections::HashMap;
 use std::sync::Arc;
+use std::time::Instant;
 
 use crate::memory::{
-    MemoryKey, MemoryReader, MemoryUpdate, MemoryWriter, SnapshotableMemory, TransactionalMemory,
+    ClearableMemory, DeletableMemory, MemoryKey, MemoryReader, MemoryUpdate, MemoryWriter,
+    ScanableMemory, SnapshotableMemory, TransactionalMemory,
 };
 
 /// Fast, transient memory implementation using lock-free DashMap for concurrent access.
@@ -15,6 +17,10 @@ use crate::memory::{
 /// This implementation uses DashMap for lock-free concurrent access, providing
 /// excellent performance under high concurrency with minimal contention.
 ///
+/// Updates with a [`ttl`](MemoryUpdate::ttl) expire after that duration.
+/// Expired entries are evicted lazily when they are next accessed; snapshots
+/// skip them but do not record remaining TTLs.
+///
 /// # Example
 ///
 /// ```rust
@@ -30,6 +36,15 @@ use crate::memory::{
 #[derive(Clone)]
 pub struct InMemoryMemory {
     store: Arc<DashMap<MemoryKey, String>>,
+    expiries: Arc<DashMap<MemoryKey, Instant>>,
+}
+
+impl std::fmt::Debug for InMemoryMemory {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("InMemoryMemory")
+            .field("entries", &self.store.len())
+            .finish()
+    }
 }
 
 impl Default for InMemoryMemory {
@@ -47,14 +62,84 @@ impl InMemoryMemory {
     pub fn new() -> Self {
         Self {
             store: Arc::new(DashMap::new()),
+            expiries: Arc::new(DashMap::new()),
+        }
+    }
+
+    /// Create an independent copy of the current contents.
+    ///
+    /// Unlike `clone`, which shares the underlying storage, writes to the
+    /// fork are not visible to the original and vice versa. Useful as a
+    /// scratch space for dry runs.
+    ///
+    /// # Returns
+    ///
+    /// A new `InMemoryMemory` holding a copy of every stored entry
+    pub fn fork(&self) -> Self {
+        Self {
+            store: Arc::new((*self.store).clone()),
+            expiries: Arc::new((*self.expiries).clone()),
+        }
+    }
+
+    /// Create an independent copy of the entries whose key starts with `prefix`.
+    ///
+    /// Like [`fork`](Self::fork), but leaves every other key behind, so a
+    /// scratch copy of one namespace does not duplicate the rest of a shared
+    /// store. Expired entries are not copied.
+    ///
+    /// # Returns
+    ///
+    /// A new `InMemoryMemory` holding a copy of the matching entries
+    pub fn fork_prefix(&self, prefix: &str) -> Self {
+        let fork = Self::new();
+        for entry in self.store.iter() {
+            let key = entry.key();
+            if !key.as_str().starts_with(prefix) || self.is_expired(key) {
+                continue;
+            }
+            if let Some(expires_at) = self.expiries.get(key) {
+                fork.expiries.insert(key.clone(), *expires_at);
+            }
+            fork.store.insert(key.clone(), entry.value().clone());
         }
+        fork
+    }
+
+    /// Get the value of `key`, evicting it first if it has expired
+    fn get_live(&self, key: &MemoryKey) -> Option<String> {
+        if self.is_expired(key) {
+            self.expiries.remove(key);
+            self.store.remove(key);
+            return None;
+        }
+        self.store.get(key).map(|entry| entry.value().clone())
+    }
+
+    fn is_expired(&self, key: &MemoryKey) -> bool {
+        self.expiries
+            .get(key)
+            .is_some_and(|expires_at| *expires_at <= Instant::now())
+    }
+
+    fn insert(&self, update: MemoryUpdate) {
+        match update.ttl {
+            Some(ttl) => {
+                self.expiries
+                    .insert(update.key.clone(), Instant::now() + ttl);
+            }
+            None => {
+                self.expiries.remove(&update.key);
+            }
+        }
+        self.store.insert(update.key, update.value);
     }
 }
 
 // Implement new trait hierarchy
 impl MemoryReader for InMemoryMemory {
     fn load(&self, key: &MemoryKey) -> Result<Option<String>, crate::error::MemoryError> {
-        Ok(self.store.get(key).map(|entry| entry.value().clone()))
+        Ok(self.get_live(key))
     }
 
     fn load_many(
@@ -68,7 +153,7 @@ impl MemoryReader for InMemoryMemory {
         // Pre-allocate result vector with exact capacity
         let mut result = Vec::with_capacity(keys.len());
         for key in keys {
-            result.push(self.store.get(key).map(|entry| entry.value().clone()));
+            result.push(self.get_live(key));
         }
         Ok(result)
     }
@@ -76,7 +161,7 @@ impl MemoryReader for InMemoryMemory {
 
 impl MemoryWriter for InMemoryMemory {
     fn store(&mut self, update: MemoryUpdate) -> Result<(), crate::error::MemoryError> {
-        self.store.insert(update.key, update.value);
+        self.insert(update);
         Ok(())
     }
 
@@ -87,39 +172,64 @@ impl MemoryWriter for InMemoryMemory {
 
         // DashMap handles concurrent access internally
         for update in updates {
-            self.store.insert(update.key, update.value);
+            self.insert(update);
         }
         Ok(())
     }
 }
 
+impl ScanableMemory for InMemoryMemory {
+    fn scan_prefix(&self, prefix: &str) -> Result<Vec<MemoryKey>, crate::error::MemoryError> {
+        let matching: Vec<MemoryKey> = self
+            .store
+            .iter()
+            .filter(|entry| entry.key().as_str().starts_with(prefix))
+            .map(|entry| entry.key().clone())
+            .collect();
+        Ok(matching
+            .into_iter()
+            .filter(|key| self.get_live(key).is_some())
+            .collect())
+    }
+}
+
+impl ClearableMemory for InMemoryMemory {
+    fn clear(&mut self) -> Result<(), crate::error::MemoryError> {
+        self.store.clear();
+        self.expiries.clear();
+        Ok(())
+    }
+}
+
+impl DeletableMemory for InMemoryMemory {
+    fn delete(&mut self, key: &MemoryKey) -> Result<bool, crate::error::MemoryError> {
+        let expired = self.is_expired(key);
+        self.expiries.remove(key);
+        Ok(self.store.remove(key).is_some() && !expired)
+    }
+}
+
 impl TransactionalMemory for InMemoryMemory {
     fn transaction<F, R>(&mut self, f: F) -> Result<R, crate::error::TransactionError>
     where
         F: FnOnce(&mut dyn MemoryWriter) -> Result<R, crate::error::TransactionError>,
     {
-        // For DashMap-based InMemoryMemory, we snapshot current state
-        let original_state: HashMap<MemoryKey, String> = self
-            .store
-            .iter()
-            .map(|entry| (entry.key().clone(), entry.value().clone()))
-            .collect();
-
-        // Create a temporary transaction memory with the same state
-        let mut tx_memory = InMemoryMemory::new();
-        for (key, value) in &original_state {
-            tx_memory.store.insert(key.clone(), value.clone());
-        }
+        // Run the transaction against an independent copy of the current state
+        let mut tx_memory = self.fork();
 
         // Execute the transaction
         match f(&mut tx_memory) {
             Ok(result) => {
                 // Commit: replace our store with the transaction store
                 self.store.clear();
+                self.expiries.clear();
                 for entry in tx_memory.store.iter() {
                     self.store
                         .insert(entry.key().clone(), entry.value().clone());
                 }
+                for entry in tx_memory.expiries.iter() {
+                    self.expiries.insert(entry.key().clone(), *entry.value());
+                }
                 Ok(result)
             }
             Err(err) => {
@@ -136,6 +246,7 @@ impl SnapshotableMemory for InMemoryMemory {
         let serializable_store: HashMap<String, String> = self
             .store
             .iter()
+            .filter(|entry| !self.is_expired(entry.key()))
             .map(|entry| (entry.key().as_str().to_string(), entry.value().clone()))
             .collect();
 
@@ -156,6 +267,7 @@ impl SnapshotableMemory for InMemoryMemory {
 
         // Clear and populate the DashMap
         self.store.clear();
+        self.expiries.clear();
         for (key_str, value) in serializable_store {
             let memory_key =
                 MemoryKey::new(&key_str).map_err(|e| crate::error::MemoryError::RestoreFailed {
@@ -170,3 +282,78 @@ impl SnapshotableMemory for InMemoryMemory {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn expired_entries_are_evicted_on_access() {
+        let mut memory = InMemoryMemory::new();
+        let scratch = MemoryKey::new("scratch").unwrap();
+        let durable = MemoryKey::new("durable").unwrap();
+        memory
+            .store(
+                MemoryUpdate::new("scratch", "draft")
+                    .unwrap()
+                    .with_ttl(Duration::from_millis(20)),
+            )
+            .unwrap();
+        memory
+            .store(MemoryUpdate::new("durable", "kept").unwrap())
+            .unwrap();
+        assert_eq!(memory.load(&scratch).unwrap(), Some("draft".to_string()));
+
+        std::thread::sleep(Duration::from_millis(40));
+
+        assert_eq!(memory.load(&scratch).unwrap(), None);
+        assert!(!memory.store.contains_key(&scratch));
+        assert_eq!(
+            memory
+                .load_many(&[scratch.clone(), durable.clone()])
+                .unwrap(),
+            vec![None, Some("kept".to_string())]
+        );
+        assert_eq!(memory.scan_prefix("").unwrap(), vec![durable]);
+    }
+
+    #[test]
+    fn storing_without_ttl_makes_key_permanent() {
+        let mut memory = InMemoryMemory::new();
+        let key = MemoryKey::new("scratch").unwrap();
+        memory
+            .store(
+                MemoryUpdate::new("scratch", "draft")
+                    .unwrap()
+                    .with_ttl(Duration::from_millis(20)),
+            )
+            .unwrap();
+        memory
+            .store(MemoryUpdate::new("scratch", "final").unwrap())
+            .unwrap();
+
+        std::thread::sleep(Duration::from_millis(40));
+
+        assert_eq!(memory.load(&key).unwrap(), Some("final".to_string()));
+    }
+
+    #[test]
+    fn fork_prefix_copies_only_matching_keys() {
+        let mut memory = InMemoryMemory::new();
+        for (key, value) in [("a:draft", "mine"), ("b:draft", "theirs")] {
+            memory
+                .store(MemoryUpdate::new(key, value).unwrap())
+                .unwrap();
+        }
+
+        let mut fork = memory.fork_prefix("a:");
+        fork.store(MemoryUpdate::new("a:draft", "edited").unwrap())
+            .unwrap();
+
+        assert_eq!(fork.scan_prefix("").unwrap().len(), 1);
+        let draft = MemoryKey::new("a:draft").unwrap();
+        assert_eq!(fork.load(&draft).unwrap(), Some("edited".to_string()));
+        assert_eq!(memory.load(&draft).unwrap(), Some("mine".to_string()));
+    }
+}
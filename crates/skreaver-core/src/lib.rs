@@ -12,6 +12,7 @@ pub mod identifiers;
 pub mod in_memory;
 pub mod memory;
 pub mod metadata;
+pub mod normalization;
 pub mod sanitization;
 pub mod security;
 pub mod structured_tool_result;
@@ -26,9 +27,12 @@ pub use database::{
 pub use error::{SkreverError, SkreverResult};
 pub use in_memory::InMemoryMemory;
 pub use memory::{
-    MemoryKey, MemoryReader, MemoryUpdate, MemoryWriter, SnapshotableMemory, TransactionalMemory,
+    ClearableMemory, DeletableMemory, MemoryBundle, MemoryKey, MemoryReader, MemorySnapshot,
+    MemoryUpdate, MemoryWriter, ReadOnlyMemory, ReadOnlyWrites, ScanableMemory, SnapshotableMemory,
+    TransactionalMemory,
 };
 pub use metadata::{Metadata, MetadataBuilder, MetadataError, MetadataKey, MetadataValue};
+pub use normalization::{NormalizationPipeline, Normalize, NormalizedObservation};
 pub use sanitization::{
     ContentSanitizer, DatabaseErrorSanitizer, SanitizeError, SanitizeIdentifier, SecretRedactor,
 };
@@ -58,9 +62,12 @@ pub use validation::ValidationError;
 pub use auth::{
     AuthContext, AuthError, AuthManager, AuthMethod, AuthResult, Principal,
     api_key::{ApiKey, ApiKeyConfig, ApiKeyManager},
-    jwt::{JwtClaims, JwtConfig, JwtManager, JwtToken},
+    jwt::{
+        ClaimMinimization, ClaimSetStore, JwtClaims, JwtConfig, JwtManager, JwtToken, KeyMaterial,
+        TrustedIssuer,
+    },
     middleware::{AuthMiddleware, AuthenticatedRequest, AuthenticationPolicy},
-    rbac::{Permission, Role, RoleManager, ToolPolicy},
+    rbac::{AuthzDenial, DenialReason, Permission, Role, RoleManager, ToolPolicy},
     storage::{CredentialStorage, InMemoryStorage, SecureStorage},
 };
 
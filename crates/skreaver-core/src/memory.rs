@@ -1,5 +1,12 @@
+pub mod bundle;
 pub mod keys;
+pub mod read_only;
+pub use bundle::{
+    BUNDLE_FORMAT_VERSION, BundleError, MemoryBundle, MemorySnapshot, export_memory, import_memory,
+    import_memory_atomically, replace_memory,
+};
 pub use keys::MemoryKeys;
+pub use read_only::{ReadOnlyMemory, ReadOnlyWrites};
 
 /// Validated memory key that prevents typos and ensures consistent naming.
 ///
@@ -203,6 +210,14 @@ pub struct MemoryUpdate {
     /// Values can be any string data - plain text, JSON, serialized objects, etc.
     /// The format depends on the agent's requirements and implementation.
     pub value: String,
+
+    /// How long the value lives before it expires, if it is ephemeral.
+    ///
+    /// Backends with expiry support (in-memory, Redis, SQLite, PostgreSQL)
+    /// stop returning the value once it has expired; storing the key again
+    /// without a TTL makes it permanent. Backends that document no TTL
+    /// support store the value permanently.
+    pub ttl: Option<std::time::Duration>,
 }
 
 impl MemoryUpdate {
@@ -222,6 +237,7 @@ impl MemoryUpdate {
         Ok(Self {
             key: MemoryKey::new(key)?,
             value: value.to_string(),
+            ttl: None,
         })
     }
 
@@ -238,7 +254,11 @@ impl MemoryUpdate {
     ///
     /// A new `MemoryUpdate` instance
     pub fn from_validated(key: MemoryKey, value: String) -> Self {
-        Self { key, value }
+        Self {
+            key,
+            value,
+            ttl: None,
+        }
     }
 
     /// Create a new MemoryUpdate from owned strings with validation.
@@ -255,8 +275,27 @@ impl MemoryUpdate {
         Ok(Self {
             key: MemoryKey::new(&key)?,
             value,
+            ttl: None,
         })
     }
+
+    /// Make the update expire after `ttl`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use skreaver_core::MemoryUpdate;
+    ///
+    /// let update = MemoryUpdate::new("scratch", "draft")
+    ///     .unwrap()
+    ///     .with_ttl(Duration::from_secs(60));
+    /// assert_eq!(update.ttl, Some(Duration::from_secs(60)));
+    /// ```
+    pub fn with_ttl(mut self, ttl: std::time::Duration) -> Self {
+        self.ttl = Some(ttl);
+        self
+    }
 }
 
 /// Read-only memory operations trait.
@@ -302,9 +341,11 @@ pub trait MemoryReader: Send + Sync {
 
     /// Load multiple values from memory by their keys (batch operation).
     ///
-    /// Returns a vector of optional values in the same order as the input keys.
-    /// This is more efficient than multiple individual `load` calls for backends
-    /// that support batch operations.
+    /// Returns a vector of optional values in the same order as the input keys,
+    /// with `None` for keys that are not found; repeated keys yield repeated
+    /// results. The default implementation calls [`load`](Self::load) for each
+    /// key; backends that support batch reads should override it to fetch all
+    /// keys in one round trip.
     ///
     /// # Parameters
     ///
@@ -358,8 +399,11 @@ pub trait MemoryWriter: Send + Sync {
 
     /// Store multiple key-value pairs in memory (batch operation).
     ///
-    /// This is more efficient than multiple individual `store` calls for backends
-    /// that support batch operations. The operation should be atomic where possible.
+    /// Updates are applied in order, so when a key appears more than once the
+    /// last update wins. The default implementation calls [`store`](Self::store)
+    /// for each update and stops at the first failure; backends that support
+    /// batch writes should override it to write all updates in one round trip.
+    /// The operation should be atomic where possible.
     ///
     /// # Parameters
     ///
@@ -473,10 +517,11 @@ pub trait TransactionalMemory: MemoryReader + MemoryWriter {
 ///
 /// let mut memory = ExampleMemory { store: HashMap::new() };
 /// let data_key = MemoryKey::new("data").unwrap();
-/// MemoryWriter::store(&mut memory, MemoryUpdate {
-///     key: data_key.clone(),
-///     value: "important".to_string(),
-/// }).unwrap();
+/// MemoryWriter::store(
+///     &mut memory,
+///     MemoryUpdate::from_validated(data_key.clone(), "important".to_string()),
+/// )
+/// .unwrap();
 ///
 /// // Create a snapshot
 /// let snapshot = memory.snapshot().unwrap();
@@ -511,6 +556,78 @@ pub trait SnapshotableMemory: Send + Sync {
     ///
     /// `Ok(())` if successful, `Err(MemoryError)` if restoration fails
     fn restore(&mut self, snapshot: &str) -> Result<(), crate::error::MemoryError>;
+
+    /// Export every key/value pair as a versioned [`MemorySnapshot`].
+    ///
+    /// Unlike [`snapshot`](Self::snapshot), whose format is specific to the
+    /// backend, the result can be imported into any other backend.
+    fn export_snapshot(&self) -> Result<MemorySnapshot, BundleError>
+    where
+        Self: ScanableMemory + Sized,
+    {
+        export_memory(self)
+    }
+
+    /// Bulk-load a [`MemorySnapshot`], merging it into the current contents.
+    ///
+    /// Snapshots with an unsupported format version are rejected. The default
+    /// writes every entry with one [`store_many`](MemoryWriter::store_many)
+    /// call; backends whose batch writes are not atomic override it to load
+    /// inside a [`TransactionalMemory`] transaction, so a failed import leaves
+    /// no keys behind.
+    fn import_snapshot(&mut self, snapshot: MemorySnapshot) -> Result<(), BundleError>
+    where
+        Self: MemoryWriter + Sized,
+    {
+        import_memory(self, &snapshot)
+    }
+}
+
+/// Key enumeration trait for memory backends.
+///
+/// Backends implementing this trait can list the keys they hold, which
+/// enables backend-independent tooling such as [`bundle::export_memory`].
+pub trait ScanableMemory: MemoryReader {
+    /// List all keys starting with `prefix`.
+    ///
+    /// An empty prefix lists every key. The order of returned keys is
+    /// unspecified.
+    ///
+    /// # Parameters
+    ///
+    /// * `prefix` - The key prefix to match
+    ///
+    /// # Returns
+    ///
+    /// `Ok(keys)` with all matching keys, `Err(MemoryError)` on failure
+    fn scan_prefix(&self, prefix: &str) -> Result<Vec<MemoryKey>, crate::error::MemoryError>;
+}
+
+/// Bulk removal trait for memory backends.
+///
+/// Used when memory contents must be replaced wholesale, such as
+/// [`bundle::replace_memory`].
+pub trait ClearableMemory: MemoryWriter {
+    /// Remove every key held by this memory.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` if successful, `Err(MemoryError)` if the operation fails
+    fn clear(&mut self) -> Result<(), crate::error::MemoryError>;
+}
+
+/// Single-key removal trait for memory backends.
+///
+/// Enables backend-independent maintenance such as expiring keys whose
+/// time-to-live has passed.
+pub trait DeletableMemory: MemoryWriter {
+    /// Remove `key` and its value.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(true)` if the key existed, `Ok(false)` if it did not,
+    /// `Err(MemoryError)` if the operation fails
+    fn delete(&mut self, key: &MemoryKey) -> Result<bool, crate::error::MemoryError>;
 }
 
 #[cfg(test)]
@@ -592,6 +709,51 @@ mod tests {
         assert_eq!(MemoryReader::load(&mem, &key).unwrap(), Some("bar".into()));
     }
 
+    /// Backend that only implements the single-key operations
+    #[derive(Default)]
+    struct SingleKeyMemory {
+        store: HashMap<String, String>,
+        loads: std::sync::atomic::AtomicUsize,
+    }
+
+    impl MemoryReader for SingleKeyMemory {
+        fn load(&self, key: &MemoryKey) -> Result<Option<String>, crate::error::MemoryError> {
+            self.loads
+                .fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+            Ok(self.store.get(key.as_str()).cloned())
+        }
+    }
+
+    impl MemoryWriter for SingleKeyMemory {
+        fn store(&mut self, update: MemoryUpdate) -> Result<(), crate::error::MemoryError> {
+            self.store
+                .insert(update.key.as_str().to_string(), update.value);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn default_batch_operations_fall_back_to_single_keys() {
+        let mut mem = SingleKeyMemory::default();
+        mem.store_many(vec![
+            MemoryUpdate::new("b", "1").unwrap(),
+            MemoryUpdate::new("a", "2").unwrap(),
+            MemoryUpdate::new("b", "3").unwrap(),
+        ])
+        .unwrap();
+
+        let keys: Vec<MemoryKey> = ["b", "missing", "a", "b"]
+            .into_iter()
+            .map(|key| MemoryKey::new(key).unwrap())
+            .collect();
+        assert_eq!(
+            mem.load_many(&keys).unwrap(),
+            vec![Some("3".into()), None, Some("2".into()), Some("3".into())]
+        );
+        assert!(mem.load_many(&[]).unwrap().is_empty());
+        assert_eq!(mem.loads.into_inner(), 4);
+    }
+
     #[test]
     fn memory_can_snapshot_and_restore() {
         let mut mem = DummyMemory {
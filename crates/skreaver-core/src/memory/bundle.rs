@@ -0,0 +1,396 @@
+//! Portable memory bundles for backup and agent seeding.
+//!
+//! A [`MemoryBundle`] is a versioned, backend-independent JSON document
+//! holding every key/value pair of a memory store, plus optional metadata.
+//! Bundles are produced by [`export_memory`] and consumed by
+//! [`import_memory`] (merge) or [`replace_memory`] (replace).
+//! [`import_memory_atomically`] merges inside a transaction, so a failed
+//! import leaves no keys behind.
+//!
+//! # Example
+//!
+//! ```rust
+//! use skreaver_core::InMemoryMemory;
+//! use skreaver_core::memory::{MemoryKey, MemoryUpdate, MemoryWriter, MemoryReader};
+//! use skreaver_core::memory::bundle::{export_memory, import_memory, MemoryBundle};
+//!
+//! let mut source = InMemoryMemory::new();
+//! source
+//!     .store(MemoryUpdate::new("greeting", "hello").unwrap())
+//!     .unwrap();
+//!
+//! let json = export_memory(&source).unwrap().to_json().unwrap();
+//!
+//! let mut target = InMemoryMemory::new();
+//! import_memory(&mut target, &MemoryBundle::from_json(&json).unwrap()).unwrap();
+//!
+//! let key = MemoryKey::new("greeting").unwrap();
+//! assert_eq!(target.load(&key).unwrap(), Some("hello".to_string()));
+//! ```
+
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::{
+    ClearableMemory, MemoryKey, MemoryUpdate, MemoryWriter, ScanableMemory, TransactionalMemory,
+};
+use crate::error::{MemoryError, TransactionError};
+
+/// Current bundle format version written by [`export_memory`].
+pub const BUNDLE_FORMAT_VERSION: u32 = 1;
+
+/// Errors that can occur when reading or applying a memory bundle.
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum BundleError {
+    /// Bundle could not be serialized or parsed as JSON.
+    #[error("Invalid memory bundle JSON: {0}")]
+    Serialization(String),
+
+    /// Bundle was written with a format version this build cannot read.
+    #[error("Unsupported memory bundle version {found} (supported: {supported})")]
+    UnsupportedVersion { found: u32, supported: u32 },
+
+    /// Bundle contains a key that is not a valid memory key.
+    #[error("Invalid key '{key}' in memory bundle: {reason}")]
+    InvalidKey { key: String, reason: String },
+
+    /// The underlying memory backend failed.
+    #[error(transparent)]
+    Memory(#[from] MemoryError),
+
+    /// The import transaction failed and was rolled back.
+    #[error(transparent)]
+    Transaction(TransactionError),
+}
+
+impl From<TransactionError> for BundleError {
+    fn from(error: TransactionError) -> Self {
+        match error {
+            TransactionError::MemoryError(error) => Self::Memory(error),
+            other => Self::Transaction(other),
+        }
+    }
+}
+
+/// Versioned, backend-independent snapshot of a memory store.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MemoryBundle {
+    /// Bundle format version.
+    pub version: u32,
+    /// When the bundle was created.
+    pub exported_at: DateTime<Utc>,
+    /// Free-form metadata (agent id, source backend, notes, ...).
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub metadata: BTreeMap<String, serde_json::Value>,
+    /// Stored key/value pairs, sorted by key.
+    pub entries: BTreeMap<String, String>,
+}
+
+impl MemoryBundle {
+    /// Create an empty bundle at the current format version.
+    pub fn new() -> Self {
+        Self {
+            version: BUNDLE_FORMAT_VERSION,
+            exported_at: Utc::now(),
+            metadata: BTreeMap::new(),
+            entries: BTreeMap::new(),
+        }
+    }
+
+    /// Attach a metadata entry to the bundle.
+    pub fn with_metadata(mut self, key: impl Into<String>, value: serde_json::Value) -> Self {
+        self.metadata.insert(key.into(), value);
+        self
+    }
+
+    /// Number of entries in the bundle.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Check whether the bundle holds no entries.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Serialize the bundle to pretty-printed JSON.
+    pub fn to_json(&self) -> Result<String, BundleError> {
+        serde_json::to_string_pretty(self).map_err(|e| BundleError::Serialization(e.to_string()))
+    }
+
+    /// Parse a bundle from JSON, rejecting unsupported format versions.
+    pub fn from_json(json: &str) -> Result<Self, BundleError> {
+        let bundle: Self =
+            serde_json::from_str(json).map_err(|e| BundleError::Serialization(e.to_string()))?;
+        bundle.validate()?;
+        Ok(bundle)
+    }
+
+    fn validate(&self) -> Result<(), BundleError> {
+        if self.version == 0 || self.version > BUNDLE_FORMAT_VERSION {
+            return Err(BundleError::UnsupportedVersion {
+                found: self.version,
+                supported: BUNDLE_FORMAT_VERSION,
+            });
+        }
+        Ok(())
+    }
+
+    fn updates(&self) -> Result<Vec<MemoryUpdate>, BundleError> {
+        self.entries
+            .iter()
+            .map(|(key, value)| {
+                MemoryUpdate::new(key, value).map_err(|e| BundleError::InvalidKey {
+                    key: key.clone(),
+                    reason: e.to_string(),
+                })
+            })
+            .collect()
+    }
+}
+
+/// Versioned snapshot produced by [`SnapshotableMemory::export_snapshot`].
+///
+/// [`SnapshotableMemory::export_snapshot`]: super::SnapshotableMemory::export_snapshot
+pub type MemorySnapshot = MemoryBundle;
+
+impl Default for MemoryBundle {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Export every key held by `memory` into a portable bundle.
+pub fn export_memory(memory: &dyn ScanableMemory) -> Result<MemoryBundle, BundleError> {
+    let keys: Vec<MemoryKey> = memory.scan_prefix("")?;
+    let values = memory.load_many(&keys)?;
+
+    let mut bundle = MemoryBundle::new();
+    for (key, value) in keys.into_iter().zip(values) {
+        // A key may disappear between scan and load; skip it rather than fail
+        if let Some(value) = value {
+            bundle.entries.insert(key.as_str().to_string(), value);
+        }
+    }
+    Ok(bundle)
+}
+
+/// Import a bundle by merging it into `memory`.
+///
+/// Bundle entries overwrite existing values with the same key; keys not
+/// present in the bundle are left untouched.
+pub fn import_memory(
+    memory: &mut dyn MemoryWriter,
+    bundle: &MemoryBundle,
+) -> Result<(), BundleError> {
+    bundle.validate()?;
+    let updates = bundle.updates()?;
+    memory.store_many(updates)?;
+    Ok(())
+}
+
+/// Import a bundle by merging it into `memory` within one transaction.
+///
+/// Either every bundle entry is stored or, if any write fails, none are.
+pub fn import_memory_atomically<M: TransactionalMemory>(
+    memory: &mut M,
+    bundle: &MemoryBundle,
+) -> Result<(), BundleError> {
+    bundle.validate()?;
+    let updates = bundle.updates()?;
+    memory.transaction(|writer| {
+        writer
+            .store_many(updates)
+            .map_err(TransactionError::MemoryError)
+    })?;
+    Ok(())
+}
+
+/// Import a bundle by replacing the entire contents of `memory`.
+///
+/// All bundle keys are validated before the memory is cleared, so an
+/// invalid bundle leaves the existing contents intact.
+pub fn replace_memory(
+    memory: &mut dyn ClearableMemory,
+    bundle: &MemoryBundle,
+) -> Result<(), BundleError> {
+    bundle.validate()?;
+    let updates = bundle.updates()?;
+    memory.clear()?;
+    memory.store_many(updates)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::InMemoryMemory;
+    use crate::memory::{MemoryReader, SnapshotableMemory};
+
+    fn memory_with(entries: &[(&str, &str)]) -> InMemoryMemory {
+        let mut memory = InMemoryMemory::new();
+        for (key, value) in entries {
+            memory
+                .store(MemoryUpdate::new(key, value).unwrap())
+                .unwrap();
+        }
+        memory
+    }
+
+    fn load(memory: &InMemoryMemory, key: &str) -> Option<String> {
+        memory.load(&MemoryKey::new(key).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_export_round_trips_through_json() {
+        let source = memory_with(&[("a", "1"), ("b", "{\"nested\": [1, 2]}"), ("c", "")]);
+
+        let bundle = export_memory(&source)
+            .unwrap()
+            .with_metadata("agent_id", serde_json::json!("agent-1"));
+        let parsed = MemoryBundle::from_json(&bundle.to_json().unwrap()).unwrap();
+
+        assert_eq!(parsed, bundle);
+        assert_eq!(parsed.len(), 3);
+        assert_eq!(parsed.entries["b"], "{\"nested\": [1, 2]}");
+        assert_eq!(parsed.metadata["agent_id"], "agent-1");
+    }
+
+    #[test]
+    fn test_import_merges_with_existing_keys() {
+        let bundle = export_memory(&memory_with(&[("a", "new"), ("b", "2")])).unwrap();
+        let mut target = memory_with(&[("a", "old"), ("keep", "yes")]);
+
+        import_memory(&mut target, &bundle).unwrap();
+
+        assert_eq!(load(&target, "a"), Some("new".to_string()));
+        assert_eq!(load(&target, "b"), Some("2".to_string()));
+        assert_eq!(load(&target, "keep"), Some("yes".to_string()));
+    }
+
+    #[test]
+    fn test_replace_drops_keys_not_in_bundle() {
+        let bundle = export_memory(&memory_with(&[("a", "new")])).unwrap();
+        let mut target = memory_with(&[("a", "old"), ("drop", "me")]);
+
+        replace_memory(&mut target, &bundle).unwrap();
+
+        assert_eq!(load(&target, "a"), Some("new".to_string()));
+        assert_eq!(load(&target, "drop"), None);
+    }
+
+    #[test]
+    fn test_rejects_unsupported_version() {
+        let mut bundle = MemoryBundle::new();
+        bundle.version = BUNDLE_FORMAT_VERSION + 1;
+        let json = serde_json::to_string(&bundle).unwrap();
+
+        assert!(matches!(
+            MemoryBundle::from_json(&json),
+            Err(BundleError::UnsupportedVersion { .. })
+        ));
+    }
+
+    #[test]
+    fn test_invalid_key_leaves_memory_untouched() {
+        let mut bundle = MemoryBundle::new();
+        bundle
+            .entries
+            .insert("bad key!".to_string(), "x".to_string());
+        let mut target = memory_with(&[("keep", "yes")]);
+
+        assert!(matches!(
+            replace_memory(&mut target, &bundle),
+            Err(BundleError::InvalidKey { .. })
+        ));
+        assert_eq!(load(&target, "keep"), Some("yes".to_string()));
+    }
+
+    #[test]
+    fn test_snapshot_moves_memory_between_instances() {
+        let source = memory_with(&[("a", "1"), ("b", "2")]);
+        let snapshot = source.export_snapshot().unwrap();
+        assert_eq!(snapshot.version, BUNDLE_FORMAT_VERSION);
+
+        let mut target = memory_with(&[("keep", "yes")]);
+        target
+            .import_snapshot(MemorySnapshot::from_json(&snapshot.to_json().unwrap()).unwrap())
+            .unwrap();
+
+        assert_eq!(load(&target, "a"), Some("1".to_string()));
+        assert_eq!(load(&target, "b"), Some("2".to_string()));
+        assert_eq!(load(&target, "keep"), Some("yes".to_string()));
+    }
+
+    /// Transactional memory whose writes fail once they reach `poison`
+    struct PoisonedMemory {
+        inner: InMemoryMemory,
+        poison: &'static str,
+    }
+
+    impl MemoryReader for PoisonedMemory {
+        fn load(&self, key: &MemoryKey) -> Result<Option<String>, MemoryError> {
+            self.inner.load(key)
+        }
+        fn load_many(&self, keys: &[MemoryKey]) -> Result<Vec<Option<String>>, MemoryError> {
+            self.inner.load_many(keys)
+        }
+    }
+
+    impl MemoryWriter for PoisonedMemory {
+        fn store(&mut self, update: MemoryUpdate) -> Result<(), MemoryError> {
+            if update.key.as_str() == self.poison {
+                return Err(MemoryError::StoreFailed {
+                    key: update.key,
+                    backend: crate::error::MemoryBackend::InMemory,
+                    kind: crate::error::MemoryErrorKind::IoError {
+                        details: "disk full".to_string(),
+                    },
+                });
+            }
+            self.inner.store(update)
+        }
+        fn store_many(&mut self, updates: Vec<MemoryUpdate>) -> Result<(), MemoryError> {
+            updates
+                .into_iter()
+                .try_for_each(|update| self.store(update))
+        }
+    }
+
+    impl TransactionalMemory for PoisonedMemory {
+        fn transaction<F, R>(&mut self, f: F) -> Result<R, TransactionError>
+        where
+            F: FnOnce(&mut dyn MemoryWriter) -> Result<R, TransactionError>,
+        {
+            // Stage writes on a fork and apply them only if `f` succeeds
+            let mut staged = PoisonedMemory {
+                inner: self.inner.fork(),
+                poison: self.poison,
+            };
+            let result = f(&mut staged)?;
+            self.inner = staged.inner;
+            Ok(result)
+        }
+    }
+
+    #[test]
+    fn test_atomic_import_leaves_no_partial_writes() {
+        let mut bundle = MemoryBundle::new();
+        for key in ["a", "b", "c"] {
+            bundle.entries.insert(key.to_string(), "v".to_string());
+        }
+        let mut target = PoisonedMemory {
+            inner: memory_with(&[("keep", "yes")]),
+            poison: "c",
+        };
+
+        let err = import_memory_atomically(&mut target, &bundle).unwrap_err();
+
+        assert!(matches!(err, BundleError::Memory(_)));
+        assert_eq!(load(&target.inner, "a"), None);
+        assert_eq!(load(&target.inner, "b"), None);
+        assert_eq!(load(&target.inner, "keep"), Some("yes".to_string()));
+    }
+}
@@ -0,0 +1,196 @@
+//! Read-only memory views.
+//!
+//! [`ReadOnlyMemory`] wraps any backend so that reads go through untouched
+//! while writes never reach it. This is meant for replay, debugging and
+//! analytics agents that share state with other agents but must never change
+//! it. Depending on its [`ReadOnlyWrites`] policy the wrapper either rejects
+//! writes with a read-only [`MemoryError`] or silently drops them.
+//!
+//! # Example
+//!
+//! ```rust
+//! use skreaver_core::InMemoryMemory;
+//! use skreaver_core::memory::{MemoryKey, MemoryReader, MemoryUpdate, MemoryWriter};
+//! use skreaver_core::memory::read_only::ReadOnlyMemory;
+//!
+//! let mut shared = InMemoryMemory::new();
+//! shared.store(MemoryUpdate::new("plan", "step 1").unwrap()).unwrap();
+//!
+//! let mut view = ReadOnlyMemory::new(shared);
+//! let key = MemoryKey::new("plan").unwrap();
+//! assert_eq!(view.load(&key).unwrap(), Some("step 1".to_string()));
+//!
+//! let err = view.store(MemoryUpdate::new("plan", "step 2").unwrap()).unwrap_err();
+//! assert!(err.is_read_only());
+//! ```
+
+use super::{DeletableMemory, MemoryKey, MemoryReader, MemoryUpdate, MemoryWriter, ScanableMemory};
+use crate::error::{MemoryBackend, MemoryError, MemoryOperation};
+
+/// What happens to writes made through a read-only memory.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ReadOnlyWrites {
+    /// Fail the write with a read-only [`MemoryError`].
+    #[default]
+    Reject,
+    /// Report success without writing anything.
+    Discard,
+}
+
+impl ReadOnlyWrites {
+    /// Apply the policy to a write of `key`.
+    ///
+    /// Returns `Ok(())` when the write should be treated as a successful
+    /// no-op, and the read-only error when it should be rejected.
+    pub fn check(
+        self,
+        key: &MemoryKey,
+        operation: MemoryOperation,
+        backend: MemoryBackend,
+    ) -> Result<(), MemoryError> {
+        match self {
+            Self::Reject => {
+                tracing::warn!(key = %key, "Rejected write to read-only memory");
+                Err(MemoryError::read_only(key.clone(), operation, backend))
+            }
+            Self::Discard => {
+                tracing::debug!(key = %key, "Discarded write to read-only memory");
+                Ok(())
+            }
+        }
+    }
+}
+
+/// A memory wrapper that passes reads through and blocks all writes.
+pub struct ReadOnlyMemory<M> {
+    inner: M,
+    writes: ReadOnlyWrites,
+}
+
+impl<M> ReadOnlyMemory<M> {
+    /// Wrap `inner`, rejecting writes.
+    pub fn new(inner: M) -> Self {
+        Self::with_policy(inner, ReadOnlyWrites::Reject)
+    }
+
+    /// Wrap `inner`, handling writes according to `writes`.
+    pub fn with_policy(inner: M, writes: ReadOnlyWrites) -> Self {
+        Self { inner, writes }
+    }
+
+    /// How writes are handled.
+    pub fn writes(&self) -> ReadOnlyWrites {
+        self.writes
+    }
+
+    /// Get an immutable reference to the underlying memory implementation.
+    pub fn inner(&self) -> &M {
+        &self.inner
+    }
+
+    /// Unwrap the underlying memory implementation.
+    pub fn into_inner(self) -> M {
+        self.inner
+    }
+
+    fn check_write(&self, key: &MemoryKey, operation: MemoryOperation) -> Result<(), MemoryError> {
+        self.writes.check(key, operation, MemoryBackend::InMemory)
+    }
+}
+
+impl<M: MemoryReader> MemoryReader for ReadOnlyMemory<M> {
+    fn load(&self, key: &MemoryKey) -> Result<Option<String>, MemoryError> {
+        self.inner.load(key)
+    }
+
+    fn load_many(&self, keys: &[MemoryKey]) -> Result<Vec<Option<String>>, MemoryError> {
+        self.inner.load_many(keys)
+    }
+}
+
+impl<M: Send + Sync> MemoryWriter for ReadOnlyMemory<M> {
+    fn store(&mut self, update: MemoryUpdate) -> Result<(), MemoryError> {
+        self.check_write(&update.key, MemoryOperation::Store)
+    }
+
+    fn store_many(&mut self, updates: Vec<MemoryUpdate>) -> Result<(), MemoryError> {
+        for update in &updates {
+            self.check_write(&update.key, MemoryOperation::Store)?;
+        }
+        Ok(())
+    }
+}
+
+impl<M: ScanableMemory> ScanableMemory for ReadOnlyMemory<M> {
+    fn scan_prefix(&self, prefix: &str) -> Result<Vec<MemoryKey>, MemoryError> {
+        self.inner.scan_prefix(prefix)
+    }
+}
+
+impl<M: Send + Sync> DeletableMemory for ReadOnlyMemory<M> {
+    fn delete(&mut self, key: &MemoryKey) -> Result<bool, MemoryError> {
+        self.check_write(key, MemoryOperation::Delete)?;
+        Ok(false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::InMemoryMemory;
+
+    fn key(s: &str) -> MemoryKey {
+        MemoryKey::new(s).unwrap()
+    }
+
+    fn seeded() -> InMemoryMemory {
+        let mut memory = InMemoryMemory::new();
+        memory
+            .store(MemoryUpdate::new("plan", "step 1").unwrap())
+            .unwrap();
+        memory
+    }
+
+    #[test]
+    fn writes_are_rejected_and_reads_still_work() {
+        let mut memory = ReadOnlyMemory::new(seeded());
+
+        let err = memory
+            .store(MemoryUpdate::new("plan", "step 2").unwrap())
+            .unwrap_err();
+        assert!(err.is_read_only());
+        assert!(!err.is_retryable());
+        assert!(
+            memory
+                .store_many(vec![MemoryUpdate::new("other", "x").unwrap()])
+                .unwrap_err()
+                .is_read_only()
+        );
+        assert!(memory.delete(&key("plan")).unwrap_err().is_read_only());
+
+        assert_eq!(
+            memory.load(&key("plan")).unwrap().as_deref(),
+            Some("step 1")
+        );
+        assert_eq!(
+            memory.load_many(&[key("plan"), key("other")]).unwrap(),
+            vec![Some("step 1".to_string()), None]
+        );
+        assert_eq!(memory.scan_prefix("").unwrap(), vec![key("plan")]);
+    }
+
+    #[test]
+    fn discarded_writes_succeed_without_mutating() {
+        let mut memory = ReadOnlyMemory::with_policy(seeded(), ReadOnlyWrites::Discard);
+
+        memory
+            .store(MemoryUpdate::new("plan", "step 2").unwrap())
+            .unwrap();
+        assert!(!memory.delete(&key("plan")).unwrap());
+
+        assert_eq!(
+            memory.into_inner().load(&key("plan")).unwrap().as_deref(),
+            Some("step 1")
+        );
+    }
+}
@@ -0,0 +1,194 @@
+//! Observation normalization
+//!
+//! Observations often arrive with inconsistent whitespace, Unicode encodings
+//! and line endings, which makes downstream matching, deduplication and golden
+//! tests brittle. A [`NormalizationPipeline`] applies a fixed sequence of
+//! steps to produce a canonical form while keeping the raw input for audit.
+//!
+//! # Steps
+//!
+//! Enabled steps always run in this order:
+//!
+//! 1. **Line endings** - `\r\n` and lone `\r` become `\n`
+//! 2. **Unicode NFC** - canonically equivalent sequences are composed
+//! 3. **Trim** - leading and trailing whitespace is removed
+//! 4. **Lowercase** - off by default, since it changes meaning for some inputs
+//!
+//! # Examples
+//!
+//! ```rust
+//! use skreaver_core::normalization::NormalizationPipeline;
+//!
+//! let pipeline = NormalizationPipeline::new();
+//! let normalized = pipeline.normalize("  Cafe\u{301}\r\nmenu ");
+//! assert_eq!(normalized.normalized, "Caf\u{e9}\nmenu");
+//! assert_eq!(normalized.raw, "  Cafe\u{301}\r\nmenu ");
+//! ```
+
+use serde::{Deserialize, Serialize};
+use unicode_normalization::{IsNormalized, UnicodeNormalization, is_nfc_quick};
+
+/// Configurable observation normalization steps
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NormalizationPipeline {
+    /// Remove leading and trailing whitespace
+    pub trim: bool,
+    /// Compose canonically equivalent sequences (Unicode NFC)
+    pub unicode_nfc: bool,
+    /// Convert CRLF and lone CR line endings to LF
+    pub normalize_line_endings: bool,
+    /// Lowercase the observation
+    pub lowercase: bool,
+}
+
+impl Default for NormalizationPipeline {
+    fn default() -> Self {
+        Self {
+            trim: true,
+            unicode_nfc: true,
+            normalize_line_endings: true,
+            lowercase: false,
+        }
+    }
+}
+
+impl NormalizationPipeline {
+    /// Create a pipeline with trim, NFC and line ending normalization enabled
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a pipeline with every step disabled
+    pub fn none() -> Self {
+        Self {
+            trim: false,
+            unicode_nfc: false,
+            normalize_line_endings: false,
+            lowercase: false,
+        }
+    }
+
+    /// Enable or disable whitespace trimming
+    pub fn with_trim(mut self, enabled: bool) -> Self {
+        self.trim = enabled;
+        self
+    }
+
+    /// Enable or disable Unicode NFC normalization
+    pub fn with_unicode_nfc(mut self, enabled: bool) -> Self {
+        self.unicode_nfc = enabled;
+        self
+    }
+
+    /// Enable or disable line ending normalization
+    pub fn with_line_endings(mut self, enabled: bool) -> Self {
+        self.normalize_line_endings = enabled;
+        self
+    }
+
+    /// Enable or disable lowercasing
+    pub fn with_lowercase(mut self, enabled: bool) -> Self {
+        self.lowercase = enabled;
+        self
+    }
+
+    /// Apply the enabled steps, returning only the normalized text
+    pub fn apply(&self, input: &str) -> String {
+        let mut text = if self.normalize_line_endings && input.contains('\r') {
+            input.replace("\r\n", "\n").replace('\r', "\n")
+        } else {
+            input.to_string()
+        };
+
+        if self.unicode_nfc && is_nfc_quick(text.chars()) != IsNormalized::Yes {
+            text = text.nfc().collect();
+        }
+
+        if self.trim {
+            let trimmed = text.trim();
+            if trimmed.len() != text.len() {
+                text = trimmed.to_string();
+            }
+        }
+
+        if self.lowercase {
+            text = text.to_lowercase();
+        }
+
+        text
+    }
+
+    /// Normalize an observation, keeping the raw form alongside the result
+    pub fn normalize(&self, input: &str) -> NormalizedObservation {
+        NormalizedObservation {
+            raw: input.to_string(),
+            normalized: self.apply(input),
+        }
+    }
+}
+
+/// An observation in both its raw and normalized forms
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NormalizedObservation {
+    /// The observation exactly as received, for audit
+    pub raw: String,
+    /// The canonical form passed to the agent
+    pub normalized: String,
+}
+
+impl NormalizedObservation {
+    /// Whether normalization changed the observation
+    pub fn is_changed(&self) -> bool {
+        self.raw != self.normalized
+    }
+}
+
+/// Observation types that can be normalized by a [`NormalizationPipeline`]
+pub trait Normalize: Sized {
+    /// Return the normalized form of this observation
+    fn normalize_with(self, pipeline: &NormalizationPipeline) -> Self;
+}
+
+impl Normalize for String {
+    fn normalize_with(self, pipeline: &NormalizationPipeline) -> Self {
+        pipeline.apply(&self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_line_endings_normalized_to_lf() {
+        let pipeline = NormalizationPipeline::none().with_line_endings(true);
+        assert_eq!(pipeline.apply("a\r\nb\rc\n"), "a\nb\nc\n");
+    }
+
+    #[test]
+    fn test_nfc_collapses_equivalent_sequences() {
+        let pipeline = NormalizationPipeline::none().with_unicode_nfc(true);
+        let decomposed = "e\u{301}";
+        let composed = "\u{e9}";
+        assert_ne!(decomposed, composed);
+        assert_eq!(pipeline.apply(decomposed), pipeline.apply(composed));
+        assert_eq!(pipeline.apply(decomposed), composed);
+    }
+
+    #[test]
+    fn test_steps_are_configurable() {
+        let input = "  Hello\r\nWorld  ";
+        assert_eq!(NormalizationPipeline::none().apply(input), input);
+        assert_eq!(NormalizationPipeline::new().apply(input), "Hello\nWorld");
+        assert_eq!(
+            NormalizationPipeline::new()
+                .with_lowercase(true)
+                .apply(input),
+            "hello\nworld"
+        );
+
+        let normalized = NormalizationPipeline::new().normalize(input);
+        assert!(normalized.is_changed());
+        assert_eq!(normalized.raw, input);
+    }
+}
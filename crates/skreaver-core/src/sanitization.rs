@@ -262,9 +262,20 @@ impl ContentSanitizer {
 }
 
 /// Helper for detecting and redacting secrets
-pub struct SecretRedactor;
+///
+/// The associated functions apply the built-in patterns only. An instance
+/// can additionally redact caller-supplied patterns, such as vendor key
+/// prefixes, via [`SecretRedactor::redact`].
+#[derive(Debug, Clone, Default)]
+pub struct SecretRedactor {
+    #[cfg(feature = "security-basic")]
+    patterns: Vec<regex::Regex>,
+}
 
 impl SecretRedactor {
+    /// Marker replacing text matched by custom patterns
+    pub const MARKER: &'static str = "[REDACTED]";
+
     /// Common secret patterns (for detection)
     pub const SECRET_PATTERNS: &'static [&'static str] = &[
         "password",
@@ -334,6 +345,78 @@ impl SecretRedactor {
 
         result
     }
+
+    /// Create a redactor using the built-in patterns only
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a redactor with additional patterns
+    #[cfg(feature = "security-basic")]
+    pub fn with_patterns(patterns: Vec<regex::Regex>) -> Self {
+        Self { patterns }
+    }
+
+    /// Register an additional pattern
+    #[cfg(feature = "security-basic")]
+    pub fn add_pattern(&mut self, pattern: regex::Regex) {
+        self.patterns.push(pattern);
+    }
+
+    /// Redact built-in secrets, then replace custom pattern matches
+    ///
+    /// Overlapping or nested matches are merged and replaced by a single
+    /// [`SecretRedactor::MARKER`]; surrounding text is kept.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use regex::Regex;
+    /// use skreaver_core::sanitization::SecretRedactor;
+    ///
+    /// let redactor = SecretRedactor::with_patterns(vec![Regex::new(r"acme_live_\w+").unwrap()]);
+    /// let redacted = redactor.redact("key acme_live_abc123 password=hunter2");
+    /// assert_eq!(redacted, "key [REDACTED] password=***");
+    /// ```
+    pub fn redact(&self, input: &str) -> String {
+        let result = Self::redact_secrets(input);
+
+        #[cfg(feature = "security-basic")]
+        {
+            let mut ranges: Vec<std::ops::Range<usize>> = self
+                .patterns
+                .iter()
+                .flat_map(|pattern| pattern.find_iter(&result))
+                .filter(|m| !m.is_empty())
+                .map(|m| m.range())
+                .collect();
+            if ranges.is_empty() {
+                return result;
+            }
+            ranges.sort_by_key(|r| r.start);
+
+            let mut redacted = String::with_capacity(result.len());
+            let mut cursor = 0;
+            let mut current = ranges[0].clone();
+            for range in ranges.into_iter().skip(1) {
+                if range.start <= current.end {
+                    current.end = current.end.max(range.end);
+                } else {
+                    redacted.push_str(&result[cursor..current.start]);
+                    redacted.push_str(Self::MARKER);
+                    cursor = current.end;
+                    current = range;
+                }
+            }
+            redacted.push_str(&result[cursor..current.start]);
+            redacted.push_str(Self::MARKER);
+            redacted.push_str(&result[current.end..]);
+            redacted
+        }
+
+        #[cfg(not(feature = "security-basic"))]
+        result
+    }
 }
 
 #[cfg(test)]
@@ -424,6 +507,34 @@ mod tests {
         assert!(redacted.contains("user=admin")); // Non-secret preserved
     }
 
+    #[test]
+    #[cfg(feature = "security-basic")]
+    fn test_redact_custom_patterns() {
+        use regex::Regex;
+
+        let mut redactor = SecretRedactor::with_patterns(vec![
+            Regex::new(r"acme_live_\w+").unwrap(),
+            Regex::new(r"live_\w+").unwrap(),
+        ]);
+        redactor.add_pattern(Regex::new(r"\d{3}").unwrap());
+
+        // Overlapping and nested matches collapse into one marker
+        assert_eq!(
+            redactor.redact("key=acme_live_abc123, done"),
+            "key=[REDACTED], done"
+        );
+        // Adjacent matches and built-in patterns both apply
+        assert_eq!(
+            redactor.redact("ids 123456 token=xyz"),
+            "ids [REDACTED] token=***"
+        );
+        assert_eq!(redactor.redact("nothing here"), "nothing here");
+
+        // Empty matches are ignored rather than inserting markers everywhere
+        let redactor = SecretRedactor::with_patterns(vec![Regex::new(r"x*").unwrap()]);
+        assert_eq!(redactor.redact("abc"), "abc");
+    }
+
     #[test]
     fn test_sanitize_output() {
         let input = "Hello\x00World\x1b[31mRed\x1b[0m";
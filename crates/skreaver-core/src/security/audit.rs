@@ -2,6 +2,7 @@
 
 use super::SecurityContext;
 use super::errors::{SecurityViolation, ViolationSeverity};
+use super::retention::{AuditRetention, AuditRetentionEnforcer, AuditStore};
 #[cfg(feature = "security-audit")]
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -128,6 +129,7 @@ pub struct AuditLogger {
     config: AuditConfig,
     violation_tracker: Arc<Mutex<ViolationTracker>>,
     redactor: SecretRedactor,
+    store: Option<Arc<dyn AuditStore>>,
 }
 
 #[derive(Debug, Clone)]
@@ -185,9 +187,28 @@ impl AuditLogger {
             config: audit_config.clone(),
             violation_tracker: Arc::new(Mutex::new(ViolationTracker::new())),
             redactor: SecretRedactor::new(&audit_config.secret_patterns),
+            store: None,
         }
     }
 
+    /// Also persist every logged entry to `store`
+    pub fn with_store(mut self, store: Arc<dyn AuditStore>) -> Self {
+        self.store = Some(store);
+        self
+    }
+
+    /// Enforcer pruning the store according to `retain_logs_days`
+    ///
+    /// Returns `None` when no store is configured, since `tracing` output is
+    /// rotated by whatever collects it.
+    pub fn retention_enforcer(&self) -> Option<AuditRetentionEnforcer> {
+        let store = self.store.clone()?;
+        Some(AuditRetentionEnforcer::new(
+            AuditRetention::new(self.config.retain_logs_days),
+            store,
+        ))
+    }
+
     pub fn log_event(&self, event: SecurityEvent) {
         let severity = self.determine_severity(&event);
 
@@ -224,6 +245,11 @@ impl AuditLogger {
 
         // Log the event
         self.write_log_entry(&audit_log);
+        if let Some(store) = &self.store
+            && let Err(e) = store.append(&audit_log)
+        {
+            tracing::error!(error = %e, "Failed to persist audit log entry");
+        }
 
         // Update metrics
         self.update_security_metrics(&audit_log);
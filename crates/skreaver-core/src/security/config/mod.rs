@@ -196,6 +196,10 @@ impl SecurityConfig {
             }
         }
 
+        // Compile allowed paths once at load, rejecting invalid glob patterns
+        // here rather than on first file access
+        self.fs.allowed_paths()?;
+
         // Note: File size, timeout, and redirect limits are validated by their newtypes (FileSizeLimit, TimeoutSeconds, RedirectLimit)
         // during deserialization, so no additional validation is needed here.
 
@@ -210,6 +214,17 @@ impl SecurityConfig {
             tracing::warn!("HTTP enabled with empty allow list (all domains will be blocked)");
         }
 
+        for limit in &self.http.rate_limits {
+            if limit.requests == 0 || limit.window_secs == 0 || limit.burst() == 0 {
+                return Err(SecurityError::ConfigError {
+                    message: format!(
+                        "Rate limit for '{}' must allow at least one request per non-zero window",
+                        limit.domain
+                    ),
+                });
+            }
+        }
+
         // Check for overly permissive settings (WARNINGS)
         if let HttpAccess::Internet {
             include_local: true,
@@ -255,7 +270,9 @@ impl SecurityConfig {
 
         // Validate audit configuration
         if self.audit.retain_logs_days == 0 {
-            tracing::warn!("Log retention is 0 days - logs will not be retained");
+            tracing::warn!(
+                "Log retention is 0 days - stored audit logs are still kept for at least 1 day"
+            );
         }
 
         // Validate secrets configuration
@@ -75,6 +75,12 @@ pub enum SecurityError {
     #[error("Rate limit exceeded: {requests} requests in {window_seconds}s")]
     RateLimitExceeded { requests: u32, window_seconds: u32 },
 
+    #[error("Rate limited for domain {domain}: retry after {retry_after:?}")]
+    RateLimited {
+        domain: String,
+        retry_after: std::time::Duration,
+    },
+
     #[error("Security configuration error: {message}")]
     ConfigError { message: String },
 
@@ -159,6 +165,30 @@ impl From<SecurityError> for SecurityViolation {
     }
 }
 
+impl From<SecurityError> for crate::tool::FailureReason {
+    /// Map a rejected operation to a tool failure, keeping rate limit
+    /// retry hints structured
+    fn from(error: SecurityError) -> Self {
+        match error {
+            SecurityError::RateLimited {
+                domain,
+                retry_after,
+            } => Self::RateLimited {
+                resource: domain,
+                // A limit that never refills has no meaningful retry time
+                retry_after_ms: (retry_after != std::time::Duration::MAX)
+                    .then(|| u64::try_from(retry_after.as_millis()).unwrap_or(u64::MAX)),
+            },
+            SecurityError::TimeoutExceeded { timeout_ms } => Self::Timeout {
+                operation: format!("exceeded {}ms", timeout_ms),
+            },
+            other => Self::PermissionDenied {
+                message: other.to_string(),
+            },
+        }
+    }
+}
+
 impl SecurityViolation {
     /// Set the security context (agent and tool) for this violation
     ///
@@ -553,6 +553,117 @@ impl RateLimiter {
     }
 }
 
+/// Token bucket allowing bursts up to its capacity at a steady refill rate
+#[derive(Debug, Clone)]
+pub struct TokenBucket {
+    capacity: f64,
+    tokens: f64,
+    /// Tokens added per second
+    refill_rate: f64,
+    last_refill: Instant,
+}
+
+impl TokenBucket {
+    /// Create a full bucket holding `burst` tokens, refilling `requests` per `window`
+    pub fn new(burst: u32, requests: u32, window: Duration) -> Self {
+        let mut bucket = Self {
+            capacity: 0.0,
+            tokens: f64::from(burst),
+            refill_rate: 0.0,
+            last_refill: Instant::now(),
+        };
+        bucket.configure(burst, requests, window);
+        bucket
+    }
+
+    /// Update the bucket's limits, keeping the tokens already available
+    fn configure(&mut self, burst: u32, requests: u32, window: Duration) {
+        self.capacity = f64::from(burst);
+        self.tokens = self.tokens.min(self.capacity);
+        self.refill_rate = if window.is_zero() {
+            f64::INFINITY
+        } else {
+            f64::from(requests) / window.as_secs_f64()
+        };
+    }
+
+    /// Take one token, or return how long until one is available
+    ///
+    /// A bucket that never refills reports `Duration::MAX`.
+    pub fn try_acquire(&mut self) -> Result<(), Duration> {
+        let now = Instant::now();
+        let elapsed = now.duration_since(self.last_refill).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.refill_rate).min(self.capacity);
+        self.last_refill = now;
+
+        if self.tokens >= 1.0 {
+            self.tokens -= 1.0;
+            return Ok(());
+        }
+
+        if self.capacity < 1.0 || self.refill_rate <= 0.0 {
+            return Err(Duration::MAX);
+        }
+        let wait = (1.0 - self.tokens) / self.refill_rate;
+        Err(Duration::try_from_secs_f64(wait).unwrap_or(Duration::MAX))
+    }
+}
+
+/// Per-domain request limiter enforcing [`HttpPolicy`] rate limits
+///
+/// Buckets are keyed by the matching rule, so every domain a pattern such as
+/// `*.example.com` covers, and every tool sharing a limiter, draws from the
+/// same budget.
+///
+/// [`HttpPolicy`]: super::policy::HttpPolicy
+#[derive(Debug, Default)]
+pub struct DomainRateLimiter {
+    buckets: Mutex<HashMap<String, TokenBucket>>,
+}
+
+impl DomainRateLimiter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a request to `domain`, failing if the policy's limit is exhausted
+    ///
+    /// Domains without a configured limit are always allowed.
+    pub fn check(
+        &self,
+        policy: &super::policy::HttpPolicy,
+        domain: &str,
+    ) -> Result<(), SecurityError> {
+        let domain = normalize_domain(domain);
+        let Some(limit) = policy.rate_limit_for(&domain) else {
+            return Ok(());
+        };
+
+        let mut buckets = self
+            .buckets
+            .lock()
+            .map_err(|_| SecurityError::ConfigError {
+                message: "Domain rate limiter mutex poisoned".to_string(),
+            })?;
+        let bucket = buckets
+            .entry(normalize_domain(&limit.domain))
+            .and_modify(|bucket| bucket.configure(limit.burst(), limit.requests, limit.window()))
+            .or_insert_with(|| TokenBucket::new(limit.burst(), limit.requests, limit.window()));
+
+        bucket
+            .try_acquire()
+            .map_err(|retry_after| SecurityError::RateLimited {
+                domain,
+                retry_after,
+            })
+    }
+}
+
+/// Lowercase a domain and drop the trailing root label dot
+pub(super) fn normalize_domain(domain: &str) -> String {
+    domain.trim_end_matches('.').to_ascii_lowercase()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -600,6 +711,58 @@ mod tests {
         assert!(limiter.check_rate_limit("test_key").is_err());
     }
 
+    #[test]
+    fn test_token_bucket_allows_burst_then_reports_retry_after() {
+        let mut bucket = TokenBucket::new(3, 1, Duration::from_secs(10));
+
+        for _ in 0..3 {
+            assert!(bucket.try_acquire().is_ok());
+        }
+        let retry_after = bucket.try_acquire().unwrap_err();
+        assert!(retry_after > Duration::from_secs(9) && retry_after <= Duration::from_secs(10));
+    }
+
+    #[test]
+    fn test_token_bucket_refills_over_time() {
+        let mut bucket = TokenBucket::new(1, 1000, Duration::from_secs(1));
+        assert!(bucket.try_acquire().is_ok());
+        assert!(bucket.try_acquire().is_err());
+
+        std::thread::sleep(Duration::from_millis(5));
+        assert!(bucket.try_acquire().is_ok());
+    }
+
+    #[test]
+    fn test_domain_rate_limiter_shares_budget_per_rule() {
+        use crate::security::policy::{DomainRateLimit, HttpPolicy};
+
+        let policy = HttpPolicy::builder()
+            .rate_limit(DomainRateLimit::new("*.example.com", 1, Duration::from_secs(60)).unwrap())
+            .rate_limit(DomainRateLimit::new("example.org", 1, Duration::from_secs(60)).unwrap())
+            .build();
+        let limiter = DomainRateLimiter::new();
+
+        assert!(limiter.check(&policy, "api.example.com").is_ok());
+        match limiter.check(&policy, "API.Example.com.") {
+            Err(SecurityError::RateLimited {
+                domain,
+                retry_after,
+            }) => {
+                assert_eq!(domain, "api.example.com");
+                assert!(retry_after > Duration::from_secs(59));
+            }
+            other => panic!("expected rate limit, got {other:?}"),
+        }
+
+        // Subdomains matched by the same rule share its budget, other rules
+        // have their own and unlimited domains none at all
+        assert!(limiter.check(&policy, "www.example.com").is_err());
+        assert!(limiter.check(&policy, "example.org").is_ok());
+        for _ in 0..10 {
+            assert!(limiter.check(&policy, "unlimited.org").is_ok());
+        }
+    }
+
     #[test]
     fn test_operation_guard() {
         let limits = ResourceLimits::default();
@@ -3,6 +3,7 @@
 //! This module provides security controls, policy enforcement, and audit logging
 //! to enable secure deployment of AI agents in production environments.
 
+use std::cell::RefCell;
 use std::path::Path;
 
 /// Convert path to string with logging for lossy conversions (LOW-3)
@@ -29,6 +30,8 @@ pub mod errors;
 pub mod fs;
 pub mod limits;
 pub mod policy;
+#[cfg(feature = "security-audit")]
+pub mod retention;
 pub mod secret;
 #[cfg(feature = "security-basic")]
 pub mod secure_tool;
@@ -51,12 +54,19 @@ pub use config::{
 pub use errors::{SecurityError, SecurityViolation};
 #[cfg(feature = "security-basic")]
 pub use fs::{SecureFileSystem, ValidatedPath};
-pub use limits::{CpuPercent, ResourceLimits, ResourceTracker, ResourceUsage};
+pub use limits::{
+    CpuPercent, DomainRateLimiter, ResourceLimits, ResourceTracker, ResourceUsage, TokenBucket,
+};
 pub use policy::{
-    ContentScanning, DomainFilter, FileCountLimit, FileSizeLimit, FileSystemAccess,
-    FileSystemPolicy, HttpAccess, HttpAccessConfig, HttpPolicy, NetworkAccess, NetworkPolicy,
-    NetworkPort, RedirectLimit, ResponseSizeLimit, SecurityPolicy, SymlinkBehavior, TimeoutSeconds,
-    ToolSecurityPolicy,
+    AllowedPaths, CompiledAllowPaths, ContentScanning, DomainFilter, DomainRateLimit,
+    FileCountLimit, FileSizeLimit, FileSystemAccess, FileSystemPolicy, HttpAccess,
+    HttpAccessConfig, HttpPolicy, NetworkAccess, NetworkPolicy, NetworkPort, RedirectLimit,
+    ResponseSizeLimit, SecurityPolicy, SymlinkBehavior, TimeoutSeconds, ToolSecurityPolicy,
+};
+#[cfg(feature = "security-audit")]
+pub use retention::{
+    AuditRetention, AuditRetentionEnforcer, AuditRetentionHandle, AuditRetentionStats, AuditStore,
+    FileAuditStore, MIN_RETENTION_DAYS, MemoryAuditStore,
 };
 #[cfg(feature = "security-basic")]
 pub use validated_fd::ValidatedFileDescriptor;
@@ -143,12 +153,48 @@ impl SecurityContext {
     }
 }
 
+/// Extract the HTTP(S) URL a tool input targets, if any
+fn target_url(input: &str) -> Option<url::Url> {
+    let input = input.trim();
+    let raw = if input.starts_with('{') {
+        serde_json::from_str::<serde_json::Value>(input)
+            .ok()?
+            .get("url")?
+            .as_str()?
+            .to_string()
+    } else {
+        input.to_string()
+    };
+    url::Url::parse(&raw)
+        .ok()
+        .filter(|url| matches!(url.scheme(), "http" | "https"))
+}
+
+thread_local! {
+    /// Domain whose rate limit was already charged for the tool call running
+    /// on this thread, with the address of the charging manager
+    static CHARGED_DOMAIN: RefCell<Option<(usize, String)>> = const { RefCell::new(None) };
+}
+
+/// Restores the previous charged domain when a wrapped call ends
+struct ChargedDomainGuard {
+    previous: Option<(usize, String)>,
+}
+
+impl Drop for ChargedDomainGuard {
+    fn drop(&mut self) {
+        let previous = self.previous.take();
+        CHARGED_DOMAIN.with(|charged| *charged.borrow_mut() = previous);
+    }
+}
+
 /// Security manager for coordinating security operations
 pub struct SecurityManager {
     config: SecurityConfig,
     #[cfg(feature = "security-audit")]
     audit_log: audit::AuditLogger,
     resource_tracker: limits::ResourceTracker,
+    domain_limiter: limits::DomainRateLimiter,
 }
 
 impl SecurityManager {
@@ -162,6 +208,7 @@ impl SecurityManager {
             #[cfg(feature = "security-audit")]
             audit_log,
             resource_tracker,
+            domain_limiter: limits::DomainRateLimiter::new(),
         }
     }
 
@@ -172,6 +219,16 @@ impl SecurityManager {
         SecurityContext::new(agent_id, tool_name, policy).with_limits(limits)
     }
 
+    /// Validate a tool call before it runs
+    ///
+    /// Checks resource limits and the input, and when the input targets an
+    /// HTTP(S) URL (a bare URL or a JSON object with a `url` field), takes one
+    /// request from that domain's rate limit.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SecurityError::RateLimited` if the domain's limit is
+    /// exhausted, or the first other limit or validation failure.
     pub fn validate_operation(
         &self,
         context: &SecurityContext,
@@ -187,6 +244,12 @@ impl SecurityManager {
             validator.validate(input)?;
         }
 
+        // Per-domain rate limits
+        if let Some(domain) = self.rate_limited_domain(context, input) {
+            self.domain_limiter
+                .check(&context.policy.http_policy, &domain)?;
+        }
+
         // Log the validation attempt
         #[cfg(feature = "security-audit")]
         {
@@ -201,6 +264,81 @@ impl SecurityManager {
         Ok(())
     }
 
+    /// Validate an outgoing HTTP request for a tool
+    ///
+    /// Checks the URL against the tool's HTTP policy, then takes one request
+    /// from the per-domain rate limit. Limits are shared by every tool using
+    /// this manager; a request already charged by a [`SecureTool`] wrapping
+    /// the calling tool is not charged again.
+    ///
+    /// # Errors
+    ///
+    /// Returns the URL validation error, or `SecurityError::RateLimited` if
+    /// the domain's limit is exhausted.
+    pub fn check_http_request(
+        &self,
+        tool_name: &str,
+        url: &str,
+    ) -> Result<ValidatedUrl, SecurityError> {
+        let policy = self.config.tool_policy(tool_name).http_policy;
+        let url = validation::DomainValidator::new(&policy).validate_url(url)?;
+        if let Some(domain) = url.host_str()
+            && !self.take_charged_domain(domain)
+        {
+            self.domain_limiter.check(&policy, domain)?;
+        }
+        Ok(url)
+    }
+
+    /// The rate limited domain a tool input targets, if any
+    fn rate_limited_domain(&self, context: &SecurityContext, input: &str) -> Option<String> {
+        let http_policy = &context.policy.http_policy;
+        if http_policy.rate_limits.is_empty() {
+            return None;
+        }
+        let domain = limits::normalize_domain(target_url(input)?.host_str()?);
+        http_policy.rate_limit_for(&domain).map(|_| domain)
+    }
+
+    /// Run a tool call that [`validate_operation`](Self::validate_operation)
+    /// already admitted
+    ///
+    /// The request to the domain `input` targets was charged by the
+    /// validation, so the first [`check_http_request`](Self::check_http_request)
+    /// for that domain on this thread during `call` does not take a second
+    /// token. Later requests from the same call are charged as usual.
+    pub(crate) fn run_validated<R>(
+        &self,
+        context: &SecurityContext,
+        input: String,
+        call: impl FnOnce(String) -> R,
+    ) -> R {
+        let Some(domain) = self.rate_limited_domain(context, &input) else {
+            return call(input);
+        };
+        let marker = (std::ptr::from_ref(self) as usize, domain);
+        let _guard = ChargedDomainGuard {
+            previous: CHARGED_DOMAIN.with(|charged| charged.borrow_mut().replace(marker)),
+        };
+        call(input)
+    }
+
+    /// Consume this manager's charge for `domain` on the current thread
+    fn take_charged_domain(&self, domain: &str) -> bool {
+        let manager = std::ptr::from_ref(self) as usize;
+        let domain = limits::normalize_domain(domain);
+        CHARGED_DOMAIN.with(|charged| {
+            let mut charged = charged.borrow_mut();
+            let matches = charged
+                .as_ref()
+                .is_some_and(|(owner, charged)| *owner == manager && *charged == domain);
+            if matches {
+                *charged = None;
+            }
+            matches
+        })
+    }
+
     pub fn enforce_timeout<T>(
         &self,
         context: &SecurityContext,
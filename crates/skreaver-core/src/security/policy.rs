@@ -2,8 +2,11 @@
 
 use super::errors::SecurityError;
 use super::path_to_string_checked;
+use globset::{GlobBuilder, GlobSet, GlobSetBuilder};
 use serde::{Deserialize, Serialize};
-use std::path::PathBuf;
+use std::borrow::Cow;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
 use std::time::Duration;
 
 // ============================================================================
@@ -519,11 +522,23 @@ impl Default for FileSystemAccess {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FileSystemPolicy {
     pub access: FileSystemAccess,
+    /// Allowed directory prefixes or glob patterns
+    ///
+    /// Entries containing `*`, `?`, `[` or `{` are glob patterns matched
+    /// against the canonical path and each of its ancestors. The literal
+    /// directories leading a pattern are canonicalized when it is compiled,
+    /// so relative or symlinked prefixes such as `./data/**` work as long as
+    /// they exist by then. `*` matches a single path component and `**` any
+    /// number of components, e.g. `/data/tenants/*/uploads`.
     pub allow_paths: Vec<PathBuf>,
     pub deny_patterns: Vec<String>,
     #[serde(alias = "max_file_size_bytes")]
     pub max_file_size: FileSizeLimit,
     pub max_files_per_operation: FileCountLimit,
+    /// `allow_paths` as compiled by [`allowed_paths`](Self::allowed_paths);
+    /// leave at its default
+    #[serde(skip)]
+    pub compiled_allow_paths: CompiledAllowPaths,
 }
 
 impl Default for FileSystemPolicy {
@@ -542,6 +557,7 @@ impl Default for FileSystemPolicy {
             ],
             max_file_size: FileSizeLimit::default(), // 16MB
             max_files_per_operation: FileCountLimit::default(), // 100
+            compiled_allow_paths: CompiledAllowPaths::default(),
         }
     }
 }
@@ -554,7 +570,70 @@ impl FileSystemPolicy {
         }
     }
 
-    pub fn is_path_allowed(&self, path: &std::path::Path) -> Result<bool, SecurityError> {
+    /// Compile `allow_paths` into a matcher, parsing glob patterns once
+    ///
+    /// The literal prefix of each glob pattern is canonicalized here, so a
+    /// prefix created later keeps its uncanonicalized form. Prefer
+    /// [`allowed_paths`](Self::allowed_paths), which reuses the result.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SecurityError::ConfigError` if a glob pattern is invalid.
+    pub fn compile_allow_paths(&self) -> Result<AllowedPaths, SecurityError> {
+        let mut prefixes = Vec::new();
+        let mut globs = GlobSetBuilder::new();
+        for allowed_path in &self.allow_paths {
+            if !is_glob_pattern(&allowed_path.to_string_lossy()) {
+                prefixes.push(allowed_path.clone());
+                continue;
+            }
+            let pattern = canonicalize_glob_prefix(allowed_path);
+            let glob = GlobBuilder::new(&pattern)
+                .literal_separator(true)
+                .build()
+                .map_err(|e| SecurityError::ConfigError {
+                    message: format!("Invalid allowed path pattern '{}': {}", pattern, e),
+                })?;
+            globs.add(glob);
+        }
+        let globs = globs.build().map_err(|e| SecurityError::ConfigError {
+            message: format!("Failed to compile allowed path patterns: {}", e),
+        })?;
+
+        Ok(AllowedPaths { prefixes, globs })
+    }
+
+    /// Get `allow_paths` compiled for matching
+    ///
+    /// They are compiled on the first call and reused afterwards, also by
+    /// clones of this policy. If `allow_paths` has been changed since, the
+    /// new value is compiled for this call only.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SecurityError::ConfigError` if a glob pattern is invalid.
+    pub fn allowed_paths(&self) -> Result<Cow<'_, AllowedPaths>, SecurityError> {
+        let (compiled_from, compiled) = self
+            .compiled_allow_paths
+            .0
+            .get_or_init(|| (self.allow_paths.clone(), self.compile_allow_paths()));
+        if *compiled_from != self.allow_paths {
+            return self.compile_allow_paths().map(Cow::Owned);
+        }
+        compiled.as_ref().map(Cow::Borrowed).map_err(Clone::clone)
+    }
+
+    /// Check a path against the policy
+    pub fn is_path_allowed(&self, path: &Path) -> Result<bool, SecurityError> {
+        self.is_path_allowed_by(path, &*self.allowed_paths()?)
+    }
+
+    /// Check a path against the policy using precompiled allowed paths
+    pub fn is_path_allowed_by(
+        &self,
+        path: &Path,
+        allowed_paths: &AllowedPaths,
+    ) -> Result<bool, SecurityError> {
         if matches!(self.access, FileSystemAccess::Disabled) {
             return Err(SecurityError::ToolDisabled {
                 tool_name: "file_system".to_string(),
@@ -568,16 +647,7 @@ impl FileSystemPolicy {
                 path: path_to_string_checked(path),
             })?;
 
-        // Check if path starts with any allowed path
-        let allowed = self.allow_paths.iter().any(|allowed_path| {
-            if let Ok(canonical_allowed) = allowed_path.canonicalize() {
-                canonical_path.starts_with(canonical_allowed)
-            } else {
-                false
-            }
-        });
-
-        if !allowed {
+        if !allowed_paths.matches(&canonical_path) {
             return Ok(false);
         }
 
@@ -608,6 +678,73 @@ impl FileSystemPolicy {
     }
 }
 
+/// Allowed paths of a [`FileSystemPolicy`], compiled for matching
+///
+/// Created by [`FileSystemPolicy::allowed_paths`].
+#[derive(Debug, Clone)]
+pub struct AllowedPaths {
+    prefixes: Vec<PathBuf>,
+    globs: GlobSet,
+}
+
+impl AllowedPaths {
+    /// Check whether a canonical path lies under an allowed prefix or matches
+    /// an allowed glob pattern, itself or through one of its ancestors
+    pub fn matches(&self, canonical_path: &Path) -> bool {
+        let under_prefix = self.prefixes.iter().any(|allowed_path| {
+            if let Ok(canonical_allowed) = allowed_path.canonicalize() {
+                canonical_path.starts_with(canonical_allowed)
+            } else {
+                false
+            }
+        });
+
+        under_prefix
+            || (!self.globs.is_empty()
+                && canonical_path
+                    .ancestors()
+                    .any(|ancestor| self.globs.is_match(ancestor)))
+    }
+}
+
+/// Cache of a [`FileSystemPolicy`]'s compiled allowed paths
+///
+/// Empty until [`FileSystemPolicy::allowed_paths`] is first called. Cloning
+/// copies the compiled result.
+#[derive(Debug, Clone, Default)]
+pub struct CompiledAllowPaths(OnceLock<(Vec<PathBuf>, Result<AllowedPaths, SecurityError>)>);
+
+/// Whether an allowed path entry uses glob syntax rather than a literal prefix
+fn is_glob_pattern(pattern: &str) -> bool {
+    pattern.contains(['*', '?', '[', '{'])
+}
+
+/// Resolve the literal directories leading a glob pattern
+///
+/// Patterns are matched against canonical paths, so `./data/**` or a prefix
+/// through a symlink would otherwise never match. A prefix that cannot be
+/// canonicalized, e.g. because it does not exist yet, is left unchanged.
+fn canonicalize_glob_prefix(pattern: &Path) -> String {
+    let mut components = pattern.components().peekable();
+    let mut prefix = PathBuf::new();
+    while let Some(component) =
+        components.next_if(|c| !is_glob_pattern(&c.as_os_str().to_string_lossy()))
+    {
+        prefix.push(component);
+    }
+    let rest: PathBuf = components.collect();
+
+    match prefix.canonicalize() {
+        Ok(canonical) if !prefix.as_os_str().is_empty() => format!(
+            "{}{}{}",
+            globset::escape(&canonical.to_string_lossy()),
+            std::path::MAIN_SEPARATOR,
+            rest.to_string_lossy()
+        ),
+        _ => pattern.to_string_lossy().into_owned(),
+    }
+}
+
 /// Builder for constructing `FileSystemPolicy` with a fluent API.
 #[derive(Debug, Default)]
 pub struct FileSystemPolicyBuilder {
@@ -713,6 +850,7 @@ impl FileSystemPolicyBuilder {
             max_files_per_operation: self
                 .max_files_per_operation
                 .unwrap_or(default.max_files_per_operation),
+            compiled_allow_paths: CompiledAllowPaths::default(),
         }
     }
 }
@@ -787,12 +925,80 @@ impl Default for HttpAccess {
     }
 }
 
+/// Request rate limit for domains matching a pattern
+///
+/// Enforced as one token bucket per rule, shared by every domain the pattern
+/// matches: the bucket holds up to `burst` requests and refills at
+/// `requests` per `window_secs`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct DomainRateLimit {
+    /// Domain pattern, matched like domain filter entries (`*.example.com`)
+    pub domain: String,
+    /// Requests allowed per window at the steady rate
+    pub requests: u32,
+    /// Window length in seconds
+    pub window_secs: u64,
+    /// Requests that may be issued back to back; defaults to `requests`
+    #[serde(default)]
+    pub burst: Option<u32>,
+}
+
+impl DomainRateLimit {
+    /// Allow `requests` per `window` to domains matching `domain`
+    ///
+    /// # Errors
+    ///
+    /// Returns `SecurityError::ConfigError` unless `window` is a whole,
+    /// non-zero number of seconds; shorter windows would otherwise be
+    /// truncated to zero.
+    pub fn new(
+        domain: impl Into<String>,
+        requests: u32,
+        window: Duration,
+    ) -> Result<Self, SecurityError> {
+        let domain = domain.into();
+        if window.as_secs() == 0 || window.subsec_nanos() != 0 {
+            return Err(SecurityError::ConfigError {
+                message: format!(
+                    "Rate limit window for '{}' must be a whole number of seconds, got {:?}",
+                    domain, window
+                ),
+            });
+        }
+        Ok(Self {
+            domain,
+            requests,
+            window_secs: window.as_secs(),
+            burst: None,
+        })
+    }
+
+    /// Allow up to `burst` requests back to back
+    pub fn with_burst(mut self, burst: u32) -> Self {
+        self.burst = Some(burst);
+        self
+    }
+
+    /// Requests that may be issued back to back
+    pub fn burst(&self) -> u32 {
+        self.burst.unwrap_or(self.requests)
+    }
+
+    /// Window length
+    pub fn window(&self) -> Duration {
+        Duration::from_secs(self.window_secs)
+    }
+}
+
 /// HTTP client access policy
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HttpPolicy {
     pub access: HttpAccess,
     pub allow_methods: Vec<String>,
     pub default_headers: Vec<(String, String)>,
+    /// Per-domain request rate limits; the first matching entry applies
+    #[serde(default)]
+    pub rate_limits: Vec<DomainRateLimit>,
 }
 
 impl Default for HttpPolicy {
@@ -810,6 +1016,7 @@ impl Default for HttpPolicy {
                 ("X-Skreaver-Agent".to_string(), "true".to_string()),
                 ("X-Requested-With".to_string(), "Skreaver".to_string()),
             ],
+            rate_limits: Vec::new(),
         }
     }
 }
@@ -889,6 +1096,13 @@ impl HttpPolicy {
         }
     }
 
+    /// Find the rate limit for a domain, if any
+    pub fn rate_limit_for(&self, domain: &str) -> Option<&DomainRateLimit> {
+        self.rate_limits
+            .iter()
+            .find(|limit| Self::matches_pattern(domain, &limit.domain))
+    }
+
     pub fn is_method_allowed(&self, method: &str) -> bool {
         self.allow_methods.contains(&method.to_uppercase())
     }
@@ -922,6 +1136,7 @@ pub struct HttpPolicyBuilder {
     access: Option<HttpAccess>,
     allow_methods: Option<Vec<String>>,
     default_headers: Option<Vec<(String, String)>>,
+    rate_limits: Option<Vec<DomainRateLimit>>,
 }
 
 impl HttpPolicyBuilder {
@@ -1038,6 +1253,12 @@ impl HttpPolicyBuilder {
         self
     }
 
+    /// Add a per-domain rate limit.
+    pub fn rate_limit(mut self, limit: DomainRateLimit) -> Self {
+        self.rate_limits.get_or_insert_with(Vec::new).push(limit);
+        self
+    }
+
     /// Build the HTTP policy.
     pub fn build(self) -> HttpPolicy {
         let default = HttpPolicy::default();
@@ -1045,6 +1266,7 @@ impl HttpPolicyBuilder {
             access: self.access.unwrap_or(default.access),
             allow_methods: self.allow_methods.unwrap_or(default.allow_methods),
             default_headers: self.default_headers.unwrap_or(default.default_headers),
+            rate_limits: self.rate_limits.unwrap_or(default.rate_limits),
         }
     }
 }
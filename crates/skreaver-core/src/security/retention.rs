@@ -0,0 +1,467 @@
+//! Audit log storage and retention enforcement
+//!
+//! [`AuditLogger`](super::AuditLogger) can persist entries to an [`AuditStore`]
+//! in addition to emitting them through `tracing`. [`AuditRetentionEnforcer`]
+//! prunes entries older than `retain_logs_days` from that store, either on
+//! demand or on a background schedule. The configured window is never allowed
+//! below [`MIN_RETENTION_DAYS`], so a zero or mistyped setting cannot wipe
+//! the whole audit trail.
+
+use super::audit::SecurityAuditLog;
+use crate::memory::{DeletableMemory, MemoryKey, MemoryUpdate, MemoryWriter, ScanableMemory};
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, BufRead, BufReader, Write};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::{Arc, Mutex};
+use std::thread::JoinHandle;
+use time::OffsetDateTime;
+
+/// Shortest retention window that will be enforced, in days
+pub const MIN_RETENTION_DAYS: u32 = 1;
+
+/// Key prefix used by [`MemoryAuditStore`]
+pub const AUDIT_KEY_PREFIX: &str = "audit:";
+
+/// Persistent destination for audit entries
+pub trait AuditStore: Send + Sync {
+    /// Persist one audit entry
+    fn append(&self, entry: &SecurityAuditLog) -> io::Result<()>;
+
+    /// Remove every entry recorded before `cutoff`, returning how many were removed
+    fn prune_before(&self, cutoff: OffsetDateTime) -> io::Result<usize>;
+}
+
+/// Audit store writing one JSON entry per line to a file
+///
+/// Pruning rewrites the file through a temporary file and a rename, so a
+/// crash mid-prune leaves the previous file intact. Lines that cannot be
+/// parsed are kept, since their age is unknown.
+#[derive(Debug)]
+pub struct FileAuditStore {
+    path: PathBuf,
+    lock: Mutex<()>,
+}
+
+impl FileAuditStore {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            lock: Mutex::new(()),
+        }
+    }
+
+    /// Path of the audit file
+    pub fn path(&self) -> &std::path::Path {
+        &self.path
+    }
+}
+
+impl AuditStore for FileAuditStore {
+    fn append(&self, entry: &SecurityAuditLog) -> io::Result<()> {
+        let line = serde_json::to_string(entry).map_err(io::Error::other)?;
+        let _guard = self.lock.lock().unwrap_or_else(|e| e.into_inner());
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        writeln!(file, "{}", line)
+    }
+
+    fn prune_before(&self, cutoff: OffsetDateTime) -> io::Result<usize> {
+        let _guard = self.lock.lock().unwrap_or_else(|e| e.into_inner());
+        let file = match File::open(&self.path) {
+            Ok(file) => file,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(0),
+            Err(e) => return Err(e),
+        };
+
+        let mut kept = Vec::new();
+        let mut pruned = 0;
+        for line in BufReader::new(file).lines() {
+            let line = line?;
+            let expired = serde_json::from_str::<SecurityAuditLog>(&line)
+                .is_ok_and(|entry| entry.timestamp < cutoff);
+            if expired {
+                pruned += 1;
+            } else if !line.is_empty() {
+                kept.push(line);
+            }
+        }
+        if pruned == 0 {
+            return Ok(0);
+        }
+
+        let tmp_path = self.path.with_extension("tmp");
+        let mut tmp = File::create(&tmp_path)?;
+        for line in &kept {
+            writeln!(tmp, "{}", line)?;
+        }
+        tmp.sync_all()?;
+        drop(tmp);
+        fs::rename(&tmp_path, &self.path)?;
+        Ok(pruned)
+    }
+}
+
+/// Audit store keeping entries in a memory backend
+///
+/// Each entry is stored under `audit:<unix millis>:<id>`, so pruning only
+/// needs a prefix scan. Works with any backend that can scan and delete
+/// keys, including Redis.
+pub struct MemoryAuditStore<M> {
+    memory: Mutex<M>,
+}
+
+impl<M> MemoryAuditStore<M> {
+    pub fn new(memory: M) -> Self {
+        Self {
+            memory: Mutex::new(memory),
+        }
+    }
+
+    /// Unwrap the underlying memory backend
+    pub fn into_inner(self) -> M {
+        self.memory.into_inner().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// Timestamp encoded in an audit key, in Unix milliseconds
+fn key_millis(key: &MemoryKey) -> Option<i128> {
+    key.as_str()
+        .strip_prefix(AUDIT_KEY_PREFIX)?
+        .split(':')
+        .next()?
+        .parse()
+        .ok()
+}
+
+fn unix_millis(timestamp: OffsetDateTime) -> i128 {
+    timestamp.unix_timestamp_nanos() / 1_000_000
+}
+
+impl<M> AuditStore for MemoryAuditStore<M>
+where
+    M: MemoryWriter + ScanableMemory + DeletableMemory + Send,
+{
+    fn append(&self, entry: &SecurityAuditLog) -> io::Result<()> {
+        let key = MemoryKey::new(&format!(
+            "{}{:013}:{}",
+            AUDIT_KEY_PREFIX,
+            unix_millis(entry.timestamp),
+            entry.id
+        ))
+        .map_err(io::Error::other)?;
+        let value = serde_json::to_string(entry).map_err(io::Error::other)?;
+
+        let mut memory = self.memory.lock().unwrap_or_else(|e| e.into_inner());
+        memory
+            .store(MemoryUpdate::from_validated(key, value))
+            .map_err(io::Error::other)
+    }
+
+    fn prune_before(&self, cutoff: OffsetDateTime) -> io::Result<usize> {
+        let cutoff = unix_millis(cutoff);
+        let mut memory = self.memory.lock().unwrap_or_else(|e| e.into_inner());
+        let keys = memory
+            .scan_prefix(AUDIT_KEY_PREFIX)
+            .map_err(io::Error::other)?;
+
+        let mut pruned = 0;
+        for key in keys {
+            if key_millis(&key).is_some_and(|millis| millis < cutoff)
+                && memory.delete(&key).map_err(io::Error::other)?
+            {
+                pruned += 1;
+            }
+        }
+        Ok(pruned)
+    }
+}
+
+/// How long audit entries are kept and how often old ones are pruned
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AuditRetention {
+    retain_days: u32,
+    interval: std::time::Duration,
+}
+
+impl AuditRetention {
+    /// Default time between background prune passes
+    pub const DEFAULT_INTERVAL: std::time::Duration = std::time::Duration::from_secs(3600);
+
+    /// Keep entries for `retain_days`, raised to [`MIN_RETENTION_DAYS`] if lower
+    pub fn new(retain_days: u32) -> Self {
+        if retain_days < MIN_RETENTION_DAYS {
+            tracing::warn!(
+                configured = retain_days,
+                enforced = MIN_RETENTION_DAYS,
+                "Audit retention below the safety floor, keeping logs for the minimum instead"
+            );
+        }
+        Self {
+            retain_days: retain_days.max(MIN_RETENTION_DAYS),
+            interval: Self::DEFAULT_INTERVAL,
+        }
+    }
+
+    /// Set how often the background enforcer prunes
+    pub fn with_interval(mut self, interval: std::time::Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Enforced retention window in days
+    pub fn retain_days(&self) -> u32 {
+        self.retain_days
+    }
+
+    /// Time between background prune passes
+    pub fn interval(&self) -> std::time::Duration {
+        self.interval
+    }
+
+    /// Oldest timestamp that is still retained at `now`
+    pub fn cutoff(&self, now: OffsetDateTime) -> OffsetDateTime {
+        now - time::Duration::days(i64::from(self.retain_days))
+    }
+}
+
+/// Snapshot of retention counters
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AuditRetentionStats {
+    /// Number of completed prune passes
+    pub runs: u64,
+    /// Total number of entries pruned
+    pub pruned_total: u64,
+    /// Entries pruned by the most recent pass
+    pub last_pruned: u64,
+    /// Number of prune passes that failed
+    pub errors: u64,
+}
+
+#[derive(Debug, Default)]
+struct RetentionCounters {
+    runs: AtomicU64,
+    pruned_total: AtomicU64,
+    last_pruned: AtomicU64,
+    errors: AtomicU64,
+}
+
+/// Prunes audit entries that fall outside the retention window
+#[derive(Clone)]
+pub struct AuditRetentionEnforcer {
+    retention: AuditRetention,
+    store: Arc<dyn AuditStore>,
+    counters: Arc<RetentionCounters>,
+}
+
+impl std::fmt::Debug for AuditRetentionEnforcer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AuditRetentionEnforcer")
+            .field("retention", &self.retention)
+            .field("stats", &self.stats())
+            .finish()
+    }
+}
+
+impl AuditRetentionEnforcer {
+    pub fn new(retention: AuditRetention, store: Arc<dyn AuditStore>) -> Self {
+        Self {
+            retention,
+            store,
+            counters: Arc::new(RetentionCounters::default()),
+        }
+    }
+
+    /// Retention settings being enforced
+    pub fn retention(&self) -> AuditRetention {
+        self.retention
+    }
+
+    /// Prune entries older than the retention window, returning how many were removed
+    pub fn prune_now(&self) -> io::Result<usize> {
+        self.prune_at(OffsetDateTime::now_utc())
+    }
+
+    fn prune_at(&self, now: OffsetDateTime) -> io::Result<usize> {
+        match self.store.prune_before(self.retention.cutoff(now)) {
+            Ok(pruned) => {
+                self.counters.runs.fetch_add(1, Ordering::Relaxed);
+                self.counters
+                    .pruned_total
+                    .fetch_add(pruned as u64, Ordering::Relaxed);
+                self.counters
+                    .last_pruned
+                    .store(pruned as u64, Ordering::Relaxed);
+                if pruned > 0 {
+                    tracing::info!(
+                        pruned,
+                        retain_days = self.retention.retain_days,
+                        "Pruned expired audit log entries"
+                    );
+                }
+                Ok(pruned)
+            }
+            Err(e) => {
+                self.counters.errors.fetch_add(1, Ordering::Relaxed);
+                Err(e)
+            }
+        }
+    }
+
+    /// Current retention counters
+    pub fn stats(&self) -> AuditRetentionStats {
+        AuditRetentionStats {
+            runs: self.counters.runs.load(Ordering::Relaxed),
+            pruned_total: self.counters.pruned_total.load(Ordering::Relaxed),
+            last_pruned: self.counters.last_pruned.load(Ordering::Relaxed),
+            errors: self.counters.errors.load(Ordering::Relaxed),
+        }
+    }
+
+    /// Prune on the configured interval in a background thread
+    ///
+    /// The returned handle stops the thread when
+    /// [`shutdown`](AuditRetentionHandle::shutdown) is called or it is dropped.
+    pub fn spawn(&self) -> AuditRetentionHandle {
+        let (stop_tx, stop_rx) = mpsc::channel::<()>();
+        let enforcer = self.clone();
+        let interval = self.retention.interval;
+
+        let thread = std::thread::spawn(move || {
+            // A stop message or a dropped handle ends the loop
+            while let Err(RecvTimeoutError::Timeout) = stop_rx.recv_timeout(interval) {
+                if let Err(e) = enforcer.prune_now() {
+                    tracing::warn!("Failed to prune audit log entries: {}", e);
+                }
+            }
+        });
+
+        AuditRetentionHandle {
+            stop_tx: Some(stop_tx),
+            thread: Some(thread),
+        }
+    }
+}
+
+/// Handle to a running background retention enforcer
+#[derive(Debug)]
+pub struct AuditRetentionHandle {
+    stop_tx: Option<mpsc::Sender<()>>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl AuditRetentionHandle {
+    /// Stop the enforcer and wait for any in-flight pass to finish
+    pub fn shutdown(mut self) {
+        self.stop();
+    }
+
+    fn stop(&mut self) {
+        if let Some(tx) = self.stop_tx.take() {
+            let _ = tx.send(());
+        }
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+impl Drop for AuditRetentionHandle {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::InMemoryMemory;
+    use crate::security::audit::{LogSeverity, SecurityEvent};
+    use std::collections::HashMap;
+    use uuid::Uuid;
+
+    fn entry_aged(days: i64) -> SecurityAuditLog {
+        SecurityAuditLog {
+            id: Uuid::new_v4(),
+            timestamp: OffsetDateTime::now_utc() - time::Duration::days(days),
+            event: SecurityEvent::EmergencyAction {
+                trigger: "test".to_string(),
+                action: format!("aged {} days", days),
+                affected_agents: Vec::new(),
+                timestamp: OffsetDateTime::now_utc(),
+            },
+            severity: LogSeverity::Info,
+            session_id: None,
+            agent_id: None,
+            tool_name: None,
+            correlation_id: None,
+            metadata: HashMap::new(),
+        }
+    }
+
+    fn seed(store: &dyn AuditStore) -> Vec<SecurityAuditLog> {
+        let entries: Vec<_> = [0, 5, 29, 31, 400].map(entry_aged).into();
+        for entry in &entries {
+            store.append(entry).unwrap();
+        }
+        entries
+    }
+
+    #[test]
+    fn test_file_store_prunes_entries_outside_window() {
+        let path = std::env::temp_dir().join(format!("audit-{}.jsonl", Uuid::new_v4()));
+        let store = Arc::new(FileAuditStore::new(&path));
+        let entries = seed(store.as_ref());
+        let enforcer = AuditRetentionEnforcer::new(AuditRetention::new(30), store.clone());
+
+        assert_eq!(enforcer.prune_now().unwrap(), 2);
+        assert_eq!(enforcer.prune_now().unwrap(), 0);
+
+        let remaining: Vec<Uuid> = fs::read_to_string(store.path())
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str::<SecurityAuditLog>(line).unwrap().id)
+            .collect();
+        assert_eq!(
+            remaining,
+            entries[..3].iter().map(|e| e.id).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            enforcer.stats(),
+            AuditRetentionStats {
+                runs: 2,
+                pruned_total: 2,
+                last_pruned: 0,
+                errors: 0,
+            }
+        );
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_memory_store_prunes_entries_outside_window() {
+        let store = Arc::new(MemoryAuditStore::new(InMemoryMemory::new()));
+        seed(store.as_ref());
+        let enforcer = AuditRetentionEnforcer::new(AuditRetention::new(30), store.clone());
+
+        assert_eq!(enforcer.prune_now().unwrap(), 2);
+        drop(enforcer);
+        let memory = Arc::try_unwrap(store).ok().unwrap().into_inner();
+        assert_eq!(memory.scan_prefix(AUDIT_KEY_PREFIX).unwrap().len(), 3);
+    }
+
+    #[test]
+    fn test_zero_retention_is_raised_to_safety_floor() {
+        let retention = AuditRetention::new(0);
+        assert_eq!(retention.retain_days(), MIN_RETENTION_DAYS);
+
+        let store = Arc::new(MemoryAuditStore::new(InMemoryMemory::new()));
+        seed(store.as_ref());
+        let enforcer = AuditRetentionEnforcer::new(retention, store);
+        // Only entries older than the floor go; today's entry survives
+        assert_eq!(enforcer.prune_now().unwrap(), 4);
+    }
+}
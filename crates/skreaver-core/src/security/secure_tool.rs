@@ -8,6 +8,11 @@ use crate::{ExecutionResult, Tool};
 use std::sync::Arc;
 
 /// A security-aware wrapper around any tool implementation
+///
+/// Calls whose input targets a URL draw one request from the manager's
+/// per-domain rate limits. Wrapped tools that check those limits themselves
+/// with the same manager, such as the HTTP tools, are not charged again for
+/// that request.
 pub struct SecureTool<T: Tool> {
     inner: T,
     security_manager: Arc<SecurityManager>,
@@ -31,18 +36,23 @@ impl<T: Tool> SecureTool<T> {
     pub fn secure_call(&self, input: String, context: SecurityContext) -> ExecutionResult {
         // 1. Validate input against security policies
         if let Err(e) = self.security_manager.validate_operation(&context, &input) {
+            if let SecurityError::RateLimited { .. } = e {
+                return ExecutionResult::failed(e.into());
+            }
             let error_msg = format!("Security validation failed: {}", e);
             return ExecutionResult::failure(error_msg);
         }
 
         // 2. Execute the tool (simplified - timeout enforcement would be added later)
-        let execution_result = self.inner.call(input);
+        let execution_result = self
+            .security_manager
+            .run_validated(&context, input, |input| self.inner.call(input));
         let result = Ok(execution_result);
 
         match result {
             Ok(execution_result) => {
                 // 3. Scan output for sensitive data if needed
-                if let ExecutionResult::Success { ref output } = execution_result
+                if let ExecutionResult::Success { ref output, .. } = execution_result
                     && let Err(scan_error) = self.scan_output_for_secrets(output)
                 {
                     return ExecutionResult::failure(format!(
@@ -187,7 +197,7 @@ mod tests {
         // Test normal operation
         let result = secure_tool.call("safe input".to_string());
         assert!(result.is_success());
-        if let ExecutionResult::Success { output } = result {
+        if let ExecutionResult::Success { output, .. } = result {
             assert_eq!(output, "safe output");
         }
     }
@@ -228,4 +238,33 @@ mod tests {
             assert!(reason.message().contains("Security validation failed"));
         }
     }
+
+    #[test]
+    fn test_secure_tool_enforces_domain_rate_limits() {
+        use crate::FailureReason;
+        use crate::security::DomainRateLimit;
+        use std::time::Duration;
+
+        let mut config = SecurityConfig::default();
+        config.http.rate_limits =
+            vec![DomainRateLimit::new("api.example.com", 1, Duration::from_secs(60)).unwrap()];
+        let factory = SecureToolFactory::new(Arc::new(SecurityManager::new(config)));
+        let secure_tool = factory.secure(MockTool::new("fetch".to_string(), "ok".to_string()));
+
+        let input = r#"{"url": "https://api.example.com/items"}"#;
+        assert!(secure_tool.call(input.to_string()).is_success());
+        match secure_tool.call(input.to_string()).failure_reason() {
+            Some(FailureReason::RateLimited {
+                resource,
+                retry_after_ms: Some(retry_after_ms),
+            }) => {
+                assert_eq!(resource, "api.example.com");
+                assert!(*retry_after_ms > 59_000);
+            }
+            other => panic!("expected rate limit failure, got {other:?}"),
+        }
+
+        // Inputs without a URL are not counted against any domain
+        assert!(secure_tool.call("plain input".to_string()).is_success());
+    }
 }
@@ -343,6 +343,7 @@ mod tests {
             deny_patterns: vec![],
             max_file_size: crate::security::policy::FileSizeLimit::megabytes(10).unwrap(),
             max_files_per_operation: crate::security::policy::FileCountLimit::new(100).unwrap(),
+            ..Default::default()
         }
     }
 
@@ -33,6 +33,7 @@
 //!     },
 //!     allow_methods: vec!["GET".to_string()],
 //!     default_headers: vec![],
+//!     rate_limits: vec![],
 //! };
 //!
 //! let validator = DomainValidator::new(&policy);
@@ -85,6 +86,7 @@ use url::Url;
 ///     },
 ///     allow_methods: vec!["GET".to_string()],
 ///     default_headers: vec![],
+///     rate_limits: vec![],
 /// };
 ///
 /// let validator = DomainValidator::new(&policy);
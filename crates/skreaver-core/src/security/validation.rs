@@ -149,12 +149,19 @@ impl InputValidator {
 }
 
 /// Path validator for file system operations
+///
+/// Validators share the policy's compiled allowed paths, see
+/// [`FileSystemPolicy::allowed_paths`]. An invalid glob pattern makes every
+/// validation fail with the compilation error.
 pub struct PathValidator {
     policy: FileSystemPolicy,
 }
 
 impl PathValidator {
     pub fn new(policy: &FileSystemPolicy) -> Self {
+        // Compile into the caller's policy so that later validators reuse it;
+        // an error is reported by `validate_path`
+        let _ = policy.allowed_paths();
         Self {
             policy: policy.clone(),
         }
@@ -177,6 +184,17 @@ impl PathValidator {
             });
         }
 
+        // Reject traversal before canonicalization resolves it, so `..` can never
+        // climb out of a directory matched by an allowed glob
+        if path_buf
+            .components()
+            .any(|c| matches!(c, std::path::Component::ParentDir))
+        {
+            return Err(SecurityError::ValidationFailed {
+                reason: "Path traversal (..) is not allowed".to_string(),
+            });
+        }
+
         // SECURITY (HIGH-3): Atomically canonicalize path without TOCTOU race
         // Use platform-specific fd-based canonicalization to prevent race between
         // symlink check and canonicalize() where attacker could swap path with symlink.
@@ -409,6 +427,7 @@ impl DomainValidator {
     ///     },
     ///     allow_methods: vec!["GET".to_string()],
     ///     default_headers: vec![],
+    ///     rate_limits: vec![],
     /// };
     ///
     /// let validator = DomainValidator::new(&policy);
@@ -949,4 +968,164 @@ mod tests {
             "Should block ftp:// scheme"
         );
     }
+
+    /// Create a tenant tree `<root>/tenants/{acme,globex}/uploads/nested`
+    fn create_tenant_tree() -> PathBuf {
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+        let root = std::env::temp_dir().join(format!("skreaver_glob_test_{}", now));
+        std::fs::create_dir_all(&root).unwrap();
+        let root = root.canonicalize().unwrap();
+        for tenant in ["acme", "globex"] {
+            let uploads = root.join("tenants").join(tenant).join("uploads");
+            std::fs::create_dir_all(uploads.join("nested")).unwrap();
+            std::fs::write(uploads.join("file.txt"), b"data").unwrap();
+            std::fs::write(uploads.join("nested").join("deep.txt"), b"data").unwrap();
+            std::fs::write(root.join("tenants").join(tenant).join("secret.txt"), b"x").unwrap();
+        }
+        root
+    }
+
+    fn glob_validator(patterns: &[String]) -> PathValidator {
+        PathValidator::new(&FileSystemPolicy {
+            allow_paths: patterns.iter().map(PathBuf::from).collect(),
+            deny_patterns: vec![],
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn test_path_validator_single_star_glob() {
+        let root = create_tenant_tree();
+        let validator = glob_validator(&[format!("{}/tenants/*/uploads", root.display())]);
+        let path = |rel: &str| root.join(rel).to_string_lossy().into_owned();
+
+        assert!(
+            validator
+                .validate_path(&path("tenants/acme/uploads/file.txt"))
+                .is_ok()
+        );
+        assert!(
+            validator
+                .validate_path(&path("tenants/globex/uploads/nested/deep.txt"))
+                .is_ok()
+        );
+        assert!(matches!(
+            validator.validate_path(&path("tenants/acme/secret.txt")),
+            Err(SecurityError::PathNotAllowed { .. })
+        ));
+
+        std::fs::remove_dir_all(&root).ok();
+    }
+
+    #[test]
+    fn test_path_validator_double_star_glob() {
+        let root = create_tenant_tree();
+        let validator = glob_validator(&[format!("{}/**/deep.txt", root.display())]);
+        let path = |rel: &str| root.join(rel).to_string_lossy().into_owned();
+
+        assert!(
+            validator
+                .validate_path(&path("tenants/acme/uploads/nested/deep.txt"))
+                .is_ok()
+        );
+        assert!(
+            validator
+                .validate_path(&path("tenants/acme/uploads/file.txt"))
+                .is_err()
+        );
+
+        std::fs::remove_dir_all(&root).ok();
+    }
+
+    #[test]
+    fn test_path_validator_glob_rejects_traversal() {
+        let root = create_tenant_tree();
+        let validator = glob_validator(&[format!("{}/tenants/*/uploads", root.display())]);
+
+        let escape = root.join("tenants/acme/uploads/../secret.txt");
+        let result = validator.validate_path(&escape.to_string_lossy());
+        assert!(
+            matches!(result, Err(SecurityError::ValidationFailed { ref reason }) if reason.contains("traversal")),
+            "Traversal out of a matched glob must be rejected, got {:?}",
+            result
+        );
+
+        let sibling = root.join("tenants/acme/uploads/../../globex/uploads/file.txt");
+        assert!(validator.validate_path(&sibling.to_string_lossy()).is_err());
+
+        std::fs::remove_dir_all(&root).ok();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_path_validator_glob_with_symlinked_prefix() {
+        let root = create_tenant_tree();
+        let link = root.join("link");
+        std::os::unix::fs::symlink(root.join("tenants"), &link).unwrap();
+        let validator = glob_validator(&[
+            format!("{}/*/uploads", link.display()),
+            format!("{}/./tenants/globex/**", root.display()),
+        ]);
+        let path = |rel: &str| root.join(rel).to_string_lossy().into_owned();
+
+        assert!(
+            validator
+                .validate_path(&path("tenants/acme/uploads/file.txt"))
+                .is_ok()
+        );
+        assert!(
+            validator
+                .validate_path(&path("tenants/globex/secret.txt"))
+                .is_ok()
+        );
+        assert!(matches!(
+            validator.validate_path(&path("tenants/acme/secret.txt")),
+            Err(SecurityError::PathNotAllowed { .. })
+        ));
+
+        std::fs::remove_dir_all(&root).ok();
+    }
+
+    #[test]
+    fn test_allowed_paths_compiled_once_and_follow_changes() {
+        use std::borrow::Cow;
+
+        let root = create_tenant_tree();
+        let mut policy = FileSystemPolicy {
+            allow_paths: vec![root.join("tenants/*/uploads")],
+            deny_patterns: vec![],
+            ..Default::default()
+        };
+        let file = root.join("tenants/acme/secret.txt");
+
+        assert!(matches!(policy.allowed_paths(), Ok(Cow::Borrowed(_))));
+        assert!(matches!(
+            policy.clone().allowed_paths(),
+            Ok(Cow::Borrowed(_))
+        ));
+        assert!(!policy.is_path_allowed(&file).unwrap());
+
+        policy.allow_paths = vec![root.join("tenants/*")];
+        assert!(matches!(policy.allowed_paths(), Ok(Cow::Owned(_))));
+        assert!(policy.is_path_allowed(&file).unwrap());
+
+        std::fs::remove_dir_all(&root).ok();
+    }
+
+    #[test]
+    fn test_path_validator_invalid_glob_fails_closed() {
+        let root = create_tenant_tree();
+        let validator = glob_validator(&[format!("{}/tenants/[acme/uploads", root.display())]);
+
+        let file = root.join("tenants/acme/uploads/file.txt");
+        assert!(matches!(
+            validator.validate_path(&file.to_string_lossy()),
+            Err(SecurityError::ConfigError { .. })
+        ));
+
+        std::fs::remove_dir_all(&root).ok();
+    }
 }
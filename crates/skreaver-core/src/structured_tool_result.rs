@@ -287,7 +287,9 @@ impl StructuredToolResult {
     ) -> Self {
         let metadata = ToolExecutionMetadata::instant(tool_name);
         match result {
-            super::tool::ExecutionResult::Success { output } => Self::Success { output, metadata },
+            super::tool::ExecutionResult::Success { output, .. } => {
+                Self::Success { output, metadata }
+            }
             super::tool::ExecutionResult::Failure { reason } => Self::Failure {
                 error: reason.message(),
                 metadata,
@@ -14,6 +14,7 @@ pub enum StandardTool {
     HttpPost,
     HttpPut,
     HttpDelete,
+    Url,
 
     // File I/O tools
     FileRead,
@@ -24,12 +25,18 @@ pub enum StandardTool {
     // Data processing tools
     JsonParse,
     JsonTransform,
+    JsonDiff,
+    JsonMerge,
+    DateTime,
     XmlParse,
     TextAnalyze,
     TextReverse,
     TextSearch,
     TextSplit,
     TextUppercase,
+
+    // Process tools
+    Command,
 }
 
 impl StandardTool {
@@ -40,18 +47,23 @@ impl StandardTool {
             StandardTool::HttpPost => "http_post",
             StandardTool::HttpPut => "http_put",
             StandardTool::HttpDelete => "http_delete",
+            StandardTool::Url => "url",
             StandardTool::FileRead => "file_read",
             StandardTool::FileWrite => "file_write",
             StandardTool::DirectoryList => "directory_list",
             StandardTool::DirectoryCreate => "directory_create",
             StandardTool::JsonParse => "json_parse",
             StandardTool::JsonTransform => "json_transform",
+            StandardTool::JsonDiff => "json_diff",
+            StandardTool::JsonMerge => "json_merge",
+            StandardTool::DateTime => "datetime",
             StandardTool::XmlParse => "xml_parse",
             StandardTool::TextAnalyze => "text_analyze",
             StandardTool::TextReverse => "text_reverse",
             StandardTool::TextSearch => "text_search",
             StandardTool::TextSplit => "text_split",
             StandardTool::TextUppercase => "text_uppercase",
+            StandardTool::Command => "command",
         }
     }
 
@@ -62,18 +74,23 @@ impl StandardTool {
             "http_post" => Some(StandardTool::HttpPost),
             "http_put" => Some(StandardTool::HttpPut),
             "http_delete" => Some(StandardTool::HttpDelete),
+            "url" => Some(StandardTool::Url),
             "file_read" => Some(StandardTool::FileRead),
             "file_write" => Some(StandardTool::FileWrite),
             "directory_list" => Some(StandardTool::DirectoryList),
             "directory_create" => Some(StandardTool::DirectoryCreate),
             "json_parse" => Some(StandardTool::JsonParse),
             "json_transform" => Some(StandardTool::JsonTransform),
+            "json_diff" => Some(StandardTool::JsonDiff),
+            "json_merge" => Some(StandardTool::JsonMerge),
+            "datetime" => Some(StandardTool::DateTime),
             "xml_parse" => Some(StandardTool::XmlParse),
             "text_analyze" => Some(StandardTool::TextAnalyze),
             "text_reverse" => Some(StandardTool::TextReverse),
             "text_search" => Some(StandardTool::TextSearch),
             "text_split" => Some(StandardTool::TextSplit),
             "text_uppercase" => Some(StandardTool::TextUppercase),
+            "command" => Some(StandardTool::Command),
             _ => None,
         }
     }
@@ -85,18 +102,23 @@ impl StandardTool {
             StandardTool::HttpPost,
             StandardTool::HttpPut,
             StandardTool::HttpDelete,
+            StandardTool::Url,
             StandardTool::FileRead,
             StandardTool::FileWrite,
             StandardTool::DirectoryList,
             StandardTool::DirectoryCreate,
             StandardTool::JsonParse,
             StandardTool::JsonTransform,
+            StandardTool::JsonDiff,
+            StandardTool::JsonMerge,
+            StandardTool::DateTime,
             StandardTool::XmlParse,
             StandardTool::TextAnalyze,
             StandardTool::TextReverse,
             StandardTool::TextSearch,
             StandardTool::TextSplit,
             StandardTool::TextUppercase,
+            StandardTool::Command,
         ]
     }
 }
@@ -331,8 +353,10 @@ impl std::error::Error for ToolCallBuildError {
 ///
 /// This enum provides structured error information instead of plain strings,
 /// making it easier to handle different failure types programmatically.
+/// New reasons may be added, so matches need a wildcard arm.
 #[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 #[serde(tag = "type", rename_all = "snake_case")]
+#[non_exhaustive]
 pub enum FailureReason {
     /// Invalid or malformed input provided to the tool
     InvalidInput {
@@ -369,6 +393,13 @@ pub enum FailureReason {
         /// Description of the internal error
         message: String,
     },
+    /// Request rejected by a rate limit
+    RateLimited {
+        /// What was rate limited (e.g. a domain)
+        resource: String,
+        /// Milliseconds until a retry can succeed, if known
+        retry_after_ms: Option<u64>,
+    },
     /// Custom error for tool-specific failures
     Custom {
         /// Error category or code
@@ -391,6 +422,14 @@ impl FailureReason {
             FailureReason::IoError { message } => format!("I/O error: {}", message),
             FailureReason::Timeout { operation } => format!("Timeout: {}", operation),
             FailureReason::InternalError { message } => format!("Internal error: {}", message),
+            FailureReason::RateLimited {
+                resource,
+                retry_after_ms: Some(ms),
+            } => format!("Rate limited: {} (retry after {}ms)", resource, ms),
+            FailureReason::RateLimited {
+                resource,
+                retry_after_ms: None,
+            } => format!("Rate limited: {}", resource),
             FailureReason::Custom { category, message } => format!("{}: {}", category, message),
         }
     }
@@ -412,8 +451,19 @@ pub enum ExecutionResult {
     /// Tool executed successfully with the given output.
     ///
     /// The output can be any string data - plain text, JSON, XML, etc.
-    /// The format depends on the specific tool implementation.
-    Success { output: String },
+    /// The format depends on the specific tool implementation. Construct it
+    /// with [`ExecutionResult::success`] or
+    /// [`ExecutionResult::success_with_content_type`], and match it with `..`
+    /// so that fields added later do not break callers.
+    #[non_exhaustive]
+    Success {
+        output: String,
+        /// MIME type of the output, if the tool declares one.
+        ///
+        /// Output with a binary content type (anything other than `text/*`,
+        /// JSON or XML) is base64-encoded.
+        content_type: Option<String>,
+    },
 
     /// Tool execution failed with a structured reason.
     ///
@@ -433,7 +483,30 @@ impl ExecutionResult {
     ///
     /// An `ExecutionResult::Success` variant
     pub fn success(output: String) -> Self {
-        ExecutionResult::Success { output }
+        ExecutionResult::Success {
+            output,
+            content_type: None,
+        }
+    }
+
+    /// Create a successful execution result with a declared content type.
+    ///
+    /// Binary content (e.g. `image/png`) must be base64-encoded in `output`;
+    /// textual content (`text/*`, JSON, XML) is passed through as is.
+    ///
+    /// # Parameters
+    ///
+    /// * `output` - The successful output from the tool
+    /// * `content_type` - MIME type of the output
+    ///
+    /// # Returns
+    ///
+    /// An `ExecutionResult::Success` variant carrying the content type
+    pub fn success_with_content_type(output: String, content_type: impl Into<String>) -> Self {
+        ExecutionResult::Success {
+            output,
+            content_type: Some(content_type.into()),
+        }
     }
 
     /// Create a failed execution result with a structured reason.
@@ -494,7 +567,7 @@ impl ExecutionResult {
     /// The output string or error message
     pub fn output(&self) -> String {
         match self {
-            ExecutionResult::Success { output } => output.clone(),
+            ExecutionResult::Success { output, .. } => output.clone(),
             ExecutionResult::Failure { reason } => reason.message(),
         }
     }
@@ -506,7 +579,19 @@ impl ExecutionResult {
     /// `Some(output)` if successful, `None` if failed
     pub fn success_output(&self) -> Option<&str> {
         match self {
-            ExecutionResult::Success { output } => Some(output),
+            ExecutionResult::Success { output, .. } => Some(output),
+            ExecutionResult::Failure { .. } => None,
+        }
+    }
+
+    /// Get the declared content type of the success output, if any.
+    ///
+    /// # Returns
+    ///
+    /// `Some(content_type)` if the tool declared one, `None` otherwise
+    pub fn content_type(&self) -> Option<&str> {
+        match self {
+            ExecutionResult::Success { content_type, .. } => content_type.as_deref(),
             ExecutionResult::Failure { .. } => None,
         }
     }
@@ -542,7 +627,7 @@ impl ExecutionResult {
     /// `Ok(output)` if successful, `Err(error_message)` if failed
     pub fn into_result(self) -> Result<String, String> {
         match self {
-            ExecutionResult::Success { output } => Ok(output),
+            ExecutionResult::Success { output, .. } => Ok(output),
             ExecutionResult::Failure { reason } => Err(reason.message()),
         }
     }
@@ -699,7 +784,7 @@ impl std::fmt::Display for ToolInput {
 ///
 ///     fn call(&self, input: String) -> ExecutionResult {
 ///         if let Ok(num) = input.parse::<f64>() {
-///             ExecutionResult::Success { output: (num * 2.0).to_string() }
+///             ExecutionResult::success((num * 2.0).to_string())
 ///         } else {
 ///             ExecutionResult::Failure {
 ///                 reason: FailureReason::InvalidInput {
@@ -1046,9 +1131,7 @@ mod tests {
         }
 
         fn call(&self, input: String) -> ExecutionResult {
-            ExecutionResult::Success {
-                output: format!("Echo: {input}"),
-            }
+            ExecutionResult::success(format!("Echo: {input}"))
         }
     }
 
@@ -1066,6 +1149,19 @@ mod tests {
         assert_eq!(tool.name(), "echo");
     }
 
+    #[test]
+    fn success_carries_declared_content_type() {
+        let result = ExecutionResult::success_with_content_type("aGk=".into(), "image/png");
+        assert_eq!(result.content_type(), Some("image/png"));
+        assert_eq!(result.success_output(), Some("aGk="));
+
+        assert_eq!(
+            ExecutionResult::success("plain".into()).content_type(),
+            None
+        );
+        assert_eq!(ExecutionResult::failure("boom".into()).content_type(), None);
+    }
+
     #[test]
     fn test_tool_call_builder() {
         let call = ToolCall::builder()
@@ -11,16 +11,31 @@ use std::collections::HashMap;
 
 use skreaver_core::memory::{MemoryKeys, MemoryReader, MemoryWriter};
 use skreaver_core::{Agent, ExecutionResult, InMemoryMemory, MemoryUpdate, Tool, ToolCall};
+use skreaver_memory::NamespacedMemory;
+use skreaver_observability::StepOutcome;
 use skreaver_tools::InMemoryToolRegistry;
 use std::sync::Arc;
 
 use crate::runtime::{
-    agent_factory::{AgentBuilder, AgentFactoryError},
+    agent_factory::{AgentBuilder, AgentFactoryError, BuildContext},
     agent_instance::CoordinatorTrait,
     api_types::{AgentSpec, AgentType},
-    coordinator::Coordinator,
+    backpressure::RequestPriority,
+    coordinator::{
+        Coordinator, ErrorStrategy, MemoryIsolation, ScratchAgent, SharedMemoryAgent,
+        normalization_from_config,
+    },
 };
 
+/// Memory of the built-in agents: a namespaced view of a backend that may be
+/// shared with other agents
+type AgentMemory = NamespacedMemory<InMemoryMemory>;
+
+/// Memory for an agent that does not share a backend
+fn private_memory() -> AgentMemory {
+    NamespacedMemory::new("agent", InMemoryMemory::default())
+}
+
 /// Simple mock tool for testing
 struct MockTool {
     name: String,
@@ -46,14 +61,14 @@ impl Tool for MockTool {
 
 /// Echo agent implementation - simple agent that echoes input
 pub struct EchoAgent {
-    memory: InMemoryMemory,
+    memory: AgentMemory,
     last_input: Option<String>,
 }
 
 impl EchoAgent {
     pub fn new(_config: HashMap<String, Value>) -> Result<Self, AgentBuildError> {
         Ok(Self {
-            memory: InMemoryMemory::default(),
+            memory: private_memory(),
             last_input: None,
         })
     }
@@ -113,9 +128,26 @@ impl Agent for EchoAgent {
     }
 }
 
+impl SharedMemoryAgent for EchoAgent {
+    type Backend = InMemoryMemory;
+
+    fn attach_memory(&mut self, memory: AgentMemory) {
+        self.memory = memory;
+    }
+}
+
+impl ScratchAgent for EchoAgent {
+    fn scratch(&self) -> Self {
+        Self {
+            memory: self.memory.fork(),
+            last_input: self.last_input.clone(),
+        }
+    }
+}
+
 /// Advanced processing agent with tool capabilities
 pub struct AdvancedAgent {
-    memory: InMemoryMemory,
+    memory: AgentMemory,
     context: String,
     processing_mode: ProcessingMode,
     use_tools: bool,
@@ -148,7 +180,7 @@ impl AdvancedAgent {
         let use_tools = config.get_bool_or("use_tools", true);
 
         Ok(Self {
-            memory: InMemoryMemory::default(),
+            memory: private_memory(),
             context: String::new(),
             processing_mode,
             use_tools,
@@ -238,9 +270,28 @@ impl Agent for AdvancedAgent {
     }
 }
 
+impl SharedMemoryAgent for AdvancedAgent {
+    type Backend = InMemoryMemory;
+
+    fn attach_memory(&mut self, memory: AgentMemory) {
+        self.memory = memory;
+    }
+}
+
+impl ScratchAgent for AdvancedAgent {
+    fn scratch(&self) -> Self {
+        Self {
+            memory: self.memory.fork(),
+            context: self.context.clone(),
+            processing_mode: self.processing_mode.clone(),
+            use_tools: self.use_tools,
+        }
+    }
+}
+
 /// Analytics agent for data analysis tasks
 pub struct AnalyticsAgent {
-    memory: InMemoryMemory,
+    memory: AgentMemory,
     data: Vec<String>,
     analysis_depth: AnalysisDepth,
 }
@@ -261,7 +312,7 @@ impl AnalyticsAgent {
         };
 
         Ok(Self {
-            memory: InMemoryMemory::default(),
+            memory: private_memory(),
             data: Vec::new(),
             analysis_depth,
         })
@@ -376,6 +427,24 @@ impl Agent for AnalyticsAgent {
     }
 }
 
+impl SharedMemoryAgent for AnalyticsAgent {
+    type Backend = InMemoryMemory;
+
+    fn attach_memory(&mut self, memory: AgentMemory) {
+        self.memory = memory;
+    }
+}
+
+impl ScratchAgent for AnalyticsAgent {
+    fn scratch(&self) -> Self {
+        Self {
+            memory: self.memory.fork(),
+            data: self.data.clone(),
+            analysis_depth: self.analysis_depth.clone(),
+        }
+    }
+}
+
 // Coordinator wrappers that implement CoordinatorTrait
 pub struct EchoCoordinator {
     coordinator: Coordinator<EchoAgent, InMemoryToolRegistry>,
@@ -383,6 +452,17 @@ pub struct EchoCoordinator {
 
 impl EchoCoordinator {
     pub fn new(config: HashMap<String, Value>) -> Result<Self, AgentBuildError> {
+        Self::with_context(config, &BuildContext::default())
+    }
+
+    /// Build the coordinator wired to the runtime resources in `context`
+    pub fn with_context(
+        config: HashMap<String, Value>,
+        context: &BuildContext,
+    ) -> Result<Self, AgentBuildError> {
+        let error_strategy = ErrorStrategy::from_config(&config)?;
+        let isolation = MemoryIsolation::from_config(&config)?;
+        let normalization = normalization_from_config(&config);
         let mut agent = EchoAgent::new(config)?;
 
         // Initialize the agent before use
@@ -394,9 +474,14 @@ impl EchoCoordinator {
             })?;
 
         let registry = InMemoryToolRegistry::new();
-        Ok(Self {
-            coordinator: Coordinator::new(agent, registry),
-        })
+        let mut coordinator = Coordinator::new(agent, registry)
+            .with_error_strategy(error_strategy)
+            .with_build_context(context)
+            .with_context_memory(context, isolation);
+        if let Some(pipeline) = normalization {
+            coordinator = coordinator.with_normalization(pipeline);
+        }
+        Ok(Self { coordinator })
     }
 }
 
@@ -405,9 +490,25 @@ impl CoordinatorTrait for EchoCoordinator {
         self.coordinator.step(input)
     }
 
+    fn step_with_outcome(&mut self, input: String) -> (String, StepOutcome) {
+        self.coordinator.step_with_outcome(input)
+    }
+
+    fn tool_invocations(&self) -> u64 {
+        self.coordinator.tool_invocations()
+    }
+
+    fn set_priority(&mut self, priority: RequestPriority) {
+        self.coordinator.set_priority(priority);
+    }
+
     fn get_agent_type(&self) -> &'static str {
         "EchoAgent"
     }
+
+    fn plan(&self, input: String) -> Option<Vec<ToolCall>> {
+        Some(self.coordinator.plan(input))
+    }
 }
 
 impl Drop for EchoCoordinator {
@@ -425,6 +526,17 @@ pub struct AdvancedCoordinator {
 
 impl AdvancedCoordinator {
     pub fn new(config: HashMap<String, Value>) -> Result<Self, AgentBuildError> {
+        Self::with_context(config, &BuildContext::default())
+    }
+
+    /// Build the coordinator wired to the runtime resources in `context`
+    pub fn with_context(
+        config: HashMap<String, Value>,
+        context: &BuildContext,
+    ) -> Result<Self, AgentBuildError> {
+        let error_strategy = ErrorStrategy::from_config(&config)?;
+        let isolation = MemoryIsolation::from_config(&config)?;
+        let normalization = normalization_from_config(&config);
         let mut agent = AdvancedAgent::new(config)?;
 
         // Initialize the agent before use
@@ -443,9 +555,14 @@ impl AdvancedCoordinator {
             .with_tool("count_words", Arc::new(MockTool::new("count_words")))
             .with_tool("generate_ideas", Arc::new(MockTool::new("generate_ideas")));
 
-        Ok(Self {
-            coordinator: Coordinator::new(agent, registry),
-        })
+        let mut coordinator = Coordinator::new(agent, registry)
+            .with_error_strategy(error_strategy)
+            .with_build_context(context)
+            .with_context_memory(context, isolation);
+        if let Some(pipeline) = normalization {
+            coordinator = coordinator.with_normalization(pipeline);
+        }
+        Ok(Self { coordinator })
     }
 }
 
@@ -454,9 +571,25 @@ impl CoordinatorTrait for AdvancedCoordinator {
         self.coordinator.step(input)
     }
 
+    fn step_with_outcome(&mut self, input: String) -> (String, StepOutcome) {
+        self.coordinator.step_with_outcome(input)
+    }
+
+    fn tool_invocations(&self) -> u64 {
+        self.coordinator.tool_invocations()
+    }
+
+    fn set_priority(&mut self, priority: RequestPriority) {
+        self.coordinator.set_priority(priority);
+    }
+
     fn get_agent_type(&self) -> &'static str {
         "AdvancedDemoAgent"
     }
+
+    fn plan(&self, input: String) -> Option<Vec<ToolCall>> {
+        Some(self.coordinator.plan(input))
+    }
 }
 
 impl Drop for AdvancedCoordinator {
@@ -474,6 +607,17 @@ pub struct AnalyticsCoordinator {
 
 impl AnalyticsCoordinator {
     pub fn new(config: HashMap<String, Value>) -> Result<Self, AgentBuildError> {
+        Self::with_context(config, &BuildContext::default())
+    }
+
+    /// Build the coordinator wired to the runtime resources in `context`
+    pub fn with_context(
+        config: HashMap<String, Value>,
+        context: &BuildContext,
+    ) -> Result<Self, AgentBuildError> {
+        let error_strategy = ErrorStrategy::from_config(&config)?;
+        let isolation = MemoryIsolation::from_config(&config)?;
+        let normalization = normalization_from_config(&config);
         let mut agent = AnalyticsAgent::new(config)?;
 
         // Initialize the agent before use
@@ -498,9 +642,14 @@ impl AnalyticsCoordinator {
             )
             .with_tool("trend_analysis", Arc::new(MockTool::new("trend_analysis")));
 
-        Ok(Self {
-            coordinator: Coordinator::new(agent, registry),
-        })
+        let mut coordinator = Coordinator::new(agent, registry)
+            .with_error_strategy(error_strategy)
+            .with_build_context(context)
+            .with_context_memory(context, isolation);
+        if let Some(pipeline) = normalization {
+            coordinator = coordinator.with_normalization(pipeline);
+        }
+        Ok(Self { coordinator })
     }
 }
 
@@ -509,9 +658,25 @@ impl CoordinatorTrait for AnalyticsCoordinator {
         self.coordinator.step(input)
     }
 
+    fn step_with_outcome(&mut self, input: String) -> (String, StepOutcome) {
+        self.coordinator.step_with_outcome(input)
+    }
+
+    fn tool_invocations(&self) -> u64 {
+        self.coordinator.tool_invocations()
+    }
+
+    fn set_priority(&mut self, priority: RequestPriority) {
+        self.coordinator.set_priority(priority);
+    }
+
     fn get_agent_type(&self) -> &'static str {
         "AnalyticsAgent"
     }
+
+    fn plan(&self, input: String) -> Option<Vec<ToolCall>> {
+        Some(self.coordinator.plan(input))
+    }
 }
 
 impl Drop for AnalyticsCoordinator {
@@ -535,12 +700,21 @@ impl AgentBuilder for EchoAgentBuilder {
         &self,
         spec: &AgentSpec,
     ) -> Result<Box<dyn CoordinatorTrait + Send + Sync>, AgentFactoryError> {
-        let coordinator = EchoCoordinator::new(spec.config.clone()).map_err(|e| {
-            AgentFactoryError::CreationFailed {
-                agent_type: self.agent_type(),
-                reason: e.to_string(),
-            }
-        })?;
+        self.build_coordinator_with(spec, &BuildContext::default())
+    }
+
+    fn build_coordinator_with(
+        &self,
+        spec: &AgentSpec,
+        context: &BuildContext,
+    ) -> Result<Box<dyn CoordinatorTrait + Send + Sync>, AgentFactoryError> {
+        let coordinator =
+            EchoCoordinator::with_context(spec.config.clone(), context).map_err(|e| {
+                AgentFactoryError::CreationFailed {
+                    agent_type: self.agent_type(),
+                    reason: e.to_string(),
+                }
+            })?;
         Ok(Box::new(coordinator))
     }
 
@@ -574,12 +748,21 @@ impl AgentBuilder for AdvancedAgentBuilder {
         &self,
         spec: &AgentSpec,
     ) -> Result<Box<dyn CoordinatorTrait + Send + Sync>, AgentFactoryError> {
-        let coordinator = AdvancedCoordinator::new(spec.config.clone()).map_err(|e| {
-            AgentFactoryError::CreationFailed {
-                agent_type: self.agent_type(),
-                reason: e.to_string(),
-            }
-        })?;
+        self.build_coordinator_with(spec, &BuildContext::default())
+    }
+
+    fn build_coordinator_with(
+        &self,
+        spec: &AgentSpec,
+        context: &BuildContext,
+    ) -> Result<Box<dyn CoordinatorTrait + Send + Sync>, AgentFactoryError> {
+        let coordinator =
+            AdvancedCoordinator::with_context(spec.config.clone(), context).map_err(|e| {
+                AgentFactoryError::CreationFailed {
+                    agent_type: self.agent_type(),
+                    reason: e.to_string(),
+                }
+            })?;
         Ok(Box::new(coordinator))
     }
 
@@ -635,12 +818,19 @@ impl AgentBuilder for AnalyticsAgentBuilder {
         &self,
         spec: &AgentSpec,
     ) -> Result<Box<dyn CoordinatorTrait + Send + Sync>, AgentFactoryError> {
-        let coordinator = AnalyticsCoordinator::new(spec.config.clone()).map_err(|e| {
-            AgentFactoryError::CreationFailed {
+        self.build_coordinator_with(spec, &BuildContext::default())
+    }
+
+    fn build_coordinator_with(
+        &self,
+        spec: &AgentSpec,
+        context: &BuildContext,
+    ) -> Result<Box<dyn CoordinatorTrait + Send + Sync>, AgentFactoryError> {
+        let coordinator = AnalyticsCoordinator::with_context(spec.config.clone(), context)
+            .map_err(|e| AgentFactoryError::CreationFailed {
                 agent_type: self.agent_type(),
                 reason: e.to_string(),
-            }
-        })?;
+            })?;
         Ok(Box::new(coordinator))
     }
 
@@ -688,6 +878,7 @@ impl AgentBuilder for AnalyticsAgentBuilder {
 mod tests {
     use super::*;
     use crate::runtime::api_types::AgentLimits;
+    use skreaver_core::memory::ScanableMemory;
 
     #[test]
     fn test_echo_agent() {
@@ -759,6 +950,30 @@ mod tests {
         assert_eq!(coordinator.get_agent_type(), "EchoAgent");
     }
 
+    #[test]
+    fn test_builtin_coordinator_plan_has_no_side_effects() {
+        let mut config = HashMap::new();
+        config.insert("mode".to_string(), Value::String("analytical".to_string()));
+        let mut coordinator = AdvancedCoordinator::new(config).unwrap();
+
+        let plan = coordinator.plan("Hello World".to_string()).unwrap();
+        let names: Vec<&str> = plan.iter().map(|call| call.name()).collect();
+        assert_eq!(names, ["analyze_text", "count_words"]);
+        assert!(plan.iter().all(|call| call.input == "Hello World"));
+
+        // Planning neither ran tools nor touched the agent's state or memory
+        assert_eq!(coordinator.tool_invocations(), 0);
+        assert!(coordinator.coordinator.tool_calls().is_empty());
+        let memory = coordinator.coordinator.agent.memory_reader();
+        assert_eq!(memory.load(&MemoryKeys::context()).unwrap(), None);
+
+        // A real step afterwards observes the input and runs the planned calls
+        coordinator.step("Hello World".to_string());
+        let memory = coordinator.coordinator.agent.memory_reader();
+        assert!(memory.load(&MemoryKeys::context()).unwrap().is_some());
+        assert_eq!(coordinator.tool_invocations(), 2);
+    }
+
     #[test]
     fn test_advanced_agent_validation() {
         let builder = AdvancedAgentBuilder;
@@ -785,4 +1000,65 @@ mod tests {
         };
         assert!(builder.validate_spec(&spec).is_err());
     }
+
+    #[test]
+    fn test_builtin_agents_isolate_shared_memory_per_agent() {
+        let backend = InMemoryMemory::new();
+        let context = BuildContext::new().with_shared_memory(backend.clone());
+        let build = |id: &str, config: HashMap<String, Value>| {
+            let id = skreaver_core::AgentId::parse(id).unwrap();
+            EchoCoordinator::with_context(config, &context.for_agent(id)).unwrap()
+        };
+        let last_input = |coordinator: &EchoCoordinator| {
+            let memory = coordinator.coordinator.agent.memory_reader();
+            memory.load(&MemoryKeys::last_input()).unwrap()
+        };
+
+        let mut planner = build("planner", HashMap::new());
+        let mut critic = build("critic", HashMap::new());
+        assert_eq!(
+            planner.coordinator.memory_namespace(),
+            Some("agent:planner")
+        );
+        planner.step("plan v1".to_string());
+        critic.step("looks risky".to_string());
+        assert_eq!(last_input(&planner).as_deref(), Some("plan v1"));
+        assert_eq!(last_input(&critic).as_deref(), Some("looks risky"));
+
+        // Opting into a group shares keys with its members only
+        let team = || {
+            HashMap::from([(
+                MemoryIsolation::CONFIG_KEY.to_string(),
+                Value::String("team".to_string()),
+            )])
+        };
+        let mut writer = build("writer", team());
+        let reviewer = build("reviewer", team());
+        writer.step("draft".to_string());
+        assert_eq!(last_input(&reviewer).as_deref(), Some("draft"));
+        assert_eq!(last_input(&planner).as_deref(), Some("plan v1"));
+
+        let mut keys: Vec<String> = backend
+            .scan_prefix("")
+            .unwrap()
+            .into_iter()
+            .map(|key| key.as_str().to_string())
+            .collect();
+        keys.sort();
+        let last_input_key = MemoryKeys::last_input();
+        assert_eq!(
+            keys,
+            [
+                format!("agent:critic:{}", last_input_key.as_str()),
+                format!("agent:planner:{}", last_input_key.as_str()),
+                format!("shared:team:{}", last_input_key.as_str()),
+            ]
+        );
+
+        let empty_group = HashMap::from([(
+            MemoryIsolation::CONFIG_KEY.to_string(),
+            Value::String(" ".to_string()),
+        )]);
+        assert!(EchoCoordinator::with_context(empty_group, &context).is_err());
+    }
 }
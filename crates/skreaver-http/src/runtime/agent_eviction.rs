@@ -0,0 +1,78 @@
+//! Idle agent eviction
+//!
+//! Agents created for short interactions would otherwise stay in memory until
+//! they are deleted explicitly. The runtime periodically removes agents that
+//! have had no activity for longer than the configured idle timeout; dropping
+//! an evicted agent runs its `cleanup` hook. Eviction is off unless an idle
+//! timeout is configured. Agents marked persistent, either
+//! with [`AgentInstance::set_persistent`](crate::runtime::AgentInstance::set_persistent)
+//! or by creating them with `"persistent": true` in their spec config, are
+//! never evicted.
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+/// Suggested idle time after which an agent is evicted
+pub const DEFAULT_IDLE_TIMEOUT: Duration = Duration::from_secs(30 * 60);
+
+/// Default interval between idle agent sweeps
+pub const DEFAULT_SWEEP_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Shortest allowed interval between idle agent sweeps
+pub const MIN_SWEEP_INTERVAL: Duration = Duration::from_millis(1);
+
+/// Spec config key marking an agent as persistent
+pub const PERSISTENT_AGENT_KEY: &str = "persistent";
+
+/// Idle agent eviction configuration
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AgentEvictionConfig {
+    /// Idle time after which an agent is evicted (`None` = never evict)
+    pub idle_timeout: Option<Duration>,
+    /// How often to look for idle agents
+    pub sweep_interval: Duration,
+}
+
+impl AgentEvictionConfig {
+    /// Configuration that never evicts agents
+    pub const fn disabled() -> Self {
+        Self {
+            idle_timeout: None,
+            sweep_interval: DEFAULT_SWEEP_INTERVAL,
+        }
+    }
+
+    /// Evict agents idle for longer than `idle_timeout`
+    pub const fn after(idle_timeout: Duration) -> Self {
+        Self {
+            idle_timeout: Some(idle_timeout),
+            sweep_interval: DEFAULT_SWEEP_INTERVAL,
+        }
+    }
+
+    /// Set how often to look for idle agents
+    ///
+    /// A zero interval is raised to [`MIN_SWEEP_INTERVAL`].
+    pub const fn with_sweep_interval(mut self, sweep_interval: Duration) -> Self {
+        self.sweep_interval = if sweep_interval.is_zero() {
+            MIN_SWEEP_INTERVAL
+        } else {
+            sweep_interval
+        };
+        self
+    }
+}
+
+impl Default for AgentEvictionConfig {
+    fn default() -> Self {
+        Self::disabled()
+    }
+}
+
+/// Whether an agent spec config marks the agent as persistent
+pub(crate) fn is_persistent_spec(config: &HashMap<String, serde_json::Value>) -> bool {
+    config
+        .get(PERSISTENT_AGENT_KEY)
+        .and_then(serde_json::Value::as_bool)
+        .unwrap_or(false)
+}
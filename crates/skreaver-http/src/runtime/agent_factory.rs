@@ -4,17 +4,38 @@
 //! dynamic agent creation from specifications. The factory maintains
 //! a registry of agent builders and handles the complete agent lifecycle.
 
+use skreaver_core::InMemoryMemory;
 use std::collections::HashMap;
 use std::sync::Arc;
+use std::time::Duration;
 use tokio::sync::RwLock;
 use uuid::Uuid;
 
 use crate::runtime::{
+    agent_eviction::is_persistent_spec,
     agent_instance::{AgentId, AgentInstance, CoordinatorTrait},
+    agent_quota::{AgentQuota, AgentQuotaConfig},
     agent_status::AgentStatusEnum,
-    api_types::{AgentEndpoints, AgentSpec, AgentType, CreateAgentResponse},
+    api_types::{
+        AgentEndpoints, AgentSpec, AgentType, CreateAgentResponse, OnConflict, SpecValidationError,
+    },
+    approval::ApprovalGate,
+    tool_limits::ToolConcurrencyLimiter,
 };
 
+/// Namespace for agent IDs derived from natural keys (UUID v5)
+const NATURAL_KEY_NAMESPACE: Uuid = Uuid::from_u128(0x6b1f_4a0e_5c2d_4e8b_9a37_d1c0_e5f2_7a94);
+
+/// Deterministic agent ID for `natural_key`, namespaced per principal
+///
+/// The same principal and key always map to the same ID, while equal keys
+/// from different principals never collide.
+pub fn natural_key_agent_id(principal: Option<&str>, natural_key: &str) -> String {
+    let name = format!("{}\u{0}{}", principal.unwrap_or_default(), natural_key);
+    let uuid = Uuid::new_v5(&NATURAL_KEY_NAMESPACE, name.as_bytes());
+    format!("agent-{}", uuid.simple())
+}
+
 /// Factory error types
 #[derive(Debug, Clone)]
 pub enum AgentFactoryError {
@@ -33,6 +54,15 @@ pub enum AgentFactoryError {
     AgentNotFound(String),
     /// Invalid agent configuration
     InvalidConfiguration { field: String, reason: String },
+    /// Agent specification failed validation (all problems are reported)
+    InvalidSpec(SpecValidationError),
+    /// The runtime already holds the maximum number of agents
+    AgentLimitReached { max_agents: usize },
+    /// The principal created too many agents recently
+    CreationRateLimited {
+        principal: String,
+        retry_after_secs: u64,
+    },
 }
 
 impl std::fmt::Display for AgentFactoryError {
@@ -52,12 +82,78 @@ impl std::fmt::Display for AgentFactoryError {
             Self::InvalidConfiguration { field, reason } => {
                 write!(f, "Invalid configuration for field '{}': {}", field, reason)
             }
+            Self::InvalidSpec(err) => write!(f, "{}", err),
+            Self::AgentLimitReached { max_agents } => {
+                write!(
+                    f,
+                    "Agent limit reached: {} agents already exist, remove one first",
+                    max_agents
+                )
+            }
+            Self::CreationRateLimited {
+                principal,
+                retry_after_secs,
+            } => write!(
+                f,
+                "Agent creation rate limit exceeded for '{}', retry in {}s",
+                principal, retry_after_secs
+            ),
         }
     }
 }
 
 impl std::error::Error for AgentFactoryError {}
 
+/// Runtime resources handed to builders for every agent they build
+///
+/// Apply it with [`Coordinator::with_build_context`](crate::runtime::Coordinator::with_build_context).
+#[derive(Debug, Clone, Default)]
+#[non_exhaustive]
+pub struct BuildContext {
+    /// Gate holding tool calls for approval (None = calls run unapproved)
+    pub approval_gate: Option<ApprovalGate>,
+    /// Per-tool concurrency limits shared by built agents (None = unlimited)
+    pub tool_limiter: Option<ToolConcurrencyLimiter>,
+    /// Memory backend shared by built agents, each seeing only its own
+    /// namespace unless its spec sets a memory group (None = private memory)
+    pub shared_memory: Option<InMemoryMemory>,
+    /// Id of the agent being built, set by the factory for every build
+    pub agent_id: Option<AgentId>,
+}
+
+impl BuildContext {
+    /// Create a context without shared resources
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Hold the tool calls of built agents at `gate`
+    pub fn with_approval_gate(mut self, gate: ApprovalGate) -> Self {
+        self.approval_gate = Some(gate);
+        self
+    }
+
+    /// Make the tool calls of built agents share the slots of `limiter`
+    pub fn with_tool_limiter(mut self, limiter: ToolConcurrencyLimiter) -> Self {
+        self.tool_limiter = Some(limiter);
+        self
+    }
+
+    /// Keep the memory of built agents in `backend`, namespaced per agent
+    pub fn with_shared_memory(mut self, backend: InMemoryMemory) -> Self {
+        self.shared_memory = Some(backend);
+        self
+    }
+
+    /// Copy of this context for building the agent `agent_id`
+    pub fn for_agent(&self, agent_id: AgentId) -> Self {
+        Self {
+            agent_id: Some(agent_id),
+            ..self.clone()
+        }
+    }
+}
+
 /// Trait for building specific agent types
 pub trait AgentBuilder: Send + Sync {
     /// Get the agent type this builder handles
@@ -69,6 +165,18 @@ pub trait AgentBuilder: Send + Sync {
         spec: &AgentSpec,
     ) -> Result<Box<dyn CoordinatorTrait + Send + Sync>, AgentFactoryError>;
 
+    /// Create a coordinator wired to the runtime resources in `context`
+    ///
+    /// The factory always builds through this method. The default ignores
+    /// `context` and calls [`build_coordinator`](Self::build_coordinator).
+    fn build_coordinator_with(
+        &self,
+        spec: &AgentSpec,
+        _context: &BuildContext,
+    ) -> Result<Box<dyn CoordinatorTrait + Send + Sync>, AgentFactoryError> {
+        self.build_coordinator(spec)
+    }
+
     /// Validate agent specification before creation
     fn validate_spec(&self, spec: &AgentSpec) -> Result<(), AgentFactoryError> {
         // Default validation - can be overridden
@@ -97,26 +205,50 @@ pub struct AgentFactory {
     builders: HashMap<AgentType, Box<dyn AgentBuilder>>,
     /// Created agent instances (using AgentId as key for type safety)
     agents: Arc<RwLock<HashMap<AgentId, AgentInstance>>>,
+    /// Agent count and creation rate limits
+    quota: AgentQuota,
+    /// Resources handed to builders
+    build_context: BuildContext,
 }
 
 impl AgentFactory {
-    /// Create a new agent factory
+    /// Create a new agent factory without creation limits
     pub fn new() -> Self {
+        Self::with_quota(AgentQuotaConfig::unlimited())
+    }
+
+    /// Create a new agent factory enforcing the given creation limits
+    pub fn with_quota(quota: AgentQuotaConfig) -> Self {
         Self {
             builders: HashMap::new(),
             agents: Arc::new(RwLock::new(HashMap::new())),
+            quota: AgentQuota::new(&quota),
+            build_context: BuildContext::default(),
         }
     }
 
+    /// Hand `context` to the builders of every agent created from now on
+    pub fn with_build_context(mut self, context: BuildContext) -> Self {
+        self.build_context = context;
+        self
+    }
+
+    /// Get the resources handed to builders
+    pub fn build_context(&self) -> &BuildContext {
+        &self.build_context
+    }
+
     /// Register an agent builder for a specific type
     pub fn register_builder(&mut self, builder: Box<dyn AgentBuilder>) {
         let agent_type = builder.agent_type();
         self.builders.insert(agent_type, builder);
     }
 
-    /// Get list of supported agent types
+    /// Get list of supported agent types, sorted by name
     pub fn supported_types(&self) -> Vec<AgentType> {
-        self.builders.keys().cloned().collect()
+        let mut types: Vec<AgentType> = self.builders.keys().cloned().collect();
+        types.sort_by_key(|agent_type| agent_type.to_string());
+        types
     }
 
     /// Check if an agent type is supported
@@ -134,6 +266,23 @@ impl AgentFactory {
         spec: AgentSpec,
         custom_id: Option<String>,
     ) -> Result<CreateAgentResponse, AgentFactoryError> {
+        self.create_agent_as(spec, custom_id, None).await
+    }
+
+    /// Create a new agent on behalf of `principal`
+    ///
+    /// Same as [`create_agent`](Self::create_agent), but also counts the
+    /// creation against the principal's rate limit. The global agent limit
+    /// applies either way.
+    pub async fn create_agent_as(
+        &self,
+        spec: AgentSpec,
+        custom_id: Option<String>,
+        principal: Option<&str>,
+    ) -> Result<CreateAgentResponse, AgentFactoryError> {
+        // Reject malformed specs up front with every problem listed
+        spec.validate().map_err(AgentFactoryError::InvalidSpec)?;
+
         // Get builder for agent type
         let builder = self
             .builders
@@ -151,13 +300,23 @@ impl AgentFactory {
 
         let agent_id = AgentId::parse(&agent_id_str).map_err(AgentFactoryError::InvalidAgentId)?;
 
+        // Fail fast when full, without spending the principal's rate limit;
+        // the limit is re-checked atomically on insert below
+        self.quota.check_capacity(self.agent_count().await)?;
+        if let Some(principal) = principal {
+            self.quota.check_rate(principal)?;
+        }
+
         // Build coordinator BEFORE acquiring any locks
         // This is the most time-consuming operation and should be done outside the critical section
-        let coordinator = builder.build_coordinator(&spec)?;
+        let context = self.build_context.for_agent(agent_id.clone());
+        let coordinator = builder.build_coordinator_with(&spec, &context)?;
 
         // Create agent instance with all metadata BEFORE acquiring write lock
         let agent_instance =
-            AgentInstance::new(agent_id.clone(), spec.agent_type.to_string(), coordinator);
+            AgentInstance::new(agent_id.clone(), spec.agent_type.to_string(), coordinator)
+                .with_spec(spec.clone());
+        agent_instance.set_persistent(is_persistent_spec(&spec.config));
 
         // Set agent to ready state
         agent_instance.set_status(AgentStatusEnum::Ready).await;
@@ -190,6 +349,7 @@ impl AgentFactory {
                 // Agent was created by another thread while we were building
                 return Err(AgentFactoryError::AgentAlreadyExists(agent_id_str));
             }
+            self.quota.check_capacity(agents.len())?;
 
             // Insert is guaranteed to succeed because we hold the write lock
             agents.insert(agent_id.clone(), agent_instance);
@@ -207,6 +367,81 @@ impl AgentFactory {
         })
     }
 
+    /// Create the agent identified by `natural_key` on behalf of `principal`
+    ///
+    /// The agent ID is derived with [`natural_key_agent_id`], so repeating the
+    /// call does not create a duplicate. If the agent already exists it is
+    /// returned as-is, with the spec it was created from, under
+    /// [`OnConflict::ReturnExisting`] and without counting against any quota; [`OnConflict::Error`] and an existing agent of a
+    /// different type both fail with [`AgentFactoryError::AgentAlreadyExists`].
+    pub async fn create_agent_with_key(
+        &self,
+        spec: AgentSpec,
+        natural_key: &str,
+        principal: Option<&str>,
+        on_conflict: OnConflict,
+    ) -> Result<CreateAgentResponse, AgentFactoryError> {
+        if natural_key.is_empty() {
+            return Err(AgentFactoryError::InvalidConfiguration {
+                field: "natural_key".to_string(),
+                reason: "must not be empty".to_string(),
+            });
+        }
+        spec.validate().map_err(AgentFactoryError::InvalidSpec)?;
+
+        let agent_id = natural_key_agent_id(principal, natural_key);
+        if let Some(existing) = self.existing_agent(&agent_id, &spec, on_conflict).await? {
+            return Ok(existing);
+        }
+
+        match self
+            .create_agent_as(spec.clone(), Some(agent_id.clone()), principal)
+            .await
+        {
+            // Another request created the agent while this one was building
+            Err(AgentFactoryError::AgentAlreadyExists(id)) => self
+                .existing_agent(&agent_id, &spec, on_conflict)
+                .await?
+                .ok_or(AgentFactoryError::AgentAlreadyExists(id)),
+            result => result,
+        }
+    }
+
+    /// Describe the agent with `agent_id` if it exists and may be reused for `spec`
+    ///
+    /// The response carries the spec the existing agent was created from,
+    /// which may differ from `spec` in everything but the agent type. An
+    /// agent without a recorded spec cannot be reused.
+    async fn existing_agent(
+        &self,
+        agent_id: &str,
+        spec: &AgentSpec,
+        on_conflict: OnConflict,
+    ) -> Result<Option<CreateAgentResponse>, AgentFactoryError> {
+        let id = AgentId::parse(agent_id).map_err(AgentFactoryError::InvalidAgentId)?;
+        let agents = self.agents.read().await;
+        let Some(instance) = agents.get(&id) else {
+            return Ok(None);
+        };
+        let existing_spec = match instance.spec() {
+            Some(existing_spec)
+                if on_conflict == OnConflict::ReturnExisting
+                    && existing_spec.agent_type == spec.agent_type =>
+            {
+                existing_spec.clone()
+            }
+            _ => return Err(AgentFactoryError::AgentAlreadyExists(agent_id.to_string())),
+        };
+
+        Ok(Some(CreateAgentResponse {
+            agent_id: agent_id.to_string(),
+            endpoints: AgentEndpoints::for_agent(agent_id, &existing_spec.agent_type),
+            spec: existing_spec,
+            status: instance.status().await,
+            created_at: instance.created_at,
+        }))
+    }
+
     /// Check if an agent exists
     pub async fn has_agent(&self, agent_id: &str) -> bool {
         if let Ok(agent_id) = AgentId::parse(agent_id) {
@@ -221,12 +456,56 @@ impl AgentFactory {
     pub async fn remove_agent(&self, agent_id: &str) -> Result<(), AgentFactoryError> {
         let agent_id = AgentId::parse(agent_id).map_err(AgentFactoryError::InvalidAgentId)?;
         let mut agents = self.agents.write().await;
-        agents
+        let instance = agents
             .remove(&agent_id)
             .ok_or_else(|| AgentFactoryError::AgentNotFound(agent_id.to_string()))?;
+        instance.set_log_level(None);
         Ok(())
     }
 
+    /// Mark an agent as persistent, exempting it from idle eviction
+    pub async fn set_persistent(
+        &self,
+        agent_id: &str,
+        persistent: bool,
+    ) -> Result<(), AgentFactoryError> {
+        let agent_id = AgentId::parse(agent_id).map_err(AgentFactoryError::InvalidAgentId)?;
+        let agents = self.agents.read().await;
+        let instance = agents
+            .get(&agent_id)
+            .ok_or_else(|| AgentFactoryError::AgentNotFound(agent_id.to_string()))?;
+        instance.set_persistent(persistent);
+        Ok(())
+    }
+
+    /// Set or clear an agent's log level override
+    pub async fn set_log_level(
+        &self,
+        agent_id: &str,
+        level: Option<tracing::Level>,
+    ) -> Result<(), AgentFactoryError> {
+        let agent_id = AgentId::parse(agent_id).map_err(AgentFactoryError::InvalidAgentId)?;
+        let agents = self.agents.read().await;
+        let instance = agents
+            .get(&agent_id)
+            .ok_or_else(|| AgentFactoryError::AgentNotFound(agent_id.to_string()))?;
+        instance.set_log_level(level);
+        Ok(())
+    }
+
+    /// Get an agent's log level override, if any
+    pub async fn log_level(
+        &self,
+        agent_id: &str,
+    ) -> Result<Option<tracing::Level>, AgentFactoryError> {
+        let agent_id = AgentId::parse(agent_id).map_err(AgentFactoryError::InvalidAgentId)?;
+        let agents = self.agents.read().await;
+        let instance = agents
+            .get(&agent_id)
+            .ok_or_else(|| AgentFactoryError::AgentNotFound(agent_id.to_string()))?;
+        Ok(instance.log_level())
+    }
+
     /// List all agent IDs
     pub async fn list_agent_ids(&self) -> Vec<String> {
         let agents = self.agents.read().await;
@@ -260,6 +539,59 @@ impl AgentFactory {
         count
     }
 
+    /// Remove agents with no activity for at least `idle_timeout`
+    ///
+    /// Persistent agents are skipped. Evicted agents are dropped after the
+    /// agents lock is released, which runs their cleanup hooks.
+    ///
+    /// # Returns
+    ///
+    /// The IDs of the evicted agents
+    pub async fn evict_idle_agents(&self, idle_timeout: Duration) -> Vec<AgentId> {
+        let now = chrono::Utc::now();
+        let evicted: Vec<(AgentId, AgentInstance)> = {
+            let mut agents = self.agents.write().await;
+            let mut idle = Vec::new();
+            for (id, instance) in agents.iter() {
+                if instance.is_persistent() {
+                    continue;
+                }
+                let idle_for = (now - instance.last_activity().await)
+                    .to_std()
+                    .unwrap_or_default();
+                if idle_for >= idle_timeout {
+                    idle.push(id.clone());
+                }
+            }
+            idle.into_iter()
+                .filter_map(|id| agents.remove(&id).map(|instance| (id, instance)))
+                .collect()
+        };
+
+        if evicted.is_empty() {
+            return Vec::new();
+        }
+        for (id, instance) in &evicted {
+            instance.set_log_level(None);
+            tracing::info!(
+                agent_id = %id,
+                agent_type = %instance.agent_type,
+                idle_timeout_secs = idle_timeout.as_secs(),
+                "Evicted idle agent"
+            );
+        }
+        if let Some(registry) = skreaver_observability::get_metrics_registry() {
+            registry.record_agent_evictions(evicted.len());
+        }
+
+        evicted.into_iter().map(|(id, _instance)| id).collect()
+    }
+
+    /// Check that the agent limit leaves room for one more agent
+    pub fn check_capacity(&self, current: usize) -> Result<(), AgentFactoryError> {
+        self.quota.check_capacity(current)
+    }
+
     /// Get agents map reference for external access
     pub fn agents(&self) -> Arc<RwLock<HashMap<AgentId, AgentInstance>>> {
         Arc::clone(&self.agents)
@@ -435,6 +767,30 @@ mod tests {
         assert!(matches!(result, Err(AgentFactoryError::InvalidAgentId(_))));
     }
 
+    #[tokio::test]
+    async fn test_agent_factory_rejects_invalid_spec() {
+        let mut factory = AgentFactory::new();
+        factory.register_builder(Box::new(MockBuilder));
+
+        let spec = AgentSpec {
+            agent_type: AgentType::Echo,
+            name: Some("bad name!".to_string()),
+            config: HashMap::new(),
+            limits: AgentLimits {
+                execution_timeout_secs: 0,
+                ..AgentLimits::default()
+            },
+        };
+
+        match factory.create_agent(spec, None).await {
+            Err(AgentFactoryError::InvalidSpec(err)) => {
+                assert_eq!(err.violations.len(), 2);
+            }
+            other => panic!("Expected InvalidSpec, got {:?}", other.map(|r| r.agent_id)),
+        }
+        assert_eq!(factory.agent_count().await, 0);
+    }
+
     #[tokio::test]
     async fn test_agent_management() {
         let mut factory = AgentFactory::new();
@@ -497,6 +853,151 @@ mod tests {
         ));
     }
 
+    #[tokio::test]
+    async fn test_natural_key_creation_is_idempotent() {
+        let mut factory = AgentFactory::new();
+        factory.register_builder(Box::new(MockBuilder));
+
+        let spec = AgentSpec {
+            agent_type: AgentType::Echo,
+            name: None,
+            config: HashMap::new(),
+            limits: AgentLimits::default(),
+        };
+
+        let first = factory
+            .create_agent_with_key(
+                spec.clone(),
+                "user-1",
+                Some("alice"),
+                OnConflict::ReturnExisting,
+            )
+            .await
+            .unwrap();
+        // A repeated create describes the existing agent, not the new spec
+        let renamed = AgentSpec {
+            name: Some("renamed".to_string()),
+            ..spec.clone()
+        };
+        let second = factory
+            .create_agent_with_key(renamed, "user-1", Some("alice"), OnConflict::ReturnExisting)
+            .await
+            .unwrap();
+
+        assert_eq!(first.agent_id, second.agent_id);
+        assert_eq!(first.created_at, second.created_at);
+        assert_eq!(second.spec.name, None);
+        assert_eq!(
+            first.agent_id,
+            natural_key_agent_id(Some("alice"), "user-1")
+        );
+        assert_eq!(factory.agent_count().await, 1);
+
+        // The same key from another principal is a different agent
+        let other = factory
+            .create_agent_with_key(spec, "user-1", Some("bob"), OnConflict::ReturnExisting)
+            .await
+            .unwrap();
+        assert_ne!(other.agent_id, first.agent_id);
+        assert_eq!(factory.agent_count().await, 2);
+    }
+
+    #[tokio::test]
+    async fn test_natural_key_conflict_error_mode() {
+        let mut factory = AgentFactory::new();
+        factory.register_builder(Box::new(MockBuilder));
+
+        let spec = AgentSpec {
+            agent_type: AgentType::Echo,
+            name: None,
+            config: HashMap::new(),
+            limits: AgentLimits::default(),
+        };
+
+        let created = factory
+            .create_agent_with_key(spec.clone(), "user-1", None, OnConflict::Error)
+            .await
+            .unwrap();
+        let result = factory
+            .create_agent_with_key(spec.clone(), "user-1", None, OnConflict::Error)
+            .await;
+        assert!(matches!(
+            result,
+            Err(AgentFactoryError::AgentAlreadyExists(id)) if id == created.agent_id
+        ));
+        assert_eq!(factory.agent_count().await, 1);
+
+        let result = factory
+            .create_agent_with_key(spec, "", None, OnConflict::Error)
+            .await;
+        assert!(matches!(
+            result,
+            Err(AgentFactoryError::InvalidConfiguration { field, .. }) if field == "natural_key"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_agent_limit_frees_capacity_on_removal() {
+        let mut factory = AgentFactory::with_quota(AgentQuotaConfig {
+            max_agents: Some(2),
+            creations_per_minute: None,
+        });
+        factory.register_builder(Box::new(MockBuilder));
+
+        let spec = AgentSpec {
+            agent_type: AgentType::Echo,
+            name: None,
+            config: HashMap::new(),
+            limits: AgentLimits::default(),
+        };
+
+        let first = factory.create_agent(spec.clone(), None).await.unwrap();
+        factory.create_agent(spec.clone(), None).await.unwrap();
+        assert!(matches!(
+            factory.create_agent(spec.clone(), None).await,
+            Err(AgentFactoryError::AgentLimitReached { max_agents: 2 })
+        ));
+        assert_eq!(factory.agent_count().await, 2);
+
+        factory.remove_agent(&first.agent_id).await.unwrap();
+        factory.create_agent(spec, None).await.unwrap();
+        assert_eq!(factory.agent_count().await, 2);
+    }
+
+    #[tokio::test]
+    async fn test_creation_rate_limited_per_principal() {
+        let mut factory = AgentFactory::with_quota(AgentQuotaConfig {
+            max_agents: None,
+            creations_per_minute: std::num::NonZeroU32::new(1),
+        });
+        factory.register_builder(Box::new(MockBuilder));
+
+        let spec = AgentSpec {
+            agent_type: AgentType::Echo,
+            name: None,
+            config: HashMap::new(),
+            limits: AgentLimits::default(),
+        };
+
+        factory
+            .create_agent_as(spec.clone(), None, Some("alice"))
+            .await
+            .unwrap();
+        assert!(matches!(
+            factory
+                .create_agent_as(spec.clone(), None, Some("alice"))
+                .await,
+            Err(AgentFactoryError::CreationRateLimited { .. })
+        ));
+        factory
+            .create_agent_as(spec.clone(), None, Some("bob"))
+            .await
+            .unwrap();
+        // Internal creations are not tied to a principal
+        factory.create_agent(spec, None).await.unwrap();
+        assert_eq!(factory.agent_count().await, 3);
+    }
+
     #[tokio::test]
     async fn test_concurrent_agent_creation_no_race() {
         use std::sync::Arc;
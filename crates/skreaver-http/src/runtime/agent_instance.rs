@@ -1,16 +1,29 @@
 //! Agent instance management with proper state tracking
 
 use crate::runtime::agent_status::AgentStatusEnum;
-use crate::runtime::api_types::AgentInstanceMetadata;
+use crate::runtime::api_types::{AgentInstanceMetadata, AgentSpec};
+use crate::runtime::backpressure::RequestPriority;
 use chrono::{DateTime, Utc};
-use std::sync::Arc;
-use std::sync::atomic::{AtomicU64, Ordering};
+use skreaver_core::ToolCall;
+use skreaver_observability::{StepOutcome, agent_log_levels, get_metrics_registry};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard};
 use tokio::sync::RwLock;
+use tracing::Level;
 
 // Re-export unified AgentId from skreaver-core
 pub use skreaver_core::AgentId;
 
+/// Coordinator shared by the clones of an [`AgentInstance`]
+pub type SharedCoordinator = Arc<Mutex<Box<dyn CoordinatorTrait + Send + Sync>>>;
+
 /// Comprehensive agent instance with state tracking
+///
+/// Clones share the agent's state and coordinator, so a handler can clone the
+/// instance out of the agent map and release the map's lock before stepping.
+/// Steps of one agent still run one at a time: each holds the coordinator's
+/// lock for its duration.
+#[derive(Clone)]
 pub struct AgentInstance {
     /// Agent identifier
     pub id: AgentId,
@@ -26,16 +39,70 @@ pub struct AgentInstance {
     pub observation_count: Arc<AtomicU64>,
     /// Number of tool calls made
     pub tool_call_count: Arc<AtomicU64>,
-    /// Agent coordinator (boxed trait object)
-    pub coordinator: Box<dyn CoordinatorTrait + Send + Sync>,
+    /// Agent coordinator, locked for the duration of each step
+    pub coordinator: SharedCoordinator,
+    /// Type name reported by the coordinator, readable while a step runs
+    coordinator_type: &'static str,
     /// Structured instance metadata for comprehensive tracking
     pub instance_metadata: Arc<RwLock<AgentInstanceMetadata>>,
+    /// Persistent agents are never evicted for being idle
+    pub persistent: Arc<AtomicBool>,
+    /// Log level override for this agent's steps
+    log_level: Arc<Mutex<Option<Level>>>,
+    /// Spec the agent was created from, if it was created from one
+    spec: Option<Arc<AgentSpec>>,
 }
 
 /// Trait for agent coordinators to allow dynamic dispatch
 pub trait CoordinatorTrait {
     fn step(&mut self, input: String) -> String;
     fn get_agent_type(&self) -> &'static str;
+
+    /// Run a step and classify how it ended
+    ///
+    /// Coordinators that cannot tell a tool failure apart from a normal step
+    /// report every step as completed.
+    fn step_with_outcome(&mut self, input: String) -> (String, StepOutcome) {
+        (self.step(input), StepOutcome::Completed)
+    }
+
+    /// Tool calls dispatched so far; coordinators that do not track them report zero.
+    fn tool_invocations(&self) -> u64 {
+        0
+    }
+
+    /// Set the priority that the tool calls of subsequent steps inherit
+    ///
+    /// Coordinators that do not prioritize tool calls ignore it.
+    fn set_priority(&mut self, _priority: RequestPriority) {}
+
+    /// Preview the tool calls `input` would trigger, without running them
+    ///
+    /// Returns `None` for coordinators whose agent cannot be planned against
+    /// (see [`Coordinator::plan`](crate::runtime::Coordinator::plan)).
+    fn plan(&self, _input: String) -> Option<Vec<ToolCall>> {
+        None
+    }
+}
+
+/// Marks a step as abandoned when the future waiting for it is dropped
+struct AbandonOnDrop(Arc<AtomicBool>);
+
+impl Drop for AbandonOnDrop {
+    fn drop(&mut self) {
+        self.0.store(true, Ordering::Release);
+    }
+}
+
+/// Record a step outcome for an agent type, if metrics are initialized
+///
+/// Agent types beyond the metrics cardinality limit are not recorded.
+pub(crate) fn record_step_outcome(agent_type: &str, outcome: StepOutcome) {
+    if let Some(registry) = get_metrics_registry()
+        && let Err(e) = registry.record_step_outcome(agent_type, outcome)
+    {
+        tracing::debug!(agent_type = %agent_type, error = %e, "Step outcome not recorded");
+    }
 }
 
 impl AgentInstance {
@@ -55,8 +122,12 @@ impl AgentInstance {
             last_activity: Arc::new(RwLock::new(now)),
             observation_count: Arc::new(AtomicU64::new(0)),
             tool_call_count: Arc::new(AtomicU64::new(0)),
-            coordinator,
+            coordinator_type: coordinator.get_agent_type(),
+            coordinator: Arc::new(Mutex::new(coordinator)),
             instance_metadata: Arc::new(RwLock::new(AgentInstanceMetadata::default())),
+            persistent: Arc::new(AtomicBool::new(false)),
+            log_level: Arc::new(Mutex::new(None)),
+            spec: None,
         }
     }
 
@@ -77,8 +148,12 @@ impl AgentInstance {
             last_activity: Arc::new(RwLock::new(now)),
             observation_count: Arc::new(AtomicU64::new(0)),
             tool_call_count: Arc::new(AtomicU64::new(0)),
-            coordinator,
+            coordinator_type: coordinator.get_agent_type(),
+            coordinator: Arc::new(Mutex::new(coordinator)),
             instance_metadata: Arc::new(RwLock::new(instance_metadata)),
+            persistent: Arc::new(AtomicBool::new(false)),
+            log_level: Arc::new(Mutex::new(None)),
+            spec: None,
         }
     }
 
@@ -111,6 +186,146 @@ impl AgentInstance {
         *self.last_activity.read().await
     }
 
+    /// Record the spec the agent was created from
+    #[must_use]
+    pub fn with_spec(mut self, spec: AgentSpec) -> Self {
+        self.spec = Some(Arc::new(spec));
+        self
+    }
+
+    /// Spec the agent was created from, if recorded
+    pub fn spec(&self) -> Option<&AgentSpec> {
+        self.spec.as_deref()
+    }
+
+    /// Mark the agent as persistent, exempting it from idle eviction
+    pub fn set_persistent(&self, persistent: bool) {
+        self.persistent.store(persistent, Ordering::Relaxed);
+    }
+
+    /// Check if the agent is exempt from idle eviction
+    pub fn is_persistent(&self) -> bool {
+        self.persistent.load(Ordering::Relaxed)
+    }
+
+    /// Override the most verbose level logged during this agent's steps
+    ///
+    /// `None` returns the agent to the global log level. The override applies
+    /// wherever the [`AgentLevelFilter`](skreaver_observability::AgentLevelFilter)
+    /// is installed, as it is by `init_tracing`.
+    pub fn set_log_level(&self, level: Option<Level>) {
+        *self.log_level.lock().unwrap_or_else(|e| e.into_inner()) = level;
+        match level {
+            Some(level) => agent_log_levels().set(self.id.as_str(), level),
+            None => {
+                agent_log_levels().clear(self.id.as_str());
+            }
+        }
+    }
+
+    /// Get the agent's log level override, if any
+    pub fn log_level(&self) -> Option<Level> {
+        *self.log_level.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Get the agent type name reported by the coordinator
+    pub fn coordinator_type(&self) -> &'static str {
+        self.coordinator_type
+    }
+
+    /// Run one coordinator step at normal priority and record it as activity
+    ///
+    /// See [`step_with_priority`](Self::step_with_priority).
+    pub fn step(&self, input: String) -> String {
+        self.step_with_priority(input, RequestPriority::Normal)
+    }
+
+    /// Run one coordinator step for a request of the given priority
+    ///
+    /// Tool calls made during the step inherit `priority` (see
+    /// [`CoordinatorTrait::set_priority`]). The step's outcome is recorded in
+    /// the step outcome metric; a panicking coordinator is recorded as an
+    /// agent error before the panic continues.
+    ///
+    /// Blocks until earlier steps of this agent finish and for as long as the
+    /// step waits on tool approvals or tool slots; async callers should use
+    /// [`run_step`](Self::run_step).
+    pub fn step_with_priority(&self, input: String, priority: RequestPriority) -> String {
+        self.step_locked(self.lock_coordinator(), input, priority)
+    }
+
+    /// Run a step on the blocking thread pool
+    ///
+    /// Holds no lock besides this agent's own coordinator, so a step waiting
+    /// for approval or a tool slot stalls neither the async runtime nor other
+    /// agents. If the returned future is dropped while the step still waits
+    /// for earlier steps of the agent, the step is skipped. Panics of the
+    /// coordinator are resumed in the caller.
+    pub async fn run_step(&self, input: String, priority: RequestPriority) -> String {
+        let instance = self.clone();
+        let abandoned = Arc::new(AtomicBool::new(false));
+        let _abandon = AbandonOnDrop(Arc::clone(&abandoned));
+        let step = tokio::task::spawn_blocking(move || {
+            let coordinator = instance.lock_coordinator();
+            if abandoned.load(Ordering::Acquire) {
+                return None;
+            }
+            Some(instance.step_locked(coordinator, input, priority))
+        });
+        match step.await {
+            Ok(Some(response)) => response,
+            Ok(None) => unreachable!("steps are only skipped once their caller is gone"),
+            Err(e) if e.is_panic() => std::panic::resume_unwind(e.into_panic()),
+            Err(e) => panic!("Agent step was cancelled: {}", e),
+        }
+    }
+
+    fn lock_coordinator(&self) -> MutexGuard<'_, Box<dyn CoordinatorTrait + Send + Sync>> {
+        self.coordinator.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn step_locked(
+        &self,
+        mut coordinator: MutexGuard<'_, Box<dyn CoordinatorTrait + Send + Sync>>,
+        input: String,
+        priority: RequestPriority,
+    ) -> String {
+        // Everything logged during the step is scoped to the agent, so its
+        // log level override applies
+        let _span = tracing::info_span!(
+            "agent_step",
+            agent.id = %self.id,
+            agent_type = %self.agent_type
+        )
+        .entered();
+        coordinator.set_priority(priority);
+        let tools_before = coordinator.tool_invocations();
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            coordinator.step_with_outcome(input)
+        }));
+        let response = match result {
+            Ok((response, outcome)) => {
+                record_step_outcome(&self.agent_type, outcome);
+                let tools_used = coordinator.tool_invocations() - tools_before;
+                self.tool_call_count
+                    .fetch_add(tools_used, Ordering::Relaxed);
+                response
+            }
+            Err(panic) => {
+                record_step_outcome(&self.agent_type, StepOutcome::AgentError);
+                drop(coordinator);
+                std::panic::resume_unwind(panic);
+            }
+        };
+        drop(coordinator);
+        // Readers hold the timestamp only briefly; a step finishing during a
+        // read is still recorded by the next one
+        if let Ok(mut last_activity) = self.last_activity.try_write() {
+            *last_activity = Utc::now();
+        }
+        response
+    }
+
     /// Increment observation count
     pub fn increment_observations(&self) {
         self.observation_count.fetch_add(1, Ordering::Relaxed);
@@ -158,9 +373,10 @@ impl AgentInstance {
     }
 
     /// Execute a step with proper state management
-    pub async fn execute_step(&mut self, input: String) -> Result<String, AgentExecutionError> {
+    pub async fn execute_step(&self, input: String) -> Result<String, AgentExecutionError> {
         // Check if agent can accept observations
         if !self.can_accept_observations().await {
+            record_step_outcome(&self.agent_type, StepOutcome::Rejected);
             let current_status = self.status().await;
             return Err(AgentExecutionError::InvalidState {
                 current_status,
@@ -177,7 +393,7 @@ impl AgentInstance {
         self.increment_observations();
 
         // Execute the step
-        let result = self.coordinator.step(input);
+        let result = self.run_step(input, RequestPriority::Normal).await;
 
         // Set status back to ready
         self.set_status(AgentStatusEnum::Ready).await;
@@ -424,4 +640,71 @@ mod tests {
         assert_eq!(metadata.environment, Some("production".to_string()));
         assert_eq!(metadata.tags.get("critical"), Some(&"true".to_string()));
     }
+
+    #[test]
+    fn test_log_level_override_scopes_debug_logs_to_one_agent() {
+        use skreaver_observability::AgentLevelFilter;
+        use tracing_subscriber::Layer;
+        use tracing_subscriber::filter::{FilterExt, LevelFilter};
+        use tracing_subscriber::layer::SubscriberExt;
+
+        struct ChattyCoordinator;
+        impl CoordinatorTrait for ChattyCoordinator {
+            fn step(&mut self, input: String) -> String {
+                tracing::debug!("thinking about {input}");
+                tracing::info!("answering {input}");
+                "response".to_string()
+            }
+            fn get_agent_type(&self) -> &'static str {
+                "chatty"
+            }
+        }
+
+        #[derive(Clone, Default)]
+        struct SharedWriter(Arc<Mutex<Vec<u8>>>);
+        impl std::io::Write for SharedWriter {
+            fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+                self.0.lock().unwrap().extend_from_slice(buf);
+                Ok(buf.len())
+            }
+            fn flush(&mut self) -> std::io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let elevated = AgentInstance::new(
+            AgentId::new_unchecked("log-level-elevated"),
+            "ChattyAgent".to_string(),
+            Box::new(ChattyCoordinator),
+        );
+        let default = AgentInstance::new(
+            AgentId::new_unchecked("log-level-default"),
+            "ChattyAgent".to_string(),
+            Box::new(ChattyCoordinator),
+        );
+        elevated.set_log_level(Some(Level::DEBUG));
+        assert_eq!(elevated.log_level(), Some(Level::DEBUG));
+        assert_eq!(default.log_level(), None);
+
+        let writer = SharedWriter::default();
+        let make_writer = writer.clone();
+        let layer = tracing_subscriber::fmt::layer()
+            .with_ansi(false)
+            .with_writer(move || make_writer.clone())
+            .with_filter(LevelFilter::INFO.or(AgentLevelFilter::global()));
+        let subscriber = tracing_subscriber::registry().with(layer);
+
+        tracing::subscriber::with_default(subscriber, || {
+            elevated.step("elevated input".to_string());
+            default.step("default input".to_string());
+        });
+
+        let logs = String::from_utf8(writer.0.lock().unwrap().clone()).unwrap();
+        assert!(logs.contains("thinking about elevated input"));
+        assert!(logs.contains("answering default input"));
+        assert!(!logs.contains("thinking about default input"));
+
+        elevated.set_log_level(None);
+        assert_eq!(agent_log_levels().get("log-level-elevated"), None);
+    }
 }
@@ -29,6 +29,7 @@
 
 use crate::runtime::agent_instance::{AgentId, AgentInstance, CoordinatorTrait};
 use crate::runtime::agent_status::AgentStatusEnum;
+use crate::runtime::backpressure::RequestPriority;
 use chrono::{DateTime, Utc};
 use std::sync::Arc;
 use tokio::sync::RwLock;
@@ -244,7 +245,7 @@ impl AgentLifecycle<Running> {
     /// This is the core operation that demonstrates the value of typestates:
     /// you CANNOT call this method unless the agent is in the Running state.
     pub async fn process_observation(&self, input: String) -> LifecycleResult<String> {
-        let mut instance = self.instance.write().await;
+        let instance = self.instance.read().await.clone();
 
         // Update activity timestamp
         {
@@ -258,7 +259,7 @@ impl AgentLifecycle<Running> {
             .fetch_add(1, std::sync::atomic::Ordering::SeqCst);
 
         // Process the observation
-        let response = instance.coordinator.step(input);
+        let response = instance.run_step(input, RequestPriority::Normal).await;
 
         Ok(response)
     }
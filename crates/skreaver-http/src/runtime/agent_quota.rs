@@ -0,0 +1,149 @@
+//! Agent creation quotas
+//!
+//! Caps how many agents the runtime holds at once and how fast a single
+//! principal may create new ones, so a misbehaving client cannot spawn
+//! unbounded agents and exhaust memory. Removing an agent frees its slot.
+
+use governor::{
+    Quota, RateLimiter,
+    clock::{Clock, DefaultClock},
+    state::keyed::DefaultKeyedStateStore,
+};
+use std::num::NonZeroU32;
+
+use crate::runtime::agent_factory::AgentFactoryError;
+
+/// Per-principal creation limiter
+type PrincipalRateLimiter = RateLimiter<String, DefaultKeyedStateStore<String>, DefaultClock>;
+
+/// Default maximum number of agents held by the runtime
+pub const DEFAULT_MAX_AGENTS: usize = 1000;
+
+/// Default agent creations allowed per principal per minute
+pub const DEFAULT_CREATIONS_PER_MINUTE: NonZeroU32 = match NonZeroU32::new(30) {
+    Some(v) => v,
+    None => panic!("DEFAULT_CREATIONS_PER_MINUTE must be non-zero"),
+};
+
+/// Number of tracked principals above which stale limiter state is pruned
+const PRUNE_THRESHOLD: usize = 10_000;
+
+/// Agent creation quota configuration
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AgentQuotaConfig {
+    /// Maximum number of agents held at once (`None` = unlimited)
+    pub max_agents: Option<usize>,
+    /// Agent creations allowed per principal per minute (`None` = unlimited)
+    pub creations_per_minute: Option<NonZeroU32>,
+}
+
+impl AgentQuotaConfig {
+    /// Configuration without any limits
+    pub const fn unlimited() -> Self {
+        Self {
+            max_agents: None,
+            creations_per_minute: None,
+        }
+    }
+}
+
+impl Default for AgentQuotaConfig {
+    fn default() -> Self {
+        Self {
+            max_agents: Some(DEFAULT_MAX_AGENTS),
+            creations_per_minute: Some(DEFAULT_CREATIONS_PER_MINUTE),
+        }
+    }
+}
+
+/// Enforces an [`AgentQuotaConfig`]
+pub(crate) struct AgentQuota {
+    max_agents: Option<usize>,
+    limiter: Option<PrincipalRateLimiter>,
+}
+
+impl AgentQuota {
+    pub(crate) fn new(config: &AgentQuotaConfig) -> Self {
+        Self {
+            max_agents: config.max_agents,
+            limiter: config
+                .creations_per_minute
+                .map(|rpm| RateLimiter::keyed(Quota::per_minute(rpm))),
+        }
+    }
+
+    /// Check that one more agent fits next to `current` existing agents
+    pub(crate) fn check_capacity(&self, current: usize) -> Result<(), AgentFactoryError> {
+        match self.max_agents {
+            Some(max_agents) if current >= max_agents => {
+                Err(AgentFactoryError::AgentLimitReached { max_agents })
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Consume one creation from `principal`'s rate limit
+    pub(crate) fn check_rate(&self, principal: &str) -> Result<(), AgentFactoryError> {
+        let Some(limiter) = &self.limiter else {
+            return Ok(());
+        };
+
+        let result = limiter.check_key(&principal.to_string());
+        if limiter.len() > PRUNE_THRESHOLD {
+            limiter.retain_recent();
+        }
+
+        result.map_err(|not_until| AgentFactoryError::CreationRateLimited {
+            principal: principal.to_string(),
+            retry_after_secs: not_until
+                .wait_time_from(DefaultClock::default().now())
+                .as_secs()
+                .max(1),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_capacity_limit() {
+        let quota = AgentQuota::new(&AgentQuotaConfig {
+            max_agents: Some(2),
+            creations_per_minute: None,
+        });
+
+        assert!(quota.check_capacity(1).is_ok());
+        assert!(matches!(
+            quota.check_capacity(2),
+            Err(AgentFactoryError::AgentLimitReached { max_agents: 2 })
+        ));
+        assert!(
+            AgentQuota::new(&AgentQuotaConfig::unlimited())
+                .check_capacity(usize::MAX - 1)
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_rate_limit_is_per_principal() {
+        let quota = AgentQuota::new(&AgentQuotaConfig {
+            max_agents: None,
+            creations_per_minute: NonZeroU32::new(1),
+        });
+
+        assert!(quota.check_rate("alice").is_ok());
+        match quota.check_rate("alice") {
+            Err(AgentFactoryError::CreationRateLimited {
+                principal,
+                retry_after_secs,
+            }) => {
+                assert_eq!(principal, "alice");
+                assert!(retry_after_secs >= 1);
+            }
+            other => panic!("Expected CreationRateLimited, got {:?}", other),
+        }
+        assert!(quota.check_rate("bob").is_ok());
+    }
+}
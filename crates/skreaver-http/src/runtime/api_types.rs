@@ -323,16 +323,16 @@ impl std::fmt::Display for AgentType {
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct AgentLimits {
     /// Maximum memory usage in megabytes
-    #[schema(example = 128)]
+    #[schema(example = 128, minimum = 1, maximum = 8192)]
     pub max_memory_mb: u32,
     /// Maximum observation size in kilobytes
-    #[schema(example = 512)]
+    #[schema(example = 512, minimum = 1, maximum = 10240)]
     pub max_observation_size_kb: u32,
     /// Maximum number of concurrent tool calls
-    #[schema(example = 3)]
+    #[schema(example = 3, minimum = 1, maximum = 100)]
     pub max_concurrent_tools: u32,
     /// Execution timeout in seconds
-    #[schema(example = 30)]
+    #[schema(example = 30, minimum = 1, maximum = 3600)]
     pub execution_timeout_secs: u32,
 }
 
@@ -347,6 +347,116 @@ impl Default for AgentLimits {
     }
 }
 
+impl AgentLimits {
+    /// Check every limit against its allowed bounds
+    ///
+    /// Returns one violation per out-of-range field, in declaration order.
+    pub fn violations(&self) -> Vec<SpecViolation> {
+        let bounds: [(&str, u32, u32, &str); 4] = [
+            ("max_memory_mb", self.max_memory_mb, 8192, " MB (8 GB)"),
+            (
+                "max_observation_size_kb",
+                self.max_observation_size_kb,
+                10240,
+                " KB (10 MB)",
+            ),
+            ("max_concurrent_tools", self.max_concurrent_tools, 100, ""),
+            (
+                "execution_timeout_secs",
+                self.execution_timeout_secs,
+                3600,
+                " seconds (1 hour)",
+            ),
+        ];
+
+        bounds
+            .into_iter()
+            .filter_map(|(field, value, max, unit)| {
+                let message = if value == 0 {
+                    format!("{} must be greater than 0", field)
+                } else if value > max {
+                    format!("{} cannot exceed {}{}", field, max, unit)
+                } else {
+                    return None;
+                };
+                Some(SpecViolation::new(format!("limits.{}", field), message))
+            })
+            .collect()
+    }
+}
+
+/// Validate an agent name, returning the reason it is invalid
+fn check_agent_name(name: &str) -> Result<(), String> {
+    if name.is_empty() {
+        return Err("Name cannot be empty".to_string());
+    }
+
+    if name.len() > 64 {
+        return Err(format!("Name too long ({} chars, max 64)", name.len()));
+    }
+
+    // Only allow alphanumeric, hyphens, and underscores
+    if !name
+        .chars()
+        .all(|c| c.is_alphanumeric() || c == '-' || c == '_')
+    {
+        return Err(
+            "Name can only contain alphanumeric characters, hyphens, and underscores".to_string(),
+        );
+    }
+
+    Ok(())
+}
+
+/// A single problem found while validating an `AgentSpec`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct SpecViolation {
+    /// Path of the offending field (e.g. `limits.max_memory_mb`, `config.mode`)
+    #[schema(example = "config.mode")]
+    pub field: String,
+    /// Human-readable description of the problem
+    #[schema(example = "Invalid mode 'fast'. Valid modes: simple, analytical, creative")]
+    pub message: String,
+}
+
+impl SpecViolation {
+    /// Create a new violation for a field
+    pub fn new(field: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            field: field.into(),
+            message: message.into(),
+        }
+    }
+}
+
+impl std::fmt::Display for SpecViolation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.field, self.message)
+    }
+}
+
+/// All problems found while validating an `AgentSpec`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpecValidationError {
+    /// Violations in the order they were found (never empty)
+    pub violations: Vec<SpecViolation>,
+}
+
+impl std::fmt::Display for SpecValidationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Invalid agent spec: ")?;
+        for (i, violation) in self.violations.iter().enumerate() {
+            if i > 0 {
+                write!(f, "; ")?;
+            }
+            write!(f, "{}", violation)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for SpecValidationError {}
+
 /// Validated builder for `AgentSpec`
 ///
 /// Ensures configuration validity at compile-time and runtime with proper validation.
@@ -396,31 +506,7 @@ impl AgentSpecBuilder {
     /// Set the agent name with validation
     pub fn name(mut self, name: impl Into<String>) -> Result<Self, AgentSpecError> {
         let name = name.into();
-
-        // Validate name
-        if name.is_empty() {
-            return Err(AgentSpecError::InvalidName(
-                "Name cannot be empty".to_string(),
-            ));
-        }
-
-        if name.len() > 64 {
-            return Err(AgentSpecError::InvalidName(format!(
-                "Name too long ({} chars, max 64)",
-                name.len()
-            )));
-        }
-
-        // Only allow alphanumeric, hyphens, and underscores
-        if !name
-            .chars()
-            .all(|c| c.is_alphanumeric() || c == '-' || c == '_')
-        {
-            return Err(AgentSpecError::InvalidName(
-                "Name can only contain alphanumeric characters, hyphens, and underscores"
-                    .to_string(),
-            ));
-        }
+        check_agent_name(&name).map_err(AgentSpecError::InvalidName)?;
 
         self.name = Some(name);
         Ok(self)
@@ -441,53 +527,8 @@ impl AgentSpecBuilder {
 
     /// Set resource limits with validation
     pub fn limits(mut self, limits: AgentLimits) -> Result<Self, AgentSpecError> {
-        // Validate limits
-        if limits.max_memory_mb == 0 {
-            return Err(AgentSpecError::InvalidLimits(
-                "max_memory_mb must be greater than 0".to_string(),
-            ));
-        }
-
-        if limits.max_memory_mb > 8192 {
-            return Err(AgentSpecError::InvalidLimits(
-                "max_memory_mb cannot exceed 8192 MB (8 GB)".to_string(),
-            ));
-        }
-
-        if limits.max_observation_size_kb == 0 {
-            return Err(AgentSpecError::InvalidLimits(
-                "max_observation_size_kb must be greater than 0".to_string(),
-            ));
-        }
-
-        if limits.max_observation_size_kb > 10240 {
-            return Err(AgentSpecError::InvalidLimits(
-                "max_observation_size_kb cannot exceed 10240 KB (10 MB)".to_string(),
-            ));
-        }
-
-        if limits.max_concurrent_tools == 0 {
-            return Err(AgentSpecError::InvalidLimits(
-                "max_concurrent_tools must be greater than 0".to_string(),
-            ));
-        }
-
-        if limits.max_concurrent_tools > 100 {
-            return Err(AgentSpecError::InvalidLimits(
-                "max_concurrent_tools cannot exceed 100".to_string(),
-            ));
-        }
-
-        if limits.execution_timeout_secs == 0 {
-            return Err(AgentSpecError::InvalidLimits(
-                "execution_timeout_secs must be greater than 0".to_string(),
-            ));
-        }
-
-        if limits.execution_timeout_secs > 3600 {
-            return Err(AgentSpecError::InvalidLimits(
-                "execution_timeout_secs cannot exceed 3600 seconds (1 hour)".to_string(),
-            ));
+        if let Some(violation) = limits.violations().into_iter().next() {
+            return Err(AgentSpecError::InvalidLimits(violation.message));
         }
 
         self.limits = Some(limits);
@@ -514,6 +555,113 @@ impl AgentSpec {
     pub fn builder() -> AgentSpecBuilder {
         AgentSpecBuilder::new()
     }
+
+    /// Validate the whole specification, collecting every problem at once
+    ///
+    /// Checks the agent type, name, resource bounds, tool ids, and the
+    /// configuration keys each built-in agent type understands. Specs that
+    /// arrive through deserialization bypass the builder, so the factory calls
+    /// this before handing the spec to a builder.
+    pub fn validate(&self) -> Result<(), SpecValidationError> {
+        let mut violations = Vec::new();
+
+        if let AgentType::Custom(type_name) = &self.agent_type {
+            if type_name.trim().is_empty() {
+                violations.push(SpecViolation::new(
+                    "agent_type",
+                    "Custom agent type name cannot be empty",
+                ));
+            }
+            if self.name.is_none() {
+                violations.push(SpecViolation::new("name", "Custom agents require a name"));
+            }
+        }
+
+        if let Some(name) = &self.name
+            && let Err(reason) = check_agent_name(name)
+        {
+            violations.push(SpecViolation::new("name", reason));
+        }
+
+        violations.extend(self.limits.violations());
+        violations.extend(self.tool_violations());
+        violations.extend(self.config_violations());
+
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(SpecValidationError { violations })
+        }
+    }
+
+    /// Check `config.tools`, which must be an array of valid tool ids
+    fn tool_violations(&self) -> Vec<SpecViolation> {
+        let Some(tools) = self.config.get("tools") else {
+            return Vec::new();
+        };
+
+        let Some(tools) = tools.as_array() else {
+            return vec![SpecViolation::new(
+                "config.tools",
+                "Must be an array of tool ids",
+            )];
+        };
+
+        tools
+            .iter()
+            .enumerate()
+            .filter_map(|(i, tool)| {
+                let field = format!("config.tools[{}]", i);
+                match tool.as_str() {
+                    Some(id) => skreaver_core::ToolId::parse(id)
+                        .err()
+                        .map(|e| SpecViolation::new(field, format!("Invalid tool id: {}", e))),
+                    None => Some(SpecViolation::new(field, "Tool id must be a string")),
+                }
+            })
+            .collect()
+    }
+
+    /// Check configuration keys understood by the built-in agent types
+    fn config_violations(&self) -> Vec<SpecViolation> {
+        let allowed: &[(&str, &[&str])] = match self.agent_type {
+            AgentType::Advanced => &[("mode", &["simple", "analytical", "creative"])],
+            AgentType::Analytics => &[("depth", &["basic", "detailed", "comprehensive"])],
+            AgentType::Echo | AgentType::Custom(_) => &[],
+        };
+
+        let mut violations = Vec::new();
+        for (key, values) in allowed {
+            let Some(value) = self.config.get(*key) else {
+                continue;
+            };
+            match value.as_str() {
+                Some(v) if values.contains(&v) => {}
+                Some(v) => violations.push(SpecViolation::new(
+                    format!("config.{}", key),
+                    format!(
+                        "Invalid {} '{}'. Valid values: {}",
+                        key,
+                        v,
+                        values.join(", ")
+                    ),
+                )),
+                None => violations.push(SpecViolation::new(
+                    format!("config.{}", key),
+                    "Must be a string",
+                )),
+            }
+        }
+
+        if self.agent_type == AgentType::Advanced
+            && let Some(use_tools) = self.config.get("use_tools")
+            && !use_tools.is_boolean()
+        {
+            violations.push(SpecViolation::new("config.use_tools", "Must be a boolean"));
+        }
+
+        violations
+    }
 }
 
 /// Comprehensive agent status with detailed information
@@ -680,6 +828,27 @@ pub struct AgentResponse {
 pub struct CreateAgentRequest {
     /// Agent specification
     pub spec: AgentSpec,
+    /// Client-chosen key identifying the agent, such as a user id
+    ///
+    /// Requests with the same key from the same principal resolve to the
+    /// same deterministic agent ID instead of creating duplicates.
+    #[serde(default)]
+    #[schema(example = "user-1234")]
+    pub natural_key: Option<String>,
+    /// What to do when an agent for `natural_key` already exists
+    #[serde(default)]
+    pub on_conflict: OnConflict,
+}
+
+/// Behaviour of keyed agent creation when the agent already exists
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum OnConflict {
+    /// Return the existing agent, making creation idempotent
+    #[default]
+    ReturnExisting,
+    /// Fail with a conflict error
+    Error,
 }
 
 /// Response after creating an agent
@@ -811,6 +980,70 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_agent_spec_validate_valid() {
+        let spec = AgentSpec {
+            agent_type: AgentType::Advanced,
+            name: Some("analysis-agent".to_string()),
+            config: HashMap::from([
+                ("mode".to_string(), serde_json::json!("analytical")),
+                (
+                    "tools".to_string(),
+                    serde_json::json!(["http_get", "text_search"]),
+                ),
+            ]),
+            limits: AgentType::Advanced.default_limits(),
+        };
+
+        assert!(spec.validate().is_ok());
+    }
+
+    #[test]
+    fn test_agent_spec_validate_reports_all_problems() {
+        let spec = AgentSpec {
+            agent_type: AgentType::Custom("plugin".to_string()),
+            name: None,
+            config: HashMap::from([("tools".to_string(), serde_json::json!(["bad tool!"]))]),
+            limits: AgentLimits {
+                max_memory_mb: 0,
+                ..AgentLimits::default()
+            },
+        };
+
+        let err = spec.validate().unwrap_err();
+        let fields: Vec<&str> = err.violations.iter().map(|v| v.field.as_str()).collect();
+
+        assert_eq!(
+            fields,
+            vec!["name", "limits.max_memory_mb", "config.tools[0]"]
+        );
+        assert!(err.to_string().contains("Custom agents require a name"));
+    }
+
+    #[test]
+    fn test_agent_spec_validate_config_values() {
+        let spec = AgentSpec {
+            agent_type: AgentType::Analytics,
+            name: None,
+            config: HashMap::from([("depth".to_string(), serde_json::json!("deep"))]),
+            limits: AgentLimits::default(),
+        };
+
+        let err = spec.validate().unwrap_err();
+        assert_eq!(err.violations.len(), 1);
+        assert_eq!(err.violations[0].field, "config.depth");
+    }
+
+    #[test]
+    fn test_agent_type_unknown_is_enumerated() {
+        let err = serde_json::from_str::<AgentType>(r#""quantum""#).unwrap_err();
+        let message = err.to_string();
+
+        assert!(message.contains("quantum"));
+        assert!(message.contains("echo"));
+        assert!(message.contains("analytics"));
+    }
+
     #[test]
     fn test_agent_endpoints() {
         let endpoints = AgentEndpoints::for_agent("test-123", &AgentType::Advanced);
@@ -0,0 +1,281 @@
+//! API versioning with content negotiation
+//!
+//! Clients select an API version either with a path prefix (`/v2/agents`) or
+//! with a vendor media type in the `Accept` header
+//! (`application/vnd.skreaver.v2+json`). The path prefix wins when both are
+//! present. Requests that specify neither are served by
+//! [`DEFAULT_API_VERSION`], which keeps existing clients on the original
+//! response shapes.
+//!
+//! The negotiated version is stored in the request extensions, where
+//! handlers read it through the [`ApiVersion`] extractor, and echoed in the
+//! `X-API-Version` response header. Unknown versions are rejected with
+//! `406 Not Acceptable`.
+
+use axum::{
+    body::Body,
+    extract::FromRequestParts,
+    http::{HeaderValue, Request, StatusCode, Uri, header, request::Parts},
+    middleware::Next,
+    response::{IntoResponse, Json, Response},
+};
+use serde::{Deserialize, Serialize};
+use std::convert::Infallible;
+use std::fmt;
+
+use crate::runtime::types::ErrorResponse;
+
+/// Version served when a request does not specify one
+pub const DEFAULT_API_VERSION: ApiVersion = ApiVersion::V1;
+
+/// Response header carrying the version that served the request
+pub const API_VERSION_HEADER: &str = "x-api-version";
+
+/// Vendor media type prefix, followed by `v{N}+json`
+const VENDOR_MEDIA_TYPE_PREFIX: &str = "application/vnd.skreaver.";
+
+/// Supported HTTP API versions
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ApiVersion {
+    /// Original response shapes
+    #[default]
+    V1,
+    /// Collection responses wrapped in a `data`/`meta` envelope
+    V2,
+}
+
+impl ApiVersion {
+    /// All supported versions, oldest first
+    pub const ALL: [ApiVersion; 2] = [ApiVersion::V1, ApiVersion::V2];
+
+    /// Version label as used in paths and headers (`v1`, `v2`)
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ApiVersion::V1 => "v1",
+            ApiVersion::V2 => "v2",
+        }
+    }
+
+    /// Vendor media type selecting this version
+    pub fn media_type(&self) -> &'static str {
+        match self {
+            ApiVersion::V1 => "application/vnd.skreaver.v1+json",
+            ApiVersion::V2 => "application/vnd.skreaver.v2+json",
+        }
+    }
+
+    /// Parse a version label (`v1`, `v2`)
+    ///
+    /// # Errors
+    ///
+    /// Returns `UnsupportedApiVersion` if the label is not a supported version
+    pub fn parse(label: &str) -> Result<Self, UnsupportedApiVersion> {
+        Self::ALL
+            .into_iter()
+            .find(|version| version.as_str().eq_ignore_ascii_case(label))
+            .ok_or_else(|| UnsupportedApiVersion(label.to_string()))
+    }
+
+    /// Version selected by a `/v{N}/...` path prefix, if any
+    ///
+    /// # Errors
+    ///
+    /// Returns `UnsupportedApiVersion` if the prefix names an unknown version
+    pub fn from_path(path: &str) -> Result<Option<Self>, UnsupportedApiVersion> {
+        let segment = path.trim_start_matches('/').split('/').next().unwrap_or("");
+        if !is_version_label(segment) {
+            return Ok(None);
+        }
+        Self::parse(segment).map(Some)
+    }
+
+    /// Version selected by a vendor media type in an `Accept` header, if any
+    ///
+    /// Other media types such as `application/json` or `*/*` select nothing.
+    ///
+    /// # Errors
+    ///
+    /// Returns `UnsupportedApiVersion` if the vendor media type names an
+    /// unknown version
+    pub fn from_accept(accept: &str) -> Result<Option<Self>, UnsupportedApiVersion> {
+        accept
+            .split(',')
+            .filter_map(vendor_version_label)
+            .map(|label| Self::parse(&label))
+            .next()
+            .transpose()
+    }
+}
+
+impl fmt::Display for ApiVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<S: Send + Sync> FromRequestParts<S> for ApiVersion {
+    type Rejection = Infallible;
+
+    /// Read the negotiated version, falling back to [`DEFAULT_API_VERSION`]
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        Ok(parts
+            .extensions
+            .get::<ApiVersion>()
+            .copied()
+            .unwrap_or(DEFAULT_API_VERSION))
+    }
+}
+
+/// A request named an API version this server does not support
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnsupportedApiVersion(pub String);
+
+impl fmt::Display for UnsupportedApiVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "API version '{}' is not supported", self.0)
+    }
+}
+
+impl std::error::Error for UnsupportedApiVersion {}
+
+impl IntoResponse for UnsupportedApiVersion {
+    fn into_response(self) -> Response {
+        let supported: Vec<&str> = ApiVersion::ALL.iter().map(ApiVersion::as_str).collect();
+        (
+            StatusCode::NOT_ACCEPTABLE,
+            Json(ErrorResponse {
+                error: "unsupported_api_version".to_string(),
+                message: format!("{}, expected one of {}", self, supported.join(", ")),
+                details: Some(serde_json::json!({
+                    "requested": self.0,
+                    "supported": supported,
+                    "default": DEFAULT_API_VERSION.as_str(),
+                })),
+            }),
+        )
+            .into_response()
+    }
+}
+
+/// Whether `label` looks like a version label (`v` followed by digits)
+fn is_version_label(label: &str) -> bool {
+    label
+        .strip_prefix(['v', 'V'])
+        .is_some_and(|digits| !digits.is_empty() && digits.bytes().all(|b| b.is_ascii_digit()))
+}
+
+/// Version label of a vendor media type (`application/vnd.skreaver.v2+json` -> `v2`)
+fn vendor_version_label(media_type: &str) -> Option<String> {
+    let essence = media_type
+        .split(';')
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .to_ascii_lowercase();
+    essence
+        .strip_prefix(VENDOR_MEDIA_TYPE_PREFIX)?
+        .strip_suffix("+json")
+        .map(str::to_string)
+}
+
+/// Whether `media_type` is a versioned Skreaver JSON media type
+pub fn is_vendor_json(media_type: &str) -> bool {
+    vendor_version_label(media_type).is_some()
+}
+
+/// Route pattern without its version prefix (`/v2/agents` -> `/agents`)
+pub fn strip_version_prefix(route: &str) -> &str {
+    let trimmed = route.trim_start_matches('/');
+    match trimmed.split_once('/') {
+        Some((segment, rest)) if is_version_label(segment) => {
+            &route[route.len() - rest.len() - 1..]
+        }
+        _ => route,
+    }
+}
+
+/// Fallback for unmatched routes
+///
+/// Middleware does not run for paths without a route, so an unknown version
+/// prefix (`/v9/agents`) is reported here instead of as a bare 404.
+pub async fn unmatched_route_fallback(uri: Uri) -> Response {
+    match ApiVersion::from_path(uri.path()) {
+        Err(e) => e.into_response(),
+        Ok(_) => StatusCode::NOT_FOUND.into_response(),
+    }
+}
+
+/// API version negotiation middleware
+///
+/// Resolves the version from the path prefix, then the `Accept` header, then
+/// [`DEFAULT_API_VERSION`], and rejects unknown versions before routing
+/// reaches a handler.
+pub async fn api_version_middleware(mut request: Request<Body>, next: Next) -> Response {
+    let from_path = ApiVersion::from_path(request.uri().path());
+    let version = match from_path {
+        Ok(Some(version)) => Ok(version),
+        Ok(None) => request
+            .headers()
+            .get(header::ACCEPT)
+            .and_then(|value| value.to_str().ok())
+            .map_or(Ok(None), ApiVersion::from_accept)
+            .map(|version| version.unwrap_or(DEFAULT_API_VERSION)),
+        Err(e) => Err(e),
+    };
+
+    let version = match version {
+        Ok(version) => version,
+        Err(e) => {
+            tracing::debug!(requested = %e.0, "Rejected request for unsupported API version");
+            return e.into_response();
+        }
+    };
+
+    request.extensions_mut().insert(version);
+    let mut response = next.run(request).await;
+    response.headers_mut().insert(
+        API_VERSION_HEADER,
+        HeaderValue::from_static(version.as_str()),
+    );
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_version_from_path_and_accept() {
+        assert_eq!(
+            ApiVersion::from_path("/v2/agents"),
+            Ok(Some(ApiVersion::V2))
+        );
+        assert_eq!(ApiVersion::from_path("/agents"), Ok(None));
+        assert_eq!(ApiVersion::from_path("/vector/agents"), Ok(None));
+        assert!(ApiVersion::from_path("/v9/agents").is_err());
+
+        assert_eq!(
+            ApiVersion::from_accept("text/html, application/vnd.skreaver.v2+json; q=0.9"),
+            Ok(Some(ApiVersion::V2))
+        );
+        assert_eq!(ApiVersion::from_accept("application/json, */*"), Ok(None));
+        assert_eq!(
+            ApiVersion::from_accept("application/vnd.skreaver.v7+json"),
+            Err(UnsupportedApiVersion("v7".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_strip_version_prefix() {
+        assert_eq!(
+            strip_version_prefix("/v1/agents/{agent_id}"),
+            "/agents/{agent_id}"
+        );
+        assert_eq!(
+            strip_version_prefix("/agents/{agent_id}"),
+            "/agents/{agent_id}"
+        );
+        assert_eq!(strip_version_prefix("/v2"), "/v2");
+    }
+}
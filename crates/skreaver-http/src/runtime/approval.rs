@@ -0,0 +1,323 @@
+//! Human-in-the-loop approval of tool calls
+//!
+//! An [`ApprovalGate`] lists the tools that must not run without an external
+//! decision. When the [`Coordinator`](super::Coordinator) reaches such a call
+//! it records a [`PendingAction`] with the planned call, notifies the
+//! registered callback and waits until the action is approved or denied
+//! through [`ApprovalGate::decide`], or until the gate's timeout passes. The
+//! wait is asynchronous and only holds the waiting agent's own lock, so other
+//! agents keep running meanwhile. Clones of a gate share their pending
+//! actions, so a clone can be handed to whatever surfaces decisions (the
+//! `/approvals` endpoints, a chat bot, ...).
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use skreaver_core::ToolCall;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::sync::oneshot;
+
+/// A tool call waiting for an approval decision
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PendingAction {
+    /// Identifier used to decide on the action
+    pub id: String,
+    /// Name of the tool the agent wants to call
+    pub tool_name: String,
+    /// Input the tool would be called with
+    pub input: String,
+    /// When approval was requested
+    pub requested_at: DateTime<Utc>,
+}
+
+/// Decision on a pending action
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ApprovalDecision {
+    /// Run the tool call as planned
+    Approve,
+    /// Skip the tool call
+    Deny,
+}
+
+/// Error returned when deciding on an action
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ApprovalError {
+    /// No pending action has this id, or it was already decided or timed out
+    UnknownAction(String),
+}
+
+impl fmt::Display for ApprovalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownAction(id) => write!(f, "No pending action with id '{}'", id),
+        }
+    }
+}
+
+impl std::error::Error for ApprovalError {}
+
+/// Callback invoked whenever a tool call starts waiting for approval
+pub type ApprovalNotifier = Arc<dyn Fn(&PendingAction) + Send + Sync>;
+
+/// An undecided action and the channel its waiting step listens on
+struct Waiting {
+    action: PendingAction,
+    decision: oneshot::Sender<ApprovalDecision>,
+}
+
+type PendingActions = Mutex<HashMap<String, Waiting>>;
+
+/// Set of tools whose calls require an external approval decision
+#[derive(Clone)]
+pub struct ApprovalGate {
+    tools: HashSet<String>,
+    timeout: Duration,
+    notifier: Option<ApprovalNotifier>,
+    pending: Arc<PendingActions>,
+}
+
+impl fmt::Debug for ApprovalGate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ApprovalGate")
+            .field("tools", &self.tools)
+            .field("timeout", &self.timeout)
+            .field("pending", &self.pending().len())
+            .finish()
+    }
+}
+
+impl Default for ApprovalGate {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ApprovalGate {
+    /// How long a tool call waits for a decision unless configured otherwise
+    pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(300);
+
+    /// Create a gate that requires approval for no tools
+    pub fn new() -> Self {
+        Self {
+            tools: HashSet::new(),
+            timeout: Self::DEFAULT_TIMEOUT,
+            notifier: None,
+            pending: Arc::new(PendingActions::default()),
+        }
+    }
+
+    /// Require approval before `tool_name` runs
+    pub fn require_approval(mut self, tool_name: impl Into<String>) -> Self {
+        self.tools.insert(tool_name.into());
+        self
+    }
+
+    /// Set how long a tool call waits for a decision before the step fails
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Call `notifier` whenever a tool call starts waiting for approval
+    pub fn with_notifier<F>(mut self, notifier: F) -> Self
+    where
+        F: Fn(&PendingAction) + Send + Sync + 'static,
+    {
+        self.notifier = Some(Arc::new(notifier));
+        self
+    }
+
+    /// Check whether calls to `tool_name` need approval
+    pub fn requires_approval(&self, tool_name: &str) -> bool {
+        self.tools.contains(tool_name)
+    }
+
+    /// Get the approval timeout
+    pub fn timeout(&self) -> Duration {
+        self.timeout
+    }
+
+    /// Actions still waiting for a decision, oldest first
+    pub fn pending(&self) -> Vec<PendingAction> {
+        let mut pending: Vec<PendingAction> = self
+            .lock()
+            .values()
+            .map(|waiting| waiting.action.clone())
+            .collect();
+        pending.sort_by_key(|action| action.requested_at);
+        pending
+    }
+
+    /// Record the decision for a pending action, waking the waiting step
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApprovalError::UnknownAction`] if no undecided action has
+    /// this id.
+    pub fn decide(&self, id: &str, decision: ApprovalDecision) -> Result<(), ApprovalError> {
+        let waiting = self
+            .lock()
+            .remove(id)
+            .ok_or_else(|| ApprovalError::UnknownAction(id.to_string()))?;
+        // The step may have given up between the lookup and the send
+        waiting
+            .decision
+            .send(decision)
+            .map_err(|_| ApprovalError::UnknownAction(id.to_string()))
+    }
+
+    /// Approve a pending action
+    ///
+    /// # Errors
+    ///
+    /// See [`decide`](Self::decide).
+    pub fn approve(&self, id: &str) -> Result<(), ApprovalError> {
+        self.decide(id, ApprovalDecision::Approve)
+    }
+
+    /// Deny a pending action
+    ///
+    /// # Errors
+    ///
+    /// See [`decide`](Self::decide).
+    pub fn deny(&self, id: &str) -> Result<(), ApprovalError> {
+        self.decide(id, ApprovalDecision::Deny)
+    }
+
+    /// Register a pending action for `tool_call` and wait until it is decided
+    ///
+    /// Returns `None` if no decision arrived within the timeout. The action
+    /// is withdrawn either way, including when the returned future is
+    /// dropped before it completes.
+    pub async fn request(&self, tool_call: &ToolCall) -> Option<ApprovalDecision> {
+        let action = PendingAction {
+            id: uuid::Uuid::new_v4().to_string(),
+            tool_name: tool_call.name().to_string(),
+            input: tool_call.input.clone(),
+            requested_at: Utc::now(),
+        };
+        let (sender, receiver) = oneshot::channel();
+        self.lock().insert(
+            action.id.clone(),
+            Waiting {
+                action: action.clone(),
+                decision: sender,
+            },
+        );
+        let _withdraw = Withdraw {
+            pending: &self.pending,
+            id: &action.id,
+        };
+
+        tracing::info!(
+            action_id = %action.id,
+            tool_name = %action.tool_name,
+            "Tool call waiting for approval"
+        );
+        if let Some(notifier) = &self.notifier {
+            notifier(&action);
+        }
+
+        tokio::time::timeout(self.timeout, receiver)
+            .await
+            .ok()
+            .and_then(Result::ok)
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<String, Waiting>> {
+        self.pending.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// Removes a pending action once its request stops waiting
+struct Withdraw<'a> {
+    pending: &'a PendingActions,
+    id: &'a str,
+}
+
+impl Drop for Withdraw<'_> {
+    fn drop(&mut self) {
+        self.pending
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .remove(self.id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_notifier_receives_pending_action() {
+        let notified = Arc::new(Mutex::new(Vec::new()));
+        let seen = Arc::clone(&notified);
+        let gate = ApprovalGate::new()
+            .require_approval("deploy")
+            .with_notifier(move |action: &PendingAction| {
+                seen.lock().unwrap().push(action.clone());
+            });
+        assert!(gate.requires_approval("deploy"));
+        assert!(!gate.requires_approval("echo"));
+
+        let waiting = tokio::spawn({
+            let gate = gate.clone();
+            async move {
+                gate.request(&ToolCall::new("deploy", "prod").unwrap())
+                    .await
+            }
+        });
+        while gate.pending().is_empty() {
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        }
+        let action = gate.pending().remove(0);
+        assert_eq!(notified.lock().unwrap()[0], action);
+        assert_eq!(action.input, "prod");
+        gate.approve(&action.id).unwrap();
+
+        assert_eq!(waiting.await.unwrap(), Some(ApprovalDecision::Approve));
+        assert!(gate.pending().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_decide_rejects_unknown_and_expired_actions() {
+        let gate = ApprovalGate::new()
+            .require_approval("deploy")
+            .with_timeout(Duration::from_millis(20));
+
+        assert_eq!(
+            gate.deny("missing"),
+            Err(ApprovalError::UnknownAction("missing".to_string()))
+        );
+
+        let notified = Arc::new(Mutex::new(None));
+        let seen = Arc::clone(&notified);
+        let gate = gate.with_notifier(move |action: &PendingAction| {
+            *seen.lock().unwrap() = Some(action.id.clone());
+        });
+        assert_eq!(
+            gate.request(&ToolCall::new("deploy", "prod").unwrap())
+                .await,
+            None
+        );
+
+        // The timed-out action was withdrawn
+        let id = notified.lock().unwrap().clone().unwrap();
+        assert!(gate.approve(&id).is_err());
+        assert!(gate.pending().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_cancelled_request_is_withdrawn() {
+        let gate = ApprovalGate::new().require_approval("deploy");
+
+        let call = ToolCall::new("deploy", "prod").unwrap();
+        let cancelled = tokio::time::timeout(Duration::from_millis(20), gate.request(&call)).await;
+
+        assert!(cancelled.is_err());
+        assert!(gate.pending().is_empty());
+    }
+}
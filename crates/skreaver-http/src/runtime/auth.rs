@@ -176,6 +176,29 @@ pub fn create_api_key_manager() -> Arc<ApiKeyManager> {
     manager
 }
 
+/// Permission required to run tools directly through `/tools/{tool_name}/invoke`
+///
+/// Matches [`Permission::ExecuteTool`](skreaver_core::auth::rbac::Permission::ExecuteTool),
+/// which the `admin` and `agent` roles grant to API keys.
+pub const TOOL_EXECUTE_PERMISSION: &str = "tool:execute";
+
+/// Permissions of an API key: its role names and every permission those roles grant
+fn role_permissions(roles: &[Role]) -> Vec<String> {
+    let mut permissions: Vec<String> = roles
+        .iter()
+        .map(|role| format!("{:?}", role).to_lowercase())
+        .chain(
+            roles
+                .iter()
+                .flat_map(Role::permissions)
+                .map(|permission| permission.to_string()),
+        )
+        .collect();
+    permissions.sort();
+    permissions.dedup();
+    permissions
+}
+
 /// JWT claims structure
 #[derive(Debug, Serialize, Deserialize)]
 pub struct Claims {
@@ -287,12 +310,7 @@ pub async fn extract_auth_context(
                                 .inc();
                         }
 
-                        // Extract permissions from roles
-                        let permissions = principal
-                            .roles
-                            .iter()
-                            .map(|role| format!("{:?}", role).to_lowercase())
-                            .collect();
+                        let permissions = role_permissions(&principal.roles);
 
                         return Ok(AuthContext {
                             user_id: principal.id,
@@ -363,12 +381,7 @@ pub async fn extract_auth_context(
                         .inc();
                 }
 
-                // Extract permissions from roles
-                let permissions = principal
-                    .roles
-                    .iter()
-                    .map(|role| format!("{:?}", role).to_lowercase())
-                    .collect();
+                let permissions = role_permissions(&principal.roles);
 
                 return Ok(AuthContext {
                     user_id: principal.id,
@@ -544,7 +557,23 @@ mod tests {
 
         let auth_context = extract_auth_context(&headers, &manager).await.unwrap();
         assert!(!auth_context.user_id.is_empty());
-        assert!(!auth_context.permissions.is_empty());
+        assert!(auth_context.permissions.contains(&"agent".to_string()));
+        assert!(
+            auth_context
+                .permissions
+                .contains(&TOOL_EXECUTE_PERMISSION.to_string())
+        );
+    }
+
+    #[test]
+    fn test_role_permissions_include_granted_permissions() {
+        assert_eq!(
+            TOOL_EXECUTE_PERMISSION,
+            skreaver_core::auth::rbac::Permission::ExecuteTool.to_string()
+        );
+        let viewer = role_permissions(&[Role::Viewer]);
+        assert_eq!(viewer, ["memory:read", "metrics:view", "viewer"]);
+        assert!(!viewer.contains(&TOOL_EXECUTE_PERMISSION.to_string()));
     }
 
     #[test]
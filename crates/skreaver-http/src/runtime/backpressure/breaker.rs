@@ -0,0 +1,145 @@
+//! Per-agent circuit breakers controlled by operators.
+//!
+//! A breaker sits in front of an agent's queue. While open, new requests for
+//! the agent fast-fail instead of being queued. Half-open admits a single
+//! trial request at a time so operators can probe a recovering agent.
+//!
+//! Breakers only change state when explicitly told to: a manually opened
+//! breaker stays open until it is reset (closed) or moved to half-open.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Circuit breaker state
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CircuitState {
+    /// Requests flow normally
+    #[default]
+    Closed,
+    /// All new requests are rejected
+    Open,
+    /// One trial request is admitted at a time
+    HalfOpen,
+}
+
+impl CircuitState {
+    /// Parse a state from its wire name or the matching action (`close`, `half-open`)
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "closed" | "close" => Some(Self::Closed),
+            "open" => Some(Self::Open),
+            "half_open" | "half-open" => Some(Self::HalfOpen),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for CircuitState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Closed => write!(f, "closed"),
+            Self::Open => write!(f, "open"),
+            Self::HalfOpen => write!(f, "half_open"),
+        }
+    }
+}
+
+/// Snapshot of an agent's circuit breaker
+#[derive(Debug, Clone, PartialEq)]
+pub struct CircuitBreakerStatus {
+    pub agent_id: String,
+    pub state: CircuitState,
+    /// Why the breaker was last changed, if a reason was given
+    pub reason: Option<String>,
+    /// Principal that last changed the breaker
+    pub changed_by: Option<String>,
+    /// When the breaker last changed state (`None` if never changed)
+    pub changed_at: Option<DateTime<Utc>>,
+    /// Requests rejected by this breaker since it last changed state
+    pub total_rejections: u64,
+}
+
+/// Stored breaker state for one agent
+#[derive(Debug, Clone)]
+struct Breaker {
+    state: CircuitState,
+    reason: Option<String>,
+    changed_by: Option<String>,
+    changed_at: DateTime<Utc>,
+    rejections: u64,
+}
+
+/// Circuit breakers keyed by agent id
+///
+/// Agents without an entry are closed; closing a breaker keeps its entry so
+/// the last change stays visible to operators.
+#[derive(Debug, Default)]
+pub(super) struct CircuitBreakers {
+    breakers: HashMap<String, Breaker>,
+}
+
+impl CircuitBreakers {
+    pub(super) fn new() -> Self {
+        Self::default()
+    }
+
+    pub(super) fn state(&self, agent_id: &str) -> CircuitState {
+        self.breakers
+            .get(agent_id)
+            .map(|b| b.state)
+            .unwrap_or_default()
+    }
+
+    pub(super) fn set(
+        &mut self,
+        agent_id: &str,
+        state: CircuitState,
+        changed_by: Option<String>,
+        reason: Option<String>,
+    ) -> CircuitBreakerStatus {
+        self.breakers.insert(
+            agent_id.to_string(),
+            Breaker {
+                state,
+                reason,
+                changed_by,
+                changed_at: Utc::now(),
+                rejections: 0,
+            },
+        );
+        self.status(agent_id)
+    }
+
+    pub(super) fn record_rejection(&mut self, agent_id: &str) {
+        if let Some(breaker) = self.breakers.get_mut(agent_id) {
+            breaker.rejections = breaker.rejections.saturating_add(1);
+        }
+    }
+
+    pub(super) fn remove(&mut self, agent_id: &str) {
+        self.breakers.remove(agent_id);
+    }
+
+    pub(super) fn status(&self, agent_id: &str) -> CircuitBreakerStatus {
+        match self.breakers.get(agent_id) {
+            Some(breaker) => CircuitBreakerStatus {
+                agent_id: agent_id.to_string(),
+                state: breaker.state,
+                reason: breaker.reason.clone(),
+                changed_by: breaker.changed_by.clone(),
+                changed_at: Some(breaker.changed_at),
+                total_rejections: breaker.rejections,
+            },
+            None => CircuitBreakerStatus {
+                agent_id: agent_id.to_string(),
+                state: CircuitState::Closed,
+                reason: None,
+                changed_by: None,
+                changed_at: None,
+                total_rejections: 0,
+            },
+        }
+    }
+}
@@ -0,0 +1,280 @@
+//! Bulkhead isolation for agent concurrency.
+//!
+//! A bulkhead is a dedicated concurrency pool. Agents are assigned to a pool by
+//! agent id or agent type (or any other grouping key such as a tenant), so a
+//! surge on one pool cannot consume capacity reserved for another. Agents
+//! without an assignment only share the global limits.
+
+use std::collections::HashMap;
+use std::sync::{
+    Arc,
+    atomic::{AtomicU64, Ordering},
+};
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+
+use super::config::ConcurrencyLimit;
+use crate::runtime::config::ConfigError;
+
+/// Bulkhead pool sizes and assignments
+#[derive(Debug, Clone, Default)]
+pub struct BulkheadConfig {
+    /// Pool name -> maximum concurrent requests in that pool
+    pub pools: HashMap<String, ConcurrencyLimit>,
+    /// Agent id or agent type -> pool name
+    ///
+    /// Agent id assignments take precedence over agent type assignments.
+    pub assignments: HashMap<String, String>,
+}
+
+impl BulkheadConfig {
+    /// Create an empty configuration (no bulkheads)
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a pool using the builder pattern
+    pub fn with_pool(mut self, name: impl Into<String>, max_concurrent: ConcurrencyLimit) -> Self {
+        self.pools.insert(name.into(), max_concurrent);
+        self
+    }
+
+    /// Assign an agent id or agent type to a pool using the builder pattern
+    pub fn assign(mut self, key: impl Into<String>, pool: impl Into<String>) -> Self {
+        self.assignments.insert(key.into(), pool.into());
+        self
+    }
+
+    /// Check that every assignment refers to a configured pool
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        for (key, pool) in &self.assignments {
+            if !self.pools.contains_key(pool) {
+                return Err(ConfigError::ValidationError(format!(
+                    "bulkhead assignment '{}' refers to unknown pool '{}'",
+                    key, pool
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Parse pools from a `name=size,name=size` list
+    pub fn parse_pools(value: &str) -> Result<HashMap<String, ConcurrencyLimit>, ConfigError> {
+        parse_pairs(value)?
+            .into_iter()
+            .map(|(name, size)| {
+                let size = size.parse::<usize>().map_err(|_| {
+                    ConfigError::ValidationError(format!(
+                        "invalid size '{}' for bulkhead '{}'",
+                        size, name
+                    ))
+                })?;
+                Ok((name, ConcurrencyLimit::new(size)?))
+            })
+            .collect()
+    }
+
+    /// Parse assignments from a `key=pool,key=pool` list
+    pub fn parse_assignments(value: &str) -> Result<HashMap<String, String>, ConfigError> {
+        Ok(parse_pairs(value)?.into_iter().collect())
+    }
+}
+
+fn parse_pairs(value: &str) -> Result<Vec<(String, String)>, ConfigError> {
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|entry| !entry.is_empty())
+        .map(|entry| {
+            entry
+                .split_once('=')
+                .map(|(k, v)| (k.trim().to_string(), v.trim().to_string()))
+                .filter(|(k, v)| !k.is_empty() && !v.is_empty())
+                .ok_or_else(|| {
+                    ConfigError::ValidationError(format!(
+                        "invalid bulkhead entry '{}', expected key=value",
+                        entry
+                    ))
+                })
+        })
+        .collect()
+}
+
+/// Utilization snapshot for a single bulkhead
+#[derive(Debug, Clone, PartialEq)]
+pub struct BulkheadMetrics {
+    pub name: String,
+    pub max_concurrent: usize,
+    pub active_requests: usize,
+    pub utilization: f64,
+    pub total_rejections: u64,
+}
+
+/// A dedicated concurrency pool
+#[derive(Debug)]
+pub(super) struct Bulkhead {
+    name: String,
+    capacity: usize,
+    semaphore: Arc<Semaphore>,
+    rejections: AtomicU64,
+}
+
+impl Bulkhead {
+    fn new(name: String, limit: ConcurrencyLimit) -> Self {
+        Self {
+            name,
+            capacity: limit.get(),
+            semaphore: Arc::new(Semaphore::new(limit.get())),
+            rejections: AtomicU64::new(0),
+        }
+    }
+
+    /// Try to take a slot in this bulkhead without waiting
+    pub(super) fn try_acquire(self: &Arc<Self>) -> Option<BulkheadPermit> {
+        match Arc::clone(&self.semaphore).try_acquire_owned() {
+            Ok(permit) => {
+                self.publish();
+                Some(BulkheadPermit {
+                    bulkhead: Arc::clone(self),
+                    permit: Some(permit),
+                })
+            }
+            Err(_) => {
+                self.rejections.fetch_add(1, Ordering::Relaxed);
+                None
+            }
+        }
+    }
+
+    fn active(&self) -> usize {
+        self.capacity - self.semaphore.available_permits()
+    }
+
+    fn metrics(&self) -> BulkheadMetrics {
+        let active = self.active();
+        BulkheadMetrics {
+            name: self.name.clone(),
+            max_concurrent: self.capacity,
+            active_requests: active,
+            utilization: active as f64 / self.capacity as f64,
+            total_rejections: self.rejections.load(Ordering::Relaxed),
+        }
+    }
+
+    fn publish(&self) {
+        if let Some(registry) = skreaver_observability::get_metrics_registry() {
+            registry.set_bulkhead_usage(&self.name, self.active(), self.capacity);
+        }
+    }
+}
+
+/// Slot held in a bulkhead for the duration of a request
+///
+/// Releases the slot and refreshes utilization metrics when dropped.
+#[derive(Debug)]
+pub(super) struct BulkheadPermit {
+    bulkhead: Arc<Bulkhead>,
+    permit: Option<OwnedSemaphorePermit>,
+}
+
+impl Drop for BulkheadPermit {
+    fn drop(&mut self) {
+        drop(self.permit.take());
+        self.bulkhead.publish();
+    }
+}
+
+/// Resolves agents to their bulkheads
+#[derive(Debug, Default)]
+pub(super) struct Bulkheads {
+    pools: HashMap<String, Arc<Bulkhead>>,
+    assignments: HashMap<String, String>,
+}
+
+impl Bulkheads {
+    pub(super) fn new(config: &BulkheadConfig) -> Self {
+        Self {
+            pools: config
+                .pools
+                .iter()
+                .map(|(name, limit)| (name.clone(), Arc::new(Bulkhead::new(name.clone(), *limit))))
+                .collect(),
+            assignments: config.assignments.clone(),
+        }
+    }
+
+    /// Find the bulkhead for an agent, by agent id first, then by agent type
+    pub(super) fn resolve(
+        &self,
+        agent_id: &str,
+        agent_type: Option<&str>,
+    ) -> Option<&Arc<Bulkhead>> {
+        self.assignments
+            .get(agent_id)
+            .or_else(|| agent_type.and_then(|t| self.assignments.get(t)))
+            .and_then(|pool| self.pools.get(pool))
+    }
+
+    pub(super) fn metrics(&self) -> Vec<BulkheadMetrics> {
+        let mut metrics: Vec<_> = self.pools.values().map(|b| b.metrics()).collect();
+        metrics.sort_by(|a, b| a.name.cmp(&b.name));
+        metrics
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn limit(n: usize) -> ConcurrencyLimit {
+        ConcurrencyLimit::new(n).unwrap()
+    }
+
+    #[test]
+    fn test_resolve_prefers_agent_id_over_type() {
+        let config = BulkheadConfig::new()
+            .with_pool("analytics", limit(2))
+            .with_pool("vip", limit(1))
+            .assign("analytics", "analytics")
+            .assign("agent-vip", "vip");
+        let bulkheads = Bulkheads::new(&config);
+
+        let by_type = bulkheads.resolve("agent-1", Some("analytics")).unwrap();
+        assert_eq!(by_type.name, "analytics");
+        let by_id = bulkheads.resolve("agent-vip", Some("analytics")).unwrap();
+        assert_eq!(by_id.name, "vip");
+        assert!(bulkheads.resolve("agent-2", Some("simple")).is_none());
+    }
+
+    #[test]
+    fn test_permit_releases_slot_on_drop() {
+        let config = BulkheadConfig::new().with_pool("pool", limit(1));
+        let bulkheads = Bulkheads::new(&config);
+        let bulkhead = bulkheads.pools.get("pool").unwrap();
+
+        let permit = bulkhead.try_acquire().unwrap();
+        assert!(bulkhead.try_acquire().is_none());
+        assert_eq!(bulkhead.metrics().utilization, 1.0);
+        assert_eq!(bulkhead.metrics().total_rejections, 1);
+
+        drop(permit);
+        assert_eq!(bulkhead.metrics().active_requests, 0);
+        assert!(bulkhead.try_acquire().is_some());
+    }
+
+    #[test]
+    fn test_config_parsing_and_validation() {
+        let pools = BulkheadConfig::parse_pools("analytics=4, vip=2").unwrap();
+        assert_eq!(pools["analytics"].get(), 4);
+        assert_eq!(pools["vip"].get(), 2);
+        assert!(BulkheadConfig::parse_pools("analytics=0").is_err());
+        assert!(BulkheadConfig::parse_pools("analytics").is_err());
+
+        let config = BulkheadConfig {
+            pools,
+            assignments: BulkheadConfig::parse_assignments("analytics=analytics,agent-x=missing")
+                .unwrap(),
+        };
+        let err = config.validate().unwrap_err();
+        assert!(err.to_string().contains("unknown pool 'missing'"));
+    }
+}
@@ -1,9 +1,11 @@
 //! Configuration types for backpressure management.
 
+use serde::{Deserialize, Serialize};
 use std::num::NonZeroUsize;
 use std::str::FromStr;
 use std::time::Duration;
 
+use super::bulkhead::BulkheadConfig;
 use crate::runtime::config::ConfigError;
 
 /// Backpressure strategy mode
@@ -151,6 +153,13 @@ pub struct BackpressureConfig {
     pub target_processing_time_ms: u64,
     /// Load factor threshold for triggering backpressure (0.0-1.0)
     pub load_threshold: LoadThreshold,
+    /// Dedicated concurrency pools per agent type or agent id
+    pub bulkheads: BulkheadConfig,
+    /// Wait after which a queued request's priority rises one level
+    ///
+    /// Keeps a steady stream of high priority work from starving lower
+    /// priority requests. `None` dispatches strictly by priority.
+    pub aging_interval: Option<Duration>,
 }
 
 impl Default for BackpressureConfig {
@@ -167,12 +176,15 @@ impl Default for BackpressureConfig {
             mode: BackpressureMode::default(),
             target_processing_time_ms: 1000,
             load_threshold: LoadThreshold::new(0.8).expect("default load threshold is valid"),
+            bulkheads: BulkheadConfig::default(),
+            aging_interval: None,
         }
     }
 }
 
 /// Priority levels for requests
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum RequestPriority {
     Low = 0,
     Normal = 1,
@@ -180,6 +192,29 @@ pub enum RequestPriority {
     Critical = 3,
 }
 
+impl RequestPriority {
+    /// Every priority, lowest first
+    pub const ALL: [Self; 4] = [Self::Low, Self::Normal, Self::High, Self::Critical];
+
+    /// Lowercase name of the priority
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Low => "low",
+            Self::Normal => "normal",
+            Self::High => "high",
+            Self::Critical => "critical",
+        }
+    }
+
+    /// Priority raised by `levels`, capped at [`Critical`](Self::Critical)
+    pub fn raised(self, levels: u64) -> Self {
+        let raised = (self as u64)
+            .saturating_add(levels)
+            .min(Self::Critical as u64);
+        Self::ALL[raised as usize]
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
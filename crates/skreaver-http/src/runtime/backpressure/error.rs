@@ -1,10 +1,17 @@
 //! Error types for backpressure and queue management.
 
+use serde::{Deserialize, Serialize};
+
 /// Backpressure and queue management errors
 #[derive(Debug, thiserror::Error)]
 pub enum BackpressureError {
     #[error("Queue is full for agent {agent_id} (max: {max_size})")]
-    QueueFull { agent_id: String, max_size: usize },
+    QueueFull {
+        agent_id: String,
+        max_size: usize,
+        /// Estimated time for the queue to drain
+        retry_after_ms: u64,
+    },
 
     #[error("Request timed out in queue after {timeout_ms}ms")]
     QueueTimeout { timeout_ms: u64 },
@@ -13,14 +20,65 @@ pub enum BackpressureError {
     ProcessingTimeout { timeout_ms: u64 },
 
     #[error("System overloaded, rejecting requests (load: {load:.2})")]
-    SystemOverloaded { load: f64 },
+    SystemOverloaded {
+        load: f64,
+        /// Estimated time for the load to fall below the threshold
+        retry_after_ms: u64,
+    },
+
+    #[error("Circuit breaker is {state} for agent {agent_id}")]
+    CircuitOpen {
+        agent_id: String,
+        state: super::CircuitState,
+        /// Suggested wait before probing the agent again
+        retry_after_ms: u64,
+    },
 
     #[error("Agent {agent_id} not found")]
     AgentNotFound { agent_id: String },
 
+    #[error("Server is shutting down, request rejected")]
+    ShuttingDown,
+
     #[error("Request cancelled")]
     RequestCancelled,
 
     #[error("Internal error: {message}")]
     Internal { message: String },
 }
+
+impl BackpressureError {
+    /// Retry guidance for rejections caused by load
+    ///
+    /// Returns `None` for errors that retrying will not fix, such as an
+    /// unknown agent, a cancelled request or a server that is shutting down.
+    pub fn retry_hint(&self) -> Option<RetryHint> {
+        match self {
+            Self::QueueFull { retry_after_ms, .. }
+            | Self::SystemOverloaded { retry_after_ms, .. }
+            | Self::CircuitOpen { retry_after_ms, .. } => Some(RetryHint {
+                retry_after_ms: *retry_after_ms,
+                // Overload rejects many clients at once; jitter keeps their
+                // retries from arriving together
+                jitter: true,
+            }),
+            _ => None,
+        }
+    }
+}
+
+/// Structured guidance for retrying a rejected request
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RetryHint {
+    /// How long to wait before retrying, in milliseconds
+    pub retry_after_ms: u64,
+    /// Whether the client should randomize the wait
+    pub jitter: bool,
+}
+
+impl RetryHint {
+    /// Wait in whole seconds for the `Retry-After` header, rounded up
+    pub fn retry_after_secs(&self) -> u64 {
+        self.retry_after_ms.div_ceil(1000).max(1)
+    }
+}
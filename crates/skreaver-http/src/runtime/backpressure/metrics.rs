@@ -1,7 +1,11 @@
 //! Metrics types for queue monitoring.
 
+use std::collections::BTreeMap;
+
+use super::config::RequestPriority;
+
 /// Metrics for queue monitoring
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct QueueMetrics {
     pub queue_size: usize,
     pub active_requests: usize,
@@ -10,4 +14,17 @@ pub struct QueueMetrics {
     pub total_rejections: u64,
     pub avg_processing_time_ms: f64,
     pub load_factor: f64,
+    /// Longest time a request of each priority waited in the queue
+    pub max_wait_ms_by_priority: BTreeMap<RequestPriority, u64>,
+}
+
+/// Outcome of draining the queues during graceful shutdown
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DrainSummary {
+    /// In-flight requests that completed before the grace deadline
+    pub drained: usize,
+    /// Queued requests rejected without being processed
+    pub rejected: usize,
+    /// In-flight requests still running when the grace deadline passed
+    pub abandoned: usize,
 }
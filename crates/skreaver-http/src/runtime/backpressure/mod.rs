@@ -5,38 +5,76 @@
 //! high load conditions using type-safe state management.
 
 use std::{
-    collections::HashMap,
+    collections::{BTreeMap, HashMap},
     sync::{
         Arc,
         atomic::{AtomicBool, Ordering},
     },
     time::{Duration, Instant},
 };
-use tokio::sync::{Notify, RwLock, Semaphore};
+use tokio::sync::{Notify, RwLock, RwLockWriteGuard, Semaphore};
 use tracing::{info, warn};
 use uuid::Uuid;
 
 // Module declarations
+mod breaker;
+mod bulkhead;
 mod config;
 mod error;
 mod metrics;
+mod persistence;
 mod queue;
 mod request;
 
 // Public re-exports
+pub use breaker::{CircuitBreakerStatus, CircuitState};
+pub use bulkhead::{BulkheadConfig, BulkheadMetrics};
 pub use config::{
     BackpressureConfig, BackpressureMode, ConcurrencyLimit, LoadThreshold, QueueSize,
     RequestPriority,
 };
-pub use error::BackpressureError;
-pub use metrics::QueueMetrics;
+pub use error::{BackpressureError, RetryHint};
+pub use metrics::{DrainSummary, QueueMetrics};
+pub use persistence::{
+    FileQueuePersistence, InMemoryQueuePersistence, PersistedRequest, QueuePersistence,
+};
 pub use request::{
     Completed, Failed, Processing, Queued, QueuedRequest, Request, ResponseReceiver, ResponseSender,
 };
 
 // Internal imports
+use breaker::CircuitBreakers;
+use bulkhead::{BulkheadPermit, Bulkheads};
 use queue::AgentQueue;
 
+/// Shortest retry hint given to rejected clients
+const MIN_RETRY_AFTER_MS: u64 = 100;
+
+/// Publish an agent's current queue depth to the global metrics registry
+fn publish_queue_depth(agent_id: &str, depth: usize) {
+    if let Some(registry) = skreaver_observability::get_metrics_registry() {
+        registry.set_agent_queue_depth(agent_id, depth);
+    }
+}
+
+/// Longest observed queue wait per priority across `queues`
+///
+/// Priorities that never left a queue are omitted.
+fn max_waits<'a>(
+    queues: impl IntoIterator<Item = &'a AgentQueue>,
+) -> BTreeMap<RequestPriority, u64> {
+    let mut waits = BTreeMap::new();
+    for queue in queues {
+        for priority in RequestPriority::ALL {
+            if let Some(waited) = queue.max_wait_ms[priority as usize] {
+                let max = waits.entry(priority).or_insert(waited);
+                *max = waited.max(*max);
+            }
+        }
+    }
+    waits
+}
+
 /// Main backpressure manager
 ///
 /// SECURITY: Uses AtomicBool for shutdown to ensure Drop can always signal
@@ -49,24 +87,109 @@ pub struct BackpressureManager {
     config: BackpressureConfig,
     agent_queues: Arc<RwLock<HashMap<String, AgentQueue>>>,
     global_semaphore: Arc<Semaphore>,
+    /// Dedicated concurrency pools isolating agent types from each other
+    bulkheads: Bulkheads,
+    /// Agent id -> agent type, used to resolve bulkhead assignments
+    agent_types: Arc<RwLock<HashMap<String, String>>>,
+    /// Operator-controlled circuit breakers per agent
+    breakers: Arc<RwLock<CircuitBreakers>>,
     /// MEDIUM-31: Replaced unbounded channel with Notify for instant shutdown
     shutdown_notify: Arc<Notify>,
     /// Atomic shutdown flag that can always be set safely in Drop
     shutdown_flag: Arc<AtomicBool>,
+    /// Set once a graceful drain starts; new and queued requests are refused
+    draining: Arc<AtomicBool>,
+    /// Checkpoints queued requests so they survive a restart
+    persistence: Option<Arc<dyn QueuePersistence>>,
 }
 
 impl BackpressureManager {
     /// Create a new backpressure manager
     pub fn new(config: BackpressureConfig) -> Self {
         let global_semaphore = Arc::new(Semaphore::new(config.global_max_concurrent.get()));
+        let bulkheads = Bulkheads::new(&config.bulkheads);
 
         Self {
             config,
             agent_queues: Arc::new(RwLock::new(HashMap::new())),
             global_semaphore,
+            bulkheads,
+            agent_types: Arc::new(RwLock::new(HashMap::new())),
+            breakers: Arc::new(RwLock::new(CircuitBreakers::new())),
             // MEDIUM-31: Use Notify instead of unbounded channel
             shutdown_notify: Arc::new(Notify::new()),
             shutdown_flag: Arc::new(AtomicBool::new(false)),
+            draining: Arc::new(AtomicBool::new(false)),
+            persistence: None,
+        }
+    }
+
+    /// Create a backpressure manager that checkpoints queued requests
+    ///
+    /// Requests left in `persistence` by a previous process are rehydrated
+    /// into the queues, oldest first, except those that outlived their
+    /// timeout or no longer fit. Their original callers are gone, so use
+    /// [`dispatch_queued`](Self::dispatch_queued) to re-dispatch them.
+    /// Only requests the process never answered are rehydrated: a request
+    /// rejected by [`drain`](Self::drain) or that timed out in the queue is
+    /// terminal, and its checkpoint is dropped.
+    pub fn new_with_persistence(
+        config: BackpressureConfig,
+        persistence: Arc<dyn QueuePersistence>,
+    ) -> Self {
+        let mut restored = persistence.load_queued();
+        restored.sort_by_key(|request| request.queued_at);
+
+        let mut queues: HashMap<String, AgentQueue> = HashMap::new();
+        for request in restored {
+            if request.has_expired() {
+                persistence.remove_queued(&request.agent_id, request.id);
+                continue;
+            }
+
+            let queue = queues
+                .entry(request.agent_id.clone())
+                .or_insert_with(|| AgentQueue::new(config.max_concurrent_requests.get()));
+            if queue.queue.len() >= config.max_queue_size.get() {
+                warn!(
+                    agent_id = %request.agent_id,
+                    request_id = %request.id,
+                    "Dropping persisted request that no longer fits in the queue"
+                );
+                persistence.remove_queued(&request.agent_id, request.id);
+                continue;
+            }
+
+            // Nobody awaits a rehydrated request, so its receiver is dropped
+            let (tx, _rx) = tokio::sync::oneshot::channel();
+            queue.insert_by_priority(request.into_queued(), tx);
+        }
+
+        let rehydrated: usize = queues.values().map(|queue| queue.queue.len()).sum();
+        for (agent_id, queue) in &queues {
+            publish_queue_depth(agent_id, queue.queue.len());
+        }
+        if rehydrated > 0 {
+            info!(rehydrated, "Rehydrated persisted queued requests");
+        }
+
+        let mut manager = Self::new(config);
+        manager.agent_queues = Arc::new(RwLock::new(queues));
+        manager.persistence = Some(persistence);
+        manager
+    }
+
+    /// Checkpoint a newly queued request
+    fn checkpoint(&self, agent_id: &str, request: &QueuedRequest) {
+        if let Some(persistence) = &self.persistence {
+            persistence.save_queued(agent_id, request);
+        }
+    }
+
+    /// Drop the checkpoint of a request that left the queue for good
+    fn forget(&self, agent_id: &str, request_id: Uuid) {
+        if let Some(persistence) = &self.persistence {
+            persistence.remove_queued(agent_id, request_id);
         }
     }
 
@@ -75,6 +198,7 @@ impl BackpressureManager {
         let agent_queues = Arc::clone(&self.agent_queues);
         let config = self.config.clone();
         let shutdown_flag = Arc::clone(&self.shutdown_flag);
+        let persistence = self.persistence.clone();
         // MEDIUM-31: Use Notify for instant shutdown notification
         let shutdown_notify = Arc::clone(&self.shutdown_notify);
 
@@ -98,7 +222,7 @@ impl BackpressureManager {
                             info!("Backpressure manager shutting down (via flag)");
                             break;
                         }
-                        Self::cleanup_expired_requests(&agent_queues, &config).await;
+                        Self::cleanup_expired_requests(&agent_queues, &config, persistence.as_deref()).await;
                     }
                 }
             }
@@ -115,18 +239,19 @@ impl BackpressureManager {
         priority: RequestPriority,
         timeout: Option<Duration>,
     ) -> Result<(Uuid, ResponseReceiver<String>), BackpressureError> {
+        if self.is_draining() {
+            return Err(BackpressureError::ShuttingDown);
+        }
+
+        // Fast-fail before any queueing when the agent's breaker is tripped;
+        // a half-open trial keeps its slot until the request is queued
+        let _trial = self.check_circuit(&agent_id).await?;
+
         // Check system load first if adaptive mode is enabled
         if self.config.mode == BackpressureMode::Adaptive {
             let load = self.calculate_system_load().await;
             if load > self.config.load_threshold.get() {
-                // Increment rejection counter for the agent
-                {
-                    let mut queues = self.agent_queues.write().await;
-                    if let Some(queue) = queues.get_mut(&agent_id) {
-                        queue.increment_rejections();
-                    }
-                }
-                return Err(BackpressureError::SystemOverloaded { load });
+                return Err(self.reject_overloaded(&agent_id, load).await);
             }
         }
 
@@ -149,21 +274,12 @@ impl BackpressureManager {
 
             // Check queue capacity
             if queue.queue.len() >= self.config.max_queue_size.get() {
-                queue.increment_rejections();
-                return Err(BackpressureError::QueueFull {
-                    agent_id,
-                    max_size: self.config.max_queue_size.get(),
-                });
+                return Err(self.reject_queue_full(agent_id, queue));
             }
 
-            // Insert based on priority
-            let insert_pos = queue
-                .queue
-                .iter()
-                .position(|(req, _)| req.priority < priority)
-                .unwrap_or(queue.queue.len());
-
-            queue.queue.insert(insert_pos, (queued_request, tx));
+            self.checkpoint(&agent_id, &queued_request);
+            queue.insert_by_priority(queued_request, tx);
+            publish_queue_depth(&agent_id, queue.queue.len());
         }
 
         Ok((request_id, rx))
@@ -176,18 +292,19 @@ impl BackpressureManager {
         priority: RequestPriority,
         timeout: Option<Duration>,
     ) -> Result<(Uuid, ResponseReceiver<String>), BackpressureError> {
+        if self.is_draining() {
+            return Err(BackpressureError::ShuttingDown);
+        }
+
+        // Fast-fail before any queueing when the agent's breaker is tripped;
+        // a half-open trial keeps its slot until the request is queued
+        let _trial = self.check_circuit(&agent_id).await?;
+
         // Check system load first if adaptive mode is enabled
         if self.config.mode == BackpressureMode::Adaptive {
             let load = self.calculate_system_load().await;
             if load > self.config.load_threshold.get() {
-                // Increment rejection counter for the agent
-                {
-                    let mut queues = self.agent_queues.write().await;
-                    if let Some(queue) = queues.get_mut(&agent_id) {
-                        queue.increment_rejections();
-                    }
-                }
-                return Err(BackpressureError::SystemOverloaded { load });
+                return Err(self.reject_overloaded(&agent_id, load).await);
             }
         }
 
@@ -210,21 +327,12 @@ impl BackpressureManager {
 
             // Check queue capacity
             if queue.queue.len() >= self.config.max_queue_size.get() {
-                queue.increment_rejections();
-                return Err(BackpressureError::QueueFull {
-                    agent_id,
-                    max_size: self.config.max_queue_size.get(),
-                });
+                return Err(self.reject_queue_full(agent_id, queue));
             }
 
-            // Insert based on priority
-            let insert_pos = queue
-                .queue
-                .iter()
-                .position(|(req, _)| req.priority < priority)
-                .unwrap_or(queue.queue.len());
-
-            queue.queue.insert(insert_pos, (queued_request, tx));
+            self.checkpoint(&agent_id, &queued_request);
+            queue.insert_by_priority(queued_request, tx);
+            publish_queue_depth(&agent_id, queue.queue.len());
         }
 
         Ok((request_id, rx))
@@ -232,6 +340,10 @@ impl BackpressureManager {
 
     /// Process the next request for an agent using queued input
     ///
+    /// The processor receives the request's input and its priority, so the
+    /// work it starts (such as tool calls) can be prioritized the same way
+    /// the request was queued.
+    ///
     /// SECURITY: Acquires permits BEFORE dequeuing requests to prevent TOCTOU race
     /// conditions that could cause priority inversion or request starvation.
     pub async fn process_next_queued_request<F, Fut>(
@@ -240,13 +352,22 @@ impl BackpressureManager {
         processor: F,
     ) -> Option<()>
     where
-        F: FnOnce(String) -> Fut + Send + 'static,
+        F: FnOnce(String, RequestPriority) -> Fut + Send + 'static,
         Fut: std::future::Future<Output = String> + Send + 'static,
     {
+        // Queued requests are rejected by the drain, not dispatched
+        if self.is_draining() {
+            return None;
+        }
+
         // SECURITY FIX: Acquire permits BEFORE dequeuing to prevent TOCTOU race
         // This ensures we have capacity before removing from queue, avoiding
         // priority inversion when requeuing on permit failure.
 
+        // Take a slot in the agent's bulkhead so a saturated pool cannot
+        // consume capacity reserved for other agent types
+        let bulkhead_permit = self.try_enter_bulkhead(agent_id).await.ok()?;
+
         // Try to acquire global permit first
         let _global_permit = self.global_semaphore.try_acquire().ok()?;
 
@@ -272,10 +393,12 @@ impl BackpressureManager {
                 return None;
             }
 
-            let (request, tx) = queue.queue.pop_front()?;
+            let (request, tx) = queue.take_next(self.config.aging_interval)?;
+            publish_queue_depth(agent_id, queue.queue.len());
             let input = request.input.clone().unwrap_or_default();
             (request, tx, input)
         };
+        self.forget(agent_id, request.id);
 
         // Check if request has timed out while in queue
         if request.queued_at.elapsed() > request.timeout {
@@ -314,16 +437,21 @@ impl BackpressureManager {
         };
 
         let agent_id_clone = request.agent_id.clone();
+        let priority = request.priority;
         let agent_queues = Arc::clone(&self.agent_queues);
         let processing_timeout = self.config.processing_timeout;
 
         // Process request in background
         tokio::spawn(async move {
+            // Hold the bulkhead slot until processing completes
+            let _bulkhead_permit = bulkhead_permit;
             let start_time = Instant::now();
 
             // Execute with timeout
-            let result =
-                tokio::time::timeout(processing_timeout, async { processor(input).await }).await;
+            let result = tokio::time::timeout(processing_timeout, async {
+                processor(input, priority).await
+            })
+            .await;
 
             let processing_time = start_time.elapsed().as_millis() as u64;
 
@@ -356,6 +484,8 @@ impl BackpressureManager {
     }
 
     /// Process the next request for an agent
+    ///
+    /// The processor receives `input` and the dequeued request's priority.
     pub async fn process_next_request<F, Fut>(
         &self,
         agent_id: &str,
@@ -363,9 +493,13 @@ impl BackpressureManager {
         processor: F,
     ) -> Option<()>
     where
-        F: FnOnce(String) -> Fut + Send + 'static,
+        F: FnOnce(String, RequestPriority) -> Fut + Send + 'static,
         Fut: std::future::Future<Output = String> + Send + 'static,
     {
+        if self.is_draining() {
+            return None;
+        }
+
         // Try to get a request from the queue
         let (request, tx, semaphore) = {
             let mut queues = self.agent_queues.write().await;
@@ -375,11 +509,27 @@ impl BackpressureManager {
                 return None;
             }
 
-            let (request, tx) = queue.queue.pop_front()?;
+            let (request, tx) = queue.take_next(self.config.aging_interval)?;
+            publish_queue_depth(agent_id, queue.queue.len());
             (request, tx, Arc::clone(&queue.semaphore))
         };
 
-        // Acquire permits
+        // Acquire permits, starting with the agent's bulkhead
+        let bulkhead_permit = match self.try_enter_bulkhead(agent_id).await {
+            Ok(permit) => permit,
+            Err(()) => {
+                // Bulkhead saturated, requeue the request
+                {
+                    let mut queues = self.agent_queues.write().await;
+                    if let Some(queue) = queues.get_mut(agent_id) {
+                        queue.requeue(request, tx);
+                        publish_queue_depth(agent_id, queue.queue.len());
+                    }
+                }
+                return None;
+            }
+        };
+
         let _global_permit = match self.global_semaphore.try_acquire() {
             Ok(permit) => permit,
             Err(_) => {
@@ -387,7 +537,8 @@ impl BackpressureManager {
                 {
                     let mut queues = self.agent_queues.write().await;
                     if let Some(queue) = queues.get_mut(agent_id) {
-                        queue.queue.push_front((request, tx));
+                        queue.requeue(request, tx);
+                        publish_queue_depth(agent_id, queue.queue.len());
                     }
                 }
                 return None;
@@ -401,12 +552,14 @@ impl BackpressureManager {
                 {
                     let mut queues = self.agent_queues.write().await;
                     if let Some(queue) = queues.get_mut(agent_id) {
-                        queue.queue.push_front((request, tx));
+                        queue.requeue(request, tx);
+                        publish_queue_depth(agent_id, queue.queue.len());
                     }
                 }
                 return None;
             }
         };
+        self.forget(agent_id, request.id);
 
         // Check if request has timed out while in queue
         if request.queued_at.elapsed() > request.timeout {
@@ -445,16 +598,21 @@ impl BackpressureManager {
         };
 
         let agent_id_clone = request.agent_id.clone();
+        let priority = request.priority;
         let agent_queues = Arc::clone(&self.agent_queues);
         let processing_timeout = self.config.processing_timeout;
 
         // Process request in background
         tokio::spawn(async move {
+            // Hold the bulkhead slot until processing completes
+            let _bulkhead_permit = bulkhead_permit;
             let start_time = Instant::now();
 
             // Execute with timeout
-            let result =
-                tokio::time::timeout(processing_timeout, async { processor(input).await }).await;
+            let result = tokio::time::timeout(processing_timeout, async {
+                processor(input, priority).await
+            })
+            .await;
 
             let processing_time = start_time.elapsed().as_millis() as u64;
 
@@ -486,6 +644,279 @@ impl BackpressureManager {
         Some(())
     }
 
+    /// Dispatch queued requests of every agent while capacity allows
+    ///
+    /// The processor receives the agent ID, input and priority of each
+    /// request, and its output is discarded when no caller awaits it. Meant
+    /// for re-dispatching requests rehydrated by
+    /// [`new_with_persistence`](Self::new_with_persistence); requests that do
+    /// not fit the concurrency limits stay queued.
+    ///
+    /// Returns the number of requests taken off the queues.
+    pub async fn dispatch_queued<F, Fut>(&self, processor: F) -> usize
+    where
+        F: Fn(String, String, RequestPriority) -> Fut + Clone + Send + 'static,
+        Fut: std::future::Future<Output = String> + Send + 'static,
+    {
+        let agent_ids: Vec<String> = self
+            .agent_queues
+            .read()
+            .await
+            .iter()
+            .filter(|(_, queue)| !queue.queue.is_empty())
+            .map(|(agent_id, _)| agent_id.clone())
+            .collect();
+
+        let mut dispatched = 0;
+        for agent_id in agent_ids {
+            loop {
+                let processor = processor.clone();
+                let target = agent_id.clone();
+                let next = self
+                    .process_next_queued_request(&agent_id, move |input, priority| {
+                        processor(target, input, priority)
+                    })
+                    .await;
+                if next.is_none() {
+                    break;
+                }
+                dispatched += 1;
+            }
+        }
+        dispatched
+    }
+
+    /// Check whether a graceful drain has started
+    pub fn is_draining(&self) -> bool {
+        self.draining.load(Ordering::Acquire)
+    }
+
+    /// Drain the manager for graceful shutdown
+    ///
+    /// New requests are refused and queued requests are no longer dispatched.
+    /// In-flight requests get up to `grace` to complete; once they finish or
+    /// the deadline passes, every request still queued is rejected with
+    /// [`BackpressureError::ShuttingDown`]. Their callers have been answered,
+    /// so with [persistence](Self::new_with_persistence) the rejected requests
+    /// are terminal and their checkpoints are dropped; the next process does
+    /// not run them.
+    pub async fn drain(&self, grace: Duration) -> DrainSummary {
+        self.draining.store(true, Ordering::Release);
+        let deadline = Instant::now() + grace;
+
+        let in_flight = self.active_request_count().await;
+        let mut remaining = in_flight;
+        while remaining > 0 && Instant::now() < deadline {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+            remaining = self.active_request_count().await;
+        }
+
+        let mut rejected = 0;
+        {
+            let mut queues = self.agent_queues.write().await;
+            for (agent_id, queue) in queues.iter_mut() {
+                if queue.queue.is_empty() {
+                    continue;
+                }
+                while let Some((request, tx)) = queue.queue.pop_front() {
+                    self.forget(agent_id, request.id);
+                    if tx.send(Err(BackpressureError::ShuttingDown)).is_err() {
+                        tracing::debug!(agent_id = %agent_id, "Client disconnected before shutdown response");
+                    }
+                    queue.increment_rejections();
+                    rejected += 1;
+                }
+                publish_queue_depth(agent_id, 0);
+            }
+        }
+
+        if remaining > 0 {
+            warn!(
+                abandoned = remaining,
+                "Grace period elapsed with requests still in flight"
+            );
+        }
+
+        DrainSummary {
+            drained: in_flight.saturating_sub(remaining),
+            rejected,
+            abandoned: remaining,
+        }
+    }
+
+    /// Total number of requests currently being processed
+    async fn active_request_count(&self) -> usize {
+        self.agent_queues
+            .read()
+            .await
+            .values()
+            .map(|q| q.active_requests.load(Ordering::Relaxed))
+            .sum()
+    }
+
+    /// Record an agent's type so bulkhead assignments by type apply to it
+    pub async fn register_agent_type(
+        &self,
+        agent_id: impl Into<String>,
+        agent_type: impl Into<String>,
+    ) {
+        self.agent_types
+            .write()
+            .await
+            .insert(agent_id.into(), agent_type.into());
+    }
+
+    /// Forget an agent's type after the agent is removed
+    pub async fn unregister_agent_type(&self, agent_id: &str) {
+        self.agent_types.write().await.remove(agent_id);
+    }
+
+    /// Manually set an agent's circuit breaker state
+    ///
+    /// The breaker stays in the given state until changed again; an open
+    /// breaker is never closed automatically.
+    pub async fn set_circuit_state(
+        &self,
+        agent_id: &str,
+        state: CircuitState,
+        changed_by: Option<String>,
+        reason: Option<String>,
+    ) -> CircuitBreakerStatus {
+        info!(
+            agent_id = %agent_id,
+            state = %state,
+            changed_by = changed_by.as_deref().unwrap_or("unknown"),
+            "Circuit breaker state changed"
+        );
+        self.breakers
+            .write()
+            .await
+            .set(agent_id, state, changed_by, reason)
+    }
+
+    /// Get an agent's circuit breaker status (closed if never changed)
+    pub async fn get_circuit_status(&self, agent_id: &str) -> CircuitBreakerStatus {
+        self.breakers.read().await.status(agent_id)
+    }
+
+    /// Forget an agent's circuit breaker after the agent is removed
+    pub async fn remove_circuit_breaker(&self, agent_id: &str) {
+        self.breakers.write().await.remove(agent_id);
+    }
+
+    /// Reject the request if the agent's circuit breaker does not admit it
+    ///
+    /// A half-open breaker admits one trial request while the agent is idle.
+    /// The trial slot is claimed under the breakers' write lock, and the
+    /// returned guard must be held until the request is queued so a
+    /// concurrent request cannot also find the agent idle.
+    async fn check_circuit(
+        &self,
+        agent_id: &str,
+    ) -> Result<Option<RwLockWriteGuard<'_, CircuitBreakers>>, BackpressureError> {
+        if self.breakers.read().await.state(agent_id) == CircuitState::Closed {
+            return Ok(None);
+        }
+
+        let mut breakers = self.breakers.write().await;
+        // The state may have changed while the write lock was acquired
+        let state = breakers.state(agent_id);
+        let admitted = match state {
+            CircuitState::Closed => true,
+            CircuitState::Open => false,
+            CircuitState::HalfOpen => {
+                let queues = self.agent_queues.read().await;
+                queues.get(agent_id).is_none_or(|queue| {
+                    queue.queue.is_empty() && queue.active_requests.load(Ordering::Relaxed) == 0
+                })
+            }
+        };
+
+        if admitted {
+            return Ok((state == CircuitState::HalfOpen).then_some(breakers));
+        }
+
+        // An open breaker waits for an operator, so suggest the longest wait;
+        // half-open frees up once the trial request finishes
+        let estimate_ms = match state {
+            CircuitState::HalfOpen => {
+                let queues = self.agent_queues.read().await;
+                self.expected_processing_ms(queues.get(agent_id))
+            }
+            _ => self.config.queue_timeout.as_millis() as f64,
+        };
+
+        breakers.record_rejection(agent_id);
+        Err(BackpressureError::CircuitOpen {
+            agent_id: agent_id.to_string(),
+            state,
+            retry_after_ms: self.retry_after_ms(estimate_ms),
+        })
+    }
+
+    /// Count a load-shedding rejection and estimate when the load will drop
+    async fn reject_overloaded(&self, agent_id: &str, load: f64) -> BackpressureError {
+        let mut queues = self.agent_queues.write().await;
+        let queue = queues.get_mut(agent_id);
+        let expected_ms = self.expected_processing_ms(queue.as_deref());
+        if let Some(queue) = queue {
+            queue.increment_rejections();
+        }
+
+        BackpressureError::SystemOverloaded {
+            load,
+            retry_after_ms: self
+                .retry_after_ms(expected_ms * load / self.config.load_threshold.get()),
+        }
+    }
+
+    /// Count a full-queue rejection and estimate how long the queue takes to drain
+    fn reject_queue_full(&self, agent_id: String, queue: &mut AgentQueue) -> BackpressureError {
+        queue.increment_rejections();
+        let drain_ms = self.expected_processing_ms(Some(queue)) * queue.queue.len() as f64
+            / self.config.max_concurrent_requests.get() as f64;
+
+        BackpressureError::QueueFull {
+            agent_id,
+            max_size: self.config.max_queue_size.get(),
+            retry_after_ms: self.retry_after_ms(drain_ms),
+        }
+    }
+
+    /// Average processing time of the agent, or the configured target before
+    /// any request has completed
+    fn expected_processing_ms(&self, queue: Option<&AgentQueue>) -> f64 {
+        match queue.map(AgentQueue::avg_processing_time) {
+            Some(avg) if avg > 0.0 => avg,
+            _ => self.config.target_processing_time_ms as f64,
+        }
+    }
+
+    /// Clamp an estimated wait to a retry hint no longer than the queue timeout
+    fn retry_after_ms(&self, estimate_ms: f64) -> u64 {
+        let max = (self.config.queue_timeout.as_millis() as u64).max(MIN_RETRY_AFTER_MS);
+        (estimate_ms.ceil() as u64).clamp(MIN_RETRY_AFTER_MS, max)
+    }
+
+    /// Get utilization of every configured bulkhead, sorted by name
+    pub fn get_bulkhead_metrics(&self) -> Vec<BulkheadMetrics> {
+        self.bulkheads.metrics()
+    }
+
+    /// Take a slot in the agent's bulkhead
+    ///
+    /// Returns `Ok(None)` for agents without a bulkhead and `Err(())` when the
+    /// agent's bulkhead is saturated.
+    async fn try_enter_bulkhead(&self, agent_id: &str) -> Result<Option<BulkheadPermit>, ()> {
+        let agent_types = self.agent_types.read().await;
+        let agent_type = agent_types.get(agent_id).map(String::as_str);
+
+        match self.bulkheads.resolve(agent_id, agent_type) {
+            Some(bulkhead) => bulkhead.try_acquire().map(Some).ok_or(()),
+            None => Ok(None),
+        }
+    }
+
     /// Get metrics for an agent
     pub async fn get_agent_metrics(&self, agent_id: &str) -> Option<QueueMetrics> {
         let queues = self.agent_queues.read().await;
@@ -499,6 +930,7 @@ impl BackpressureManager {
             total_rejections: queue.total_rejections,
             avg_processing_time_ms: queue.avg_processing_time(),
             load_factor: self.calculate_agent_load(queue).await,
+            max_wait_ms_by_priority: max_waits([queue]),
         })
     }
 
@@ -533,6 +965,25 @@ impl BackpressureManager {
             total_rejections,
             avg_processing_time_ms: avg_processing_time,
             load_factor: self.calculate_system_load().await,
+            max_wait_ms_by_priority: max_waits(queues.values()),
+        }
+    }
+
+    /// Forget an agent's queue after the agent is removed
+    ///
+    /// An empty, idle queue is dropped together with its queue depth series;
+    /// a queue still holding requests is kept until they are processed.
+    pub async fn remove_agent_queue(&self, agent_id: &str) {
+        let mut queues = self.agent_queues.write().await;
+        let idle = queues.get(agent_id).is_none_or(|queue| {
+            queue.queue.is_empty() && queue.active_requests.load(Ordering::Relaxed) == 0
+        });
+        if !idle {
+            return;
+        }
+        queues.remove(agent_id);
+        if let Some(registry) = skreaver_observability::get_metrics_registry() {
+            registry.remove_agent_queue_depth(agent_id);
         }
     }
 
@@ -565,6 +1016,7 @@ impl BackpressureManager {
     async fn cleanup_expired_requests(
         agent_queues: &Arc<RwLock<HashMap<String, AgentQueue>>>,
         config: &BackpressureConfig,
+        persistence: Option<&dyn QueuePersistence>,
     ) {
         let mut queues = agent_queues.write().await;
         let now = Instant::now();
@@ -575,7 +1027,11 @@ impl BackpressureManager {
             // Remove expired requests from front of queue
             while let Some((request, _)) = queue.queue.front() {
                 if now.duration_since(request.queued_at) > config.queue_timeout {
-                    if let Some((_, tx)) = queue.queue.pop_front() {
+                    if let Some((request, tx)) = queue.queue.pop_front() {
+                        queue.record_wait(&request);
+                        if let Some(persistence) = persistence {
+                            persistence.remove_queued(agent_id, request.id);
+                        }
                         if tx
                             .send(Err(BackpressureError::QueueTimeout {
                                 timeout_ms: config.queue_timeout.as_millis() as u64,
@@ -593,6 +1049,7 @@ impl BackpressureManager {
             }
 
             if expired_count > 0 {
+                publish_queue_depth(agent_id, queue.queue.len());
                 warn!(
                     "Cleaned up {} expired requests for agent {}",
                     expired_count, agent_id
@@ -701,6 +1158,51 @@ mod tests {
         assert_eq!(queue.queue[2].0.priority, RequestPriority::Low);
     }
 
+    #[tokio::test]
+    async fn test_aging_prevents_starvation() {
+        let config = BackpressureConfig {
+            aging_interval: Some(Duration::from_millis(50)),
+            ..BackpressureConfig::default()
+        };
+        let manager = BackpressureManager::new(config);
+        let queue = |input: &str, priority| {
+            manager.queue_request_with_input("agent".to_string(), input.to_string(), priority, None)
+        };
+        let process_next = || {
+            manager.process_next_queued_request("agent", |input, _priority| async move { input })
+        };
+
+        let (_, low_rx) = queue("low", RequestPriority::Low).await.unwrap();
+        let (_, high_rx) = queue("high", RequestPriority::High).await.unwrap();
+
+        // A fresh request has not aged yet, so priority decides
+        process_next().await.unwrap();
+        assert_eq!(high_rx.await.unwrap().unwrap(), "high");
+
+        // After two intervals the low request ties with high priority and,
+        // being older, goes ahead of newer high priority work
+        sleep(Duration::from_millis(120)).await;
+        let (_, newer_high_rx) = queue("newer-high", RequestPriority::High).await.unwrap();
+        process_next().await.unwrap();
+        assert_eq!(low_rx.await.unwrap().unwrap(), "low");
+
+        process_next().await.unwrap();
+        assert_eq!(newer_high_rx.await.unwrap().unwrap(), "newer-high");
+
+        let waits = manager
+            .get_agent_metrics("agent")
+            .await
+            .unwrap()
+            .max_wait_ms_by_priority;
+        assert!(waits[&RequestPriority::Low] >= 100);
+        assert!(waits[&RequestPriority::High] < 100);
+        assert!(!waits.contains_key(&RequestPriority::Critical));
+        assert_eq!(
+            manager.get_global_metrics().await.max_wait_ms_by_priority,
+            waits
+        );
+    }
+
     #[tokio::test]
     async fn test_processing_timeout() {
         let config = BackpressureConfig {
@@ -717,10 +1219,14 @@ mod tests {
 
         // Process with a slow operation
         manager
-            .process_next_request("test-agent", "test-input".to_string(), |_input| async {
-                sleep(Duration::from_millis(200)).await;
-                "result".to_string()
-            })
+            .process_next_request(
+                "test-agent",
+                "test-input".to_string(),
+                |_input, _priority| async {
+                    sleep(Duration::from_millis(200)).await;
+                    "result".to_string()
+                },
+            )
             .await;
 
         let result = rx.await.unwrap();
@@ -801,6 +1307,104 @@ mod tests {
         assert_eq!(global_metrics.total_rejections, 2);
     }
 
+    #[tokio::test]
+    async fn test_saturated_bulkhead_does_not_starve_other_bulkhead() {
+        let config = BackpressureConfig {
+            mode: BackpressureMode::Static,
+            bulkheads: BulkheadConfig::new()
+                .with_pool("batch", ConcurrencyLimit::new(1).unwrap())
+                .with_pool("interactive", ConcurrencyLimit::new(1).unwrap())
+                .assign("analytics", "batch")
+                .assign("echo", "interactive"),
+            ..BackpressureConfig::default()
+        };
+        let manager = BackpressureManager::new(config);
+        manager.start().await.unwrap();
+        manager.register_agent_type("batch-1", "analytics").await;
+        manager.register_agent_type("batch-2", "analytics").await;
+        manager.register_agent_type("chat-1", "echo").await;
+
+        // Saturate the batch bulkhead with a long-running request
+        let release = Arc::new(Notify::new());
+        let (_id, slow_rx) = manager
+            .queue_request_with_input(
+                "batch-1".to_string(),
+                "slow".to_string(),
+                RequestPriority::Normal,
+                None,
+            )
+            .await
+            .unwrap();
+        let gate = Arc::clone(&release);
+        manager
+            .process_next_queued_request("batch-1", move |input, _priority| async move {
+                gate.notified().await;
+                input
+            })
+            .await
+            .unwrap();
+
+        // Another agent in the same bulkhead cannot start
+        let (_id, _blocked_rx) = manager
+            .queue_request_with_input(
+                "batch-2".to_string(),
+                "blocked".to_string(),
+                RequestPriority::Normal,
+                None,
+            )
+            .await
+            .unwrap();
+        assert!(
+            manager
+                .process_next_queued_request("batch-2", |input, _priority| async move { input })
+                .await
+                .is_none()
+        );
+
+        // The interactive bulkhead keeps its full throughput
+        for i in 0..5 {
+            let (_id, rx) = manager
+                .queue_request_with_input(
+                    "chat-1".to_string(),
+                    format!("hello {}", i),
+                    RequestPriority::Normal,
+                    None,
+                )
+                .await
+                .unwrap();
+            manager
+                .process_next_queued_request("chat-1", |input, _priority| async move { input })
+                .await
+                .unwrap();
+            assert_eq!(rx.await.unwrap().unwrap(), format!("hello {}", i));
+        }
+
+        let metrics = manager.get_bulkhead_metrics();
+        assert_eq!(metrics[0].name, "batch");
+        assert_eq!(metrics[0].active_requests, 1);
+        assert_eq!(metrics[0].utilization, 1.0);
+        assert_eq!(metrics[0].total_rejections, 1);
+        assert_eq!(metrics[1].name, "interactive");
+        assert_eq!(metrics[1].total_rejections, 0);
+
+        // Releasing the slow request frees the batch bulkhead
+        release.notify_one();
+        assert_eq!(slow_rx.await.unwrap().unwrap(), "slow");
+        tokio::time::timeout(Duration::from_secs(1), async {
+            while manager.get_bulkhead_metrics()[0].active_requests > 0 {
+                sleep(Duration::from_millis(5)).await;
+            }
+        })
+        .await
+        .expect("batch bulkhead released");
+        assert!(
+            manager
+                .process_next_queued_request("batch-2", |input, _priority| async move { input })
+                .await
+                .is_some()
+        );
+    }
+
     #[tokio::test]
     async fn test_system_overload_rejection_metrics() {
         let config = BackpressureConfig {
@@ -836,9 +1440,349 @@ mod tests {
             result,
             Err(BackpressureError::SystemOverloaded { .. })
         ));
+        // Load is 100x the threshold, so the hint is capped at the queue timeout
+        let hint = result.unwrap_err().retry_hint().unwrap();
+        assert_eq!(hint.retry_after_ms, 30_000);
+        assert_eq!(hint.retry_after_secs(), 30);
 
         // Verify rejection was counted
         let metrics = manager.get_agent_metrics("test-agent").await.unwrap();
         assert_eq!(metrics.total_rejections, 1);
     }
+
+    #[tokio::test]
+    async fn test_queue_full_rejection_includes_retry_hint() {
+        let config = BackpressureConfig {
+            max_queue_size: QueueSize::new(4).unwrap(),
+            max_concurrent_requests: ConcurrencyLimit::new(2).unwrap(),
+            target_processing_time_ms: 250,
+            ..BackpressureConfig::default()
+        };
+        let manager = BackpressureManager::new(config);
+
+        let mut receivers = Vec::new();
+        for _ in 0..4 {
+            receivers.push(
+                manager
+                    .queue_request("test-agent".to_string(), RequestPriority::Normal, None)
+                    .await
+                    .unwrap(),
+            );
+        }
+        let error = manager
+            .queue_request("test-agent".to_string(), RequestPriority::Normal, None)
+            .await
+            .unwrap_err();
+
+        // Four queued requests at 250ms each, two at a time
+        let hint = error.retry_hint().unwrap();
+        assert_eq!(hint.retry_after_ms, 500);
+        assert!(hint.jitter);
+        assert_eq!(hint.retry_after_secs(), 1);
+    }
+
+    #[test]
+    fn test_non_retryable_errors_have_no_retry_hint() {
+        for error in [
+            BackpressureError::AgentNotFound {
+                agent_id: "missing".to_string(),
+            },
+            BackpressureError::Internal {
+                message: "boom".to_string(),
+            },
+            BackpressureError::RequestCancelled,
+            BackpressureError::ShuttingDown,
+        ] {
+            assert_eq!(error.retry_hint(), None, "{error}");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_open_circuit_fast_fails_until_reset() {
+        let manager = BackpressureManager::new(BackpressureConfig::default());
+
+        let status = manager
+            .set_circuit_state(
+                "test-agent",
+                CircuitState::Open,
+                Some("ops".to_string()),
+                Some("incident".to_string()),
+            )
+            .await;
+        assert_eq!(status.state, CircuitState::Open);
+        assert_eq!(status.changed_by.as_deref(), Some("ops"));
+
+        for _ in 0..2 {
+            let result = manager
+                .queue_request("test-agent".to_string(), RequestPriority::Critical, None)
+                .await;
+            assert!(matches!(
+                result,
+                Err(BackpressureError::CircuitOpen {
+                    state: CircuitState::Open,
+                    ..
+                })
+            ));
+        }
+        assert_eq!(
+            manager
+                .get_circuit_status("test-agent")
+                .await
+                .total_rejections,
+            2
+        );
+
+        // Other agents are unaffected
+        assert!(
+            manager
+                .queue_request("other-agent".to_string(), RequestPriority::Normal, None)
+                .await
+                .is_ok()
+        );
+
+        manager
+            .set_circuit_state("test-agent", CircuitState::Closed, None, None)
+            .await;
+        assert!(
+            manager
+                .queue_request("test-agent".to_string(), RequestPriority::Normal, None)
+                .await
+                .is_ok()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_half_open_circuit_admits_one_trial_request() {
+        let manager = BackpressureManager::new(BackpressureConfig::default());
+        manager
+            .set_circuit_state("test-agent", CircuitState::HalfOpen, None, None)
+            .await;
+
+        let (_id, _rx) = manager
+            .queue_request("test-agent".to_string(), RequestPriority::Normal, None)
+            .await
+            .unwrap();
+
+        // The trial request is still pending, so further requests are rejected
+        let result = manager
+            .queue_request("test-agent".to_string(), RequestPriority::Normal, None)
+            .await;
+        assert!(matches!(
+            result,
+            Err(BackpressureError::CircuitOpen {
+                state: CircuitState::HalfOpen,
+                ..
+            })
+        ));
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn test_half_open_circuit_admits_one_of_concurrent_requests() {
+        let manager = Arc::new(BackpressureManager::new(BackpressureConfig::default()));
+        manager
+            .set_circuit_state("test-agent", CircuitState::HalfOpen, None, None)
+            .await;
+
+        let attempts: Vec<_> = (0..16)
+            .map(|_| {
+                let manager = Arc::clone(&manager);
+                tokio::spawn(async move {
+                    manager
+                        .queue_request("test-agent".to_string(), RequestPriority::Normal, None)
+                        .await
+                })
+            })
+            .collect();
+        let mut admitted = Vec::new();
+        for attempt in attempts {
+            if let Ok(queued) = attempt.await.unwrap() {
+                admitted.push(queued);
+            }
+        }
+
+        assert_eq!(admitted.len(), 1);
+        assert_eq!(
+            manager
+                .get_circuit_status("test-agent")
+                .await
+                .total_rejections,
+            15
+        );
+    }
+
+    #[tokio::test]
+    async fn test_drain_waits_for_in_flight_and_rejects_queued() {
+        let manager = BackpressureManager::new(BackpressureConfig::default());
+
+        let (_id1, in_flight_rx) = manager
+            .queue_request_with_input(
+                "agent".to_string(),
+                "a".to_string(),
+                RequestPriority::Normal,
+                None,
+            )
+            .await
+            .unwrap();
+        let (_id2, queued_rx) = manager
+            .queue_request_with_input(
+                "agent".to_string(),
+                "b".to_string(),
+                RequestPriority::Normal,
+                None,
+            )
+            .await
+            .unwrap();
+
+        manager
+            .process_next_queued_request("agent", |input, _priority| async move {
+                sleep(Duration::from_millis(50)).await;
+                input
+            })
+            .await
+            .unwrap();
+
+        let summary = manager.drain(Duration::from_secs(5)).await;
+        assert_eq!(
+            summary,
+            DrainSummary {
+                drained: 1,
+                rejected: 1,
+                abandoned: 0,
+            }
+        );
+        assert_eq!(in_flight_rx.await.unwrap().unwrap(), "a");
+        assert!(matches!(
+            queued_rx.await.unwrap(),
+            Err(BackpressureError::ShuttingDown)
+        ));
+
+        let result = manager
+            .queue_request("agent".to_string(), RequestPriority::Normal, None)
+            .await;
+        assert!(matches!(result, Err(BackpressureError::ShuttingDown)));
+    }
+
+    #[tokio::test]
+    async fn test_drain_abandons_requests_past_grace_deadline() {
+        let manager = BackpressureManager::new(BackpressureConfig::default());
+
+        let (_id, _rx) = manager
+            .queue_request("agent".to_string(), RequestPriority::Normal, None)
+            .await
+            .unwrap();
+        manager
+            .process_next_request("agent", String::new(), |input, _priority| async move {
+                sleep(Duration::from_secs(5)).await;
+                input
+            })
+            .await
+            .unwrap();
+
+        let summary = manager.drain(Duration::from_millis(20)).await;
+        assert_eq!(summary.drained, 0);
+        assert_eq!(summary.abandoned, 1);
+    }
+
+    #[tokio::test]
+    async fn test_queued_requests_survive_restart() {
+        let persistence = Arc::new(InMemoryQueuePersistence::new());
+        let manager = BackpressureManager::new_with_persistence(
+            BackpressureConfig::default(),
+            persistence.clone(),
+        );
+
+        for (input, priority) in [
+            ("low", RequestPriority::Low),
+            ("high", RequestPriority::High),
+        ] {
+            manager
+                .queue_request_with_input("agent".to_string(), input.to_string(), priority, None)
+                .await
+                .unwrap();
+        }
+        manager
+            .queue_request_with_input(
+                "agent".to_string(),
+                "expired".to_string(),
+                RequestPriority::Critical,
+                Some(Duration::from_millis(1)),
+            )
+            .await
+            .unwrap();
+        assert_eq!(persistence.len(), 3);
+
+        // The process dies without answering the queued requests
+        drop(manager);
+        assert_eq!(persistence.len(), 3);
+        sleep(Duration::from_millis(5)).await;
+
+        let manager = BackpressureManager::new_with_persistence(
+            BackpressureConfig::default(),
+            persistence.clone(),
+        );
+        assert_eq!(
+            manager.get_agent_metrics("agent").await.unwrap().queue_size,
+            2
+        );
+        assert_eq!(persistence.len(), 2);
+
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        let dispatched = manager
+            .dispatch_queued(move |agent_id, input, priority| {
+                let tx = tx.clone();
+                async move {
+                    tx.send((agent_id, input.clone(), priority)).unwrap();
+                    input
+                }
+            })
+            .await;
+        assert_eq!(dispatched, 2);
+        assert!(persistence.is_empty());
+
+        assert_eq!(
+            rx.recv().await.unwrap(),
+            (
+                "agent".to_string(),
+                "high".to_string(),
+                RequestPriority::High
+            )
+        );
+        assert_eq!(
+            rx.recv().await.unwrap(),
+            ("agent".to_string(), "low".to_string(), RequestPriority::Low)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_drained_requests_are_not_rehydrated() {
+        let persistence = Arc::new(InMemoryQueuePersistence::new());
+        let manager = BackpressureManager::new_with_persistence(
+            BackpressureConfig::default(),
+            persistence.clone(),
+        );
+        let (_, rx) = manager
+            .queue_request_with_input(
+                "agent".to_string(),
+                "work".to_string(),
+                RequestPriority::Normal,
+                None,
+            )
+            .await
+            .unwrap();
+        assert_eq!(persistence.len(), 1);
+
+        let summary = manager.drain(Duration::from_millis(10)).await;
+        assert_eq!(summary.rejected, 1);
+        assert!(matches!(
+            rx.await.unwrap(),
+            Err(BackpressureError::ShuttingDown)
+        ));
+        assert!(persistence.is_empty());
+
+        let manager = BackpressureManager::new_with_persistence(
+            BackpressureConfig::default(),
+            persistence.clone(),
+        );
+        assert!(manager.get_agent_metrics("agent").await.is_none());
+    }
 }
@@ -0,0 +1,534 @@
+//! Checkpointing of queued requests across restarts.
+//!
+//! A [`QueuePersistence`] receives every request the manager queues and is
+//! told when each one leaves the queue, so whatever it still holds on startup
+//! is the work a previous process accepted but never started. Only the input,
+//! priority, queue time and timeout are kept: response channels cannot
+//! survive a restart, so rehydrated requests are re-dispatched rather than
+//! awaited by their original callers.
+//!
+//! [`FileQueuePersistence`] keeps the checkpoints in an append-only journal
+//! file and is the backend to use across process restarts; [`InMemoryQueuePersistence`] only
+//! outlives a single manager.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use uuid::Uuid;
+
+use super::config::RequestPriority;
+use super::request::QueuedRequest;
+
+/// Queued request as checkpointed by a [`QueuePersistence`]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PersistedRequest {
+    /// Request ID
+    pub id: Uuid,
+    /// Agent the request was queued for
+    pub agent_id: String,
+    /// Input payload, if the request carried one
+    pub input: Option<String>,
+    /// Request priority
+    pub priority: RequestPriority,
+    /// When the request was queued
+    pub queued_at: DateTime<Utc>,
+    /// How long the request may wait in the queue, in milliseconds
+    pub timeout_ms: u64,
+}
+
+impl PersistedRequest {
+    /// Capture a queued request, converting its queue time to wall-clock time
+    pub fn from_queued(request: &QueuedRequest) -> Self {
+        let waited = chrono::Duration::from_std(request.queued_at.elapsed()).unwrap_or_default();
+        Self {
+            id: request.id,
+            agent_id: request.agent_id.clone(),
+            input: request.input.clone(),
+            priority: request.priority,
+            queued_at: Utc::now() - waited,
+            timeout_ms: request.timeout.as_millis() as u64,
+        }
+    }
+
+    /// Time spent queued so far, across restarts
+    pub fn waited(&self) -> Duration {
+        (Utc::now() - self.queued_at).to_std().unwrap_or_default()
+    }
+
+    /// Check whether the request outlived its queue timeout
+    pub fn has_expired(&self) -> bool {
+        self.waited() > Duration::from_millis(self.timeout_ms)
+    }
+
+    /// Rebuild the in-memory request, keeping the time already spent queued
+    pub(super) fn into_queued(self) -> QueuedRequest {
+        let waited = self.waited();
+        QueuedRequest {
+            id: self.id,
+            agent_id: self.agent_id,
+            priority: self.priority,
+            queued_at: Instant::now()
+                .checked_sub(waited)
+                .unwrap_or_else(Instant::now),
+            timeout: Duration::from_millis(self.timeout_ms),
+            input: self.input,
+        }
+    }
+}
+
+/// Durable store for queued requests
+///
+/// Implementations should be quick; they are called while the agent's queue
+/// is locked.
+pub trait QueuePersistence: Send + Sync {
+    /// Checkpoint a request that was just queued for `agent_id`
+    fn save_queued(&self, agent_id: &str, request: &QueuedRequest);
+
+    /// Forget a request that left the queue for good
+    fn remove_queued(&self, agent_id: &str, request_id: Uuid);
+
+    /// All checkpointed requests, read once on startup
+    fn load_queued(&self) -> Vec<PersistedRequest>;
+}
+
+impl fmt::Debug for dyn QueuePersistence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("QueuePersistence").finish_non_exhaustive()
+    }
+}
+
+/// Queue persistence that keeps checkpoints in memory
+///
+/// Survives replacing a [`BackpressureManager`](super::BackpressureManager)
+/// within one process, which makes it useful for tests.
+#[derive(Debug, Default)]
+pub struct InMemoryQueuePersistence {
+    requests: Mutex<HashMap<Uuid, PersistedRequest>>,
+}
+
+impl InMemoryQueuePersistence {
+    /// Create an empty store
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of checkpointed requests
+    pub fn len(&self) -> usize {
+        self.requests
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .len()
+    }
+
+    /// Check whether no requests are checkpointed
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl QueuePersistence for InMemoryQueuePersistence {
+    fn save_queued(&self, _agent_id: &str, request: &QueuedRequest) {
+        self.requests
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(request.id, PersistedRequest::from_queued(request));
+    }
+
+    fn remove_queued(&self, _agent_id: &str, request_id: Uuid) {
+        self.requests
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .remove(&request_id);
+    }
+
+    fn load_queued(&self) -> Vec<PersistedRequest> {
+        self.requests
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .values()
+            .cloned()
+            .collect()
+    }
+}
+
+/// Queue persistence that keeps checkpoints in an append-only JSON lines file
+///
+/// Every change appends one record, so saving or removing a checkpoint costs
+/// the same regardless of how many requests are queued. Records are handed to
+/// the OS but not synced, so they survive a process crash but not necessarily
+/// a power loss. Once dead records make up most of the file it is compacted:
+/// the live checkpoints are written to a synced temporary file that is renamed
+/// over it. A record torn by a crash mid-write is dropped on the next open.
+///
+/// Request inputs are stored in plain text. The file is created readable and
+/// writable by its owner only (mode `0600` on Unix); keep it out of shared
+/// directories. Write failures are logged and leave the affected request
+/// without a checkpoint; they never fail the request itself.
+#[derive(Debug)]
+pub struct FileQueuePersistence {
+    path: PathBuf,
+    journal: Mutex<Journal>,
+}
+
+/// Minimum number of records before a journal is compacted
+const COMPACTION_MIN_RECORDS: usize = 1024;
+
+/// One line of the checkpoint journal
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+enum JournalRecord {
+    /// A request was queued
+    Queued(PersistedRequest),
+    /// A request left the queue
+    Removed { id: Uuid },
+}
+
+/// Checkpoints and the open journal file
+#[derive(Debug, Default)]
+struct Journal {
+    requests: HashMap<Uuid, PersistedRequest>,
+    /// Append handle, opened on the first write
+    file: Option<fs::File>,
+    /// Records in the file, live or not
+    records: usize,
+}
+
+impl Journal {
+    fn apply(&mut self, record: JournalRecord) {
+        match record {
+            JournalRecord::Queued(request) => {
+                self.requests.insert(request.id, request);
+            }
+            JournalRecord::Removed { id } => {
+                self.requests.remove(&id);
+            }
+        }
+    }
+
+    fn append(&mut self, path: &Path, line: &[u8]) -> io::Result<()> {
+        let file = match &mut self.file {
+            Some(file) => file,
+            None => self
+                .file
+                .insert(open_private(path, fs::OpenOptions::new().append(true))?),
+        };
+        file.write_all(line)?;
+        self.records += 1;
+        Ok(())
+    }
+
+    fn needs_compaction(&self) -> bool {
+        self.records >= COMPACTION_MIN_RECORDS && self.records > 2 * self.requests.len()
+    }
+
+    /// Rewrite the file with only the live checkpoints
+    fn compact(&mut self, path: &Path) -> io::Result<()> {
+        let mut contents = Vec::new();
+        for request in self.requests.values() {
+            serde_json::to_writer(&mut contents, &JournalRecord::Queued(request.clone()))
+                .map_err(io::Error::other)?;
+            contents.push(b'\n');
+        }
+
+        let tmp = path.with_extension("tmp");
+        let mut file = open_private(&tmp, fs::OpenOptions::new().write(true).truncate(true))?;
+        file.write_all(&contents)?;
+        file.sync_all()?;
+        // Drop the old append handle before its file is replaced
+        self.file = None;
+        fs::rename(&tmp, path)?;
+        self.records = self.requests.len();
+        Ok(())
+    }
+}
+
+impl FileQueuePersistence {
+    /// Open the checkpoint file at `path`, creating it on the first write
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file exists but cannot be read, contains a
+    /// malformed record other than a torn last one, or a torn record cannot
+    /// be compacted away.
+    pub fn open(path: impl Into<PathBuf>) -> io::Result<Self> {
+        let path = path.into();
+        let mut journal = Journal::default();
+        let contents = match fs::read(&path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Vec::new(),
+            Err(e) => return Err(e),
+        };
+
+        // Only complete lines are records; anything after the last newline
+        // was torn by a crash mid-append
+        let complete = contents
+            .iter()
+            .rposition(|&b| b == b'\n')
+            .map_or(0, |end| end + 1);
+        for line in contents[..complete].split(|&b| b == b'\n') {
+            if line.is_empty() {
+                continue;
+            }
+            let record = serde_json::from_slice::<JournalRecord>(line)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+            journal.apply(record);
+            journal.records += 1;
+        }
+        if complete < contents.len() {
+            tracing::warn!(path = ?path, "Dropping torn record from queue checkpoints");
+            journal.compact(&path)?;
+        }
+
+        Ok(Self {
+            path,
+            journal: Mutex::new(journal),
+        })
+    }
+
+    /// Path of the checkpoint file
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Rewrite the file with only the live checkpoints
+    ///
+    /// Runs automatically once dead records dominate the file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the compacted file cannot be written.
+    pub fn compact(&self) -> io::Result<()> {
+        self.journal
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .compact(&self.path)
+    }
+
+    /// Append a record and apply it to the checkpoints
+    fn record(&self, record: JournalRecord) {
+        let mut journal = self.journal.lock().unwrap_or_else(|e| e.into_inner());
+        let result = serde_json::to_vec(&record)
+            .map_err(io::Error::other)
+            .and_then(|mut line| {
+                line.push(b'\n');
+                journal.append(&self.path, &line)
+            });
+        journal.apply(record);
+        if let Err(e) = result {
+            tracing::warn!(path = ?self.path, error = %e, "Failed to write queue checkpoint");
+            return;
+        }
+        if journal.needs_compaction()
+            && let Err(e) = journal.compact(&self.path)
+        {
+            tracing::warn!(path = ?self.path, error = %e, "Failed to compact queue checkpoints");
+        }
+    }
+}
+
+impl QueuePersistence for FileQueuePersistence {
+    fn save_queued(&self, _agent_id: &str, request: &QueuedRequest) {
+        self.record(JournalRecord::Queued(PersistedRequest::from_queued(
+            request,
+        )));
+    }
+
+    fn remove_queued(&self, _agent_id: &str, request_id: Uuid) {
+        self.record(JournalRecord::Removed { id: request_id });
+    }
+
+    fn load_queued(&self) -> Vec<PersistedRequest> {
+        self.journal
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .requests
+            .values()
+            .cloned()
+            .collect()
+    }
+}
+
+/// Open `path` for writing, creating it readable by its owner only
+fn open_private(path: &Path, options: &mut fs::OpenOptions) -> io::Result<fs::File> {
+    options.create(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    options.open(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_persisted_request_keeps_time_already_queued() {
+        let request = QueuedRequest {
+            id: Uuid::new_v4(),
+            agent_id: "agent".to_string(),
+            priority: RequestPriority::High,
+            queued_at: Instant::now() - Duration::from_secs(5),
+            timeout: Duration::from_secs(30),
+            input: Some("hello".to_string()),
+        };
+
+        let persisted = PersistedRequest::from_queued(&request);
+        assert!(persisted.waited() >= Duration::from_secs(5));
+        assert!(!persisted.has_expired());
+
+        let json = serde_json::to_string(&persisted).unwrap();
+        let restored: PersistedRequest = serde_json::from_str(&json).unwrap();
+        let rehydrated = restored.into_queued();
+        assert_eq!(rehydrated.id, request.id);
+        assert_eq!(rehydrated.priority, RequestPriority::High);
+        assert_eq!(rehydrated.input.as_deref(), Some("hello"));
+        assert!(rehydrated.queued_at.elapsed() >= Duration::from_secs(5));
+    }
+
+    #[test]
+    fn test_file_persistence_survives_reopening() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("queue.json");
+        let request = |input: &str| QueuedRequest {
+            id: Uuid::new_v4(),
+            agent_id: "agent".to_string(),
+            priority: RequestPriority::Normal,
+            queued_at: Instant::now(),
+            timeout: Duration::from_secs(30),
+            input: Some(input.to_string()),
+        };
+        let (kept, done) = (request("kept"), request("done"));
+
+        let persistence = FileQueuePersistence::open(&path).unwrap();
+        assert!(persistence.load_queued().is_empty());
+        persistence.save_queued("agent", &kept);
+        persistence.save_queued("agent", &done);
+        persistence.remove_queued("agent", done.id);
+        drop(persistence);
+
+        let reopened = FileQueuePersistence::open(&path).unwrap();
+        let restored = reopened.load_queued();
+        assert_eq!(restored.len(), 1);
+        assert_eq!(restored[0].id, kept.id);
+        assert_eq!(restored[0].input.as_deref(), Some("kept"));
+
+        fs::write(&path, b"not json\n").unwrap();
+        assert!(FileQueuePersistence::open(&path).is_err());
+    }
+
+    fn queued(input: &str) -> QueuedRequest {
+        QueuedRequest {
+            id: Uuid::new_v4(),
+            agent_id: "agent".to_string(),
+            priority: RequestPriority::Normal,
+            queued_at: Instant::now(),
+            timeout: Duration::from_secs(30),
+            input: Some(input.to_string()),
+        }
+    }
+
+    fn line_count(path: &Path) -> usize {
+        fs::read_to_string(path).unwrap().lines().count()
+    }
+
+    #[test]
+    fn test_file_persistence_appends_and_compacts() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("queue.jsonl");
+        let persistence = FileQueuePersistence::open(&path).unwrap();
+
+        let kept = queued("kept");
+        persistence.save_queued("agent", &kept);
+        for _ in 0..10 {
+            let request = queued("done");
+            persistence.save_queued("agent", &request);
+            persistence.remove_queued("agent", request.id);
+        }
+        // One record per change, nothing rewritten yet
+        assert_eq!(line_count(&path), 21);
+
+        persistence.compact().unwrap();
+        assert_eq!(line_count(&path), 1);
+
+        // Appends continue on the compacted file
+        let later = queued("later");
+        persistence.save_queued("agent", &later);
+        assert_eq!(line_count(&path), 2);
+
+        // Dead records trigger compaction on their own
+        for _ in 0..COMPACTION_MIN_RECORDS {
+            let request = queued("done");
+            persistence.save_queued("agent", &request);
+            persistence.remove_queued("agent", request.id);
+        }
+        assert!(line_count(&path) < COMPACTION_MIN_RECORDS);
+
+        let mut restored: Vec<_> = FileQueuePersistence::open(&path)
+            .unwrap()
+            .load_queued()
+            .into_iter()
+            .map(|request| request.id)
+            .collect();
+        restored.sort();
+        let mut expected = vec![kept.id, later.id];
+        expected.sort();
+        assert_eq!(restored, expected);
+    }
+
+    #[test]
+    fn test_file_persistence_drops_torn_record() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("queue.jsonl");
+        let kept = queued("kept");
+        FileQueuePersistence::open(&path)
+            .unwrap()
+            .save_queued("agent", &kept);
+
+        let mut file = fs::OpenOptions::new().append(true).open(&path).unwrap();
+        file.write_all(br#"{"op":"queued","id":"#).unwrap();
+        drop(file);
+
+        let reopened = FileQueuePersistence::open(&path).unwrap();
+        assert_eq!(reopened.load_queued().len(), 1);
+        assert_eq!(line_count(&path), 1);
+
+        let next = queued("next");
+        reopened.save_queued("agent", &next);
+        assert_eq!(
+            FileQueuePersistence::open(&path)
+                .unwrap()
+                .load_queued()
+                .len(),
+            2
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_file_persistence_is_private() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("queue.jsonl");
+        let persistence = FileQueuePersistence::open(&path).unwrap();
+        persistence.save_queued("agent", &queued("secret"));
+        assert_eq!(
+            fs::metadata(&path).unwrap().permissions().mode() & 0o777,
+            0o600
+        );
+
+        persistence.compact().unwrap();
+        assert_eq!(
+            fs::metadata(&path).unwrap().permissions().mode() & 0o777,
+            0o600
+        );
+    }
+}
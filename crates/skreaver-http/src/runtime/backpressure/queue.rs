@@ -2,8 +2,10 @@
 
 use std::collections::VecDeque;
 use std::sync::{Arc, atomic::AtomicUsize};
+use std::time::{Duration, Instant};
 use tokio::sync::Semaphore;
 
+use super::config::RequestPriority;
 use super::request::{QueuedRequest, ResponseSender};
 
 /// Per-agent queue state
@@ -15,6 +17,8 @@ pub(super) struct AgentQueue {
     pub(super) total_timeouts: u64,
     pub(super) total_rejections: u64,
     pub(super) recent_processing_times: VecDeque<u64>,
+    /// Longest time a request waited in the queue, per priority
+    pub(super) max_wait_ms: [Option<u64>; RequestPriority::ALL.len()],
 }
 
 impl AgentQueue {
@@ -27,9 +31,93 @@ impl AgentQueue {
             total_timeouts: 0,
             total_rejections: 0,
             recent_processing_times: VecDeque::new(),
+            max_wait_ms: [None; RequestPriority::ALL.len()],
         }
     }
 
+    /// Insert behind every request of equal or higher priority
+    pub(super) fn insert_by_priority(
+        &mut self,
+        request: QueuedRequest,
+        tx: ResponseSender<String>,
+    ) {
+        let insert_pos = self
+            .queue
+            .iter()
+            .position(|(req, _)| req.priority < request.priority)
+            .unwrap_or(self.queue.len());
+        self.queue.insert(insert_pos, (request, tx));
+    }
+
+    /// Put back a request that was taken but could not be dispatched
+    ///
+    /// The queue is ordered by priority, oldest first, so the request returns
+    /// to the place it was taken from even if others arrived meanwhile.
+    pub(super) fn requeue(&mut self, request: QueuedRequest, tx: ResponseSender<String>) {
+        let insert_pos = self
+            .queue
+            .iter()
+            .position(|(req, _)| {
+                req.priority < request.priority
+                    || (req.priority == request.priority && req.queued_at > request.queued_at)
+            })
+            .unwrap_or(self.queue.len());
+        self.queue.insert(insert_pos, (request, tx));
+    }
+
+    /// Take the request to dispatch next
+    ///
+    /// Without aging this is the front of the queue. With aging, a request's
+    /// priority rises one level for every `aging_interval` it has waited; the
+    /// highest effective priority wins and ties go to the oldest request.
+    pub(super) fn take_next(
+        &mut self,
+        aging_interval: Option<Duration>,
+    ) -> Option<(QueuedRequest, ResponseSender<String>)> {
+        let index = match aging_interval {
+            Some(interval) => self.aged_front(interval, Instant::now())?,
+            None => 0,
+        };
+        let (request, tx) = self.queue.remove(index)?;
+        self.record_wait(&request);
+        Some((request, tx))
+    }
+
+    /// Track how long `request` waited before leaving the queue
+    pub(super) fn record_wait(&mut self, request: &QueuedRequest) {
+        let waited_ms = request.queued_at.elapsed().as_millis() as u64;
+        let max_wait = &mut self.max_wait_ms[request.priority as usize];
+        *max_wait = Some(max_wait.map_or(waited_ms, |max| max.max(waited_ms)));
+    }
+
+    /// Index of the request with the highest aged priority
+    fn aged_front(&self, interval: Duration, now: Instant) -> Option<usize> {
+        let mut best: Option<(usize, RequestPriority, Instant)> = None;
+        let mut previous = None;
+        for (index, (request, _)) in self.queue.iter().enumerate() {
+            // Within a priority level the oldest request comes first and
+            // ages the most, so only the head of each level can win
+            if previous == Some(request.priority) {
+                continue;
+            }
+            previous = Some(request.priority);
+
+            let waited = now.saturating_duration_since(request.queued_at);
+            let levels = match interval.as_nanos() {
+                0 => u64::MAX,
+                nanos => (waited.as_nanos() / nanos).min(u64::MAX as u128) as u64,
+            };
+            let effective = request.priority.raised(levels);
+            let wins = best.is_none_or(|(_, priority, queued_at)| {
+                effective > priority || (effective == priority && request.queued_at < queued_at)
+            });
+            if wins {
+                best = Some((index, effective, request.queued_at));
+            }
+        }
+        best.map(|(index, _, _)| index)
+    }
+
     pub(super) fn avg_processing_time(&self) -> f64 {
         if self.recent_processing_times.is_empty() {
             0.0
@@ -11,6 +11,7 @@
 //! - `SKREAVER_MAX_BODY_SIZE` - Maximum request body size in bytes (default: 16777216 / 16MB)
 //! - `SKREAVER_ENABLE_CORS` - Enable CORS (default: true)
 //! - `SKREAVER_ENABLE_OPENAPI` - Enable OpenAPI docs (default: true)
+//! - `SKREAVER_ENFORCE_CONTENT_TYPE` - Reject request bodies with unsupported content types (default: true)
 //! - `SKREAVER_SECURITY_CONFIG_PATH` - Path to security configuration file
 //!
 //! ### Rate Limiting
@@ -28,6 +29,13 @@
 //! - `SKREAVER_BACKPRESSURE_ENABLE_ADAPTIVE` - [DEPRECATED] Use SKREAVER_BACKPRESSURE_MODE instead
 //! - `SKREAVER_BACKPRESSURE_TARGET_PROCESSING_MS` - Target processing time in ms (default: 1000)
 //! - `SKREAVER_BACKPRESSURE_LOAD_THRESHOLD` - Load threshold 0.0-1.0 (default: 0.8)
+//! - `SKREAVER_BACKPRESSURE_AGING_INTERVAL_MS` - Queue wait after which a request's priority rises one level (default: unset, no aging)
+//! - `SKREAVER_BACKPRESSURE_PERSISTENCE_PATH` - Owner-only journal file checkpointing queued requests, inputs included, across restarts (default: unset, not kept)
+//!
+//! ### Streaming
+//! - `SKREAVER_STREAM_BUFFER_SIZE` - Updates buffered per streaming client (default: 100)
+//! - `SKREAVER_STREAM_STALL_TIMEOUT_SECS` - Abandon a stream after the client reads nothing
+//!   for this long (default: 30)
 //!
 //! ### Connection Limits
 //! - `SKREAVER_CONNECTION_LIMIT_MAX` - Global max concurrent connections (default: 10000)
@@ -38,19 +46,39 @@
 //!   - `disable_per_ip` - Only enforce global limit when ConnectInfo missing
 //!   - `fallback:<IP>` - Use fallback IP (e.g., `fallback:127.0.0.1` for testing)
 //!
+//! ### Agent Quotas
+//! - `SKREAVER_MAX_AGENTS` - Maximum number of agents, 0 = unlimited (default: 1000)
+//! - `SKREAVER_AGENT_CREATION_PER_PRINCIPAL_RPM` - Agent creations per principal per minute,
+//!   0 = unlimited (default: 30)
+//! - `SKREAVER_AGENT_IDLE_TIMEOUT_SECS` - Evict agents idle for this long, 0 = never
+//!   (default: 0)
+//! - `SKREAVER_AGENT_EVICTION_SWEEP_SECS` - Interval between idle agent sweeps (default: 60)
+//!
 //! ### Observability
 //! - `SKREAVER_OBSERVABILITY_ENABLE_METRICS` - Enable Prometheus metrics (default: true)
 //! - `SKREAVER_OBSERVABILITY_ENABLE_TRACING` - Enable OpenTelemetry tracing (default: false)
 //! - `SKREAVER_OBSERVABILITY_ENABLE_HEALTH` - Enable health checks (default: true)
 //! - `SKREAVER_OBSERVABILITY_OTEL_ENDPOINT` - OTLP endpoint for traces
 //! - `SKREAVER_OBSERVABILITY_NAMESPACE` - Metrics namespace prefix (default: "skreaver")
+//! - `SKREAVER_LOG_FORMAT` - Log output format: "json", "logfmt" or "pretty" (default: json)
 
 use crate::runtime::{
-    HttpRuntimeConfig, backpressure::BackpressureConfig, connection_limits::ConnectionLimitConfig,
-    rate_limit::RateLimitConfig,
+    HttpRuntimeConfig,
+    agent_eviction::AgentEvictionConfig,
+    agent_quota::AgentQuotaConfig,
+    approval::ApprovalGate,
+    backpressure::{BackpressureConfig, FileQueuePersistence, QueuePersistence},
+    connection_limits::ConnectionLimitConfig,
+    content_type::ContentTypeConfig,
+    handlers::DlqAdmin,
+    rate_limit::{RateLimitConfig, RouteRateLimits},
+    streaming::StreamBackpressureConfig,
+    tool_limits::ToolConcurrencyLimiter,
+    usage::UsageSink,
 };
-use skreaver_observability::{ObservabilityConfig, ObservabilityMode};
-use std::{env, num::NonZeroU64, path::PathBuf, time::Duration};
+use skreaver_core::InMemoryMemory;
+use skreaver_observability::{ObservabilityConfig, ObservabilityError, ObservabilityMode};
+use std::{env, num::NonZeroU64, path::PathBuf, sync::Arc, time::Duration};
 
 /// Error type for configuration loading
 #[derive(Debug, thiserror::Error)]
@@ -187,28 +215,50 @@ impl std::fmt::Display for MaxBodySize {
 #[derive(Debug, Clone)]
 pub struct HttpRuntimeConfigBuilder {
     rate_limit: RateLimitConfig,
+    route_rate_limits: Option<RouteRateLimits>,
     backpressure: BackpressureConfig,
+    stream_backpressure: StreamBackpressureConfig,
     connection_limits: ConnectionLimitConfig,
+    agent_quota: AgentQuotaConfig,
+    agent_eviction: AgentEvictionConfig,
     request_timeout: RequestTimeout,
     max_body_size: MaxBodySize,
     cors: Option<crate::runtime::http::CorsConfig>,
+    content_types: Option<ContentTypeConfig>,
     openapi: Option<crate::runtime::http::OpenApiConfig>,
     observability: ObservabilityConfig,
     security_config_path: Option<PathBuf>,
+    usage_sink: Option<Arc<dyn UsageSink>>,
+    dead_letters: Option<DlqAdmin>,
+    approval_gate: Option<ApprovalGate>,
+    tool_limiter: Option<ToolConcurrencyLimiter>,
+    shared_memory: Option<InMemoryMemory>,
+    queue_persistence: Option<Arc<dyn QueuePersistence>>,
 }
 
 impl Default for HttpRuntimeConfigBuilder {
     fn default() -> Self {
         Self {
             rate_limit: RateLimitConfig::default(),
+            route_rate_limits: None,
             backpressure: BackpressureConfig::default(),
+            stream_backpressure: StreamBackpressureConfig::default(),
             connection_limits: ConnectionLimitConfig::default(),
+            agent_quota: AgentQuotaConfig::default(),
+            agent_eviction: AgentEvictionConfig::default(),
             request_timeout: RequestTimeout::default(),
             max_body_size: MaxBodySize::default(),
             cors: Some(crate::runtime::http::CorsConfig::default()),
+            content_types: Some(ContentTypeConfig::default()),
             openapi: Some(crate::runtime::http::OpenApiConfig::default()),
             observability: ObservabilityConfig::default(),
             security_config_path: None,
+            usage_sink: None,
+            dead_letters: None,
+            approval_gate: None,
+            tool_limiter: None,
+            shared_memory: None,
+            queue_persistence: None,
         }
     }
 }
@@ -243,6 +293,9 @@ impl HttpRuntimeConfigBuilder {
                 None
             });
         }
+        if let Some(enforce) = get_env_bool("SKREAVER_ENFORCE_CONTENT_TYPE")? {
+            builder = builder.content_types(enforce.then(ContentTypeConfig::default));
+        }
         if let Some(openapi) = get_env_bool("SKREAVER_ENABLE_OPENAPI")? {
             builder = builder.openapi(if openapi {
                 Some(crate::runtime::http::OpenApiConfig::default())
@@ -250,6 +303,16 @@ impl HttpRuntimeConfigBuilder {
                 None
             });
         }
+        if let Some(mut openapi) = builder.openapi.take() {
+            if let Some(rpm) = get_env_u32("SKREAVER_OPENAPI_RATE_LIMIT_RPM")? {
+                // 0 disables the docs rate limit
+                openapi.rate_limit_rpm = std::num::NonZeroU32::new(rpm);
+            }
+            if let Some(require_auth) = get_env_bool("SKREAVER_OPENAPI_REQUIRE_AUTH")? {
+                openapi.require_auth = require_auth;
+            }
+            builder.openapi = Some(openapi);
+        }
         if let Some(path) = get_env_string("SKREAVER_SECURITY_CONFIG_PATH") {
             builder = builder.security_config_path(PathBuf::from(path));
         }
@@ -313,7 +376,40 @@ impl HttpRuntimeConfigBuilder {
             backpressure.load_threshold =
                 crate::runtime::backpressure::LoadThreshold::new(threshold)?;
         }
+        if let Some(aging_ms) = get_env_u64("SKREAVER_BACKPRESSURE_AGING_INTERVAL_MS")? {
+            backpressure.aging_interval = Some(Duration::from_millis(aging_ms));
+        }
+        if let Some(pools) = get_env_string("SKREAVER_BACKPRESSURE_BULKHEADS") {
+            backpressure.bulkheads.pools =
+                crate::runtime::backpressure::BulkheadConfig::parse_pools(&pools)?;
+        }
+        if let Some(assignments) = get_env_string("SKREAVER_BACKPRESSURE_BULKHEAD_ASSIGNMENTS") {
+            backpressure.bulkheads.assignments =
+                crate::runtime::backpressure::BulkheadConfig::parse_assignments(&assignments)?;
+        }
         builder = builder.backpressure(backpressure);
+        if let Some(path) = get_env_string("SKREAVER_BACKPRESSURE_PERSISTENCE_PATH") {
+            let persistence =
+                FileQueuePersistence::open(&path).map_err(|e| ConfigError::InvalidEnvVar {
+                    key: "SKREAVER_BACKPRESSURE_PERSISTENCE_PATH".to_string(),
+                    message: format!("cannot open queue checkpoints at '{}': {}", path, e),
+                })?;
+            builder = builder.queue_persistence(Some(Arc::new(persistence)));
+        }
+
+        // Streaming
+        let mut stream_backpressure = StreamBackpressureConfig::default();
+        if let Some(size) = get_env_usize("SKREAVER_STREAM_BUFFER_SIZE")? {
+            stream_backpressure.buffer_size =
+                std::num::NonZeroUsize::new(size).ok_or_else(|| ConfigError::InvalidEnvVar {
+                    key: "SKREAVER_STREAM_BUFFER_SIZE".to_string(),
+                    message: "must be at least 1".to_string(),
+                })?;
+        }
+        if let Some(timeout) = get_env_u64("SKREAVER_STREAM_STALL_TIMEOUT_SECS")? {
+            stream_backpressure.stall_timeout = Duration::from_secs(timeout);
+        }
+        builder = builder.stream_backpressure(stream_backpressure);
 
         // Connection Limits
         let mut connection_limits = ConnectionLimitConfig::default();
@@ -362,6 +458,25 @@ impl HttpRuntimeConfigBuilder {
 
         builder = builder.connection_limits(connection_limits);
 
+        // Agent Quotas (0 disables a limit)
+        let mut agent_quota = AgentQuotaConfig::default();
+        if let Some(max) = get_env_usize("SKREAVER_MAX_AGENTS")? {
+            agent_quota.max_agents = (max > 0).then_some(max);
+        }
+        if let Some(rpm) = get_env_u32("SKREAVER_AGENT_CREATION_PER_PRINCIPAL_RPM")? {
+            agent_quota.creations_per_minute = std::num::NonZeroU32::new(rpm);
+        }
+        builder = builder.agent_quota(agent_quota);
+
+        let mut agent_eviction = AgentEvictionConfig::default();
+        if let Some(secs) = get_env_u64("SKREAVER_AGENT_IDLE_TIMEOUT_SECS")? {
+            agent_eviction.idle_timeout = (secs > 0).then(|| Duration::from_secs(secs));
+        }
+        if let Some(secs) = get_env_u64("SKREAVER_AGENT_EVICTION_SWEEP_SECS")? {
+            agent_eviction.sweep_interval = Duration::from_secs(secs.max(1));
+        }
+        builder = builder.agent_eviction(agent_eviction);
+
         // Observability
         let mut observability = ObservabilityConfig::default();
 
@@ -394,6 +509,15 @@ impl HttpRuntimeConfigBuilder {
         if let Some(namespace) = get_env_string("SKREAVER_OBSERVABILITY_NAMESPACE") {
             observability.namespace = namespace;
         }
+        if let Some(format) = get_env_string("SKREAVER_LOG_FORMAT") {
+            observability.log_format =
+                format
+                    .parse()
+                    .map_err(|e: ObservabilityError| ConfigError::InvalidEnvVar {
+                        key: "SKREAVER_LOG_FORMAT".to_string(),
+                        message: e.to_string(),
+                    })?;
+        }
         builder = builder.observability(observability);
 
         Ok(builder)
@@ -406,6 +530,13 @@ impl HttpRuntimeConfigBuilder {
         self
     }
 
+    /// Set separate budgets per route group (None = not throttled by route group)
+    #[must_use]
+    pub fn route_rate_limits(mut self, route_rate_limits: Option<RouteRateLimits>) -> Self {
+        self.route_rate_limits = route_rate_limits;
+        self
+    }
+
     /// Set backpressure configuration
     #[must_use]
     pub fn backpressure(mut self, backpressure: BackpressureConfig) -> Self {
@@ -413,6 +544,13 @@ impl HttpRuntimeConfigBuilder {
         self
     }
 
+    /// Set buffering and stall timeout for streamed agent updates
+    #[must_use]
+    pub fn stream_backpressure(mut self, stream_backpressure: StreamBackpressureConfig) -> Self {
+        self.stream_backpressure = stream_backpressure;
+        self
+    }
+
     /// Set connection limits configuration
     #[must_use]
     pub fn connection_limits(mut self, connection_limits: ConnectionLimitConfig) -> Self {
@@ -420,6 +558,20 @@ impl HttpRuntimeConfigBuilder {
         self
     }
 
+    /// Set agent count and creation rate limits
+    #[must_use]
+    pub fn agent_quota(mut self, agent_quota: AgentQuotaConfig) -> Self {
+        self.agent_quota = agent_quota;
+        self
+    }
+
+    /// Set idle agent eviction
+    #[must_use]
+    pub fn agent_eviction(mut self, agent_eviction: AgentEvictionConfig) -> Self {
+        self.agent_eviction = agent_eviction;
+        self
+    }
+
     /// Set request timeout using validated type
     #[must_use]
     pub fn request_timeout(mut self, timeout: RequestTimeout) -> Self {
@@ -471,6 +623,13 @@ impl HttpRuntimeConfigBuilder {
         self
     }
 
+    /// Set accepted body content types per route (None = not enforced)
+    #[must_use]
+    pub fn content_types(mut self, content_types: Option<ContentTypeConfig>) -> Self {
+        self.content_types = content_types;
+        self
+    }
+
     /// Set OpenAPI configuration (None = disabled, Some = enabled)
     #[must_use]
     pub fn openapi(mut self, openapi: Option<crate::runtime::http::OpenApiConfig>) -> Self {
@@ -492,6 +651,48 @@ impl HttpRuntimeConfigBuilder {
         self
     }
 
+    /// Set the sink for per-principal usage accounting (None = not recorded)
+    #[must_use]
+    pub fn usage_sink(mut self, usage_sink: Option<Arc<dyn UsageSink>>) -> Self {
+        self.usage_sink = usage_sink;
+        self
+    }
+
+    /// Set the mesh dead letter queue exposed on the admin routes (None = not mounted)
+    #[must_use]
+    pub fn dead_letters(mut self, dead_letters: Option<DlqAdmin>) -> Self {
+        self.dead_letters = dead_letters;
+        self
+    }
+
+    /// Hold agents' tool calls for approval at `gate` (None = calls run unapproved)
+    #[must_use]
+    pub fn approval_gate(mut self, gate: Option<ApprovalGate>) -> Self {
+        self.approval_gate = gate;
+        self
+    }
+
+    /// Set the per-tool concurrency limits shared by every agent (None = unlimited)
+    #[must_use]
+    pub fn tool_limiter(mut self, limiter: Option<ToolConcurrencyLimiter>) -> Self {
+        self.tool_limiter = limiter;
+        self
+    }
+
+    /// Keep the built-in agents' memory in `backend`, namespaced per agent (None = private memory)
+    #[must_use]
+    pub fn shared_memory(mut self, backend: Option<InMemoryMemory>) -> Self {
+        self.shared_memory = backend;
+        self
+    }
+
+    /// Checkpoint queued requests in `persistence` so they survive a restart (None = not kept)
+    #[must_use]
+    pub fn queue_persistence(mut self, persistence: Option<Arc<dyn QueuePersistence>>) -> Self {
+        self.queue_persistence = persistence;
+        self
+    }
+
     /// Build `HttpRuntimeConfig`
     ///
     /// This method is infallible because all validated values use newtypes
@@ -507,14 +708,25 @@ impl HttpRuntimeConfigBuilder {
 
         Ok(HttpRuntimeConfig {
             rate_limit: self.rate_limit,
+            route_rate_limits: self.route_rate_limits,
             backpressure: self.backpressure,
+            stream_backpressure: self.stream_backpressure,
             connection_limits: self.connection_limits,
+            agent_quota: self.agent_quota,
+            agent_eviction: self.agent_eviction,
             request_timeout: self.request_timeout,
             max_body_size: self.max_body_size,
             cors: self.cors,
+            content_types: self.content_types,
             openapi: self.openapi,
             observability: self.observability,
             security_config_path: self.security_config_path,
+            usage_sink: self.usage_sink,
+            dead_letters: self.dead_letters,
+            approval_gate: self.approval_gate,
+            tool_limiter: self.tool_limiter,
+            shared_memory: self.shared_memory,
+            queue_persistence: self.queue_persistence,
         })
     }
 
@@ -534,6 +746,8 @@ impl HttpRuntimeConfigBuilder {
 
         // Backpressure validation - ELIMINATED
         // Now validated at construction time via QueueSize, ConcurrencyLimit, and LoadThreshold newtypes
+        // Bulkhead assignments must refer to configured pools
+        self.backpressure.bulkheads.validate()?;
 
         // Observability validation
         if self.observability.namespace.is_empty() {
@@ -0,0 +1,201 @@
+//! Request body content-type enforcement
+//!
+//! Routes that parse a body declare the media types they accept. A request
+//! with a body whose `Content-Type` is missing or not accepted is rejected
+//! with `415 Unsupported Media Type` before the handler runs, so clients get
+//! a clear error instead of a parse failure. Requests without a body are left
+//! to the handler, which keeps optional bodies working.
+
+use axum::{
+    body::{Body, HttpBody},
+    extract::{MatchedPath, State},
+    http::{Request, StatusCode, header},
+    middleware::Next,
+    response::{IntoResponse, Json, Response},
+};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::runtime::{
+    api_version::{is_vendor_json, strip_version_prefix},
+    types::ErrorResponse,
+};
+
+/// JSON media type
+pub const APPLICATION_JSON: &str = "application/json";
+
+/// Routes that accept a JSON body in the default configuration
+const DEFAULT_JSON_ROUTES: &[&str] = &[
+    "/agents",
+    "/agents/{agent_id}/observe",
+    "/agents/{agent_id}/observe/stream",
+    "/agents/{agent_id}/batch",
+    "/agents/{agent_id}/circuit/{action}",
+    "/agents/{agent_id}/log-level",
+    "/tools/{tool_name}/invoke",
+    "/auth/token",
+];
+
+/// Accepted request body content types per route
+///
+/// Routes are identified by their pattern as registered with the router,
+/// e.g. `/agents/{agent_id}/observe`. Accepted types may be exact media types
+/// (`application/json`) or a `type/*` wildcard. Routes without an entry
+/// accept any content type. Versioned routes (`/v2/agents`) use the entry of
+/// the unprefixed route, and versioned Skreaver media types
+/// (`application/vnd.skreaver.v2+json`) count as `application/json`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContentTypeConfig {
+    routes: HashMap<String, Vec<String>>,
+}
+
+impl ContentTypeConfig {
+    /// Configuration without any route restrictions
+    pub fn empty() -> Self {
+        Self {
+            routes: HashMap::new(),
+        }
+    }
+
+    /// Set the accepted content types for `route`, replacing any previous entry
+    pub fn with_route<I, S>(mut self, route: impl Into<String>, accepted: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let accepted = accepted
+            .into_iter()
+            .map(|media_type| media_type.into().to_ascii_lowercase())
+            .collect();
+        self.routes.insert(route.into(), accepted);
+        self
+    }
+
+    /// Stop enforcing content types on `route`
+    pub fn without_route(mut self, route: &str) -> Self {
+        self.routes.remove(route);
+        self
+    }
+
+    /// Accepted content types for `route`, if it is restricted
+    pub fn accepted(&self, route: &str) -> Option<&[String]> {
+        self.routes
+            .get(strip_version_prefix(route))
+            .map(Vec::as_slice)
+    }
+
+    /// Check whether `content_type` is accepted on `route`
+    pub fn allows(&self, route: &str, content_type: Option<&str>) -> bool {
+        let Some(accepted) = self.accepted(route) else {
+            return true;
+        };
+        let Some(mut essence) = content_type.map(media_type_essence) else {
+            return false;
+        };
+        if is_vendor_json(&essence) {
+            essence = APPLICATION_JSON.to_string();
+        }
+        accepted
+            .iter()
+            .any(|allowed| match allowed.strip_suffix("/*") {
+                Some(top_level) => essence
+                    .split_once('/')
+                    .is_some_and(|(ty, _)| ty == top_level),
+                None => *allowed == essence,
+            })
+    }
+}
+
+impl Default for ContentTypeConfig {
+    /// Require `application/json` on every route that parses a JSON body
+    fn default() -> Self {
+        DEFAULT_JSON_ROUTES
+            .iter()
+            .fold(Self::empty(), |config, route| {
+                config.with_route(*route, [APPLICATION_JSON])
+            })
+    }
+}
+
+/// Media type without parameters, lowercased (`Application/JSON; charset=utf-8` -> `application/json`)
+fn media_type_essence(content_type: &str) -> String {
+    content_type
+        .split(';')
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .to_ascii_lowercase()
+}
+
+/// Content-type enforcement middleware
+///
+/// Must be added with `Router::layer` so the matched route is known.
+pub async fn content_type_middleware(
+    State(config): State<Arc<ContentTypeConfig>>,
+    request: Request<Body>,
+    next: Next,
+) -> Response {
+    let Some(route) = request.extensions().get::<MatchedPath>() else {
+        return next.run(request).await;
+    };
+    if request.body().size_hint().exact() == Some(0) {
+        return next.run(request).await;
+    }
+
+    let content_type = request
+        .headers()
+        .get(header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok());
+    if config.allows(route.as_str(), content_type) {
+        return next.run(request).await;
+    }
+
+    let accepted = config.accepted(route.as_str()).unwrap_or_default();
+    tracing::debug!(
+        route = %route.as_str(),
+        content_type = ?content_type,
+        "Rejected request with unsupported content type"
+    );
+    (
+        StatusCode::UNSUPPORTED_MEDIA_TYPE,
+        Json(ErrorResponse {
+            error: "unsupported_media_type".to_string(),
+            message: match content_type {
+                Some(content_type) => format!(
+                    "Content type '{}' is not supported, expected {}",
+                    content_type,
+                    accepted.join(" or ")
+                ),
+                None => format!(
+                    "Missing Content-Type header, expected {}",
+                    accepted.join(" or ")
+                ),
+            },
+            details: Some(serde_json::json!({ "accepted": accepted })),
+        }),
+    )
+        .into_response()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_allows_matches_essence_and_wildcards() {
+        let config = ContentTypeConfig::empty()
+            .with_route("/json", [APPLICATION_JSON])
+            .with_route("/text", ["text/*"]);
+
+        assert!(config.allows("/json", Some("application/json")));
+        assert!(config.allows("/json", Some("Application/JSON; charset=utf-8")));
+        assert!(!config.allows("/json", Some("text/plain")));
+        assert!(!config.allows("/json", None));
+
+        assert!(config.allows("/text", Some("text/csv")));
+        assert!(!config.allows("/text", Some("application/json")));
+
+        assert!(config.allows("/unrestricted", Some("text/plain")));
+        assert!(config.without_route("/json").allows("/json", None));
+    }
+}
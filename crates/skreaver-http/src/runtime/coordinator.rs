@@ -1,6 +1,392 @@
-use skreaver_core::{Agent, ExecutionResult, MemoryUpdate, ToolCall};
+use crate::runtime::agent_error::{AgentBuildError, ConfigExt};
+use crate::runtime::agent_factory::BuildContext;
+use crate::runtime::approval::{ApprovalDecision, ApprovalGate};
+use crate::runtime::backpressure::RequestPriority;
+use crate::runtime::tool_budget::ToolTimeBudget;
+use crate::runtime::tool_limits::{ToolConcurrencyLimiter, ToolPermit};
+use serde_json::Value;
+use skreaver_core::error::{MemoryBackend, MemoryError, MemoryOperation, ToolError};
+use skreaver_core::memory::ReadOnlyWrites;
+use skreaver_core::normalization::{NormalizationPipeline, Normalize};
+use skreaver_core::{Agent, AgentId, ExecutionResult, InMemoryMemory, MemoryUpdate, ToolCall};
+use skreaver_memory::NamespacedMemory;
+use skreaver_observability::{InFlightGuard, StepOutcome, get_metrics_registry};
 use skreaver_tools::ToolRegistry;
+use std::any::Any;
+use std::collections::{HashMap, VecDeque};
 use std::fmt::Display;
+use std::future::Future;
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::time::{Duration, Instant};
+
+/// Track a tool call on the global in-flight gauge, if metrics are initialized.
+fn track_tool_call() -> Option<InFlightGuard> {
+    get_metrics_registry().map(|registry| registry.track_tool_call())
+}
+
+/// Tool limiter slot and in-flight gauge entry held for the duration of a tool call
+type ToolCallGuards = (Option<ToolPermit>, Option<InFlightGuard>);
+
+/// Dispatcher enforcing a deadline on a tool call, see [`dispatch_with_deadline`]
+type DeadlineDispatch<R> =
+    fn(&R, &ToolCall, Duration, ToolCallGuards) -> Option<Option<ExecutionResult>>;
+
+/// Run `tool_call` on a helper thread, giving up on it after `timeout`.
+///
+/// Returns `None` if the call did not finish in time. Synchronous tools cannot
+/// be interrupted, so the call keeps running on the helper thread and holds
+/// `guards` until it returns.
+fn dispatch_with_deadline<R>(
+    registry: &R,
+    tool_call: &ToolCall,
+    timeout: Duration,
+    guards: ToolCallGuards,
+) -> Option<Option<ExecutionResult>>
+where
+    R: ToolRegistry + Clone + Send + 'static,
+{
+    let registry = registry.clone();
+    let tool_call = tool_call.clone();
+    let (sender, receiver) = std::sync::mpsc::channel();
+    let worker = std::thread::spawn(move || {
+        let _guards = guards;
+        // The coordinator may have stopped waiting for the result
+        let _ = sender.send(registry.dispatch(tool_call));
+    });
+
+    match receiver.recv_timeout(timeout) {
+        Ok(result) => Some(result),
+        Err(std::sync::mpsc::RecvTimeoutError::Timeout) => None,
+        Err(std::sync::mpsc::RecvTimeoutError::Disconnected) => {
+            // The tool panicked; surface the panic as a direct call would
+            match worker.join() {
+                Err(panic) => std::panic::resume_unwind(panic),
+                Ok(()) => unreachable!("tool worker exited without sending a result"),
+            }
+        }
+    }
+}
+
+/// Wait for `future` from synchronous step code.
+///
+/// Only the calling thread is parked. On a worker of a multi-threaded runtime
+/// its other tasks are handed off first; anywhere else the future runs on a
+/// helper thread with its own timer, since a current-thread runtime cannot
+/// drive timers while its only thread is blocked.
+fn block_on<F>(future: F) -> F::Output
+where
+    F: Future + Send,
+    F::Output: Send,
+{
+    use tokio::runtime::{Handle, RuntimeFlavor};
+
+    match Handle::try_current() {
+        Ok(handle) if handle.runtime_flavor() == RuntimeFlavor::MultiThread => {
+            tokio::task::block_in_place(|| handle.block_on(future))
+        }
+        _ => std::thread::scope(|scope| {
+            scope
+                .spawn(|| {
+                    tokio::runtime::Builder::new_current_thread()
+                        .enable_time()
+                        .build()
+                        .expect("failed to build a runtime for a blocking wait")
+                        .block_on(future)
+                })
+                .join()
+                .unwrap_or_else(|panic| std::panic::resume_unwind(panic))
+        }),
+    }
+}
+
+/// Policy applied by the coordinator when a tool call fails during a step.
+///
+/// The failed result is always passed to the agent's `handle_result` before
+/// the strategy is applied, so agents can still inspect every failure.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ErrorStrategy {
+    /// Stop dispatching the remaining tool calls of the step
+    AbortStep,
+    /// Ignore the failure and keep dispatching the remaining tool calls
+    #[default]
+    SkipFailedTool,
+    /// Re-run the step's tool calls, up to `max_retries` additional times
+    RetryStep {
+        /// Maximum number of times the tool calls are re-run
+        max_retries: u32,
+    },
+}
+
+impl ErrorStrategy {
+    /// Agent config key selecting the strategy: `abort`, `skip` or `retry`
+    pub const CONFIG_KEY: &'static str = "error_strategy";
+    /// Agent config key holding the retry limit for the `retry` strategy
+    pub const MAX_RETRIES_KEY: &'static str = "max_step_retries";
+    /// Retry limit used when `max_step_retries` is not configured
+    pub const DEFAULT_MAX_RETRIES: u32 = 3;
+    /// Upper bound for `max_step_retries`
+    pub const MAX_RETRIES_LIMIT: u32 = 10;
+
+    /// Read the strategy from an agent configuration map.
+    ///
+    /// Defaults to [`ErrorStrategy::SkipFailedTool`] when no strategy is set.
+    pub fn from_config(config: &HashMap<String, Value>) -> Result<Self, AgentBuildError> {
+        if !config.contains_key(Self::CONFIG_KEY) {
+            return Ok(Self::default());
+        }
+
+        match config.get_string(Self::CONFIG_KEY)?.as_str() {
+            "abort" => Ok(Self::AbortStep),
+            "skip" => Ok(Self::SkipFailedTool),
+            "retry" => {
+                let max_retries =
+                    config.get_i64_or(Self::MAX_RETRIES_KEY, i64::from(Self::DEFAULT_MAX_RETRIES));
+                match u32::try_from(max_retries) {
+                    Ok(max_retries) if max_retries <= Self::MAX_RETRIES_LIMIT => {
+                        Ok(Self::RetryStep { max_retries })
+                    }
+                    _ => Err(AgentBuildError::invalid_value(
+                        Self::MAX_RETRIES_KEY,
+                        max_retries,
+                        format!("must be between 0 and {}", Self::MAX_RETRIES_LIMIT),
+                    )),
+                }
+            }
+            other => Err(AgentBuildError::invalid_value(
+                Self::CONFIG_KEY,
+                other,
+                "must be one of: abort, skip, retry",
+            )),
+        }
+    }
+}
+
+/// Agent config key enabling observation normalization (`true`/`false`)
+pub const NORMALIZE_OBSERVATIONS_KEY: &str = "normalize_observations";
+/// Agent config key adding lowercasing to observation normalization
+pub const LOWERCASE_OBSERVATIONS_KEY: &str = "lowercase_observations";
+
+/// Read the observation normalization pipeline from an agent configuration map.
+///
+/// Normalization is opt-in: returns `None` unless `normalize_observations` is
+/// set to `true`. The pipeline trims, applies Unicode NFC and converts line
+/// endings to LF; `lowercase_observations` additionally lowercases.
+pub fn normalization_from_config(config: &HashMap<String, Value>) -> Option<NormalizationPipeline> {
+    config
+        .get_bool_or(NORMALIZE_OBSERVATIONS_KEY, false)
+        .then(|| {
+            NormalizationPipeline::new()
+                .with_lowercase(config.get_bool_or(LOWERCASE_OBSERVATIONS_KEY, false))
+        })
+}
+
+/// Reason a step ended before the agent could act normally.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StepError {
+    /// A tool failed under [`ErrorStrategy::AbortStep`]
+    Aborted {
+        /// Name of the failed tool
+        tool_name: String,
+        /// Failure message reported by the tool
+        reason: String,
+    },
+    /// Tools kept failing after every retry under [`ErrorStrategy::RetryStep`]
+    RetriesExhausted {
+        /// Name of the tool that failed on the last attempt
+        tool_name: String,
+        /// Total number of attempts made
+        attempts: u32,
+    },
+    /// No approval decision arrived for a tool that requires one
+    ApprovalTimedOut {
+        /// Name of the tool waiting for approval
+        tool_name: String,
+        /// How long the step waited
+        timeout: Duration,
+    },
+    /// The step's tool calls used up its [`ToolTimeBudget`]
+    ToolBudgetExhausted {
+        /// Name of the tool that overran or could not start
+        tool_name: String,
+        /// Total tool time allowed per step
+        budget: Duration,
+    },
+}
+
+impl std::fmt::Display for StepError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Aborted { tool_name, reason } => {
+                write!(
+                    f,
+                    "Step aborted after tool '{}' failed: {}",
+                    tool_name, reason
+                )
+            }
+            Self::RetriesExhausted {
+                tool_name,
+                attempts,
+            } => write!(
+                f,
+                "Tool '{}' still failing after {} attempts",
+                tool_name, attempts
+            ),
+            Self::ApprovalTimedOut { tool_name, timeout } => write!(
+                f,
+                "No approval decision for tool '{}' within {:?}",
+                tool_name, timeout
+            ),
+            Self::ToolBudgetExhausted { tool_name, budget } => write!(
+                f,
+                "Tool time budget of {:?} exhausted at tool '{}'",
+                budget, tool_name
+            ),
+        }
+    }
+}
+
+impl std::error::Error for StepError {}
+
+/// A tool failure observed while dispatching a step's tool calls
+struct ToolFailure {
+    tool_name: String,
+    reason: String,
+}
+
+/// Normalization applied to observations before the agent sees them.
+///
+/// The pipeline works on strings; `apply` adapts it to the agent's
+/// observation type and is captured when normalization is enabled.
+struct ObservationNormalization<O> {
+    pipeline: NormalizationPipeline,
+    apply: fn(O, &NormalizationPipeline) -> O,
+}
+
+/// Recent step results, used to answer duplicate observations.
+///
+/// Each coordinator owns its cache, so results never leak between agents.
+/// Actions are stored type-erased so the cache does not change the
+/// coordinator's `Send`/`Sync` bounds.
+struct DedupCache {
+    window: Duration,
+    max_entries: usize,
+    entries: HashMap<String, Box<dyn Any + Send + Sync>>,
+    /// Keys in insertion order, with the time they were inserted
+    order: VecDeque<(Instant, String)>,
+}
+
+impl DedupCache {
+    fn new(window: Duration, max_entries: usize) -> Self {
+        Self {
+            window,
+            max_entries,
+            entries: HashMap::new(),
+            order: VecDeque::new(),
+        }
+    }
+
+    fn get<T: Clone + 'static>(&mut self, key: &str) -> Option<T> {
+        self.evict_expired();
+        self.entries.get(key)?.downcast_ref::<T>().cloned()
+    }
+
+    fn insert<T: Send + Sync + 'static>(&mut self, key: String, value: T) {
+        self.evict_expired();
+        if self.max_entries == 0 {
+            return;
+        }
+        while self.entries.len() >= self.max_entries {
+            let Some((_, oldest)) = self.order.pop_front() else {
+                break;
+            };
+            self.entries.remove(&oldest);
+        }
+        self.order.push_back((Instant::now(), key.clone()));
+        self.entries.insert(key, Box::new(value));
+    }
+
+    fn evict_expired(&mut self) {
+        while let Some((inserted_at, _)) = self.order.front()
+            && inserted_at.elapsed() >= self.window
+        {
+            if let Some((_, key)) = self.order.pop_front() {
+                self.entries.remove(&key);
+            }
+        }
+    }
+}
+
+/// Agents that can produce an isolated scratch copy of themselves.
+///
+/// Used by [`Coordinator::plan`] to preview tool calls without touching the
+/// real agent. The copy must not share mutable memory with the original:
+/// anything the copy writes while observing must be invisible to `self`.
+/// For [`InMemoryMemory`](skreaver_core::InMemoryMemory) this means using
+/// `fork()` rather than `clone()`; when the backend is shared between agents,
+/// fork only the agent's own namespace so each plan does not copy everyone
+/// else's data.
+pub trait ScratchAgent: Agent + Sized {
+    /// Create a copy of the agent whose state and memory writes are discarded
+    /// with it.
+    fn scratch(&self) -> Self;
+}
+
+/// How a coordinator scopes a memory backend shared between agents.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum MemoryIsolation {
+    /// Keys are namespaced by agent id, so agents cannot read or list each
+    /// other's keys
+    #[default]
+    PerAgent,
+    /// Opt out of isolation: every agent using the same group name reads and
+    /// writes the same keys
+    Shared(String),
+}
+
+impl MemoryIsolation {
+    /// Agent config key naming a memory group to share instead of isolating
+    pub const CONFIG_KEY: &'static str = "memory_group";
+
+    /// Read the isolation from an agent configuration map.
+    ///
+    /// Defaults to [`MemoryIsolation::PerAgent`] when no group is set.
+    pub fn from_config(config: &HashMap<String, Value>) -> Result<Self, AgentBuildError> {
+        if !config.contains_key(Self::CONFIG_KEY) {
+            return Ok(Self::default());
+        }
+
+        let group = config.get_string(Self::CONFIG_KEY)?;
+        if group.trim().is_empty() {
+            return Err(AgentBuildError::invalid_value(
+                Self::CONFIG_KEY,
+                group,
+                "must not be empty",
+            ));
+        }
+        Ok(Self::Shared(group))
+    }
+
+    /// Namespace the agent's keys are stored under
+    pub fn namespace(&self, agent_id: &AgentId) -> String {
+        match self {
+            Self::PerAgent => format!("agent:{}", agent_id),
+            Self::Shared(group) => format!("shared:{}", group),
+        }
+    }
+}
+
+/// Agents that keep their memory in a backend shared with other agents.
+///
+/// Used by [`Coordinator::with_shared_memory`], which hands the agent a view
+/// of the backend namespaced according to a [`MemoryIsolation`]. The agent
+/// should read, write and scan only through that view.
+pub trait SharedMemoryAgent: Agent {
+    /// The shared backend, typically a cheap handle to a connection pool
+    type Backend;
+
+    /// Replace the agent's memory with `memory`.
+    fn attach_memory(&mut self, memory: NamespacedMemory<Self::Backend>);
+}
 
 /// Central runtime coordinator for agent execution.
 ///
@@ -59,12 +445,61 @@ where
     /// This field is public to allow direct registry operations when needed,
     /// though tool dispatch should typically use coordinator methods.
     pub registry: R,
+
+    /// Policy applied when a tool call fails during [`step`](Self::step).
+    error_strategy: ErrorStrategy,
+
+    /// Recent results for [`step_deduplicated`](Self::step_deduplicated),
+    /// present only when a dedup window is configured.
+    dedup: Option<DedupCache>,
+
+    /// Observation normalization, present only when enabled with
+    /// [`with_normalization`](Self::with_normalization).
+    normalization: Option<ObservationNormalization<A::Observation>>,
+
+    /// Raw form of the most recent observation, kept for audit when
+    /// normalization is enabled.
+    last_raw_observation: Option<String>,
+
+    /// Tool calls dispatched by [`step`](Self::step) over the coordinator's lifetime.
+    tool_invocations: u64,
+
+    /// Tools that wait for an external decision before running, present
+    /// only when configured with [`with_approval_gate`](Self::with_approval_gate).
+    approval: Option<ApprovalGate>,
+
+    /// Handling of context updates, present only when the coordinator was
+    /// made read-only with [`with_read_only`](Self::with_read_only).
+    read_only: Option<ReadOnlyWrites>,
+
+    /// Per-tool concurrency limits, present only when configured with
+    /// [`with_tool_limiter`](Self::with_tool_limiter).
+    tool_limiter: Option<ToolConcurrencyLimiter>,
+
+    /// Priority of the request being served, used when tool calls wait for
+    /// a slot in the tool limiter.
+    priority: RequestPriority,
+
+    /// Cap on the total time a step spends in tools, present only when
+    /// configured with [`with_tool_budget`](Self::with_tool_budget).
+    tool_budget: Option<ToolTimeBudget>,
+
+    /// Dispatcher that stops waiting for a tool call at its budgeted
+    /// deadline, set together with `tool_budget`.
+    deadline_dispatch: Option<DeadlineDispatch<R>>,
+
+    /// Namespace of the agent's shared memory, present only when configured
+    /// with [`with_shared_memory`](Self::with_shared_memory).
+    memory_namespace: Option<String>,
 }
 
 impl<A: Agent, R: ToolRegistry> Coordinator<A, R>
 where
     A::Observation: Display,
 {
+    /// Results kept by [`with_dedup_window`](Self::with_dedup_window)
+    pub const DEFAULT_DEDUP_CAPACITY: usize = 1024;
+
     /// Create a new coordinator with an agent and tool registry.
     ///
     /// # Parameters
@@ -76,7 +511,267 @@ where
     ///
     /// A new `Coordinator` instance ready for execution
     pub fn new(agent: A, registry: R) -> Self {
-        Self { agent, registry }
+        Self {
+            agent,
+            registry,
+            error_strategy: ErrorStrategy::default(),
+            dedup: None,
+            normalization: None,
+            last_raw_observation: None,
+            tool_invocations: 0,
+            approval: None,
+            read_only: None,
+            tool_limiter: None,
+            priority: RequestPriority::Normal,
+            tool_budget: None,
+            deadline_dispatch: None,
+            memory_namespace: None,
+        }
+    }
+
+    /// Set the policy applied when a tool call fails during a step.
+    pub fn with_error_strategy(mut self, error_strategy: ErrorStrategy) -> Self {
+        self.error_strategy = error_strategy;
+        self
+    }
+
+    /// Get the configured error strategy.
+    pub fn error_strategy(&self) -> ErrorStrategy {
+        self.error_strategy
+    }
+
+    /// Require external approval before the gate's tools run.
+    ///
+    /// During a step, a call to a gated tool waits until the action is
+    /// approved or denied through the gate (see [`ApprovalGate`]). The wait
+    /// parks only the thread running the step, so steps should run on a
+    /// blocking thread, as
+    /// [`AgentInstance::run_step`](crate::runtime::AgentInstance::run_step) does. Approved
+    /// calls run normally. Denied calls are skipped: the agent receives a
+    /// failure result, but the error strategy does not treat it as a tool
+    /// failure. If no decision arrives within the gate's timeout the step
+    /// ends with [`StepError::ApprovalTimedOut`].
+    pub fn with_approval_gate(mut self, gate: ApprovalGate) -> Self {
+        self.approval = Some(gate);
+        self
+    }
+
+    /// Get the approval gate, if one is configured.
+    pub fn approval_gate(&self) -> Option<&ApprovalGate> {
+        self.approval.as_ref()
+    }
+
+    /// Apply the runtime resources an [`AgentFactory`] hands to builders.
+    ///
+    /// Sets the context's approval gate and tool limiter, if it has them.
+    /// The shared memory backend is attached separately by
+    /// [`with_context_memory`](Self::with_context_memory), which needs a
+    /// [`SharedMemoryAgent`].
+    ///
+    /// [`AgentFactory`]: crate::runtime::AgentFactory
+    pub fn with_build_context(mut self, context: &BuildContext) -> Self {
+        if let Some(gate) = &context.approval_gate {
+            self.approval = Some(gate.clone());
+        }
+        if let Some(limiter) = &context.tool_limiter {
+            self.tool_limiter = Some(limiter.clone());
+        }
+        self
+    }
+
+    /// Make the coordinator read-only.
+    ///
+    /// [`update_context`](Self::update_context) no longer reaches the agent:
+    /// updates are rejected with a read-only [`MemoryError`] or silently
+    /// dropped, depending on `writes`. Writes the agent makes to its own
+    /// memory are not intercepted; give it a
+    /// [`ReadOnlyMemory`](skreaver_core::memory::ReadOnlyMemory) to cover those.
+    pub fn with_read_only(mut self, writes: ReadOnlyWrites) -> Self {
+        self.read_only = Some(writes);
+        self
+    }
+
+    /// Get how context updates are handled, if the coordinator is read-only.
+    pub fn read_only(&self) -> Option<ReadOnlyWrites> {
+        self.read_only
+    }
+
+    /// Give the agent its view of a memory backend shared with other agents.
+    ///
+    /// With [`MemoryIsolation::PerAgent`] the agent's keys are namespaced by
+    /// `agent_id`, so agents sharing `backend` cannot read, overwrite or scan
+    /// each other's keys. Pass [`MemoryIsolation::Shared`] to share keys on
+    /// purpose.
+    pub fn with_shared_memory(
+        mut self,
+        agent_id: &AgentId,
+        backend: A::Backend,
+        isolation: MemoryIsolation,
+    ) -> Self
+    where
+        A: SharedMemoryAgent,
+    {
+        let namespace = isolation.namespace(agent_id);
+        self.agent
+            .attach_memory(NamespacedMemory::new(namespace.clone(), backend));
+        self.memory_namespace = Some(namespace);
+        self
+    }
+
+    /// Attach the memory backend an [`AgentFactory`] shares between agents.
+    ///
+    /// Does nothing unless `context` carries both a shared backend and the id
+    /// of the agent being built. Otherwise the agent gets its view of the
+    /// backend as with [`with_shared_memory`](Self::with_shared_memory), so
+    /// agents built by one factory are isolated from each other unless
+    /// `isolation` names a shared group.
+    ///
+    /// [`AgentFactory`]: crate::runtime::AgentFactory
+    pub fn with_context_memory(self, context: &BuildContext, isolation: MemoryIsolation) -> Self
+    where
+        A: SharedMemoryAgent<Backend = InMemoryMemory>,
+    {
+        match (&context.shared_memory, &context.agent_id) {
+            (Some(backend), Some(agent_id)) => {
+                self.with_shared_memory(agent_id, backend.clone(), isolation)
+            }
+            _ => self,
+        }
+    }
+
+    /// Get the namespace of the agent's shared memory, if one is configured.
+    pub fn memory_namespace(&self) -> Option<&str> {
+        self.memory_namespace.as_deref()
+    }
+
+    /// Limit how many calls to each tool may run at once.
+    ///
+    /// Calls beyond a tool's limit wait until a slot frees up; waiting calls
+    /// are served by the [`priority`](Self::priority) of the request that made
+    /// them. Steps are synchronous, so a waiting call holds the thread running
+    /// the step; the HTTP runtime runs steps on the blocking pool. Share clones
+    /// of one limiter between coordinators to make their tool calls compete
+    /// for the same slots.
+    pub fn with_tool_limiter(mut self, limiter: ToolConcurrencyLimiter) -> Self {
+        self.tool_limiter = Some(limiter);
+        self
+    }
+
+    /// Get the tool limiter, if one is configured.
+    pub fn tool_limiter(&self) -> Option<&ToolConcurrencyLimiter> {
+        self.tool_limiter.as_ref()
+    }
+
+    /// Bound the total time each step spends in tools.
+    ///
+    /// Every tool call of a step, including calls repeated by
+    /// [`ErrorStrategy::RetryStep`], draws from the budget; time spent waiting
+    /// for approval does not. Each call may run for the smaller of its own
+    /// timeout and the remaining budget. Budgeted calls run on a helper thread
+    /// with a clone of the registry; when a call reaches its deadline the
+    /// agent gets a [`ToolError::Timeout`] failure at once, while the call,
+    /// which cannot be interrupted, finishes in the background and keeps its
+    /// [tool limiter](Self::with_tool_limiter) slot until then. When the
+    /// budget is used up the step ends with [`StepError::ToolBudgetExhausted`].
+    pub fn with_tool_budget(mut self, budget: ToolTimeBudget) -> Self
+    where
+        R: Clone + Send + 'static,
+    {
+        self.tool_budget = Some(budget);
+        self.deadline_dispatch = Some(dispatch_with_deadline::<R>);
+        self
+    }
+
+    /// Get the tool time budget, if one is configured.
+    pub fn tool_budget(&self) -> Option<&ToolTimeBudget> {
+        self.tool_budget.as_ref()
+    }
+
+    /// Set the priority of the request served by subsequent steps.
+    ///
+    /// Tool calls inherit this priority when they wait for a slot in the
+    /// tool limiter. Defaults to [`RequestPriority::Normal`].
+    pub fn set_priority(&mut self, priority: RequestPriority) {
+        self.priority = priority;
+    }
+
+    /// Get the priority of the request being served.
+    pub fn priority(&self) -> RequestPriority {
+        self.priority
+    }
+
+    /// Enable observation deduplication for [`step_deduplicated`](Self::step_deduplicated).
+    ///
+    /// Results are remembered for `window`; at most
+    /// [`DEFAULT_DEDUP_CAPACITY`](Self::DEFAULT_DEDUP_CAPACITY) results are
+    /// kept, evicting the oldest first.
+    pub fn with_dedup_window(self, window: Duration) -> Self {
+        self.with_dedup_window_capacity(window, Self::DEFAULT_DEDUP_CAPACITY)
+    }
+
+    /// Enable observation deduplication, keeping at most `max_entries` results.
+    pub fn with_dedup_window_capacity(mut self, window: Duration, max_entries: usize) -> Self {
+        self.dedup = Some(DedupCache::new(window, max_entries));
+        self
+    }
+
+    /// Get the configured dedup window, if deduplication is enabled.
+    pub fn dedup_window(&self) -> Option<Duration> {
+        self.dedup.as_ref().map(|cache| cache.window)
+    }
+
+    /// Normalize observations before they reach the agent.
+    ///
+    /// The normalized form is what the agent observes and what
+    /// [`step_deduplicated`](Self::step_deduplicated) hashes, so inputs that
+    /// differ only in whitespace, line endings or Unicode composition are
+    /// treated as the same observation. The raw form of the latest
+    /// observation stays available through
+    /// [`last_raw_observation`](Self::last_raw_observation).
+    pub fn with_normalization(mut self, pipeline: NormalizationPipeline) -> Self
+    where
+        A::Observation: Normalize,
+    {
+        self.normalization = Some(ObservationNormalization {
+            pipeline,
+            apply: A::Observation::normalize_with,
+        });
+        self
+    }
+
+    /// Get the observation normalization pipeline, if enabled.
+    pub fn normalization(&self) -> Option<&NormalizationPipeline> {
+        self.normalization.as_ref().map(|n| &n.pipeline)
+    }
+
+    /// Get the raw, un-normalized form of the most recent observation.
+    ///
+    /// Only recorded when normalization is enabled.
+    pub fn last_raw_observation(&self) -> Option<&str> {
+        self.last_raw_observation.as_deref()
+    }
+
+    /// Apply the configured normalization without recording the raw form.
+    fn apply_normalization(&self, observation: A::Observation) -> A::Observation {
+        match &self.normalization {
+            Some(normalization) => (normalization.apply)(observation, &normalization.pipeline),
+            None => observation,
+        }
+    }
+
+    /// Normalize an incoming observation, recording its raw form for audit.
+    fn normalize_observation(&mut self, observation: A::Observation) -> A::Observation {
+        if self.normalization.is_none() {
+            return observation;
+        }
+
+        let raw = observation.to_string();
+        let observation = self.apply_normalization(observation);
+        if tracing::enabled!(tracing::Level::DEBUG) && observation.to_string() != raw {
+            tracing::debug!(raw = %raw.escape_debug(), "Normalized observation");
+        }
+        self.last_raw_observation = Some(raw);
+        observation
     }
 
     /// Execute a complete agent step: observe, use tools, and act.
@@ -85,6 +780,11 @@ where
     /// agent lifecycle: processes the observation, executes any requested tools,
     /// and generates the final action/response.
     ///
+    /// Tool failures are handled according to the configured
+    /// [`ErrorStrategy`]. If the strategy ends the step early, the agent still
+    /// acts on the results it has seen so far; use [`try_step`](Self::try_step)
+    /// to observe the early exit instead.
+    ///
     /// # Parameters
     ///
     /// * `observation` - The input data for the agent to process
@@ -93,36 +793,273 @@ where
     ///
     /// The action/response generated by the agent after processing
     pub fn step(&mut self, observation: A::Observation) -> A::Action {
+        match self.try_step(observation) {
+            Ok(action) => action,
+            Err(error) => {
+                tracing::warn!(error = %error, "Step ended early after tool failure");
+                self.agent.act()
+            }
+        }
+    }
+
+    /// Execute a complete agent step and classify how it ended.
+    ///
+    /// Behaves like [`step`](Self::step). The outcome is
+    /// [`StepOutcome::ToolFailure`] when the error strategy ended the step
+    /// early, and [`StepOutcome::Completed`] otherwise; failures skipped under
+    /// [`ErrorStrategy::SkipFailedTool`] still count as completed.
+    ///
+    /// # Parameters
+    ///
+    /// * `observation` - The input data for the agent to process
+    ///
+    /// # Returns
+    ///
+    /// The agent's action together with the step outcome
+    pub fn step_with_outcome(&mut self, observation: A::Observation) -> (A::Action, StepOutcome) {
+        match self.try_step(observation) {
+            Ok(action) => (action, StepOutcome::Completed),
+            Err(error) => {
+                tracing::warn!(error = %error, "Step ended early after tool failure");
+                (self.agent.act(), StepOutcome::ToolFailure)
+            }
+        }
+    }
+
+    /// Execute a step unless the same observation was already handled recently.
+    ///
+    /// Observations are identified by `observation_id` when the client
+    /// supplies one, or by a hash of the observation's content otherwise. If a
+    /// matching observation completed within the dedup window, its action is
+    /// returned without calling `observe` or dispatching any tools. Steps that
+    /// end early (see [`try_step`](Self::try_step)) are not remembered, so a
+    /// retry runs them again.
+    ///
+    /// Without a configured window (see
+    /// [`with_dedup_window`](Self::with_dedup_window)) this behaves like
+    /// [`step`](Self::step).
+    ///
+    /// # Parameters
+    ///
+    /// * `observation_id` - Optional client-supplied id for the observation
+    /// * `observation` - The input data for the agent to process
+    ///
+    /// # Returns
+    ///
+    /// The action for this observation, fresh or remembered
+    pub fn step_deduplicated(
+        &mut self,
+        observation_id: Option<&str>,
+        observation: A::Observation,
+    ) -> A::Action
+    where
+        A::Action: Clone + Send + Sync + 'static,
+    {
+        if self.dedup.is_none() {
+            return self.step(observation);
+        }
+
+        let observation = self.normalize_observation(observation);
+        let key = match observation_id {
+            Some(id) => format!("id:{}", id),
+            None => {
+                let mut hasher = DefaultHasher::new();
+                observation.to_string().hash(&mut hasher);
+                format!("hash:{:016x}", hasher.finish())
+            }
+        };
+
+        if let Some(action) = self.dedup.as_mut().and_then(|cache| cache.get(&key)) {
+            tracing::debug!(key = %key, "Returning cached result for duplicate observation");
+            return action;
+        }
+
+        match self.run_step(observation) {
+            Ok(action) => {
+                if let Some(cache) = self.dedup.as_mut() {
+                    cache.insert(key, action.clone());
+                }
+                action
+            }
+            Err(error) => {
+                tracing::warn!(error = %error, "Step ended early after tool failure");
+                self.agent.act()
+            }
+        }
+    }
+
+    /// Execute a complete agent step, reporting early exits as errors.
+    ///
+    /// Behaves like [`step`](Self::step), but returns a [`StepError`] without
+    /// calling the agent's `act` when the error strategy aborts the step or
+    /// runs out of retries.
+    ///
+    /// # Parameters
+    ///
+    /// * `observation` - The input data for the agent to process
+    ///
+    /// # Returns
+    ///
+    /// The agent's action, or the reason the step ended early
+    pub fn try_step(&mut self, observation: A::Observation) -> Result<A::Action, StepError> {
+        let observation = self.normalize_observation(observation);
+        self.run_step(observation)
+    }
+
+    /// Run a step on an already-normalized observation.
+    fn run_step(&mut self, observation: A::Observation) -> Result<A::Action, StepError> {
         self.agent.observe(observation);
 
-        let tool_calls = self.agent.call_tools();
+        let mut attempts = 1;
+        let mut tool_time = Duration::ZERO;
+        loop {
+            let stop_on_failure = self.error_strategy != ErrorStrategy::SkipFailedTool;
+            let Some(failure) = self.run_tool_calls(stop_on_failure, &mut tool_time)? else {
+                return Ok(self.agent.act());
+            };
 
-        // Pre-allocate with capacity if we know tools will fail
-        let mut failed_tools = Vec::with_capacity(tool_calls.len());
+            match self.error_strategy {
+                ErrorStrategy::SkipFailedTool => return Ok(self.agent.act()),
+                ErrorStrategy::AbortStep => {
+                    return Err(StepError::Aborted {
+                        tool_name: failure.tool_name,
+                        reason: failure.reason,
+                    });
+                }
+                ErrorStrategy::RetryStep { max_retries } => {
+                    if attempts > max_retries {
+                        return Err(StepError::RetriesExhausted {
+                            tool_name: failure.tool_name,
+                            attempts,
+                        });
+                    }
+                    tracing::debug!(
+                        tool_name = %failure.tool_name,
+                        attempt = attempts,
+                        "Retrying step after tool failure"
+                    );
+                    attempts += 1;
+                }
+            }
+        }
+    }
 
-        for tool_call in &tool_calls {
-            if let Some(result) = self.registry.dispatch_ref(tool_call) {
-                self.agent.handle_result(result);
-            } else {
-                let tool_name = tool_call.name();
-                failed_tools.push(tool_name.to_string());
-                tracing::warn!(
-                    tool_name = %tool_name,
-                    "Tool not found in registry"
-                );
+    /// Dispatch the agent's current tool calls, passing every result to it.
+    ///
+    /// Returns the first failure, stopping at it if `stop_on_failure` is set.
+    /// Fails if a call requiring approval gets no decision in time, or once
+    /// `tool_time` uses up the tool budget.
+    fn run_tool_calls(
+        &mut self,
+        stop_on_failure: bool,
+        tool_time: &mut Duration,
+    ) -> Result<Option<ToolFailure>, StepError> {
+        let mut first_failure = None;
+
+        for tool_call in &self.agent.call_tools() {
+            let tool_name = tool_call.name();
+            if let Some(gate) = &self.approval
+                && gate.requires_approval(tool_name)
+            {
+                match block_on(gate.request(tool_call)) {
+                    Some(ApprovalDecision::Approve) => {}
+                    Some(ApprovalDecision::Deny) => {
+                        tracing::info!(tool_name = %tool_name, "Tool call denied by approver");
+                        self.agent.handle_result(ExecutionResult::failure(format!(
+                            "Tool '{}' was denied by the approver",
+                            tool_name
+                        )));
+                        continue;
+                    }
+                    None => {
+                        return Err(StepError::ApprovalTimedOut {
+                            tool_name: tool_name.to_string(),
+                            timeout: gate.timeout(),
+                        });
+                    }
+                }
+            }
+
+            let timeout = match &self.tool_budget {
+                Some(budget) if budget.remaining(*tool_time).is_zero() => {
+                    return Err(StepError::ToolBudgetExhausted {
+                        tool_name: tool_name.to_string(),
+                        budget: budget.total(),
+                    });
+                }
+                Some(budget) => Some(budget.effective_timeout(tool_name, *tool_time)),
+                None => None,
+            };
+
+            self.tool_invocations += 1;
+            let started = Instant::now();
+            let result = match (timeout, self.deadline_dispatch) {
+                (Some(timeout), Some(dispatch)) => {
+                    let guards = (self.acquire_tool_slot(tool_call), track_tool_call());
+                    dispatch(&self.registry, tool_call, timeout, guards)
+                }
+                _ => Some(self.dispatch_tool_ref(tool_call)),
+            };
+            let elapsed = started.elapsed();
+            *tool_time += elapsed;
 
-                // Pre-allocate error message with exact capacity
-                let mut error_msg = String::with_capacity(tool_name.len() + 28);
-                error_msg.push_str("Tool '");
-                error_msg.push_str(tool_name);
-                error_msg.push_str("' not found in registry");
+            let overran = result.is_none();
+            let result = match result {
+                Some(result) => result.unwrap_or_else(|| {
+                    tracing::warn!(
+                        tool_name = %tool_name,
+                        "Tool not found in registry"
+                    );
 
-                self.agent
-                    .handle_result(ExecutionResult::failure(error_msg));
+                    // Pre-allocate error message with exact capacity
+                    let mut error_msg = String::with_capacity(tool_name.len() + 28);
+                    error_msg.push_str("Tool '");
+                    error_msg.push_str(tool_name);
+                    error_msg.push_str("' not found in registry");
+                    ExecutionResult::failure(error_msg)
+                }),
+                None => {
+                    let timeout = timeout.unwrap_or_default();
+                    tracing::warn!(
+                        tool_name = %tool_name,
+                        timeout = ?timeout,
+                        "Tool call exceeded its timeout"
+                    );
+                    ExecutionResult::failure(
+                        ToolError::timeout(
+                            tool_call.dispatch.clone(),
+                            u64::try_from(timeout.as_millis()).unwrap_or(u64::MAX),
+                        )
+                        .to_string(),
+                    )
+                }
+            };
+
+            let failure = result.error_message().map(|reason| ToolFailure {
+                tool_name: tool_name.to_string(),
+                reason,
+            });
+            self.agent.handle_result(result);
+
+            if let Some(budget) = &self.tool_budget
+                && overran
+                && budget.remaining(*tool_time).is_zero()
+            {
+                return Err(StepError::ToolBudgetExhausted {
+                    tool_name: tool_name.to_string(),
+                    budget: budget.total(),
+                });
+            }
+
+            if let Some(failure) = failure {
+                if stop_on_failure {
+                    return Ok(Some(failure));
+                }
+                first_failure.get_or_insert(failure);
             }
         }
 
-        self.agent.act()
+        Ok(first_failure)
     }
 
     /// Update the agent's context with new information.
@@ -133,8 +1070,17 @@ where
     /// # Parameters
     ///
     /// * `update` - The memory update containing new context data
-    pub fn update_context(&mut self, update: MemoryUpdate) {
+    ///
+    /// # Errors
+    ///
+    /// Returns a read-only [`MemoryError`] if the coordinator is read-only
+    /// and rejects writes.
+    pub fn update_context(&mut self, update: MemoryUpdate) -> Result<(), MemoryError> {
+        if let Some(writes) = self.read_only {
+            return writes.check(&update.key, MemoryOperation::Store, MemoryBackend::InMemory);
+        }
         self.agent.update_context(update);
+        Ok(())
     }
 
     /// Process an observation without executing tools or generating actions.
@@ -146,9 +1092,15 @@ where
     ///
     /// * `observation` - The input data for the agent to process
     pub fn observe(&mut self, observation: A::Observation) {
+        let observation = self.normalize_observation(observation);
         self.agent.observe(observation);
     }
 
+    /// Number of tool calls dispatched by steps so far, including retries.
+    pub fn tool_invocations(&self) -> u64 {
+        self.tool_invocations
+    }
+
     /// Get the current tool calls requested by the agent.
     ///
     /// Returns the list of tools the agent wants to execute based on its
@@ -161,6 +1113,30 @@ where
         self.agent.call_tools()
     }
 
+    /// Preview the tool calls an observation would trigger, without running them.
+    ///
+    /// The observation is processed by a scratch copy of the agent (see
+    /// [`ScratchAgent`]), so no tools are executed and neither the agent's
+    /// state nor its memory is changed. Useful for approval workflows that
+    /// need to inspect a plan before committing to a real [`step`](Self::step).
+    ///
+    /// # Parameters
+    ///
+    /// * `observation` - The input data to plan for
+    ///
+    /// # Returns
+    ///
+    /// The tool calls the agent would request after observing the input
+    pub fn plan(&self, observation: A::Observation) -> Vec<ToolCall>
+    where
+        A: ScratchAgent,
+    {
+        let observation = self.apply_normalization(observation);
+        let mut scratch = self.agent.scratch();
+        scratch.observe(observation);
+        scratch.call_tools()
+    }
+
     /// Dispatch a single tool call through the registry.
     ///
     /// Executes a specific tool call and returns the result. This provides
@@ -174,6 +1150,8 @@ where
     ///
     /// `Some(ExecutionResult)` if the tool exists, `None` if not found
     pub fn dispatch_tool(&self, tool_call: ToolCall) -> Option<ExecutionResult> {
+        let _permit = self.acquire_tool_slot(&tool_call);
+        let _in_flight = track_tool_call();
         self.registry.dispatch(tool_call)
     }
 
@@ -190,9 +1168,19 @@ where
     ///
     /// `Some(ExecutionResult)` if the tool exists, `None` if not found
     pub fn dispatch_tool_ref(&self, tool_call: &ToolCall) -> Option<ExecutionResult> {
+        let _permit = self.acquire_tool_slot(tool_call);
+        let _in_flight = track_tool_call();
         self.registry.dispatch_ref(tool_call)
     }
 
+    /// Wait for a slot to run `tool_call` at the current priority, if its tool is limited.
+    fn acquire_tool_slot(&self, tool_call: &ToolCall) -> Option<ToolPermit> {
+        let limiter = self.tool_limiter.as_ref()?;
+        limiter
+            .try_acquire(tool_call.name())
+            .or_else(|| block_on(limiter.acquire(tool_call.name(), self.priority)))
+    }
+
     /// Handle a tool execution result.
     ///
     /// Provides the agent with the results of a tool execution, allowing
@@ -218,3 +1206,821 @@ where
         self.agent.act()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use skreaver_core::memory::{MemoryReader, MemoryWriter};
+    use skreaver_core::{InMemoryMemory, Tool};
+    use skreaver_tools::InMemoryToolRegistry;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// Tool that fails its first `failures` calls and succeeds afterwards
+    struct FlakyTool {
+        failures: usize,
+        calls: Arc<AtomicUsize>,
+    }
+
+    impl Tool for FlakyTool {
+        fn name(&self) -> &str {
+            "flaky"
+        }
+
+        fn call(&self, _input: String) -> ExecutionResult {
+            if self.calls.fetch_add(1, Ordering::SeqCst) < self.failures {
+                ExecutionResult::failure("flaky failure".to_string())
+            } else {
+                ExecutionResult::success("flaky ok".to_string())
+            }
+        }
+    }
+
+    struct EchoTool;
+
+    impl Tool for EchoTool {
+        fn name(&self) -> &str {
+            "echo"
+        }
+
+        fn call(&self, input: String) -> ExecutionResult {
+            ExecutionResult::success(input)
+        }
+    }
+
+    /// Agent that calls `flaky` then `echo` and records every result it sees
+    struct RecordingAgent {
+        memory: InMemoryMemory,
+        results: Vec<ExecutionResult>,
+    }
+
+    impl Agent for RecordingAgent {
+        type Observation = String;
+        type Action = usize;
+        type Error = std::convert::Infallible;
+
+        fn memory_reader(&self) -> &dyn MemoryReader {
+            &self.memory
+        }
+        fn memory_writer(&mut self) -> &mut dyn MemoryWriter {
+            &mut self.memory
+        }
+        fn observe(&mut self, _input: String) {}
+        fn act(&mut self) -> usize {
+            self.results.len()
+        }
+        fn call_tools(&self) -> Vec<ToolCall> {
+            vec![
+                ToolCall::new("flaky", "in").unwrap(),
+                ToolCall::new("echo", "in").unwrap(),
+            ]
+        }
+        fn handle_result(&mut self, result: ExecutionResult) {
+            self.results.push(result);
+        }
+        fn update_context(&mut self, update: MemoryUpdate) {
+            let _ = self.memory.store(update);
+        }
+    }
+
+    fn setup(
+        failures: usize,
+        strategy: ErrorStrategy,
+    ) -> (
+        Coordinator<RecordingAgent, InMemoryToolRegistry>,
+        Arc<AtomicUsize>,
+    ) {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let registry = InMemoryToolRegistry::new()
+            .with_tool(
+                "flaky",
+                Arc::new(FlakyTool {
+                    failures,
+                    calls: Arc::clone(&calls),
+                }),
+            )
+            .with_tool("echo", Arc::new(EchoTool));
+        let agent = RecordingAgent {
+            memory: InMemoryMemory::new(),
+            results: Vec::new(),
+        };
+        let coordinator = Coordinator::new(agent, registry).with_error_strategy(strategy);
+        (coordinator, calls)
+    }
+
+    #[test]
+    fn test_abort_stops_remaining_tools() {
+        let (mut coordinator, calls) = setup(usize::MAX, ErrorStrategy::AbortStep);
+
+        let error = coordinator.try_step("go".to_string()).unwrap_err();
+        assert_eq!(
+            error,
+            StepError::Aborted {
+                tool_name: "flaky".to_string(),
+                reason: "Internal error: flaky failure".to_string(),
+            }
+        );
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        // The agent saw the failure, but `echo` was never dispatched
+        assert_eq!(coordinator.agent.results.len(), 1);
+        assert!(coordinator.agent.results[0].is_failure());
+    }
+
+    #[test]
+    fn test_skip_continues_after_failure() {
+        let (mut coordinator, calls) = setup(usize::MAX, ErrorStrategy::SkipFailedTool);
+
+        assert_eq!(coordinator.try_step("go".to_string()), Ok(2));
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        assert!(coordinator.agent.results[0].is_failure());
+        assert_eq!(coordinator.agent.results[1].output(), "in");
+    }
+
+    #[test]
+    fn test_retry_reruns_step_until_success() {
+        let (mut coordinator, calls) = setup(2, ErrorStrategy::RetryStep { max_retries: 3 });
+
+        // Two failed attempts followed by a successful one running both tools
+        assert_eq!(coordinator.try_step("go".to_string()), Ok(4));
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+        assert!(coordinator.agent.results[2].is_success());
+    }
+
+    #[test]
+    fn test_retry_gives_up_after_limit() {
+        let (mut coordinator, calls) =
+            setup(usize::MAX, ErrorStrategy::RetryStep { max_retries: 2 });
+
+        let error = coordinator.try_step("go".to_string()).unwrap_err();
+        assert_eq!(
+            error,
+            StepError::RetriesExhausted {
+                tool_name: "flaky".to_string(),
+                attempts: 3,
+            }
+        );
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+
+        // `step` still lets the agent act on what it has seen
+        let (mut coordinator, _) = setup(usize::MAX, ErrorStrategy::AbortStep);
+        assert_eq!(coordinator.step("go".to_string()), 1);
+    }
+
+    /// Agent that records each observation in memory and plans one tool call per word
+    struct PlanningAgent {
+        memory: InMemoryMemory,
+        pending: Vec<ToolCall>,
+    }
+
+    impl Agent for PlanningAgent {
+        type Observation = String;
+        type Action = usize;
+        type Error = std::convert::Infallible;
+
+        fn memory_reader(&self) -> &dyn MemoryReader {
+            &self.memory
+        }
+        fn memory_writer(&mut self) -> &mut dyn MemoryWriter {
+            &mut self.memory
+        }
+        fn observe(&mut self, input: String) {
+            self.memory
+                .store(MemoryUpdate::new("last_input", &input).unwrap())
+                .unwrap();
+            self.pending = input
+                .split_whitespace()
+                .map(|word| ToolCall::new("flaky", word).unwrap())
+                .collect();
+        }
+        fn act(&mut self) -> usize {
+            self.pending.len()
+        }
+        fn call_tools(&self) -> Vec<ToolCall> {
+            self.pending.clone()
+        }
+        fn handle_result(&mut self, _result: ExecutionResult) {}
+        fn update_context(&mut self, _update: MemoryUpdate) {}
+    }
+
+    impl ScratchAgent for PlanningAgent {
+        fn scratch(&self) -> Self {
+            Self {
+                memory: self.memory.fork(),
+                pending: self.pending.clone(),
+            }
+        }
+    }
+
+    /// Tool that takes `delay` to answer
+    struct SlowTool {
+        delay: Duration,
+        calls: Arc<AtomicUsize>,
+    }
+
+    impl Tool for SlowTool {
+        fn name(&self) -> &str {
+            "flaky"
+        }
+
+        fn call(&self, input: String) -> ExecutionResult {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            std::thread::sleep(self.delay);
+            ExecutionResult::success(input)
+        }
+    }
+
+    fn budgeted_coordinator(
+        budget: ToolTimeBudget,
+        delay: Duration,
+    ) -> (
+        Coordinator<PlanningAgent, InMemoryToolRegistry>,
+        Arc<AtomicUsize>,
+    ) {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let registry = InMemoryToolRegistry::new().with_tool(
+            "flaky",
+            Arc::new(SlowTool {
+                delay,
+                calls: Arc::clone(&calls),
+            }),
+        );
+        let agent = PlanningAgent {
+            memory: InMemoryMemory::new(),
+            pending: Vec::new(),
+        };
+        let coordinator = Coordinator::new(agent, registry).with_tool_budget(budget);
+        (coordinator, calls)
+    }
+
+    #[test]
+    fn test_tool_budget_exhaustion_fails_the_step() {
+        let budget = Duration::from_millis(75);
+        let (mut coordinator, calls) =
+            budgeted_coordinator(ToolTimeBudget::new(budget), Duration::from_millis(30));
+
+        // Two calls fit; the third overruns the 15ms left and ends the step
+        let error = coordinator.try_step("a b c d e".to_string()).unwrap_err();
+        assert_eq!(
+            error,
+            StepError::ToolBudgetExhausted {
+                tool_name: "flaky".to_string(),
+                budget,
+            }
+        );
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+
+        // The budget is per step
+        assert_eq!(coordinator.try_step("a".to_string()), Ok(1));
+        assert_eq!(calls.load(Ordering::SeqCst), 4);
+    }
+
+    #[test]
+    fn test_tool_timeout_below_budget_is_a_tool_failure() {
+        let (mut coordinator, calls) = budgeted_coordinator(
+            ToolTimeBudget::new(Duration::from_secs(10))
+                .with_tool_timeout("flaky", Duration::from_millis(5)),
+            Duration::from_millis(30),
+        );
+        coordinator = coordinator.with_error_strategy(ErrorStrategy::AbortStep);
+
+        let error = coordinator.try_step("a b".to_string()).unwrap_err();
+        let StepError::Aborted { tool_name, reason } = error else {
+            panic!("expected an aborted step, got {error:?}");
+        };
+        assert_eq!(tool_name, "flaky");
+        assert!(reason.contains(&ToolError::timeout_by_name("flaky", 5).to_string()));
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_tool_call_is_abandoned_at_its_deadline() {
+        let (mut coordinator, calls) = budgeted_coordinator(
+            ToolTimeBudget::new(Duration::from_secs(10))
+                .with_tool_timeout("flaky", Duration::from_millis(20)),
+            Duration::from_secs(2),
+        );
+        coordinator = coordinator.with_error_strategy(ErrorStrategy::SkipFailedTool);
+
+        // The step does not wait for the slow call to return
+        let started = Instant::now();
+        assert_eq!(coordinator.try_step("a".to_string()), Ok(1));
+        assert!(started.elapsed() < Duration::from_secs(1));
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_plan_previews_tool_calls_without_side_effects() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let registry = InMemoryToolRegistry::new().with_tool(
+            "flaky",
+            Arc::new(FlakyTool {
+                failures: 0,
+                calls: Arc::clone(&calls),
+            }),
+        );
+        let agent = PlanningAgent {
+            memory: InMemoryMemory::new(),
+            pending: Vec::new(),
+        };
+        let coordinator = Coordinator::new(agent, registry);
+
+        let plan = coordinator.plan("fetch parse store".to_string());
+
+        let inputs: Vec<&str> = plan.iter().map(|call| call.input.as_str()).collect();
+        assert_eq!(inputs, ["fetch", "parse", "store"]);
+        assert!(plan.iter().all(|call| call.name() == "flaky"));
+
+        // Nothing ran and nothing was committed
+        assert_eq!(calls.load(Ordering::SeqCst), 0);
+        assert!(coordinator.tool_calls().is_empty());
+        let key = skreaver_core::MemoryKey::new("last_input").unwrap();
+        assert_eq!(coordinator.agent.memory_reader().load(&key).unwrap(), None);
+    }
+
+    #[test]
+    fn test_duplicate_observation_returns_cached_result() {
+        let (coordinator, calls) = setup(0, ErrorStrategy::default());
+        let mut coordinator = coordinator.with_dedup_window(Duration::from_secs(60));
+
+        let first = coordinator.step_deduplicated(Some("obs-1"), "go".to_string());
+        let second = coordinator.step_deduplicated(Some("obs-1"), "go again".to_string());
+
+        assert_eq!(first, 2);
+        assert_eq!(second, first);
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        assert_eq!(coordinator.agent.results.len(), 2);
+
+        // A distinct id runs normally
+        let third = coordinator.step_deduplicated(Some("obs-2"), "go".to_string());
+        assert_eq!(third, 4);
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn test_dedup_by_content_hash_and_window_expiry() {
+        let (coordinator, calls) = setup(0, ErrorStrategy::default());
+        let mut coordinator = coordinator.with_dedup_window(Duration::from_secs(60));
+
+        coordinator.step_deduplicated(None, "same".to_string());
+        coordinator.step_deduplicated(None, "same".to_string());
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+        coordinator.step_deduplicated(None, "different".to_string());
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+
+        // With a zero window nothing is remembered
+        let (coordinator, calls) = setup(0, ErrorStrategy::default());
+        let mut coordinator = coordinator.with_dedup_window(Duration::ZERO);
+        coordinator.step_deduplicated(Some("obs-1"), "go".to_string());
+        coordinator.step_deduplicated(Some("obs-1"), "go".to_string());
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn test_dedup_does_not_cache_failed_steps() {
+        let (coordinator, calls) = setup(1, ErrorStrategy::AbortStep);
+        let mut coordinator = coordinator.with_dedup_window(Duration::from_secs(60));
+
+        coordinator.step_deduplicated(Some("obs-1"), "go".to_string());
+        coordinator.step_deduplicated(Some("obs-1"), "go".to_string());
+        coordinator.step_deduplicated(Some("obs-1"), "go".to_string());
+
+        // The aborted first attempt is retried; the successful second is cached
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn test_dedup_capacity_evicts_oldest() {
+        let (coordinator, calls) = setup(0, ErrorStrategy::default());
+        let mut coordinator = coordinator.with_dedup_window_capacity(Duration::from_secs(60), 1);
+
+        coordinator.step_deduplicated(Some("a"), "go".to_string());
+        coordinator.step_deduplicated(Some("b"), "go".to_string());
+        coordinator.step_deduplicated(Some("a"), "go".to_string());
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+    }
+
+    fn gated_coordinator(
+        timeout: Duration,
+    ) -> (
+        Coordinator<RecordingAgent, InMemoryToolRegistry>,
+        Arc<AtomicUsize>,
+        ApprovalGate,
+    ) {
+        let (coordinator, calls) = setup(0, ErrorStrategy::AbortStep);
+        let gate = ApprovalGate::new()
+            .require_approval("flaky")
+            .with_timeout(timeout);
+        (coordinator.with_approval_gate(gate.clone()), calls, gate)
+    }
+
+    /// Run a step on another thread and decide on its pending action
+    fn step_with_decision(
+        coordinator: &mut Coordinator<RecordingAgent, InMemoryToolRegistry>,
+        gate: &ApprovalGate,
+        calls: &AtomicUsize,
+        decision: ApprovalDecision,
+    ) -> Result<usize, StepError> {
+        std::thread::scope(|scope| {
+            let step = scope.spawn(|| coordinator.try_step("go".to_string()));
+            while gate.pending().is_empty() {
+                std::thread::sleep(Duration::from_millis(5));
+            }
+
+            // The step is blocked and the tool has not run yet
+            std::thread::sleep(Duration::from_millis(20));
+            assert!(!step.is_finished());
+            assert_eq!(calls.load(Ordering::SeqCst), 0);
+
+            let action = gate.pending().remove(0);
+            assert_eq!(action.tool_name, "flaky");
+            assert_eq!(action.input, "in");
+            gate.decide(&action.id, decision).unwrap();
+            step.join().unwrap()
+        })
+    }
+
+    #[test]
+    fn test_approval_required_tool_runs_after_approval() {
+        let (mut coordinator, calls, gate) = gated_coordinator(Duration::from_secs(10));
+
+        let result = step_with_decision(&mut coordinator, &gate, &calls, ApprovalDecision::Approve);
+
+        assert_eq!(result, Ok(2));
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        assert!(coordinator.agent.results[0].is_success());
+        assert_eq!(coordinator.tool_invocations(), 2);
+    }
+
+    #[test]
+    fn test_denied_tool_is_skipped() {
+        let (mut coordinator, calls, gate) = gated_coordinator(Duration::from_secs(10));
+
+        let result = step_with_decision(&mut coordinator, &gate, &calls, ApprovalDecision::Deny);
+
+        // The denial does not abort the step; `echo` still runs
+        assert_eq!(result, Ok(2));
+        assert_eq!(calls.load(Ordering::SeqCst), 0);
+        assert!(coordinator.agent.results[0].is_failure());
+        assert_eq!(coordinator.agent.results[1].output(), "in");
+        assert_eq!(coordinator.tool_invocations(), 1);
+    }
+
+    #[test]
+    fn test_approval_timeout_ends_step() {
+        let (mut coordinator, calls, gate) = gated_coordinator(Duration::from_millis(30));
+
+        let error = coordinator.try_step("go".to_string()).unwrap_err();
+
+        assert_eq!(
+            error,
+            StepError::ApprovalTimedOut {
+                tool_name: "flaky".to_string(),
+                timeout: Duration::from_millis(30),
+            }
+        );
+        assert_eq!(calls.load(Ordering::SeqCst), 0);
+        assert!(coordinator.agent.results.is_empty());
+        assert!(gate.pending().is_empty());
+    }
+
+    #[test]
+    fn test_error_strategy_from_config() {
+        let config = |pairs: &[(&str, Value)]| -> HashMap<String, Value> {
+            pairs
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.clone()))
+                .collect()
+        };
+
+        assert_eq!(
+            ErrorStrategy::from_config(&HashMap::new()),
+            Ok(ErrorStrategy::SkipFailedTool)
+        );
+        assert_eq!(
+            ErrorStrategy::from_config(&config(&[("error_strategy", "abort".into())])),
+            Ok(ErrorStrategy::AbortStep)
+        );
+        assert_eq!(
+            ErrorStrategy::from_config(&config(&[("error_strategy", "retry".into())])),
+            Ok(ErrorStrategy::RetryStep { max_retries: 3 })
+        );
+        assert_eq!(
+            ErrorStrategy::from_config(&config(&[
+                ("error_strategy", "retry".into()),
+                ("max_step_retries", 5.into()),
+            ])),
+            Ok(ErrorStrategy::RetryStep { max_retries: 5 })
+        );
+        assert!(
+            ErrorStrategy::from_config(&config(&[("error_strategy", "explode".into())])).is_err()
+        );
+        assert!(
+            ErrorStrategy::from_config(&config(&[
+                ("error_strategy", "retry".into()),
+                ("max_step_retries", (-1).into()),
+            ]))
+            .is_err()
+        );
+    }
+
+    fn planning_coordinator() -> Coordinator<PlanningAgent, InMemoryToolRegistry> {
+        let registry = InMemoryToolRegistry::new().with_tool(
+            "flaky",
+            Arc::new(FlakyTool {
+                failures: 0,
+                calls: Arc::new(AtomicUsize::new(0)),
+            }),
+        );
+        let agent = PlanningAgent {
+            memory: InMemoryMemory::new(),
+            pending: Vec::new(),
+        };
+        Coordinator::new(agent, registry)
+    }
+
+    fn last_input(coordinator: &Coordinator<PlanningAgent, InMemoryToolRegistry>) -> String {
+        let key = skreaver_core::MemoryKey::new("last_input").unwrap();
+        coordinator
+            .agent
+            .memory_reader()
+            .load(&key)
+            .unwrap()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_normalization_converts_crlf_and_keeps_raw() {
+        let mut coordinator =
+            planning_coordinator().with_normalization(NormalizationPipeline::new());
+
+        coordinator.step("  fetch\r\nparse\r\n".to_string());
+
+        assert_eq!(last_input(&coordinator), "fetch\nparse");
+        assert_eq!(
+            coordinator.last_raw_observation(),
+            Some("  fetch\r\nparse\r\n")
+        );
+
+        // Without normalization the agent sees the input unchanged
+        let mut coordinator = planning_coordinator();
+        coordinator.step("fetch\r\n".to_string());
+        assert_eq!(last_input(&coordinator), "fetch\r\n");
+        assert_eq!(coordinator.last_raw_observation(), None);
+    }
+
+    #[test]
+    fn test_normalization_applies_nfc_before_dedup() {
+        let (coordinator, calls) = setup(0, ErrorStrategy::default());
+        let mut coordinator = coordinator
+            .with_normalization(NormalizationPipeline::none().with_unicode_nfc(true))
+            .with_dedup_window(Duration::from_secs(60));
+
+        // "café" composed and decomposed are the same observation
+        coordinator.step_deduplicated(None, "caf\u{e9}".to_string());
+        coordinator.step_deduplicated(None, "cafe\u{301}".to_string());
+
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        assert_eq!(coordinator.last_raw_observation(), Some("cafe\u{301}"));
+    }
+
+    #[test]
+    fn test_normalization_from_config_is_opt_in() {
+        let config = |pairs: &[(&str, Value)]| -> HashMap<String, Value> {
+            pairs
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.clone()))
+                .collect()
+        };
+
+        assert_eq!(normalization_from_config(&HashMap::new()), None);
+        assert_eq!(
+            normalization_from_config(&config(&[(NORMALIZE_OBSERVATIONS_KEY, true.into())])),
+            Some(NormalizationPipeline::new())
+        );
+        assert_eq!(
+            normalization_from_config(&config(&[
+                (NORMALIZE_OBSERVATIONS_KEY, true.into()),
+                (LOWERCASE_OBSERVATIONS_KEY, true.into()),
+            ])),
+            Some(NormalizationPipeline::new().with_lowercase(true))
+        );
+    }
+
+    /// Echo tool that records which coordinator ran it
+    struct LoggingEchoTool {
+        label: &'static str,
+        log: Arc<std::sync::Mutex<Vec<&'static str>>>,
+    }
+
+    impl Tool for LoggingEchoTool {
+        fn name(&self) -> &str {
+            "echo"
+        }
+
+        fn call(&self, input: String) -> ExecutionResult {
+            self.log.lock().unwrap().push(self.label);
+            ExecutionResult::success(input)
+        }
+    }
+
+    #[test]
+    fn test_tool_calls_inherit_request_priority() {
+        let limiter = ToolConcurrencyLimiter::new().with_limit("echo", 1);
+        let log = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let coordinator = |label, priority| {
+            let registry = InMemoryToolRegistry::new()
+                .with_tool(
+                    "flaky",
+                    Arc::new(FlakyTool {
+                        failures: 0,
+                        calls: Arc::new(AtomicUsize::new(0)),
+                    }),
+                )
+                .with_tool(
+                    "echo",
+                    Arc::new(LoggingEchoTool {
+                        label,
+                        log: Arc::clone(&log),
+                    }),
+                );
+            let agent = RecordingAgent {
+                memory: InMemoryMemory::new(),
+                results: Vec::new(),
+            };
+            let mut coordinator =
+                Coordinator::new(agent, registry).with_tool_limiter(limiter.clone());
+            coordinator.set_priority(priority);
+            coordinator
+        };
+        let mut low = coordinator("low", RequestPriority::Low);
+        let mut high = coordinator("high", RequestPriority::High);
+        assert_eq!(high.priority(), RequestPriority::High);
+
+        // Occupy the only slot so both steps queue for it, low priority first
+        let holder = limiter.try_acquire("echo");
+        std::thread::scope(|scope| {
+            let low_step = scope.spawn(|| low.try_step("go".to_string()));
+            while limiter.waiting("echo") < 1 {
+                std::thread::sleep(Duration::from_millis(5));
+            }
+            let high_step = scope.spawn(|| high.try_step("go".to_string()));
+            while limiter.waiting("echo") < 2 {
+                std::thread::sleep(Duration::from_millis(5));
+            }
+
+            drop(holder);
+            assert_eq!(low_step.join().unwrap(), Ok(2));
+            assert_eq!(high_step.join().unwrap(), Ok(2));
+        });
+
+        assert_eq!(*log.lock().unwrap(), vec!["high", "low"]);
+        assert_eq!(limiter.active("echo"), 0);
+    }
+
+    #[test]
+    fn test_read_only_coordinator_blocks_context_updates() {
+        let (mut coordinator, _) = setup(0, ErrorStrategy::default());
+        let key = skreaver_core::MemoryKey::new("seed").unwrap();
+        coordinator
+            .update_context(MemoryUpdate::new("seed", "original").unwrap())
+            .unwrap();
+
+        let mut coordinator = coordinator.with_read_only(ReadOnlyWrites::Reject);
+        let err = coordinator
+            .update_context(MemoryUpdate::new("seed", "changed").unwrap())
+            .unwrap_err();
+        assert!(err.is_read_only());
+        assert_eq!(
+            coordinator.agent.memory_reader().load(&key).unwrap(),
+            Some("original".to_string())
+        );
+
+        let mut coordinator = coordinator.with_read_only(ReadOnlyWrites::Discard);
+        assert_eq!(coordinator.read_only(), Some(ReadOnlyWrites::Discard));
+        coordinator
+            .update_context(MemoryUpdate::new("seed", "changed").unwrap())
+            .unwrap();
+        assert_eq!(
+            coordinator.agent.memory_reader().load(&key).unwrap(),
+            Some("original".to_string())
+        );
+    }
+
+    /// Agent that keeps its notes in a memory backend shared with other agents
+    struct NotesAgent {
+        memory: NamespacedMemory<InMemoryMemory>,
+    }
+
+    impl NotesAgent {
+        fn new() -> Self {
+            Self {
+                memory: NamespacedMemory::new("unattached", InMemoryMemory::new()),
+            }
+        }
+
+        fn note(&self, key: &str) -> Option<String> {
+            let key = skreaver_core::MemoryKey::new(key).unwrap();
+            self.memory.load(&key).unwrap()
+        }
+
+        fn scanned_keys(&self) -> Vec<String> {
+            let mut keys: Vec<String> = self
+                .memory
+                .scan()
+                .unwrap()
+                .into_iter()
+                .map(|key| key.as_str().to_string())
+                .collect();
+            keys.sort();
+            keys
+        }
+    }
+
+    impl Agent for NotesAgent {
+        type Observation = String;
+        type Action = ();
+        type Error = std::convert::Infallible;
+
+        fn memory_reader(&self) -> &dyn MemoryReader {
+            &self.memory
+        }
+        fn memory_writer(&mut self) -> &mut dyn MemoryWriter {
+            &mut self.memory
+        }
+        fn observe(&mut self, _input: String) {}
+        fn act(&mut self) {}
+        fn call_tools(&self) -> Vec<ToolCall> {
+            Vec::new()
+        }
+        fn handle_result(&mut self, _result: ExecutionResult) {}
+        fn update_context(&mut self, update: MemoryUpdate) {
+            let _ = self.memory.store(update);
+        }
+    }
+
+    impl SharedMemoryAgent for NotesAgent {
+        type Backend = InMemoryMemory;
+
+        fn attach_memory(&mut self, memory: NamespacedMemory<InMemoryMemory>) {
+            self.memory = memory;
+        }
+    }
+
+    fn notes_coordinator(
+        id: &str,
+        backend: &InMemoryMemory,
+        isolation: MemoryIsolation,
+    ) -> Coordinator<NotesAgent, InMemoryToolRegistry> {
+        let id = AgentId::parse(id).unwrap();
+        Coordinator::new(NotesAgent::new(), InMemoryToolRegistry::new()).with_shared_memory(
+            &id,
+            backend.clone(),
+            isolation,
+        )
+    }
+
+    #[test]
+    fn test_shared_memory_is_isolated_per_agent_by_default() {
+        let backend = InMemoryMemory::new();
+        let mut planner = notes_coordinator("planner", &backend, MemoryIsolation::default());
+        let mut critic = notes_coordinator("critic", &backend, MemoryIsolation::default());
+        assert_eq!(planner.memory_namespace(), Some("agent:planner"));
+
+        planner
+            .update_context(MemoryUpdate::new("draft", "plan v1").unwrap())
+            .unwrap();
+        critic
+            .update_context(MemoryUpdate::new("draft", "looks risky").unwrap())
+            .unwrap();
+        planner
+            .update_context(MemoryUpdate::new("budget", "10").unwrap())
+            .unwrap();
+
+        assert_eq!(planner.agent.note("draft").as_deref(), Some("plan v1"));
+        assert_eq!(critic.agent.note("draft").as_deref(), Some("looks risky"));
+        assert_eq!(critic.agent.note("budget"), None);
+
+        assert_eq!(planner.agent.scanned_keys(), ["budget", "draft"]);
+        assert_eq!(critic.agent.scanned_keys(), ["draft"]);
+    }
+
+    #[test]
+    fn test_shared_memory_opt_out_shares_keys_within_group() {
+        let backend = InMemoryMemory::new();
+        let shared = MemoryIsolation::Shared("team".to_string());
+        let mut planner = notes_coordinator("planner", &backend, shared.clone());
+        let critic = notes_coordinator("critic", &backend, shared);
+        let outsider = notes_coordinator("outsider", &backend, MemoryIsolation::PerAgent);
+        assert_eq!(critic.memory_namespace(), Some("shared:team"));
+
+        planner
+            .update_context(MemoryUpdate::new("draft", "plan v1").unwrap())
+            .unwrap();
+
+        assert_eq!(critic.agent.note("draft").as_deref(), Some("plan v1"));
+        assert_eq!(critic.agent.scanned_keys(), ["draft"]);
+        assert_eq!(outsider.agent.note("draft"), None);
+        assert!(outsider.agent.scanned_keys().is_empty());
+    }
+}
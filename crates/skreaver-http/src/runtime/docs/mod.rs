@@ -2,13 +2,35 @@
 //!
 //! This module provides OpenAPI specification and Swagger UI endpoints
 //! for interactive API documentation.
+//!
+//! The specification is generated once and served from a process-wide cache.
+//! The docs endpoints are throttled by a dedicated limiter so they can stay
+//! public without sharing (or exhausting) the API rate limits.
 
-use axum::response::{Html, Json};
+use axum::{
+    extract::{Request, State},
+    http::{StatusCode, header},
+    middleware::Next,
+    response::{Html, IntoResponse, Json, Response},
+};
+use governor::{
+    Quota, RateLimiter,
+    clock::{Clock, DefaultClock},
+};
+use std::num::NonZeroU32;
+use std::sync::{Arc, OnceLock};
 use utoipa::OpenApi;
 
+use crate::runtime::rate_limit::{GlobalRateLimiter, RateLimitError};
+
+use crate::runtime::api_types::{AgentLimits, AgentSpec, AgentType, SpecViolation};
 use crate::runtime::types::{
-    AgentStatus, AgentsListResponse, CreateAgentRequest, CreateAgentResponse, CreateTokenRequest,
-    CreateTokenResponse, ErrorResponse, ObserveRequest, ObserveResponse, QueueMetricsResponse,
+    AgentLogLevelRequest, AgentLogLevelResponse, AgentStatus, AgentsListResponse,
+    AgentsListResponseV2, ApprovalDecisionResponse, CircuitBreakerRequest, CircuitBreakerResponse,
+    CollectionMeta, CreateAgentRequest, CreateAgentResponse, CreateTokenRequest,
+    CreateTokenResponse, DlqEntryResponse, DlqListResponse, DlqPurgeResponse, DlqReplayResponse,
+    DlqStatsResponse, ErrorResponse, InvokeToolRequest, InvokeToolResponse, ObserveRequest,
+    ObserveResponse, PendingApprovalResponse, PendingApprovalsResponse, QueueMetricsResponse,
 };
 
 /// GET /docs - Swagger UI for interactive API documentation
@@ -41,7 +63,28 @@ pub async fn swagger_ui() -> Html<&'static str> {
 }
 
 /// GET /api-docs/openapi.json - OpenAPI specification endpoint
-pub async fn openapi_spec() -> Json<utoipa::openapi::OpenApi> {
+pub async fn openapi_spec() -> impl IntoResponse {
+    (
+        [(header::CONTENT_TYPE, "application/json")],
+        openapi_spec_json(),
+    )
+}
+
+/// Serialized OpenAPI specification, generated on first use
+pub fn openapi_spec_json() -> &'static str {
+    static SPEC_JSON: OnceLock<String> = OnceLock::new();
+    SPEC_JSON.get_or_init(|| {
+        serde_json::to_string(openapi_document()).expect("OpenAPI document serializes to JSON")
+    })
+}
+
+/// OpenAPI document for the runtime API, generated on first use
+pub fn openapi_document() -> &'static utoipa::openapi::OpenApi {
+    static DOCUMENT: OnceLock<utoipa::openapi::OpenApi> = OnceLock::new();
+    DOCUMENT.get_or_init(generate_openapi)
+}
+
+fn generate_openapi() -> utoipa::openapi::OpenApi {
     #[derive(OpenApi)]
     #[openapi(
         paths(
@@ -54,7 +97,21 @@ pub async fn openapi_spec() -> Json<utoipa::openapi::OpenApi> {
             crate::runtime::handlers::get_agent_status,
             crate::runtime::handlers::delete_agent,
             crate::runtime::handlers::get_agent_queue_metrics,
-            crate::runtime::handlers::get_global_queue_metrics
+            crate::runtime::handlers::get_global_queue_metrics,
+            crate::runtime::handlers::invoke_tool,
+            crate::runtime::handlers::get_circuit_breaker,
+            crate::runtime::handlers::set_circuit_breaker,
+            crate::runtime::handlers::get_agent_log_level,
+            crate::runtime::handlers::set_agent_log_level,
+            crate::runtime::handlers::list_dlq_entries,
+            crate::runtime::handlers::get_dlq_stats,
+            crate::runtime::handlers::get_dlq_entry,
+            crate::runtime::handlers::replay_dlq_entry,
+            crate::runtime::handlers::delete_dlq_entry,
+            crate::runtime::handlers::purge_dlq_entries,
+            crate::runtime::handlers::list_pending_approvals,
+            crate::runtime::handlers::approve_action,
+            crate::runtime::handlers::deny_action
         ),
         components(
             schemas(
@@ -62,12 +119,32 @@ pub async fn openapi_spec() -> Json<utoipa::openapi::OpenApi> {
                 CreateAgentResponse,
                 ObserveRequest,
                 ObserveResponse,
+                InvokeToolRequest,
+                InvokeToolResponse,
                 AgentStatus,
                 AgentsListResponse,
+                AgentsListResponseV2,
+                CollectionMeta,
                 ErrorResponse,
                 CreateTokenRequest,
                 CreateTokenResponse,
-                QueueMetricsResponse
+                QueueMetricsResponse,
+                CircuitBreakerRequest,
+                CircuitBreakerResponse,
+                AgentLogLevelRequest,
+                AgentLogLevelResponse,
+                DlqEntryResponse,
+                DlqListResponse,
+                DlqStatsResponse,
+                DlqReplayResponse,
+                DlqPurgeResponse,
+                PendingApprovalResponse,
+                PendingApprovalsResponse,
+                ApprovalDecisionResponse,
+                AgentSpec,
+                AgentType,
+                AgentLimits,
+                SpecViolation
             )
         ),
         tags(
@@ -87,5 +164,60 @@ pub async fn openapi_spec() -> Json<utoipa::openapi::OpenApi> {
     )]
     struct ApiDoc;
 
-    Json(ApiDoc::openapi())
+    ApiDoc::openapi()
+}
+
+/// Create the rate limiter shared by the docs endpoints
+pub fn create_docs_rate_limiter(rpm: NonZeroU32) -> Arc<GlobalRateLimiter> {
+    Arc::new(RateLimiter::direct(Quota::per_minute(rpm)))
+}
+
+/// Middleware that throttles the docs endpoints with their own limiter
+pub async fn docs_rate_limit_middleware(
+    State(limiter): State<Arc<GlobalRateLimiter>>,
+    request: Request,
+    next: Next,
+) -> Response {
+    if let Err(not_until) = limiter.check() {
+        if let Some(registry) = skreaver_observability::get_metrics_registry() {
+            registry
+                .core_metrics()
+                .security_rate_limit_exceeded_total
+                .with_label_values(&["docs"])
+                .inc();
+        }
+
+        let retry_after = not_until
+            .wait_time_from(DefaultClock::default().now())
+            .as_secs()
+            .max(1);
+        let error = RateLimitError {
+            error: "docs_rate_limit_exceeded".to_string(),
+            message: "Documentation rate limit exceeded. Please try again later.".to_string(),
+            retry_after,
+        };
+
+        return (
+            StatusCode::TOO_MANY_REQUESTS,
+            [(header::RETRY_AFTER, retry_after.to_string())],
+            Json(error),
+        )
+            .into_response();
+    }
+
+    next.run(request).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_openapi_spec_is_cached() {
+        assert!(std::ptr::eq(openapi_document(), openapi_document()));
+        assert!(std::ptr::eq(openapi_spec_json(), openapi_spec_json()));
+
+        let spec: serde_json::Value = serde_json::from_str(openapi_spec_json()).unwrap();
+        assert_eq!(spec["info"]["title"], "Skreaver HTTP Runtime API");
+    }
 }
@@ -13,6 +13,7 @@ use axum::{
     response::{IntoResponse, Json, Response},
 };
 use serde::{Deserialize, Serialize};
+use skreaver_core::auth::AuthzDenial;
 use std::collections::HashMap;
 
 // Re-export unified RequestId from skreaver-core
@@ -299,6 +300,9 @@ pub enum RuntimeErrorKind {
         provided: Vec<String>,
     },
 
+    /// Tool authorization denied
+    ToolAccessDenied { denial: AuthzDenial },
+
     /// Token creation failed
     TokenCreationFailed { reason: String },
 
@@ -442,6 +446,11 @@ impl RuntimeError {
         )
     }
 
+    /// Create a ToolAccessDenied error from a structured authorization denial
+    pub fn tool_access_denied(denial: AuthzDenial, request_id: RequestId) -> Self {
+        Self::new(RuntimeErrorKind::ToolAccessDenied { denial }, request_id)
+    }
+
     /// Create a TokenCreationFailed error
     pub fn token_creation_failed(reason: impl Into<String>, request_id: RequestId) -> Self {
         Self::new(
@@ -606,6 +615,7 @@ impl RuntimeError {
             RuntimeErrorKind::AuthenticationRequired => StatusCode::UNAUTHORIZED,
             RuntimeErrorKind::InvalidAuthentication { .. } => StatusCode::UNAUTHORIZED,
             RuntimeErrorKind::InsufficientPermissions { .. } => StatusCode::FORBIDDEN,
+            RuntimeErrorKind::ToolAccessDenied { .. } => StatusCode::FORBIDDEN,
             RuntimeErrorKind::TokenCreationFailed { .. } => StatusCode::INTERNAL_SERVER_ERROR,
             RuntimeErrorKind::RateLimitExceeded { .. } => StatusCode::TOO_MANY_REQUESTS,
             RuntimeErrorKind::InvalidInput { .. } => StatusCode::BAD_REQUEST,
@@ -631,7 +641,8 @@ impl RuntimeError {
             RuntimeErrorKind::AgentOperationFailed { .. } => ErrorCode::AgentOperationFailed,
             RuntimeErrorKind::AuthenticationRequired => ErrorCode::AuthenticationRequired,
             RuntimeErrorKind::InvalidAuthentication { .. } => ErrorCode::InvalidAuthentication,
-            RuntimeErrorKind::InsufficientPermissions { .. } => ErrorCode::InsufficientPermissions,
+            RuntimeErrorKind::InsufficientPermissions { .. }
+            | RuntimeErrorKind::ToolAccessDenied { .. } => ErrorCode::InsufficientPermissions,
             RuntimeErrorKind::TokenCreationFailed { .. } => ErrorCode::TokenCreationFailed,
             RuntimeErrorKind::RateLimitExceeded { .. } => ErrorCode::RateLimitExceeded,
             RuntimeErrorKind::InvalidInput { .. } => ErrorCode::InvalidInput,
@@ -710,6 +721,15 @@ impl RuntimeError {
                     "required_permissions": required
                 }));
             }
+            RuntimeErrorKind::ToolAccessDenied { denial } => {
+                // The denial names the failed check and what would satisfy it;
+                // it never includes the caller's own roles
+                let mut details = serde_json::to_value(denial).unwrap_or_default();
+                if let Some(details) = details.as_object_mut() {
+                    details.insert("remedy".to_string(), denial.remedy().into());
+                }
+                response = response.with_details(details);
+            }
 
             // Rate limiting: Safe to expose limits (helps clients implement backoff)
             RuntimeErrorKind::RateLimitExceeded {
@@ -775,6 +795,7 @@ impl RuntimeError {
             RuntimeErrorKind::InsufficientPermissions { .. } => {
                 "You don't have permission to perform this action.".to_string()
             }
+            RuntimeErrorKind::ToolAccessDenied { denial } => denial.message(),
             RuntimeErrorKind::TokenCreationFailed { .. } => {
                 "Failed to create authentication token.".to_string()
             }
@@ -836,6 +857,7 @@ impl std::fmt::Display for RuntimeError {
             RuntimeErrorKind::InsufficientPermissions { required, .. } => {
                 write!(f, "Insufficient permissions: required {:?}", required)
             }
+            RuntimeErrorKind::ToolAccessDenied { denial } => write!(f, "{}", denial),
             RuntimeErrorKind::TokenCreationFailed { reason } => {
                 write!(f, "Token creation failed: {}", reason)
             }
@@ -1081,4 +1103,26 @@ mod tests {
         assert_eq!(format!("{}", code), "rate_limit_exceeded");
         assert_eq!(code.to_string(), "rate_limit_exceeded");
     }
+
+    #[test]
+    fn test_tool_access_denied_reports_reason_in_forbidden_body() {
+        use skreaver_core::auth::{DenialReason, Role};
+
+        let denial = AuthzDenial::new(
+            "shell_exec",
+            DenialReason::MissingRole {
+                required_any: vec![Role::Admin],
+            },
+        );
+        let error = RuntimeError::tool_access_denied(denial, RequestId::generate());
+        assert_eq!(error.status_code(), StatusCode::FORBIDDEN);
+
+        let response = error.to_error_response();
+        assert_eq!(response.error, "insufficient_permissions");
+        let details = response.details.expect("denial details");
+        assert_eq!(details["tool"], "shell_exec");
+        assert_eq!(details["reason"], "missing_role");
+        assert_eq!(details["required_any"], serde_json::json!(["Admin"]));
+        assert!(details["remedy"].as_str().unwrap().contains("admin"));
+    }
 }
@@ -10,6 +10,7 @@ use axum::{
 use futures::Stream;
 use serde::{Deserialize, Serialize};
 use skreaver_a2a::{Message, Task, TaskStatus};
+use skreaver_observability::{StreamGuard, StreamTransport, get_metrics_registry};
 use skreaver_tools::ToolRegistry;
 use std::convert::Infallible;
 use std::time::Duration;
@@ -204,8 +205,10 @@ impl Default for A2aEventBroadcaster {
 fn create_event_stream(
     receiver: broadcast::Receiver<A2aEvent>,
     task_filter: Option<String>,
+    guard: Option<StreamGuard>,
 ) -> impl Stream<Item = Result<Event, Infallible>> {
-    futures::stream::unfold((receiver, task_filter), |(mut rx, filter)| async move {
+    let state = (receiver, task_filter, guard);
+    futures::stream::unfold(state, |(mut rx, filter, guard)| async move {
         loop {
             match rx.recv().await {
                 Ok(event) => {
@@ -220,13 +223,16 @@ fn create_event_stream(
                     let event_type = event.event_type();
                     let json_data =
                         serde_json::to_string(&event).unwrap_or_else(|_| "{}".to_string());
+                    if let Some(guard) = &guard {
+                        guard.record_frame(json_data.len());
+                    }
 
                     let sse_event = Event::default()
                         .event(event_type)
                         .data(json_data)
                         .id(uuid::Uuid::new_v4().to_string());
 
-                    return Some((Ok(sse_event), (rx, filter)));
+                    return Some((Ok(sse_event), (rx, filter, guard)));
                 }
                 Err(broadcast::error::RecvError::Lagged(_)) => {
                     // Lagged, try again
@@ -258,7 +264,8 @@ pub async fn events_stream<T: ToolRegistry + Clone + Send + Sync + 'static>(
     Query(params): Query<EventsQuery>,
 ) -> Sse<impl Stream<Item = Result<Event, Infallible>>> {
     let receiver = state.event_broadcaster.subscribe();
-    let stream = create_event_stream(receiver, params.task_id);
+    let guard = get_metrics_registry().map(|registry| registry.track_stream(StreamTransport::Sse));
+    let stream = create_event_stream(receiver, params.task_id, guard);
 
     Sse::new(stream).keep_alive(
         KeepAlive::new()
@@ -19,6 +19,7 @@ use tokio::sync::RwLock;
 
 use super::A2aState;
 use super::errors::{A2aApiError, A2aApiResult};
+use crate::runtime::backpressure::RequestPriority;
 
 /// In-memory task storage for A2A tasks
 ///
@@ -315,28 +316,16 @@ async fn process_task<T: ToolRegistry + Clone + Send + Sync + 'static>(
     // Use the first available agent
     let agents = runtime.agents.read().await;
 
-    if let Some((agent_id, _instance)) = agents.iter().next() {
-        let agent_id = agent_id.clone();
+    if let Some(instance) = agents.values().next().cloned() {
         drop(agents); // Release the read lock
 
-        // Process through the agent's coordinator (requires write lock for mutable access)
-        let response = {
-            let mut agents = runtime.agents.write().await;
-            if let Some(instance) = agents.get_mut(&agent_id) {
-                Some(instance.coordinator.step(input))
-            } else {
-                None
-            }
-        };
+        // Process through the agent's coordinator
+        let response = instance.run_step(input, RequestPriority::Normal).await;
 
-        if let Some(response) = response {
-            // Update task with agent response
-            let agent_message = Message::agent(&response);
-            task.add_message(agent_message);
-            task.set_status(TaskStatus::Completed);
-        } else {
-            task.set_status(TaskStatus::Failed);
-        }
+        // Update task with agent response
+        let agent_message = Message::agent(&response);
+        task.add_message(agent_message);
+        task.set_status(TaskStatus::Completed);
     } else {
         // No agents available - mark as failed
         tracing::warn!(task_id = %task_id, "No agents available to process task");
@@ -3,24 +3,34 @@
 //! This module provides CRUD operations for managing agents through HTTP endpoints.
 
 use axum::{
+    Extension,
     extract::{Path, State},
     http::StatusCode,
-    response::Json,
+    response::{IntoResponse, Json, Response},
 };
 use skreaver_tools::ToolRegistry;
 
 use crate::runtime::{
     AgentFactoryError, HttpAgentRuntime,
     api_types::CreateAgentRequest,
-    types::{AgentStatus, AgentsListResponse, CreateAgentResponse, ErrorResponse},
+    api_version::ApiVersion,
+    auth::AuthContext,
+    types::{
+        AgentStatus, AgentsListResponse, AgentsListResponseV2, CollectionMeta, CreateAgentResponse,
+        ErrorResponse,
+    },
 };
 
 /// GET /agents - List all agents
+///
+/// API v1 returns [`AgentsListResponse`]; v2 returns the
+/// [`AgentsListResponseV2`] envelope.
 #[utoipa::path(
     get,
     path = "/agents",
     responses(
-        (status = 200, description = "List of all agents", body = AgentsListResponse),
+        (status = 200, description = "List of all agents (v1 shape; v2 returns AgentsListResponseV2)", body = AgentsListResponse),
+        (status = 406, description = "Unsupported API version", body = ErrorResponse),
         (status = 401, description = "Authentication required", body = crate::runtime::auth::AuthError)
     ),
     security(
@@ -30,7 +40,8 @@ use crate::runtime::{
 )]
 pub async fn list_agents<T: ToolRegistry + Clone + Send + Sync + 'static>(
     State(runtime): State<HttpAgentRuntime<T>>,
-) -> Result<Json<AgentsListResponse>, (StatusCode, Json<ErrorResponse>)> {
+    version: ApiVersion,
+) -> Result<Response, (StatusCode, Json<ErrorResponse>)> {
     let agents = runtime.agents.read().await;
 
     // Collect agent statuses with actual creation time and last activity
@@ -38,17 +49,26 @@ pub async fn list_agents<T: ToolRegistry + Clone + Send + Sync + 'static>(
     for (id, instance) in agents.iter() {
         agent_statuses.push(AgentStatus {
             agent_id: id.to_string(),
-            agent_type: instance.coordinator.get_agent_type().to_string(),
+            agent_type: instance.coordinator_type().to_string(),
             status: crate::runtime::agent_status::AgentStatusEnum::Ready,
             created_at: instance.created_at,
             last_activity: Some(instance.last_activity().await),
         });
     }
 
-    Ok(Json(AgentsListResponse {
-        total: agent_statuses.len(),
-        agents: agent_statuses,
-    }))
+    let total = agent_statuses.len();
+    Ok(match version {
+        ApiVersion::V1 => Json(AgentsListResponse {
+            total,
+            agents: agent_statuses,
+        })
+        .into_response(),
+        ApiVersion::V2 => Json(AgentsListResponseV2 {
+            data: agent_statuses,
+            meta: CollectionMeta { total },
+        })
+        .into_response(),
+    })
 }
 
 /// POST /agents - Create a new agent
@@ -60,6 +80,8 @@ pub async fn list_agents<T: ToolRegistry + Clone + Send + Sync + 'static>(
         (status = 201, description = "Agent created successfully", body = CreateAgentResponse),
         (status = 400, description = "Invalid request", body = ErrorResponse),
         (status = 401, description = "Authentication required", body = crate::runtime::auth::AuthError),
+        (status = 409, description = "Maximum number of agents reached, or an agent with the natural key already exists", body = ErrorResponse),
+        (status = 429, description = "Agent creation rate limit exceeded", body = ErrorResponse),
         (status = 500, description = "Agent creation failed", body = ErrorResponse)
     ),
     security(
@@ -69,9 +91,29 @@ pub async fn list_agents<T: ToolRegistry + Clone + Send + Sync + 'static>(
 )]
 pub async fn create_agent<T: ToolRegistry + Clone + Send + Sync + 'static>(
     State(runtime): State<HttpAgentRuntime<T>>,
+    auth: Option<Extension<AuthContext>>,
     Json(request): Json<CreateAgentRequest>,
 ) -> Result<Json<CreateAgentResponse>, (StatusCode, Json<ErrorResponse>)> {
-    match runtime.create_agent(request.spec, None).await {
+    let supported_types = runtime.supported_agent_types();
+    let principal = auth.map(|Extension(ctx)| ctx.user_id);
+    let created = match request.natural_key {
+        Some(natural_key) => {
+            runtime
+                .create_agent_with_key(
+                    request.spec,
+                    &natural_key,
+                    principal.as_deref(),
+                    request.on_conflict,
+                )
+                .await
+        }
+        None => {
+            runtime
+                .create_agent_as(request.spec, None, principal.as_deref())
+                .await
+        }
+    };
+    match created {
         Ok(response) => {
             // Convert the factory response to the HTTP response format
             Ok(Json(CreateAgentResponse {
@@ -80,12 +122,27 @@ pub async fn create_agent<T: ToolRegistry + Clone + Send + Sync + 'static>(
                 status: response.status,
             }))
         }
-        Err(AgentFactoryError::UnknownAgentType(agent_type)) => Err((
+        Err(AgentFactoryError::UnknownAgentType(agent_type)) => {
+            let supported: Vec<String> = supported_types.iter().map(|t| t.to_string()).collect();
+            Err((
+                StatusCode::BAD_REQUEST,
+                Json(ErrorResponse {
+                    error: "unknown_agent_type".to_string(),
+                    message: format!(
+                        "Unknown agent type: {} (supported: {})",
+                        agent_type,
+                        supported.join(", ")
+                    ),
+                    details: Some(serde_json::json!({ "supported_types": supported })),
+                }),
+            ))
+        }
+        Err(AgentFactoryError::InvalidSpec(err)) => Err((
             StatusCode::BAD_REQUEST,
             Json(ErrorResponse {
-                error: "unknown_agent_type".to_string(),
-                message: format!("Unknown agent type: {}", agent_type),
-                details: None,
+                error: "invalid_agent_spec".to_string(),
+                message: err.to_string(),
+                details: Some(serde_json::json!({ "violations": err.violations })),
             }),
         )),
         Err(AgentFactoryError::InvalidConfiguration { field, reason }) => Err((
@@ -96,6 +153,34 @@ pub async fn create_agent<T: ToolRegistry + Clone + Send + Sync + 'static>(
                 details: None,
             }),
         )),
+        Err(e @ AgentFactoryError::AgentAlreadyExists(_)) => Err((
+            StatusCode::CONFLICT,
+            Json(ErrorResponse {
+                error: "agent_already_exists".to_string(),
+                message: e.to_string(),
+                details: None,
+            }),
+        )),
+        Err(e @ AgentFactoryError::AgentLimitReached { max_agents }) => Err((
+            StatusCode::CONFLICT,
+            Json(ErrorResponse {
+                error: "agent_limit_reached".to_string(),
+                message: e.to_string(),
+                details: Some(serde_json::json!({ "max_agents": max_agents })),
+            }),
+        )),
+        Err(
+            e @ AgentFactoryError::CreationRateLimited {
+                retry_after_secs, ..
+            },
+        ) => Err((
+            StatusCode::TOO_MANY_REQUESTS,
+            Json(ErrorResponse {
+                error: "agent_creation_rate_limited".to_string(),
+                message: e.to_string(),
+                details: Some(serde_json::json!({ "retry_after": retry_after_secs })),
+            }),
+        )),
         Err(AgentFactoryError::CreationFailed { agent_type, reason }) => Err((
             StatusCode::INTERNAL_SERVER_ERROR,
             Json(ErrorResponse {
@@ -157,7 +242,7 @@ pub async fn get_agent_status<T: ToolRegistry + Clone + Send + Sync + 'static>(
             let last_activity = instance.last_activity().await;
             Ok(Json(AgentStatus {
                 agent_id, // No need to clone, we own it
-                agent_type: instance.coordinator.get_agent_type().to_string(),
+                agent_type: instance.coordinator_type().to_string(),
                 status: crate::runtime::agent_status::AgentStatusEnum::Ready,
                 created_at: instance.created_at,
                 last_activity: Some(last_activity),
@@ -0,0 +1,135 @@
+//! Tool call approval HTTP handlers
+//!
+//! This module provides admin endpoints for deciding on tool calls held by
+//! the runtime's [`ApprovalGate`]. The routes are only mounted when the
+//! runtime is configured with a gate.
+
+use axum::{
+    Router,
+    extract::{Path, State},
+    http::StatusCode,
+    response::Json,
+    routing::{get, post},
+};
+
+use crate::runtime::approval::{ApprovalDecision, ApprovalGate, PendingAction};
+use crate::runtime::types::{
+    ApprovalDecisionResponse, ErrorResponse, PendingApprovalResponse, PendingApprovalsResponse,
+};
+
+type HandlerError = (StatusCode, Json<ErrorResponse>);
+
+/// Routes for listing and deciding on pending tool calls
+///
+/// The caller is responsible for gating them behind the admin permission.
+pub fn approvals_router<S>(gate: ApprovalGate) -> Router<S> {
+    Router::new()
+        .route("/approvals", get(list_pending_approvals))
+        .route("/approvals/{action_id}/approve", post(approve_action))
+        .route("/approvals/{action_id}/deny", post(deny_action))
+        .with_state(gate)
+}
+
+/// GET /approvals - List tool calls waiting for a decision
+#[utoipa::path(
+    get,
+    path = "/approvals",
+    responses(
+        (status = 200, description = "Pending tool calls, oldest first", body = PendingApprovalsResponse),
+        (status = 401, description = "Authentication required", body = crate::runtime::auth::AuthError),
+        (status = 403, description = "Admin permission required", body = crate::runtime::auth::AuthError)
+    ),
+    security(
+        ("api_key" = []),
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn list_pending_approvals(
+    State(gate): State<ApprovalGate>,
+) -> Json<PendingApprovalsResponse> {
+    Json(PendingApprovalsResponse {
+        pending: gate.pending().into_iter().map(pending_response).collect(),
+    })
+}
+
+/// POST /approvals/{action_id}/approve - Let a held tool call run
+#[utoipa::path(
+    post,
+    path = "/approvals/{action_id}/approve",
+    params(
+        ("action_id" = String, Path, description = "Pending action identifier")
+    ),
+    responses(
+        (status = 200, description = "Tool call approved", body = ApprovalDecisionResponse),
+        (status = 404, description = "No pending action with this id", body = ErrorResponse),
+        (status = 401, description = "Authentication required", body = crate::runtime::auth::AuthError),
+        (status = 403, description = "Admin permission required", body = crate::runtime::auth::AuthError)
+    ),
+    security(
+        ("api_key" = []),
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn approve_action(
+    State(gate): State<ApprovalGate>,
+    Path(action_id): Path<String>,
+) -> Result<Json<ApprovalDecisionResponse>, HandlerError> {
+    decide(&gate, action_id, ApprovalDecision::Approve)
+}
+
+/// POST /approvals/{action_id}/deny - Skip a held tool call
+#[utoipa::path(
+    post,
+    path = "/approvals/{action_id}/deny",
+    params(
+        ("action_id" = String, Path, description = "Pending action identifier")
+    ),
+    responses(
+        (status = 200, description = "Tool call denied", body = ApprovalDecisionResponse),
+        (status = 404, description = "No pending action with this id", body = ErrorResponse),
+        (status = 401, description = "Authentication required", body = crate::runtime::auth::AuthError),
+        (status = 403, description = "Admin permission required", body = crate::runtime::auth::AuthError)
+    ),
+    security(
+        ("api_key" = []),
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn deny_action(
+    State(gate): State<ApprovalGate>,
+    Path(action_id): Path<String>,
+) -> Result<Json<ApprovalDecisionResponse>, HandlerError> {
+    decide(&gate, action_id, ApprovalDecision::Deny)
+}
+
+fn decide(
+    gate: &ApprovalGate,
+    action_id: String,
+    decision: ApprovalDecision,
+) -> Result<Json<ApprovalDecisionResponse>, HandlerError> {
+    gate.decide(&action_id, decision).map_err(|e| {
+        (
+            StatusCode::NOT_FOUND,
+            Json(ErrorResponse {
+                error: "approval_not_found".to_string(),
+                message: e.to_string(),
+                details: None,
+            }),
+        )
+    })?;
+    tracing::info!(action_id = %action_id, decision = ?decision, "Tool call decided");
+    Ok(Json(ApprovalDecisionResponse {
+        action_id,
+        approved: decision == ApprovalDecision::Approve,
+        decided_at: chrono::Utc::now(),
+    }))
+}
+
+fn pending_response(action: PendingAction) -> PendingApprovalResponse {
+    PendingApprovalResponse {
+        action_id: action.id,
+        tool_name: action.tool_name,
+        input: action.input,
+        requested_at: action.requested_at,
+    }
+}
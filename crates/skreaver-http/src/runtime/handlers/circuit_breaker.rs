@@ -0,0 +1,146 @@
+//! Agent circuit breaker HTTP handlers
+//!
+//! This module provides admin endpoints to inspect and manually trip or reset
+//! an agent's circuit breaker in the backpressure layer.
+
+use axum::{
+    Extension,
+    extract::{Path, State},
+    http::StatusCode,
+    response::Json,
+};
+use skreaver_tools::ToolRegistry;
+
+use crate::runtime::{
+    HttpAgentRuntime,
+    auth::AuthContext,
+    backpressure::{CircuitBreakerStatus, CircuitState},
+    types::{CircuitBreakerRequest, CircuitBreakerResponse, ErrorResponse},
+};
+
+type HandlerError = (StatusCode, Json<ErrorResponse>);
+
+/// GET /agents/{agent_id}/circuit - Get an agent's circuit breaker state
+#[utoipa::path(
+    get,
+    path = "/agents/{agent_id}/circuit",
+    params(
+        ("agent_id" = String, Path, description = "Agent identifier")
+    ),
+    responses(
+        (status = 200, description = "Circuit breaker state", body = CircuitBreakerResponse),
+        (status = 404, description = "Agent not found", body = ErrorResponse),
+        (status = 401, description = "Authentication required", body = crate::runtime::auth::AuthError),
+        (status = 403, description = "Admin permission required", body = crate::runtime::auth::AuthError)
+    ),
+    security(
+        ("api_key" = []),
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn get_circuit_breaker<T: ToolRegistry + Clone + Send + Sync>(
+    State(runtime): State<HttpAgentRuntime<T>>,
+    Path(agent_id): Path<String>,
+) -> Result<Json<CircuitBreakerResponse>, HandlerError> {
+    ensure_agent_exists(&runtime, &agent_id).await?;
+
+    let status = runtime
+        .backpressure_manager
+        .get_circuit_status(&agent_id)
+        .await;
+    Ok(Json(status.into()))
+}
+
+/// POST /agents/{agent_id}/circuit/{action} - Open, close or half-open an agent's circuit breaker
+#[utoipa::path(
+    post,
+    path = "/agents/{agent_id}/circuit/{action}",
+    params(
+        ("agent_id" = String, Path, description = "Agent identifier"),
+        ("action" = String, Path, description = "One of `open`, `close` or `half-open`")
+    ),
+    request_body(content = Option<CircuitBreakerRequest>, description = "Optional reason for the change"),
+    responses(
+        (status = 200, description = "Updated circuit breaker state", body = CircuitBreakerResponse),
+        (status = 400, description = "Unknown action", body = ErrorResponse),
+        (status = 404, description = "Agent not found", body = ErrorResponse),
+        (status = 401, description = "Authentication required", body = crate::runtime::auth::AuthError),
+        (status = 403, description = "Admin permission required", body = crate::runtime::auth::AuthError)
+    ),
+    security(
+        ("api_key" = []),
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn set_circuit_breaker<T: ToolRegistry + Clone + Send + Sync>(
+    State(runtime): State<HttpAgentRuntime<T>>,
+    Path((agent_id, action)): Path<(String, String)>,
+    auth: Option<Extension<AuthContext>>,
+    body: Option<Json<CircuitBreakerRequest>>,
+) -> Result<Json<CircuitBreakerResponse>, HandlerError> {
+    let state = CircuitState::parse(&action).ok_or_else(|| {
+        (
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse {
+                error: "invalid_circuit_action".to_string(),
+                message: format!(
+                    "Unknown circuit breaker action '{}', expected open, close or half-open",
+                    action
+                ),
+                details: None,
+            }),
+        )
+    })?;
+
+    ensure_agent_exists(&runtime, &agent_id).await?;
+
+    let changed_by = auth.map(|Extension(ctx)| ctx.user_id);
+    let reason = body.and_then(|Json(request)| request.reason);
+    let status = runtime
+        .backpressure_manager
+        .set_circuit_state(&agent_id, state, changed_by, reason)
+        .await;
+    Ok(Json(status.into()))
+}
+
+async fn ensure_agent_exists<T: ToolRegistry + Clone + Send + Sync>(
+    runtime: &HttpAgentRuntime<T>,
+    agent_id: &str,
+) -> Result<(), HandlerError> {
+    let parsed_id = skreaver_core::AgentId::parse(agent_id).map_err(|e| {
+        (
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse {
+                error: "invalid_agent_id".to_string(),
+                message: format!("Invalid agent ID: {}", e),
+                details: None,
+            }),
+        )
+    })?;
+
+    if !runtime.agents.read().await.contains_key(&parsed_id) {
+        return Err((
+            StatusCode::NOT_FOUND,
+            Json(ErrorResponse {
+                error: "agent_not_found".to_string(),
+                message: format!("Agent with ID '{}' not found", agent_id),
+                details: None,
+            }),
+        ));
+    }
+
+    Ok(())
+}
+
+impl From<CircuitBreakerStatus> for CircuitBreakerResponse {
+    fn from(status: CircuitBreakerStatus) -> Self {
+        Self {
+            agent_id: status.agent_id,
+            state: status.state.to_string(),
+            reason: status.reason,
+            changed_by: status.changed_by,
+            changed_at: status.changed_at,
+            total_rejections: status.total_rejections,
+        }
+    }
+}
@@ -0,0 +1,336 @@
+//! Mesh dead letter queue HTTP handlers
+//!
+//! This module provides admin endpoints for operators to inspect, replay and
+//! purge messages that failed delivery on the agent mesh. The routes are only
+//! mounted when a [`DlqAdmin`] is configured on the runtime.
+
+use axum::{
+    Router,
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::Json,
+    routing::{get, post},
+};
+use skreaver_mesh::{AgentMesh, DeadLetterQueue, DlqEntry, DlqQuery, DlqStats};
+use std::fmt;
+use std::sync::Arc;
+
+use crate::runtime::types::{
+    DlqEntryResponse, DlqListResponse, DlqPurgeResponse, DlqQueryParams, DlqReplayResponse,
+    DlqStatsResponse, ErrorResponse,
+};
+
+type HandlerError = (StatusCode, Json<ErrorResponse>);
+
+/// Page size used when a listing does not ask for one
+const DEFAULT_PAGE_SIZE: usize = 50;
+
+/// Largest page a listing may ask for
+const MAX_PAGE_SIZE: usize = 500;
+
+/// Dead letter queue exposed to operators, and the mesh replays are sent on
+#[derive(Clone)]
+pub struct DlqAdmin {
+    dlq: Arc<DeadLetterQueue>,
+    mesh: Arc<dyn AgentMesh>,
+}
+
+impl DlqAdmin {
+    /// Expose `dlq` to operators, replaying its messages on `mesh`
+    pub fn new(dlq: Arc<DeadLetterQueue>, mesh: Arc<dyn AgentMesh>) -> Self {
+        Self { dlq, mesh }
+    }
+
+    /// The dead letter queue being managed
+    pub fn dlq(&self) -> &Arc<DeadLetterQueue> {
+        &self.dlq
+    }
+}
+
+impl fmt::Debug for DlqAdmin {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DlqAdmin")
+            .field("dlq_enabled", &self.dlq.is_enabled())
+            .finish_non_exhaustive()
+    }
+}
+
+/// Routes for managing the dead letter queue
+///
+/// The caller is responsible for gating them behind the admin permission.
+pub fn dlq_router<S>(admin: DlqAdmin) -> Router<S> {
+    Router::new()
+        .route("/dlq", get(list_dlq_entries).delete(purge_dlq_entries))
+        .route("/dlq/stats", get(get_dlq_stats))
+        .route(
+            "/dlq/{message_id}",
+            get(get_dlq_entry).delete(delete_dlq_entry),
+        )
+        .route("/dlq/{message_id}/replay", post(replay_dlq_entry))
+        .with_state(admin)
+}
+
+/// GET /dlq - List dead-lettered messages
+#[utoipa::path(
+    get,
+    path = "/dlq",
+    params(
+        ("reason" = Option<String>, Query, description = "Only entries whose failure reason contains this text"),
+        ("min_age_secs" = Option<u64>, Query, description = "Only entries at least this many seconds old"),
+        ("max_age_secs" = Option<u64>, Query, description = "Only entries at most this many seconds old"),
+        ("offset" = Option<usize>, Query, description = "Number of matching entries to skip"),
+        ("limit" = Option<usize>, Query, description = "Page size (default 50, max 500)")
+    ),
+    responses(
+        (status = 200, description = "Page of DLQ entries, oldest first", body = DlqListResponse),
+        (status = 401, description = "Authentication required", body = crate::runtime::auth::AuthError),
+        (status = 403, description = "Admin permission required", body = crate::runtime::auth::AuthError)
+    ),
+    security(
+        ("api_key" = []),
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn list_dlq_entries(
+    State(admin): State<DlqAdmin>,
+    Query(params): Query<DlqQueryParams>,
+) -> Json<DlqListResponse> {
+    let limit = params.limit.unwrap_or(DEFAULT_PAGE_SIZE).min(MAX_PAGE_SIZE);
+    let query = DlqQuery {
+        offset: params.offset,
+        limit: Some(limit),
+        ..dlq_query(&params)
+    };
+
+    let page = admin.dlq.query(&query).await;
+    Json(DlqListResponse {
+        entries: page.entries.into_iter().map(entry_response).collect(),
+        total: page.total,
+        offset: params.offset,
+        limit,
+    })
+}
+
+/// GET /dlq/stats - Get dead letter queue size and age
+#[utoipa::path(
+    get,
+    path = "/dlq/stats",
+    responses(
+        (status = 200, description = "DLQ statistics", body = DlqStatsResponse),
+        (status = 401, description = "Authentication required", body = crate::runtime::auth::AuthError),
+        (status = 403, description = "Admin permission required", body = crate::runtime::auth::AuthError)
+    ),
+    security(
+        ("api_key" = []),
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn get_dlq_stats(State(admin): State<DlqAdmin>) -> Json<DlqStatsResponse> {
+    Json(stats_response(admin.dlq.stats().await))
+}
+
+/// GET /dlq/{message_id} - Peek at a dead-lettered message
+#[utoipa::path(
+    get,
+    path = "/dlq/{message_id}",
+    params(
+        ("message_id" = String, Path, description = "Message identifier")
+    ),
+    responses(
+        (status = 200, description = "DLQ entry", body = DlqEntryResponse),
+        (status = 404, description = "Message not in the DLQ", body = ErrorResponse),
+        (status = 401, description = "Authentication required", body = crate::runtime::auth::AuthError),
+        (status = 403, description = "Admin permission required", body = crate::runtime::auth::AuthError)
+    ),
+    security(
+        ("api_key" = []),
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn get_dlq_entry(
+    State(admin): State<DlqAdmin>,
+    Path(message_id): Path<String>,
+) -> Result<Json<DlqEntryResponse>, HandlerError> {
+    admin
+        .dlq
+        .peek(&message_id)
+        .await
+        .map(|entry| Json(entry_response(entry)))
+        .ok_or_else(|| entry_not_found(&message_id))
+}
+
+/// POST /dlq/{message_id}/replay - Re-send a dead-lettered message on the mesh
+#[utoipa::path(
+    post,
+    path = "/dlq/{message_id}/replay",
+    params(
+        ("message_id" = String, Path, description = "Message identifier")
+    ),
+    responses(
+        (status = 200, description = "Message re-sent and removed from the DLQ", body = DlqReplayResponse),
+        (status = 404, description = "Message not in the DLQ", body = ErrorResponse),
+        (status = 502, description = "Re-send failed; the message stays in the DLQ", body = ErrorResponse),
+        (status = 401, description = "Authentication required", body = crate::runtime::auth::AuthError),
+        (status = 403, description = "Admin permission required", body = crate::runtime::auth::AuthError)
+    ),
+    security(
+        ("api_key" = []),
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn replay_dlq_entry(
+    State(admin): State<DlqAdmin>,
+    Path(message_id): Path<String>,
+) -> Result<Json<DlqReplayResponse>, HandlerError> {
+    match admin.dlq.replay(&message_id, admin.mesh.as_ref()).await {
+        Ok(true) => {
+            tracing::info!(message_id = %message_id, "Replayed message from DLQ");
+            Ok(Json(DlqReplayResponse {
+                message_id,
+                replayed_at: chrono::Utc::now(),
+            }))
+        }
+        Ok(false) => Err(entry_not_found(&message_id)),
+        Err(e) => {
+            tracing::warn!(message_id = %message_id, error = %e, "DLQ replay failed");
+            Err((
+                StatusCode::BAD_GATEWAY,
+                Json(ErrorResponse {
+                    error: "dlq_replay_failed".to_string(),
+                    message: format!("Failed to replay message '{}': {}", message_id, e),
+                    details: None,
+                }),
+            ))
+        }
+    }
+}
+
+/// DELETE /dlq/{message_id} - Remove a single dead-lettered message
+#[utoipa::path(
+    delete,
+    path = "/dlq/{message_id}",
+    params(
+        ("message_id" = String, Path, description = "Message identifier")
+    ),
+    responses(
+        (status = 200, description = "Message removed", body = DlqPurgeResponse),
+        (status = 404, description = "Message not in the DLQ", body = ErrorResponse),
+        (status = 401, description = "Authentication required", body = crate::runtime::auth::AuthError),
+        (status = 403, description = "Admin permission required", body = crate::runtime::auth::AuthError)
+    ),
+    security(
+        ("api_key" = []),
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn delete_dlq_entry(
+    State(admin): State<DlqAdmin>,
+    Path(message_id): Path<String>,
+) -> Result<Json<DlqPurgeResponse>, HandlerError> {
+    if admin.dlq.peek(&message_id).await.is_none() {
+        return Err(entry_not_found(&message_id));
+    }
+    admin.dlq.remove(&message_id).await.map_err(purge_failed)?;
+    tracing::info!(message_id = %message_id, "Removed message from DLQ");
+    Ok(Json(DlqPurgeResponse { purged: 1 }))
+}
+
+/// DELETE /dlq - Purge dead-lettered messages matching the filters
+#[utoipa::path(
+    delete,
+    path = "/dlq",
+    params(
+        ("reason" = Option<String>, Query, description = "Only entries whose failure reason contains this text"),
+        ("min_age_secs" = Option<u64>, Query, description = "Only entries at least this many seconds old"),
+        ("max_age_secs" = Option<u64>, Query, description = "Only entries at most this many seconds old")
+    ),
+    responses(
+        (status = 200, description = "Messages purged", body = DlqPurgeResponse),
+        (status = 401, description = "Authentication required", body = crate::runtime::auth::AuthError),
+        (status = 403, description = "Admin permission required", body = crate::runtime::auth::AuthError)
+    ),
+    security(
+        ("api_key" = []),
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn purge_dlq_entries(
+    State(admin): State<DlqAdmin>,
+    Query(params): Query<DlqQueryParams>,
+) -> Result<Json<DlqPurgeResponse>, HandlerError> {
+    let purged = admin
+        .dlq
+        .purge(&dlq_query(&params))
+        .await
+        .map_err(purge_failed)?;
+    tracing::info!(
+        purged,
+        reason = ?params.reason,
+        min_age_secs = ?params.min_age_secs,
+        max_age_secs = ?params.max_age_secs,
+        "Purged messages from DLQ"
+    );
+    Ok(Json(DlqPurgeResponse { purged }))
+}
+
+/// Reason and age filters from the query string, without paging
+fn dlq_query(params: &DlqQueryParams) -> DlqQuery {
+    let seconds = |secs: u64| chrono::Duration::seconds(i64::try_from(secs).unwrap_or(i64::MAX));
+    DlqQuery {
+        reason: params.reason.clone(),
+        min_age: params.min_age_secs.map(seconds),
+        max_age: params.max_age_secs.map(seconds),
+        ..Default::default()
+    }
+}
+
+fn age_secs(age: chrono::Duration) -> u64 {
+    age.num_seconds().max(0) as u64
+}
+
+fn entry_response(entry: DlqEntry) -> DlqEntryResponse {
+    DlqEntryResponse {
+        message_id: entry.message.id.to_string(),
+        age_secs: age_secs(entry.age()),
+        message: serde_json::to_value(&entry.message).unwrap_or_default(),
+        failure_reason: entry.failure_reason,
+        last_error: entry.last_error,
+        retry_count: entry.retry_count,
+        added_at: entry.added_at,
+        expires_at: entry.expires_at,
+    }
+}
+
+fn stats_response(stats: DlqStats) -> DlqStatsResponse {
+    DlqStatsResponse {
+        size: stats.current_size,
+        oldest_age_secs: stats.oldest_age.map(age_secs),
+        total_added: stats.total_added,
+        total_removed: stats.total_removed,
+        total_expired: stats.total_expired,
+        total_exhausted: stats.total_exhausted,
+        total_retried: stats.total_retried,
+    }
+}
+
+fn entry_not_found(message_id: &str) -> HandlerError {
+    (
+        StatusCode::NOT_FOUND,
+        Json(ErrorResponse {
+            error: "dlq_entry_not_found".to_string(),
+            message: format!("Message '{}' is not in the dead letter queue", message_id),
+            details: None,
+        }),
+    )
+}
+
+fn purge_failed(error: skreaver_mesh::MeshError) -> HandlerError {
+    (
+        StatusCode::INTERNAL_SERVER_ERROR,
+        Json(ErrorResponse {
+            error: "dlq_purge_failed".to_string(),
+            message: error.to_string(),
+            details: None,
+        }),
+    )
+}
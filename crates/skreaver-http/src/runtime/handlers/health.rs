@@ -3,7 +3,11 @@
 //! This module provides health check endpoints, readiness checks, and metrics
 //! collection endpoints for monitoring the HTTP runtime.
 
-use axum::{extract::State, http::StatusCode, response::Json};
+use axum::{
+    extract::State,
+    http::{StatusCode, header},
+    response::{IntoResponse, Json},
+};
 use skreaver_observability::health::{ComponentHealth, SystemHealth};
 use skreaver_observability::metrics::get_metrics_registry;
 use skreaver_tools::ToolRegistry;
@@ -131,6 +135,11 @@ pub async fn readiness_check<T: ToolRegistry + Clone + Send + Sync>(
     let system_health_comp = check_system_resources().await;
     components.insert("system_resources".to_string(), system_health_comp);
 
+    // Check registered critical dependencies; these override built-in
+    // components with the same name
+    let dependencies = runtime.dependency_health.read().await.check_all().await;
+    components.extend(dependencies.components);
+
     // Create system health response
     let mut system_health = SystemHealth::from_components(components);
     system_health.uptime_seconds = get_uptime_seconds();
@@ -299,25 +308,16 @@ async fn check_system_resources() -> ComponentHealth {
     get,
     path = "/metrics",
     responses(
-        (status = 200, description = "Prometheus metrics", content_type = "text/plain"),
-        (status = 500, description = "Metrics collection failed")
+        (status = 200, description = "Prometheus metrics", content_type = "text/plain; version=0.0.4"),
+        (status = 500, description = "Metrics registry not initialized")
     )
 )]
-pub async fn metrics_endpoint() -> Result<String, (StatusCode, String)> {
+pub async fn metrics_endpoint() -> Result<impl IntoResponse, (StatusCode, String)> {
     match get_metrics_registry() {
-        Some(registry) => {
-            let prometheus_registry = registry.prometheus_registry();
-            let encoder = prometheus::TextEncoder::new();
-            let metric_families = prometheus_registry.gather();
-
-            match encoder.encode_to_string(&metric_families) {
-                Ok(metrics) => Ok(metrics),
-                Err(e) => Err((
-                    StatusCode::INTERNAL_SERVER_ERROR,
-                    format!("Failed to encode metrics: {}", e),
-                )),
-            }
-        }
+        Some(registry) => Ok((
+            [(header::CONTENT_TYPE, prometheus::TEXT_FORMAT)],
+            registry.render_prometheus(),
+        )),
         None => Err((
             StatusCode::INTERNAL_SERVER_ERROR,
             "Metrics registry not initialized".to_string(),
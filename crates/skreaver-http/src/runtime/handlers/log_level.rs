@@ -0,0 +1,142 @@
+//! Agent log level HTTP handlers
+//!
+//! This module provides admin endpoints to raise or lower the log level of a
+//! single agent's steps at runtime, leaving every other agent at the global
+//! level.
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::Json,
+};
+use skreaver_tools::ToolRegistry;
+use std::str::FromStr;
+use tracing::Level;
+
+use crate::runtime::{
+    HttpAgentRuntime,
+    agent_factory::AgentFactoryError,
+    types::{AgentLogLevelRequest, AgentLogLevelResponse, ErrorResponse},
+};
+
+type HandlerError = (StatusCode, Json<ErrorResponse>);
+
+/// GET /agents/{agent_id}/log-level - Get an agent's log level override
+#[utoipa::path(
+    get,
+    path = "/agents/{agent_id}/log-level",
+    params(
+        ("agent_id" = String, Path, description = "Agent identifier")
+    ),
+    responses(
+        (status = 200, description = "Log level override", body = AgentLogLevelResponse),
+        (status = 404, description = "Agent not found", body = ErrorResponse),
+        (status = 401, description = "Authentication required", body = crate::runtime::auth::AuthError),
+        (status = 403, description = "Admin permission required", body = crate::runtime::auth::AuthError)
+    ),
+    security(
+        ("api_key" = []),
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn get_agent_log_level<T: ToolRegistry + Clone + Send + Sync + 'static>(
+    State(runtime): State<HttpAgentRuntime<T>>,
+    Path(agent_id): Path<String>,
+) -> Result<Json<AgentLogLevelResponse>, HandlerError> {
+    let level = runtime
+        .agent_log_level(&agent_id)
+        .await
+        .map_err(|e| factory_error(&agent_id, e))?;
+    Ok(Json(log_level_response(agent_id, level)))
+}
+
+/// PUT /agents/{agent_id}/log-level - Set or clear an agent's log level override
+#[utoipa::path(
+    put,
+    path = "/agents/{agent_id}/log-level",
+    params(
+        ("agent_id" = String, Path, description = "Agent identifier")
+    ),
+    request_body = AgentLogLevelRequest,
+    responses(
+        (status = 200, description = "Updated log level override", body = AgentLogLevelResponse),
+        (status = 400, description = "Unknown log level", body = ErrorResponse),
+        (status = 404, description = "Agent not found", body = ErrorResponse),
+        (status = 401, description = "Authentication required", body = crate::runtime::auth::AuthError),
+        (status = 403, description = "Admin permission required", body = crate::runtime::auth::AuthError)
+    ),
+    security(
+        ("api_key" = []),
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn set_agent_log_level<T: ToolRegistry + Clone + Send + Sync + 'static>(
+    State(runtime): State<HttpAgentRuntime<T>>,
+    Path(agent_id): Path<String>,
+    Json(request): Json<AgentLogLevelRequest>,
+) -> Result<Json<AgentLogLevelResponse>, HandlerError> {
+    let level = request
+        .level
+        .map(|level| {
+            Level::from_str(&level).map_err(|_| {
+                (
+                    StatusCode::BAD_REQUEST,
+                    Json(ErrorResponse {
+                        error: "invalid_log_level".to_string(),
+                        message: format!(
+                            "Unknown log level '{}', expected trace, debug, info, warn or error",
+                            level
+                        ),
+                        details: None,
+                    }),
+                )
+            })
+        })
+        .transpose()?;
+
+    runtime
+        .set_agent_log_level(&agent_id, level)
+        .await
+        .map_err(|e| factory_error(&agent_id, e))?;
+    tracing::info!(
+        agent_id = %agent_id,
+        level = ?level,
+        "Changed agent log level override"
+    );
+    Ok(Json(log_level_response(agent_id, level)))
+}
+
+fn log_level_response(agent_id: String, level: Option<Level>) -> AgentLogLevelResponse {
+    AgentLogLevelResponse {
+        agent_id,
+        level: level.map(|level| level.as_str().to_lowercase()),
+    }
+}
+
+fn factory_error(agent_id: &str, error: AgentFactoryError) -> HandlerError {
+    let (status, error, message) = match error {
+        AgentFactoryError::AgentNotFound(_) => (
+            StatusCode::NOT_FOUND,
+            "agent_not_found",
+            format!("Agent with ID '{}' not found", agent_id),
+        ),
+        AgentFactoryError::InvalidAgentId(e) => (
+            StatusCode::BAD_REQUEST,
+            "invalid_agent_id",
+            format!("Invalid agent ID: {}", e),
+        ),
+        e => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            "log_level_update_failed",
+            e.to_string(),
+        ),
+    };
+    (
+        status,
+        Json(ErrorResponse {
+            error: error.to_string(),
+            message,
+            details: None,
+        }),
+    )
+}
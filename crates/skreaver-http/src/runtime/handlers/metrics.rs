@@ -8,6 +8,7 @@ use axum::{
     response::Json,
 };
 use skreaver_tools::ToolRegistry;
+use std::collections::BTreeMap;
 
 use crate::runtime::{
     HttpAgentRuntime,
@@ -15,6 +16,15 @@ use crate::runtime::{
     types::{ErrorResponse, QueueMetricsResponse},
 };
 
+/// Longest queue waits keyed by priority name
+fn max_waits_by_name(metrics: &QueueMetrics) -> BTreeMap<String, u64> {
+    metrics
+        .max_wait_ms_by_priority
+        .iter()
+        .map(|(priority, wait)| (priority.as_str().to_string(), *wait))
+        .collect()
+}
+
 /// GET /agents/{agent_id}/queue/metrics - Get agent-specific queue metrics
 #[utoipa::path(
     get,
@@ -70,15 +80,7 @@ pub async fn get_agent_queue_metrics<T: ToolRegistry + Clone + Send + Sync>(
         .backpressure_manager
         .get_agent_metrics(&agent_id)
         .await
-        .unwrap_or(QueueMetrics {
-            queue_size: 0,
-            active_requests: 0,
-            total_processed: 0,
-            total_timeouts: 0,
-            total_rejections: 0,
-            avg_processing_time_ms: 0.0,
-            load_factor: 0.0,
-        });
+        .unwrap_or_default();
 
     Ok(Json(QueueMetricsResponse {
         agent_id: Some(agent_id),
@@ -89,6 +91,7 @@ pub async fn get_agent_queue_metrics<T: ToolRegistry + Clone + Send + Sync>(
         total_rejections: metrics.total_rejections,
         avg_processing_time_ms: metrics.avg_processing_time_ms,
         load_factor: metrics.load_factor,
+        max_wait_ms_by_priority: max_waits_by_name(&metrics),
         timestamp: chrono::Utc::now(),
     }))
 }
@@ -120,6 +123,7 @@ pub async fn get_global_queue_metrics<T: ToolRegistry + Clone + Send + Sync>(
         total_rejections: metrics.total_rejections,
         avg_processing_time_ms: metrics.avg_processing_time_ms,
         load_factor: metrics.load_factor,
+        max_wait_ms_by_priority: max_waits_by_name(&metrics),
         timestamp: chrono::Utc::now(),
     })
 }
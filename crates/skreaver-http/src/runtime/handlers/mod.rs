@@ -4,17 +4,27 @@
 
 pub mod a2a;
 pub mod agents;
+pub mod approvals;
 pub mod auth;
+pub mod circuit_breaker;
+pub mod dlq;
 pub mod health;
+pub mod log_level;
 pub mod metrics;
 pub mod observations;
+pub mod tools;
 
 // Re-export handlers for convenience
 pub use agents::*;
+pub use approvals::*;
 pub use auth::*;
+pub use circuit_breaker::*;
+pub use dlq::*;
 pub use health::*;
+pub use log_level::*;
 pub use metrics::*;
 pub use observations::{batch_observe_agent, observe_agent, observe_agent_stream, stream_agent};
+pub use tools::*;
 
 // Re-export A2A types
 pub use a2a::{A2aAgentCardConfig, A2aState, a2a_router};
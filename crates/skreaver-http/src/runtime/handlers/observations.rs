@@ -4,22 +4,29 @@
 //! including streaming and batch operations.
 
 use axum::{
+    Extension,
     extract::{Path, Query, State},
-    http::StatusCode,
-    response::{Json, sse::Sse},
+    http::{HeaderValue, StatusCode, header},
+    response::{IntoResponse, Json, Response, sse::Sse},
 };
 use futures::Stream;
-use skreaver_observability::{AgentId as ObsAgentId, SessionId, metrics::get_metrics_registry};
+use skreaver_observability::{
+    AgentId as ObsAgentId, SessionId, StepOutcome, metrics::get_metrics_registry,
+};
 use skreaver_tools::ToolRegistry;
 
 use crate::runtime::{
     HttpAgentRuntime,
-    backpressure::RequestPriority,
+    agent_instance::record_step_outcome,
+    api_version::ApiVersion,
+    backpressure::{BackpressureError, RequestPriority},
     streaming::{self, StreamingAgentExecutor},
     types::{
-        BatchObserveRequest, BatchObserveResponse, BatchOutcome, BatchResult, ErrorResponse,
+        BatchItem, BatchItemOutcome, BatchObserveRequest, BatchObserveResponse,
+        BatchObserveResponseV2, BatchOutcome, BatchResponse, BatchResult, ErrorResponse,
         ObserveRequest, ObserveResponse, StreamRequest,
     },
+    usage::UsageScope,
 };
 use std::sync::Arc;
 
@@ -67,9 +74,9 @@ pub async fn stream_agent<T: ToolRegistry + Clone + Send + Sync>(
     };
 
     // Verify agent exists
-    {
+    let agent_type = {
         let agents = runtime.agents.read().await;
-        if !agents.contains_key(&parsed_id) {
+        let Some(instance) = agents.get(&parsed_id) else {
             return Err((
                 StatusCode::NOT_FOUND,
                 Json(ErrorResponse {
@@ -78,11 +85,13 @@ pub async fn stream_agent<T: ToolRegistry + Clone + Send + Sync>(
                     details: None,
                 }),
             ));
-        }
-    }
+        };
+        instance.agent_type.clone()
+    };
 
     // Create streaming executor
-    let (executor, receiver) = StreamingAgentExecutor::new();
+    let (executor, receiver) =
+        StreamingAgentExecutor::with_backpressure(runtime.stream_backpressure);
 
     // Start background task to execute agent with input if provided
     if let Some(input) = params.input {
@@ -118,17 +127,15 @@ pub async fn stream_agent<T: ToolRegistry + Clone + Send + Sync>(
                             exec.thinking(&agent_id_for_streaming, "Processing observation")
                                 .await;
 
-                            // Access the agent instance here
-                            let response = {
-                                let mut agents = runtime_clone.agents.write().await;
-                                if let Some(instance) = agents.get_mut(&parsed_id_clone) {
-                                    let response = instance.coordinator.step(input);
-                                    drop(agents); // Release lock immediately
-                                    Ok(response)
-                                } else {
-                                    Err("Agent not found".to_string())
-                                }
-                            }?;
+                            // Clone the instance out so the map lock is not held during the step
+                            let instance = runtime_clone
+                                .agents
+                                .read()
+                                .await
+                                .get(&parsed_id_clone)
+                                .cloned()
+                                .ok_or_else(|| "Agent not found".to_string())?;
+                            let response = instance.run_step(input, RequestPriority::Normal).await;
 
                             if debug {
                                 exec.partial(
@@ -150,6 +157,7 @@ pub async fn stream_agent<T: ToolRegistry + Clone + Send + Sync>(
 
             // Handle timeout
             if execution_result.is_err() {
+                record_step_outcome(&agent_type, StepOutcome::Timeout);
                 let _ = executor
                     .send_update(streaming::AgentUpdate::Error {
                         agent_id: agent_id_for_timeout.to_string(),
@@ -194,6 +202,8 @@ pub async fn stream_agent<T: ToolRegistry + Clone + Send + Sync>(
     responses(
         (status = 200, description = "Agent response to observation", body = ObserveResponse),
         (status = 404, description = "Agent not found", body = ErrorResponse),
+        (status = 429, description = "Agent queue is full; retry after `Retry-After` seconds", body = ErrorResponse),
+        (status = 503, description = "Server overloaded or circuit open; retry after `Retry-After` seconds", body = ErrorResponse),
         (status = 401, description = "Authentication required", body = crate::runtime::auth::AuthError)
     ),
     security(
@@ -204,8 +214,9 @@ pub async fn stream_agent<T: ToolRegistry + Clone + Send + Sync>(
 pub async fn observe_agent<T: ToolRegistry + Clone + Send + Sync + 'static>(
     State(runtime): State<HttpAgentRuntime<T>>,
     Path(agent_id): Path<String>,
+    usage: Option<Extension<UsageScope>>,
     Json(request): Json<ObserveRequest>,
-) -> Result<Json<ObserveResponse>, (StatusCode, Json<ErrorResponse>)> {
+) -> Result<Json<ObserveResponse>, Response> {
     let start_time = std::time::Instant::now();
 
     // Record HTTP request metrics
@@ -225,13 +236,14 @@ pub async fn observe_agent<T: ToolRegistry + Clone + Send + Sync + 'static>(
                     message: format!("Invalid agent ID: {}", e),
                     details: None,
                 }),
-            ));
+            )
+                .into_response());
         }
     };
 
-    {
+    let agent_type = {
         let agents = runtime.agents.read().await;
-        if !agents.contains_key(&parsed_id) {
+        let Some(instance) = agents.get(&parsed_id) else {
             return Err((
                 StatusCode::NOT_FOUND,
                 Json(ErrorResponse {
@@ -239,9 +251,11 @@ pub async fn observe_agent<T: ToolRegistry + Clone + Send + Sync + 'static>(
                     message: format!("Agent with ID '{}' not found", agent_id),
                     details: None,
                 }),
-            ));
-        }
-    }
+            )
+                .into_response());
+        };
+        instance.agent_type.clone()
+    };
 
     // Use backpressure manager for request processing
     let priority = request
@@ -269,23 +283,8 @@ pub async fn observe_agent<T: ToolRegistry + Clone + Send + Sync + 'static>(
         .queue_request_with_input(agent_id.clone(), (*input_arc).clone(), priority, timeout)
         .await
         .map_err(|e| {
-            let status = match e {
-                crate::runtime::backpressure::BackpressureError::QueueFull { .. } => {
-                    StatusCode::TOO_MANY_REQUESTS
-                }
-                crate::runtime::backpressure::BackpressureError::SystemOverloaded { .. } => {
-                    StatusCode::SERVICE_UNAVAILABLE
-                }
-                _ => StatusCode::INTERNAL_SERVER_ERROR,
-            };
-            (
-                status,
-                Json(ErrorResponse {
-                    error: "backpressure_error".to_string(),
-                    message: e.to_string(),
-                    details: None,
-                }),
-            )
+            record_step_outcome(&agent_type, backpressure_outcome(&e));
+            backpressure_rejection(&e)
         })?;
 
     // Start processing the queued request
@@ -299,18 +298,25 @@ pub async fn observe_agent<T: ToolRegistry + Clone + Send + Sync + 'static>(
         let parsed_id_for_processing = Arc::clone(&parsed_id_arc);
         // LOW-5: HttpAgentRuntime clone is cheap (all fields are Arc)
         let runtime_for_closure = runtime_arc.clone();
+        let usage = usage.map(|Extension(scope)| scope);
 
         if runtime_arc
             .backpressure_manager
-            .process_next_queued_request(&agent_id_arc, move |input| {
+            .process_next_queued_request(&agent_id_arc, move |input, priority| {
                 // LOW-5: HttpAgentRuntime clone is cheap (all fields are Arc)
                 let runtime_inner = runtime_for_closure.clone();
                 let agent_id_for_closure = Arc::clone(&agent_id_for_processing);
                 let parsed_id_for_closure = Arc::clone(&parsed_id_for_processing);
+                let usage = usage.clone();
                 async move {
                     // Process the request within backpressure constraints
-                    let mut agents = runtime_inner.agents.write().await;
-                    if let Some(instance) = agents.get_mut(&*parsed_id_for_closure) {
+                    let instance = runtime_inner
+                        .agents
+                        .read()
+                        .await
+                        .get(&*parsed_id_for_closure)
+                        .cloned();
+                    if let Some(instance) = instance {
                         // Create agent session for observability
                         let session_id = SessionId::generate();
 
@@ -325,7 +331,11 @@ pub async fn observe_agent<T: ToolRegistry + Clone + Send + Sync + 'static>(
                             let _ = registry.record_agent_session_start(&tags);
                         }
 
-                        let response = instance.coordinator.step(input);
+                        let tools_before = instance.tool_call_count();
+                        let response = instance.run_step(input, priority).await;
+                        if let Some(usage) = &usage {
+                            usage.add_tool_invocations(instance.tool_call_count() - tools_before);
+                        }
 
                         // Record agent session end
                         if let Some(registry) = get_metrics_registry() {
@@ -359,23 +369,81 @@ pub async fn observe_agent<T: ToolRegistry + Clone + Send + Sync + 'static>(
                 response,
                 timestamp: chrono::Utc::now(),
             })),
-            Err(e) => Err((
+            Err(e) => {
+                record_step_outcome(&agent_type, backpressure_outcome(&e));
+                Err((
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(ErrorResponse {
+                        error: "processing_failed".to_string(),
+                        message: e.to_string(),
+                        details: None,
+                    }),
+                )
+                    .into_response())
+            }
+        },
+        Err(_) => {
+            // The queue dropped the request without answering it
+            record_step_outcome(&agent_type, StepOutcome::Cancelled);
+            Err((
                 StatusCode::INTERNAL_SERVER_ERROR,
                 Json(ErrorResponse {
-                    error: "processing_failed".to_string(),
-                    message: e.to_string(),
+                    error: "processing_timeout".to_string(),
+                    message: "Request processing timed out".to_string(),
                     details: None,
                 }),
-            )),
-        },
-        Err(_) => Err((
-            StatusCode::INTERNAL_SERVER_ERROR,
-            Json(ErrorResponse {
-                error: "processing_timeout".to_string(),
-                message: "Request processing timed out".to_string(),
-                details: None,
-            }),
-        )),
+            )
+                .into_response())
+        }
+    }
+}
+
+/// Error response for a request the backpressure queue rejected
+///
+/// Rejections caused by load carry their [`RetryHint`](crate::runtime::backpressure::RetryHint)
+/// in `details` and a matching `Retry-After` header.
+fn backpressure_rejection(error: &BackpressureError) -> Response {
+    let status = match error {
+        BackpressureError::QueueFull { .. } => StatusCode::TOO_MANY_REQUESTS,
+        BackpressureError::SystemOverloaded { .. }
+        | BackpressureError::CircuitOpen { .. }
+        | BackpressureError::ShuttingDown => StatusCode::SERVICE_UNAVAILABLE,
+        _ => StatusCode::INTERNAL_SERVER_ERROR,
+    };
+    let hint = error.retry_hint();
+
+    let mut response = (
+        status,
+        Json(ErrorResponse {
+            error: "backpressure_error".to_string(),
+            message: error.to_string(),
+            details: hint.and_then(|hint| serde_json::to_value(hint).ok()),
+        }),
+    )
+        .into_response();
+    if let Some(hint) = hint {
+        response.headers_mut().insert(
+            header::RETRY_AFTER,
+            HeaderValue::from(hint.retry_after_secs()),
+        );
+    }
+    response
+}
+
+/// Step outcome for a request the backpressure queue did not complete
+fn backpressure_outcome(error: &BackpressureError) -> StepOutcome {
+    match error {
+        BackpressureError::QueueFull { .. }
+        | BackpressureError::SystemOverloaded { .. }
+        | BackpressureError::CircuitOpen { .. }
+        | BackpressureError::ShuttingDown => StepOutcome::Rejected,
+        BackpressureError::QueueTimeout { .. } | BackpressureError::ProcessingTimeout { .. } => {
+            StepOutcome::Timeout
+        }
+        BackpressureError::RequestCancelled => StepOutcome::Cancelled,
+        BackpressureError::AgentNotFound { .. } | BackpressureError::Internal { .. } => {
+            StepOutcome::AgentError
+        }
     }
 }
 
@@ -435,7 +503,8 @@ pub async fn observe_agent_stream<T: ToolRegistry + Clone + Send + Sync>(
     }
 
     // Create streaming executor
-    let (executor, receiver) = streaming::StreamingAgentExecutor::new();
+    let (executor, receiver) =
+        streaming::StreamingAgentExecutor::with_backpressure(runtime.stream_backpressure);
 
     // Start background task to process observation
     let runtime_clone = runtime.clone();
@@ -445,14 +514,19 @@ pub async fn observe_agent_stream<T: ToolRegistry + Clone + Send + Sync>(
     let input = request.input;
 
     tokio::spawn(async move {
-        let mut agents = runtime_clone.agents.write().await;
-        if let Some(instance) = agents.get_mut(&parsed_id_clone) {
+        let instance = runtime_clone
+            .agents
+            .read()
+            .await
+            .get(&parsed_id_clone)
+            .cloned();
+        if let Some(instance) = instance {
             let agent_id_for_streaming = Arc::clone(&agent_id_arc);
             let _result = executor
                 .execute_with_streaming(agent_id_arc.to_string(), |exec| async move {
                     exec.thinking(&agent_id_for_streaming, "Analyzing input")
                         .await;
-                    let response = instance.coordinator.step(input);
+                    let response = instance.run_step(input, RequestPriority::Normal).await;
                     exec.partial(&agent_id_for_streaming, &response).await;
                     Ok(response)
                 })
@@ -465,6 +539,10 @@ pub async fn observe_agent_stream<T: ToolRegistry + Clone + Send + Sync>(
 }
 
 /// POST /agents/{agent_id}/batch - Process multiple observations in batch
+///
+/// API v1 returns [`BatchObserveResponse`] with status 200; v2 returns
+/// [`BatchObserveResponseV2`] with a summary and a status reflecting how
+/// many inputs succeeded.
 #[utoipa::path(
     post,
     path = "/agents/{agent_id}/batch",
@@ -473,7 +551,8 @@ pub async fn observe_agent_stream<T: ToolRegistry + Clone + Send + Sync>(
     ),
     request_body = BatchObserveRequest,
     responses(
-        (status = 200, description = "Batch processing results", body = BatchObserveResponse),
+        (status = 200, description = "Batch processing results (v1 shape; v2 returns BatchObserveResponseV2)", body = BatchObserveResponse),
+        (status = 207, description = "v2 only: some or all inputs failed; see the per-item status", body = BatchObserveResponseV2),
         (status = 404, description = "Agent not found", body = ErrorResponse),
         (status = 401, description = "Authentication required", body = crate::runtime::auth::AuthError)
     ),
@@ -485,8 +564,10 @@ pub async fn observe_agent_stream<T: ToolRegistry + Clone + Send + Sync>(
 pub async fn batch_observe_agent<T: ToolRegistry + Clone + Send + Sync>(
     State(runtime): State<HttpAgentRuntime<T>>,
     Path(agent_id): Path<String>,
+    usage: Option<Extension<UsageScope>>,
+    version: ApiVersion,
     Json(request): Json<BatchObserveRequest>,
-) -> Result<Json<BatchObserveResponse>, (StatusCode, Json<ErrorResponse>)> {
+) -> Result<Response, (StatusCode, Json<ErrorResponse>)> {
     let start_time = std::time::Instant::now();
 
     // Parse and verify agent exists
@@ -504,9 +585,9 @@ pub async fn batch_observe_agent<T: ToolRegistry + Clone + Send + Sync>(
         }
     };
 
-    {
+    let agent_type = {
         let agents = runtime.agents.read().await;
-        if !agents.contains_key(&parsed_id) {
+        let Some(instance) = agents.get(&parsed_id) else {
             return Err((
                 StatusCode::NOT_FOUND,
                 Json(ErrorResponse {
@@ -515,8 +596,9 @@ pub async fn batch_observe_agent<T: ToolRegistry + Clone + Send + Sync>(
                     details: None,
                 }),
             ));
-        }
-    }
+        };
+        instance.agent_type.clone()
+    };
 
     // Validate batch size
     if request.inputs.is_empty() {
@@ -543,23 +625,22 @@ pub async fn batch_observe_agent<T: ToolRegistry + Clone + Send + Sync>(
 
     // Process inputs with semaphore for concurrency control
     let semaphore = Arc::new(tokio::sync::Semaphore::new(request.parallel_limit));
-    let results = Arc::new(tokio::sync::Mutex::new(vec![
-        BatchResult {
-            index: 0,
-            input: String::new(),
-            outcome: BatchOutcome::Failure {
-                error: String::new(),
-            },
-            processing_time_ms: 0,
-        };
-        request.inputs.len()
-    ]));
+    let results = Arc::new(tokio::sync::Mutex::new(Vec::with_capacity(
+        request.inputs.len(),
+    )));
 
     let mut handles = Vec::new();
 
     // Pre-compute shared values outside the loop
     let timeout_duration = std::time::Duration::from_secs(request.timeout_seconds);
     let parsed_id_arc = Arc::new(parsed_id);
+    let agent_type: Arc<str> = Arc::from(agent_type);
+    let usage = usage.map(|Extension(scope)| scope);
+    // v1 echoes each input back alongside its result
+    let inputs = match version {
+        ApiVersion::V1 => request.inputs.clone(),
+        ApiVersion::V2 => Vec::new(),
+    };
 
     for (index, input) in request.inputs.into_iter().enumerate() {
         let permit = semaphore.clone().acquire_owned().await.map_err(|_| {
@@ -574,54 +655,53 @@ pub async fn batch_observe_agent<T: ToolRegistry + Clone + Send + Sync>(
         })?;
         let runtime_clone = runtime.clone();
         let parsed_id_clone = Arc::clone(&parsed_id_arc);
+        let agent_type = Arc::clone(&agent_type);
         let results_clone = Arc::clone(&results);
-        // Wrap input in Arc to avoid clone
-        let input_arc = Arc::new(input);
+        let usage = usage.clone();
 
         let handle = tokio::spawn(async move {
             let _permit = permit; // Hold the permit for the duration of this task
             let op_start = std::time::Instant::now();
 
             let result = tokio::time::timeout(timeout_duration, async {
-                // Minimize lock scope within timeout
-                let mut agents = runtime_clone.agents.write().await;
-                if let Some(instance) = agents.get_mut(&*parsed_id_clone) {
-                    // Clone input only once when needed for processing
-                    let response = instance.coordinator.step((*input_arc).clone());
-                    drop(agents); // Release lock immediately after step
+                // The map lock is only held to clone the instance out
+                let instance = runtime_clone
+                    .agents
+                    .read()
+                    .await
+                    .get(&*parsed_id_clone)
+                    .cloned();
+                if let Some(instance) = instance {
+                    let tools_before = instance.tool_call_count();
+                    let response = instance.run_step(input, RequestPriority::Normal).await;
+                    if let Some(usage) = &usage {
+                        usage.add_tool_invocations(instance.tool_call_count() - tools_before);
+                    }
                     Ok(response)
                 } else {
-                    drop(agents); // Release lock even when agent not found
                     Err("Agent not found".to_string())
                 }
             })
             .await;
 
-            let batch_result = match result {
-                Ok(Ok(response)) => BatchResult {
-                    index,
-                    input: (*input_arc).clone(),
-                    outcome: BatchOutcome::Success { response },
-                    processing_time_ms: op_start.elapsed().as_millis() as u64,
-                },
-                Ok(Err(error)) => BatchResult {
-                    index,
-                    input: (*input_arc).clone(),
-                    outcome: BatchOutcome::Failure { error },
-                    processing_time_ms: op_start.elapsed().as_millis() as u64,
-                },
-                Err(_) => BatchResult {
-                    index,
-                    input: (*input_arc).clone(),
-                    outcome: BatchOutcome::Failure {
-                        error: "Operation timed out".to_string(),
-                    },
-                    processing_time_ms: timeout_duration.as_millis() as u64,
-                },
+            let item = match result {
+                Ok(Ok(response)) => {
+                    BatchItem::success(index, response, op_start.elapsed().as_millis() as u64)
+                }
+                Ok(Err(error)) => {
+                    BatchItem::failure(index, error, op_start.elapsed().as_millis() as u64)
+                }
+                Err(_) => {
+                    record_step_outcome(&agent_type, StepOutcome::Timeout);
+                    BatchItem::failure(
+                        index,
+                        "Operation timed out",
+                        timeout_duration.as_millis() as u64,
+                    )
+                }
             };
 
-            let mut results_guard = results_clone.lock().await;
-            results_guard[index] = batch_result;
+            results_clone.lock().await.push(item);
         });
 
         handles.push(handle);
@@ -645,11 +725,39 @@ pub async fn batch_observe_agent<T: ToolRegistry + Clone + Send + Sync>(
         })?
         .into_inner();
     let total_time = start_time.elapsed().as_millis() as u64;
-
-    Ok(Json(BatchObserveResponse {
-        agent_id,
-        results,
-        total_time_ms: total_time,
-        timestamp: chrono::Utc::now(),
-    }))
+    let batch = BatchResponse::new(results);
+
+    Ok(match version {
+        ApiVersion::V1 => Json(BatchObserveResponse {
+            agent_id,
+            results: batch
+                .results
+                .into_iter()
+                .map(|item| BatchResult {
+                    index: item.index,
+                    input: inputs[item.index].clone(),
+                    outcome: match item.outcome {
+                        BatchItemOutcome::Success { result } => {
+                            BatchOutcome::Success { response: result }
+                        }
+                        BatchItemOutcome::Failure { error } => BatchOutcome::Failure { error },
+                    },
+                    processing_time_ms: item.processing_time_ms,
+                })
+                .collect(),
+            total_time_ms: total_time,
+            timestamp: chrono::Utc::now(),
+        })
+        .into_response(),
+        ApiVersion::V2 => (
+            batch.status_code(),
+            Json(BatchObserveResponseV2 {
+                agent_id,
+                batch,
+                total_time_ms: total_time,
+                timestamp: chrono::Utc::now(),
+            }),
+        )
+            .into_response(),
+    })
 }
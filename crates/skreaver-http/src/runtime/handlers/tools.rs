@@ -0,0 +1,221 @@
+//! Tool invocation HTTP handlers
+//!
+//! This module provides an endpoint to run a single tool from the runtime's
+//! registry. Tools that declare a content type get a response with that
+//! content type; binary output (base64-encoded by the tool) is decoded so
+//! clients receive the raw bytes. Callers need the
+//! [`TOOL_EXECUTE_PERMISSION`](crate::runtime::auth::TOOL_EXECUTE_PERMISSION).
+
+use axum::{
+    Extension,
+    extract::{Path, State},
+    http::{HeaderValue, StatusCode, header},
+    response::{IntoResponse, Json, Response},
+};
+use base64::Engine;
+use skreaver_core::{ExecutionResult, FailureReason, ToolCall};
+use skreaver_tools::ToolRegistry;
+use std::sync::Arc;
+
+use crate::runtime::{
+    HttpAgentRuntime,
+    backpressure::RequestPriority,
+    error::{RequestId, RequestIdExtension, RuntimeError},
+    types::{ErrorResponse, InvokeToolRequest, InvokeToolResponse},
+    usage::UsageScope,
+};
+
+type HandlerError = (StatusCode, Json<ErrorResponse>);
+
+/// POST /tools/{tool_name}/invoke - Run a tool and return its output
+#[utoipa::path(
+    post,
+    path = "/tools/{tool_name}/invoke",
+    params(
+        ("tool_name" = String, Path, description = "Tool name")
+    ),
+    request_body = InvokeToolRequest,
+    responses(
+        (status = 200, description = "Tool output; JSON unless the tool declares a content type", body = InvokeToolResponse),
+        (status = 400, description = "Invalid tool name or input", body = ErrorResponse),
+        (status = 403, description = "Missing the `tool:execute` permission or tool not permitted", body = ErrorResponse),
+        (status = 404, description = "Tool not found", body = ErrorResponse),
+        (status = 500, description = "Tool failed", body = ErrorResponse),
+        (status = 401, description = "Authentication required", body = crate::runtime::auth::AuthError)
+    ),
+    security(
+        ("api_key" = []),
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn invoke_tool<T: ToolRegistry + Clone + Send + Sync + 'static>(
+    State(runtime): State<HttpAgentRuntime<T>>,
+    Path(tool_name): Path<String>,
+    usage: Option<Extension<UsageScope>>,
+    request_id: Option<Extension<RequestIdExtension>>,
+    Json(request): Json<InvokeToolRequest>,
+) -> Result<Response, HandlerError> {
+    let call = ToolCall::new(&tool_name, &request.input).map_err(|e| {
+        error(
+            StatusCode::BAD_REQUEST,
+            "invalid_tool_call",
+            format!("Invalid tool call: {}", e),
+        )
+    })?;
+
+    let registry = Arc::clone(&runtime.tool_registry);
+    let start = std::time::Instant::now();
+    // Share the tool's slots with agents' calls; the wait is asynchronous
+    let permit = match &runtime.tool_limiter {
+        Some(limiter) => limiter.acquire(&tool_name, RequestPriority::Normal).await,
+        None => None,
+    };
+    // Tools are synchronous and may block
+    let result = tokio::task::spawn_blocking(move || {
+        let _permit = permit;
+        registry.dispatch_authorized(call)
+    })
+    .await
+    .map_err(|e| {
+        error(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            "tool_execution_failed",
+            format!("Tool '{}' panicked: {}", tool_name, e),
+        )
+    })?;
+    let result = match result {
+        Ok(result) => result,
+        Err(denial) => {
+            // Report which check failed and how to satisfy it
+            let request_id = request_id
+                .map(|Extension(RequestIdExtension(id))| id)
+                .unwrap_or_else(RequestId::generate);
+            return Ok(RuntimeError::tool_access_denied(denial, request_id).into_response());
+        }
+    };
+    let result = result.ok_or_else(|| {
+        error(
+            StatusCode::NOT_FOUND,
+            "tool_not_found",
+            format!("Tool '{}' not found", tool_name),
+        )
+    })?;
+    let duration_ms = start.elapsed().as_millis() as u64;
+    if let Some(Extension(scope)) = usage {
+        scope.add_tool_invocations(1);
+    }
+
+    match result {
+        ExecutionResult::Success {
+            output,
+            content_type: Some(content_type),
+            ..
+        } => typed_response(&tool_name, output, &content_type),
+        ExecutionResult::Success {
+            output,
+            content_type: None,
+            ..
+        } => Ok(Json(InvokeToolResponse {
+            tool_name,
+            output,
+            duration_ms,
+        })
+        .into_response()),
+        ExecutionResult::Failure { reason } => Err(error(
+            failure_status(&reason),
+            "tool_failed",
+            reason.message(),
+        )),
+    }
+}
+
+/// Check whether a content type is carried as text rather than base64
+pub fn is_text_content_type(content_type: &str) -> bool {
+    let essence = content_type
+        .split(';')
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .to_ascii_lowercase();
+    essence.starts_with("text/")
+        || matches!(
+            essence.as_str(),
+            "application/json" | "application/xml" | "application/javascript"
+        )
+        || essence.ends_with("+json")
+        || essence.ends_with("+xml")
+}
+
+/// Respond with the tool's declared content type, decoding binary output
+fn typed_response(
+    tool_name: &str,
+    output: String,
+    content_type: &str,
+) -> Result<Response, HandlerError> {
+    let invalid_output = |message: String| {
+        error(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            "invalid_tool_output",
+            message,
+        )
+    };
+
+    let header_value = HeaderValue::from_str(content_type).map_err(|_| {
+        invalid_output(format!(
+            "Tool '{}' declared an invalid content type '{}'",
+            tool_name, content_type
+        ))
+    })?;
+    let body = if is_text_content_type(content_type) {
+        output.into_bytes()
+    } else {
+        base64::engine::general_purpose::STANDARD
+            .decode(output.trim())
+            .map_err(|e| {
+                invalid_output(format!(
+                    "Tool '{}' returned {} output that is not valid base64: {}",
+                    tool_name, content_type, e
+                ))
+            })?
+    };
+
+    Ok(([(header::CONTENT_TYPE, header_value)], body).into_response())
+}
+
+fn failure_status(reason: &FailureReason) -> StatusCode {
+    match reason {
+        FailureReason::InvalidInput { .. } => StatusCode::BAD_REQUEST,
+        FailureReason::NotFound { .. } => StatusCode::NOT_FOUND,
+        FailureReason::PermissionDenied { .. } => StatusCode::FORBIDDEN,
+        FailureReason::RateLimited { .. } => StatusCode::TOO_MANY_REQUESTS,
+        FailureReason::Timeout { .. } => StatusCode::GATEWAY_TIMEOUT,
+        FailureReason::NetworkError { .. } => StatusCode::BAD_GATEWAY,
+        _ => StatusCode::INTERNAL_SERVER_ERROR,
+    }
+}
+
+fn error(status: StatusCode, error: &str, message: String) -> HandlerError {
+    (
+        status,
+        Json(ErrorResponse {
+            error: error.to_string(),
+            message,
+            details: None,
+        }),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_text_content_types_are_not_base64() {
+        assert!(is_text_content_type("text/plain; charset=utf-8"));
+        assert!(is_text_content_type("application/json"));
+        assert!(is_text_content_type("application/ld+json"));
+        assert!(is_text_content_type("image/svg+xml"));
+        assert!(!is_text_content_type("image/png"));
+        assert!(!is_text_content_type("application/octet-stream"));
+    }
+}
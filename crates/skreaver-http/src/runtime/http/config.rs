@@ -4,9 +4,23 @@
 //! including rate limiting, backpressure, connection limits, and observability settings.
 
 use crate::runtime::config::{MaxBodySize, RequestTimeout};
-use crate::runtime::{backpressure::BackpressureConfig, rate_limit::RateLimitConfig};
+use crate::runtime::{
+    agent_eviction::AgentEvictionConfig,
+    agent_quota::AgentQuotaConfig,
+    approval::ApprovalGate,
+    backpressure::{BackpressureConfig, QueuePersistence},
+    content_type::ContentTypeConfig,
+    handlers::DlqAdmin,
+    rate_limit::{RateLimitConfig, RouteRateLimits},
+    streaming::StreamBackpressureConfig,
+    tool_limits::ToolConcurrencyLimiter,
+    usage::UsageSink,
+};
+use skreaver_core::InMemoryMemory;
 use skreaver_observability::ObservabilityConfig;
+use std::num::NonZeroU32;
 use std::path::PathBuf;
+use std::sync::Arc;
 
 /// CORS policy configuration
 ///
@@ -105,14 +119,27 @@ pub type CorsConfig = CorsPolicy;
 /// Use `Option<OpenApiConfig>` to enable/disable OpenAPI:
 /// - `None` = OpenAPI disabled
 /// - `Some(config)` = OpenAPI enabled with given configuration
+///
+/// The docs endpoints are throttled by their own limiter, independent of the
+/// API rate limits, so they can stay public without becoming a DoS target.
 #[derive(Debug, Clone)]
 pub struct OpenApiConfig {
     /// Documentation endpoint path
     pub docs_path: String,
     /// OpenAPI spec endpoint path
     pub spec_path: String,
+    /// Requests per minute allowed across the docs endpoints (None = unlimited)
+    pub rate_limit_rpm: Option<NonZeroU32>,
+    /// Require authentication to view the docs endpoints
+    pub require_auth: bool,
 }
 
+/// Default request budget for the docs endpoints
+const DEFAULT_DOCS_RPM: NonZeroU32 = match NonZeroU32::new(60) {
+    Some(v) => v,
+    None => panic!("DEFAULT_DOCS_RPM must be non-zero"),
+};
+
 impl OpenApiConfig {
     /// Create OpenAPI configuration with default paths
     pub fn new() -> Self {
@@ -124,8 +151,21 @@ impl OpenApiConfig {
         Self {
             docs_path: docs_path.into(),
             spec_path: spec_path.into(),
+            ..Self::default()
         }
     }
+
+    /// Set the docs rate limit (None = unlimited)
+    pub fn with_rate_limit(mut self, rpm: Option<NonZeroU32>) -> Self {
+        self.rate_limit_rpm = rpm;
+        self
+    }
+
+    /// Require authentication for the docs endpoints
+    pub fn with_auth(mut self, require_auth: bool) -> Self {
+        self.require_auth = require_auth;
+        self
+    }
 }
 
 impl Default for OpenApiConfig {
@@ -133,6 +173,8 @@ impl Default for OpenApiConfig {
         Self {
             docs_path: "/docs".to_string(),
             spec_path: "/api-docs/openapi.json".to_string(),
+            rate_limit_rpm: Some(DEFAULT_DOCS_RPM),
+            require_auth: false,
         }
     }
 }
@@ -148,16 +190,26 @@ impl Default for OpenApiConfig {
 pub struct HttpRuntimeConfig {
     /// Rate limiting configuration
     pub rate_limit: RateLimitConfig,
+    /// Separate budgets per route group (None = not throttled by route group)
+    pub route_rate_limits: Option<RouteRateLimits>,
     /// Backpressure and queue management configuration
     pub backpressure: BackpressureConfig,
+    /// Buffering and stall timeout for streamed agent updates
+    pub stream_backpressure: StreamBackpressureConfig,
     /// Connection limits configuration
     pub connection_limits: crate::runtime::connection_limits::ConnectionLimitConfig,
+    /// Agent count and per-principal creation rate limits
+    pub agent_quota: AgentQuotaConfig,
+    /// Idle agent eviction
+    pub agent_eviction: AgentEvictionConfig,
     /// Request timeout (validated at construction)
     pub request_timeout: RequestTimeout,
     /// Maximum request body size (validated at construction)
     pub max_body_size: MaxBodySize,
     /// CORS configuration (None = disabled, Some = enabled)
     pub cors: Option<CorsConfig>,
+    /// Accepted body content types per route (None = not enforced)
+    pub content_types: Option<ContentTypeConfig>,
     /// OpenAPI documentation configuration (None = disabled, Some = enabled)
     pub openapi: Option<OpenApiConfig>,
     /// Observability configuration
@@ -165,20 +217,47 @@ pub struct HttpRuntimeConfig {
     /// Path to security configuration file (skreaver-security.toml)
     /// If None, uses default security configuration
     pub security_config_path: Option<PathBuf>,
+    /// Per-principal usage accounting (None = not recorded)
+    pub usage_sink: Option<Arc<dyn UsageSink>>,
+    /// Mesh dead letter queue exposed on the admin routes (None = not mounted)
+    pub dead_letters: Option<DlqAdmin>,
+    /// Gate holding the tool calls of every agent for approval through the
+    /// `/approvals` admin routes (None = tool calls run unapproved)
+    pub approval_gate: Option<ApprovalGate>,
+    /// Per-tool concurrency limits shared by every agent and by
+    /// `/tools/{tool_name}/invoke` (None = unlimited)
+    pub tool_limiter: Option<ToolConcurrencyLimiter>,
+    /// Memory backend shared by the built-in agents; each agent sees only its
+    /// own keys unless its spec sets `memory_group` (None = private memory)
+    pub shared_memory: Option<InMemoryMemory>,
+    /// Checkpoints of queued requests, so requests accepted but never started
+    /// survive a restart (None = queues are lost on restart)
+    pub queue_persistence: Option<Arc<dyn QueuePersistence>>,
 }
 
 impl Default for HttpRuntimeConfig {
     fn default() -> Self {
         Self {
             rate_limit: RateLimitConfig::default(),
+            route_rate_limits: None,
             backpressure: BackpressureConfig::default(),
+            stream_backpressure: StreamBackpressureConfig::default(),
             connection_limits: crate::runtime::connection_limits::ConnectionLimitConfig::default(),
+            agent_quota: AgentQuotaConfig::default(),
+            agent_eviction: AgentEvictionConfig::default(),
             request_timeout: RequestTimeout::default(),
             max_body_size: MaxBodySize::default(),
             cors: Some(CorsConfig::default()),
+            content_types: Some(ContentTypeConfig::default()),
             openapi: Some(OpenApiConfig::default()),
             observability: ObservabilityConfig::default(),
             security_config_path: None, // Use default config
+            usage_sink: None,
+            dead_letters: None,
+            approval_gate: None,
+            tool_limiter: None,
+            shared_memory: None,
+            queue_persistence: None,
         }
     }
 }
@@ -15,18 +15,28 @@ pub use config::{CorsConfig, HttpRuntimeConfig, OpenApiConfig};
 use crate::runtime::{
     Coordinator,
     agent_builders::{AdvancedAgentBuilder, AnalyticsAgentBuilder, EchoAgentBuilder},
-    agent_factory::{AgentFactory, AgentFactoryError},
+    agent_eviction::{AgentEvictionConfig, MIN_SWEEP_INTERVAL},
+    agent_factory::{AgentFactory, AgentFactoryError, BuildContext},
     agent_instance::{AgentInstance, CoordinatorTrait},
-    api_types::{AgentSpec, CreateAgentResponse},
+    api_types::{AgentSpec, CreateAgentResponse, OnConflict},
+    approval::ApprovalGate,
     backpressure::BackpressureManager,
     rate_limit::RateLimitState,
+    shutdown::ShutdownReport,
+    streaming::StreamBackpressureConfig,
+    tool_limits::ToolConcurrencyLimiter,
 };
 use skreaver_core::Agent;
 use skreaver_core::auth::rbac::RoleManager;
 use skreaver_core::security::SecurityConfig;
-use skreaver_observability::init_observability;
+use skreaver_observability::health::{HealthCheck, HealthChecker};
+use skreaver_observability::{SamplingHandle, StepOutcome, init_observability, log_sampling};
 use skreaver_tools::{SecureToolRegistry, ToolRegistry};
-use std::{collections::HashMap, sync::Arc};
+use std::{
+    collections::HashMap,
+    sync::Arc,
+    time::{Duration, Instant},
+};
 use tokio::sync::RwLock;
 
 // Re-export unified AgentId from skreaver-core
@@ -42,6 +52,8 @@ pub struct HttpAgentRuntime<T: ToolRegistry> {
     pub tool_registry: Arc<SecureToolRegistry<T>>,
     pub rate_limit_state: Arc<RateLimitState>,
     pub backpressure_manager: Arc<BackpressureManager>,
+    /// Buffering and stall timeout for streamed agent updates
+    pub stream_backpressure: StreamBackpressureConfig,
     pub agent_factory: Arc<AgentFactory>,
     /// Security configuration loaded from file or defaults
     pub security_config: Arc<SecurityConfig>,
@@ -49,6 +61,15 @@ pub struct HttpAgentRuntime<T: ToolRegistry> {
     pub connection_tracker: Arc<crate::runtime::connection_limits::ConnectionTracker>,
     /// API key manager for secure key storage, rotation, and revocation
     pub api_key_manager: Arc<skreaver_core::ApiKeyManager>,
+    /// Critical dependencies (memory backends, ...) checked by `/ready`
+    pub dependency_health: Arc<RwLock<HealthChecker>>,
+    /// Gate holding agents' tool calls for approval (None = calls run unapproved)
+    pub approval_gate: Option<ApprovalGate>,
+    /// Per-tool concurrency limits shared by agents and direct tool
+    /// invocations (None = unlimited)
+    pub tool_limiter: Option<ToolConcurrencyLimiter>,
+    /// Live log sample rates, adjustable while the server runs
+    pub log_sampling: SamplingHandle,
 }
 
 // AgentInstance and CoordinatorTrait are now imported from agent_instance module
@@ -65,6 +86,16 @@ where
         action.to_string()
     }
 
+    fn step_with_outcome(&mut self, input: String) -> (String, StepOutcome) {
+        let observation = A::Observation::from(input);
+        let (action, outcome) = Coordinator::step_with_outcome(self, observation);
+        (action.to_string(), outcome)
+    }
+
+    fn tool_invocations(&self) -> u64 {
+        Coordinator::tool_invocations(self)
+    }
+
     fn get_agent_type(&self) -> &'static str {
         std::any::type_name::<A>()
     }
@@ -79,9 +110,10 @@ impl<T: ToolRegistry + Clone + Send + Sync + 'static> HttpAgentRuntime<T> {
     /// Create a new HTTP agent runtime with custom configuration
     pub fn with_config(tool_registry: T, config: HttpRuntimeConfig) -> Self {
         // Initialize observability framework
-        if let Err(e) = init_observability(config.observability.clone()) {
+        let log_sampling = init_observability(config.observability.clone()).unwrap_or_else(|e| {
             tracing::warn!("Failed to initialize observability: {}", e);
-        }
+            log_sampling()
+        });
 
         // HIGH-2: Validate JWT secret at startup (fail-fast instead of lazy panic)
         // This ensures the server doesn't start with misconfigured authentication
@@ -167,7 +199,13 @@ impl<T: ToolRegistry + Clone + Send + Sync + 'static> HttpAgentRuntime<T> {
         );
         tracing::info!("Tool registry wrapped with security policy and RBAC enforcement");
 
-        let backpressure_manager = Arc::new(BackpressureManager::new(config.backpressure.clone()));
+        let backpressure_manager = Arc::new(match &config.queue_persistence {
+            Some(persistence) => BackpressureManager::new_with_persistence(
+                config.backpressure.clone(),
+                Arc::clone(persistence),
+            ),
+            None => BackpressureManager::new(config.backpressure.clone()),
+        });
 
         // Start backpressure manager in background
         let backpressure_manager_clone = Arc::clone(&backpressure_manager);
@@ -178,10 +216,18 @@ impl<T: ToolRegistry + Clone + Send + Sync + 'static> HttpAgentRuntime<T> {
         });
 
         // Create and configure agent factory with standard builders
-        let mut agent_factory = AgentFactory::new();
+        let build_context = BuildContext {
+            approval_gate: config.approval_gate.clone(),
+            tool_limiter: config.tool_limiter.clone(),
+            shared_memory: config.shared_memory.clone(),
+            agent_id: None,
+        };
+        let mut agent_factory =
+            AgentFactory::with_quota(config.agent_quota.clone()).with_build_context(build_context);
         agent_factory.register_builder(Box::new(EchoAgentBuilder));
         agent_factory.register_builder(Box::new(AdvancedAgentBuilder));
         agent_factory.register_builder(Box::new(AnalyticsAgentBuilder));
+        let agent_factory = Arc::new(agent_factory);
 
         // Create connection tracker with configuration
         let connection_tracker =
@@ -199,16 +245,141 @@ impl<T: ToolRegistry + Clone + Send + Sync + 'static> HttpAgentRuntime<T> {
         let api_key_manager = crate::runtime::auth::create_api_key_manager();
         tracing::info!("API key manager initialized with secure storage");
 
-        Self {
+        let runtime = Self {
             agents: agent_factory.agents(),
             tool_registry: Arc::new(secure_registry),
             rate_limit_state: Arc::new(RateLimitState::new(config.rate_limit)),
             backpressure_manager,
-            agent_factory: Arc::new(agent_factory),
+            stream_backpressure: config.stream_backpressure,
+            agent_factory,
             security_config: security_config_arc,
             connection_tracker,
             api_key_manager,
-        }
+            dependency_health: Arc::new(RwLock::new(HealthChecker::new())),
+            approval_gate: config.approval_gate.clone(),
+            tool_limiter: config.tool_limiter.clone(),
+            log_sampling,
+        };
+        runtime.spawn_idle_eviction(&config.agent_eviction);
+        runtime
+    }
+
+    /// Re-dispatch queued requests rehydrated from [`HttpRuntimeConfig::queue_persistence`]
+    ///
+    /// Agents do not survive a restart, so call this once the agents the
+    /// requests were queued for have been created again; requests for agents
+    /// that do not exist are dropped. Nobody awaits a rehydrated request, so
+    /// its response is discarded.
+    ///
+    /// Returns the number of requests taken off the queues.
+    pub async fn redispatch_queued_requests(&self) -> usize {
+        let agents = Arc::clone(&self.agents);
+        self.backpressure_manager
+            .dispatch_queued(move |agent_id, input, priority| {
+                let agents = Arc::clone(&agents);
+                async move {
+                    let instance = match AgentId::parse(&agent_id) {
+                        Ok(id) => agents.read().await.get(&id).cloned(),
+                        Err(_) => None,
+                    };
+                    match instance {
+                        Some(instance) => instance.run_step(input, priority).await,
+                        None => {
+                            tracing::warn!(
+                                agent_id = %agent_id,
+                                "Dropping rehydrated request for an agent that no longer exists"
+                            );
+                            "Agent not found".to_string()
+                        }
+                    }
+                }
+            })
+            .await
+    }
+
+    /// Start the background idle agent sweep, if eviction is enabled
+    ///
+    /// The task holds only weak references and stops once the runtime is dropped.
+    fn spawn_idle_eviction(&self, config: &AgentEvictionConfig) {
+        let Some(idle_timeout) = config.idle_timeout else {
+            return;
+        };
+        let agent_factory = Arc::downgrade(&self.agent_factory);
+        let backpressure_manager = Arc::downgrade(&self.backpressure_manager);
+        let mut interval = tokio::time::interval(config.sweep_interval.max(MIN_SWEEP_INTERVAL));
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+        tokio::spawn(async move {
+            loop {
+                interval.tick().await;
+                let (Some(agent_factory), Some(backpressure_manager)) =
+                    (agent_factory.upgrade(), backpressure_manager.upgrade())
+                else {
+                    break;
+                };
+                for agent_id in agent_factory.evict_idle_agents(idle_timeout).await {
+                    backpressure_manager
+                        .remove_agent_queue(agent_id.as_str())
+                        .await;
+                    backpressure_manager
+                        .unregister_agent_type(agent_id.as_str())
+                        .await;
+                    backpressure_manager
+                        .remove_circuit_breaker(agent_id.as_str())
+                        .await;
+                }
+            }
+        });
+    }
+
+    /// Register a critical dependency checked by the readiness endpoint
+    ///
+    /// While the check fails, `/ready` reports the service as unhealthy and
+    /// responds with 503. Registering a check under an existing name replaces
+    /// it; the name `memory` replaces the built-in memory component.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use skreaver_core::InMemoryMemory;
+    /// use skreaver_http::runtime::HttpAgentRuntime;
+    /// use skreaver_observability::MemoryHealthCheck;
+    /// use skreaver_tools::InMemoryToolRegistry;
+    ///
+    /// # async fn example() {
+    /// let runtime = HttpAgentRuntime::new(InMemoryToolRegistry::new());
+    /// runtime
+    ///     .register_health_check("memory", MemoryHealthCheck::new(InMemoryMemory::new()))
+    ///     .await;
+    /// # }
+    /// ```
+    pub async fn register_health_check<C>(&self, name: impl Into<String>, check: C)
+    where
+        C: HealthCheck + Send + Sync + 'static,
+    {
+        self.dependency_health
+            .write()
+            .await
+            .register(name.into(), check);
+    }
+
+    /// Register a dependency check that itself relies on other components
+    ///
+    /// `/ready` reports the component as degraded while any of `deps` is
+    /// unhealthy or degraded. Dependencies may name built-in components such
+    /// as `memory` or other registered checks.
+    pub async fn register_health_check_with_deps<C>(
+        &self,
+        name: impl Into<String>,
+        check: C,
+        deps: &[&str],
+    ) where
+        C: HealthCheck + Send + Sync + 'static,
+    {
+        self.dependency_health
+            .write()
+            .await
+            .register_with_deps(name.into(), check, deps);
     }
 
     /// Create a new agent from specification using the factory pattern
@@ -217,7 +388,42 @@ impl<T: ToolRegistry + Clone + Send + Sync + 'static> HttpAgentRuntime<T> {
         spec: AgentSpec,
         custom_id: Option<String>,
     ) -> Result<CreateAgentResponse, AgentFactoryError> {
-        self.agent_factory.create_agent(spec, custom_id).await
+        self.create_agent_as(spec, custom_id, None).await
+    }
+
+    /// Create a new agent on behalf of `principal`, enforcing their creation rate limit
+    pub async fn create_agent_as(
+        &self,
+        spec: AgentSpec,
+        custom_id: Option<String>,
+        principal: Option<&str>,
+    ) -> Result<CreateAgentResponse, AgentFactoryError> {
+        let response = self
+            .agent_factory
+            .create_agent_as(spec, custom_id, principal)
+            .await?;
+        self.backpressure_manager
+            .register_agent_type(&response.agent_id, response.spec.agent_type.to_string())
+            .await;
+        Ok(response)
+    }
+
+    /// Create the agent identified by `natural_key`, returning or rejecting an existing one
+    pub async fn create_agent_with_key(
+        &self,
+        spec: AgentSpec,
+        natural_key: &str,
+        principal: Option<&str>,
+        on_conflict: OnConflict,
+    ) -> Result<CreateAgentResponse, AgentFactoryError> {
+        let response = self
+            .agent_factory
+            .create_agent_with_key(spec, natural_key, principal, on_conflict)
+            .await?;
+        self.backpressure_manager
+            .register_agent_type(&response.agent_id, response.spec.agent_type.to_string())
+            .await;
+        Ok(response)
     }
 
     /// Get list of supported agent types
@@ -232,7 +438,15 @@ impl<T: ToolRegistry + Clone + Send + Sync + 'static> HttpAgentRuntime<T> {
 
     /// Remove an agent by ID
     pub async fn remove_agent(&self, agent_id: &str) -> Result<(), AgentFactoryError> {
-        self.agent_factory.remove_agent(agent_id).await
+        self.agent_factory.remove_agent(agent_id).await?;
+        self.backpressure_manager.remove_agent_queue(agent_id).await;
+        self.backpressure_manager
+            .unregister_agent_type(agent_id)
+            .await;
+        self.backpressure_manager
+            .remove_circuit_breaker(agent_id)
+            .await;
+        Ok(())
     }
 
     /// Check if an agent exists by ID
@@ -276,6 +490,105 @@ impl<T: ToolRegistry + Clone + Send + Sync + 'static> HttpAgentRuntime<T> {
         self.agent_factory.shutdown_all_agents().await
     }
 
+    /// Gracefully shut down the runtime and report the outcome
+    ///
+    /// Stops accepting new requests, gives in-flight requests up to `grace`
+    /// to complete, rejects anything still queued, and then shuts down all
+    /// agents. The returned [`ShutdownReport`] is also published as metrics
+    /// and logged.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use skreaver_http::runtime::{HttpAgentRuntime, shutdown_signal};
+    /// use skreaver_tools::InMemoryToolRegistry;
+    /// use std::time::Duration;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let runtime = HttpAgentRuntime::new(InMemoryToolRegistry::new());
+    ///
+    ///     shutdown_signal().await;
+    ///     let report = runtime.shutdown(Duration::from_secs(30)).await;
+    ///     println!("drained {} requests", report.requests_drained);
+    /// }
+    /// ```
+    pub async fn shutdown(&self, grace: Duration) -> ShutdownReport {
+        let started = Instant::now();
+        let report = self.drain_requests(grace).await;
+        self.finish_shutdown(report, started).await
+    }
+
+    /// Gracefully shut down the runtime, closing WebSocket connections too
+    ///
+    /// Behaves like [`shutdown`](Self::shutdown), and closes every connection
+    /// held by `websockets` once requests have drained.
+    #[cfg(feature = "websocket")]
+    pub async fn shutdown_with_websockets(
+        &self,
+        grace: Duration,
+        websockets: &crate::websocket::WebSocketManager,
+    ) -> ShutdownReport {
+        let started = Instant::now();
+        let mut report = self.drain_requests(grace).await;
+        report.ws_connections_closed = websockets.close_all_connections().await;
+        websockets.shutdown().await;
+        self.finish_shutdown(report, started).await
+    }
+
+    async fn drain_requests(&self, grace: Duration) -> ShutdownReport {
+        tracing::info!(
+            grace_ms = grace.as_millis() as u64,
+            "Draining requests for shutdown"
+        );
+        let summary = self.backpressure_manager.drain(grace).await;
+        ShutdownReport {
+            requests_drained: summary.drained,
+            requests_rejected: summary.rejected,
+            requests_abandoned: summary.abandoned,
+            ..Default::default()
+        }
+    }
+
+    async fn finish_shutdown(
+        &self,
+        mut report: ShutdownReport,
+        started: Instant,
+    ) -> ShutdownReport {
+        report.agents_stopped = self.shutdown_all_agents().await;
+        report.drain_duration = started.elapsed();
+        report.emit();
+        report
+    }
+
+    /// Mark an agent as persistent, exempting it from idle eviction
+    pub async fn set_agent_persistent(
+        &self,
+        agent_id: &str,
+        persistent: bool,
+    ) -> Result<(), AgentFactoryError> {
+        self.agent_factory
+            .set_persistent(agent_id, persistent)
+            .await
+    }
+
+    /// Set or clear the log level override for one agent's steps
+    pub async fn set_agent_log_level(
+        &self,
+        agent_id: &str,
+        level: Option<tracing::Level>,
+    ) -> Result<(), AgentFactoryError> {
+        self.agent_factory.set_log_level(agent_id, level).await
+    }
+
+    /// Get an agent's log level override, if any
+    pub async fn agent_log_level(
+        &self,
+        agent_id: &str,
+    ) -> Result<Option<tracing::Level>, AgentFactoryError> {
+        self.agent_factory.log_level(agent_id).await
+    }
+
     /// Get agent count
     pub async fn agent_count(&self) -> usize {
         self.agent_factory.agent_count().await
@@ -296,7 +609,8 @@ impl<T: ToolRegistry + Clone + Send + Sync + 'static> HttpAgentRuntime<T> {
         let agent_id =
             AgentId::parse(agent_id.as_ref()).map_err(|e| format!("Invalid agent ID: {}", e))?;
 
-        let coordinator = Coordinator::new(agent, (*self.tool_registry).clone());
+        let coordinator = Coordinator::new(agent, (*self.tool_registry).clone())
+            .with_build_context(self.agent_factory.build_context());
         let agent_instance = crate::runtime::agent_instance::AgentInstance::new(
             agent_id.clone(),
             std::any::type_name::<A>().to_string(),
@@ -304,6 +618,15 @@ impl<T: ToolRegistry + Clone + Send + Sync + 'static> HttpAgentRuntime<T> {
         );
 
         let mut agents = self.agents.write().await;
+        if !agents.contains_key(&agent_id) {
+            self.agent_factory
+                .check_capacity(agents.len())
+                .map_err(|e| e.to_string())?;
+        }
+
+        self.backpressure_manager
+            .register_agent_type(agent_id.as_str(), std::any::type_name::<A>())
+            .await;
         agents.insert(agent_id, agent_instance);
         Ok(())
     }
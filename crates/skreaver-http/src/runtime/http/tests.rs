@@ -175,6 +175,78 @@ async fn test_list_agents() {
     assert_eq!(json["agents"].as_array().unwrap().len(), 2);
 }
 
+fn list_agents_request(uri: &str, accept: Option<&str>) -> Request<Body> {
+    let mut builder = Request::builder()
+        .uri(uri)
+        .header("Authorization", format!("Bearer {}", create_test_token()));
+    if let Some(accept) = accept {
+        builder = builder.header("Accept", accept);
+    }
+    builder.body(Body::empty()).unwrap()
+}
+
+#[tokio::test]
+async fn test_list_agents_versioned_response_shapes() {
+    let runtime = create_test_runtime();
+    setup_test_agent(&runtime, "test-agent-1").await;
+    let app = runtime.router();
+
+    // Unversioned requests get the documented default (v1)
+    for (uri, accept) in [
+        ("/agents", None),
+        ("/v1/agents", None),
+        ("/agents", Some("application/vnd.skreaver.v1+json")),
+    ] {
+        let response = app
+            .clone()
+            .oneshot(list_agents_request(uri, accept))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()["x-api-version"], "v1");
+        let json = response_json(response).await;
+        assert_eq!(json["total"], 1);
+        assert_eq!(json["agents"].as_array().unwrap().len(), 1);
+    }
+
+    for (uri, accept) in [
+        ("/v2/agents", None),
+        ("/agents", Some("application/vnd.skreaver.v2+json")),
+    ] {
+        let response = app
+            .clone()
+            .oneshot(list_agents_request(uri, accept))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()["x-api-version"], "v2");
+        let json = response_json(response).await;
+        assert_eq!(json["meta"]["total"], 1);
+        assert_eq!(json["data"].as_array().unwrap().len(), 1);
+        assert!(json.get("agents").is_none());
+    }
+}
+
+#[tokio::test]
+async fn test_unknown_api_version_rejected() {
+    let app = create_test_runtime().router();
+
+    for (uri, accept) in [
+        ("/agents", Some("application/vnd.skreaver.v9+json")),
+        ("/v9/agents", None),
+    ] {
+        let response = app
+            .clone()
+            .oneshot(list_agents_request(uri, accept))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_ACCEPTABLE);
+        let json = response_json(response).await;
+        assert_eq!(json["error"], "unsupported_api_version");
+        assert_eq!(json["details"]["requested"], "v9");
+    }
+}
+
 #[tokio::test]
 async fn test_get_agent_status() {
     let runtime = create_test_runtime();
@@ -303,6 +375,156 @@ async fn test_openapi_spec_endpoint() {
     assert!(json["paths"].is_object());
 }
 
+fn docs_config(openapi: super::OpenApiConfig) -> super::HttpRuntimeConfig {
+    super::HttpRuntimeConfig {
+        openapi: Some(openapi),
+        ..Default::default()
+    }
+}
+
+fn get_request(uri: &str) -> Request<Body> {
+    Request::builder().uri(uri).body(Body::empty()).unwrap()
+}
+
+#[tokio::test]
+async fn test_openapi_spec_rate_limited_independently() {
+    let runtime = create_test_runtime();
+    let rpm = std::num::NonZeroU32::new(2).unwrap();
+    let app = runtime.router_with_config(docs_config(
+        super::OpenApiConfig::new().with_rate_limit(Some(rpm)),
+    ));
+
+    for _ in 0..2 {
+        let response = app
+            .clone()
+            .oneshot(get_request("/api-docs/openapi.json"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    // Docs budget is shared between the UI and the spec
+    let response = app.clone().oneshot(get_request("/docs")).await.unwrap();
+    assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+    assert!(response.headers().contains_key("retry-after"));
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let json: Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(json["error"], "docs_rate_limit_exceeded");
+
+    // API routes are unaffected by the exhausted docs budget
+    let response = app.oneshot(get_request("/health")).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_exhausted_mutation_budget_does_not_throttle_reads() {
+    use crate::runtime::rate_limit::{RateLimitKey, RouteGroupLimit, RouteRateLimits};
+
+    let runtime = create_test_runtime();
+    setup_test_agent(&runtime, "route-limit-agent").await;
+    let rpm = |n| std::num::NonZeroU32::new(n).unwrap();
+    let app = runtime.router_with_config(super::HttpRuntimeConfig {
+        route_rate_limits: Some(
+            RouteRateLimits::default()
+                .with_mutation(RouteGroupLimit::new(rpm(2), RateLimitKey::User))
+                .with_read(RouteGroupLimit::new(rpm(100), RateLimitKey::User)),
+        ),
+        ..Default::default()
+    });
+    let token = create_test_token();
+    let observe = || {
+        Request::builder()
+            .method("POST")
+            .uri("/agents/route-limit-agent/observe")
+            .header("Authorization", format!("Bearer {}", token))
+            .header("content-type", "application/json")
+            .body(Body::from(json!({ "input": "hi" }).to_string()))
+            .unwrap()
+    };
+
+    for _ in 0..2 {
+        let response = app.clone().oneshot(observe()).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+    let response = app.clone().oneshot(observe()).await.unwrap();
+    assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+    assert!(response.headers().contains_key("retry-after"));
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let json: Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(json["error"], "mutation_rate_limit_exceeded");
+
+    // Reads by the same user, versioned or not, have their own budget
+    for uri in [
+        "/agents/route-limit-agent/status",
+        "/v1/agents/route-limit-agent/status",
+    ] {
+        let request = Request::builder()
+            .uri(uri)
+            .header("Authorization", format!("Bearer {}", token))
+            .body(Body::empty())
+            .unwrap();
+        let response = app.clone().oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+    let response = app.oneshot(get_request("/health")).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_openapi_spec_served_from_cache() {
+    let runtime = create_test_runtime();
+    let app = runtime.router_with_config(docs_config(
+        super::OpenApiConfig::new().with_rate_limit(None),
+    ));
+
+    let mut bodies = Vec::new();
+    for _ in 0..3 {
+        let response = app
+            .clone()
+            .oneshot(get_request("/api-docs/openapi.json"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()["content-type"], "application/json");
+        bodies.push(
+            axum::body::to_bytes(response.into_body(), usize::MAX)
+                .await
+                .unwrap(),
+        );
+    }
+
+    assert!(bodies.iter().all(|body| body == &bodies[0]));
+    assert_eq!(
+        bodies[0],
+        crate::runtime::docs::openapi_spec_json().as_bytes()
+    );
+}
+
+#[tokio::test]
+async fn test_openapi_docs_require_auth() {
+    let runtime = create_test_runtime();
+    let app = runtime.router_with_config(docs_config(super::OpenApiConfig::new().with_auth(true)));
+
+    let response = app
+        .clone()
+        .oneshot(get_request("/api-docs/openapi.json"))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    let request = Request::builder()
+        .uri("/api-docs/openapi.json")
+        .header("Authorization", format!("Bearer {}", create_test_token()))
+        .body(Body::empty())
+        .unwrap();
+    let response = app.oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
 #[tokio::test]
 async fn test_batch_observe_agent() {
     let runtime = create_test_runtime();
@@ -317,15 +539,21 @@ async fn test_batch_observe_agent() {
         "timeout_seconds": 30
     });
 
-    let request = Request::builder()
-        .method("POST")
-        .uri("/agents/batch-test-agent/batch")
-        .header("Authorization", format!("Bearer {}", token))
-        .header("content-type", "application/json")
-        .body(Body::from(request_body.to_string()))
-        .unwrap();
+    let batch_request = |uri: &str| {
+        Request::builder()
+            .method("POST")
+            .uri(uri)
+            .header("Authorization", format!("Bearer {}", token))
+            .header("content-type", "application/json")
+            .body(Body::from(request_body.to_string()))
+            .unwrap()
+    };
 
-    let response = app.oneshot(request).await.unwrap();
+    let response = app
+        .clone()
+        .oneshot(batch_request("/agents/batch-test-agent/batch"))
+        .await
+        .unwrap();
     assert_eq!(response.status(), StatusCode::OK);
 
     let body = axum::body::to_bytes(response.into_body(), usize::MAX)
@@ -336,15 +564,40 @@ async fn test_batch_observe_agent() {
     assert_eq!(json["agent_id"], "batch-test-agent");
     assert_eq!(json["results"].as_array().unwrap().len(), 3);
     assert!(json["total_time_ms"].as_u64().is_some());
+    assert!(json.get("summary").is_none());
 
     // Check individual results
     let results = json["results"].as_array().unwrap();
     for (i, result) in results.iter().enumerate() {
         assert_eq!(result["index"], i);
+        assert_eq!(result["input"], format!("Hello batch {}", i + 1));
         assert_eq!(result["status"], "success");
         assert!(result["response"].is_string());
         assert!(result["processing_time_ms"].as_u64().is_some());
     }
+
+    // v2 reports results in the uniform batch shape with a summary
+    let response = app
+        .oneshot(batch_request("/v2/agents/batch-test-agent/batch"))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let json: Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(
+        json["summary"],
+        json!({"total": 3, "succeeded": 3, "failed": 0})
+    );
+    let results = json["results"].as_array().unwrap();
+    for (i, result) in results.iter().enumerate() {
+        assert_eq!(result["index"], i);
+        assert_eq!(result["status"], "success");
+        assert!(result["result"].is_string());
+        assert!(result.get("input").is_none());
+    }
 }
 
 #[tokio::test]
@@ -629,6 +882,36 @@ async fn test_high_concurrency_stress() {
     );
 }
 
+fn has_queue_depth_series(agent_id: &str) -> bool {
+    use prometheus::core::Collector;
+    skreaver_observability::get_metrics_registry()
+        .expect("runtime initializes the metrics registry")
+        .core_metrics()
+        .backpressure_queue_depth
+        .collect()
+        .iter()
+        .flat_map(|family| family.get_metric())
+        .any(|metric| {
+            metric
+                .get_label()
+                .iter()
+                .any(|label| label.value() == agent_id)
+        })
+}
+
+#[tokio::test]
+async fn test_removed_agent_drops_queue_depth_series() {
+    let runtime = create_test_runtime();
+    setup_test_agent(&runtime, "gauge-agent").await;
+    skreaver_observability::get_metrics_registry()
+        .unwrap()
+        .set_agent_queue_depth("gauge-agent", 0);
+    assert!(has_queue_depth_series("gauge-agent"));
+
+    runtime.remove_agent("gauge-agent").await.unwrap();
+    assert!(!has_queue_depth_series("gauge-agent"));
+}
+
 // ===================================================================
 // Authentication Integration Tests
 // ===================================================================
@@ -909,3 +1192,1287 @@ async fn test_all_agent_endpoints_require_auth() {
         );
     }
 }
+
+#[tokio::test]
+async fn test_admin_can_trip_and_reset_circuit_breaker() {
+    let runtime = create_test_runtime();
+    setup_test_agent(&runtime, "breaker-agent").await;
+    let app = runtime.router();
+
+    let admin_token = create_jwt_token("ops-user".to_string(), vec!["admin".to_string()]).unwrap();
+    let observe = |token: &str| {
+        Request::builder()
+            .method("POST")
+            .uri("/agents/breaker-agent/observe")
+            .header("Authorization", format!("Bearer {}", token))
+            .header("content-type", "application/json")
+            .body(Body::from(json!({"input": "hello"}).to_string()))
+            .unwrap()
+    };
+
+    // Trip the breaker
+    let request = Request::builder()
+        .method("POST")
+        .uri("/agents/breaker-agent/circuit/open")
+        .header("Authorization", format!("Bearer {}", admin_token))
+        .header("content-type", "application/json")
+        .body(Body::from(json!({"reason": "incident"}).to_string()))
+        .unwrap();
+    let response = app.clone().oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let json: Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(json["state"], "open");
+    assert_eq!(json["reason"], "incident");
+    assert_eq!(json["changed_by"], "ops-user");
+
+    // New requests fast-fail while the breaker is open
+    let response = app
+        .clone()
+        .oneshot(observe(&create_test_token()))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+
+    let request = Request::builder()
+        .uri("/agents/breaker-agent/circuit")
+        .header("Authorization", format!("Bearer {}", admin_token))
+        .body(Body::empty())
+        .unwrap();
+    let response = app.clone().oneshot(request).await.unwrap();
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let json: Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(json["state"], "open");
+    assert_eq!(json["total_rejections"], 1);
+
+    // Reset the breaker
+    let request = Request::builder()
+        .method("POST")
+        .uri("/agents/breaker-agent/circuit/close")
+        .header("Authorization", format!("Bearer {}", admin_token))
+        .body(Body::empty())
+        .unwrap();
+    let response = app.clone().oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let response = app.oneshot(observe(&create_test_token())).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_overload_rejection_includes_retry_after() {
+    use crate::runtime::backpressure::CircuitState;
+
+    let runtime = create_test_runtime();
+    setup_test_agent(&runtime, "retry-agent").await;
+    runtime
+        .backpressure_manager
+        .set_circuit_state("retry-agent", CircuitState::Open, None, None)
+        .await;
+    let app = runtime.router();
+    let token = create_test_token();
+
+    let observe = |agent_id: &str| {
+        Request::builder()
+            .method("POST")
+            .uri(format!("/agents/{}/observe", agent_id))
+            .header("Authorization", format!("Bearer {}", token))
+            .header("content-type", "application/json")
+            .body(Body::from(json!({"input": "hello"}).to_string()))
+            .unwrap()
+    };
+
+    let response = app.clone().oneshot(observe("retry-agent")).await.unwrap();
+    assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+    let retry_after: u64 = response.headers()["retry-after"]
+        .to_str()
+        .unwrap()
+        .parse()
+        .unwrap();
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let json: Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(json["error"], "backpressure_error");
+    assert_eq!(json["details"]["jitter"], true);
+    let retry_after_ms = json["details"]["retry_after_ms"].as_u64().unwrap();
+    assert!(retry_after_ms > 0);
+    assert_eq!(retry_after, retry_after_ms.div_ceil(1000));
+
+    // Errors that retrying will not fix carry no retry guidance
+    let response = app.oneshot(observe("missing-agent")).await.unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    assert!(response.headers().get("retry-after").is_none());
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let json: Value = serde_json::from_slice(&body).unwrap();
+    assert!(json.get("details").is_none());
+}
+
+#[tokio::test]
+async fn test_circuit_breaker_endpoints_require_admin() {
+    let runtime = create_test_runtime();
+    setup_test_agent(&runtime, "breaker-agent").await;
+    let app = runtime.router();
+
+    let request = Request::builder()
+        .method("POST")
+        .uri("/agents/breaker-agent/circuit/open")
+        .header("Authorization", format!("Bearer {}", create_test_token()))
+        .body(Body::empty())
+        .unwrap();
+    let response = app.clone().oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+    let request = Request::builder()
+        .uri("/agents/breaker-agent/circuit")
+        .body(Body::empty())
+        .unwrap();
+    let response = app.clone().oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    let admin_token = create_jwt_token("ops-user".to_string(), vec!["admin".to_string()]).unwrap();
+    let request = Request::builder()
+        .method("POST")
+        .uri("/agents/breaker-agent/circuit/explode")
+        .header("Authorization", format!("Bearer {}", admin_token))
+        .body(Body::empty())
+        .unwrap();
+    let response = app.oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_admin_can_set_and_clear_agent_log_level() {
+    let runtime = create_test_runtime();
+    setup_test_agent(&runtime, "log-level-agent").await;
+    let app = runtime.clone().router();
+
+    let admin_token = create_jwt_token("ops-user".to_string(), vec!["admin".to_string()]).unwrap();
+    let set_level = |token: &str, level: Value| {
+        Request::builder()
+            .method("PUT")
+            .uri("/agents/log-level-agent/log-level")
+            .header("Authorization", format!("Bearer {}", token))
+            .header("content-type", "application/json")
+            .body(Body::from(json!({ "level": level }).to_string()))
+            .unwrap()
+    };
+
+    let response = app
+        .clone()
+        .oneshot(set_level(&create_test_token(), json!("debug")))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+    let response = app
+        .clone()
+        .oneshot(set_level(&admin_token, json!("debug")))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let json: Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(json["level"], "debug");
+    assert_eq!(
+        runtime.agent_log_level("log-level-agent").await.unwrap(),
+        Some(tracing::Level::DEBUG)
+    );
+    assert_eq!(
+        skreaver_observability::agent_log_levels().get("log-level-agent"),
+        Some(tracing::Level::DEBUG)
+    );
+
+    let response = app
+        .clone()
+        .oneshot(set_level(&admin_token, json!("loud")))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let response = app
+        .clone()
+        .oneshot(set_level(&admin_token, Value::Null))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let request = Request::builder()
+        .uri("/agents/log-level-agent/log-level")
+        .header("Authorization", format!("Bearer {}", admin_token))
+        .body(Body::empty())
+        .unwrap();
+    let response = app.oneshot(request).await.unwrap();
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let json: Value = serde_json::from_slice(&body).unwrap();
+    assert!(json["level"].is_null());
+    assert_eq!(
+        skreaver_observability::agent_log_levels().get("log-level-agent"),
+        None
+    );
+}
+
+fn dlq_config(mesh: std::sync::Arc<skreaver_mesh::InMemoryMesh>) -> super::HttpRuntimeConfig {
+    let dlq = std::sync::Arc::new(skreaver_mesh::DeadLetterQueue::with_defaults());
+    super::HttpRuntimeConfig {
+        dead_letters: Some(crate::runtime::DlqAdmin::new(dlq, mesh)),
+        ..Default::default()
+    }
+}
+
+#[tokio::test]
+async fn test_admin_lists_dlq_entries_with_pagination() {
+    let mesh = std::sync::Arc::new(skreaver_mesh::InMemoryMesh::new());
+    let config = dlq_config(mesh);
+    let dlq = std::sync::Arc::clone(config.dead_letters.as_ref().unwrap().dlq());
+    for i in 0..5 {
+        let reason = if i == 4 { "rejected" } else { "timeout" };
+        dlq.add(skreaver_mesh::Message::new(format!("msg-{}", i)), reason)
+            .await
+            .unwrap();
+    }
+    let app = create_test_runtime().router_with_config(config);
+
+    let request = Request::builder()
+        .uri("/dlq")
+        .header("Authorization", format!("Bearer {}", create_test_token()))
+        .body(Body::empty())
+        .unwrap();
+    let response = app.clone().oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+    let response = app
+        .clone()
+        .oneshot(admin_request("GET", "/dlq?reason=timeout&offset=1&limit=2"))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let json: Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(json["total"], 4);
+    assert_eq!(json["offset"], 1);
+    assert_eq!(json["limit"], 2);
+    let entries = json["entries"].as_array().unwrap();
+    assert_eq!(entries.len(), 2);
+    assert_eq!(entries[0]["message"]["payload"]["data"], "msg-1");
+    assert_eq!(entries[1]["message"]["payload"]["data"], "msg-2");
+    assert_eq!(entries[0]["failure_reason"], "timeout");
+
+    let response = app
+        .clone()
+        .oneshot(admin_request("GET", "/dlq/stats"))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let json: Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(json["size"], 5);
+    assert!(json["oldest_age_secs"].is_u64());
+
+    let response = app
+        .clone()
+        .oneshot(admin_request("DELETE", "/dlq?reason=rejected"))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(dlq.size().await, 4);
+}
+
+/// Tool returning a tiny PNG, base64-encoded as `ExecutionResult` requires
+struct PngTool;
+
+const PNG_BYTES: &[u8] = &[0x89, b'P', b'N', b'G', 0x0d, 0x0a, 0x1a, 0x0a, 0x00, 0xff];
+
+impl skreaver_core::Tool for PngTool {
+    fn name(&self) -> &str {
+        "render_png"
+    }
+
+    fn call(&self, _input: String) -> ExecutionResult {
+        use base64::Engine;
+        ExecutionResult::success_with_content_type(
+            base64::engine::general_purpose::STANDARD.encode(PNG_BYTES),
+            "image/png",
+        )
+    }
+}
+
+/// Tool returning plain output without a content type
+struct UpperTool;
+
+impl skreaver_core::Tool for UpperTool {
+    fn name(&self) -> &str {
+        "upper"
+    }
+
+    fn call(&self, input: String) -> ExecutionResult {
+        ExecutionResult::success(input.to_uppercase())
+    }
+}
+
+#[tokio::test]
+async fn test_tool_declaring_png_returns_image_bytes() {
+    let registry = InMemoryToolRegistry::new()
+        .with_tool("render_png", std::sync::Arc::new(PngTool))
+        .with_tool("upper", std::sync::Arc::new(UpperTool));
+    // The default RBAC policy denies tools without a policy
+    let mut role_manager = skreaver_core::RoleManager::with_defaults();
+    role_manager.add_default_allow_policy("render_png");
+    role_manager.add_default_allow_policy("upper");
+    let mut runtime = HttpAgentRuntime::new(registry.clone());
+    runtime.tool_registry = std::sync::Arc::new(skreaver_tools::SecureToolRegistry::new(
+        registry,
+        std::sync::Arc::clone(&runtime.security_config),
+        std::sync::Arc::new(role_manager),
+    ));
+    let app = runtime.router();
+    let tool_token = create_jwt_token(
+        "tool-user".to_string(),
+        vec![crate::runtime::auth::TOOL_EXECUTE_PERMISSION.to_string()],
+    )
+    .unwrap();
+    let invoke_as = |tool: &str, token: &str| {
+        Request::builder()
+            .method("POST")
+            .uri(format!("/tools/{}/invoke", tool))
+            .header("Authorization", format!("Bearer {}", token))
+            .header("content-type", "application/json")
+            .body(Body::from(json!({ "input": "chart" }).to_string()))
+            .unwrap()
+    };
+    let invoke = |tool: &str| invoke_as(tool, &tool_token);
+
+    // Agent access alone does not allow running tools directly
+    let response = app
+        .clone()
+        .oneshot(invoke_as("upper", &create_test_token()))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+    let response = app.clone().oneshot(invoke("render_png")).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers()["content-type"], "image/png");
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    assert_eq!(&body[..], PNG_BYTES);
+
+    let response = app.clone().oneshot(invoke("upper")).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers()["content-type"], "application/json");
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let json: Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(json["output"], "CHART");
+}
+
+#[tokio::test]
+async fn test_tool_invoke_denial_reports_reason_and_remedy() {
+    let registry = InMemoryToolRegistry::new().with_tool("upper", std::sync::Arc::new(UpperTool));
+    // No policy covers "upper", so the default RBAC policy denies it
+    let app = HttpAgentRuntime::new(registry).router();
+    let tool_token = create_jwt_token(
+        "tool-user".to_string(),
+        vec![crate::runtime::auth::TOOL_EXECUTE_PERMISSION.to_string()],
+    )
+    .unwrap();
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/tools/upper/invoke")
+                .header("Authorization", format!("Bearer {}", tool_token))
+                .header("content-type", "application/json")
+                .body(Body::from(json!({ "input": "chart" }).to_string()))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let json: Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(json["error"], "insufficient_permissions");
+    assert_eq!(json["details"]["tool"], "upper");
+    assert_eq!(json["details"]["reason"], "no_policy");
+    assert!(json["details"]["remedy"].is_string());
+}
+
+#[tokio::test]
+async fn test_admin_replay_removes_resent_dlq_entry() {
+    let mesh = std::sync::Arc::new(skreaver_mesh::InMemoryMesh::new());
+    let config = dlq_config(std::sync::Arc::clone(&mesh));
+    let dlq = std::sync::Arc::clone(config.dead_letters.as_ref().unwrap().dlq());
+    let worker = skreaver_mesh::AgentId::new_unchecked("worker");
+    let message = skreaver_mesh::Message::unicast(
+        skreaver_mesh::AgentId::new_unchecked("sender"),
+        worker.clone(),
+        "retry me",
+    );
+    let message_id = message.id.to_string();
+    dlq.add(message, "worker offline").await.unwrap();
+    let app = create_test_runtime().router_with_config(config);
+
+    let response = app
+        .clone()
+        .oneshot(admin_request("GET", &format!("/dlq/{}", message_id)))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let response = app
+        .clone()
+        .oneshot(admin_request(
+            "POST",
+            &format!("/dlq/{}/replay", message_id),
+        ))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(dlq.size().await, 0);
+    let delivered = mesh
+        .receive(&worker, std::time::Duration::from_millis(100))
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(delivered.id.to_string(), message_id);
+
+    let response = app
+        .clone()
+        .oneshot(admin_request("GET", &format!("/dlq/{}", message_id)))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+/// Dependency that is always unreachable
+struct UnreachableDependency;
+
+#[async_trait::async_trait]
+impl skreaver_observability::HealthCheck for UnreachableDependency {
+    async fn check(&self) -> Result<(), String> {
+        Err("connection refused".to_string())
+    }
+}
+
+async fn ready_components(app: axum::Router) -> (StatusCode, Value) {
+    let request = Request::builder()
+        .uri("/ready")
+        .body(Body::empty())
+        .unwrap();
+    let response = app.oneshot(request).await.unwrap();
+    let status = response.status();
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let json: Value = serde_json::from_slice(&body).unwrap();
+    (status, json["components"].clone())
+}
+
+#[tokio::test]
+async fn test_readiness_reflects_memory_backend_health() {
+    let runtime = create_test_runtime();
+    runtime
+        .register_health_check(
+            "memory",
+            skreaver_observability::MemoryHealthCheck::new(InMemoryMemory::new()),
+        )
+        .await;
+
+    let (_, components) = ready_components(runtime.clone().router()).await;
+    assert_eq!(components["memory"]["status"], "Healthy");
+
+    runtime
+        .register_health_check("redis", UnreachableDependency)
+        .await;
+
+    let (status, components) = ready_components(runtime.router()).await;
+    assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+    assert_eq!(
+        components["redis"]["status"]["Unhealthy"]["reason"],
+        "connection refused"
+    );
+}
+
+#[tokio::test]
+async fn test_readiness_rolls_up_dependency_health() {
+    let runtime = create_test_runtime();
+    runtime
+        .register_health_check_with_deps(
+            "agent_runtime",
+            skreaver_observability::health::AlwaysHealthy,
+            &["redis"],
+        )
+        .await;
+    runtime
+        .register_health_check("redis", UnreachableDependency)
+        .await;
+
+    let (status, components) = ready_components(runtime.router()).await;
+    assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+    assert_eq!(components["agent_runtime"]["depends_on"], json!(["redis"]));
+    let reason = components["agent_runtime"]["status"]["Degraded"]["reason"]
+        .as_str()
+        .unwrap();
+    assert!(reason.contains("'redis' is unhealthy"), "{}", reason);
+}
+
+fn create_agent_request(token: &str) -> Request<Body> {
+    Request::builder()
+        .method("POST")
+        .uri("/agents")
+        .header("Authorization", format!("Bearer {}", token))
+        .header("content-type", "application/json")
+        .body(Body::from(
+            json!({"spec": {"agent_type": "echo"}}).to_string(),
+        ))
+        .unwrap()
+}
+
+async fn response_json(response: axum::response::Response) -> Value {
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    serde_json::from_slice(&body).unwrap()
+}
+
+#[tokio::test]
+async fn test_agent_limit_rejects_creation_until_removal() {
+    let runtime = HttpAgentRuntime::with_config(
+        InMemoryToolRegistry::new(),
+        super::HttpRuntimeConfig {
+            agent_quota: crate::runtime::AgentQuotaConfig {
+                max_agents: Some(1),
+                creations_per_minute: None,
+            },
+            ..Default::default()
+        },
+    );
+    let app = runtime.clone().router();
+    let token = create_test_token();
+
+    let response = app
+        .clone()
+        .oneshot(create_agent_request(&token))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let agent_id = response_json(response).await["agent_id"]
+        .as_str()
+        .unwrap()
+        .to_string();
+
+    let response = app
+        .clone()
+        .oneshot(create_agent_request(&token))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+    let json = response_json(response).await;
+    assert_eq!(json["error"], "agent_limit_reached");
+    assert_eq!(json["details"]["max_agents"], 1);
+
+    runtime.remove_agent(&agent_id).await.unwrap();
+
+    let response = app.oneshot(create_agent_request(&token)).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(runtime.agent_count().await, 1);
+}
+
+#[tokio::test]
+async fn test_idle_agents_evicted_unless_persistent() {
+    let runtime = HttpAgentRuntime::with_config(
+        InMemoryToolRegistry::new(),
+        super::HttpRuntimeConfig {
+            agent_eviction: crate::runtime::AgentEvictionConfig::after(
+                std::time::Duration::from_millis(100),
+            )
+            .with_sweep_interval(std::time::Duration::from_millis(20)),
+            ..Default::default()
+        },
+    );
+    let app = runtime.clone().router();
+    let token = create_test_token();
+
+    let response = app
+        .clone()
+        .oneshot(create_agent_request(&token))
+        .await
+        .unwrap();
+    let idle_id = response_json(response).await["agent_id"]
+        .as_str()
+        .unwrap()
+        .to_string();
+
+    let request = Request::builder()
+        .method("POST")
+        .uri("/agents")
+        .header("Authorization", format!("Bearer {}", token))
+        .header("content-type", "application/json")
+        .body(Body::from(
+            json!({"spec": {"agent_type": "echo", "config": {"persistent": true}}}).to_string(),
+        ))
+        .unwrap();
+    let response = app.oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let persistent_id = response_json(response).await["agent_id"]
+        .as_str()
+        .unwrap()
+        .to_string();
+    assert_eq!(runtime.agent_count().await, 2);
+    skreaver_observability::get_metrics_registry()
+        .unwrap()
+        .set_agent_queue_depth(&idle_id, 0);
+
+    tokio::time::sleep(std::time::Duration::from_millis(300)).await;
+
+    assert!(!runtime.agent_factory.has_agent(&idle_id).await);
+    assert!(!has_queue_depth_series(&idle_id));
+    assert!(runtime.agent_factory.has_agent(&persistent_id).await);
+    assert_eq!(runtime.agent_count().await, 1);
+}
+
+#[test]
+fn test_eviction_is_opt_in() {
+    use crate::runtime::AgentEvictionConfig;
+
+    assert_eq!(
+        super::HttpRuntimeConfig::default().agent_eviction,
+        AgentEvictionConfig::disabled()
+    );
+    let config = AgentEvictionConfig::after(std::time::Duration::from_secs(60))
+        .with_sweep_interval(std::time::Duration::ZERO);
+    assert!(!config.sweep_interval.is_zero());
+}
+
+#[tokio::test]
+async fn test_active_agent_not_evicted() {
+    let runtime = create_test_runtime();
+    setup_test_agent(&runtime, "busy-agent").await;
+    setup_test_agent(&runtime, "quiet-agent").await;
+    runtime
+        .set_agent_persistent("quiet-agent", true)
+        .await
+        .unwrap();
+
+    tokio::time::sleep(std::time::Duration::from_millis(60)).await;
+    {
+        let mut agents = runtime.agents.write().await;
+        let id = skreaver_core::AgentId::parse("busy-agent").unwrap();
+        agents.get_mut(&id).unwrap().step("ping".to_string());
+    }
+
+    let evicted = runtime
+        .agent_factory
+        .evict_idle_agents(std::time::Duration::from_millis(50))
+        .await;
+    assert!(evicted.is_empty());
+    assert_eq!(runtime.agent_count().await, 2);
+}
+
+#[tokio::test]
+async fn test_agent_creation_rate_limited_per_principal() {
+    let runtime = HttpAgentRuntime::with_config(
+        InMemoryToolRegistry::new(),
+        super::HttpRuntimeConfig {
+            agent_quota: crate::runtime::AgentQuotaConfig {
+                max_agents: None,
+                creations_per_minute: std::num::NonZeroU32::new(1),
+            },
+            ..Default::default()
+        },
+    );
+    let app = runtime.router();
+    let token = create_test_token();
+    let other_token =
+        create_jwt_token("other-user".to_string(), vec!["write".to_string()]).unwrap();
+
+    let response = app
+        .clone()
+        .oneshot(create_agent_request(&token))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let response = app
+        .clone()
+        .oneshot(create_agent_request(&token))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+    let json = response_json(response).await;
+    assert_eq!(json["error"], "agent_creation_rate_limited");
+    assert!(json["details"]["retry_after"].as_u64().unwrap() >= 1);
+
+    let response = app
+        .oneshot(create_agent_request(&other_token))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+fn observe_request(token: &str, content_type: Option<&str>) -> Request<Body> {
+    let mut builder = Request::builder()
+        .method("POST")
+        .uri("/agents/content-agent/observe")
+        .header("Authorization", format!("Bearer {}", token));
+    if let Some(content_type) = content_type {
+        builder = builder.header("content-type", content_type);
+    }
+    builder
+        .body(Body::from(json!({"input": "hello"}).to_string()))
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_observe_rejects_unsupported_content_type() {
+    let runtime = create_test_runtime();
+    setup_test_agent(&runtime, "content-agent").await;
+    let app = runtime.router();
+    let token = create_test_token();
+
+    for content_type in [Some("text/plain"), None] {
+        let response = app
+            .clone()
+            .oneshot(observe_request(&token, content_type))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::UNSUPPORTED_MEDIA_TYPE);
+        let json = response_json(response).await;
+        assert_eq!(json["error"], "unsupported_media_type");
+        assert_eq!(json["details"]["accepted"], json!(["application/json"]));
+    }
+
+    let response = app
+        .oneshot(observe_request(
+            &token,
+            Some("application/json; charset=utf-8"),
+        ))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_content_types_configurable_per_route() {
+    let runtime = create_test_runtime();
+    setup_test_agent(&runtime, "content-agent").await;
+    let app = runtime.router_with_config(super::HttpRuntimeConfig {
+        content_types: Some(crate::runtime::ContentTypeConfig::default().with_route(
+            "/agents/{agent_id}/observe",
+            ["application/vnd.skreaver+json"],
+        )),
+        ..Default::default()
+    });
+    let token = create_test_token();
+
+    let response = app
+        .clone()
+        .oneshot(observe_request(&token, Some("application/json")))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::UNSUPPORTED_MEDIA_TYPE);
+
+    // Accepted by the route policy; the JSON extractor still applies its own checks
+    let response = app
+        .oneshot(observe_request(
+            &token,
+            Some("application/vnd.skreaver+json"),
+        ))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_shutdown_report_counts_drained_and_rejected_requests() {
+    use crate::runtime::backpressure::RequestPriority;
+    use std::time::Duration;
+
+    let runtime = create_test_runtime();
+    setup_test_agent(&runtime, "drain-agent").await;
+    let manager = &runtime.backpressure_manager;
+
+    let (_id1, in_flight_rx) = manager
+        .queue_request_with_input(
+            "drain-agent".to_string(),
+            "slow".to_string(),
+            RequestPriority::Normal,
+            None,
+        )
+        .await
+        .unwrap();
+    let (_id2, queued_rx) = manager
+        .queue_request_with_input(
+            "drain-agent".to_string(),
+            "waiting".to_string(),
+            RequestPriority::Normal,
+            None,
+        )
+        .await
+        .unwrap();
+    manager
+        .process_next_queued_request("drain-agent", |input, _priority| async move {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            input
+        })
+        .await
+        .unwrap();
+
+    let report = runtime.shutdown(Duration::from_millis(500)).await;
+
+    assert_eq!(report.requests_drained, 1);
+    assert_eq!(report.requests_rejected, 1);
+    assert_eq!(report.requests_abandoned, 0);
+    assert_eq!(report.ws_connections_closed, 0);
+    assert_eq!(report.agents_stopped, 1);
+    assert!(report.is_clean());
+    assert!(in_flight_rx.await.unwrap().is_ok());
+    assert!(queued_rx.await.unwrap().is_err());
+}
+
+/// Test agent that calls a tool missing from the registry on every step
+struct MissingToolAgent {
+    memory: InMemoryMemory,
+}
+
+impl Agent for MissingToolAgent {
+    type Observation = String;
+    type Action = String;
+    type Error = std::convert::Infallible;
+
+    fn observe(&mut self, _input: Self::Observation) {}
+
+    fn act(&mut self) -> Self::Action {
+        "acted".to_string()
+    }
+
+    fn call_tools(&self) -> Vec<ToolCall> {
+        vec![ToolCall::new("missing_tool", "").unwrap()]
+    }
+
+    fn handle_result(&mut self, _result: ExecutionResult) {}
+
+    fn update_context(&mut self, _update: MemoryUpdate) {}
+
+    fn memory_reader(&self) -> &dyn MemoryReader {
+        &self.memory
+    }
+
+    fn memory_writer(&mut self) -> &mut dyn MemoryWriter {
+        &mut self.memory
+    }
+}
+
+/// Insert an agent under a dedicated agent type so its step outcome counters
+/// are not shared with other tests
+async fn insert_outcome_agent<A>(
+    runtime: &HttpAgentRuntime<InMemoryToolRegistry>,
+    agent_id: &str,
+    agent_type: &str,
+    coordinator: crate::runtime::Coordinator<A, InMemoryToolRegistry>,
+) where
+    A: Agent + Send + Sync + 'static,
+    A::Observation: From<String> + std::fmt::Display,
+    A::Action: ToString,
+{
+    let id = skreaver_core::AgentId::parse(agent_id).unwrap();
+    let instance = crate::runtime::AgentInstance::new(
+        id.clone(),
+        agent_type.to_string(),
+        Box::new(coordinator),
+    );
+    runtime.agents.write().await.insert(id, instance);
+}
+
+fn step_outcome_count(agent_type: &str, outcome: &str) -> f64 {
+    skreaver_observability::get_metrics_registry()
+        .expect("runtime initializes the metrics registry")
+        .core_metrics()
+        .agent_step_outcomes_total
+        .with_label_values(&[agent_type, outcome])
+        .get()
+}
+
+#[tokio::test]
+async fn test_successful_step_records_completed_outcome() {
+    let runtime = create_test_runtime();
+    let coordinator = crate::runtime::Coordinator::new(
+        TestAgent::new(InMemoryMemory::new()),
+        InMemoryToolRegistry::new(),
+    );
+    insert_outcome_agent(&runtime, "outcome-ok", "outcome_completed", coordinator).await;
+
+    let app = runtime.router();
+    let request = Request::builder()
+        .method("POST")
+        .uri("/agents/outcome-ok/batch")
+        .header("Authorization", format!("Bearer {}", create_test_token()))
+        .header("content-type", "application/json")
+        .body(Body::from(
+            json!({"inputs": ["hello"], "timeout_seconds": 30}).to_string(),
+        ))
+        .unwrap();
+    let response = app.oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    assert_eq!(step_outcome_count("outcome_completed", "completed"), 1.0);
+    assert_eq!(step_outcome_count("outcome_completed", "tool_failure"), 0.0);
+}
+
+/// Run a one-input batch against `uri` while the agent's coordinator is held
+/// elsewhere, so the step waits for it until its deadline passes
+async fn run_timed_out_batch(
+    runtime: &HttpAgentRuntime<InMemoryToolRegistry>,
+    agent_id: &str,
+    uri: &str,
+) -> (StatusCode, Value) {
+    let id = skreaver_core::AgentId::parse(agent_id).unwrap();
+    let coordinator = runtime.agents.read().await[&id].coordinator.clone();
+    let (locked_tx, locked_rx) = std::sync::mpsc::channel();
+    let (release_tx, release_rx) = std::sync::mpsc::channel::<()>();
+    let holder = std::thread::spawn(move || {
+        let _guard = coordinator.lock().unwrap();
+        locked_tx.send(()).unwrap();
+        let _ = release_rx.recv();
+    });
+    locked_rx.recv().unwrap();
+    let request = Request::builder()
+        .method("POST")
+        .uri(uri)
+        .header("Authorization", format!("Bearer {}", create_test_token()))
+        .header("content-type", "application/json")
+        .body(Body::from(
+            json!({"inputs": ["hello"], "timeout_seconds": 1}).to_string(),
+        ))
+        .unwrap();
+    let response = runtime.clone().router().oneshot(request).await.unwrap();
+    release_tx.send(()).unwrap();
+    holder.join().unwrap();
+
+    let status = response.status();
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    (status, serde_json::from_slice(&body).unwrap())
+}
+
+#[tokio::test]
+async fn test_timed_out_step_records_timeout_outcome() {
+    let runtime = create_test_runtime();
+    let coordinator = crate::runtime::Coordinator::new(
+        TestAgent::new(InMemoryMemory::new()),
+        InMemoryToolRegistry::new(),
+    );
+    insert_outcome_agent(&runtime, "outcome-slow", "outcome_timeout", coordinator).await;
+
+    let (status, json) =
+        run_timed_out_batch(&runtime, "outcome-slow", "/agents/outcome-slow/batch").await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(json["results"][0]["status"], "failure");
+    assert_eq!(json["results"][0]["error"], "Operation timed out");
+    assert_eq!(step_outcome_count("outcome_timeout", "timeout"), 1.0);
+    assert_eq!(step_outcome_count("outcome_timeout", "completed"), 0.0);
+}
+
+#[tokio::test]
+async fn test_timed_out_step_v2_reports_item_failure() {
+    let runtime = create_test_runtime();
+    let coordinator = crate::runtime::Coordinator::new(
+        TestAgent::new(InMemoryMemory::new()),
+        InMemoryToolRegistry::new(),
+    );
+    insert_outcome_agent(
+        &runtime,
+        "outcome-slow-v2",
+        "outcome_timeout_v2",
+        coordinator,
+    )
+    .await;
+
+    let (status, json) = run_timed_out_batch(
+        &runtime,
+        "outcome-slow-v2",
+        "/v2/agents/outcome-slow-v2/batch",
+    )
+    .await;
+    // The batch itself ran; only its one input failed
+    assert_eq!(status, StatusCode::MULTI_STATUS);
+    assert_eq!(json["results"][0]["status"], "failure");
+    assert_eq!(json["results"][0]["error"], "Operation timed out");
+    assert_eq!(json["summary"]["failed"], 1);
+    assert_eq!(step_outcome_count("outcome_timeout_v2", "timeout"), 1.0);
+    assert_eq!(step_outcome_count("outcome_timeout_v2", "completed"), 0.0);
+}
+
+#[tokio::test]
+async fn test_tool_failing_step_records_tool_failure_outcome() {
+    let runtime = create_test_runtime();
+    let coordinator = crate::runtime::Coordinator::new(
+        MissingToolAgent {
+            memory: InMemoryMemory::new(),
+        },
+        InMemoryToolRegistry::new(),
+    )
+    .with_error_strategy(crate::runtime::ErrorStrategy::AbortStep);
+    insert_outcome_agent(
+        &runtime,
+        "outcome-tool",
+        "outcome_tool_failure",
+        coordinator,
+    )
+    .await;
+
+    let response = {
+        let agents = runtime.agents.read().await;
+        let id = skreaver_core::AgentId::parse("outcome-tool").unwrap();
+        agents.get(&id).unwrap().step("hello".to_string())
+    };
+
+    // The agent still acts after the step is aborted
+    assert_eq!(response, "acted");
+    assert_eq!(
+        step_outcome_count("outcome_tool_failure", "tool_failure"),
+        1.0
+    );
+    assert_eq!(step_outcome_count("outcome_tool_failure", "completed"), 0.0);
+}
+
+fn admin_request(method: &str, uri: &str) -> Request<Body> {
+    let admin_token = create_jwt_token("ops-user".to_string(), vec!["admin".to_string()]).unwrap();
+    Request::builder()
+        .method(method)
+        .uri(uri)
+        .header("Authorization", format!("Bearer {}", admin_token))
+        .body(Body::empty())
+        .unwrap()
+}
+
+fn observe_agent_request(agent_id: &str, input: &str) -> Request<Body> {
+    Request::builder()
+        .method("POST")
+        .uri(format!("/agents/{}/observe", agent_id))
+        .header("Authorization", format!("Bearer {}", create_test_token()))
+        .header("content-type", "application/json")
+        .body(Body::from(json!({ "input": input }).to_string()))
+        .unwrap()
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_pending_approval_does_not_block_other_agents() {
+    let gate = crate::runtime::ApprovalGate::new().require_approval("missing_tool");
+    let runtime = HttpAgentRuntime::with_config(
+        InMemoryToolRegistry::new(),
+        super::HttpRuntimeConfig {
+            approval_gate: Some(gate.clone()),
+            ..Default::default()
+        },
+    );
+    runtime
+        .add_agent(
+            "approval-gated",
+            MissingToolAgent {
+                memory: InMemoryMemory::new(),
+            },
+        )
+        .await
+        .unwrap();
+    setup_test_agent(&runtime, "approval-bystander").await;
+    let app = runtime.router();
+
+    let gated = tokio::spawn(
+        app.clone()
+            .oneshot(observe_agent_request("approval-gated", "deploy")),
+    );
+    let deadline = std::time::Instant::now() + std::time::Duration::from_secs(5);
+    while gate.pending().is_empty() {
+        assert!(
+            std::time::Instant::now() < deadline,
+            "step never reached the gate"
+        );
+        tokio::time::sleep(std::time::Duration::from_millis(5)).await;
+    }
+
+    // Another agent still answers while the gated step waits
+    let response = tokio::time::timeout(
+        std::time::Duration::from_secs(5),
+        app.clone()
+            .oneshot(observe_agent_request("approval-bystander", "hello")),
+    )
+    .await
+    .expect("other agents must not wait for the approval")
+    .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert!(!gated.is_finished());
+
+    let response = app
+        .clone()
+        .oneshot(admin_request("GET", "/approvals"))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let json: Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(json["pending"][0]["tool_name"], "missing_tool");
+    let action_id = json["pending"][0]["action_id"]
+        .as_str()
+        .unwrap()
+        .to_string();
+
+    let response = app
+        .clone()
+        .oneshot(admin_request(
+            "POST",
+            &format!("/approvals/{}/approve", action_id),
+        ))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let response = gated.await.unwrap().unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let json: Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(json["response"], "acted");
+
+    // Decided actions are gone, and deciding requires the admin permission
+    let response = app
+        .clone()
+        .oneshot(admin_request(
+            "POST",
+            &format!("/approvals/{}/deny", action_id),
+        ))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    let request = Request::builder()
+        .uri("/approvals")
+        .header("Authorization", format!("Bearer {}", create_test_token()))
+        .body(Body::empty())
+        .unwrap();
+    let response = app.oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_runtime_tool_limiter_applies_to_agents() {
+    let limiter = crate::runtime::ToolConcurrencyLimiter::new().with_limit("missing_tool", 1);
+    let runtime = HttpAgentRuntime::with_config(
+        InMemoryToolRegistry::new(),
+        super::HttpRuntimeConfig {
+            tool_limiter: Some(limiter.clone()),
+            ..Default::default()
+        },
+    );
+    runtime
+        .add_agent(
+            "limited-agent",
+            MissingToolAgent {
+                memory: InMemoryMemory::new(),
+            },
+        )
+        .await
+        .unwrap();
+    setup_test_agent(&runtime, "limited-bystander").await;
+    let app = runtime.router();
+
+    // Occupy the tool's only slot so the agent's call has to wait for it
+    let holder = limiter.try_acquire("missing_tool");
+    assert!(holder.is_some());
+    let limited = tokio::spawn(
+        app.clone()
+            .oneshot(observe_agent_request("limited-agent", "go")),
+    );
+    let deadline = std::time::Instant::now() + std::time::Duration::from_secs(5);
+    while limiter.waiting("missing_tool") == 0 {
+        assert!(
+            std::time::Instant::now() < deadline,
+            "tool call never queued for a slot"
+        );
+        tokio::time::sleep(std::time::Duration::from_millis(5)).await;
+    }
+
+    let response = tokio::time::timeout(
+        std::time::Duration::from_secs(5),
+        app.oneshot(observe_agent_request("limited-bystander", "hello")),
+    )
+    .await
+    .expect("a queued tool call must not block other agents")
+    .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert!(!limited.is_finished());
+
+    drop(holder);
+    let response = limited.await.unwrap().unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(limiter.active("missing_tool"), 0);
+    assert_eq!(limiter.waiting("missing_tool"), 0);
+}
+
+#[tokio::test]
+async fn test_runtime_rehydrates_and_redispatches_persisted_requests() {
+    use crate::runtime::backpressure::{
+        BackpressureConfig, BackpressureManager, InMemoryQueuePersistence, RequestPriority,
+    };
+    use std::sync::Arc;
+
+    // A previous process accepted the request but never started it
+    let persistence = Arc::new(InMemoryQueuePersistence::new());
+    let previous = BackpressureManager::new_with_persistence(
+        BackpressureConfig::default(),
+        persistence.clone(),
+    );
+    previous
+        .queue_request_with_input(
+            "restored-agent".to_string(),
+            "hello".to_string(),
+            RequestPriority::Normal,
+            None,
+        )
+        .await
+        .unwrap();
+    drop(previous);
+
+    let runtime = HttpAgentRuntime::with_config(
+        InMemoryToolRegistry::new(),
+        super::HttpRuntimeConfig {
+            queue_persistence: Some(persistence.clone()),
+            ..Default::default()
+        },
+    );
+    assert_eq!(
+        runtime
+            .backpressure_manager
+            .get_agent_metrics("restored-agent")
+            .await
+            .unwrap()
+            .queue_size,
+        1
+    );
+
+    setup_test_agent(&runtime, "restored-agent").await;
+    assert_eq!(runtime.redispatch_queued_requests().await, 1);
+    assert!(persistence.is_empty());
+}
@@ -0,0 +1,77 @@
+//! In-flight request tracking
+//!
+//! This module provides middleware that keeps the `http_requests_in_flight`
+//! gauge accurate. The gauge is held by an RAII guard for the lifetime of the
+//! request, so it is decremented even when a handler errors or panics.
+
+use axum::{extract::Request, extract::State, middleware::Next, response::Response};
+use skreaver_observability::MetricsRegistry;
+use std::sync::Arc;
+
+/// Middleware that tracks in-flight HTTP requests on the given metrics registry
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use axum::{Router, routing::get, middleware};
+/// use skreaver_http::runtime::in_flight::in_flight_middleware;
+///
+/// let app = Router::new()
+///     .route("/", get(handler))
+///     .layer(middleware::from_fn_with_state(registry, in_flight_middleware));
+/// ```
+pub async fn in_flight_middleware(
+    State(registry): State<Arc<MetricsRegistry>>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let _guard = registry.track_http_request();
+    next.run(request).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{Router, body::Body, http::StatusCode, middleware, routing::get};
+    use tower::ServiceExt;
+
+    fn test_registry() -> Arc<MetricsRegistry> {
+        let id = uuid::Uuid::new_v4().simple().to_string();
+        Arc::new(MetricsRegistry::new(&format!("test{}", &id[0..8])).unwrap())
+    }
+
+    fn app(registry: Arc<MetricsRegistry>) -> Router {
+        Router::new()
+            .route("/ok", get(|| async { "ok" }))
+            .route(
+                "/fail",
+                get(|| async { Err::<&str, _>(StatusCode::INTERNAL_SERVER_ERROR) }),
+            )
+            .layer(middleware::from_fn_with_state(
+                registry,
+                in_flight_middleware,
+            ))
+    }
+
+    #[tokio::test]
+    async fn test_in_flight_returns_to_zero_after_success() {
+        let registry = test_registry();
+        let request = Request::builder().uri("/ok").body(Body::empty()).unwrap();
+
+        let response = app(Arc::clone(&registry)).oneshot(request).await.unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(registry.core_metrics().http_requests_in_flight.get(), 0.0);
+    }
+
+    #[tokio::test]
+    async fn test_in_flight_returns_to_zero_after_error() {
+        let registry = test_registry();
+        let request = Request::builder().uri("/fail").body(Body::empty()).unwrap();
+
+        let response = app(Arc::clone(&registry)).oneshot(request).await.unwrap();
+
+        assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+        assert_eq!(registry.core_metrics().http_requests_in_flight.get(), 0.0);
+    }
+}
@@ -55,16 +55,24 @@
 pub mod agent_builders;
 /// Specific error types for agent operations.
 pub mod agent_error;
+/// Idle agent eviction.
+pub mod agent_eviction;
 /// Agent factory pattern for dynamic agent creation.
 pub mod agent_factory;
 /// Agent instance management with state tracking.
 pub mod agent_instance;
 /// Typestate pattern for agent lifecycle management.
 pub mod agent_lifecycle;
+/// Agent count and creation rate quotas.
+pub mod agent_quota;
 /// Type-safe agent status management.
 pub mod agent_status;
 /// Improved API types with type safety and validation.
 pub mod api_types;
+/// HTTP API versioning and content negotiation.
+pub mod api_version;
+/// Human-in-the-loop approval of tool calls.
+pub mod approval;
 /// Authentication middleware for HTTP runtime.
 pub mod auth;
 /// Authentication token types for compile-time safety.
@@ -75,6 +83,8 @@ pub mod backpressure;
 pub mod config;
 /// HTTP connection limits and tracking.
 pub mod connection_limits;
+/// Per-route request body content-type enforcement.
+pub mod content_type;
 /// Central coordinator for agent execution and tool dispatch.
 pub mod coordinator;
 /// API documentation endpoints.
@@ -85,6 +95,8 @@ pub mod error;
 pub mod handlers;
 /// HTTP runtime for serving agents over REST API.
 pub mod http;
+/// In-flight request tracking middleware.
+pub mod in_flight;
 /// Rate limiting middleware for HTTP runtime.
 pub mod rate_limit;
 /// HTTP router configuration and route registration.
@@ -95,24 +107,55 @@ pub mod security;
 pub mod shutdown;
 /// Streaming responses for long-running operations.
 pub mod streaming;
+/// Time budget for the tool calls of a single step.
+pub mod tool_budget;
+/// Per-tool concurrency limits with priority-ordered queueing.
+pub mod tool_limits;
 /// Type definitions for HTTP runtime (requests, responses, etc.).
 pub mod types;
+/// Per-principal usage accounting for billing.
+pub mod usage;
 
 pub use agent_builders::{AdvancedAgentBuilder, AnalyticsAgentBuilder, EchoAgentBuilder};
-pub use agent_factory::{AgentBuilder, AgentFactory, AgentFactoryError};
+pub use agent_eviction::AgentEvictionConfig;
+pub use agent_factory::{
+    AgentBuilder, AgentFactory, AgentFactoryError, BuildContext, natural_key_agent_id,
+};
 pub use agent_instance::{AgentId, AgentInstance, CoordinatorTrait};
+pub use agent_quota::AgentQuotaConfig;
 pub use agent_status::{AgentStatus, AgentStatusEnum, AgentStatusError, AgentStatusManager};
 pub use api_types::{
-    AgentObservation, AgentResponse, AgentSpec, AgentType, DeliveryError, ResponseDelivery,
+    AgentObservation, AgentResponse, AgentSpec, AgentType, DeliveryError, OnConflict,
+    ResponseDelivery, SpecValidationError, SpecViolation,
+};
+pub use api_version::{ApiVersion, DEFAULT_API_VERSION};
+pub use approval::{
+    ApprovalDecision, ApprovalError, ApprovalGate, ApprovalNotifier, PendingAction,
+};
+pub use backpressure::{
+    BackpressureConfig, BackpressureManager, CircuitBreakerStatus, CircuitState, DrainSummary,
+    FileQueuePersistence, InMemoryQueuePersistence, PersistedRequest, QueueMetrics,
+    QueuePersistence, RequestPriority,
 };
-pub use backpressure::{BackpressureConfig, BackpressureManager, QueueMetrics, RequestPriority};
 pub use config::{ConfigError, HttpRuntimeConfigBuilder};
 pub use connection_limits::{ConnectionLimitConfig, ConnectionStats, ConnectionTracker};
-pub use coordinator::Coordinator;
+pub use content_type::ContentTypeConfig;
+pub use coordinator::{
+    Coordinator, ErrorStrategy, MemoryIsolation, ScratchAgent, SharedMemoryAgent, StepError,
+};
 pub use error::{
     ErrorResponse, RequestId, RequestIdExtension, RuntimeError, RuntimeErrorKind, RuntimeResult,
     request_id_middleware,
 };
+pub use handlers::DlqAdmin;
 pub use http::{HttpAgentRuntime, HttpRuntimeConfig};
 pub use security::{ApiKeyData, SecretKey, SecurityConfig};
-pub use shutdown::{shutdown_signal, shutdown_signal_with_timeout, shutdown_with_cleanup};
+pub use shutdown::{
+    ShutdownReport, shutdown_signal, shutdown_signal_with_timeout, shutdown_with_cleanup,
+};
+pub use tool_budget::ToolTimeBudget;
+pub use tool_limits::{ToolConcurrencyLimiter, ToolPermit};
+pub use usage::{
+    FileUsageSink, InMemoryUsageSink, UsageEvent, UsageScope, UsageSink, UsageTotals,
+    usage_middleware,
+};
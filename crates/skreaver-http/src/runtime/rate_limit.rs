@@ -3,15 +3,32 @@
 //! This module provides rate limiting middleware for the HTTP runtime,
 //! protecting against abuse and ensuring fair usage of agent resources.
 
+use axum::{
+    extract::{ConnectInfo, Request, State},
+    http::{Method, StatusCode, header},
+    middleware::Next,
+    response::{IntoResponse, Json, Response},
+};
 use governor::{
     Quota, RateLimiter,
     clock::{Clock, DefaultClock},
     state::{InMemoryState, NotKeyed, keyed::DefaultKeyedStateStore},
 };
 use serde::Serialize;
-use std::{collections::HashMap, num::NonZeroU32, sync::Arc, time::Instant};
+use std::{
+    collections::HashMap,
+    net::SocketAddr,
+    num::NonZeroU32,
+    sync::Arc,
+    time::{Duration, Instant},
+};
 use tokio::sync::RwLock;
 
+use crate::runtime::{api_version::ApiVersion, auth::AuthContext};
+
+/// How often the HTTP runtime prunes the state of idle rate limit keys
+pub const RATE_LIMIT_CLEANUP_INTERVAL: Duration = Duration::from_secs(60);
+
 /// Rate limiter for global requests
 pub type GlobalRateLimiter = RateLimiter<NotKeyed, InMemoryState, DefaultClock>;
 
@@ -250,8 +267,11 @@ impl RateLimitState {
         limiter
     }
 
-    /// Clean up expired user limiters (called periodically)
+    /// Clean up expired user limiters and idle per-IP state (called periodically)
     pub async fn cleanup_expired_limiters(&self) {
+        self.ip_limiter.retain_recent();
+        self.ip_limiter.shrink_to_fit();
+
         let mut user_limiters = self.user_limiters.write().await;
         let ttl = std::time::Duration::from_secs(self.config.user_limiter_ttl_secs);
         let now = Instant::now();
@@ -364,6 +384,302 @@ pub fn create_rate_limit_state(config: RateLimitConfig) -> Arc<RateLimitState> {
     Arc::new(RateLimitState::new(config))
 }
 
+/// Groups of routes that are throttled with separate budgets
+///
+/// Keeps cheap reads such as health checks from competing with expensive
+/// mutations such as agent creation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RouteGroup {
+    /// Requests that change state (`POST`, `PUT`, `PATCH`, `DELETE`)
+    Mutation,
+    /// Requests that only read state (`GET`, `HEAD`, `OPTIONS`)
+    Read,
+    /// API documentation (`/docs`, `/api-docs/...`)
+    Docs,
+    /// Token issuance (`/auth/...`)
+    Auth,
+}
+
+impl RouteGroup {
+    /// All route groups
+    pub const ALL: [RouteGroup; 4] = [
+        RouteGroup::Mutation,
+        RouteGroup::Read,
+        RouteGroup::Docs,
+        RouteGroup::Auth,
+    ];
+
+    /// Classify a request by method and path
+    ///
+    /// Paths may carry an API version prefix (`/v1/...`). Docs and auth routes
+    /// are grouped by path regardless of method.
+    pub fn classify(method: &Method, path: &str) -> Self {
+        let path = match ApiVersion::from_path(path) {
+            Ok(Some(version)) => path
+                .trim_start_matches('/')
+                .strip_prefix(version.as_str())
+                .unwrap_or(path),
+            _ => path,
+        };
+
+        if path == "/docs" || path.starts_with("/docs/") || path.starts_with("/api-docs/") {
+            RouteGroup::Docs
+        } else if path.starts_with("/auth/") {
+            RouteGroup::Auth
+        } else if matches!(*method, Method::GET | Method::HEAD | Method::OPTIONS) {
+            RouteGroup::Read
+        } else {
+            RouteGroup::Mutation
+        }
+    }
+
+    /// Group name as used in metrics and error codes
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RouteGroup::Mutation => "mutation",
+            RouteGroup::Read => "read",
+            RouteGroup::Docs => "docs",
+            RouteGroup::Auth => "auth",
+        }
+    }
+}
+
+impl std::fmt::Display for RouteGroup {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// What a route group's budget is counted per
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RateLimitKey {
+    /// One budget shared by every client
+    Global,
+    /// One budget per client IP address
+    #[default]
+    Ip,
+    /// One budget per authenticated principal, per client IP for anonymous requests
+    User,
+}
+
+/// Budget for one route group
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RouteGroupLimit {
+    /// Maximum requests per minute for each key
+    pub rpm: NonZeroU32,
+    /// What the budget is counted per
+    pub key: RateLimitKey,
+}
+
+impl RouteGroupLimit {
+    /// Create a limit of `rpm` requests per minute per `key`
+    pub const fn new(rpm: NonZeroU32, key: RateLimitKey) -> Self {
+        Self { rpm, key }
+    }
+}
+
+/// Per-route-group rate limits
+///
+/// Groups without a limit are not throttled by route group.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RouteRateLimits {
+    /// Limit for state-changing requests
+    pub mutation: Option<RouteGroupLimit>,
+    /// Limit for read-only requests
+    pub read: Option<RouteGroupLimit>,
+    /// Limit for the documentation endpoints
+    pub docs: Option<RouteGroupLimit>,
+    /// Limit for the token endpoints
+    pub auth: Option<RouteGroupLimit>,
+}
+
+impl RouteRateLimits {
+    /// Set the limit for state-changing requests
+    #[must_use]
+    pub fn with_mutation(mut self, limit: RouteGroupLimit) -> Self {
+        self.mutation = Some(limit);
+        self
+    }
+
+    /// Set the limit for read-only requests
+    #[must_use]
+    pub fn with_read(mut self, limit: RouteGroupLimit) -> Self {
+        self.read = Some(limit);
+        self
+    }
+
+    /// Set the limit for the documentation endpoints
+    #[must_use]
+    pub fn with_docs(mut self, limit: RouteGroupLimit) -> Self {
+        self.docs = Some(limit);
+        self
+    }
+
+    /// Set the limit for the token endpoints
+    #[must_use]
+    pub fn with_auth(mut self, limit: RouteGroupLimit) -> Self {
+        self.auth = Some(limit);
+        self
+    }
+
+    /// Limit configured for `group`, if any
+    pub fn get(&self, group: RouteGroup) -> Option<RouteGroupLimit> {
+        match group {
+            RouteGroup::Mutation => self.mutation,
+            RouteGroup::Read => self.read,
+            RouteGroup::Docs => self.docs,
+            RouteGroup::Auth => self.auth,
+        }
+    }
+}
+
+/// Rate limiter keyed by client IP or principal
+type KeyedRateLimiter = RateLimiter<String, DefaultKeyedStateStore<String>, DefaultClock>;
+
+/// Limiters for each configured route group
+pub struct RouteRateLimitState {
+    limiters: HashMap<RouteGroup, (RateLimitKey, KeyedRateLimiter)>,
+}
+
+impl RouteRateLimitState {
+    /// Create limiters for every group with a configured limit
+    pub fn new(limits: &RouteRateLimits) -> Self {
+        let limiters = RouteGroup::ALL
+            .into_iter()
+            .filter_map(|group| {
+                let limit = limits.get(group)?;
+                let limiter = RateLimiter::keyed(Quota::per_minute(limit.rpm));
+                Some((group, (limit.key, limiter)))
+            })
+            .collect();
+        Self { limiters }
+    }
+
+    /// Drop the state of keys whose budgets have fully refilled
+    ///
+    /// An untracked key behaves exactly like one with a full budget, so this
+    /// only frees the memory held for clients that went quiet.
+    pub fn retain_recent(&self) {
+        for (_, limiter) in self.limiters.values() {
+            limiter.retain_recent();
+            limiter.shrink_to_fit();
+        }
+    }
+
+    /// Number of keys currently tracked across all route groups
+    pub fn tracked_keys(&self) -> usize {
+        self.limiters
+            .values()
+            .map(|(_, limiter)| limiter.len())
+            .sum()
+    }
+
+    /// Check a request against its route group's budget
+    pub fn check(&self, group: RouteGroup, request: &Request) -> Result<(), RateLimitError> {
+        let Some((key, limiter)) = self.limiters.get(&group) else {
+            return Ok(());
+        };
+
+        let client_ip = || {
+            request
+                .extensions()
+                .get::<ConnectInfo<SocketAddr>>()
+                .map(|ConnectInfo(addr)| addr.ip().to_string())
+                .unwrap_or_else(|| "unknown".to_string())
+        };
+        let key = match key {
+            RateLimitKey::Global => String::new(),
+            RateLimitKey::Ip => format!("ip:{}", client_ip()),
+            RateLimitKey::User => match request.extensions().get::<AuthContext>() {
+                Some(auth) => format!("user:{}", auth.user_id),
+                None => format!("ip:{}", client_ip()),
+            },
+        };
+
+        limiter.check_key(&key).map_err(|not_until| {
+            if let Some(registry) = skreaver_observability::get_metrics_registry() {
+                registry
+                    .core_metrics()
+                    .security_rate_limit_exceeded_total
+                    .with_label_values(&[group.as_str()])
+                    .inc();
+            }
+
+            let retry_after = not_until
+                .wait_time_from(DefaultClock::default().now())
+                .as_secs()
+                .max(1);
+            RateLimitError {
+                error: format!("{}_rate_limit_exceeded", group),
+                message: format!(
+                    "Rate limit for {} requests exceeded. Please try again later.",
+                    group
+                ),
+                retry_after,
+            }
+        })
+    }
+}
+
+/// Periodically prune idle rate limit state every `period`
+///
+/// Runs [`RateLimitState::cleanup_expired_limiters`] and, when route group
+/// budgets are configured, [`RouteRateLimitState::retain_recent`]. The task
+/// holds only weak references and stops once the limiters are dropped; it is
+/// not started outside a Tokio runtime.
+pub(crate) fn spawn_limiter_cleanup(
+    global: &Arc<RateLimitState>,
+    routes: Option<&Arc<RouteRateLimitState>>,
+    period: Duration,
+) {
+    let Ok(handle) = tokio::runtime::Handle::try_current() else {
+        return;
+    };
+    let global = Arc::downgrade(global);
+    let routes = routes.map(Arc::downgrade);
+    let mut interval = tokio::time::interval_at(tokio::time::Instant::now() + period, period);
+    interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+    handle.spawn(async move {
+        loop {
+            interval.tick().await;
+            let Some(global) = global.upgrade() else {
+                break;
+            };
+            global.cleanup_expired_limiters().await;
+            if let Some(routes) = &routes {
+                let Some(routes) = routes.upgrade() else {
+                    break;
+                };
+                routes.retain_recent();
+            }
+        }
+    });
+}
+
+/// Middleware that throttles each route group with its own budget
+///
+/// Apply it inside authentication so that [`RateLimitKey::User`] budgets can
+/// key on the authenticated principal.
+pub async fn route_rate_limit_middleware(
+    State(state): State<Arc<RouteRateLimitState>>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let group = RouteGroup::classify(request.method(), request.uri().path());
+    if let Err(error) = state.check(group, &request) {
+        let retry_after = error.retry_after;
+        return (
+            StatusCode::TOO_MANY_REQUESTS,
+            [(header::RETRY_AFTER, retry_after.to_string())],
+            Json(error),
+        )
+            .into_response();
+    }
+
+    next.run(request).await
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -405,6 +721,58 @@ mod tests {
         assert_eq!(default_config.per_user_rpm.get(), 120);
     }
 
+    #[test]
+    fn test_route_group_classification() {
+        assert_eq!(
+            RouteGroup::classify(&Method::POST, "/agents"),
+            RouteGroup::Mutation
+        );
+        assert_eq!(
+            RouteGroup::classify(&Method::DELETE, "/v1/agents/a1"),
+            RouteGroup::Mutation
+        );
+        assert_eq!(
+            RouteGroup::classify(&Method::GET, "/v2/agents/a1/status"),
+            RouteGroup::Read
+        );
+        assert_eq!(
+            RouteGroup::classify(&Method::GET, "/health"),
+            RouteGroup::Read
+        );
+        assert_eq!(
+            RouteGroup::classify(&Method::POST, "/v1/auth/token"),
+            RouteGroup::Auth
+        );
+        assert_eq!(
+            RouteGroup::classify(&Method::GET, "/api-docs/openapi.json"),
+            RouteGroup::Docs
+        );
+        assert_eq!(
+            RouteGroup::classify(&Method::GET, "/docs"),
+            RouteGroup::Docs
+        );
+    }
+
+    #[tokio::test]
+    async fn test_route_limiter_forgets_idle_keys() {
+        let limit = RouteGroupLimit::new(NonZeroU32::new(60_000).unwrap(), RateLimitKey::Ip);
+        let state = RouteRateLimitState::new(&RouteRateLimits::default().with_read(limit));
+
+        for octet in 1..=3 {
+            let mut request = Request::new(axum::body::Body::empty());
+            request
+                .extensions_mut()
+                .insert(ConnectInfo(SocketAddr::from(([10, 0, 0, octet], 8080))));
+            state.check(RouteGroup::Read, &request).unwrap();
+        }
+        assert_eq!(state.tracked_keys(), 3);
+
+        // Each key's single request is refilled after 1ms
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        state.retain_recent();
+        assert_eq!(state.tracked_keys(), 0);
+    }
+
     #[tokio::test]
     async fn test_user_limiter_creation() {
         let config = RateLimitConfig::default();
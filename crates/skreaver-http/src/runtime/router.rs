@@ -6,28 +6,40 @@ use axum::{
     Router, middleware,
     routing::{get, post},
 };
+use skreaver_core::ApiKeyManager;
 use skreaver_tools::ToolRegistry;
 use std::sync::Arc;
 use tower_http::{cors::CorsLayer, trace::TraceLayer};
 
 use crate::runtime::{
     HttpAgentRuntime, HttpRuntimeConfig,
-    auth::{inject_api_key_manager, require_auth},
+    api_version::{ApiVersion, api_version_middleware, unmatched_route_fallback},
+    auth::{TOOL_EXECUTE_PERMISSION, inject_api_key_manager, require_auth, require_permissions},
     connection_limits::connection_limit_middleware,
-    docs::{openapi_spec, swagger_ui},
+    content_type::content_type_middleware,
+    docs::{create_docs_rate_limiter, docs_rate_limit_middleware, openapi_spec, swagger_ui},
     error::request_id_middleware,
     handlers::{
+        // Approvals
+        approvals_router,
         batch_observe_agent,
         create_agent,
         // Authentication
         create_token,
         delete_agent,
+        // Dead letter queue
+        dlq_router,
+        get_agent_log_level,
         // Queue metrics
         get_agent_queue_metrics,
         get_agent_status,
+        // Circuit breakers
+        get_circuit_breaker,
         get_global_queue_metrics,
         // Health and metrics
         health_check,
+        // Tools
+        invoke_tool,
         // Agents
         list_agents,
         metrics_endpoint,
@@ -35,8 +47,17 @@ use crate::runtime::{
         observe_agent,
         observe_agent_stream,
         readiness_check,
+        set_agent_log_level,
+        set_circuit_breaker,
         stream_agent,
     },
+    http::OpenApiConfig,
+    in_flight::in_flight_middleware,
+    rate_limit::{
+        RATE_LIMIT_CLEANUP_INTERVAL, RouteRateLimitState, route_rate_limit_middleware,
+        spawn_limiter_cleanup,
+    },
+    usage::usage_middleware,
 };
 
 impl<T: ToolRegistry + Clone + Send + Sync + 'static> HttpAgentRuntime<T> {
@@ -51,6 +72,27 @@ impl<T: ToolRegistry + Clone + Send + Sync + 'static> HttpAgentRuntime<T> {
         let connection_tracker = Arc::clone(&self.connection_tracker);
         let api_key_manager = Arc::clone(&self.api_key_manager);
 
+        // Record per-principal usage inside authentication, once the principal is known
+        let record_usage = |routes: Router<Self>| match &config.usage_sink {
+            Some(sink) => routes.route_layer(middleware::from_fn_with_state(
+                Arc::clone(sink),
+                usage_middleware,
+            )),
+            None => routes,
+        };
+
+        // Route group budgets sit inside authentication so per-user budgets
+        // can key on the principal
+        let route_limits = config
+            .route_rate_limits
+            .as_ref()
+            .map(|limits| Arc::new(RouteRateLimitState::new(limits)));
+        spawn_limiter_cleanup(
+            &self.rate_limit_state,
+            route_limits.as_ref(),
+            RATE_LIMIT_CLEANUP_INTERVAL,
+        );
+
         // Protected routes - require authentication
         // Use route_layer to apply middleware to specific routes before merging
         let protected_routes = Router::new()
@@ -68,8 +110,37 @@ impl<T: ToolRegistry + Clone + Send + Sync + 'static> HttpAgentRuntime<T> {
                 get(get_agent_queue_metrics),
             )
             .route("/agents/{agent_id}", axum::routing::delete(delete_agent))
-            .route("/queue/metrics", get(get_global_queue_metrics))
-            .route_layer(middleware::from_fn(require_auth)); // Apply auth to these routes only
+            .route("/queue/metrics", get(get_global_queue_metrics));
+        let protected_routes =
+            limit_route_groups(record_usage(protected_routes), route_limits.as_ref())
+                .route_layer(middleware::from_fn(require_auth)); // Apply auth to these routes only
+
+        // Direct tool invocation - requires the tool execution permission
+        let tool_routes = Router::new().route("/tools/{tool_name}/invoke", post(invoke_tool));
+        let tool_routes = limit_route_groups(record_usage(tool_routes), route_limits.as_ref())
+            .route_layer(middleware::from_fn(require_permissions(vec![
+                TOOL_EXECUTE_PERMISSION,
+            ])));
+
+        // Admin routes - require the admin permission
+        let mut admin_routes = Router::new()
+            .route("/agents/{agent_id}/circuit", get(get_circuit_breaker))
+            .route(
+                "/agents/{agent_id}/circuit/{action}",
+                post(set_circuit_breaker),
+            )
+            .route(
+                "/agents/{agent_id}/log-level",
+                get(get_agent_log_level).put(set_agent_log_level),
+            );
+        if let Some(dead_letters) = &config.dead_letters {
+            admin_routes = admin_routes.merge(dlq_router(dead_letters.clone()));
+        }
+        if let Some(gate) = &self.approval_gate {
+            admin_routes = admin_routes.merge(approvals_router(gate.clone()));
+        }
+        let admin_routes = limit_route_groups(record_usage(admin_routes), route_limits.as_ref())
+            .route_layer(middleware::from_fn(require_permissions(vec!["admin"])));
 
         // Public routes - no authentication required
         let public_routes = Router::new()
@@ -77,20 +148,51 @@ impl<T: ToolRegistry + Clone + Send + Sync + 'static> HttpAgentRuntime<T> {
             .route("/ready", get(readiness_check))
             .route("/metrics", get(metrics_endpoint))
             .route("/auth/token", post(create_token));
+        let public_routes = limit_route_groups(public_routes, route_limits.as_ref());
 
         // Combine public and protected routes
-        let mut router = Router::new()
+        let api_routes = Router::new()
             .merge(public_routes)
             .merge(protected_routes)
-            .with_state(self)
-            .layer(TraceLayer::new_for_http());
+            .merge(tool_routes)
+            .merge(admin_routes);
+
+        // Serve every route unprefixed and under each version prefix
+        let mut router = ApiVersion::ALL
+            .iter()
+            .fold(api_routes.clone(), |router, version| {
+                router.nest(&format!("/{}", version), api_routes.clone())
+            })
+            .fallback(unmatched_route_fallback)
+            .with_state(self);
+
+        // Reject bodies with unsupported content types before handlers parse them
+        if let Some(content_types) = config.content_types.clone() {
+            router = router.layer(middleware::from_fn_with_state(
+                Arc::new(content_types),
+                content_type_middleware,
+            ));
+        }
+
+        // Negotiate the API version before handlers build their responses
+        router = router.layer(middleware::from_fn(api_version_middleware));
+
+        router = router.layer(TraceLayer::new_for_http());
+
+        // Track in-flight requests when the global metrics registry is initialized
+        if let Some(registry) = skreaver_observability::get_metrics_registry() {
+            router = router.layer(middleware::from_fn_with_state(
+                registry,
+                in_flight_middleware,
+            ));
+        }
 
         // Add request ID middleware (applies to all routes, should be early in the stack)
         router = router.layer(middleware::from_fn(request_id_middleware));
 
         // Add API key manager injection middleware (applies to all routes)
         router = router.layer(middleware::from_fn_with_state(
-            api_key_manager,
+            Arc::clone(&api_key_manager),
             inject_api_key_manager,
         ));
 
@@ -112,8 +214,12 @@ impl<T: ToolRegistry + Clone + Send + Sync + 'static> HttpAgentRuntime<T> {
         // Add OpenAPI documentation if configured
         // OpenApiConfig presence enables /docs and /api-docs routes.
         // Additional config (title, version, servers) can be added to OpenApiConfig.
-        if config.openapi.is_some() {
-            router = router.merge(create_openapi_router());
+        if let Some(openapi) = &config.openapi {
+            router = router.merge(create_openapi_router(
+                openapi,
+                api_key_manager,
+                route_limits.as_ref(),
+            ));
         }
 
         router
@@ -121,8 +227,47 @@ impl<T: ToolRegistry + Clone + Send + Sync + 'static> HttpAgentRuntime<T> {
 }
 
 /// Create OpenAPI documentation router
-fn create_openapi_router() -> Router {
-    Router::new()
-        .route("/docs", get(swagger_ui))
-        .route("/api-docs/openapi.json", get(openapi_spec))
+///
+/// The docs routes get their own rate limiter and, optionally, authentication,
+/// independent of the API routes.
+fn create_openapi_router(
+    config: &OpenApiConfig,
+    api_key_manager: Arc<ApiKeyManager>,
+    route_limits: Option<&Arc<RouteRateLimitState>>,
+) -> Router {
+    let mut router = limit_route_groups(
+        Router::new()
+            .route("/docs", get(swagger_ui))
+            .route("/api-docs/openapi.json", get(openapi_spec)),
+        route_limits,
+    );
+
+    if config.require_auth {
+        router = router.route_layer(middleware::from_fn(require_auth)).layer(
+            middleware::from_fn_with_state(api_key_manager, inject_api_key_manager),
+        );
+    }
+
+    if let Some(rpm) = config.rate_limit_rpm {
+        router = router.layer(middleware::from_fn_with_state(
+            create_docs_rate_limiter(rpm),
+            docs_rate_limit_middleware,
+        ));
+    }
+
+    router
+}
+
+/// Throttle `routes` with the budget of each request's route group
+fn limit_route_groups<S>(routes: Router<S>, state: Option<&Arc<RouteRateLimitState>>) -> Router<S>
+where
+    S: Clone + Send + Sync + 'static,
+{
+    match state {
+        Some(state) => routes.route_layer(middleware::from_fn_with_state(
+            Arc::clone(state),
+            route_rate_limit_middleware,
+        )),
+        None => routes,
+    }
 }
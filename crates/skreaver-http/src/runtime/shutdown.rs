@@ -3,8 +3,66 @@
 //! This module provides signal handling and graceful shutdown capabilities
 //! for Kubernetes deployments and production environments.
 
+use std::time::Duration;
 use tokio::signal;
-use tracing::{error, info};
+use tracing::{error, info, warn};
+
+/// Summary of a graceful shutdown, returned for logging and alerting
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ShutdownReport {
+    /// In-flight requests that completed within the grace period
+    pub requests_drained: usize,
+    /// Queued requests rejected without being processed
+    pub requests_rejected: usize,
+    /// In-flight requests still running when the grace period elapsed
+    pub requests_abandoned: usize,
+    /// WebSocket connections closed
+    pub ws_connections_closed: usize,
+    /// Agents whose cleanup hook ran
+    pub agents_stopped: usize,
+    /// Total time spent draining
+    pub drain_duration: Duration,
+}
+
+impl ShutdownReport {
+    /// Whether every in-flight request finished before the deadline
+    pub fn is_clean(&self) -> bool {
+        self.requests_abandoned == 0
+    }
+
+    /// Publish the report to the global metrics registry and the log
+    pub fn emit(&self) {
+        if let Some(registry) = skreaver_observability::get_metrics_registry() {
+            registry.record_shutdown(
+                self.requests_drained,
+                self.requests_rejected,
+                self.ws_connections_closed,
+                self.drain_duration,
+            );
+        }
+
+        if self.is_clean() {
+            info!(
+                requests_drained = self.requests_drained,
+                requests_rejected = self.requests_rejected,
+                ws_connections_closed = self.ws_connections_closed,
+                agents_stopped = self.agents_stopped,
+                drain_duration_ms = self.drain_duration.as_millis() as u64,
+                "Graceful shutdown complete"
+            );
+        } else {
+            warn!(
+                requests_drained = self.requests_drained,
+                requests_rejected = self.requests_rejected,
+                requests_abandoned = self.requests_abandoned,
+                ws_connections_closed = self.ws_connections_closed,
+                agents_stopped = self.agents_stopped,
+                drain_duration_ms = self.drain_duration.as_millis() as u64,
+                "Graceful shutdown finished with requests still in flight"
+            );
+        }
+    }
+}
 
 /// Create a future that completes when a shutdown signal is received
 ///
@@ -171,7 +229,6 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::time::Duration;
 
     #[tokio::test]
     async fn test_shutdown_signal_timeout() {
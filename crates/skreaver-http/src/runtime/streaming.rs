@@ -9,8 +9,9 @@ use axum::{
 };
 use futures::Stream;
 use serde::{Deserialize, Serialize};
-use std::time::Duration;
-use tokio_stream::{StreamExt, wrappers::ReceiverStream};
+use skreaver_observability::{StreamGuard, StreamTransport, get_metrics_registry};
+use std::{num::NonZeroUsize, sync::Arc, time::Duration};
+use tokio::sync::watch;
 
 /// Agent execution update types
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -247,7 +248,25 @@ impl AgentUpdate {
 pub fn create_sse_stream(
     updates: tokio::sync::mpsc::Receiver<AgentUpdate>,
 ) -> Sse<impl Stream<Item = Result<Event, BoxError>>> {
-    let stream = ReceiverStream::new(updates).map(|update| {
+    let guard = get_metrics_registry().map(|registry| registry.track_stream(StreamTransport::Sse));
+
+    Sse::new(sse_events(updates, guard)).keep_alive(
+        KeepAlive::new()
+            .interval(Duration::from_secs(10))
+            .text("keep-alive"),
+    )
+}
+
+/// Turn agent updates into SSE events, reporting each one to the stream guard
+///
+/// The guard is released as soon as the update channel closes, so the stream
+/// stops counting as active even if the response body is dropped later.
+fn sse_events(
+    updates: tokio::sync::mpsc::Receiver<AgentUpdate>,
+    guard: Option<StreamGuard>,
+) -> impl Stream<Item = Result<Event, BoxError>> {
+    futures::stream::unfold((updates, guard), |(mut updates, guard)| async move {
+        let update = updates.recv().await?;
         let event_type = match &update {
             AgentUpdate::Started { .. } => "started",
             AgentUpdate::Thinking { .. } => "thinking",
@@ -261,43 +280,128 @@ pub fn create_sse_stream(
             AgentUpdate::Progress { .. } => "progress",
         };
 
-        let json_data = serde_json::to_string(&update).map_err(|e| Box::new(e) as BoxError)?;
+        let event = serde_json::to_string(&update)
+            .map(|json_data| {
+                if let Some(guard) = &guard {
+                    guard.record_frame(json_data.len());
+                }
+                Event::default()
+                    .event(event_type)
+                    .data(json_data)
+                    .id(uuid::Uuid::new_v4().to_string())
+            })
+            .map_err(|e| Box::new(e) as BoxError);
 
-        Ok(Event::default()
-            .event(event_type)
-            .data(json_data)
-            .id(uuid::Uuid::new_v4().to_string()))
-    });
+        Some((event, (updates, guard)))
+    })
+}
 
-    Sse::new(stream).keep_alive(
-        KeepAlive::new()
-            .interval(Duration::from_secs(10))
-            .text("keep-alive"),
-    )
+const DEFAULT_STREAM_BUFFER_SIZE: NonZeroUsize = match NonZeroUsize::new(100) {
+    Some(v) => v,
+    None => panic!("DEFAULT_STREAM_BUFFER_SIZE must be non-zero"),
+};
+
+/// Producer-side backpressure for streamed agent updates
+///
+/// Updates are buffered per client up to `buffer_size`. Once the buffer is
+/// full, producers wait for the client to catch up; if it does not read
+/// anything for `stall_timeout`, the stream is abandoned instead of buffering
+/// without bound. Abandoning drops the streaming operation, so a step still
+/// waiting for its agent is skipped; a step already running on the blocking
+/// pool cannot be interrupted and finishes, but its result is discarded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StreamBackpressureConfig {
+    /// Updates buffered for a client before producers wait
+    pub buffer_size: NonZeroUsize,
+    /// How long a producer waits for buffer space before abandoning the stream
+    pub stall_timeout: Duration,
+}
+
+impl Default for StreamBackpressureConfig {
+    fn default() -> Self {
+        Self {
+            buffer_size: DEFAULT_STREAM_BUFFER_SIZE,
+            stall_timeout: Duration::from_secs(30),
+        }
+    }
+}
+
+/// Error sending an update to a streaming client
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum StreamSendError {
+    /// The client disconnected
+    #[error("stream closed by client")]
+    Closed,
+    /// The client did not read updates within the stall timeout
+    #[error("client did not read updates for {0:?}")]
+    Stalled(Duration),
 }
 
 /// Streaming agent executor that sends updates via channel
 #[derive(Clone)]
 pub struct StreamingAgentExecutor {
     pub update_sender: tokio::sync::mpsc::Sender<AgentUpdate>,
+    stall_timeout: Duration,
+    /// Set once a send times out; shared by all clones for the same client
+    stalled: Arc<watch::Sender<bool>>,
 }
 
 impl StreamingAgentExecutor {
-    /// Create a new streaming executor
+    /// Create a new streaming executor with default backpressure
     pub fn new() -> (Self, tokio::sync::mpsc::Receiver<AgentUpdate>) {
-        let (tx, rx) = tokio::sync::mpsc::channel(100);
-        (Self { update_sender: tx }, rx)
+        Self::with_backpressure(StreamBackpressureConfig::default())
     }
 
-    /// Send an agent update
-    pub async fn send_update(
-        &self,
-        update: AgentUpdate,
-    ) -> Result<(), tokio::sync::mpsc::error::SendError<AgentUpdate>> {
-        self.update_sender.send(update).await
+    /// Create a new streaming executor with the given backpressure settings
+    pub fn with_backpressure(
+        config: StreamBackpressureConfig,
+    ) -> (Self, tokio::sync::mpsc::Receiver<AgentUpdate>) {
+        let (tx, rx) = tokio::sync::mpsc::channel(config.buffer_size.get());
+        let executor = Self {
+            update_sender: tx,
+            stall_timeout: config.stall_timeout,
+            stalled: Arc::new(watch::Sender::new(false)),
+        };
+        (executor, rx)
+    }
+
+    /// Send an agent update, waiting for buffer space
+    ///
+    /// # Errors
+    ///
+    /// Returns `StreamSendError::Stalled` if the buffer stays full for the
+    /// stall timeout, or has done so before for this client. Operations
+    /// running under [`execute_with_streaming`](Self::execute_with_streaming)
+    /// are abandoned once that happens.
+    pub async fn send_update(&self, update: AgentUpdate) -> Result<(), StreamSendError> {
+        if self.is_stalled() {
+            return Err(StreamSendError::Stalled(self.stall_timeout));
+        }
+
+        match tokio::time::timeout(self.stall_timeout, self.update_sender.send(update)).await {
+            Ok(Ok(())) => Ok(()),
+            Ok(Err(_)) => Err(StreamSendError::Closed),
+            Err(_) => {
+                tracing::warn!(
+                    stall_timeout = ?self.stall_timeout,
+                    "Streaming client stalled, abandoning stream"
+                );
+                self.stalled.send_replace(true);
+                Err(StreamSendError::Stalled(self.stall_timeout))
+            }
+        }
+    }
+
+    /// Whether the client stopped reading updates
+    pub fn is_stalled(&self) -> bool {
+        *self.stalled.borrow()
     }
 
     /// Execute an agent with streaming updates
+    ///
+    /// If the client stalls, `operation` is dropped at its next await point
+    /// and an error is returned; see [`StreamBackpressureConfig`] for what
+    /// that means for an agent step.
     pub async fn execute_with_streaming<F, Fut>(
         &self,
         agent_id: String,
@@ -315,12 +419,30 @@ impl StreamingAgentExecutor {
             })
             .await;
 
-        // Execute the operation
-        let executor = StreamingAgentExecutor {
-            update_sender: self.update_sender.clone(),
+        // Execute the operation, abandoning it if the client stalls. An operation
+        // that ignores failed sends may also finish in the poll that stalled, so
+        // check again afterwards: its result never reaches the client either way.
+        let mut stalled = self.stalled.subscribe();
+        let outcome = tokio::select! {
+            biased;
+            _ = stalled.wait_for(|stalled| *stalled) => None,
+            result = operation(self.clone()) => Some(result),
+        };
+        let Some(outcome) = outcome.filter(|_| !self.is_stalled()) else {
+            let error = format!(
+                "Stream abandoned: client did not read updates for {:?}",
+                self.stall_timeout
+            );
+            // The buffer is full, so only report the abandonment if there is room
+            let _ = self.update_sender.try_send(AgentUpdate::Error {
+                agent_id,
+                error: error.clone(),
+                timestamp: chrono::Utc::now(),
+            });
+            return Err(error);
         };
 
-        match operation(executor).await {
+        match outcome {
             Ok(result) => {
                 // Send completion event
                 let _ = self
@@ -548,6 +670,53 @@ mod tests {
         assert_eq!(result.unwrap(), "Hello, world!");
     }
 
+    #[tokio::test]
+    async fn test_stalled_consumer_blocks_then_cancels_producer() {
+        let (executor, _receiver) =
+            StreamingAgentExecutor::with_backpressure(StreamBackpressureConfig {
+                buffer_size: NonZeroUsize::new(2).unwrap(),
+                stall_timeout: Duration::from_millis(200),
+            });
+        let sent = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+
+        let step = tokio::spawn({
+            let executor = executor.clone();
+            let sent = Arc::clone(&sent);
+            async move {
+                executor
+                    .execute_with_streaming("test-agent".to_string(), |exec| async move {
+                        for i in 0..100 {
+                            exec.partial("test-agent", &format!("chunk {}", i)).await;
+                            sent.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+                        }
+                        Ok("done".to_string())
+                    })
+                    .await
+            }
+        });
+
+        // The producer fills the buffer (the Started update takes one slot) and waits
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        assert!(!step.is_finished());
+        assert_eq!(sent.load(std::sync::atomic::Ordering::SeqCst), 1);
+
+        let result = tokio::time::timeout(Duration::from_secs(2), step)
+            .await
+            .expect("stream should be abandoned after the stall timeout")
+            .unwrap();
+        let error = result.unwrap_err();
+        assert!(error.contains("client did not read updates"), "{}", error);
+        assert!(executor.is_stalled());
+        assert_eq!(
+            executor
+                .send_update(AgentUpdate::Ping {
+                    timestamp: chrono::Utc::now()
+                })
+                .await,
+            Err(StreamSendError::Stalled(Duration::from_millis(200)))
+        );
+    }
+
     #[test]
     fn test_agent_update_serialization() {
         let update = AgentUpdate::Started {
@@ -957,4 +1126,45 @@ mod tests {
         // Should succeed even with closed channel
         assert_eq!(result.unwrap(), "Success");
     }
+
+    #[tokio::test]
+    async fn test_sse_stream_reports_stream_metrics() {
+        use futures::StreamExt;
+        use skreaver_observability::MetricsRegistry;
+
+        let id = uuid::Uuid::new_v4().simple().to_string();
+        let registry = MetricsRegistry::new(&format!("test{}", &id[0..8])).unwrap();
+        let metrics = registry.core_metrics();
+        let active = metrics.streams_active.with_label_values(&["sse"]);
+        let frames = metrics.stream_frames_sent_total.with_label_values(&["sse"]);
+        let bytes = metrics.stream_bytes_sent_total.with_label_values(&["sse"]);
+
+        let (tx, rx) = tokio::sync::mpsc::channel(4);
+        let update = AgentUpdate::Ping {
+            timestamp: chrono::Utc::now(),
+        };
+        let expected_bytes = serde_json::to_string(&update).unwrap().len();
+
+        let mut stream = Box::pin(sse_events(
+            rx,
+            Some(registry.track_stream(StreamTransport::Sse)),
+        ));
+        assert_eq!(active.get(), 1.0);
+
+        tx.send(update).await.unwrap();
+        assert!(stream.next().await.unwrap().is_ok());
+        assert_eq!(frames.get(), 1.0);
+        assert_eq!(bytes.get(), expected_bytes as f64);
+
+        drop(tx);
+        assert!(stream.next().await.is_none());
+        assert_eq!(active.get(), 0.0);
+        assert_eq!(
+            metrics
+                .stream_duration_seconds
+                .with_label_values(&["sse"])
+                .get_sample_count(),
+            1
+        );
+    }
 }
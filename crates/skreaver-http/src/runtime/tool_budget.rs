@@ -0,0 +1,109 @@
+//! Time budget for the tool calls of a single step
+//!
+//! Tool timeouts bound individual calls, but an agent that makes many calls
+//! can still spend a long time in tools during one step. A [`ToolTimeBudget`]
+//! caps the total: every call draws from the step's remaining budget, and
+//! each call may run for at most the smaller of its own timeout and what is
+//! left. The [`Coordinator`](super::Coordinator) ends the step with
+//! [`StepError::ToolBudgetExhausted`](super::StepError::ToolBudgetExhausted)
+//! once the budget runs out.
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+/// Total tool time allowed per step, with optional per-tool timeouts
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ToolTimeBudget {
+    total: Duration,
+    timeouts: HashMap<String, Duration>,
+    default_timeout: Option<Duration>,
+}
+
+impl ToolTimeBudget {
+    /// Allow the tool calls of a step to run for `total` combined
+    pub fn new(total: Duration) -> Self {
+        Self {
+            total,
+            timeouts: HashMap::new(),
+            default_timeout: None,
+        }
+    }
+
+    /// Let a single call to `tool_name` run for at most `timeout`
+    pub fn with_tool_timeout(mut self, tool_name: impl Into<String>, timeout: Duration) -> Self {
+        self.timeouts.insert(tool_name.into(), timeout);
+        self
+    }
+
+    /// Let a single call to a tool without its own timeout run for at most `timeout`
+    pub fn with_default_timeout(mut self, timeout: Duration) -> Self {
+        self.default_timeout = Some(timeout);
+        self
+    }
+
+    /// Total tool time allowed per step
+    pub fn total(&self) -> Duration {
+        self.total
+    }
+
+    /// Own timeout of `tool_name`, if it has one
+    pub fn tool_timeout(&self, tool_name: &str) -> Option<Duration> {
+        self.timeouts
+            .get(tool_name)
+            .copied()
+            .or(self.default_timeout)
+    }
+
+    /// Time budget left after `spent` has been used in the current step
+    pub fn remaining(&self, spent: Duration) -> Duration {
+        self.total.saturating_sub(spent)
+    }
+
+    /// Timeout for the next call to `tool_name` after `spent` has been used
+    ///
+    /// This is the smaller of the tool's own timeout and the remaining budget.
+    pub fn effective_timeout(&self, tool_name: &str, spent: Duration) -> Duration {
+        let remaining = self.remaining(spent);
+        self.tool_timeout(tool_name)
+            .map_or(remaining, |timeout| timeout.min(remaining))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_effective_timeout_is_capped_by_remaining_budget() {
+        let budget = ToolTimeBudget::new(Duration::from_secs(10))
+            .with_tool_timeout("search", Duration::from_secs(4))
+            .with_default_timeout(Duration::from_secs(2));
+
+        assert_eq!(
+            budget.effective_timeout("search", Duration::ZERO),
+            Duration::from_secs(4)
+        );
+        assert_eq!(
+            budget.effective_timeout("other", Duration::ZERO),
+            Duration::from_secs(2)
+        );
+        assert_eq!(
+            budget.effective_timeout("search", Duration::from_secs(7)),
+            Duration::from_secs(3)
+        );
+        assert_eq!(
+            budget.effective_timeout("search", Duration::from_secs(12)),
+            Duration::ZERO
+        );
+    }
+
+    #[test]
+    fn test_tools_without_timeouts_get_the_remaining_budget() {
+        let budget = ToolTimeBudget::new(Duration::from_secs(5));
+        assert_eq!(budget.tool_timeout("any"), None);
+        assert_eq!(
+            budget.effective_timeout("any", Duration::from_secs(1)),
+            Duration::from_secs(4)
+        );
+    }
+}
@@ -0,0 +1,345 @@
+//! Per-tool concurrency limits with priority-ordered queueing
+//!
+//! A [`ToolConcurrencyLimiter`] caps how many calls to a tool may run at
+//! once. Calls beyond the cap wait, and when a slot frees up it goes to the
+//! waiting call with the highest [`RequestPriority`], oldest first among equal
+//! priorities. Waiting is asynchronous: a queued call parks its task rather
+//! than a runtime thread, and a call whose future is dropped leaves the queue.
+//! The [`Coordinator`](super::Coordinator) acquires a slot with the priority of
+//! the request it is serving, so tool calls made for a high-priority request
+//! overtake those of low-priority requests queued before them. Clones of a
+//! limiter share their slots, so one limiter can be handed to every
+//! coordinator that should compete for the same tools; the HTTP runtime does
+//! this for the limiter in
+//! [`HttpRuntimeConfig::tool_limiter`](super::HttpRuntimeConfig::tool_limiter).
+
+use super::backpressure::RequestPriority;
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+use std::fmt;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard};
+use tokio::sync::Notify;
+
+/// Running and waiting calls of one tool
+#[derive(Default)]
+struct ToolSlots {
+    active: usize,
+    /// Waiting calls, highest priority first and oldest first within a priority
+    waiting: BinaryHeap<(RequestPriority, Reverse<u64>)>,
+}
+
+/// Slots shared by all clones of a limiter
+#[derive(Default)]
+struct SharedSlots {
+    tools: Mutex<HashMap<String, ToolSlots>>,
+    released: Notify,
+    next_ticket: AtomicU64,
+}
+
+/// Concurrency limits for individual tools
+#[derive(Clone)]
+pub struct ToolConcurrencyLimiter {
+    limits: HashMap<String, usize>,
+    default_limit: Option<usize>,
+    slots: Arc<SharedSlots>,
+}
+
+impl fmt::Debug for ToolConcurrencyLimiter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ToolConcurrencyLimiter")
+            .field("limits", &self.limits)
+            .field("default_limit", &self.default_limit)
+            .finish()
+    }
+}
+
+impl Default for ToolConcurrencyLimiter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ToolConcurrencyLimiter {
+    /// Create a limiter that limits no tools
+    pub fn new() -> Self {
+        Self {
+            limits: HashMap::new(),
+            default_limit: None,
+            slots: Arc::new(SharedSlots::default()),
+        }
+    }
+
+    /// Allow at most `max_concurrent` calls to `tool_name` at once
+    ///
+    /// A limit of zero is treated as one.
+    pub fn with_limit(mut self, tool_name: impl Into<String>, max_concurrent: usize) -> Self {
+        self.limits.insert(tool_name.into(), max_concurrent.max(1));
+        self
+    }
+
+    /// Allow at most `max_concurrent` calls at once to tools without their own limit
+    ///
+    /// A limit of zero is treated as one.
+    pub fn with_default_limit(mut self, max_concurrent: usize) -> Self {
+        self.default_limit = Some(max_concurrent.max(1));
+        self
+    }
+
+    /// Get the concurrency limit for `tool_name`, if it has one
+    pub fn limit(&self, tool_name: &str) -> Option<usize> {
+        self.limits.get(tool_name).copied().or(self.default_limit)
+    }
+
+    /// Number of calls to `tool_name` currently running
+    pub fn active(&self, tool_name: &str) -> usize {
+        self.lock().get(tool_name).map_or(0, |slots| slots.active)
+    }
+
+    /// Number of calls to `tool_name` waiting for a slot
+    pub fn waiting(&self, tool_name: &str) -> usize {
+        self.lock()
+            .get(tool_name)
+            .map_or(0, |slots| slots.waiting.len())
+    }
+
+    /// Wait until a call to `tool_name` may run at `priority`
+    ///
+    /// Returns `None` at once when the tool has no limit. Otherwise the
+    /// returned permit holds the slot until it is dropped. Dropping the
+    /// future before it completes gives up the call's place in the queue.
+    pub async fn acquire(&self, tool_name: &str, priority: RequestPriority) -> Option<ToolPermit> {
+        let limit = self.limit(tool_name)?;
+        let ticket = (
+            priority,
+            Reverse(self.slots.next_ticket.fetch_add(1, Ordering::Relaxed)),
+        );
+        self.lock()
+            .entry(tool_name.to_string())
+            .or_default()
+            .waiting
+            .push(ticket);
+        let mut queued = Queued {
+            slots: &self.slots,
+            tool_name,
+            ticket: Some(ticket),
+        };
+
+        loop {
+            // Register for wakeups before checking so a release between the
+            // check and the wait is not missed
+            let released = self.slots.released.notified();
+            tokio::pin!(released);
+            released.as_mut().enable();
+            if queued.take_slot(limit) {
+                break;
+            }
+            released.await;
+        }
+
+        Some(self.permit(tool_name))
+    }
+
+    /// Take a slot for `tool_name` without waiting
+    ///
+    /// Returns `None` when the tool has no limit, when all its slots are in
+    /// use, or when other calls are already waiting for one.
+    pub fn try_acquire(&self, tool_name: &str) -> Option<ToolPermit> {
+        let limit = self.limit(tool_name)?;
+        let mut tools = self.lock();
+        let slots = tools.entry(tool_name.to_string()).or_default();
+        if slots.active < limit && slots.waiting.is_empty() {
+            slots.active += 1;
+            drop(tools);
+            Some(self.permit(tool_name))
+        } else {
+            if slots.active == 0 && slots.waiting.is_empty() {
+                tools.remove(tool_name);
+            }
+            None
+        }
+    }
+
+    fn permit(&self, tool_name: &str) -> ToolPermit {
+        ToolPermit {
+            tool_name: tool_name.to_string(),
+            slots: Arc::clone(&self.slots),
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, HashMap<String, ToolSlots>> {
+        self.slots.tools.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// A call's place in the queue, withdrawn if it is dropped before getting a slot
+struct Queued<'a> {
+    slots: &'a SharedSlots,
+    tool_name: &'a str,
+    ticket: Option<(RequestPriority, Reverse<u64>)>,
+}
+
+impl Queued<'_> {
+    /// Take a free slot if this call is first in line
+    fn take_slot(&mut self, limit: usize) -> bool {
+        let mut tools = self.slots.tools.lock().unwrap_or_else(|e| e.into_inner());
+        let slots = tools
+            .get_mut(self.tool_name)
+            .expect("waiting tool keeps its slots");
+        if slots.active < limit && slots.waiting.peek() == self.ticket.as_ref() {
+            slots.waiting.pop();
+            slots.active += 1;
+            self.ticket = None;
+            // The next waiter may fit into another free slot
+            self.slots.released.notify_waiters();
+            true
+        } else {
+            false
+        }
+    }
+}
+
+impl Drop for Queued<'_> {
+    fn drop(&mut self) {
+        let Some(ticket) = self.ticket else {
+            return;
+        };
+        let mut tools = self.slots.tools.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(slots) = tools.get_mut(self.tool_name) {
+            slots.waiting.retain(|waiting| *waiting != ticket);
+            if slots.active == 0 && slots.waiting.is_empty() {
+                tools.remove(self.tool_name);
+            }
+        }
+        // The call behind this one may now be first in line
+        self.slots.released.notify_waiters();
+    }
+}
+
+/// A running call's slot, released when dropped
+pub struct ToolPermit {
+    tool_name: String,
+    slots: Arc<SharedSlots>,
+}
+
+impl fmt::Debug for ToolPermit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ToolPermit")
+            .field("tool_name", &self.tool_name)
+            .finish()
+    }
+}
+
+impl Drop for ToolPermit {
+    fn drop(&mut self) {
+        let mut tools = self.slots.tools.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(slots) = tools.get_mut(&self.tool_name) {
+            slots.active = slots.active.saturating_sub(1);
+            if slots.active == 0 && slots.waiting.is_empty() {
+                tools.remove(&self.tool_name);
+            }
+        }
+        self.slots.released.notify_waiters();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::{Duration, Instant};
+
+    async fn wait_for(condition: impl Fn() -> bool) {
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while !condition() {
+            assert!(Instant::now() < deadline, "condition not reached");
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        }
+    }
+
+    #[tokio::test]
+    async fn test_unlimited_tools_do_not_wait() {
+        let limiter = ToolConcurrencyLimiter::new().with_limit("search", 0);
+        assert_eq!(limiter.limit("search"), Some(1));
+        assert_eq!(limiter.limit("echo"), None);
+        assert!(
+            limiter
+                .acquire("echo", RequestPriority::Low)
+                .await
+                .is_none()
+        );
+
+        let permit = limiter.acquire("search", RequestPriority::Normal).await;
+        assert!(permit.is_some());
+        assert_eq!(limiter.active("search"), 1);
+        assert!(limiter.try_acquire("search").is_none());
+        drop(permit);
+        assert_eq!(limiter.active("search"), 0);
+        assert!(limiter.try_acquire("search").is_some());
+        assert_eq!(limiter.active("search"), 0);
+
+        let limiter = limiter.with_default_limit(2);
+        assert_eq!(limiter.limit("echo"), Some(2));
+    }
+
+    #[tokio::test]
+    async fn test_slots_go_to_highest_priority_first() {
+        let limiter = ToolConcurrencyLimiter::new().with_limit("search", 1);
+        let order = Arc::new(Mutex::new(Vec::new()));
+        let holder = limiter.try_acquire("search");
+        assert!(holder.is_some());
+
+        let mut waiters = Vec::new();
+        for (waiting, priority) in [
+            (1, RequestPriority::Low),
+            (2, RequestPriority::Normal),
+            (3, RequestPriority::High),
+        ] {
+            let waiter = limiter.clone();
+            let order = Arc::clone(&order);
+            waiters.push(tokio::spawn(async move {
+                let _permit = waiter.acquire("search", priority).await;
+                order.lock().unwrap().push(priority);
+            }));
+            wait_for(|| limiter.waiting("search") == waiting).await;
+        }
+        // Queued calls do not jump ahead of waiting ones
+        assert!(limiter.try_acquire("search").is_none());
+        drop(holder);
+        for waiter in waiters {
+            waiter.await.unwrap();
+        }
+
+        assert_eq!(
+            *order.lock().unwrap(),
+            vec![
+                RequestPriority::High,
+                RequestPriority::Normal,
+                RequestPriority::Low
+            ]
+        );
+        assert_eq!(limiter.waiting("search"), 0);
+    }
+
+    #[tokio::test]
+    async fn test_cancelled_acquire_leaves_the_queue() {
+        let limiter = ToolConcurrencyLimiter::new().with_limit("search", 1);
+        let holder = limiter.try_acquire("search");
+
+        let waiter = limiter.clone();
+        let cancelled =
+            tokio::spawn(async move { waiter.acquire("search", RequestPriority::High).await });
+        wait_for(|| limiter.waiting("search") == 1).await;
+        cancelled.abort();
+        assert!(cancelled.await.unwrap_err().is_cancelled());
+        assert_eq!(limiter.waiting("search"), 0);
+
+        drop(holder);
+        let permit = tokio::time::timeout(
+            Duration::from_secs(5),
+            limiter.acquire("search", RequestPriority::Low),
+        )
+        .await
+        .expect("slot is not held by the cancelled call");
+        assert!(permit.is_some());
+    }
+}
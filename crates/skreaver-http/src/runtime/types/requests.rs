@@ -113,3 +113,41 @@ fn default_parallel_limit() -> usize {
 fn default_operation_timeout() -> u64 {
     30
 }
+
+/// Request body for changing an agent's circuit breaker state
+#[derive(Debug, Default, Deserialize, ToSchema)]
+pub struct CircuitBreakerRequest {
+    /// Why the breaker is being changed (recorded for operators)
+    pub reason: Option<String>,
+}
+
+/// Request body for invoking a tool directly
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct InvokeToolRequest {
+    /// Input passed to the tool
+    #[schema(example = "hello")]
+    pub input: String,
+}
+
+/// Query parameters for listing and purging DLQ entries
+#[derive(Debug, Default, Deserialize)]
+pub struct DlqQueryParams {
+    /// Only entries whose failure reason contains this text
+    pub reason: Option<String>,
+    /// Only entries at least this many seconds old
+    pub min_age_secs: Option<u64>,
+    /// Only entries at most this many seconds old
+    pub max_age_secs: Option<u64>,
+    /// Number of matching entries to skip (listing only)
+    #[serde(default)]
+    pub offset: usize,
+    /// Maximum number of entries to return (listing only)
+    pub limit: Option<usize>,
+}
+
+/// Request body for changing an agent's log level override
+#[derive(Debug, Default, Deserialize, ToSchema)]
+pub struct AgentLogLevelRequest {
+    /// `trace`, `debug`, `info`, `warn` or `error`; omit or null to clear the override
+    pub level: Option<String>,
+}
@@ -58,6 +58,23 @@ pub struct AgentsListResponse {
     pub total: usize,
 }
 
+/// Response containing list of agents in the v2 envelope format
+#[derive(Debug, Serialize, ToSchema)]
+pub struct AgentsListResponseV2 {
+    /// List of agent status information
+    pub data: Vec<AgentStatus>,
+    /// Collection metadata
+    pub meta: CollectionMeta,
+}
+
+/// Metadata returned alongside v2 collection responses
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CollectionMeta {
+    /// Total number of items in the collection
+    #[schema(example = 5)]
+    pub total: usize,
+}
+
 /// Error response format for handler return types and OpenAPI documentation.
 ///
 /// This is the lightweight version used in handler signatures.
@@ -133,6 +150,125 @@ pub struct BatchResult {
     pub processing_time_ms: u64,
 }
 
+/// Response for batch observe operations in the v2 format
+#[derive(Debug, Serialize, ToSchema)]
+pub struct BatchObserveResponseV2 {
+    /// Agent identifier
+    pub agent_id: String,
+    /// Per-input results (agent responses) and summary
+    #[serde(flatten)]
+    pub batch: BatchResponse<String>,
+    /// Total processing time
+    pub total_time_ms: u64,
+    /// Request timestamp
+    pub timestamp: chrono::DateTime<chrono::Utc>,
+}
+
+/// Outcome of a single batch item
+#[derive(Debug, Clone, PartialEq, Serialize, ToSchema)]
+#[serde(tag = "status", rename_all = "lowercase")]
+pub enum BatchItemOutcome<T> {
+    /// Processing succeeded
+    Success {
+        /// Result produced for the item
+        result: T,
+    },
+    /// Processing failed
+    Failure {
+        /// Error message
+        error: String,
+    },
+}
+
+/// Result of a single item in a batch operation
+#[derive(Debug, Clone, PartialEq, Serialize, ToSchema)]
+pub struct BatchItem<T> {
+    /// Position of the item in the request
+    pub index: usize,
+    /// Item status with its result or error
+    #[serde(flatten)]
+    pub outcome: BatchItemOutcome<T>,
+    /// Processing time for this item in milliseconds
+    pub processing_time_ms: u64,
+}
+
+impl<T> BatchItem<T> {
+    /// Successful item
+    pub fn success(index: usize, result: T, processing_time_ms: u64) -> Self {
+        Self {
+            index,
+            outcome: BatchItemOutcome::Success { result },
+            processing_time_ms,
+        }
+    }
+
+    /// Failed item
+    pub fn failure(index: usize, error: impl Into<String>, processing_time_ms: u64) -> Self {
+        Self {
+            index,
+            outcome: BatchItemOutcome::Failure {
+                error: error.into(),
+            },
+            processing_time_ms,
+        }
+    }
+
+    /// Check whether the item succeeded
+    pub fn is_success(&self) -> bool {
+        matches!(self.outcome, BatchItemOutcome::Success { .. })
+    }
+}
+
+/// Item counts of a batch operation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, ToSchema)]
+pub struct BatchSummary {
+    /// Number of items in the batch
+    pub total: usize,
+    /// Items that succeeded
+    pub succeeded: usize,
+    /// Items that failed
+    pub failed: usize,
+}
+
+/// Per-item results of a batch operation with a summary
+///
+/// Every batch endpoint reports its items in this shape, so clients can
+/// handle partial success the same way everywhere.
+#[derive(Debug, Clone, PartialEq, Serialize, ToSchema)]
+pub struct BatchResponse<T> {
+    /// Item results, ordered by index
+    pub results: Vec<BatchItem<T>>,
+    /// Success and failure counts
+    pub summary: BatchSummary,
+}
+
+impl<T> BatchResponse<T> {
+    /// Collect item results, ordering them by index and counting outcomes
+    pub fn new(mut results: Vec<BatchItem<T>>) -> Self {
+        results.sort_by_key(|item| item.index);
+        let succeeded = results.iter().filter(|item| item.is_success()).count();
+        let summary = BatchSummary {
+            total: results.len(),
+            succeeded,
+            failed: results.len() - succeeded,
+        };
+        Self { results, summary }
+    }
+
+    /// Overall HTTP status: `200 OK` when every item succeeded, otherwise
+    /// `207 Multi-Status`, so clients know to inspect the individual items
+    ///
+    /// Items fail for their own reasons, such as bad input or a timed out
+    /// agent, so even a batch where every item failed ran successfully.
+    pub fn status_code(&self) -> axum::http::StatusCode {
+        if self.summary.failed == 0 {
+            axum::http::StatusCode::OK
+        } else {
+            axum::http::StatusCode::MULTI_STATUS
+        }
+    }
+}
+
 /// Response for queue metrics
 #[derive(Debug, Serialize, ToSchema)]
 pub struct QueueMetricsResponse {
@@ -152,6 +288,188 @@ pub struct QueueMetricsResponse {
     pub avg_processing_time_ms: f64,
     /// Current load factor (0.0-1.0)
     pub load_factor: f64,
+    /// Longest queue wait in milliseconds per priority (`low`, `normal`,
+    /// `high`, `critical`), for priorities that have been dequeued
+    pub max_wait_ms_by_priority: std::collections::BTreeMap<String, u64>,
     /// Timestamp when metrics were collected
     pub timestamp: chrono::DateTime<chrono::Utc>,
 }
+
+/// Response describing an agent's circuit breaker
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CircuitBreakerResponse {
+    /// Agent ID
+    pub agent_id: String,
+    /// Breaker state: `closed`, `open` or `half_open`
+    pub state: String,
+    /// Why the breaker was last changed
+    pub reason: Option<String>,
+    /// Principal that last changed the breaker
+    pub changed_by: Option<String>,
+    /// When the breaker last changed state
+    pub changed_at: Option<chrono::DateTime<chrono::Utc>>,
+    /// Requests rejected since the breaker last changed state
+    pub total_rejections: u64,
+}
+
+/// Response describing an agent's log level override
+#[derive(Debug, Serialize, ToSchema)]
+pub struct AgentLogLevelResponse {
+    /// Agent ID
+    pub agent_id: String,
+    /// Overridden level, or null when the agent logs at the global level
+    pub level: Option<String>,
+}
+
+/// Output of a tool that did not declare a content type
+#[derive(Debug, Serialize, ToSchema)]
+pub struct InvokeToolResponse {
+    /// Name of the invoked tool
+    pub tool_name: String,
+    /// Tool output
+    pub output: String,
+    /// Tool execution time in milliseconds
+    pub duration_ms: u64,
+}
+
+/// A message held in the mesh dead letter queue
+#[derive(Debug, Serialize, ToSchema)]
+pub struct DlqEntryResponse {
+    /// Message ID
+    pub message_id: String,
+    /// The failed message, as sent on the mesh
+    pub message: serde_json::Value,
+    /// Why the message was dead-lettered
+    pub failure_reason: String,
+    /// Error from the last retry attempt
+    pub last_error: Option<String>,
+    /// Number of retry attempts so far
+    pub retry_count: u32,
+    /// Seconds since the message was dead-lettered
+    pub age_secs: u64,
+    /// When the message was dead-lettered
+    pub added_at: chrono::DateTime<chrono::Utc>,
+    /// When the message will be dropped from the DLQ
+    pub expires_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// One page of dead letter queue entries
+#[derive(Debug, Serialize, ToSchema)]
+pub struct DlqListResponse {
+    /// Entries on this page, oldest first
+    pub entries: Vec<DlqEntryResponse>,
+    /// Number of entries matching the filters across all pages
+    pub total: usize,
+    /// Number of matching entries skipped
+    pub offset: usize,
+    /// Maximum number of entries on this page
+    pub limit: usize,
+}
+
+/// Dead letter queue size, age and lifetime counters
+#[derive(Debug, Serialize, ToSchema)]
+pub struct DlqStatsResponse {
+    /// Messages currently in the DLQ
+    pub size: usize,
+    /// Seconds since the oldest message was dead-lettered, or null when empty
+    pub oldest_age_secs: Option<u64>,
+    /// Messages ever added
+    pub total_added: u64,
+    /// Messages removed or purged by operators
+    pub total_removed: u64,
+    /// Messages dropped after their TTL
+    pub total_expired: u64,
+    /// Messages dropped after exhausting their retries
+    pub total_exhausted: u64,
+    /// Messages successfully replayed
+    pub total_retried: u64,
+}
+
+/// Result of replaying a dead-lettered message
+#[derive(Debug, Serialize, ToSchema)]
+pub struct DlqReplayResponse {
+    /// Message ID
+    pub message_id: String,
+    /// When the message was re-sent and removed from the DLQ
+    pub replayed_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// Result of purging dead-lettered messages
+#[derive(Debug, Serialize, ToSchema)]
+pub struct DlqPurgeResponse {
+    /// Number of messages removed
+    pub purged: usize,
+}
+
+/// A tool call held for an approval decision
+#[derive(Debug, Serialize, ToSchema)]
+pub struct PendingApprovalResponse {
+    /// Identifier to approve or deny the call with
+    pub action_id: String,
+    /// Name of the tool the agent wants to call
+    pub tool_name: String,
+    /// Input the tool would be called with
+    pub input: String,
+    /// When approval was requested
+    pub requested_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// Tool calls waiting for an approval decision
+#[derive(Debug, Serialize, ToSchema)]
+pub struct PendingApprovalsResponse {
+    /// Pending calls, oldest first
+    pub pending: Vec<PendingApprovalResponse>,
+}
+
+/// Result of deciding on a held tool call
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ApprovalDecisionResponse {
+    /// Identifier of the decided action
+    pub action_id: String,
+    /// Whether the call was approved (true) or denied (false)
+    pub approved: bool,
+    /// When the decision was recorded
+    pub decided_at: chrono::DateTime<chrono::Utc>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::http::StatusCode;
+    use serde_json::json;
+
+    #[test]
+    fn test_mixed_batch_serializes_per_item_status_and_summary() {
+        let batch = BatchResponse::new(vec![
+            BatchItem::failure(1, "Operation timed out", 30),
+            BatchItem::success(0, "hello".to_string(), 5),
+            BatchItem::success(2, "world".to_string(), 7),
+        ]);
+        assert_eq!(batch.status_code(), StatusCode::MULTI_STATUS);
+
+        assert_eq!(
+            serde_json::to_value(&batch).unwrap(),
+            json!({
+                "results": [
+                    {"index": 0, "status": "success", "result": "hello", "processing_time_ms": 5},
+                    {"index": 1, "status": "failure", "error": "Operation timed out", "processing_time_ms": 30},
+                    {"index": 2, "status": "success", "result": "world", "processing_time_ms": 7}
+                ],
+                "summary": {"total": 3, "succeeded": 2, "failed": 1}
+            })
+        );
+    }
+
+    #[test]
+    fn test_batch_status_code() {
+        let all_ok = BatchResponse::new(vec![BatchItem::success(0, json!({"ok": true}), 1)]);
+        assert_eq!(all_ok.status_code(), StatusCode::OK);
+
+        let all_failed = BatchResponse::<String>::new(vec![
+            BatchItem::failure(0, "boom", 1),
+            BatchItem::failure(1, "boom", 1),
+        ]);
+        assert_eq!(all_failed.status_code(), StatusCode::MULTI_STATUS);
+        assert_eq!(all_failed.summary.failed, 2);
+    }
+}
@@ -0,0 +1,371 @@
+//! Per-principal usage accounting
+//!
+//! Tallies request counts, request/response body sizes and tool invocations
+//! per authenticated principal so operators can bill by usage. Only sizes and
+//! counts are recorded, never request or response content. Totals are handed
+//! to a pluggable [`UsageSink`]: [`InMemoryUsageSink`] keeps them in memory,
+//! [`FileUsageSink`] periodically appends them to a JSON-lines file.
+
+use axum::{
+    body::HttpBody,
+    extract::{Request, State},
+    http::{HeaderMap, header},
+    middleware::Next,
+    response::Response,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+use std::io::Write;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use crate::runtime::auth::AuthContext;
+
+/// Usage of a single request by a principal
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UsageEvent {
+    /// Principal (tenant) the request is billed to
+    pub principal: String,
+    /// Request body size in bytes
+    pub input_bytes: u64,
+    /// Response body size in bytes
+    pub output_bytes: u64,
+    /// Tool calls dispatched while serving the request
+    pub tool_invocations: u64,
+}
+
+/// Aggregated usage of one principal
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UsageTotals {
+    /// Number of requests
+    pub requests: u64,
+    /// Total request body bytes
+    pub input_bytes: u64,
+    /// Total response body bytes
+    pub output_bytes: u64,
+    /// Total tool invocations
+    pub tool_invocations: u64,
+}
+
+impl UsageTotals {
+    /// Add one request's usage to the totals
+    pub fn add(&mut self, event: &UsageEvent) {
+        self.requests += 1;
+        self.input_bytes += event.input_bytes;
+        self.output_bytes += event.output_bytes;
+        self.tool_invocations += event.tool_invocations;
+    }
+}
+
+/// Destination for per-request usage events
+pub trait UsageSink: Send + Sync {
+    /// Record one request's usage
+    fn record(&self, event: UsageEvent);
+
+    /// Aggregated usage per principal since the sink was created
+    fn totals(&self) -> HashMap<String, UsageTotals>;
+}
+
+impl fmt::Debug for dyn UsageSink {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UsageSink")
+            .field("principals", &self.totals().len())
+            .finish()
+    }
+}
+
+fn aggregate(totals: &Mutex<HashMap<String, UsageTotals>>, event: &UsageEvent) {
+    let mut totals = totals.lock().unwrap_or_else(|e| e.into_inner());
+    totals
+        .entry(event.principal.clone())
+        .or_default()
+        .add(event);
+}
+
+fn snapshot(totals: &Mutex<HashMap<String, UsageTotals>>) -> HashMap<String, UsageTotals> {
+    totals.lock().unwrap_or_else(|e| e.into_inner()).clone()
+}
+
+/// Usage sink that keeps totals in memory
+#[derive(Debug, Default)]
+pub struct InMemoryUsageSink {
+    totals: Mutex<HashMap<String, UsageTotals>>,
+}
+
+impl InMemoryUsageSink {
+    /// Create an empty sink
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl UsageSink for InMemoryUsageSink {
+    fn record(&self, event: UsageEvent) {
+        aggregate(&self.totals, &event);
+    }
+
+    fn totals(&self) -> HashMap<String, UsageTotals> {
+        snapshot(&self.totals)
+    }
+}
+
+/// One principal's usage for a flush period, as written by [`FileUsageSink`]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UsageFileRecord {
+    /// Principal (tenant) the usage is billed to
+    pub principal: String,
+    /// When the period ended
+    pub flushed_at: chrono::DateTime<chrono::Utc>,
+    /// Usage during the period
+    #[serde(flatten)]
+    pub usage: UsageTotals,
+}
+
+/// Usage sink that appends per-period totals to a JSON-lines file
+///
+/// Usage accumulates in memory and [`flush`](Self::flush) appends one line
+/// per principal with the usage since the previous flush. Use
+/// [`spawn_flush_task`](Self::spawn_flush_task) to flush on an interval.
+#[derive(Debug)]
+pub struct FileUsageSink {
+    path: PathBuf,
+    totals: Mutex<HashMap<String, UsageTotals>>,
+    pending: Mutex<HashMap<String, UsageTotals>>,
+}
+
+impl FileUsageSink {
+    /// Create a sink that appends to `path`
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            totals: Mutex::new(HashMap::new()),
+            pending: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Append usage recorded since the last flush, returning the lines written
+    ///
+    /// # Errors
+    ///
+    /// Returns the I/O error if the file cannot be written; the unflushed
+    /// usage is kept for the next attempt.
+    pub fn flush(&self) -> std::io::Result<usize> {
+        let mut pending = self.pending.lock().unwrap_or_else(|e| e.into_inner());
+        if pending.is_empty() {
+            return Ok(0);
+        }
+
+        let flushed_at = chrono::Utc::now();
+        let mut lines = String::new();
+        for (principal, usage) in pending.iter() {
+            let record = UsageFileRecord {
+                principal: principal.clone(),
+                flushed_at,
+                usage: usage.clone(),
+            };
+            lines.push_str(&serde_json::to_string(&record).map_err(std::io::Error::other)?);
+            lines.push('\n');
+        }
+
+        let mut file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        file.write_all(lines.as_bytes())?;
+
+        let written = pending.len();
+        pending.clear();
+        Ok(written)
+    }
+
+    /// Flush every `interval` until the returned task is aborted
+    pub fn spawn_flush_task(self: Arc<Self>, interval: Duration) -> tokio::task::JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            ticker.tick().await;
+            loop {
+                ticker.tick().await;
+                if let Err(e) = self.flush() {
+                    tracing::warn!(path = %self.path.display(), error = %e, "Usage flush failed");
+                }
+            }
+        })
+    }
+}
+
+impl UsageSink for FileUsageSink {
+    fn record(&self, event: UsageEvent) {
+        aggregate(&self.totals, &event);
+        aggregate(&self.pending, &event);
+    }
+
+    fn totals(&self) -> HashMap<String, UsageTotals> {
+        snapshot(&self.totals)
+    }
+}
+
+/// Request-scoped tool invocation counter
+///
+/// [`usage_middleware`] adds this to the request extensions; handlers that
+/// run agent steps report the tool calls they dispatched through it.
+#[derive(Debug, Clone, Default)]
+pub struct UsageScope(Arc<AtomicU64>);
+
+impl UsageScope {
+    /// Count tool calls dispatched for this request
+    pub fn add_tool_invocations(&self, count: u64) {
+        self.0.fetch_add(count, Ordering::Relaxed);
+    }
+
+    /// Tool calls counted so far
+    pub fn tool_invocations(&self) -> u64 {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+/// Body size from the exact size hint, falling back to `Content-Length`
+fn body_size(hint: Option<u64>, headers: &HeaderMap) -> u64 {
+    hint.or_else(|| {
+        headers
+            .get(header::CONTENT_LENGTH)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.parse().ok())
+    })
+    .unwrap_or(0)
+}
+
+/// Middleware that records usage of authenticated requests
+///
+/// Must run after authentication, since the principal comes from the
+/// [`AuthContext`]; unauthenticated requests are not recorded. Streaming
+/// bodies without a known size count as zero bytes.
+pub async fn usage_middleware(
+    State(sink): State<Arc<dyn UsageSink>>,
+    mut request: Request,
+    next: Next,
+) -> Response {
+    let Some(principal) = request
+        .extensions()
+        .get::<AuthContext>()
+        .map(|auth| auth.user_id.clone())
+    else {
+        return next.run(request).await;
+    };
+
+    let input_bytes = body_size(request.body().size_hint().exact(), request.headers());
+    let scope = UsageScope::default();
+    request.extensions_mut().insert(scope.clone());
+
+    let response = next.run(request).await;
+
+    sink.record(UsageEvent {
+        principal,
+        input_bytes,
+        output_bytes: body_size(response.body().size_hint().exact(), response.headers()),
+        tool_invocations: scope.tool_invocations(),
+    });
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runtime::auth::AuthMethod;
+    use axum::{Extension, Router, body::Body, middleware, routing::post};
+    use tower::ServiceExt;
+
+    /// Stand-in for `require_auth` that trusts an `x-user` header
+    async fn fake_auth(mut request: Request, next: Next) -> Response {
+        if let Some(user) = request.headers().get("x-user") {
+            let auth = AuthContext {
+                user_id: user.to_str().unwrap().to_string(),
+                permissions: Vec::new(),
+                auth_method: AuthMethod::JWT,
+            };
+            request.extensions_mut().insert(auth);
+        }
+        next.run(request).await
+    }
+
+    /// Echoes the body and reports one tool call per `!` in it
+    async fn tool_handler(Extension(scope): Extension<UsageScope>, body: String) -> String {
+        scope.add_tool_invocations(body.matches('!').count() as u64);
+        body
+    }
+
+    fn app(sink: Arc<dyn UsageSink>) -> Router {
+        Router::new()
+            .route("/run", post(tool_handler))
+            .route_layer(middleware::from_fn_with_state(sink, usage_middleware))
+            .route_layer(middleware::from_fn(fake_auth))
+    }
+
+    async fn send(app: &Router, user: &str, body: &'static str) {
+        let request = Request::builder()
+            .method("POST")
+            .uri("/run")
+            .header("x-user", user)
+            .body(Body::from(body))
+            .unwrap();
+        let response = app.clone().oneshot(request).await.unwrap();
+        assert!(response.status().is_success());
+    }
+
+    #[tokio::test]
+    async fn test_requests_aggregate_per_principal() {
+        let sink = Arc::new(InMemoryUsageSink::new());
+        let app = app(sink.clone());
+
+        send(&app, "alice", "hello!").await;
+        send(&app, "bob", "hi").await;
+        send(&app, "alice", "again!!").await;
+
+        let totals = sink.totals();
+        assert_eq!(totals.len(), 2);
+        assert_eq!(
+            totals["alice"],
+            UsageTotals {
+                requests: 2,
+                input_bytes: 13,
+                output_bytes: 13,
+                tool_invocations: 3,
+            }
+        );
+        assert_eq!(
+            totals["bob"],
+            UsageTotals {
+                requests: 1,
+                input_bytes: 2,
+                output_bytes: 2,
+                tool_invocations: 0,
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_file_sink_flushes_usage_since_last_flush() {
+        let path = std::env::temp_dir().join(format!("usage-{}.jsonl", uuid::Uuid::new_v4()));
+        let sink = Arc::new(FileUsageSink::new(&path));
+        let app = app(sink.clone());
+
+        send(&app, "alice", "one!").await;
+        assert_eq!(sink.flush().unwrap(), 1);
+        send(&app, "alice", "two").await;
+        assert_eq!(sink.flush().unwrap(), 1);
+        assert_eq!(sink.flush().unwrap(), 0);
+
+        let records: Vec<UsageFileRecord> = std::fs::read_to_string(&path)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].usage.tool_invocations, 1);
+        assert_eq!(records[1].usage.input_bytes, 3);
+        assert_eq!(sink.totals()["alice"].requests, 2);
+        std::fs::remove_file(&path).unwrap();
+    }
+}
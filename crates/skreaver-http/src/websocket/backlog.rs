@@ -0,0 +1,125 @@
+//! Recent events kept per channel for replay on subscribe
+//!
+//! Reconnecting clients can ask for the last N events of a channel when they
+//! subscribe. The manager records every published event here while holding
+//! the subscription read lock, and reads the backlog while holding the write
+//! lock during subscribe, so an event is either replayed to a new subscriber
+//! or delivered live, never both and never neither.
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::Mutex;
+
+use super::manager::ChannelEvent;
+
+/// Bounded ring buffer of recent events for each channel
+#[derive(Debug)]
+pub(crate) struct ChannelBacklog {
+    capacity: usize,
+    inner: Mutex<BacklogInner>,
+}
+
+#[derive(Debug, Default)]
+struct BacklogInner {
+    /// Sequence number of the last recorded event
+    last_seq: u64,
+    /// Events per channel, tagged with a global sequence number so replays
+    /// across several channels keep publish order
+    channels: HashMap<String, VecDeque<(u64, ChannelEvent)>>,
+}
+
+impl ChannelBacklog {
+    /// Create a backlog keeping up to `capacity` events per channel
+    pub(crate) fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            inner: Mutex::new(BacklogInner::default()),
+        }
+    }
+
+    /// Check whether events are kept at all
+    pub(crate) fn is_enabled(&self) -> bool {
+        self.capacity > 0
+    }
+
+    /// Record a published event, evicting the oldest one when the channel is full
+    pub(crate) fn record(&self, channel: &str, event: &ChannelEvent) {
+        if !self.is_enabled() {
+            return;
+        }
+
+        let mut inner = self.inner.lock().unwrap_or_else(|e| e.into_inner());
+        inner.last_seq += 1;
+        let seq = inner.last_seq;
+        let events = inner.channels.entry(channel.to_string()).or_default();
+        if events.len() == self.capacity {
+            events.pop_front();
+        }
+        events.push_back((seq, event.clone()));
+    }
+
+    /// The last `count` events of each channel, oldest first across channels
+    pub(crate) fn recent<'a>(
+        &self,
+        channels: impl IntoIterator<Item = &'a str>,
+        count: usize,
+    ) -> Vec<ChannelEvent> {
+        if !self.is_enabled() || count == 0 {
+            return Vec::new();
+        }
+
+        let inner = self.inner.lock().unwrap_or_else(|e| e.into_inner());
+        let mut events: Vec<_> = channels
+            .into_iter()
+            .filter_map(|channel| inner.channels.get(channel))
+            .flat_map(|events| events.iter().skip(events.len().saturating_sub(count)))
+            .collect();
+        events.sort_by_key(|(seq, _)| *seq);
+        events.into_iter().map(|(_, event)| event.clone()).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(channel: &str, n: u64) -> ChannelEvent {
+        ChannelEvent {
+            channel: channel.into(),
+            data: serde_json::json!({ "n": n }),
+            user_id: None,
+        }
+    }
+
+    fn numbers(events: Vec<ChannelEvent>) -> Vec<u64> {
+        events
+            .iter()
+            .map(|e| e.data["n"].as_u64().unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_backlog_is_bounded_and_keeps_publish_order() {
+        let backlog = ChannelBacklog::new(3);
+        for n in 1..=5 {
+            let channel = if n % 2 == 0 { "logs" } else { "tasks" };
+            backlog.record(channel, &event(channel, n));
+        }
+
+        assert_eq!(numbers(backlog.recent(["tasks"], 10)), vec![1, 3, 5]);
+        assert_eq!(numbers(backlog.recent(["tasks"], 2)), vec![3, 5]);
+        assert_eq!(
+            numbers(backlog.recent(["logs", "tasks"], 2)),
+            vec![2, 3, 4, 5]
+        );
+
+        backlog.record("tasks", &event("tasks", 7));
+        assert_eq!(numbers(backlog.recent(["tasks"], 10)), vec![3, 5, 7]);
+    }
+
+    #[test]
+    fn test_disabled_backlog_keeps_nothing() {
+        let backlog = ChannelBacklog::new(0);
+        backlog.record("tasks", &event("tasks", 1));
+        assert!(backlog.recent(["tasks"], 5).is_empty());
+    }
+}
@@ -0,0 +1,123 @@
+//! Encoding of WebSocket messages for binary frames
+//!
+//! Text frames always carry JSON. A [`WsCodec`] decides how binary frames
+//! are decoded, how outgoing messages are encoded, and whether they are sent
+//! as text or binary frames, so clients can use compact encodings such as
+//! MessagePack. The default [`JsonCodec`] keeps the JSON-over-text behavior
+//! and also accepts JSON in binary frames.
+
+use std::fmt;
+
+use super::{WsError, WsMessage, WsResult};
+
+/// Encoding of WebSocket messages
+pub trait WsCodec: Send + Sync + fmt::Debug {
+    /// Decode a message received in a binary frame
+    fn decode(&self, bytes: &[u8]) -> WsResult<WsMessage>;
+
+    /// Encode an outgoing message
+    fn encode(&self, message: &WsMessage) -> Vec<u8>;
+
+    /// Whether encoded messages are sent as binary frames
+    ///
+    /// Codecs that return `false` must encode to UTF-8, since their output is
+    /// sent as text frames.
+    fn is_binary(&self) -> bool {
+        true
+    }
+}
+
+/// JSON encoding sent as text frames
+#[derive(Debug, Clone, Copy, Default)]
+pub struct JsonCodec;
+
+impl WsCodec for JsonCodec {
+    fn decode(&self, bytes: &[u8]) -> WsResult<WsMessage> {
+        serde_json::from_slice(bytes).map_err(|e| WsError::InvalidMessage(e.to_string()))
+    }
+
+    fn encode(&self, message: &WsMessage) -> Vec<u8> {
+        // WsMessage only holds JSON-representable data, so this cannot fail
+        serde_json::to_vec(message).unwrap_or_default()
+    }
+
+    fn is_binary(&self) -> bool {
+        false
+    }
+}
+
+/// Decode a binary frame, rejecting frames larger than `max_message_size`
+pub(crate) fn decode_frame(
+    codec: &dyn WsCodec,
+    bytes: &[u8],
+    max_message_size: usize,
+) -> WsResult<WsMessage> {
+    if bytes.len() > max_message_size {
+        return Err(WsError::MessageTooLarge {
+            size: bytes.len(),
+            max: max_message_size,
+        });
+    }
+    codec.decode(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Codec that prefixes the JSON encoding with a marker byte
+    #[derive(Debug)]
+    struct TaggedCodec;
+
+    impl WsCodec for TaggedCodec {
+        fn decode(&self, bytes: &[u8]) -> WsResult<WsMessage> {
+            match bytes.split_first() {
+                Some((0xC1, rest)) => JsonCodec.decode(rest),
+                _ => Err(WsError::InvalidMessage("missing marker".to_string())),
+            }
+        }
+
+        fn encode(&self, message: &WsMessage) -> Vec<u8> {
+            let mut bytes = vec![0xC1];
+            bytes.extend(JsonCodec.encode(message));
+            bytes
+        }
+    }
+
+    #[test]
+    fn test_json_codec_round_trips_as_text() {
+        let codec = JsonCodec;
+        assert!(!codec.is_binary());
+
+        let bytes = codec.encode(&WsMessage::success("ok"));
+        assert!(std::str::from_utf8(&bytes).is_ok());
+        assert!(matches!(
+            codec.decode(&bytes).unwrap(),
+            WsMessage::Success { .. }
+        ));
+        assert!(matches!(
+            codec.decode(b"not json"),
+            Err(WsError::InvalidMessage(_))
+        ));
+    }
+
+    #[test]
+    fn test_decode_frame_uses_codec_and_enforces_size() {
+        let codec = TaggedCodec;
+        assert!(codec.is_binary());
+
+        let bytes = codec.encode(&WsMessage::pong());
+        assert!(matches!(
+            decode_frame(&codec, &bytes, 1024).unwrap(),
+            WsMessage::Pong { .. }
+        ));
+        assert!(matches!(
+            decode_frame(&codec, &bytes[1..], 1024),
+            Err(WsError::InvalidMessage(_))
+        ));
+        assert!(matches!(
+            decode_frame(&codec, &bytes, bytes.len() - 1),
+            Err(WsError::MessageTooLarge { size, max }) if size == bytes.len() && max == bytes.len() - 1
+        ));
+    }
+}
@@ -0,0 +1,360 @@
+//! Server-side event filtering for channel subscriptions
+//!
+//! A subscription may carry a filter so that only matching events are
+//! delivered to the connection. Filters are a map of field paths to matchers:
+//!
+//! ```json
+//! {
+//!   "status": "failed",
+//!   "$.agent.id": { "$in": ["agent-1", "agent-2"] },
+//!   "metrics.latency_ms": { "$gt": 500 }
+//! }
+//! ```
+//!
+//! Paths are a JSON-path subset (`$`, `.field`, `[index]`) evaluated against
+//! the event data. A plain value matches by equality; an object with a single
+//! `$`-prefixed key selects an operator (`$eq`, `$ne`, `$in`, `$exists`,
+//! `$gt`, `$gte`, `$lt`, `$lte`). All conditions must match.
+//!
+//! Filters are evaluated for every broadcast while the subscription lock is
+//! held, so their size is bounded at parse time to keep evaluation cheap.
+
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// Maximum number of conditions in a single filter
+pub const MAX_FILTER_CONDITIONS: usize = 16;
+
+/// Maximum number of segments in a filter path
+pub const MAX_FILTER_PATH_DEPTH: usize = 8;
+
+/// Maximum length of a filter path in bytes
+pub const MAX_FILTER_PATH_LENGTH: usize = 256;
+
+/// Maximum number of values in an `$in` set
+pub const MAX_FILTER_SET_SIZE: usize = 64;
+
+/// Errors that can occur when parsing an event filter
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum FilterError {
+    #[error("Too many filter conditions: {count} (max: {max})")]
+    TooManyConditions { count: usize, max: usize },
+
+    #[error("Filter path '{path}' is too deep (max {max} segments)")]
+    PathTooDeep { path: String, max: usize },
+
+    #[error("Invalid filter path '{path}': {reason}")]
+    InvalidPath { path: String, reason: String },
+
+    #[error("Unknown filter operator: {0}")]
+    UnknownOperator(String),
+
+    #[error("Invalid operand for {operator}: {reason}")]
+    InvalidOperand { operator: String, reason: String },
+}
+
+/// Compiled event filter for a channel subscription
+#[derive(Debug, Clone, PartialEq)]
+pub struct EventFilter {
+    conditions: Vec<Condition>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Condition {
+    path: Vec<PathSegment>,
+    matcher: Matcher,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum PathSegment {
+    Key(String),
+    Index(usize),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Matcher {
+    Eq(Value),
+    Ne(Value),
+    In(Vec<Value>),
+    Exists(bool),
+    Gt(f64),
+    Gte(f64),
+    Lt(f64),
+    Lte(f64),
+}
+
+impl EventFilter {
+    /// Compile a filter from a map of field paths to matchers
+    pub fn from_fields(fields: &HashMap<String, Value>) -> Result<Self, FilterError> {
+        if fields.len() > MAX_FILTER_CONDITIONS {
+            return Err(FilterError::TooManyConditions {
+                count: fields.len(),
+                max: MAX_FILTER_CONDITIONS,
+            });
+        }
+
+        let conditions = fields
+            .iter()
+            .map(|(path, spec)| {
+                Ok(Condition {
+                    path: parse_path(path)?,
+                    matcher: parse_matcher(spec)?,
+                })
+            })
+            .collect::<Result<Vec<_>, FilterError>>()?;
+
+        Ok(Self { conditions })
+    }
+
+    /// Number of conditions in this filter
+    pub fn len(&self) -> usize {
+        self.conditions.len()
+    }
+
+    /// Check if the filter has no conditions (matches everything)
+    pub fn is_empty(&self) -> bool {
+        self.conditions.is_empty()
+    }
+
+    /// Check whether event data satisfies every condition
+    pub fn matches(&self, data: &Value) -> bool {
+        self.conditions.iter().all(|condition| {
+            let field = resolve(data, &condition.path);
+            condition.matcher.matches(field)
+        })
+    }
+}
+
+impl Matcher {
+    fn matches(&self, field: Option<&Value>) -> bool {
+        match self {
+            Matcher::Exists(expected) => field.is_some() == *expected,
+            Matcher::Ne(value) => field != Some(value),
+            Matcher::Eq(value) => field == Some(value),
+            Matcher::In(values) => field.is_some_and(|f| values.contains(f)),
+            Matcher::Gt(bound) => number(field).is_some_and(|n| n > *bound),
+            Matcher::Gte(bound) => number(field).is_some_and(|n| n >= *bound),
+            Matcher::Lt(bound) => number(field).is_some_and(|n| n < *bound),
+            Matcher::Lte(bound) => number(field).is_some_and(|n| n <= *bound),
+        }
+    }
+}
+
+fn number(field: Option<&Value>) -> Option<f64> {
+    field.and_then(Value::as_f64)
+}
+
+fn resolve<'a>(data: &'a Value, path: &[PathSegment]) -> Option<&'a Value> {
+    path.iter()
+        .try_fold(data, |current, segment| match segment {
+            PathSegment::Key(key) => current.get(key.as_str()),
+            PathSegment::Index(index) => current.get(*index),
+        })
+}
+
+fn parse_path(path: &str) -> Result<Vec<PathSegment>, FilterError> {
+    let invalid = |reason: &str| FilterError::InvalidPath {
+        path: path.to_string(),
+        reason: reason.to_string(),
+    };
+
+    if path.len() > MAX_FILTER_PATH_LENGTH {
+        return Err(invalid("path is too long"));
+    }
+
+    // "$.a.b" and "a.b" are equivalent; "$" alone refers to the whole event
+    let rest = match path.strip_prefix('$') {
+        Some("") => return Ok(Vec::new()),
+        Some(rest) if rest.starts_with('[') => rest,
+        Some(rest) => rest
+            .strip_prefix('.')
+            .ok_or_else(|| invalid("expected '.' or '[' after '$'"))?,
+        None => path,
+    };
+    if rest.is_empty() {
+        return Err(invalid("path is empty"));
+    }
+
+    let mut segments = Vec::new();
+    let mut chars = rest.chars().peekable();
+    let mut expect_key = !rest.starts_with('[');
+
+    while chars.peek().is_some() {
+        if expect_key {
+            let key: String =
+                std::iter::from_fn(|| chars.next_if(|c| *c != '.' && *c != '[')).collect();
+            if key.is_empty() {
+                return Err(invalid("empty field name"));
+            }
+            segments.push(PathSegment::Key(key));
+        } else {
+            // Consume "[<digits>]"
+            chars.next();
+            let digits: String =
+                std::iter::from_fn(|| chars.next_if(char::is_ascii_digit)).collect();
+            if chars.next() != Some(']') {
+                return Err(invalid("expected ']' after array index"));
+            }
+            let index = digits
+                .parse()
+                .map_err(|_| invalid("array index must be a non-negative integer"))?;
+            segments.push(PathSegment::Index(index));
+        }
+
+        if segments.len() > MAX_FILTER_PATH_DEPTH {
+            return Err(FilterError::PathTooDeep {
+                path: path.to_string(),
+                max: MAX_FILTER_PATH_DEPTH,
+            });
+        }
+
+        match chars.peek() {
+            Some('.') => {
+                chars.next();
+                if chars.peek().is_none() {
+                    return Err(invalid("trailing '.'"));
+                }
+                expect_key = true;
+            }
+            Some('[') => expect_key = false,
+            Some(_) => return Err(invalid("unexpected character")),
+            None => {}
+        }
+    }
+
+    Ok(segments)
+}
+
+fn parse_matcher(spec: &Value) -> Result<Matcher, FilterError> {
+    let operator = match spec {
+        Value::Object(map) if map.len() == 1 => {
+            map.iter().next().filter(|(key, _)| key.starts_with('$'))
+        }
+        _ => None,
+    };
+
+    let Some((operator, operand)) = operator else {
+        return Ok(Matcher::Eq(spec.clone()));
+    };
+
+    let invalid = |reason: &str| FilterError::InvalidOperand {
+        operator: operator.clone(),
+        reason: reason.to_string(),
+    };
+    let bound = || operand.as_f64().ok_or_else(|| invalid("expected a number"));
+
+    match operator.as_str() {
+        "$eq" => Ok(Matcher::Eq(operand.clone())),
+        "$ne" => Ok(Matcher::Ne(operand.clone())),
+        "$in" => {
+            let values = operand
+                .as_array()
+                .ok_or_else(|| invalid("expected an array"))?;
+            if values.len() > MAX_FILTER_SET_SIZE {
+                return Err(invalid(&format!(
+                    "set has {} values (max: {})",
+                    values.len(),
+                    MAX_FILTER_SET_SIZE
+                )));
+            }
+            Ok(Matcher::In(values.clone()))
+        }
+        "$exists" => operand
+            .as_bool()
+            .map(Matcher::Exists)
+            .ok_or_else(|| invalid("expected a boolean")),
+        "$gt" => bound().map(Matcher::Gt),
+        "$gte" => bound().map(Matcher::Gte),
+        "$lt" => bound().map(Matcher::Lt),
+        "$lte" => bound().map(Matcher::Lte),
+        other => Err(FilterError::UnknownOperator(other.to_string())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn filter(spec: Value) -> Result<EventFilter, FilterError> {
+        let fields: HashMap<String, Value> = serde_json::from_value(spec).unwrap();
+        EventFilter::from_fields(&fields)
+    }
+
+    #[test]
+    fn test_equality_and_nested_paths() {
+        let f = filter(json!({"status": "failed", "$.agent.tags[1]": "prod"})).unwrap();
+
+        assert!(f.matches(&json!({"status": "failed", "agent": {"tags": ["a", "prod"]}})));
+        assert!(!f.matches(&json!({"status": "ok", "agent": {"tags": ["a", "prod"]}})));
+        assert!(!f.matches(&json!({"status": "failed"})));
+    }
+
+    #[test]
+    fn test_operators() {
+        let f = filter(json!({
+            "level": {"$in": ["warn", "error"]},
+            "latency": {"$gte": 100},
+            "debug": {"$exists": false},
+            "source": {"$ne": "internal"}
+        }))
+        .unwrap();
+
+        assert!(f.matches(&json!({"level": "warn", "latency": 100, "source": "api"})));
+        assert!(!f.matches(&json!({"level": "info", "latency": 100})));
+        assert!(!f.matches(&json!({"level": "warn", "latency": 99})));
+        assert!(!f.matches(&json!({"level": "warn", "latency": 150, "debug": true})));
+        assert!(!f.matches(&json!({"level": "warn", "latency": 150, "source": "internal"})));
+    }
+
+    #[test]
+    fn test_literal_objects_compare_by_equality() {
+        let f = filter(json!({"meta": {"a": 1, "b": 2}})).unwrap();
+        assert!(f.matches(&json!({"meta": {"b": 2, "a": 1}})));
+        assert!(!f.matches(&json!({"meta": {"a": 1}})));
+    }
+
+    #[test]
+    fn test_complexity_limits() {
+        let too_many: HashMap<String, Value> = (0..=MAX_FILTER_CONDITIONS)
+            .map(|i| (format!("f{i}"), json!(i)))
+            .collect();
+        assert!(matches!(
+            EventFilter::from_fields(&too_many),
+            Err(FilterError::TooManyConditions { .. })
+        ));
+
+        let deep = ["a"; MAX_FILTER_PATH_DEPTH + 1].join(".");
+        assert!(matches!(
+            filter(json!({ deep: 1 })),
+            Err(FilterError::PathTooDeep { .. })
+        ));
+
+        let big_set: Vec<usize> = (0..=MAX_FILTER_SET_SIZE).collect();
+        assert!(matches!(
+            filter(json!({"id": {"$in": big_set}})),
+            Err(FilterError::InvalidOperand { .. })
+        ));
+    }
+
+    #[test]
+    fn test_rejects_malformed_filters() {
+        for path in ["", "a..b", "a.", "a[x]", "a[1", "$x"] {
+            assert!(
+                matches!(
+                    filter(json!({ path: 1 })),
+                    Err(FilterError::InvalidPath { .. })
+                ),
+                "path {path:?} should be rejected"
+            );
+        }
+        assert!(matches!(
+            filter(json!({"a": {"$regex": ".*"}})),
+            Err(FilterError::UnknownOperator(_))
+        ));
+        assert!(matches!(
+            filter(json!({"a": {"$gt": "ten"}})),
+            Err(FilterError::InvalidOperand { .. })
+        ));
+    }
+}
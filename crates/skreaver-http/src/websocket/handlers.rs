@@ -1,7 +1,7 @@
 //! WebSocket route handlers and middleware
 
 use super::{
-    ConnectionInfo, WebSocketManager, WsError, WsMessage,
+    ConnectionInfo, EventFilter, WebSocketManager, WsError, WsMessage,
     protocol::{Channel, MessageEnvelope, MessagePayload, ResponseData, events},
 };
 use axum::{
@@ -11,6 +11,7 @@ use axum::{
     response::{IntoResponse, Response},
 };
 use serde::{Deserialize, Serialize};
+use skreaver_observability::{StreamTransport, get_metrics_registry};
 use std::{net::SocketAddr, sync::Arc};
 use tracing::{debug, error, info, warn};
 
@@ -154,6 +155,9 @@ async fn handle_websocket_connection(
         }
     };
 
+    let stream_guard =
+        get_metrics_registry().map(|registry| registry.track_stream(StreamTransport::WebSocket));
+
     let (mut ws_sender, mut ws_receiver) = socket.split();
 
     // Send welcome message
@@ -175,13 +179,17 @@ async fn handle_websocket_connection(
         }),
     );
 
-    if let Ok(welcome_json) = serde_json::to_string(&welcome)
-        && ws_sender
+    if let Ok(welcome_json) = serde_json::to_string(&welcome) {
+        let frame_len = welcome_json.len();
+        if ws_sender
             .send(axum::extract::ws::Message::Text(welcome_json.into()))
             .await
             .is_err()
-    {
-        warn!("Failed to send welcome message to {}", conn_id);
+        {
+            warn!("Failed to send welcome message to {}", conn_id);
+        } else if let Some(guard) = &stream_guard {
+            guard.record_frame(frame_len);
+        }
     }
 
     // SECURITY: Authentication via WebSocket message only (not query params)
@@ -229,8 +237,20 @@ async fn handle_websocket_connection(
                         warn!("Invalid message format from {}", conn_id);
                     }
                 }
-                Ok(axum::extract::ws::Message::Binary(_)) => {
-                    warn!("Binary messages not supported from {}", conn_id);
+                Ok(axum::extract::ws::Message::Binary(data)) => {
+                    let config = &manager_clone.config;
+                    match super::codec::decode_frame(
+                        config.codec.as_ref(),
+                        &data,
+                        config.max_message_size,
+                    ) {
+                        Ok(ws_msg) => {
+                            if let Err(e) = manager_clone.handle_message(conn_id, ws_msg).await {
+                                error!("Error handling binary message from {}: {}", conn_id, e);
+                            }
+                        }
+                        Err(e) => warn!("Invalid binary message from {}: {}", conn_id, e),
+                    }
                 }
                 Ok(axum::extract::ws::Message::Close(_)) => {
                     info!("Connection {} closed by client", conn_id);
@@ -294,13 +314,21 @@ async fn handle_protocol_message(
             manager.handle_message(conn_id, ws_msg).await
         }
         MessagePayload::Subscribe(data) => {
-            let channels = data
+            let subscriptions = data
                 .channels
                 .into_iter()
-                .map(|sub| sub.channel.to_string())
-                .collect();
-            let ws_msg = WsMessage::Subscribe { channels };
-            manager.handle_message(conn_id, ws_msg).await
+                .map(|sub| {
+                    let filter = sub
+                        .filters
+                        .map(|fields| EventFilter::from_fields(&fields))
+                        .transpose()?;
+                    Ok((sub.channel.to_string(), filter))
+                })
+                .collect::<Result<Vec<_>, WsError>>()?;
+            manager.update_activity(conn_id).await;
+            manager
+                .handle_subscribe_filtered(conn_id, subscriptions)
+                .await
         }
         MessagePayload::Request(data) => {
             // Handle RPC-style requests
@@ -336,7 +364,11 @@ async fn handle_rpc_request(
         })),
         "subscribe" => {
             if let Ok(channels) = serde_json::from_value::<Vec<String>>(request.params) {
-                let ws_msg = WsMessage::Subscribe { channels };
+                let ws_msg = WsMessage::Subscribe {
+                    channels,
+                    filter: None,
+                    replay: None,
+                };
                 manager.handle_message(conn_id, ws_msg).await?;
                 MessageEnvelope::success_response(serde_json::json!({
                     "message": "Subscribed successfully"
@@ -410,6 +442,7 @@ async fn send_protocol_message(
         },
         MessagePayload::Pong(_) => WsMessage::Pong {
             timestamp: envelope.timestamp,
+            health: None,
         },
         _ => return Ok(()), // Skip other message types for now
     };
@@ -0,0 +1,109 @@
+//! Heartbeat pings and pong timeout detection
+//!
+//! The server pings every connection each `ping_interval`. A client that does
+//! not answer a ping within `pong_timeout` is treated as dead, so its slot is
+//! freed long before `connection_timeout` would expire it.
+
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use tokio::sync::mpsc;
+
+use super::WsMessage;
+
+/// Time of the last pong received on a connection
+#[derive(Debug)]
+pub(crate) struct PongTracker {
+    last_pong: Mutex<Instant>,
+}
+
+impl PongTracker {
+    pub(crate) fn new() -> Self {
+        Self {
+            last_pong: Mutex::new(Instant::now()),
+        }
+    }
+
+    /// Record a pong, whether a protocol pong frame or a `WsMessage::Pong`
+    pub(crate) fn record_pong(&self) {
+        *self.last_pong.lock().unwrap_or_else(|e| e.into_inner()) = Instant::now();
+    }
+
+    /// Check whether a pong arrived at or after `ping_sent`
+    pub(crate) fn pong_since(&self, ping_sent: Instant) -> bool {
+        *self.last_pong.lock().unwrap_or_else(|e| e.into_inner()) >= ping_sent
+    }
+}
+
+/// How the heartbeat loop ended
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum HeartbeatEnd {
+    /// The outgoing channel closed, so the connection is already going away
+    ChannelClosed,
+    /// No pong arrived within the timeout after a ping
+    PongTimeout,
+}
+
+/// Send a heartbeat every `ping_interval` and wait up to `pong_timeout` for
+/// the answer to each one
+pub(crate) async fn run_heartbeat(
+    tx: mpsc::Sender<WsMessage>,
+    heartbeat: impl Fn() -> WsMessage,
+    ping_interval: Duration,
+    pong_timeout: Duration,
+    tracker: &PongTracker,
+) -> HeartbeatEnd {
+    let mut interval = tokio::time::interval(ping_interval);
+    interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+    loop {
+        interval.tick().await;
+        let ping_sent = Instant::now();
+        if tx.send(heartbeat()).await.is_err() {
+            return HeartbeatEnd::ChannelClosed;
+        }
+
+        tokio::time::sleep(pong_timeout).await;
+        if !tracker.pong_since(ping_sent) {
+            return HeartbeatEnd::PongTimeout;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    const INTERVAL: Duration = Duration::from_millis(40);
+    const TIMEOUT: Duration = Duration::from_millis(20);
+
+    #[tokio::test]
+    async fn test_silent_client_times_out() {
+        let (tx, mut rx) = mpsc::channel(4);
+        let tracker = PongTracker::new();
+
+        let end = run_heartbeat(tx, WsMessage::ping, INTERVAL, TIMEOUT, &tracker).await;
+
+        assert_eq!(end, HeartbeatEnd::PongTimeout);
+        assert!(matches!(rx.recv().await, Some(WsMessage::Ping { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_responsive_client_stays_connected() {
+        let (tx, mut rx) = mpsc::channel(4);
+        let tracker = Arc::new(PongTracker::new());
+
+        let client_tracker = Arc::clone(&tracker);
+        let client = tokio::spawn(async move {
+            for _ in 0..3 {
+                assert!(matches!(rx.recv().await, Some(WsMessage::Ping { .. })));
+                client_tracker.record_pong();
+            }
+            // Client goes away without answering further pings
+        });
+
+        let end = run_heartbeat(tx, WsMessage::ping, INTERVAL, TIMEOUT, &tracker).await;
+        client.await.unwrap();
+
+        assert_eq!(end, HeartbeatEnd::ChannelClosed);
+    }
+}
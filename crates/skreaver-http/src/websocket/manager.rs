@@ -1,7 +1,10 @@
 //! WebSocket connection manager
 
+use super::backlog::ChannelBacklog;
+use super::filter::EventFilter;
 use super::lock_ordering::ManagerLocks;
-use super::{WebSocketConfig, WsError, WsMessage, WsResult};
+use super::{ClientHealthReport, WebSocketConfig, WsError, WsMessage, WsResult};
+use std::collections::HashMap;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, AtomicU8, Ordering};
 use tokio::sync::{Mutex, Notify, broadcast, mpsc};
@@ -149,6 +152,8 @@ pub struct WebSocketManager {
     auth_handler: Option<Arc<dyn AuthHandler + Send + Sync>>,
     /// Background task handles for lifecycle management
     background_tasks: Arc<Mutex<BackgroundTasks>>,
+    /// Recent events per channel for replay on subscribe
+    backlog: Arc<ChannelBacklog>,
 }
 
 /// Authentication state for a connection
@@ -191,8 +196,12 @@ pub(super) struct ConnectionState {
     sender: mpsc::Sender<WsMessage>,
     /// Subscribed channels
     channels: Vec<String>,
+    /// Server-side event filters keyed by channel
+    filters: HashMap<String, EventFilter>,
     /// Authentication state
     auth_state: AuthState,
+    /// Most recent health report piggybacked on a pong
+    health: Option<ClientHealthReport>,
 }
 
 impl ConnectionState {
@@ -205,7 +214,9 @@ impl ConnectionState {
             info,
             sender,
             channels: Vec::new(),
+            filters: HashMap::new(),
             auth_state: AuthState::Unauthenticated,
+            health: None,
         }
     }
 
@@ -234,6 +245,13 @@ impl ConnectionState {
         &mut self.channels
     }
 
+    /// Check whether an event on `channel` passes this connection's filter
+    fn accepts(&self, channel: &str, data: &serde_json::Value) -> bool {
+        self.filters
+            .get(channel)
+            .is_none_or(|filter| filter.matches(data))
+    }
+
     /// Check if connection is authenticated
     fn is_authenticated(&self) -> bool {
         matches!(self.auth_state, AuthState::Authenticated { .. })
@@ -304,6 +322,7 @@ impl WebSocketManager {
     /// Create a new WebSocket manager
     pub fn new(config: WebSocketConfig) -> Self {
         let (event_sender, _) = broadcast::channel(config.broadcast_buffer_size);
+        let backlog = Arc::new(ChannelBacklog::new(config.channel_backlog_size));
 
         Self {
             config,
@@ -311,6 +330,7 @@ impl WebSocketManager {
             event_sender,
             auth_handler: None,
             background_tasks: Arc::new(Mutex::new(BackgroundTasks::new())),
+            backlog,
         }
     }
 
@@ -443,6 +463,31 @@ impl WebSocketManager {
         }
     }
 
+    /// Build the heartbeat ping, carrying the configured application payload
+    pub fn heartbeat(&self) -> WsMessage {
+        WsMessage::ping_with_payload(self.config.heartbeat_payload.clone())
+    }
+
+    /// Record the health report a client sent with its pong
+    async fn record_health(&self, id: Uuid, report: ClientHealthReport) {
+        let mut guard = self.locks.level1_write().await;
+        if let Some(state) = guard.connections.get_mut(&id) {
+            if report.degraded {
+                debug!("Connection {} reported degraded health: {:?}", id, report);
+            }
+            state.health = Some(report);
+        }
+    }
+
+    /// Get the latest health report a client piggybacked on a pong
+    pub async fn client_health(&self, id: Uuid) -> Option<ClientHealthReport> {
+        let guard = self.locks.level1_read().await;
+        guard
+            .connections
+            .get(&id)
+            .and_then(|state| state.health.clone())
+    }
+
     /// Store handshake information in connection metadata
     pub async fn store_handshake_info(
         &self,
@@ -487,14 +532,29 @@ impl WebSocketManager {
                     );
                 }
             }
-            WsMessage::Pong { .. } => {
+            WsMessage::Pong { health, .. } => {
                 // Activity already updated
+                if let Some(report) = health {
+                    self.record_health(conn_id, report).await;
+                }
             }
             WsMessage::Auth { token } => {
                 self.handle_auth(conn_id, &token).await?;
             }
-            WsMessage::Subscribe { channels } => {
-                self.handle_subscribe(conn_id, channels).await?;
+            WsMessage::Subscribe {
+                channels,
+                filter,
+                replay,
+            } => {
+                let filter = filter
+                    .map(|fields| EventFilter::from_fields(&fields))
+                    .transpose()?;
+                let subscriptions = channels
+                    .into_iter()
+                    .map(|channel| (channel, filter.clone()))
+                    .collect();
+                self.handle_subscribe_with_replay(conn_id, subscriptions, replay)
+                    .await?;
             }
             WsMessage::Unsubscribe { channels } => {
                 self.handle_unsubscribe(conn_id, channels).await?;
@@ -565,6 +625,43 @@ impl WebSocketManager {
     /// Handle channel subscription
     #[doc(hidden)] // Public for testing only
     pub async fn handle_subscribe(&self, conn_id: Uuid, channels: Vec<String>) -> WsResult<()> {
+        let subscriptions = channels
+            .into_iter()
+            .map(|channel| (channel, None))
+            .collect();
+        self.handle_subscribe_filtered(conn_id, subscriptions).await
+    }
+
+    /// Handle channel subscription with optional per-channel event filters
+    ///
+    /// Subscribing to an already-subscribed channel replaces its filter;
+    /// passing `None` removes any existing filter.
+    pub async fn handle_subscribe_filtered(
+        &self,
+        conn_id: Uuid,
+        subscriptions: Vec<(String, Option<EventFilter>)>,
+    ) -> WsResult<()> {
+        self.handle_subscribe_with_replay(conn_id, subscriptions, None)
+            .await
+    }
+
+    /// Handle channel subscription, first sending up to `replay` recent events
+    /// of each newly subscribed channel
+    ///
+    /// Replayed events pass the same user and filter checks as live events and
+    /// are sent before any live event, with no gap or overlap between the two.
+    /// Channels the connection was already subscribed to are not replayed.
+    pub async fn handle_subscribe_with_replay(
+        &self,
+        conn_id: Uuid,
+        subscriptions: Vec<(String, Option<EventFilter>)>,
+        replay: Option<usize>,
+    ) -> WsResult<()> {
+        let channels: Vec<String> = subscriptions
+            .iter()
+            .map(|(channel, _)| channel.clone())
+            .collect();
+
         // Phase 1: Check permissions outside critical section (read-only snapshot)
         // This prevents race conditions by doing async operations before acquiring write lock
         let user_id_opt = {
@@ -622,6 +719,8 @@ impl WebSocketManager {
             });
         }
 
+        let replay_channels: Vec<String> = new_channels.iter().map(|ch| ch.to_string()).collect();
+
         // Add subscriptions atomically
         for channel in new_channels {
             // Check channel subscriber limit
@@ -650,20 +749,70 @@ impl WebSocketManager {
             debug!("Connection {} subscribed to channel {}", conn_id, channel);
         }
 
-        drop(guards);
+        for (channel, filter) in subscriptions {
+            match filter {
+                Some(filter) => state.filters.insert(channel, filter),
+                None => state.filters.remove(&channel),
+            };
+        }
 
-        let result = self
-            .send_to_connection(conn_id, WsMessage::success("Subscription successful"))
-            .await?;
+        // Acknowledge and replay while still holding the write lock, so no
+        // live event can reach this connection before its backlog
+        let sender = state.sender().clone();
+        let replayed: Vec<_> = match replay {
+            Some(count) => self
+                .backlog
+                .recent(replay_channels.iter().map(String::as_str), count)
+                .into_iter()
+                .filter(|event| {
+                    event
+                        .user_id
+                        .as_deref()
+                        .is_none_or(|target| state.user_id() == Some(target))
+                        && state.accepts(&event.channel.to_string(), &event.data)
+                })
+                .collect(),
+            None => Vec::new(),
+        };
 
-        if result.is_failure() {
-            tracing::warn!(
+        // Nothing else can queue messages for this connection while the write
+        // lock is held, so reserving every slot up front cannot fail halfway.
+        // When the send buffer cannot hold the whole replay, the oldest events
+        // are skipped so the replay still joins up with the live events.
+        let skipped = replayed
+            .len()
+            .saturating_sub(sender.capacity().saturating_sub(1));
+        let permits = match sender.try_reserve_many(1 + replayed.len() - skipped) {
+            Ok(permits) => permits,
+            Err(e) => {
+                warn!(
+                    connection_id = %conn_id,
+                    error = %e,
+                    "Failed to send subscription success message"
+                );
+                return Ok(());
+            }
+        };
+        if skipped > 0 {
+            warn!(
                 connection_id = %conn_id,
-                send_result = %result,
-                "Failed to send subscription success message"
+                skipped,
+                "Send buffer too small for the requested replay, skipping oldest events"
             );
         }
 
+        let messages = std::iter::once(WsMessage::success("Subscription successful")).chain(
+            replayed
+                .into_iter()
+                .skip(skipped)
+                .map(|event| WsMessage::event(&event.channel, event.data)),
+        );
+        for (permit, message) in permits.zip(messages) {
+            permit.send(message);
+        }
+
+        drop(guards);
+
         Ok(())
     }
 
@@ -677,6 +826,7 @@ impl WebSocketManager {
             for channel in channels {
                 if let Some(index) = state.channels().iter().position(|c| c == &channel) {
                     state.channels_mut().remove(index);
+                    state.filters.remove(&channel);
 
                     if let Some(subscribers) = guards.subscriptions.get_mut(&channel) {
                         subscribers.retain(|&id| id != conn_id);
@@ -833,11 +983,15 @@ impl WebSocketManager {
 
         let mut authenticated_count = 0;
         let mut expired_count = 0;
+        let mut degraded_count = 0;
 
         for state in guard.connections.values() {
             if state.is_authenticated() {
                 authenticated_count += 1;
             }
+            if state.health.as_ref().is_some_and(|health| health.degraded) {
+                degraded_count += 1;
+            }
             if state.info().is_expired(self.config.connection_timeout) {
                 expired_count += 1;
             }
@@ -847,6 +1001,7 @@ impl WebSocketManager {
             total_connections: guard.connections.len(),
             authenticated_connections: authenticated_count,
             expired_connections: expired_count,
+            degraded_connections: degraded_count,
             total_channels: guard.subscriptions.len(),
         }
     }
@@ -876,6 +1031,28 @@ impl WebSocketManager {
         count
     }
 
+    /// Close every connection, returning how many were closed
+    ///
+    /// Dropping a connection's state closes its outbound channel, which ends
+    /// the socket's send loop. Used during graceful shutdown.
+    pub async fn close_all_connections(&self) -> usize {
+        let ids: Vec<Uuid> = {
+            let guard = self.locks.level1_read().await;
+            guard.connections.keys().copied().collect()
+        };
+
+        let count = ids.len();
+        for id in ids {
+            self.remove_connection(id).await;
+        }
+
+        if count > 0 {
+            info!("Closed {} WebSocket connections for shutdown", count);
+        }
+
+        count
+    }
+
     /// Detect and clean up orphaned state
     ///
     /// Checks for:
@@ -1097,7 +1274,11 @@ impl WebSocketManager {
         let subscribers_with_senders = {
             let guard = self.locks.level3_read().await;
 
-            if let Some(subscribers) = guard.subscriptions.get(&event.channel.to_string()) {
+            let channel = event.channel.to_string();
+            // Recorded under the lock so subscribe sees each event either in
+            // the backlog or as a live subscriber, never both
+            self.backlog.record(&channel, &event);
+            if let Some(subscribers) = guard.subscriptions.get(&channel) {
                 subscribers
                     .iter()
                     .filter_map(|&conn_id| {
@@ -1108,6 +1289,10 @@ impl WebSocketManager {
                             {
                                 return None;
                             }
+                            // Skip connections whose subscription filter rejects the event
+                            if !state.accepts(&channel, &event.data) {
+                                return None;
+                            }
                             Some((conn_id, state.sender().clone()))
                         })
                     })
@@ -1161,6 +1346,7 @@ impl Clone for WebSocketManager {
             event_sender: self.event_sender.clone(),
             auth_handler: self.auth_handler.clone(),
             background_tasks: Arc::clone(&self.background_tasks),
+            backlog: Arc::clone(&self.backlog),
         }
     }
 }
@@ -1174,6 +1360,8 @@ pub struct ConnectionStats {
     pub authenticated_connections: usize,
     /// Number of expired connections
     pub expired_connections: usize,
+    /// Number of connections whose last health report was degraded
+    pub degraded_connections: usize,
     /// Total number of channels
     pub total_channels: usize,
 }
@@ -1253,6 +1441,21 @@ mod tests {
         assert_eq!(stats.total_channels, 0);
     }
 
+    #[tokio::test]
+    async fn test_close_all_connections() {
+        let manager = WebSocketManager::new(WebSocketConfig::default());
+
+        for port in [8080, 8081] {
+            let addr: SocketAddr = format!("127.0.0.1:{}", port).parse().unwrap();
+            let info = ConnectionInfo::new(addr);
+            manager.add_connection(info.id(), info).await.unwrap();
+        }
+
+        assert_eq!(manager.close_all_connections().await, 2);
+        assert_eq!(manager.connection_count().await, 0);
+        assert_eq!(manager.close_all_connections().await, 0);
+    }
+
     #[tokio::test]
     async fn test_add_remove_connection() {
         let config = WebSocketConfig::default();
@@ -1466,6 +1669,222 @@ mod tests {
         );
     }
 
+    /// Register a connection whose outgoing messages can be observed
+    async fn add_observed_connection(
+        manager: &WebSocketManager,
+    ) -> (Uuid, mpsc::Receiver<WsMessage>) {
+        let info = ConnectionInfo::new("127.0.0.1:8080".parse().unwrap());
+        let conn_id = info.id();
+        let (sender, receiver) = mpsc::channel(16);
+        let mut guard = manager.locks.level1_write().await;
+        guard
+            .connections
+            .insert(conn_id, ConnectionState::new_unauthenticated(info, sender));
+        (conn_id, receiver)
+    }
+
+    fn received_events(receiver: &mut mpsc::Receiver<WsMessage>) -> Vec<serde_json::Value> {
+        std::iter::from_fn(|| receiver.try_recv().ok())
+            .filter_map(|msg| match msg {
+                WsMessage::Event { data, .. } => Some(data),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_filtered_subscription_receives_only_matching_events() {
+        let manager = WebSocketManager::new(WebSocketConfig::default());
+        let (filtered_id, mut filtered_rx) = add_observed_connection(&manager).await;
+        let (plain_id, mut plain_rx) = add_observed_connection(&manager).await;
+
+        let subscribe = serde_json::json!({
+            "type": "subscribe",
+            "channels": ["tasks"],
+            "filter": {"status": "failed", "$.agent.id": {"$in": ["a1", "a2"]}}
+        });
+        manager
+            .handle_message(filtered_id, serde_json::from_value(subscribe).unwrap())
+            .await
+            .unwrap();
+        manager
+            .handle_subscribe(plain_id, vec!["tasks".to_string()])
+            .await
+            .unwrap();
+
+        let events = [
+            serde_json::json!({"status": "failed", "agent": {"id": "a1"}}),
+            serde_json::json!({"status": "done", "agent": {"id": "a1"}}),
+            serde_json::json!({"status": "failed", "agent": {"id": "a3"}}),
+            serde_json::json!({"status": "failed", "agent": {"id": "a2"}}),
+        ];
+        for data in &events {
+            manager
+                .handle_channel_event(ChannelEvent {
+                    channel: "tasks".into(),
+                    data: data.clone(),
+                    user_id: None,
+                })
+                .await;
+        }
+
+        assert_eq!(
+            received_events(&mut filtered_rx),
+            vec![events[0].clone(), events[3].clone()]
+        );
+        assert_eq!(received_events(&mut plain_rx), events.to_vec());
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_replays_backlog_before_live_events() {
+        let config = WebSocketConfig {
+            channel_backlog_size: 3,
+            ..Default::default()
+        };
+        let manager = WebSocketManager::new(config);
+        let (replay_id, mut replay_rx) = add_observed_connection(&manager).await;
+        let (plain_id, mut plain_rx) = add_observed_connection(&manager).await;
+
+        let publish = |n: u64| {
+            manager.handle_channel_event(ChannelEvent {
+                channel: "tasks".into(),
+                data: serde_json::json!({ "n": n }),
+                user_id: None,
+            })
+        };
+        for n in 1..=4 {
+            publish(n).await;
+        }
+
+        let subscribe = serde_json::json!({
+            "type": "subscribe",
+            "channels": ["tasks"],
+            "replay": 2
+        });
+        manager
+            .handle_message(replay_id, serde_json::from_value(subscribe).unwrap())
+            .await
+            .unwrap();
+        manager
+            .handle_subscribe(plain_id, vec!["tasks".to_string()])
+            .await
+            .unwrap();
+        publish(5).await;
+
+        assert!(matches!(
+            replay_rx.try_recv(),
+            Ok(WsMessage::Success { .. })
+        ));
+        let numbers = |rx: &mut mpsc::Receiver<WsMessage>| -> Vec<u64> {
+            received_events(rx)
+                .iter()
+                .map(|data| data["n"].as_u64().unwrap())
+                .collect()
+        };
+        assert_eq!(numbers(&mut replay_rx), vec![3, 4, 5]);
+        assert_eq!(numbers(&mut plain_rx), vec![5]);
+
+        // Re-subscribing to a channel already subscribed does not replay it again
+        manager
+            .handle_subscribe_with_replay(replay_id, vec![("tasks".to_string(), None)], Some(3))
+            .await
+            .unwrap();
+        assert!(numbers(&mut replay_rx).is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_replay_larger_than_send_buffer_skips_oldest_events() {
+        let config = WebSocketConfig {
+            channel_backlog_size: 10,
+            ..Default::default()
+        };
+        let manager = WebSocketManager::new(config);
+        // The observed connection's send buffer holds 16 messages
+        let (conn_id, mut rx) = add_observed_connection(&manager).await;
+
+        for n in 1..=10 {
+            for channel in ["tasks", "alerts"] {
+                manager
+                    .handle_channel_event(ChannelEvent {
+                        channel: channel.into(),
+                        data: serde_json::json!({ "n": n, "channel": channel }),
+                        user_id: None,
+                    })
+                    .await;
+            }
+        }
+
+        manager
+            .handle_subscribe_with_replay(
+                conn_id,
+                vec![("tasks".to_string(), None), ("alerts".to_string(), None)],
+                Some(10),
+            )
+            .await
+            .unwrap();
+
+        assert!(matches!(rx.try_recv(), Ok(WsMessage::Success { .. })));
+        let replayed = received_events(&mut rx);
+        assert_eq!(replayed.len(), 15);
+        // The newest events are kept, so the replay ends at the latest event
+        assert_eq!(
+            replayed.last().unwrap(),
+            &serde_json::json!({"n": 10, "channel": "alerts"})
+        );
+        assert_eq!(
+            replayed.first().unwrap(),
+            &serde_json::json!({"n": 3, "channel": "alerts"})
+        );
+    }
+
+    #[tokio::test]
+    async fn test_resubscribe_without_filter_clears_it() {
+        let manager = WebSocketManager::new(WebSocketConfig::default());
+        let (conn_id, mut rx) = add_observed_connection(&manager).await;
+
+        let filter = EventFilter::from_fields(
+            &serde_json::from_value(serde_json::json!({"level": "error"})).unwrap(),
+        )
+        .unwrap();
+        manager
+            .handle_subscribe_filtered(conn_id, vec![("logs".to_string(), Some(filter))])
+            .await
+            .unwrap();
+        manager
+            .handle_subscribe(conn_id, vec!["logs".to_string()])
+            .await
+            .unwrap();
+
+        let data = serde_json::json!({"level": "info"});
+        manager
+            .handle_channel_event(ChannelEvent {
+                channel: "logs".into(),
+                data: data.clone(),
+                user_id: None,
+            })
+            .await;
+
+        assert_eq!(received_events(&mut rx), vec![data]);
+    }
+
+    #[tokio::test]
+    async fn test_invalid_filter_is_rejected() {
+        let manager = WebSocketManager::new(WebSocketConfig::default());
+        let (conn_id, _rx) = add_observed_connection(&manager).await;
+
+        let subscribe = serde_json::json!({
+            "type": "subscribe",
+            "channels": ["tasks"],
+            "filter": {"status": {"$regex": "fail.*"}}
+        });
+        let result = manager
+            .handle_message(conn_id, serde_json::from_value(subscribe).unwrap())
+            .await;
+
+        assert!(matches!(result, Err(WsError::InvalidFilter(_))));
+        assert_eq!(manager.get_stats().await.total_channels, 0);
+    }
+
     #[tokio::test]
     async fn test_subscription_race_condition() {
         use std::sync::Arc;
@@ -1510,4 +1929,50 @@ mod tests {
             "Should have exactly 5 unique channel subscriptions"
         );
     }
+
+    #[tokio::test]
+    async fn test_heartbeat_carries_configured_payload() {
+        let manager = WebSocketManager::new(WebSocketConfig::default());
+        assert!(matches!(
+            manager.heartbeat(),
+            WsMessage::Ping { payload: None, .. }
+        ));
+
+        let payload = serde_json::json!({"serverTimeOffsetMs": 12, "region": "eu"});
+        let config = WebSocketConfig::builder()
+            .heartbeat_payload(payload.clone())
+            .unwrap()
+            .build();
+        let manager = WebSocketManager::new(config);
+
+        let json = serde_json::to_value(manager.heartbeat()).unwrap();
+        assert_eq!(json["type"], "ping");
+        assert_eq!(json["payload"], payload);
+    }
+
+    #[tokio::test]
+    async fn test_pong_health_report_reaches_manager() {
+        let manager = WebSocketManager::new(WebSocketConfig::default());
+        let addr: SocketAddr = "127.0.0.1:8080".parse().unwrap();
+        let info = ConnectionInfo::new(addr);
+        let conn_id = info.id();
+        manager.add_connection(conn_id, info).await.unwrap();
+
+        // Plain pongs leave no report
+        let plain: WsMessage = serde_json::from_str(r#"{"type":"pong","timestamp":1}"#).unwrap();
+        manager.handle_message(conn_id, plain).await.unwrap();
+        assert!(manager.client_health(conn_id).await.is_none());
+
+        let pong: WsMessage = serde_json::from_str(
+            r#"{"type":"pong","timestamp":2,"health":{"latencyMs":250,"backlog":40,"degraded":true}}"#,
+        )
+        .unwrap();
+        manager.handle_message(conn_id, pong).await.unwrap();
+
+        let report = manager.client_health(conn_id).await.unwrap();
+        assert_eq!(report.latency_ms, Some(250));
+        assert_eq!(report.backlog, Some(40));
+        assert!(report.degraded);
+        assert_eq!(manager.get_stats().await.degraded_connections, 1);
+    }
 }
@@ -8,10 +8,12 @@ use axum::{
         ConnectInfo, State,
         ws::{Message, WebSocket, WebSocketUpgrade},
     },
-    response::Response,
+    http::{HeaderMap, StatusCode, header},
+    response::{IntoResponse, Response},
 };
 use futures::{sink::SinkExt, stream::StreamExt};
 use serde::{Deserialize, Serialize};
+use skreaver_observability::{StreamTransport, get_metrics_registry};
 use std::{
     collections::HashMap,
     net::SocketAddr,
@@ -22,16 +24,24 @@ use tokio::sync::mpsc;
 use tracing::{error, info, warn};
 use uuid::Uuid;
 
+mod backlog;
+pub mod codec;
+pub mod filter;
 pub mod guard;
 pub mod handlers;
+mod heartbeat;
 pub mod lock_ordering;
 pub mod manager;
+pub mod origin;
 pub mod protocol;
 pub mod subscription_limits;
 
+pub use codec::{JsonCodec, WsCodec};
+pub use filter::{EventFilter, FilterError};
 pub use guard::*;
 pub use handlers::*;
 pub use manager::*;
+pub use origin::{OriginPattern, OriginPolicy};
 pub use protocol::*;
 
 /// WebSocket message compression mode
@@ -63,6 +73,9 @@ impl Default for CompressionMode {
     }
 }
 
+/// Maximum serialized size of the heartbeat payload in bytes
+pub const MAX_HEARTBEAT_PAYLOAD_SIZE: usize = 4 * 1024;
+
 /// WebSocket configuration
 #[derive(Debug, Clone)]
 pub struct WebSocketConfig {
@@ -88,6 +101,14 @@ pub struct WebSocketConfig {
     pub max_connections_per_ip: usize,
     /// Broadcast channel buffer size
     pub broadcast_buffer_size: usize,
+    /// Recent events kept per channel for replay on subscribe (0 disables replay)
+    pub channel_backlog_size: usize,
+    /// Application payload attached to every server heartbeat ping
+    pub heartbeat_payload: Option<serde_json::Value>,
+    /// Origins allowed to open connections
+    pub origin_policy: OriginPolicy,
+    /// Encoding for binary frames and outgoing messages
+    pub codec: Arc<dyn WsCodec>,
 }
 
 impl Default for WebSocketConfig {
@@ -104,6 +125,10 @@ impl Default for WebSocketConfig {
             max_subscribers_per_channel: 10000,
             max_connections_per_ip: 10,
             broadcast_buffer_size: 1000,
+            channel_backlog_size: 0,
+            heartbeat_payload: None,
+            origin_policy: OriginPolicy::default(),
+            codec: Arc::new(JsonCodec),
         }
     }
 }
@@ -124,6 +149,10 @@ pub struct WebSocketConfigBuilder {
     max_subscribers_per_channel: Option<usize>,
     max_connections_per_ip: Option<usize>,
     broadcast_buffer_size: Option<usize>,
+    channel_backlog_size: Option<usize>,
+    heartbeat_payload: Option<serde_json::Value>,
+    origin_policy: OriginPolicy,
+    codec: Option<Arc<dyn WsCodec>>,
 }
 
 /// Errors that can occur when building a `WebSocketConfig`
@@ -135,6 +164,8 @@ pub enum WebSocketConfigError {
     InvalidSize(String),
     /// Invalid limit value
     InvalidLimit(String),
+    /// Invalid origin policy
+    InvalidOrigin(String),
 }
 
 impl std::fmt::Display for WebSocketConfigError {
@@ -143,6 +174,7 @@ impl std::fmt::Display for WebSocketConfigError {
             Self::InvalidTimeout(reason) => write!(f, "Invalid timeout: {}", reason),
             Self::InvalidSize(reason) => write!(f, "Invalid size: {}", reason),
             Self::InvalidLimit(reason) => write!(f, "Invalid limit: {}", reason),
+            Self::InvalidOrigin(reason) => write!(f, "Invalid origin: {}", reason),
         }
     }
 }
@@ -170,6 +202,10 @@ impl WebSocketConfigBuilder {
             max_subscribers_per_channel: None,
             max_connections_per_ip: None,
             broadcast_buffer_size: None,
+            channel_backlog_size: None,
+            heartbeat_payload: None,
+            origin_policy: OriginPolicy::default(),
+            codec: None,
         }
     }
 
@@ -271,6 +307,14 @@ impl WebSocketConfigBuilder {
                 "buffer_size cannot exceed 10,000".to_string(),
             ));
         }
+        if self
+            .channel_backlog_size
+            .is_some_and(|backlog| backlog >= size)
+        {
+            return Err(WebSocketConfigError::InvalidSize(
+                "buffer_size must be larger than channel_backlog_size".to_string(),
+            ));
+        }
         self.buffer_size = Some(size);
         Ok(self)
     }
@@ -342,6 +386,52 @@ impl WebSocketConfigBuilder {
         Ok(self)
     }
 
+    /// Set the per-channel replay backlog size (0 disables replay)
+    ///
+    /// Must be smaller than `buffer_size`, so a replayed channel and the
+    /// subscription acknowledgment fit in a connection's send buffer.
+    pub fn channel_backlog_size(mut self, size: usize) -> Result<Self, WebSocketConfigError> {
+        let buffer_size = self
+            .buffer_size
+            .unwrap_or(WebSocketConfig::default().buffer_size);
+        if size >= buffer_size {
+            return Err(WebSocketConfigError::InvalidSize(format!(
+                "channel_backlog_size must be smaller than buffer_size ({})",
+                buffer_size
+            )));
+        }
+        self.channel_backlog_size = Some(size);
+        Ok(self)
+    }
+
+    /// Set the application payload sent with each heartbeat ping (at most 4 KB serialized)
+    pub fn heartbeat_payload(
+        mut self,
+        payload: serde_json::Value,
+    ) -> Result<Self, WebSocketConfigError> {
+        let size = payload.to_string().len();
+        if size > MAX_HEARTBEAT_PAYLOAD_SIZE {
+            return Err(WebSocketConfigError::InvalidSize(format!(
+                "heartbeat_payload cannot exceed {} bytes (got {})",
+                MAX_HEARTBEAT_PAYLOAD_SIZE, size
+            )));
+        }
+        self.heartbeat_payload = Some(payload);
+        Ok(self)
+    }
+
+    /// Set the origins allowed to open connections
+    pub fn origin_policy(mut self, policy: OriginPolicy) -> Self {
+        self.origin_policy = policy;
+        self
+    }
+
+    /// Set the codec for binary frames and outgoing messages
+    pub fn codec(mut self, codec: Arc<dyn WsCodec>) -> Self {
+        self.codec = Some(codec);
+        self
+    }
+
     /// Build the `WebSocketConfig` (uses defaults for unset fields)
     pub fn build(self) -> WebSocketConfig {
         let defaults = WebSocketConfig::default();
@@ -368,6 +458,12 @@ impl WebSocketConfigBuilder {
             broadcast_buffer_size: self
                 .broadcast_buffer_size
                 .unwrap_or(defaults.broadcast_buffer_size),
+            channel_backlog_size: self
+                .channel_backlog_size
+                .unwrap_or(defaults.channel_backlog_size),
+            heartbeat_payload: self.heartbeat_payload,
+            origin_policy: self.origin_policy,
+            codec: self.codec.unwrap_or(defaults.codec),
         }
     }
 }
@@ -485,18 +581,50 @@ impl ConnectionInfo<Authenticated> {
     }
 }
 
+/// Health report a client may piggyback on a pong
+///
+/// All fields are optional so clients can report only what they track.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClientHealthReport {
+    /// Round-trip latency measured by the client in milliseconds
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub latency_ms: Option<u64>,
+    /// Messages received but not yet processed by the client
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub backlog: Option<u32>,
+    /// Whether the client considers itself degraded
+    #[serde(default)]
+    pub degraded: bool,
+}
+
 /// WebSocket message types
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "type", rename_all = "camelCase")]
 pub enum WsMessage {
-    /// Ping message
-    Ping { timestamp: i64 },
-    /// Pong message
-    Pong { timestamp: i64 },
+    /// Ping message, optionally carrying an application heartbeat payload
+    Ping {
+        timestamp: i64,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        payload: Option<serde_json::Value>,
+    },
+    /// Pong message, optionally carrying the client's health report
+    Pong {
+        timestamp: i64,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        health: Option<ClientHealthReport>,
+    },
     /// Authentication message
     Auth { token: String },
-    /// Subscribe to events
-    Subscribe { channels: Vec<String> },
+    /// Subscribe to events, optionally filtered server-side and preceded by
+    /// up to `replay` recent events per channel
+    Subscribe {
+        channels: Vec<String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        filter: Option<HashMap<String, serde_json::Value>>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        replay: Option<usize>,
+    },
     /// Unsubscribe from events
     Unsubscribe { channels: Vec<String> },
     /// Event notification
@@ -513,14 +641,21 @@ pub enum WsMessage {
 
 impl WsMessage {
     pub fn ping() -> Self {
+        Self::ping_with_payload(None)
+    }
+
+    /// Create a heartbeat ping carrying an application payload
+    pub fn ping_with_payload(payload: Option<serde_json::Value>) -> Self {
         Self::Ping {
             timestamp: chrono::Utc::now().timestamp(),
+            payload,
         }
     }
 
     pub fn pong() -> Self {
         Self::Pong {
             timestamp: chrono::Utc::now().timestamp(),
+            health: None,
         }
     }
 
@@ -584,6 +719,9 @@ pub enum WsError {
 
     #[error("Rate limit exceeded for IP address")]
     RateLimitExceeded,
+
+    #[error("Invalid subscription filter: {0}")]
+    InvalidFilter(#[from] FilterError),
 }
 
 impl WsError {
@@ -624,18 +762,37 @@ impl WsError {
             WsError::RateLimitExceeded => {
                 WsMessage::error("RATE_LIMIT_EXCEEDED", "Rate limit exceeded for IP address")
             }
+            WsError::InvalidFilter(err) => WsMessage::error("INVALID_FILTER", &err.to_string()),
         }
     }
 }
 
 /// WebSocket upgrade handler
+///
+/// Handshakes whose `Origin` is not allowed by the configured
+/// [`OriginPolicy`] are rejected with `403 Forbidden` before upgrading.
 pub async fn websocket_handler(
     ws: WebSocketUpgrade,
+    headers: HeaderMap,
     ConnectInfo(addr): ConnectInfo<SocketAddr>,
     State(manager): State<Arc<WebSocketManager>>,
 ) -> Response {
     info!("WebSocket connection request from {}", addr);
 
+    let origin = headers
+        .get(header::ORIGIN)
+        .and_then(|value| value.to_str().ok());
+    let host = headers
+        .get(header::HOST)
+        .and_then(|value| value.to_str().ok());
+    if !manager.config.origin_policy.is_allowed(origin, host) {
+        warn!(
+            "Rejected WebSocket connection from {} with origin {:?}",
+            addr, origin
+        );
+        return (StatusCode::FORBIDDEN, "Origin not allowed").into_response();
+    }
+
     ws.on_upgrade(move |socket| handle_socket(socket, addr, manager))
 }
 
@@ -656,43 +813,61 @@ async fn handle_socket(socket: WebSocket, addr: SocketAddr, manager: Arc<WebSock
     }
 
     // RAII guard ensures cleanup even on panic
-    let _guard = ConnectionGuard::new(conn_id, Arc::clone(&manager));
+    let mut guard = ConnectionGuard::new(conn_id, Arc::clone(&manager));
+
+    let stream_guard = get_metrics_registry()
+        .map(|registry| Arc::new(registry.track_stream(StreamTransport::WebSocket)));
 
     let (mut sender, mut receiver) = socket.split();
     let (tx, mut rx) = mpsc::channel::<WsMessage>(manager.config.buffer_size);
 
     // Start background tasks
+    let pongs = Arc::new(heartbeat::PongTracker::new());
     let manager_clone = Arc::clone(&manager);
     let tx_ping = tx.clone();
-    let ping_task = tokio::spawn(async move {
-        let mut interval = tokio::time::interval(manager_clone.config.ping_interval);
-        loop {
-            interval.tick().await;
-            if tx_ping.send(WsMessage::ping()).await.is_err() {
-                break;
-            }
-        }
+    let ping_pongs = Arc::clone(&pongs);
+    let mut ping_task = tokio::spawn(async move {
+        let config = &manager_clone.config;
+        heartbeat::run_heartbeat(
+            tx_ping,
+            || manager_clone.heartbeat(),
+            config.ping_interval,
+            config.pong_timeout,
+            &ping_pongs,
+        )
+        .await
     });
 
-    let send_task = tokio::spawn(async move {
+    let send_guard = stream_guard.clone();
+    let codec = Arc::clone(&manager.config.codec);
+    let mut send_task = tokio::spawn(async move {
         while let Some(msg) = rx.recv().await {
-            let json_msg = match serde_json::to_string(&msg) {
-                Ok(json) => json,
-                Err(e) => {
-                    error!("Failed to serialize message: {}", e);
-                    continue;
+            let bytes = codec.encode(&msg);
+            let frame_len = bytes.len();
+            let frame = if codec.is_binary() {
+                Message::Binary(bytes.into())
+            } else {
+                match String::from_utf8(bytes) {
+                    Ok(text) => Message::Text(text.into()),
+                    Err(e) => {
+                        error!("Codec produced a non-UTF-8 text frame: {}", e);
+                        continue;
+                    }
                 }
             };
 
-            if sender.send(Message::Text(json_msg.into())).await.is_err() {
+            if sender.send(frame).await.is_err() {
                 break;
             }
+            if let Some(guard) = &send_guard {
+                guard.record_frame(frame_len);
+            }
         }
     });
 
     let manager_clone = Arc::clone(&manager);
     let max_message_size = manager.config.max_message_size;
-    let receive_task = tokio::spawn(async move {
+    let mut receive_task = tokio::spawn(async move {
         while let Some(msg) = receiver.next().await {
             match msg {
                 Ok(Message::Text(text)) => {
@@ -712,6 +887,9 @@ async fn handle_socket(socket: WebSocket, addr: SocketAddr, manager: Arc<WebSock
 
                     match serde_json::from_str::<WsMessage>(&text) {
                         Ok(ws_msg) => {
+                            if matches!(ws_msg, WsMessage::Pong { .. }) {
+                                pongs.record_pong();
+                            }
                             if let Err(e) = manager_clone.handle_message(conn_id, ws_msg).await {
                                 error!("Error handling message from {}: {}", conn_id, e);
                                 let error_msg = e.to_message();
@@ -729,8 +907,33 @@ async fn handle_socket(socket: WebSocket, addr: SocketAddr, manager: Arc<WebSock
                         }
                     }
                 }
-                Ok(Message::Binary(_)) => {
-                    warn!("Binary messages not supported from {}", conn_id);
+                Ok(Message::Binary(data)) => {
+                    let result = codec::decode_frame(
+                        manager_clone.config.codec.as_ref(),
+                        &data,
+                        max_message_size,
+                    );
+                    let error = match result {
+                        Ok(ws_msg) => {
+                            if matches!(ws_msg, WsMessage::Pong { .. }) {
+                                pongs.record_pong();
+                            }
+                            match manager_clone.handle_message(conn_id, ws_msg).await {
+                                Ok(()) => continue,
+                                Err(e) => {
+                                    error!("Error handling message from {}: {}", conn_id, e);
+                                    e
+                                }
+                            }
+                        }
+                        Err(e) => {
+                            error!("Invalid binary message from {}: {}", conn_id, e);
+                            e
+                        }
+                    };
+                    if tx.send(error.to_message()).await.is_err() {
+                        break;
+                    }
                 }
                 Ok(Message::Close(_)) => {
                     info!("Connection {} closed by client", conn_id);
@@ -742,7 +945,7 @@ async fn handle_socket(socket: WebSocket, addr: SocketAddr, manager: Arc<WebSock
                     }
                 }
                 Ok(Message::Pong(_)) => {
-                    // Update last activity
+                    pongs.record_pong();
                     manager_clone.update_activity(conn_id).await;
                 }
                 Err(e) => {
@@ -755,26 +958,39 @@ async fn handle_socket(socket: WebSocket, addr: SocketAddr, manager: Arc<WebSock
 
     // Wait for any task to complete and handle panics
     tokio::select! {
-        result = ping_task => {
-            if let Err(e) = result {
-                error!("Ping task panicked for connection {}: {:?}", conn_id, e);
+        result = &mut ping_task => match result {
+            Ok(heartbeat::HeartbeatEnd::PongTimeout) => {
+                let pong_timeout = manager.config.pong_timeout;
+                warn!(
+                    connection_id = %conn_id,
+                    peer = %addr,
+                    pong_timeout_ms = pong_timeout.as_millis() as u64,
+                    "Closing WebSocket connection after missed pong"
+                );
             }
-        }
-        result = send_task => {
+            Ok(heartbeat::HeartbeatEnd::ChannelClosed) => {}
+            Err(e) => error!("Ping task panicked for connection {}: {:?}", conn_id, e),
+        },
+        result = &mut send_task => {
             if let Err(e) = result {
                 error!("Send task panicked for connection {}: {:?}", conn_id, e);
             }
         }
-        result = receive_task => {
+        result = &mut receive_task => {
             if let Err(e) = result {
                 error!("Receive task panicked for connection {}: {:?}", conn_id, e);
             }
         }
     }
 
-    // Cleanup happens automatically when _guard drops
+    // Stop the remaining tasks so the socket halves they own are dropped,
+    // closing the connection
+    ping_task.abort();
+    send_task.abort();
+    receive_task.abort();
+
+    guard.cleanup().await;
     info!("WebSocket connection {} closed", conn_id);
-    // Note: Connection cleanup is now handled by ConnectionGuard RAII pattern
 }
 
 #[cfg(test)]
@@ -795,6 +1011,8 @@ mod tests {
         assert_eq!(config.max_subscribers_per_channel, 10000);
         assert_eq!(config.max_connections_per_ip, 10);
         assert_eq!(config.broadcast_buffer_size, 1000);
+        assert_eq!(config.channel_backlog_size, 0);
+        assert!(!config.codec.is_binary());
     }
 
     #[test]
@@ -864,4 +1082,38 @@ mod tests {
         assert!(json.contains("\"type\":\"event\""));
         assert!(json.contains("\"channel\":\"test\""));
     }
+
+    #[test]
+    fn test_heartbeat_payload_is_optional_on_the_wire() {
+        let json = serde_json::to_value(WsMessage::ping()).unwrap();
+        assert!(json.get("payload").is_none());
+
+        let pong: WsMessage = serde_json::from_str(r#"{"type":"pong","timestamp":1}"#).unwrap();
+        assert!(matches!(pong, WsMessage::Pong { health: None, .. }));
+
+        let oversized = serde_json::json!({"blob": "x".repeat(MAX_HEARTBEAT_PAYLOAD_SIZE)});
+        assert!(
+            WebSocketConfig::builder()
+                .heartbeat_payload(oversized)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_channel_backlog_must_fit_in_send_buffer() {
+        assert!(WebSocketConfig::builder().channel_backlog_size(99).is_ok());
+        assert!(
+            WebSocketConfig::builder()
+                .channel_backlog_size(100)
+                .is_err()
+        );
+
+        let builder = WebSocketConfig::builder()
+            .buffer_size(500)
+            .unwrap()
+            .channel_backlog_size(400)
+            .unwrap();
+        assert!(builder.clone().buffer_size(400).is_err());
+        assert_eq!(builder.build().channel_backlog_size, 400);
+    }
 }
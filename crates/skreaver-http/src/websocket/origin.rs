@@ -0,0 +1,175 @@
+//! Origin checking for WebSocket upgrades
+//!
+//! Browsers attach an `Origin` header to WebSocket handshakes but do not apply
+//! CORS to them, so without a check any website can open a socket against the
+//! server with the visitor's cookies (cross-site WebSocket hijacking). An
+//! [`OriginPolicy`] decides which origins may upgrade. The default policy only
+//! admits same-origin pages and non-browser clients that send no `Origin`.
+
+use regex::Regex;
+
+use super::WebSocketConfigError;
+
+/// Pattern an allowed origin must match
+#[derive(Debug, Clone)]
+pub enum OriginPattern {
+    /// Exact origin such as `https://app.example.com` (case-insensitive)
+    Exact(String),
+    /// Host equal to the domain or one of its subdomains, over http or https
+    Subdomains(String),
+    /// Regular expression matched against the whole origin
+    Regex(Regex),
+}
+
+impl OriginPattern {
+    fn matches(&self, origin: &str) -> bool {
+        match self {
+            Self::Exact(allowed) => origin.eq_ignore_ascii_case(allowed),
+            Self::Subdomains(domain) => {
+                let Some(host) = origin_host(origin) else {
+                    return false;
+                };
+                let host = host.split(':').next().unwrap_or(host);
+                host.eq_ignore_ascii_case(domain)
+                    || host
+                        .to_ascii_lowercase()
+                        .ends_with(&format!(".{}", domain.to_ascii_lowercase()))
+            }
+            Self::Regex(regex) => regex.is_match(origin),
+        }
+    }
+}
+
+/// Host and port of an http(s) origin, e.g. `app.example.com:8443`
+fn origin_host(origin: &str) -> Option<&str> {
+    let (scheme, host) = origin.split_once("://")?;
+    if !scheme.eq_ignore_ascii_case("http") && !scheme.eq_ignore_ascii_case("https") {
+        return None;
+    }
+    (!host.is_empty() && !host.contains('/')).then_some(host)
+}
+
+/// Which origins may open WebSocket connections
+///
+/// Same-origin requests (the `Origin` host equals the `Host` header) are
+/// always allowed; other origins must match one of the configured patterns.
+#[derive(Debug, Clone)]
+pub struct OriginPolicy {
+    patterns: Vec<OriginPattern>,
+    allow_missing_origin: bool,
+}
+
+impl Default for OriginPolicy {
+    fn default() -> Self {
+        Self::same_origin()
+    }
+}
+
+impl OriginPolicy {
+    /// Only allow same-origin pages and clients that send no `Origin`
+    pub fn same_origin() -> Self {
+        Self {
+            patterns: Vec::new(),
+            allow_missing_origin: true,
+        }
+    }
+
+    /// Allow an exact origin such as `https://app.example.com`
+    pub fn allow_origin(mut self, origin: impl Into<String>) -> Self {
+        let origin = origin.into();
+        self.patterns.push(OriginPattern::Exact(
+            origin.trim_end_matches('/').to_string(),
+        ));
+        self
+    }
+
+    /// Allow `domain` and all of its subdomains, on any port
+    pub fn allow_subdomains_of(mut self, domain: impl Into<String>) -> Self {
+        let domain = domain.into();
+        self.patterns.push(OriginPattern::Subdomains(
+            domain.trim_start_matches('.').to_string(),
+        ));
+        self
+    }
+
+    /// Allow origins matching a regular expression
+    ///
+    /// The expression is anchored, so it must match the whole origin.
+    pub fn allow_pattern(mut self, pattern: &str) -> Result<Self, WebSocketConfigError> {
+        let regex = Regex::new(&format!("^(?:{})$", pattern)).map_err(|e| {
+            WebSocketConfigError::InvalidOrigin(format!("invalid origin pattern: {}", e))
+        })?;
+        self.patterns.push(OriginPattern::Regex(regex));
+        Ok(self)
+    }
+
+    /// Set whether requests without an `Origin` header are allowed
+    ///
+    /// Browsers always send `Origin` on WebSocket handshakes, so this only
+    /// affects non-browser clients. Allowed by default.
+    pub fn allow_missing_origin(mut self, allow: bool) -> Self {
+        self.allow_missing_origin = allow;
+        self
+    }
+
+    /// Configured patterns for cross-origin requests
+    pub fn patterns(&self) -> &[OriginPattern] {
+        &self.patterns
+    }
+
+    /// Check whether a handshake with these `Origin` and `Host` headers may upgrade
+    pub fn is_allowed(&self, origin: Option<&str>, host: Option<&str>) -> bool {
+        let Some(origin) = origin else {
+            return self.allow_missing_origin;
+        };
+        let origin = origin.trim_end_matches('/');
+
+        let same_origin = matches!(
+            (origin_host(origin), host),
+            (Some(origin_host), Some(host)) if origin_host.eq_ignore_ascii_case(host)
+        );
+        same_origin || self.patterns.iter().any(|pattern| pattern.matches(origin))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_policy_allows_same_origin_only() {
+        let policy = OriginPolicy::default();
+
+        assert!(policy.is_allowed(None, Some("api.example.com")));
+        assert!(policy.is_allowed(Some("https://api.example.com"), Some("api.example.com")));
+        assert!(policy.is_allowed(Some("http://localhost:3000"), Some("localhost:3000")));
+        assert!(!policy.is_allowed(Some("https://evil.com"), Some("api.example.com")));
+        assert!(!policy.is_allowed(Some("http://localhost:4000"), Some("localhost:3000")));
+        assert!(!policy.is_allowed(Some("null"), Some("api.example.com")));
+        assert!(!policy.is_allowed(Some("https://api.example.com"), None));
+
+        let strict = OriginPolicy::same_origin().allow_missing_origin(false);
+        assert!(!strict.is_allowed(None, Some("api.example.com")));
+    }
+
+    #[test]
+    fn test_allowlist_patterns() {
+        let policy = OriginPolicy::same_origin()
+            .allow_origin("https://app.example.com/")
+            .allow_subdomains_of(".partner.io")
+            .allow_pattern(r"https://preview-\d+\.example\.dev")
+            .unwrap();
+        let allowed = |origin| policy.is_allowed(Some(origin), Some("api.example.com"));
+
+        assert!(allowed("https://APP.example.com"));
+        assert!(!allowed("https://app.example.com.evil.com"));
+        assert!(allowed("https://partner.io"));
+        assert!(allowed("https://eu.partner.io:8443"));
+        assert!(!allowed("https://notpartner.io"));
+        assert!(!allowed("ftp://eu.partner.io"));
+        assert!(allowed("https://preview-42.example.dev"));
+        assert!(!allowed("https://preview-42.example.dev.evil.com"));
+
+        assert!(OriginPolicy::same_origin().allow_pattern("(").is_err());
+    }
+}
@@ -116,6 +116,34 @@ fn test_env_config_rate_limits() {
     clear_env("SKREAVER_RATE_LIMIT_PER_USER_RPM");
 }
 
+#[test]
+#[serial]
+fn test_env_config_openapi_limits() {
+    clear_all_skreaver_env_vars();
+    set_env("SKREAVER_OPENAPI_RATE_LIMIT_RPM", "5");
+    set_env("SKREAVER_OPENAPI_REQUIRE_AUTH", "true");
+
+    let config = HttpRuntimeConfigBuilder::from_env()
+        .expect("should load config")
+        .build()
+        .expect("should build valid config");
+
+    let openapi = config.openapi.expect("openapi enabled by default");
+    assert_eq!(openapi.rate_limit_rpm.map(|rpm| rpm.get()), Some(5));
+    assert!(openapi.require_auth);
+
+    // 0 disables the docs rate limit
+    set_env("SKREAVER_OPENAPI_RATE_LIMIT_RPM", "0");
+    let config = HttpRuntimeConfigBuilder::from_env()
+        .expect("should load config")
+        .build()
+        .expect("should build valid config");
+    assert!(config.openapi.unwrap().rate_limit_rpm.is_none());
+
+    clear_env("SKREAVER_OPENAPI_RATE_LIMIT_RPM");
+    clear_env("SKREAVER_OPENAPI_REQUIRE_AUTH");
+}
+
 #[test]
 #[serial]
 fn test_env_config_backpressure() {
@@ -378,6 +406,8 @@ fn clear_all_skreaver_env_vars() {
         "SKREAVER_MAX_BODY_SIZE",
         "SKREAVER_ENABLE_CORS",
         "SKREAVER_ENABLE_OPENAPI",
+        "SKREAVER_OPENAPI_RATE_LIMIT_RPM",
+        "SKREAVER_OPENAPI_REQUIRE_AUTH",
         "SKREAVER_SECURITY_CONFIG_PATH",
         "SKREAVER_RATE_LIMIT_GLOBAL_RPM",
         "SKREAVER_RATE_LIMIT_PER_IP_RPM",
@@ -229,16 +229,20 @@ async fn test_metrics_endpoint_returns_prometheus_format() {
 
     // Metrics endpoint should return OK
     assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        response.headers()["content-type"],
+        "text/plain; version=0.0.4"
+    );
 
     let body = axum::body::to_bytes(response.into_body(), usize::MAX)
         .await
         .unwrap();
     let text = String::from_utf8(body.to_vec()).unwrap();
 
-    // Should be in Prometheus text format (contains # HELP or # TYPE)
+    // Core metrics are exported under the default namespace
     assert!(
-        text.contains("# HELP") || text.contains("# TYPE") || text.is_empty(),
-        "Metrics should be in Prometheus format or empty"
+        text.contains("# TYPE skreaver_agent_sessions_active gauge"),
+        "Metrics should include core metrics in Prometheus format"
     );
 }
 
@@ -27,9 +27,7 @@ impl Tool for DummyTool {
     }
 
     fn call(&self, input: String) -> ExecutionResult {
-        ExecutionResult::Success {
-            output: format!("Dummy output: {}", input),
-        }
+        ExecutionResult::success(format!("Dummy output: {}", input))
     }
 }
 
@@ -1,7 +1,8 @@
 //! WebSocket security and concurrent access tests
 
 use skreaver_http::websocket::{
-    AuthHandler, ConnectionInfo, WebSocketConfig, WebSocketManager, WsError,
+    AuthHandler, ConnectionInfo, OriginPolicy, WebSocketConfig, WebSocketManager, WsError,
+    websocket_handler,
 };
 use std::{net::SocketAddr, sync::Arc, time::Duration};
 
@@ -330,3 +331,86 @@ async fn test_expired_connections_cleanup() {
     let stats = manager.get_stats().await;
     assert_eq!(stats.total_connections, 0);
 }
+
+/// Serve `websocket_handler` on a local port and return its address
+async fn serve_websocket(origin_policy: OriginPolicy) -> SocketAddr {
+    let config = WebSocketConfig::builder()
+        .origin_policy(origin_policy)
+        .build();
+    let manager = Arc::new(WebSocketManager::new(config));
+    let app = axum::Router::new()
+        .route("/ws", axum::routing::get(websocket_handler))
+        .with_state(manager);
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        axum::serve(
+            listener,
+            app.into_make_service_with_connect_info::<SocketAddr>(),
+        )
+        .await
+        .unwrap();
+    });
+    addr
+}
+
+/// Send a WebSocket handshake and return the response status line
+async fn handshake_status(addr: SocketAddr, origin: Option<&str>) -> String {
+    use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+
+    let mut stream = tokio::net::TcpStream::connect(addr).await.unwrap();
+    let origin = origin
+        .map(|origin| format!("Origin: {}\r\n", origin))
+        .unwrap_or_default();
+    let request = format!(
+        "GET /ws HTTP/1.1\r\nHost: {}\r\nConnection: Upgrade\r\nUpgrade: websocket\r\n\
+         Sec-WebSocket-Version: 13\r\nSec-WebSocket-Key: dGhlIHNhbXBsZSBub25jZQ==\r\n{}\r\n",
+        addr, origin
+    );
+    stream.write_all(request.as_bytes()).await.unwrap();
+
+    let mut status = String::new();
+    BufReader::new(stream).read_line(&mut status).await.unwrap();
+    status.trim_end().to_string()
+}
+
+#[tokio::test]
+async fn test_allowed_origin_upgrades() {
+    let addr =
+        serve_websocket(OriginPolicy::same_origin().allow_subdomains_of("example.com")).await;
+
+    for origin in [
+        Some("https://app.example.com"),
+        Some(format!("http://{}", addr).as_str()),
+        None,
+    ] {
+        let status = handshake_status(addr, origin).await;
+        assert!(
+            status.starts_with("HTTP/1.1 101"),
+            "origin {:?} got {}",
+            origin,
+            status
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_disallowed_origin_is_rejected_before_upgrade() {
+    let addr =
+        serve_websocket(OriginPolicy::same_origin().allow_origin("https://app.example.com")).await;
+
+    for origin in [
+        "https://evil.com",
+        "https://app.example.com.evil.com",
+        "null",
+    ] {
+        let status = handshake_status(addr, Some(origin)).await;
+        assert!(
+            status.starts_with("HTTP/1.1 403"),
+            "origin {} got {}",
+            origin,
+            status
+        );
+    }
+}
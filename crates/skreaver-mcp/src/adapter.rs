@@ -207,9 +207,7 @@ mod tests {
         }
 
         fn call(&self, input: String) -> ExecutionResult {
-            ExecutionResult::Success {
-                output: format!("Echo: {}", input),
-            }
+            ExecutionResult::success(format!("Echo: {}", input))
         }
     }
 
@@ -3,7 +3,7 @@
 //! This module provides integration with external MCP servers, allowing them to be used
 //! as Skreaver tools. The bridge handles:
 //! - Spawning and connecting to MCP server processes
-//! - Tool discovery via the MCP protocol
+//! - Tool discovery via the MCP protocol, cached for a configurable TTL
 //! - Request/response translation between Skreaver and MCP formats
 //!
 //! # Example
@@ -28,15 +28,18 @@ use rmcp::{
     ClientHandler, ServiceExt,
     model::{
         CallToolRequestParams, CallToolResult, ClientCapabilities, ClientInfo, Content,
-        Implementation, RawContent, Tool as McpToolInfo,
+        Implementation, JsonObject, Meta, RawContent, Tool as McpToolInfo,
     },
     service::{Peer, RoleClient, RunningService},
-    transport::child_process::TokioChildProcess,
+    transport::{IntoTransport, child_process::TokioChildProcess},
 };
 use serde_json::Value;
 use skreaver_core::tool::{ExecutionResult, Tool};
+use skreaver_tools::validate_json;
 use std::borrow::Cow;
-use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant};
 use tokio::process::Command;
 use tracing::{debug, error, info, warn};
 
@@ -45,9 +48,17 @@ use tracing::{debug, error, info, warn};
 ///
 /// The bridge maintains a connection to an external MCP server process and
 /// translates tool calls between Skreaver's format and the MCP protocol.
+///
+/// The tool list discovered from the server is cached according to a
+/// [`ToolCacheConfig`]. [`cached_tools`](Self::cached_tools) and
+/// [`find_cached_tool`](Self::find_cached_tool) re-query the server once the
+/// cache expires; the synchronous accessors such as [`tools`](Self::tools)
+/// always return the last discovered list.
 pub struct McpBridge {
     server_name: String,
-    tools: Vec<Arc<BridgedTool>>,
+    tools: Arc<RwLock<ToolCache>>,
+    cache_config: ToolCacheConfig,
+    revalidating: Arc<AtomicBool>,
     service: RunningService<RoleClient, McpClientHandler>,
 }
 
@@ -55,11 +66,58 @@ impl std::fmt::Debug for McpBridge {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("McpBridge")
             .field("server_name", &self.server_name)
-            .field("tool_count", &self.tools.len())
+            .field("tool_count", &self.tool_count())
+            .field("cache_config", &self.cache_config)
             .finish()
     }
 }
 
+/// How long a bridge reuses the tool list discovered from its server
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ToolCacheConfig {
+    /// How long a discovered tool list is reused before re-querying the server
+    pub ttl: Duration,
+    /// Keep serving an expired tool list while it is re-queried in the
+    /// background, instead of waiting for the server
+    pub stale_while_revalidate: bool,
+}
+
+impl ToolCacheConfig {
+    /// Cache tool lists for `ttl`, waiting for the server once it expires
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            stale_while_revalidate: false,
+        }
+    }
+
+    /// Serve the expired tool list while it is re-queried in the background
+    pub fn with_stale_while_revalidate(mut self, enabled: bool) -> Self {
+        self.stale_while_revalidate = enabled;
+        self
+    }
+}
+
+impl Default for ToolCacheConfig {
+    fn default() -> Self {
+        Self::new(Duration::from_secs(300))
+    }
+}
+
+/// Tool list discovered from the server and when it was fetched
+struct ToolCache {
+    tools: Vec<Arc<BridgedTool>>,
+    /// `None` once the list has been invalidated
+    fetched_at: Option<Instant>,
+}
+
+impl ToolCache {
+    fn replace(&mut self, tools: Vec<Arc<BridgedTool>>) {
+        self.tools = tools;
+        self.fetched_at = Some(Instant::now());
+    }
+}
+
 /// Simple MCP client handler that implements ClientHandler trait
 #[derive(Clone, Default)]
 struct McpClientHandler {
@@ -136,6 +194,27 @@ impl McpBridge {
             });
         }
 
+        Self::connect_transport(server_command, transport).await
+    }
+
+    /// Connect to an MCP server over an already established transport
+    ///
+    /// This is useful for servers that are not child processes, such as
+    /// in-process servers connected through pipes.
+    ///
+    /// # Parameters
+    ///
+    /// * `server_name` - Name reported by [`server_name`](Self::server_name)
+    /// * `transport` - Transport connected to the MCP server
+    ///
+    /// # Returns
+    ///
+    /// A connected `McpBridge` with all discovered tools, or an error
+    pub async fn connect_transport<T, E, A>(server_name: &str, transport: T) -> McpResult<Self>
+    where
+        T: IntoTransport<RoleClient, E, A>,
+        E: std::error::Error + Send + Sync + 'static,
+    {
         // Create MCP client handler with info
         let handler = McpClientHandler {
             client_info: ClientInfo {
@@ -162,37 +241,33 @@ impl McpBridge {
 
         info!("MCP client connected, discovering tools...");
 
-        // Get the peer to make requests
-        let peer = service.peer();
-
-        // List all available tools
-        let mcp_tools = peer
-            .list_all_tools()
-            .await
-            .map_err(|e| McpError::ClientError(format!("Failed to list tools: {}", e)))?;
-
-        info!(count = mcp_tools.len(), "Discovered MCP tools");
+        let tools = discover_tools(service.peer()).await?;
 
-        // Create bridged tools
-        let tools: Vec<Arc<BridgedTool>> = mcp_tools
-            .into_iter()
-            .map(|tool_info| {
-                debug!(
-                    name = %tool_info.name,
-                    description = ?tool_info.description,
-                    "Creating bridged tool"
-                );
-                Arc::new(BridgedTool::new(tool_info, peer.clone()))
-            })
-            .collect();
+        info!(count = tools.len(), "Discovered MCP tools");
 
         Ok(Self {
-            server_name: server_command.to_string(),
-            tools,
+            server_name: server_name.to_string(),
+            tools: Arc::new(RwLock::new(ToolCache {
+                tools,
+                fetched_at: Some(Instant::now()),
+            })),
+            cache_config: ToolCacheConfig::default(),
+            revalidating: Arc::new(AtomicBool::new(false)),
             service,
         })
     }
 
+    /// Set how long the discovered tool list is reused
+    pub fn with_tool_cache(mut self, config: ToolCacheConfig) -> Self {
+        self.cache_config = config;
+        self
+    }
+
+    /// Get the tool cache configuration
+    pub fn tool_cache_config(&self) -> &ToolCacheConfig {
+        &self.cache_config
+    }
+
     /// Connect to an external MCP server with custom arguments
     ///
     /// This is a more flexible version that allows specifying the program
@@ -216,9 +291,15 @@ impl McpBridge {
         Self::connect_stdio(&full_command).await
     }
 
+    /// Read the cached tool list, whether or not it has expired
+    fn cache(&self) -> std::sync::RwLockReadGuard<'_, ToolCache> {
+        self.tools.read().unwrap_or_else(|e| e.into_inner())
+    }
+
     /// Get all bridged tools
     pub fn tools(&self) -> Vec<Arc<dyn Tool>> {
-        self.tools
+        self.cache()
+            .tools
             .iter()
             .map(|t| Arc::clone(t) as Arc<dyn Tool>)
             .collect()
@@ -231,22 +312,119 @@ impl McpBridge {
 
     /// Get the number of available tools
     pub fn tool_count(&self) -> usize {
-        self.tools.len()
+        self.cache().tools.len()
     }
 
     /// Find a tool by name
     pub fn find_tool(&self, name: &str) -> Option<Arc<dyn Tool>> {
-        self.tools
+        self.find_bridged_tool(name).map(|t| t as Arc<dyn Tool>)
+    }
+
+    /// Find a tool by name, keeping access to MCP-specific calls
+    pub fn find_bridged_tool(&self, name: &str) -> Option<Arc<BridgedTool>> {
+        self.cache()
+            .tools
             .iter()
             .find(|t| t.name() == name)
-            .map(|t| Arc::clone(t) as Arc<dyn Tool>)
+            .cloned()
+    }
+
+    /// Get the tool list, re-querying the server once the cache has expired
+    ///
+    /// With [`stale_while_revalidate`](ToolCacheConfig::stale_while_revalidate)
+    /// an expired list is returned immediately and refreshed in the
+    /// background. A list dropped by [`invalidate_tools`](Self::invalidate_tools)
+    /// or a closed connection is never served.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the connection is closed or the server cannot
+    /// list its tools.
+    pub async fn cached_tools(&self) -> McpResult<Vec<Arc<BridgedTool>>> {
+        if self.is_closed() {
+            self.invalidate_tools();
+            return Err(McpError::ConnectionError(format!(
+                "Connection to MCP server '{}' is closed",
+                self.server_name
+            )));
+        }
+
+        let (tools, fetched_at) = {
+            let cache = self.cache();
+            (cache.tools.clone(), cache.fetched_at)
+        };
+        match fetched_at {
+            Some(at) if at.elapsed() < self.cache_config.ttl => Ok(tools),
+            Some(_) if self.cache_config.stale_while_revalidate => {
+                self.spawn_revalidation();
+                Ok(tools)
+            }
+            _ => {
+                self.refresh_tools().await?;
+                Ok(self.cache().tools.clone())
+            }
+        }
+    }
+
+    /// Find a tool by name in the tool list returned by
+    /// [`cached_tools`](Self::cached_tools)
+    pub async fn find_cached_tool(&self, name: &str) -> McpResult<Option<Arc<BridgedTool>>> {
+        Ok(self
+            .cached_tools()
+            .await?
+            .into_iter()
+            .find(|t| t.name() == name))
+    }
+
+    /// Drop the cached tool list so the next lookup re-queries the server
+    ///
+    /// The last discovered list stays visible through the synchronous
+    /// accessors until the re-query completes.
+    pub fn invalidate_tools(&self) {
+        self.tools
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .fetched_at = None;
+    }
+
+    /// Re-query the tool list in the background unless a re-query is running
+    fn spawn_revalidation(&self) {
+        if self.revalidating.swap(true, Ordering::SeqCst) {
+            return;
+        }
+
+        let peer = self.service.peer().clone();
+        let cache = Arc::clone(&self.tools);
+        let revalidating = Arc::clone(&self.revalidating);
+        let server = self.server_name.clone();
+        tokio::spawn(async move {
+            match discover_tools(&peer).await {
+                Ok(tools) => cache
+                    .write()
+                    .unwrap_or_else(|e| e.into_inner())
+                    .replace(tools),
+                Err(e) => warn!(server = %server, error = %e, "Background tool refresh failed"),
+            }
+            revalidating.store(false, Ordering::SeqCst);
+        });
+    }
+
+    /// Check whether a tool is safe to call more than once
+    ///
+    /// Returns `false` for unknown tools and for tools the server did not
+    /// annotate as read-only or idempotent.
+    pub fn is_idempotent(&self, name: &str) -> bool {
+        self.cache()
+            .tools
+            .iter()
+            .any(|t| t.name() == name && t.is_idempotent())
     }
 
     /// Refresh the tool list from the MCP server
     ///
     /// This re-queries the MCP server for available tools and updates
-    /// the internal tool cache. Useful when tools may be dynamically
-    /// added or removed on the server side.
+    /// the internal tool cache, whether or not it has expired. Useful when
+    /// tools may be dynamically added or removed on the server side.
     ///
     /// # Returns
     ///
@@ -255,7 +433,7 @@ impl McpBridge {
     /// # Example
     ///
     /// ```rust,ignore
-    /// let mut bridge = McpBridge::connect_stdio("npx server").await?;
+    /// let bridge = McpBridge::connect_stdio("npx server").await?;
     /// println!("Initial tools: {}", bridge.tool_count());
     ///
     /// // ... server may add/remove tools ...
@@ -263,39 +441,22 @@ impl McpBridge {
     /// let count = bridge.refresh_tools().await?;
     /// println!("Updated tools: {}", count);
     /// ```
-    pub async fn refresh_tools(&mut self) -> McpResult<usize> {
+    pub async fn refresh_tools(&self) -> McpResult<usize> {
         info!(server = %self.server_name, "Refreshing tool list from MCP server");
 
-        // Get the peer to make requests
-        let peer = self.service.peer();
-
-        // List all available tools
-        let mcp_tools = peer
-            .list_all_tools()
-            .await
-            .map_err(|e| McpError::ClientError(format!("Failed to list tools: {}", e)))?;
+        let tools = discover_tools(self.service.peer()).await?;
 
         info!(
             server = %self.server_name,
-            count = mcp_tools.len(),
+            count = tools.len(),
             "Tool list refreshed"
         );
 
-        // Create bridged tools
-        let tools: Vec<Arc<BridgedTool>> = mcp_tools
-            .into_iter()
-            .map(|tool_info| {
-                debug!(
-                    name = %tool_info.name,
-                    description = ?tool_info.description,
-                    "Creating bridged tool"
-                );
-                Arc::new(BridgedTool::new(tool_info, peer.clone()))
-            })
-            .collect();
-
         let count = tools.len();
-        self.tools = tools;
+        self.tools
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .replace(tools);
 
         Ok(count)
     }
@@ -304,21 +465,30 @@ impl McpBridge {
     ///
     /// Useful for displaying available tools without cloning all tool instances.
     pub fn tool_names(&self) -> Vec<String> {
-        self.tools.iter().map(|t| t.name.clone()).collect()
+        self.cache().tools.iter().map(|t| t.name.clone()).collect()
     }
 
     /// Get tool information including name, description, and schema
     ///
     /// Returns a vector of tuples containing (name, description, input_schema)
     /// for all available tools.
-    pub fn tool_info(&self) -> Vec<(String, String, &Value)> {
-        self.tools
+    pub fn tool_info(&self) -> Vec<(String, String, Value)> {
+        self.cache()
+            .tools
             .iter()
-            .map(|t| (t.name.clone(), t.description.clone(), t.input_schema()))
+            .map(|t| {
+                (
+                    t.name.clone(),
+                    t.description.clone(),
+                    t.input_schema.clone(),
+                )
+            })
             .collect()
     }
 
     /// Check if the connection to the MCP server is closed.
+    ///
+    /// A closed connection invalidates the cached tool list on its next use.
     pub fn is_closed(&self) -> bool {
         self.service.is_closed()
     }
@@ -359,6 +529,26 @@ impl McpBridge {
     }
 }
 
+/// List every tool the server exposes and wrap each as a [`BridgedTool`]
+async fn discover_tools(peer: &Peer<RoleClient>) -> McpResult<Vec<Arc<BridgedTool>>> {
+    let mcp_tools = peer
+        .list_all_tools()
+        .await
+        .map_err(|e| McpError::ClientError(format!("Failed to list tools: {}", e)))?;
+
+    Ok(mcp_tools
+        .into_iter()
+        .map(|tool_info| {
+            debug!(
+                name = %tool_info.name,
+                description = ?tool_info.description,
+                "Creating bridged tool"
+            );
+            Arc::new(BridgedTool::new(tool_info, peer.clone()))
+        })
+        .collect())
+}
+
 /// A tool from an external MCP server, adapted to Skreaver's Tool trait
 ///
 /// Each `BridgedTool` represents a tool exposed by an external MCP server.
@@ -368,16 +558,23 @@ pub struct BridgedTool {
     name: String,
     description: String,
     input_schema: Value,
+    idempotent: bool,
     peer: Peer<RoleClient>,
 }
 
 impl BridgedTool {
     /// Create a new bridged tool from MCP tool info
     fn new(info: McpToolInfo, peer: Peer<RoleClient>) -> Self {
+        // Read-only tools are idempotent regardless of the idempotent hint
+        let idempotent = info.annotations.as_ref().is_some_and(|annotations| {
+            annotations.read_only_hint == Some(true) || annotations.idempotent_hint == Some(true)
+        });
+
         Self {
             name: info.name.to_string(),
             description: info.description.map(|s| s.to_string()).unwrap_or_default(),
-            input_schema: Value::Object((*info.input_schema).clone()),
+            input_schema: translate_input_schema(&info.input_schema),
+            idempotent,
             peer,
         }
     }
@@ -392,9 +589,28 @@ impl BridgedTool {
         &self.description
     }
 
+    /// Whether the server annotated this tool as read-only or idempotent
+    ///
+    /// Only idempotent tools are safe to retry or hedge.
+    pub fn is_idempotent(&self) -> bool {
+        self.idempotent
+    }
+
+    /// Check `input` against the tool's input schema
+    ///
+    /// Inputs are checked before every call so that mismatches are reported
+    /// locally instead of as opaque errors from the MCP server.
+    pub fn validate_input(&self, input: &Value) -> McpResult<()> {
+        validate_json(&self.input_schema, input).map_err(|reason| McpError::SchemaMismatch {
+            tool: self.name.clone(),
+            reason,
+        })
+    }
+
     /// Call the tool asynchronously
     pub async fn call_async(&self, input: Value) -> McpResult<Value> {
         debug!(tool = %self.name, "Calling MCP tool");
+        self.validate_input(&input)?;
 
         // Build the call request (2025-11-25 spec: includes meta and task fields)
         let params = CallToolRequestParams {
@@ -437,6 +653,16 @@ impl Tool for BridgedTool {
     }
 
     fn call(&self, input: String) -> ExecutionResult {
+        self.call_with_meta(input, JsonObject::new())
+    }
+}
+
+impl BridgedTool {
+    /// Call the tool, sending `meta` as the request's `_meta`
+    ///
+    /// Used to pass request-scoped data such as the W3C `traceparent`
+    /// alongside the arguments. An empty map sends no `_meta`.
+    pub fn call_with_meta(&self, input: String, meta: JsonObject) -> ExecutionResult {
         debug!(tool = %self.name, "Bridged MCP tool called");
 
         // Parse input as JSON
@@ -453,6 +679,13 @@ impl Tool for BridgedTool {
             }
         };
 
+        if let Err(e) = self.validate_input(&input_value) {
+            warn!(tool = %self.name, error = %e, "Rejected input before calling MCP tool");
+            return ExecutionResult::Failure {
+                reason: e.to_failure_reason(),
+            };
+        }
+
         // The Tool trait is synchronous but MCP calls are async.
         // Use tokio::runtime::Handle to bridge them.
         let handle = match tokio::runtime::Handle::try_current() {
@@ -470,11 +703,12 @@ impl Tool for BridgedTool {
         // Clone what we need for the async block
         let name = self.name.clone();
         let peer = self.peer.clone();
+        let meta = (!meta.is_empty()).then_some(Meta(meta));
 
         // Execute the async call (2025-11-25 spec: CallToolRequestParams with meta/task)
         let result = handle.block_on(async move {
             let params = CallToolRequestParams {
-                meta: None,
+                meta,
                 name: Cow::Owned(name),
                 arguments: Some(input_value.as_object().cloned().unwrap_or_default()),
                 task: None,
@@ -493,10 +727,9 @@ impl Tool for BridgedTool {
                     }
                 } else {
                     let output = contents_to_json(&call_result.content);
-                    ExecutionResult::Success {
-                        output: serde_json::to_string(&output)
-                            .unwrap_or_else(|_| output.to_string()),
-                    }
+                    ExecutionResult::success(
+                        serde_json::to_string(&output).unwrap_or_else(|_| output.to_string()),
+                    )
                 }
             }
             Err(e) => {
@@ -510,6 +743,23 @@ impl Tool for BridgedTool {
     }
 }
 
+/// Translate an MCP tool input schema into a Skreaver tool input schema
+///
+/// MCP tools always take an object of named arguments, so the schema is made
+/// explicit about that even when the server leaves it implied. The `$schema`
+/// dialect marker is dropped since Skreaver schemas don't carry one.
+fn translate_input_schema(schema: &rmcp::model::JsonObject) -> Value {
+    let mut schema = schema.clone();
+    schema.remove("$schema");
+    schema
+        .entry("type")
+        .or_insert_with(|| Value::String("object".to_string()));
+    schema
+        .entry("properties")
+        .or_insert_with(|| Value::Object(Default::default()));
+    Value::Object(schema)
+}
+
 /// Extract text content from MCP Content array
 fn extract_text_from_contents(contents: &[Content]) -> String {
     contents
@@ -605,6 +855,29 @@ mod tests {
         assert_eq!(result, Value::String("plain text".to_string()));
     }
 
+    #[test]
+    fn test_translate_input_schema_makes_object_type_explicit() {
+        let schema: rmcp::model::JsonObject = serde_json::from_value(serde_json::json!({
+            "$schema": "http://json-schema.org/draft-07/schema#",
+            "required": ["city"],
+            "properties": {"city": {"type": "string"}}
+        }))
+        .unwrap();
+
+        let translated = translate_input_schema(&schema);
+
+        assert_eq!(
+            translated,
+            serde_json::json!({
+                "type": "object",
+                "required": ["city"],
+                "properties": {"city": {"type": "string"}}
+            })
+        );
+        assert!(validate_json(&translated, &serde_json::json!({"city": "Kyiv"})).is_ok());
+        assert!(validate_json(&translated, &serde_json::json!({"city": 7})).is_err());
+    }
+
     #[test]
     fn test_contents_to_json_empty() {
         let contents: Vec<Content> = vec![];
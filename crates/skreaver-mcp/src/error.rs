@@ -25,6 +25,10 @@ pub enum McpError {
     #[error("Invalid tool parameters: {0}")]
     InvalidParameters(String),
 
+    /// Tool input does not match the tool's input schema
+    #[error("Input for tool '{tool}' does not match its schema: {reason}")]
+    SchemaMismatch { tool: String, reason: String },
+
     /// Serialization error
     #[error("Serialization error: {0}")]
     SerializationError(#[from] serde_json::Error),
@@ -112,6 +116,9 @@ impl McpError {
             McpError::InvalidParameters(msg) => FailureReason::InvalidInput {
                 message: msg.clone(),
             },
+            McpError::SchemaMismatch { .. } => FailureReason::InvalidInput {
+                message: self.to_string(),
+            },
             McpError::SerializationError(e) => FailureReason::InvalidInput {
                 message: format!("JSON serialization error: {}", e),
             },
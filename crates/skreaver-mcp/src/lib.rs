@@ -77,4 +77,4 @@ pub use elicitation::{
 };
 
 #[cfg(feature = "client")]
-pub use bridge::McpBridge;
+pub use bridge::{McpBridge, ToolCacheConfig};
@@ -262,9 +262,7 @@ mod tests {
                 .and_then(|v| v.as_str())
                 .unwrap_or("default");
 
-            ExecutionResult::Success {
-                output: serde_json::json!({"echo": message}).to_string(),
-            }
+            ExecutionResult::success(serde_json::json!({"echo": message}).to_string())
         }
     }
 
@@ -13,6 +13,8 @@ use rmcp::{
 };
 use serde::{Deserialize, Serialize};
 use skreaver_mcp::McpBridge;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::time::Duration;
 use tokio::time::timeout;
 
@@ -38,6 +40,7 @@ struct CalculatorRequest {
 #[derive(Clone)]
 struct TestMcpServer {
     tool_router: ToolRouter<Self>,
+    calculator_calls: Arc<AtomicUsize>,
 }
 
 #[tool_router(router = tool_router)]
@@ -45,6 +48,7 @@ impl TestMcpServer {
     fn new() -> Self {
         Self {
             tool_router: Self::tool_router(),
+            calculator_calls: Arc::new(AtomicUsize::new(0)),
         }
     }
 
@@ -57,6 +61,7 @@ impl TestMcpServer {
     /// Calculator tool - performs basic math operations
     #[tool(name = "calculator", description = "Perform basic math operations")]
     async fn calculator(&self, request: Parameters<CalculatorRequest>) -> Result<String, String> {
+        self.calculator_calls.fetch_add(1, Ordering::SeqCst);
         let result = match request.0.operation.as_str() {
             "add" => request.0.a + request.0.b,
             "subtract" => request.0.a - request.0.b,
@@ -763,6 +768,73 @@ async fn test_tool_error_handling() {
     server_handle.abort();
 }
 
+/// Test that bridged tools expose the MCP input schema and enforce it locally
+#[tokio::test(flavor = "multi_thread")]
+async fn test_bridged_tool_rejects_schema_violation_before_calling_server() {
+    use skreaver_core::{ExecutionResult, FailureReason};
+
+    let (client_read, server_write) = tokio::io::duplex(4096);
+    let (server_read, client_write) = tokio::io::duplex(4096);
+
+    let server = TestMcpServer::new();
+    let calculator_calls = Arc::clone(&server.calculator_calls);
+    let server_transport =
+        rmcp::transport::async_rw::AsyncRwTransport::new(server_read, server_write);
+
+    let server_handle = tokio::spawn(async move {
+        let service = server.serve(server_transport).await;
+        if let Ok(service) = service {
+            let _ = service.waiting().await;
+        }
+    });
+
+    let client_transport =
+        rmcp::transport::async_rw::AsyncRwTransport::new(client_read, client_write);
+    let bridge = McpBridge::connect_transport("test-mcp-server", client_transport)
+        .await
+        .expect("Failed to connect bridge");
+    let tool = bridge.find_tool("calculator").expect("calculator tool");
+
+    let schema = tool.input_schema().expect("bridged tools expose a schema");
+    assert_eq!(schema["type"], "object");
+    assert_eq!(schema["properties"]["a"]["type"], "number");
+    let required: Vec<&str> = schema["required"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .filter_map(|v| v.as_str())
+        .collect();
+    assert!(required.contains(&"operation"));
+
+    let calculator = Arc::clone(&tool);
+    let result = tokio::task::spawn_blocking(move || {
+        calculator.call(r#"{"a": "ten", "b": 5, "operation": "add"}"#.to_string())
+    })
+    .await
+    .unwrap();
+    match result {
+        ExecutionResult::Failure {
+            reason: FailureReason::InvalidInput { message },
+        } => assert!(
+            message.contains("calculator") && message.contains("$.a"),
+            "unexpected message: {}",
+            message
+        ),
+        other => panic!("expected a schema mismatch, got {:?}", other),
+    }
+    assert_eq!(calculator_calls.load(Ordering::SeqCst), 0);
+
+    let result = tokio::task::spawn_blocking(move || {
+        tool.call(r#"{"a": 10, "b": 5, "operation": "add"}"#.to_string())
+    })
+    .await
+    .unwrap();
+    assert!(result.is_success(), "valid input should reach the server");
+    assert_eq!(calculator_calls.load(Ordering::SeqCst), 1);
+
+    server_handle.abort();
+}
+
 /// Test error mapping from McpError to FailureReason
 mod error_mapping {
     use skreaver_mcp::error::McpError;
@@ -820,3 +892,112 @@ mod error_mapping {
         }
     }
 }
+
+/// Test server that counts how often its tool list is requested
+#[derive(Clone)]
+struct CountingMcpServer {
+    inner: TestMcpServer,
+    list_calls: Arc<AtomicUsize>,
+}
+
+impl ServerHandler for CountingMcpServer {
+    fn get_info(&self) -> ServerInfo {
+        self.inner.get_info()
+    }
+
+    async fn list_tools(
+        &self,
+        request: Option<rmcp::model::PaginatedRequestParams>,
+        context: rmcp::service::RequestContext<rmcp::RoleServer>,
+    ) -> Result<rmcp::model::ListToolsResult, rmcp::ErrorData> {
+        self.list_calls.fetch_add(1, Ordering::SeqCst);
+        self.inner.list_tools(request, context).await
+    }
+
+    async fn call_tool(
+        &self,
+        request: rmcp::model::CallToolRequestParams,
+        context: rmcp::service::RequestContext<rmcp::RoleServer>,
+    ) -> Result<rmcp::model::CallToolResult, rmcp::ErrorData> {
+        self.inner.call_tool(request, context).await
+    }
+}
+
+/// Connect a bridge to a counting server, returning its list call counter
+async fn connect_counting_bridge() -> (McpBridge, Arc<AtomicUsize>, tokio::task::JoinHandle<()>) {
+    let (client_read, server_write) = tokio::io::duplex(4096);
+    let (server_read, client_write) = tokio::io::duplex(4096);
+
+    let list_calls = Arc::new(AtomicUsize::new(0));
+    let server = CountingMcpServer {
+        inner: TestMcpServer::new(),
+        list_calls: Arc::clone(&list_calls),
+    };
+    let server_transport =
+        rmcp::transport::async_rw::AsyncRwTransport::new(server_read, server_write);
+    let server_handle = tokio::spawn(async move {
+        if let Ok(service) = server.serve(server_transport).await {
+            let _ = service.waiting().await;
+        }
+    });
+
+    let client_transport =
+        rmcp::transport::async_rw::AsyncRwTransport::new(client_read, client_write);
+    let bridge = McpBridge::connect_transport("counting-mcp-server", client_transport)
+        .await
+        .expect("Failed to connect bridge");
+    (bridge, list_calls, server_handle)
+}
+
+/// Test that dispatches reuse the cached tool list until it is refreshed
+#[tokio::test]
+async fn test_cached_tool_list_is_reused_until_refreshed() {
+    let (bridge, list_calls, server_handle) = connect_counting_bridge().await;
+    assert_eq!(list_calls.load(Ordering::SeqCst), 1);
+
+    for name in ["echo", "calculator"] {
+        assert!(bridge.find_cached_tool(name).await.unwrap().is_some());
+    }
+    assert_eq!(
+        list_calls.load(Ordering::SeqCst),
+        1,
+        "fresh cache must not re-query the server"
+    );
+
+    assert_eq!(bridge.refresh_tools().await.unwrap(), 3);
+    assert_eq!(list_calls.load(Ordering::SeqCst), 2);
+
+    bridge.invalidate_tools();
+    assert_eq!(bridge.tool_count(), 3, "last list stays visible");
+    assert!(bridge.find_cached_tool("greet").await.unwrap().is_some());
+    assert_eq!(list_calls.load(Ordering::SeqCst), 3);
+    assert!(bridge.find_cached_tool("greet").await.unwrap().is_some());
+    assert_eq!(list_calls.load(Ordering::SeqCst), 3);
+
+    server_handle.abort();
+}
+
+/// Test that an expired cache is re-queried, in the background when stale
+/// lists may be served
+#[tokio::test]
+async fn test_expired_tool_cache_is_requeried() {
+    use skreaver_mcp::ToolCacheConfig;
+
+    let (bridge, list_calls, server_handle) = connect_counting_bridge().await;
+    let bridge = bridge.with_tool_cache(ToolCacheConfig::new(Duration::ZERO));
+    assert_eq!(bridge.cached_tools().await.unwrap().len(), 3);
+    assert_eq!(list_calls.load(Ordering::SeqCst), 2);
+
+    let bridge = bridge
+        .with_tool_cache(ToolCacheConfig::new(Duration::ZERO).with_stale_while_revalidate(true));
+    assert_eq!(bridge.cached_tools().await.unwrap().len(), 3);
+    timeout(Duration::from_secs(5), async {
+        while list_calls.load(Ordering::SeqCst) < 3 {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    })
+    .await
+    .expect("stale list should be refreshed in the background");
+
+    server_handle.abort();
+}
@@ -0,0 +1,345 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, RwLock};
+
+use skreaver_core::AgentId;
+use skreaver_core::error::{MemoryBackend, MemoryError, MemoryOperation};
+use skreaver_core::memory::{
+    DeletableMemory, MemoryKey, MemoryReader, MemoryUpdate, MemoryWriter, ScanableMemory,
+};
+use skreaver_core::security::SecurityContext;
+
+/// Access rules for a single memory key.
+///
+/// The owner may always read and write the key. Other agents need to be
+/// listed as readers or writers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyAcl {
+    owner: AgentId,
+    readers: HashSet<AgentId>,
+    writers: HashSet<AgentId>,
+}
+
+impl KeyAcl {
+    /// Create an ACL granting access only to `owner`.
+    pub fn new(owner: AgentId) -> Self {
+        Self {
+            owner,
+            readers: HashSet::new(),
+            writers: HashSet::new(),
+        }
+    }
+
+    /// Allow `agent` to read the key.
+    pub fn with_reader(mut self, agent: AgentId) -> Self {
+        self.readers.insert(agent);
+        self
+    }
+
+    /// Allow `agent` to write the key.
+    pub fn with_writer(mut self, agent: AgentId) -> Self {
+        self.writers.insert(agent);
+        self
+    }
+
+    /// The agent owning the key.
+    pub fn owner(&self) -> &AgentId {
+        &self.owner
+    }
+
+    /// Check whether `agent` may read the key.
+    pub fn can_read(&self, agent: &AgentId) -> bool {
+        &self.owner == agent || self.readers.contains(agent)
+    }
+
+    /// Check whether `agent` may write the key.
+    pub fn can_write(&self, agent: &AgentId) -> bool {
+        &self.owner == agent || self.writers.contains(agent)
+    }
+}
+
+/// Table of per-key ACLs shared by every agent using the same memory.
+///
+/// Keys without an entry are unprotected and open to all agents.
+#[derive(Debug, Default)]
+pub struct MemoryAcl {
+    rules: RwLock<HashMap<String, KeyAcl>>,
+}
+
+impl MemoryAcl {
+    /// Create an empty ACL table.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Restrict `key` with the given ACL, replacing any previous one.
+    pub fn protect(&self, key: &MemoryKey, acl: KeyAcl) {
+        self.write_rules().insert(key.as_str().to_string(), acl);
+    }
+
+    /// Remove the ACL for `key`, making it open again.
+    pub fn unprotect(&self, key: &MemoryKey) -> Option<KeyAcl> {
+        self.write_rules().remove(key.as_str())
+    }
+
+    /// Get the ACL for `key`, if it is protected.
+    pub fn get(&self, key: &MemoryKey) -> Option<KeyAcl> {
+        self.read_rules().get(key.as_str()).cloned()
+    }
+
+    /// Check whether `agent` may read `key`.
+    pub fn can_read(&self, agent: &AgentId, key: &MemoryKey) -> bool {
+        self.read_rules()
+            .get(key.as_str())
+            .is_none_or(|acl| acl.can_read(agent))
+    }
+
+    /// Check whether `agent` may write `key`.
+    pub fn can_write(&self, agent: &AgentId, key: &MemoryKey) -> bool {
+        self.read_rules()
+            .get(key.as_str())
+            .is_none_or(|acl| acl.can_write(agent))
+    }
+
+    fn read_rules(&self) -> std::sync::RwLockReadGuard<'_, HashMap<String, KeyAcl>> {
+        self.rules.read().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn write_rules(&self) -> std::sync::RwLockWriteGuard<'_, HashMap<String, KeyAcl>> {
+        self.rules.write().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// A memory wrapper enforcing per-key ACLs for one agent.
+///
+/// Each agent sharing a backend gets its own wrapper carrying its identity,
+/// while all wrappers share a single [`MemoryAcl`] table. Reads and writes of
+/// protected keys by agents not listed in the key's ACL fail with an
+/// access-denied [`MemoryError`] naming the wrapped backend.
+pub struct AccessControlledMemory<M> {
+    inner: M,
+    backend: MemoryBackend,
+    agent_id: AgentId,
+    acl: Arc<MemoryAcl>,
+}
+
+impl<M> AccessControlledMemory<M> {
+    /// Wrap `inner`, a `backend` memory, for the agent identified by the
+    /// security context.
+    pub fn new(
+        inner: M,
+        backend: MemoryBackend,
+        context: &SecurityContext,
+        acl: Arc<MemoryAcl>,
+    ) -> Self {
+        Self::for_agent(inner, backend, context.agent_id.clone(), acl)
+    }
+
+    /// Wrap `inner`, a `backend` memory, for the given agent.
+    pub fn for_agent(
+        inner: M,
+        backend: MemoryBackend,
+        agent_id: AgentId,
+        acl: Arc<MemoryAcl>,
+    ) -> Self {
+        Self {
+            inner,
+            backend,
+            agent_id,
+            acl,
+        }
+    }
+
+    /// The agent this wrapper acts as.
+    pub fn agent_id(&self) -> &AgentId {
+        &self.agent_id
+    }
+
+    /// The shared ACL table.
+    pub fn acl(&self) -> &Arc<MemoryAcl> {
+        &self.acl
+    }
+
+    /// Get an immutable reference to the underlying memory implementation.
+    pub fn inner(&self) -> &M {
+        &self.inner
+    }
+
+    /// Unwrap the underlying memory implementation.
+    pub fn into_inner(self) -> M {
+        self.inner
+    }
+
+    fn check_read(&self, key: &MemoryKey) -> Result<(), MemoryError> {
+        if self.acl.can_read(&self.agent_id, key) {
+            Ok(())
+        } else {
+            Err(self.denied(key, MemoryOperation::Load, "read"))
+        }
+    }
+
+    fn check_write(&self, key: &MemoryKey, operation: MemoryOperation) -> Result<(), MemoryError> {
+        if self.acl.can_write(&self.agent_id, key) {
+            Ok(())
+        } else {
+            Err(self.denied(key, operation, "write"))
+        }
+    }
+
+    fn denied(&self, key: &MemoryKey, operation: MemoryOperation, access: &str) -> MemoryError {
+        tracing::warn!(
+            agent_id = %self.agent_id,
+            key = %key,
+            "Denied {} access to protected memory key",
+            access
+        );
+        MemoryError::access_denied(
+            key.clone(),
+            operation,
+            self.backend,
+            format!("agent '{}' may not {} key '{}'", self.agent_id, access, key),
+        )
+    }
+}
+
+impl<M: MemoryReader> MemoryReader for AccessControlledMemory<M> {
+    fn load(&self, key: &MemoryKey) -> Result<Option<String>, MemoryError> {
+        self.check_read(key)?;
+        self.inner.load(key)
+    }
+
+    fn load_many(&self, keys: &[MemoryKey]) -> Result<Vec<Option<String>>, MemoryError> {
+        for key in keys {
+            self.check_read(key)?;
+        }
+        self.inner.load_many(keys)
+    }
+}
+
+impl<M: MemoryWriter> MemoryWriter for AccessControlledMemory<M> {
+    fn store(&mut self, update: MemoryUpdate) -> Result<(), MemoryError> {
+        self.check_write(&update.key, MemoryOperation::Store)?;
+        self.inner.store(update)
+    }
+
+    fn store_many(&mut self, updates: Vec<MemoryUpdate>) -> Result<(), MemoryError> {
+        for update in &updates {
+            self.check_write(&update.key, MemoryOperation::Store)?;
+        }
+        self.inner.store_many(updates)
+    }
+}
+
+impl<M: ScanableMemory> ScanableMemory for AccessControlledMemory<M> {
+    fn scan_prefix(&self, prefix: &str) -> Result<Vec<MemoryKey>, MemoryError> {
+        // Hide keys this agent is not allowed to read
+        Ok(self
+            .inner
+            .scan_prefix(prefix)?
+            .into_iter()
+            .filter(|key| self.acl.can_read(&self.agent_id, key))
+            .collect())
+    }
+}
+
+impl<M: DeletableMemory> DeletableMemory for AccessControlledMemory<M> {
+    fn delete(&mut self, key: &MemoryKey) -> Result<bool, MemoryError> {
+        self.check_write(key, MemoryOperation::Delete)?;
+        self.inner.delete(key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use skreaver_core::InMemoryMemory;
+
+    fn agent(id: &str) -> AgentId {
+        AgentId::new_unchecked(id)
+    }
+
+    fn key(s: &str) -> MemoryKey {
+        MemoryKey::new(s).unwrap()
+    }
+
+    #[test]
+    fn owner_succeeds_and_non_owner_is_denied() {
+        let acl = Arc::new(MemoryAcl::new());
+        acl.protect(&key("plan"), KeyAcl::new(agent("planner")));
+
+        let mut owner = AccessControlledMemory::for_agent(
+            InMemoryMemory::new(),
+            MemoryBackend::InMemory,
+            agent("planner"),
+            acl.clone(),
+        );
+        owner
+            .store(MemoryUpdate::new("plan", "step 1").unwrap())
+            .unwrap();
+        assert_eq!(owner.load(&key("plan")).unwrap().as_deref(), Some("step 1"));
+
+        let mut other = AccessControlledMemory::for_agent(
+            owner.into_inner(),
+            MemoryBackend::InMemory,
+            agent("worker"),
+            acl,
+        );
+        let read = other.load(&key("plan")).unwrap_err();
+        assert!(read.is_access_denied());
+        let write = other
+            .store(MemoryUpdate::new("plan", "hijacked").unwrap())
+            .unwrap_err();
+        assert!(write.is_access_denied());
+        assert!(other.scan_prefix("").unwrap().is_empty());
+        assert_eq!(
+            other.inner().load(&key("plan")).unwrap().as_deref(),
+            Some("step 1")
+        );
+    }
+
+    #[test]
+    fn unprotected_keys_and_granted_agents_have_access() {
+        let acl = Arc::new(MemoryAcl::new());
+        acl.protect(
+            &key("shared"),
+            KeyAcl::new(agent("owner")).with_reader(agent("reader")),
+        );
+
+        let mut reader = AccessControlledMemory::for_agent(
+            InMemoryMemory::new(),
+            MemoryBackend::InMemory,
+            agent("reader"),
+            acl,
+        );
+        reader
+            .store(MemoryUpdate::new("scratch", "open").unwrap())
+            .unwrap();
+        assert_eq!(
+            reader.load(&key("scratch")).unwrap().as_deref(),
+            Some("open")
+        );
+        assert_eq!(reader.load(&key("shared")).unwrap(), None);
+        assert!(
+            reader
+                .store(MemoryUpdate::new("shared", "x").unwrap())
+                .unwrap_err()
+                .is_access_denied()
+        );
+    }
+
+    #[test]
+    fn denied_error_names_the_wrapped_backend() {
+        let dir = tempfile::tempdir().unwrap();
+        let acl = Arc::new(MemoryAcl::new());
+        acl.protect(&key("plan"), KeyAcl::new(agent("planner")));
+
+        let other = AccessControlledMemory::for_agent(
+            crate::FileMemory::new(dir.path().join("memory.json")),
+            MemoryBackend::File,
+            agent("worker"),
+            acl,
+        );
+        let error = other.load(&key("plan")).unwrap_err();
+        assert!(error.is_access_denied());
+        assert_eq!(error.backend(), MemoryBackend::File);
+    }
+}
@@ -0,0 +1,413 @@
+//! Fallback memory backend for primary outages.
+//!
+//! When a networked primary backend (Redis, PostgreSQL) is down, agents that
+//! depend on it fail outright. [`FailoverMemory`] keeps them running in a
+//! degraded mode instead: reads that fail on the primary are served from a
+//! local fallback, and writes that fail are stored in the fallback and
+//! buffered for replay. Each write retries the replay first, so buffered
+//! writes reach the primary as soon as it is reachable again. Only transient
+//! failures (see [`is_transient`]) trigger the fallback; logical errors from
+//! the primary are returned unchanged.
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use skreaver_core::error::MemoryError;
+use skreaver_core::memory::{MemoryKey, MemoryReader, MemoryUpdate, MemoryWriter};
+
+use crate::retrying_memory::is_transient;
+
+/// How buffered writes are reconciled with the primary on recovery.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ConflictPolicy {
+    /// Buffered writes overwrite whatever the primary holds.
+    #[default]
+    FallbackWins,
+    /// A buffered write is discarded when the primary already holds a
+    /// different value for its key, for example one written by another
+    /// process during the outage.
+    PrimaryWins,
+}
+
+/// Snapshot of failover counters.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FailoverStats {
+    /// Times the primary failed while no writes were buffered.
+    pub failovers: u64,
+    /// Reads served from the fallback.
+    pub fallback_reads: u64,
+    /// Writes stored in the fallback and buffered for replay.
+    pub fallback_writes: u64,
+    /// Buffered writes applied to the primary after recovery.
+    pub replayed: u64,
+    /// Buffered writes discarded because the primary held a different value.
+    pub conflicts: u64,
+    /// Buffered writes the primary rejected with a non-transient error.
+    pub replay_failures: u64,
+    /// Writes currently waiting for replay.
+    pub pending: u64,
+}
+
+#[derive(Debug, Default)]
+struct FailoverCounters {
+    failovers: AtomicU64,
+    fallback_reads: AtomicU64,
+    fallback_writes: AtomicU64,
+    replayed: AtomicU64,
+    conflicts: AtomicU64,
+    replay_failures: AtomicU64,
+}
+
+/// A memory wrapper that falls back to a second backend while the primary is
+/// unavailable.
+///
+/// Buffered writes are kept in order, one per key, holding the latest value.
+/// Reads of a buffered key always come from the fallback, which has the
+/// newer value; other reads try the primary first. Keys are only written to
+/// the fallback while the primary is failing, so degraded reads of keys that
+/// were last written while the primary was healthy return `None`.
+///
+/// # Example
+///
+/// ```rust
+/// use skreaver_memory::{ConflictPolicy, FailoverMemory};
+/// use skreaver_core::{InMemoryMemory, MemoryReader, MemoryWriter, MemoryUpdate};
+///
+/// let mut memory = FailoverMemory::new(InMemoryMemory::new(), InMemoryMemory::new())
+///     .with_conflict_policy(ConflictPolicy::PrimaryWins);
+///
+/// memory.store(MemoryUpdate::new("status", "ready").unwrap()).unwrap();
+/// assert!(!memory.is_degraded());
+/// assert_eq!(memory.stats().fallback_writes, 0);
+/// ```
+pub struct FailoverMemory<P, F> {
+    primary: P,
+    fallback: F,
+    policy: ConflictPolicy,
+    pending: Vec<MemoryUpdate>,
+    counters: Arc<FailoverCounters>,
+}
+
+impl<P, F> FailoverMemory<P, F> {
+    /// Wrap a primary backend with a fallback, using
+    /// [`ConflictPolicy::FallbackWins`].
+    pub fn new(primary: P, fallback: F) -> Self {
+        Self {
+            primary,
+            fallback,
+            policy: ConflictPolicy::default(),
+            pending: Vec::new(),
+            counters: Arc::new(FailoverCounters::default()),
+        }
+    }
+
+    /// Set how buffered writes are reconciled with the primary on recovery.
+    pub fn with_conflict_policy(mut self, policy: ConflictPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Get the conflict policy.
+    pub fn conflict_policy(&self) -> ConflictPolicy {
+        self.policy
+    }
+
+    /// Check whether writes are buffered waiting for the primary.
+    pub fn is_degraded(&self) -> bool {
+        !self.pending.is_empty()
+    }
+
+    /// Keys of the writes waiting for replay, oldest first.
+    pub fn pending_keys(&self) -> impl Iterator<Item = &MemoryKey> {
+        self.pending.iter().map(|update| &update.key)
+    }
+
+    /// Current failover counters.
+    pub fn stats(&self) -> FailoverStats {
+        FailoverStats {
+            failovers: self.counters.failovers.load(Ordering::Relaxed),
+            fallback_reads: self.counters.fallback_reads.load(Ordering::Relaxed),
+            fallback_writes: self.counters.fallback_writes.load(Ordering::Relaxed),
+            replayed: self.counters.replayed.load(Ordering::Relaxed),
+            conflicts: self.counters.conflicts.load(Ordering::Relaxed),
+            replay_failures: self.counters.replay_failures.load(Ordering::Relaxed),
+            pending: self.pending.len() as u64,
+        }
+    }
+
+    /// Get an immutable reference to the primary backend.
+    pub fn primary(&self) -> &P {
+        &self.primary
+    }
+
+    /// Get a mutable reference to the primary backend.
+    pub fn primary_mut(&mut self) -> &mut P {
+        &mut self.primary
+    }
+
+    /// Get an immutable reference to the fallback backend.
+    pub fn fallback(&self) -> &F {
+        &self.fallback
+    }
+
+    /// Get a mutable reference to the fallback backend.
+    pub fn fallback_mut(&mut self) -> &mut F {
+        &mut self.fallback
+    }
+
+    fn is_pending(&self, key: &MemoryKey) -> bool {
+        self.pending.iter().any(|update| &update.key == key)
+    }
+
+    fn note_failure(&self, error: &MemoryError) {
+        if self.pending.is_empty() {
+            self.counters.failovers.fetch_add(1, Ordering::Relaxed);
+        }
+        tracing::warn!(error = %error, "Primary memory backend failed, using fallback");
+    }
+}
+
+impl<P: MemoryReader + MemoryWriter, F: MemoryWriter> FailoverMemory<P, F> {
+    /// Apply buffered writes to the primary, oldest first.
+    ///
+    /// Stops at the first transient failure and keeps the remaining writes
+    /// buffered. Returns the number of writes still pending. Writes call this
+    /// automatically, so it only needs to be called directly to recover
+    /// without writing.
+    pub fn replay_pending(&mut self) -> usize {
+        let mut applied = 0;
+        for update in &self.pending {
+            if self.policy == ConflictPolicy::PrimaryWins {
+                match self.primary.load(&update.key) {
+                    Ok(Some(current)) if current != update.value => {
+                        tracing::debug!(
+                            key = %update.key,
+                            "Discarding buffered write that conflicts with the primary"
+                        );
+                        self.counters.conflicts.fetch_add(1, Ordering::Relaxed);
+                        applied += 1;
+                        continue;
+                    }
+                    Ok(_) => {}
+                    Err(e) if is_transient(&e) => break,
+                    Err(_) => {}
+                }
+            }
+
+            match self.primary.store(update.clone()) {
+                Ok(()) => {
+                    self.counters.replayed.fetch_add(1, Ordering::Relaxed);
+                }
+                Err(e) if is_transient(&e) => break,
+                Err(e) => {
+                    tracing::warn!(
+                        key = %update.key,
+                        error = %e,
+                        "Primary rejected buffered write, dropping it"
+                    );
+                    self.counters
+                        .replay_failures
+                        .fetch_add(1, Ordering::Relaxed);
+                }
+            }
+            applied += 1;
+        }
+
+        self.pending.drain(..applied);
+        if applied > 0 && self.pending.is_empty() {
+            tracing::info!("Primary memory backend recovered, buffered writes replayed");
+        }
+        self.pending.len()
+    }
+
+    /// Store `updates` in the fallback and buffer them for replay.
+    fn buffer(&mut self, updates: Vec<MemoryUpdate>) -> Result<(), MemoryError> {
+        self.fallback.store_many(updates.clone())?;
+        self.counters
+            .fallback_writes
+            .fetch_add(updates.len() as u64, Ordering::Relaxed);
+        for update in updates {
+            self.pending.retain(|pending| pending.key != update.key);
+            self.pending.push(update);
+        }
+        Ok(())
+    }
+}
+
+impl<P: MemoryReader, F: MemoryReader> MemoryReader for FailoverMemory<P, F> {
+    fn load(&self, key: &MemoryKey) -> Result<Option<String>, MemoryError> {
+        if !self.is_pending(key) {
+            match self.primary.load(key) {
+                Err(e) if is_transient(&e) => self.note_failure(&e),
+                result => return result,
+            }
+        }
+        self.counters.fallback_reads.fetch_add(1, Ordering::Relaxed);
+        self.fallback.load(key)
+    }
+
+    fn load_many(&self, keys: &[MemoryKey]) -> Result<Vec<Option<String>>, MemoryError> {
+        if self.is_degraded() {
+            return keys.iter().map(|key| self.load(key)).collect();
+        }
+        match self.primary.load_many(keys) {
+            Err(e) if is_transient(&e) => self.note_failure(&e),
+            result => return result,
+        }
+        self.counters
+            .fallback_reads
+            .fetch_add(keys.len() as u64, Ordering::Relaxed);
+        self.fallback.load_many(keys)
+    }
+}
+
+impl<P: MemoryReader + MemoryWriter, F: MemoryWriter> MemoryWriter for FailoverMemory<P, F> {
+    fn store(&mut self, update: MemoryUpdate) -> Result<(), MemoryError> {
+        self.store_many(vec![update])
+    }
+
+    fn store_many(&mut self, updates: Vec<MemoryUpdate>) -> Result<(), MemoryError> {
+        // Writes must not overtake buffered ones, so they are buffered too
+        // until the replay catches up
+        if self.is_degraded() && self.replay_pending() > 0 {
+            return self.buffer(updates);
+        }
+        match self.primary.store_many(updates.clone()) {
+            Err(e) if is_transient(&e) => {
+                self.note_failure(&e);
+                self.buffer(updates)
+            }
+            result => result,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use skreaver_core::InMemoryMemory;
+    use skreaver_core::error::{MemoryBackend, MemoryOperation};
+    use std::sync::atomic::AtomicBool;
+
+    /// Backend that fails every operation while `down` is set
+    #[derive(Clone, Default)]
+    struct SwitchableMemory {
+        inner: Arc<std::sync::Mutex<InMemoryMemory>>,
+        down: Arc<AtomicBool>,
+    }
+
+    impl SwitchableMemory {
+        fn set_down(&self, down: bool) {
+            self.down.store(down, Ordering::SeqCst);
+        }
+
+        fn check(&self, operation: MemoryOperation) -> Result<(), MemoryError> {
+            if self.down.load(Ordering::SeqCst) {
+                return Err(MemoryError::network_error(
+                    operation,
+                    MemoryBackend::Redis,
+                    "connection refused".to_string(),
+                ));
+            }
+            Ok(())
+        }
+    }
+
+    impl MemoryReader for SwitchableMemory {
+        fn load(&self, key: &MemoryKey) -> Result<Option<String>, MemoryError> {
+            self.check(MemoryOperation::Load)?;
+            self.inner.lock().unwrap().load(key)
+        }
+    }
+
+    impl MemoryWriter for SwitchableMemory {
+        fn store(&mut self, update: MemoryUpdate) -> Result<(), MemoryError> {
+            self.check(MemoryOperation::Store)?;
+            self.inner.lock().unwrap().store(update)
+        }
+    }
+
+    fn key(name: &str) -> MemoryKey {
+        MemoryKey::new(name).unwrap()
+    }
+
+    fn update(name: &str, value: &str) -> MemoryUpdate {
+        MemoryUpdate::new(name, value).unwrap()
+    }
+
+    #[test]
+    fn test_primary_outage_uses_fallback_for_reads_and_writes() {
+        let primary = SwitchableMemory::default();
+        let mut fallback = InMemoryMemory::new();
+        fallback.store(update("cached", "local")).unwrap();
+        let mut memory = FailoverMemory::new(primary.clone(), fallback);
+
+        primary.set_down(true);
+        assert_eq!(
+            memory.load(&key("cached")).unwrap().as_deref(),
+            Some("local")
+        );
+
+        memory.store(update("status", "degraded")).unwrap();
+        assert!(memory.is_degraded());
+        assert_eq!(
+            memory.load(&key("status")).unwrap().as_deref(),
+            Some("degraded")
+        );
+        assert_eq!(
+            memory.stats(),
+            FailoverStats {
+                failovers: 2,
+                fallback_reads: 2,
+                fallback_writes: 1,
+                pending: 1,
+                ..FailoverStats::default()
+            }
+        );
+    }
+
+    #[test]
+    fn test_recovery_replays_buffered_writes_in_order() {
+        let primary = SwitchableMemory::default();
+        let mut memory = FailoverMemory::new(primary.clone(), InMemoryMemory::new());
+
+        primary.set_down(true);
+        memory.store(update("a", "1")).unwrap();
+        memory.store(update("b", "2")).unwrap();
+        memory.store(update("a", "3")).unwrap();
+        assert_eq!(
+            memory.pending_keys().cloned().collect::<Vec<_>>(),
+            vec![key("b"), key("a")]
+        );
+
+        primary.set_down(false);
+        memory.store(update("c", "4")).unwrap();
+
+        assert!(!memory.is_degraded());
+        assert_eq!(primary.load(&key("a")).unwrap().as_deref(), Some("3"));
+        assert_eq!(primary.load(&key("b")).unwrap().as_deref(), Some("2"));
+        assert_eq!(primary.load(&key("c")).unwrap().as_deref(), Some("4"));
+        assert_eq!(memory.stats().replayed, 2);
+        assert_eq!(memory.stats().fallback_writes, 3);
+    }
+
+    #[test]
+    fn test_primary_wins_discards_conflicting_buffered_writes() {
+        let primary = SwitchableMemory::default();
+        let mut memory = FailoverMemory::new(primary.clone(), InMemoryMemory::new())
+            .with_conflict_policy(ConflictPolicy::PrimaryWins);
+
+        primary.set_down(true);
+        memory.store(update("owner", "us")).unwrap();
+        memory.store(update("note", "kept")).unwrap();
+
+        primary.set_down(false);
+        primary.clone().store(update("owner", "them")).unwrap();
+        assert_eq!(memory.replay_pending(), 0);
+
+        assert_eq!(memory.load(&key("owner")).unwrap().as_deref(), Some("them"));
+        assert_eq!(memory.load(&key("note")).unwrap().as_deref(), Some("kept"));
+        assert_eq!(memory.stats().conflicts, 1);
+        assert_eq!(memory.stats().replayed, 1);
+    }
+}
@@ -1,10 +1,12 @@
 use std::collections::HashMap;
 use std::fs;
-use std::path::PathBuf;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
 
 use skreaver_core::error::MemoryError;
 use skreaver_core::memory::{
-    MemoryKey, MemoryReader, MemoryUpdate, MemoryWriter, SnapshotableMemory,
+    ClearableMemory, DeletableMemory, MemoryKey, MemoryReader, MemoryUpdate, MemoryWriter,
+    ScanableMemory, SnapshotableMemory,
 };
 
 /// A simple persistent key-value memory that syncs to a JSON file.
@@ -47,25 +49,98 @@ use skreaver_core::memory::{
 /// Multiple `FileMemory` instances pointing to the same file path will conflict
 /// and may corrupt data. Ensure that each file path is used by only one
 /// `FileMemory` instance at a time, or use proper file locking mechanisms.
+///
+/// # Compaction
+///
+/// The state file is pretty-printed JSON. [`compact`](Self::compact) rewrites
+/// it as minified JSON holding only the live keys, and
+/// [`with_autocompact`](Self::with_autocompact) does so once a write takes the
+/// file above a size threshold. Writes after that keep the minified format, so
+/// the file is not compacted again. Both go through a temporary file that is
+/// synced and then renamed over the state file, so a crash mid-compaction
+/// leaves either the old or the new file in place, never a partial one.
+///
+/// # Expiry
+///
+/// `FileMemory` ignores [`MemoryUpdate::ttl`]; keys are kept until deleted.
+/// Use [`store_with_ttl`](crate::store_with_ttl) with a
+/// [`MemoryTtlSweeper`](crate::MemoryTtlSweeper) to expire keys instead.
 pub struct FileMemory {
     path: PathBuf,
     cache: HashMap<String, String>,
+    autocompact_threshold: Option<u64>,
+    compacted: bool,
 }
 
 impl FileMemory {
     /// Initializes a new FileMemory and loads existing data if available.
     pub fn new(path: impl Into<PathBuf>) -> Self {
         let path = path.into();
-        let cache = Self::load_cache(&path).unwrap_or_default();
-        Self { path, cache }
+        let (cache, compacted) = Self::load_cache(&path).unwrap_or_default();
+        Self {
+            path,
+            cache,
+            autocompact_threshold: None,
+            compacted,
+        }
+    }
+
+    /// Like [`new`](Self::new), but compacts the file once a write leaves it
+    /// larger than `threshold_bytes`.
+    pub fn with_autocompact(path: impl Into<PathBuf>, threshold_bytes: u64) -> Self {
+        Self {
+            autocompact_threshold: Some(threshold_bytes),
+            ..Self::new(path)
+        }
+    }
+
+    /// Rewrite the state file so it holds only the live keys, minified.
+    ///
+    /// Later writes keep the compact format. The file is replaced atomically,
+    /// so the existing state survives a crash at any point.
+    pub fn compact(&mut self) -> io::Result<()> {
+        let before = fs::metadata(&self.path).map(|m| m.len()).ok();
+        let json = serde_json::to_vec(&self.cache).map_err(io::Error::other)?;
+        write_atomically(&self.path, &json)?;
+        self.compacted = true;
+
+        tracing::info!(
+            path = ?self.path,
+            entries = self.cache.len(),
+            before_bytes = ?before,
+            after_bytes = json.len(),
+            "Compacted memory file"
+        );
+        Ok(())
+    }
+
+    /// Compact if autocompaction is enabled and a write of `written_bytes`
+    /// took the file above its threshold
+    ///
+    /// Once compacted, every write already produces the compact form, so
+    /// compacting again would only rewrite the same bytes.
+    fn maybe_autocompact(&mut self, written_bytes: u64) {
+        let Some(threshold) = self.autocompact_threshold else {
+            return;
+        };
+        if !self.compacted
+            && written_bytes > threshold
+            && let Err(e) = self.compact()
+        {
+            // The write itself succeeded, so only the space saving is lost
+            tracing::warn!(path = ?self.path, error = %e, "Automatic compaction failed");
+        }
     }
 
-    fn load_cache(path: &PathBuf) -> Option<HashMap<String, String>> {
+    /// Load the cache, and whether the file is already in the compact format
+    fn load_cache(path: &PathBuf) -> Option<(HashMap<String, String>, bool)> {
         match fs::read_to_string(path) {
             Ok(contents) => match serde_json::from_str::<HashMap<String, String>>(&contents) {
                 Ok(cache) => {
                     tracing::debug!(path = ?path, entries = cache.len(), "Loaded memory cache");
-                    Some(cache)
+                    // Pretty-printed files span several lines once they hold a key
+                    let compacted = !cache.is_empty() && !contents.contains('\n');
+                    Some((cache, compacted))
                 }
                 Err(e) => {
                     tracing::error!(
@@ -96,8 +171,13 @@ impl FileMemory {
         }
     }
 
-    fn persist(&self) -> Result<(), MemoryError> {
-        let json = serde_json::to_string_pretty(&self.cache).map_err(|e| {
+    fn persist(&mut self) -> Result<(), MemoryError> {
+        let json = if self.compacted {
+            serde_json::to_string(&self.cache)
+        } else {
+            serde_json::to_string_pretty(&self.cache)
+        }
+        .map_err(|e| {
             tracing::error!(error = %e, "Failed to serialize memory cache");
             MemoryError::StoreFailed {
                 key: skreaver_core::memory::MemoryKeys::snapshot(),
@@ -108,45 +188,23 @@ impl FileMemory {
             }
         })?;
 
-        let tmp_path = self.path.with_extension("tmp");
-
-        fs::write(&tmp_path, json).map_err(|e| {
-            tracing::error!(
-                path = ?tmp_path,
-                error = %e,
-                "Failed to write memory cache to temporary file"
-            );
-            MemoryError::StoreFailed {
-                key: skreaver_core::memory::MemoryKeys::snapshot(),
-                backend: skreaver_core::error::MemoryBackend::File,
-                kind: skreaver_core::error::MemoryErrorKind::IoError {
-                    details: format!("Failed to write to {}: {}", tmp_path.display(), e),
-                },
-            }
-        })?;
-
-        fs::rename(&tmp_path, &self.path).map_err(|e| {
+        write_atomically(&self.path, json.as_bytes()).map_err(|e| {
             tracing::error!(
-                from = ?tmp_path,
-                to = ?self.path,
+                path = ?self.path,
                 error = %e,
-                "Failed to atomically rename memory cache"
+                "Failed to atomically write memory cache"
             );
             MemoryError::StoreFailed {
                 key: skreaver_core::memory::MemoryKeys::snapshot(),
                 backend: skreaver_core::error::MemoryBackend::File,
                 kind: skreaver_core::error::MemoryErrorKind::IoError {
-                    details: format!(
-                        "Failed to rename {} to {}: {}",
-                        tmp_path.display(),
-                        self.path.display(),
-                        e
-                    ),
+                    details: format!("Failed to write {}: {}", self.path.display(), e),
                 },
             }
         })?;
 
         tracing::debug!(path = ?self.path, entries = self.cache.len(), "Persisted memory cache");
+        self.maybe_autocompact(json.len() as u64);
         Ok(())
     }
 
@@ -280,6 +338,16 @@ impl FileMemory {
     }
 }
 
+/// Replace `path` with `contents` via a synced temporary file and a rename
+fn write_atomically(path: &Path, contents: &[u8]) -> io::Result<()> {
+    let tmp_path = path.with_extension("tmp");
+    let mut file = fs::File::create(&tmp_path)?;
+    file.write_all(contents)?;
+    file.sync_all()?;
+    drop(file);
+    fs::rename(&tmp_path, path)
+}
+
 /// Result of a backup cleanup operation
 ///
 /// Tracks how many backup files were successfully removed vs failed to be removed,
@@ -338,6 +406,48 @@ impl MemoryWriter for FileMemory {
     }
 }
 
+impl ScanableMemory for FileMemory {
+    fn scan_prefix(&self, prefix: &str) -> Result<Vec<MemoryKey>, MemoryError> {
+        self.cache
+            .keys()
+            .filter(|key| key.starts_with(prefix))
+            .map(|key| {
+                MemoryKey::new(key).map_err(|e| MemoryError::OperationFailed {
+                    operation: skreaver_core::error::MemoryOperation::List,
+                    backend: skreaver_core::error::MemoryBackend::File,
+                    kind: skreaver_core::error::MemoryErrorKind::InvalidKey {
+                        validation_error: format!("Invalid key '{}': {}", key, e),
+                    },
+                })
+            })
+            .collect()
+    }
+}
+
+impl ClearableMemory for FileMemory {
+    fn clear(&mut self) -> Result<(), MemoryError> {
+        let old_cache = std::mem::take(&mut self.cache);
+        if let Err(e) = self.persist() {
+            self.cache = old_cache;
+            return Err(e);
+        }
+        Ok(())
+    }
+}
+
+impl DeletableMemory for FileMemory {
+    fn delete(&mut self, key: &MemoryKey) -> Result<bool, MemoryError> {
+        let Some(old_value) = self.cache.remove(key.as_str()) else {
+            return Ok(false);
+        };
+        if let Err(e) = self.persist() {
+            self.cache.insert(key.as_str().to_string(), old_value);
+            return Err(e);
+        }
+        Ok(true)
+    }
+}
+
 impl Default for FileMemory {
     fn default() -> Self {
         Self::new(std::env::temp_dir().join("skreaver_temp_memory.json"))
@@ -399,3 +509,96 @@ impl SnapshotableMemory for FileMemory {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn update(key: &str, value: &str) -> MemoryUpdate {
+        MemoryUpdate::new(key, value).unwrap()
+    }
+
+    #[test]
+    fn test_compact_keeps_live_keys_and_shrinks_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("memory.json");
+        let mut memory = FileMemory::new(&path);
+
+        memory
+            .store_many(
+                (0..20)
+                    .map(|i| update(&format!("key{i}"), "value"))
+                    .collect(),
+            )
+            .unwrap();
+        for i in 1..20 {
+            memory
+                .delete(&MemoryKey::new(&format!("key{i}")).unwrap())
+                .unwrap();
+        }
+        let before = fs::metadata(&path).unwrap().len();
+
+        memory.compact().unwrap();
+
+        assert!(fs::metadata(&path).unwrap().len() < before);
+        assert!(!path.with_extension("tmp").exists());
+        let reopened = FileMemory::new(&path);
+        assert_eq!(
+            reopened.load(&MemoryKey::new("key0").unwrap()).unwrap(),
+            Some("value".to_string())
+        );
+        assert_eq!(reopened.scan_prefix("key").unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_autocompact_minifies_once_threshold_is_exceeded() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("memory.json");
+        let mut memory = FileMemory::with_autocompact(&path, 64);
+
+        memory.store(update("a", "1")).unwrap();
+        assert!(fs::read_to_string(&path).unwrap().contains('\n'));
+
+        memory
+            .store_many(
+                (0..10)
+                    .map(|i| update(&format!("key{i}"), "value"))
+                    .collect(),
+            )
+            .unwrap();
+        let contents = fs::read_to_string(&path).unwrap();
+        assert!(!contents.contains('\n'));
+
+        // Later writes stay minified
+        memory.store(update("b", "2")).unwrap();
+        let contents = fs::read_to_string(&path).unwrap();
+        assert!(!contents.contains('\n'));
+        assert_eq!(
+            serde_json::from_str::<HashMap<String, String>>(&contents)
+                .unwrap()
+                .len(),
+            12
+        );
+    }
+
+    #[test]
+    fn test_compacted_file_stays_minified_after_reopening() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("memory.json");
+        let mut memory = FileMemory::new(&path);
+        memory.store(update("a", "1")).unwrap();
+        memory.compact().unwrap();
+        drop(memory);
+
+        let mut reopened = FileMemory::with_autocompact(&path, u64::MAX);
+        reopened.store(update("b", "2")).unwrap();
+        let contents = fs::read_to_string(&path).unwrap();
+        assert!(!contents.contains('\n'));
+        assert_eq!(
+            serde_json::from_str::<HashMap<String, String>>(&contents)
+                .unwrap()
+                .len(),
+            2
+        );
+    }
+}
@@ -0,0 +1,208 @@
+//! Hash-tag-aware key layout for sharded backends.
+//!
+//! Redis Cluster assigns every key to one of 16384 slots, hashing only the
+//! text inside the first `{...}` when a key contains one. [`KeyHashing`]
+//! decides how logical memory keys are laid out as physical keys so that
+//! unrelated keys spread across slots while keys that must take part in the
+//! same multi-key operation can still be pinned to one slot.
+//!
+//! Memory keys cannot contain braces, so co-location is requested with the
+//! [`HASH_TAG_DELIMITER`]: everything before the first `::` in a key is its
+//! explicit hash tag. `session-42::history` and `session-42::summary` always
+//! share a slot under [`KeyHashing::HashTagged`].
+
+/// Separates an explicit hash tag from the rest of a memory key.
+pub const HASH_TAG_DELIMITER: &str = "::";
+
+/// Number of hash slots in a Redis Cluster.
+pub const CLUSTER_SLOTS: u16 = 16384;
+
+/// How logical memory keys map onto physical backend keys.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum KeyHashing {
+    /// Store keys as `prefix:key`, exactly as written.
+    #[default]
+    None,
+    /// Store keys as `prefix:{tag}:key`.
+    ///
+    /// The tag is the key's explicit hash tag when it has one, otherwise a
+    /// short hash of the whole key, so sequential keys land on different
+    /// slots while tagged keys stay together. The backend prefix must not
+    /// contain braces itself, or Redis hashes the prefix instead.
+    HashTagged,
+}
+
+impl KeyHashing {
+    /// Build the physical key for `key` under an optional backend prefix.
+    ///
+    /// The layout is deterministic, so a read always resolves to the same
+    /// physical key as the write that preceded it.
+    pub fn apply(&self, prefix: Option<&str>, key: &str) -> String {
+        let tagged = match self {
+            Self::None => key.to_string(),
+            Self::HashTagged => format!("{{{}}}:{}", hash_tag(key), key),
+        };
+        match prefix {
+            Some(prefix) => format!("{}:{}", prefix, tagged),
+            None => tagged,
+        }
+    }
+
+    /// Recover the logical key from a physical key produced by [`apply`].
+    ///
+    /// Returns `None` for keys that do not follow this layout.
+    ///
+    /// [`apply`]: KeyHashing::apply
+    pub fn strip<'a>(&self, prefix: Option<&str>, raw: &'a str) -> Option<&'a str> {
+        let unprefixed = match prefix {
+            Some(prefix) => raw.strip_prefix(prefix)?.strip_prefix(':')?,
+            None => raw,
+        };
+        match self {
+            Self::None => Some(unprefixed),
+            Self::HashTagged => {
+                let rest = unprefixed.strip_prefix('{')?;
+                let (_, key) = rest.split_once("}:")?;
+                Some(key)
+            }
+        }
+    }
+
+    /// Glob matching every physical key whose logical key starts with
+    /// `escaped_prefix`, which must already be glob-escaped.
+    pub fn scan_pattern(&self, prefix: Option<&str>, escaped_prefix: &str) -> String {
+        let tag = match self {
+            Self::None => "",
+            Self::HashTagged => "{*}:",
+        };
+        match prefix {
+            Some(prefix) => format!("{}:{}{}*", prefix, tag, escaped_prefix),
+            None => format!("{}{}*", tag, escaped_prefix),
+        }
+    }
+}
+
+/// Hash tag used for `key` under [`KeyHashing::HashTagged`].
+///
+/// Keys with an explicit tag (`tag::rest`) use it verbatim; all other keys
+/// get a four-digit hex digest of the whole key.
+pub fn hash_tag(key: &str) -> String {
+    match key.split_once(HASH_TAG_DELIMITER) {
+        Some((tag, _)) if !tag.is_empty() => tag.to_string(),
+        _ => format!("{:04x}", crc16(key.as_bytes())),
+    }
+}
+
+/// Redis Cluster slot for a physical key.
+///
+/// Follows the cluster specification: only the contents of the first
+/// non-empty `{...}` are hashed when present.
+pub fn cluster_slot(key: &str) -> u16 {
+    let hashed = key
+        .find('{')
+        .and_then(|open| {
+            let rest = &key[open + 1..];
+            rest.find('}')
+                .filter(|&close| close > 0)
+                .map(|close| &rest[..close])
+        })
+        .unwrap_or(key);
+    crc16(hashed.as_bytes()) % CLUSTER_SLOTS
+}
+
+/// CRC16-CCITT (XMODEM), the checksum Redis Cluster uses for key slots.
+fn crc16(bytes: &[u8]) -> u16 {
+    let mut crc: u16 = 0;
+    for &byte in bytes {
+        crc ^= (byte as u16) << 8;
+        for _ in 0..8 {
+            crc = if crc & 0x8000 != 0 {
+                (crc << 1) ^ 0x1021
+            } else {
+                crc << 1
+            };
+        }
+    }
+    crc
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn cluster_slot_matches_redis_reference_values() {
+        // Values from the Redis Cluster specification and `CLUSTER KEYSLOT`
+        assert_eq!(crc16(b"123456789"), 0x31c3);
+        assert_eq!(cluster_slot("foo"), 12182);
+        assert_eq!(
+            cluster_slot("{user1000}.following"),
+            cluster_slot("user1000")
+        );
+        assert_eq!(
+            cluster_slot("foo{}{bar}"),
+            crc16(b"foo{}{bar}") % CLUSTER_SLOTS
+        );
+        assert_eq!(cluster_slot("foo{{bar}}zap"), cluster_slot("{bar"));
+    }
+
+    #[test]
+    fn plain_layout_round_trips() {
+        let hashing = KeyHashing::None;
+        let raw = hashing.apply(Some("app"), "agent_1:state");
+        assert_eq!(raw, "app:agent_1:state");
+        assert_eq!(hashing.strip(Some("app"), &raw), Some("agent_1:state"));
+        assert_eq!(hashing.apply(None, "k"), "k");
+    }
+
+    #[test]
+    fn hash_tagged_layout_round_trips() {
+        let hashing = KeyHashing::HashTagged;
+        for key in ["agent_1:state", "session-42::history", "x"] {
+            let raw = hashing.apply(Some("app"), key);
+            assert_eq!(raw, hashing.apply(Some("app"), key));
+            assert_eq!(hashing.strip(Some("app"), &raw), Some(key));
+            assert_eq!(hashing.strip(None, &hashing.apply(None, key)), Some(key));
+        }
+        assert_eq!(hashing.strip(Some("app"), "app:untagged"), None);
+        assert_eq!(hashing.strip(Some("app"), "other:{1}:key"), None);
+    }
+
+    #[test]
+    fn sequential_keys_spread_across_slots() {
+        let hashing = KeyHashing::HashTagged;
+        let slots: HashSet<u16> = (0..1000)
+            .map(|i| cluster_slot(&hashing.apply(Some("app"), &format!("agent-{i}:state"))))
+            .collect();
+        assert!(slots.len() > 900, "only {} distinct slots", slots.len());
+    }
+
+    #[test]
+    fn explicitly_tagged_keys_share_a_slot() {
+        let hashing = KeyHashing::HashTagged;
+        let slots: HashSet<u16> = ["history", "summary", "tools:last"]
+            .iter()
+            .map(|suffix| {
+                cluster_slot(&hashing.apply(Some("app"), &format!("session-42::{suffix}")))
+            })
+            .collect();
+        assert_eq!(slots.len(), 1);
+
+        let other = cluster_slot(&hashing.apply(Some("app"), "session-43::history"));
+        assert!(!slots.contains(&other));
+    }
+
+    #[test]
+    fn scan_pattern_covers_tagged_keys() {
+        assert_eq!(
+            KeyHashing::None.scan_pattern(Some("app"), "agent"),
+            "app:agent*"
+        );
+        assert_eq!(
+            KeyHashing::HashTagged.scan_pattern(Some("app"), "agent"),
+            "app:{*}:agent*"
+        );
+        assert_eq!(KeyHashing::HashTagged.scan_pattern(None, ""), "{*}:*");
+    }
+}
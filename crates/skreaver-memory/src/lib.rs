@@ -7,8 +7,18 @@
 //!
 //! - **[FileMemory]**: Persistent file-based storage with JSON serialization  
 //! - **[NamespacedMemory]**: Wrapper providing key namespacing for any backend
+//! - **[AccessControlledMemory]**: Wrapper enforcing per-key ACLs for shared memory
+//! - **[RetryingMemory]**: Wrapper retrying transient backend failures with backoff
+//! - **[FailoverMemory]**: Wrapper falling back to a second backend while the primary is down
 //! - **[RedisMemory]**: Redis-based distributed memory (requires `redis` feature)
 //!
+//! [MemoryTtlSweeper] expires keys written with [store_with_ttl] on any backend
+//! that supports scanning and deleting keys. [SnapshotScheduler] takes
+//! periodic snapshots of any snapshotable backend and rotates old ones out.
+//!
+//! [KeyHashing] lays keys out with Redis hash tags so sharded backends spread
+//! unrelated keys across slots while keeping explicitly tagged keys together.
+//!
 //! Note: `InMemoryMemory` is available in `skreaver-core` as the default implementation.
 //!
 //! ## Feature Flags
@@ -48,9 +58,33 @@ pub use admin::{
 mod file_memory;
 pub use file_memory::FileMemory;
 
+pub mod key_hashing;
+pub use key_hashing::KeyHashing;
+
 mod namespaced_memory;
 pub use namespaced_memory::NamespacedMemory;
 
+mod access_controlled_memory;
+pub use access_controlled_memory::{AccessControlledMemory, KeyAcl, MemoryAcl};
+
+mod retrying_memory;
+pub use retrying_memory::{MemoryRetryConfig, MemoryRetryStats, RetryingMemory, is_transient};
+
+mod failover_memory;
+pub use failover_memory::{ConflictPolicy, FailoverMemory, FailoverStats};
+
+pub mod snapshot_scheduler;
+pub use snapshot_scheduler::{
+    DirectorySnapshotStore, InMemorySnapshotStore, SnapshotAlert, SnapshotFailure,
+    SnapshotRetention, SnapshotScheduler, SnapshotSchedulerConfig, SnapshotSchedulerHandle,
+    SnapshotStats, SnapshotStore, StoredSnapshot,
+};
+
+pub mod ttl_sweeper;
+pub use ttl_sweeper::{
+    MemoryTtlSweeper, TtlSweepStats, TtlSweeperConfig, TtlSweeperHandle, store_with_ttl,
+};
+
 // Conditional memory backends
 #[cfg(feature = "redis")]
 pub mod redis;
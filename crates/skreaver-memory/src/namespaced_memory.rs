@@ -1,8 +1,12 @@
 use std::marker::PhantomData;
 
+use skreaver_core::InMemoryMemory;
 use skreaver_core::error::MemoryError;
+
+use crate::key_hashing::HASH_TAG_DELIMITER;
 use skreaver_core::memory::{
-    MemoryKey, MemoryReader, MemoryUpdate, MemoryWriter, SnapshotableMemory, TransactionalMemory,
+    MemoryKey, MemoryReader, MemoryUpdate, MemoryWriter, ScanableMemory, SnapshotableMemory,
+    TransactionalMemory,
 };
 
 /// A memory wrapper that adds namespacing to keys.
@@ -11,6 +15,7 @@ use skreaver_core::memory::{
 /// memory backend while maintaining isolation between their data.
 pub struct NamespacedMemory<M> {
     prefix: String,
+    separator: &'static str,
     inner: M,
     _phantom: PhantomData<M>,
 }
@@ -29,21 +34,44 @@ impl<M> NamespacedMemory<M> {
     pub fn new(prefix: impl Into<String>, inner: M) -> Self {
         Self {
             prefix: prefix.into(),
+            separator: ":",
             inner,
             _phantom: PhantomData,
         }
     }
 
+    /// Use the namespace as an explicit hash tag for every key.
+    ///
+    /// Keys are joined with [`HASH_TAG_DELIMITER`] instead of `:`, so a
+    /// backend using [`KeyHashing::HashTagged`] stores the whole namespace
+    /// in one cluster slot and multi-key operations on it stay valid.
+    ///
+    /// [`KeyHashing::HashTagged`]: crate::KeyHashing::HashTagged
+    pub fn colocated(mut self) -> Self {
+        self.separator = HASH_TAG_DELIMITER;
+        self
+    }
+
+    /// The prefix every wrapped key starts with, separator included.
+    fn namespace_prefix(&self) -> String {
+        format!("{}{}", self.prefix, self.separator)
+    }
+
+    /// List every key in this namespace.
+    ///
+    /// Keys are returned without the namespace prefix, as passed to
+    /// [`store`](MemoryWriter::store). Keys of other namespaces sharing the
+    /// backend are never returned.
+    pub fn scan(&self) -> Result<Vec<MemoryKey>, MemoryError>
+    where
+        M: ScanableMemory,
+    {
+        self.scan_prefix("")
+    }
+
     /// Wrap a key with the namespace prefix.
     fn wrap_key(&self, key: &MemoryKey) -> Result<MemoryKey, MemoryError> {
-        let wrapped_key_str = format!("{}:{}", self.prefix, key.as_str());
-        MemoryKey::new(&wrapped_key_str).map_err(|e| MemoryError::StoreFailed {
-            key: skreaver_core::memory::MemoryKeys::fallback_namespaced(),
-            backend: skreaver_core::error::MemoryBackend::InMemory,
-            kind: skreaver_core::error::MemoryErrorKind::InvalidKey {
-                validation_error: format!("Invalid namespaced key: {}", e),
-            },
-        })
+        wrap_key(&self.prefix, self.separator, key)
     }
 
     /// Get a mutable reference to the underlying memory implementation.
@@ -58,6 +86,72 @@ impl<M> NamespacedMemory<M> {
     pub fn inner(&self) -> &M {
         &self.inner
     }
+
+    /// Wrap another backend in the same namespace as this memory.
+    ///
+    /// The wrapper keeps this memory's prefix and separator, so the new
+    /// backend is read and written under the same keys.
+    pub fn with_inner<N>(&self, inner: N) -> NamespacedMemory<N> {
+        NamespacedMemory {
+            prefix: self.prefix.clone(),
+            separator: self.separator,
+            inner,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl NamespacedMemory<InMemoryMemory> {
+    /// Create an independent scratch copy of this namespace.
+    ///
+    /// Only keys under this namespace are copied; other namespaces sharing
+    /// the backend are left out of the copy entirely.
+    pub fn fork(&self) -> Self {
+        self.with_inner(self.inner.fork_prefix(&self.namespace_prefix()))
+    }
+}
+
+/// Prepend `prefix` and `separator` to a key.
+fn wrap_key(prefix: &str, separator: &str, key: &MemoryKey) -> Result<MemoryKey, MemoryError> {
+    let wrapped_key_str = format!("{}{}{}", prefix, separator, key.as_str());
+    MemoryKey::new(&wrapped_key_str).map_err(|e| MemoryError::StoreFailed {
+        key: skreaver_core::memory::MemoryKeys::fallback_namespaced(),
+        backend: skreaver_core::error::MemoryBackend::InMemory,
+        kind: skreaver_core::error::MemoryErrorKind::InvalidKey {
+            validation_error: format!("Invalid namespaced key: {}", e),
+        },
+    })
+}
+
+/// Namespaces the writes made through a backend's transaction writer.
+struct NamespacedWriter<'a> {
+    prefix: &'a str,
+    separator: &'static str,
+    inner: &'a mut dyn MemoryWriter,
+}
+
+impl NamespacedWriter<'_> {
+    fn wrap_update(&self, update: MemoryUpdate) -> Result<MemoryUpdate, MemoryError> {
+        Ok(MemoryUpdate {
+            key: wrap_key(self.prefix, self.separator, &update.key)?,
+            ..update
+        })
+    }
+}
+
+impl MemoryWriter for NamespacedWriter<'_> {
+    fn store(&mut self, update: MemoryUpdate) -> Result<(), MemoryError> {
+        let wrapped_update = self.wrap_update(update)?;
+        self.inner.store(wrapped_update)
+    }
+
+    fn store_many(&mut self, updates: Vec<MemoryUpdate>) -> Result<(), MemoryError> {
+        let wrapped_updates: Result<Vec<_>, _> = updates
+            .into_iter()
+            .map(|update| self.wrap_update(update))
+            .collect();
+        self.inner.store_many(wrapped_updates?)
+    }
 }
 
 impl<M: MemoryReader> MemoryReader for NamespacedMemory<M> {
@@ -78,7 +172,7 @@ impl<M: MemoryWriter> MemoryWriter for NamespacedMemory<M> {
         let wrapped_key = self.wrap_key(&update.key)?;
         let wrapped_update = MemoryUpdate {
             key: wrapped_key,
-            value: update.value,
+            ..update
         };
         self.inner.store(wrapped_update)
     }
@@ -90,7 +184,7 @@ impl<M: MemoryWriter> MemoryWriter for NamespacedMemory<M> {
                 let wrapped_key = self.wrap_key(&update.key)?;
                 Ok(MemoryUpdate {
                     key: wrapped_key,
-                    value: update.value,
+                    ..update
                 })
             })
             .collect();
@@ -98,12 +192,37 @@ impl<M: MemoryWriter> MemoryWriter for NamespacedMemory<M> {
     }
 }
 
+impl<M: ScanableMemory> ScanableMemory for NamespacedMemory<M> {
+    fn scan_prefix(&self, prefix: &str) -> Result<Vec<MemoryKey>, MemoryError> {
+        let namespace = self.namespace_prefix();
+        let keys = self
+            .inner
+            .scan_prefix(&format!("{}{}", namespace, prefix))?;
+        // Backends may match loosely (e.g. case-insensitive LIKE), so check again
+        Ok(keys
+            .iter()
+            .filter_map(|key| key.as_str().strip_prefix(&namespace))
+            .filter(|key| key.starts_with(prefix))
+            .filter_map(|key| MemoryKey::new(key).ok())
+            .collect())
+    }
+}
+
 impl<M: TransactionalMemory> TransactionalMemory for NamespacedMemory<M> {
     fn transaction<F, R>(&mut self, f: F) -> Result<R, skreaver_core::error::TransactionError>
     where
         F: FnOnce(&mut dyn MemoryWriter) -> Result<R, skreaver_core::error::TransactionError>,
     {
-        self.inner.transaction(f)
+        // Every write in the transaction goes to this namespace; the backend
+        // still commits or rolls back the whole batch
+        let (prefix, separator) = (self.prefix.as_str(), self.separator);
+        self.inner.transaction(|writer| {
+            f(&mut NamespacedWriter {
+                prefix,
+                separator,
+                inner: writer,
+            })
+        })
     }
 }
 
@@ -116,3 +235,152 @@ impl<M: SnapshotableMemory> SnapshotableMemory for NamespacedMemory<M> {
         self.inner.restore(snapshot)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::key_hashing::{KeyHashing, cluster_slot};
+
+    fn slot(memory: &NamespacedMemory<InMemoryMemory>, key: &str) -> u16 {
+        let wrapped = memory.wrap_key(&MemoryKey::new(key).unwrap()).unwrap();
+        cluster_slot(&KeyHashing::HashTagged.apply(Some("app"), wrapped.as_str()))
+    }
+
+    #[test]
+    fn colocated_namespace_shares_one_slot() {
+        let memory = NamespacedMemory::new("agent_1", InMemoryMemory::new()).colocated();
+        let first = slot(&memory, "history");
+        assert!(
+            ["summary", "tools:last", "x"]
+                .iter()
+                .all(|k| slot(&memory, k) == first)
+        );
+    }
+
+    #[test]
+    fn plain_namespace_spreads_keys() {
+        let memory = NamespacedMemory::new("agent_1", InMemoryMemory::new());
+        let slots: std::collections::HashSet<u16> = (0..100)
+            .map(|i| slot(&memory, &format!("step-{i}")))
+            .collect();
+        assert!(slots.len() > 90);
+    }
+
+    #[test]
+    fn colocated_reads_find_writes() {
+        let mut memory = NamespacedMemory::new("agent_1", InMemoryMemory::new()).colocated();
+        let key = MemoryKey::new("history").unwrap();
+        memory
+            .store(MemoryUpdate::new("history", "v1").unwrap())
+            .unwrap();
+        assert_eq!(memory.load(&key).unwrap(), Some("v1".to_string()));
+        let raw = MemoryKey::new("agent_1::history").unwrap();
+        assert_eq!(memory.inner().load(&raw).unwrap(), Some("v1".to_string()));
+    }
+
+    #[test]
+    fn scan_lists_logical_keys_of_own_namespace_only() {
+        let shared = InMemoryMemory::new();
+        let mut agent_1 = NamespacedMemory::new("agent_1", shared.clone());
+        let mut agent_10 = NamespacedMemory::new("agent_10", shared.clone());
+        for key in ["history", "tools:last"] {
+            agent_1.store(MemoryUpdate::new(key, "v").unwrap()).unwrap();
+        }
+        agent_10
+            .store(MemoryUpdate::new("history", "other").unwrap())
+            .unwrap();
+
+        let mut keys: Vec<String> = agent_1
+            .scan()
+            .unwrap()
+            .iter()
+            .map(|k| k.as_str().to_string())
+            .collect();
+        keys.sort();
+        assert_eq!(keys, vec!["history", "tools:last"]);
+
+        let tools = agent_1.scan_prefix("tools:").unwrap();
+        assert_eq!(tools, vec![MemoryKey::new("tools:last").unwrap()]);
+    }
+
+    #[test]
+    fn colocated_scan_strips_hash_tag() {
+        let mut memory = NamespacedMemory::new("agent_1", InMemoryMemory::new()).colocated();
+        memory
+            .store(MemoryUpdate::new("summary", "tagged").unwrap())
+            .unwrap();
+        assert_eq!(
+            memory.scan().unwrap(),
+            vec![MemoryKey::new("summary").unwrap()]
+        );
+    }
+
+    #[test]
+    fn transaction_commits_namespaced_keys() {
+        let shared = InMemoryMemory::new();
+        let mut memory = NamespacedMemory::new("agent_1", shared.clone());
+        memory
+            .transaction(|writer| {
+                writer.store(MemoryUpdate::new("balance", "90").unwrap())?;
+                writer.store_many(vec![MemoryUpdate::new("ledger:last", "-10").unwrap()])?;
+                Ok(())
+            })
+            .unwrap();
+
+        let balance = MemoryKey::new("balance").unwrap();
+        assert_eq!(memory.load(&balance).unwrap(), Some("90".to_string()));
+        assert_eq!(shared.load(&balance).unwrap(), None);
+        let raw = MemoryKey::new("agent_1:ledger:last").unwrap();
+        assert_eq!(shared.load(&raw).unwrap(), Some("-10".to_string()));
+    }
+
+    #[test]
+    fn transaction_rollback_leaves_no_partial_writes() {
+        let shared = InMemoryMemory::new();
+        let mut memory = NamespacedMemory::new("agent_1", shared.clone());
+        memory
+            .store(MemoryUpdate::new("balance", "100").unwrap())
+            .unwrap();
+
+        let result: Result<(), _> = memory.transaction(|writer| {
+            writer.store(MemoryUpdate::new("balance", "90").unwrap())?;
+            writer.store(MemoryUpdate::new("ledger:last", "-10").unwrap())?;
+            Err(skreaver_core::error::TransactionError::TransactionAborted {
+                reason: "insufficient funds".to_string(),
+            })
+        });
+
+        assert!(result.is_err());
+        let balance = MemoryKey::new("balance").unwrap();
+        assert_eq!(memory.load(&balance).unwrap(), Some("100".to_string()));
+        assert_eq!(
+            memory.scan().unwrap(),
+            vec![MemoryKey::new("balance").unwrap()]
+        );
+        assert_eq!(shared.scan_prefix("").unwrap().len(), 1);
+    }
+
+    #[test]
+    fn fork_keeps_the_namespace_and_leaves_others_behind() {
+        let shared = InMemoryMemory::new();
+        let mut memory = NamespacedMemory::new("agent_1", shared.clone()).colocated();
+        let mut other = NamespacedMemory::new("agent_2", shared.clone()).colocated();
+        memory
+            .store(MemoryUpdate::new("draft", "v1").unwrap())
+            .unwrap();
+        other
+            .store(MemoryUpdate::new("draft", "other").unwrap())
+            .unwrap();
+
+        let mut scratch = memory.fork();
+        assert_eq!(scratch.inner().scan_prefix("").unwrap().len(), 1);
+        scratch
+            .store(MemoryUpdate::new("draft", "v2").unwrap())
+            .unwrap();
+
+        let draft = MemoryKey::new("draft").unwrap();
+        assert_eq!(scratch.load(&draft).unwrap(), Some("v2".to_string()));
+        assert_eq!(memory.load(&draft).unwrap(), Some("v1".to_string()));
+        assert_eq!(slot(&scratch, "draft"), slot(&memory, "draft"));
+    }
+}
@@ -33,10 +33,11 @@ impl PostgresMigrationEngine {
     }
 
     fn default_migrations() -> Vec<PostgresMigration> {
-        vec![PostgresMigration {
-            version: 1,
-            description: "Initial PostgreSQL memory schema".to_string(),
-            up_sql: r#"
+        vec![
+            PostgresMigration {
+                version: 1,
+                description: "Initial PostgreSQL memory schema".to_string(),
+                up_sql: r#"
                 CREATE TABLE IF NOT EXISTS memory_entries (
                     key TEXT PRIMARY KEY,
                     value JSONB NOT NULL,
@@ -54,9 +55,24 @@ impl PostgresMigrationEngine {
                     description TEXT NOT NULL,
                     applied_at TIMESTAMP WITH TIME ZONE DEFAULT NOW()
                 );
-            "#.to_string(),
-            down_sql: Some("DROP TABLE IF EXISTS memory_entries CASCADE; DROP TABLE IF EXISTS schema_migrations CASCADE;".to_string()),
-        }]
+                "#.to_string(),
+                down_sql: Some("DROP TABLE IF EXISTS memory_entries CASCADE; DROP TABLE IF EXISTS schema_migrations CASCADE;".to_string()),
+            },
+            PostgresMigration {
+                version: 2,
+                description: "Add expiry timestamps to memory entries".to_string(),
+                up_sql: r#"
+                    ALTER TABLE memory_entries ADD COLUMN IF NOT EXISTS expires_at TIMESTAMP WITH TIME ZONE;
+
+                    CREATE INDEX IF NOT EXISTS idx_memory_expires_at ON memory_entries(expires_at);
+                "#
+                .to_string(),
+                down_sql: Some(
+                    "DROP INDEX IF EXISTS idx_memory_expires_at; ALTER TABLE memory_entries DROP COLUMN IF EXISTS expires_at;"
+                        .to_string(),
+                ),
+            },
+        ]
     }
 
     pub async fn migrate(
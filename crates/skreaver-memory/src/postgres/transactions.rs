@@ -6,6 +6,8 @@
 use skreaver_core::error::MemoryError;
 use skreaver_core::memory::{MemoryUpdate, MemoryWriter};
 
+use crate::postgres_memory::ttl_secs;
+
 /// Transactional wrapper for PostgreSQL operations with proper resource management
 pub struct PostgresTransactionalMemory<'a> {
     tx: Option<tokio_postgres::Transaction<'a>>,
@@ -36,16 +38,18 @@ impl<'a> PostgresTransactionalMemory<'a> {
 
                 tx.execute(
                     r#"
-                    INSERT INTO memory_entries (key, value, namespace, updated_at)
-                    VALUES ($1, $2, $3, NOW())
+                    INSERT INTO memory_entries (key, value, namespace, updated_at, expires_at)
+                    VALUES ($1, $2, $3, NOW(), NOW() + $4::float8 * INTERVAL '1 second')
                     ON CONFLICT (key) DO UPDATE SET
                         value = EXCLUDED.value,
-                        updated_at = NOW()
+                        updated_at = NOW(),
+                        expires_at = EXCLUDED.expires_at
                     "#,
                     &[
                         &namespaced_key,
                         &json_value,
                         &self.namespace.as_deref().unwrap_or(""),
+                        &ttl_secs(update),
                     ],
                 )
                 .await?;
@@ -12,7 +12,8 @@ use tokio_postgres::IsolationLevel;
 
 use skreaver_core::error::{MemoryError, TransactionError};
 use skreaver_core::memory::{
-    MemoryKey, MemoryReader, MemoryUpdate, MemoryWriter, SnapshotableMemory, TransactionalMemory,
+    MemoryKey, MemoryReader, MemoryUpdate, MemoryWriter, ScanableMemory, SnapshotableMemory,
+    TransactionalMemory,
 };
 
 // Import shared admin types
@@ -114,7 +115,7 @@ impl PostgresMemory {
 
         let row = conn
             .query_opt(
-                "SELECT value FROM memory_entries WHERE key = $1",
+                "SELECT value FROM memory_entries WHERE key = $1 AND (expires_at IS NULL OR expires_at > NOW())",
                 &[&namespaced_key],
             )
             .await
@@ -142,16 +143,18 @@ impl PostgresMemory {
 
         conn.execute(
             r#"
-                INSERT INTO memory_entries (key, value, namespace, updated_at)
-                VALUES ($1, $2, $3, NOW())
+                INSERT INTO memory_entries (key, value, namespace, updated_at, expires_at)
+                VALUES ($1, $2, $3, NOW(), NOW() + $4::float8 * INTERVAL '1 second')
                 ON CONFLICT (key) DO UPDATE SET
                     value = EXCLUDED.value,
-                    updated_at = NOW()
+                    updated_at = NOW(),
+                    expires_at = EXCLUDED.expires_at
                 "#,
             &[
                 &namespaced_key,
                 &update.value,
                 &self.namespace.as_deref().unwrap_or("").to_string(),
+                &ttl_secs(&update),
             ],
         )
         .await
@@ -166,6 +169,115 @@ impl PostgresMemory {
         Ok(())
     }
 
+    /// Async load many operation fetching all keys in a single query
+    pub async fn load_many_async(
+        &self,
+        keys: &[MemoryKey],
+    ) -> Result<Vec<Option<String>>, MemoryError> {
+        if keys.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let conn = self.pool.acquire().await?;
+        let namespaced_keys: Vec<String> = keys.iter().map(|k| self.namespaced_key(k)).collect();
+
+        let rows = conn
+            .query(
+                "SELECT key, value FROM memory_entries WHERE key = ANY($1) AND (expires_at IS NULL OR expires_at > NOW())",
+                &[&namespaced_keys],
+            )
+            .await
+            .map_err(|e| MemoryError::LoadFailed {
+                key: skreaver_core::memory::MemoryKeys::batch(),
+                backend: skreaver_core::error::MemoryBackend::Postgres,
+                kind: skreaver_core::error::MemoryErrorKind::IoError {
+                    details: format!("Database error: {}", e),
+                },
+            })?;
+
+        let found = rows
+            .into_iter()
+            .map(|row| (row.get::<_, String>(0), row.get::<_, String>(1)))
+            .collect();
+        Ok(in_key_order(&namespaced_keys, found))
+    }
+
+    /// Async store many operation writing all updates in a single statement
+    pub async fn store_many_async(&self, updates: Vec<MemoryUpdate>) -> Result<(), MemoryError> {
+        if updates.is_empty() {
+            return Ok(());
+        }
+
+        let conn = self.pool.acquire().await?;
+        let updates = last_update_per_key(updates);
+        let ttls: Vec<Option<f64>> = updates.iter().map(ttl_secs).collect();
+        let (keys, values): (Vec<String>, Vec<String>) = updates
+            .into_iter()
+            .map(|update| (self.namespaced_key(&update.key), update.value))
+            .unzip();
+
+        conn.execute(
+            r#"
+                INSERT INTO memory_entries (key, value, namespace, updated_at, expires_at)
+                SELECT key, value, $3, NOW(), NOW() + ttl * INTERVAL '1 second'
+                FROM UNNEST($1::text[], $2::text[], $4::float8[]) AS batch(key, value, ttl)
+                ON CONFLICT (key) DO UPDATE SET
+                    value = EXCLUDED.value,
+                    updated_at = NOW(),
+                    expires_at = EXCLUDED.expires_at
+                "#,
+            &[
+                &keys,
+                &values,
+                &self.namespace.as_deref().unwrap_or("").to_string(),
+                &ttls,
+            ],
+        )
+        .await
+        .map_err(|e| MemoryError::StoreFailed {
+            key: skreaver_core::memory::MemoryKeys::batch(),
+            backend: skreaver_core::error::MemoryBackend::Postgres,
+            kind: skreaver_core::error::MemoryErrorKind::IoError {
+                details: format!("Database error: {}", e),
+            },
+        })?;
+
+        Ok(())
+    }
+
+    /// Async scan listing keys in this namespace that start with `prefix`
+    pub async fn scan_prefix_async(&self, prefix: &str) -> Result<Vec<MemoryKey>, MemoryError> {
+        let conn = self.pool.acquire().await?;
+        let namespace = self
+            .namespace
+            .as_ref()
+            .map(|ns| format!("{}:", ns))
+            .unwrap_or_default();
+        let full_prefix = format!("{}{}", namespace, prefix);
+
+        let rows = conn
+            .query(
+                "SELECT key FROM memory_entries WHERE key LIKE $1 ESCAPE '\\' AND (expires_at IS NULL OR expires_at > NOW())",
+                &[&format!("{}%", escape_like(&full_prefix))],
+            )
+            .await
+            .map_err(|e| MemoryError::LoadFailed {
+                key: skreaver_core::memory::MemoryKeys::scan(),
+                backend: skreaver_core::error::MemoryBackend::Postgres,
+                kind: skreaver_core::error::MemoryErrorKind::IoError {
+                    details: format!("Database error: {}", e),
+                },
+            })?;
+
+        Ok(rows
+            .into_iter()
+            .filter_map(|row| {
+                let key: String = row.get(0);
+                MemoryKey::new(key.strip_prefix(&namespace)?).ok()
+            })
+            .collect())
+    }
+
     /// Get all data for snapshot operations
     ///
     /// SECURITY: Uses parameterized queries to prevent SQL injection (CRITICAL-1 fix)
@@ -177,7 +289,7 @@ impl PostgresMemory {
         let namespace_value = self.namespace.as_deref().unwrap_or("");
         let rows = conn
             .query(
-                "SELECT key, value FROM memory_entries WHERE namespace = $1",
+                "SELECT key, value FROM memory_entries WHERE namespace = $1 AND (expires_at IS NULL OR expires_at > NOW())",
                 &[&namespace_value],
             )
             .await
@@ -235,6 +347,50 @@ impl PostgresMemory {
     }
 }
 
+/// Arrange batch query results in the order of the requested keys
+fn in_key_order(
+    keys: &[String],
+    found: std::collections::HashMap<String, String>,
+) -> Vec<Option<String>> {
+    keys.iter().map(|key| found.get(key).cloned()).collect()
+}
+
+/// Escape SQL `LIKE` wildcards so `pattern` matches literally with `ESCAPE '\'`
+fn escape_like(pattern: &str) -> String {
+    let mut escaped = String::with_capacity(pattern.len());
+    for c in pattern.chars() {
+        if matches!(c, '%' | '_' | '\\') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// TTL of an update in seconds, bound as `float8` so that `NULL` keeps the key forever
+pub(crate) fn ttl_secs(update: &MemoryUpdate) -> Option<f64> {
+    update.ttl.map(|ttl| ttl.as_secs_f64())
+}
+
+/// Collapse repeated keys to their last update, keeping first-seen order
+///
+/// A single `INSERT ... ON CONFLICT` cannot touch the same row twice, and
+/// applying updates in order means the last one wins anyway.
+fn last_update_per_key(updates: Vec<MemoryUpdate>) -> Vec<MemoryUpdate> {
+    let mut positions = std::collections::HashMap::new();
+    let mut unique: Vec<MemoryUpdate> = Vec::with_capacity(updates.len());
+    for update in updates {
+        match positions.get(update.key.as_str()) {
+            Some(&i) => unique[i] = update,
+            None => {
+                positions.insert(update.key.as_str().to_string(), unique.len());
+                unique.push(update);
+            }
+        }
+    }
+    unique
+}
+
 impl MemoryReader for PostgresMemory {
     fn load(&self, key: &MemoryKey) -> Result<Option<String>, MemoryError> {
         // Block on async operation
@@ -249,13 +405,15 @@ impl MemoryReader for PostgresMemory {
 
         // Block on async operation
         let rt = tokio::runtime::Handle::current();
-        rt.block_on(async {
-            let mut results = Vec::with_capacity(keys.len());
-            for key in keys {
-                results.push(self.load_async(key).await?);
-            }
-            Ok(results)
-        })
+        rt.block_on(self.load_many_async(keys))
+    }
+}
+
+impl ScanableMemory for PostgresMemory {
+    fn scan_prefix(&self, prefix: &str) -> Result<Vec<MemoryKey>, MemoryError> {
+        // Block on async operation
+        let rt = tokio::runtime::Handle::current();
+        rt.block_on(self.scan_prefix_async(prefix))
     }
 }
 
@@ -278,12 +436,7 @@ impl MemoryWriter for PostgresMemory {
     fn store_many(&mut self, updates: Vec<MemoryUpdate>) -> Result<(), MemoryError> {
         // Block on async operation
         let rt = tokio::runtime::Handle::current();
-        rt.block_on(async {
-            for update in updates {
-                self.store_async(update).await?;
-            }
-            Ok(())
-        })
+        rt.block_on(self.store_many_async(updates))
     }
 }
 
@@ -393,7 +546,10 @@ impl MemoryAdmin for PostgresMemory {
 
             // Query all entries
             let rows = conn
-                .query("SELECT key, value FROM memory_entries ORDER BY key", &[])
+                .query(
+                    "SELECT key, value FROM memory_entries WHERE expires_at IS NULL OR expires_at > NOW() ORDER BY key",
+                    &[],
+                )
                 .await
                 .map_err(|e| MemoryError::SnapshotFailed {
                     backend: skreaver_core::error::MemoryBackend::Postgres,
@@ -655,6 +811,44 @@ mod tests {
         assert_eq!(config.password, Some("pass".to_string()));
     }
 
+    #[test]
+    fn test_like_pattern_escapes_wildcards() {
+        assert_eq!(escape_like("agent_1:50%"), "agent\\_1:50\\%");
+        assert_eq!(escape_like("a\\b"), "a\\\\b");
+    }
+
+    #[test]
+    fn test_batch_results_follow_key_order() {
+        let keys: Vec<String> = ["b", "missing", "a", "b"].map(String::from).to_vec();
+        let found = [("a", "1"), ("b", "2")]
+            .into_iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+
+        assert_eq!(
+            in_key_order(&keys, found),
+            vec![Some("2".into()), None, Some("1".into()), Some("2".into())]
+        );
+    }
+
+    #[test]
+    fn test_batch_store_keeps_last_update_per_key() {
+        let updates = vec![
+            MemoryUpdate::new("a", "1").unwrap(),
+            MemoryUpdate::new("b", "2").unwrap(),
+            MemoryUpdate::new("a", "3").unwrap(),
+        ];
+
+        let unique: Vec<(String, String)> = last_update_per_key(updates)
+            .into_iter()
+            .map(|u| (u.key.as_str().to_string(), u.value))
+            .collect();
+        assert_eq!(
+            unique,
+            vec![("a".into(), "3".into()), ("b".into(), "2".into())]
+        );
+    }
+
     // Additional tests would require PostgreSQL instance
     // We'll add integration tests later
 }
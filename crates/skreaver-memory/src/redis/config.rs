@@ -4,6 +4,7 @@
 //! phantom types and the type system to prevent invalid configurations
 //! at compile time rather than runtime.
 
+use crate::key_hashing::KeyHashing;
 use skreaver_core::error::MemoryError;
 use std::time::Duration;
 
@@ -179,6 +180,8 @@ pub struct RedisConfigBuilder {
     pub database: DatabaseId,
     /// Key prefix for namespace isolation
     pub key_prefix: Option<NonEmptyString>,
+    /// Physical key layout used to spread keys across cluster slots
+    pub key_hashing: KeyHashing,
 }
 
 /// Valid Redis configuration (guaranteed to have all required fields)
@@ -206,6 +209,8 @@ pub struct ValidRedisConfig {
     pub database: DatabaseId,
     /// Key prefix for namespace isolation
     pub key_prefix: Option<NonEmptyString>,
+    /// Physical key layout used to spread keys across cluster slots
+    pub key_hashing: KeyHashing,
 }
 
 impl Default for RedisConfigBuilder {
@@ -222,6 +227,7 @@ impl Default for RedisConfigBuilder {
             tls: false,
             database: DatabaseId::default(),
             key_prefix: None,
+            key_hashing: KeyHashing::None,
         }
     }
 }
@@ -315,6 +321,12 @@ impl RedisConfigBuilder {
         self
     }
 
+    /// Set key hashing scheme
+    pub fn with_key_hashing(mut self, hashing: KeyHashing) -> Self {
+        self.key_hashing = hashing;
+        self
+    }
+
     /// Set connection timeout
     pub fn with_connect_timeout(mut self, timeout: Duration) -> Self {
         self.connect_timeout = timeout;
@@ -367,6 +379,7 @@ impl RedisConfigBuilder {
             tls: self.tls,
             database: self.database,
             key_prefix: self.key_prefix,
+            key_hashing: self.key_hashing,
         })
     }
 }
@@ -392,6 +405,11 @@ impl ValidRedisConfig {
         self.key_prefix.as_ref().map(|p| p.as_str())
     }
 
+    /// Get key hashing scheme
+    pub fn key_hashing(&self) -> KeyHashing {
+        self.key_hashing
+    }
+
     /// Get username as string for convenience
     pub fn username(&self) -> Option<&str> {
         self.username.as_ref().map(|u| u.as_str())
@@ -453,6 +471,7 @@ impl ValidRedisConfig {
             tls: false,
             database: DatabaseId::default(),
             key_prefix: None,
+            key_hashing: KeyHashing::None,
         }
     }
 }
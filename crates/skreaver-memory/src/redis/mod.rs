@@ -25,3 +25,8 @@ pub use health::{ConnectionMetrics, PoolStats, RedisHealth};
 pub use pool::RedisPoolUtils;
 pub use runtime::{REDIS_RUNTIME, with_redis_runtime};
 pub use transactions::{ConfigProvider, RedisConnectionProvider, RedisTransactionExecutor};
+
+/// Whole seconds for `SETEX`, rounded up so a key never expires early
+pub(crate) fn ttl_seconds(ttl: std::time::Duration) -> u64 {
+    (ttl.as_millis().div_ceil(1000) as u64).max(1)
+}
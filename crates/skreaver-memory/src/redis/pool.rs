@@ -134,10 +134,9 @@ impl RedisPoolUtils {
 
     /// Apply key prefix based on configuration
     pub fn prefixed_key(config: &ValidRedisConfig, key: &MemoryKey) -> String {
-        match &config.key_prefix {
-            Some(prefix) => format!("{}:{}", prefix.as_str(), key.as_str()),
-            None => key.as_str().to_string(),
-        }
+        config
+            .key_hashing()
+            .apply(config.key_prefix(), key.as_str())
     }
 
     /// Update connection metrics
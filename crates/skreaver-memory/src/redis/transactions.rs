@@ -22,8 +22,14 @@ pub struct RedisTransactionalMemory {
 #[cfg(feature = "redis")]
 #[derive(Debug, Clone)]
 enum TransactionOperation {
-    Set { key: String, value: String },
-    Del { key: String },
+    Set {
+        key: String,
+        value: String,
+        ttl_secs: Option<u64>,
+    },
+    Del {
+        key: String,
+    },
 }
 
 #[cfg(feature = "redis")]
@@ -67,13 +73,29 @@ impl RedisTransactionalMemory {
         // Execute all operations
         for operation in &self.operations {
             match operation {
-                TransactionOperation::Set { key, value } => {
+                TransactionOperation::Set {
+                    key,
+                    value,
+                    ttl_secs: None,
+                } => {
                     let _: () = redis::cmd("SET")
                         .arg(key)
                         .arg(value)
                         .query_async(&mut *conn)
                         .await?;
                 }
+                TransactionOperation::Set {
+                    key,
+                    value,
+                    ttl_secs: Some(ttl_secs),
+                } => {
+                    let _: () = redis::cmd("SETEX")
+                        .arg(key)
+                        .arg(ttl_secs)
+                        .arg(value)
+                        .query_async(&mut *conn)
+                        .await?;
+                }
                 TransactionOperation::Del { key } => {
                     let _: () = redis::cmd("DEL").arg(key).query_async(&mut *conn).await?;
                 }
@@ -102,6 +124,7 @@ impl MemoryWriter for RedisTransactionalMemory {
         self.operations.push(TransactionOperation::Set {
             key: prefixed_key,
             value: update.value,
+            ttl_secs: update.ttl.map(super::ttl_seconds),
         });
         Ok(())
     }
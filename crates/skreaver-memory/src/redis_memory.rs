@@ -21,14 +21,16 @@ use tokio::sync::{Mutex, RwLock};
 
 use skreaver_core::error::{MemoryError, TransactionError};
 use skreaver_core::memory::{
-    MemoryKey, MemoryReader, MemoryUpdate, MemoryWriter, SnapshotableMemory, TransactionalMemory,
+    BundleError, DeletableMemory, MemoryKey, MemoryReader, MemorySnapshot, MemoryUpdate,
+    MemoryWriter, ScanableMemory, SnapshotableMemory, TransactionalMemory,
+    import_memory_atomically,
 };
 
 // Use the modular components
 use crate::redis::{
     ConfigProvider, ConnectionMetrics, PoolStats, REDIS_RUNTIME, RedisConnectionProvider,
     RedisHealth, RedisPoolUtils, RedisTransactionExecutor, StatefulConnectionManager,
-    ValidRedisConfig, with_redis_runtime,
+    ValidRedisConfig, ttl_seconds, with_redis_runtime,
 };
 
 /// Enhanced Redis memory backend with enterprise features
@@ -226,7 +228,14 @@ impl RedisMemory {
 
         let mut conn = self.get_connection().await?;
 
-        let _: () = conn.set(&prefixed_key, &update.value).await.map_err(|e| {
+        let stored = match update.ttl {
+            Some(ttl) => {
+                conn.set_ex(&prefixed_key, &update.value, ttl_seconds(ttl))
+                    .await
+            }
+            None => conn.set(&prefixed_key, &update.value).await,
+        };
+        let _: () = stored.map_err(|e| {
             self.update_metrics(false, start.elapsed());
             MemoryError::StoreFailed {
                 key: update.key.clone(),
@@ -255,7 +264,10 @@ impl RedisMemory {
 
         for update in &updates {
             let prefixed_key = self.prefixed_key(&update.key);
-            pipe.set(&prefixed_key, &update.value);
+            match update.ttl {
+                Some(ttl) => pipe.set_ex(&prefixed_key, &update.value, ttl_seconds(ttl)),
+                None => pipe.set(&prefixed_key, &update.value),
+            };
         }
 
         let _: () = pipe.query_async(&mut *conn).await.map_err(|e| {
@@ -280,10 +292,10 @@ impl RedisMemory {
         // Use SCAN instead of KEYS for production safety
         let mut cursor = 0;
         let mut all_keys = Vec::new();
-        let scan_pattern = match self.config.key_prefix() {
-            Some(prefix) => format!("{}:*", prefix),
-            None => "*".to_string(),
-        };
+        let scan_pattern = self
+            .config
+            .key_hashing()
+            .scan_pattern(self.config.key_prefix(), "");
 
         loop {
             let (next_cursor, keys): (u64, Vec<String>) = redis::cmd("SCAN")
@@ -330,14 +342,12 @@ impl RedisMemory {
         let mut snapshot_data = HashMap::new();
         for (key, value) in all_keys.into_iter().zip(values) {
             if let Some(val) = value {
-                // Remove prefix if present
-                let clean_key = match self.config.key_prefix() {
-                    Some(prefix) => {
-                        let prefix_with_colon = format!("{}:", prefix);
-                        key.strip_prefix(&prefix_with_colon).unwrap_or(&key)
-                    }
-                    None => &key,
-                };
+                // Remove prefix and hash tag if present
+                let clean_key = self
+                    .config
+                    .key_hashing()
+                    .strip(self.config.key_prefix(), &key)
+                    .unwrap_or(&key);
                 snapshot_data.insert(clean_key.to_string(), val);
             }
         }
@@ -370,10 +380,10 @@ impl RedisMemory {
         pipe.atomic();
 
         // Clear existing keys with our prefix
-        let scan_pattern = match self.config.key_prefix() {
-            Some(prefix) => format!("{}:*", prefix),
-            None => "*".to_string(),
-        };
+        let scan_pattern = self
+            .config
+            .key_hashing()
+            .scan_pattern(self.config.key_prefix(), "");
 
         // Get existing keys to clear
         let existing_keys: Vec<String> = redis::cmd("KEYS")
@@ -389,10 +399,10 @@ impl RedisMemory {
 
         // Set new data
         for (key, value) in snapshot_data {
-            let prefixed_key = match self.config.key_prefix() {
-                Some(prefix) => format!("{}:{}", prefix, key),
-                None => key,
-            };
+            let prefixed_key = self
+                .config
+                .key_hashing()
+                .apply(self.config.key_prefix(), &key);
             pipe.set(&prefixed_key, &value);
         }
 
@@ -408,6 +418,71 @@ impl RedisMemory {
 
         Ok(())
     }
+
+    /// Async prefix scan using SCAN, so large keyspaces never block Redis
+    pub async fn scan_prefix_async(&self, prefix: &str) -> Result<Vec<MemoryKey>, MemoryError> {
+        let mut conn = self.get_connection().await?;
+        let hashing = self.config.key_hashing();
+        let key_prefix = self.config.key_prefix();
+        let pattern =
+            hashing.scan_pattern(key_prefix.map(escape_glob).as_deref(), &escape_glob(prefix));
+
+        let mut cursor = 0;
+        let mut keys = Vec::new();
+        loop {
+            let (next_cursor, batch): (u64, Vec<String>) = redis::cmd("SCAN")
+                .arg(cursor)
+                .arg("MATCH")
+                .arg(&pattern)
+                .arg("COUNT")
+                .arg(100)
+                .query_async(&mut *conn)
+                .await
+                .map_err(|e| MemoryError::LoadFailed {
+                    key: skreaver_core::memory::MemoryKeys::scan(),
+                    backend: skreaver_core::error::MemoryBackend::Redis,
+                    kind: skreaver_core::error::MemoryErrorKind::NetworkError {
+                        details: Self::sanitize_error(&e),
+                    },
+                })?;
+
+            for raw in batch {
+                let clean = hashing.strip(key_prefix, &raw).unwrap_or(&raw);
+                // Keys written outside skreaver may not be valid memory keys
+                if let Ok(key) = MemoryKey::new(clean) {
+                    keys.push(key);
+                }
+            }
+            cursor = next_cursor;
+            if cursor == 0 {
+                break;
+            }
+        }
+
+        Ok(keys)
+    }
+
+    /// Async delete operation, returning whether the key existed
+    pub async fn delete_async(&self, key: &MemoryKey) -> Result<bool, MemoryError> {
+        let prefixed_key = self.prefixed_key(key);
+        let start = Instant::now();
+
+        let mut conn = self.get_connection().await?;
+
+        let removed: u64 = conn.del(&prefixed_key).await.map_err(|e| {
+            self.update_metrics(false, start.elapsed());
+            MemoryError::DeleteFailed {
+                key: key.clone(),
+                backend: skreaver_core::error::MemoryBackend::Redis,
+                kind: skreaver_core::error::MemoryErrorKind::NetworkError {
+                    details: Self::sanitize_error(&e),
+                },
+            }
+        })?;
+
+        self.update_metrics(true, start.elapsed());
+        Ok(removed > 0)
+    }
 }
 
 #[cfg(feature = "redis")]
@@ -448,6 +523,28 @@ impl MemoryWriter for RedisMemory {
     }
 }
 
+#[cfg(feature = "redis")]
+impl ScanableMemory for RedisMemory {
+    fn scan_prefix(&self, prefix: &str) -> Result<Vec<MemoryKey>, MemoryError> {
+        with_redis_runtime(|| {
+            let prefix = prefix.to_string();
+            let memory = self.clone();
+            Box::pin(async move { memory.scan_prefix_async(&prefix).await })
+        })
+    }
+}
+
+#[cfg(feature = "redis")]
+impl DeletableMemory for RedisMemory {
+    fn delete(&mut self, key: &MemoryKey) -> Result<bool, MemoryError> {
+        with_redis_runtime(|| {
+            let key = key.clone();
+            let memory = self.clone();
+            Box::pin(async move { memory.delete_async(&key).await })
+        })
+    }
+}
+
 #[cfg(feature = "redis")]
 impl SnapshotableMemory for RedisMemory {
     fn snapshot(&mut self) -> Option<String> {
@@ -465,6 +562,11 @@ impl SnapshotableMemory for RedisMemory {
             Box::pin(async move { memory.restore_async(&snapshot).await })
         })
     }
+
+    fn import_snapshot(&mut self, snapshot: MemorySnapshot) -> Result<(), BundleError> {
+        // Pipelined batch writes are not atomic, so load through MULTI/EXEC
+        import_memory_atomically(self, &snapshot)
+    }
 }
 
 #[cfg(feature = "redis")]
@@ -491,3 +593,16 @@ impl TransactionalMemory for RedisMemory {
             .with(|rt_cell| RedisTransactionExecutor::execute_transaction(self, rt_cell, f))
     }
 }
+
+/// Escape Redis glob metacharacters so `pattern` matches literally
+#[cfg(feature = "redis")]
+fn escape_glob(pattern: &str) -> String {
+    let mut escaped = String::with_capacity(pattern.len());
+    for c in pattern.chars() {
+        if matches!(c, '*' | '?' | '[' | ']' | '\\') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
@@ -0,0 +1,399 @@
+//! Retries of transient memory backend failures.
+//!
+//! Networked backends can fail for reasons that clear up on their own, such
+//! as a reset connection or a timed-out request. [`RetryingMemory`] wraps any
+//! backend and retries those failures with exponential backoff, so agents only
+//! see errors that persist. Logical errors (missing keys, invalid keys or
+//! values, access denied) are returned immediately without a retry.
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+use skreaver_core::error::{MemoryError, MemoryErrorKind, TransactionError};
+use skreaver_core::memory::{
+    MemoryKey, MemoryReader, MemoryUpdate, MemoryWriter, SnapshotableMemory, TransactionalMemory,
+};
+
+/// Retry settings for one kind of memory operation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MemoryRetryConfig {
+    /// Total attempts per operation, including the first one.
+    pub max_attempts: u32,
+    /// Delay before the first retry.
+    pub initial_backoff: Duration,
+    /// Upper bound for the exponential backoff delay.
+    pub max_backoff: Duration,
+}
+
+impl Default for MemoryRetryConfig {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(50),
+            max_backoff: Duration::from_secs(1),
+        }
+    }
+}
+
+impl MemoryRetryConfig {
+    /// Create a configuration with default retry settings.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a configuration that never retries.
+    pub fn disabled() -> Self {
+        Self {
+            max_attempts: 1,
+            ..Self::default()
+        }
+    }
+
+    /// Set the total number of attempts per operation.
+    pub fn with_max_attempts(mut self, attempts: u32) -> Self {
+        self.max_attempts = attempts.max(1);
+        self
+    }
+
+    /// Set the initial and maximum backoff delays.
+    pub fn with_backoff(mut self, initial: Duration, max: Duration) -> Self {
+        self.initial_backoff = initial;
+        self.max_backoff = max.max(initial);
+        self
+    }
+}
+
+/// Check whether a memory error is transient and worth retrying.
+///
+/// Network failures, unavailable services, exhausted resources and timeouts
+/// are transient; every other error kind is a logical error.
+pub fn is_transient(error: &MemoryError) -> bool {
+    error.is_retryable() || matches!(error.kind(), MemoryErrorKind::Timeout { .. })
+}
+
+/// Snapshot of retry counters.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MemoryRetryStats {
+    /// Retries issued for load operations.
+    pub load_retries: u64,
+    /// Retries issued for store operations.
+    pub store_retries: u64,
+    /// Operations that succeeded after at least one retry.
+    pub recovered: u64,
+    /// Operations that still failed after their last attempt.
+    pub exhausted: u64,
+}
+
+#[derive(Debug, Default)]
+struct RetryCounters {
+    load_retries: AtomicU64,
+    store_retries: AtomicU64,
+    recovered: AtomicU64,
+    exhausted: AtomicU64,
+}
+
+/// A memory wrapper that retries transient backend failures.
+///
+/// Loads and stores are configured separately with
+/// [`with_load_retry`](Self::with_load_retry) and
+/// [`with_store_retry`](Self::with_store_retry). Transactions and snapshots
+/// are passed through unchanged, since a partially applied transaction
+/// cannot be safely replayed. Backoff sleeps block the calling thread, like
+/// the synchronous memory traits themselves.
+///
+/// # Example
+///
+/// ```rust
+/// use skreaver_memory::{MemoryRetryConfig, RetryingMemory};
+/// use skreaver_core::{InMemoryMemory, MemoryReader, MemoryWriter, MemoryUpdate};
+/// use std::time::Duration;
+///
+/// let mut memory = RetryingMemory::new(InMemoryMemory::new())
+///     .with_store_retry(
+///         MemoryRetryConfig::new()
+///             .with_max_attempts(5)
+///             .with_backoff(Duration::from_millis(10), Duration::from_millis(200)),
+///     );
+///
+/// memory.store(MemoryUpdate::new("status", "ready").unwrap()).unwrap();
+/// assert_eq!(memory.stats().store_retries, 0);
+/// ```
+pub struct RetryingMemory<M> {
+    inner: M,
+    load_retry: MemoryRetryConfig,
+    store_retry: MemoryRetryConfig,
+    counters: Arc<RetryCounters>,
+}
+
+impl<M> RetryingMemory<M> {
+    /// Wrap a memory backend using the default retry settings for every
+    /// operation.
+    pub fn new(inner: M) -> Self {
+        Self {
+            inner,
+            load_retry: MemoryRetryConfig::default(),
+            store_retry: MemoryRetryConfig::default(),
+            counters: Arc::new(RetryCounters::default()),
+        }
+    }
+
+    /// Set the retry settings for `load` and `load_many`.
+    pub fn with_load_retry(mut self, config: MemoryRetryConfig) -> Self {
+        self.load_retry = config;
+        self
+    }
+
+    /// Set the retry settings for `store` and `store_many`.
+    pub fn with_store_retry(mut self, config: MemoryRetryConfig) -> Self {
+        self.store_retry = config;
+        self
+    }
+
+    /// Get the retry settings for loads.
+    pub fn load_retry(&self) -> &MemoryRetryConfig {
+        &self.load_retry
+    }
+
+    /// Get the retry settings for stores.
+    pub fn store_retry(&self) -> &MemoryRetryConfig {
+        &self.store_retry
+    }
+
+    /// Current retry counters.
+    pub fn stats(&self) -> MemoryRetryStats {
+        MemoryRetryStats {
+            load_retries: self.counters.load_retries.load(Ordering::Relaxed),
+            store_retries: self.counters.store_retries.load(Ordering::Relaxed),
+            recovered: self.counters.recovered.load(Ordering::Relaxed),
+            exhausted: self.counters.exhausted.load(Ordering::Relaxed),
+        }
+    }
+
+    /// Get a mutable reference to the underlying memory implementation.
+    pub fn inner_mut(&mut self) -> &mut M {
+        &mut self.inner
+    }
+
+    /// Get an immutable reference to the underlying memory implementation.
+    pub fn inner(&self) -> &M {
+        &self.inner
+    }
+}
+
+/// Run `operation`, retrying transient failures according to `config`.
+fn retry<T>(
+    config: &MemoryRetryConfig,
+    counters: &RetryCounters,
+    retries: &AtomicU64,
+    mut operation: impl FnMut() -> Result<T, MemoryError>,
+) -> Result<T, MemoryError> {
+    let mut backoff = config.initial_backoff;
+    let mut attempt = 1;
+    loop {
+        let error = match operation() {
+            Ok(value) => {
+                if attempt > 1 {
+                    counters.recovered.fetch_add(1, Ordering::Relaxed);
+                }
+                return Ok(value);
+            }
+            Err(e) if !is_transient(&e) => return Err(e),
+            Err(e) => e,
+        };
+
+        if attempt >= config.max_attempts {
+            counters.exhausted.fetch_add(1, Ordering::Relaxed);
+            tracing::debug!(attempt, error = %error, "Memory retry attempts exhausted");
+            return Err(error);
+        }
+
+        // Honor a backend's requested delay, within the configured bound
+        let delay = error
+            .retry_after_ms()
+            .map_or(backoff, Duration::from_millis)
+            .min(config.max_backoff);
+        tracing::debug!(
+            attempt,
+            ?delay,
+            error = %error,
+            "Retrying memory operation after transient failure"
+        );
+        retries.fetch_add(1, Ordering::Relaxed);
+        std::thread::sleep(delay);
+        backoff = (backoff * 2).min(config.max_backoff);
+        attempt += 1;
+    }
+}
+
+impl<M: MemoryReader> MemoryReader for RetryingMemory<M> {
+    fn load(&self, key: &MemoryKey) -> Result<Option<String>, MemoryError> {
+        retry(
+            &self.load_retry,
+            &self.counters,
+            &self.counters.load_retries,
+            || self.inner.load(key),
+        )
+    }
+
+    fn load_many(&self, keys: &[MemoryKey]) -> Result<Vec<Option<String>>, MemoryError> {
+        retry(
+            &self.load_retry,
+            &self.counters,
+            &self.counters.load_retries,
+            || self.inner.load_many(keys),
+        )
+    }
+}
+
+impl<M: MemoryWriter> MemoryWriter for RetryingMemory<M> {
+    fn store(&mut self, update: MemoryUpdate) -> Result<(), MemoryError> {
+        let inner = &mut self.inner;
+        retry(
+            &self.store_retry,
+            &self.counters,
+            &self.counters.store_retries,
+            || inner.store(update.clone()),
+        )
+    }
+
+    fn store_many(&mut self, updates: Vec<MemoryUpdate>) -> Result<(), MemoryError> {
+        let inner = &mut self.inner;
+        retry(
+            &self.store_retry,
+            &self.counters,
+            &self.counters.store_retries,
+            || inner.store_many(updates.clone()),
+        )
+    }
+}
+
+impl<M: TransactionalMemory> TransactionalMemory for RetryingMemory<M> {
+    fn transaction<F, R>(&mut self, f: F) -> Result<R, TransactionError>
+    where
+        F: FnOnce(&mut dyn MemoryWriter) -> Result<R, TransactionError>,
+    {
+        self.inner.transaction(f)
+    }
+}
+
+impl<M: SnapshotableMemory> SnapshotableMemory for RetryingMemory<M> {
+    fn snapshot(&mut self) -> Option<String> {
+        self.inner.snapshot()
+    }
+
+    fn restore(&mut self, snapshot: &str) -> Result<(), MemoryError> {
+        self.inner.restore(snapshot)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use skreaver_core::InMemoryMemory;
+    use skreaver_core::error::{MemoryBackend, MemoryOperation};
+    use std::sync::atomic::AtomicU32;
+
+    /// Backend whose loads fail with `error` until `failures` run out
+    struct FlakyMemory {
+        inner: InMemoryMemory,
+        failures: AtomicU32,
+        error: MemoryError,
+        loads: AtomicU32,
+    }
+
+    impl FlakyMemory {
+        fn new(failures: u32, error: MemoryError) -> Self {
+            Self {
+                inner: InMemoryMemory::new(),
+                failures: AtomicU32::new(failures),
+                error,
+                loads: AtomicU32::new(0),
+            }
+        }
+    }
+
+    impl MemoryReader for FlakyMemory {
+        fn load(&self, key: &MemoryKey) -> Result<Option<String>, MemoryError> {
+            self.loads.fetch_add(1, Ordering::SeqCst);
+            let remaining = self.failures.load(Ordering::SeqCst);
+            if remaining > 0 {
+                self.failures.store(remaining - 1, Ordering::SeqCst);
+                return Err(self.error.clone());
+            }
+            self.inner.load(key)
+        }
+    }
+
+    impl MemoryWriter for FlakyMemory {
+        fn store(&mut self, update: MemoryUpdate) -> Result<(), MemoryError> {
+            self.inner.store(update)
+        }
+    }
+
+    fn fast_retry() -> MemoryRetryConfig {
+        MemoryRetryConfig::new()
+            .with_max_attempts(3)
+            .with_backoff(Duration::from_millis(1), Duration::from_millis(5))
+    }
+
+    #[test]
+    fn test_transient_load_failure_is_retried_to_success() {
+        let reset = MemoryError::network_error(
+            MemoryOperation::Load,
+            MemoryBackend::Redis,
+            "connection reset by peer".to_string(),
+        );
+        let mut memory =
+            RetryingMemory::new(FlakyMemory::new(2, reset)).with_load_retry(fast_retry());
+        memory
+            .store(MemoryUpdate::new("greeting", "hello").unwrap())
+            .unwrap();
+
+        let value = memory.load(&MemoryKey::new("greeting").unwrap()).unwrap();
+
+        assert_eq!(value.as_deref(), Some("hello"));
+        assert_eq!(memory.inner().loads.load(Ordering::SeqCst), 3);
+        assert_eq!(
+            memory.stats(),
+            MemoryRetryStats {
+                load_retries: 2,
+                recovered: 1,
+                ..MemoryRetryStats::default()
+            }
+        );
+    }
+
+    #[test]
+    fn test_not_found_is_returned_without_retry() {
+        let key = MemoryKey::new("missing").unwrap();
+        let not_found = MemoryError::key_not_found(key.clone(), MemoryBackend::Redis);
+        let memory =
+            RetryingMemory::new(FlakyMemory::new(1, not_found)).with_load_retry(fast_retry());
+
+        let error = memory.load(&key).unwrap_err();
+
+        assert!(matches!(error.kind(), MemoryErrorKind::KeyNotFound));
+        assert_eq!(memory.inner().loads.load(Ordering::SeqCst), 1);
+        assert_eq!(memory.stats(), MemoryRetryStats::default());
+    }
+
+    #[test]
+    fn test_persistent_timeout_exhausts_attempts() {
+        let timeout = MemoryError::OperationFailed {
+            operation: MemoryOperation::Load,
+            backend: MemoryBackend::Postgres,
+            kind: MemoryErrorKind::Timeout {
+                operation: "load".to_string(),
+                timeout_seconds: 5,
+            },
+        };
+        let memory =
+            RetryingMemory::new(FlakyMemory::new(10, timeout)).with_load_retry(fast_retry());
+
+        assert!(memory.load(&MemoryKey::new("slow").unwrap()).is_err());
+        assert_eq!(memory.inner().loads.load(Ordering::SeqCst), 3);
+        assert_eq!(memory.stats().load_retries, 2);
+        assert_eq!(memory.stats().exhausted, 1);
+    }
+}
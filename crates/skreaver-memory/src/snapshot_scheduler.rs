@@ -0,0 +1,741 @@
+//! Scheduled snapshots of memory with rotation.
+//!
+//! [`SnapshotScheduler`] periodically snapshots any [`SnapshotableMemory`]
+//! into a pluggable [`SnapshotStore`] under timestamped names, and rotates
+//! out old snapshots according to a [`SnapshotRetention`] policy. Failed
+//! snapshots are retried with backoff and reported to an alert hook once the
+//! attempts are exhausted. The newest stored snapshot can be restored with
+//! [`restore_latest`](SnapshotScheduler::restore_latest).
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::{Arc, Mutex};
+use std::thread::JoinHandle;
+use std::time::Duration;
+
+use chrono::{DateTime, NaiveDateTime, Utc};
+use skreaver_core::error::{MemoryBackend, MemoryError, MemoryErrorKind, MemoryOperation};
+use skreaver_core::memory::SnapshotableMemory;
+
+use crate::retrying_memory::MemoryRetryConfig;
+
+/// Timestamp format of snapshot names; sorts lexicographically by time.
+const TIMESTAMP_FORMAT: &str = "%Y%m%dT%H%M%S%.9fZ";
+
+/// Storage for named snapshots.
+///
+/// Names are generated by the scheduler and only contain ASCII letters,
+/// digits, `-`, `_` and `.`.
+pub trait SnapshotStore: Send + Sync {
+    /// Store a snapshot under `name`, replacing any existing one.
+    fn put(&self, name: &str, snapshot: &str) -> Result<(), MemoryError>;
+
+    /// Load the snapshot stored under `name`.
+    fn get(&self, name: &str) -> Result<Option<String>, MemoryError>;
+
+    /// Names of all stored snapshots, in any order.
+    fn list(&self) -> Result<Vec<String>, MemoryError>;
+
+    /// Delete the snapshot stored under `name`, if any.
+    fn delete(&self, name: &str) -> Result<(), MemoryError>;
+}
+
+/// Snapshot store that keeps snapshots in process memory.
+#[derive(Debug, Default)]
+pub struct InMemorySnapshotStore {
+    snapshots: Mutex<BTreeMap<String, String>>,
+}
+
+impl InMemorySnapshotStore {
+    /// Create an empty store.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn snapshots(&self) -> std::sync::MutexGuard<'_, BTreeMap<String, String>> {
+        self.snapshots.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl SnapshotStore for InMemorySnapshotStore {
+    fn put(&self, name: &str, snapshot: &str) -> Result<(), MemoryError> {
+        self.snapshots()
+            .insert(name.to_string(), snapshot.to_string());
+        Ok(())
+    }
+
+    fn get(&self, name: &str) -> Result<Option<String>, MemoryError> {
+        Ok(self.snapshots().get(name).cloned())
+    }
+
+    fn list(&self) -> Result<Vec<String>, MemoryError> {
+        Ok(self.snapshots().keys().cloned().collect())
+    }
+
+    fn delete(&self, name: &str) -> Result<(), MemoryError> {
+        self.snapshots().remove(name);
+        Ok(())
+    }
+}
+
+/// Snapshot store that writes one `<name>.snapshot` file per snapshot.
+#[derive(Debug, Clone)]
+pub struct DirectorySnapshotStore {
+    dir: PathBuf,
+}
+
+impl DirectorySnapshotStore {
+    /// File extension of snapshot files.
+    pub const EXTENSION: &'static str = "snapshot";
+
+    /// Store snapshots in `dir`, which is created on the first write.
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    fn path(&self, name: &str, operation: MemoryOperation) -> Result<PathBuf, MemoryError> {
+        let valid = !name.is_empty()
+            && !name.starts_with('.')
+            && name
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'));
+        if !valid {
+            return Err(MemoryError::OperationFailed {
+                operation,
+                backend: MemoryBackend::File,
+                kind: MemoryErrorKind::InvalidKey {
+                    validation_error: format!("Invalid snapshot name '{}'", name),
+                },
+            });
+        }
+        Ok(self.dir.join(format!("{}.{}", name, Self::EXTENSION)))
+    }
+}
+
+fn io_error(operation: MemoryOperation, e: std::io::Error) -> MemoryError {
+    MemoryError::OperationFailed {
+        operation,
+        backend: MemoryBackend::File,
+        kind: MemoryErrorKind::IoError {
+            details: e.to_string(),
+        },
+    }
+}
+
+impl SnapshotStore for DirectorySnapshotStore {
+    fn put(&self, name: &str, snapshot: &str) -> Result<(), MemoryError> {
+        let path = self.path(name, MemoryOperation::Snapshot)?;
+        std::fs::create_dir_all(&self.dir).map_err(|e| io_error(MemoryOperation::Snapshot, e))?;
+        // Write then rename so a crash never leaves a truncated snapshot
+        let tmp = path.with_extension("tmp");
+        std::fs::write(&tmp, snapshot).map_err(|e| io_error(MemoryOperation::Snapshot, e))?;
+        std::fs::rename(&tmp, &path).map_err(|e| io_error(MemoryOperation::Snapshot, e))
+    }
+
+    fn get(&self, name: &str) -> Result<Option<String>, MemoryError> {
+        let path = self.path(name, MemoryOperation::Restore)?;
+        match std::fs::read_to_string(path) {
+            Ok(snapshot) => Ok(Some(snapshot)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(io_error(MemoryOperation::Restore, e)),
+        }
+    }
+
+    fn list(&self) -> Result<Vec<String>, MemoryError> {
+        let entries = match std::fs::read_dir(&self.dir) {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(io_error(MemoryOperation::List, e)),
+        };
+
+        let mut names = Vec::new();
+        for entry in entries {
+            let path = entry
+                .map_err(|e| io_error(MemoryOperation::List, e))?
+                .path();
+            if path.extension().is_some_and(|ext| ext == Self::EXTENSION)
+                && let Some(name) = path.file_stem().and_then(|stem| stem.to_str())
+            {
+                names.push(name.to_string());
+            }
+        }
+        Ok(names)
+    }
+
+    fn delete(&self, name: &str) -> Result<(), MemoryError> {
+        let path = self.path(name, MemoryOperation::Delete)?;
+        match std::fs::remove_file(path) {
+            Ok(()) => Ok(()),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+            Err(e) => Err(io_error(MemoryOperation::Delete, e)),
+        }
+    }
+}
+
+/// Which snapshots survive rotation.
+///
+/// The newest snapshot is always kept, whatever the policy.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SnapshotRetention {
+    /// Keep the given number of newest snapshots.
+    KeepLast(usize),
+    /// Keep snapshots taken within the given age.
+    MaxAge(Duration),
+}
+
+/// Configuration for [`SnapshotScheduler`].
+#[derive(Debug, Clone)]
+pub struct SnapshotSchedulerConfig {
+    /// How often the background scheduler takes a snapshot.
+    pub interval: Duration,
+    /// Which snapshots survive rotation.
+    pub retention: SnapshotRetention,
+    /// Retries for failed snapshots.
+    pub retry: MemoryRetryConfig,
+    /// Prefix of snapshot names; snapshots with other names are left alone.
+    pub name_prefix: String,
+}
+
+impl Default for SnapshotSchedulerConfig {
+    fn default() -> Self {
+        Self {
+            interval: Duration::from_secs(3600),
+            retention: SnapshotRetention::KeepLast(24),
+            retry: MemoryRetryConfig::default(),
+            name_prefix: "snapshot".to_string(),
+        }
+    }
+}
+
+/// A snapshot or rotation that failed after all its attempts.
+#[derive(Debug, Clone)]
+pub struct SnapshotFailure {
+    /// Operation that failed: [`MemoryOperation::Snapshot`] or [`MemoryOperation::Delete`].
+    pub operation: MemoryOperation,
+    /// Number of attempts made.
+    pub attempts: u32,
+    /// Error of the last attempt.
+    pub error: MemoryError,
+}
+
+/// Hook notified of every [`SnapshotFailure`].
+pub type SnapshotAlert = Arc<dyn Fn(&SnapshotFailure) + Send + Sync>;
+
+/// A stored snapshot with the time it was taken.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StoredSnapshot {
+    /// Name in the snapshot store.
+    pub name: String,
+    /// When the snapshot was taken.
+    pub taken_at: DateTime<Utc>,
+}
+
+/// Snapshot of scheduler counters.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SnapshotStats {
+    /// Snapshots stored.
+    pub snapshots: u64,
+    /// Snapshots deleted by rotation.
+    pub rotated: u64,
+    /// Retries issued for failed snapshots.
+    pub retries: u64,
+    /// Snapshots or rotations that failed after all attempts.
+    pub failures: u64,
+}
+
+#[derive(Debug, Default)]
+struct SnapshotCounters {
+    snapshots: AtomicU64,
+    rotated: AtomicU64,
+    retries: AtomicU64,
+    failures: AtomicU64,
+}
+
+/// Takes timestamped snapshots of memory and rotates old ones out.
+///
+/// Use [`snapshot`](Self::snapshot) for a single snapshot or
+/// [`spawn`](Self::spawn) to take snapshots on an interval in the background.
+///
+/// # Example
+///
+/// ```rust
+/// use skreaver_memory::{
+///     InMemorySnapshotStore, SnapshotRetention, SnapshotScheduler, SnapshotSchedulerConfig,
+/// };
+/// use skreaver_core::InMemoryMemory;
+/// use std::sync::Arc;
+///
+/// let scheduler = SnapshotScheduler::new(
+///     SnapshotSchedulerConfig {
+///         retention: SnapshotRetention::KeepLast(3),
+///         ..Default::default()
+///     },
+///     Arc::new(InMemorySnapshotStore::new()),
+/// )
+/// .with_alert(Arc::new(|failure| eprintln!("snapshot failed: {}", failure.error)));
+///
+/// let mut memory = InMemoryMemory::new();
+/// scheduler.snapshot(&mut memory).unwrap();
+/// assert!(scheduler.latest().unwrap().is_some());
+/// ```
+#[derive(Clone)]
+pub struct SnapshotScheduler {
+    config: SnapshotSchedulerConfig,
+    store: Arc<dyn SnapshotStore>,
+    alert: Option<SnapshotAlert>,
+    counters: Arc<SnapshotCounters>,
+    last_taken: Arc<Mutex<Option<DateTime<Utc>>>>,
+}
+
+impl fmt::Debug for SnapshotScheduler {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SnapshotScheduler")
+            .field("config", &self.config)
+            .field("alert", &self.alert.is_some())
+            .field("stats", &self.stats())
+            .finish()
+    }
+}
+
+impl SnapshotScheduler {
+    /// Create a scheduler writing to `store`.
+    pub fn new(config: SnapshotSchedulerConfig, store: Arc<dyn SnapshotStore>) -> Self {
+        Self {
+            config,
+            store,
+            alert: None,
+            counters: Arc::new(SnapshotCounters::default()),
+            last_taken: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    /// Notify `alert` of snapshots and rotations that fail after all attempts.
+    pub fn with_alert(mut self, alert: SnapshotAlert) -> Self {
+        self.alert = Some(alert);
+        self
+    }
+
+    /// Take and store a snapshot, then rotate old ones out.
+    ///
+    /// Failed attempts are retried per the retry configuration; the error of
+    /// the last attempt is returned and reported to the alert hook. A failed
+    /// rotation is reported but does not fail the snapshot.
+    pub fn snapshot<M: SnapshotableMemory + ?Sized>(
+        &self,
+        memory: &mut M,
+    ) -> Result<StoredSnapshot, MemoryError> {
+        let retry = self.config.retry;
+        let mut backoff = retry.initial_backoff;
+        let mut attempt = 1;
+
+        let stored = loop {
+            match self.try_snapshot(memory) {
+                Ok(stored) => break stored,
+                Err(error) if attempt >= retry.max_attempts => {
+                    self.fail(MemoryOperation::Snapshot, attempt, error.clone());
+                    return Err(error);
+                }
+                Err(error) => {
+                    tracing::debug!(attempt, error = %error, "Retrying memory snapshot");
+                    self.counters.retries.fetch_add(1, Ordering::Relaxed);
+                    std::thread::sleep(backoff);
+                    backoff = (backoff * 2).min(retry.max_backoff);
+                    attempt += 1;
+                }
+            }
+        };
+        self.counters.snapshots.fetch_add(1, Ordering::Relaxed);
+
+        if let Err(error) = self.rotate() {
+            self.fail(MemoryOperation::Delete, 1, error);
+        }
+        Ok(stored)
+    }
+
+    fn try_snapshot<M: SnapshotableMemory + ?Sized>(
+        &self,
+        memory: &mut M,
+    ) -> Result<StoredSnapshot, MemoryError> {
+        let data = memory
+            .snapshot()
+            .ok_or_else(|| MemoryError::SnapshotFailed {
+                backend: MemoryBackend::InMemory,
+                kind: MemoryErrorKind::SerializationError {
+                    details: "Memory returned no snapshot".to_string(),
+                },
+            })?;
+
+        let taken_at = self.next_timestamp();
+        let name = format!(
+            "{}-{}",
+            self.config.name_prefix,
+            taken_at.format(TIMESTAMP_FORMAT)
+        );
+        self.store.put(&name, &data)?;
+        Ok(StoredSnapshot { name, taken_at })
+    }
+
+    /// Current time, bumped past the previous snapshot so names stay unique.
+    fn next_timestamp(&self) -> DateTime<Utc> {
+        let mut last = self.last_taken.lock().unwrap_or_else(|e| e.into_inner());
+        let mut now = Utc::now();
+        if let Some(previous) = *last
+            && now <= previous
+        {
+            now = previous + chrono::Duration::nanoseconds(1);
+        }
+        *last = Some(now);
+        now
+    }
+
+    fn fail(&self, operation: MemoryOperation, attempts: u32, error: MemoryError) {
+        self.counters.failures.fetch_add(1, Ordering::Relaxed);
+        tracing::error!(%operation, attempts, error = %error, "Memory snapshot failed");
+        if let Some(alert) = &self.alert {
+            alert(&SnapshotFailure {
+                operation,
+                attempts,
+                error,
+            });
+        }
+    }
+
+    /// Stored snapshots written by this scheduler's prefix, oldest first.
+    pub fn snapshots(&self) -> Result<Vec<StoredSnapshot>, MemoryError> {
+        let prefix = format!("{}-", self.config.name_prefix);
+        let mut snapshots: Vec<StoredSnapshot> = self
+            .store
+            .list()?
+            .into_iter()
+            .filter_map(|name| {
+                let timestamp = name.strip_prefix(&prefix)?;
+                let taken_at = NaiveDateTime::parse_from_str(timestamp, TIMESTAMP_FORMAT)
+                    .ok()?
+                    .and_utc();
+                Some(StoredSnapshot { name, taken_at })
+            })
+            .collect();
+        snapshots.sort_by_key(|snapshot| snapshot.taken_at);
+        Ok(snapshots)
+    }
+
+    /// The newest stored snapshot, if any.
+    pub fn latest(&self) -> Result<Option<StoredSnapshot>, MemoryError> {
+        Ok(self.snapshots()?.pop())
+    }
+
+    /// Restore `memory` from the newest stored snapshot.
+    ///
+    /// Returns the restored snapshot, or `None` if there is none.
+    pub fn restore_latest<M: SnapshotableMemory + ?Sized>(
+        &self,
+        memory: &mut M,
+    ) -> Result<Option<StoredSnapshot>, MemoryError> {
+        let Some(latest) = self.latest()? else {
+            return Ok(None);
+        };
+        let data = self
+            .store
+            .get(&latest.name)?
+            .ok_or(MemoryError::RestoreFailed {
+                backend: MemoryBackend::InMemory,
+                kind: MemoryErrorKind::KeyNotFound,
+            })?;
+        memory.restore(&data)?;
+        Ok(Some(latest))
+    }
+
+    /// Delete snapshots the retention policy no longer keeps.
+    ///
+    /// Returns the number of snapshots deleted.
+    pub fn rotate(&self) -> Result<usize, MemoryError> {
+        let snapshots = self.snapshots()?;
+        let Some(newest) = snapshots.len().checked_sub(1) else {
+            return Ok(0);
+        };
+
+        let expired = match self.config.retention {
+            SnapshotRetention::KeepLast(count) => snapshots.len().saturating_sub(count.max(1)),
+            SnapshotRetention::MaxAge(age) => {
+                let cutoff = chrono::Duration::from_std(age)
+                    .ok()
+                    .and_then(|age| Utc::now().checked_sub_signed(age));
+                cutoff.map_or(0, |cutoff| {
+                    snapshots[..newest]
+                        .iter()
+                        .take_while(|snapshot| snapshot.taken_at < cutoff)
+                        .count()
+                })
+            }
+        };
+
+        for snapshot in &snapshots[..expired] {
+            self.store.delete(&snapshot.name)?;
+            self.counters.rotated.fetch_add(1, Ordering::Relaxed);
+        }
+        if expired > 0 {
+            tracing::debug!(expired, "Rotated memory snapshots");
+        }
+        Ok(expired)
+    }
+
+    /// Current scheduler counters.
+    pub fn stats(&self) -> SnapshotStats {
+        SnapshotStats {
+            snapshots: self.counters.snapshots.load(Ordering::Relaxed),
+            rotated: self.counters.rotated.load(Ordering::Relaxed),
+            retries: self.counters.retries.load(Ordering::Relaxed),
+            failures: self.counters.failures.load(Ordering::Relaxed),
+        }
+    }
+
+    /// Start snapshotting `memory` every configured interval on a background thread.
+    ///
+    /// The returned handle stops the thread when
+    /// [`shutdown`](SnapshotSchedulerHandle::shutdown) is called or the handle
+    /// is dropped.
+    pub fn spawn<M>(&self, memory: Arc<Mutex<M>>) -> SnapshotSchedulerHandle
+    where
+        M: SnapshotableMemory + 'static,
+    {
+        let (stop_tx, stop_rx) = mpsc::channel::<()>();
+        let scheduler = self.clone();
+        let interval = self.config.interval;
+
+        let thread = std::thread::spawn(move || {
+            // A stop message or a dropped handle ends the loop
+            while let Err(RecvTimeoutError::Timeout) = stop_rx.recv_timeout(interval) {
+                let mut guard = match memory.lock() {
+                    Ok(guard) => guard,
+                    Err(poisoned) => poisoned.into_inner(),
+                };
+                // Failures are already logged and alerted
+                let _ = scheduler.snapshot(&mut *guard);
+            }
+        });
+
+        SnapshotSchedulerHandle {
+            stop_tx: Some(stop_tx),
+            thread: Some(thread),
+        }
+    }
+}
+
+/// Handle to a running background snapshot scheduler.
+#[derive(Debug)]
+pub struct SnapshotSchedulerHandle {
+    stop_tx: Option<mpsc::Sender<()>>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl SnapshotSchedulerHandle {
+    /// Stop the scheduler and wait for any in-flight snapshot to finish.
+    pub fn shutdown(mut self) {
+        self.stop();
+    }
+
+    fn stop(&mut self) {
+        if let Some(tx) = self.stop_tx.take() {
+            let _ = tx.send(());
+        }
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+impl Drop for SnapshotSchedulerHandle {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use skreaver_core::InMemoryMemory;
+    use skreaver_core::memory::{MemoryKey, MemoryReader, MemoryUpdate, MemoryWriter};
+    use std::sync::atomic::AtomicU32;
+
+    fn scheduler(retention: SnapshotRetention) -> (SnapshotScheduler, Arc<InMemorySnapshotStore>) {
+        let store = Arc::new(InMemorySnapshotStore::new());
+        let config = SnapshotSchedulerConfig {
+            interval: Duration::from_millis(10),
+            retention,
+            retry: MemoryRetryConfig::new()
+                .with_backoff(Duration::from_millis(1), Duration::from_millis(1)),
+            ..Default::default()
+        };
+        (SnapshotScheduler::new(config, store.clone()), store)
+    }
+
+    fn store_value(memory: &mut InMemoryMemory, value: &str) {
+        memory
+            .store(MemoryUpdate::new("state", value).unwrap())
+            .unwrap();
+    }
+
+    #[test]
+    fn rotation_removes_oldest_beyond_retention_count() {
+        let (scheduler, store) = scheduler(SnapshotRetention::KeepLast(2));
+        let mut memory = InMemoryMemory::new();
+
+        let mut taken = Vec::new();
+        for value in ["one", "two", "three", "four"] {
+            store_value(&mut memory, value);
+            taken.push(scheduler.snapshot(&mut memory).unwrap());
+        }
+
+        let names: Vec<String> = scheduler
+            .snapshots()
+            .unwrap()
+            .into_iter()
+            .map(|snapshot| snapshot.name)
+            .collect();
+        assert_eq!(names, vec![taken[2].name.clone(), taken[3].name.clone()]);
+        assert_eq!(store.list().unwrap().len(), 2);
+        assert_eq!(
+            scheduler.stats(),
+            SnapshotStats {
+                snapshots: 4,
+                rotated: 2,
+                retries: 0,
+                failures: 0,
+            }
+        );
+
+        // The latest snapshot restores the newest state
+        store_value(&mut memory, "changed");
+        let restored = scheduler.restore_latest(&mut memory).unwrap().unwrap();
+        assert_eq!(restored, taken[3]);
+        assert_eq!(
+            memory
+                .load(&MemoryKey::new("state").unwrap())
+                .unwrap()
+                .as_deref(),
+            Some("four")
+        );
+    }
+
+    #[test]
+    fn age_retention_keeps_newest_snapshot() {
+        let (scheduler, _store) = scheduler(SnapshotRetention::MaxAge(Duration::ZERO));
+        let mut memory = InMemoryMemory::new();
+
+        scheduler.snapshot(&mut memory).unwrap();
+        let newest = scheduler.snapshot(&mut memory).unwrap();
+
+        assert_eq!(scheduler.snapshots().unwrap(), vec![newest]);
+    }
+
+    #[test]
+    fn scheduled_snapshots_are_created() {
+        let (scheduler, _store) = scheduler(SnapshotRetention::KeepLast(10));
+        let memory = Arc::new(Mutex::new(InMemoryMemory::new()));
+        store_value(&mut memory.lock().unwrap(), "scheduled");
+
+        let handle = scheduler.spawn(Arc::clone(&memory));
+        let deadline = std::time::Instant::now() + Duration::from_secs(5);
+        while scheduler.stats().snapshots < 2 && std::time::Instant::now() < deadline {
+            std::thread::sleep(Duration::from_millis(5));
+        }
+        handle.shutdown();
+
+        let snapshots = scheduler.snapshots().unwrap();
+        assert!(snapshots.len() >= 2);
+        assert!(snapshots.windows(2).all(|w| w[0].taken_at < w[1].taken_at));
+    }
+
+    /// Store whose writes fail a set number of times before succeeding
+    #[derive(Default)]
+    struct FlakyStore {
+        inner: InMemorySnapshotStore,
+        failures_left: AtomicU32,
+    }
+
+    impl SnapshotStore for FlakyStore {
+        fn put(&self, name: &str, snapshot: &str) -> Result<(), MemoryError> {
+            if self
+                .failures_left
+                .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |n| n.checked_sub(1))
+                .is_ok()
+            {
+                return Err(io_error(
+                    MemoryOperation::Snapshot,
+                    std::io::Error::other("disk full"),
+                ));
+            }
+            self.inner.put(name, snapshot)
+        }
+
+        fn get(&self, name: &str) -> Result<Option<String>, MemoryError> {
+            self.inner.get(name)
+        }
+
+        fn list(&self) -> Result<Vec<String>, MemoryError> {
+            self.inner.list()
+        }
+
+        fn delete(&self, name: &str) -> Result<(), MemoryError> {
+            self.inner.delete(name)
+        }
+    }
+
+    #[test]
+    fn failures_are_retried_then_alerted() {
+        let store = Arc::new(FlakyStore {
+            failures_left: AtomicU32::new(4),
+            ..Default::default()
+        });
+        let alerts = Arc::new(Mutex::new(Vec::new()));
+        let recorded = Arc::clone(&alerts);
+        let scheduler = SnapshotScheduler::new(
+            SnapshotSchedulerConfig {
+                retry: MemoryRetryConfig::new()
+                    .with_max_attempts(3)
+                    .with_backoff(Duration::from_millis(1), Duration::from_millis(1)),
+                ..Default::default()
+            },
+            store,
+        )
+        .with_alert(Arc::new(move |failure: &SnapshotFailure| {
+            recorded.lock().unwrap().push(failure.attempts);
+        }));
+        let mut memory = InMemoryMemory::new();
+
+        // Three failed attempts exhaust the retries and raise an alert
+        assert!(scheduler.snapshot(&mut memory).is_err());
+        assert_eq!(*alerts.lock().unwrap(), vec![3]);
+
+        // The fourth failure is retried and the snapshot then succeeds
+        scheduler.snapshot(&mut memory).unwrap();
+        assert_eq!(alerts.lock().unwrap().len(), 1);
+        assert_eq!(
+            scheduler.stats(),
+            SnapshotStats {
+                snapshots: 1,
+                rotated: 0,
+                retries: 3,
+                failures: 1,
+            }
+        );
+    }
+
+    #[test]
+    fn directory_store_round_trips_snapshots() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = DirectorySnapshotStore::new(dir.path().join("snapshots"));
+
+        assert!(store.list().unwrap().is_empty());
+        store.put("snapshot-1", "{}").unwrap();
+        assert_eq!(store.list().unwrap(), vec!["snapshot-1".to_string()]);
+        assert_eq!(store.get("snapshot-1").unwrap().as_deref(), Some("{}"));
+        assert!(store.put("../escape", "{}").is_err());
+
+        store.delete("snapshot-1").unwrap();
+        assert_eq!(store.get("snapshot-1").unwrap(), None);
+    }
+}
@@ -46,10 +46,11 @@ impl MigrationEngine {
 
     /// Define the default migrations for the memory backend
     fn default_migrations() -> Vec<Migration> {
-        vec![Migration {
-            version: 1,
-            description: "Create initial memory table".to_string(),
-            up: r#"
+        vec![
+            Migration {
+                version: 1,
+                description: "Create initial memory table".to_string(),
+                up: r#"
                     CREATE TABLE IF NOT EXISTS memory (
                         key TEXT PRIMARY KEY,
                         value TEXT NOT NULL,
@@ -59,9 +60,27 @@ impl MigrationEngine {
                     
                     CREATE INDEX IF NOT EXISTS idx_memory_updated_at ON memory(updated_at);
                 "#
-            .to_string(),
-            down: Some("DROP TABLE IF EXISTS memory;".to_string()),
-        }]
+                .to_string(),
+                down: Some("DROP TABLE IF EXISTS memory;".to_string()),
+            },
+            Migration {
+                version: 2,
+                description: "Add expiry timestamps to memory entries".to_string(),
+                up: r#"
+                    ALTER TABLE memory ADD COLUMN expires_at INTEGER;
+
+                    CREATE INDEX IF NOT EXISTS idx_memory_expires_at ON memory(expires_at);
+                "#
+                .to_string(),
+                down: Some(
+                    r#"
+                    DROP INDEX IF EXISTS idx_memory_expires_at;
+                    ALTER TABLE memory DROP COLUMN expires_at;
+                "#
+                    .to_string(),
+                ),
+            },
+        ]
     }
 
     /// Run migrations up to the specified version
@@ -10,7 +10,7 @@ use std::path::Path;
 use std::sync::Arc;
 
 use skreaver_core::error::MemoryError;
-use skreaver_core::memory::MemoryKey;
+use skreaver_core::memory::{MemoryKey, MemoryUpdate};
 
 // Module declarations
 mod admin;
@@ -73,6 +73,21 @@ impl SqliteMemory {
     }
 }
 
+/// Current time as Unix milliseconds, the unit of the `expires_at` column
+pub(crate) fn now_millis() -> i64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_millis() as i64)
+        .unwrap_or(0)
+}
+
+/// `expires_at` value for an update: `None` for keys that never expire
+pub(crate) fn expires_at(update: &MemoryUpdate) -> Option<i64> {
+    update
+        .ttl
+        .map(|ttl| now_millis().saturating_add(ttl.as_millis() as i64))
+}
+
 // Need Clone for backup method
 impl Clone for SqliteMemory {
     fn clone(&self) -> Self {
@@ -108,6 +123,43 @@ mod tests {
         assert_eq!(value, Some("test_value".to_string()));
     }
 
+    #[test]
+    fn test_sqlite_memory_expired_keys_are_not_returned() {
+        use skreaver_core::memory::SnapshotableMemory;
+        use std::time::Duration;
+
+        let dir = tempdir().unwrap();
+        let mut memory = SqliteMemory::new(dir.path().join("test_ttl.db")).unwrap();
+
+        let scratch = MemoryKey::new("scratch").unwrap();
+        let durable = MemoryKey::new("durable").unwrap();
+        memory
+            .store_many(vec![
+                MemoryUpdate::new("scratch", "draft")
+                    .unwrap()
+                    .with_ttl(Duration::from_millis(50)),
+                MemoryUpdate::new("durable", "kept")
+                    .unwrap()
+                    .with_ttl(Duration::from_millis(50)),
+            ])
+            .unwrap();
+        // Storing again without a TTL makes the key permanent
+        memory
+            .store(MemoryUpdate::new("durable", "kept").unwrap())
+            .unwrap();
+        assert_eq!(memory.load(&scratch).unwrap(), Some("draft".to_string()));
+
+        std::thread::sleep(Duration::from_millis(100));
+
+        assert_eq!(memory.load(&scratch).unwrap(), None);
+        assert_eq!(
+            memory.load_many(&[scratch, durable]).unwrap(),
+            vec![None, Some("kept".to_string())]
+        );
+        let snapshot = memory.snapshot().unwrap();
+        assert!(!snapshot.contains("scratch"));
+    }
+
     #[test]
     fn test_sqlite_memory_wal_mode() {
         let dir = tempdir().unwrap();
@@ -137,7 +189,7 @@ mod tests {
                 row.get(0)
             })
             .unwrap();
-        assert_eq!(version, 1); // We have 1 migration defined
+        assert_eq!(version, 2); // We have 2 migrations defined
 
         // Check that table exists
         let table_count: i64 = conn
@@ -150,6 +202,42 @@ mod tests {
         assert_eq!(table_count, 1);
     }
 
+    #[test]
+    fn test_sqlite_memory_scan_prefix_matches_literally() {
+        use skreaver_core::memory::ScanableMemory;
+
+        let dir = tempdir().unwrap();
+        let db_path = dir.path().join("test.db");
+        let memory = SqliteMemory::new(&db_path).unwrap();
+        let mut agent_1 = memory
+            .clone()
+            .with_namespace("agent_1".to_string())
+            .unwrap();
+        let mut other = memory
+            .clone()
+            .with_namespace("agentX1".to_string())
+            .unwrap();
+        let mut upper = memory.with_namespace("AGENT_1".to_string()).unwrap();
+
+        agent_1
+            .store(MemoryUpdate::new("history", "v").unwrap())
+            .unwrap();
+        // `_` would match any character if it were not escaped
+        other
+            .store(MemoryUpdate::new("history", "other").unwrap())
+            .unwrap();
+        // SQLite LIKE ignores ASCII case
+        upper
+            .store(MemoryUpdate::new("history", "upper").unwrap())
+            .unwrap();
+
+        assert_eq!(
+            agent_1.scan_prefix("").unwrap(),
+            vec![MemoryKey::new("history").unwrap()]
+        );
+        assert!(agent_1.scan_prefix("tools").unwrap().is_empty());
+    }
+
     #[test]
     fn test_sqlite_memory_admin_operations() {
         let dir = tempdir().unwrap();
@@ -174,8 +262,8 @@ mod tests {
 
         // Test migration status
         let migration_status = memory.migration_status().unwrap();
-        assert_eq!(migration_status.current_version, 1);
-        assert_eq!(migration_status.latest_version, 1);
+        assert_eq!(migration_status.current_version, 2);
+        assert_eq!(migration_status.latest_version, 2);
         assert!(migration_status.pending_migrations.is_empty());
     }
 
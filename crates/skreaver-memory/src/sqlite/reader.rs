@@ -1,11 +1,11 @@
-//! MemoryReader implementation for SqliteMemory
+//! MemoryReader and ScanableMemory implementations for SqliteMemory
 
 use rusqlite::{OptionalExtension, params};
 
 use skreaver_core::error::{MemoryBackend, MemoryError, MemoryErrorKind};
-use skreaver_core::memory::{MemoryKey, MemoryKeys, MemoryReader};
+use skreaver_core::memory::{MemoryKey, MemoryKeys, MemoryReader, ScanableMemory};
 
-use super::SqliteMemory;
+use super::{SqliteMemory, now_millis};
 
 impl MemoryReader for SqliteMemory {
     fn load(&self, key: &MemoryKey) -> Result<Option<String>, MemoryError> {
@@ -13,8 +13,9 @@ impl MemoryReader for SqliteMemory {
         let namespaced_key = self.namespaced_key(key);
 
         conn.query_row(
-            "SELECT value FROM memory WHERE key = ?1",
-            params![namespaced_key],
+            "SELECT value FROM memory
+             WHERE key = ?1 AND (expires_at IS NULL OR expires_at > ?2)",
+            params![namespaced_key, now_millis()],
             |row| row.get(0),
         )
         .optional()
@@ -36,7 +37,8 @@ impl MemoryReader for SqliteMemory {
         let namespaced_keys: Vec<String> = keys.iter().map(|k| self.namespaced_key(k)).collect();
         let placeholders = vec!["?"; namespaced_keys.len()].join(",");
         let query = format!(
-            "SELECT key, value FROM memory WHERE key IN ({})",
+            "SELECT key, value FROM memory
+             WHERE key IN ({}) AND (expires_at IS NULL OR expires_at > ?)",
             placeholders
         );
 
@@ -49,9 +51,11 @@ impl MemoryReader for SqliteMemory {
         })?;
 
         let mut results = std::collections::HashMap::new();
+        let now = now_millis();
         let params: Vec<&dyn rusqlite::ToSql> = namespaced_keys
             .iter()
             .map(|k| k as &dyn rusqlite::ToSql)
+            .chain(std::iter::once(&now as &dyn rusqlite::ToSql))
             .collect();
 
         let rows = stmt
@@ -84,3 +88,59 @@ impl MemoryReader for SqliteMemory {
             .collect())
     }
 }
+
+impl ScanableMemory for SqliteMemory {
+    fn scan_prefix(&self, prefix: &str) -> Result<Vec<MemoryKey>, MemoryError> {
+        let conn = self.pool.acquire()?;
+        let namespace = self
+            .namespace
+            .as_ref()
+            .map(|ns| format!("{}:", ns))
+            .unwrap_or_default();
+        let full_prefix = format!("{}{}", namespace, prefix);
+        let scan_failed = |e: rusqlite::Error| MemoryError::LoadFailed {
+            key: MemoryKeys::scan(),
+            backend: MemoryBackend::Sqlite,
+            kind: MemoryErrorKind::IoError {
+                details: e.to_string(),
+            },
+        };
+
+        let mut stmt = conn
+            .prepare(
+                "SELECT key FROM memory
+                 WHERE key LIKE ?1 ESCAPE '\\' AND (expires_at IS NULL OR expires_at > ?2)",
+            )
+            .map_err(scan_failed)?;
+        let rows = stmt
+            .query_map(
+                params![format!("{}%", escape_like(&full_prefix)), now_millis()],
+                |row| row.get::<_, String>(0),
+            )
+            .map_err(scan_failed)?;
+
+        let mut keys = Vec::new();
+        for row in rows {
+            let raw = row.map_err(scan_failed)?;
+            // LIKE ignores ASCII case, so match the prefix exactly here
+            if raw.starts_with(&full_prefix)
+                && let Ok(key) = MemoryKey::new(&raw[namespace.len()..])
+            {
+                keys.push(key);
+            }
+        }
+        Ok(keys)
+    }
+}
+
+/// Escape SQL `LIKE` wildcards so `pattern` matches literally with `ESCAPE '\'`
+fn escape_like(pattern: &str) -> String {
+    let mut escaped = String::with_capacity(pattern.len());
+    for c in pattern.chars() {
+        if matches!(c, '%' | '_' | '\\') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
@@ -5,8 +5,8 @@ use rusqlite::params;
 use skreaver_core::error::{MemoryBackend, MemoryError, MemoryErrorKind};
 use skreaver_core::memory::SnapshotableMemory;
 
-use super::SqliteMemory;
 use super::pool::SqlitePool;
+use super::{SqliteMemory, now_millis};
 
 impl SqliteMemory {
     /// Create snapshot with proper error handling (internal method)
@@ -21,18 +21,18 @@ impl SqliteMemory {
                 },
             })?;
 
-        let mut stmt = conn.prepare("SELECT key, value FROM memory").map_err(|e| {
-            MemoryError::SnapshotFailed {
+        let mut stmt = conn
+            .prepare("SELECT key, value FROM memory WHERE expires_at IS NULL OR expires_at > ?1")
+            .map_err(|e| MemoryError::SnapshotFailed {
                 backend: MemoryBackend::Sqlite,
                 kind: MemoryErrorKind::IoError {
                     details: SqlitePool::sanitize_error(&e),
                 },
-            }
-        })?;
+            })?;
 
         let mut snapshot = std::collections::HashMap::new();
         let rows = stmt
-            .query_map([], |row| {
+            .query_map(params![now_millis()], |row| {
                 Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
             })
             .map_err(|e| MemoryError::SnapshotFailed {
@@ -5,7 +5,7 @@ use rusqlite::params;
 use skreaver_core::error::{MemoryBackend, MemoryError, MemoryErrorKind};
 use skreaver_core::memory::{MemoryKeys, MemoryUpdate, MemoryWriter};
 
-use super::SqliteMemory;
+use super::{SqliteMemory, expires_at};
 
 impl MemoryWriter for SqliteMemory {
     fn store(&mut self, update: MemoryUpdate) -> Result<(), MemoryError> {
@@ -13,11 +13,12 @@ impl MemoryWriter for SqliteMemory {
         let namespaced_key = self.namespaced_key(&update.key);
 
         conn.execute(
-            "INSERT INTO memory (key, value) VALUES (?1, ?2)
+            "INSERT INTO memory (key, value, expires_at) VALUES (?1, ?2, ?3)
              ON CONFLICT(key) DO UPDATE SET
                 value = excluded.value,
+                expires_at = excluded.expires_at,
                 updated_at = strftime('%s', 'now')",
-            params![namespaced_key, update.value],
+            params![namespaced_key, update.value, expires_at(&update)],
         )
         .map_err(|e| MemoryError::StoreFailed {
             key: update.key.clone(),
@@ -49,9 +50,10 @@ impl MemoryWriter for SqliteMemory {
         {
             let mut stmt = tx
                 .prepare(
-                    "INSERT INTO memory (key, value) VALUES (?1, ?2)
+                    "INSERT INTO memory (key, value, expires_at) VALUES (?1, ?2, ?3)
                  ON CONFLICT(key) DO UPDATE SET
                     value = excluded.value,
+                    expires_at = excluded.expires_at,
                     updated_at = strftime('%s', 'now')",
                 )
                 .map_err(|e| MemoryError::StoreFailed {
@@ -64,7 +66,8 @@ impl MemoryWriter for SqliteMemory {
 
             for update in updates {
                 let namespaced_key = self.namespaced_key(&update.key);
-                stmt.execute(params![namespaced_key, update.value])
+                let expires_at = expires_at(&update);
+                stmt.execute(params![namespaced_key, update.value, expires_at])
                     .map_err(|e| MemoryError::StoreFailed {
                         key: update.key.clone(),
                         backend: MemoryBackend::Sqlite,
@@ -0,0 +1,317 @@
+//! Backend-independent expiry of memory keys.
+//!
+//! Backends such as [`FileMemory`](crate::FileMemory) have no native key
+//! expiry. [`store_with_ttl`] records an expiry timestamp next to the value
+//! under a companion key, and [`MemoryTtlSweeper`] periodically scans those
+//! companion keys and deletes every entry whose deadline has passed.
+//!
+//! Backends with native expiry (in-memory, Redis, SQLite, PostgreSQL) honour
+//! [`MemoryUpdate::ttl`] directly and do not need the sweeper.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::{Arc, Mutex};
+use std::thread::JoinHandle;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use skreaver_core::error::{MemoryBackend, MemoryError, MemoryErrorKind};
+use skreaver_core::memory::{
+    DeletableMemory, MemoryKey, MemoryUpdate, MemoryWriter, ScanableMemory,
+};
+
+/// Prefix of the companion keys holding expiry timestamps.
+pub const TTL_KEY_PREFIX: &str = "__ttl:";
+
+/// Store `update` and record that it expires after `ttl`.
+///
+/// The expiry is written as Unix milliseconds under `__ttl:<key>`, so the
+/// value itself is stored unchanged and remains readable by any consumer.
+pub fn store_with_ttl<M: MemoryWriter>(
+    memory: &mut M,
+    update: MemoryUpdate,
+    ttl: Duration,
+) -> Result<(), MemoryError> {
+    let ttl_key = ttl_key_for(&update.key)?;
+    let expires_at = now_millis().saturating_add(ttl.as_millis() as u64);
+    memory.store_many(vec![
+        update,
+        MemoryUpdate::from_validated(ttl_key, expires_at.to_string()),
+    ])
+}
+
+fn ttl_key_for(key: &MemoryKey) -> Result<MemoryKey, MemoryError> {
+    MemoryKey::new(&format!("{}{}", TTL_KEY_PREFIX, key.as_str())).map_err(|e| {
+        MemoryError::StoreFailed {
+            key: key.clone(),
+            backend: MemoryBackend::InMemory,
+            kind: MemoryErrorKind::InvalidKey {
+                validation_error: format!("Key too long to carry a TTL: {}", e),
+            },
+        }
+    })
+}
+
+fn now_millis() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or(0)
+}
+
+/// Configuration for [`MemoryTtlSweeper`].
+#[derive(Debug, Clone)]
+pub struct TtlSweeperConfig {
+    /// How often the background sweeper scans for expired keys.
+    pub interval: Duration,
+}
+
+impl Default for TtlSweeperConfig {
+    fn default() -> Self {
+        Self {
+            interval: Duration::from_secs(60),
+        }
+    }
+}
+
+/// Snapshot of sweeper counters.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TtlSweepStats {
+    /// Number of completed sweep passes.
+    pub sweeps: u64,
+    /// Total number of expired keys deleted.
+    pub keys_swept: u64,
+    /// Number of sweep passes that failed.
+    pub errors: u64,
+}
+
+#[derive(Debug, Default)]
+struct SweepCounters {
+    sweeps: AtomicU64,
+    keys_swept: AtomicU64,
+    errors: AtomicU64,
+}
+
+/// Deletes keys whose TTL, recorded by [`store_with_ttl`], has passed.
+///
+/// Works with any backend implementing [`ScanableMemory`] and
+/// [`DeletableMemory`]. Use [`sweep`](Self::sweep) for a single pass or
+/// [`spawn`](Self::spawn) to run passes on an interval in the background.
+#[derive(Debug, Clone, Default)]
+pub struct MemoryTtlSweeper {
+    config: TtlSweeperConfig,
+    counters: Arc<SweepCounters>,
+}
+
+impl MemoryTtlSweeper {
+    /// Create a sweeper with the given configuration.
+    pub fn new(config: TtlSweeperConfig) -> Self {
+        Self {
+            config,
+            counters: Arc::new(SweepCounters::default()),
+        }
+    }
+
+    /// Run one sweep pass, returning the number of expired keys deleted.
+    pub fn sweep<M>(&self, memory: &mut M) -> Result<usize, MemoryError>
+    where
+        M: ScanableMemory + DeletableMemory,
+    {
+        match sweep_expired(memory, now_millis()) {
+            Ok(swept) => {
+                self.counters.sweeps.fetch_add(1, Ordering::Relaxed);
+                self.counters
+                    .keys_swept
+                    .fetch_add(swept as u64, Ordering::Relaxed);
+                if swept > 0 {
+                    tracing::debug!(swept, "Swept expired memory keys");
+                }
+                Ok(swept)
+            }
+            Err(e) => {
+                self.counters.errors.fetch_add(1, Ordering::Relaxed);
+                Err(e)
+            }
+        }
+    }
+
+    /// Current sweep counters.
+    pub fn stats(&self) -> TtlSweepStats {
+        TtlSweepStats {
+            sweeps: self.counters.sweeps.load(Ordering::Relaxed),
+            keys_swept: self.counters.keys_swept.load(Ordering::Relaxed),
+            errors: self.counters.errors.load(Ordering::Relaxed),
+        }
+    }
+
+    /// Start sweeping `memory` every configured interval on a background thread.
+    ///
+    /// The returned handle stops the thread when [`shutdown`](TtlSweeperHandle::shutdown)
+    /// is called or the handle is dropped.
+    pub fn spawn<M>(&self, memory: Arc<Mutex<M>>) -> TtlSweeperHandle
+    where
+        M: ScanableMemory + DeletableMemory + 'static,
+    {
+        let (stop_tx, stop_rx) = mpsc::channel::<()>();
+        let sweeper = self.clone();
+        let interval = self.config.interval;
+
+        let thread = std::thread::spawn(move || {
+            // A stop message or a dropped handle ends the loop
+            while let Err(RecvTimeoutError::Timeout) = stop_rx.recv_timeout(interval) {
+                let mut guard = match memory.lock() {
+                    Ok(guard) => guard,
+                    Err(poisoned) => poisoned.into_inner(),
+                };
+                if let Err(e) = sweeper.sweep(&mut *guard) {
+                    tracing::warn!("Failed to sweep expired memory keys: {}", e);
+                }
+            }
+        });
+
+        TtlSweeperHandle {
+            stop_tx: Some(stop_tx),
+            thread: Some(thread),
+        }
+    }
+}
+
+/// Handle to a running background sweeper.
+#[derive(Debug)]
+pub struct TtlSweeperHandle {
+    stop_tx: Option<mpsc::Sender<()>>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl TtlSweeperHandle {
+    /// Stop the sweeper and wait for any in-flight pass to finish.
+    pub fn shutdown(mut self) {
+        self.stop();
+    }
+
+    fn stop(&mut self) {
+        if let Some(tx) = self.stop_tx.take() {
+            let _ = tx.send(());
+        }
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+impl Drop for TtlSweeperHandle {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
+
+fn sweep_expired<M>(memory: &mut M, now: u64) -> Result<usize, MemoryError>
+where
+    M: ScanableMemory + DeletableMemory,
+{
+    let ttl_keys = memory.scan_prefix(TTL_KEY_PREFIX)?;
+    let mut swept = 0;
+
+    for ttl_key in ttl_keys {
+        let Some(raw) = memory.load(&ttl_key)? else {
+            continue;
+        };
+        // Unparseable deadlines are treated as already expired
+        let expired = raw.parse::<u64>().map_or(true, |deadline| deadline <= now);
+        if !expired {
+            continue;
+        }
+
+        let value_key = ttl_key
+            .as_str()
+            .strip_prefix(TTL_KEY_PREFIX)
+            .and_then(|k| MemoryKey::new(k).ok());
+        if let Some(value_key) = value_key
+            && memory.delete(&value_key)?
+        {
+            swept += 1;
+        }
+        memory.delete(&ttl_key)?;
+    }
+
+    Ok(swept)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use skreaver_core::InMemoryMemory;
+    use skreaver_core::memory::MemoryReader;
+
+    fn key(s: &str) -> MemoryKey {
+        MemoryKey::new(s).unwrap()
+    }
+
+    #[test]
+    fn sweep_removes_expired_and_keeps_live_keys() {
+        let mut memory = InMemoryMemory::new();
+        store_with_ttl(
+            &mut memory,
+            MemoryUpdate::new("session", "stale").unwrap(),
+            Duration::ZERO,
+        )
+        .unwrap();
+        store_with_ttl(
+            &mut memory,
+            MemoryUpdate::new("profile", "fresh").unwrap(),
+            Duration::from_secs(3600),
+        )
+        .unwrap();
+        memory
+            .store(MemoryUpdate::new("permanent", "forever").unwrap())
+            .unwrap();
+
+        let sweeper = MemoryTtlSweeper::default();
+        assert_eq!(sweeper.sweep(&mut memory).unwrap(), 1);
+
+        assert_eq!(memory.load(&key("session")).unwrap(), None);
+        assert_eq!(memory.load(&key("__ttl:session")).unwrap(), None);
+        assert_eq!(
+            memory.load(&key("profile")).unwrap().as_deref(),
+            Some("fresh")
+        );
+        assert!(memory.load(&key("__ttl:profile")).unwrap().is_some());
+        assert_eq!(
+            memory.load(&key("permanent")).unwrap().as_deref(),
+            Some("forever")
+        );
+
+        assert_eq!(
+            sweeper.stats(),
+            TtlSweepStats {
+                sweeps: 1,
+                keys_swept: 1,
+                errors: 0,
+            }
+        );
+    }
+
+    #[test]
+    fn background_sweeper_runs_and_shuts_down() {
+        let memory = Arc::new(Mutex::new(InMemoryMemory::new()));
+        store_with_ttl(
+            &mut *memory.lock().unwrap(),
+            MemoryUpdate::new("expired", "x").unwrap(),
+            Duration::ZERO,
+        )
+        .unwrap();
+
+        let sweeper = MemoryTtlSweeper::new(TtlSweeperConfig {
+            interval: Duration::from_millis(10),
+        });
+        let handle = sweeper.spawn(Arc::clone(&memory));
+
+        let deadline = std::time::Instant::now() + Duration::from_secs(5);
+        while sweeper.stats().keys_swept == 0 && std::time::Instant::now() < deadline {
+            std::thread::sleep(Duration::from_millis(5));
+        }
+        handle.shutdown();
+
+        assert_eq!(sweeper.stats().keys_swept, 1);
+        assert_eq!(memory.lock().unwrap().load(&key("expired")).unwrap(), None);
+    }
+}
@@ -0,0 +1,124 @@
+//! Integration tests for portable memory bundles
+//!
+//! These tests move memory between different backends through the
+//! JSON bundle format and check that values survive unchanged.
+
+use skreaver_core::InMemoryMemory;
+use skreaver_memory::{
+    FileMemory, MemoryBundle, MemoryKey, MemoryReader, MemoryUpdate, MemoryWriter, export_memory,
+    import_memory, replace_memory,
+};
+use tempfile::TempDir;
+
+const ENTRIES: &[(&str, &str)] = &[
+    ("agent:name", "scout"),
+    ("context.history", "[{\"role\":\"user\",\"text\":\"hi\"}]"),
+    ("unicode", "héllo — 世界 🚀"),
+    ("multiline", "line one\nline two\ttabbed \"quoted\""),
+    ("empty", ""),
+];
+
+fn seeded_in_memory() -> InMemoryMemory {
+    let mut memory = InMemoryMemory::new();
+    for (key, value) in ENTRIES {
+        memory
+            .store(MemoryUpdate::new(key, value).unwrap())
+            .unwrap();
+    }
+    memory
+}
+
+fn load(memory: &dyn MemoryReader, key: &str) -> Option<String> {
+    memory.load(&MemoryKey::new(key).unwrap()).unwrap()
+}
+
+#[test]
+fn test_in_memory_to_file_preserves_values() {
+    let dir = TempDir::new().unwrap();
+    let source = seeded_in_memory();
+
+    let json = export_memory(&source)
+        .unwrap()
+        .with_metadata("source", serde_json::json!("in_memory"))
+        .to_json()
+        .unwrap();
+    let bundle = MemoryBundle::from_json(&json).unwrap();
+    assert_eq!(bundle.metadata["source"], "in_memory");
+
+    let mut target = FileMemory::new(dir.path().join("memory.json"));
+    import_memory(&mut target, &bundle).unwrap();
+
+    for (key, value) in ENTRIES {
+        assert_eq!(load(&target, key).as_deref(), Some(*value), "key {key}");
+    }
+
+    // Values must also survive the file round trip
+    let reopened = FileMemory::new(dir.path().join("memory.json"));
+    for (key, value) in ENTRIES {
+        assert_eq!(load(&reopened, key).as_deref(), Some(*value), "key {key}");
+    }
+}
+
+#[test]
+fn test_file_to_in_memory_round_trip() {
+    let dir = TempDir::new().unwrap();
+    let mut file = FileMemory::new(dir.path().join("memory.json"));
+    import_memory(&mut file, &export_memory(&seeded_in_memory()).unwrap()).unwrap();
+
+    let mut target = InMemoryMemory::new();
+    import_memory(&mut target, &export_memory(&file).unwrap()).unwrap();
+
+    assert_eq!(
+        export_memory(&target).unwrap().entries,
+        export_memory(&file).unwrap().entries
+    );
+}
+
+#[test]
+fn test_merge_and_replace_into_file() {
+    let dir = TempDir::new().unwrap();
+    let bundle = export_memory(&seeded_in_memory()).unwrap();
+
+    let mut merged = FileMemory::new(dir.path().join("merged.json"));
+    merged
+        .store(MemoryUpdate::new("local_only", "kept").unwrap())
+        .unwrap();
+    import_memory(&mut merged, &bundle).unwrap();
+    assert_eq!(load(&merged, "local_only").as_deref(), Some("kept"));
+    assert_eq!(load(&merged, "agent:name").as_deref(), Some("scout"));
+
+    let mut replaced = FileMemory::new(dir.path().join("replaced.json"));
+    replaced
+        .store(MemoryUpdate::new("local_only", "dropped").unwrap())
+        .unwrap();
+    replace_memory(&mut replaced, &bundle).unwrap();
+    assert_eq!(load(&replaced, "local_only"), None);
+    assert_eq!(export_memory(&replaced).unwrap().entries, bundle.entries);
+}
+
+#[cfg(feature = "sqlite")]
+#[test]
+fn test_snapshot_moves_in_memory_to_sqlite() {
+    use skreaver_core::memory::SnapshotableMemory;
+    use skreaver_memory::{MemorySnapshot, SqliteMemory};
+
+    let dir = TempDir::new().unwrap();
+    let json = seeded_in_memory()
+        .export_snapshot()
+        .unwrap()
+        .to_json()
+        .unwrap();
+
+    let mut target = SqliteMemory::new(dir.path().join("memory.db")).unwrap();
+    target
+        .import_snapshot(MemorySnapshot::from_json(&json).unwrap())
+        .unwrap();
+
+    for (key, value) in ENTRIES {
+        assert_eq!(load(&target, key).as_deref(), Some(*value), "key {key}");
+    }
+    assert_eq!(
+        target.export_snapshot().unwrap().entries,
+        MemorySnapshot::from_json(&json).unwrap().entries
+    );
+}
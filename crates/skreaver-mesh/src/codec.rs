@@ -0,0 +1,429 @@
+//! Wire codecs for mesh messages
+//!
+//! Messages are JSON-encoded by default, which keeps them readable in Redis
+//! and compatible with [`Message::to_json`]. High-throughput deployments can
+//! switch a mesh to MessagePack or bincode for smaller, faster frames.
+//!
+//! # Frame Format
+//!
+//! - **JSON** frames are plain JSON text, byte-for-byte identical to
+//!   [`Message::to_json`].
+//! - **Binary** frames start with a 4-byte header (`\0SK` followed by a codec
+//!   id) so a reader configured for a different codec fails with
+//!   [`MeshError::CodecMismatch`] instead of producing garbage.
+//!
+//! Binary codecs keep the tagged payload semantics of the JSON format: text,
+//! JSON and binary payloads always decode to the same variant. Binary payloads
+//! are carried as raw bytes rather than base64.
+//!
+//! # Example
+//!
+//! ```
+//! use skreaver_mesh::{Message, MessageCodec};
+//!
+//! let message = Message::new(vec![0u8, 159, 146, 150]);
+//! let codec = MessageCodec::MessagePack;
+//!
+//! let frame = codec.encode(&message).unwrap();
+//! let decoded = codec.decode(&frame).unwrap();
+//! assert_eq!(decoded.id, message.id);
+//!
+//! // Reading the frame with another codec fails clearly
+//! assert!(MessageCodec::Json.decode(&frame).is_err());
+//! ```
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+use crate::error::{MeshError, MeshResult};
+use crate::message::{Message, MessageId, MessageMetadata, MessagePayload, Route};
+
+/// Header prefix for binary frames; never valid at the start of JSON text
+const FRAME_MAGIC: [u8; 3] = *b"\0SK";
+
+/// Length of the binary frame header (magic + codec id)
+const HEADER_LEN: usize = FRAME_MAGIC.len() + 1;
+
+/// Serialization format used on the wire for mesh messages
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MessageCodec {
+    /// Human-readable JSON (default)
+    #[default]
+    Json,
+    /// Compact MessagePack encoding
+    #[serde(rename = "msgpack")]
+    MessagePack,
+    /// Compact bincode encoding
+    Bincode,
+}
+
+impl MessageCodec {
+    /// Get the canonical codec name
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MessageCodec::Json => "json",
+            MessageCodec::MessagePack => "msgpack",
+            MessageCodec::Bincode => "bincode",
+        }
+    }
+
+    /// Frame header id for binary codecs
+    fn frame_id(&self) -> Option<u8> {
+        match self {
+            MessageCodec::Json => None,
+            MessageCodec::MessagePack => Some(1),
+            MessageCodec::Bincode => Some(2),
+        }
+    }
+
+    fn from_frame_id(id: u8) -> Option<Self> {
+        match id {
+            1 => Some(MessageCodec::MessagePack),
+            2 => Some(MessageCodec::Bincode),
+            _ => None,
+        }
+    }
+
+    /// Detect which codec produced a frame
+    ///
+    /// Returns `None` if the frame was not produced by any known codec.
+    pub fn detect(frame: &[u8]) -> Option<Self> {
+        if frame.starts_with(&FRAME_MAGIC) {
+            return frame
+                .get(FRAME_MAGIC.len())
+                .and_then(|id| Self::from_frame_id(*id));
+        }
+
+        match frame.iter().find(|b| !b.is_ascii_whitespace()) {
+            Some(b'{') => Some(MessageCodec::Json),
+            _ => None,
+        }
+    }
+
+    /// Encode a message into a frame
+    pub fn encode(&self, message: &Message) -> MeshResult<Vec<u8>> {
+        let Some(id) = self.frame_id() else {
+            return serde_json::to_vec(message)
+                .map_err(|e| MeshError::SerializationFailed(e.to_string()));
+        };
+
+        let wire = WireMessageRef::from_message(message)?;
+        let mut frame = Vec::with_capacity(128);
+        frame.extend_from_slice(&FRAME_MAGIC);
+        frame.push(id);
+
+        match self {
+            MessageCodec::MessagePack => rmp_serde::encode::write_named(&mut frame, &wire)
+                .map_err(|e| MeshError::SerializationFailed(e.to_string()))?,
+            MessageCodec::Bincode => {
+                bincode::serde::encode_into_std_write(&wire, &mut frame, bincode_config())
+                    .map_err(|e| MeshError::SerializationFailed(e.to_string()))?;
+            }
+            MessageCodec::Json => unreachable!("JSON frames have no header"),
+        }
+
+        Ok(frame)
+    }
+
+    /// Decode a frame produced by this codec
+    ///
+    /// Fails with [`MeshError::CodecMismatch`] if the frame was produced by a
+    /// different codec.
+    pub fn decode(&self, frame: &[u8]) -> MeshResult<Message> {
+        match Self::detect(frame) {
+            Some(found) if found == *self => {}
+            Some(found) => {
+                return Err(MeshError::CodecMismatch {
+                    expected: *self,
+                    found,
+                });
+            }
+            None => {
+                return Err(MeshError::DeserializationFailed(format!(
+                    "frame is not a valid {} message",
+                    self
+                )));
+            }
+        }
+
+        let body = &frame[if self.frame_id().is_some() {
+            HEADER_LEN
+        } else {
+            0
+        }..];
+
+        let wire: WireMessage = match self {
+            MessageCodec::Json => {
+                return serde_json::from_slice(body)
+                    .map_err(|e| MeshError::DeserializationFailed(e.to_string()));
+            }
+            MessageCodec::MessagePack => rmp_serde::from_slice(body)
+                .map_err(|e| MeshError::DeserializationFailed(e.to_string()))?,
+            MessageCodec::Bincode => {
+                let (wire, read) = bincode::serde::decode_from_slice(body, bincode_config())
+                    .map_err(|e| MeshError::DeserializationFailed(e.to_string()))?;
+                if read != body.len() {
+                    return Err(MeshError::DeserializationFailed(format!(
+                        "{} trailing bytes after bincode message",
+                        body.len() - read
+                    )));
+                }
+                wire
+            }
+        };
+
+        wire.into_message()
+    }
+}
+
+impl fmt::Display for MessageCodec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for MessageCodec {
+    type Err = MeshError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "json" => Ok(MessageCodec::Json),
+            "msgpack" | "messagepack" => Ok(MessageCodec::MessagePack),
+            "bincode" => Ok(MessageCodec::Bincode),
+            other => Err(MeshError::InvalidConfig(format!(
+                "Unknown message codec '{}'. Expected one of: json, msgpack, bincode",
+                other
+            ))),
+        }
+    }
+}
+
+fn bincode_config() -> bincode::config::Configuration {
+    bincode::config::standard()
+}
+
+// ============================================================================
+// Wire representation for binary codecs
+// ============================================================================
+//
+// Non-self-describing formats such as bincode cannot deserialize
+// `serde_json::Value` or adjacently tagged enums, so binary codecs use an
+// explicit wire shape. JSON payloads are carried as JSON text, binary payloads
+// as raw bytes.
+
+#[derive(Serialize)]
+struct WireMessageRef<'a> {
+    id: &'a MessageId,
+    route: &'a Route,
+    payload: WirePayloadRef<'a>,
+    metadata: &'a MessageMetadata,
+    timestamp: &'a DateTime<Utc>,
+    correlation_id: &'a Option<String>,
+}
+
+#[derive(Serialize)]
+enum WirePayloadRef<'a> {
+    Text(&'a str),
+    Json(String),
+    Binary(&'a [u8]),
+}
+
+impl<'a> WireMessageRef<'a> {
+    fn from_message(message: &'a Message) -> MeshResult<Self> {
+        let payload = match &message.payload {
+            MessagePayload::Text(text) => WirePayloadRef::Text(text),
+            MessagePayload::Json(value) => WirePayloadRef::Json(
+                serde_json::to_string(value)
+                    .map_err(|e| MeshError::SerializationFailed(e.to_string()))?,
+            ),
+            MessagePayload::Binary(bytes) => WirePayloadRef::Binary(bytes),
+        };
+
+        Ok(Self {
+            id: &message.id,
+            route: &message.route,
+            payload,
+            metadata: &message.metadata,
+            timestamp: &message.timestamp,
+            correlation_id: &message.correlation_id,
+        })
+    }
+}
+
+#[derive(Deserialize)]
+struct WireMessage {
+    id: MessageId,
+    route: Route,
+    payload: WirePayload,
+    metadata: MessageMetadata,
+    timestamp: DateTime<Utc>,
+    correlation_id: Option<String>,
+}
+
+#[derive(Deserialize)]
+enum WirePayload {
+    Text(String),
+    Json(String),
+    Binary(Vec<u8>),
+}
+
+impl WireMessage {
+    fn into_message(self) -> MeshResult<Message> {
+        let payload = match self.payload {
+            WirePayload::Text(text) => MessagePayload::Text(text),
+            WirePayload::Json(json) => MessagePayload::Json(
+                serde_json::from_str(&json)
+                    .map_err(|e| MeshError::DeserializationFailed(e.to_string()))?,
+            ),
+            WirePayload::Binary(bytes) => MessagePayload::Binary(bytes),
+        };
+
+        Ok(Message {
+            id: self.id,
+            route: self.route,
+            payload,
+            metadata: self.metadata,
+            timestamp: self.timestamp,
+            correlation_id: self.correlation_id,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::AgentId;
+
+    const ALL_CODECS: [MessageCodec; 3] = [
+        MessageCodec::Json,
+        MessageCodec::MessagePack,
+        MessageCodec::Bincode,
+    ];
+
+    fn sample(payload: impl Into<MessagePayload>) -> Message {
+        Message::unicast(
+            AgentId::new_unchecked("agent-1"),
+            AgentId::new_unchecked("agent-2"),
+            payload,
+        )
+        .with_metadata("priority", "high")
+        .with_correlation_id("req-123")
+    }
+
+    fn roundtrip(codec: MessageCodec, message: &Message) -> Message {
+        let frame = codec.encode(message).unwrap();
+        assert_eq!(MessageCodec::detect(&frame), Some(codec));
+        let decoded = codec.decode(&frame).unwrap();
+
+        assert_eq!(decoded.id, message.id);
+        assert_eq!(decoded.route, message.route);
+        assert_eq!(decoded.metadata, message.metadata);
+        assert_eq!(decoded.timestamp, message.timestamp);
+        assert_eq!(decoded.correlation_id, message.correlation_id);
+        decoded
+    }
+
+    #[test]
+    fn test_codecs_roundtrip_text_payload() {
+        for codec in ALL_CODECS {
+            let decoded = roundtrip(codec, &sample("hello world"));
+            match decoded.payload {
+                MessagePayload::Text(text) => assert_eq!(text, "hello world", "{}", codec),
+                other => panic!("{} decoded text payload as {:?}", codec, other),
+            }
+        }
+    }
+
+    #[test]
+    fn test_codecs_roundtrip_json_payload() {
+        let value = serde_json::json!({"task": "summarize", "depth": 3, "tags": ["a", null]});
+
+        for codec in ALL_CODECS {
+            let decoded = roundtrip(codec, &sample(value.clone()));
+            match decoded.payload {
+                MessagePayload::Json(decoded_value) => {
+                    assert_eq!(decoded_value, value, "{}", codec)
+                }
+                other => panic!("{} decoded json payload as {:?}", codec, other),
+            }
+        }
+    }
+
+    #[test]
+    fn test_codecs_preserve_binary_payload() {
+        // Includes bytes that are invalid UTF-8 and look like JSON/frame headers
+        let bytes = vec![0u8, b'S', b'K', 1, b'{', 0xff, 0x80, 0x00, 0xfe];
+
+        for codec in ALL_CODECS {
+            let decoded = roundtrip(codec, &sample(bytes.clone()));
+            match decoded.payload {
+                MessagePayload::Binary(decoded_bytes) => {
+                    assert_eq!(decoded_bytes, bytes, "{}", codec)
+                }
+                other => panic!("{} decoded binary payload as {:?}", codec, other),
+            }
+        }
+    }
+
+    #[test]
+    fn test_json_frames_match_to_json() {
+        let message = sample("compat");
+        let frame = MessageCodec::Json.encode(&message).unwrap();
+
+        assert_eq!(frame, message.to_json().unwrap().into_bytes());
+        let decoded = MessageCodec::Json
+            .decode(message.to_json().unwrap().as_bytes())
+            .unwrap();
+        assert_eq!(decoded.id, message.id);
+    }
+
+    #[test]
+    fn test_cross_codec_reads_fail_clearly() {
+        let message = sample("hello");
+
+        for writer in ALL_CODECS {
+            let frame = writer.encode(&message).unwrap();
+            for reader in ALL_CODECS.into_iter().filter(|c| *c != writer) {
+                match reader.decode(&frame) {
+                    Err(MeshError::CodecMismatch { expected, found }) => {
+                        assert_eq!(expected, reader);
+                        assert_eq!(found, writer);
+                    }
+                    other => panic!("{} read {} frame: {:?}", reader, writer, other),
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_decode_rejects_unknown_frames() {
+        for codec in ALL_CODECS {
+            assert!(matches!(
+                codec.decode(b"not a frame"),
+                Err(MeshError::DeserializationFailed(_))
+            ));
+            assert!(matches!(
+                codec.decode(b"\0SK\x09"),
+                Err(MeshError::DeserializationFailed(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_codec_from_str() {
+        assert_eq!("json".parse::<MessageCodec>().unwrap(), MessageCodec::Json);
+        assert_eq!(
+            "MessagePack".parse::<MessageCodec>().unwrap(),
+            MessageCodec::MessagePack
+        );
+        assert_eq!(
+            "bincode".parse::<MessageCodec>().unwrap(),
+            MessageCodec::Bincode
+        );
+        assert!("yaml".parse::<MessageCodec>().is_err());
+        assert_eq!(MessageCodec::default(), MessageCodec::Json);
+    }
+}
@@ -0,0 +1,118 @@
+//! Consumer groups for load-balanced topic consumption
+//!
+//! Plain topic subscriptions fan out: every subscriber receives every message.
+//! Members of a consumer group instead compete for messages, so each message
+//! published to the topic is delivered to exactly one member of each group.
+//! Fan-out subscribers and every group each still see all messages.
+//!
+//! Messages are rebalanced as membership changes: undelivered messages held
+//! for a member that leaves are handed to the remaining members, and messages
+//! published after a group is created but while it has no members are kept,
+//! up to a backend-specific limit, until one joins.
+//!
+//! Members acknowledge each message with [`ConsumerGroups::ack`] once they
+//! have handled it. Durable backends redeliver messages that are never
+//! acknowledged, for example because the member crashed mid-handling, to
+//! another member of the group.
+
+use async_trait::async_trait;
+
+use crate::{
+    error::{MeshError, MeshResult},
+    mesh::{AgentMesh, MessageStream},
+    message::Message,
+    types::{AgentId, Topic},
+};
+
+/// Validate a consumer group name
+///
+/// Group names follow the same rules as topic names.
+pub fn validate_group_name(group: &str) -> MeshResult<()> {
+    Topic::parse(group)
+        .map(|_| ())
+        .map_err(|e| MeshError::InvalidConfig(format!("Invalid consumer group name: {}", e)))
+}
+
+/// Extension trait for competing-consumer topic subscriptions
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use futures::StreamExt;
+/// use skreaver_mesh::{AgentId, ConsumerGroups, InMemoryMesh, Topic};
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let mesh = InMemoryMesh::new();
+/// let topic = Topic::from("jobs");
+///
+/// // Each job goes to exactly one of the two workers
+/// let mut worker_1 = mesh
+///     .subscribe_group(&topic, "workers", &AgentId::new_unchecked("worker-1"))
+///     .await?;
+/// let mut worker_2 = mesh
+///     .subscribe_group(&topic, "workers", &AgentId::new_unchecked("worker-2"))
+///     .await?;
+///
+/// // Acknowledge a job only after it has been handled
+/// if let Some(job) = worker_1.next().await {
+///     let job = job?;
+///     // ... handle the job ...
+///     mesh.ack(&topic, "workers", &job).await?;
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[async_trait]
+pub trait ConsumerGroups: AgentMesh {
+    /// Join `group` on `topic` as `member`, returning the member's share of messages
+    ///
+    /// Joining again with the same member ID replaces the previous
+    /// subscription, whose stream then ends. Dropping the stream leaves the
+    /// group.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MeshError` if the group name is invalid or joining fails
+    async fn subscribe_group(
+        &self,
+        topic: &Topic,
+        group: &str,
+        member: &AgentId,
+    ) -> MeshResult<MessageStream>;
+
+    /// Leave `group` on `topic`, ending the member's stream
+    ///
+    /// Messages not yet delivered to the member are rebalanced to the
+    /// remaining members.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MeshError` if leaving fails
+    async fn leave_group(&self, topic: &Topic, group: &str, member: &AgentId) -> MeshResult<()>;
+
+    /// Acknowledge that a message received from `group` has been handled
+    ///
+    /// Call this only after handling succeeded. A message that is never
+    /// acknowledged stays pending and is redelivered to a member of the group
+    /// by backends that persist deliveries; backends without persistence
+    /// accept the acknowledgement as a no-op.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MeshError` if the message was not received from a consumer
+    /// group or the acknowledgement fails
+    async fn ack(&self, topic: &Topic, group: &str, message: &Message) -> MeshResult<()>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_group_name() {
+        assert!(validate_group_name("workers").is_ok());
+        assert!(validate_group_name("team.a-workers_1").is_ok());
+        assert!(validate_group_name("").is_err());
+        assert!(validate_group_name("has space").is_err());
+    }
+}
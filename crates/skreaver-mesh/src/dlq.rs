@@ -3,7 +3,11 @@
 //! The DLQ stores messages that failed to be delivered, with TTL and volume limits.
 //! Messages in the DLQ can be retried or inspected for debugging.
 
-use crate::{error::MeshResult, message::Message};
+use crate::{
+    error::MeshResult,
+    mesh::AgentMesh,
+    message::{Message, Route},
+};
 use chrono::{DateTime, Duration, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::VecDeque;
@@ -102,6 +106,49 @@ impl DlqEntry {
         self.retry_count += 1;
         self.last_error = error;
     }
+
+    /// Time since the message was added to the DLQ
+    pub fn age(&self) -> Duration {
+        Utc::now() - self.added_at
+    }
+}
+
+/// Filter and page for inspecting DLQ entries
+///
+/// Filters apply before paging; entries are returned oldest first.
+#[derive(Debug, Clone, Default)]
+pub struct DlqQuery {
+    /// Only entries whose failure reason contains this text
+    pub reason: Option<String>,
+    /// Only entries at least this old
+    pub min_age: Option<Duration>,
+    /// Only entries at most this old
+    pub max_age: Option<Duration>,
+    /// Number of matching entries to skip
+    pub offset: usize,
+    /// Maximum number of entries to return (None = all)
+    pub limit: Option<usize>,
+}
+
+impl DlqQuery {
+    /// Check whether an entry passes the reason and age filters
+    pub fn matches(&self, entry: &DlqEntry) -> bool {
+        let age = entry.age();
+        self.reason
+            .as_deref()
+            .is_none_or(|reason| entry.failure_reason.contains(reason))
+            && self.min_age.is_none_or(|min| age >= min)
+            && self.max_age.is_none_or(|max| age <= max)
+    }
+}
+
+/// One page of DLQ entries
+#[derive(Debug, Clone)]
+pub struct DlqPage {
+    /// Entries on this page, oldest first
+    pub entries: Vec<DlqEntry>,
+    /// Number of entries matching the filters across all pages
+    pub total: usize,
 }
 
 /// Statistics for the Dead Letter Queue
@@ -119,6 +166,8 @@ pub struct DlqStats {
     pub total_exhausted: u64,
     /// Total successful retries
     pub total_retried: u64,
+    /// Age of the oldest message currently in DLQ
+    pub oldest_age: Option<Duration>,
 }
 
 /// Dead Letter Queue for failed messages
@@ -189,6 +238,80 @@ impl DeadLetterQueue {
         queue.iter().cloned().collect()
     }
 
+    /// Get a page of messages matching the query (for inspection)
+    pub async fn query(&self, query: &DlqQuery) -> DlqPage {
+        let queue = self.queue.read().await;
+        let matching = queue.iter().filter(|entry| query.matches(entry));
+        let total = matching.clone().count();
+        let entries = matching
+            .skip(query.offset)
+            .take(query.limit.unwrap_or(usize::MAX))
+            .cloned()
+            .collect();
+        DlqPage { entries, total }
+    }
+
+    /// Get a single message without removing it
+    pub async fn peek(&self, message_id: &str) -> Option<DlqEntry> {
+        let queue = self.queue.read().await;
+        queue
+            .iter()
+            .find(|entry| entry.message.id.as_str() == message_id)
+            .cloned()
+    }
+
+    /// Re-send a message through `mesh`, removing it from the DLQ on success
+    ///
+    /// Unicast and system messages are sent to their recipient; broadcast and
+    /// anonymous messages are broadcast again. A failed re-send counts as a
+    /// retry and leaves the message in the DLQ. Returns `Ok(false)` if the
+    /// message is not in the DLQ.
+    pub async fn replay(&self, message_id: &str, mesh: &dyn AgentMesh) -> MeshResult<bool> {
+        let Some(entry) = self.peek(message_id).await else {
+            return Ok(false);
+        };
+
+        let message = entry.message;
+        let result = match message.route.clone() {
+            Route::Unicast { to, .. } | Route::System { to } => mesh.send(&to, message).await,
+            Route::Broadcast { .. } | Route::Anonymous => mesh.broadcast(message).await,
+        };
+
+        match result {
+            Ok(()) => {
+                let mut queue = self.queue.write().await;
+                let mut stats = self.stats.write().await;
+                queue.retain(|entry| entry.message.id.as_str() != message_id);
+                stats.total_retried = stats.total_retried.saturating_add(1);
+                stats.current_size = queue.len();
+                debug!("Replayed message {} from DLQ", message_id);
+                Ok(true)
+            }
+            Err(e) => {
+                self.mark_retried(message_id, Some(e.to_string())).await?;
+                Err(e)
+            }
+        }
+    }
+
+    /// Remove all messages matching the query's filters, ignoring paging
+    pub async fn purge(&self, query: &DlqQuery) -> MeshResult<usize> {
+        let mut queue = self.queue.write().await;
+        let mut stats = self.stats.write().await;
+
+        let initial_len = queue.len();
+        queue.retain(|entry| !query.matches(entry));
+        let purged = initial_len - queue.len();
+
+        if purged > 0 {
+            stats.total_removed = stats.total_removed.saturating_add(purged as u64);
+            stats.current_size = queue.len();
+            debug!("Purged {} messages from DLQ", purged);
+        }
+
+        Ok(purged)
+    }
+
     /// Get messages that are ready for retry
     pub async fn get_retriable(&self, limit: usize) -> Vec<DlqEntry> {
         let Some(config) = &self.config else {
@@ -284,7 +407,11 @@ impl DeadLetterQueue {
 
     /// Get current statistics
     pub async fn stats(&self) -> DlqStats {
-        self.stats.read().await.clone()
+        let queue = self.queue.read().await;
+        let mut stats = self.stats.read().await.clone();
+        // Entries are appended in arrival order, so the front is the oldest
+        stats.oldest_age = queue.front().map(DlqEntry::age);
+        stats
     }
 
     /// Get current queue size
@@ -329,6 +456,7 @@ impl DeadLetterQueue {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::message::MessagePayload;
 
     #[tokio::test]
     async fn test_dlq_add_and_list() {
@@ -416,6 +544,79 @@ mod tests {
         let stats = dlq.stats().await;
         assert_eq!(stats.total_added, 2);
         assert_eq!(stats.current_size, 2);
+        assert!(stats.oldest_age.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_dlq_query_filters_and_pages() {
+        let dlq = DeadLetterQueue::with_defaults();
+        for i in 0..5 {
+            let reason = if i % 2 == 0 { "timeout" } else { "rejected" };
+            dlq.add(Message::new(format!("msg-{}", i)), reason)
+                .await
+                .unwrap();
+        }
+
+        let page = dlq
+            .query(&DlqQuery {
+                reason: Some("timeout".to_string()),
+                offset: 1,
+                limit: Some(1),
+                ..Default::default()
+            })
+            .await;
+        assert_eq!(page.total, 3);
+        assert_eq!(page.entries.len(), 1);
+        assert!(
+            matches!(&page.entries[0].message.payload, MessagePayload::Text(text) if text == "msg-2")
+        );
+
+        let too_old = dlq
+            .query(&DlqQuery {
+                min_age: Some(Duration::hours(1)),
+                ..Default::default()
+            })
+            .await;
+        assert_eq!(too_old.total, 0);
+
+        let purged = dlq
+            .purge(&DlqQuery {
+                reason: Some("rejected".to_string()),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert_eq!(purged, 2);
+        assert_eq!(dlq.size().await, 3);
+        assert_eq!(dlq.stats().await.total_removed, 2);
+    }
+
+    #[tokio::test]
+    async fn test_dlq_replay_resends_and_removes() {
+        use crate::memory::InMemoryMesh;
+        use crate::types::AgentId;
+
+        let mesh = InMemoryMesh::new();
+        let dlq = DeadLetterQueue::with_defaults();
+        let to = AgentId::new_unchecked("worker");
+        let msg = Message::unicast(AgentId::new_unchecked("sender"), to.clone(), "retry me");
+        let msg_id = msg.id.clone();
+        dlq.add(msg, "worker offline").await.unwrap();
+
+        assert!(dlq.peek(msg_id.as_str()).await.is_some());
+        assert!(dlq.replay(msg_id.as_str(), &mesh).await.unwrap());
+
+        let delivered = mesh
+            .receive(&to, std::time::Duration::from_millis(100))
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(delivered.id, msg_id);
+        assert_eq!(dlq.size().await, 0);
+        assert_eq!(dlq.stats().await.total_retried, 1);
+
+        // Replaying a message that is no longer in the DLQ is a no-op
+        assert!(!dlq.replay(msg_id.as_str(), &mesh).await.unwrap());
     }
 
     #[tokio::test]
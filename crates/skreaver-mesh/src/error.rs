@@ -36,6 +36,13 @@ pub enum MeshError {
     #[error("Deserialization failed: {0}")]
     DeserializationFailed(String),
 
+    /// Message frame was written with a different codec than the reader expects
+    #[error("Codec mismatch: expected {expected} frame, found {found} frame")]
+    CodecMismatch {
+        expected: crate::codec::MessageCodec,
+        found: crate::codec::MessageCodec,
+    },
+
     /// Queue is full (backpressure)
     #[error("Queue full: capacity {capacity}, current size {current}")]
     QueueFull { capacity: usize, current: usize },
@@ -9,6 +9,8 @@
 //!
 //! - **Typed Messages**: Strongly-typed message schemas with automatic serialization
 //! - **Pub/Sub Patterns**: Point-to-point, broadcast, and topic-based messaging
+//! - **Consumer Groups**: Load-balanced topic consumption across group members
+//! - **Regions**: Region-local routing with explicit cross-region bridging
 //! - **Backpressure**: Queue depth monitoring and flow control
 //! - **Reliability**: Dead letter queues and retry mechanisms
 //! - **Observability**: Built-in metrics and tracing
@@ -42,12 +44,16 @@
 //! ```
 
 pub mod backpressure;
+pub mod codec;
+pub mod consumer_group;
 pub mod dlq;
 pub mod error;
+pub mod memory;
 pub mod mesh;
 pub mod message;
 pub mod metrics;
 pub mod patterns;
+pub mod region;
 pub mod types;
 
 #[cfg(feature = "redis")]
@@ -57,8 +63,11 @@ pub use backpressure::{
     BackpressureConfig, BackpressureMonitor, BackpressureQueue, BackpressureSignal,
     BackpressureStats,
 };
-pub use dlq::{DeadLetterQueue, DlqConfig, DlqEntry, DlqStats};
+pub use codec::MessageCodec;
+pub use consumer_group::ConsumerGroups;
+pub use dlq::{DeadLetterQueue, DlqConfig, DlqEntry, DlqPage, DlqQuery, DlqStats};
 pub use error::{MeshError, MeshResult};
+pub use memory::InMemoryMesh;
 pub use mesh::AgentMesh;
 pub use message::{
     AnonymousRoute, BroadcastRoute, Message, MessageBuilder, MessageId, MessageIdError,
@@ -69,6 +78,7 @@ pub use patterns::{
     BroadcastGather, GatherConfig, GatherResult, Pipeline, PipelineStage, RequestReply,
     RequestReplyConfig, Supervisor, SupervisorConfig, TaskStatus, WorkerPool,
 };
+pub use region::{Region, RegionScope, RegionalMesh};
 pub use types::{AgentId, Topic, ValidationError};
 
 #[cfg(feature = "redis")]
@@ -0,0 +1,618 @@
+//! In-memory implementation of AgentMesh
+//!
+//! Suitable for tests and single-process deployments. Cloned handles share
+//! the same mesh, so agents in one process can communicate through clones.
+
+use async_trait::async_trait;
+use futures::stream;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard, Weak};
+use std::time::Duration;
+use tokio::sync::{Notify, broadcast};
+use tracing::{debug, warn};
+
+use crate::{
+    consumer_group::{ConsumerGroups, validate_group_name},
+    error::MeshResult,
+    mesh::{AgentMesh, MessageStream, validate_broadcast_route, validate_send_route},
+    message::Message,
+    types::{AgentId, Topic},
+};
+
+/// Default buffer size of broadcast and topic channels
+const DEFAULT_CHANNEL_CAPACITY: usize = 1024;
+
+/// Default number of messages kept for a consumer group without members,
+/// matching the default stream length of the Redis backend
+const DEFAULT_GROUP_BACKLOG_CAPACITY: usize = 10_000;
+
+/// Message queue with a single consumer waiting on it
+#[derive(Default)]
+struct MessageQueue {
+    messages: Mutex<VecDeque<Message>>,
+    notify: Notify,
+    closed: AtomicBool,
+}
+
+impl MessageQueue {
+    fn lock(&self) -> MutexGuard<'_, VecDeque<Message>> {
+        self.messages.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn push(&self, message: Message) {
+        self.lock().push_back(message);
+        self.notify.notify_one();
+    }
+
+    fn try_pop(&self) -> Option<Message> {
+        self.lock().pop_front()
+    }
+
+    /// Wait for the next message; `None` once the queue is closed and empty
+    async fn pop(&self) -> Option<Message> {
+        loop {
+            if let Some(message) = self.try_pop() {
+                return Some(message);
+            }
+            if self.closed.load(Ordering::Acquire) {
+                return None;
+            }
+            self.notify.notified().await;
+        }
+    }
+
+    fn drain(&self) -> Vec<Message> {
+        self.lock().drain(..).collect()
+    }
+
+    fn len(&self) -> usize {
+        self.lock().len()
+    }
+
+    fn close(&self) {
+        self.closed.store(true, Ordering::Release);
+        self.notify.notify_one();
+    }
+}
+
+/// Members of one consumer group on one topic
+struct GroupState {
+    members: Vec<(AgentId, Arc<MessageQueue>)>,
+    /// Round-robin position of the next member to receive a message
+    next: usize,
+    /// Messages published while the group had no members
+    backlog: VecDeque<Message>,
+    /// Maximum length of `backlog`; the oldest messages are dropped beyond it
+    backlog_capacity: usize,
+}
+
+impl GroupState {
+    fn new(backlog_capacity: usize) -> Self {
+        Self {
+            members: Vec::new(),
+            next: 0,
+            backlog: VecDeque::new(),
+            backlog_capacity,
+        }
+    }
+
+    fn dispatch(&mut self, message: Message) {
+        if self.members.is_empty() {
+            if self.backlog.len() >= self.backlog_capacity
+                && let Some(dropped) = self.backlog.pop_front()
+            {
+                warn!(
+                    "Consumer group backlog full ({} messages), dropping message {}",
+                    self.backlog_capacity, dropped.id
+                );
+            }
+            self.backlog.push_back(message);
+            return;
+        }
+        let index = self.next % self.members.len();
+        self.members[index].1.push(message);
+        self.next = (index + 1) % self.members.len();
+    }
+
+    /// Deal every undelivered message out again across the current members
+    fn rebalance(&mut self) {
+        let mut pending: Vec<Message> = self.backlog.drain(..).collect();
+        for (_, queue) in &self.members {
+            pending.extend(queue.drain());
+        }
+        self.next = 0;
+        for message in pending {
+            self.dispatch(message);
+        }
+    }
+
+    fn join(&mut self, member: AgentId, queue: Arc<MessageQueue>) {
+        self.remove(&member);
+        self.members.push((member, queue));
+        self.rebalance();
+    }
+
+    /// Remove a member, handing its undelivered messages to the others
+    fn remove(&mut self, member: &AgentId) -> Option<Arc<MessageQueue>> {
+        let index = self.members.iter().position(|(id, _)| id == member)?;
+        let (_, queue) = self.members.remove(index);
+        queue.close();
+        if !self.members.is_empty() {
+            self.next %= self.members.len();
+        }
+        for message in queue.drain() {
+            self.dispatch(message);
+        }
+        Some(queue)
+    }
+
+    fn pending(&self) -> usize {
+        self.backlog.len() + self.members.iter().map(|(_, q)| q.len()).sum::<usize>()
+    }
+}
+
+type GroupKey = (Topic, String);
+
+struct MeshState {
+    agents: HashSet<AgentId>,
+    mailboxes: HashMap<AgentId, Arc<MessageQueue>>,
+    broadcast: broadcast::Sender<Message>,
+    topics: HashMap<Topic, broadcast::Sender<Message>>,
+    groups: HashMap<GroupKey, GroupState>,
+}
+
+impl MeshState {
+    fn mailbox(&mut self, agent_id: &AgentId) -> Arc<MessageQueue> {
+        Arc::clone(self.mailboxes.entry(agent_id.clone()).or_default())
+    }
+}
+
+/// Group membership held by a member's stream; leaves the group when dropped
+struct GroupMembership {
+    state: Weak<Mutex<MeshState>>,
+    key: GroupKey,
+    member: AgentId,
+    queue: Arc<MessageQueue>,
+}
+
+impl Drop for GroupMembership {
+    fn drop(&mut self) {
+        let Some(state) = self.state.upgrade() else {
+            return;
+        };
+        let mut state = state.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(group) = state.groups.get_mut(&self.key) {
+            // Only leave if this stream still owns the membership
+            let owned = group
+                .members
+                .iter()
+                .any(|(id, queue)| id == &self.member && Arc::ptr_eq(queue, &self.queue));
+            if owned {
+                group.remove(&self.member);
+                debug!(
+                    "Member {} left group {} on topic {}",
+                    self.member, self.key.1, self.key.0
+                );
+            }
+        }
+    }
+}
+
+/// In-memory agent mesh
+#[derive(Clone)]
+pub struct InMemoryMesh {
+    state: Arc<Mutex<MeshState>>,
+    channel_capacity: usize,
+    group_backlog_capacity: usize,
+}
+
+impl Default for InMemoryMesh {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl InMemoryMesh {
+    /// Create a new in-memory mesh
+    pub fn new() -> Self {
+        Self::with_channel_capacity(DEFAULT_CHANNEL_CAPACITY)
+    }
+
+    /// Create a mesh whose broadcast and topic channels buffer `capacity` messages
+    ///
+    /// Fan-out subscribers that fall further behind skip the oldest messages.
+    pub fn with_channel_capacity(capacity: usize) -> Self {
+        let capacity = capacity.max(1);
+        let (broadcast, _) = broadcast::channel(capacity);
+        Self {
+            state: Arc::new(Mutex::new(MeshState {
+                agents: HashSet::new(),
+                mailboxes: HashMap::new(),
+                broadcast,
+                topics: HashMap::new(),
+                groups: HashMap::new(),
+            })),
+            channel_capacity: capacity,
+            group_backlog_capacity: DEFAULT_GROUP_BACKLOG_CAPACITY,
+        }
+    }
+
+    /// Limit how many messages a consumer group without members keeps
+    ///
+    /// Once the limit is reached the oldest messages are dropped. Defaults to
+    /// 10,000.
+    pub fn with_group_backlog_capacity(mut self, capacity: usize) -> Self {
+        self.group_backlog_capacity = capacity.max(1);
+        self
+    }
+
+    fn lock(&self) -> MutexGuard<'_, MeshState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Register an agent as present in the mesh
+    pub fn register_agent(&self, agent_id: &AgentId) {
+        self.lock().agents.insert(agent_id.clone());
+    }
+
+    /// Deregister an agent from the mesh
+    pub fn deregister_agent(&self, agent_id: &AgentId) {
+        self.lock().agents.remove(agent_id);
+    }
+
+    /// Receive the next message from an agent's mailbox, waiting up to `timeout`
+    pub async fn receive(
+        &self,
+        agent_id: &AgentId,
+        timeout: Duration,
+    ) -> MeshResult<Option<Message>> {
+        let mailbox = self.lock().mailbox(agent_id);
+        Ok(tokio::time::timeout(timeout, mailbox.pop())
+            .await
+            .ok()
+            .flatten())
+    }
+
+    /// Subscribe to messages sent with [`broadcast`](AgentMesh::broadcast)
+    pub fn subscribe_broadcast(&self) -> MessageStream {
+        receiver_stream(self.lock().broadcast.subscribe())
+    }
+
+    /// List the current members of a consumer group, in join order
+    pub fn group_members(&self, topic: &Topic, group: &str) -> Vec<AgentId> {
+        self.lock()
+            .groups
+            .get(&(topic.clone(), group.to_string()))
+            .map(|g| g.members.iter().map(|(id, _)| id.clone()).collect())
+            .unwrap_or_default()
+    }
+}
+
+/// Adapt a broadcast receiver to a message stream, skipping over lag
+fn receiver_stream(receiver: broadcast::Receiver<Message>) -> MessageStream {
+    Box::pin(stream::unfold(receiver, |mut receiver| async move {
+        loop {
+            match receiver.recv().await {
+                Ok(message) => return Some((Ok(message), receiver)),
+                Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                    warn!("Subscriber lagged, skipped {} messages", skipped);
+                }
+                Err(broadcast::error::RecvError::Closed) => return None,
+            }
+        }
+    }))
+}
+
+#[async_trait]
+impl AgentMesh for InMemoryMesh {
+    async fn send(&self, to: &AgentId, message: Message) -> MeshResult<()> {
+        validate_send_route(&message.route, to)?;
+        debug!("Sent message {} to agent {}", message.id, to);
+        self.lock().mailbox(to).push(message);
+        Ok(())
+    }
+
+    async fn broadcast(&self, message: Message) -> MeshResult<()> {
+        validate_broadcast_route(&message.route)?;
+        debug!("Broadcast message {}", message.id);
+        // No subscribers is not an error
+        let _ = self.lock().broadcast.send(message);
+        Ok(())
+    }
+
+    async fn subscribe(&self, topic: &Topic) -> MeshResult<MessageStream> {
+        let capacity = self.channel_capacity;
+        let receiver = self
+            .lock()
+            .topics
+            .entry(topic.clone())
+            .or_insert_with(|| broadcast::channel(capacity).0)
+            .subscribe();
+        debug!("Subscribed to topic {}", topic);
+        Ok(receiver_stream(receiver))
+    }
+
+    async fn publish(&self, topic: &Topic, message: Message) -> MeshResult<()> {
+        validate_broadcast_route(&message.route)?;
+
+        let mut state = self.lock();
+        if let Some(sender) = state.topics.get(topic) {
+            let _ = sender.send(message.clone());
+        }
+        for ((group_topic, _), group) in state.groups.iter_mut() {
+            if group_topic == topic {
+                group.dispatch(message.clone());
+            }
+        }
+
+        debug!("Published message {} to topic {}", message.id, topic);
+        Ok(())
+    }
+
+    async fn unsubscribe(&self, topic: &Topic) -> MeshResult<()> {
+        // Dropping the sender ends every fan-out stream for the topic
+        if self.lock().topics.remove(topic).is_some() {
+            debug!("Unsubscribed from topic {}", topic);
+        } else {
+            warn!(
+                "Attempted to unsubscribe from topic {} but no subscription found",
+                topic
+            );
+        }
+        Ok(())
+    }
+
+    async fn queue_depth(&self) -> MeshResult<usize> {
+        let state = self.lock();
+        let mailboxes: usize = state.mailboxes.values().map(|q| q.len()).sum();
+        let groups: usize = state.groups.values().map(GroupState::pending).sum();
+        Ok(mailboxes + groups)
+    }
+
+    async fn is_reachable(&self, agent_id: &AgentId) -> bool {
+        self.lock().agents.contains(agent_id)
+    }
+
+    async fn list_agents(&self) -> MeshResult<Vec<AgentId>> {
+        let mut agents: Vec<AgentId> = self.lock().agents.iter().cloned().collect();
+        agents.sort_by(|a, b| a.as_str().cmp(b.as_str()));
+        Ok(agents)
+    }
+}
+
+#[async_trait]
+impl ConsumerGroups for InMemoryMesh {
+    async fn subscribe_group(
+        &self,
+        topic: &Topic,
+        group: &str,
+        member: &AgentId,
+    ) -> MeshResult<MessageStream> {
+        validate_group_name(group)?;
+
+        let key = (topic.clone(), group.to_string());
+        let queue = Arc::new(MessageQueue::default());
+        let backlog_capacity = self.group_backlog_capacity;
+        self.lock()
+            .groups
+            .entry(key.clone())
+            .or_insert_with(|| GroupState::new(backlog_capacity))
+            .join(member.clone(), Arc::clone(&queue));
+        debug!(
+            "Member {} joined group {} on topic {}",
+            member, group, topic
+        );
+
+        let membership = GroupMembership {
+            state: Arc::downgrade(&self.state),
+            key,
+            member: member.clone(),
+            queue,
+        };
+        Ok(Box::pin(stream::unfold(
+            membership,
+            |membership| async move {
+                let message = membership.queue.pop().await?;
+                Some((Ok(message), membership))
+            },
+        )))
+    }
+
+    async fn leave_group(&self, topic: &Topic, group: &str, member: &AgentId) -> MeshResult<()> {
+        let key = (topic.clone(), group.to_string());
+        let removed = self
+            .lock()
+            .groups
+            .get_mut(&key)
+            .and_then(|g| g.remove(member));
+        if removed.is_some() {
+            debug!("Member {} left group {} on topic {}", member, group, topic);
+        } else {
+            warn!(
+                "Attempted to leave group {} on topic {} but {} is not a member",
+                group, topic, member
+            );
+        }
+        Ok(())
+    }
+    /// Messages are handed over in memory and cannot be redelivered, so
+    /// acknowledging is a no-op
+    async fn ack(&self, _topic: &Topic, _group: &str, _message: &Message) -> MeshResult<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::message::MessagePayload;
+    use futures::StreamExt;
+
+    fn agent(id: &str) -> AgentId {
+        AgentId::new_unchecked(id)
+    }
+
+    async fn next_text(stream: &mut MessageStream) -> String {
+        let message = tokio::time::timeout(Duration::from_secs(1), stream.next())
+            .await
+            .expect("message within timeout")
+            .expect("stream open")
+            .expect("message ok");
+        text(&message)
+    }
+
+    fn text(message: &Message) -> String {
+        match &message.payload {
+            MessagePayload::Text(text) => text.clone(),
+            other => panic!("expected text payload, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_group_members_split_messages_without_duplicates() {
+        let mesh = InMemoryMesh::new();
+        let topic = Topic::from("jobs");
+        let mut worker_1 = mesh
+            .subscribe_group(&topic, "workers", &agent("worker-1"))
+            .await
+            .unwrap();
+        let mut worker_2 = mesh
+            .subscribe_group(&topic, "workers", &agent("worker-2"))
+            .await
+            .unwrap();
+        let mut fan_out = mesh.subscribe(&topic).await.unwrap();
+
+        for n in 0..20 {
+            mesh.publish(&topic, Message::new(format!("job-{}", n)))
+                .await
+                .unwrap();
+        }
+
+        let mut first = Vec::new();
+        let mut second = Vec::new();
+        for _ in 0..10 {
+            first.push(next_text(&mut worker_1).await);
+            second.push(next_text(&mut worker_2).await);
+        }
+        assert_eq!(mesh.queue_depth().await.unwrap(), 0);
+
+        let mut all: Vec<String> = first.iter().chain(&second).cloned().collect();
+        all.sort();
+        all.dedup();
+        assert_eq!(all.len(), 20, "every job delivered exactly once");
+        assert!(first.iter().all(|job| !second.contains(job)));
+
+        // Non-group subscribers still see every message
+        for n in 0..20 {
+            assert_eq!(next_text(&mut fan_out).await, format!("job-{}", n));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_group_rebalances_on_join_and_leave() {
+        let mesh = InMemoryMesh::new();
+        let topic = Topic::from("jobs");
+
+        let mut worker_1 = mesh
+            .subscribe_group(&topic, "workers", &agent("worker-1"))
+            .await
+            .unwrap();
+        for n in 0..4 {
+            mesh.publish(&topic, Message::new(format!("job-{}", n)))
+                .await
+                .unwrap();
+        }
+
+        // A second member takes half of the undelivered backlog
+        let mut worker_2 = mesh
+            .subscribe_group(&topic, "workers", &agent("worker-2"))
+            .await
+            .unwrap();
+        assert_eq!(next_text(&mut worker_1).await, "job-0");
+        assert_eq!(next_text(&mut worker_2).await, "job-1");
+
+        // Leaving hands worker-2's remaining message to worker-1
+        mesh.leave_group(&topic, "workers", &agent("worker-2"))
+            .await
+            .unwrap();
+        assert!(worker_2.next().await.is_none());
+        let mut rest = vec![
+            next_text(&mut worker_1).await,
+            next_text(&mut worker_1).await,
+        ];
+        rest.sort();
+        assert_eq!(rest, ["job-2", "job-3"]);
+
+        // Dropping a stream also leaves the group
+        drop(worker_1);
+        assert!(mesh.group_members(&topic, "workers").is_empty());
+
+        // Published while the group is empty: kept for the next member
+        mesh.publish(&topic, Message::new("job-4")).await.unwrap();
+        let mut worker_3 = mesh
+            .subscribe_group(&topic, "workers", &agent("worker-3"))
+            .await
+            .unwrap();
+        assert_eq!(next_text(&mut worker_3).await, "job-4");
+    }
+
+    #[tokio::test]
+    async fn test_group_backlog_drops_oldest_beyond_capacity() {
+        let mesh = InMemoryMesh::new().with_group_backlog_capacity(3);
+        let topic = Topic::from("jobs");
+
+        let worker_1 = mesh
+            .subscribe_group(&topic, "workers", &agent("worker-1"))
+            .await
+            .unwrap();
+        drop(worker_1);
+
+        for n in 0..5 {
+            mesh.publish(&topic, Message::new(format!("job-{}", n)))
+                .await
+                .unwrap();
+        }
+        assert_eq!(mesh.queue_depth().await.unwrap(), 3);
+
+        let mut worker_2 = mesh
+            .subscribe_group(&topic, "workers", &agent("worker-2"))
+            .await
+            .unwrap();
+        for n in 2..5 {
+            let message = tokio::time::timeout(Duration::from_secs(1), worker_2.next())
+                .await
+                .unwrap()
+                .unwrap()
+                .unwrap();
+            assert_eq!(text(&message), format!("job-{}", n));
+            mesh.ack(&topic, "workers", &message).await.unwrap();
+        }
+    }
+
+    #[tokio::test]
+    async fn test_mailbox_send_and_receive() {
+        let mesh = InMemoryMesh::new();
+        let to = agent("agent-2");
+        mesh.register_agent(&to);
+        assert!(mesh.is_reachable(&to).await);
+
+        mesh.send(&to, Message::unicast(agent("agent-1"), to.clone(), "hello"))
+            .await
+            .unwrap();
+        assert_eq!(mesh.queue_depth().await.unwrap(), 1);
+
+        let received = mesh
+            .receive(&to, Duration::from_millis(100))
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(text(&received), "hello");
+        assert!(
+            mesh.receive(&to, Duration::from_millis(10))
+                .await
+                .unwrap()
+                .is_none()
+        );
+    }
+}
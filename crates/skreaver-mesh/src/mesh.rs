@@ -5,11 +5,60 @@ use futures::Stream;
 use std::pin::Pin;
 
 use crate::{
-    error::MeshResult,
-    message::Message,
+    error::{MeshError, MeshResult},
+    message::{Message, Route},
     types::{AgentId, Topic},
 };
 
+/// Validates that a message route is compatible with the send operation
+pub(crate) fn validate_send_route(route: &Route, to: &AgentId) -> MeshResult<()> {
+    match route {
+        Route::Unicast { to: route_to, .. } => {
+            if route_to != to {
+                Err(MeshError::InvalidConfig(format!(
+                    "Route specifies recipient '{}' but send() called with '{}'",
+                    route_to, to
+                )))
+            } else {
+                Ok(())
+            }
+        }
+        Route::System { to: route_to } => {
+            if route_to != to {
+                Err(MeshError::InvalidConfig(format!(
+                    "Route specifies recipient '{}' but send() called with '{}'",
+                    route_to, to
+                )))
+            } else {
+                Ok(())
+            }
+        }
+        Route::Broadcast { from } => Err(MeshError::InvalidConfig(format!(
+            "Cannot send broadcast message from '{}' to specific agent '{}'. Use broadcast() instead",
+            from, to
+        ))),
+        Route::Anonymous => Err(MeshError::InvalidConfig(format!(
+            "Cannot send anonymous message to specific agent '{}'. Anonymous messages should use broadcast()",
+            to
+        ))),
+    }
+}
+
+/// Validates that a message route is compatible with the broadcast operation
+pub(crate) fn validate_broadcast_route(route: &Route) -> MeshResult<()> {
+    match route {
+        Route::Broadcast { .. } | Route::Anonymous => Ok(()),
+        Route::Unicast { from, to } => Err(MeshError::InvalidConfig(format!(
+            "Cannot broadcast unicast message from '{}' to '{}'. Use send() instead",
+            from, to
+        ))),
+        Route::System { to } => Err(MeshError::InvalidConfig(format!(
+            "Cannot broadcast system message to '{}'. Use send() instead",
+            to
+        ))),
+    }
+}
+
 /// Stream type for receiving messages
 pub type MessageStream = Pin<Box<dyn Stream<Item = MeshResult<Message>> + Send + 'static>>;
 
@@ -232,4 +281,115 @@ mod tests {
         let depth = mesh.queue_depth().await.unwrap();
         assert_eq!(depth, 0);
     }
+
+    #[test]
+    fn test_validate_send_route_unicast_valid() {
+        let route = Route::unicast(
+            AgentId::new_unchecked("sender"),
+            AgentId::new_unchecked("recipient"),
+        );
+        let to = AgentId::new_unchecked("recipient");
+        assert!(validate_send_route(&route, &to).is_ok());
+    }
+
+    #[test]
+    fn test_validate_send_route_unicast_mismatch() {
+        let route = Route::unicast(
+            AgentId::new_unchecked("sender"),
+            AgentId::new_unchecked("recipient"),
+        );
+        let to = AgentId::new_unchecked("wrong-recipient");
+        let result = validate_send_route(&route, &to);
+        assert!(result.is_err());
+        assert!(
+            result
+                .unwrap_err()
+                .to_string()
+                .contains("Route specifies recipient")
+        );
+    }
+
+    #[test]
+    fn test_validate_send_route_broadcast_invalid() {
+        let route = Route::broadcast(AgentId::new_unchecked("sender"));
+        let to = AgentId::new_unchecked("recipient");
+        let result = validate_send_route(&route, &to);
+        assert!(result.is_err());
+        assert!(
+            result
+                .unwrap_err()
+                .to_string()
+                .contains("Cannot send broadcast message")
+        );
+    }
+
+    #[test]
+    fn test_validate_send_route_anonymous_invalid() {
+        let route = Route::anonymous();
+        let to = AgentId::new_unchecked("recipient");
+        let result = validate_send_route(&route, &to);
+        assert!(result.is_err());
+        assert!(
+            result
+                .unwrap_err()
+                .to_string()
+                .contains("Cannot send anonymous message")
+        );
+    }
+
+    #[test]
+    fn test_validate_send_route_system_valid() {
+        let route = Route::system(AgentId::new_unchecked("recipient"));
+        let to = AgentId::new_unchecked("recipient");
+        assert!(validate_send_route(&route, &to).is_ok());
+    }
+
+    #[test]
+    fn test_validate_send_route_system_mismatch() {
+        let route = Route::system(AgentId::new_unchecked("recipient"));
+        let to = AgentId::new_unchecked("wrong-recipient");
+        let result = validate_send_route(&route, &to);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_validate_broadcast_route_broadcast_valid() {
+        let route = Route::broadcast(AgentId::new_unchecked("sender"));
+        assert!(validate_broadcast_route(&route).is_ok());
+    }
+
+    #[test]
+    fn test_validate_broadcast_route_anonymous_valid() {
+        let route = Route::anonymous();
+        assert!(validate_broadcast_route(&route).is_ok());
+    }
+
+    #[test]
+    fn test_validate_broadcast_route_unicast_invalid() {
+        let route = Route::unicast(
+            AgentId::new_unchecked("sender"),
+            AgentId::new_unchecked("recipient"),
+        );
+        let result = validate_broadcast_route(&route);
+        assert!(result.is_err());
+        assert!(
+            result
+                .unwrap_err()
+                .to_string()
+                .contains("Cannot broadcast unicast message")
+        );
+    }
+
+    #[test]
+    fn test_validate_broadcast_route_system_invalid() {
+        let route = Route::system(AgentId::new_unchecked("recipient"));
+        let result = validate_broadcast_route(&route);
+        assert!(result.is_err());
+        assert!(
+            result
+                .unwrap_err()
+                .to_string()
+                .contains("Cannot broadcast system message")
+        );
+    }
 }
@@ -9,6 +9,7 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 use super::types::{MessageId, MessageMetadata, MessagePayload, Route};
+use crate::region::{REGION_SCOPE_METADATA_KEY, RegionScope};
 use crate::types::AgentId;
 
 /// A message sent between agents in the mesh
@@ -106,6 +107,24 @@ impl Message {
         self.metadata.get(key).map(|s| s.as_str())
     }
 
+    /// Set how far the message may travel in a multi-region mesh
+    pub fn with_region_scope(self, scope: RegionScope) -> Self {
+        self.with_metadata(REGION_SCOPE_METADATA_KEY, scope.as_str())
+    }
+
+    /// Mark the message for delivery in every region, not just the sender's
+    pub fn cross_region(self) -> Self {
+        self.with_region_scope(RegionScope::CrossRegion)
+    }
+
+    /// How far the message may travel; local unless marked otherwise
+    pub fn region_scope(&self) -> RegionScope {
+        match self.metadata(REGION_SCOPE_METADATA_KEY) {
+            Some(scope) if scope == RegionScope::CrossRegion.as_str() => RegionScope::CrossRegion,
+            _ => RegionScope::Local,
+        }
+    }
+
     /// Serialize message to JSON
     pub fn to_json(&self) -> Result<String, serde_json::Error> {
         serde_json::to_string(self)
@@ -3,6 +3,7 @@
 //! Provides cardinality-safe metrics for monitoring mesh health and performance.
 //! Avoids high-cardinality labels like agent_id to prevent metrics explosion.
 
+use crate::dlq::DlqStats;
 use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Instant;
@@ -27,6 +28,8 @@ pub struct MeshMetrics {
     pub dlq_size: usize,
     /// Total messages added to DLQ
     pub dlq_total_added: u64,
+    /// Age of the oldest message in DLQ, in seconds
+    pub dlq_oldest_age_secs: u64,
     /// Current queue depths by topic (limited cardinality)
     pub queue_depths: HashMap<String, usize>,
     /// Message send latency samples (p50, p95, p99)
@@ -128,6 +131,16 @@ impl MeshMetricsCollector {
         metrics.dlq_total_added = total_added;
     }
 
+    /// Update DLQ size and age metrics from a DLQ stats snapshot
+    pub async fn record_dlq_stats(&self, stats: &DlqStats) {
+        let mut metrics = self.metrics.write().await;
+        metrics.dlq_size = stats.current_size;
+        metrics.dlq_total_added = stats.total_added;
+        metrics.dlq_oldest_age_secs = stats
+            .oldest_age
+            .map_or(0, |age| age.num_seconds().max(0) as u64);
+    }
+
     /// Record send latency
     pub async fn record_latency(&self, duration_ms: u64) {
         let mut samples = self.latency_samples.write().await;
@@ -266,6 +279,19 @@ mod tests {
         let metrics = collector.snapshot().await;
         assert_eq!(metrics.dlq_size, 5);
         assert_eq!(metrics.dlq_total_added, 10);
+
+        let stats = DlqStats {
+            current_size: 2,
+            total_added: 12,
+            oldest_age: Some(chrono::Duration::seconds(90)),
+            ..Default::default()
+        };
+        collector.record_dlq_stats(&stats).await;
+
+        let metrics = collector.snapshot().await;
+        assert_eq!(metrics.dlq_size, 2);
+        assert_eq!(metrics.dlq_total_added, 12);
+        assert_eq!(metrics.dlq_oldest_age_secs, 90);
     }
 
     #[tokio::test]
@@ -3,66 +3,25 @@
 use async_trait::async_trait;
 use futures::StreamExt;
 use redis::AsyncCommands;
+use redis::streams::{
+    StreamAutoClaimOptions, StreamAutoClaimReply, StreamId, StreamMaxlen, StreamPendingCountReply,
+    StreamReadOptions, StreamReadReply,
+};
+use std::collections::{HashMap, VecDeque};
 use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
 use tokio::sync::RwLock;
 use tracing::{debug, error, warn};
 
 use crate::{
+    codec::MessageCodec,
+    consumer_group::{ConsumerGroups, validate_group_name},
     error::{MeshError, MeshResult},
-    mesh::{AgentMesh, MessageStream},
-    message::{Message, Route},
+    mesh::{AgentMesh, MessageStream, validate_broadcast_route, validate_send_route},
+    message::Message,
     types::{AgentId, Topic},
 };
 
-/// Validates that a message route is compatible with the send operation
-fn validate_send_route(route: &Route, to: &AgentId) -> MeshResult<()> {
-    match route {
-        Route::Unicast { to: route_to, .. } => {
-            if route_to != to {
-                Err(MeshError::InvalidConfig(format!(
-                    "Route specifies recipient '{}' but send() called with '{}'",
-                    route_to, to
-                )))
-            } else {
-                Ok(())
-            }
-        }
-        Route::System { to: route_to } => {
-            if route_to != to {
-                Err(MeshError::InvalidConfig(format!(
-                    "Route specifies recipient '{}' but send() called with '{}'",
-                    route_to, to
-                )))
-            } else {
-                Ok(())
-            }
-        }
-        Route::Broadcast { from } => Err(MeshError::InvalidConfig(format!(
-            "Cannot send broadcast message from '{}' to specific agent '{}'. Use broadcast() instead",
-            from, to
-        ))),
-        Route::Anonymous => Err(MeshError::InvalidConfig(format!(
-            "Cannot send anonymous message to specific agent '{}'. Anonymous messages should use broadcast()",
-            to
-        ))),
-    }
-}
-
-/// Validates that a message route is compatible with the broadcast operation
-fn validate_broadcast_route(route: &Route) -> MeshResult<()> {
-    match route {
-        Route::Broadcast { .. } | Route::Anonymous => Ok(()),
-        Route::Unicast { from, to } => Err(MeshError::InvalidConfig(format!(
-            "Cannot broadcast unicast message from '{}' to '{}'. Use send() instead",
-            from, to
-        ))),
-        Route::System { to } => Err(MeshError::InvalidConfig(format!(
-            "Cannot broadcast system message to '{}'. Use send() instead",
-            to
-        ))),
-    }
-}
-
 /// Redis connection configuration
 #[derive(Debug, Clone)]
 pub struct RedisConfig {
@@ -74,6 +33,13 @@ pub struct RedisConfig {
     pub connect_timeout_secs: u64,
     /// Command timeout in seconds
     pub command_timeout_secs: u64,
+    /// Wire codec for messages; all agents on a mesh must use the same one
+    pub codec: MessageCodec,
+    /// Approximate number of entries kept in each topic's consumer group stream
+    pub group_stream_maxlen: usize,
+    /// Idle time in milliseconds after which a group member takes over
+    /// messages left unacknowledged by another member
+    pub group_claim_idle_ms: u64,
 }
 
 impl Default for RedisConfig {
@@ -83,6 +49,9 @@ impl Default for RedisConfig {
             pool_size: 10,
             connect_timeout_secs: 5,
             command_timeout_secs: 3,
+            codec: MessageCodec::default(),
+            group_stream_maxlen: 10_000,
+            group_claim_idle_ms: 30_000,
         }
     }
 }
@@ -113,6 +82,24 @@ impl RedisConfig {
         self.command_timeout_secs = secs;
         self
     }
+
+    /// Set the message codec
+    pub fn with_codec(mut self, codec: MessageCodec) -> Self {
+        self.codec = codec;
+        self
+    }
+
+    /// Set the approximate length cap of consumer group streams
+    pub fn with_group_stream_maxlen(mut self, maxlen: usize) -> Self {
+        self.group_stream_maxlen = maxlen;
+        self
+    }
+
+    /// Set the idle time after which unacknowledged group messages are reclaimed
+    pub fn with_group_claim_idle_ms(mut self, idle_ms: u64) -> Self {
+        self.group_claim_idle_ms = idle_ms;
+        self
+    }
 }
 
 /// Redis-based agent mesh implementation
@@ -120,9 +107,24 @@ pub struct RedisMesh {
     pool: deadpool_redis::Pool,
     config: RedisConfig,
     /// Active subscriptions (topic -> subscription handle)
-    subscriptions: Arc<RwLock<std::collections::HashMap<Topic, tokio::task::JoinHandle<()>>>>,
+    subscriptions: Arc<RwLock<HashMap<Topic, tokio::task::JoinHandle<()>>>>,
+    /// Stop flags of active consumer group readers (topic, group, member)
+    group_readers: Arc<RwLock<HashMap<GroupMemberKey, Arc<AtomicBool>>>>,
 }
 
+type GroupMemberKey = (Topic, String, AgentId);
+
+/// How long a group reader blocks waiting for new entries before re-checking
+/// whether it has left the group
+const GROUP_READ_BLOCK_MS: usize = 1_000;
+
+/// Stream entry field holding the encoded message frame
+const GROUP_FRAME_FIELD: &str = "frame";
+
+/// Metadata key recording which stream entry a group message was read from,
+/// so that [`ConsumerGroups::ack`] can acknowledge it
+const GROUP_ENTRY_ID_METADATA: &str = "skreaver.group.entry_id";
+
 impl RedisMesh {
     /// Create a new Redis mesh with default configuration
     pub async fn new(url: impl Into<String>) -> MeshResult<Self> {
@@ -155,7 +157,8 @@ impl RedisMesh {
         Ok(Self {
             pool,
             config,
-            subscriptions: Arc::new(RwLock::new(std::collections::HashMap::new())),
+            subscriptions: Arc::new(RwLock::new(HashMap::new())),
+            group_readers: Arc::new(RwLock::new(HashMap::new())),
         })
     }
 
@@ -182,6 +185,11 @@ impl RedisMesh {
         format!("skreaver:topic:{}", topic)
     }
 
+    /// Build Redis key for the stream backing a topic's consumer groups
+    fn topic_stream_key(topic: &Topic) -> String {
+        format!("skreaver:topic:{}:stream", topic)
+    }
+
     /// Build Redis key for agent presence
     fn presence_key(agent_id: &AgentId) -> String {
         format!("skreaver:presence:{}", agent_id)
@@ -200,13 +208,13 @@ impl AgentMesh for RedisMesh {
         validate_send_route(&message.route, to)?;
 
         // Serialize message
-        let json = message.to_json()?;
+        let frame = self.config.codec.encode(&message)?;
 
         // Push to agent's mailbox (Redis list)
         let mut conn = self.get_connection().await?;
         let key = Self::agent_key(to);
 
-        conn.lpush::<_, _, ()>(&key, json)
+        conn.lpush::<_, _, ()>(&key, frame)
             .await
             .map_err(|e| MeshError::SendFailed(e.to_string()))?;
 
@@ -219,13 +227,13 @@ impl AgentMesh for RedisMesh {
         validate_broadcast_route(&message.route)?;
 
         // Serialize message
-        let json = message.to_json()?;
+        let frame = self.config.codec.encode(&message)?;
 
         // Publish to broadcast channel
         let mut conn = self.get_connection().await?;
         let channel = Self::broadcast_key();
 
-        conn.publish::<_, _, ()>(&channel, json)
+        conn.publish::<_, _, ()>(&channel, frame)
             .await
             .map_err(|e| MeshError::SendFailed(e.to_string()))?;
 
@@ -254,15 +262,15 @@ impl AgentMesh for RedisMesh {
         debug!("Subscribed to topic {}", topic);
 
         // Convert Redis message stream to our message stream
-        let stream = pubsub.into_on_message().map(|msg| {
-            let payload: String = msg.get_payload().map_err(|e| {
+        let codec = self.config.codec;
+        let stream = pubsub.into_on_message().map(move |msg| {
+            let payload: Vec<u8> = msg.get_payload().map_err(|e| {
                 error!("Failed to get message payload: {}", e);
                 MeshError::DeserializationFailed(e.to_string())
             })?;
 
-            Message::from_json(&payload).map_err(|e| {
+            codec.decode(&payload).inspect_err(|e| {
                 error!("Failed to deserialize message: {}", e);
-                MeshError::DeserializationFailed(e.to_string())
             })
         });
 
@@ -274,16 +282,27 @@ impl AgentMesh for RedisMesh {
         validate_broadcast_route(&message.route)?;
 
         // Serialize message
-        let json = message.to_json()?;
+        let frame = self.config.codec.encode(&message)?;
 
         // Publish to topic channel
         let mut conn = self.get_connection().await?;
         let channel = Self::topic_key(topic);
 
-        conn.publish::<_, _, ()>(&channel, json)
+        conn.publish::<_, _, ()>(&channel, &frame)
             .await
             .map_err(|e| MeshError::SendFailed(e.to_string()))?;
 
+        // Append to the topic stream for consumer groups
+        let stream_key = Self::topic_stream_key(topic);
+        conn.xadd_maxlen::<_, _, _, _, ()>(
+            &stream_key,
+            StreamMaxlen::Approx(self.config.group_stream_maxlen),
+            "*",
+            &[(GROUP_FRAME_FIELD, frame)],
+        )
+        .await
+        .map_err(|e| MeshError::SendFailed(e.to_string()))?;
+
         debug!("Published message {} to topic {}", message.id, topic);
         Ok(())
     }
@@ -417,14 +436,14 @@ impl RedisMesh {
         let key = Self::agent_key(agent_id);
 
         // BRPOP: blocking right pop with timeout
-        let result: Option<(String, String)> = conn
+        let result: Option<(String, Vec<u8>)> = conn
             .brpop(&key, timeout_secs as f64)
             .await
             .map_err(|e| MeshError::ReceiveFailed(e.to_string()))?;
 
         match result {
-            Some((_key, json)) => {
-                let message = Message::from_json(&json)?;
+            Some((_key, frame)) => {
+                let message = self.config.codec.decode(&frame)?;
                 debug!("Received message {} for agent {}", message.id, agent_id);
                 Ok(Some(message))
             }
@@ -433,139 +452,260 @@ impl RedisMesh {
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+#[async_trait]
+impl ConsumerGroups for RedisMesh {
+    async fn subscribe_group(
+        &self,
+        topic: &Topic,
+        group: &str,
+        member: &AgentId,
+    ) -> MeshResult<MessageStream> {
+        validate_group_name(group)?;
+        let key = Self::topic_stream_key(topic);
+
+        // Create the group at the end of the stream; it may already exist
+        let mut conn = self.get_connection().await?;
+        let created = conn
+            .xgroup_create_mkstream::<_, _, _, ()>(&key, group, "$")
+            .await;
+        if let Err(e) = created
+            && e.code() != Some("BUSYGROUP")
+        {
+            return Err(MeshError::SubscribeFailed(e.to_string()));
+        }
 
-    #[test]
-    fn test_redis_config() {
-        let config = RedisConfig::new("redis://localhost:6379")
-            .with_pool_size(20)
-            .with_connect_timeout(10);
+        // Blocking reads need a dedicated connection
+        let client = redis::Client::open(self.config.url.as_str())
+            .map_err(|e| MeshError::ConnectionFailed(e.to_string()))?;
+        let reader_conn = client
+            .get_multiplexed_async_connection()
+            .await
+            .map_err(|e| MeshError::ConnectionFailed(e.to_string()))?;
 
-        assert_eq!(config.pool_size, 20);
-        assert_eq!(config.connect_timeout_secs, 10);
+        // Joining again replaces the previous reader for this member
+        let stop = Arc::new(AtomicBool::new(false));
+        let previous = self.group_readers.write().await.insert(
+            (topic.clone(), group.to_string(), member.clone()),
+            Arc::clone(&stop),
+        );
+        if let Some(previous) = previous {
+            previous.store(true, Ordering::Release);
+        }
+
+        debug!("Agent {} joined group {} on topic {}", member, group, topic);
+
+        let reader = GroupReader {
+            conn: reader_conn,
+            key,
+            group: group.to_string(),
+            consumer: member.to_string(),
+            codec: self.config.codec,
+            claim_idle_ms: self.config.group_claim_idle_ms,
+            stop,
+            buffered: VecDeque::new(),
+            reclaim: true,
+        };
+        Ok(Box::pin(futures::stream::unfold(reader, GroupReader::next)))
     }
 
-    #[test]
-    fn test_key_generation() {
-        let agent_id = AgentId::new_unchecked("agent-1");
-        let key = RedisMesh::agent_key(&agent_id);
-        assert_eq!(key, "skreaver:agent:agent-1:mailbox");
+    async fn leave_group(&self, topic: &Topic, group: &str, member: &AgentId) -> MeshResult<()> {
+        let stop = self.group_readers.write().await.remove(&(
+            topic.clone(),
+            group.to_string(),
+            member.clone(),
+        ));
+        let Some(stop) = stop else {
+            warn!(
+                "Agent {} attempted to leave group {} on topic {} without joining",
+                member, group, topic
+            );
+            return Ok(());
+        };
+        stop.store(true, Ordering::Release);
 
-        let topic = Topic::from("notifications");
-        let topic_key = RedisMesh::topic_key(&topic);
-        assert_eq!(topic_key, "skreaver:topic:notifications");
-    }
+        // Deleting a consumer drops its pending entries, so only do so once
+        // they have been acknowledged; otherwise other members reclaim them
+        let mut conn = self.get_connection().await?;
+        let key = Self::topic_stream_key(topic);
+        let pending: StreamPendingCountReply = conn
+            .xpending_consumer_count(&key, group, "-", "+", 1, member.as_str())
+            .await
+            .map_err(|e| MeshError::BackendError(e.to_string()))?;
+        if pending.ids.is_empty() {
+            conn.xgroup_delconsumer::<_, _, _, ()>(&key, group, member.as_str())
+                .await
+                .map_err(|e| MeshError::BackendError(e.to_string()))?;
+        }
 
-    #[test]
-    fn test_validate_send_route_unicast_valid() {
-        let route = Route::unicast(
-            AgentId::new_unchecked("sender"),
-            AgentId::new_unchecked("recipient"),
-        );
-        let to = AgentId::new_unchecked("recipient");
-        assert!(validate_send_route(&route, &to).is_ok());
+        debug!("Agent {} left group {} on topic {}", member, group, topic);
+        Ok(())
     }
 
-    #[test]
-    fn test_validate_send_route_unicast_mismatch() {
-        let route = Route::unicast(
-            AgentId::new_unchecked("sender"),
-            AgentId::new_unchecked("recipient"),
-        );
-        let to = AgentId::new_unchecked("wrong-recipient");
-        let result = validate_send_route(&route, &to);
-        assert!(result.is_err());
-        assert!(
-            result
-                .unwrap_err()
-                .to_string()
-                .contains("Route specifies recipient")
-        );
-    }
+    async fn ack(&self, topic: &Topic, group: &str, message: &Message) -> MeshResult<()> {
+        let entry_id = message.metadata(GROUP_ENTRY_ID_METADATA).ok_or_else(|| {
+            MeshError::BackendError(format!(
+                "Message {} was not received from a consumer group",
+                message.id
+            ))
+        })?;
 
-    #[test]
-    fn test_validate_send_route_broadcast_invalid() {
-        let route = Route::broadcast(AgentId::new_unchecked("sender"));
-        let to = AgentId::new_unchecked("recipient");
-        let result = validate_send_route(&route, &to);
-        assert!(result.is_err());
-        assert!(
-            result
-                .unwrap_err()
-                .to_string()
-                .contains("Cannot send broadcast message")
-        );
-    }
+        let mut conn = self.get_connection().await?;
+        conn.xack::<_, _, _, ()>(Self::topic_stream_key(topic), group, &[entry_id])
+            .await
+            .map_err(|e| MeshError::BackendError(e.to_string()))?;
 
-    #[test]
-    fn test_validate_send_route_anonymous_invalid() {
-        let route = Route::anonymous();
-        let to = AgentId::new_unchecked("recipient");
-        let result = validate_send_route(&route, &to);
-        assert!(result.is_err());
-        assert!(
-            result
-                .unwrap_err()
-                .to_string()
-                .contains("Cannot send anonymous message")
+        debug!(
+            "Acknowledged message {} in group {} on topic {}",
+            message.id, group, topic
         );
+        Ok(())
     }
+}
 
-    #[test]
-    fn test_validate_send_route_system_valid() {
-        let route = Route::system(AgentId::new_unchecked("recipient"));
-        let to = AgentId::new_unchecked("recipient");
-        assert!(validate_send_route(&route, &to).is_ok());
-    }
+/// State of one consumer group member's message stream
+struct GroupReader {
+    conn: redis::aio::MultiplexedConnection,
+    key: String,
+    group: String,
+    consumer: String,
+    codec: MessageCodec,
+    claim_idle_ms: u64,
+    stop: Arc<AtomicBool>,
+    /// Entries read but not yet yielded
+    buffered: VecDeque<StreamId>,
+    /// Whether to reclaim idle entries of other members before the next read
+    reclaim: bool,
+}
 
-    #[test]
-    fn test_validate_send_route_system_mismatch() {
-        let route = Route::system(AgentId::new_unchecked("recipient"));
-        let to = AgentId::new_unchecked("wrong-recipient");
-        let result = validate_send_route(&route, &to);
-        assert!(result.is_err());
+impl GroupReader {
+    async fn next(mut self) -> Option<(MeshResult<Message>, Self)> {
+        loop {
+            if self.stop.load(Ordering::Acquire) {
+                return None;
+            }
+
+            if let Some(entry) = self.buffered.pop_front() {
+                let entry_id = entry.id.clone();
+                let result = self.decode(entry);
+                if result.is_err() {
+                    // No member can handle an undecodable entry; drop it rather
+                    // than reclaiming it forever
+                    if let Err(e) = self
+                        .conn
+                        .xack::<_, _, _, ()>(&self.key, &self.group, &[&entry_id])
+                        .await
+                    {
+                        warn!("Failed to acknowledge malformed entry {}: {}", entry_id, e);
+                    }
+                }
+                return Some((result, self));
+            }
+
+            let fetched = if self.reclaim {
+                self.reclaim = false;
+                self.claim_idle().await
+            } else {
+                self.read_new().await
+            };
+
+            match fetched {
+                Ok(0) => {
+                    // Nothing new arrived; look for entries abandoned by members
+                    // that left before acknowledging them
+                    self.reclaim = true;
+                }
+                Ok(_) => {}
+                Err(e) => return Some((Err(e), self)),
+            }
+        }
     }
 
-    #[test]
-    fn test_validate_broadcast_route_broadcast_valid() {
-        let route = Route::broadcast(AgentId::new_unchecked("sender"));
-        assert!(validate_broadcast_route(&route).is_ok());
+    /// Take over entries idle for longer than `claim_idle_ms`
+    async fn claim_idle(&mut self) -> MeshResult<usize> {
+        let reply: StreamAutoClaimReply = self
+            .conn
+            .xautoclaim_options(
+                &self.key,
+                &self.group,
+                &self.consumer,
+                self.claim_idle_ms,
+                "0-0",
+                StreamAutoClaimOptions::default().count(16),
+            )
+            .await
+            .map_err(|e| MeshError::ReceiveFailed(e.to_string()))?;
+        let claimed = reply.claimed.len();
+        self.buffered.extend(reply.claimed);
+        Ok(claimed)
+    }
+
+    /// Block for entries not yet delivered to any member
+    async fn read_new(&mut self) -> MeshResult<usize> {
+        let options = StreamReadOptions::default()
+            .group(&self.group, &self.consumer)
+            .block(GROUP_READ_BLOCK_MS)
+            .count(16);
+        let reply: Option<StreamReadReply> = self
+            .conn
+            .xread_options(&[&self.key], &[">"], &options)
+            .await
+            .map_err(|e| MeshError::ReceiveFailed(e.to_string()))?;
+
+        let mut read = 0;
+        for stream in reply.into_iter().flat_map(|reply| reply.keys) {
+            read += stream.ids.len();
+            self.buffered.extend(stream.ids);
+        }
+        Ok(read)
     }
 
-    #[test]
-    fn test_validate_broadcast_route_anonymous_valid() {
-        let route = Route::anonymous();
-        assert!(validate_broadcast_route(&route).is_ok());
+    /// Decode an entry's message, tagging it with the entry ID for acknowledgement
+    ///
+    /// The entry stays pending until the member acknowledges it, so a member
+    /// that stops before handling it leaves it to be reclaimed by another.
+    fn decode(&self, entry: StreamId) -> MeshResult<Message> {
+        let frame: Vec<u8> = entry.get(GROUP_FRAME_FIELD).ok_or_else(|| {
+            MeshError::DeserializationFailed(format!("Stream entry {} has no frame", entry.id))
+        })?;
+        let mut message = self.codec.decode(&frame).inspect_err(|e| {
+            error!("Failed to deserialize message: {}", e);
+        })?;
+        message
+            .metadata
+            .insert(GROUP_ENTRY_ID_METADATA.to_string(), entry.id);
+        Ok(message)
     }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
 
     #[test]
-    fn test_validate_broadcast_route_unicast_invalid() {
-        let route = Route::unicast(
-            AgentId::new_unchecked("sender"),
-            AgentId::new_unchecked("recipient"),
-        );
-        let result = validate_broadcast_route(&route);
-        assert!(result.is_err());
-        assert!(
-            result
-                .unwrap_err()
-                .to_string()
-                .contains("Cannot broadcast unicast message")
-        );
+    fn test_redis_config() {
+        let config = RedisConfig::new("redis://localhost:6379")
+            .with_pool_size(20)
+            .with_connect_timeout(10)
+            .with_codec(MessageCodec::MessagePack);
+
+        assert_eq!(config.pool_size, 20);
+        assert_eq!(config.connect_timeout_secs, 10);
+        assert_eq!(config.codec, MessageCodec::MessagePack);
+        assert_eq!(RedisConfig::default().codec, MessageCodec::Json);
     }
 
     #[test]
-    fn test_validate_broadcast_route_system_invalid() {
-        let route = Route::system(AgentId::new_unchecked("recipient"));
-        let result = validate_broadcast_route(&route);
-        assert!(result.is_err());
-        assert!(
-            result
-                .unwrap_err()
-                .to_string()
-                .contains("Cannot broadcast system message")
+    fn test_key_generation() {
+        let agent_id = AgentId::new_unchecked("agent-1");
+        let key = RedisMesh::agent_key(&agent_id);
+        assert_eq!(key, "skreaver:agent:agent-1:mailbox");
+
+        let topic = Topic::from("notifications");
+        let topic_key = RedisMesh::topic_key(&topic);
+        assert_eq!(topic_key, "skreaver:topic:notifications");
+        assert_eq!(
+            RedisMesh::topic_stream_key(&topic),
+            "skreaver:topic:notifications:stream"
         );
     }
 }
@@ -0,0 +1,439 @@
+//! Region-aware routing for multi-region deployments
+//!
+//! A [`RegionalMesh`] fronts the mesh of one region and bridges to the meshes
+//! of its peer regions. Messages are delivered in the local region only,
+//! unless they are explicitly marked with [`RegionScope::CrossRegion`], in
+//! which case broadcasts and topic publishes are also forwarded to every peer
+//! region and unicasts may reach agents that live in another region. Keeping
+//! traffic local by default avoids paying inter-region bandwidth and latency
+//! for messages only local agents need.
+//!
+//! Each region's name comes from configuration, and the region of remote
+//! agents from their metadata ([`MetadataKey::Region`]).
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use std::sync::Arc;
+//! use skreaver_mesh::{AgentMesh, InMemoryMesh, Message, Region, RegionalMesh};
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let us = Arc::new(InMemoryMesh::new());
+//! let eu = Arc::new(InMemoryMesh::new());
+//! let mesh = RegionalMesh::new(Region::parse("us-east")?, us)
+//!     .with_peer(Region::parse("eu-west")?, eu);
+//!
+//! // Only agents in us-east see this
+//! mesh.broadcast(Message::new("local cache flush")).await?;
+//! // Agents in both regions see this
+//! mesh.broadcast(Message::new("shutdown").cross_region()).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use skreaver_core::{Metadata, MetadataKey};
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use tracing::debug;
+
+use crate::{
+    error::{MeshError, MeshResult},
+    mesh::{AgentMesh, MessageStream},
+    message::Message,
+    types::{AgentId, Topic, ValidationError},
+};
+
+/// Message metadata key holding the region a message was sent from
+pub const REGION_METADATA_KEY: &str = "region";
+
+/// Message metadata key holding the message's [`RegionScope`]
+pub const REGION_SCOPE_METADATA_KEY: &str = "region_scope";
+
+/// Name of a deployment region, such as `us-east` or `eu-west`
+///
+/// Region names follow the same rules as topic names.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Region(String);
+
+impl Region {
+    /// Parse and validate a region name
+    pub fn parse(region: impl AsRef<str>) -> Result<Self, ValidationError> {
+        Topic::parse(region).map(|topic| Self(topic.as_str().to_string()))
+    }
+
+    /// Read the region from agent metadata, if present and valid
+    pub fn from_metadata(metadata: &Metadata) -> Option<Self> {
+        let region = metadata.get(&MetadataKey::Region)?.as_string()?;
+        Self::parse(region).ok()
+    }
+
+    /// Get the region name as a string slice
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl FromStr for Region {
+    type Err = ValidationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl fmt::Display for Region {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// How far a message may travel in a multi-region mesh
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum RegionScope {
+    /// Deliver only within the sender's region
+    #[default]
+    Local,
+    /// Deliver in the sender's region and bridge to peer regions
+    CrossRegion,
+}
+
+impl RegionScope {
+    /// Get the scope as it is stored in message metadata
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Local => "local",
+            Self::CrossRegion => "cross-region",
+        }
+    }
+}
+
+/// Mesh that routes within its own region and bridges to peer regions on request
+///
+/// Peers should be the underlying meshes of the other regions, not their
+/// `RegionalMesh`, so a bridged message is delivered once and never
+/// forwarded again. Subscriptions, queue depth and agent listings only cover
+/// the local region.
+pub struct RegionalMesh<M: AgentMesh> {
+    region: Region,
+    local: Arc<M>,
+    peers: Vec<(Region, Arc<dyn AgentMesh>)>,
+    agent_regions: HashMap<AgentId, Region>,
+    bridged: AtomicU64,
+}
+
+impl<M: AgentMesh> RegionalMesh<M> {
+    /// Create a mesh for `region` delivering through `local`
+    pub fn new(region: Region, local: Arc<M>) -> Self {
+        Self {
+            region,
+            local,
+            peers: Vec::new(),
+            agent_regions: HashMap::new(),
+            bridged: AtomicU64::new(0),
+        }
+    }
+
+    /// Bridge cross-region messages to the mesh of `region`
+    pub fn with_peer(mut self, region: Region, mesh: Arc<dyn AgentMesh>) -> Self {
+        self.peers.retain(|(existing, _)| *existing != region);
+        self.peers.push((region, mesh));
+        self
+    }
+
+    /// Record that `agent_id` lives in `region`
+    pub fn with_agent_region(mut self, agent_id: AgentId, region: Region) -> Self {
+        self.agent_regions.insert(agent_id, region);
+        self
+    }
+
+    /// Record the region of `agent_id` from its metadata
+    ///
+    /// Agents whose metadata names no valid region are assumed to be local.
+    pub fn with_agent_metadata(self, agent_id: AgentId, metadata: &Metadata) -> Self {
+        match Region::from_metadata(metadata) {
+            Some(region) => self.with_agent_region(agent_id, region),
+            None => self,
+        }
+    }
+
+    /// Region this mesh routes for
+    pub fn region(&self) -> &Region {
+        &self.region
+    }
+
+    /// Mesh of the local region
+    pub fn local(&self) -> &Arc<M> {
+        &self.local
+    }
+
+    /// Region an agent lives in; agents without a recorded region are local
+    pub fn agent_region(&self, agent_id: &AgentId) -> &Region {
+        self.agent_regions.get(agent_id).unwrap_or(&self.region)
+    }
+
+    /// Number of message deliveries bridged to peer regions
+    pub fn bridged_messages(&self) -> u64 {
+        self.bridged.load(Ordering::Relaxed)
+    }
+
+    fn peer(&self, region: &Region) -> Option<&Arc<dyn AgentMesh>> {
+        self.peers
+            .iter()
+            .find(|(peer, _)| peer == region)
+            .map(|(_, mesh)| mesh)
+    }
+
+    /// Stamp the origin region on a message that does not carry one yet
+    fn stamp(&self, mut message: Message) -> Message {
+        message
+            .metadata
+            .entry(REGION_METADATA_KEY.to_string())
+            .or_insert_with(|| self.region.as_str().to_string());
+        message
+    }
+
+    /// Deliver a message to every peer region through `deliver`
+    async fn bridge<'a, F, Fut>(&'a self, message: &Message, deliver: F) -> MeshResult<()>
+    where
+        F: Fn(&'a Arc<dyn AgentMesh>, Message) -> Fut,
+        Fut: std::future::Future<Output = MeshResult<()>>,
+    {
+        for (region, mesh) in &self.peers {
+            deliver(mesh, message.clone()).await?;
+            self.bridged.fetch_add(1, Ordering::Relaxed);
+            debug!(
+                "Bridged message {} from region {} to {}",
+                message.id, self.region, region
+            );
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<M: AgentMesh> AgentMesh for RegionalMesh<M> {
+    async fn send(&self, to: &AgentId, message: Message) -> MeshResult<()> {
+        let message = self.stamp(message);
+        let region = self.agent_region(to);
+        if *region == self.region {
+            return self.local.send(to, message).await;
+        }
+
+        if message.region_scope() != RegionScope::CrossRegion {
+            return Err(MeshError::SendFailed(format!(
+                "Agent '{}' is in region '{}'; mark the message cross-region to reach it from '{}'",
+                to, region, self.region
+            )));
+        }
+        let peer = self.peer(region).ok_or_else(|| {
+            MeshError::SendFailed(format!(
+                "No bridge from region '{}' to region '{}'",
+                self.region, region
+            ))
+        })?;
+        peer.send(to, message).await?;
+        self.bridged.fetch_add(1, Ordering::Relaxed);
+        Ok(())
+    }
+
+    async fn broadcast(&self, message: Message) -> MeshResult<()> {
+        let message = self.stamp(message);
+        if message.region_scope() == RegionScope::CrossRegion {
+            self.bridge(&message, |mesh, message| mesh.broadcast(message))
+                .await?;
+        }
+        self.local.broadcast(message).await
+    }
+
+    async fn subscribe(&self, topic: &Topic) -> MeshResult<MessageStream> {
+        self.local.subscribe(topic).await
+    }
+
+    async fn publish(&self, topic: &Topic, message: Message) -> MeshResult<()> {
+        let message = self.stamp(message);
+        if message.region_scope() == RegionScope::CrossRegion {
+            self.bridge(&message, |mesh, message| mesh.publish(topic, message))
+                .await?;
+        }
+        self.local.publish(topic, message).await
+    }
+
+    async fn unsubscribe(&self, topic: &Topic) -> MeshResult<()> {
+        self.local.unsubscribe(topic).await
+    }
+
+    async fn queue_depth(&self) -> MeshResult<usize> {
+        self.local.queue_depth().await
+    }
+
+    async fn is_reachable(&self, agent_id: &AgentId) -> bool {
+        let region = self.agent_region(agent_id);
+        if *region == self.region {
+            return self.local.is_reachable(agent_id).await;
+        }
+        match self.peer(region) {
+            Some(peer) => peer.is_reachable(agent_id).await,
+            None => false,
+        }
+    }
+
+    async fn list_agents(&self) -> MeshResult<Vec<AgentId>> {
+        self.local.list_agents().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::memory::InMemoryMesh;
+    use crate::message::MessagePayload;
+    use futures::StreamExt;
+    use std::time::Duration;
+
+    fn region(name: &str) -> Region {
+        Region::parse(name).unwrap()
+    }
+
+    fn agent(id: &str) -> AgentId {
+        AgentId::new_unchecked(id)
+    }
+
+    /// Two regions; the returned regional mesh routes for `us-east`
+    fn two_regions() -> (RegionalMesh<InMemoryMesh>, InMemoryMesh, InMemoryMesh) {
+        let us = InMemoryMesh::new();
+        let eu = InMemoryMesh::new();
+        let mesh = RegionalMesh::new(region("us-east"), Arc::new(us.clone()))
+            .with_peer(region("eu-west"), Arc::new(eu.clone()));
+        (mesh, us, eu)
+    }
+
+    async fn next_message(stream: &mut MessageStream) -> Option<Message> {
+        tokio::time::timeout(Duration::from_millis(50), stream.next())
+            .await
+            .ok()
+            .flatten()
+            .map(Result::unwrap)
+    }
+
+    fn text(message: &Message) -> &str {
+        match &message.payload {
+            MessagePayload::Text(text) => text,
+            other => panic!("expected text payload, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_region_scoped_broadcast_stays_local() {
+        let (mesh, us, eu) = two_regions();
+        let mut local = us.subscribe_broadcast();
+        let mut remote = eu.subscribe_broadcast();
+
+        mesh.broadcast(Message::new("flush cache")).await.unwrap();
+
+        let received = next_message(&mut local).await.unwrap();
+        assert_eq!(text(&received), "flush cache");
+        assert_eq!(received.metadata(REGION_METADATA_KEY), Some("us-east"));
+        assert!(next_message(&mut remote).await.is_none());
+        assert_eq!(mesh.bridged_messages(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_cross_region_broadcast_and_publish_reach_all_regions() {
+        let (mesh, us, eu) = two_regions();
+        let mut local = us.subscribe_broadcast();
+        let mut remote = eu.subscribe_broadcast();
+
+        mesh.broadcast(Message::new("shutdown").cross_region())
+            .await
+            .unwrap();
+
+        assert_eq!(text(&next_message(&mut local).await.unwrap()), "shutdown");
+        let bridged = next_message(&mut remote).await.unwrap();
+        assert_eq!(text(&bridged), "shutdown");
+        assert_eq!(bridged.metadata(REGION_METADATA_KEY), Some("us-east"));
+        assert_eq!(bridged.region_scope(), RegionScope::CrossRegion);
+
+        let topic = Topic::from("alerts");
+        let mut local_topic = us.subscribe(&topic).await.unwrap();
+        let mut remote_topic = eu.subscribe(&topic).await.unwrap();
+        mesh.publish(&topic, Message::new("local alert"))
+            .await
+            .unwrap();
+        mesh.publish(&topic, Message::new("global alert").cross_region())
+            .await
+            .unwrap();
+
+        assert_eq!(
+            text(&next_message(&mut local_topic).await.unwrap()),
+            "local alert"
+        );
+        assert_eq!(
+            text(&next_message(&mut local_topic).await.unwrap()),
+            "global alert"
+        );
+        assert_eq!(
+            text(&next_message(&mut remote_topic).await.unwrap()),
+            "global alert"
+        );
+        assert!(next_message(&mut remote_topic).await.is_none());
+        assert_eq!(mesh.bridged_messages(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_unicast_to_remote_agent_requires_cross_region() {
+        let metadata = skreaver_core::MetadataBuilder::new()
+            .with_string(MetadataKey::Region, "eu-west")
+            .unwrap()
+            .build();
+        let (mesh, us, eu) = two_regions();
+        let mesh = mesh
+            .with_agent_metadata(agent("planner"), &metadata)
+            .with_agent_region(agent("auditor"), region("ap-south"));
+        assert_eq!(mesh.agent_region(&agent("planner")).as_str(), "eu-west");
+        assert_eq!(mesh.agent_region(&agent("worker")).as_str(), "us-east");
+
+        mesh.send(
+            &agent("worker"),
+            Message::system(agent("worker"), "local job"),
+        )
+        .await
+        .unwrap();
+        let received = us
+            .receive(&agent("worker"), Duration::from_millis(50))
+            .await
+            .unwrap();
+        assert_eq!(text(&received.unwrap()), "local job");
+
+        assert!(
+            mesh.send(&agent("planner"), Message::system(agent("planner"), "plan"))
+                .await
+                .is_err()
+        );
+        mesh.send(
+            &agent("planner"),
+            Message::system(agent("planner"), "plan").cross_region(),
+        )
+        .await
+        .unwrap();
+        let received = eu
+            .receive(&agent("planner"), Duration::from_millis(50))
+            .await
+            .unwrap();
+        assert_eq!(text(&received.unwrap()), "plan");
+
+        let err = mesh
+            .send(
+                &agent("auditor"),
+                Message::system(agent("auditor"), "audit").cross_region(),
+            )
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("No bridge"));
+    }
+}
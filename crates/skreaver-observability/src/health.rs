@@ -5,7 +5,9 @@
 
 use crate::ObservabilityError;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use skreaver_core::memory::{MemoryKey, MemoryReader, MemoryUpdate, MemoryWriter};
+use std::collections::{HashMap, HashSet};
+use std::time::Duration;
 
 // ============================================================================
 // Typestate Pattern Markers for Health States
@@ -260,6 +262,14 @@ impl HealthStatus {
             HealthStatus::Unhealthy { .. } => "unhealthy",
         }
     }
+
+    /// Get the reason for a degraded or unhealthy status
+    pub fn reason(&self) -> Option<&str> {
+        match self {
+            HealthStatus::Healthy => None,
+            HealthStatus::Degraded { reason } | HealthStatus::Unhealthy { reason } => Some(reason),
+        }
+    }
 }
 
 // Conversion traits for backward compatibility
@@ -299,6 +309,9 @@ pub struct ComponentHealth {
     pub response_time_ms: u64,
     /// Additional metadata
     pub metadata: HashMap<String, String>,
+    /// Components this one depends on
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub depends_on: Vec<String>,
 }
 
 impl ComponentHealth {
@@ -310,6 +323,7 @@ impl ComponentHealth {
             last_check: chrono::Utc::now(),
             response_time_ms: 0,
             metadata: HashMap::new(),
+            depends_on: Vec::new(),
         }
     }
 
@@ -321,6 +335,7 @@ impl ComponentHealth {
             last_check: chrono::Utc::now(),
             response_time_ms: 0,
             metadata: HashMap::new(),
+            depends_on: Vec::new(),
         }
     }
 
@@ -332,6 +347,7 @@ impl ComponentHealth {
             last_check: chrono::Utc::now(),
             response_time_ms: 0,
             metadata: HashMap::new(),
+            depends_on: Vec::new(),
         }
     }
 
@@ -340,6 +356,12 @@ impl ComponentHealth {
         self.metadata.insert(key, value);
         self
     }
+
+    /// Set the components this one depends on
+    pub fn with_dependencies(mut self, depends_on: Vec<String>) -> Self {
+        self.depends_on = depends_on;
+        self
+    }
 }
 
 /// Overall system health information
@@ -358,7 +380,11 @@ pub struct SystemHealth {
 
 impl SystemHealth {
     /// Create new system health from components
-    pub fn from_components(components: HashMap<String, ComponentHealth>) -> Self {
+    ///
+    /// Component statuses are rolled up along `depends_on` first, see
+    /// [`roll_up_dependencies`](Self::roll_up_dependencies).
+    pub fn from_components(mut components: HashMap<String, ComponentHealth>) -> Self {
+        Self::roll_up_dependencies(&mut components);
         let status = Self::calculate_overall_status(&components);
 
         Self {
@@ -372,7 +398,11 @@ impl SystemHealth {
     }
 
     /// Create system health with explicit uptime
-    pub fn with_uptime(components: HashMap<String, ComponentHealth>, uptime_seconds: u64) -> Self {
+    pub fn with_uptime(
+        mut components: HashMap<String, ComponentHealth>,
+        uptime_seconds: u64,
+    ) -> Self {
+        Self::roll_up_dependencies(&mut components);
         let status = Self::calculate_overall_status(&components);
 
         Self {
@@ -383,6 +413,64 @@ impl SystemHealth {
         }
     }
 
+    /// Cap each component's status by the health of its dependencies
+    ///
+    /// A healthy component with a degraded or unhealthy dependency, direct or
+    /// transitive, becomes `Degraded`: it still runs, but cannot do all of its
+    /// work. Components that already report a problem keep their own status.
+    /// Dependencies missing from `components` are ignored, and dependency
+    /// cycles are cut where they close. Applying this more than once is a no-op.
+    pub fn roll_up_dependencies(components: &mut HashMap<String, ComponentHealth>) {
+        let mut resolved = HashMap::new();
+        let names: Vec<String> = components.keys().cloned().collect();
+        for name in &names {
+            Self::resolve_status(name, components, &mut resolved, &mut HashSet::new());
+        }
+        for (name, status) in resolved {
+            if let Some(component) = components.get_mut(&name) {
+                component.status = status;
+            }
+        }
+    }
+
+    fn resolve_status(
+        name: &str,
+        components: &HashMap<String, ComponentHealth>,
+        resolved: &mut HashMap<String, HealthStatus>,
+        visiting: &mut HashSet<String>,
+    ) -> Option<HealthStatus> {
+        if let Some(status) = resolved.get(name) {
+            return Some(status.clone());
+        }
+        let component = components.get(name)?;
+        if !visiting.insert(name.to_string()) {
+            return Some(component.status.clone());
+        }
+
+        let mut status = component.status.clone();
+        for dependency in &component.depends_on {
+            let Some(dependency_status) =
+                Self::resolve_status(dependency, components, resolved, visiting)
+            else {
+                continue;
+            };
+            if status.is_healthy() && !dependency_status.is_healthy() {
+                status = HealthStatus::Degraded {
+                    reason: format!(
+                        "dependency '{}' is {}: {}",
+                        dependency,
+                        dependency_status.as_str(),
+                        dependency_status.reason().unwrap_or_default()
+                    ),
+                };
+            }
+        }
+
+        visiting.remove(name);
+        resolved.insert(name.to_string(), status.clone());
+        Some(status)
+    }
+
     /// Calculate overall system status from component statuses
     fn calculate_overall_status(components: &HashMap<String, ComponentHealth>) -> HealthStatus {
         if components.is_empty() {
@@ -424,6 +512,7 @@ impl SystemHealth {
 /// Health checker for system components
 pub struct HealthChecker {
     components: HashMap<String, Box<dyn HealthCheck + Send + Sync>>,
+    dependencies: HashMap<String, Vec<String>>,
 }
 
 impl HealthChecker {
@@ -431,6 +520,7 @@ impl HealthChecker {
     pub fn new() -> Self {
         Self {
             components: HashMap::new(),
+            dependencies: HashMap::new(),
         }
     }
 
@@ -439,10 +529,36 @@ impl HealthChecker {
     where
         T: HealthCheck + Send + Sync + 'static,
     {
+        self.register_with_deps(name, check, &[]);
+    }
+
+    /// Register a health check for a component that depends on others
+    ///
+    /// [`check_all`](Self::check_all) reports the component as at most
+    /// `Degraded` while any of `deps` is not healthy. Dependencies may be
+    /// registered before or after the component.
+    pub fn register_with_deps<T>(&mut self, name: String, check: T, deps: &[&str])
+    where
+        T: HealthCheck + Send + Sync + 'static,
+    {
+        let deps: Vec<String> = deps.iter().map(|dep| dep.to_string()).collect();
+        if deps.is_empty() {
+            self.dependencies.remove(&name);
+        } else {
+            self.dependencies.insert(name.clone(), deps);
+        }
         self.components.insert(name, Box::new(check));
     }
 
+    /// Dependencies of each registered component that has any
+    pub fn dependency_graph(&self) -> &HashMap<String, Vec<String>> {
+        &self.dependencies
+    }
+
     /// Perform health checks on all registered components
+    ///
+    /// Component statuses are rolled up along the dependency graph, and the
+    /// overall status reflects the rolled-up components.
     pub async fn check_all(&self) -> SystemHealth {
         let mut components = HashMap::new();
 
@@ -456,6 +572,7 @@ impl HealthChecker {
                 Err(reason) => ComponentHealth::unhealthy(name.clone(), reason),
             };
             component.response_time_ms = response_time.as_millis() as u64;
+            component.depends_on = self.dependencies.get(name).cloned().unwrap_or_default();
             components.insert(name.clone(), component);
         }
 
@@ -463,6 +580,9 @@ impl HealthChecker {
     }
 
     /// Check specific component by name
+    ///
+    /// Only the component's own check runs, so its dependencies are not
+    /// rolled up.
     pub async fn check_component(&self, name: &str) -> Option<ComponentHealth> {
         let checker = self.components.get(name)?;
         let start = std::time::Instant::now();
@@ -474,6 +594,7 @@ impl HealthChecker {
             Err(reason) => ComponentHealth::unhealthy(name.to_string(), reason),
         };
         component.response_time_ms = response_time.as_millis() as u64;
+        component.depends_on = self.dependencies.get(name).cloned().unwrap_or_default();
         Some(component)
     }
 }
@@ -512,6 +633,95 @@ impl HealthCheck for AlwaysHealthy {
     }
 }
 
+/// Default key written by [`MemoryHealthCheck`] on every probe
+pub const DEFAULT_SENTINEL_KEY: &str = "skreaver:health:sentinel";
+
+/// Default time limit for a single [`MemoryHealthCheck`] probe
+pub const DEFAULT_MEMORY_CHECK_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Health check that verifies a memory backend is reachable
+///
+/// Each check performs a lightweight round-trip: a fresh nonce is written to a
+/// sentinel key and read back. The check fails if either operation errors, the
+/// value read back differs, or the round-trip exceeds the timeout. Works with
+/// any backend implementing the core memory traits (`RedisMemory`,
+/// `PostgresMemory`, `SqliteMemory`, ...); the backend is cloned for each
+/// probe, so it should share its connection pool between clones.
+///
+/// The sentinel key is overwritten on every probe and never deleted, so pick
+/// a key (see [`with_sentinel_key`](Self::with_sentinel_key)) that does not
+/// collide with agent data.
+#[derive(Debug, Clone)]
+pub struct MemoryHealthCheck<M> {
+    memory: M,
+    sentinel_key: MemoryKey,
+    timeout: Duration,
+}
+
+impl<M> MemoryHealthCheck<M>
+where
+    M: MemoryReader + MemoryWriter + Clone + Send + 'static,
+{
+    /// Create a health check for `memory` using the default sentinel key
+    pub fn new(memory: M) -> Self {
+        Self {
+            memory,
+            sentinel_key: MemoryKey::new(DEFAULT_SENTINEL_KEY)
+                .expect("default sentinel key is valid"),
+            timeout: DEFAULT_MEMORY_CHECK_TIMEOUT,
+        }
+    }
+
+    /// Use a custom sentinel key
+    pub fn with_sentinel_key(mut self, key: MemoryKey) -> Self {
+        self.sentinel_key = key;
+        self
+    }
+
+    /// Set the time limit for a single probe
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Write and read back the sentinel key, blocking the current thread
+    fn round_trip(mut memory: M, key: MemoryKey) -> Result<(), String> {
+        let nonce = uuid::Uuid::new_v4().to_string();
+        memory
+            .store(MemoryUpdate::from_validated(key.clone(), nonce.clone()))
+            .map_err(|e| format!("Memory write failed: {}", e))?;
+
+        match memory.load(&key) {
+            Ok(Some(value)) if value == nonce => Ok(()),
+            Ok(Some(_)) => Err("Memory returned a stale sentinel value".to_string()),
+            Ok(None) => Err("Memory lost the sentinel value".to_string()),
+            Err(e) => Err(format!("Memory read failed: {}", e)),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl<M> HealthCheck for MemoryHealthCheck<M>
+where
+    M: MemoryReader + MemoryWriter + Clone + Send + Sync + 'static,
+{
+    async fn check(&self) -> Result<(), String> {
+        // Backends expose blocking APIs, so keep them off the async workers
+        let memory = self.memory.clone();
+        let key = self.sentinel_key.clone();
+        let probe = tokio::task::spawn_blocking(move || Self::round_trip(memory, key));
+
+        match tokio::time::timeout(self.timeout, probe).await {
+            Ok(Ok(result)) => result,
+            Ok(Err(e)) => Err(format!("Memory health probe panicked: {}", e)),
+            Err(_) => Err(format!(
+                "Memory health probe timed out after {}ms",
+                self.timeout.as_millis()
+            )),
+        }
+    }
+}
+
 /// Initialize health check system
 pub fn init_health_checks() -> Result<(), ObservabilityError> {
     // Basic initialization - in a real implementation this would
@@ -522,6 +732,7 @@ pub fn init_health_checks() -> Result<(), ObservabilityError> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use skreaver_core::error::{MemoryBackend, MemoryError, MemoryErrorKind};
 
     #[test]
     fn test_health_status() {
@@ -592,4 +803,133 @@ mod tests {
         assert!(health.status.is_healthy());
         assert_eq!(health.components.len(), 1);
     }
+
+    struct AlwaysDown;
+
+    #[async_trait::async_trait]
+    impl HealthCheck for AlwaysDown {
+        async fn check(&self) -> Result<(), String> {
+            Err("connection refused".to_string())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_dependency_failure_degrades_dependents() {
+        let mut checker = HealthChecker::new();
+        checker.register_with_deps("api".to_string(), AlwaysHealthy, &["agent-runtime"]);
+        checker.register_with_deps(
+            "agent-runtime".to_string(),
+            AlwaysHealthy,
+            &["redis", "not-registered"],
+        );
+        checker.register("redis".to_string(), AlwaysDown);
+        checker.register("metrics".to_string(), AlwaysHealthy);
+
+        assert_eq!(
+            checker.dependency_graph()["agent-runtime"],
+            vec!["redis".to_string(), "not-registered".to_string()]
+        );
+
+        let health = checker.check_all().await;
+        assert_eq!(health.status.as_str(), "unhealthy");
+        assert_eq!(health.components["redis"].status.as_str(), "unhealthy");
+        assert!(health.components["metrics"].status.is_healthy());
+
+        let runtime = &health.components["agent-runtime"];
+        assert_eq!(runtime.depends_on, vec!["redis", "not-registered"]);
+        assert!(matches!(
+            &runtime.status,
+            HealthStatus::Degraded { reason } if reason.contains("'redis' is unhealthy")
+        ));
+        assert!(matches!(
+            &health.components["api"].status,
+            HealthStatus::Degraded { reason } if reason.contains("'agent-runtime' is degraded")
+        ));
+
+        // Rolling up again leaves the statuses unchanged
+        let again = SystemHealth::from_components(health.components.clone());
+        assert_eq!(
+            again.components["api"].status,
+            health.components["api"].status
+        );
+    }
+
+    #[test]
+    fn test_dependency_cycle_is_cut() {
+        let mut components = HashMap::new();
+        components.insert(
+            "a".to_string(),
+            ComponentHealth::healthy("a".to_string()).with_dependencies(vec!["b".to_string()]),
+        );
+        components.insert(
+            "b".to_string(),
+            ComponentHealth::healthy("b".to_string()).with_dependencies(vec!["a".to_string()]),
+        );
+
+        let system = SystemHealth::from_components(components);
+        assert!(system.status.is_healthy());
+    }
+
+    /// Memory whose backend is unreachable: every operation fails
+    #[derive(Clone)]
+    struct UnreachableMemory;
+
+    fn connection_refused() -> MemoryError {
+        MemoryError::ConnectionFailed {
+            backend: MemoryBackend::Redis,
+            kind: MemoryErrorKind::NetworkError {
+                details: "connection refused".to_string(),
+            },
+        }
+    }
+
+    impl MemoryReader for UnreachableMemory {
+        fn load(&self, _key: &MemoryKey) -> Result<Option<String>, MemoryError> {
+            Err(connection_refused())
+        }
+        fn load_many(&self, _keys: &[MemoryKey]) -> Result<Vec<Option<String>>, MemoryError> {
+            Err(connection_refused())
+        }
+    }
+
+    impl MemoryWriter for UnreachableMemory {
+        fn store(&mut self, _update: MemoryUpdate) -> Result<(), MemoryError> {
+            Err(connection_refused())
+        }
+        fn store_many(&mut self, _updates: Vec<MemoryUpdate>) -> Result<(), MemoryError> {
+            Err(connection_refused())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_memory_health_check_reachable_backend() {
+        let memory = skreaver_core::InMemoryMemory::new();
+        let check = MemoryHealthCheck::new(memory.clone());
+
+        assert_eq!(check.check().await, Ok(()));
+        let sentinel = MemoryKey::new(DEFAULT_SENTINEL_KEY).unwrap();
+        assert!(memory.load(&sentinel).unwrap().is_some());
+    }
+
+    #[tokio::test]
+    async fn test_memory_health_check_unreachable_backend_flips_readiness() {
+        let mut checker = HealthChecker::new();
+        checker.register(
+            "memory".to_string(),
+            MemoryHealthCheck::new(skreaver_core::InMemoryMemory::new()),
+        );
+        assert!(checker.check_all().await.status.is_healthy());
+
+        checker.register(
+            "redis".to_string(),
+            MemoryHealthCheck::new(UnreachableMemory),
+        );
+        let health = checker.check_all().await;
+        assert_eq!(health.status.as_str(), "unhealthy");
+        let redis = &health.components["redis"];
+        assert!(matches!(
+            &redis.status,
+            HealthStatus::Unhealthy { reason } if reason.contains("connection refused")
+        ));
+    }
 }
@@ -7,6 +7,9 @@
 #[cfg(feature = "metrics")]
 pub mod metrics;
 
+#[cfg(feature = "tracing")]
+pub mod log_level;
+
 #[cfg(feature = "tracing")]
 pub mod trace;
 
@@ -16,22 +19,35 @@ pub mod health;
 #[cfg(feature = "opentelemetry")]
 pub mod otel;
 
+pub mod sampling;
+
 pub mod tags;
 
 // Re-export core types for easy access
 #[cfg(feature = "metrics")]
-pub use metrics::{CoreMetrics, MetricsCollector, MetricsRegistry, get_metrics_registry};
+pub use metrics::{
+    CoreMetrics, InFlightGuard, MetricsCollector, MetricsRegistry, StreamGuard,
+    get_metrics_registry,
+};
+
+#[cfg(feature = "tracing")]
+pub use log_level::{AGENT_ID_FIELD, AgentLevelFilter, AgentLogLevels, agent_log_levels};
+
+pub use sampling::{SamplingHandle, log_sampling};
 
 #[cfg(feature = "tracing")]
-pub use trace::{SessionTracker, TraceContext};
+pub use sampling::SamplingFilter;
+
+#[cfg(feature = "tracing")]
+pub use trace::{LogfmtFormat, SessionTracker, TraceContext, fmt_layer};
 
 #[cfg(feature = "health")]
 pub use health::{
-    ComponentHealth, DegradationLevel, Degraded, Health, HealthChecker, HealthStatus, Healthy,
-    Unhealthy,
+    ComponentHealth, DegradationLevel, Degraded, Health, HealthCheck, HealthChecker, HealthStatus,
+    Healthy, MemoryHealthCheck, Unhealthy,
 };
 
-pub use tags::{AgentId, CardinalTags, ErrorKind, SessionId, ToolId};
+pub use tags::{AgentId, CardinalTags, ErrorKind, SessionId, StepOutcome, StreamTransport, ToolId};
 
 /// Standard latency buckets as defined in development plan
 /// Covers microseconds to 10+ seconds with production-focused distribution
@@ -122,18 +138,80 @@ pub struct ObservabilityConfig {
     pub namespace: String,
     /// Log sampling configuration
     pub log_sampling: LogSamplingConfig,
+    /// Output format for log lines
+    pub log_format: LogFormat,
+}
+
+/// Output format for structured logs.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LogFormat {
+    /// One JSON object per line (production default).
+    #[default]
+    Json,
+    /// `key=value` pairs per line, as used by logfmt tooling.
+    Logfmt,
+    /// Multi-line human-readable output for local development.
+    Pretty,
+}
+
+impl LogFormat {
+    /// Environment variable selecting the log format.
+    pub const ENV_VAR: &'static str = "SKREAVER_LOG_FORMAT";
+
+    /// Read the format from `SKREAVER_LOG_FORMAT`, falling back to JSON
+    /// when the variable is unset or invalid.
+    pub fn from_env() -> Self {
+        std::env::var(Self::ENV_VAR)
+            .ok()
+            .and_then(|value| value.parse().ok())
+            .unwrap_or_default()
+    }
+
+    /// Name of the format as accepted by [`FromStr`](std::str::FromStr).
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Json => "json",
+            Self::Logfmt => "logfmt",
+            Self::Pretty => "pretty",
+        }
+    }
+}
+
+impl std::fmt::Display for LogFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl std::str::FromStr for LogFormat {
+    type Err = ObservabilityError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "json" => Ok(Self::Json),
+            "logfmt" => Ok(Self::Logfmt),
+            "pretty" => Ok(Self::Pretty),
+            other => Err(ObservabilityError::Config(format!(
+                "Invalid log format '{}'. Must be 'json', 'logfmt', or 'pretty'",
+                other
+            ))),
+        }
+    }
 }
 
 /// Log sampling configuration per DEVELOPMENT_PLAN.md
+///
+/// These are the initial rates; use the [`SamplingHandle`] returned by
+/// [`init_observability`] to change them at runtime. Rates must be at least 1.
 #[derive(Debug, Clone)]
 pub struct LogSamplingConfig {
     /// Sample rate for ERROR level (1 = no sampling)
     pub error_sample_rate: u32,
     /// Sample rate for WARN level (1 = no sampling)
     pub warn_sample_rate: u32,
-    /// Sample rate for INFO level (100 = 1 in 100)
+    /// Sample rate for INFO level (e.g. 100 = 1 in 100)
     pub info_sample_rate: u32,
-    /// Sample rate for DEBUG level (1000 = 1 in 1000)
+    /// Sample rate for DEBUG level (e.g. 1000 = 1 in 1000)
     pub debug_sample_rate: u32,
 }
 
@@ -144,24 +222,33 @@ impl Default for ObservabilityConfig {
             otel_endpoint: std::env::var("OTEL_EXPORTER_OTLP_ENDPOINT").ok(),
             namespace: "skreaver".to_string(),
             log_sampling: LogSamplingConfig::default(),
+            log_format: LogFormat::default(),
         }
     }
 }
 
 impl Default for LogSamplingConfig {
-    /// Default sampling rates per DEVELOPMENT_PLAN.md specification
+    /// Keep every event; the DEVELOPMENT_PLAN.md rates (INFO 100, DEBUG
+    /// 1000) are opt-in so enabling sampling never silently drops logs
     fn default() -> Self {
         Self {
-            error_sample_rate: 1,    // No sampling for errors
-            warn_sample_rate: 1,     // No sampling for warnings
-            info_sample_rate: 100,   // Sample 1 in 100 info logs
-            debug_sample_rate: 1000, // Sample 1 in 1000 debug logs
+            error_sample_rate: 1,
+            warn_sample_rate: 1,
+            info_sample_rate: 1,
+            debug_sample_rate: 1,
         }
     }
 }
 
 /// Initialize observability framework
-pub fn init_observability(config: ObservabilityConfig) -> Result<(), ObservabilityError> {
+///
+/// Returns the handle for adjusting log sample rates while running.
+pub fn init_observability(
+    config: ObservabilityConfig,
+) -> Result<SamplingHandle, ObservabilityError> {
+    let sampling = log_sampling();
+    sampling.apply(&config.log_sampling)?;
+
     #[cfg(feature = "metrics")]
     if config.mode.metrics_enabled() {
         metrics::init_metrics_registry(&config.namespace)?;
@@ -192,7 +279,7 @@ pub fn init_observability(config: ObservabilityConfig) -> Result<(), Observabili
         // For now, it will be dropped at the end of this scope, which will trigger cleanup
     }
 
-    Ok(())
+    Ok(sampling)
 }
 
 /// Observability framework errors
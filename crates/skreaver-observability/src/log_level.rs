@@ -0,0 +1,197 @@
+//! Per-Agent Log Level Overrides
+//!
+//! Lets operators raise the log level of a single agent without changing the
+//! global filter. Overrides apply to everything logged inside a span carrying
+//! an `agent.id` field, so only the overridden agent's steps log more.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::{Arc, OnceLock, RwLock};
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id};
+use tracing::{Level, Metadata};
+use tracing_subscriber::layer::{Context, Filter};
+use tracing_subscriber::registry::LookupSpan;
+
+/// Span field naming the agent a span belongs to
+pub const AGENT_ID_FIELD: &str = "agent.id";
+
+/// Global per-agent log level overrides
+static AGENT_LOG_LEVELS: OnceLock<Arc<AgentLogLevels>> = OnceLock::new();
+
+/// Log level overrides keyed by agent ID
+#[derive(Debug, Default)]
+pub struct AgentLogLevels {
+    levels: RwLock<HashMap<String, Level>>,
+}
+
+impl AgentLogLevels {
+    /// Create an empty set of overrides
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the most verbose level logged for an agent
+    pub fn set(&self, agent_id: &str, level: Level) {
+        self.levels
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(agent_id.to_string(), level);
+    }
+
+    /// Remove an agent's override, returning it to the global level
+    pub fn clear(&self, agent_id: &str) -> Option<Level> {
+        self.levels
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .remove(agent_id)
+    }
+
+    /// Get an agent's override, if any
+    pub fn get(&self, agent_id: &str) -> Option<Level> {
+        self.levels
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(agent_id)
+            .copied()
+    }
+}
+
+/// Get the global per-agent log level overrides
+pub fn agent_log_levels() -> Arc<AgentLogLevels> {
+    Arc::clone(AGENT_LOG_LEVELS.get_or_init(|| Arc::new(AgentLogLevels::new())))
+}
+
+/// Agent ID recorded on spans carrying an `agent.id` field
+struct AgentScope(String);
+
+/// Per-layer filter enabling events inside agent spans up to the agent's override
+///
+/// Combine it with the global filter using [`FilterExt::or`], so events pass
+/// when either allows them:
+///
+/// ```rust,ignore
+/// use tracing_subscriber::filter::FilterExt;
+/// let filter = EnvFilter::new("info").or(AgentLevelFilter::global());
+/// ```
+///
+/// [`FilterExt::or`]: tracing_subscriber::filter::FilterExt::or
+#[derive(Debug, Clone)]
+pub struct AgentLevelFilter {
+    levels: Arc<AgentLogLevels>,
+}
+
+impl AgentLevelFilter {
+    /// Create a filter reading the given overrides
+    pub fn new(levels: Arc<AgentLogLevels>) -> Self {
+        Self { levels }
+    }
+
+    /// Create a filter reading the global overrides
+    pub fn global() -> Self {
+        Self::new(agent_log_levels())
+    }
+}
+
+impl<S> Filter<S> for AgentLevelFilter
+where
+    S: tracing::Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn enabled(&self, meta: &Metadata<'_>, cx: &Context<'_, S>) -> bool {
+        // Agent spans are always enabled so their events can be scoped to them
+        if meta.is_span() && meta.fields().field(AGENT_ID_FIELD).is_some() {
+            return true;
+        }
+
+        let Some(current) = cx.lookup_current() else {
+            return false;
+        };
+        current.scope().any(|span| {
+            span.extensions()
+                .get::<AgentScope>()
+                .and_then(|scope| self.levels.get(&scope.0))
+                .is_some_and(|level| *meta.level() <= level)
+        })
+    }
+
+    fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, cx: Context<'_, S>) {
+        let mut visitor = AgentIdVisitor(None);
+        attrs.record(&mut visitor);
+        if let (Some(agent_id), Some(span)) = (visitor.0, cx.span(id)) {
+            span.extensions_mut().insert(AgentScope(agent_id));
+        }
+    }
+}
+
+/// Extracts the `agent.id` field from span attributes
+struct AgentIdVisitor(Option<String>);
+
+impl Visit for AgentIdVisitor {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == AGENT_ID_FIELD {
+            self.0 = Some(value.to_string());
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        if field.name() == AGENT_ID_FIELD {
+            self.0 = Some(format!("{:?}", value));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tracing_subscriber::Layer;
+    use tracing_subscriber::filter::{FilterExt, LevelFilter};
+    use tracing_subscriber::layer::SubscriberExt;
+
+    #[derive(Clone, Default)]
+    struct SharedWriter(Arc<std::sync::Mutex<Vec<u8>>>);
+
+    impl std::io::Write for SharedWriter {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_override_elevates_only_that_agent() {
+        let levels = Arc::new(AgentLogLevels::new());
+        levels.set("noisy", Level::DEBUG);
+
+        let writer = SharedWriter::default();
+        let make_writer = writer.clone();
+        let layer = tracing_subscriber::fmt::layer()
+            .with_ansi(false)
+            .with_writer(move || make_writer.clone())
+            .with_filter(LevelFilter::INFO.or(AgentLevelFilter::new(Arc::clone(&levels))));
+        let subscriber = tracing_subscriber::registry().with(layer);
+
+        tracing::subscriber::with_default(subscriber, || {
+            for agent in ["noisy", "quiet"] {
+                let _span = tracing::info_span!("agent_step", agent.id = %agent).entered();
+                tracing::debug!("debug from {agent}");
+                tracing::trace!("trace from {agent}");
+                tracing::info!("info from {agent}");
+            }
+            tracing::debug!("debug outside agents");
+        });
+
+        let logs = String::from_utf8(writer.0.lock().unwrap().clone()).unwrap();
+        assert!(logs.contains("debug from noisy"));
+        assert!(!logs.contains("trace from noisy"));
+        assert!(logs.contains("info from quiet"));
+        assert!(!logs.contains("debug from quiet"));
+        assert!(!logs.contains("debug outside agents"));
+
+        assert_eq!(levels.clear("noisy"), Some(Level::DEBUG));
+        assert_eq!(levels.get("noisy"), None);
+    }
+}
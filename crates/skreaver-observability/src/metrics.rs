@@ -4,15 +4,22 @@
 //! strict cardinality controls and production-ready Prometheus integration.
 
 use crate::LATENCY_BUCKETS;
-use crate::tags::{CardinalTags, ErrorKind, MemoryOp, ToolId};
+use crate::tags::{CardinalTags, ErrorKind, MemoryOp, StepOutcome, StreamTransport, ToolId};
 use prometheus::{
-    CounterVec, Gauge, HistogramOpts, HistogramVec, Opts, Registry, register_counter_vec,
-    register_gauge, register_histogram_vec,
+    Counter, CounterVec, Encoder, Gauge, GaugeVec, Histogram, HistogramOpts, HistogramVec, Opts,
+    Registry, TextEncoder, register_counter, register_counter_vec, register_gauge,
+    register_gauge_vec, register_histogram_vec,
 };
 use std::sync::{Arc, OnceLock, RwLock};
 use std::time::Instant;
 use thiserror::Error;
 
+/// Maximum number of distinct agent types tracked by step outcome metrics
+pub const MAX_AGENT_TYPES: usize = 10;
+
+/// Stream duration buckets, from short polls to connections held for an hour
+pub const STREAM_DURATION_BUCKETS: &[f64] = &[1.0, 5.0, 15.0, 60.0, 300.0, 900.0, 1800.0, 3600.0];
+
 /// Global metrics registry instance
 static METRICS_REGISTRY: OnceLock<Arc<MetricsRegistry>> = OnceLock::new();
 
@@ -20,12 +27,14 @@ static METRICS_REGISTRY: OnceLock<Arc<MetricsRegistry>> = OnceLock::new();
 #[derive(Debug)]
 pub struct CoreMetrics {
     // Agent metrics
-    pub agent_sessions_active: Gauge,   // cardinality: 1
-    pub agent_errors_total: CounterVec, // cardinality: ≤10
+    pub agent_sessions_active: Gauge,          // cardinality: 1
+    pub agent_errors_total: CounterVec,        // cardinality: ≤10
+    pub agent_step_outcomes_total: CounterVec, // cardinality: ≤60 (agent_type, outcome)
 
     // Tool metrics
     pub tool_exec_total: CounterVec,              // cardinality: ≤20
     pub tool_exec_duration_seconds: HistogramVec, // cardinality: ≤20
+    pub tool_calls_in_flight: Gauge,              // cardinality: 1
 
     // Memory metrics
     pub memory_ops_total: CounterVec, // cardinality: 4
@@ -42,6 +51,22 @@ pub struct CoreMetrics {
     pub agent_requests_total: CounterVec, // cardinality: dynamic (agent_id, operation)
     pub agent_errors_by_type: CounterVec, // cardinality: dynamic (agent_id, error_type)
     pub agent_tool_executions: CounterVec, // cardinality: dynamic (agent_id, tool)
+    pub backpressure_queue_depth: GaugeVec, // cardinality: dynamic (agent_id)
+    pub bulkhead_active_requests: GaugeVec, // cardinality: configured (bulkhead)
+    pub bulkhead_utilization: GaugeVec,   // cardinality: configured (bulkhead)
+    pub agent_idle_evictions_total: Counter, // cardinality: 1
+
+    // Shutdown metrics
+    pub shutdown_requests_drained_total: Counter, // cardinality: 1
+    pub shutdown_requests_rejected_total: Counter, // cardinality: 1
+    pub shutdown_ws_connections_closed_total: Counter, // cardinality: 1
+    pub shutdown_drain_duration_seconds: Gauge,   // cardinality: 1
+
+    // Streaming metrics
+    pub streams_active: GaugeVec, // cardinality: 2 (transport)
+    pub stream_bytes_sent_total: CounterVec, // cardinality: 2 (transport)
+    pub stream_frames_sent_total: CounterVec, // cardinality: 2 (transport)
+    pub stream_duration_seconds: HistogramVec, // cardinality: 2 (transport)
 
     // Security metrics (GAP-003 & GAP-004 resolution)
     pub security_auth_attempts_total: CounterVec, // cardinality: ≤5 (result: success|failure|invalid)
@@ -67,6 +92,14 @@ impl CoreMetrics {
             &["kind"]
         )?;
 
+        let agent_step_outcomes_total = register_counter_vec!(
+            Opts::new(
+                format!("{}_agent_step_outcomes_total", namespace),
+                "Total agent steps by agent type and outcome"
+            ),
+            &["agent_type", "outcome"]
+        )?;
+
         let tool_exec_total = register_counter_vec!(
             Opts::new(
                 format!("{}_tool_exec_total", namespace),
@@ -84,6 +117,11 @@ impl CoreMetrics {
             &["tool"]
         )?;
 
+        let tool_calls_in_flight = register_gauge!(Opts::new(
+            format!("{}_tool_calls_in_flight", namespace),
+            "Number of tool calls currently executing"
+        ))?;
+
         let memory_ops_total = register_counter_vec!(
             Opts::new(
                 format!("{}_memory_ops_total", namespace),
@@ -221,11 +259,97 @@ impl CoreMetrics {
             &["agent_id", "tool"]
         )?;
 
+        let backpressure_queue_depth = register_gauge_vec!(
+            Opts::new(
+                format!("{}_backpressure_queue_depth", namespace),
+                "Number of requests currently queued per agent"
+            ),
+            &["agent_id"]
+        )?;
+
+        let bulkhead_active_requests = register_gauge_vec!(
+            Opts::new(
+                format!("{}_bulkhead_active_requests", namespace),
+                "Number of requests currently executing per bulkhead"
+            ),
+            &["bulkhead"]
+        )?;
+
+        let bulkhead_utilization = register_gauge_vec!(
+            Opts::new(
+                format!("{}_bulkhead_utilization", namespace),
+                "Fraction of bulkhead capacity in use (0.0-1.0)"
+            ),
+            &["bulkhead"]
+        )?;
+
+        let agent_idle_evictions_total = register_counter!(Opts::new(
+            format!("{}_agent_idle_evictions_total", namespace),
+            "Agents removed after exceeding the idle timeout"
+        ))?;
+
+        // Shutdown metrics
+        let shutdown_requests_drained_total = register_counter!(Opts::new(
+            format!("{}_shutdown_requests_drained_total", namespace),
+            "In-flight requests that completed during graceful shutdown"
+        ))?;
+
+        let shutdown_requests_rejected_total = register_counter!(Opts::new(
+            format!("{}_shutdown_requests_rejected_total", namespace),
+            "Queued requests rejected during graceful shutdown"
+        ))?;
+
+        let shutdown_ws_connections_closed_total = register_counter!(Opts::new(
+            format!("{}_shutdown_ws_connections_closed_total", namespace),
+            "WebSocket connections closed during graceful shutdown"
+        ))?;
+
+        let shutdown_drain_duration_seconds = register_gauge!(Opts::new(
+            format!("{}_shutdown_drain_duration_seconds", namespace),
+            "Duration of the last graceful shutdown drain in seconds"
+        ))?;
+
+        // Streaming metrics
+        let streams_active = register_gauge_vec!(
+            Opts::new(
+                format!("{}_streams_active", namespace),
+                "Number of open SSE and WebSocket streams by transport"
+            ),
+            &["transport"]
+        )?;
+
+        let stream_bytes_sent_total = register_counter_vec!(
+            Opts::new(
+                format!("{}_stream_bytes_sent_total", namespace),
+                "Payload bytes sent to stream clients by transport"
+            ),
+            &["transport"]
+        )?;
+
+        let stream_frames_sent_total = register_counter_vec!(
+            Opts::new(
+                format!("{}_stream_frames_sent_total", namespace),
+                "Events or messages sent to stream clients by transport"
+            ),
+            &["transport"]
+        )?;
+
+        let stream_duration_seconds = register_histogram_vec!(
+            HistogramOpts::new(
+                format!("{}_stream_duration_seconds", namespace),
+                "How long streams stayed open in seconds by transport"
+            )
+            .buckets(STREAM_DURATION_BUCKETS.to_vec()),
+            &["transport"]
+        )?;
+
         Ok(Self {
             agent_sessions_active,
             agent_errors_total,
+            agent_step_outcomes_total,
             tool_exec_total,
             tool_exec_duration_seconds,
+            tool_calls_in_flight,
             memory_ops_total,
             http_requests_total,
             http_request_duration_seconds,
@@ -236,6 +360,18 @@ impl CoreMetrics {
             agent_requests_total,
             agent_errors_by_type,
             agent_tool_executions,
+            backpressure_queue_depth,
+            bulkhead_active_requests,
+            bulkhead_utilization,
+            agent_idle_evictions_total,
+            shutdown_requests_drained_total,
+            shutdown_requests_rejected_total,
+            shutdown_ws_connections_closed_total,
+            shutdown_drain_duration_seconds,
+            streams_active,
+            stream_bytes_sent_total,
+            stream_frames_sent_total,
+            stream_duration_seconds,
             security_auth_attempts_total,
             security_rbac_checks_total,
             security_policy_violations_total,
@@ -248,6 +384,7 @@ impl CoreMetrics {
 /// Metrics registry with cardinality tracking
 #[derive(Debug)]
 pub struct MetricsRegistry {
+    namespace: String,
     core_metrics: CoreMetrics,
     prometheus_registry: Registry,
     cardinality_tracker: RwLock<CardinalityTracker>,
@@ -261,22 +398,52 @@ impl MetricsRegistry {
         let cardinality_tracker = RwLock::new(CardinalityTracker::new());
 
         Ok(Self {
+            namespace: namespace.to_string(),
             core_metrics,
             prometheus_registry,
             cardinality_tracker,
         })
     }
 
+    /// Get the metric name prefix
+    pub fn namespace(&self) -> &str {
+        &self.namespace
+    }
+
     /// Get core metrics instance
     pub fn core_metrics(&self) -> &CoreMetrics {
         &self.core_metrics
     }
 
-    /// Get Prometheus registry for metrics export
+    /// Get Prometheus registry for additional application metrics
+    ///
+    /// Core metrics are not registered here; use
+    /// [`render_prometheus`](Self::render_prometheus) to export everything.
     pub fn prometheus_registry(&self) -> &Registry {
         &self.prometheus_registry
     }
 
+    /// Render all metrics in the Prometheus text exposition format (0.0.4)
+    ///
+    /// Includes the core metrics under this registry's namespace and any
+    /// metrics registered with [`prometheus_registry`](Self::prometheus_registry).
+    pub fn render_prometheus(&self) -> String {
+        let prefix = format!("{}_", self.namespace);
+        let mut families: Vec<_> = prometheus::gather()
+            .into_iter()
+            .filter(|family| family.name().starts_with(&prefix))
+            .collect();
+        families.extend(self.prometheus_registry.gather());
+
+        let mut buffer = Vec::new();
+        // Encoding only fails for malformed families, which registered
+        // metrics cannot produce
+        if let Err(e) = TextEncoder::new().encode(&families, &mut buffer) {
+            return format!("# Failed to encode metrics: {}\n", e);
+        }
+        String::from_utf8(buffer).unwrap_or_default()
+    }
+
     /// Record agent session start
     pub fn record_agent_session_start(&self, _tags: &CardinalTags) -> Result<(), MetricsError> {
         self.core_metrics.agent_sessions_active.inc();
@@ -336,6 +503,41 @@ impl MetricsRegistry {
         Ok(())
     }
 
+    /// Record the outcome of an agent step
+    ///
+    /// # Errors
+    ///
+    /// Returns error if more than [`MAX_AGENT_TYPES`] agent types would be tracked
+    pub fn record_step_outcome(
+        &self,
+        agent_type: &str,
+        outcome: StepOutcome,
+    ) -> Result<(), MetricsError> {
+        // Enforce cardinality limit for agent types (≤10)
+        {
+            let mut tracker = self.cardinality_tracker.write().map_err(|_| {
+                MetricsError::CardinalityTracking("Failed to acquire write lock".to_string())
+            })?;
+
+            if !tracker.agent_types.contains(agent_type) {
+                if tracker.agent_types.len() >= MAX_AGENT_TYPES {
+                    return Err(MetricsError::CardinalityViolation {
+                        metric: "agent_step_outcomes_total".to_string(),
+                        limit: MAX_AGENT_TYPES,
+                        current: tracker.agent_types.len(),
+                    });
+                }
+                tracker.agent_types.insert(agent_type.to_string());
+            }
+        }
+
+        self.core_metrics
+            .agent_step_outcomes_total
+            .with_label_values(&[agent_type, outcome.as_str()])
+            .inc();
+        Ok(())
+    }
+
     /// Record memory operation
     pub fn record_memory_operation(&self, op: &MemoryOp) -> Result<(), MetricsError> {
         let op_str = op.as_str();
@@ -432,6 +634,90 @@ impl MetricsRegistry {
         self.core_metrics.http_requests_in_flight.dec();
     }
 
+    /// Track an in-flight HTTP request until the returned guard is dropped
+    pub fn track_http_request(&self) -> InFlightGuard {
+        InFlightGuard::new(self.core_metrics.http_requests_in_flight.clone())
+    }
+
+    /// Track an in-flight tool call until the returned guard is dropped
+    pub fn track_tool_call(&self) -> InFlightGuard {
+        InFlightGuard::new(self.core_metrics.tool_calls_in_flight.clone())
+    }
+
+    /// Track an open SSE or WebSocket stream until the returned guard is dropped
+    pub fn track_stream(&self, transport: StreamTransport) -> StreamGuard {
+        let label = [transport.as_str()];
+        let metrics = &self.core_metrics;
+        StreamGuard::new(
+            metrics.streams_active.with_label_values(&label),
+            metrics.stream_bytes_sent_total.with_label_values(&label),
+            metrics.stream_frames_sent_total.with_label_values(&label),
+            metrics.stream_duration_seconds.with_label_values(&label),
+        )
+    }
+
+    /// Set the current backpressure queue depth for an agent
+    pub fn set_agent_queue_depth(&self, agent_id: &str, depth: usize) {
+        self.core_metrics
+            .backpressure_queue_depth
+            .with_label_values(&[agent_id])
+            .set(depth as f64);
+    }
+
+    /// Set the current usage of a concurrency bulkhead
+    pub fn set_bulkhead_usage(&self, bulkhead: &str, active: usize, capacity: usize) {
+        self.core_metrics
+            .bulkhead_active_requests
+            .with_label_values(&[bulkhead])
+            .set(active as f64);
+        self.core_metrics
+            .bulkhead_utilization
+            .with_label_values(&[bulkhead])
+            .set(if capacity == 0 {
+                0.0
+            } else {
+                active as f64 / capacity as f64
+            });
+    }
+
+    /// Record the outcome of a graceful shutdown drain
+    pub fn record_shutdown(
+        &self,
+        requests_drained: usize,
+        requests_rejected: usize,
+        ws_connections_closed: usize,
+        drain_duration: std::time::Duration,
+    ) {
+        let metrics = &self.core_metrics;
+        metrics
+            .shutdown_requests_drained_total
+            .inc_by(requests_drained as f64);
+        metrics
+            .shutdown_requests_rejected_total
+            .inc_by(requests_rejected as f64);
+        metrics
+            .shutdown_ws_connections_closed_total
+            .inc_by(ws_connections_closed as f64);
+        metrics
+            .shutdown_drain_duration_seconds
+            .set(drain_duration.as_secs_f64());
+    }
+
+    /// Record agents evicted for exceeding the idle timeout
+    pub fn record_agent_evictions(&self, count: usize) {
+        self.core_metrics
+            .agent_idle_evictions_total
+            .inc_by(count as f64);
+    }
+
+    /// Remove the backpressure queue depth series for an agent
+    pub fn remove_agent_queue_depth(&self, agent_id: &str) {
+        let _ = self
+            .core_metrics
+            .backpressure_queue_depth
+            .remove_label_values(&[agent_id]);
+    }
+
     /// Record per-agent request
     ///
     /// # Errors
@@ -492,6 +778,7 @@ impl MetricsRegistry {
         Ok(CardinalityStats {
             tool_names_count: tracker.tool_names.len(),
             http_routes_count: tracker.http_routes.len(),
+            agent_types_count: tracker.agent_types.len(),
             error_kinds_count: 10, // Fixed cardinality from ErrorKind enum
             memory_ops_count: 4,   // Fixed cardinality from MemoryOp enum
         })
@@ -503,6 +790,7 @@ impl MetricsRegistry {
 struct CardinalityTracker {
     tool_names: std::collections::HashSet<ToolId>,
     http_routes: std::collections::HashSet<String>,
+    agent_types: std::collections::HashSet<String>,
 }
 
 impl CardinalityTracker {
@@ -510,6 +798,7 @@ impl CardinalityTracker {
         Self {
             tool_names: std::collections::HashSet::new(),
             http_routes: std::collections::HashSet::new(),
+            agent_types: std::collections::HashSet::new(),
         }
     }
 }
@@ -519,6 +808,7 @@ impl CardinalityTracker {
 pub struct CardinalityStats {
     pub tool_names_count: usize,
     pub http_routes_count: usize,
+    pub agent_types_count: usize,
     pub error_kinds_count: usize,
     pub memory_ops_count: usize,
 }
@@ -624,6 +914,72 @@ impl Drop for HttpRequestTimer {
     }
 }
 
+/// RAII guard for in-flight gauges
+///
+/// Increments the gauge on creation and decrements it on drop, so the gauge
+/// stays accurate across early returns, errors, and panics.
+#[must_use = "the gauge is decremented as soon as the guard is dropped"]
+#[derive(Debug)]
+pub struct InFlightGuard {
+    gauge: Gauge,
+}
+
+impl InFlightGuard {
+    /// Increment the gauge and return a guard that decrements it on drop
+    pub fn new(gauge: Gauge) -> Self {
+        gauge.inc();
+        Self { gauge }
+    }
+}
+
+impl Drop for InFlightGuard {
+    fn drop(&mut self) {
+        self.gauge.dec();
+    }
+}
+
+/// RAII guard for an open stream
+///
+/// Counts the stream as active until dropped, then records how long it was
+/// open. Frames sent through the stream are reported with
+/// [`record_frame`](Self::record_frame).
+#[must_use = "the stream is counted as closed as soon as the guard is dropped"]
+#[derive(Debug)]
+pub struct StreamGuard {
+    active: Gauge,
+    bytes_sent: Counter,
+    frames_sent: Counter,
+    duration: Histogram,
+    opened_at: Instant,
+}
+
+impl StreamGuard {
+    fn new(active: Gauge, bytes_sent: Counter, frames_sent: Counter, duration: Histogram) -> Self {
+        active.inc();
+        Self {
+            active,
+            bytes_sent,
+            frames_sent,
+            duration,
+            opened_at: Instant::now(),
+        }
+    }
+
+    /// Record one frame of `bytes` payload bytes sent to the client
+    pub fn record_frame(&self, bytes: usize) {
+        self.frames_sent.inc();
+        self.bytes_sent.inc_by(bytes as f64);
+    }
+}
+
+impl Drop for StreamGuard {
+    fn drop(&mut self) {
+        self.active.dec();
+        self.duration
+            .observe(self.opened_at.elapsed().as_secs_f64());
+    }
+}
+
 /// Initialize global metrics registry
 pub fn init_metrics_registry(namespace: &str) -> Result<(), MetricsError> {
     let registry = Arc::new(MetricsRegistry::new(namespace)?);
@@ -680,6 +1036,47 @@ mod tests {
         assert_eq!(registry.core_metrics().agent_sessions_active.get(), 0.0);
     }
 
+    #[test]
+    fn test_render_prometheus_uses_namespace_labels_and_buckets() {
+        let id = uuid::Uuid::new_v4().simple().to_string();
+        let namespace = format!("test{}", &id[0..8]);
+        let registry = MetricsRegistry::new(&namespace).unwrap();
+        registry
+            .record_tool_execution(
+                &ToolId::new_unchecked("search"),
+                std::time::Duration::from_millis(30),
+            )
+            .unwrap();
+        registry.record_agent_error(&ErrorKind::Timeout).unwrap();
+
+        let text = registry.render_prometheus();
+
+        assert!(text.contains(&format!(
+            "# TYPE {}_tool_exec_duration_seconds histogram",
+            namespace
+        )));
+        assert!(text.contains(&format!(
+            "{}_tool_exec_total{{tool=\"search\"}} 1",
+            namespace
+        )));
+        assert!(text.contains(&format!(
+            "{}_agent_errors_total{{kind=\"timeout\"}} 1",
+            namespace
+        )));
+        for bucket in LATENCY_BUCKETS {
+            assert!(text.contains(&format!(
+                "{}_tool_exec_duration_seconds_bucket{{tool=\"search\",le=\"{}\"}}",
+                namespace, bucket
+            )));
+        }
+        // Other registries' metrics stay out of this export
+        assert!(
+            text.lines()
+                .filter(|line| !line.starts_with('#'))
+                .all(|line| line.starts_with(&namespace))
+        );
+    }
+
     #[test]
     fn test_tool_execution_recording() {
         let id = uuid::Uuid::new_v4().simple().to_string();
@@ -717,6 +1114,113 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_step_outcome_recording_and_cardinality() {
+        let id = uuid::Uuid::new_v4().simple().to_string();
+        let registry = MetricsRegistry::new(&format!("test{}", &id[0..8])).unwrap();
+        let counter = &registry.core_metrics().agent_step_outcomes_total;
+
+        registry
+            .record_step_outcome("echo", StepOutcome::Completed)
+            .unwrap();
+        registry
+            .record_step_outcome("echo", StepOutcome::Timeout)
+            .unwrap();
+        assert_eq!(counter.with_label_values(&["echo", "completed"]).get(), 1.0);
+        assert_eq!(counter.with_label_values(&["echo", "timeout"]).get(), 1.0);
+
+        for i in 1..MAX_AGENT_TYPES {
+            registry
+                .record_step_outcome(&format!("type_{}", i), StepOutcome::Completed)
+                .unwrap();
+        }
+        let result = registry.record_step_outcome("one_too_many", StepOutcome::Completed);
+        assert!(matches!(
+            result,
+            Err(MetricsError::CardinalityViolation { .. })
+        ));
+        // Known agent types keep recording once the limit is reached
+        registry
+            .record_step_outcome("echo", StepOutcome::ToolFailure)
+            .unwrap();
+        assert_eq!(
+            registry.cardinality_stats().unwrap().agent_types_count,
+            MAX_AGENT_TYPES
+        );
+    }
+
+    #[test]
+    fn test_in_flight_guard_returns_to_zero() {
+        let id = uuid::Uuid::new_v4().simple().to_string();
+        let registry = MetricsRegistry::new(&format!("test{}", &id[0..8])).unwrap();
+        let gauge = &registry.core_metrics().http_requests_in_flight;
+
+        {
+            let _first = registry.track_http_request();
+            let _second = registry.track_http_request();
+            assert_eq!(gauge.get(), 2.0);
+        }
+        assert_eq!(gauge.get(), 0.0);
+
+        let fails = || -> Result<(), &'static str> {
+            let _guard = registry.track_http_request();
+            Err("request failed")?;
+            Ok(())
+        };
+        assert!(fails().is_err());
+        assert_eq!(gauge.get(), 0.0);
+    }
+
+    #[test]
+    fn test_in_flight_guard_survives_panic() {
+        let id = uuid::Uuid::new_v4().simple().to_string();
+        let registry = MetricsRegistry::new(&format!("test{}", &id[0..8])).unwrap();
+
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            let _guard = registry.track_tool_call();
+            panic!("tool panicked");
+        }));
+
+        assert!(result.is_err());
+        assert_eq!(registry.core_metrics().tool_calls_in_flight.get(), 0.0);
+    }
+
+    #[test]
+    fn test_agent_queue_depth_gauge() {
+        let id = uuid::Uuid::new_v4().simple().to_string();
+        let registry = MetricsRegistry::new(&format!("test{}", &id[0..8])).unwrap();
+        let gauge = &registry.core_metrics().backpressure_queue_depth;
+
+        registry.set_agent_queue_depth("agent-1", 3);
+        assert_eq!(gauge.with_label_values(&["agent-1"]).get(), 3.0);
+
+        registry.set_agent_queue_depth("agent-1", 0);
+        assert_eq!(gauge.with_label_values(&["agent-1"]).get(), 0.0);
+    }
+
+    #[test]
+    fn test_bulkhead_usage_gauges() {
+        let id = uuid::Uuid::new_v4().simple().to_string();
+        let registry = MetricsRegistry::new(&format!("test{}", &id[0..8])).unwrap();
+        let metrics = registry.core_metrics();
+
+        registry.set_bulkhead_usage("premium", 3, 4);
+        assert_eq!(
+            metrics
+                .bulkhead_active_requests
+                .with_label_values(&["premium"])
+                .get(),
+            3.0
+        );
+        assert_eq!(
+            metrics
+                .bulkhead_utilization
+                .with_label_values(&["premium"])
+                .get(),
+            0.75
+        );
+    }
+
     #[test]
     fn test_tool_timer() {
         let id = uuid::Uuid::new_v4().simple().to_string();
@@ -729,4 +1233,57 @@ mod tests {
         // Timer should finish without error
         timer.finish().unwrap();
     }
+
+    #[test]
+    fn test_stream_guard_tracks_frames_and_duration() {
+        let id = uuid::Uuid::new_v4().simple().to_string();
+        let registry = MetricsRegistry::new(&format!("test{}", &id[0..8])).unwrap();
+        let metrics = registry.core_metrics();
+
+        {
+            let guard = registry.track_stream(StreamTransport::WebSocket);
+            guard.record_frame(10);
+            guard.record_frame(32);
+            assert_eq!(
+                metrics
+                    .streams_active
+                    .with_label_values(&["websocket"])
+                    .get(),
+                1.0
+            );
+            assert_eq!(
+                metrics.streams_active.with_label_values(&["sse"]).get(),
+                0.0
+            );
+        }
+
+        assert_eq!(
+            metrics
+                .streams_active
+                .with_label_values(&["websocket"])
+                .get(),
+            0.0
+        );
+        assert_eq!(
+            metrics
+                .stream_frames_sent_total
+                .with_label_values(&["websocket"])
+                .get(),
+            2.0
+        );
+        assert_eq!(
+            metrics
+                .stream_bytes_sent_total
+                .with_label_values(&["websocket"])
+                .get(),
+            42.0
+        );
+        assert_eq!(
+            metrics
+                .stream_duration_seconds
+                .with_label_values(&["websocket"])
+                .get_sample_count(),
+            1
+        );
+    }
 }
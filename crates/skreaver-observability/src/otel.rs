@@ -345,6 +345,52 @@ impl Drop for OtelState {
     }
 }
 
+pub use opentelemetry::propagation::{Extractor, Injector};
+
+/// W3C trace context propagation between services
+///
+/// Transports carry the context as `traceparent` (and `tracestate`) entries
+/// in whatever key-value metadata they have, e.g. HTTP headers or message
+/// metadata. Spans only carry an OpenTelemetry context when a
+/// `tracing-opentelemetry` layer is installed; otherwise these are no-ops.
+impl crate::TraceContext {
+    /// Write the context of `span` into `carrier`
+    ///
+    /// Returns `false`, leaving the carrier untouched, if the span has no
+    /// valid trace context.
+    pub fn inject(span: &tracing::Span, carrier: &mut dyn Injector) -> bool {
+        use opentelemetry::propagation::TextMapPropagator;
+        use opentelemetry::trace::TraceContextExt;
+        use tracing_opentelemetry::OpenTelemetrySpanExt;
+
+        let context = span.context();
+        if !context.span().span_context().is_valid() {
+            return false;
+        }
+        opentelemetry_sdk::propagation::TraceContextPropagator::new()
+            .inject_context(&context, carrier);
+        true
+    }
+
+    /// Make the remote context found in `carrier` the parent of `span`
+    ///
+    /// Returns `false`, leaving the span's parent unchanged, if the carrier
+    /// has no valid `traceparent`.
+    pub fn extract(span: &tracing::Span, carrier: &dyn Extractor) -> bool {
+        use opentelemetry::propagation::TextMapPropagator;
+        use opentelemetry::trace::TraceContextExt;
+        use tracing_opentelemetry::OpenTelemetrySpanExt;
+
+        let context =
+            opentelemetry_sdk::propagation::TraceContextPropagator::new().extract(carrier);
+        if !context.span().span_context().is_valid() {
+            return false;
+        }
+        span.set_parent(context);
+        true
+    }
+}
+
 #[cfg(not(feature = "opentelemetry"))]
 pub fn init_otel_exporter(_config: &OtelConfig) -> Result<OtelState, ObservabilityError> {
     Err(ObservabilityError::OpenTelemetryInit(
@@ -530,4 +576,36 @@ mod tests {
         assert!(ServiceIdentity::new("".to_string(), "1.0.0".to_string()).is_err());
         assert!(ServiceIdentity::new("test".to_string(), "".to_string()).is_err());
     }
+
+    #[test]
+    fn test_trace_context_continues_across_carrier() {
+        use crate::TraceContext;
+        use opentelemetry::trace::TracerProvider;
+        use tracing_subscriber::layer::SubscriberExt;
+
+        let provider = opentelemetry_sdk::trace::SdkTracerProvider::builder().build();
+        let subscriber = tracing_subscriber::registry()
+            .with(tracing_opentelemetry::layer().with_tracer(provider.tracer("test")));
+
+        tracing::subscriber::with_default(subscriber, || {
+            let incoming = HashMap::from([(
+                "traceparent".to_string(),
+                "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01".to_string(),
+            )]);
+            let span = tracing::info_span!("bridge");
+            assert!(TraceContext::extract(&span, &incoming));
+
+            let mut outgoing = HashMap::new();
+            assert!(TraceContext::inject(&span, &mut outgoing));
+            let traceparent = &outgoing["traceparent"];
+            assert!(traceparent.starts_with("00-4bf92f3577b34da6a3ce929d0e0e4736-"));
+            assert!(!traceparent.contains("00f067aa0ba902b7"));
+
+            let missing = HashMap::<String, String>::new();
+            assert!(!TraceContext::extract(
+                &tracing::info_span!("orphan"),
+                &missing
+            ));
+        });
+    }
 }
@@ -0,0 +1,271 @@
+//! Runtime-Adjustable Log Sampling
+//!
+//! Sample rates from [`LogSamplingConfig`] live in atomics behind a
+//! [`SamplingHandle`], so operators can change them while the process runs,
+//! e.g. log every INFO event during an incident without a redeploy. The
+//! tracing filter reads the current rates on every event.
+
+use crate::{LogSamplingConfig, ObservabilityError};
+#[cfg(feature = "tracing")]
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::{Arc, OnceLock};
+
+/// Global log sampling rates
+static LOG_SAMPLING: OnceLock<SamplingHandle> = OnceLock::new();
+
+/// Shared handle to live log sampling rates
+///
+/// A rate of `n` keeps one event in `n` at that level; `1` keeps every event.
+/// TRACE events are sampled at the DEBUG rate.
+#[derive(Debug, Clone)]
+pub struct SamplingHandle {
+    inner: Arc<SamplingRates>,
+}
+
+#[derive(Debug)]
+struct SamplingRates {
+    /// Rates for ERROR, WARN, INFO and DEBUG
+    rates: [AtomicU32; 4],
+    /// Events seen per level, used to pick which ones to keep
+    #[cfg(feature = "tracing")]
+    seen: [AtomicU64; 4],
+}
+
+const ERROR: usize = 0;
+const WARN: usize = 1;
+const INFO: usize = 2;
+const DEBUG: usize = 3;
+
+impl SamplingHandle {
+    /// Create a handle with the given rates
+    ///
+    /// # Errors
+    ///
+    /// Returns `ObservabilityError::Config` if any rate is 0.
+    pub fn new(config: &LogSamplingConfig) -> Result<Self, ObservabilityError> {
+        let handle = Self {
+            inner: Arc::new(SamplingRates {
+                rates: Default::default(),
+                #[cfg(feature = "tracing")]
+                seen: Default::default(),
+            }),
+        };
+        handle.apply(config)?;
+        Ok(handle)
+    }
+
+    /// Replace all rates at once, leaving them unchanged if any is invalid
+    pub fn apply(&self, config: &LogSamplingConfig) -> Result<(), ObservabilityError> {
+        let rates = [
+            check_rate("error", config.error_sample_rate)?,
+            check_rate("warn", config.warn_sample_rate)?,
+            check_rate("info", config.info_sample_rate)?,
+            check_rate("debug", config.debug_sample_rate)?,
+        ];
+        for (slot, rate) in self.inner.rates.iter().zip(rates) {
+            slot.store(rate, Ordering::Relaxed);
+        }
+        Ok(())
+    }
+
+    /// Set the ERROR sample rate
+    pub fn set_error_rate(&self, rate: u32) -> Result<(), ObservabilityError> {
+        self.set_rate(ERROR, "error", rate)
+    }
+
+    /// Set the WARN sample rate
+    pub fn set_warn_rate(&self, rate: u32) -> Result<(), ObservabilityError> {
+        self.set_rate(WARN, "warn", rate)
+    }
+
+    /// Set the INFO sample rate
+    pub fn set_info_rate(&self, rate: u32) -> Result<(), ObservabilityError> {
+        self.set_rate(INFO, "info", rate)
+    }
+
+    /// Set the DEBUG (and TRACE) sample rate
+    pub fn set_debug_rate(&self, rate: u32) -> Result<(), ObservabilityError> {
+        self.set_rate(DEBUG, "debug", rate)
+    }
+
+    /// Snapshot of the current rates
+    pub fn config(&self) -> LogSamplingConfig {
+        let rate = |level: usize| self.inner.rates[level].load(Ordering::Relaxed);
+        LogSamplingConfig {
+            error_sample_rate: rate(ERROR),
+            warn_sample_rate: rate(WARN),
+            info_sample_rate: rate(INFO),
+            debug_sample_rate: rate(DEBUG),
+        }
+    }
+
+    /// Decide whether to keep the next event at `level`
+    #[cfg(feature = "tracing")]
+    pub fn sample(&self, level: &tracing::Level) -> bool {
+        let index = match *level {
+            tracing::Level::ERROR => ERROR,
+            tracing::Level::WARN => WARN,
+            tracing::Level::INFO => INFO,
+            _ => DEBUG,
+        };
+        let rate = u64::from(self.inner.rates[index].load(Ordering::Relaxed).max(1));
+        self.inner.seen[index]
+            .fetch_add(1, Ordering::Relaxed)
+            .is_multiple_of(rate)
+    }
+
+    fn set_rate(&self, level: usize, name: &str, rate: u32) -> Result<(), ObservabilityError> {
+        let rate = check_rate(name, rate)?;
+        self.inner.rates[level].store(rate, Ordering::Relaxed);
+        Ok(())
+    }
+}
+
+/// Get the global log sampling handle used by [`init_observability`]
+///
+/// [`init_observability`]: crate::init_observability
+pub fn log_sampling() -> SamplingHandle {
+    LOG_SAMPLING
+        .get_or_init(|| {
+            SamplingHandle::new(&LogSamplingConfig::default())
+                .expect("default sample rates are non-zero")
+        })
+        .clone()
+}
+
+fn check_rate(level: &str, rate: u32) -> Result<u32, ObservabilityError> {
+    if rate == 0 {
+        return Err(ObservabilityError::Config(format!(
+            "{} sample rate must be at least 1 (1 = log every event)",
+            level
+        )));
+    }
+    Ok(rate)
+}
+
+/// Per-layer filter dropping events according to the live sample rates
+///
+/// Spans are never sampled. Combine it with the level filter using
+/// [`FilterExt::and`] so only events that filter lets through are counted.
+///
+/// [`FilterExt::and`]: tracing_subscriber::filter::FilterExt::and
+#[cfg(feature = "tracing")]
+#[derive(Debug, Clone)]
+pub struct SamplingFilter {
+    handle: SamplingHandle,
+}
+
+#[cfg(feature = "tracing")]
+impl SamplingFilter {
+    /// Create a filter reading the given rates
+    pub fn new(handle: SamplingHandle) -> Self {
+        Self { handle }
+    }
+
+    /// Create a filter reading the global rates
+    pub fn global() -> Self {
+        Self::new(log_sampling())
+    }
+}
+
+#[cfg(feature = "tracing")]
+impl<S: tracing::Subscriber> tracing_subscriber::layer::Filter<S> for SamplingFilter {
+    fn enabled(
+        &self,
+        meta: &tracing::Metadata<'_>,
+        _cx: &tracing_subscriber::layer::Context<'_, S>,
+    ) -> bool {
+        !meta.is_event() || self.handle.sample(meta.level())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_zero_rate_is_rejected() {
+        let handle = SamplingHandle::new(&LogSamplingConfig::default()).unwrap();
+        // Every level keeps all events unless sampling is configured
+        let config = handle.config();
+        assert_eq!(
+            [
+                config.error_sample_rate,
+                config.warn_sample_rate,
+                config.info_sample_rate,
+                config.debug_sample_rate
+            ],
+            [1; 4]
+        );
+        handle.set_info_rate(100).unwrap();
+        assert!(handle.set_info_rate(0).is_err());
+        assert_eq!(handle.config().info_sample_rate, 100);
+
+        let invalid = LogSamplingConfig {
+            warn_sample_rate: 0,
+            ..Default::default()
+        };
+        assert!(SamplingHandle::new(&invalid).is_err());
+        assert!(handle.apply(&invalid).is_err());
+        assert_eq!(handle.config().warn_sample_rate, 1);
+    }
+
+    #[cfg(feature = "tracing")]
+    #[test]
+    fn test_rate_change_applies_to_next_events() {
+        use tracing_subscriber::Layer;
+        use tracing_subscriber::filter::{FilterExt, LevelFilter};
+        use tracing_subscriber::layer::SubscriberExt;
+
+        let handle = SamplingHandle::new(&LogSamplingConfig {
+            info_sample_rate: 10,
+            ..Default::default()
+        })
+        .unwrap();
+
+        let lines = Arc::new(std::sync::Mutex::new(Vec::<u8>::new()));
+        let writer = Arc::clone(&lines);
+        let layer = tracing_subscriber::fmt::layer()
+            .with_ansi(false)
+            .with_writer(move || SharedWriter(Arc::clone(&writer)))
+            .with_filter(LevelFilter::INFO.and(SamplingFilter::new(handle.clone())));
+        let subscriber = tracing_subscriber::registry().with(layer);
+
+        let count = |lines: &std::sync::Mutex<Vec<u8>>| {
+            String::from_utf8(lines.lock().unwrap().clone())
+                .unwrap()
+                .lines()
+                .count()
+        };
+
+        tracing::subscriber::with_default(subscriber, || {
+            for i in 0..20 {
+                tracing::info!(i, "sampled");
+            }
+            tracing::error!("errors are kept");
+            assert_eq!(count(&lines), 3);
+
+            handle.set_info_rate(1).unwrap();
+            for i in 0..5 {
+                tracing::info!(i, "incident");
+            }
+            assert_eq!(count(&lines), 8);
+        });
+    }
+
+    #[cfg(feature = "tracing")]
+    struct SharedWriter(Arc<std::sync::Mutex<Vec<u8>>>);
+
+    #[cfg(feature = "tracing")]
+    impl std::io::Write for SharedWriter {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+}
@@ -170,6 +170,68 @@ impl fmt::Display for MemoryOp {
     }
 }
 
+/// Outcome of a single agent step (cardinality: 6)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum StepOutcome {
+    /// The agent acted on the observation
+    Completed,
+    /// A tool failure ended the step early
+    ToolFailure,
+    /// The agent failed or panicked while handling the step
+    AgentError,
+    /// The step did not finish within its deadline
+    Timeout,
+    /// The caller abandoned the step before it finished
+    Cancelled,
+    /// The step was refused before the agent saw it (backpressure, shutdown)
+    Rejected,
+}
+
+impl StepOutcome {
+    /// Get string representation for metrics
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            StepOutcome::Completed => "completed",
+            StepOutcome::ToolFailure => "tool_failure",
+            StepOutcome::AgentError => "agent_error",
+            StepOutcome::Timeout => "timeout",
+            StepOutcome::Cancelled => "cancelled",
+            StepOutcome::Rejected => "rejected",
+        }
+    }
+}
+
+impl fmt::Display for StepOutcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// Transport carrying a long-lived stream (cardinality: 2)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum StreamTransport {
+    /// Server-Sent Events
+    Sse,
+    /// WebSocket connection
+    WebSocket,
+}
+
+impl StreamTransport {
+    /// Get string representation for metrics
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            StreamTransport::Sse => "sse",
+            StreamTransport::WebSocket => "websocket",
+        }
+    }
+}
+
+impl fmt::Display for StreamTransport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
 /// Tag validation errors
 #[derive(thiserror::Error, Debug)]
 pub enum TagValidationError {
@@ -4,9 +4,12 @@
 //! tool execution tracking as specified in DEVELOPMENT_PLAN.md.
 
 use crate::tags::{AgentId, CardinalTags, SessionId, ToolId};
-use crate::{ObservabilityConfig, ObservabilityError};
+use crate::{LogFormat, ObservabilityConfig, ObservabilityError};
 use std::collections::HashMap;
 use std::sync::{Arc, Mutex, OnceLock};
+use tracing_subscriber::fmt::format::Writer;
+use tracing_subscriber::fmt::{FmtContext, FormatEvent, FormatFields, MakeWriter};
+use tracing_subscriber::registry::LookupSpan;
 
 /// Global session tracker instance
 static SESSION_TRACKER: OnceLock<Arc<SessionTracker>> = OnceLock::new();
@@ -285,18 +288,26 @@ pub fn init_tracing(config: &ObservabilityConfig) -> Result<(), ObservabilityErr
     #[cfg(feature = "tracing")]
     {
         // Set up tracing subscriber with sampling
-        use tracing_subscriber::{EnvFilter, layer::SubscriberExt, util::SubscriberInitExt};
+        use tracing_subscriber::{
+            EnvFilter, Layer, filter::FilterExt, layer::SubscriberExt, util::SubscriberInitExt,
+        };
 
         let env_filter =
             EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info"));
+        // Sample what the global filter lets through; per-agent overrides can
+        // only widen that and are never sampled
+        let filter = env_filter
+            .and(crate::SamplingFilter::global())
+            .or(crate::AgentLevelFilter::global());
 
         tracing_subscriber::registry()
-            .with(env_filter)
-            .with(tracing_subscriber::fmt::layer().json())
-            .init();
+            .with(fmt_layer(config.log_format, std::io::stdout).with_filter(filter))
+            .try_init()
+            .map_err(|e| ObservabilityError::TracingInit(e.to_string()))?;
 
         tracing::info!(
             namespace = config.namespace,
+            log_format = %config.log_format,
             sampling.error = config.log_sampling.error_sample_rate,
             sampling.warn = config.log_sampling.warn_sample_rate,
             sampling.info = config.log_sampling.info_sample_rate,
@@ -308,6 +319,121 @@ pub fn init_tracing(config: &ObservabilityConfig) -> Result<(), ObservabilityErr
     Ok(())
 }
 
+/// Build a formatting layer emitting log lines in `format` to `writer`.
+///
+/// Used by [`init_tracing`] and by binaries that install their own subscriber.
+pub fn fmt_layer<S, W>(
+    format: LogFormat,
+    writer: W,
+) -> Box<dyn tracing_subscriber::Layer<S> + Send + Sync + 'static>
+where
+    S: tracing::Subscriber + for<'a> LookupSpan<'a>,
+    W: for<'w> MakeWriter<'w> + Send + Sync + 'static,
+{
+    use tracing_subscriber::Layer;
+
+    let layer = tracing_subscriber::fmt::layer().with_writer(writer);
+    match format {
+        LogFormat::Json => layer.json().boxed(),
+        LogFormat::Logfmt => layer.event_format(LogfmtFormat).boxed(),
+        LogFormat::Pretty => layer.pretty().boxed(),
+    }
+}
+
+/// Event formatter producing logfmt lines (`ts=... level=info msg="..." key=value`).
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LogfmtFormat;
+
+impl<S, N> FormatEvent<S, N> for LogfmtFormat
+where
+    S: tracing::Subscriber + for<'a> LookupSpan<'a>,
+    N: for<'a> FormatFields<'a> + 'static,
+{
+    fn format_event(
+        &self,
+        ctx: &FmtContext<'_, S, N>,
+        mut writer: Writer<'_>,
+        event: &tracing::Event<'_>,
+    ) -> std::fmt::Result {
+        let metadata = event.metadata();
+        write!(
+            writer,
+            "ts={} level={} target={}",
+            chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Micros, true),
+            metadata.level().as_str().to_ascii_lowercase(),
+            logfmt_value(metadata.target())
+        )?;
+
+        if let Some(scope) = ctx.event_scope() {
+            let spans: Vec<_> = scope.from_root().map(|span| span.name()).collect();
+            write!(writer, " span={}", logfmt_value(&spans.join(">")))?;
+        }
+
+        let mut visitor = LogfmtVisitor {
+            writer: &mut writer,
+            result: Ok(()),
+        };
+        event.record(&mut visitor);
+        visitor.result?;
+
+        writeln!(writer)
+    }
+}
+
+struct LogfmtVisitor<'a, 'w> {
+    writer: &'a mut Writer<'w>,
+    result: std::fmt::Result,
+}
+
+impl LogfmtVisitor<'_, '_> {
+    fn write_pair(&mut self, field: &tracing::field::Field, value: &str) {
+        if self.result.is_err() {
+            return;
+        }
+        let key = match field.name() {
+            "message" => "msg",
+            name => name,
+        };
+        self.result = write!(self.writer, " {}={}", key, logfmt_value(value));
+    }
+}
+
+impl tracing::field::Visit for LogfmtVisitor<'_, '_> {
+    fn record_str(&mut self, field: &tracing::field::Field, value: &str) {
+        self.write_pair(field, value);
+    }
+
+    fn record_debug(&mut self, field: &tracing::field::Field, value: &dyn std::fmt::Debug) {
+        self.write_pair(field, &format!("{:?}", value));
+    }
+}
+
+/// Quote a logfmt value when it contains spaces, quotes, `=` or control characters.
+fn logfmt_value(value: &str) -> std::borrow::Cow<'_, str> {
+    let needs_quotes = value.is_empty()
+        || value
+            .chars()
+            .any(|c| c == ' ' || c == '=' || c == '"' || c == '\\' || c.is_control());
+    if !needs_quotes {
+        return std::borrow::Cow::Borrowed(value);
+    }
+
+    let mut quoted = String::with_capacity(value.len() + 2);
+    quoted.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    std::borrow::Cow::Owned(quoted)
+}
+
 /// Get global session tracker
 pub fn get_session_tracker() -> Option<Arc<SessionTracker>> {
     SESSION_TRACKER.get().cloned()
@@ -383,4 +509,73 @@ mod tests {
         assert_eq!(tags.agent_id, Some(agent_id));
         assert_eq!(tags.session_id, Some(session_id));
     }
+
+    #[derive(Clone, Default)]
+    struct CapturedOutput(Arc<Mutex<Vec<u8>>>);
+
+    impl std::io::Write for CapturedOutput {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl CapturedOutput {
+        fn contents(&self) -> String {
+            String::from_utf8(self.0.lock().unwrap().clone()).unwrap()
+        }
+    }
+
+    fn capture_logs(format: LogFormat, emit: impl FnOnce()) -> String {
+        use tracing_subscriber::layer::SubscriberExt;
+
+        let output = CapturedOutput::default();
+        let writer = output.clone();
+        let subscriber =
+            tracing_subscriber::registry().with(fmt_layer(format, move || writer.clone()));
+        tracing::subscriber::with_default(subscriber, emit);
+        output.contents()
+    }
+
+    #[test]
+    fn test_json_format_emits_json_lines() {
+        let output = capture_logs(LogFormat::Json, || {
+            tracing::info!(agent = "echo", attempts = 3, "first");
+            tracing::warn!("second");
+        });
+
+        let lines: Vec<_> = output.lines().collect();
+        assert_eq!(lines.len(), 2);
+        for line in lines {
+            let value: serde_json::Value = serde_json::from_str(line).unwrap();
+            assert!(value.get("level").is_some());
+        }
+    }
+
+    #[test]
+    fn test_logfmt_format_quotes_values() {
+        let output = capture_logs(LogFormat::Logfmt, || {
+            tracing::info!(agent = "echo", note = "has spaces", "done");
+        });
+
+        assert!(output.contains("level=info"));
+        assert!(output.contains("msg=done"));
+        assert!(output.contains("agent=echo"));
+        assert!(output.contains("note=\"has spaces\""));
+        assert!(output.ends_with('\n'));
+    }
+
+    #[test]
+    fn test_pretty_format_initializes() {
+        let config = ObservabilityConfig {
+            log_format: LogFormat::Pretty,
+            ..Default::default()
+        };
+        assert!(init_tracing(&config).is_ok());
+        tracing::info!("pretty logging enabled");
+    }
 }
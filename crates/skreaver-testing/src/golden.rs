@@ -5,7 +5,8 @@
 
 use crate::MockToolRegistry;
 use serde::{Deserialize, Serialize};
-use skreaver_core::{ExecutionResult, ToolCall, ToolDispatch};
+use skreaver_core::sanitization::SecretRedactor;
+use skreaver_core::{ExecutionResult, StandardTool, ToolCall, ToolDispatch};
 use skreaver_tools::ToolRegistry;
 use std::collections::HashMap;
 use std::fs;
@@ -76,6 +77,24 @@ pub enum ToolDispatchType {
     Custom(String),
 }
 
+impl ToolDispatchType {
+    /// Rebuild a tool call for this dispatch type with the given input
+    pub fn to_tool_call(&self, input: &str) -> Result<ToolCall, GoldenTestError> {
+        match self {
+            ToolDispatchType::Standard(tool_name) => StandardTool::from_name(tool_name)
+                .map(|tool| ToolCall::from_standard(tool, input.to_string()))
+                .ok_or_else(|| {
+                    GoldenTestError::ValidationError(format!(
+                        "Unknown standard tool: {}",
+                        tool_name
+                    ))
+                }),
+            ToolDispatchType::Custom(tool_name) => ToolCall::new(tool_name, input)
+                .map_err(|e| GoldenTestError::ValidationError(e.to_string())),
+        }
+    }
+}
+
 impl From<&ToolDispatch> for ToolDispatchType {
     fn from(dispatch: &ToolDispatch) -> Self {
         match dispatch {
@@ -305,10 +324,67 @@ impl SnapshotManager {
     }
 }
 
+/// Rule for masking sensitive data in captured snapshots
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub enum RedactionRule {
+    /// Mask values of well-known secret keys (`password=...`, `token: ...`)
+    Secrets,
+    /// Replace every occurrence of a literal string
+    Literal { value: String, replacement: String },
+    /// Replace the value of a named field anywhere in a JSON document
+    JsonField { field: String, replacement: String },
+}
+
+impl RedactionRule {
+    /// Apply the rule to a captured input or output
+    pub fn apply(&self, text: &str) -> String {
+        match self {
+            RedactionRule::Secrets => SecretRedactor::redact_secrets(text),
+            RedactionRule::Literal { value, replacement } => {
+                if value.is_empty() {
+                    text.to_string()
+                } else {
+                    text.replace(value.as_str(), replacement)
+                }
+            }
+            RedactionRule::JsonField { field, replacement } => {
+                match serde_json::from_str::<serde_json::Value>(text) {
+                    Ok(mut json_val) => {
+                        Self::redact_json_field(&mut json_val, field, replacement);
+                        serde_json::to_string(&json_val).unwrap_or_else(|_| text.to_string())
+                    }
+                    Err(_) => text.to_string(),
+                }
+            }
+        }
+    }
+
+    fn redact_json_field(value: &mut serde_json::Value, field: &str, replacement: &str) {
+        match value {
+            serde_json::Value::Object(map) => {
+                for (key, val) in map.iter_mut() {
+                    if key == field {
+                        *val = serde_json::Value::String(replacement.to_string());
+                    } else {
+                        Self::redact_json_field(val, field, replacement);
+                    }
+                }
+            }
+            serde_json::Value::Array(arr) => {
+                for item in arr.iter_mut() {
+                    Self::redact_json_field(item, field, replacement);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
 /// Tool output capture for golden testing
 pub struct ToolCapture {
     registry: Box<dyn ToolRegistry + Send + Sync>,
     normalization_enabled: bool,
+    redaction_rules: Vec<RedactionRule>,
 }
 
 impl ToolCapture {
@@ -317,6 +393,7 @@ impl ToolCapture {
         Self {
             registry,
             normalization_enabled: true,
+            redaction_rules: Vec::new(),
         }
     }
 
@@ -333,6 +410,18 @@ impl ToolCapture {
         &self,
         tool_call: ToolCall,
     ) -> Result<ToolSnapshot, GoldenTestError> {
+        self.capture_with_result(tool_call)
+            .map(|(_, snapshot)| snapshot)
+    }
+
+    /// Capture tool execution, returning the raw result alongside the snapshot
+    ///
+    /// The snapshot is normalized and redacted; the raw result is what the tool
+    /// actually returned.
+    pub fn capture_with_result(
+        &self,
+        tool_call: ToolCall,
+    ) -> Result<(ExecutionResult, ToolSnapshot), GoldenTestError> {
         let start_time = Instant::now();
 
         // Execute the tool
@@ -361,11 +450,25 @@ impl ToolCapture {
         };
 
         // Apply normalization if enabled
-        if self.normalization_enabled {
-            Ok(self.normalize_snapshot(snapshot)?)
+        let snapshot = if self.normalization_enabled {
+            self.normalize_snapshot(snapshot)?
         } else {
-            Ok(snapshot)
+            snapshot
+        };
+
+        Ok((result, self.redact_snapshot(snapshot)))
+    }
+
+    /// Apply configured redaction rules to input, output and error
+    fn redact_snapshot(&self, mut snapshot: ToolSnapshot) -> ToolSnapshot {
+        for rule in &self.redaction_rules {
+            snapshot.input = rule.apply(&snapshot.input);
+            snapshot.result.output = rule.apply(&snapshot.result.output);
+            if let Some(ref mut error) = snapshot.result.error {
+                *error = rule.apply(error);
+            }
         }
+        snapshot
     }
 
     /// Normalize snapshot for cross-platform consistency
@@ -431,6 +534,11 @@ impl ToolCapture {
     pub fn set_normalization(&mut self, enabled: bool) {
         self.normalization_enabled = enabled;
     }
+
+    /// Set redaction rules applied to every captured snapshot
+    pub fn set_redaction_rules(&mut self, rules: Vec<RedactionRule>) {
+        self.redaction_rules = rules;
+    }
 }
 
 /// Compare two snapshots and return differences
@@ -4,7 +4,7 @@
 //! extending the existing AgentTestHarness with snapshot management capabilities.
 
 use crate::golden::{
-    GoldenTestError, SnapshotComparison, SnapshotManager, ToolCapture, ToolSnapshot,
+    GoldenTestError, RedactionRule, SnapshotComparison, SnapshotManager, ToolCapture, ToolSnapshot,
     compare_snapshots,
 };
 use skreaver_core::{StandardTool, ToolCall};
@@ -28,6 +28,8 @@ pub struct GoldenTestConfig {
     pub validate_timing: bool,
     /// Custom snapshot file prefix
     pub snapshot_prefix: String,
+    /// Redaction rules applied to captured snapshots before comparison
+    pub redaction_rules: Vec<RedactionRule>,
 }
 
 impl Default for GoldenTestConfig {
@@ -39,6 +41,7 @@ impl Default for GoldenTestConfig {
             max_time_variance: 0.5, // 50% variance allowed
             validate_timing: false, // Off by default for CI stability
             snapshot_prefix: "snapshot".to_string(),
+            redaction_rules: Vec::new(),
         }
     }
 }
@@ -87,6 +90,7 @@ impl GoldenTestHarness {
         let snapshot_manager = SnapshotManager::new(&config.snapshot_dir)?;
         let mut tool_capture = ToolCapture::new(registry);
         tool_capture.set_normalization(config.normalize_outputs);
+        tool_capture.set_redaction_rules(config.redaction_rules.clone());
 
         // Set up standard directory structure
         snapshot_manager.setup_standard_directories()?;
@@ -257,25 +261,7 @@ impl GoldenTestHarness {
         &self,
         snapshot: &ToolSnapshot,
     ) -> Result<ToolCall, GoldenTestError> {
-        match &snapshot.tool_type {
-            crate::golden::ToolDispatchType::Standard(tool_name) => {
-                if let Some(standard_tool) = StandardTool::from_name(tool_name) {
-                    Ok(ToolCall::from_standard(
-                        standard_tool,
-                        snapshot.input.clone(),
-                    ))
-                } else {
-                    Err(GoldenTestError::ValidationError(format!(
-                        "Unknown standard tool: {}",
-                        tool_name
-                    )))
-                }
-            }
-            crate::golden::ToolDispatchType::Custom(tool_name) => {
-                ToolCall::new(tool_name, &snapshot.input)
-                    .map_err(|e| GoldenTestError::ValidationError(e.to_string()))
-            }
-        }
+        snapshot.tool_type.to_tool_call(&snapshot.input)
     }
 
     /// Get test summary
@@ -369,6 +355,8 @@ impl GoldenTestHarness {
         // Update tool capture normalization
         self.tool_capture
             .set_normalization(config.normalize_outputs);
+        self.tool_capture
+            .set_redaction_rules(config.redaction_rules.clone());
 
         self.config = config;
         Ok(())
@@ -493,6 +481,12 @@ impl GoldenTestHarnessBuilder {
         self
     }
 
+    /// Set redaction rules applied to captured snapshots
+    pub fn redaction_rules(mut self, rules: Vec<RedactionRule>) -> Self {
+        self.config.redaction_rules = rules;
+        self
+    }
+
     /// Set timing validation
     pub fn validate_timing(mut self, enabled: bool) -> Self {
         self.config.validate_timing = enabled;
@@ -9,6 +9,7 @@
 //! - **Agent Test Harness**: Controlled environments for agent testing
 //! - **Integration Tests**: End-to-end testing utilities
 //! - **Performance Benchmarks**: Basic performance testing framework
+//! - **Tool Recording**: Generate golden fixtures from live tool calls
 //!
 //! ## Usage
 //!
@@ -57,6 +58,8 @@ pub mod integration;
 pub mod macros;
 /// Mock tools for predictable testing
 pub mod mock_tools;
+/// Tool call recording for golden fixture generation
+pub mod recording;
 /// Performance regression detection system
 pub mod regression;
 /// Agent test harness for controlled testing environments
@@ -66,8 +69,8 @@ pub use benchmarks::{BenchmarkRunner, PerformanceTest};
 pub use cli::{CliRunner, RegressionCli};
 pub use criterion_parser::{CriterionCli, CriterionParser};
 pub use golden::{
-    GoldenTestError, SnapshotCollection, SnapshotComparison, SnapshotManager, ToolCapture,
-    ToolSnapshot, compare_snapshots,
+    GoldenTestError, RedactionRule, SnapshotCollection, SnapshotComparison, SnapshotManager,
+    ToolCapture, ToolSnapshot, compare_snapshots,
 };
 pub use golden_harness::{
     GoldenTestConfig, GoldenTestHarness, GoldenTestHarnessBuilder, GoldenTestResult,
@@ -75,6 +78,7 @@ pub use golden_harness::{
 };
 pub use integration::{HttpRuntimeTester, IntegrationTest};
 pub use mock_tools::{MockTool, MockToolRegistry};
+pub use recording::{RecordedCall, RecordingConfig, ToolRecorder, load_scenarios};
 pub use regression::{
     BaselineManager, PerformanceBaseline, PerformanceMeasurement, RegressionAnalysis,
     RegressionConfig, RegressionError,
@@ -384,6 +384,10 @@ macro_rules! standard_tool_inputs {
                 "resource_id_123".to_string(),
             ]);
 
+            inputs.insert(StandardTool::Url, vec![
+                r#"{"operation": "parse", "url": "https://example.com/search?q=rust#top"}"#.to_string(),
+            ]);
+
             // File tools
             inputs.insert(StandardTool::FileRead, vec![
                 "/tmp/test.txt".to_string(),
@@ -416,6 +420,20 @@ macro_rules! standard_tool_inputs {
                 r#"{"transform": "this"}"#.to_string(),
             ]);
 
+            inputs.insert(StandardTool::JsonDiff, vec![
+                r#"{"source": {"a": 1}, "target": {"a": 2, "b": true}}"#.to_string(),
+            ]);
+
+            inputs.insert(StandardTool::JsonMerge, vec![
+                r#"{"document": {"a": 1}, "merge": {"b": 2}}"#.to_string(),
+                r#"{"document": {"a": 1}, "patch": [{"op": "remove", "path": "/a"}]}"#.to_string(),
+            ]);
+
+            inputs.insert(StandardTool::DateTime, vec![
+                r#"{"operation": "parse", "input": "2024-03-10T14:30:00+02:00"}"#.to_string(),
+                r#"{"operation": "add", "input": "2024-03-10T14:30:00Z", "duration": "1h 30m"}"#.to_string(),
+            ]);
+
             inputs.insert(StandardTool::XmlParse, vec![
                 r#"<root><item>value</item></root>"#.to_string(),
             ]);
@@ -447,6 +465,11 @@ macro_rules! standard_tool_inputs {
                 "MiXeD CaSe TeXt".to_string(),
             ]);
 
+            // Process tools
+            inputs.insert(StandardTool::Command, vec![
+                r#"{"command": "greet", "args": {"name": "Ada"}}"#.to_string(),
+            ]);
+
             inputs
         }
     };
@@ -0,0 +1,288 @@
+//! # Tool Call Recording
+//!
+//! This module records real tool executions and writes them out as golden test
+//! fixtures, so a regression suite can be generated from a live session instead
+//! of hand-authoring expected outputs.
+//!
+//! A [`ToolRecorder`] wraps a tool registry and implements [`ToolRegistry`]
+//! itself, so it can be dropped into an agent in place of the real registry.
+//! Every call is forwarded unchanged; the recorder keeps a redacted snapshot of
+//! each `(input, output)` pair. [`ToolRecorder::write_fixtures`] then stores the
+//! snapshots through [`SnapshotManager`] and writes a `scenarios.json` file that
+//! [`load_scenarios`] turns back into [`GoldenTestScenario`]s.
+
+use crate::golden::{
+    GoldenTestError, RedactionRule, SnapshotManager, ToolCapture, ToolDispatchType, ToolSnapshot,
+};
+use crate::golden_harness::GoldenTestScenario;
+use serde::{Deserialize, Serialize};
+use skreaver_core::{ExecutionResult, ToolCall};
+use skreaver_tools::ToolRegistry;
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use std::sync::Mutex;
+
+/// File name of the scenario list written next to the snapshots
+pub const SCENARIOS_FILE: &str = "scenarios.json";
+
+/// Recording configuration options
+#[derive(Debug, Clone)]
+pub struct RecordingConfig {
+    /// Redaction rules applied to recorded inputs and outputs
+    pub redaction_rules: Vec<RedactionRule>,
+    /// Whether failed tool calls are recorded as well
+    pub record_failures: bool,
+    /// Whether to enable cross-platform normalization
+    pub normalize_outputs: bool,
+    /// Prefix for generated test IDs (`<prefix><tool>_<n>`)
+    pub test_id_prefix: String,
+}
+
+impl Default for RecordingConfig {
+    fn default() -> Self {
+        Self {
+            redaction_rules: vec![RedactionRule::Secrets],
+            record_failures: false,
+            normalize_outputs: true,
+            test_id_prefix: "recorded_".to_string(),
+        }
+    }
+}
+
+/// A single recorded tool call
+#[derive(Debug, Clone)]
+pub struct RecordedCall {
+    pub test_id: String,
+    pub snapshot: ToolSnapshot,
+}
+
+/// Serialized scenario entry in `scenarios.json`
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ScenarioFixture {
+    pub test_id: String,
+    pub tool_type: ToolDispatchType,
+    pub input: String,
+    pub description: Option<String>,
+    pub expected_to_pass: bool,
+}
+
+/// Contents of a scenario file
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ScenarioFile {
+    pub scenarios: Vec<ScenarioFixture>,
+}
+
+/// Tool registry wrapper that records executions as golden fixtures
+pub struct ToolRecorder {
+    capture: ToolCapture,
+    config: RecordingConfig,
+    recorded: Mutex<RecordingState>,
+}
+
+#[derive(Default)]
+struct RecordingState {
+    calls: Vec<RecordedCall>,
+    per_tool: HashMap<String, usize>,
+}
+
+impl ToolRecorder {
+    /// Create a recorder around the registry running the real tools
+    pub fn new(registry: Box<dyn ToolRegistry + Send + Sync>, config: RecordingConfig) -> Self {
+        let mut capture = ToolCapture::new(registry);
+        capture.set_normalization(config.normalize_outputs);
+        capture.set_redaction_rules(config.redaction_rules.clone());
+
+        Self {
+            capture,
+            config,
+            recorded: Mutex::new(RecordingState::default()),
+        }
+    }
+
+    /// Execute a tool call and record it
+    ///
+    /// Returns the unredacted result, or `None` if the tool is not registered.
+    pub fn record(&self, tool_call: ToolCall) -> Option<ExecutionResult> {
+        let (result, snapshot) = self.capture.capture_with_result(tool_call).ok()?;
+
+        if result.is_success() || self.config.record_failures {
+            let mut state = self.lock_state();
+            let counter = state
+                .per_tool
+                .entry(snapshot.tool_name.clone())
+                .or_insert(0);
+            let test_id = format!(
+                "{}{}_{}",
+                self.config.test_id_prefix, snapshot.tool_name, counter
+            );
+            *counter += 1;
+            state.calls.push(RecordedCall { test_id, snapshot });
+        }
+
+        Some(result)
+    }
+
+    /// Calls recorded so far
+    pub fn recorded(&self) -> Vec<RecordedCall> {
+        self.lock_state().calls.clone()
+    }
+
+    /// Number of calls recorded so far
+    pub fn len(&self) -> usize {
+        self.lock_state().calls.len()
+    }
+
+    /// Whether nothing has been recorded yet
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Write recorded calls as snapshots and a scenario file into `dir`
+    ///
+    /// Returns the number of fixtures written.
+    pub fn write_fixtures<P: AsRef<Path>>(&self, dir: P) -> Result<usize, GoldenTestError> {
+        let dir = dir.as_ref();
+        let calls = self.recorded();
+
+        let mut manager = SnapshotManager::new(dir)?;
+        let mut scenario_file = ScenarioFile::default();
+        for call in &calls {
+            manager.store_snapshot(&call.test_id, call.snapshot.clone())?;
+            scenario_file.scenarios.push(ScenarioFixture {
+                test_id: call.test_id.clone(),
+                tool_type: call.snapshot.tool_type.clone(),
+                input: call.snapshot.input.clone(),
+                description: Some(format!("Recorded {} call", call.snapshot.tool_name)),
+                expected_to_pass: call.snapshot.result.success,
+            });
+        }
+
+        let content = serde_json::to_string_pretty(&scenario_file)?;
+        fs::write(dir.join(SCENARIOS_FILE), content)?;
+
+        Ok(calls.len())
+    }
+
+    fn lock_state(&self) -> std::sync::MutexGuard<'_, RecordingState> {
+        self.recorded
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl ToolRegistry for ToolRecorder {
+    fn dispatch(&self, call: ToolCall) -> Option<ExecutionResult> {
+        self.record(call)
+    }
+}
+
+/// Load golden test scenarios from a scenario file written by [`ToolRecorder`]
+pub fn load_scenarios<P: AsRef<Path>>(path: P) -> Result<Vec<GoldenTestScenario>, GoldenTestError> {
+    let path = path.as_ref();
+    if !path.exists() {
+        return Err(GoldenTestError::SnapshotNotFound(path.to_path_buf()));
+    }
+
+    let content = fs::read_to_string(path)?;
+    let scenario_file: ScenarioFile = serde_json::from_str(&content)?;
+
+    scenario_file
+        .scenarios
+        .into_iter()
+        .map(|fixture| {
+            let tool_call = fixture.tool_type.to_tool_call(&fixture.input)?;
+            let mut scenario = GoldenTestScenario::new(fixture.test_id, tool_call);
+            if let Some(description) = fixture.description {
+                scenario = scenario.with_description(description);
+            }
+            if !fixture.expected_to_pass {
+                scenario = scenario.expect_failure();
+            }
+            Ok(scenario)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::golden_harness::{GoldenTestAction, GoldenTestConfig, GoldenTestHarness};
+    use crate::{MockTool, MockToolRegistry};
+
+    fn live_registry() -> MockToolRegistry {
+        MockToolRegistry::new().with_mock_tools().with_tool(
+            MockTool::new("login").with_default_response("session started for password=hunter2"),
+        )
+    }
+
+    #[test]
+    fn test_recorded_fixtures_pass_in_golden_mode() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let recorder = ToolRecorder::new(Box::new(live_registry()), RecordingConfig::default());
+
+        let first = recorder.dispatch(ToolCall::new("echo", "hello").unwrap());
+        let second = recorder.dispatch(ToolCall::new("echo", "world").unwrap());
+        assert!(first.unwrap().is_success());
+        assert!(second.unwrap().is_success());
+
+        assert_eq!(recorder.write_fixtures(temp_dir.path()).unwrap(), 2);
+
+        let scenarios = load_scenarios(temp_dir.path().join(SCENARIOS_FILE)).unwrap();
+        assert_eq!(scenarios.len(), 2);
+        assert_eq!(scenarios[0].test_id, "recorded_echo_0");
+        assert_eq!(scenarios[1].test_id, "recorded_echo_1");
+
+        let config = GoldenTestConfig {
+            snapshot_dir: temp_dir.path().to_path_buf(),
+            ..Default::default()
+        };
+        let mut harness = GoldenTestHarness::new(Box::new(live_registry()), config).unwrap();
+        let results = harness.run_golden_scenarios(scenarios).unwrap();
+
+        assert_eq!(results.len(), 2);
+        for result in results {
+            assert!(result.passed, "{:?}", result.snapshot_comparison);
+            assert_eq!(result.action_taken, GoldenTestAction::Compared);
+        }
+    }
+
+    #[test]
+    fn test_recording_redacts_and_skips_failures() {
+        let recorder = ToolRecorder::new(Box::new(live_registry()), RecordingConfig::default());
+
+        let live = recorder
+            .dispatch(ToolCall::new("login", "user").unwrap())
+            .unwrap();
+        assert!(live.output().contains("hunter2"));
+
+        let failed = recorder.dispatch(ToolCall::new("fail_tool", "x").unwrap());
+        assert!(!failed.unwrap().is_success());
+
+        let recorded = recorder.recorded();
+        assert_eq!(recorded.len(), 1);
+        let output = &recorded[0].snapshot.result.output;
+        assert!(!output.contains("hunter2"));
+        assert!(output.contains("password=***"));
+    }
+
+    #[test]
+    fn test_recording_failures_when_enabled() {
+        let config = RecordingConfig {
+            record_failures: true,
+            ..Default::default()
+        };
+        let recorder = ToolRecorder::new(Box::new(live_registry()), config);
+
+        recorder.dispatch(ToolCall::new("fail_tool", "x").unwrap());
+        assert!(
+            recorder
+                .dispatch(ToolCall::new("missing", "x").unwrap())
+                .is_none()
+        );
+
+        let recorded = recorder.recorded();
+        assert_eq!(recorded.len(), 1);
+        assert!(!recorded[0].snapshot.result.success);
+    }
+}
@@ -0,0 +1,529 @@
+//! Tool result caching with dependency-based invalidation
+//!
+//! This module provides a wrapper around any `ToolRegistry` that caches the
+//! results of selected tools. Besides expiring after a TTL, cached results can
+//! declare the memory keys they were computed from; writing one of those keys
+//! (via [`CachingToolRegistry::invalidate`], a [`CacheInvalidator`] handle or
+//! an [`InvalidatingMemory`] wrapper) evicts every dependent result, so tools
+//! that are pure functions of memory can be cached safely.
+
+use super::{ExecutionResult, ToolCall, ToolRegistry};
+use skreaver_core::error::MemoryError;
+use skreaver_core::memory::{MemoryKey, MemoryReader, MemoryUpdate, MemoryWriter};
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// Function computing the dependency keys of a tool call from its input
+type DependencyFn = Arc<dyn Fn(&str) -> Vec<String> + Send + Sync>;
+
+/// Caching policy for a single tool
+#[derive(Clone)]
+pub struct CachePolicy {
+    ttl: Option<Duration>,
+    dependencies: Option<DependencyFn>,
+}
+
+impl CachePolicy {
+    /// Cache results until they are invalidated
+    pub fn new() -> Self {
+        Self {
+            ttl: None,
+            dependencies: None,
+        }
+    }
+
+    /// Cache results for at most `ttl`
+    pub fn with_ttl(ttl: Duration) -> Self {
+        Self {
+            ttl: Some(ttl),
+            ..Self::new()
+        }
+    }
+
+    /// Declare the same dependency keys for every call of the tool
+    pub fn depends_on_keys<I, K>(self, keys: I) -> Self
+    where
+        I: IntoIterator<Item = K>,
+        K: Into<String>,
+    {
+        let keys: Vec<String> = keys.into_iter().map(Into::into).collect();
+        self.depends_on(move |_| keys.clone())
+    }
+
+    /// Derive the dependency keys of each call from its input
+    ///
+    /// For example, a tool that summarizes the memory key named by its input
+    /// would use `depends_on(|input| vec![input.to_string()])`.
+    pub fn depends_on<F>(mut self, dependencies: F) -> Self
+    where
+        F: Fn(&str) -> Vec<String> + Send + Sync + 'static,
+    {
+        self.dependencies = Some(Arc::new(dependencies));
+        self
+    }
+
+    fn dependencies_for(&self, input: &str) -> Vec<String> {
+        self.dependencies
+            .as_ref()
+            .map(|dependencies| dependencies(input))
+            .unwrap_or_default()
+    }
+}
+
+impl Default for CachePolicy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl std::fmt::Debug for CachePolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CachePolicy")
+            .field("ttl", &self.ttl)
+            .field("has_dependencies", &self.dependencies.is_some())
+            .finish()
+    }
+}
+
+/// Cache key: tool name and input
+type EntryKey = (String, String);
+
+struct CacheEntry {
+    result: ExecutionResult,
+    inserted_at: Instant,
+    expires_at: Option<Instant>,
+    dependencies: Vec<String>,
+}
+
+/// Cached results plus a reverse index from dependency key to entries
+#[derive(Default)]
+struct CacheState {
+    entries: HashMap<EntryKey, CacheEntry>,
+    dependents: HashMap<String, HashSet<EntryKey>>,
+}
+
+impl CacheState {
+    fn get(&mut self, key: &EntryKey) -> Option<ExecutionResult> {
+        let expired = self
+            .entries
+            .get(key)?
+            .expires_at
+            .is_some_and(|expires_at| Instant::now() >= expires_at);
+        if expired {
+            self.remove(key);
+            return None;
+        }
+        self.entries.get(key).map(|entry| entry.result.clone())
+    }
+
+    fn insert(&mut self, key: EntryKey, entry: CacheEntry, max_entries: usize) {
+        self.remove(&key);
+        while self.entries.len() >= max_entries {
+            let Some(oldest) = self
+                .entries
+                .iter()
+                .min_by_key(|(_, entry)| entry.inserted_at)
+                .map(|(key, _)| key.clone())
+            else {
+                break;
+            };
+            self.remove(&oldest);
+        }
+        for dependency in &entry.dependencies {
+            self.dependents
+                .entry(dependency.clone())
+                .or_default()
+                .insert(key.clone());
+        }
+        self.entries.insert(key, entry);
+    }
+
+    fn remove(&mut self, key: &EntryKey) {
+        let Some(entry) = self.entries.remove(key) else {
+            return;
+        };
+        for dependency in entry.dependencies {
+            if let Some(keys) = self.dependents.get_mut(&dependency) {
+                keys.remove(key);
+                if keys.is_empty() {
+                    self.dependents.remove(&dependency);
+                }
+            }
+        }
+    }
+
+    fn invalidate(&mut self, dependency: &str) -> usize {
+        let Some(keys) = self.dependents.remove(dependency) else {
+            return 0;
+        };
+        let evicted = keys.len();
+        for key in keys {
+            self.remove(&key);
+        }
+        evicted
+    }
+
+    fn invalidate_tool(&mut self, tool_name: &str) -> usize {
+        let keys: Vec<EntryKey> = self
+            .entries
+            .keys()
+            .filter(|(name, _)| name == tool_name)
+            .cloned()
+            .collect();
+        for key in &keys {
+            self.remove(key);
+        }
+        keys.len()
+    }
+}
+
+/// Cloneable handle that evicts cached results by dependency key
+///
+/// Obtained from [`CachingToolRegistry::invalidator`]; useful for code that
+/// writes data tools depend on but does not own the registry.
+#[derive(Clone)]
+pub struct CacheInvalidator {
+    state: Arc<Mutex<CacheState>>,
+}
+
+impl CacheInvalidator {
+    /// Evict every cached result that depends on `key`
+    ///
+    /// Returns the number of evicted results.
+    pub fn invalidate(&self, key: &str) -> usize {
+        let evicted = lock(&self.state).invalidate(key);
+        if evicted > 0 {
+            tracing::debug!(key = %key, evicted, "Invalidated cached tool results");
+        }
+        evicted
+    }
+}
+
+impl std::fmt::Debug for CacheInvalidator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CacheInvalidator").finish_non_exhaustive()
+    }
+}
+
+fn lock(state: &Mutex<CacheState>) -> std::sync::MutexGuard<'_, CacheState> {
+    // The cache holds no invariants a panic could break, so recover from poison
+    state
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// A tool registry wrapper that caches results of selected tools
+///
+/// Only tools registered with [`with_cached_tool`](Self::with_cached_tool)
+/// are cached, and only successful results are stored. Clones share the same
+/// cache.
+///
+/// # Example
+///
+/// ```rust
+/// use skreaver_tools::{CachePolicy, CachingToolRegistry, InMemoryToolRegistry};
+/// use std::time::Duration;
+///
+/// let registry = CachingToolRegistry::new(InMemoryToolRegistry::new()).with_cached_tool(
+///     "summarize_key",
+///     CachePolicy::with_ttl(Duration::from_secs(300)).depends_on(|input| vec![input.to_string()]),
+/// );
+///
+/// // After the memory key "notes" changes:
+/// registry.invalidate("notes");
+/// ```
+#[derive(Clone)]
+pub struct CachingToolRegistry<T: ToolRegistry> {
+    inner: T,
+    policies: HashMap<String, CachePolicy>,
+    max_entries: usize,
+    state: Arc<Mutex<CacheState>>,
+}
+
+impl<T: ToolRegistry> CachingToolRegistry<T> {
+    /// Default maximum number of cached results
+    pub const DEFAULT_MAX_ENTRIES: usize = 1000;
+
+    /// Wrap `inner` without caching any tool yet
+    pub fn new(inner: T) -> Self {
+        Self {
+            inner,
+            policies: HashMap::new(),
+            max_entries: Self::DEFAULT_MAX_ENTRIES,
+            state: Arc::new(Mutex::new(CacheState::default())),
+        }
+    }
+
+    /// Cache results of the tool `name` according to `policy`
+    pub fn with_cached_tool(mut self, name: &str, policy: CachePolicy) -> Self {
+        self.policies.insert(name.to_string(), policy);
+        self
+    }
+
+    /// Limit the number of cached results, evicting the oldest first
+    pub fn with_max_entries(mut self, max_entries: usize) -> Self {
+        self.max_entries = max_entries;
+        self
+    }
+
+    /// Get a handle that can invalidate this registry's cache
+    pub fn invalidator(&self) -> CacheInvalidator {
+        CacheInvalidator {
+            state: Arc::clone(&self.state),
+        }
+    }
+
+    /// Evict every cached result that depends on `key`
+    ///
+    /// Returns the number of evicted results.
+    pub fn invalidate(&self, key: &str) -> usize {
+        self.invalidator().invalidate(key)
+    }
+
+    /// Evict every cached result of the tool `name`
+    pub fn invalidate_tool(&self, name: &str) -> usize {
+        lock(&self.state).invalidate_tool(name)
+    }
+
+    /// Evict all cached results
+    pub fn clear(&self) {
+        *lock(&self.state) = CacheState::default();
+    }
+
+    /// Number of cached results, including expired ones not yet evicted
+    pub fn cached_len(&self) -> usize {
+        lock(&self.state).entries.len()
+    }
+
+    /// Get the wrapped registry
+    pub fn inner(&self) -> &T {
+        &self.inner
+    }
+
+    fn dispatch_cached(&self, call: &ToolCall, policy: &CachePolicy) -> Option<ExecutionResult> {
+        let key = (call.name().to_string(), call.input.clone());
+        if let Some(result) = lock(&self.state).get(&key) {
+            tracing::trace!(tool_name = %call.name(), "Tool result served from cache");
+            return Some(result);
+        }
+
+        // Run the tool without holding the lock; concurrent misses may both
+        // execute, which is harmless for the pure tools this cache targets
+        let result = self.inner.dispatch_ref(call)?;
+        if result.is_success() && self.max_entries > 0 {
+            let now = Instant::now();
+            let entry = CacheEntry {
+                result: result.clone(),
+                inserted_at: now,
+                expires_at: policy.ttl.map(|ttl| now + ttl),
+                dependencies: policy.dependencies_for(&call.input),
+            };
+            lock(&self.state).insert(key, entry, self.max_entries);
+        }
+        Some(result)
+    }
+}
+
+impl<T: ToolRegistry> ToolRegistry for CachingToolRegistry<T> {
+    fn dispatch(&self, call: ToolCall) -> Option<ExecutionResult> {
+        match self.policies.get(call.name()) {
+            Some(policy) => self.dispatch_cached(&call, policy),
+            None => self.inner.dispatch(call),
+        }
+    }
+
+    fn dispatch_ref(&self, call: &ToolCall) -> Option<ExecutionResult> {
+        match self.policies.get(call.name()) {
+            Some(policy) => self.dispatch_cached(call, policy),
+            None => self.inner.dispatch_ref(call),
+        }
+    }
+}
+
+/// Memory wrapper that invalidates cached tool results on every write
+///
+/// Each stored key is passed to the [`CacheInvalidator`], evicting cached
+/// results that declared it as a dependency. Reads pass straight through.
+#[derive(Debug, Clone)]
+pub struct InvalidatingMemory<M> {
+    inner: M,
+    invalidator: CacheInvalidator,
+}
+
+impl<M> InvalidatingMemory<M> {
+    /// Wrap `inner`, invalidating through `invalidator` on writes
+    pub fn new(inner: M, invalidator: CacheInvalidator) -> Self {
+        Self { inner, invalidator }
+    }
+
+    /// Get the wrapped memory
+    pub fn inner(&self) -> &M {
+        &self.inner
+    }
+
+    /// Unwrap the memory
+    pub fn into_inner(self) -> M {
+        self.inner
+    }
+}
+
+impl<M: MemoryReader> MemoryReader for InvalidatingMemory<M> {
+    fn load(&self, key: &MemoryKey) -> Result<Option<String>, MemoryError> {
+        self.inner.load(key)
+    }
+
+    fn load_many(&self, keys: &[MemoryKey]) -> Result<Vec<Option<String>>, MemoryError> {
+        self.inner.load_many(keys)
+    }
+}
+
+impl<M: MemoryWriter> MemoryWriter for InvalidatingMemory<M> {
+    fn store(&mut self, update: MemoryUpdate) -> Result<(), MemoryError> {
+        let key = update.key.clone();
+        self.inner.store(update)?;
+        self.invalidator.invalidate(key.as_str());
+        Ok(())
+    }
+
+    fn store_many(&mut self, updates: Vec<MemoryUpdate>) -> Result<(), MemoryError> {
+        let keys: Vec<MemoryKey> = updates.iter().map(|update| update.key.clone()).collect();
+        self.inner.store_many(updates)?;
+        for key in keys {
+            self.invalidator.invalidate(key.as_str());
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{InMemoryToolRegistry, Tool};
+    use skreaver_core::InMemoryMemory;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// Tool returning the memory value named by its input, counting calls
+    struct ReadKeyTool {
+        memory: InMemoryMemory,
+        calls: Arc<AtomicUsize>,
+    }
+
+    impl Tool for ReadKeyTool {
+        fn name(&self) -> &str {
+            "read_key"
+        }
+
+        fn call(&self, input: String) -> ExecutionResult {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            let key = MemoryKey::new(&input).unwrap();
+            ExecutionResult::success(self.memory.load(&key).unwrap().unwrap_or_default())
+        }
+    }
+
+    fn setup() -> (
+        CachingToolRegistry<InMemoryToolRegistry>,
+        InvalidatingMemory<InMemoryMemory>,
+        Arc<AtomicUsize>,
+    ) {
+        let memory = InMemoryMemory::new();
+        let calls = Arc::new(AtomicUsize::new(0));
+        let inner = InMemoryToolRegistry::new().with_tool(
+            "read_key",
+            Arc::new(ReadKeyTool {
+                memory: memory.clone(),
+                calls: Arc::clone(&calls),
+            }),
+        );
+        let registry = CachingToolRegistry::new(inner).with_cached_tool(
+            "read_key",
+            CachePolicy::new().depends_on(|input| vec![input.to_string()]),
+        );
+        let memory = InvalidatingMemory::new(memory, registry.invalidator());
+        (registry, memory, calls)
+    }
+
+    fn read(registry: &impl ToolRegistry, key: &str) -> String {
+        registry
+            .dispatch(ToolCall::new("read_key", key).unwrap())
+            .unwrap()
+            .output()
+    }
+
+    #[test]
+    fn test_dependency_write_evicts_only_dependent_results() {
+        let (registry, mut memory, calls) = setup();
+        memory.store(MemoryUpdate::new("a", "1").unwrap()).unwrap();
+        memory.store(MemoryUpdate::new("b", "1").unwrap()).unwrap();
+
+        assert_eq!(read(&registry, "a"), "1");
+        assert_eq!(read(&registry, "b"), "1");
+        assert_eq!(read(&registry, "a"), "1");
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+
+        memory.store(MemoryUpdate::new("a", "2").unwrap()).unwrap();
+
+        assert_eq!(read(&registry, "a"), "2");
+        assert_eq!(read(&registry, "b"), "1");
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+    }
+
+    #[test]
+    fn test_explicit_invalidate_and_store_many() {
+        let (registry, mut memory, calls) = setup();
+        read(&registry, "a");
+        read(&registry, "b");
+
+        assert_eq!(registry.invalidate("unrelated"), 0);
+        assert_eq!(registry.invalidate("a"), 1);
+        assert_eq!(registry.cached_len(), 1);
+
+        memory
+            .store_many(vec![MemoryUpdate::new("b", "x").unwrap()])
+            .unwrap();
+        assert_eq!(registry.cached_len(), 0);
+        assert_eq!(read(&registry, "b"), "x");
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+    }
+
+    #[test]
+    fn test_ttl_failures_and_uncached_tools() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let memory = InMemoryMemory::new();
+        let tool = Arc::new(ReadKeyTool {
+            memory,
+            calls: Arc::clone(&calls),
+        });
+        let inner = InMemoryToolRegistry::new()
+            .with_tool("read_key", tool.clone())
+            .with_tool("uncached", tool);
+        let registry = CachingToolRegistry::new(inner)
+            .with_cached_tool("read_key", CachePolicy::with_ttl(Duration::ZERO));
+
+        read(&registry, "a");
+        read(&registry, "a");
+        assert_eq!(
+            calls.load(Ordering::SeqCst),
+            2,
+            "expired entries are re-run"
+        );
+
+        let uncached = ToolCall::new("uncached", "a").unwrap();
+        registry.dispatch_ref(&uncached);
+        registry.dispatch_ref(&uncached);
+        assert_eq!(calls.load(Ordering::SeqCst), 4);
+        assert_eq!(registry.cached_len(), 1);
+    }
+
+    #[test]
+    fn test_max_entries_evicts_oldest() {
+        let (registry, _memory, calls) = setup();
+        let registry = registry.with_max_entries(1);
+
+        read(&registry, "a");
+        read(&registry, "b");
+        read(&registry, "a");
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+        assert_eq!(registry.cached_len(), 1);
+    }
+}
@@ -0,0 +1,95 @@
+//! Nesting depth tracking for tools that invoke other tools
+//!
+//! Composed tools such as [`PipelineTool`](crate::PipelineTool) dispatch
+//! further tool calls from inside their own execution, so a misconfigured
+//! composition can recurse without bound. Registries enter a
+//! [`ToolCallDepthGuard`] around every tool execution; the depth is tracked
+//! per thread and returns to zero when the top-level dispatch finishes.
+
+use skreaver_core::{ExecutionResult, FailureReason, ToolDispatch, error::ToolError};
+use std::cell::Cell;
+use std::marker::PhantomData;
+
+/// Default maximum number of nested tool calls
+pub const DEFAULT_MAX_TOOL_CALL_DEPTH: usize = 8;
+
+/// Failure category used for calls rejected for exceeding the depth limit
+pub const MAX_DEPTH_EXCEEDED_CATEGORY: &str = "max_depth_exceeded";
+
+thread_local! {
+    static TOOL_CALL_DEPTH: Cell<usize> = const { Cell::new(0) };
+}
+
+/// Number of tool executions currently in progress on this thread
+///
+/// Zero outside of any dispatch, one inside a top-level tool call.
+pub fn current_tool_call_depth() -> usize {
+    TOOL_CALL_DEPTH.with(Cell::get)
+}
+
+/// Marks one level of tool call nesting until dropped
+#[must_use = "the depth is released as soon as the guard is dropped"]
+pub struct ToolCallDepthGuard {
+    // Depth is thread-local, so the guard must be released on the same thread
+    _not_send: PhantomData<*const ()>,
+}
+
+impl ToolCallDepthGuard {
+    /// Enter a call to `tool`, failing if it would nest deeper than `max_depth`
+    ///
+    /// # Errors
+    ///
+    /// Returns `ToolError::MaxDepthExceeded` if `max_depth` calls are already
+    /// in progress on this thread
+    pub fn enter(tool: &ToolDispatch, max_depth: usize) -> Result<Self, ToolError> {
+        TOOL_CALL_DEPTH.with(|depth| {
+            if depth.get() >= max_depth {
+                return Err(ToolError::max_depth_exceeded(tool.clone(), max_depth));
+            }
+            depth.set(depth.get() + 1);
+            Ok(Self {
+                _not_send: PhantomData,
+            })
+        })
+    }
+}
+
+impl Drop for ToolCallDepthGuard {
+    fn drop(&mut self) {
+        TOOL_CALL_DEPTH.with(|depth| depth.set(depth.get().saturating_sub(1)));
+    }
+}
+
+/// Convert a depth limit error into the failure result returned by registries
+pub fn depth_exceeded_result(error: &ToolError) -> ExecutionResult {
+    ExecutionResult::Failure {
+        reason: FailureReason::Custom {
+            category: MAX_DEPTH_EXCEEDED_CATEGORY.to_string(),
+            message: error.to_string(),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_guard_limits_and_releases_depth() {
+        let tool = ToolDispatch::from_name("nested_tool").unwrap();
+        assert_eq!(current_tool_call_depth(), 0);
+        {
+            let _outer = ToolCallDepthGuard::enter(&tool, 2).unwrap();
+            let _inner = ToolCallDepthGuard::enter(&tool, 2).unwrap();
+            assert_eq!(current_tool_call_depth(), 2);
+
+            let error = ToolCallDepthGuard::enter(&tool, 2).err().unwrap();
+            assert!(matches!(
+                error,
+                ToolError::MaxDepthExceeded { max_depth: 2, .. }
+            ));
+            assert_eq!(current_tool_call_depth(), 2);
+        }
+        assert_eq!(current_tool_call_depth(), 0);
+    }
+}
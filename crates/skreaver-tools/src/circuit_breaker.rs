@@ -0,0 +1,450 @@
+//! Per-tool circuit breakers
+//!
+//! A breaker isolates a single failing tool (for example one flaky HTTP API)
+//! without affecting the rest of the registry. Each tool has its own breaker:
+//!
+//! - **Closed**: calls flow normally; consecutive failures and slow calls are
+//!   counted, and reaching the threshold opens the breaker
+//! - **Open**: calls fast-fail without reaching the tool until the open
+//!   window has elapsed
+//! - **HalfOpen**: one trial call is admitted at a time; success closes the
+//!   breaker, failure opens it again
+//!
+//! Operators can also set a breaker's state by hand, mirroring the per-agent
+//! breakers in the HTTP runtime's backpressure manager.
+
+use serde::{Deserialize, Serialize};
+use skreaver_core::ExecutionResult;
+use skreaver_core::tool::FailureReason;
+use std::collections::HashMap;
+use std::sync::{Mutex, MutexGuard};
+use std::time::{Duration, Instant, SystemTime};
+
+/// Failure category used for calls rejected by an open breaker
+pub const CIRCUIT_OPEN_CATEGORY: &str = "circuit_open";
+
+/// Circuit breaker state
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ToolCircuitState {
+    /// Calls flow normally
+    #[default]
+    Closed,
+    /// All calls are rejected
+    Open,
+    /// One trial call is admitted at a time
+    HalfOpen,
+}
+
+impl ToolCircuitState {
+    /// Parse a state from its wire name or the matching action (`close`, `half-open`)
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "closed" | "close" => Some(Self::Closed),
+            "open" => Some(Self::Open),
+            "half_open" | "half-open" => Some(Self::HalfOpen),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for ToolCircuitState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Closed => write!(f, "closed"),
+            Self::Open => write!(f, "open"),
+            Self::HalfOpen => write!(f, "half_open"),
+        }
+    }
+}
+
+/// Configuration for per-tool circuit breakers
+#[derive(Debug, Clone, PartialEq)]
+pub struct ToolCircuitBreakerConfig {
+    /// Consecutive failures that open a tool's breaker
+    pub failure_threshold: u32,
+    /// How long a tripped breaker stays open before admitting a trial call
+    pub open_duration: Duration,
+    /// Calls running longer than this count as failures (timeouts)
+    pub slow_call_threshold: Option<Duration>,
+}
+
+impl Default for ToolCircuitBreakerConfig {
+    fn default() -> Self {
+        Self {
+            failure_threshold: 5,
+            open_duration: Duration::from_secs(30),
+            slow_call_threshold: None,
+        }
+    }
+}
+
+impl ToolCircuitBreakerConfig {
+    /// Set the number of consecutive failures that open a breaker
+    pub fn with_failure_threshold(mut self, threshold: u32) -> Self {
+        self.failure_threshold = threshold.max(1);
+        self
+    }
+
+    /// Set how long a tripped breaker stays open
+    pub fn with_open_duration(mut self, duration: Duration) -> Self {
+        self.open_duration = duration;
+        self
+    }
+
+    /// Count calls slower than `threshold` as failures
+    pub fn with_slow_call_threshold(mut self, threshold: Duration) -> Self {
+        self.slow_call_threshold = Some(threshold);
+        self
+    }
+}
+
+/// Snapshot of a tool's circuit breaker
+#[derive(Debug, Clone, PartialEq)]
+pub struct ToolCircuitStatus {
+    pub tool: String,
+    pub state: ToolCircuitState,
+    /// Why the breaker was last changed, if a reason was given
+    pub reason: Option<String>,
+    /// Principal that last changed the breaker (`None` when tripped automatically)
+    pub changed_by: Option<String>,
+    /// When the breaker last changed state (`None` if never changed)
+    pub changed_at: Option<SystemTime>,
+    /// Failures counted since the last success
+    pub consecutive_failures: u32,
+    /// Calls rejected by this breaker since it last changed state
+    pub total_rejections: u64,
+}
+
+/// Stored breaker state for one tool
+#[derive(Debug, Clone)]
+struct Breaker {
+    state: ToolCircuitState,
+    reason: Option<String>,
+    changed_by: Option<String>,
+    changed_at: SystemTime,
+    opened_at: Instant,
+    consecutive_failures: u32,
+    rejections: u64,
+    trial_in_flight: bool,
+}
+
+impl Default for Breaker {
+    fn default() -> Self {
+        Self {
+            state: ToolCircuitState::Closed,
+            reason: None,
+            changed_by: None,
+            changed_at: SystemTime::now(),
+            opened_at: Instant::now(),
+            consecutive_failures: 0,
+            rejections: 0,
+            trial_in_flight: false,
+        }
+    }
+}
+
+impl Breaker {
+    fn transition(
+        &mut self,
+        state: ToolCircuitState,
+        changed_by: Option<String>,
+        reason: Option<String>,
+    ) {
+        self.state = state;
+        self.changed_by = changed_by;
+        self.reason = reason;
+        self.changed_at = SystemTime::now();
+        self.rejections = 0;
+        self.trial_in_flight = false;
+        if state == ToolCircuitState::Open {
+            self.opened_at = Instant::now();
+        }
+        if state == ToolCircuitState::Closed {
+            self.consecutive_failures = 0;
+        }
+    }
+}
+
+/// Circuit breakers keyed by tool name
+///
+/// Tools without an entry are closed.
+#[derive(Debug, Default)]
+pub struct ToolCircuitBreakers {
+    config: ToolCircuitBreakerConfig,
+    breakers: Mutex<HashMap<String, Breaker>>,
+}
+
+impl ToolCircuitBreakers {
+    /// Create breakers with the given configuration
+    pub fn new(config: ToolCircuitBreakerConfig) -> Self {
+        Self {
+            config,
+            breakers: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Get the breaker configuration
+    pub fn config(&self) -> &ToolCircuitBreakerConfig {
+        &self.config
+    }
+
+    /// Check whether a call to `tool` may proceed
+    ///
+    /// Returns the failure to report to the caller when the breaker rejects
+    /// the call. An open breaker whose window has elapsed moves to half-open
+    /// and admits the call as its trial.
+    pub fn admit(&self, tool: &str) -> Result<(), ExecutionResult> {
+        let mut breakers = self.lock();
+        let Some(breaker) = breakers.get_mut(tool) else {
+            return Ok(());
+        };
+
+        match breaker.state {
+            ToolCircuitState::Closed => return Ok(()),
+            ToolCircuitState::Open
+                if breaker.changed_by.is_none()
+                    && breaker.opened_at.elapsed() >= self.config.open_duration =>
+            {
+                tracing::info!(
+                    tool = tool,
+                    "Tool circuit breaker half-open, admitting trial call"
+                );
+                breaker.transition(
+                    ToolCircuitState::HalfOpen,
+                    None,
+                    Some("open window elapsed".to_string()),
+                );
+                breaker.trial_in_flight = true;
+                return Ok(());
+            }
+            ToolCircuitState::HalfOpen if !breaker.trial_in_flight => {
+                breaker.trial_in_flight = true;
+                return Ok(());
+            }
+            _ => {}
+        }
+
+        breaker.rejections = breaker.rejections.saturating_add(1);
+        Err(ExecutionResult::Failure {
+            reason: FailureReason::Custom {
+                category: CIRCUIT_OPEN_CATEGORY.to_string(),
+                message: format!(
+                    "Circuit breaker is {} for tool '{}'; call rejected without execution",
+                    breaker.state, tool
+                ),
+            },
+        })
+    }
+
+    /// Record the outcome of a call admitted by [`admit`](Self::admit)
+    pub fn record(&self, tool: &str, result: &ExecutionResult, elapsed: Duration) {
+        let slow = self
+            .config
+            .slow_call_threshold
+            .is_some_and(|threshold| elapsed > threshold);
+
+        if result.is_success() && !slow {
+            self.record_success(tool);
+        } else {
+            let reason = if slow {
+                format!("call took {}ms", elapsed.as_millis())
+            } else {
+                result.output()
+            };
+            self.record_failure(tool, reason);
+        }
+    }
+
+    /// Release an admitted call that produced no outcome (e.g. tool not found)
+    ///
+    /// Lets a half-open breaker admit its next trial call.
+    pub fn release(&self, tool: &str) {
+        if let Some(breaker) = self.lock().get_mut(tool) {
+            breaker.trial_in_flight = false;
+        }
+    }
+
+    /// Record a successful call, closing a half-open breaker
+    pub fn record_success(&self, tool: &str) {
+        let mut breakers = self.lock();
+        let Some(breaker) = breakers.get_mut(tool) else {
+            return;
+        };
+        breaker.consecutive_failures = 0;
+        if breaker.state == ToolCircuitState::HalfOpen {
+            tracing::info!(
+                tool = tool,
+                "Tool circuit breaker closed after successful trial"
+            );
+            breaker.transition(
+                ToolCircuitState::Closed,
+                None,
+                Some("trial call succeeded".to_string()),
+            );
+        }
+    }
+
+    /// Record a failed call, opening the breaker once the threshold is reached
+    pub fn record_failure(&self, tool: &str, error: impl Into<String>) {
+        let mut breakers = self.lock();
+        let breaker = breakers.entry(tool.to_string()).or_default();
+        breaker.consecutive_failures = breaker.consecutive_failures.saturating_add(1);
+
+        let trip = match breaker.state {
+            ToolCircuitState::Closed => {
+                breaker.consecutive_failures >= self.config.failure_threshold
+            }
+            ToolCircuitState::HalfOpen => true,
+            ToolCircuitState::Open => false,
+        };
+        if trip {
+            let error = error.into();
+            tracing::warn!(
+                tool = tool,
+                consecutive_failures = breaker.consecutive_failures,
+                error = %error,
+                "Tool circuit breaker opened"
+            );
+            breaker.transition(
+                ToolCircuitState::Open,
+                None,
+                Some(format!(
+                    "{} consecutive failures, last: {}",
+                    breaker.consecutive_failures, error
+                )),
+            );
+        }
+    }
+
+    /// Manually set a tool's breaker state
+    ///
+    /// A manually opened breaker stays open until changed again; it does not
+    /// move to half-open when the open window elapses.
+    pub fn set(
+        &self,
+        tool: &str,
+        state: ToolCircuitState,
+        changed_by: Option<String>,
+        reason: Option<String>,
+    ) -> ToolCircuitStatus {
+        tracing::info!(
+            tool = tool,
+            state = %state,
+            changed_by = changed_by.as_deref().unwrap_or("unknown"),
+            "Tool circuit breaker state changed"
+        );
+        let mut breakers = self.lock();
+        let breaker = breakers.entry(tool.to_string()).or_default();
+        breaker.transition(state, changed_by, reason);
+        Self::status_of(tool, breakers.get(tool))
+    }
+
+    /// Get a tool's breaker status (closed if never changed)
+    pub fn status(&self, tool: &str) -> ToolCircuitStatus {
+        Self::status_of(tool, self.lock().get(tool))
+    }
+
+    /// Get the state of a tool's breaker
+    pub fn state(&self, tool: &str) -> ToolCircuitState {
+        self.lock().get(tool).map(|b| b.state).unwrap_or_default()
+    }
+
+    /// Forget a tool's breaker, closing it
+    pub fn remove(&self, tool: &str) {
+        self.lock().remove(tool);
+    }
+
+    fn status_of(tool: &str, breaker: Option<&Breaker>) -> ToolCircuitStatus {
+        match breaker {
+            Some(breaker) => ToolCircuitStatus {
+                tool: tool.to_string(),
+                state: breaker.state,
+                reason: breaker.reason.clone(),
+                changed_by: breaker.changed_by.clone(),
+                changed_at: (breaker.state != ToolCircuitState::Closed || breaker.reason.is_some())
+                    .then_some(breaker.changed_at),
+                consecutive_failures: breaker.consecutive_failures,
+                total_rejections: breaker.rejections,
+            },
+            None => ToolCircuitStatus {
+                tool: tool.to_string(),
+                state: ToolCircuitState::Closed,
+                reason: None,
+                changed_by: None,
+                changed_at: None,
+                consecutive_failures: 0,
+                total_rejections: 0,
+            },
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, HashMap<String, Breaker>> {
+        self.breakers.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn breakers(threshold: u32, open: Duration) -> ToolCircuitBreakers {
+        ToolCircuitBreakers::new(
+            ToolCircuitBreakerConfig::default()
+                .with_failure_threshold(threshold)
+                .with_open_duration(open),
+        )
+    }
+
+    #[test]
+    fn test_half_open_trial_closes_or_reopens() {
+        let breakers = breakers(1, Duration::ZERO);
+        breakers.record_failure("api", "boom");
+        assert_eq!(breakers.state("api"), ToolCircuitState::Open);
+
+        // Window elapsed: one trial is admitted, concurrent calls are rejected
+        assert!(breakers.admit("api").is_ok());
+        assert_eq!(breakers.state("api"), ToolCircuitState::HalfOpen);
+        assert!(breakers.admit("api").is_err());
+
+        breakers.record_failure("api", "still down");
+        assert_eq!(breakers.state("api"), ToolCircuitState::Open);
+
+        assert!(breakers.admit("api").is_ok());
+        breakers.record_success("api");
+        assert_eq!(breakers.state("api"), ToolCircuitState::Closed);
+        assert_eq!(breakers.status("api").consecutive_failures, 0);
+    }
+
+    #[test]
+    fn test_slow_calls_count_as_failures() {
+        let breakers = ToolCircuitBreakers::new(
+            ToolCircuitBreakerConfig::default()
+                .with_failure_threshold(2)
+                .with_slow_call_threshold(Duration::from_millis(10)),
+        );
+        let ok = ExecutionResult::success("ok".to_string());
+
+        breakers.record("api", &ok, Duration::from_millis(50));
+        breakers.record("api", &ok, Duration::from_millis(50));
+
+        assert_eq!(breakers.state("api"), ToolCircuitState::Open);
+    }
+
+    #[test]
+    fn test_manual_open_stays_open() {
+        let breakers = breakers(5, Duration::ZERO);
+        breakers.set(
+            "api",
+            ToolCircuitState::Open,
+            Some("ops".to_string()),
+            Some("maintenance".to_string()),
+        );
+
+        assert!(breakers.admit("api").is_err());
+        let status = breakers.status("api");
+        assert_eq!(status.state, ToolCircuitState::Open);
+        assert_eq!(status.total_rejections, 1);
+        assert_eq!(status.changed_by.as_deref(), Some("ops"));
+    }
+}
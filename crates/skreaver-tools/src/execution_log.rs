@@ -0,0 +1,194 @@
+//! Per-tool execution logging
+//!
+//! Logging is opt-in per tool and meant for debugging: each call to an
+//! enabled tool emits one `tracing` event carrying its input, output and
+//! duration. Payloads are passed through a [`SecretRedactor`] (which by
+//! default also masks common PII) and truncated before they are logged.
+//! Calls to tools without logging enabled emit nothing.
+
+use regex::Regex;
+use skreaver_core::ExecutionResult;
+use skreaver_core::sanitization::SecretRedactor;
+use std::collections::HashSet;
+use std::time::Duration;
+use tracing::Level;
+
+/// Tracing target of execution log events
+pub const EXECUTION_LOG_TARGET: &str = "skreaver_tools::execution";
+
+/// Configuration for per-tool execution logging
+#[derive(Debug, Clone)]
+pub struct ToolExecutionLogConfig {
+    /// Tools whose calls are logged
+    pub tools: HashSet<String>,
+    /// Level of the emitted events
+    pub level: Level,
+    /// Payloads longer than this many bytes are truncated
+    pub max_payload_bytes: usize,
+    /// Redactor applied to inputs and outputs
+    pub redactor: SecretRedactor,
+}
+
+impl Default for ToolExecutionLogConfig {
+    fn default() -> Self {
+        Self {
+            tools: HashSet::new(),
+            level: Level::DEBUG,
+            max_payload_bytes: 1024,
+            redactor: pii_redactor(),
+        }
+    }
+}
+
+impl ToolExecutionLogConfig {
+    /// Create a config with logging disabled for every tool
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Enable logging for `tool`
+    pub fn enable_tool(mut self, tool: impl Into<String>) -> Self {
+        self.tools.insert(tool.into());
+        self
+    }
+
+    /// Set the level of the emitted events
+    pub fn with_level(mut self, level: Level) -> Self {
+        self.level = level;
+        self
+    }
+
+    /// Set the payload size above which inputs and outputs are truncated
+    pub fn with_max_payload_bytes(mut self, bytes: usize) -> Self {
+        self.max_payload_bytes = bytes;
+        self
+    }
+
+    /// Replace the redactor applied to payloads
+    pub fn with_redactor(mut self, redactor: SecretRedactor) -> Self {
+        self.redactor = redactor;
+        self
+    }
+}
+
+/// Redactor masking built-in secrets plus email addresses, card numbers and
+/// US social security numbers
+pub fn pii_redactor() -> SecretRedactor {
+    let patterns = [
+        r"[A-Za-z0-9._%+-]+@[A-Za-z0-9.-]+\.[A-Za-z]{2,}",
+        r"\b(?:\d[ -]?){12,18}\d\b",
+        r"\b\d{3}-\d{2}-\d{4}\b",
+    ];
+    SecretRedactor::with_patterns(
+        patterns
+            .iter()
+            .map(|p| Regex::new(p).expect("valid PII pattern"))
+            .collect(),
+    )
+}
+
+/// Emits redacted execution log events for enabled tools
+#[derive(Debug, Clone)]
+pub struct ToolExecutionLogger {
+    config: ToolExecutionLogConfig,
+}
+
+impl ToolExecutionLogger {
+    /// Create a logger from its configuration
+    pub fn new(config: ToolExecutionLogConfig) -> Self {
+        Self { config }
+    }
+
+    /// Get the logger configuration
+    pub fn config(&self) -> &ToolExecutionLogConfig {
+        &self.config
+    }
+
+    /// Check whether calls to `tool` are logged
+    pub fn is_enabled(&self, tool: &str) -> bool {
+        self.config.tools.contains(tool)
+    }
+
+    /// Log one call to `tool`; does nothing if logging is disabled for it
+    ///
+    /// A missing `result` means the tool was not found.
+    pub fn log(
+        &self,
+        tool: &str,
+        input: &str,
+        result: Option<&ExecutionResult>,
+        duration: Duration,
+    ) {
+        if !self.is_enabled(tool) {
+            return;
+        }
+
+        let input = self.prepare(input);
+        let (success, output) = match result {
+            Some(result) => (result.is_success(), self.prepare(&result.output())),
+            None => (false, "tool not found".to_string()),
+        };
+        let duration_ms = duration.as_millis() as u64;
+
+        macro_rules! emit {
+            ($level:expr) => {
+                tracing::event!(
+                    target: EXECUTION_LOG_TARGET,
+                    $level,
+                    tool_name = tool,
+                    input = %input,
+                    output = %output,
+                    success,
+                    duration_ms,
+                    "Tool execution"
+                )
+            };
+        }
+        match self.config.level {
+            Level::ERROR => emit!(Level::ERROR),
+            Level::WARN => emit!(Level::WARN),
+            Level::INFO => emit!(Level::INFO),
+            Level::DEBUG => emit!(Level::DEBUG),
+            Level::TRACE => emit!(Level::TRACE),
+        }
+    }
+
+    /// Redact, then truncate a payload on a character boundary
+    fn prepare(&self, payload: &str) -> String {
+        let mut redacted = self.config.redactor.redact(payload);
+        let max = self.config.max_payload_bytes;
+        if redacted.len() > max {
+            let total = redacted.len();
+            let cut = (0..=max)
+                .rev()
+                .find(|&i| redacted.is_char_boundary(i))
+                .unwrap_or(0);
+            redacted.truncate(cut);
+            redacted.push_str(&format!("... ({} bytes truncated)", total - cut));
+        }
+        redacted
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_prepare_redacts_and_truncates() {
+        let logger = ToolExecutionLogger::new(
+            ToolExecutionLogConfig::new()
+                .enable_tool("lookup")
+                .with_max_payload_bytes(40),
+        );
+
+        assert_eq!(
+            logger.prepare("mail alice@example.com token=abc"),
+            "mail [REDACTED] token=***"
+        );
+
+        let prepared = logger.prepare(&"é".repeat(30));
+        assert!(prepared.starts_with(&"é".repeat(20)));
+        assert!(prepared.ends_with("... (20 bytes truncated)"));
+    }
+}
@@ -8,6 +8,7 @@
 //! - **I/O Tools** (`io`): File system operations and directory management
 //! - **Network Tools** (`network`): HTTP/REST API interactions
 //! - **Data Tools** (`data`): JSON/XML/text processing and transformation
+//! - **Process Tools**: allowlisted external commands without a shell
 //!
 //! ## Tool Categories
 //!
@@ -23,18 +24,56 @@
 //! - JSON parsing and transformation
 //! - XML processing
 //! - Text analysis and manipulation
+//!
+//! ### Process Tools
+//! - Allowlisted commands with templated, validated arguments
 
+/// Tool result caching with dependency-based invalidation.
+pub mod caching_registry;
+/// Nesting depth limits for tools that invoke other tools.
+pub mod call_depth;
+/// Per-tool circuit breakers for isolating failing tools.
+pub mod circuit_breaker;
 /// Core tool trait definitions and data structures.
 pub mod core;
+/// Opt-in, redacted logging of tool executions.
+pub mod execution_log;
+/// Output schema validation for tool results.
+pub mod output_schema;
+/// Pipeline tools composed from other registered tools.
+pub mod pipeline;
 /// Tool registry implementations for managing collections of tools.
 pub mod registry;
+/// Shared resources injected into tools at registration.
+pub mod resources;
 /// Secure tool registry with RBAC enforcement.
 pub mod secure_registry;
 /// Standard tool library providing common functionality.
 pub mod standard;
+/// All-or-nothing groups of tool calls with compensators.
+pub mod transaction;
 
+pub use caching_registry::{
+    CacheInvalidator, CachePolicy, CachingToolRegistry, InvalidatingMemory,
+};
+pub use call_depth::{
+    DEFAULT_MAX_TOOL_CALL_DEPTH, MAX_DEPTH_EXCEEDED_CATEGORY, ToolCallDepthGuard,
+    current_tool_call_depth,
+};
+pub use circuit_breaker::{
+    ToolCircuitBreakerConfig, ToolCircuitBreakers, ToolCircuitState, ToolCircuitStatus,
+};
 pub use core::{ToolCallBuildError, ToolCallBuilder, ToolConfig, ToolId, ValidationError};
+pub use execution_log::{
+    EXECUTION_LOG_TARGET, ToolExecutionLogConfig, ToolExecutionLogger, pii_redactor,
+};
+pub use output_schema::{
+    INVALID_OUTPUT_CATEGORY, OutputValidation, validate_json, validate_output,
+};
+pub use pipeline::PipelineTool;
 pub use registry::{InMemoryToolRegistry, ToolRegistry};
+pub use resources::{InjectableTool, SharedResources};
 pub use secure_registry::SecureToolRegistry;
 pub use skreaver_core::{ExecutionResult, StandardTool, Tool, ToolCall, ToolDispatch};
 pub use standard::*;
+pub use transaction::{Compensation, RollbackReport, ToolTransaction, TransactionOutcome};
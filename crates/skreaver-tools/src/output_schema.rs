@@ -0,0 +1,270 @@
+//! Validation of tool outputs against declared output schemas
+//!
+//! Tools may declare a JSON Schema for their output through
+//! [`Tool::output_schema`](skreaver_core::Tool::output_schema). Registries
+//! check successful results against it after execution so that malformed data
+//! is caught at the tool boundary instead of breaking agents downstream.
+//!
+//! The supported schema subset covers `type`, `enum`, `const`, `properties`,
+//! `required`, `additionalProperties`, `items`, `minItems`/`maxItems`,
+//! `minLength`/`maxLength` and `minimum`/`maximum`. Unknown keywords are
+//! ignored. Outputs that are not valid JSON are validated as JSON strings.
+
+use serde_json::Value;
+use skreaver_core::{ExecutionResult, FailureReason, Tool, ToolDispatch, error::ToolError};
+
+/// Failure category used for results rejected by output schema validation
+pub const INVALID_OUTPUT_CATEGORY: &str = "invalid_output";
+
+/// What a registry does with output that violates the tool's output schema
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OutputValidation {
+    /// Replace the result with an `invalid_output` failure
+    #[default]
+    Enforce,
+    /// Log a warning and return the result unchanged
+    Warn,
+}
+
+/// Validate a tool output against a JSON Schema
+///
+/// # Errors
+///
+/// Returns a description of the first violation, prefixed with the JSON path
+/// of the offending value
+pub fn validate_output(schema: &Value, output: &str) -> Result<(), String> {
+    let value = serde_json::from_str(output).unwrap_or_else(|_| Value::String(output.to_string()));
+    validate_json(schema, &value)
+}
+
+/// Validate a JSON value against a JSON Schema
+///
+/// Uses the same schema subset as [`validate_output`], which makes it
+/// suitable for checking tool inputs before they are forwarded.
+///
+/// # Errors
+///
+/// Returns a description of the first violation, prefixed with the JSON path
+/// of the offending value
+pub fn validate_json(schema: &Value, value: &Value) -> Result<(), String> {
+    validate_value(schema, value, "$")
+}
+
+/// Check a successful result against the tool's output schema
+///
+/// Failures and tools without an output schema pass through unchanged.
+pub(crate) fn check_output(
+    dispatch: &ToolDispatch,
+    tool: &dyn Tool,
+    result: ExecutionResult,
+    mode: OutputValidation,
+) -> ExecutionResult {
+    let (ExecutionResult::Success { output, .. }, Some(schema)) = (&result, tool.output_schema())
+    else {
+        return result;
+    };
+    let Err(reason) = validate_output(&schema, output) else {
+        return result;
+    };
+
+    let error = ToolError::invalid_output(dispatch.clone(), reason);
+    match mode {
+        OutputValidation::Enforce => ExecutionResult::Failure {
+            reason: FailureReason::Custom {
+                category: INVALID_OUTPUT_CATEGORY.to_string(),
+                message: error.to_string(),
+            },
+        },
+        OutputValidation::Warn => {
+            tracing::warn!(
+                tool = %dispatch.name(),
+                error = %error,
+                "Tool output violates its output schema"
+            );
+            result
+        }
+    }
+}
+
+fn validate_value(schema: &Value, value: &Value, path: &str) -> Result<(), String> {
+    let Some(schema) = schema.as_object() else {
+        // `true` and `{}` accept everything; `false` accepts nothing
+        return match schema {
+            Value::Bool(false) => Err(format!("{}: no value is allowed here", path)),
+            _ => Ok(()),
+        };
+    };
+
+    if let Some(expected) = schema.get("type") {
+        let allowed: Vec<&str> = match expected {
+            Value::String(name) => vec![name.as_str()],
+            Value::Array(names) => names.iter().filter_map(Value::as_str).collect(),
+            _ => Vec::new(),
+        };
+        if !allowed.is_empty() && !allowed.iter().any(|name| is_type(value, name)) {
+            return Err(format!(
+                "{}: expected {}, found {}",
+                path,
+                allowed.join(" or "),
+                type_name(value)
+            ));
+        }
+    }
+
+    if let Some(options) = schema.get("enum").and_then(Value::as_array)
+        && !options.contains(value)
+    {
+        return Err(format!(
+            "{}: {} is not one of the allowed values",
+            path, value
+        ));
+    }
+    if let Some(expected) = schema.get("const")
+        && expected != value
+    {
+        return Err(format!("{}: expected {}, found {}", path, expected, value));
+    }
+
+    match value {
+        Value::Object(object) => {
+            if let Some(required) = schema.get("required").and_then(Value::as_array) {
+                for key in required.iter().filter_map(Value::as_str) {
+                    if !object.contains_key(key) {
+                        return Err(format!("{}: missing required property '{}'", path, key));
+                    }
+                }
+            }
+            let properties = schema.get("properties").and_then(Value::as_object);
+            for (key, child) in object {
+                let child_path = format!("{}.{}", path, key);
+                match properties.and_then(|properties| properties.get(key)) {
+                    Some(child_schema) => validate_value(child_schema, child, &child_path)?,
+                    None => match schema.get("additionalProperties") {
+                        Some(Value::Bool(false)) => {
+                            return Err(format!("{}: unexpected property '{}'", path, key));
+                        }
+                        Some(additional @ Value::Object(_)) => {
+                            validate_value(additional, child, &child_path)?;
+                        }
+                        _ => {}
+                    },
+                }
+            }
+        }
+        Value::Array(items) => {
+            check_bounds(schema, "minItems", "maxItems", items.len(), "items", path)?;
+            if let Some(item_schema) = schema.get("items") {
+                for (index, item) in items.iter().enumerate() {
+                    validate_value(item_schema, item, &format!("{}[{}]", path, index))?;
+                }
+            }
+        }
+        Value::String(text) => {
+            let length = text.chars().count();
+            check_bounds(schema, "minLength", "maxLength", length, "characters", path)?;
+        }
+        Value::Number(number) => {
+            let number = number.as_f64().unwrap_or_default();
+            if let Some(minimum) = schema.get("minimum").and_then(Value::as_f64)
+                && number < minimum
+            {
+                return Err(format!("{}: {} is less than {}", path, number, minimum));
+            }
+            if let Some(maximum) = schema.get("maximum").and_then(Value::as_f64)
+                && number > maximum
+            {
+                return Err(format!("{}: {} is greater than {}", path, number, maximum));
+            }
+        }
+        Value::Bool(_) | Value::Null => {}
+    }
+
+    Ok(())
+}
+
+fn check_bounds(
+    schema: &serde_json::Map<String, Value>,
+    min_key: &str,
+    max_key: &str,
+    count: usize,
+    unit: &str,
+    path: &str,
+) -> Result<(), String> {
+    let count = count as u64;
+    if let Some(min) = schema.get(min_key).and_then(Value::as_u64)
+        && count < min
+    {
+        return Err(format!(
+            "{}: expected at least {} {}, found {}",
+            path, min, unit, count
+        ));
+    }
+    if let Some(max) = schema.get(max_key).and_then(Value::as_u64)
+        && count > max
+    {
+        return Err(format!(
+            "{}: expected at most {} {}, found {}",
+            path, max, unit, count
+        ));
+    }
+    Ok(())
+}
+
+fn is_type(value: &Value, name: &str) -> bool {
+    match name {
+        "object" => value.is_object(),
+        "array" => value.is_array(),
+        "string" => value.is_string(),
+        "number" => value.is_number(),
+        "integer" => value.is_i64() || value.is_u64(),
+        "boolean" => value.is_boolean(),
+        "null" => value.is_null(),
+        _ => false,
+    }
+}
+
+fn type_name(value: &Value) -> &'static str {
+    match value {
+        Value::Object(_) => "object",
+        Value::Array(_) => "array",
+        Value::String(_) => "string",
+        Value::Number(_) => "number",
+        Value::Bool(_) => "boolean",
+        Value::Null => "null",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_validate_output_reports_path_of_violation() {
+        let schema = json!({
+            "type": "object",
+            "required": ["status", "items"],
+            "properties": {
+                "status": {"enum": ["ok", "partial"]},
+                "items": {"type": "array", "items": {"type": "integer", "minimum": 0}}
+            },
+            "additionalProperties": false
+        });
+
+        assert!(validate_output(&schema, r#"{"status": "ok", "items": [1, 2]}"#).is_ok());
+        assert_eq!(
+            validate_output(&schema, r#"{"status": "ok", "items": [1, "2"]}"#),
+            Err("$.items[1]: expected integer, found string".to_string())
+        );
+        assert_eq!(
+            validate_output(&schema, r#"{"status": "ok"}"#),
+            Err("$: missing required property 'items'".to_string())
+        );
+        assert_eq!(
+            validate_output(&schema, r#"{"status": "ok", "items": [], "debug": true}"#),
+            Err("$: unexpected property 'debug'".to_string())
+        );
+        assert!(validate_output(&schema, "plain text").is_err());
+        assert!(validate_output(&json!({"type": "string"}), "plain text").is_ok());
+    }
+}
@@ -0,0 +1,168 @@
+//! Pipeline tools composed from other registered tools
+//!
+//! A [`PipelineTool`] runs a fixed sequence of tools, feeding each stage's
+//! output to the next. Stages are dispatched through a registry, so a stage
+//! may itself be a pipeline; the registry's maximum tool call depth (see
+//! [`call_depth`](crate::call_depth)) bounds how deeply pipelines can nest.
+
+use super::{ExecutionResult, Tool, ToolCall, ToolRegistry};
+use std::sync::Arc;
+
+/// A tool that chains other tools, passing each output to the next stage
+///
+/// # Example
+///
+/// ```rust
+/// use skreaver_tools::{InMemoryToolRegistry, PipelineTool, ToolRegistry};
+/// use skreaver_core::{ExecutionResult, Tool, ToolCall};
+/// use std::sync::Arc;
+///
+/// struct TrimTool;
+///
+/// impl Tool for TrimTool {
+///     fn name(&self) -> &str { "trim" }
+///     fn call(&self, input: String) -> ExecutionResult {
+///         ExecutionResult::success(input.trim().to_string())
+///     }
+/// }
+///
+/// let stages = InMemoryToolRegistry::new().with_tool("trim", Arc::new(TrimTool));
+/// let pipeline = PipelineTool::new("clean", Arc::new(stages.clone())).then("trim");
+/// let registry = stages.with_tool("clean", Arc::new(pipeline));
+///
+/// let result = registry.dispatch(ToolCall::new("clean", "  text  ").unwrap());
+/// assert_eq!(result.unwrap().output(), "text");
+/// ```
+pub struct PipelineTool {
+    name: String,
+    registry: Arc<dyn ToolRegistry + Send + Sync>,
+    stages: Vec<String>,
+}
+
+impl PipelineTool {
+    /// Create an empty pipeline whose stages are dispatched through `registry`
+    pub fn new(name: impl Into<String>, registry: Arc<dyn ToolRegistry + Send + Sync>) -> Self {
+        Self {
+            name: name.into(),
+            registry,
+            stages: Vec::new(),
+        }
+    }
+
+    /// Append a stage that runs the named tool
+    pub fn then(mut self, tool_name: impl Into<String>) -> Self {
+        self.stages.push(tool_name.into());
+        self
+    }
+
+    /// Names of the tools run by this pipeline, in order
+    pub fn stages(&self) -> &[String] {
+        &self.stages
+    }
+}
+
+impl Tool for PipelineTool {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn description(&self) -> &str {
+        "Runs a sequence of tools, passing each output to the next"
+    }
+
+    fn call(&self, input: String) -> ExecutionResult {
+        let mut current = input;
+        for stage in &self.stages {
+            let call = match ToolCall::new(stage, &current) {
+                Ok(call) => call,
+                Err(e) => {
+                    return ExecutionResult::failure(format!(
+                        "Pipeline '{}' has invalid stage '{}': {}",
+                        self.name, stage, e
+                    ));
+                }
+            };
+
+            match self.registry.try_dispatch(&call) {
+                Ok(ExecutionResult::Success { output, .. }) => current = output,
+                // Stage failures, including depth limit rejections, end the pipeline
+                Ok(failure) => return failure,
+                Err(e) => return ExecutionResult::failure(e),
+            }
+        }
+        ExecutionResult::success(current)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::call_depth::{MAX_DEPTH_EXCEEDED_CATEGORY, current_tool_call_depth};
+    use crate::{InMemoryToolRegistry, registry::ToolRegistry};
+    use skreaver_core::FailureReason;
+
+    struct UppercaseTool;
+
+    impl Tool for UppercaseTool {
+        fn name(&self) -> &str {
+            "uppercase"
+        }
+
+        fn call(&self, input: String) -> ExecutionResult {
+            ExecutionResult::success(input.to_uppercase())
+        }
+    }
+
+    /// Build a registry where `pipeline_N` wraps `pipeline_{N-1}`, down to
+    /// `pipeline_1` wrapping `uppercase`
+    fn nested_registry(levels: usize, max_depth: usize) -> InMemoryToolRegistry {
+        let mut registry = InMemoryToolRegistry::new()
+            .with_max_call_depth(max_depth)
+            .with_tool("uppercase", Arc::new(UppercaseTool));
+        let mut inner = "uppercase".to_string();
+        for level in 1..=levels {
+            let name = format!("pipeline_{}", level);
+            let pipeline = PipelineTool::new(&name, Arc::new(registry.clone())).then(&inner);
+            registry = registry.with_tool(&name, Arc::new(pipeline));
+            inner = name;
+        }
+        registry
+    }
+
+    #[test]
+    fn pipeline_within_depth_limit_succeeds() {
+        // pipeline_2 -> pipeline_1 -> uppercase is three calls deep
+        let registry = nested_registry(2, 3);
+        let result = registry
+            .dispatch(ToolCall::new("pipeline_2", "nested").unwrap())
+            .unwrap();
+
+        assert_eq!(result.output(), "NESTED");
+        assert_eq!(current_tool_call_depth(), 0);
+    }
+
+    #[test]
+    fn pipeline_beyond_depth_limit_is_rejected() {
+        // pipeline_3 -> pipeline_2 -> pipeline_1 -> uppercase is four calls deep
+        let registry = nested_registry(3, 3);
+        let result = registry
+            .dispatch(ToolCall::new("pipeline_3", "nested").unwrap())
+            .unwrap();
+
+        match result.failure_reason() {
+            Some(FailureReason::Custom { category, message }) => {
+                assert_eq!(category, MAX_DEPTH_EXCEEDED_CATEGORY);
+                assert!(message.contains("'uppercase'"));
+                assert!(message.contains("depth of 3"));
+            }
+            other => panic!("Expected depth limit failure, got {:?}", other),
+        }
+
+        // Depth resets once the top-level dispatch returns
+        assert_eq!(current_tool_call_depth(), 0);
+        let shallow = registry
+            .dispatch(ToolCall::new("pipeline_1", "ok").unwrap())
+            .unwrap();
+        assert_eq!(shallow.output(), "OK");
+    }
+}
@@ -1,4 +1,7 @@
 use super::{ExecutionResult, ToolCall};
+use crate::call_depth::{DEFAULT_MAX_TOOL_CALL_DEPTH, ToolCallDepthGuard, depth_exceeded_result};
+use crate::output_schema::{OutputValidation, check_output};
+use crate::resources::{InjectableTool, SharedResources};
 use skreaver_core::collections::NonEmptyVec;
 use std::collections::HashMap;
 use std::sync::Arc;
@@ -110,7 +113,7 @@ pub trait ToolRegistry {
 /// impl Tool for EchoTool {
 ///     fn name(&self) -> &str { "echo" }
 ///     fn call(&self, input: String) -> ExecutionResult {
-///         ExecutionResult::Success { output: input }
+///         ExecutionResult::success(input)
 ///     }
 /// }
 ///
@@ -123,6 +126,9 @@ pub trait ToolRegistry {
 pub struct InMemoryToolRegistry {
     standard_tools: HashMap<super::StandardTool, Arc<dyn super::Tool>>,
     custom_tools: HashMap<super::ToolId, Arc<dyn super::Tool>>,
+    resources: SharedResources,
+    max_call_depth: usize,
+    output_validation: OutputValidation,
 }
 
 impl Default for InMemoryToolRegistry {
@@ -141,9 +147,120 @@ impl InMemoryToolRegistry {
         Self {
             standard_tools: HashMap::new(),
             custom_tools: HashMap::new(),
+            resources: SharedResources::new(),
+            max_call_depth: DEFAULT_MAX_TOOL_CALL_DEPTH,
+            output_validation: OutputValidation::default(),
         }
     }
 
+    /// Set the maximum depth of nested tool calls.
+    ///
+    /// Tools that dispatch other tools, such as pipelines, nest one level per
+    /// call. A call that would nest deeper than `max_depth` fails with a
+    /// `max_depth_exceeded` failure instead of running. Defaults to
+    /// [`DEFAULT_MAX_TOOL_CALL_DEPTH`].
+    ///
+    /// # Parameters
+    ///
+    /// * `max_depth` - Maximum number of nested tool calls
+    ///
+    /// # Returns
+    ///
+    /// Self for method chaining
+    pub fn with_max_call_depth(mut self, max_depth: usize) -> Self {
+        self.max_call_depth = max_depth;
+        self
+    }
+
+    /// Get the maximum depth of nested tool calls.
+    pub fn max_call_depth(&self) -> usize {
+        self.max_call_depth
+    }
+
+    /// Set how outputs violating a tool's output schema are handled.
+    ///
+    /// Successful results of tools that declare an
+    /// [`output_schema`](super::Tool::output_schema) are validated after
+    /// execution. With [`OutputValidation::Enforce`] (the default) a
+    /// violating result becomes an `invalid_output` failure; with
+    /// [`OutputValidation::Warn`] it is logged and returned unchanged.
+    ///
+    /// # Parameters
+    ///
+    /// * `mode` - Enforce or warn on schema violations
+    ///
+    /// # Returns
+    ///
+    /// Self for method chaining
+    pub fn with_output_validation(mut self, mode: OutputValidation) -> Self {
+        self.output_validation = mode;
+        self
+    }
+
+    /// Get how outputs violating a tool's output schema are handled.
+    pub fn output_validation(&self) -> OutputValidation {
+        self.output_validation
+    }
+
+    /// Run a tool inside a call depth guard and validate its output
+    fn execute(
+        &self,
+        dispatch: &super::ToolDispatch,
+        tool: &Arc<dyn super::Tool>,
+        input: String,
+    ) -> ExecutionResult {
+        match ToolCallDepthGuard::enter(dispatch, self.max_call_depth) {
+            Ok(_guard) => check_output(
+                dispatch,
+                tool.as_ref(),
+                tool.call(input),
+                self.output_validation,
+            ),
+            Err(e) => depth_exceeded_result(&e),
+        }
+    }
+
+    /// Set the shared resources injected into tools at registration.
+    ///
+    /// Tools registered via [`with_injected_tool`](Self::with_injected_tool)
+    /// after this call are built from these resources, so they share pooled
+    /// clients instead of creating their own.
+    ///
+    /// # Parameters
+    ///
+    /// * `resources` - The shared resource container
+    ///
+    /// # Returns
+    ///
+    /// Self for method chaining
+    pub fn with_resources(mut self, resources: SharedResources) -> Self {
+        self.resources = resources;
+        self
+    }
+
+    /// Get the shared resources used for tool injection.
+    pub fn resources(&self) -> &SharedResources {
+        &self.resources
+    }
+
+    /// Build a tool from the registry's shared resources and register it.
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - The name to register the tool under (will be validated)
+    ///
+    /// # Returns
+    ///
+    /// Self for method chaining
+    ///
+    /// # Panics
+    ///
+    /// Panics if the tool name is invalid.
+    pub fn with_injected_tool<T: InjectableTool + 'static>(self, name: &str) -> Self {
+        let tool = Arc::new(T::from_resources(&self.resources));
+        self.with_tool(name, tool)
+    }
+
     /// Add a tool to the registry using the builder pattern.
     ///
     /// This is a convenience method for chaining tool registrations
@@ -299,30 +416,20 @@ impl InMemoryToolRegistry {
 
 impl super::registry::ToolRegistry for InMemoryToolRegistry {
     fn dispatch(&self, call: ToolCall) -> Option<ExecutionResult> {
-        match &call.dispatch {
-            super::ToolDispatch::Standard(standard_tool) => self
-                .standard_tools
-                .get(standard_tool)
-                .map(|tool| tool.call(call.input)),
-            super::ToolDispatch::Custom(tool_name) => self
-                .custom_tools
-                .get(tool_name)
-                .map(|tool| tool.call(call.input)),
-        }
+        let tool = match &call.dispatch {
+            super::ToolDispatch::Standard(standard_tool) => self.standard_tools.get(standard_tool),
+            super::ToolDispatch::Custom(tool_name) => self.custom_tools.get(tool_name),
+        }?;
+        Some(self.execute(&call.dispatch, tool, call.input))
     }
 
     fn dispatch_ref(&self, call: &ToolCall) -> Option<ExecutionResult> {
         // Zero-copy implementation: only clone the input string, not the entire ToolCall
-        match &call.dispatch {
-            super::ToolDispatch::Standard(standard_tool) => self
-                .standard_tools
-                .get(standard_tool)
-                .map(|tool| tool.call(call.input.clone())),
-            super::ToolDispatch::Custom(tool_name) => self
-                .custom_tools
-                .get(tool_name)
-                .map(|tool| tool.call(call.input.clone())),
-        }
+        let tool = match &call.dispatch {
+            super::ToolDispatch::Standard(standard_tool) => self.standard_tools.get(standard_tool),
+            super::ToolDispatch::Custom(tool_name) => self.custom_tools.get(tool_name),
+        }?;
+        Some(self.execute(&call.dispatch, tool, call.input.clone()))
     }
 }
 
@@ -340,9 +447,7 @@ mod tests {
         }
 
         fn call(&self, input: String) -> ExecutionResult {
-            ExecutionResult::Success {
-                output: input.to_uppercase(),
-            }
+            ExecutionResult::success(input.to_uppercase())
         }
     }
 
@@ -354,12 +459,72 @@ mod tests {
         }
 
         fn call(&self, input: String) -> ExecutionResult {
-            ExecutionResult::Success {
-                output: input.chars().rev().collect(),
+            ExecutionResult::success(input.chars().rev().collect())
+        }
+    }
+
+    /// Echoes its input and declares that output must be `{"count": <integer>}`
+    struct CountTool;
+
+    impl Tool for CountTool {
+        fn name(&self) -> &str {
+            "count"
+        }
+
+        fn output_schema(&self) -> Option<serde_json::Value> {
+            Some(serde_json::json!({
+                "type": "object",
+                "required": ["count"],
+                "properties": {"count": {"type": "integer"}}
+            }))
+        }
+
+        fn call(&self, input: String) -> ExecutionResult {
+            ExecutionResult::success(input)
+        }
+    }
+
+    #[test]
+    fn registry_rejects_output_violating_schema() {
+        use crate::output_schema::INVALID_OUTPUT_CATEGORY;
+        use skreaver_core::FailureReason;
+
+        let registry = InMemoryToolRegistry::new().with_tool("count", Arc::new(CountTool));
+        assert_eq!(registry.output_validation(), OutputValidation::Enforce);
+
+        let result = registry
+            .dispatch(ToolCall::new("count", r#"{"count": "three"}"#).unwrap())
+            .unwrap();
+        match result.failure_reason() {
+            Some(FailureReason::Custom { category, message }) => {
+                assert_eq!(category, INVALID_OUTPUT_CATEGORY);
+                assert!(message.contains("'count'"));
+                assert!(message.contains("$.count: expected integer, found string"));
             }
+            other => panic!("Expected invalid output failure, got {:?}", other),
         }
     }
 
+    #[test]
+    fn registry_passes_output_matching_schema() {
+        let registry = InMemoryToolRegistry::new().with_tool("count", Arc::new(CountTool));
+        let result = registry
+            .dispatch(ToolCall::new("count", r#"{"count": 3}"#).unwrap())
+            .unwrap();
+        assert!(result.is_success());
+        assert_eq!(result.output(), r#"{"count": 3}"#);
+
+        // In warn mode violations are logged but the result is kept
+        let warning = InMemoryToolRegistry::new()
+            .with_output_validation(OutputValidation::Warn)
+            .with_tool("count", Arc::new(CountTool));
+        let result = warning
+            .dispatch(ToolCall::new("count", "not json").unwrap())
+            .unwrap();
+        assert!(result.is_success());
+        assert_eq!(result.output(), "not json");
+    }
+
     #[test]
     fn registry_dispatches_to_correct_tool() {
         let registry = InMemoryToolRegistry::new()
@@ -465,6 +630,52 @@ mod tests {
         assert!(missing.is_none());
     }
 
+    #[test]
+    fn registry_injects_shared_resources_into_tools() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        #[derive(Default)]
+        struct CountingClient {
+            calls: AtomicUsize,
+        }
+
+        struct ClientTool {
+            client: Arc<CountingClient>,
+        }
+
+        impl Tool for ClientTool {
+            fn name(&self) -> &str {
+                "client_tool"
+            }
+
+            fn call(&self, input: String) -> ExecutionResult {
+                self.client.calls.fetch_add(1, Ordering::SeqCst);
+                ExecutionResult::success(input)
+            }
+        }
+
+        impl InjectableTool for ClientTool {
+            fn from_resources(resources: &SharedResources) -> Self {
+                Self {
+                    client: resources.get::<CountingClient>().unwrap_or_default(),
+                }
+            }
+        }
+
+        let client = Arc::new(CountingClient::default());
+        let registry = InMemoryToolRegistry::new()
+            .with_resources(SharedResources::new().with_shared(Arc::clone(&client)))
+            .with_injected_tool::<ClientTool>("first_tool")
+            .with_injected_tool::<ClientTool>("second_tool");
+
+        registry.dispatch(ToolCall::new("first_tool", "a").expect("Valid tool name"));
+        registry.dispatch(ToolCall::new("second_tool", "b").expect("Valid tool name"));
+
+        assert_eq!(client.calls.load(Ordering::SeqCst), 2);
+        // Registry keeps one reference, plus one per tool and the test's own
+        assert_eq!(Arc::strong_count(&client), 4);
+    }
+
     #[test]
     fn registry_len_and_is_empty() {
         let empty_registry = InMemoryToolRegistry::new();
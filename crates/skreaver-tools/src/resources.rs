@@ -0,0 +1,138 @@
+//! Shared resources for tool dependency injection.
+//!
+//! Tools that talk to the same upstream (HTTP APIs, databases, caches) should
+//! reuse a single connection pool instead of each building their own. This
+//! module provides [`SharedResources`], a type-keyed container the registry
+//! hands to tools at registration time, and the [`InjectableTool`] trait tools
+//! implement to opt in.
+//!
+//! # Example
+//!
+//! ```rust
+//! use skreaver_tools::{InMemoryToolRegistry, SharedResources};
+//! # #[cfg(feature = "network")]
+//! use skreaver_tools::{HttpGetTool, HttpPostTool};
+//!
+//! # #[cfg(feature = "network")]
+//! # {
+//! let resources = SharedResources::new().with(reqwest::Client::new());
+//!
+//! // Both tools reuse the same pooled client
+//! let registry = InMemoryToolRegistry::new()
+//!     .with_resources(resources)
+//!     .with_injected_tool::<HttpGetTool>("http_get")
+//!     .with_injected_tool::<HttpPostTool>("http_post");
+//! # }
+//! ```
+
+use skreaver_core::Tool;
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Type-keyed container of shared resources for tools.
+///
+/// Each resource is stored behind an `Arc` and looked up by its concrete type,
+/// so at most one instance of a given type is held. Cloning the container is
+/// cheap and shares the underlying resources.
+#[derive(Clone, Default)]
+pub struct SharedResources {
+    entries: HashMap<TypeId, Arc<dyn Any + Send + Sync>>,
+}
+
+impl SharedResources {
+    /// Create an empty resource container.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a resource using the builder pattern.
+    ///
+    /// Replaces any existing resource of the same type.
+    pub fn with<T: Any + Send + Sync>(mut self, resource: T) -> Self {
+        self.insert(Arc::new(resource));
+        self
+    }
+
+    /// Add an already shared resource using the builder pattern.
+    pub fn with_shared<T: Any + Send + Sync>(mut self, resource: Arc<T>) -> Self {
+        self.insert(resource);
+        self
+    }
+
+    /// Insert a shared resource, returning the previous one of the same type.
+    pub fn insert<T: Any + Send + Sync>(&mut self, resource: Arc<T>) -> Option<Arc<T>> {
+        self.entries
+            .insert(TypeId::of::<T>(), resource)
+            .and_then(|previous| previous.downcast::<T>().ok())
+    }
+
+    /// Get the shared resource of type `T`, if registered.
+    pub fn get<T: Any + Send + Sync>(&self) -> Option<Arc<T>> {
+        self.entries
+            .get(&TypeId::of::<T>())
+            .cloned()
+            .and_then(|resource| resource.downcast::<T>().ok())
+    }
+
+    /// Check whether a resource of type `T` is registered.
+    pub fn contains<T: Any + Send + Sync>(&self) -> bool {
+        self.entries.contains_key(&TypeId::of::<T>())
+    }
+
+    /// Number of registered resources.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Check if no resources are registered.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+impl std::fmt::Debug for SharedResources {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SharedResources")
+            .field("len", &self.entries.len())
+            .finish()
+    }
+}
+
+/// Tools that can be constructed from [`SharedResources`].
+///
+/// Implementations should pull the resources they need from the container and
+/// fall back to creating private ones when a resource is not registered, so
+/// that injection stays optional.
+pub trait InjectableTool: Tool + Sized {
+    /// Build the tool using resources from the shared container.
+    fn from_resources(resources: &SharedResources) -> Self;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn get_returns_same_instance() {
+        let resources = SharedResources::new().with(String::from("pool"));
+
+        let first = resources.get::<String>().unwrap();
+        let second = resources.get::<String>().unwrap();
+
+        assert!(Arc::ptr_eq(&first, &second));
+        assert!(resources.get::<u32>().is_none());
+        assert_eq!(resources.len(), 1);
+    }
+
+    #[test]
+    fn insert_replaces_resource_of_same_type() {
+        let mut resources = SharedResources::new().with(1u32);
+
+        let previous = resources.insert(Arc::new(2u32));
+
+        assert_eq!(previous.as_deref(), Some(&1));
+        assert_eq!(resources.get::<u32>().as_deref(), Some(&2));
+        assert!(resources.contains::<u32>());
+    }
+}
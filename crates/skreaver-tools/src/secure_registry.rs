@@ -2,13 +2,19 @@
 //!
 //! This module provides a wrapper around any `ToolRegistry` that enforces
 //! role-based access control (RBAC) by checking security policies before
-//! dispatching tool calls.
+//! dispatching tool calls. Optional per-tool circuit breakers fast-fail
+//! calls to tools that keep failing, and optional per-tool execution logging
+//! records redacted calls for debugging.
 
 use super::{ExecutionResult, ToolCall, ToolRegistry};
-use skreaver_core::auth::rbac::{Role, RoleManager};
+use crate::circuit_breaker::{ToolCircuitBreakerConfig, ToolCircuitBreakers};
+use crate::execution_log::{ToolExecutionLogConfig, ToolExecutionLogger};
+use skreaver_core::FailureReason;
+use skreaver_core::auth::rbac::{AuthzDenial, DenialReason, Role, RoleManager};
 use skreaver_core::collections::NonEmptyVec;
 use skreaver_core::security::config::SecurityConfig;
 use std::sync::Arc;
+use std::time::Instant;
 
 /// A secure tool registry wrapper that enforces RBAC policies
 ///
@@ -22,8 +28,13 @@ use std::sync::Arc;
 /// - Each tool call is checked against security configuration AND RBAC policies
 /// - Tools can be completely disabled via security config (fs_enabled, http_enabled, network_enabled)
 /// - Tools can require specific roles/permissions via RoleManager
-/// - Failed permission checks return `ExecutionResult::Failure` with a clear error message
+/// - Failed permission checks return `ExecutionResult::Failure` with a clear error message,
+///   or the structured [`AuthzDenial`] through [`dispatch_authorized`](Self::dispatch_authorized)
 /// - The underlying registry is never called if permissions are denied
+/// - With [`with_circuit_breaker`](Self::with_circuit_breaker), a tool whose
+///   breaker is open fails fast without reaching the underlying registry
+/// - With [`with_execution_logging`](Self::with_execution_logging), calls to
+///   selected tools are logged with redacted input and output
 ///
 /// # Example
 ///
@@ -48,6 +59,10 @@ pub struct SecureToolRegistry<T: ToolRegistry> {
     // Default role and permissions used when no user context is available
     // This provides baseline RBAC enforcement
     default_role: Role,
+    // Per-tool circuit breakers, shared between clones
+    circuit_breakers: Option<Arc<ToolCircuitBreakers>>,
+    // Per-tool execution logging, shared between clones
+    execution_logger: Option<Arc<ToolExecutionLogger>>,
 }
 
 impl<T: ToolRegistry> SecureToolRegistry<T> {
@@ -72,6 +87,8 @@ impl<T: ToolRegistry> SecureToolRegistry<T> {
             security_config,
             role_manager,
             default_role: Role::Agent, // Default to Agent role for backward compatibility
+            circuit_breakers: None,
+            execution_logger: None,
         }
     }
 
@@ -90,9 +107,39 @@ impl<T: ToolRegistry> SecureToolRegistry<T> {
             security_config,
             role_manager,
             default_role,
+            circuit_breakers: None,
+            execution_logger: None,
         }
     }
 
+    /// Enable per-tool circuit breakers
+    ///
+    /// Each tool gets its own breaker: repeated failures or slow calls to one
+    /// tool open its breaker and fast-fail further calls to it, while other
+    /// tools are unaffected.
+    pub fn with_circuit_breaker(mut self, config: ToolCircuitBreakerConfig) -> Self {
+        self.circuit_breakers = Some(Arc::new(ToolCircuitBreakers::new(config)));
+        self
+    }
+
+    /// Get the per-tool circuit breakers, if enabled
+    pub fn circuit_breakers(&self) -> Option<&Arc<ToolCircuitBreakers>> {
+        self.circuit_breakers.as_ref()
+    }
+
+    /// Enable execution logging for the tools listed in `config`
+    ///
+    /// Calls denied by RBAC or an open circuit breaker are not logged.
+    pub fn with_execution_logging(mut self, config: ToolExecutionLogConfig) -> Self {
+        self.execution_logger = Some(Arc::new(ToolExecutionLogger::new(config)));
+        self
+    }
+
+    /// Get the execution logger, if enabled
+    pub fn execution_logger(&self) -> Option<&Arc<ToolExecutionLogger>> {
+        self.execution_logger.as_ref()
+    }
+
     /// Check if a tool is allowed to execute based on security policy and RBAC
     ///
     /// This method checks both:
@@ -105,8 +152,8 @@ impl<T: ToolRegistry> SecureToolRegistry<T> {
     ///
     /// # Returns
     ///
-    /// `Ok(())` if the tool is allowed, `Err(String)` with error message if denied
-    fn check_permissions(&self, tool_name: &str) -> Result<(), String> {
+    /// `Ok(())` if the tool is allowed, or an [`AuthzDenial`] naming the failed check
+    pub fn authorize_tool(&self, tool_name: &str) -> Result<(), AuthzDenial> {
         // Step 1: Check security configuration (capability-based)
         let policy = self.security_config.tool_policy(tool_name);
 
@@ -119,49 +166,65 @@ impl<T: ToolRegistry> SecureToolRegistry<T> {
         let has_any_capability = fs_enabled || http_enabled || network_enabled;
 
         if !has_any_capability {
-            return Err(format!(
-                "Permission denied: Tool '{}' is not allowed by security policy. \
-                 All capabilities (filesystem, HTTP, network) are disabled.",
-                tool_name
+            return Err(AuthzDenial::new(
+                tool_name,
+                DenialReason::CapabilitiesDisabled,
             ));
         }
 
         // Check for emergency lockdown mode
-        if self.security_config.emergency.lockdown_enabled {
-            let allowed_tools = &self.security_config.emergency.lockdown_allowed_tools;
-            if !allowed_tools.contains(&tool_name.to_string()) {
-                return Err(format!(
-                    "Permission denied: System is in emergency lockdown mode. \
-                     Tool '{}' is not in the allowed list.",
-                    tool_name
-                ));
-            }
+        let emergency = &self.security_config.emergency;
+        if emergency.lockdown_enabled
+            && !emergency
+                .lockdown_allowed_tools
+                .iter()
+                .any(|allowed| allowed == tool_name)
+        {
+            return Err(AuthzDenial::new(
+                tool_name,
+                DenialReason::Lockdown {
+                    allowed_tools: emergency.lockdown_allowed_tools.clone(),
+                },
+            ));
         }
 
         // Step 2: Check RBAC policies (role and permission-based)
         let roles = vec![self.default_role.clone()];
         let permissions = self.default_role.permissions();
 
-        if !self
-            .role_manager
-            .check_tool_access(tool_name, &roles, &permissions)
-        {
-            return Err(format!(
-                "Permission denied: Tool '{}' requires higher privileges. \
-                 Current role '{}' does not have sufficient permissions.",
-                tool_name, self.default_role
-            ));
-        }
+        self.role_manager
+            .authorize_tool(tool_name, &roles, &permissions)
+    }
 
-        Ok(())
+    /// Execute a tool call, keeping an authorization denial structured
+    ///
+    /// [`ToolRegistry::dispatch`] reports a denied call as an
+    /// `ExecutionResult::Failure` carrying only a message. This returns the
+    /// [`AuthzDenial`] instead, so callers can report which check failed and
+    /// how to satisfy it. Other failures, including an open circuit breaker,
+    /// are returned as results as usual.
+    pub fn dispatch_authorized(
+        &self,
+        call: ToolCall,
+    ) -> Result<Option<ExecutionResult>, AuthzDenial> {
+        self.check_and_log_permissions(call.name())?;
+        let started = match self.admit_breaker(call.name()) {
+            Ok(started) => started,
+            Err(failure) => return Ok(Some(failure)),
+        };
+        let tool_name = call.name().to_string();
+        let input = self.logged_input(&call);
+        let result = self.inner.dispatch(call);
+        self.record_outcome(&tool_name, input.as_deref(), started, result.as_ref());
+        Ok(result)
     }
 
     /// Check permissions and record metrics for a tool call.
     ///
     /// This method combines permission checking with logging and metrics recording.
-    /// Returns `Ok(())` if allowed, or `Err(ExecutionResult::Failure)` if denied.
-    fn check_and_log_permissions(&self, tool_name: &str) -> Result<(), ExecutionResult> {
-        match self.check_permissions(tool_name) {
+    /// Returns `Ok(())` if allowed, or the [`AuthzDenial`] if denied.
+    fn check_and_log_permissions(&self, tool_name: &str) -> Result<(), AuthzDenial> {
+        match self.authorize_tool(tool_name) {
             Ok(()) => {
                 // Record RBAC allowed metric
                 if let Some(registry) = skreaver_observability::get_metrics_registry() {
@@ -173,10 +236,11 @@ impl<T: ToolRegistry> SecureToolRegistry<T> {
                 }
                 Ok(())
             }
-            Err(error) => {
+            Err(denial) => {
                 tracing::warn!(
                     tool_name = tool_name,
-                    error = %error,
+                    error = %denial,
+                    remedy = %denial.remedy(),
                     "Tool execution blocked by RBAC policy"
                 );
 
@@ -189,17 +253,73 @@ impl<T: ToolRegistry> SecureToolRegistry<T> {
                         .inc();
                 }
 
-                Err(ExecutionResult::failure(error))
+                Err(denial)
             }
         }
     }
 
+    /// Check permissions and the tool's circuit breaker before a call.
+    ///
+    /// Returns the call start time, used to detect slow calls, or the failure
+    /// to report if the call is rejected.
+    fn admit(&self, tool_name: &str) -> Result<Instant, ExecutionResult> {
+        self.check_and_log_permissions(tool_name)
+            .map_err(|denial| ExecutionResult::Failure {
+                reason: FailureReason::PermissionDenied {
+                    message: denial.message(),
+                },
+            })?;
+        self.admit_breaker(tool_name)
+    }
+
+    /// Check the tool's circuit breaker before an authorized call.
+    fn admit_breaker(&self, tool_name: &str) -> Result<Instant, ExecutionResult> {
+        if let Some(breakers) = &self.circuit_breakers {
+            breakers.admit(tool_name)?;
+        }
+        Ok(Instant::now())
+    }
+
+    /// Copy the input of a call that will be logged, before it is consumed.
+    fn logged_input(&self, call: &ToolCall) -> Option<String> {
+        self.execution_logger
+            .as_ref()
+            .filter(|logger| logger.is_enabled(call.name()))
+            .map(|_| call.input.clone())
+    }
+
+    /// Feed the outcome of an admitted call into the tool's circuit breaker
+    /// and execution log.
+    fn record_outcome(
+        &self,
+        tool_name: &str,
+        input: Option<&str>,
+        started: Instant,
+        result: Option<&ExecutionResult>,
+    ) {
+        let elapsed = started.elapsed();
+        if let (Some(logger), Some(input)) = (&self.execution_logger, input) {
+            logger.log(tool_name, input, result, elapsed);
+        }
+        let Some(breakers) = &self.circuit_breakers else {
+            return;
+        };
+        match result {
+            Some(result) => breakers.record(tool_name, result, elapsed),
+            None => breakers.release(tool_name),
+        }
+    }
+
     /// Execute a tool call after checking permissions, returning the failure result if denied.
     fn dispatch_single(&self, call: &ToolCall) -> ExecutionResult {
-        match self.check_and_log_permissions(call.name()) {
-            Ok(()) => self.inner.dispatch_ref(call).unwrap_or_else(|| {
-                ExecutionResult::failure(format!("Tool not found: {}", call.name()))
-            }),
+        match self.admit(call.name()) {
+            Ok(started) => {
+                let result = self.inner.dispatch_ref(call);
+                self.record_outcome(call.name(), Some(&call.input), started, result.as_ref());
+                result.unwrap_or_else(|| {
+                    ExecutionResult::failure(format!("Tool not found: {}", call.name()))
+                })
+            }
             Err(failure) => failure,
         }
     }
@@ -207,24 +327,40 @@ impl<T: ToolRegistry> SecureToolRegistry<T> {
 
 impl<T: ToolRegistry> ToolRegistry for SecureToolRegistry<T> {
     fn dispatch(&self, call: ToolCall) -> Option<ExecutionResult> {
-        if let Err(failure) = self.check_and_log_permissions(call.name()) {
-            return Some(failure);
-        }
-        self.inner.dispatch(call)
+        let started = match self.admit(call.name()) {
+            Ok(started) => started,
+            Err(failure) => return Some(failure),
+        };
+        let tool_name = call.name().to_string();
+        let input = self.logged_input(&call);
+        let result = self.inner.dispatch(call);
+        self.record_outcome(&tool_name, input.as_deref(), started, result.as_ref());
+        result
     }
 
     fn dispatch_ref(&self, call: &ToolCall) -> Option<ExecutionResult> {
-        if let Err(failure) = self.check_and_log_permissions(call.name()) {
-            return Some(failure);
-        }
-        self.inner.dispatch_ref(call)
+        let started = match self.admit(call.name()) {
+            Ok(started) => started,
+            Err(failure) => return Some(failure),
+        };
+        let result = self.inner.dispatch_ref(call);
+        self.record_outcome(call.name(), Some(&call.input), started, result.as_ref());
+        result
     }
 
     fn try_dispatch(&self, call: &ToolCall) -> Result<ExecutionResult, String> {
-        if let Err(failure) = self.check_and_log_permissions(call.name()) {
-            return Ok(failure);
-        }
-        self.inner.try_dispatch(call)
+        let started = match self.admit(call.name()) {
+            Ok(started) => started,
+            Err(failure) => return Ok(failure),
+        };
+        let result = self.inner.try_dispatch(call);
+        self.record_outcome(
+            call.name(),
+            Some(&call.input),
+            started,
+            result.as_ref().ok(),
+        );
+        result
     }
 
     fn dispatch_batch(&self, calls: &NonEmptyVec<ToolCall>) -> NonEmptyVec<ExecutionResult> {
@@ -241,22 +377,33 @@ impl<T: ToolRegistry> ToolRegistry for SecureToolRegistry<T> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::circuit_breaker::ToolCircuitState;
     use crate::{InMemoryToolRegistry, Tool};
-    use skreaver_core::auth::rbac::RoleManager;
+    use skreaver_core::auth::rbac::{RoleManager, ToolPolicy};
     use skreaver_core::security::policy::ToolSecurityPolicy;
     use std::collections::HashMap;
 
     struct TestTool;
 
+    struct FailingTool;
+
+    impl Tool for FailingTool {
+        fn name(&self) -> &str {
+            "flaky_tool"
+        }
+
+        fn call(&self, _input: String) -> ExecutionResult {
+            ExecutionResult::failure("upstream unavailable".to_string())
+        }
+    }
+
     impl Tool for TestTool {
         fn name(&self) -> &str {
             "test_tool"
         }
 
         fn call(&self, input: String) -> ExecutionResult {
-            ExecutionResult::Success {
-                output: format!("Executed: {}", input),
-            }
+            ExecutionResult::success(format!("Executed: {}", input))
         }
     }
 
@@ -267,6 +414,7 @@ mod tests {
         manager.add_default_allow_policy("test_tool");
         manager.add_default_allow_policy("allowed_tool");
         manager.add_default_allow_policy("blocked_tool");
+        manager.add_default_allow_policy("flaky_tool");
         manager
     }
 
@@ -284,7 +432,7 @@ mod tests {
 
         assert!(result.is_some());
         match result.unwrap() {
-            ExecutionResult::Success { output } => {
+            ExecutionResult::Success { output, .. } => {
                 assert_eq!(output, "Executed: hello");
             }
             _ => panic!("Expected success"),
@@ -402,4 +550,217 @@ mod tests {
             _ => panic!("Expected permission failure for blocked_tool"),
         }
     }
+
+    #[test]
+    fn test_circuit_breaker_isolates_failing_tool() {
+        let registry = InMemoryToolRegistry::new()
+            .with_tool("flaky_tool", Arc::new(FailingTool))
+            .with_tool("test_tool", Arc::new(TestTool));
+
+        let config = Arc::new(SecurityConfig::create_default());
+        let role_manager = Arc::new(create_test_role_manager());
+        let secure_registry = SecureToolRegistry::new(registry, config, role_manager)
+            .with_circuit_breaker(
+                ToolCircuitBreakerConfig::default()
+                    .with_failure_threshold(2)
+                    .with_open_duration(std::time::Duration::from_secs(60)),
+            );
+
+        for _ in 0..2 {
+            let result = secure_registry
+                .dispatch(ToolCall::new("flaky_tool", "x").expect("Valid tool name"))
+                .unwrap();
+            assert!(result.output().contains("upstream unavailable"));
+        }
+
+        let breakers = secure_registry.circuit_breakers().unwrap();
+        assert_eq!(breakers.state("flaky_tool"), ToolCircuitState::Open);
+
+        // Open breaker fast-fails without calling the tool
+        let rejected = secure_registry
+            .dispatch(ToolCall::new("flaky_tool", "x").expect("Valid tool name"))
+            .unwrap();
+        let message = rejected.output();
+        assert!(message.contains("Circuit breaker is open"));
+        assert!(message.contains("flaky_tool"));
+        assert_eq!(breakers.status("flaky_tool").total_rejections, 1);
+
+        // Other tools keep working
+        let ok = secure_registry
+            .dispatch(ToolCall::new("test_tool", "hello").expect("Valid tool name"))
+            .unwrap();
+        assert_eq!(ok.output(), "Executed: hello");
+        assert_eq!(breakers.state("test_tool"), ToolCircuitState::Closed);
+    }
+
+    #[test]
+    fn test_circuit_breaker_half_open_recovery() {
+        let registry = InMemoryToolRegistry::new().with_tool("test_tool", Arc::new(TestTool));
+
+        let config = Arc::new(SecurityConfig::create_default());
+        let role_manager = Arc::new(create_test_role_manager());
+        let secure_registry = SecureToolRegistry::new(registry, config, role_manager)
+            .with_circuit_breaker(
+                ToolCircuitBreakerConfig::default().with_open_duration(std::time::Duration::ZERO),
+            );
+
+        let breakers = secure_registry.circuit_breakers().unwrap();
+        for _ in 0..5 {
+            breakers.record_failure("test_tool", "timeout");
+        }
+        assert_eq!(breakers.state("test_tool"), ToolCircuitState::Open);
+
+        // Open window elapsed: the trial call goes through and closes the breaker
+        let result = secure_registry
+            .dispatch(ToolCall::new("test_tool", "again").expect("Valid tool name"))
+            .unwrap();
+        assert!(result.is_success());
+        assert_eq!(breakers.state("test_tool"), ToolCircuitState::Closed);
+    }
+
+    /// Run `f` with a subscriber capturing execution log events as text
+    fn capture_execution_logs(f: impl FnOnce()) -> String {
+        use std::sync::Mutex;
+
+        #[derive(Clone, Default)]
+        struct Buffer(Arc<Mutex<Vec<u8>>>);
+
+        impl std::io::Write for Buffer {
+            fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+                self.0.lock().unwrap().extend_from_slice(buf);
+                Ok(buf.len())
+            }
+
+            fn flush(&mut self) -> std::io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let buffer = Buffer::default();
+        let writer = buffer.clone();
+        let subscriber = tracing_subscriber::fmt()
+            .with_max_level(tracing::Level::TRACE)
+            .with_ansi(false)
+            .with_writer(move || writer.clone())
+            .finish();
+        tracing::subscriber::with_default(subscriber, f);
+
+        let output = String::from_utf8(buffer.0.lock().unwrap().clone()).unwrap();
+        output
+            .lines()
+            .filter(|line| line.contains(crate::EXECUTION_LOG_TARGET))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    fn logging_registry() -> SecureToolRegistry<InMemoryToolRegistry> {
+        let registry = InMemoryToolRegistry::new()
+            .with_tool("test_tool", Arc::new(TestTool))
+            .with_tool("allowed_tool", Arc::new(TestTool));
+        SecureToolRegistry::new(
+            registry,
+            Arc::new(SecurityConfig::create_default()),
+            Arc::new(create_test_role_manager()),
+        )
+        .with_execution_logging(
+            ToolExecutionLogConfig::new()
+                .enable_tool("test_tool")
+                .with_level(tracing::Level::INFO),
+        )
+    }
+
+    #[test]
+    fn test_execution_logging_redacts_enabled_tool() {
+        let secure_registry = logging_registry();
+
+        let logs = capture_execution_logs(|| {
+            let result = secure_registry.dispatch(
+                ToolCall::new("test_tool", "api_key=s3cr3t user=bob@example.com")
+                    .expect("Valid tool name"),
+            );
+            assert!(matches!(result, Some(ExecutionResult::Success { .. })));
+        });
+
+        assert_eq!(logs.lines().count(), 1, "logs: {logs}");
+        assert!(logs.contains("INFO"));
+        assert!(logs.contains("tool_name=\"test_tool\""));
+        assert!(logs.contains("api_key=***"));
+        assert!(logs.contains("[REDACTED]"));
+        assert!(logs.contains("duration_ms="));
+        assert!(!logs.contains("s3cr3t"));
+        assert!(!logs.contains("bob@example.com"));
+    }
+
+    #[test]
+    fn test_execution_logging_skips_disabled_tool() {
+        let secure_registry = logging_registry();
+
+        let logs = capture_execution_logs(|| {
+            let result = secure_registry
+                .dispatch(ToolCall::new("allowed_tool", "hello").expect("Valid tool name"));
+            assert!(matches!(result, Some(ExecutionResult::Success { .. })));
+        });
+
+        assert!(logs.is_empty(), "logs: {logs}");
+    }
+
+    #[test]
+    fn test_authorize_tool_distinguishes_role_and_lockdown_denials() {
+        let mut role_manager = create_test_role_manager();
+        role_manager
+            .add_tool_policy(ToolPolicy::new("admin_tool".to_string()).require_role(Role::Admin));
+        let role_manager = Arc::new(role_manager);
+
+        let registry = InMemoryToolRegistry::new().with_tool("admin_tool", Arc::new(TestTool));
+        let secure_registry = SecureToolRegistry::new(
+            registry,
+            Arc::new(SecurityConfig::create_default()),
+            role_manager.clone(),
+        );
+        let role_denial = secure_registry.authorize_tool("admin_tool").unwrap_err();
+        assert_eq!(
+            role_denial.reason,
+            DenialReason::MissingRole {
+                required_any: vec![Role::Admin]
+            }
+        );
+
+        let mut config = SecurityConfig::create_default();
+        config.emergency.lockdown_enabled = true;
+        config.emergency.lockdown_allowed_tools = vec!["test_tool".to_string()];
+        let locked_registry = SecureToolRegistry::new(
+            InMemoryToolRegistry::new().with_tool("admin_tool", Arc::new(TestTool)),
+            Arc::new(config),
+            role_manager,
+        );
+        let lockdown_denial = locked_registry.authorize_tool("admin_tool").unwrap_err();
+        assert_eq!(
+            lockdown_denial.reason,
+            DenialReason::Lockdown {
+                allowed_tools: vec!["test_tool".to_string()]
+            }
+        );
+
+        assert_ne!(role_denial.reason, lockdown_denial.reason);
+        assert_ne!(role_denial.remedy(), lockdown_denial.remedy());
+
+        // Denied dispatches surface as permission failures
+        let result = secure_registry
+            .dispatch(ToolCall::new("admin_tool", "hello").expect("Valid tool name"))
+            .unwrap();
+        assert!(matches!(
+            result.failure_reason(),
+            Some(FailureReason::PermissionDenied { .. })
+        ));
+
+        // ...while dispatch_authorized keeps the structured denial
+        let denial = secure_registry
+            .dispatch_authorized(ToolCall::new("admin_tool", "hello").expect("Valid tool name"))
+            .unwrap_err();
+        assert_eq!(denial, role_denial);
+        let allowed = locked_registry
+            .dispatch_authorized(ToolCall::new("test_tool", "hello").expect("Valid tool name"))
+            .unwrap();
+        assert!(allowed.is_none(), "test_tool is not registered there");
+    }
 }
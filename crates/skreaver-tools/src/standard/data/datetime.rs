@@ -0,0 +1,465 @@
+//! # Date and Time Tool
+//!
+//! This module provides a tool for parsing, formatting and doing arithmetic on
+//! instants. Every instant in a result is rendered as RFC 3339. Inputs without
+//! an offset are only accepted together with an explicit `timezone`, so a
+//! local wall-clock time is never silently interpreted as UTC.
+
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, TimeDelta, TimeZone};
+use serde::{Deserialize, Serialize};
+use skreaver_core::{ExecutionResult, Tool};
+use std::time::Duration;
+
+/// Formats tried, in order, for inputs that carry a UTC offset
+const OFFSET_FORMATS: &[&str] = &[
+    "%Y-%m-%d %H:%M:%S%.f %:z",
+    "%Y-%m-%d %H:%M:%S%.f%:z",
+    "%Y-%m-%d %H:%M:%S %z",
+    "%Y-%m-%d %H:%M %:z",
+];
+
+/// Formats tried, in order, for wall-clock inputs without an offset
+const NAIVE_FORMATS: &[&str] = &[
+    "%Y-%m-%dT%H:%M:%S%.f",
+    "%Y-%m-%d %H:%M:%S%.f",
+    "%Y-%m-%dT%H:%M",
+    "%Y-%m-%d %H:%M",
+    "%d/%m/%Y %H:%M:%S",
+    "%d/%m/%Y %H:%M",
+];
+
+/// Formats tried, in order, for date-only inputs (interpreted as midnight)
+const DATE_FORMATS: &[&str] = &["%Y-%m-%d", "%d/%m/%Y", "%B %d, %Y", "%d %B %Y"];
+
+/// Duration units without a fixed length
+const CALENDAR_UNITS: &[&str] = &["M", "month", "months", "y", "year", "years"];
+
+/// Operation performed by the date/time tool
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DateTimeOperation {
+    /// Parse `input` into an instant
+    Parse,
+    /// Render `input` with a strftime `format`
+    Format,
+    /// Add `duration` to `input`
+    Add,
+    /// Subtract `duration` from `input`
+    Subtract,
+    /// Compute `end - input`
+    Diff,
+}
+
+/// Configuration for the date/time tool
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct DateTimeConfig {
+    /// Operation to perform
+    pub operation: DateTimeOperation,
+    /// Instant to operate on
+    pub input: String,
+    /// Timezone used for inputs without an offset and for the result:
+    /// `UTC`, `Z` or a fixed offset such as `+02:00`
+    #[serde(default)]
+    pub timezone: Option<String>,
+    /// strftime format used to parse `input` (`parse`) or render the result (`format`)
+    #[serde(default)]
+    pub format: Option<String>,
+    /// Duration for `add` and `subtract`, such as `1h 30m` or `2days`
+    #[serde(default)]
+    pub duration: Option<String>,
+    /// Second instant for `diff`
+    #[serde(default)]
+    pub end: Option<String>,
+}
+
+/// Date/time parsing, formatting and arithmetic tool
+#[derive(Debug)]
+pub struct DateTimeTool;
+
+impl DateTimeTool {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for DateTimeTool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Tool for DateTimeTool {
+    fn name(&self) -> &str {
+        "datetime"
+    }
+
+    fn call(&self, input: String) -> ExecutionResult {
+        let config: DateTimeConfig = match serde_json::from_str(&input) {
+            Ok(config) => config,
+            Err(e) => return ExecutionResult::failure(format!("Invalid config JSON: {}", e)),
+        };
+
+        match run(&config) {
+            Ok(result) => ExecutionResult::success(result.to_string()),
+            Err(e) => ExecutionResult::failure(e),
+        }
+    }
+}
+
+fn run(config: &DateTimeConfig) -> Result<serde_json::Value, String> {
+    let timezone = config.timezone.as_deref().map(parse_timezone).transpose()?;
+    let format = config.format.as_deref();
+    let parse_format = match config.operation {
+        DateTimeOperation::Parse => format,
+        _ => None,
+    };
+    let instant = parse_instant(&config.input, parse_format, timezone)?;
+
+    let result = match config.operation {
+        DateTimeOperation::Parse => serde_json::json!({
+            "datetime": instant.to_rfc3339(),
+            "timestamp": instant.timestamp(),
+            "offset": instant.offset().to_string(),
+            "success": true
+        }),
+        DateTimeOperation::Format => {
+            let format = format.ok_or("Operation 'format' requires a 'format'")?;
+            serde_json::json!({
+                "formatted": render(&instant, format)?,
+                "datetime": instant.to_rfc3339(),
+                "success": true
+            })
+        }
+        DateTimeOperation::Add | DateTimeOperation::Subtract => {
+            let operation = config.operation;
+            let duration = config.duration.as_deref().ok_or_else(|| {
+                format!(
+                    "Operation '{}' requires a 'duration'",
+                    operation_name(operation)
+                )
+            })?;
+            let delta = parse_duration(duration)?;
+            let shifted = match operation {
+                DateTimeOperation::Add => instant.checked_add_signed(delta),
+                _ => instant.checked_sub_signed(delta),
+            }
+            .ok_or_else(|| format!("Result of shifting '{}' is out of range", config.input))?;
+            serde_json::json!({
+                "datetime": shifted.to_rfc3339(),
+                "timestamp": shifted.timestamp(),
+                "success": true
+            })
+        }
+        DateTimeOperation::Diff => {
+            let end = config
+                .end
+                .as_deref()
+                .ok_or("Operation 'diff' requires an 'end'")?;
+            let end = parse_instant(end, None, timezone)?;
+            let delta = end.signed_duration_since(instant);
+            serde_json::json!({
+                "start": instant.to_rfc3339(),
+                "end": end.to_rfc3339(),
+                "seconds": delta.num_seconds(),
+                "milliseconds": delta.num_milliseconds(),
+                "duration": describe_delta(delta),
+                "success": true
+            })
+        }
+    };
+    Ok(result)
+}
+
+fn operation_name(operation: DateTimeOperation) -> &'static str {
+    match operation {
+        DateTimeOperation::Parse => "parse",
+        DateTimeOperation::Format => "format",
+        DateTimeOperation::Add => "add",
+        DateTimeOperation::Subtract => "subtract",
+        DateTimeOperation::Diff => "diff",
+    }
+}
+
+/// Parse a timezone as `UTC`, `Z` or a fixed `±HH:MM` / `±HHMM` offset
+fn parse_timezone(timezone: &str) -> Result<FixedOffset, String> {
+    let trimmed = timezone.trim();
+    if trimmed.eq_ignore_ascii_case("utc") || trimmed.eq_ignore_ascii_case("z") {
+        return Ok(FixedOffset::east_opt(0).expect("zero offset is valid"));
+    }
+
+    let unsupported = || {
+        format!(
+            "Unsupported timezone '{}', expected UTC or a fixed offset such as +02:00",
+            timezone
+        )
+    };
+    let (sign, rest) = match trimmed.as_bytes().first() {
+        Some(b'+') => (1, &trimmed[1..]),
+        Some(b'-') => (-1, &trimmed[1..]),
+        _ => return Err(unsupported()),
+    };
+    let digits: String = rest.chars().filter(|c| *c != ':').collect();
+    if digits.len() != 4 || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(unsupported());
+    }
+    let hours: i32 = digits[..2].parse().map_err(|_| unsupported())?;
+    let minutes: i32 = digits[2..].parse().map_err(|_| unsupported())?;
+    if minutes >= 60 {
+        return Err(unsupported());
+    }
+    FixedOffset::east_opt(sign * (hours * 3600 + minutes * 60)).ok_or_else(unsupported)
+}
+
+/// Parse an instant, converting it to `timezone` when one is given
+///
+/// Inputs with an offset are accepted as-is; inputs without one require a
+/// `timezone` and are rejected as ambiguous otherwise.
+fn parse_instant(
+    input: &str,
+    format: Option<&str>,
+    timezone: Option<FixedOffset>,
+) -> Result<DateTime<FixedOffset>, String> {
+    let input = input.trim();
+    if input.is_empty() {
+        return Err("Input date/time is empty".to_string());
+    }
+
+    let with_offset = match format {
+        Some(format) => DateTime::parse_from_str(input, format).ok(),
+        None => DateTime::parse_from_rfc3339(input)
+            .or_else(|_| DateTime::parse_from_rfc2822(input))
+            .ok()
+            .or_else(|| {
+                OFFSET_FORMATS
+                    .iter()
+                    .find_map(|format| DateTime::parse_from_str(input, format).ok())
+            }),
+    };
+    if let Some(instant) = with_offset {
+        return Ok(match timezone {
+            Some(timezone) => instant.with_timezone(&timezone),
+            None => instant,
+        });
+    }
+
+    let naive = match format {
+        Some(format) => NaiveDateTime::parse_from_str(input, format)
+            .ok()
+            .or_else(|| {
+                NaiveDate::parse_from_str(input, format)
+                    .ok()
+                    .map(|date| date.and_time(chrono::NaiveTime::MIN))
+            }),
+        None => NAIVE_FORMATS
+            .iter()
+            .find_map(|format| NaiveDateTime::parse_from_str(input, format).ok())
+            .or_else(|| {
+                DATE_FORMATS.iter().find_map(|format| {
+                    NaiveDate::parse_from_str(input, format)
+                        .ok()
+                        .map(|date| date.and_time(chrono::NaiveTime::MIN))
+                })
+            }),
+    };
+
+    let Some(naive) = naive else {
+        return Err(match format {
+            Some(format) => format!("Could not parse '{}' with format '{}'", input, format),
+            None => format!(
+                "Could not parse '{}' as a date/time; use RFC 3339 (e.g. 2024-03-10T14:30:00+02:00) or provide a 'format'",
+                input
+            ),
+        });
+    };
+    let Some(timezone) = timezone else {
+        return Err(format!(
+            "Ambiguous date/time '{}' has no UTC offset; provide a 'timezone' such as UTC or +02:00",
+            input
+        ));
+    };
+    timezone
+        .from_local_datetime(&naive)
+        .single()
+        .ok_or_else(|| {
+            format!(
+                "Date/time '{}' does not exist in timezone {}",
+                input, timezone
+            )
+        })
+}
+
+/// Render `instant` with a strftime format, rejecting invalid specifiers
+fn render(instant: &DateTime<FixedOffset>, format: &str) -> Result<String, String> {
+    use std::fmt::Write;
+
+    let mut rendered = String::new();
+    write!(rendered, "{}", instant.format(format))
+        .map_err(|_| format!("Invalid format string '{}'", format))?;
+    Ok(rendered)
+}
+
+/// Parse a duration such as `1h 30m`, `2days` or `-15min`
+fn parse_duration(duration: &str) -> Result<TimeDelta, String> {
+    let trimmed = duration.trim();
+    let (negative, magnitude) = match trimmed.strip_prefix('-') {
+        Some(rest) => (true, rest.trim_start()),
+        None => (false, trimmed),
+    };
+    let calendar_unit = magnitude
+        .split(|c: char| !c.is_ascii_alphabetic())
+        .any(|unit| CALENDAR_UNITS.contains(&unit));
+    if calendar_unit {
+        return Err(format!(
+            "Ambiguous duration '{}': months and years have no fixed length; use days or weeks",
+            duration
+        ));
+    }
+    let parsed: Duration = humantime::parse_duration(magnitude).map_err(|e| {
+        format!(
+            "Invalid duration '{}': {}; use units such as 30s, 15m, 2h, 1d or 1w",
+            duration, e
+        )
+    })?;
+    let delta = TimeDelta::from_std(parsed)
+        .map_err(|_| format!("Duration '{}' is out of range", duration))?;
+    Ok(if negative { -delta } else { delta })
+}
+
+/// Human-readable form of a signed duration (`-1day 2h`)
+fn describe_delta(delta: TimeDelta) -> String {
+    let magnitude = delta.abs().to_std().unwrap_or_default();
+    let described = humantime::format_duration(magnitude).to_string();
+    if delta < TimeDelta::zero() {
+        format!("-{}", described)
+    } else {
+        described
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::{Value as JsonValue, json};
+
+    fn call(input: JsonValue) -> ExecutionResult {
+        DateTimeTool::new().call(input.to_string())
+    }
+
+    fn output(input: JsonValue) -> JsonValue {
+        let result = call(input);
+        assert!(
+            result.is_success(),
+            "unexpected failure: {}",
+            result.output()
+        );
+        serde_json::from_str(&result.output()).unwrap()
+    }
+
+    #[test]
+    fn test_parse_timezone_aware_input() {
+        let result = output(json!({
+            "operation": "parse",
+            "input": "2024-03-10T14:30:00+02:00"
+        }));
+        assert_eq!(result["datetime"], "2024-03-10T14:30:00+02:00");
+        assert_eq!(result["timestamp"], 1_710_073_800);
+        assert_eq!(result["offset"], "+02:00");
+
+        let converted = output(json!({
+            "operation": "parse",
+            "input": "Sun, 10 Mar 2024 14:30:00 +0200",
+            "timezone": "UTC"
+        }));
+        assert_eq!(converted["datetime"], "2024-03-10T12:30:00+00:00");
+
+        let naive = output(json!({
+            "operation": "parse",
+            "input": "10/03/2024 14:30",
+            "timezone": "-05:00"
+        }));
+        assert_eq!(naive["datetime"], "2024-03-10T14:30:00-05:00");
+    }
+
+    #[test]
+    fn test_add_duration() {
+        let result = output(json!({
+            "operation": "add",
+            "input": "2024-02-28T22:00:00Z",
+            "duration": "1day 3h 15m"
+        }));
+        assert_eq!(result["datetime"], "2024-03-01T01:15:00+00:00");
+
+        let result = output(json!({
+            "operation": "subtract",
+            "input": "2024-03-01T01:15:00+01:00",
+            "duration": "90min"
+        }));
+        assert_eq!(result["datetime"], "2024-02-29T23:45:00+01:00");
+    }
+
+    #[test]
+    fn test_diff_between_instants() {
+        let result = output(json!({
+            "operation": "diff",
+            "input": "2024-03-10T14:30:00+02:00",
+            "end": "2024-03-11T13:00:30Z"
+        }));
+        assert_eq!(result["start"], "2024-03-10T14:30:00+02:00");
+        assert_eq!(result["seconds"], 88_230);
+        assert_eq!(result["milliseconds"], 88_230_000);
+        assert_eq!(result["duration"], "1day 30m 30s");
+    }
+
+    #[test]
+    fn test_format_output() {
+        let result = output(json!({
+            "operation": "format",
+            "input": "2024-03-10T14:30:00Z",
+            "timezone": "+05:30",
+            "format": "%d %B %Y %H:%M"
+        }));
+        assert_eq!(result["formatted"], "10 March 2024 20:00");
+        assert_eq!(result["datetime"], "2024-03-10T20:00:00+05:30");
+    }
+
+    #[test]
+    fn test_ambiguous_and_invalid_inputs_fail_clearly() {
+        let cases = [
+            (
+                json!({"operation": "parse", "input": "2024-03-10 14:30"}),
+                "Ambiguous date/time",
+            ),
+            (
+                json!({"operation": "parse", "input": "next tuesday"}),
+                "Could not parse 'next tuesday'",
+            ),
+            (
+                json!({"operation": "parse", "input": "2024-03-10", "timezone": "Europe/Kyiv"}),
+                "Unsupported timezone 'Europe/Kyiv'",
+            ),
+            (
+                json!({"operation": "add", "input": "2024-03-10T00:00:00Z", "duration": "1month"}),
+                "Ambiguous duration '1month'",
+            ),
+            (
+                json!({"operation": "add", "input": "2024-03-10T00:00:00Z", "duration": "soon"}),
+                "Invalid duration 'soon'",
+            ),
+            (
+                json!({"operation": "diff", "input": "2024-03-10T00:00:00Z"}),
+                "requires an 'end'",
+            ),
+        ];
+
+        for (input, expected) in cases {
+            let result = call(input);
+            assert!(!result.is_success());
+            assert!(
+                result.output().contains(expected),
+                "expected '{}' in '{}'",
+                expected,
+                result.output()
+            );
+        }
+    }
+}
@@ -2,12 +2,18 @@
 //!
 //! This module provides tools for data transformation, parsing, and text processing.
 
+/// Date and time parsing, formatting and arithmetic.
+pub mod datetime;
 /// JSON and XML data processing tools.
 pub mod json;
+/// JSON diff, patch and merge tools.
+pub mod patch;
 /// Text processing and manipulation tools.
 pub mod text;
 
+pub use datetime::DateTimeTool;
 pub use json::{JsonParseTool, JsonTransformTool, XmlParseTool};
+pub use patch::{JsonDiffTool, JsonMergeTool};
 pub use text::{
     TextAnalyzeTool, TextReverseTool, TextSearchTool, TextSplitTool, TextUppercaseTool,
 };
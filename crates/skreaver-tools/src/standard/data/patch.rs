@@ -0,0 +1,584 @@
+//! # JSON Diff and Merge Tools
+//!
+//! This module provides tools for comparing and combining JSON documents:
+//! computing an RFC 6902 JSON Patch between two documents, applying a patch,
+//! and deep-merging two documents with a configurable array strategy.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+use skreaver_core::{ExecutionResult, Tool};
+
+/// A single RFC 6902 JSON Patch operation
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(tag = "op", rename_all = "lowercase", deny_unknown_fields)]
+pub enum PatchOperation {
+    Add { path: String, value: JsonValue },
+    Remove { path: String },
+    Replace { path: String, value: JsonValue },
+    Move { from: String, path: String },
+    Copy { from: String, path: String },
+    Test { path: String, value: JsonValue },
+}
+
+/// How arrays are combined when deep-merging two documents
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ArrayMergeStrategy {
+    /// The array from the merged document replaces the original array
+    #[default]
+    Replace,
+    /// The array from the merged document is appended to the original array
+    Concat,
+}
+
+/// Configuration for the JSON diff tool
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct JsonDiffConfig {
+    /// Original document
+    pub source: JsonValue,
+    /// Desired document
+    pub target: JsonValue,
+}
+
+/// Configuration for the JSON merge tool
+///
+/// Exactly one of `patch` or `merge` must be provided.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct JsonMergeConfig {
+    /// Document to patch or merge into
+    pub document: JsonValue,
+    /// RFC 6902 operations to apply to the document
+    #[serde(default)]
+    pub patch: Option<JsonValue>,
+    /// Document to deep-merge into the original
+    #[serde(default)]
+    pub merge: Option<JsonValue>,
+    /// Array handling for deep merges
+    #[serde(default)]
+    pub array_strategy: ArrayMergeStrategy,
+}
+
+/// JSON diff tool producing an RFC 6902 JSON Patch
+#[derive(Debug)]
+pub struct JsonDiffTool;
+
+impl JsonDiffTool {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for JsonDiffTool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Tool for JsonDiffTool {
+    fn name(&self) -> &str {
+        "json_diff"
+    }
+
+    fn call(&self, input: String) -> ExecutionResult {
+        let config: JsonDiffConfig = match serde_json::from_str(&input) {
+            Ok(config) => config,
+            Err(e) => return ExecutionResult::failure(format!("Invalid config JSON: {}", e)),
+        };
+
+        let patch = diff(&config.source, &config.target);
+        let result = serde_json::json!({
+            "patch": patch,
+            "operations": patch.len(),
+            "identical": patch.is_empty(),
+            "success": true
+        });
+        ExecutionResult::success(result.to_string())
+    }
+}
+
+/// JSON merge tool applying a JSON Patch or deep-merging two documents
+#[derive(Debug)]
+pub struct JsonMergeTool;
+
+impl JsonMergeTool {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for JsonMergeTool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Tool for JsonMergeTool {
+    fn name(&self) -> &str {
+        "json_merge"
+    }
+
+    fn call(&self, input: String) -> ExecutionResult {
+        let config: JsonMergeConfig = match serde_json::from_str(&input) {
+            Ok(config) => config,
+            Err(e) => return ExecutionResult::failure(format!("Invalid config JSON: {}", e)),
+        };
+
+        let (mode, merged) = match (config.patch, config.merge) {
+            (Some(patch), None) => {
+                let operations: Vec<PatchOperation> = match serde_json::from_value(patch) {
+                    Ok(operations) => operations,
+                    Err(e) => return ExecutionResult::failure(format!("Invalid patch: {}", e)),
+                };
+                match apply_patch(&config.document, &operations) {
+                    Ok(patched) => ("patch", patched),
+                    Err(e) => return ExecutionResult::failure(format!("Invalid patch: {}", e)),
+                }
+            }
+            (None, Some(other)) => (
+                "merge",
+                deep_merge(config.document, other, config.array_strategy),
+            ),
+            _ => {
+                return ExecutionResult::failure(
+                    "Exactly one of 'patch' or 'merge' must be provided".to_string(),
+                );
+            }
+        };
+
+        let result = serde_json::json!({
+            "result": merged,
+            "mode": mode,
+            "success": true
+        });
+        ExecutionResult::success(result.to_string())
+    }
+}
+
+/// Compute the operations that turn `source` into `target`
+fn diff(source: &JsonValue, target: &JsonValue) -> Vec<PatchOperation> {
+    let mut operations = Vec::new();
+    diff_into(source, target, String::new(), &mut operations);
+    operations
+}
+
+fn diff_into(
+    source: &JsonValue,
+    target: &JsonValue,
+    path: String,
+    operations: &mut Vec<PatchOperation>,
+) {
+    if source == target {
+        return;
+    }
+
+    match (source, target) {
+        (JsonValue::Object(from), JsonValue::Object(to)) => {
+            for (key, value) in from {
+                let child = format!("{}/{}", path, escape_token(key));
+                match to.get(key) {
+                    Some(other) => diff_into(value, other, child, operations),
+                    None => operations.push(PatchOperation::Remove { path: child }),
+                }
+            }
+            for (key, value) in to {
+                if !from.contains_key(key) {
+                    operations.push(PatchOperation::Add {
+                        path: format!("{}/{}", path, escape_token(key)),
+                        value: value.clone(),
+                    });
+                }
+            }
+        }
+        (JsonValue::Array(from), JsonValue::Array(to)) => {
+            let common = from.len().min(to.len());
+            for index in 0..common {
+                diff_into(
+                    &from[index],
+                    &to[index],
+                    format!("{}/{}", path, index),
+                    operations,
+                );
+            }
+            // Remove from the end so earlier indices stay valid
+            for index in (common..from.len()).rev() {
+                operations.push(PatchOperation::Remove {
+                    path: format!("{}/{}", path, index),
+                });
+            }
+            for (index, value) in to.iter().enumerate().skip(common) {
+                operations.push(PatchOperation::Add {
+                    path: format!("{}/{}", path, index),
+                    value: value.clone(),
+                });
+            }
+        }
+        _ => operations.push(PatchOperation::Replace {
+            path,
+            value: target.clone(),
+        }),
+    }
+}
+
+/// Apply a patch to a copy of `document`
+///
+/// Operations are applied in order; the first failing operation aborts the
+/// whole patch and the original document is left untouched.
+fn apply_patch(document: &JsonValue, operations: &[PatchOperation]) -> Result<JsonValue, String> {
+    let mut patched = document.clone();
+    for (index, operation) in operations.iter().enumerate() {
+        apply_operation(&mut patched, operation)
+            .map_err(|e| format!("operation {} failed: {}", index, e))?;
+    }
+    Ok(patched)
+}
+
+fn apply_operation(document: &mut JsonValue, operation: &PatchOperation) -> Result<(), String> {
+    match operation {
+        PatchOperation::Add { path, value } => add(document, &parse_pointer(path)?, value.clone()),
+        PatchOperation::Remove { path } => remove(document, &parse_pointer(path)?).map(|_| ()),
+        PatchOperation::Replace { path, value } => {
+            let target = resolve_mut(document, &parse_pointer(path)?)
+                .ok_or_else(|| format!("path '{}' does not exist", path))?;
+            *target = value.clone();
+            Ok(())
+        }
+        PatchOperation::Move { from, path } => {
+            if path.starts_with(&format!("{}/", from)) {
+                return Err(format!(
+                    "cannot move '{}' into its own child '{}'",
+                    from, path
+                ));
+            }
+            let value = remove(document, &parse_pointer(from)?)?;
+            add(document, &parse_pointer(path)?, value)
+        }
+        PatchOperation::Copy { from, path } => {
+            let value = resolve(document, &parse_pointer(from)?)
+                .cloned()
+                .ok_or_else(|| format!("path '{}' does not exist", from))?;
+            add(document, &parse_pointer(path)?, value)
+        }
+        PatchOperation::Test { path, value } => match resolve(document, &parse_pointer(path)?) {
+            Some(actual) if actual == value => Ok(()),
+            Some(_) => Err(format!("test failed: value at '{}' does not match", path)),
+            None => Err(format!("path '{}' does not exist", path)),
+        },
+    }
+}
+
+fn add(document: &mut JsonValue, tokens: &[String], value: JsonValue) -> Result<(), String> {
+    let Some((last, parent)) = tokens.split_last() else {
+        *document = value;
+        return Ok(());
+    };
+
+    match resolve_mut(document, parent) {
+        Some(JsonValue::Object(map)) => {
+            map.insert(last.clone(), value);
+            Ok(())
+        }
+        Some(JsonValue::Array(items)) => {
+            let index = if last == "-" {
+                items.len()
+            } else {
+                parse_index(last)?
+            };
+            if index > items.len() {
+                return Err(format!("array index {} is out of bounds", index));
+            }
+            items.insert(index, value);
+            Ok(())
+        }
+        Some(_) => Err(format!("parent of '{}' is not a container", last)),
+        None => Err("parent path does not exist".to_string()),
+    }
+}
+
+fn remove(document: &mut JsonValue, tokens: &[String]) -> Result<JsonValue, String> {
+    let Some((last, parent)) = tokens.split_last() else {
+        return Err("cannot remove the document root".to_string());
+    };
+
+    match resolve_mut(document, parent) {
+        Some(JsonValue::Object(map)) => map
+            .remove(last)
+            .ok_or_else(|| format!("key '{}' does not exist", last)),
+        Some(JsonValue::Array(items)) => {
+            let index = parse_index(last)?;
+            if index >= items.len() {
+                return Err(format!("array index {} is out of bounds", index));
+            }
+            Ok(items.remove(index))
+        }
+        Some(_) => Err(format!("parent of '{}' is not a container", last)),
+        None => Err("parent path does not exist".to_string()),
+    }
+}
+
+fn resolve<'a>(document: &'a JsonValue, tokens: &[String]) -> Option<&'a JsonValue> {
+    tokens
+        .iter()
+        .try_fold(document, |current, token| match current {
+            JsonValue::Object(map) => map.get(token),
+            JsonValue::Array(items) => parse_index(token).ok().and_then(|i| items.get(i)),
+            _ => None,
+        })
+}
+
+fn resolve_mut<'a>(document: &'a mut JsonValue, tokens: &[String]) -> Option<&'a mut JsonValue> {
+    tokens
+        .iter()
+        .try_fold(document, |current, token| match current {
+            JsonValue::Object(map) => map.get_mut(token),
+            JsonValue::Array(items) => parse_index(token).ok().and_then(|i| items.get_mut(i)),
+            _ => None,
+        })
+}
+
+/// Split an RFC 6901 JSON Pointer into unescaped reference tokens
+fn parse_pointer(pointer: &str) -> Result<Vec<String>, String> {
+    if pointer.is_empty() {
+        return Ok(Vec::new());
+    }
+    let Some(rest) = pointer.strip_prefix('/') else {
+        return Err(format!("invalid JSON pointer '{}'", pointer));
+    };
+    Ok(rest
+        .split('/')
+        .map(|token| token.replace("~1", "/").replace("~0", "~"))
+        .collect())
+}
+
+fn escape_token(token: &str) -> String {
+    token.replace('~', "~0").replace('/', "~1")
+}
+
+fn parse_index(token: &str) -> Result<usize, String> {
+    if token.len() > 1 && token.starts_with('0') {
+        return Err(format!("invalid array index '{}'", token));
+    }
+    token
+        .parse::<usize>()
+        .map_err(|_| format!("invalid array index '{}'", token))
+}
+
+/// Recursively merge `other` into `base`
+///
+/// Objects are merged key by key, arrays follow `strategy`, and any other
+/// value in `other` replaces the value in `base`.
+fn deep_merge(base: JsonValue, other: JsonValue, strategy: ArrayMergeStrategy) -> JsonValue {
+    match (base, other) {
+        (JsonValue::Object(mut base), JsonValue::Object(other)) => {
+            for (key, value) in other {
+                let merged = match base.remove(&key) {
+                    Some(existing) => deep_merge(existing, value, strategy),
+                    None => value,
+                };
+                base.insert(key, merged);
+            }
+            JsonValue::Object(base)
+        }
+        (JsonValue::Array(mut base), JsonValue::Array(other)) => match strategy {
+            ArrayMergeStrategy::Replace => JsonValue::Array(other),
+            ArrayMergeStrategy::Concat => {
+                base.extend(other);
+                JsonValue::Array(base)
+            }
+        },
+        (_, other) => other,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn source() -> JsonValue {
+        json!({
+            "name": "agent",
+            "settings": {"retries": 3, "timeout": 30},
+            "tags": ["a", "b", "c"],
+            "version": 1
+        })
+    }
+
+    fn target() -> JsonValue {
+        json!({
+            "name": "agent",
+            "owner": "ops",
+            "settings": {"retries": 5},
+            "tags": ["a", "x"],
+            "version": "2"
+        })
+    }
+
+    fn call(tool: &dyn Tool, input: JsonValue) -> ExecutionResult {
+        tool.call(input.to_string())
+    }
+
+    #[test]
+    fn test_tool_names() {
+        assert_eq!(JsonDiffTool::new().name(), "json_diff");
+        assert_eq!(JsonMergeTool::new().name(), "json_merge");
+    }
+
+    #[test]
+    fn test_diff_produces_expected_patch() {
+        let result = call(
+            &JsonDiffTool::new(),
+            json!({"source": source(), "target": target()}),
+        );
+        assert!(result.is_success());
+
+        let output: JsonValue = serde_json::from_str(&result.output()).unwrap();
+        assert_eq!(
+            output["patch"],
+            json!([
+                {"op": "replace", "path": "/settings/retries", "value": 5},
+                {"op": "remove", "path": "/settings/timeout"},
+                {"op": "replace", "path": "/tags/1", "value": "x"},
+                {"op": "remove", "path": "/tags/2"},
+                {"op": "replace", "path": "/version", "value": "2"},
+                {"op": "add", "path": "/owner", "value": "ops"}
+            ])
+        );
+        assert_eq!(output["operations"], 6);
+        assert_eq!(output["identical"], false);
+    }
+
+    #[test]
+    fn test_diff_identical_documents() {
+        let result = call(
+            &JsonDiffTool::new(),
+            json!({"source": source(), "target": source()}),
+        );
+        let output: JsonValue = serde_json::from_str(&result.output()).unwrap();
+        assert_eq!(output["patch"], json!([]));
+        assert_eq!(output["identical"], true);
+    }
+
+    #[test]
+    fn test_applying_diff_reconstructs_target() {
+        let pairs = [
+            (source(), target()),
+            (json!([1, 2]), json!([1, 2, 3, {"k": "v"}])),
+            (json!({"a/b": {"~c": 1}}), json!({"a/b": {"~c": 2}})),
+            (json!({"a": 1}), json!(["root", "replaced"])),
+        ];
+
+        for (from, to) in pairs {
+            let diff = call(&JsonDiffTool::new(), json!({"source": from, "target": to}));
+            let patch = serde_json::from_str::<JsonValue>(&diff.output()).unwrap()["patch"].clone();
+
+            let merged = call(
+                &JsonMergeTool::new(),
+                json!({"document": from, "patch": patch}),
+            );
+            assert!(merged.is_success(), "{}", merged.output());
+            let output: JsonValue = serde_json::from_str(&merged.output()).unwrap();
+            assert_eq!(output["mode"], "patch");
+            assert_eq!(output["result"], to);
+        }
+    }
+
+    #[test]
+    fn test_apply_move_copy_and_test_operations() {
+        let result = call(
+            &JsonMergeTool::new(),
+            json!({
+                "document": {"a": {"b": 1}, "list": [1, 2]},
+                "patch": [
+                    {"op": "test", "path": "/a/b", "value": 1},
+                    {"op": "copy", "from": "/a/b", "path": "/list/-"},
+                    {"op": "move", "from": "/a", "path": "/moved"}
+                ]
+            }),
+        );
+        assert!(result.is_success(), "{}", result.output());
+        let output: JsonValue = serde_json::from_str(&result.output()).unwrap();
+        assert_eq!(
+            output["result"],
+            json!({"list": [1, 2, 1], "moved": {"b": 1}})
+        );
+    }
+
+    #[test]
+    fn test_invalid_patches_fail_cleanly() {
+        let document = json!({"a": [1]});
+        let invalid = [
+            json!({"op": "frobnicate", "path": "/a"}),
+            json!({"op": "add", "path": "/a"}),
+            json!({"op": "remove", "path": "/missing"}),
+            json!({"op": "replace", "path": "/a/5", "value": 0}),
+            json!({"op": "add", "path": "a", "value": 0}),
+            json!({"op": "add", "path": "/a/01", "value": 0}),
+            json!({"op": "test", "path": "/a/0", "value": 2}),
+            json!({"op": "move", "from": "/a", "path": "/a/0"}),
+            json!({"op": "remove", "path": ""}),
+        ];
+
+        for operation in invalid {
+            let result = call(
+                &JsonMergeTool::new(),
+                json!({"document": document, "patch": [operation]}),
+            );
+            assert!(result.is_failure(), "{} should fail", operation);
+            assert!(result.output().contains("Invalid patch"));
+        }
+
+        let not_a_list = call(
+            &JsonMergeTool::new(),
+            json!({"document": document, "patch": {"op": "remove", "path": "/a"}}),
+        );
+        assert!(not_a_list.is_failure());
+    }
+
+    #[test]
+    fn test_merge_array_replace_vs_concat() {
+        let document = json!({"name": "base", "nested": {"keep": true, "items": [1, 2]}});
+        let other = json!({"nested": {"items": [3], "extra": null}, "tags": ["new"]});
+
+        let replaced = call(
+            &JsonMergeTool::new(),
+            json!({"document": document, "merge": other}),
+        );
+        let output: JsonValue = serde_json::from_str(&replaced.output()).unwrap();
+        assert_eq!(output["mode"], "merge");
+        assert_eq!(
+            output["result"],
+            json!({
+                "name": "base",
+                "nested": {"extra": null, "items": [3], "keep": true},
+                "tags": ["new"]
+            })
+        );
+
+        let concatenated = call(
+            &JsonMergeTool::new(),
+            json!({"document": document, "merge": other, "array_strategy": "concat"}),
+        );
+        let output: JsonValue = serde_json::from_str(&concatenated.output()).unwrap();
+        assert_eq!(
+            output["result"],
+            json!({
+                "name": "base",
+                "nested": {"extra": null, "items": [1, 2, 3], "keep": true},
+                "tags": ["new"]
+            })
+        );
+    }
+
+    #[test]
+    fn test_merge_requires_exactly_one_mode() {
+        let neither = call(&JsonMergeTool::new(), json!({"document": {}}));
+        assert!(neither.is_failure());
+
+        let both = call(
+            &JsonMergeTool::new(),
+            json!({"document": {}, "patch": [], "merge": {}}),
+        );
+        assert!(both.is_failure());
+        assert!(both.output().contains("Exactly one"));
+    }
+}
@@ -9,6 +9,7 @@
 //! - **I/O Tools**: File system operations and directory management
 //! - **Network Tools**: HTTP/REST API interactions with authentication support
 //! - **Data Tools**: JSON/XML/text processing and transformation
+//! - **Process Tools**: Allowlisted external commands without a shell
 //!
 //! ## Usage
 //!
@@ -27,10 +28,15 @@ pub mod data;
 pub mod io;
 /// Network communication tools
 pub mod network;
+/// External command execution
+pub mod process;
 
-pub use data::{JsonParseTool, JsonTransformTool, XmlParseTool};
+pub use data::{
+    DateTimeTool, JsonDiffTool, JsonMergeTool, JsonParseTool, JsonTransformTool, XmlParseTool,
+};
 pub use data::{
     TextAnalyzeTool, TextReverseTool, TextSearchTool, TextSplitTool, TextUppercaseTool,
 };
 pub use io::{DirectoryCreateTool, DirectoryListTool, FileReadTool, FileWriteTool};
-pub use network::{HttpDeleteTool, HttpGetTool, HttpPostTool, HttpPutTool};
+pub use network::{HttpDeleteTool, HttpGetTool, HttpPostTool, HttpPutTool, UrlTool};
+pub use process::{CommandInput, CommandSpec, CommandSpecError, CommandTool};
@@ -2,13 +2,18 @@
 //!
 //! This module provides HTTP client tools for making REST API requests with
 //! authentication support, error handling, and flexible configuration.
+//!
+//! Tools given a [`SecurityManager`] validate each URL against the HTTP
+//! policy and its per-domain rate limits before issuing the request.
 
 use crate::core::ToolConfig;
+use crate::resources::{InjectableTool, SharedResources};
 use reqwest::Client;
 use serde::{Deserialize, Serialize};
-use skreaver_core::{ExecutionResult, Tool};
+use skreaver_core::{ExecutionResult, SecurityManager, Tool};
 use std::collections::HashMap;
 use std::future::Future;
+use std::sync::Arc;
 use std::time::Duration;
 
 /// Execute an async operation using the current runtime or creating a new one.
@@ -30,6 +35,22 @@ where
     }
 }
 
+/// Get the shared HTTP client, or a new one if none was registered.
+///
+/// `reqwest::Client` is internally reference-counted, so cloning it shares
+/// the underlying connection pool.
+fn shared_client(resources: &SharedResources) -> Client {
+    resources
+        .get::<Client>()
+        .map(|client| Client::clone(&client))
+        .unwrap_or_default()
+}
+
+/// Get the shared security manager, if one was registered.
+fn shared_security(resources: &SharedResources) -> Option<Arc<SecurityManager>> {
+    resources.get::<SecurityManager>()
+}
+
 /// HTTP method for requests
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum HttpMethod {
@@ -108,6 +129,7 @@ impl ToolConfig for HttpConfig {
 /// Core HTTP execution logic shared by all HTTP tools
 async fn execute_http_request(
     client: &Client,
+    security: Option<&SecurityManager>,
     method: HttpMethod,
     input: String,
 ) -> ExecutionResult {
@@ -121,6 +143,13 @@ async fn execute_http_request(
         }
     };
 
+    // Enforce the HTTP policy and domain rate limits before sending anything
+    if let Some(security) = security
+        && let Err(e) = security.check_http_request(method.tool_name(), &config.url)
+    {
+        return ExecutionResult::failed(e.into());
+    }
+
     // Build request based on method
     let mut request = match method {
         HttpMethod::Get => client.get(&config.url),
@@ -167,6 +196,7 @@ async fn execute_http_request(
 /// HTTP GET tool for retrieving resources
 pub struct HttpGetTool {
     client: Client,
+    security: Option<Arc<SecurityManager>>,
 }
 
 impl std::fmt::Debug for HttpGetTool {
@@ -177,8 +207,29 @@ impl std::fmt::Debug for HttpGetTool {
 
 impl HttpGetTool {
     pub fn new() -> Self {
+        Self::with_client(Client::new())
+    }
+
+    /// Create the tool with an existing client so its connection pool is reused.
+    pub fn with_client(client: Client) -> Self {
         Self {
-            client: Client::new(),
+            client,
+            security: None,
+        }
+    }
+
+    /// Validate requests against the manager's HTTP policy and rate limits.
+    pub fn with_security_manager(mut self, security: Arc<SecurityManager>) -> Self {
+        self.security = Some(security);
+        self
+    }
+}
+
+impl InjectableTool for HttpGetTool {
+    fn from_resources(resources: &SharedResources) -> Self {
+        Self {
+            client: shared_client(resources),
+            security: shared_security(resources),
         }
     }
 }
@@ -196,13 +247,15 @@ impl Tool for HttpGetTool {
 
     fn call(&self, input: String) -> ExecutionResult {
         let client = self.client.clone();
-        run_async(|| execute_http_request(&client, HttpMethod::Get, input))
+        let security = self.security.clone();
+        run_async(|| execute_http_request(&client, security.as_deref(), HttpMethod::Get, input))
     }
 }
 
 /// HTTP POST tool for creating resources
 pub struct HttpPostTool {
     client: Client,
+    security: Option<Arc<SecurityManager>>,
 }
 
 impl std::fmt::Debug for HttpPostTool {
@@ -213,8 +266,29 @@ impl std::fmt::Debug for HttpPostTool {
 
 impl HttpPostTool {
     pub fn new() -> Self {
+        Self::with_client(Client::new())
+    }
+
+    /// Create the tool with an existing client so its connection pool is reused.
+    pub fn with_client(client: Client) -> Self {
+        Self {
+            client,
+            security: None,
+        }
+    }
+
+    /// Validate requests against the manager's HTTP policy and rate limits.
+    pub fn with_security_manager(mut self, security: Arc<SecurityManager>) -> Self {
+        self.security = Some(security);
+        self
+    }
+}
+
+impl InjectableTool for HttpPostTool {
+    fn from_resources(resources: &SharedResources) -> Self {
         Self {
-            client: Client::new(),
+            client: shared_client(resources),
+            security: shared_security(resources),
         }
     }
 }
@@ -232,13 +306,15 @@ impl Tool for HttpPostTool {
 
     fn call(&self, input: String) -> ExecutionResult {
         let client = self.client.clone();
-        run_async(|| execute_http_request(&client, HttpMethod::Post, input))
+        let security = self.security.clone();
+        run_async(|| execute_http_request(&client, security.as_deref(), HttpMethod::Post, input))
     }
 }
 
 /// HTTP PUT tool for updating resources
 pub struct HttpPutTool {
     client: Client,
+    security: Option<Arc<SecurityManager>>,
 }
 
 impl std::fmt::Debug for HttpPutTool {
@@ -249,8 +325,29 @@ impl std::fmt::Debug for HttpPutTool {
 
 impl HttpPutTool {
     pub fn new() -> Self {
+        Self::with_client(Client::new())
+    }
+
+    /// Create the tool with an existing client so its connection pool is reused.
+    pub fn with_client(client: Client) -> Self {
         Self {
-            client: Client::new(),
+            client,
+            security: None,
+        }
+    }
+
+    /// Validate requests against the manager's HTTP policy and rate limits.
+    pub fn with_security_manager(mut self, security: Arc<SecurityManager>) -> Self {
+        self.security = Some(security);
+        self
+    }
+}
+
+impl InjectableTool for HttpPutTool {
+    fn from_resources(resources: &SharedResources) -> Self {
+        Self {
+            client: shared_client(resources),
+            security: shared_security(resources),
         }
     }
 }
@@ -268,13 +365,15 @@ impl Tool for HttpPutTool {
 
     fn call(&self, input: String) -> ExecutionResult {
         let client = self.client.clone();
-        run_async(|| execute_http_request(&client, HttpMethod::Put, input))
+        let security = self.security.clone();
+        run_async(|| execute_http_request(&client, security.as_deref(), HttpMethod::Put, input))
     }
 }
 
 /// HTTP DELETE tool for removing resources
 pub struct HttpDeleteTool {
     client: Client,
+    security: Option<Arc<SecurityManager>>,
 }
 
 impl std::fmt::Debug for HttpDeleteTool {
@@ -285,8 +384,29 @@ impl std::fmt::Debug for HttpDeleteTool {
 
 impl HttpDeleteTool {
     pub fn new() -> Self {
+        Self::with_client(Client::new())
+    }
+
+    /// Create the tool with an existing client so its connection pool is reused.
+    pub fn with_client(client: Client) -> Self {
+        Self {
+            client,
+            security: None,
+        }
+    }
+
+    /// Validate requests against the manager's HTTP policy and rate limits.
+    pub fn with_security_manager(mut self, security: Arc<SecurityManager>) -> Self {
+        self.security = Some(security);
+        self
+    }
+}
+
+impl InjectableTool for HttpDeleteTool {
+    fn from_resources(resources: &SharedResources) -> Self {
         Self {
-            client: Client::new(),
+            client: shared_client(resources),
+            security: shared_security(resources),
         }
     }
 }
@@ -304,14 +424,15 @@ impl Tool for HttpDeleteTool {
 
     fn call(&self, input: String) -> ExecutionResult {
         let client = self.client.clone();
-        run_async(|| execute_http_request(&client, HttpMethod::Delete, input))
+        let security = self.security.clone();
+        run_async(|| execute_http_request(&client, security.as_deref(), HttpMethod::Delete, input))
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use skreaver_core::Tool;
+    use skreaver_core::{FailureReason, Tool};
     use wiremock::matchers::{body_string, header, method, path};
     use wiremock::{Mock, MockServer, ResponseTemplate};
 
@@ -665,8 +786,148 @@ mod tests {
         assert_eq!(delete_tool.name(), "http_delete");
     }
 
+    // ==================== Shared Client Injection ====================
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn test_http_tools_share_injected_client() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/shared"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("OK"))
+            .expect(2)
+            .mount(&mock_server)
+            .await;
+
+        let resources = SharedResources::new().with(Client::new());
+        let first = HttpGetTool::from_resources(&resources);
+        let second = HttpDeleteTool::from_resources(&resources);
+        assert!(resources.contains::<Client>());
+
+        let url = format!("{}/shared", mock_server.uri());
+        assert!(first.call(url.clone()).is_success());
+        assert!(
+            HttpGetTool::from_resources(&resources)
+                .call(url)
+                .is_success()
+        );
+        assert_eq!(second.name(), "http_delete");
+    }
+
     // ==================== Debug Implementations ====================
 
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn test_http_tools_share_domain_rate_limit() {
+        use skreaver_core::security::{
+            DomainFilter, DomainRateLimit, HttpAccess, HttpAccessConfig, RedirectLimit,
+            SecurityConfig,
+        };
+
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("ok"))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("DELETE"))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(0)
+            .mount(&mock_server)
+            .await;
+
+        let mut config = SecurityConfig::create_default();
+        config.http.access = HttpAccess::Internet {
+            config: HttpAccessConfig::default(),
+            domain_filter: DomainFilter::AllowAll { deny_list: vec![] },
+            include_local: true,
+            max_redirects: RedirectLimit::default(),
+            user_agent: "test".to_string(),
+        };
+        config.http.rate_limits = vec![
+            DomainRateLimit::new("127.0.0.1", 1, Duration::from_secs(60))
+                .unwrap()
+                .with_burst(1),
+        ];
+        let resources = SharedResources::new().with(SecurityManager::new(config));
+        let get = HttpGetTool::from_resources(&resources);
+        let delete = HttpDeleteTool::from_resources(&resources);
+
+        assert!(get.call(mock_server.uri()).is_success());
+        let limited = delete.call(mock_server.uri());
+        match limited.failure_reason() {
+            Some(FailureReason::RateLimited {
+                resource,
+                retry_after_ms: Some(retry_after_ms),
+            }) => {
+                assert_eq!(resource, "127.0.0.1");
+                assert!(*retry_after_ms > 59_000);
+            }
+            other => panic!("expected rate limit failure, got {other:?}"),
+        }
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn test_secure_tool_wrapped_http_tool_takes_one_token_per_call() {
+        use skreaver_core::security::{
+            DomainFilter, DomainRateLimit, HttpAccess, HttpAccessConfig, RedirectLimit, SecureTool,
+            SecurityConfig,
+        };
+
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("ok"))
+            .expect(2)
+            .mount(&mock_server)
+            .await;
+
+        let mut config = SecurityConfig::create_default();
+        config.http.access = HttpAccess::Internet {
+            config: HttpAccessConfig::default(),
+            domain_filter: DomainFilter::AllowAll { deny_list: vec![] },
+            include_local: true,
+            max_redirects: RedirectLimit::default(),
+            user_agent: "test".to_string(),
+        };
+        config.http.rate_limits = vec![
+            DomainRateLimit::new("127.0.0.1", 2, Duration::from_secs(60))
+                .unwrap()
+                .with_burst(2),
+        ];
+        let resources = SharedResources::new().with(SecurityManager::new(config));
+        let security = resources.get::<SecurityManager>().unwrap();
+        let get = SecureTool::new(HttpGetTool::from_resources(&resources), security);
+
+        // The wrapper and the tool check the same manager, but each call
+        // spends a single token
+        assert!(get.call(mock_server.uri()).is_success());
+        assert!(get.call(mock_server.uri()).is_success());
+        assert!(matches!(
+            get.call(mock_server.uri()).failure_reason(),
+            Some(FailureReason::RateLimited { .. })
+        ));
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn test_http_tool_rejects_disallowed_domain_before_request() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(0)
+            .mount(&mock_server)
+            .await;
+
+        // The default policy blocks loopback addresses
+        let security = Arc::new(SecurityManager::new(
+            skreaver_core::security::SecurityConfig::create_default(),
+        ));
+        let tool = HttpGetTool::new().with_security_manager(security);
+
+        assert!(matches!(
+            tool.call(mock_server.uri()).failure_reason(),
+            Some(FailureReason::PermissionDenied { .. })
+        ));
+    }
+
     #[test]
     fn test_http_tools_debug() {
         let get_tool = HttpGetTool::new();
@@ -5,5 +5,8 @@
 
 /// HTTP client tools for REST API interactions.
 pub mod http;
+/// URL parsing, building and query manipulation.
+pub mod url;
 
 pub use http::{HttpDeleteTool, HttpGetTool, HttpPostTool, HttpPutTool};
+pub use url::UrlTool;
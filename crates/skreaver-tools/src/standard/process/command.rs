@@ -0,0 +1,665 @@
+//! # Command Tool
+//!
+//! This module provides a tool for running pre-registered external commands
+//! without a shell. Each command is an allowlisted program with an argument
+//! template; callers only supply values for named parameters, which are
+//! validated and passed to the program as separate arguments.
+
+use crate::core::ToolConfig;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use skreaver_core::tool::FailureReason;
+use skreaver_core::{
+    ExecutionResult, InputValidator, PathValidator, ResourceLimits, SecurityPolicy, Tool,
+};
+use std::collections::HashMap;
+use std::io::Read;
+use std::path::PathBuf;
+use std::process::{Child, Command, Stdio};
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
+
+/// Default cap on captured bytes per output stream
+pub const DEFAULT_MAX_OUTPUT_BYTES: usize = 1024 * 1024;
+
+/// How often a running command is polled for exit
+const POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// Error building a command template
+#[derive(Debug, thiserror::Error)]
+pub enum CommandSpecError {
+    #[error("Invalid pattern for parameter '{name}': {source}")]
+    InvalidPattern {
+        name: String,
+        #[source]
+        source: regex::Error,
+    },
+    #[error("Parameter '{name}' is already defined")]
+    DuplicateParam { name: String },
+}
+
+/// One argument of a command template
+#[derive(Debug, Clone)]
+enum ArgTemplate {
+    /// Passed to the program as-is
+    Literal(String),
+    /// Filled from the caller's value for the named parameter
+    Param { name: String, pattern: Regex },
+}
+
+/// An allowlisted command: program, argument template and environment
+///
+/// Parameters always fill a whole argument, so a value can never be split
+/// into several arguments or interpreted by a shell.
+///
+/// ```rust
+/// use skreaver_tools::CommandSpec;
+///
+/// let spec = CommandSpec::new("git")
+///     .arg("log")
+///     .arg("--oneline")
+///     .param("revision", r"[A-Za-z0-9_./-]{1,64}")
+///     .unwrap();
+/// ```
+#[derive(Debug, Clone)]
+pub struct CommandSpec {
+    program: PathBuf,
+    args: Vec<ArgTemplate>,
+    working_dir: Option<PathBuf>,
+    env: Vec<(String, String)>,
+    timeout: Option<Duration>,
+}
+
+impl CommandSpec {
+    pub fn new(program: impl Into<PathBuf>) -> Self {
+        Self {
+            program: program.into(),
+            args: Vec::new(),
+            working_dir: None,
+            env: Vec::new(),
+            timeout: None,
+        }
+    }
+
+    /// Append a fixed argument
+    pub fn arg(mut self, arg: impl Into<String>) -> Self {
+        self.args.push(ArgTemplate::Literal(arg.into()));
+        self
+    }
+
+    /// Append an argument filled from parameter `name`
+    ///
+    /// The value must match `pattern` in full. Values starting with `-` are
+    /// always rejected so they cannot be read as options.
+    pub fn param(
+        mut self,
+        name: impl Into<String>,
+        pattern: &str,
+    ) -> Result<Self, CommandSpecError> {
+        let name = name.into();
+        if self.param_names().any(|existing| existing == name) {
+            return Err(CommandSpecError::DuplicateParam { name });
+        }
+        let pattern = Regex::new(&format!("^(?:{})$", pattern)).map_err(|source| {
+            CommandSpecError::InvalidPattern {
+                name: name.clone(),
+                source,
+            }
+        })?;
+        self.args.push(ArgTemplate::Param { name, pattern });
+        Ok(self)
+    }
+
+    /// Run the command in `dir`, which must be allowed by the file system policy
+    pub fn working_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.working_dir = Some(dir.into());
+        self
+    }
+
+    /// Set an environment variable; the command inherits no other variables
+    pub fn env(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.env.push((key.into(), value.into()));
+        self
+    }
+
+    /// Limit this command's run time, capped by the tool's resource limits
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    fn param_names(&self) -> impl Iterator<Item = &str> {
+        self.args.iter().filter_map(|arg| match arg {
+            ArgTemplate::Param { name, .. } => Some(name.as_str()),
+            ArgTemplate::Literal(_) => None,
+        })
+    }
+
+    /// Fill the template, validating every parameter value
+    fn render(
+        &self,
+        values: &HashMap<String, String>,
+        validator: &InputValidator,
+    ) -> Result<Vec<String>, FailureReason> {
+        if let Some(unknown) = values
+            .keys()
+            .find(|key| !self.param_names().any(|name| name == key.as_str()))
+        {
+            return Err(FailureReason::InvalidInput {
+                message: format!("Unknown parameter '{}'", unknown),
+            });
+        }
+
+        self.args
+            .iter()
+            .map(|arg| match arg {
+                ArgTemplate::Literal(literal) => Ok(literal.clone()),
+                ArgTemplate::Param { name, pattern } => {
+                    let value = values
+                        .get(name)
+                        .ok_or_else(|| FailureReason::InvalidInput {
+                            message: format!("Missing parameter '{}'", name),
+                        })?;
+                    if value.starts_with('-') || value.contains('\0') || !pattern.is_match(value) {
+                        return Err(FailureReason::InvalidInput {
+                            message: format!("Invalid value for parameter '{}'", name),
+                        });
+                    }
+                    validator
+                        .validate(value)
+                        .map_err(|e| FailureReason::PermissionDenied {
+                            message: format!("Parameter '{}' rejected: {}", name, e),
+                        })?;
+                    Ok(value.clone())
+                }
+            })
+            .collect()
+    }
+}
+
+/// Input for the command tool
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct CommandInput {
+    pub command: String,
+    #[serde(default)]
+    pub args: HashMap<String, String>,
+}
+
+impl ToolConfig for CommandInput {
+    fn from_simple(input: String) -> Self {
+        Self {
+            command: input,
+            args: HashMap::new(),
+        }
+    }
+}
+
+/// Captured output stream, truncated at the tool's output cap
+struct Captured {
+    bytes: Vec<u8>,
+    truncated: bool,
+}
+
+/// Drain a pipe on a background thread, keeping at most `limit` bytes
+///
+/// The rest is read and discarded so the child never blocks on a full pipe.
+fn capture(stream: Option<impl Read + Send + 'static>, limit: usize) -> JoinHandle<Captured> {
+    std::thread::spawn(move || {
+        let mut captured = Captured {
+            bytes: Vec::new(),
+            truncated: false,
+        };
+        let Some(mut stream) = stream else {
+            return captured;
+        };
+        let mut buf = [0u8; 8192];
+        while let Ok(n) = stream.read(&mut buf) {
+            if n == 0 {
+                break;
+            }
+            let room = limit.saturating_sub(captured.bytes.len());
+            captured.bytes.extend_from_slice(&buf[..n.min(room)]);
+            captured.truncated |= n > room;
+        }
+        captured
+    })
+}
+
+/// Holds one of the tool's concurrency slots until dropped
+struct RunningSlot<'a>(&'a AtomicU32);
+
+impl Drop for RunningSlot<'_> {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::AcqRel);
+    }
+}
+
+/// Runs allowlisted commands with templated, validated arguments
+///
+/// Commands run without a shell and with an empty environment apart from the
+/// variables set on their [`CommandSpec`]. Parameter values are checked
+/// against the template and the [`SecurityPolicy`] input validation, working
+/// directories against the file system policy, and run time and concurrency
+/// against the [`ResourceLimits`]. On Unix, `max_memory_mb` and
+/// `max_open_files` are also applied to each command as resource limits
+/// (`RLIMIT_DATA` and `RLIMIT_NOFILE`); elsewhere they are not enforced.
+#[derive(Debug)]
+pub struct CommandTool {
+    commands: HashMap<String, CommandSpec>,
+    policy: SecurityPolicy,
+    limits: ResourceLimits,
+    max_output_bytes: usize,
+    running: AtomicU32,
+}
+
+impl CommandTool {
+    pub fn new() -> Self {
+        Self {
+            commands: HashMap::new(),
+            policy: SecurityPolicy::default(),
+            limits: ResourceLimits::default(),
+            max_output_bytes: DEFAULT_MAX_OUTPUT_BYTES,
+            running: AtomicU32::new(0),
+        }
+    }
+
+    /// Allow `spec` to be run under `name`
+    pub fn with_command(mut self, name: impl Into<String>, spec: CommandSpec) -> Self {
+        self.commands.insert(name.into(), spec);
+        self
+    }
+
+    pub fn with_policy(mut self, policy: SecurityPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    pub fn with_limits(mut self, limits: ResourceLimits) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    /// Cap on captured bytes per output stream
+    pub fn with_max_output_bytes(mut self, max_output_bytes: usize) -> Self {
+        self.max_output_bytes = max_output_bytes;
+        self
+    }
+
+    /// Names of the allowlisted commands, sorted
+    pub fn commands(&self) -> Vec<&str> {
+        let mut names: Vec<&str> = self.commands.keys().map(String::as_str).collect();
+        names.sort_unstable();
+        names
+    }
+
+    /// Take a concurrency slot, or `None` if every slot is in use
+    fn try_acquire_slot(&self) -> Option<RunningSlot<'_>> {
+        let limit = self.limits.max_concurrent_operations;
+        self.running
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |running| {
+                (running < limit).then_some(running + 1)
+            })
+            .ok()
+            .map(|_| RunningSlot(&self.running))
+    }
+
+    /// Cap the child's memory and open files once it is forked
+    #[cfg(unix)]
+    fn apply_rlimits(&self, command: &mut Command) {
+        use std::os::unix::process::CommandExt;
+
+        let memory_bytes = (self.limits.max_memory_mb as libc::rlim_t).saturating_mul(1024 * 1024);
+        let open_files = self.limits.max_open_files as libc::rlim_t;
+        let limit = |value: libc::rlim_t| libc::rlimit {
+            rlim_cur: value,
+            rlim_max: value,
+        };
+        // SAFETY: the hook runs in the forked child before exec and only
+        // calls setrlimit, which is async-signal-safe and does not allocate
+        unsafe {
+            command.pre_exec(move || {
+                if libc::setrlimit(libc::RLIMIT_DATA, &limit(memory_bytes)) != 0
+                    || libc::setrlimit(libc::RLIMIT_NOFILE, &limit(open_files)) != 0
+                {
+                    return Err(std::io::Error::last_os_error());
+                }
+                Ok(())
+            });
+        }
+    }
+
+    fn spawn(&self, spec: &CommandSpec, args: Vec<String>) -> Result<Child, FailureReason> {
+        let mut command = Command::new(&spec.program);
+        command
+            .args(args)
+            .env_clear()
+            .envs(spec.env.iter().map(|(key, value)| (key, value)))
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped());
+
+        if let Some(dir) = &spec.working_dir {
+            let dir = PathValidator::new(&self.policy.fs_policy)
+                .validate_path(&dir.to_string_lossy())
+                .map_err(|e| FailureReason::PermissionDenied {
+                    message: format!("Working directory rejected: {}", e),
+                })?;
+            command.current_dir(dir);
+        }
+
+        #[cfg(unix)]
+        self.apply_rlimits(&mut command);
+
+        command.spawn().map_err(|e| FailureReason::IoError {
+            message: format!("Failed to start '{}': {}", spec.program.display(), e),
+        })
+    }
+
+    fn run(&self, name: &str, spec: &CommandSpec, args: Vec<String>) -> ExecutionResult {
+        let timeout = spec
+            .timeout
+            .map_or(self.limits.max_execution_time, |timeout| {
+                timeout.min(self.limits.max_execution_time)
+            });
+
+        let mut child = match self.spawn(spec, args) {
+            Ok(child) => child,
+            Err(reason) => return ExecutionResult::failed(reason),
+        };
+        let stdout = capture(child.stdout.take(), self.max_output_bytes);
+        let stderr = capture(child.stderr.take(), self.max_output_bytes);
+
+        let deadline = Instant::now() + timeout;
+        let status = loop {
+            match child.try_wait() {
+                Ok(Some(status)) => break status,
+                Ok(None) if Instant::now() >= deadline => {
+                    let _ = child.kill();
+                    let _ = child.wait();
+                    return ExecutionResult::failed(FailureReason::Timeout {
+                        operation: format!("command '{}' after {:?}", name, timeout),
+                    });
+                }
+                Ok(None) => std::thread::sleep(POLL_INTERVAL),
+                Err(e) => {
+                    let _ = child.kill();
+                    return ExecutionResult::failed(FailureReason::IoError {
+                        message: format!("Failed to wait for command '{}': {}", name, e),
+                    });
+                }
+            }
+        };
+
+        // A background process the command left behind can keep the pipes
+        // open after it exits, so collecting output is bounded by the deadline
+        while !(stdout.is_finished() && stderr.is_finished()) {
+            if Instant::now() >= deadline {
+                return ExecutionResult::failed(FailureReason::Timeout {
+                    operation: format!("output of command '{}' after {:?}", name, timeout),
+                });
+            }
+            std::thread::sleep(POLL_INTERVAL);
+        }
+
+        let (Ok(stdout), Ok(stderr)) = (stdout.join(), stderr.join()) else {
+            return ExecutionResult::failed(FailureReason::InternalError {
+                message: format!("Failed to capture output of command '{}'", name),
+            });
+        };
+
+        let result = serde_json::json!({
+            "command": name,
+            "exit_code": status.code(),
+            "stdout": String::from_utf8_lossy(&stdout.bytes),
+            "stderr": String::from_utf8_lossy(&stderr.bytes),
+            "stdout_truncated": stdout.truncated,
+            "stderr_truncated": stderr.truncated,
+            "success": status.success()
+        });
+        ExecutionResult::success(result.to_string())
+    }
+}
+
+impl Default for CommandTool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Tool for CommandTool {
+    fn name(&self) -> &str {
+        "command"
+    }
+
+    fn description(&self) -> &str {
+        "Run an allowlisted command with validated arguments, without a shell"
+    }
+
+    fn input_schema(&self) -> Option<serde_json::Value> {
+        Some(serde_json::json!({
+            "type": "object",
+            "properties": {
+                "command": {
+                    "type": "string",
+                    "description": "Name of the allowlisted command",
+                    "enum": self.commands()
+                },
+                "args": {
+                    "type": "object",
+                    "description": "Values for the command's template parameters",
+                    "additionalProperties": { "type": "string" }
+                }
+            },
+            "required": ["command"]
+        }))
+    }
+
+    fn output_schema(&self) -> Option<serde_json::Value> {
+        Some(serde_json::json!({
+            "type": "object",
+            "properties": {
+                "command": {
+                    "type": "string",
+                    "description": "Name of the command that was run"
+                },
+                "exit_code": {
+                    "type": ["integer", "null"],
+                    "description": "Exit code, or null if the command was killed by a signal"
+                },
+                "stdout": { "type": "string" },
+                "stderr": { "type": "string" },
+                "stdout_truncated": { "type": "boolean" },
+                "stderr_truncated": { "type": "boolean" },
+                "success": {
+                    "type": "boolean",
+                    "description": "Whether the command exited with code 0"
+                }
+            },
+            "required": ["command", "exit_code", "stdout", "stderr", "success"]
+        }))
+    }
+
+    fn call(&self, input: String) -> ExecutionResult {
+        let input = match CommandInput::parse_strict(input) {
+            Ok(input) => input,
+            Err(e) => {
+                return ExecutionResult::failed(FailureReason::InvalidInput {
+                    message: format!("Invalid command input: {}", e),
+                });
+            }
+        };
+
+        let Some(spec) = self.commands.get(&input.command) else {
+            return ExecutionResult::failed(FailureReason::PermissionDenied {
+                message: format!("Command '{}' is not allowlisted", input.command),
+            });
+        };
+
+        let args = match spec.render(&input.args, &InputValidator::new(&self.policy)) {
+            Ok(args) => args,
+            Err(reason) => return ExecutionResult::failed(reason),
+        };
+
+        let Some(_slot) = self.try_acquire_slot() else {
+            return ExecutionResult::failed(FailureReason::Custom {
+                category: "resource_limit".to_string(),
+                message: format!(
+                    "Too many concurrent commands (limit {})",
+                    self.limits.max_concurrent_operations
+                ),
+            });
+        };
+        self.run(&input.command, spec, args)
+    }
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use serde_json::{Value as JsonValue, json};
+
+    fn tool() -> CommandTool {
+        CommandTool::new().with_command(
+            "greet",
+            CommandSpec::new("echo")
+                .arg("hello")
+                .param("name", r"[A-Za-z ]{1,32}")
+                .unwrap(),
+        )
+    }
+
+    fn failure(result: &ExecutionResult) -> &FailureReason {
+        result.failure_reason().expect("expected a failure")
+    }
+
+    #[test]
+    fn test_allowlisted_command_runs() {
+        let result = tool().call(json!({"command": "greet", "args": {"name": "Ada"}}).to_string());
+        assert!(
+            result.is_success(),
+            "unexpected failure: {}",
+            result.output()
+        );
+
+        let output: JsonValue = serde_json::from_str(&result.output()).unwrap();
+        assert_eq!(output["command"], "greet");
+        assert_eq!(output["exit_code"], 0);
+        assert_eq!(output["stdout"], "hello Ada\n");
+        assert_eq!(output["stderr"], "");
+        assert_eq!(output["success"], true);
+    }
+
+    #[test]
+    fn test_command_not_on_allowlist_is_refused() {
+        let result = tool().call(json!({"command": "rm", "args": {"name": "x"}}).to_string());
+        assert!(matches!(
+            failure(&result),
+            FailureReason::PermissionDenied { message } if message.contains("not allowlisted")
+        ));
+    }
+
+    #[test]
+    fn test_injection_style_arguments_are_refused() {
+        for name in ["Ada; rm -rf /", "$(whoami)", "-n", "Ada\nrm"] {
+            let result =
+                tool().call(json!({"command": "greet", "args": {"name": name}}).to_string());
+            assert!(
+                matches!(failure(&result), FailureReason::InvalidInput { .. }),
+                "accepted {:?}",
+                name
+            );
+        }
+
+        // Even a permissive template cannot smuggle shell metacharacters past the policy
+        let permissive = CommandTool::new().with_command(
+            "echo",
+            CommandSpec::new("echo").param("text", ".*").unwrap(),
+        );
+        let result =
+            permissive.call(json!({"command": "echo", "args": {"text": "a | cat"}}).to_string());
+        assert!(matches!(
+            failure(&result),
+            FailureReason::PermissionDenied { .. }
+        ));
+    }
+
+    #[test]
+    fn test_missing_and_unknown_parameters_are_rejected() {
+        let missing = tool().call(json!({"command": "greet"}).to_string());
+        assert!(matches!(
+            failure(&missing),
+            FailureReason::InvalidInput { .. }
+        ));
+
+        let unknown = tool()
+            .call(json!({"command": "greet", "args": {"name": "Ada", "extra": "x"}}).to_string());
+        assert!(matches!(
+            failure(&unknown),
+            FailureReason::InvalidInput { .. }
+        ));
+    }
+
+    #[test]
+    fn test_timeout_kills_command() {
+        let tool = CommandTool::new().with_command(
+            "sleep",
+            CommandSpec::new("sleep")
+                .arg("5")
+                .timeout(Duration::from_millis(100)),
+        );
+        let started = Instant::now();
+        let result = tool.call("sleep".to_string());
+        assert!(matches!(failure(&result), FailureReason::Timeout { .. }));
+        assert!(started.elapsed() < Duration::from_secs(4));
+    }
+
+    #[test]
+    fn test_lingering_background_process_does_not_block_output() {
+        let tool = CommandTool::new().with_command(
+            "detach",
+            CommandSpec::new("sh")
+                .arg("-c")
+                .arg("sleep 5 &")
+                .timeout(Duration::from_millis(200)),
+        );
+        let started = Instant::now();
+        let result = tool.call("detach".to_string());
+        assert!(matches!(failure(&result), FailureReason::Timeout { .. }));
+        assert!(started.elapsed() < Duration::from_secs(4));
+        assert_eq!(tool.running.load(Ordering::Acquire), 0);
+    }
+
+    #[test]
+    fn test_memory_and_open_file_limits_apply_to_command() {
+        let tool = CommandTool::new()
+            .with_command(
+                "limits",
+                CommandSpec::new("sh").arg("-c").arg("ulimit -d; ulimit -n"),
+            )
+            .with_limits(ResourceLimits {
+                max_memory_mb: 64,
+                max_open_files: 32,
+                ..ResourceLimits::default()
+            });
+        let result = tool.call("limits".to_string());
+        assert!(
+            result.is_success(),
+            "unexpected failure: {}",
+            result.output()
+        );
+
+        let output: JsonValue = serde_json::from_str(&result.output()).unwrap();
+        assert_eq!(output["stdout"], "65536\n32\n");
+    }
+
+    #[test]
+    fn test_duplicate_param_is_rejected() {
+        let err = CommandSpec::new("echo")
+            .param("a", ".*")
+            .unwrap()
+            .param("a", ".*")
+            .unwrap_err();
+        assert!(matches!(err, CommandSpecError::DuplicateParam { .. }));
+    }
+}
@@ -0,0 +1,8 @@
+//! # Process Operations
+//!
+//! This module provides tools for running external commands safely.
+
+/// Allowlisted command execution without a shell.
+pub mod command;
+
+pub use command::{CommandInput, CommandSpec, CommandSpecError, CommandTool};
@@ -0,0 +1,385 @@
+//! All-or-nothing groups of tool calls
+//!
+//! A [`ToolTransaction`] runs a fixed list of tool calls in order. If every
+//! call succeeds the group is committed. If one fails, the calls that already
+//! succeeded are undone by running their compensators, most recent first, and
+//! the group is reported as rolled back.
+//!
+//! A compensator is an ordinary registered tool. It receives a JSON object
+//! describing the call it undoes:
+//!
+//! ```json
+//! {"tool": "write_primary", "input": "...", "output": "..."}
+//! ```
+//!
+//! Calls are dispatched through a registry like [`PipelineTool`] stages, so
+//! the registry's security checks and call depth limit apply to them and to
+//! their compensators.
+//!
+//! [`PipelineTool`]: crate::PipelineTool
+
+use super::{ExecutionResult, ToolCall, ToolRegistry};
+use std::sync::Arc;
+
+/// One call in a transaction and the tool that undoes it
+#[derive(Debug, Clone)]
+struct TransactionStep {
+    call: ToolCall,
+    compensator: Option<String>,
+}
+
+/// A group of tool calls that either all succeed or are compensated
+///
+/// # Example
+///
+/// ```rust
+/// use skreaver_tools::{InMemoryToolRegistry, ToolTransaction};
+/// use skreaver_core::{ExecutionResult, Tool, ToolCall};
+/// use std::sync::Arc;
+///
+/// struct EchoTool;
+///
+/// impl Tool for EchoTool {
+///     fn name(&self) -> &str { "echo" }
+///     fn call(&self, input: String) -> ExecutionResult {
+///         ExecutionResult::success(input)
+///     }
+/// }
+///
+/// let registry = InMemoryToolRegistry::new().with_tool("echo", Arc::new(EchoTool));
+/// let outcome = ToolTransaction::new("copy", Arc::new(registry))
+///     .call_with_compensator(ToolCall::new("echo", "a").unwrap(), "echo")
+///     .call(ToolCall::new("echo", "b").unwrap())
+///     .execute();
+///
+/// assert!(outcome.is_committed());
+/// ```
+pub struct ToolTransaction {
+    name: String,
+    registry: Arc<dyn ToolRegistry + Send + Sync>,
+    steps: Vec<TransactionStep>,
+}
+
+impl ToolTransaction {
+    /// Create an empty transaction whose calls are dispatched through `registry`
+    pub fn new(name: impl Into<String>, registry: Arc<dyn ToolRegistry + Send + Sync>) -> Self {
+        Self {
+            name: name.into(),
+            registry,
+            steps: Vec::new(),
+        }
+    }
+
+    /// Append a call that needs no undoing, such as a read
+    pub fn call(mut self, call: ToolCall) -> Self {
+        self.steps.push(TransactionStep {
+            call,
+            compensator: None,
+        });
+        self
+    }
+
+    /// Append a call that the named tool undoes if a later call fails
+    pub fn call_with_compensator(mut self, call: ToolCall, compensator: impl Into<String>) -> Self {
+        self.steps.push(TransactionStep {
+            call,
+            compensator: Some(compensator.into()),
+        });
+        self
+    }
+
+    /// Transaction name, used in logs
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Number of calls in the transaction
+    pub fn len(&self) -> usize {
+        self.steps.len()
+    }
+
+    /// Check whether the transaction has no calls
+    pub fn is_empty(&self) -> bool {
+        self.steps.is_empty()
+    }
+
+    /// Run every call, compensating the succeeded ones if any call fails
+    pub fn execute(&self) -> TransactionOutcome {
+        let mut outputs = Vec::with_capacity(self.steps.len());
+
+        for (index, step) in self.steps.iter().enumerate() {
+            let result = self
+                .registry
+                .try_dispatch(&step.call)
+                .unwrap_or_else(ExecutionResult::failure);
+
+            match result {
+                ExecutionResult::Success { output, .. } => outputs.push(output),
+                ExecutionResult::Failure { reason } => {
+                    tracing::warn!(
+                        transaction = %self.name,
+                        tool_name = %step.call.name(),
+                        error = %reason,
+                        "Tool transaction step failed, rolling back"
+                    );
+                    return TransactionOutcome::RolledBack(RollbackReport {
+                        failed_step: index,
+                        failed_tool: step.call.name().to_string(),
+                        error: reason.message(),
+                        compensations: self.compensate(&self.steps[..index], &outputs),
+                    });
+                }
+            }
+        }
+
+        TransactionOutcome::Committed { outputs }
+    }
+
+    /// Run the compensators of succeeded steps, most recent first
+    ///
+    /// Every compensator runs even if an earlier one fails, so as much as
+    /// possible is undone.
+    fn compensate(&self, succeeded: &[TransactionStep], outputs: &[String]) -> Vec<Compensation> {
+        succeeded
+            .iter()
+            .zip(outputs)
+            .rev()
+            .filter_map(|(step, output)| {
+                let compensator = step.compensator.as_ref()?;
+                let input = serde_json::json!({
+                    "tool": step.call.name(),
+                    "input": step.call.input,
+                    "output": output,
+                })
+                .to_string();
+
+                let result = ToolCall::new(compensator, &input)
+                    .map_err(|e| format!("Invalid compensator '{}': {}", compensator, e))
+                    .and_then(|call| self.registry.try_dispatch(&call))
+                    .unwrap_or_else(ExecutionResult::failure);
+                if let ExecutionResult::Failure { reason } = &result {
+                    tracing::error!(
+                        transaction = %self.name,
+                        tool_name = %step.call.name(),
+                        compensator = %compensator,
+                        error = %reason,
+                        "Tool transaction compensator failed"
+                    );
+                }
+
+                Some(Compensation {
+                    tool_name: step.call.name().to_string(),
+                    compensator: compensator.clone(),
+                    result,
+                })
+            })
+            .collect()
+    }
+}
+
+/// Result of running a [`ToolTransaction`]
+#[derive(Debug, Clone)]
+pub enum TransactionOutcome {
+    /// Every call succeeded
+    Committed {
+        /// Output of each call, in order
+        outputs: Vec<String>,
+    },
+    /// A call failed and the earlier calls were compensated
+    RolledBack(RollbackReport),
+}
+
+impl TransactionOutcome {
+    /// Check whether every call succeeded
+    pub fn is_committed(&self) -> bool {
+        matches!(self, Self::Committed { .. })
+    }
+}
+
+/// What failed in a rolled back transaction and how it was undone
+#[derive(Debug, Clone)]
+pub struct RollbackReport {
+    /// Index of the failed call
+    pub failed_step: usize,
+    /// Name of the failed tool
+    pub failed_tool: String,
+    /// Why the call failed
+    pub error: String,
+    /// Compensators that ran, in the order they ran
+    pub compensations: Vec<Compensation>,
+}
+
+impl RollbackReport {
+    /// Check whether every compensator succeeded
+    pub fn fully_compensated(&self) -> bool {
+        self.compensations.iter().all(|c| c.result.is_success())
+    }
+}
+
+/// A compensator run for one succeeded call
+#[derive(Debug, Clone)]
+pub struct Compensation {
+    /// Tool whose call was undone
+    pub tool_name: String,
+    /// Compensating tool
+    pub compensator: String,
+    /// Result of the compensating call
+    pub result: ExecutionResult,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::InMemoryToolRegistry;
+    use skreaver_core::Tool;
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+
+    type Store = Arc<Mutex<HashMap<String, String>>>;
+
+    /// Writes `key=value` input into a store
+    struct WriteTool {
+        name: &'static str,
+        store: Store,
+    }
+
+    impl Tool for WriteTool {
+        fn name(&self) -> &str {
+            self.name
+        }
+
+        fn call(&self, input: String) -> ExecutionResult {
+            let Some((key, value)) = input.split_once('=') else {
+                return ExecutionResult::failure(format!("expected key=value, got '{}'", input));
+            };
+            self.store
+                .lock()
+                .unwrap()
+                .insert(key.to_string(), value.to_string());
+            ExecutionResult::success(key.to_string())
+        }
+    }
+
+    /// Removes the key written by the compensated call
+    struct DeleteTool {
+        name: &'static str,
+        store: Store,
+    }
+
+    impl Tool for DeleteTool {
+        fn name(&self) -> &str {
+            self.name
+        }
+
+        fn call(&self, input: String) -> ExecutionResult {
+            let undo: serde_json::Value = serde_json::from_str(&input).unwrap();
+            let key = undo["output"].as_str().unwrap();
+            self.store.lock().unwrap().remove(key);
+            ExecutionResult::success(format!("deleted {}", key))
+        }
+    }
+
+    struct FailingTool;
+
+    impl Tool for FailingTool {
+        fn name(&self) -> &str {
+            "notify"
+        }
+
+        fn call(&self, _input: String) -> ExecutionResult {
+            ExecutionResult::failure("notification service unavailable".to_string())
+        }
+    }
+
+    fn registry(primary: &Store, replica: &Store) -> Arc<InMemoryToolRegistry> {
+        let tool = |name, store: &Store| {
+            Arc::new(WriteTool {
+                name,
+                store: Arc::clone(store),
+            })
+        };
+        let undo = |name, store: &Store| {
+            Arc::new(DeleteTool {
+                name,
+                store: Arc::clone(store),
+            })
+        };
+        Arc::new(
+            InMemoryToolRegistry::new()
+                .with_tool("write_primary", tool("write_primary", primary))
+                .with_tool("write_replica", tool("write_replica", replica))
+                .with_tool("delete_primary", undo("delete_primary", primary))
+                .with_tool("delete_replica", undo("delete_replica", replica))
+                .with_tool("notify", Arc::new(FailingTool)),
+        )
+    }
+
+    fn write_both(registry: Arc<InMemoryToolRegistry>) -> ToolTransaction {
+        ToolTransaction::new("replicate", registry)
+            .call_with_compensator(
+                ToolCall::new("write_primary", "user=alice").unwrap(),
+                "delete_primary",
+            )
+            .call_with_compensator(
+                ToolCall::new("write_replica", "user=alice").unwrap(),
+                "delete_replica",
+            )
+    }
+
+    #[test]
+    fn successful_group_commits_every_call() {
+        let (primary, replica) = (Store::default(), Store::default());
+        let outcome = write_both(registry(&primary, &replica)).execute();
+
+        match outcome {
+            TransactionOutcome::Committed { outputs } => assert_eq!(outputs, ["user", "user"]),
+            other => panic!("Expected commit, got {:?}", other),
+        }
+        assert_eq!(primary.lock().unwrap()["user"], "alice");
+        assert_eq!(replica.lock().unwrap()["user"], "alice");
+    }
+
+    #[test]
+    fn failing_last_step_rolls_back_earlier_steps() {
+        let (primary, replica) = (Store::default(), Store::default());
+        let outcome = write_both(registry(&primary, &replica))
+            .call(ToolCall::new("notify", "user written").unwrap())
+            .execute();
+
+        let TransactionOutcome::RolledBack(report) = outcome else {
+            panic!("Expected rollback");
+        };
+        assert_eq!(report.failed_step, 2);
+        assert_eq!(report.failed_tool, "notify");
+        assert!(report.error.contains("notification service unavailable"));
+        assert!(report.fully_compensated());
+
+        let order: Vec<&str> = report
+            .compensations
+            .iter()
+            .map(|c| c.compensator.as_str())
+            .collect();
+        assert_eq!(order, ["delete_replica", "delete_primary"]);
+        assert!(primary.lock().unwrap().is_empty());
+        assert!(replica.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn missing_tool_rolls_back_and_reports_failed_compensators() {
+        let (primary, replica) = (Store::default(), Store::default());
+        let outcome = ToolTransaction::new("replicate", registry(&primary, &replica))
+            .call_with_compensator(
+                ToolCall::new("write_primary", "user=alice").unwrap(),
+                "no_such_compensator",
+            )
+            .call(ToolCall::new("no_such_tool", "x").unwrap())
+            .execute();
+
+        let TransactionOutcome::RolledBack(report) = outcome else {
+            panic!("Expected rollback");
+        };
+        assert_eq!(report.failed_tool, "no_such_tool");
+        assert!(!report.fully_compensated());
+        assert_eq!(report.compensations.len(), 1);
+    }
+}
@@ -90,9 +90,18 @@ pub use skreaver_core::{
 
 // Memory traits
 pub use skreaver_core::{
-    MemoryKey, MemoryReader, MemoryUpdate, MemoryWriter, SnapshotableMemory, TransactionalMemory,
+    ClearableMemory, DeletableMemory, MemoryKey, MemoryReader, MemoryUpdate, MemoryWriter,
+    ScanableMemory, SnapshotableMemory, TransactionalMemory,
 };
 
+// Portable memory bundles
+pub use skreaver_core::memory::{
+    BundleError, MemoryBundle, export_memory, import_memory, replace_memory,
+};
+
+// Read-only memory views
+pub use skreaver_core::memory::{ReadOnlyMemory, ReadOnlyWrites};
+
 // In-memory implementation
 pub use skreaver_core::InMemoryMemory;
 
@@ -122,22 +131,25 @@ pub use skreaver_core::{StructuredToolResult, ToolExecutionMetadata, ToolResultB
 
 // Tool registry
 pub use skreaver_tools::{
-    InMemoryToolRegistry, SecureToolRegistry, ToolCallBuildError, ToolCallBuilder, ToolConfig,
-    ToolRegistry,
+    CacheInvalidator, CachePolicy, CachingToolRegistry, InMemoryToolRegistry, InvalidatingMemory,
+    SecureToolRegistry, ToolCallBuildError, ToolCallBuilder, ToolConfig, ToolRegistry,
 };
 
 // Standard tools - I/O
 pub use skreaver_tools::{DirectoryCreateTool, DirectoryListTool, FileReadTool, FileWriteTool};
 
 // Standard tools - Network
-pub use skreaver_tools::{HttpDeleteTool, HttpGetTool, HttpPostTool, HttpPutTool};
+pub use skreaver_tools::{HttpDeleteTool, HttpGetTool, HttpPostTool, HttpPutTool, UrlTool};
 
 // Standard tools - Data
 pub use skreaver_tools::{
-    JsonParseTool, JsonTransformTool, TextAnalyzeTool, TextReverseTool, TextSearchTool,
-    TextSplitTool, TextUppercaseTool, XmlParseTool,
+    DateTimeTool, JsonDiffTool, JsonMergeTool, JsonParseTool, JsonTransformTool, TextAnalyzeTool,
+    TextReverseTool, TextSearchTool, TextSplitTool, TextUppercaseTool, XmlParseTool,
 };
 
+// Standard tools - Process
+pub use skreaver_tools::{CommandSpec, CommandTool};
+
 // ============================================================================
 // Collections
 // ============================================================================
@@ -165,9 +177,10 @@ pub use skreaver_core::{
 
 pub use skreaver_core::{
     ApiKey, ApiKeyConfig, ApiKeyManager, AuthContext, AuthError, AuthManager, AuthMethod,
-    AuthMiddleware, AuthResult, AuthenticatedRequest, AuthenticationPolicy, CredentialStorage,
-    InMemoryStorage, JwtClaims, JwtConfig, JwtManager, JwtToken, Permission, Principal, Role,
-    RoleManager, SecureStorage, ToolPolicy,
+    AuthMiddleware, AuthResult, AuthenticatedRequest, AuthenticationPolicy, AuthzDenial,
+    CredentialStorage, DenialReason, InMemoryStorage, JwtClaims, JwtConfig, JwtManager, JwtToken,
+    KeyMaterial, Permission, Principal, Role, RoleManager, SecureStorage, ToolPolicy,
+    TrustedIssuer,
 };
 
 // ============================================================================
@@ -183,7 +196,10 @@ pub use skreaver_core::{
 // Memory backends
 // ============================================================================
 
-pub use skreaver_memory::{FileMemory, NamespacedMemory};
+pub use skreaver_memory::{
+    AccessControlledMemory, FileMemory, KeyAcl, MemoryAcl, MemoryRetryConfig, MemoryTtlSweeper,
+    NamespacedMemory, RetryingMemory,
+};
 
 // Memory admin operations
 pub use skreaver_memory::{
@@ -216,6 +232,7 @@ pub use skreaver_http::runtime::{
     AgentFactoryError,
     AgentInstance,
     AgentObservation,
+    AgentQuotaConfig,
     AgentResponse,
     AgentSpec,
     AgentStatus,
@@ -233,6 +250,8 @@ pub use skreaver_http::runtime::{
     ConnectionLimitConfig,
     ConnectionStats,
     ConnectionTracker,
+    // Content types
+    ContentTypeConfig,
     // Coordinator
     Coordinator,
     CoordinatorTrait,
@@ -241,18 +260,23 @@ pub use skreaver_http::runtime::{
     EchoAgentBuilder,
     // Error handling
     ErrorResponse,
+    ErrorStrategy,
     // HTTP runtime
     HttpAgentRuntime,
     HttpRuntimeConfig,
     HttpRuntimeConfigBuilder,
+    MemoryIsolation,
     QueueMetrics,
     RequestIdExtension,
     RequestPriority,
     ResponseDelivery,
     RuntimeError,
     RuntimeResult,
+    ScratchAgent,
     // Security (HTTP-specific - different from core SecurityConfig)
     SecretKey,
+    SharedMemoryAgent,
+    StepError,
     // Shutdown
     request_id_middleware,
     shutdown_signal,
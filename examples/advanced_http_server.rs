@@ -245,15 +245,26 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Create HTTP runtime configuration
     let http_config = HttpRuntimeConfig {
         rate_limit: rate_config,
+        route_rate_limits: None,
         backpressure: BackpressureConfig::default(),
+        stream_backpressure: Default::default(),
         connection_limits: ConnectionLimitConfig::default(),
+        agent_quota: Default::default(),
+        agent_eviction: Default::default(),
         request_timeout: skreaver_http::runtime::config::RequestTimeout::from_seconds(30).unwrap(),
         max_body_size: skreaver_http::runtime::config::MaxBodySize::from_bytes(16 * 1024 * 1024)
             .unwrap(), // 16MB
         cors: Some(skreaver_http::runtime::http::CorsConfig::default()),
+        content_types: Some(Default::default()),
         openapi: Some(skreaver_http::runtime::http::OpenApiConfig::default()),
         observability: Default::default(),
         security_config_path: None, // Use default security config
+        usage_sink: None,
+        dead_letters: None,
+        approval_gate: None,
+        tool_limiter: None,
+        shared_memory: None,
+        queue_persistence: None,
     };
 
     // Create HTTP runtime with configuration
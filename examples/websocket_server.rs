@@ -101,6 +101,10 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         max_subscribers_per_channel: 1000,
         max_connections_per_ip: 10,
         broadcast_buffer_size: 1000,
+        heartbeat_payload: None,
+        origin_policy: skreaver_http::websocket::OriginPolicy::same_origin(),
+        codec: Arc::new(skreaver_http::websocket::JsonCodec),
+        channel_backlog_size: 0,
     };
 
     // Create WebSocket manager
@@ -299,6 +299,7 @@ where
         let _ = self.memory.store(MemoryUpdate {
             key: MemoryKey::new("last_reasoning_step").expect("Valid memory key"),
             value: step_json,
+            ttl: None,
         });
     }
 
@@ -1,4 +1,6 @@
 use clap::{Parser, Subcommand};
+use skreaver_observability::{LogFormat, fmt_layer};
+use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
 
 mod agents;
 mod perf;
@@ -12,6 +14,10 @@ use scaffold::{generate_agent, generate_tool, list_templates};
 #[command(name = "skreaver", version = "0.3.0")]
 #[command(about = "Skreaver CLI - Agent infrastructure and performance tools")]
 struct Cli {
+    /// Log output format (json, logfmt, pretty); defaults to $SKREAVER_LOG_FORMAT or json
+    #[arg(long, global = true)]
+    log_format: Option<LogFormat>,
+
     #[command(subcommand)]
     command: Commands,
 }
@@ -114,20 +120,21 @@ enum PerfCommands {
 }
 
 fn main() {
-    // Initialize JSON logging once.
+    let cli = Cli::parse();
+
+    // Initialize logging once.
     let env_filter = tracing_subscriber::EnvFilter::from_default_env();
     let env_filter = match "info".parse() {
         Ok(directive) => env_filter.add_directive(directive),
         Err(_) => env_filter, // fallback to default if parsing fails
     };
 
-    let _ = tracing_subscriber::fmt()
-        .with_env_filter(env_filter)
-        .json()
+    let log_format = cli.log_format.unwrap_or_else(LogFormat::from_env);
+    let _ = tracing_subscriber::registry()
+        .with(env_filter)
+        .with(fmt_layer(log_format, std::io::stdout))
         .try_init();
 
-    let cli = Cli::parse();
-
     match cli.command {
         Commands::Agent { name } => match name.as_str() {
             "echo" => {
@@ -151,6 +151,7 @@ impl PyFileMemory {
         let update = MemoryUpdate {
             key: make_memory_key(key)?,
             value: value.to_string(),
+            ttl: None,
         };
         memory
             .store(update)
@@ -174,6 +175,7 @@ impl PyFileMemory {
             memory_updates.push(MemoryUpdate {
                 key: make_memory_key(&key_str)?,
                 value: value_str,
+                ttl: None,
             });
         }
 
@@ -481,6 +483,7 @@ impl PyRedisMemory {
             let update = MemoryUpdate {
                 key: memory_key,
                 value,
+                ttl: None,
             };
             memory
                 .store_async(update)
@@ -510,6 +513,7 @@ impl PyRedisMemory {
                     Ok(MemoryUpdate {
                         key: memory_key,
                         value: v,
+                        ttl: None,
                     })
                 })
                 .collect::<Result<Vec<_>, pyo3::PyErr>>()?;
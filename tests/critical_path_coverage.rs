@@ -40,6 +40,7 @@ async fn test_memory_agent_integration() {
         .store(MemoryUpdate {
             key: context_key.clone(),
             value: r#"{"session": "test_session", "user": "test_user"}"#.to_string(),
+            ttl: None,
         })
         .expect("Store should succeed");
 
@@ -82,6 +83,7 @@ async fn test_tool_execution_with_memory_persistence() {
         .store(MemoryUpdate {
             key: result_key.clone(),
             value: result.output().to_string(),
+            ttl: None,
         })
         .expect("Store should succeed");
 
@@ -147,6 +149,7 @@ async fn test_concurrent_memory_operations() {
             mem.store(MemoryUpdate {
                 key: key.clone(),
                 value: format!("value_{}", i),
+                ttl: None,
             })
             .expect("Store should succeed");
 
@@ -178,6 +181,7 @@ async fn test_batch_operations_performance() {
         .map(|i| MemoryUpdate {
             key: MemoryKey::new(&format!("batch_key_{}", i)).expect("Valid key"),
             value: format!("batch_value_{}", i),
+            ttl: None,
         })
         .collect();
 
@@ -238,6 +242,7 @@ async fn test_tool_chain_execution() {
         .store(MemoryUpdate {
             key: intermediate_key.clone(),
             value: result1.output().to_string(),
+            ttl: None,
         })
         .expect("Store should succeed");
 
@@ -258,6 +263,7 @@ async fn test_tool_chain_execution() {
         .store(MemoryUpdate {
             key: final_key.clone(),
             value: result2.output().to_string(),
+            ttl: None,
         })
         .expect("Store should succeed");
 
@@ -282,6 +288,7 @@ async fn test_realistic_workload_performance() {
             .store(MemoryUpdate {
                 key: obs_key,
                 value: format!("observation_data_{}", i),
+                ttl: None,
             })
             .expect("Store should succeed");
 
@@ -298,6 +305,7 @@ async fn test_realistic_workload_performance() {
                 .store(MemoryUpdate {
                     key: result_key,
                     value: result.output().to_string(),
+                    ttl: None,
                 })
                 .expect("Store should succeed");
         }
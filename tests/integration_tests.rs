@@ -25,6 +25,7 @@ async fn test_complete_memory_tool_workflow() {
         .store(MemoryUpdate {
             key: context_key.clone(),
             value: context_value.to_string(),
+            ttl: None,
         })
         .expect("Store context should succeed");
 
@@ -43,6 +44,7 @@ async fn test_complete_memory_tool_workflow() {
         .store(MemoryUpdate {
             key: result_key.clone(),
             value: result.output().to_string(),
+            ttl: None,
         })
         .expect("Store result should succeed");
 
@@ -92,6 +94,7 @@ async fn test_concurrent_tool_execution_with_memory() {
             mem.store(MemoryUpdate {
                 key: result_key,
                 value: result.output().to_string(),
+                ttl: None,
             })
             .expect("Store should succeed");
 
@@ -130,6 +133,7 @@ async fn test_error_handling_workflow() {
         .store(MemoryUpdate {
             key: data_key.clone(),
             value: "initial_data".to_string(),
+            ttl: None,
         })
         .expect("Store should succeed");
 
@@ -148,6 +152,7 @@ async fn test_error_handling_workflow() {
         .store(MemoryUpdate {
             key: success_key.clone(),
             value: good_result.output().to_string(),
+            ttl: None,
         })
         .expect("Store should succeed");
 
@@ -161,6 +166,7 @@ async fn test_error_handling_workflow() {
         .store(MemoryUpdate {
             key: error_key.clone(),
             value: format!("Error: {}", bad_result.output()),
+            ttl: None,
         })
         .expect("Store should succeed");
 
@@ -190,6 +196,7 @@ async fn test_batch_operations_with_tools() {
         .map(|(key, value)| MemoryUpdate {
             key: MemoryKey::new(key).expect("Valid key"),
             value: value.to_string(),
+            ttl: None,
         })
         .collect();
 
@@ -227,6 +234,7 @@ async fn test_batch_operations_with_tools() {
         .map(|(key, value)| MemoryUpdate {
             key: MemoryKey::new(key).expect("Valid key"),
             value: value.clone(),
+            ttl: None,
         })
         .collect();
 
@@ -259,6 +267,7 @@ async fn test_complex_data_flow() {
         .store(MemoryUpdate {
             key: stage1_key.clone(),
             value: ingestion_result.output().to_string(),
+            ttl: None,
         })
         .expect("Store stage1 should succeed");
 
@@ -279,6 +288,7 @@ async fn test_complex_data_flow() {
         .store(MemoryUpdate {
             key: stage2_key.clone(),
             value: validation_result.output().to_string(),
+            ttl: None,
         })
         .expect("Store stage2 should succeed");
 
@@ -301,6 +311,7 @@ async fn test_complex_data_flow() {
         .store(MemoryUpdate {
             key: final_key.clone(),
             value: transformation_result.output().to_string(),
+            ttl: None,
         })
         .expect("Store final should succeed");
 
@@ -338,6 +349,7 @@ async fn test_resource_cleanup() {
             .store(MemoryUpdate {
                 key: temp_key,
                 value: format!("temporary_data_{}", i),
+                ttl: None,
             })
             .expect("Store should succeed");
     }
@@ -349,6 +361,7 @@ async fn test_resource_cleanup() {
             .store(MemoryUpdate {
                 key: temp_key,
                 value: "cleaned".to_string(),
+                ttl: None,
             })
             .expect("Store should succeed");
     }
@@ -359,6 +372,7 @@ async fn test_resource_cleanup() {
         .store(MemoryUpdate {
             key: test_key.clone(),
             value: "cleanup_successful".to_string(),
+            ttl: None,
         })
         .expect("Store should succeed");
 
@@ -399,6 +413,7 @@ async fn test_tool_call_integration() {
         .store(MemoryUpdate {
             key: result_key.clone(),
             value: metadata,
+            ttl: None,
         })
         .expect("Store should succeed");
 
@@ -427,6 +442,7 @@ async fn test_realistic_performance() {
             .store(MemoryUpdate {
                 key: data_key.clone(),
                 value: format!("data_value_{}", i),
+                ttl: None,
             })
             .expect("Store should succeed");
 
@@ -444,6 +460,7 @@ async fn test_realistic_performance() {
                 .store(MemoryUpdate {
                     key: result_key,
                     value: result.output().to_string(),
+                    ttl: None,
                 })
                 .expect("Store should succeed");
         }
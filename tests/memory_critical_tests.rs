@@ -18,6 +18,7 @@ async fn test_memory_store_load_critical_path() {
     let update = MemoryUpdate {
         key: key.clone(),
         value: "test_value".to_string(),
+        ttl: None,
     };
 
     memory.store(update).expect("Store should succeed");
@@ -39,18 +40,21 @@ async fn test_memory_load_many_critical_path() {
         .store(MemoryUpdate {
             key: key1.clone(),
             value: "value1".to_string(),
+            ttl: None,
         })
         .expect("Store should succeed");
     memory
         .store(MemoryUpdate {
             key: key2.clone(),
             value: "value2".to_string(),
+            ttl: None,
         })
         .expect("Store should succeed");
     memory
         .store(MemoryUpdate {
             key: key3.clone(),
             value: "value3".to_string(),
+            ttl: None,
         })
         .expect("Store should succeed");
 
@@ -74,14 +78,17 @@ async fn test_memory_store_many_critical_path() {
         MemoryUpdate {
             key: MemoryKey::new("batch_key1").expect("Valid key"),
             value: "batch_value1".to_string(),
+            ttl: None,
         },
         MemoryUpdate {
             key: MemoryKey::new("batch_key2").expect("Valid key"),
             value: "batch_value2".to_string(),
+            ttl: None,
         },
         MemoryUpdate {
             key: MemoryKey::new("batch_key3").expect("Valid key"),
             value: "batch_value3".to_string(),
+            ttl: None,
         },
     ];
 
@@ -119,6 +126,7 @@ async fn test_memory_overwrite_key() {
         .store(MemoryUpdate {
             key: key.clone(),
             value: "initial_value".to_string(),
+            ttl: None,
         })
         .expect("Store should succeed");
 
@@ -127,6 +135,7 @@ async fn test_memory_overwrite_key() {
         .store(MemoryUpdate {
             key: key.clone(),
             value: "new_value".to_string(),
+            ttl: None,
         })
         .expect("Store should succeed");
 
@@ -166,6 +175,7 @@ async fn test_memory_concurrent_access() {
             let update = MemoryUpdate {
                 key: key.clone(),
                 value: format!("value_{}", i),
+                ttl: None,
             };
 
             // Store and immediately load
@@ -205,6 +215,7 @@ async fn test_memory_large_values() {
         .store(MemoryUpdate {
             key: key.clone(),
             value: large_value.clone(),
+            ttl: None,
         })
         .expect("Store should succeed");
 
@@ -224,6 +235,7 @@ async fn test_memory_special_characters() {
         .store(MemoryUpdate {
             key: key.clone(),
             value: special_value.to_string(),
+            ttl: None,
         })
         .expect("Store should succeed");
 
@@ -245,6 +257,7 @@ async fn test_memory_performance() {
         let update = MemoryUpdate {
             key: key.clone(),
             value: format!("value_{}", i),
+            ttl: None,
         };
         memory.store(update).expect("Store should succeed");
         memory.load(&key).expect("Load should succeed");
@@ -159,6 +159,7 @@ proptest! {
             let update = MemoryUpdate {
                 key: key.clone(),
                 value: value.clone(),
+                ttl: None,
             };
 
             // Store the value
@@ -186,6 +187,7 @@ proptest! {
                 let update = MemoryUpdate {
                     key: key.clone(),
                     value: value.clone(),
+                    ttl: None,
                 };
                 memory.store(update).expect("Store should succeed");
             }
@@ -228,6 +230,7 @@ proptest! {
                 let update = MemoryUpdate {
                     key: key.clone(),
                     value: value.clone(),
+                    ttl: None,
                 };
                 memory1.store(update).expect("Store should succeed");
             }
@@ -238,6 +241,7 @@ proptest! {
                 let update = MemoryUpdate {
                     key: key.clone(),
                     value: value.clone(),
+                    ttl: None,
                 };
                 memory2.store(update).expect("Store should succeed");
             }
@@ -342,6 +346,7 @@ proptest! {
                 let update = MemoryUpdate {
                     key: key.clone(),
                     value: value.clone(),
+                    ttl: None,
                 };
                 memory.store(update).expect("Store should succeed");
             }
@@ -368,6 +373,7 @@ proptest! {
                 let update = MemoryUpdate {
                     key: key.clone(),
                     value: format!("value_{}", i),
+                    ttl: None,
                 };
                 memory.store(update).expect("Store should succeed");
             }
@@ -400,6 +406,7 @@ proptest! {
                 let update = MemoryUpdate {
                     key: key.clone(),
                     value: value.clone(),
+                    ttl: None,
                 };
                 in_memory.store(update).expect("InMemory store should succeed");
             }
@@ -451,6 +458,7 @@ proptest! {
                     let update = MemoryUpdate {
                         key: key.clone(),
                         value: format!("initial_{}", value),
+                        ttl: None,
                     };
                     memory.store(update).expect("Initial store should succeed");
                 }
@@ -468,6 +476,7 @@ proptest! {
                     let update = MemoryUpdate {
                         key: key.clone(),
                         value: format!("tx_{}", value),
+                        ttl: None,
                     };
                     tx.store(update)?;
                 }
@@ -512,6 +521,7 @@ proptest! {
                 let update = MemoryUpdate {
                     key: key.clone(),
                     value: value.clone(),
+                    ttl: None,
                 };
                 original.store(update).expect("Store should succeed");
             }
@@ -551,6 +561,7 @@ proptest! {
                     let update = MemoryUpdate {
                         key: key.clone(),
                         value: safe_value.clone(),
+                        ttl: None,
                     };
 
                     // Should not panic or corrupt memory
@@ -583,6 +594,7 @@ proptest! {
             let update = MemoryUpdate {
                 key: safe_key.clone(),
                 value: malicious_value.clone(),
+                ttl: None,
             };
 
             // Should not panic or corrupt memory, even with malicious values
@@ -611,6 +623,7 @@ proptest! {
                 let update = MemoryUpdate {
                     key: key.clone(),
                     value: value.clone(),
+                    ttl: None,
                 };
 
                 // Time the store operation
@@ -661,6 +674,7 @@ proptest! {
                     let update = MemoryUpdate {
                         key: key_clone.clone(),
                         value: value_clone.clone(),
+                        ttl: None,
                     };
                     mem.store(update).expect("Concurrent store should succeed");
                     (key_clone, value_clone)
@@ -799,6 +813,7 @@ proptest! {
                         let update = MemoryUpdate {
                             key: key.clone(),
                             value: unique_value.clone(),
+                            ttl: None,
                         };
                         mem.store(update).expect("Concurrent store should succeed");
                         local_results.push((key, unique_value));
@@ -847,6 +862,7 @@ proptest! {
                 let update = MemoryUpdate {
                     key: key.clone(),
                     value: value.clone(),
+                    ttl: None,
                 };
                 memory1.store(update).expect("Individual store should succeed");
             }
@@ -856,6 +872,7 @@ proptest! {
                 MemoryUpdate {
                     key: key.clone(),
                     value: value.clone(),
+                    ttl: None,
                 }
             }).collect();
             memory2.store_many(batch_updates).expect("Batch store should succeed");
@@ -903,6 +920,7 @@ proptest! {
                 let update = MemoryUpdate {
                     key: key.clone(),
                     value: value.clone(),
+                    ttl: None,
                 };
                 memory.store(update).expect("Initial store should succeed");
             }
@@ -913,6 +931,7 @@ proptest! {
                 let corrupt_update = MemoryUpdate {
                     key: corrupt_key.clone(),
                     value: String::new(),
+                    ttl: None,
                 };
                 let _result = memory.store(corrupt_update); // May succeed or fail, but should not corrupt
             }
@@ -959,6 +978,7 @@ mod quickcheck_tests {
             let update = MemoryUpdate {
                 key: key.clone(),
                 value: value.clone(),
+                ttl: None,
             };
 
             memory.store(update).expect("Store should succeed");
@@ -1007,6 +1027,7 @@ mod property_helpers {
             let update = MemoryUpdate {
                 key,
                 value: format!("value_{}", i),
+                ttl: None,
             };
             memory.store(update).expect("Store should succeed");
         }
@@ -1033,6 +1054,7 @@ mod property_helpers {
             let update = MemoryUpdate {
                 key: small_key.clone(),
                 value: small_value.clone(),
+                ttl: None,
             };
             memory.store(update).expect("Small store should succeed");
         }
@@ -1054,6 +1076,7 @@ mod property_helpers {
             let update = MemoryUpdate {
                 key: MemoryKey::new(&format!("large_key_{}", i)).unwrap(),
                 value: large_value.clone(),
+                ttl: None,
             };
             memory.store(update).expect("Large store should succeed");
         }
@@ -1103,6 +1126,7 @@ mod property_helpers {
             let update = MemoryUpdate {
                 key: key.clone(),
                 value: value.clone(),
+                ttl: None,
             };
             memory.store(update).expect("CI test store should succeed");
         }
@@ -51,6 +51,7 @@ fn test_memory_value_size_limits() {
     let result = memory.store(MemoryUpdate {
         key: key.clone(),
         value: reasonable_value,
+        ttl: None,
     });
     assert!(result.is_ok());
 
@@ -59,6 +60,7 @@ fn test_memory_value_size_limits() {
     let result = memory.store(MemoryUpdate {
         key: key.clone(),
         value: large_value,
+        ttl: None,
     });
     // Should either succeed with limits or reject gracefully
     // The specific behavior depends on memory backend implementation
@@ -148,6 +150,7 @@ fn test_resource_exhaustion_protection() {
         let result = memory.store(MemoryUpdate {
             key: key.clone(),
             value: format!("value_{}", i),
+            ttl: None,
         });
         // Should either succeed or fail gracefully
         assert!(result.is_ok() || result.is_err());
@@ -158,6 +161,7 @@ fn test_resource_exhaustion_protection() {
     let result = memory.store(MemoryUpdate {
         key: test_key.clone(),
         value: "still_working".to_string(),
+        ttl: None,
     });
     assert!(result.is_ok());
 }
@@ -184,6 +188,7 @@ async fn test_concurrent_access_security() {
                 let update = MemoryUpdate {
                     key: key.clone(),
                     value: format!("value_{}_{}", i, j),
+                    ttl: None,
                 };
                 let _ = mem.store(update);
                 let _ = mem.load(&key);
@@ -296,6 +301,7 @@ fn test_graceful_degradation() {
             let result = memory.store(MemoryUpdate {
                 key: key.clone(),
                 value: format!("stress_value_{}", i),
+                ttl: None,
             });
 
             // Should either work or fail gracefully